@@ -0,0 +1,706 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{Display, Write},
+};
+
+use pxp_ast::{
+    visitor::{
+        walk_anonymous_class_expression, walk_arrow_function_expression,
+        walk_backed_enum_statement, walk_class_statement, walk_closure_expression,
+        walk_function_call_expression, walk_function_closure_creation_expression,
+        walk_function_statement, walk_interface_statement, walk_method,
+        walk_method_call_expression, walk_method_closure_creation_expression, walk_new_expression,
+        walk_nullsafe_method_call_expression, walk_static_method_call_expression,
+        walk_static_method_closure_creation_expression,
+        walk_static_variable_method_call_expression,
+        walk_static_variable_method_closure_creation_expression, walk_trait_statement,
+        walk_unit_enum_statement, Visitor,
+    },
+    *,
+};
+use pxp_bytestring::{ByteStr, ByteString};
+use pxp_index::{AccessContext, FileId, Index, MemberKind, ReflectionFunctionLike, Signature};
+use pxp_inference::TypeMap;
+use pxp_span::Span;
+use pxp_type::Type;
+
+use crate::ParsedFile;
+
+/// A node in a [`CallGraph`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Callable {
+    /// The code in a file that isn't inside of any function, method or closure.
+    File(FileId),
+    /// A function, by its fully qualified name without a leading `\`.
+    Function(ByteString),
+    /// A method, by the class-like that declares it.
+    Method { class: ByteString, name: ByteString },
+    /// A closure or arrow function, which is identified by where it's written.
+    Closure { file: FileId, span: Span },
+    /// Stands in for whatever a call that couldn't be resolved might call, e.g. `$callback()`.
+    Unknown,
+}
+
+impl Display for Callable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Callable::File(_) => write!(f, "{{file}}"),
+            Callable::Function(name) => write!(f, "{}", name),
+            Callable::Method { class, name } => write!(f, "{}::{}", class, name),
+            Callable::Closure { span, .. } => write!(f, "{{closure}}@{}", span.start),
+            Callable::Unknown => write!(f, "{{unknown}}"),
+        }
+    }
+}
+
+/// A call from one [`Callable`] to another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallEdge {
+    pub caller: Callable,
+    pub callee: Callable,
+    /// The file that the call is written in.
+    pub file: FileId,
+    /// The call, or the closure, first-class callable or string that refers to the callee.
+    pub span: Span,
+}
+
+/// The functions, methods and closures in a set of files and the calls between them.
+///
+/// A call is an edge to the method that the receiver's type resolves to, so calling a method
+/// through an interface or a parent class is an edge to the interface's or the parent's method.
+/// The implementations aren't known until runtime, so [`CallGraph::reachable_from`] treats every
+/// method that overrides a reachable method as reachable too.
+///
+/// Calls that can't be resolved, such as calls through variables or to functions that aren't in
+/// the index, are edges to [`Callable::Unknown`]. Defining a closure is an edge from the code that
+/// defines it, and so is creating a first-class callable. Strings are only treated as calls when
+/// they're called directly, e.g. `'strlen'($a)`, or passed to a `callable` parameter.
+#[derive(Debug, Clone, Default)]
+pub struct CallGraph {
+    nodes: Vec<Callable>,
+    edges: Vec<CallEdge>,
+    // The methods that override each method, including the implementations of interface methods.
+    overrides: HashMap<Callable, Vec<Callable>>,
+}
+
+impl CallGraph {
+    /// Build the call graph of a set of files. The `TypeMap` for each file is expected at the same
+    /// position in `maps`, and is used to find the type of the receiver of each method call.
+    pub fn build(files: &[ParsedFile], index: &Index, maps: &[TypeMap]) -> Self {
+        let mut visitor = CallGraphVisitor {
+            index,
+            types: &TypeMap::new(),
+            file: FileId::new(0),
+            classes: Vec::new(),
+            callers: Vec::new(),
+            nodes: Vec::new(),
+            seen: HashSet::new(),
+            edges: Vec::new(),
+            overrides: HashMap::new(),
+        };
+
+        for (file, types) in files.iter().zip(maps.iter()) {
+            visitor.types = types;
+            visitor.file = file.file;
+            visitor.callers = vec![Callable::File(file.file)];
+            visitor.node(Callable::File(file.file));
+            visitor.visit(file.ast);
+        }
+
+        visitor.node(Callable::Unknown);
+
+        Self {
+            nodes: visitor.nodes,
+            edges: visitor.edges,
+            overrides: visitor.overrides,
+        }
+    }
+
+    /// Every callable in the graph, in the order they were found.
+    pub fn nodes(&self) -> &[Callable] {
+        &self.nodes
+    }
+
+    pub fn edges(&self) -> &[CallEdge] {
+        &self.edges
+    }
+
+    /// The calls to the given callable.
+    pub fn callers_of(&self, callee: &Callable) -> Vec<&CallEdge> {
+        self.edges
+            .iter()
+            .filter(|edge| edge.callee == *callee)
+            .collect()
+    }
+
+    /// The calls made by the given callable.
+    pub fn callees_of(&self, caller: &Callable) -> Vec<&CallEdge> {
+        self.edges
+            .iter()
+            .filter(|edge| edge.caller == *caller)
+            .collect()
+    }
+
+    /// Find every callable that can be reached from the entry points, including the entry points
+    /// themselves.
+    ///
+    /// When [`Callable::Unknown`] is reachable, some of the calls couldn't be resolved and anything
+    /// else could be reachable too.
+    pub fn reachable_from(&self, entry_points: &[Callable]) -> HashSet<Callable> {
+        let mut callees: HashMap<&Callable, Vec<&Callable>> = HashMap::new();
+
+        for edge in self.edges.iter() {
+            callees.entry(&edge.caller).or_default().push(&edge.callee);
+        }
+
+        let mut reachable = HashSet::new();
+        let mut pending = entry_points.iter().collect::<Vec<_>>();
+
+        while let Some(callable) = pending.pop() {
+            if !reachable.insert(callable.clone()) {
+                continue;
+            }
+
+            pending.extend(callees.get(callable).into_iter().flatten());
+            pending.extend(self.overrides.get(callable).into_iter().flatten());
+        }
+
+        reachable
+    }
+
+    /// Render the graph in Graphviz's DOT format.
+    pub fn to_dot(&self) -> String {
+        let ids = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(id, node)| (node, id))
+            .collect::<HashMap<_, _>>();
+
+        let mut dot = String::from("digraph calls {\n");
+
+        for (id, node) in self.nodes.iter().enumerate() {
+            let _ = writeln!(dot, "    n{} [label={:?}];", id, node.to_string());
+        }
+
+        let mut seen = HashSet::new();
+
+        for edge in self.edges.iter() {
+            let edge = (ids[&edge.caller], ids[&edge.callee]);
+
+            if seen.insert(edge) {
+                let _ = writeln!(dot, "    n{} -> n{};", edge.0, edge.1);
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+struct CallGraphVisitor<'a> {
+    index: &'a Index,
+    types: &'a TypeMap,
+    file: FileId,
+    // The class-like that we're currently inside of, used to resolve `self`, `static` and `$this`.
+    // Anonymous classes push `None`, and their methods are treated as part of the code around them.
+    classes: Vec<Option<ByteString>>,
+    callers: Vec<Callable>,
+    nodes: Vec<Callable>,
+    seen: HashSet<Callable>,
+    edges: Vec<CallEdge>,
+    overrides: HashMap<Callable, Vec<Callable>>,
+}
+
+impl<'a> CallGraphVisitor<'a> {
+    fn node(&mut self, callable: Callable) {
+        if self.seen.insert(callable.clone()) {
+            self.nodes.push(callable);
+        }
+    }
+
+    fn call(&mut self, callee: Callable, span: Span) {
+        self.node(callee.clone());
+        self.edges.push(CallEdge {
+            caller: self.callers.last().cloned().unwrap_or(Callable::Unknown),
+            callee,
+            file: self.file,
+            span,
+        });
+    }
+
+    fn with_class(&mut self, name: Option<&Name>, f: impl FnOnce(&mut Self)) {
+        self.classes.push(
+            name.and_then(|name| name.as_resolved())
+                .map(|name| name.resolved.clone()),
+        );
+        f(self);
+        self.classes.pop();
+    }
+
+    fn with_caller(&mut self, callable: Callable, f: impl FnOnce(&mut Self)) {
+        self.node(callable.clone());
+        self.callers.push(callable);
+        f(self);
+        self.callers.pop();
+    }
+
+    fn context(&self) -> AccessContext {
+        match self.classes.last() {
+            Some(Some(class)) => AccessContext::Class(class.clone()),
+            _ => AccessContext::Global,
+        }
+    }
+
+    /// The type of the receiver of a method call.
+    fn receiver(&self, target: &Expression) -> Type<ResolvedName> {
+        match &target.kind {
+            ExpressionKind::Name(name) => match &name.kind {
+                NameKind::Resolved(name) => Type::Named(name.clone()),
+                NameKind::Special(special) => match special.kind {
+                    SpecialNameKind::Self_ => Type::SelfReference,
+                    SpecialNameKind::Static => Type::StaticReference,
+                    SpecialNameKind::Parent => Type::ParentReference,
+                },
+                NameKind::Unresolved(_) => Type::Mixed,
+            },
+            ExpressionKind::Self_(_) => Type::SelfReference,
+            ExpressionKind::Static(_) => Type::StaticReference,
+            ExpressionKind::Parent(_) => Type::ParentReference,
+            ExpressionKind::Variable(variable)
+                if variable.is_simple() && variable.to_simple().symbol == b"$this" =>
+            {
+                Type::StaticReference
+            }
+            _ => match self.types.resolve(target.id) {
+                Type::This => Type::StaticReference,
+                r#type => r#type.clone(),
+            },
+        }
+    }
+
+    /// Find a function by the name it's called with, along with its signature.
+    fn function(&self, name: &[u8]) -> Option<(Callable, Signature)> {
+        let name = name.strip_prefix(b"\\").unwrap_or(name);
+        let function = self.index.get_function(ByteString::from(name))?;
+
+        Some((
+            Callable::Function(function.get_name().to_bytestring()),
+            function.effective_signature().clone(),
+        ))
+    }
+
+    /// Find the function that a name refers to, falling back to the global namespace for
+    /// unqualified names.
+    fn function_for_name(&self, name: &Name) -> Option<(Callable, Signature)> {
+        match &name.kind {
+            NameKind::Resolved(name) => self
+                .function(name.resolved.as_ref())
+                .or_else(|| self.function(name.original.as_ref())),
+            NameKind::Unresolved(name) => self.function(name.symbol.as_ref()),
+            NameKind::Special(_) => None,
+        }
+    }
+
+    fn method(
+        &self,
+        receiver: &Type<ResolvedName>,
+        name: &ByteStr,
+    ) -> Option<(Callable, Option<Signature>)> {
+        let method = self
+            .index
+            .members_of(receiver, self.context())
+            .into_iter()
+            .find(|candidate| {
+                candidate.get_kind() == MemberKind::Method
+                    && candidate.get_name().eq_ignore_ascii_case(name)
+            })?;
+
+        Some((
+            Callable::Method {
+                class: method.get_declaring_class().to_bytestring(),
+                name: method.get_name().to_bytestring(),
+            },
+            method.get_signature().cloned(),
+        ))
+    }
+
+    /// Add an edge for calling a method on a receiver, which goes to [`Callable::Unknown`] when
+    /// the method can't be found.
+    fn method_call(
+        &mut self,
+        target: &Expression,
+        method: Option<&ByteStr>,
+        arguments: Option<&ArgumentList>,
+        span: Span,
+    ) {
+        let method = method.and_then(|method| self.method(&self.receiver(target), method));
+
+        match method {
+            Some((callee, signature)) => {
+                self.call(callee, span);
+
+                if let Some(arguments) = arguments {
+                    self.callable_arguments(signature.as_ref(), arguments);
+                }
+            }
+            None => self.call(Callable::Unknown, span),
+        }
+    }
+
+    /// Add an edge for each string that names a function and is passed to a `callable` parameter,
+    /// e.g. `array_map('trim', $lines)`.
+    fn callable_arguments(&mut self, signature: Option<&Signature>, arguments: &ArgumentList) {
+        let Some(signature) = signature else {
+            return;
+        };
+
+        for (position, parameter) in signature.get_parameters().iter().enumerate() {
+            if !parameter.get_type().is_some_and(is_callable) {
+                continue;
+            }
+
+            let Some(argument) = arguments.argument_for_parameter(position, parameter.get_name())
+            else {
+                continue;
+            };
+
+            let value = match argument {
+                Argument::Positional(argument) => &argument.value,
+                Argument::Named(argument) => &argument.value,
+            };
+
+            if let Some(callee) = self.string_callable(value) {
+                self.call(callee, value.span);
+            }
+        }
+    }
+
+    /// The function or static method that a string literal names, e.g. `'trim'` or `'Foo::bar'`.
+    fn string_callable(&self, expression: &Expression) -> Option<Callable> {
+        let ExpressionKind::Literal(literal) = &expression.kind else {
+            return None;
+        };
+
+        let value = literal.string_value()?;
+        let value = value.value();
+
+        match value.iter().position(|byte| *byte == b':') {
+            Some(colon) if value[colon..].starts_with(b"::") => {
+                let class = &value[..colon];
+                let class = class.strip_prefix(b"\\").unwrap_or(class);
+                let receiver = Type::Named(ResolvedName {
+                    resolved: class.into(),
+                    original: class.into(),
+                });
+
+                self.method(&receiver, ByteStr::new(&value[colon + 2..]))
+                    .map(|(callee, _)| callee)
+            }
+            Some(_) => None,
+            None => self.function(value).map(|(callee, _)| callee),
+        }
+    }
+
+    /// Record the methods that a method overrides, so that they can reach it.
+    fn overrides(&mut self, class: &ByteString, method: &SimpleIdentifier) {
+        let overrider = Callable::Method {
+            class: class.clone(),
+            name: method.symbol.clone(),
+        };
+
+        let mut visited = HashSet::new();
+        let mut pending = self.ancestors(class);
+
+        while let Some(ancestor) = pending.pop() {
+            if !visited.insert(ancestor.clone()) {
+                continue;
+            }
+
+            let Some(reflection) = self.index.get_class(ancestor.clone()) else {
+                continue;
+            };
+
+            if let Some(overridden) = reflection
+                .get_methods()
+                .into_iter()
+                .find(|candidate| candidate.get_name().eq_ignore_ascii_case(&method.symbol))
+            {
+                self.overrides
+                    .entry(Callable::Method {
+                        class: ancestor.clone(),
+                        name: overridden.get_name().to_bytestring(),
+                    })
+                    .or_default()
+                    .push(overrider.clone());
+            }
+
+            pending.extend(self.ancestors(&ancestor));
+        }
+    }
+
+    /// The parent and interfaces that a class-like directly extends or implements.
+    fn ancestors(&self, class: &ByteString) -> Vec<ByteString> {
+        let Some(class) = self.index.get_class(class.clone()) else {
+            return Vec::new();
+        };
+
+        class
+            .get_parent_name()
+            .into_iter()
+            .chain(class.get_interface_names())
+            .map(|name| name.to_bytestring())
+            .collect()
+    }
+}
+
+impl<'a> Visitor for CallGraphVisitor<'a> {
+    fn visit_class_statement(&mut self, node: &ClassStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_class_statement(visitor, node)
+        });
+    }
+
+    fn visit_interface_statement(&mut self, node: &InterfaceStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_interface_statement(visitor, node)
+        });
+    }
+
+    fn visit_trait_statement(&mut self, node: &TraitStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_trait_statement(visitor, node)
+        });
+    }
+
+    fn visit_unit_enum_statement(&mut self, node: &UnitEnumStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_unit_enum_statement(visitor, node)
+        });
+    }
+
+    fn visit_backed_enum_statement(&mut self, node: &BackedEnumStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_backed_enum_statement(visitor, node)
+        });
+    }
+
+    fn visit_anonymous_class_expression(&mut self, node: &AnonymousClassExpression) {
+        self.with_class(None, |visitor| {
+            walk_anonymous_class_expression(visitor, node)
+        });
+    }
+
+    fn visit_function_statement(&mut self, node: &FunctionStatement) {
+        let name = match &node.name.kind {
+            NameKind::Resolved(name) => name.resolved.clone(),
+            _ => node.name.symbol().clone(),
+        };
+
+        self.with_caller(Callable::Function(name), |visitor| {
+            walk_function_statement(visitor, node)
+        });
+    }
+
+    fn visit_method(&mut self, node: &Method) {
+        let Some(Some(class)) = self.classes.last().cloned() else {
+            return walk_method(self, node);
+        };
+
+        self.overrides(&class, &node.name);
+
+        let method = Callable::Method {
+            class,
+            name: node.name.symbol.clone(),
+        };
+
+        self.with_caller(method, |visitor| walk_method(visitor, node));
+    }
+
+    fn visit_closure_expression(&mut self, node: &ClosureExpression) {
+        let closure = Callable::Closure {
+            file: self.file,
+            span: node.span,
+        };
+
+        self.call(closure.clone(), node.span);
+        self.with_caller(closure, |visitor| walk_closure_expression(visitor, node));
+    }
+
+    fn visit_arrow_function_expression(&mut self, node: &ArrowFunctionExpression) {
+        let closure = Callable::Closure {
+            file: self.file,
+            span: node.span,
+        };
+
+        self.call(closure.clone(), node.span);
+        self.with_caller(closure, |visitor| {
+            walk_arrow_function_expression(visitor, node)
+        });
+    }
+
+    fn visit_function_call_expression(&mut self, node: &FunctionCallExpression) {
+        let function = match &node.target.kind {
+            ExpressionKind::Name(name) => self
+                .function_for_name(name)
+                .map(|(callee, signature)| (callee, Some(signature))),
+            ExpressionKind::Literal(_) => self
+                .string_callable(&node.target)
+                .map(|callee| (callee, None)),
+            _ => None,
+        };
+
+        match function {
+            Some((callee, signature)) => {
+                self.call(callee, node.span);
+                self.callable_arguments(signature.as_ref(), &node.arguments);
+            }
+            None => self.call(Callable::Unknown, node.span),
+        }
+
+        walk_function_call_expression(self, node);
+    }
+
+    fn visit_function_closure_creation_expression(
+        &mut self,
+        node: &FunctionClosureCreationExpression,
+    ) {
+        let function = match &node.target.kind {
+            ExpressionKind::Name(name) => self.function_for_name(name),
+            _ => None,
+        };
+
+        match function {
+            Some((callee, _)) => self.call(callee, node.span),
+            None => self.call(Callable::Unknown, node.span),
+        }
+
+        walk_function_closure_creation_expression(self, node);
+    }
+
+    fn visit_method_call_expression(&mut self, node: &MethodCallExpression) {
+        self.method_call(
+            &node.target,
+            identifier(&node.method).map(|method| method.symbol.as_ref()),
+            Some(&node.arguments),
+            node.span,
+        );
+
+        walk_method_call_expression(self, node);
+    }
+
+    fn visit_nullsafe_method_call_expression(&mut self, node: &NullsafeMethodCallExpression) {
+        self.method_call(
+            &node.target,
+            identifier(&node.method).map(|method| method.symbol.as_ref()),
+            Some(&node.arguments),
+            node.span,
+        );
+
+        walk_nullsafe_method_call_expression(self, node);
+    }
+
+    fn visit_static_method_call_expression(&mut self, node: &StaticMethodCallExpression) {
+        self.method_call(
+            &node.target,
+            node.method
+                .is_simple()
+                .then(|| node.method.to_simple().symbol.as_ref()),
+            Some(&node.arguments),
+            node.span,
+        );
+
+        walk_static_method_call_expression(self, node);
+    }
+
+    fn visit_static_variable_method_call_expression(
+        &mut self,
+        node: &StaticVariableMethodCallExpression,
+    ) {
+        self.call(Callable::Unknown, node.span);
+
+        walk_static_variable_method_call_expression(self, node);
+    }
+
+    fn visit_method_closure_creation_expression(&mut self, node: &MethodClosureCreationExpression) {
+        self.method_call(
+            &node.target,
+            identifier(&node.method).map(|method| method.symbol.as_ref()),
+            None,
+            node.span,
+        );
+
+        walk_method_closure_creation_expression(self, node);
+    }
+
+    fn visit_static_method_closure_creation_expression(
+        &mut self,
+        node: &StaticMethodClosureCreationExpression,
+    ) {
+        self.method_call(
+            &node.target,
+            node.method
+                .is_simple()
+                .then(|| node.method.to_simple().symbol.as_ref()),
+            None,
+            node.span,
+        );
+
+        walk_static_method_closure_creation_expression(self, node);
+    }
+
+    fn visit_static_variable_method_closure_creation_expression(
+        &mut self,
+        node: &StaticVariableMethodClosureCreationExpression,
+    ) {
+        self.call(Callable::Unknown, node.span);
+
+        walk_static_variable_method_closure_creation_expression(self, node);
+    }
+
+    fn visit_new_expression(&mut self, node: &NewExpression) {
+        let receiver = self.receiver(&node.target);
+        let known = match &receiver {
+            Type::Named(name) => self.index.get_class(name.resolved.clone()).is_some(),
+            Type::SelfReference | Type::StaticReference | Type::ParentReference => true,
+            _ => false,
+        };
+
+        // Anonymous classes are visited on their own, and a class without a constructor doesn't
+        // run any code when it's created.
+        if !matches!(node.target.kind, ExpressionKind::AnonymousClass(_)) {
+            match self.method(&receiver, ByteStr::new(b"__construct")) {
+                Some((callee, signature)) => {
+                    self.call(callee, node.span);
+
+                    if let Some(arguments) = &node.arguments {
+                        self.callable_arguments(signature.as_ref(), arguments);
+                    }
+                }
+                None if !known => self.call(Callable::Unknown, node.span),
+                None => {}
+            }
+        }
+
+        walk_new_expression(self, node);
+    }
+}
+
+fn identifier(member: &Expression) -> Option<&SimpleIdentifier> {
+    match &member.kind {
+        ExpressionKind::Identifier(identifier) if identifier.is_simple() => {
+            Some(identifier.to_simple())
+        }
+        _ => None,
+    }
+}
+
+/// Whether a parameter accepts callables, e.g. `callable`, `?callable` or `callable(int): void`.
+fn is_callable(r#type: &Type<ResolvedName>) -> bool {
+    match r#type {
+        Type::Callable | Type::CallableString | Type::CallableSignature(..) => true,
+        Type::Nullable(inner) => is_callable(inner),
+        Type::Union(types) => types.iter().any(is_callable),
+        _ => false,
+    }
+}
@@ -0,0 +1,415 @@
+use pxp_ast::{
+    visitor::{
+        walk_anonymous_class_expression, walk_backed_enum_statement, walk_braced_namespace,
+        walk_class_statement, walk_constant_fetch_expression, walk_function_call_expression,
+        walk_interface_statement, walk_method_call_expression, walk_new_expression,
+        walk_nullsafe_method_call_expression, walk_static_method_call_expression,
+        walk_trait_statement, walk_unbraced_namespace, walk_unit_enum_statement, Visitor,
+    },
+    *,
+};
+use pxp_bytestring::{ByteStr, ByteString};
+use pxp_index::{AccessContext, Index, MemberKind, ReflectionFunctionLike, Signature};
+use pxp_inference::TypeMap;
+use pxp_span::Span;
+use pxp_type::{GenericTypeArgument, Type};
+
+use crate::passes::resolve_function;
+
+/// How sure we are that a [`ClassStringRef`] refers to a class-like.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ClassStringConfidence {
+    /// A string that looks like a qualified class name, but isn't in the index.
+    Low,
+    /// A string that is the name of a class-like in the index.
+    High,
+    /// A `::class` fetch, or a string passed to a `class-string` parameter.
+    Certain,
+}
+
+/// A class-like that is referred to by a string, rather than by a name in the code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassStringRef {
+    pub span: Span,
+    /// The fully qualified name of the class-like, without a leading `\`.
+    pub name: ByteString,
+    pub confidence: ClassStringConfidence,
+}
+
+/// Find the class-likes that a file refers to by string, using the default
+/// [`ClassStringFinder`].
+pub fn class_string_references(
+    ast: &[Statement],
+    types: &TypeMap,
+    index: &Index,
+) -> Vec<ClassStringRef> {
+    ClassStringFinder::new().find(ast, types, index)
+}
+
+/// Collects the class-likes that are referred to by strings, such as `Foo::class` fetches and
+/// class names in configuration arrays, so that they can be counted as dependencies.
+///
+/// Escape sequences in string literals are decoded before they're compared, so `'App\\User'` and
+/// `'App\User'` both refer to `App\User`. Strings that look like a qualified class name but aren't
+/// in the index are only included when [`ClassStringFinder::with_unknown_classes`] is enabled.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ClassStringFinder {
+    unknown_classes: bool,
+}
+
+impl ClassStringFinder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Include strings that look like a qualified class name, e.g. `'App\Jobs\SendEmail'`, even
+    /// when the class-like isn't in the index.
+    pub fn with_unknown_classes(mut self, unknown_classes: bool) -> Self {
+        self.unknown_classes = unknown_classes;
+        self
+    }
+
+    /// Find the references in a file, sorted by their position. A string that is found in more than
+    /// one way is only reported once, with the highest confidence.
+    pub fn find(&self, ast: &[Statement], types: &TypeMap, index: &Index) -> Vec<ClassStringRef> {
+        let mut visitor = ClassStringVisitor {
+            index,
+            types,
+            unknown_classes: self.unknown_classes,
+            namespace: None,
+            classes: Vec::new(),
+            references: Vec::new(),
+        };
+
+        visitor.visit(ast);
+
+        let mut references = visitor.references;
+        references.sort_by(|a, b| {
+            (a.span.start, a.span.end, b.confidence).cmp(&(b.span.start, b.span.end, a.confidence))
+        });
+        references.dedup_by_key(|reference| reference.span);
+
+        references
+    }
+}
+
+struct ClassStringVisitor<'a> {
+    index: &'a Index,
+    types: &'a TypeMap,
+    unknown_classes: bool,
+    // The namespace that we're currently inside of, used to resolve unqualified function calls.
+    namespace: Option<ByteString>,
+    // The class-like that we're currently inside of, used to resolve `self`, `static` and `$this`.
+    classes: Vec<Option<ByteString>>,
+    references: Vec<ClassStringRef>,
+}
+
+impl<'a> ClassStringVisitor<'a> {
+    fn push(&mut self, span: Span, name: ByteString, confidence: ClassStringConfidence) {
+        self.references.push(ClassStringRef {
+            span,
+            name,
+            confidence,
+        });
+    }
+
+    fn with_class(&mut self, name: Option<&Name>, f: impl FnOnce(&mut Self)) {
+        self.classes.push(
+            name.and_then(|name| name.as_resolved())
+                .map(|name| name.resolved.clone()),
+        );
+        f(self);
+        self.classes.pop();
+    }
+
+    fn context(&self) -> AccessContext {
+        match self.classes.last() {
+            Some(Some(class)) => AccessContext::Class(class.clone()),
+            _ => AccessContext::Global,
+        }
+    }
+
+    /// The type of the receiver of a method call.
+    fn receiver(&self, target: &Expression) -> Type<ResolvedName> {
+        match &target.kind {
+            ExpressionKind::Name(name) => match &name.kind {
+                NameKind::Resolved(name) => Type::Named(name.clone()),
+                NameKind::Special(special) => match special.kind {
+                    SpecialNameKind::Self_ => Type::SelfReference,
+                    SpecialNameKind::Static => Type::StaticReference,
+                    SpecialNameKind::Parent => Type::ParentReference,
+                },
+                NameKind::Unresolved(_) => Type::Mixed,
+            },
+            ExpressionKind::Self_(_) => Type::SelfReference,
+            ExpressionKind::Static(_) => Type::StaticReference,
+            ExpressionKind::Parent(_) => Type::ParentReference,
+            ExpressionKind::Variable(variable)
+                if variable.is_simple() && variable.to_simple().symbol == b"$this" =>
+            {
+                Type::StaticReference
+            }
+            _ => match self.types.resolve(target.id) {
+                Type::This => Type::StaticReference,
+                r#type => r#type.clone(),
+            },
+        }
+    }
+
+    fn function(&self, target: &Expression) -> Option<Signature> {
+        let ExpressionKind::Name(name) = &target.kind else {
+            return None;
+        };
+
+        let function = resolve_function(self.index, self.namespace.as_ref(), name)?;
+
+        Some(function.effective_signature().clone())
+    }
+
+    fn method(&self, receiver: &Type<ResolvedName>, name: &ByteStr) -> Option<Signature> {
+        self.index
+            .members_of(receiver, self.context())
+            .into_iter()
+            .find(|candidate| {
+                candidate.get_kind() == MemberKind::Method
+                    && candidate.get_name().eq_ignore_ascii_case(name)
+            })
+            .and_then(|candidate| candidate.get_signature().cloned())
+    }
+
+    /// Report the string literals that are passed to `class-string` parameters.
+    fn arguments(&mut self, signature: Option<Signature>, arguments: &ArgumentList) {
+        let Some(signature) = signature else {
+            return;
+        };
+
+        for (position, parameter) in signature.get_parameters().iter().enumerate() {
+            if !parameter.get_type().is_some_and(is_class_string) {
+                continue;
+            }
+
+            let values: Vec<&Expression> = if parameter.is_variadic() {
+                arguments
+                    .arguments
+                    .iter()
+                    .skip(position)
+                    .map_while(|argument| match argument {
+                        Argument::Positional(argument) if argument.ellipsis.is_none() => {
+                            Some(&argument.value)
+                        }
+                        _ => None,
+                    })
+                    .collect()
+            } else {
+                arguments
+                    .argument_for_parameter(position, parameter.get_name())
+                    .map(|argument| match argument {
+                        Argument::Positional(argument) => &argument.value,
+                        Argument::Named(argument) => &argument.value,
+                    })
+                    .into_iter()
+                    .collect()
+            };
+
+            for value in values {
+                let ExpressionKind::Literal(literal) = &value.kind else {
+                    continue;
+                };
+
+                if let Some(name) = class_name(literal) {
+                    let name = self
+                        .index
+                        .get_class(name.clone())
+                        .map(|class| class.name().to_bytestring())
+                        .unwrap_or(name);
+
+                    self.push(value.span, name, ClassStringConfidence::Certain);
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Visitor for ClassStringVisitor<'a> {
+    fn visit_unbraced_namespace(&mut self, node: &UnbracedNamespace) {
+        self.namespace = Some(node.name.symbol.clone());
+        walk_unbraced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_braced_namespace(&mut self, node: &BracedNamespace) {
+        self.namespace = node.name.as_ref().map(|name| name.symbol.clone());
+        walk_braced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_class_statement(&mut self, node: &ClassStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_class_statement(visitor, node)
+        });
+    }
+
+    fn visit_interface_statement(&mut self, node: &InterfaceStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_interface_statement(visitor, node)
+        });
+    }
+
+    fn visit_trait_statement(&mut self, node: &TraitStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_trait_statement(visitor, node)
+        });
+    }
+
+    fn visit_unit_enum_statement(&mut self, node: &UnitEnumStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_unit_enum_statement(visitor, node)
+        });
+    }
+
+    fn visit_backed_enum_statement(&mut self, node: &BackedEnumStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_backed_enum_statement(visitor, node)
+        });
+    }
+
+    fn visit_anonymous_class_expression(&mut self, node: &AnonymousClassExpression) {
+        self.with_class(None, |visitor| {
+            walk_anonymous_class_expression(visitor, node)
+        });
+    }
+
+    fn visit_constant_fetch_expression(&mut self, node: &ConstantFetchExpression) {
+        if node.is_class() {
+            let name = match &node.target.kind {
+                ExpressionKind::Name(name) if name.is_resolved() => {
+                    Some(name.to_resolved().resolved.clone())
+                }
+                // `self::class` and `$object::class` are only known through their type.
+                _ => match self.types.resolve(node.id) {
+                    Type::Generic(base, arguments)
+                        if matches!(base.as_ref(), Type::ClassString) =>
+                    {
+                        match arguments.as_slice() {
+                            [GenericTypeArgument {
+                                r#type: Type::Named(name),
+                                ..
+                            }] => Some(name.resolved.clone()),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                },
+            };
+
+            if let Some(name) = name {
+                self.push(node.span, name, ClassStringConfidence::Certain);
+            }
+        }
+
+        walk_constant_fetch_expression(self, node);
+    }
+
+    fn visit_literal(&mut self, node: &Literal) {
+        let Some(name) = class_name(node) else {
+            return;
+        };
+
+        if let Some(class) = self.index.get_class(name.clone()) {
+            let name = class.name().to_bytestring();
+            self.push(node.span, name, ClassStringConfidence::High);
+        } else if self.unknown_classes && name.contains(&b'\\') {
+            self.push(node.span, name, ClassStringConfidence::Low);
+        }
+    }
+
+    fn visit_function_call_expression(&mut self, node: &FunctionCallExpression) {
+        let signature = self.function(&node.target);
+        self.arguments(signature, &node.arguments);
+
+        walk_function_call_expression(self, node);
+    }
+
+    fn visit_method_call_expression(&mut self, node: &MethodCallExpression) {
+        if let Some(method) = identifier(&node.method) {
+            let signature = self.method(&self.receiver(&node.target), method.symbol.as_ref());
+            self.arguments(signature, &node.arguments);
+        }
+
+        walk_method_call_expression(self, node);
+    }
+
+    fn visit_nullsafe_method_call_expression(&mut self, node: &NullsafeMethodCallExpression) {
+        if let Some(method) = identifier(&node.method) {
+            let signature = self.method(&self.receiver(&node.target), method.symbol.as_ref());
+            self.arguments(signature, &node.arguments);
+        }
+
+        walk_nullsafe_method_call_expression(self, node);
+    }
+
+    fn visit_static_method_call_expression(&mut self, node: &StaticMethodCallExpression) {
+        if node.method.is_simple() {
+            let method = node.method.to_simple();
+            let signature = self.method(&self.receiver(&node.target), method.symbol.as_ref());
+            self.arguments(signature, &node.arguments);
+        }
+
+        walk_static_method_call_expression(self, node);
+    }
+
+    fn visit_new_expression(&mut self, node: &NewExpression) {
+        if let Some(arguments) = &node.arguments {
+            let signature = self.method(&self.receiver(&node.target), ByteStr::new(b"__construct"));
+            self.arguments(signature, arguments);
+        }
+
+        walk_new_expression(self, node);
+    }
+
+    // Docblocks aren't code, so the strings inside of them are never class names.
+    fn visit_doc_block(&mut self, _: &DocBlock) {}
+}
+
+fn identifier(member: &Expression) -> Option<&SimpleIdentifier> {
+    match &member.kind {
+        ExpressionKind::Identifier(identifier) if identifier.is_simple() => {
+            Some(identifier.to_simple())
+        }
+        _ => None,
+    }
+}
+
+/// The class name that a string literal contains, if it looks like one.
+fn class_name(literal: &Literal) -> Option<ByteString> {
+    let value = literal.string_value()?;
+    let value = value.value();
+    let name = <[u8]>::strip_prefix(value, b"\\").unwrap_or(value);
+
+    is_class_name(name).then(|| ByteString::from(name))
+}
+
+/// Whether a type only accepts class names, e.g. `class-string`, `class-string<Foo>` or
+/// `?class-string`.
+fn is_class_string(r#type: &Type<ResolvedName>) -> bool {
+    match r#type {
+        Type::ClassString => true,
+        Type::Generic(base, _) | Type::Nullable(base) => is_class_string(base),
+        Type::Union(types) => types
+            .iter()
+            .filter(|r#type| !matches!(r#type, Type::Null))
+            .all(is_class_string),
+        _ => false,
+    }
+}
+
+/// Whether a string is a valid, possibly qualified, class name, e.g. `App\Models\User`.
+fn is_class_name(name: &[u8]) -> bool {
+    !name.is_empty()
+        && name.split(|byte| *byte == b'\\').all(|segment| {
+            matches!(segment.first(), Some(byte) if byte.is_ascii_alphabetic() || *byte == b'_' || *byte >= 0x80)
+                && segment
+                    .iter()
+                    .all(|byte| byte.is_ascii_alphanumeric() || *byte == b'_' || *byte >= 0x80)
+        })
+}
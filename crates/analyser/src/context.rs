@@ -0,0 +1,42 @@
+use pxp_index::Index;
+use pxp_inference::TypeMap;
+
+use crate::TargetEnvironment;
+
+/// The shared information that every analysis pass has access to.
+///
+/// The `TypeMap` is expected to have been generated for the same AST that is being analysed.
+#[derive(Debug, Clone, Copy)]
+pub struct AnalyserContext<'a> {
+    index: &'a Index,
+    types: &'a TypeMap,
+    target: Option<&'a TargetEnvironment>,
+}
+
+impl<'a> AnalyserContext<'a> {
+    pub fn new(index: &'a Index, types: &'a TypeMap) -> Self {
+        Self {
+            index,
+            types,
+            target: None,
+        }
+    }
+
+    /// Analyse the code for a specific environment. Without one, every branch is assumed to run.
+    pub fn with_target(mut self, target: &'a TargetEnvironment) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn index(&self) -> &'a Index {
+        self.index
+    }
+
+    pub fn types(&self) -> &'a TypeMap {
+        self.types
+    }
+
+    pub fn target(&self) -> Option<&'a TargetEnvironment> {
+        self.target
+    }
+}
@@ -0,0 +1,552 @@
+use std::fmt::Display;
+
+use pxp_bytestring::ByteString;
+use pxp_diagnostics::{DiagnosticCategory, DiagnosticKind};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnalyserDiagnostic {
+    TooFewArguments {
+        callable: ByteString,
+        given: usize,
+        required: usize,
+    },
+    TooManyArguments {
+        callable: ByteString,
+        given: usize,
+        maximum: usize,
+    },
+    MissingArgument {
+        callable: ByteString,
+        parameter: ByteString,
+    },
+    UnknownNamedArgument {
+        callable: ByteString,
+        parameter: ByteString,
+    },
+    NamedArgumentOverwritesPreviousArgument {
+        callable: ByteString,
+        parameter: ByteString,
+    },
+    OverrideWithoutParentMethod {
+        class: ByteString,
+        method: ByteString,
+    },
+    ImplicitFallthrough,
+    EnumUsesTraitWithProperties {
+        r#enum: ByteString,
+        r#trait: ByteString,
+    },
+    UndefinedVariable {
+        variable: ByteString,
+    },
+    PossiblyUndefinedVariable {
+        variable: ByteString,
+    },
+    UncheckedVariableScope {
+        construct: ByteString,
+    },
+    RedundantTypeCheck {
+        function: ByteString,
+        r#type: ByteString,
+        result: bool,
+    },
+    RedundantStrictComparison {
+        left: ByteString,
+        right: ByteString,
+        result: bool,
+    },
+    InArrayWithEmptyArray,
+    DeprecatedSymbol {
+        symbol: ByteString,
+        message: Option<ByteString>,
+    },
+    InvalidOctalLiteral {
+        literal: ByteString,
+    },
+    TraitMethodCollision {
+        class: ByteString,
+        method: ByteString,
+        r#trait: ByteString,
+        other: ByteString,
+    },
+    MissingTraitAbstractMethod {
+        class: ByteString,
+        r#trait: ByteString,
+        method: ByteString,
+    },
+    IncompatibleTraitMethodSignature {
+        class: ByteString,
+        r#trait: ByteString,
+        method: ByteString,
+    },
+    TraitPropertyConflict {
+        class: ByteString,
+        r#trait: ByteString,
+        other: ByteString,
+        property: ByteString,
+    },
+    UnescapedOutput {
+        source: ByteString,
+        output: ByteString,
+    },
+    MissingReturn {
+        function: ByteString,
+        r#type: ByteString,
+    },
+    ReturnWithoutValue {
+        function: ByteString,
+        r#type: ByteString,
+    },
+    VoidFunctionReturnsValue {
+        function: ByteString,
+    },
+    DuplicateArrayKey {
+        key: ByteString,
+    },
+    ImplicitArrayKeyCast {
+        key: ByteString,
+        cast: ByteString,
+    },
+    InvalidFormatString {
+        function: ByteString,
+        message: ByteString,
+    },
+    TooFewFormatArguments {
+        function: ByteString,
+        given: usize,
+        required: usize,
+    },
+    TooManyFormatArguments {
+        function: ByteString,
+        given: usize,
+        used: usize,
+    },
+    FormatArgumentTypeMismatch {
+        function: ByteString,
+        specifier: ByteString,
+        r#type: ByteString,
+    },
+    EmptyMagicConstant {
+        constant: ByteString,
+        context: ByteString,
+    },
+    MethodConstantOutsideMethod,
+    UnsupportedOperandTypes {
+        operator: ByteString,
+        left: ByteString,
+        right: ByteString,
+    },
+    InvalidStringConversion {
+        r#type: ByteString,
+    },
+    UncomparableOperands {
+        operator: ByteString,
+        left: ByteString,
+        right: ByteString,
+    },
+    DivisionByZero {
+        operator: ByteString,
+    },
+    LooseComparisonWithBoolean {
+        left: ByteString,
+        right: ByteString,
+    },
+    InvalidIncrement {
+        operation: ByteString,
+        r#type: ByteString,
+    },
+    EmbeddedCodeError {
+        function: ByteString,
+        message: ByteString,
+    },
+    InvalidCloneOperand {
+        r#type: ByteString,
+    },
+}
+
+impl DiagnosticKind for AnalyserDiagnostic {
+    fn get_code(&self) -> String {
+        match self {
+            AnalyserDiagnostic::TooFewArguments { .. } => "A001",
+            AnalyserDiagnostic::TooManyArguments { .. } => "A002",
+            AnalyserDiagnostic::MissingArgument { .. } => "A003",
+            AnalyserDiagnostic::UnknownNamedArgument { .. } => "A004",
+            AnalyserDiagnostic::NamedArgumentOverwritesPreviousArgument { .. } => "A005",
+            AnalyserDiagnostic::OverrideWithoutParentMethod { .. } => "A008",
+            AnalyserDiagnostic::ImplicitFallthrough => "A009",
+            AnalyserDiagnostic::EnumUsesTraitWithProperties { .. } => "A010",
+            AnalyserDiagnostic::UndefinedVariable { .. } => "A011",
+            AnalyserDiagnostic::PossiblyUndefinedVariable { .. } => "A012",
+            AnalyserDiagnostic::UncheckedVariableScope { .. } => "A013",
+            AnalyserDiagnostic::RedundantTypeCheck { .. } => "A014",
+            AnalyserDiagnostic::RedundantStrictComparison { .. } => "A015",
+            AnalyserDiagnostic::InArrayWithEmptyArray => "A016",
+            AnalyserDiagnostic::DeprecatedSymbol { .. } => "A017",
+            AnalyserDiagnostic::InvalidOctalLiteral { .. } => "A018",
+            AnalyserDiagnostic::TraitMethodCollision { .. } => "A019",
+            AnalyserDiagnostic::MissingTraitAbstractMethod { .. } => "A020",
+            AnalyserDiagnostic::IncompatibleTraitMethodSignature { .. } => "A021",
+            AnalyserDiagnostic::TraitPropertyConflict { .. } => "A022",
+            AnalyserDiagnostic::UnescapedOutput { .. } => "A023",
+            AnalyserDiagnostic::MissingReturn { .. } => "A024",
+            AnalyserDiagnostic::ReturnWithoutValue { .. } => "A025",
+            AnalyserDiagnostic::VoidFunctionReturnsValue { .. } => "A026",
+            AnalyserDiagnostic::DuplicateArrayKey { .. } => "A027",
+            AnalyserDiagnostic::ImplicitArrayKeyCast { .. } => "A028",
+            AnalyserDiagnostic::InvalidFormatString { .. } => "A029",
+            AnalyserDiagnostic::TooFewFormatArguments { .. } => "A030",
+            AnalyserDiagnostic::TooManyFormatArguments { .. } => "A031",
+            AnalyserDiagnostic::FormatArgumentTypeMismatch { .. } => "A032",
+            AnalyserDiagnostic::EmptyMagicConstant { .. } => "A033",
+            AnalyserDiagnostic::MethodConstantOutsideMethod => "A034",
+            AnalyserDiagnostic::UnsupportedOperandTypes { .. } => "A035",
+            AnalyserDiagnostic::InvalidStringConversion { .. } => "A036",
+            AnalyserDiagnostic::UncomparableOperands { .. } => "A037",
+            AnalyserDiagnostic::DivisionByZero { .. } => "A038",
+            AnalyserDiagnostic::LooseComparisonWithBoolean { .. } => "A039",
+            AnalyserDiagnostic::InvalidIncrement { .. } => "A040",
+            AnalyserDiagnostic::EmbeddedCodeError { .. } => "A041",
+            AnalyserDiagnostic::InvalidCloneOperand { .. } => "A042",
+        }
+        .to_string()
+    }
+
+    fn get_identifier(&self) -> String {
+        match self {
+            AnalyserDiagnostic::TooFewArguments { .. } => "analyser.too-few-arguments",
+            AnalyserDiagnostic::TooManyArguments { .. } => "analyser.too-many-arguments",
+            AnalyserDiagnostic::MissingArgument { .. } => "analyser.missing-argument",
+            AnalyserDiagnostic::UnknownNamedArgument { .. } => "analyser.unknown-named-argument",
+            AnalyserDiagnostic::NamedArgumentOverwritesPreviousArgument { .. } => {
+                "analyser.named-argument-overwrites-previous-argument"
+            }
+            AnalyserDiagnostic::OverrideWithoutParentMethod { .. } => {
+                "analyser.override-without-parent-method"
+            }
+            AnalyserDiagnostic::ImplicitFallthrough => "analyser.implicit-fallthrough",
+            AnalyserDiagnostic::EnumUsesTraitWithProperties { .. } => {
+                "analyser.enum-uses-trait-with-properties"
+            }
+            AnalyserDiagnostic::UndefinedVariable { .. } => "analyser.undefined-variable",
+            AnalyserDiagnostic::PossiblyUndefinedVariable { .. } => {
+                "analyser.possibly-undefined-variable"
+            }
+            AnalyserDiagnostic::UncheckedVariableScope { .. } => {
+                "analyser.unchecked-variable-scope"
+            }
+            AnalyserDiagnostic::RedundantTypeCheck { .. } => "analyser.redundant-type-check",
+            AnalyserDiagnostic::RedundantStrictComparison { .. } => {
+                "analyser.redundant-strict-comparison"
+            }
+            AnalyserDiagnostic::InArrayWithEmptyArray => "analyser.in-array-with-empty-array",
+            AnalyserDiagnostic::DeprecatedSymbol { .. } => "analyser.deprecated-symbol",
+            AnalyserDiagnostic::InvalidOctalLiteral { .. } => "analyser.invalid-octal-literal",
+            AnalyserDiagnostic::TraitMethodCollision { .. } => "analyser.trait-method-collision",
+            AnalyserDiagnostic::MissingTraitAbstractMethod { .. } => {
+                "analyser.missing-trait-abstract-method"
+            }
+            AnalyserDiagnostic::IncompatibleTraitMethodSignature { .. } => {
+                "analyser.incompatible-trait-method-signature"
+            }
+            AnalyserDiagnostic::TraitPropertyConflict { .. } => "analyser.trait-property-conflict",
+            AnalyserDiagnostic::UnescapedOutput { .. } => "analyser.unescaped-output",
+            AnalyserDiagnostic::MissingReturn { .. } => "analyser.missing-return",
+            AnalyserDiagnostic::ReturnWithoutValue { .. } => "analyser.return-without-value",
+            AnalyserDiagnostic::VoidFunctionReturnsValue { .. } => {
+                "analyser.void-function-returns-value"
+            }
+            AnalyserDiagnostic::DuplicateArrayKey { .. } => "analyser.duplicate-array-key",
+            AnalyserDiagnostic::ImplicitArrayKeyCast { .. } => "analyser.implicit-array-key-cast",
+            AnalyserDiagnostic::InvalidFormatString { .. } => "analyser.invalid-format-string",
+            AnalyserDiagnostic::TooFewFormatArguments { .. } => "analyser.too-few-format-arguments",
+            AnalyserDiagnostic::TooManyFormatArguments { .. } => {
+                "analyser.too-many-format-arguments"
+            }
+            AnalyserDiagnostic::FormatArgumentTypeMismatch { .. } => {
+                "analyser.format-argument-type-mismatch"
+            }
+            AnalyserDiagnostic::EmptyMagicConstant { .. } => "analyser.empty-magic-constant",
+            AnalyserDiagnostic::MethodConstantOutsideMethod => {
+                "analyser.method-constant-outside-method"
+            }
+            AnalyserDiagnostic::UnsupportedOperandTypes { .. } => {
+                "analyser.unsupported-operand-types"
+            }
+            AnalyserDiagnostic::InvalidStringConversion { .. } => {
+                "analyser.invalid-string-conversion"
+            }
+            AnalyserDiagnostic::UncomparableOperands { .. } => "analyser.uncomparable-operands",
+            AnalyserDiagnostic::DivisionByZero { .. } => "analyser.division-by-zero",
+            AnalyserDiagnostic::LooseComparisonWithBoolean { .. } => {
+                "analyser.loose-comparison-with-boolean"
+            }
+            AnalyserDiagnostic::InvalidIncrement { .. } => "analyser.invalid-increment",
+            AnalyserDiagnostic::EmbeddedCodeError { .. } => "analyser.embedded-code-error",
+            AnalyserDiagnostic::InvalidCloneOperand { .. } => "analyser.invalid-clone-operand",
+        }
+        .to_string()
+    }
+
+    fn get_message(&self) -> String {
+        match self {
+            AnalyserDiagnostic::TooFewArguments {
+                callable,
+                given,
+                required,
+            } => format!(
+                "too few arguments to {}(), {} passed and {} required",
+                callable, given, required
+            ),
+            AnalyserDiagnostic::TooManyArguments {
+                callable,
+                given,
+                maximum,
+            } => format!(
+                "too many arguments to {}(), {} passed and at most {} accepted",
+                callable, given, maximum
+            ),
+            AnalyserDiagnostic::MissingArgument {
+                callable,
+                parameter,
+            } => format!("argument ${} of {}() not passed", parameter, callable),
+            AnalyserDiagnostic::UnknownNamedArgument {
+                callable,
+                parameter,
+            } => format!("unknown named argument ${} for {}()", parameter, callable),
+            AnalyserDiagnostic::NamedArgumentOverwritesPreviousArgument {
+                callable,
+                parameter,
+            } => format!(
+                "named argument ${} of {}() overwrites previous argument",
+                parameter, callable
+            ),
+            AnalyserDiagnostic::OverrideWithoutParentMethod { class, method } => format!(
+                "{}::{}() has #[\\Override] attribute, but no matching parent method exists",
+                class, method
+            ),
+            AnalyserDiagnostic::ImplicitFallthrough => {
+                "case falls through to the next case without a break or `// no break` comment"
+                    .to_string()
+            }
+            AnalyserDiagnostic::EnumUsesTraitWithProperties { r#enum, r#trait } => format!(
+                "enum {} cannot use trait {} because it includes properties",
+                r#enum, r#trait
+            ),
+            AnalyserDiagnostic::UndefinedVariable { variable } => {
+                format!("undefined variable ${}", variable)
+            }
+            AnalyserDiagnostic::PossiblyUndefinedVariable { variable } => {
+                format!("variable ${} might not be defined", variable)
+            }
+            AnalyserDiagnostic::UncheckedVariableScope { construct } => format!(
+                "undefined variables are not reported in this scope because it uses {}",
+                construct
+            ),
+            AnalyserDiagnostic::RedundantTypeCheck {
+                function,
+                r#type,
+                result,
+            } => format!(
+                "call to {}() is always {} because the value is {}",
+                function, result, r#type
+            ),
+            AnalyserDiagnostic::RedundantStrictComparison {
+                left,
+                right,
+                result,
+            } => format!(
+                "strict comparison between {} and {} is always {}",
+                left, right, result
+            ),
+            AnalyserDiagnostic::InArrayWithEmptyArray => {
+                "in_array() is always false when searching an empty array".to_string()
+            }
+            AnalyserDiagnostic::DeprecatedSymbol { symbol, message } => match message {
+                Some(message) => format!("{} is deprecated: {}", symbol, message),
+                None => format!("{} is deprecated", symbol),
+            },
+            AnalyserDiagnostic::InvalidOctalLiteral { literal } => format!(
+                "invalid numeric literal {}, octal literals can only contain the digits 0 to 7",
+                literal
+            ),
+            AnalyserDiagnostic::TraitMethodCollision {
+                class,
+                method,
+                r#trait,
+                other,
+            } => format!(
+                "trait method {}::{}() has not been applied to {}, because it collides with {}::{}()",
+                r#trait, method, class, other, method
+            ),
+            AnalyserDiagnostic::MissingTraitAbstractMethod {
+                class,
+                r#trait,
+                method,
+            } => format!(
+                "class {} must implement abstract method {}::{}()",
+                class, r#trait, method
+            ),
+            AnalyserDiagnostic::IncompatibleTraitMethodSignature {
+                class,
+                r#trait,
+                method,
+            } => format!(
+                "declaration of {}::{}() must be compatible with abstract method {}::{}()",
+                class, method, r#trait, method
+            ),
+            AnalyserDiagnostic::TraitPropertyConflict {
+                class,
+                r#trait,
+                other,
+                property,
+            } => format!(
+                "{} and {} define the same property ${} in the composition of {}, but the definitions differ",
+                other, r#trait, property, class
+            ),
+            AnalyserDiagnostic::UnescapedOutput { source, output } => format!(
+                "input from {} is written by {} without being escaped",
+                source, output
+            ),
+            AnalyserDiagnostic::MissingReturn { function, r#type } => format!(
+                "{}() must return a value of type {}, but can reach the end without returning",
+                function, r#type
+            ),
+            AnalyserDiagnostic::ReturnWithoutValue { function, r#type } => format!(
+                "{}() must return a value of type {}, but returns without one",
+                function, r#type
+            ),
+            AnalyserDiagnostic::VoidFunctionReturnsValue { function } => {
+                format!("{}() has a void return type, so it cannot return a value", function)
+            }
+            AnalyserDiagnostic::DuplicateArrayKey { key } => format!(
+                "duplicate array key {}, the earlier value is overwritten",
+                key
+            ),
+            AnalyserDiagnostic::ImplicitArrayKeyCast { key, cast } => {
+                format!("array key {} is implicitly cast to {}", key, cast)
+            }
+            AnalyserDiagnostic::InvalidFormatString { function, message } => {
+                format!("invalid format string passed to {}(), {}", function, message)
+            }
+            AnalyserDiagnostic::TooFewFormatArguments {
+                function,
+                given,
+                required,
+            } => format!(
+                "format string passed to {}() requires {} values, but {} given",
+                function, required, given
+            ),
+            AnalyserDiagnostic::TooManyFormatArguments {
+                function,
+                given,
+                used,
+            } => format!(
+                "format string passed to {}() only uses {} values, but {} given",
+                function, used, given
+            ),
+            AnalyserDiagnostic::FormatArgumentTypeMismatch {
+                function,
+                specifier,
+                r#type,
+            } => format!(
+                "{} in the format string passed to {}() cannot format a value of type {}",
+                specifier, function, r#type
+            ),
+            AnalyserDiagnostic::EmptyMagicConstant { constant, context } => {
+                format!("{} is an empty string outside of a {}", constant, context)
+            }
+            AnalyserDiagnostic::MethodConstantOutsideMethod => {
+                "__METHOD__ is the same as __FUNCTION__ outside of a method".to_string()
+            }
+            AnalyserDiagnostic::UnsupportedOperandTypes {
+                operator,
+                left,
+                right,
+            } => format!("unsupported operand types: {} {} {}", left, operator, right),
+            AnalyserDiagnostic::InvalidStringConversion { r#type } => {
+                format!("{} cannot be converted to a string", r#type)
+            }
+            AnalyserDiagnostic::UncomparableOperands {
+                operator,
+                left,
+                right,
+            } => format!(
+                "{} and {} cannot be compared with {}, so the result doesn't depend on their values",
+                left, right, operator
+            ),
+            AnalyserDiagnostic::DivisionByZero { operator } => format!(
+                "{} by zero always throws a DivisionByZeroError",
+                match &operator[..] {
+                    b"%" => "modulo",
+                    _ => "division",
+                }
+            ),
+            AnalyserDiagnostic::LooseComparisonWithBoolean { left, right } => format!(
+                "loose comparison between {} and {} converts both sides to bool",
+                left, right
+            ),
+            AnalyserDiagnostic::InvalidIncrement { operation, r#type } => {
+                format!("cannot {} {}", operation, r#type)
+            }
+            AnalyserDiagnostic::EmbeddedCodeError { function, message } => {
+                format!("in the code passed to {}(): {}", function, message)
+            }
+            AnalyserDiagnostic::InvalidCloneOperand { r#type } => {
+                format!("cannot clone {}, only objects can be cloned", r#type)
+            }
+        }
+    }
+
+    fn get_category(&self) -> DiagnosticCategory {
+        match self {
+            AnalyserDiagnostic::UndefinedVariable { .. }
+            | AnalyserDiagnostic::PossiblyUndefinedVariable { .. }
+            | AnalyserDiagnostic::UncheckedVariableScope { .. } => {
+                DiagnosticCategory::NameResolution
+            }
+            AnalyserDiagnostic::TooFewArguments { .. }
+            | AnalyserDiagnostic::TooManyArguments { .. }
+            | AnalyserDiagnostic::MissingArgument { .. }
+            | AnalyserDiagnostic::UnknownNamedArgument { .. }
+            | AnalyserDiagnostic::NamedArgumentOverwritesPreviousArgument { .. }
+            | AnalyserDiagnostic::OverrideWithoutParentMethod { .. }
+            | AnalyserDiagnostic::ImplicitFallthrough
+            | AnalyserDiagnostic::EnumUsesTraitWithProperties { .. }
+            | AnalyserDiagnostic::RedundantTypeCheck { .. }
+            | AnalyserDiagnostic::RedundantStrictComparison { .. }
+            | AnalyserDiagnostic::InArrayWithEmptyArray
+            | AnalyserDiagnostic::TraitMethodCollision { .. }
+            | AnalyserDiagnostic::MissingTraitAbstractMethod { .. }
+            | AnalyserDiagnostic::IncompatibleTraitMethodSignature { .. }
+            | AnalyserDiagnostic::TraitPropertyConflict { .. }
+            | AnalyserDiagnostic::MissingReturn { .. }
+            | AnalyserDiagnostic::ReturnWithoutValue { .. }
+            | AnalyserDiagnostic::VoidFunctionReturnsValue { .. }
+            | AnalyserDiagnostic::DuplicateArrayKey { .. }
+            | AnalyserDiagnostic::InvalidFormatString { .. }
+            | AnalyserDiagnostic::TooFewFormatArguments { .. }
+            | AnalyserDiagnostic::TooManyFormatArguments { .. }
+            | AnalyserDiagnostic::FormatArgumentTypeMismatch { .. }
+            | AnalyserDiagnostic::EmptyMagicConstant { .. }
+            | AnalyserDiagnostic::MethodConstantOutsideMethod
+            | AnalyserDiagnostic::UnsupportedOperandTypes { .. }
+            | AnalyserDiagnostic::InvalidStringConversion { .. }
+            | AnalyserDiagnostic::UncomparableOperands { .. }
+            | AnalyserDiagnostic::DivisionByZero { .. }
+            | AnalyserDiagnostic::LooseComparisonWithBoolean { .. }
+            | AnalyserDiagnostic::InvalidIncrement { .. }
+            | AnalyserDiagnostic::InvalidCloneOperand { .. } => DiagnosticCategory::Correctness,
+            AnalyserDiagnostic::DeprecatedSymbol { .. }
+            | AnalyserDiagnostic::ImplicitArrayKeyCast { .. } => DiagnosticCategory::Deprecation,
+            AnalyserDiagnostic::InvalidOctalLiteral { .. }
+            | AnalyserDiagnostic::EmbeddedCodeError { .. } => DiagnosticCategory::Syntax,
+            AnalyserDiagnostic::UnescapedOutput { .. } => DiagnosticCategory::Security,
+        }
+    }
+}
+
+impl Display for AnalyserDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.get_message())
+    }
+}
@@ -0,0 +1,200 @@
+use std::fmt::Display;
+
+use pxp_bytestring::ByteString;
+
+/// The kind of value that a conversion specification formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatKind {
+    /// `%s`, which converts any value to a string.
+    String,
+    /// `%b`, `%c`, `%d`, `%o`, `%u`, `%x` and `%X`, along with `*` widths and precisions.
+    Integer,
+    /// `%e`, `%E`, `%f`, `%F`, `%g`, `%G`, `%h` and `%H`.
+    Float,
+}
+
+/// A value that's consumed by a `sprintf()` style format string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatArgument {
+    /// The zero-based position of the value, among the values that follow the format string.
+    pub position: usize,
+    pub kind: FormatKind,
+    /// The conversion specification that consumes the value, as written, e.g. `%1$05.2f`.
+    pub specifier: ByteString,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatStringError {
+    MissingSpecifier,
+    MissingPaddingCharacter,
+    UnknownSpecifier(u8),
+    InvalidArgumentNumber,
+}
+
+impl Display for FormatStringError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormatStringError::MissingSpecifier => {
+                write!(f, "missing format specifier at end of string")
+            }
+            FormatStringError::MissingPaddingCharacter => write!(f, "missing padding character"),
+            FormatStringError::UnknownSpecifier(specifier) => write!(
+                f,
+                "unknown format specifier \"{}\"",
+                ByteString::from(*specifier)
+            ),
+            FormatStringError::InvalidArgumentNumber => {
+                write!(f, "argument number specifier must be greater than zero")
+            }
+        }
+    }
+}
+
+/// Parse a format string the way that `sprintf()` does, returning the values that it consumes in
+/// the order that they're written.
+///
+/// Each specification is `%`, followed by an optional argument number (`1$`), any number of flags
+/// (`-`, `+`, ` `, `0` and `'` followed by a padding character), an optional width, an optional
+/// precision and then the conversion specifier. A width or precision of `*` consumes a value of its
+/// own. `%%` is a literal percent sign.
+pub fn parse_format_string(format: &[u8]) -> Result<Vec<FormatArgument>, FormatStringError> {
+    let mut arguments = Vec::new();
+    // Values without an argument number are consumed in order, regardless of any that have one.
+    let mut next = 0;
+    let mut position = 0;
+
+    while let Some(offset) = format[position..].iter().position(|byte| *byte == b'%') {
+        let start = position + offset;
+        let mut cursor = Cursor {
+            format,
+            position: start + 1,
+        };
+
+        if cursor.eat(b'%') {
+            position = cursor.position;
+            continue;
+        }
+
+        let explicit = cursor.argument_number()?;
+        let mut consumed = Vec::new();
+
+        while let Some(flag) = cursor.peek() {
+            match flag {
+                b'-' | b'+' | b' ' | b'0' => cursor.position += 1,
+                b'\'' => {
+                    cursor.position += 1;
+
+                    if cursor.peek().is_none() {
+                        return Err(FormatStringError::MissingPaddingCharacter);
+                    }
+
+                    cursor.position += 1;
+                }
+                _ => break,
+            }
+        }
+
+        if cursor.eat(b'*') {
+            consumed.push(cursor.argument_number()?);
+        } else {
+            cursor.digits();
+        }
+
+        if cursor.eat(b'.') {
+            if cursor.eat(b'*') {
+                consumed.push(cursor.argument_number()?);
+            } else {
+                cursor.digits();
+            }
+        }
+
+        // The `l` length modifier is accepted, but has no effect.
+        cursor.eat(b'l');
+
+        let kind = match cursor.peek() {
+            None => return Err(FormatStringError::MissingSpecifier),
+            Some(b's') => FormatKind::String,
+            Some(b'b' | b'c' | b'd' | b'o' | b'u' | b'x' | b'X') => FormatKind::Integer,
+            Some(b'e' | b'E' | b'f' | b'F' | b'g' | b'G' | b'h' | b'H') => FormatKind::Float,
+            Some(specifier) => return Err(FormatStringError::UnknownSpecifier(specifier)),
+        };
+
+        cursor.position += 1;
+
+        let specifier = ByteString::from(&format[start..cursor.position]);
+
+        for width in consumed {
+            arguments.push(FormatArgument {
+                position: width.unwrap_or_else(|| {
+                    next += 1;
+                    next - 1
+                }),
+                kind: FormatKind::Integer,
+                specifier: specifier.clone(),
+            });
+        }
+
+        arguments.push(FormatArgument {
+            position: explicit.unwrap_or_else(|| {
+                next += 1;
+                next - 1
+            }),
+            kind,
+            specifier,
+        });
+
+        position = cursor.position;
+    }
+
+    Ok(arguments)
+}
+
+struct Cursor<'a> {
+    format: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.format.get(self.position).copied()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn digits(&mut self) -> &'a [u8] {
+        let start = self.position;
+
+        while self.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+            self.position += 1;
+        }
+
+        &self.format[start..self.position]
+    }
+
+    /// Parse an explicit argument number like `2$`, returning its zero-based position. Digits that
+    /// aren't followed by `$` are a width instead, so they're left for the caller.
+    fn argument_number(&mut self) -> Result<Option<usize>, FormatStringError> {
+        let start = self.position;
+        let digits = self.digits();
+
+        if digits.is_empty() || !self.eat(b'$') {
+            self.position = start;
+            return Ok(None);
+        }
+
+        match std::str::from_utf8(digits)
+            .ok()
+            .and_then(|n| n.parse::<usize>().ok())
+        {
+            Some(number) if number > 0 => Ok(Some(number - 1)),
+            _ => Err(FormatStringError::InvalidArgumentNumber),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+mod call_graph;
+mod class_strings;
+mod context;
+mod diagnostics;
+mod format_string;
+mod passes;
+mod profile;
+mod references;
+mod rules;
+mod semantic_tokens;
+mod similarity;
+mod target;
+mod throws;
+
+pub use call_graph::{CallEdge, CallGraph, Callable};
+pub use class_strings::{
+    class_string_references, ClassStringConfidence, ClassStringFinder, ClassStringRef,
+};
+pub use context::AnalyserContext;
+pub use diagnostics::AnalyserDiagnostic;
+pub use format_string::{parse_format_string, FormatArgument, FormatKind, FormatStringError};
+pub use passes::{
+    passes, ArgumentsPass, ArrayKeyPass, ConditionPass, DeprecationPass, EmbeddedCodePass,
+    EnumPass, FallthroughPass, FormatStringPass, LiteralPass, MagicConstantPass, OutputPass,
+    OverridePass, Pass, ReturnPass, TraitPass, TypeCompatibilityPass, UndefinedVariablePass,
+};
+pub use profile::AnalysisProfile;
+pub use references::{find_references, ParsedFile, Reference, ReferenceKind, SymbolRef};
+pub use rules::{
+    psr12_rules, Analyser, BracePlacement, DisallowErrorSuppression, DisallowEval,
+    ExplicitVisibility, FileDocblockPlacement, ImportOrder, MultilineTrailingComma,
+    OneClassPerFile, Rule, RuleContext, RuleDiagnostic, UndocumentedThrows,
+};
+pub use semantic_tokens::{
+    semantic_tokens, SemanticToken, SemanticTokenKind, SemanticTokenModifier,
+};
+pub use similarity::{find_similar_functions, SimilarFunction, SimilarPair, SimilarityDetector};
+pub use target::{DeadBranches, TargetEnvironment};
+pub use throws::{ThrowAnalysis, Thrown};
@@ -0,0 +1,461 @@
+use std::collections::HashSet;
+
+use pxp_ast::{
+    visitor::{
+        walk_anonymous_class_expression, walk_backed_enum_statement, walk_braced_namespace,
+        walk_class_statement, walk_function_call_expression, walk_method_call_expression,
+        walk_new_expression, walk_nullsafe_method_call_expression,
+        walk_static_method_call_expression, walk_trait_statement, walk_unbraced_namespace,
+        walk_unit_enum_statement, Visitor,
+    },
+    *,
+};
+use pxp_bytestring::{ByteStr, ByteString};
+use pxp_diagnostics::{Diagnostic, Severity};
+use pxp_index::{
+    CanReflectParameters, ReflectionClass, ReflectionFunctionLike, ReflectsParameters,
+};
+use pxp_span::Span;
+use pxp_type::Type;
+
+use crate::{AnalyserContext, AnalyserDiagnostic};
+
+use super::{resolve_function, Pass};
+
+/// Checks the arguments passed to functions, methods and constructors against the
+/// signatures stored in the `Index`.
+///
+/// Calls that can't be resolved to a known signature, e.g. calls through variables
+/// or dynamic names, are skipped.
+#[derive(Debug, Default)]
+pub struct ArgumentsPass;
+
+impl ArgumentsPass {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Pass for ArgumentsPass {
+    fn codes(&self) -> &'static [&'static str] {
+        &["A001", "A002", "A003", "A004", "A005"]
+    }
+
+    fn run(
+        &mut self,
+        context: &AnalyserContext,
+        ast: &[Statement],
+    ) -> Vec<Diagnostic<AnalyserDiagnostic>> {
+        let mut visitor = ArgumentsVisitor {
+            context,
+            namespace: None,
+            classes: Vec::new(),
+            diagnostics: Vec::new(),
+        };
+
+        visitor.visit(ast);
+        visitor.diagnostics
+    }
+}
+
+struct Signature {
+    callable: ByteString,
+    parameters: Vec<SignatureParameter>,
+    // Whether the body calls `func_get_args()` or one of its siblings to read extra arguments.
+    reads_arguments: bool,
+}
+
+struct SignatureParameter {
+    name: ByteString,
+    optional: bool,
+    variadic: bool,
+}
+
+impl Signature {
+    fn new<'a, O, R>(callable: ByteString, reflection: &R) -> Self
+    where
+        O: CanReflectParameters,
+        R: ReflectsParameters<'a, O> + ReflectionFunctionLike<'a>,
+    {
+        Self {
+            callable,
+            parameters: reflection
+                .get_parameters()
+                .iter()
+                .map(|parameter| SignatureParameter {
+                    name: parameter.get_name().to_bytestring(),
+                    optional: parameter.is_optional(),
+                    variadic: parameter.is_variadic(),
+                })
+                .collect(),
+            reads_arguments: reflection.reads_arguments(),
+        }
+    }
+
+    fn is_variadic(&self) -> bool {
+        self.parameters.iter().any(|parameter| parameter.variadic)
+    }
+
+    fn required(&self) -> impl Iterator<Item = &SignatureParameter> {
+        self.parameters
+            .iter()
+            .filter(|parameter| !parameter.optional && !parameter.variadic)
+    }
+}
+
+struct ArgumentsVisitor<'a> {
+    context: &'a AnalyserContext<'a>,
+    // The namespace that we're currently inside of, used to resolve unqualified function calls.
+    namespace: Option<ByteString>,
+    // The class-like that we're currently inside of, used to resolve `self`, `static` and `$this`.
+    // Traits and anonymous classes push `None` since we can't know what they refer to.
+    classes: Vec<Option<ByteString>>,
+    diagnostics: Vec<Diagnostic<AnalyserDiagnostic>>,
+}
+
+impl<'a> ArgumentsVisitor<'a> {
+    fn report(&mut self, kind: AnalyserDiagnostic, span: Span) {
+        self.report_with_severity(kind, Severity::Error, span);
+    }
+
+    fn report_with_severity(&mut self, kind: AnalyserDiagnostic, severity: Severity, span: Span) {
+        self.diagnostics.push(Diagnostic::new(kind, severity, span));
+    }
+
+    fn current_class(&self) -> Option<ReflectionClass<'a>> {
+        let name = self.classes.last()?.as_ref()?;
+
+        self.context.index().get_class(name.clone())
+    }
+
+    fn resolve_function(&self, target: &Expression) -> Option<Signature> {
+        let ExpressionKind::Name(name) = &target.kind else {
+            return None;
+        };
+
+        let function = resolve_function(self.context.index(), self.namespace.as_ref(), name)?;
+
+        Some(Signature::new(
+            function.get_name().to_bytestring(),
+            &function,
+        ))
+    }
+
+    fn resolve_class_expression(&self, target: &Expression) -> Option<ReflectionClass<'a>> {
+        match &target.kind {
+            ExpressionKind::Name(name) => match &name.kind {
+                NameKind::Resolved(name) => self.context.index().get_class(name.resolved.clone()),
+                NameKind::Special(special) => self.resolve_special_class(special.kind),
+                NameKind::Unresolved(_) => None,
+            },
+            ExpressionKind::Self_(_) => self.resolve_special_class(SpecialNameKind::Self_),
+            ExpressionKind::Static(_) => self.resolve_special_class(SpecialNameKind::Static),
+            ExpressionKind::Parent(_) => self.resolve_special_class(SpecialNameKind::Parent),
+            _ => None,
+        }
+    }
+
+    fn resolve_special_class(&self, kind: SpecialNameKind) -> Option<ReflectionClass<'a>> {
+        let class = self.current_class()?;
+
+        match kind {
+            SpecialNameKind::Self_ | SpecialNameKind::Static => Some(class),
+            SpecialNameKind::Parent => self
+                .context
+                .index()
+                .get_class(class.get_parent_name()?.to_bytestring()),
+        }
+    }
+
+    fn resolve_class_from_type(&self, ty: &Type<ResolvedName>) -> Option<ReflectionClass<'a>> {
+        match ty {
+            Type::Named(name) => self.context.index().get_class(name.resolved.clone()),
+            Type::Nullable(inner) => self.resolve_class_from_type(inner),
+            _ => None,
+        }
+    }
+
+    fn resolve_receiver(&self, target: &Expression) -> Option<ReflectionClass<'a>> {
+        if let ExpressionKind::Variable(variable) = &target.kind {
+            if variable.is_simple() && variable.to_simple().stripped == b"this" {
+                return self.current_class();
+            }
+        }
+
+        self.resolve_class_from_type(self.context.types().resolve(target.id))
+    }
+
+    fn resolve_method(&self, class: ReflectionClass<'a>, method: &ByteStr) -> Option<Signature> {
+        let mut class = class;
+        let mut visited = HashSet::new();
+
+        // Walk up the class hierarchy until we find the class that declares the method.
+        while visited.insert(class.name().to_bytestring()) {
+            if let Some(method) = class.get_method(method) {
+                return Some(Signature::new(
+                    ByteString::from(format!("{}::{}", class.name(), method.get_name())),
+                    &method,
+                ));
+            }
+
+            class = self
+                .context
+                .index()
+                .get_class(class.get_parent_name()?.to_bytestring())?;
+        }
+
+        None
+    }
+
+    fn method_name(method: &Expression) -> Option<&ByteStr> {
+        match &method.kind {
+            ExpressionKind::Identifier(identifier) if identifier.is_simple() => {
+                Some(identifier.to_simple().symbol.as_ref())
+            }
+            _ => None,
+        }
+    }
+
+    fn check(&mut self, signature: Signature, arguments: &[Argument], span: Span) {
+        let variadic = signature.is_variadic();
+        let mut supplied: HashSet<&ByteStr> = HashSet::new();
+        let mut names: HashSet<&ByteStr> = HashSet::new();
+        let mut positional = 0;
+        let mut unpacked = false;
+        let mut named = false;
+
+        // The order of the arguments and duplicate names are already reported by the parser.
+        for argument in arguments {
+            match argument {
+                Argument::Positional(argument) if argument.ellipsis.is_some() => {
+                    unpacked = true;
+                }
+                Argument::Positional(_) => {
+                    if named || unpacked {
+                        continue;
+                    }
+
+                    if let Some(parameter) = signature.parameters.get(positional) {
+                        if !parameter.variadic {
+                            supplied.insert(parameter.name.as_ref());
+                        }
+                    }
+
+                    positional += 1;
+                }
+                Argument::Named(argument) => {
+                    named = true;
+
+                    let name: &ByteStr = argument.name.symbol.as_ref();
+
+                    if !names.insert(name) {
+                        continue;
+                    }
+
+                    match signature.parameters.iter().find(|parameter| {
+                        parameter.name.as_bytestr() == name && !parameter.variadic
+                    }) {
+                        Some(parameter) => {
+                            if !supplied.insert(parameter.name.as_ref()) {
+                                self.report(
+                                    AnalyserDiagnostic::NamedArgumentOverwritesPreviousArgument {
+                                        callable: signature.callable.clone(),
+                                        parameter: parameter.name.clone(),
+                                    },
+                                    span,
+                                );
+                            }
+                        }
+                        // Unknown named arguments are collected by a variadic parameter.
+                        None if variadic => {}
+                        None => self.report(
+                            AnalyserDiagnostic::UnknownNamedArgument {
+                                callable: signature.callable.clone(),
+                                parameter: name.to_bytestring(),
+                            },
+                            span,
+                        ),
+                    }
+                }
+            }
+        }
+
+        // We can't know how many arguments an unpacked array provides.
+        if unpacked {
+            return;
+        }
+
+        let maximum = signature.parameters.len();
+
+        // PHP quietly ignores extra arguments passed to userland functions, which is where every
+        // signature in the index comes from, so they're only a warning.
+        if !variadic && !signature.reads_arguments && positional > maximum {
+            self.report_with_severity(
+                AnalyserDiagnostic::TooManyArguments {
+                    callable: signature.callable.clone(),
+                    given: positional,
+                    maximum,
+                },
+                Severity::Warning,
+                span,
+            );
+        }
+
+        if !named {
+            let required = signature.required().count();
+
+            if positional < required {
+                self.report(
+                    AnalyserDiagnostic::TooFewArguments {
+                        callable: signature.callable.clone(),
+                        given: positional,
+                        required,
+                    },
+                    span,
+                );
+            }
+
+            return;
+        }
+
+        let missing = signature
+            .required()
+            .filter(|parameter| !supplied.contains(parameter.name.as_ref()))
+            .map(|parameter| parameter.name.clone())
+            .collect::<Vec<_>>();
+
+        for parameter in missing {
+            self.report(
+                AnalyserDiagnostic::MissingArgument {
+                    callable: signature.callable.clone(),
+                    parameter,
+                },
+                span,
+            );
+        }
+    }
+
+    fn with_class(&mut self, name: Option<&Name>, f: impl FnOnce(&mut Self)) {
+        self.classes.push(
+            name.and_then(|name| name.as_resolved())
+                .map(|name| name.resolved.clone()),
+        );
+        f(self);
+        self.classes.pop();
+    }
+}
+
+impl<'a> Visitor for ArgumentsVisitor<'a> {
+    fn visit_unbraced_namespace(&mut self, node: &UnbracedNamespace) {
+        self.namespace = Some(node.name.symbol.clone());
+        walk_unbraced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_braced_namespace(&mut self, node: &BracedNamespace) {
+        self.namespace = node.name.as_ref().map(|name| name.symbol.clone());
+        walk_braced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_class_statement(&mut self, node: &ClassStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_class_statement(visitor, node)
+        });
+    }
+
+    fn visit_unit_enum_statement(&mut self, node: &UnitEnumStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_unit_enum_statement(visitor, node)
+        });
+    }
+
+    fn visit_backed_enum_statement(&mut self, node: &BackedEnumStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_backed_enum_statement(visitor, node)
+        });
+    }
+
+    fn visit_trait_statement(&mut self, node: &TraitStatement) {
+        self.with_class(None, |visitor| walk_trait_statement(visitor, node));
+    }
+
+    fn visit_anonymous_class_expression(&mut self, node: &AnonymousClassExpression) {
+        self.with_class(None, |visitor| {
+            walk_anonymous_class_expression(visitor, node)
+        });
+    }
+
+    fn visit_function_call_expression(&mut self, node: &FunctionCallExpression) {
+        walk_function_call_expression(self, node);
+
+        if let Some(signature) = self.resolve_function(&node.target) {
+            self.check(signature, &node.arguments.arguments, node.span);
+        }
+    }
+
+    fn visit_method_call_expression(&mut self, node: &MethodCallExpression) {
+        walk_method_call_expression(self, node);
+
+        let Some(method) = Self::method_name(&node.method) else {
+            return;
+        };
+
+        if let Some(signature) = self
+            .resolve_receiver(&node.target)
+            .and_then(|class| self.resolve_method(class, method))
+        {
+            self.check(signature, &node.arguments.arguments, node.span);
+        }
+    }
+
+    fn visit_nullsafe_method_call_expression(&mut self, node: &NullsafeMethodCallExpression) {
+        walk_nullsafe_method_call_expression(self, node);
+
+        let Some(method) = Self::method_name(&node.method) else {
+            return;
+        };
+
+        if let Some(signature) = self
+            .resolve_receiver(&node.target)
+            .and_then(|class| self.resolve_method(class, method))
+        {
+            self.check(signature, &node.arguments.arguments, node.span);
+        }
+    }
+
+    fn visit_static_method_call_expression(&mut self, node: &StaticMethodCallExpression) {
+        walk_static_method_call_expression(self, node);
+
+        if !node.method.is_simple() {
+            return;
+        }
+
+        if let Some(signature) = self
+            .resolve_class_expression(&node.target)
+            .and_then(|class| self.resolve_method(class, node.method.to_simple().symbol.as_ref()))
+        {
+            self.check(signature, &node.arguments.arguments, node.span);
+        }
+    }
+
+    fn visit_new_expression(&mut self, node: &NewExpression) {
+        walk_new_expression(self, node);
+
+        // Classes without a constructor accept any number of arguments.
+        let Some(signature) = self
+            .resolve_class_expression(&node.target)
+            .and_then(|class| self.resolve_method(class, b"__construct".into()))
+        else {
+            return;
+        };
+
+        let arguments = node
+            .arguments
+            .as_ref()
+            .map(|arguments| arguments.arguments.as_slice())
+            .unwrap_or_default();
+
+        self.check(signature, arguments, node.span);
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::HashSet;
+
+use pxp_ast::{
+    constants::ConstantValue,
+    visitor::{walk_array_expression, walk_assignment_operation_expression, Visitor},
+    *,
+};
+use pxp_bytestring::ByteString;
+use pxp_diagnostics::{Diagnostic, Severity};
+use pxp_inference::ArrayKey;
+
+use crate::{AnalyserContext, AnalyserDiagnostic};
+
+use super::Pass;
+
+/// Checks the keys of array literals after PHP has normalized them.
+///
+/// Keys like `'5'`, `5.9` and `true` are all cast to the integer `5` or `1`, so two keys that are
+/// written differently can still refer to the same element, in which case the later value
+/// silently overwrites the earlier one. Float and bool keys are also reported, since the cast is
+/// easy to miss and PHP deprecates float keys that lose precision.
+#[derive(Debug, Default)]
+pub struct ArrayKeyPass;
+
+impl ArrayKeyPass {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Pass for ArrayKeyPass {
+    fn codes(&self) -> &'static [&'static str] {
+        &["A027", "A028"]
+    }
+
+    fn run(
+        &mut self,
+        _: &AnalyserContext,
+        ast: &[Statement],
+    ) -> Vec<Diagnostic<AnalyserDiagnostic>> {
+        let mut visitor = ArrayKeyVisitor {
+            patterns: HashSet::new(),
+            diagnostics: Vec::new(),
+        };
+
+        visitor.visit(ast);
+        visitor.diagnostics
+    }
+}
+
+struct ArrayKeyVisitor {
+    // Arrays that are destructured into, where reading the same key twice is fine.
+    patterns: HashSet<NodeId>,
+    diagnostics: Vec<Diagnostic<AnalyserDiagnostic>>,
+}
+
+impl ArrayKeyVisitor {
+    fn mark_pattern(&mut self, expression: &Expression) {
+        let ExpressionKind::Array(array) = &expression.kind else {
+            return;
+        };
+
+        self.patterns.insert(array.id);
+
+        for item in array.items.iter() {
+            match item {
+                ArrayItem::Value(item) => self.mark_pattern(&item.value),
+                ArrayItem::KeyValue(item) => self.mark_pattern(&item.value),
+                _ => {}
+            }
+        }
+    }
+
+    fn check(&mut self, node: &ArrayExpression) {
+        let mut seen = HashSet::new();
+        // The key that's used for the next item without one, which is lost after a spread or a
+        // key that can't be known.
+        let mut next = Some(0);
+
+        for item in node.items.iter() {
+            let key = match item {
+                ArrayItem::Value(_) | ArrayItem::ReferencedValue(_) => {
+                    next.map(|next| (ArrayKey::Integer(next), None))
+                }
+                ArrayItem::KeyValue(ArrayItemKeyValue { key, .. })
+                | ArrayItem::ReferencedKeyValue(ArrayItemReferencedKeyValue { key, .. }) => {
+                    self.key(key).map(|normalized| (normalized, Some(key.span)))
+                }
+                ArrayItem::SpreadValue(_) => {
+                    next = None;
+                    continue;
+                }
+                ArrayItem::Skipped(_) => continue,
+            };
+
+            let Some((key, span)) = key else {
+                next = None;
+                continue;
+            };
+
+            if let ArrayKey::Integer(integer) = key {
+                next = next.map(|next: i64| next.max(integer.saturating_add(1)));
+            }
+
+            if !seen.insert(key.clone()) {
+                if let Some(span) = span {
+                    self.diagnostics.push(Diagnostic::new(
+                        AnalyserDiagnostic::DuplicateArrayKey {
+                            key: display_key(&key),
+                        },
+                        Severity::Warning,
+                        span,
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Normalize a key, reporting keys that are implicitly cast to an integer.
+    fn key(&mut self, key: &Expression) -> Option<ArrayKey> {
+        let value = key.evaluate_constant().ok()?;
+        let normalized = ArrayKey::from_constant(&value)?;
+
+        let written = match &value {
+            ConstantValue::Float(value) => Some(ByteString::from(format!("{:?}", value))),
+            ConstantValue::Bool(true) => Some(ByteString::from(b"true")),
+            ConstantValue::Bool(false) => Some(ByteString::from(b"false")),
+            _ => None,
+        };
+
+        if let Some(written) = written {
+            self.diagnostics.push(Diagnostic::new(
+                AnalyserDiagnostic::ImplicitArrayKeyCast {
+                    key: written,
+                    cast: display_key(&normalized),
+                },
+                Severity::Warning,
+                key.span,
+            ));
+        }
+
+        Some(normalized)
+    }
+}
+
+fn display_key(key: &ArrayKey) -> ByteString {
+    match key {
+        ArrayKey::Integer(integer) => ByteString::from(integer.to_string()),
+        ArrayKey::String(string) => {
+            let mut quoted = ByteString::from(b"'");
+            quoted.extend(string);
+            quoted.push(b'\'');
+            quoted
+        }
+    }
+}
+
+impl Visitor for ArrayKeyVisitor {
+    fn visit_assignment_operation_expression(&mut self, node: &AssignmentOperationExpression) {
+        self.mark_pattern(&node.left);
+
+        walk_assignment_operation_expression(self, node);
+    }
+
+    fn visit_array_expression(&mut self, node: &ArrayExpression) {
+        if !self.patterns.contains(&node.id) {
+            self.check(node);
+        }
+
+        walk_array_expression(self, node);
+    }
+}
@@ -0,0 +1,516 @@
+use pxp_ast::{
+    literals::IntegerValue,
+    visitor::{
+        walk_arithmetic_operation_expression, walk_clone_expression,
+        walk_comparison_operation_expression, walk_concat_expression, Visitor,
+    },
+    *,
+};
+use pxp_bytestring::ByteString;
+use pxp_diagnostics::{Diagnostic, Severity};
+use pxp_index::{AccessContext, MemberKind};
+use pxp_span::Span;
+use pxp_type::Type;
+
+use crate::{AnalyserContext, AnalyserDiagnostic};
+
+use super::Pass;
+
+use self::{Kind::*, Operation::*, Outcome::*};
+
+const ANY: &[Kind] = &[
+    Null, Boolean, Integer, Float, String, Array, Object, Stringable,
+];
+const OBJECT: &[Kind] = &[Object, Stringable];
+
+/// What PHP 8 does when an operation is applied to values of the given kinds, checked in order.
+/// The kinds of a binary operation match in either order, and the right-hand kinds of a unary
+/// operation are ignored. Anything that isn't in the table is allowed.
+#[rustfmt::skip]
+const TABLE: &[(Operation, &[Kind], &[Kind], Outcome)] = &[
+    // `+` on two arrays is their union.
+    (Add, &[Array], &[Array], Allowed),
+    (Add, &[Array], ANY, Error),
+    (Arithmetic, &[Array], ANY, Error),
+    (Add, OBJECT, ANY, Error),
+    (Arithmetic, OBJECT, ANY, Error),
+    // Arrays are converted to `"Array"` with a warning, objects without `__toString()` throw.
+    (Concat, &[Array], ANY, Warning),
+    (Concat, &[Object], ANY, Error),
+    (Identical, OBJECT, &[Boolean, Integer, Float, String, Array], Warning),
+    // Objects are always greater than anything they can't be compared with, and so are arrays.
+    (Ordering, &[Object], &[Integer, Float, String], Warning),
+    (Ordering, &[Array], &[Integer, Float, String], Warning),
+    (Equal, &[Boolean], &[Integer, Float, String], Pedantic),
+    // Strings are incremented alphanumerically, e.g. `'a'` becomes `'b'` and `'Az'` becomes
+    // `'Ba'`. Decrementing a string that isn't numeric does nothing, but doesn't throw.
+    (Increment, &[String], ANY, Allowed),
+    (Decrement, &[String], ANY, Allowed),
+    (Increment, &[Array], ANY, Error),
+    (Decrement, &[Array], ANY, Error),
+    (Increment, OBJECT, ANY, Error),
+    (Decrement, OBJECT, ANY, Error),
+];
+
+/// Classes that overload the arithmetic operators.
+const OVERLOADED_CLASSES: [&[u8]; 2] = [b"GMP", b"BcMath\\Number"];
+
+/// Reports operators that are applied to values of types that they can't be used with, based on
+/// the inferred types of their operands, e.g. `[1] + 1`, `$user . ''` when `User` doesn't have a
+/// `__toString()` method and `$user === 1`.
+///
+/// Operations that throw a `TypeError` are reported as errors, and operations whose result doesn't
+/// depend on the values, like strict comparisons that are always false, as warnings. Operations
+/// that are legal but usually a mistake, like `==` between a `bool` and a `string`, are only
+/// reported as notes when [`TypeCompatibilityPass::with_pedantic`] is enabled.
+///
+/// An operand is only checked when every type that it could have is known, so mixed types and
+/// class-likes that aren't in the index are never reported. When an operand could have more than
+/// one type, the operation is only reported if it's invalid for all of them. The exception is
+/// `clone`, which is also reported as a warning when its operand could be `null` or a value that
+/// isn't an object.
+#[derive(Debug, Default)]
+pub struct TypeCompatibilityPass {
+    pedantic: bool,
+}
+
+impl TypeCompatibilityPass {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Report operations that are legal but suspicious.
+    pub fn with_pedantic(mut self, pedantic: bool) -> Self {
+        self.pedantic = pedantic;
+        self
+    }
+}
+
+impl Pass for TypeCompatibilityPass {
+    fn codes(&self) -> &'static [&'static str] {
+        &[
+            "A015", "A035", "A036", "A037", "A038", "A039", "A040", "A042",
+        ]
+    }
+
+    fn run(
+        &mut self,
+        context: &AnalyserContext,
+        ast: &[Statement],
+    ) -> Vec<Diagnostic<AnalyserDiagnostic>> {
+        let mut visitor = CompatibilityVisitor {
+            context,
+            pedantic: self.pedantic,
+            diagnostics: Vec::new(),
+        };
+
+        visitor.visit(ast);
+        visitor.diagnostics
+    }
+}
+
+/// The kinds of value that decide how PHP's operators behave.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Null,
+    Boolean,
+    Integer,
+    Float,
+    String,
+    Array,
+    /// An object that can't be converted to a string.
+    Object,
+    /// An object that has a `__toString()` method, or could be an instance of a subclass that does.
+    Stringable,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Add,
+    /// Every arithmetic operator other than `+`.
+    Arithmetic,
+    Concat,
+    Identical,
+    Ordering,
+    Equal,
+    Increment,
+    Decrement,
+}
+
+/// The outcomes of an operation, from the least to the most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Outcome {
+    Allowed,
+    Pedantic,
+    Warning,
+    Error,
+}
+
+impl Outcome {
+    fn severity(&self) -> Severity {
+        match self {
+            Allowed | Pedantic => Severity::Information,
+            Warning => Severity::Warning,
+            Error => Severity::Error,
+        }
+    }
+}
+
+fn outcome(operation: Operation, left: Kind, right: Kind) -> Outcome {
+    TABLE
+        .iter()
+        .find(|(candidate, a, b, _)| {
+            *candidate == operation
+                && match operation {
+                    Increment | Decrement => a.contains(&left),
+                    _ => {
+                        (a.contains(&left) && b.contains(&right))
+                            || (a.contains(&right) && b.contains(&left))
+                    }
+                }
+        })
+        .map(|(.., outcome)| *outcome)
+        .unwrap_or(Allowed)
+}
+
+struct CompatibilityVisitor<'a> {
+    context: &'a AnalyserContext<'a>,
+    pedantic: bool,
+    diagnostics: Vec<Diagnostic<AnalyserDiagnostic>>,
+}
+
+impl<'a> CompatibilityVisitor<'a> {
+    fn report(&mut self, kind: AnalyserDiagnostic, outcome: Outcome, span: Span) {
+        if outcome == Allowed || (outcome == Pedantic && !self.pedantic) {
+            return;
+        }
+
+        self.diagnostics
+            .push(Diagnostic::new(kind, outcome.severity(), span));
+    }
+
+    /// The outcome of a binary operation, or `None` if the kinds of the operands aren't known.
+    fn binary(
+        &self,
+        operation: Operation,
+        left: &Expression,
+        right: &Expression,
+    ) -> Option<Outcome> {
+        let (left, right) = (self.kinds(left)?, self.kinds(right)?);
+
+        left.iter()
+            .flat_map(|left| right.iter().map(|right| outcome(operation, *left, *right)))
+            .min()
+    }
+
+    /// The outcome of an operation that only depends on one operand.
+    fn unary(&self, operation: Operation, operand: &Expression) -> Option<Outcome> {
+        self.kinds(operand)?
+            .iter()
+            .map(|kind| outcome(operation, *kind, *kind))
+            .min()
+    }
+
+    /// The kinds of value that an expression could evaluate to, if they're all known.
+    fn kinds(&self, expression: &Expression) -> Option<Vec<Kind>> {
+        let mut kinds = Vec::new();
+
+        if !self.collect_kinds(self.context.types().resolve(expression.id), &mut kinds) {
+            return None;
+        }
+
+        kinds.dedup();
+
+        (!kinds.is_empty()).then_some(kinds)
+    }
+
+    fn collect_kinds(&self, ty: &Type<ResolvedName>, kinds: &mut Vec<Kind>) -> bool {
+        let kind = match ty {
+            Type::Null => Null,
+            Type::True | Type::False | Type::Boolean => Boolean,
+            Type::Integer | Type::NonNegativeInteger => Integer,
+            Type::Float => Float,
+            Type::String
+            | Type::LiteralString(_)
+            | Type::NonEmptyString
+            | Type::NumericString
+            | Type::ClassString
+            | Type::CallableString => String,
+            Type::Array
+            | Type::NonEmptyArray
+            | Type::List
+            | Type::NonEmptyList
+            | Type::TypedArray(..)
+            | Type::Shaped { .. } => Array,
+            // An `object` could be an instance of anything, including a class with `__toString()`.
+            Type::Object => Stringable,
+            Type::Named(name) => match self.object_kind(name) {
+                Some(kind) => kind,
+                None => return false,
+            },
+            Type::Generic(base, _) => return self.collect_kinds(base, kinds),
+            Type::Nullable(inner) => {
+                kinds.push(Null);
+
+                return self.collect_kinds(inner, kinds);
+            }
+            Type::Union(types) => {
+                return types.iter().all(|ty| self.collect_kinds(ty, kinds));
+            }
+            // Everything else is either unknown or could be more than one kind of value, e.g. a callable.
+            _ => return false,
+        };
+
+        kinds.push(kind);
+
+        true
+    }
+
+    /// The kind of an instance of a class-like, or `None` if it isn't in the index or overloads
+    /// the arithmetic operators.
+    fn object_kind(&self, name: &ResolvedName) -> Option<Kind> {
+        let class = self.context.index().get_class(name.resolved.clone())?;
+
+        if OVERLOADED_CLASSES.contains(&&class.name()[..]) {
+            return None;
+        }
+
+        let has_to_string = self
+            .context
+            .index()
+            .members_of(&Type::Named(name.clone()), AccessContext::Global)
+            .iter()
+            .any(|member| {
+                member.get_kind() == MemberKind::Method
+                    && member.get_name().eq_ignore_ascii_case(b"__toString")
+            });
+
+        // Only final classes and enums can't have a subclass that adds `__toString()`.
+        if has_to_string || !(class.is_final() || class.is_enum()) {
+            Some(Stringable)
+        } else {
+            Some(Object)
+        }
+    }
+
+    /// Describe the type of an expression in a diagnostic, showing the value of literal strings.
+    fn describe(&self, expression: &Expression) -> ByteString {
+        match self.context.types().resolve(expression.id) {
+            Type::LiteralString(value) => {
+                let mut described = ByteString::from("'");
+                described.extend(value);
+                described.extend_with_bytes(b"'");
+                described
+            }
+            ty => ByteString::from(ty.to_string()),
+        }
+    }
+
+    fn check_binary(
+        &mut self,
+        operation: Operation,
+        operator: &str,
+        left: &Expression,
+        right: &Expression,
+        span: Span,
+    ) {
+        let Some(outcome) = self.binary(operation, left, right) else {
+            return;
+        };
+
+        let result = operator == "!==";
+        let (left, right, operator) = (
+            self.describe(left),
+            self.describe(right),
+            ByteString::from(operator),
+        );
+
+        let kind = match operation {
+            Add | Arithmetic => AnalyserDiagnostic::UnsupportedOperandTypes {
+                operator,
+                left,
+                right,
+            },
+            Identical => AnalyserDiagnostic::RedundantStrictComparison {
+                left,
+                right,
+                result,
+            },
+            Ordering => AnalyserDiagnostic::UncomparableOperands {
+                operator,
+                left,
+                right,
+            },
+            Equal => AnalyserDiagnostic::LooseComparisonWithBoolean { left, right },
+            Concat | Increment | Decrement => unreachable!(),
+        };
+
+        self.report(kind, outcome, span);
+    }
+
+    fn check_increment(&mut self, operation: Operation, operand: &Expression, span: Span) {
+        let Some(outcome) = self.unary(operation, operand) else {
+            return;
+        };
+
+        let kind = AnalyserDiagnostic::InvalidIncrement {
+            operation: ByteString::from(match operation {
+                Increment => "increment",
+                _ => "decrement",
+            }),
+            r#type: self.describe(operand),
+        };
+
+        self.report(kind, outcome, span);
+    }
+
+    fn check_clone(&mut self, operand: &Expression) {
+        let Some(kinds) = self.kinds(operand) else {
+            return;
+        };
+
+        let objects = kinds
+            .iter()
+            .filter(|kind| matches!(kind, Object | Stringable))
+            .count();
+
+        if objects == kinds.len() {
+            return;
+        }
+
+        let kind = AnalyserDiagnostic::InvalidCloneOperand {
+            r#type: self.describe(operand),
+        };
+
+        self.report(
+            kind,
+            if objects == 0 { Error } else { Warning },
+            operand.span,
+        );
+    }
+
+    fn check_division(&mut self, operator: &str, right: &Expression, span: Span) {
+        if is_zero(right) {
+            self.report(
+                AnalyserDiagnostic::DivisionByZero {
+                    operator: ByteString::from(operator),
+                },
+                Error,
+                span,
+            );
+        }
+    }
+}
+
+impl<'a> Visitor for CompatibilityVisitor<'a> {
+    fn visit_arithmetic_operation_expression(&mut self, node: &ArithmeticOperationExpression) {
+        let span = node.span;
+
+        match &node.kind {
+            ArithmeticOperationKind::Addition { left, right, .. } => {
+                self.check_binary(Add, "+", left, right, span)
+            }
+            ArithmeticOperationKind::Subtraction { left, right, .. } => {
+                self.check_binary(Arithmetic, "-", left, right, span)
+            }
+            ArithmeticOperationKind::Multiplication { left, right, .. } => {
+                self.check_binary(Arithmetic, "*", left, right, span)
+            }
+            ArithmeticOperationKind::Division { left, right, .. } => {
+                self.check_binary(Arithmetic, "/", left, right, span);
+                self.check_division("/", right, span);
+            }
+            ArithmeticOperationKind::Modulo { left, right, .. } => {
+                self.check_binary(Arithmetic, "%", left, right, span);
+                self.check_division("%", right, span);
+            }
+            ArithmeticOperationKind::Exponentiation { left, right, .. } => {
+                self.check_binary(Arithmetic, "**", left, right, span)
+            }
+            ArithmeticOperationKind::PreIncrement { right: operand, .. }
+            | ArithmeticOperationKind::PostIncrement { left: operand, .. } => {
+                self.check_increment(Increment, operand, span)
+            }
+            ArithmeticOperationKind::PreDecrement { right: operand, .. }
+            | ArithmeticOperationKind::PostDecrement { left: operand, .. } => {
+                self.check_increment(Decrement, operand, span)
+            }
+            ArithmeticOperationKind::Negative { .. } | ArithmeticOperationKind::Positive { .. } => {
+            }
+        }
+
+        walk_arithmetic_operation_expression(self, node);
+    }
+
+    fn visit_clone_expression(&mut self, node: &CloneExpression) {
+        self.check_clone(&node.target);
+
+        walk_clone_expression(self, node);
+    }
+
+    fn visit_concat_expression(&mut self, node: &ConcatExpression) {
+        for operand in [&node.left, &node.right] {
+            if let Some(outcome) = self.unary(Concat, operand) {
+                let kind = AnalyserDiagnostic::InvalidStringConversion {
+                    r#type: self.describe(operand),
+                };
+
+                self.report(kind, outcome, operand.span);
+            }
+        }
+
+        walk_concat_expression(self, node);
+    }
+
+    fn visit_comparison_operation_expression(&mut self, node: &ComparisonOperationExpression) {
+        let span = node.span;
+
+        let (operation, operator, left, right) = match &node.kind {
+            ComparisonOperationKind::Identical { left, right, .. } => {
+                (Identical, "===", left, right)
+            }
+            ComparisonOperationKind::NotIdentical { left, right, .. } => {
+                (Identical, "!==", left, right)
+            }
+            ComparisonOperationKind::Equal { left, right, .. } => (Equal, "==", left, right),
+            ComparisonOperationKind::NotEqual { left, right, .. } => (Equal, "!=", left, right),
+            ComparisonOperationKind::AngledNotEqual { left, right, .. } => {
+                (Equal, "<>", left, right)
+            }
+            ComparisonOperationKind::LessThan { left, right, .. } => (Ordering, "<", left, right),
+            ComparisonOperationKind::GreaterThan { left, right, .. } => {
+                (Ordering, ">", left, right)
+            }
+            ComparisonOperationKind::LessThanOrEqual { left, right, .. } => {
+                (Ordering, "<=", left, right)
+            }
+            ComparisonOperationKind::GreaterThanOrEqual { left, right, .. } => {
+                (Ordering, ">=", left, right)
+            }
+            ComparisonOperationKind::Spaceship { left, right, .. } => {
+                (Ordering, "<=>", left, right)
+            }
+        };
+
+        self.check_binary(operation, operator, left, right, span);
+
+        walk_comparison_operation_expression(self, node);
+    }
+}
+
+/// Whether an expression is a literal zero, e.g. `0`, `0.0` or `-0`.
+fn is_zero(expression: &Expression) -> bool {
+    match &expression.kind {
+        ExpressionKind::Literal(literal) => {
+            matches!(literal.integer_value(), Some(IntegerValue::Int(0)))
+                || literal.float_value() == Some(0.0)
+        }
+        ExpressionKind::Parenthesized(inner) => is_zero(&inner.expr),
+        ExpressionKind::ArithmeticOperation(operation) => match &operation.kind {
+            ArithmeticOperationKind::Negative { right, .. }
+            | ArithmeticOperationKind::Positive { right, .. } => is_zero(right),
+            _ => false,
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,815 @@
+use std::collections::{HashMap, HashSet};
+
+use pxp_ast::{
+    visitor::{
+        walk_arrow_function_expression, walk_braced_namespace, walk_closure_expression,
+        walk_comparison_operation_expression, walk_function_call_expression,
+        walk_function_statement, walk_method, walk_statement, walk_unbraced_namespace, Visitor,
+    },
+    *,
+};
+use pxp_bytestring::ByteString;
+use pxp_diagnostics::{Diagnostic, Severity};
+use pxp_span::Span;
+use pxp_type::{ConstExpr, Type};
+
+use crate::{AnalyserContext, AnalyserDiagnostic};
+
+use super::{global_function_name, Pass};
+
+/// The type checking functions that are understood, along with the kind of value they check for.
+const TYPE_CHECKS: [(&[u8], Kind); 10] = [
+    (b"is_string", Kind::String),
+    (b"is_int", Kind::Integer),
+    (b"is_integer", Kind::Integer),
+    (b"is_long", Kind::Integer),
+    (b"is_float", Kind::Float),
+    (b"is_double", Kind::Float),
+    (b"is_bool", Kind::Boolean),
+    (b"is_array", Kind::Array),
+    (b"is_null", Kind::Null),
+    (b"is_object", Kind::Object),
+];
+
+/// Comments that mark a diagnostic on the statement as expected, compared case-insensitively.
+const IGNORE_MARKERS: [&[u8]; 2] = [b"@pxp-ignore", b"@phpstan-ignore"];
+
+/// Reports conditions that are decided by the types of their operands, e.g. `is_string($name)`
+/// when `$name` is always a string, `$value === null` when `$value` can never be `null` and
+/// `'a' === 1`.
+///
+/// Only types that can be relied on are used: literals, the declared types of calls and properties,
+/// parameters that are never reassigned and local variables with a single, unconditional
+/// assignment. Mixed types and class-likes that aren't in the index are never reported. Loose
+/// comparisons aren't checked at all, since `'1' == 1` is true.
+///
+/// Statements with a `@pxp-ignore` or `@phpstan-ignore` comment still produce the diagnostics, but
+/// as notes rather than warnings.
+#[derive(Debug, Default)]
+pub struct ConditionPass;
+
+impl ConditionPass {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Pass for ConditionPass {
+    fn codes(&self) -> &'static [&'static str] {
+        &["A014", "A015", "A016"]
+    }
+
+    fn run(
+        &mut self,
+        context: &AnalyserContext,
+        ast: &[Statement],
+    ) -> Vec<Diagnostic<AnalyserDiagnostic>> {
+        let mut visitor = ConditionVisitor {
+            context,
+            diagnostics: Vec::new(),
+            scopes: vec![Scope::new(&[], &top_level_statements(ast), ast)],
+            namespace: None,
+            ignored: 0,
+        };
+
+        visitor.visit(ast);
+        visitor.diagnostics
+    }
+}
+
+/// The kinds of value that PHP's strict comparison and type checking functions tell apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Null,
+    Boolean,
+    Integer,
+    Float,
+    String,
+    Array,
+    Object,
+}
+
+/// One of the values an expression could evaluate to. Literal values are known for strings,
+/// booleans and `null`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Value {
+    kind: Kind,
+    literal: Option<ByteString>,
+}
+
+impl Value {
+    fn new(kind: Kind) -> Self {
+        Self {
+            kind,
+            literal: None,
+        }
+    }
+
+    fn literal(kind: Kind, literal: impl Into<ByteString>) -> Self {
+        Self {
+            kind,
+            literal: Some(literal.into()),
+        }
+    }
+
+    /// Whether the values could be identical, i.e. `===` could be true.
+    fn overlaps(&self, other: &Value) -> bool {
+        self.kind == other.kind
+            && match (&self.literal, &other.literal) {
+                (Some(a), Some(b)) => a == b,
+                _ => true,
+            }
+    }
+}
+
+/// The variables whose inferred types can be relied on inside of a function body.
+struct Scope {
+    reliable: HashSet<ByteString>,
+}
+
+impl Scope {
+    fn new(parameters: &[&SimpleVariable], body: &[&Statement], nested: &[Statement]) -> Self {
+        let mut writes = Writes::default();
+        writes.visit(nested);
+
+        let parameters = parameters
+            .iter()
+            .map(|parameter| &parameter.symbol)
+            .filter(|name| writes.count(name) == 0);
+
+        // The type of a variable is the type of its last assignment, so it's only correct everywhere
+        // after the assignment when there aren't any others.
+        let assigned = body
+            .iter()
+            .filter_map(|statement| unconditional_assignment(statement))
+            .filter(|name| writes.count(name) == 1);
+
+        Self {
+            reliable: parameters.chain(assigned).cloned().collect(),
+        }
+    }
+
+    /// A scope where no variables can be relied on.
+    fn unreliable() -> Self {
+        Self {
+            reliable: HashSet::new(),
+        }
+    }
+}
+
+struct ConditionVisitor<'a> {
+    context: &'a AnalyserContext<'a>,
+    diagnostics: Vec<Diagnostic<AnalyserDiagnostic>>,
+    scopes: Vec<Scope>,
+    namespace: Option<ByteString>,
+    // The number of enclosing statements with an ignore comment.
+    ignored: usize,
+}
+
+impl<'a> ConditionVisitor<'a> {
+    fn report(&mut self, kind: AnalyserDiagnostic, span: Span) {
+        let severity = if self.ignored > 0 {
+            Severity::Information
+        } else {
+            Severity::Warning
+        };
+
+        self.diagnostics.push(Diagnostic::new(kind, severity, span));
+    }
+
+    fn in_scope(&mut self, scope: Scope, f: impl FnOnce(&mut Self)) {
+        self.scopes.push(scope);
+        f(self);
+        self.scopes.pop();
+    }
+
+    /// Whether the inferred type of the expression can be relied on.
+    fn is_reliable(&self, expression: &Expression) -> bool {
+        match &expression.kind {
+            ExpressionKind::Literal(_)
+            | ExpressionKind::Bool(_)
+            | ExpressionKind::Null(_)
+            | ExpressionKind::New(_) => true,
+            ExpressionKind::Parenthesized(inner) => self.is_reliable(&inner.expr),
+            ExpressionKind::FunctionCall(call) => {
+                matches!(call.target.kind, ExpressionKind::Name(_))
+            }
+            ExpressionKind::StaticMethodCall(call) => {
+                matches!(call.target.kind, ExpressionKind::Name(_))
+            }
+            ExpressionKind::MethodCall(call) => self.is_reliable(&call.target),
+            ExpressionKind::NullsafeMethodCall(call) => self.is_reliable(&call.target),
+            ExpressionKind::PropertyFetch(fetch) => self.is_reliable(&fetch.target),
+            ExpressionKind::NullsafePropertyFetch(fetch) => self.is_reliable(&fetch.target),
+            ExpressionKind::Variable(variable) => match variable.as_ref() {
+                Variable::SimpleVariable(variable) => self
+                    .scopes
+                    .last()
+                    .is_some_and(|scope| scope.reliable.contains(&variable.symbol)),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Determine the values that the expression could evaluate to, if they're known.
+    fn values(&self, expression: &Expression) -> Option<Vec<Value>> {
+        if !self.is_reliable(expression) {
+            return None;
+        }
+
+        // `null` doesn't have a node of its own, so it isn't given a type by inference.
+        if let ExpressionKind::Null(_) = expression.kind {
+            return Some(vec![Value::literal(Kind::Null, "null")]);
+        }
+
+        let mut values = Vec::new();
+
+        if !self.collect_values(self.context.types().resolve(expression.id), &mut values) {
+            return None;
+        }
+
+        values.dedup();
+
+        (!values.is_empty()).then_some(values)
+    }
+
+    fn collect_values(&self, ty: &Type<ResolvedName>, values: &mut Vec<Value>) -> bool {
+        let value = match ty {
+            Type::Null => Value::literal(Kind::Null, "null"),
+            Type::True => Value::literal(Kind::Boolean, "true"),
+            Type::False => Value::literal(Kind::Boolean, "false"),
+            Type::Boolean => Value::new(Kind::Boolean),
+            Type::Integer | Type::NonNegativeInteger => Value::new(Kind::Integer),
+            Type::Float => Value::new(Kind::Float),
+            Type::LiteralString(value) => Value::literal(Kind::String, value.clone()),
+            Type::String
+            | Type::NonEmptyString
+            | Type::NumericString
+            | Type::ClassString
+            | Type::CallableString => Value::new(Kind::String),
+            Type::ConstExpr(inner) => match inner.as_ref() {
+                ConstExpr::Integer(_) | ConstExpr::NegativeInteger(_) => Value::new(Kind::Integer),
+                ConstExpr::Float(_) => Value::new(Kind::Float),
+                ConstExpr::String(value) => Value::literal(Kind::String, value.clone()),
+                ConstExpr::ConstFetch(..) => return false,
+            },
+            Type::Array
+            | Type::NonEmptyArray
+            | Type::List
+            | Type::NonEmptyList
+            | Type::TypedArray(..)
+            | Type::Shaped { .. } => Value::new(Kind::Array),
+            Type::Object => Value::new(Kind::Object),
+            Type::Named(name)
+                if self
+                    .context
+                    .index()
+                    .get_class(name.resolved.clone())
+                    .is_some() =>
+            {
+                Value::new(Kind::Object)
+            }
+            Type::Generic(base, _) => return self.collect_values(base, values),
+            Type::ArrayKey => {
+                values.push(Value::new(Kind::Integer));
+                Value::new(Kind::String)
+            }
+            Type::Nullable(inner) => {
+                values.push(Value::literal(Kind::Null, "null"));
+
+                return self.collect_values(inner, values);
+            }
+            Type::Union(types) => {
+                return types.iter().all(|ty| self.collect_values(ty, values));
+            }
+            // Everything else is either unknown or could be more than one kind of value, e.g. a callable.
+            _ => return false,
+        };
+
+        values.push(value);
+
+        true
+    }
+
+    fn check_type_check(&mut self, call: &FunctionCallExpression) {
+        let Some((function, kind)) = self.type_check(call) else {
+            return;
+        };
+
+        let [Argument::Positional(PositionalArgument {
+            value,
+            ellipsis: None,
+            ..
+        })] = call.arguments.arguments.as_slice()
+        else {
+            return;
+        };
+
+        let Some(values) = self.values(value) else {
+            return;
+        };
+
+        let result = if values.iter().all(|value| value.kind == kind) {
+            true
+        } else if values.iter().all(|value| value.kind != kind) {
+            false
+        } else {
+            return;
+        };
+
+        self.report(
+            AnalyserDiagnostic::RedundantTypeCheck {
+                function,
+                r#type: self.describe(value),
+                result,
+            },
+            call.span,
+        );
+    }
+
+    fn check_in_array(&mut self, call: &FunctionCallExpression) {
+        if !global_function_name(self.context, self.namespace.as_ref(), &call.target)
+            .is_some_and(|name| name == b"in_array")
+        {
+            return;
+        }
+
+        let haystack =
+            call.arguments
+                .arguments
+                .iter()
+                .enumerate()
+                .find_map(|(position, argument)| match argument {
+                    Argument::Positional(argument)
+                        if position == 1 && argument.ellipsis.is_none() =>
+                    {
+                        Some(&argument.value)
+                    }
+                    Argument::Named(argument) if argument.name.symbol == b"haystack" => {
+                        Some(&argument.value)
+                    }
+                    _ => None,
+                });
+
+        let is_empty = match haystack.map(|haystack| &haystack.kind) {
+            Some(ExpressionKind::Array(array)) => array.items.is_empty(),
+            Some(ExpressionKind::List(list)) => list.items.is_empty(),
+            _ => false,
+        };
+
+        if is_empty {
+            self.report(AnalyserDiagnostic::InArrayWithEmptyArray, call.span);
+        }
+    }
+
+    fn check_strict_comparison(
+        &mut self,
+        left: &Expression,
+        right: &Expression,
+        identical: bool,
+        span: Span,
+    ) {
+        let (Some(left_values), Some(right_values)) = (self.values(left), self.values(right))
+        else {
+            return;
+        };
+
+        // Comparing an object with a scalar or an array is reported by `TypeCompatibilityPass`.
+        if is_object_comparison(&left_values, &right_values)
+            || is_object_comparison(&right_values, &left_values)
+        {
+            return;
+        }
+
+        let overlaps = left_values
+            .iter()
+            .any(|left| right_values.iter().any(|right| left.overlaps(right)));
+
+        let always_identical = match (left_values.as_slice(), right_values.as_slice()) {
+            ([left], [right]) => left.literal.is_some() && left == right,
+            _ => false,
+        };
+
+        let result = if !overlaps {
+            !identical
+        } else if always_identical {
+            identical
+        } else {
+            return;
+        };
+
+        self.report(
+            AnalyserDiagnostic::RedundantStrictComparison {
+                left: self.describe(left),
+                right: self.describe(right),
+                result,
+            },
+            span,
+        );
+    }
+
+    /// Describe the type of an expression in a diagnostic, showing the value of literal strings.
+    fn describe(&self, expression: &Expression) -> ByteString {
+        if let ExpressionKind::Null(_) = expression.kind {
+            return ByteString::from("null");
+        }
+
+        match self.context.types().resolve(expression.id) {
+            Type::LiteralString(value) => {
+                let mut described = ByteString::from("'");
+                described.extend(value);
+                described.extend_with_bytes(b"'");
+                described
+            }
+            ty => ByteString::from(ty.to_string()),
+        }
+    }
+
+    /// Find the type checking function being called, unless a function with the same name has been
+    /// declared in the current namespace.
+    fn type_check(&self, call: &FunctionCallExpression) -> Option<(ByteString, Kind)> {
+        let name = global_function_name(self.context, self.namespace.as_ref(), &call.target)?;
+
+        TYPE_CHECKS
+            .iter()
+            .find(|(function, _)| &name[..] == *function)
+            .map(|(_, kind)| (name, *kind))
+    }
+}
+
+impl<'a> Visitor for ConditionVisitor<'a> {
+    fn visit_statement(&mut self, node: &Statement) {
+        let ignored = is_ignored(&node.comments) || is_ignored(&node.trailing_comments);
+
+        self.ignored += ignored as usize;
+        walk_statement(self, node);
+        self.ignored -= ignored as usize;
+    }
+
+    fn visit_unbraced_namespace(&mut self, node: &UnbracedNamespace) {
+        self.namespace = Some(node.name.symbol.clone());
+        walk_unbraced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_braced_namespace(&mut self, node: &BracedNamespace) {
+        self.namespace = node.name.as_ref().map(|name| name.symbol.clone());
+        walk_braced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_function_statement(&mut self, node: &FunctionStatement) {
+        let parameters = node
+            .parameters
+            .parameters
+            .iter()
+            .map(|parameter| &parameter.name)
+            .collect::<Vec<_>>();
+        let body = node.body.statements.iter().collect::<Vec<_>>();
+
+        self.in_scope(
+            Scope::new(&parameters, &body, &node.body.statements),
+            |visitor| walk_function_statement(visitor, node),
+        );
+    }
+
+    fn visit_method(&mut self, node: &Method) {
+        // The parameters of methods aren't given types by inference yet.
+        let scope = match &node.body.kind {
+            MethodBodyKind::Concrete(body) => {
+                let statements = body.statements.iter().collect::<Vec<_>>();
+
+                Scope::new(&[], &statements, &body.statements)
+            }
+            _ => Scope::unreliable(),
+        };
+
+        self.in_scope(scope, |visitor| walk_method(visitor, node));
+    }
+
+    fn visit_closure_expression(&mut self, node: &ClosureExpression) {
+        // Closures share the scope of their parent during inference, so their variables can't be
+        // relied on.
+        self.in_scope(Scope::unreliable(), |visitor| {
+            walk_closure_expression(visitor, node)
+        });
+    }
+
+    fn visit_arrow_function_expression(&mut self, node: &ArrowFunctionExpression) {
+        self.in_scope(Scope::unreliable(), |visitor| {
+            walk_arrow_function_expression(visitor, node)
+        });
+    }
+
+    fn visit_function_call_expression(&mut self, node: &FunctionCallExpression) {
+        self.check_type_check(node);
+        self.check_in_array(node);
+
+        walk_function_call_expression(self, node);
+    }
+
+    fn visit_comparison_operation_expression(&mut self, node: &ComparisonOperationExpression) {
+        match &node.kind {
+            ComparisonOperationKind::Identical { left, right, .. } => {
+                self.check_strict_comparison(left, right, true, node.span)
+            }
+            ComparisonOperationKind::NotIdentical { left, right, .. } => {
+                self.check_strict_comparison(left, right, false, node.span)
+            }
+            _ => {}
+        }
+
+        walk_comparison_operation_expression(self, node);
+    }
+}
+
+/// Whether one side of a comparison is always an object, and the other is never an object or `null`.
+fn is_object_comparison(objects: &[Value], others: &[Value]) -> bool {
+    objects.iter().all(|value| value.kind == Kind::Object)
+        && others
+            .iter()
+            .all(|value| !matches!(value.kind, Kind::Object | Kind::Null))
+}
+
+/// The statements at the top level of a file, including those inside of namespaces.
+fn top_level_statements(ast: &[Statement]) -> Vec<&Statement> {
+    ast.iter()
+        .flat_map(|statement| match &statement.kind {
+            StatementKind::Namespace(namespace) => match namespace.as_ref() {
+                NamespaceStatement::Unbraced(namespace) => {
+                    top_level_statements(&namespace.statements)
+                }
+                NamespaceStatement::Braced(namespace) => {
+                    top_level_statements(&namespace.body.statements)
+                }
+            },
+            _ => vec![statement],
+        })
+        .collect()
+}
+
+/// Get the variable assigned by a statement like `$name = 'value';`.
+fn unconditional_assignment(statement: &Statement) -> Option<&ByteString> {
+    let StatementKind::Expression(statement) = &statement.kind else {
+        return None;
+    };
+
+    let ExpressionKind::AssignmentOperation(assignment) = &statement.expression.kind else {
+        return None;
+    };
+
+    match (
+        &assignment.kind,
+        &assignment.left.kind,
+        &assignment.right.kind,
+    ) {
+        (_, _, ExpressionKind::Reference(_)) => None,
+        (AssignmentOperationKind::Assign(_), ExpressionKind::Variable(variable), _) => {
+            match variable.as_ref() {
+                Variable::SimpleVariable(variable) => Some(&variable.symbol),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn is_ignored(comments: &CommentGroup) -> bool {
+    comments.iter().any(|comment| {
+        let content = match &comment.kind {
+            CommentKind::SingleLine(SingleLineComment { content, .. })
+            | CommentKind::MultiLine(MultiLineComment { content, .. })
+            | CommentKind::HashMark(HashMarkComment { content, .. }) => content.clone(),
+            CommentKind::DocBlock(DocBlockComment { doc, .. }) => {
+                let mut content = ByteString::empty();
+
+                for node in doc.nodes.iter() {
+                    if let DocBlockNode::Tag(DocBlockTagNode {
+                        tag: DocBlockTag::Generic(tag),
+                        ..
+                    }) = node
+                    {
+                        content.extend(&tag.tag.symbol);
+                        content.extend_with_bytes(b" ");
+                    }
+                }
+
+                content
+            }
+        };
+
+        let content = content.to_ascii_lowercase();
+
+        IGNORE_MARKERS.iter().any(|marker| {
+            content
+                .windows(marker.len())
+                .any(|window| window == *marker)
+        })
+    })
+}
+
+/// Counts how many times each variable is written to, including inside of methods and closures
+/// since inference doesn't give them a scope of their own yet. Functions have their own scope.
+#[derive(Debug, Default)]
+struct Writes(HashMap<ByteString, usize>);
+
+impl Writes {
+    fn count(&self, name: &ByteString) -> usize {
+        self.0.get(name).copied().unwrap_or(0)
+    }
+
+    fn insert(&mut self, variable: &SimpleVariable) {
+        *self.0.entry(variable.symbol.clone()).or_default() += 1;
+    }
+
+    fn target(&mut self, target: &Expression) {
+        match &target.kind {
+            ExpressionKind::Variable(variable) => {
+                if let Variable::SimpleVariable(variable) = variable.as_ref() {
+                    self.insert(variable);
+                }
+            }
+            ExpressionKind::ArrayIndex(index) => self.target(&index.array),
+            ExpressionKind::Parenthesized(inner) => self.target(&inner.expr),
+            ExpressionKind::Array(array) => {
+                for item in array.items.iter() {
+                    match item {
+                        ArrayItem::Value(ArrayItemValue { value, .. })
+                        | ArrayItem::ReferencedValue(ArrayItemReferencedValue { value, .. })
+                        | ArrayItem::KeyValue(ArrayItemKeyValue { value, .. })
+                        | ArrayItem::ReferencedKeyValue(ArrayItemReferencedKeyValue {
+                            value,
+                            ..
+                        }) => self.target(value),
+                        _ => {}
+                    }
+                }
+            }
+            ExpressionKind::List(list) => {
+                for entry in list.items.iter() {
+                    match entry {
+                        ListEntry::Value(ListEntryValue { value, .. })
+                        | ListEntry::KeyValue(ListEntryKeyValue { value, .. }) => {
+                            self.target(value)
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Any plain variable passed to a function could be passed by reference, except to the
+    /// functions that this pass checks.
+    fn arguments(&mut self, arguments: &ArgumentList) {
+        for argument in arguments.arguments.iter() {
+            match argument {
+                Argument::Positional(argument) => self.target(&argument.value),
+                Argument::Named(argument) => self.target(&argument.value),
+            }
+        }
+    }
+}
+
+impl Visitor for Writes {
+    fn visit_assignment_operation_expression(&mut self, node: &AssignmentOperationExpression) {
+        self.target(&node.left);
+        self.visit_expression(&node.left);
+        self.visit_expression(&node.right);
+    }
+
+    fn visit_arithmetic_operation_expression(&mut self, node: &ArithmeticOperationExpression) {
+        match &node.kind {
+            ArithmeticOperationKind::PreIncrement { right, .. }
+            | ArithmeticOperationKind::PreDecrement { right, .. } => self.target(right),
+            ArithmeticOperationKind::PostIncrement { left, .. }
+            | ArithmeticOperationKind::PostDecrement { left, .. } => self.target(left),
+            _ => {}
+        }
+
+        pxp_ast::visitor::walk_arithmetic_operation_expression(self, node);
+    }
+
+    fn visit_reference_expression(&mut self, node: &ReferenceExpression) {
+        self.target(&node.right);
+    }
+
+    fn visit_array_item_referenced_value(&mut self, node: &ArrayItemReferencedValue) {
+        self.target(&node.value);
+    }
+
+    fn visit_array_item_referenced_key_value(&mut self, node: &ArrayItemReferencedKeyValue) {
+        self.target(&node.value);
+    }
+
+    fn visit_foreach_statement(&mut self, node: &ForeachStatement) {
+        match &node.iterator {
+            ForeachStatementIterator::Value(iterator) => self.target(&iterator.value),
+            ForeachStatementIterator::KeyAndValue(iterator) => {
+                self.target(&iterator.key);
+                self.target(&iterator.value);
+            }
+        }
+
+        pxp_ast::visitor::walk_foreach_statement(self, node);
+    }
+
+    fn visit_catch_block(&mut self, node: &CatchBlock) {
+        if let Some(variable) = &node.var {
+            self.insert(variable);
+        }
+
+        self.visit(&node.body);
+    }
+
+    fn visit_global_statement(&mut self, node: &GlobalStatement) {
+        for variable in node.variables.iter() {
+            if let Variable::SimpleVariable(variable) = variable {
+                self.insert(variable);
+            }
+        }
+    }
+
+    fn visit_static_var(&mut self, node: &StaticVar) {
+        if let Variable::SimpleVariable(variable) = &node.var {
+            self.insert(variable);
+        }
+    }
+
+    fn visit_unset_expression(&mut self, node: &UnsetExpression) {
+        self.arguments(&node.arguments);
+    }
+
+    fn visit_function_call_expression(&mut self, node: &FunctionCallExpression) {
+        let checked = match &node.target.kind {
+            ExpressionKind::Name(name) => {
+                let name = name.to_string().to_ascii_lowercase();
+                let name = name.rsplit('\\').next().unwrap_or_default();
+
+                name == "in_array"
+                    || TYPE_CHECKS
+                        .iter()
+                        .any(|(function, _)| name.as_bytes() == *function)
+            }
+            _ => false,
+        };
+
+        if !checked {
+            self.arguments(&node.arguments);
+        }
+
+        walk_function_call_expression(self, node);
+    }
+
+    fn visit_method_call_expression(&mut self, node: &MethodCallExpression) {
+        self.arguments(&node.arguments);
+        pxp_ast::visitor::walk_method_call_expression(self, node);
+    }
+
+    fn visit_nullsafe_method_call_expression(&mut self, node: &NullsafeMethodCallExpression) {
+        self.arguments(&node.arguments);
+        pxp_ast::visitor::walk_nullsafe_method_call_expression(self, node);
+    }
+
+    fn visit_static_method_call_expression(&mut self, node: &StaticMethodCallExpression) {
+        self.arguments(&node.arguments);
+        pxp_ast::visitor::walk_static_method_call_expression(self, node);
+    }
+
+    fn visit_new_expression(&mut self, node: &NewExpression) {
+        if let Some(arguments) = &node.arguments {
+            self.arguments(arguments);
+        }
+
+        pxp_ast::visitor::walk_new_expression(self, node);
+    }
+
+    fn visit_closure_expression(&mut self, node: &ClosureExpression) {
+        if let Some(uses) = &node.uses {
+            for variable in uses.variables.iter() {
+                if variable.ampersand.is_some() {
+                    self.insert(&variable.variable);
+                }
+            }
+        }
+
+        // Inference writes the types of closure parameters into the enclosing scope.
+        for parameter in node.parameters.parameters.iter() {
+            self.insert(&parameter.name);
+        }
+
+        walk_closure_expression(self, node);
+    }
+
+    fn visit_arrow_function_expression(&mut self, node: &ArrowFunctionExpression) {
+        for parameter in node.parameters.parameters.iter() {
+            self.insert(&parameter.name);
+        }
+
+        walk_arrow_function_expression(self, node);
+    }
+
+    fn visit_function_statement(&mut self, _: &FunctionStatement) {}
+}
@@ -0,0 +1,302 @@
+use pxp_ast::*;
+use pxp_bytestring::ByteString;
+use pxp_index::ReflectionFunctionLike;
+use pxp_type::Type;
+
+use crate::AnalyserContext;
+
+use super::resolve_function;
+
+/// Works out how control can leave a list of statements, so that passes can tell whether the end
+/// of a function body or a `case` can be reached. [`ReturnPass`](super::ReturnPass) describes
+/// which statements end a path.
+pub(super) struct ControlFlow<'a> {
+    context: &'a AnalyserContext<'a>,
+    // The namespace that the statements are in, used to resolve unqualified function calls.
+    namespace: Option<&'a ByteString>,
+}
+
+impl<'a> ControlFlow<'a> {
+    pub(super) fn new(context: &'a AnalyserContext<'a>, namespace: Option<&'a ByteString>) -> Self {
+        Self { context, namespace }
+    }
+
+    /// Find the ways that control can leave the given statements.
+    pub(super) fn exits(&self, statements: &[Statement]) -> Exits {
+        let mut exits = Exits::completes();
+
+        for statement in statements {
+            if !exits.completes {
+                break;
+            }
+
+            let next = self.statement(statement);
+
+            exits.completes = next.completes;
+            exits.jumps.extend(next.jumps);
+        }
+
+        exits
+    }
+
+    fn statement(&self, statement: &Statement) -> Exits {
+        match &statement.kind {
+            StatementKind::Return(_) | StatementKind::Goto(_) => Exits::ends(),
+            StatementKind::Break(statement) => Exits::jump(statement.level.as_ref(), true),
+            StatementKind::Continue(statement) => Exits::jump(statement.level.as_ref(), false),
+            StatementKind::Expression(statement) => {
+                if self.terminates(&statement.expression) {
+                    Exits::ends()
+                } else {
+                    Exits::completes()
+                }
+            }
+            StatementKind::Block(block) => self.exits(&block.statements),
+            StatementKind::If(statement) => self.if_statement(statement),
+            StatementKind::Switch(statement) => self.switch_statement(statement),
+            StatementKind::While(statement) => {
+                let body = match &statement.body {
+                    WhileStatementBody::Statement(body) => self.statement(&body.statement),
+                    WhileStatementBody::Block(body) => self.exits(&body.statements),
+                };
+
+                body.leave_loop(!is_true(&statement.condition))
+            }
+            StatementKind::DoWhile(statement) => {
+                let body = self.statement(&statement.body);
+
+                // The body always runs once, so the condition is only reached if it completes.
+                let reaches_condition = body.completes || body.continues();
+
+                body.leave_loop(reaches_condition && !is_true(&statement.condition))
+            }
+            StatementKind::For(statement) => {
+                let body = match &statement.body {
+                    ForStatementBody::Statement(body) => self.statement(&body.statement),
+                    ForStatementBody::Block(body) => self.exits(&body.statements),
+                };
+
+                // A loop without any conditions runs forever, like `for (;;)`.
+                let infinite = statement
+                    .iterator
+                    .conditions
+                    .inner
+                    .last()
+                    .map_or(true, is_true);
+
+                body.leave_loop(!infinite)
+            }
+            StatementKind::Foreach(statement) => {
+                let body = match &statement.body {
+                    ForeachStatementBody::Statement(body) => self.statement(&body.statement),
+                    ForeachStatementBody::Block(body) => self.exits(&body.statements),
+                };
+
+                body.leave_loop(true)
+            }
+            StatementKind::Try(statement) => {
+                let mut exits = self.exits(&statement.body);
+
+                for catch in statement.catches.iter() {
+                    exits.merge(self.exits(&catch.body));
+                }
+
+                if let Some(finally) = &statement.finally {
+                    let finally = self.exits(&finally.body);
+
+                    exits.completes &= finally.completes;
+                    exits.jumps.extend(finally.jumps);
+                }
+
+                exits
+            }
+            // The statements following a bodiless declare are checked by the caller.
+            StatementKind::Declare(statement) => self.exits(statement.governed_statements(&[])),
+            _ => Exits::completes(),
+        }
+    }
+
+    fn if_statement(&self, statement: &IfStatement) -> Exits {
+        let (mut exits, has_else) = match &statement.body {
+            IfStatementBody::Statement(body) => {
+                let mut exits = self.statement(&body.statement);
+
+                for elseif in body.elseifs.iter() {
+                    exits.merge(self.statement(&elseif.statement));
+                }
+
+                if let Some(r#else) = &body.r#else {
+                    exits.merge(self.statement(&r#else.statement));
+                }
+
+                (exits, body.r#else.is_some())
+            }
+            IfStatementBody::Block(body) => {
+                let mut exits = self.exits(&body.statements);
+
+                for elseif in body.elseifs.iter() {
+                    exits.merge(self.exits(&elseif.statements));
+                }
+
+                if let Some(r#else) = &body.r#else {
+                    exits.merge(self.exits(&r#else.statements));
+                }
+
+                (exits, body.r#else.is_some())
+            }
+        };
+
+        // Without an `else`, none of the branches might run.
+        exits.completes |= !has_else;
+        exits
+    }
+
+    fn switch_statement(&self, statement: &SwitchStatement) -> Exits {
+        let mut exits = Exits::ends();
+
+        // Cases fall through into the next one, so a case only reaches the end of the switch if
+        // every case after it completes too.
+        let mut reaches_end = true;
+
+        for case in statement.cases.iter().rev() {
+            let body = self.exits(&case.body);
+
+            reaches_end &= body.completes;
+            exits.completes |= reaches_end;
+            exits.jumps.extend(body.jumps);
+        }
+
+        // Without a `default`, none of the cases might match.
+        exits.completes |= !statement.cases.iter().any(|case| case.condition.is_none());
+
+        // `continue` behaves like `break` inside of a switch.
+        let completes = exits.completes || exits.breaks() || exits.continues();
+        let mut exits = exits.leave_loop(false);
+
+        exits.completes = completes;
+        exits
+    }
+
+    /// Whether evaluating the given expression statement always ends the current path.
+    fn terminates(&self, expression: &Expression) -> bool {
+        match &expression.kind {
+            ExpressionKind::Throw(_) | ExpressionKind::Exit(_) | ExpressionKind::Die(_) => true,
+            ExpressionKind::Parenthesized(inner) => self.terminates(&inner.expr),
+            // A match without a matching arm throws an `UnhandledMatchError`, so the arms are
+            // the only paths through it.
+            ExpressionKind::Match(r#match) => {
+                r#match.arms.iter().all(|arm| self.terminates(&arm.body))
+                    && r#match
+                        .default
+                        .as_ref()
+                        .map_or(true, |default| self.terminates(&default.body))
+            }
+            ExpressionKind::FunctionCall(call) => {
+                matches!(self.function_return_type(&call.target), Some(Type::Never))
+            }
+            _ => false,
+        }
+    }
+
+    fn function_return_type(&self, target: &Expression) -> Option<Type<ResolvedName>> {
+        let ExpressionKind::Name(name) = &target.kind else {
+            return None;
+        };
+
+        let function = resolve_function(self.context.index(), self.namespace, name)?;
+
+        function.effective_signature().get_return_type().cloned()
+    }
+}
+
+/// The ways that control can leave a statement.
+#[derive(Debug)]
+pub(super) struct Exits {
+    // Whether control can carry on to the next statement.
+    pub(super) completes: bool,
+    // The `break` and `continue` statements that leave the statement.
+    jumps: Vec<Jump>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Jump {
+    // The number of enclosing loops and switches that are left, including the target.
+    levels: usize,
+    r#break: bool,
+}
+
+impl Exits {
+    fn completes() -> Self {
+        Self {
+            completes: true,
+            jumps: Vec::new(),
+        }
+    }
+
+    fn ends() -> Self {
+        Self {
+            completes: false,
+            jumps: Vec::new(),
+        }
+    }
+
+    fn jump(level: Option<&Level>, r#break: bool) -> Self {
+        let levels = match level {
+            Some(Level::Literal(level)) => std::str::from_utf8(&level.literal.token.symbol)
+                .ok()
+                .and_then(|level| level.parse().ok())
+                .unwrap_or(1),
+            _ => 1,
+        };
+
+        Self {
+            completes: false,
+            jumps: vec![Jump { levels, r#break }],
+        }
+    }
+
+    /// Combine the exits of two branches, either of which might run.
+    fn merge(&mut self, other: Exits) {
+        self.completes |= other.completes;
+        self.jumps.extend(other.jumps);
+    }
+
+    fn breaks(&self) -> bool {
+        self.jumps
+            .iter()
+            .any(|jump| jump.levels == 1 && jump.r#break)
+    }
+
+    fn continues(&self) -> bool {
+        self.jumps
+            .iter()
+            .any(|jump| jump.levels == 1 && !jump.r#break)
+    }
+
+    /// Turn the exits of a loop's body into the exits of the loop itself, where `exhausts` is
+    /// whether the loop can stop by itself without a `break`.
+    fn leave_loop(self, exhausts: bool) -> Exits {
+        let completes = exhausts || self.breaks();
+
+        Exits {
+            completes,
+            jumps: self
+                .jumps
+                .into_iter()
+                .filter(|jump| jump.levels > 1)
+                .map(|jump| Jump {
+                    levels: jump.levels - 1,
+                    ..jump
+                })
+                .collect(),
+        }
+    }
+}
+
+fn is_true(expression: &Expression) -> bool {
+    match &expression.kind {
+        ExpressionKind::Bool(value) => value.value.symbol.eq_ignore_ascii_case(b"true"),
+        ExpressionKind::Parenthesized(inner) => is_true(&inner.expr),
+        _ => false,
+    }
+}
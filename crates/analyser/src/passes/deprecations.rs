@@ -0,0 +1,530 @@
+use std::collections::{HashSet, VecDeque};
+
+use pxp_ast::{
+    visitor::{
+        walk_anonymous_class_expression, walk_backed_enum_statement, walk_braced_namespace,
+        walk_class_statement, walk_clone_expression, walk_constant_fetch_expression,
+        walk_function_call_expression, walk_function_statement, walk_interface_statement,
+        walk_method, walk_method_call_expression, walk_new_expression,
+        walk_nullsafe_method_call_expression, walk_nullsafe_property_fetch_expression,
+        walk_property_fetch_expression, walk_static_method_call_expression,
+        walk_static_property_fetch_expression, walk_trait_statement, walk_unbraced_namespace,
+        walk_unit_enum_statement, Visitor,
+    },
+    *,
+};
+use pxp_bytestring::{ByteStr, ByteString};
+use pxp_diagnostics::{Diagnostic, Severity};
+use pxp_index::ReflectionClass;
+use pxp_span::Span;
+use pxp_type::Type;
+
+use crate::{AnalyserContext, AnalyserDiagnostic};
+
+use super::{resolve_function, Pass};
+
+/// Reports usages of classes, functions, methods, constants and properties that are marked as
+/// deprecated with a `@deprecated` tag or a `#[\Deprecated]` attribute.
+///
+/// Usages inside of a symbol that is deprecated itself aren't reported, since deprecated code is
+/// expected to be removed along with the code that it uses.
+#[derive(Debug, Default)]
+pub struct DeprecationPass;
+
+impl DeprecationPass {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Pass for DeprecationPass {
+    fn codes(&self) -> &'static [&'static str] {
+        &["A017"]
+    }
+
+    fn run(
+        &mut self,
+        context: &AnalyserContext,
+        ast: &[Statement],
+    ) -> Vec<Diagnostic<AnalyserDiagnostic>> {
+        let mut visitor = DeprecationVisitor {
+            context,
+            namespace: None,
+            classes: Vec::new(),
+            deprecated: 0,
+            diagnostics: Vec::new(),
+        };
+
+        visitor.visit(ast);
+        visitor.diagnostics
+    }
+}
+
+struct DeprecatedMember {
+    /// The member as it was declared, e.g. `Foo::bar()`.
+    symbol: ByteString,
+    message: ByteString,
+}
+
+struct DeprecationVisitor<'a> {
+    context: &'a AnalyserContext<'a>,
+    // The namespace that we're currently inside of, used to resolve unqualified function calls.
+    namespace: Option<ByteString>,
+    // The class-like that we're currently inside of, used to resolve `self`, `static` and `$this`.
+    // Anonymous classes push `None` since they aren't in the index.
+    classes: Vec<Option<ByteString>>,
+    // The number of deprecated symbols that we're currently inside of.
+    deprecated: usize,
+    diagnostics: Vec<Diagnostic<AnalyserDiagnostic>>,
+}
+
+impl<'a> DeprecationVisitor<'a> {
+    fn report(&mut self, symbol: ByteString, message: &ByteStr, span: Span) {
+        if self.deprecated > 0 {
+            return;
+        }
+
+        self.diagnostics.push(Diagnostic::new(
+            AnalyserDiagnostic::DeprecatedSymbol {
+                symbol,
+                message: (!message.is_empty()).then(|| message.to_bytestring()),
+            },
+            Severity::Warning,
+            span,
+        ));
+    }
+
+    fn within(&mut self, deprecated: bool, f: impl FnOnce(&mut Self)) {
+        if deprecated {
+            self.deprecated += 1;
+        }
+
+        f(self);
+
+        if deprecated {
+            self.deprecated -= 1;
+        }
+    }
+
+    fn with_class(&mut self, name: Option<&Name>, f: impl FnOnce(&mut Self)) {
+        let name = name
+            .and_then(|name| name.as_resolved())
+            .map(|name| name.resolved.clone());
+
+        let deprecated = name
+            .as_ref()
+            .and_then(|name| self.context.index().get_class(name.clone()))
+            .is_some_and(|class| class.is_deprecated());
+
+        self.classes.push(name);
+        self.within(deprecated, f);
+        self.classes.pop();
+    }
+
+    fn current_class(&self) -> Option<ReflectionClass<'a>> {
+        let name = self.classes.last()?.as_ref()?;
+
+        self.context.index().get_class(name.clone())
+    }
+
+    /// Check a class that is referenced by name, e.g. in `new Foo` or `extends Foo`.
+    fn check_class_name(&mut self, name: &Name) {
+        let Some(class) = name
+            .as_resolved()
+            .and_then(|name| self.context.index().get_class(name.resolved.clone()))
+        else {
+            return;
+        };
+
+        if let Some(message) = class.get_deprecation_message() {
+            self.report(class.name().to_bytestring(), message, name.span);
+        }
+    }
+
+    fn check_class_expression(&mut self, target: &Expression) {
+        if let ExpressionKind::Name(name) = &target.kind {
+            self.check_class_name(name);
+        }
+    }
+
+    fn check_function(&mut self, target: &Expression) {
+        let ExpressionKind::Name(name) = &target.kind else {
+            return;
+        };
+
+        let function = resolve_function(self.context.index(), self.namespace.as_ref(), name);
+
+        let Some(function) = function else {
+            return;
+        };
+
+        if let Some(message) = function.get_deprecation_message() {
+            self.report(
+                ByteString::from(format!("{}()", function.get_name())),
+                message,
+                target.span,
+            );
+        }
+    }
+
+    fn check_method(&mut self, class: Option<ReflectionClass<'a>>, method: &ByteStr, span: Span) {
+        let member = class.and_then(|class| {
+            self.find_member(class, |class| {
+                let method = class
+                    .get_methods()
+                    .into_iter()
+                    .find(|candidate| candidate.get_name().eq_ignore_ascii_case(method))?;
+
+                Some(
+                    method
+                        .get_deprecation_message()
+                        .map(|message| DeprecatedMember {
+                            symbol: format!("{}::{}()", class.name(), method.get_name()).into(),
+                            message: message.to_bytestring(),
+                        }),
+                )
+            })
+        });
+
+        if let Some(member) = member {
+            self.report(member.symbol, member.message.as_ref(), span);
+        }
+    }
+
+    fn check_constant(
+        &mut self,
+        class: Option<ReflectionClass<'a>>,
+        constant: &ByteStr,
+        span: Span,
+    ) {
+        let member = class.and_then(|class| {
+            self.find_member(class, |class| {
+                let constant = class.get_constant(constant)?;
+
+                Some(
+                    constant
+                        .get_deprecation_message()
+                        .map(|message| DeprecatedMember {
+                            symbol: format!("{}::{}", class.name(), constant.get_name()).into(),
+                            message: message.to_bytestring(),
+                        }),
+                )
+            })
+        });
+
+        if let Some(member) = member {
+            self.report(member.symbol, member.message.as_ref(), span);
+        }
+    }
+
+    fn check_property(
+        &mut self,
+        class: Option<ReflectionClass<'a>>,
+        property: &ByteStr,
+        span: Span,
+    ) {
+        let member = class.and_then(|class| {
+            self.find_member(class, |class| {
+                let property = class.get_property(property)?;
+
+                Some(
+                    property
+                        .get_deprecation_message()
+                        .map(|message| DeprecatedMember {
+                            symbol: format!("{}::${}", class.name(), property.get_name()).into(),
+                            message: message.to_bytestring(),
+                        }),
+                )
+            })
+        });
+
+        if let Some(member) = member {
+            self.report(member.symbol, member.message.as_ref(), span);
+        }
+    }
+
+    /// Find the member in the class-like itself, then the traits that it uses and its ancestors.
+    /// The callback returns `Some` when the class-like declares the member, which stops the search,
+    /// and `Some(Some(..))` when that member is deprecated.
+    fn find_member(
+        &self,
+        class: ReflectionClass<'a>,
+        member: impl Fn(&ReflectionClass) -> Option<Option<DeprecatedMember>>,
+    ) -> Option<DeprecatedMember> {
+        let index = self.context.index();
+        let mut pending = VecDeque::from([class.name().to_bytestring()]);
+        let mut visited = HashSet::new();
+
+        while let Some(name) = pending.pop_front() {
+            if !visited.insert(name.clone()) {
+                continue;
+            }
+
+            let Some(class) = index.get_class(name) else {
+                continue;
+            };
+
+            if let Some(member) = member(&class) {
+                return member;
+            }
+
+            pending.extend(
+                class
+                    .get_trait_names()
+                    .chain(class.get_parent_name())
+                    .chain(class.get_interface_names())
+                    .map(|name| name.to_bytestring()),
+            );
+        }
+
+        None
+    }
+
+    fn resolve_class_expression(&self, target: &Expression) -> Option<ReflectionClass<'a>> {
+        let index = self.context.index();
+
+        match &target.kind {
+            ExpressionKind::Name(name) => match &name.kind {
+                NameKind::Resolved(name) => index.get_class(name.resolved.clone()),
+                NameKind::Special(special) => self.resolve_special_class(special.kind),
+                NameKind::Unresolved(_) => None,
+            },
+            ExpressionKind::Self_(_) => self.resolve_special_class(SpecialNameKind::Self_),
+            ExpressionKind::Static(_) => self.resolve_special_class(SpecialNameKind::Static),
+            ExpressionKind::Parent(_) => self.resolve_special_class(SpecialNameKind::Parent),
+            _ => None,
+        }
+    }
+
+    fn resolve_special_class(&self, kind: SpecialNameKind) -> Option<ReflectionClass<'a>> {
+        let class = self.current_class()?;
+
+        match kind {
+            SpecialNameKind::Self_ | SpecialNameKind::Static => Some(class),
+            SpecialNameKind::Parent => self
+                .context
+                .index()
+                .get_class(class.get_parent_name()?.to_bytestring()),
+        }
+    }
+
+    fn resolve_class_from_type(&self, ty: &Type<ResolvedName>) -> Option<ReflectionClass<'a>> {
+        match ty {
+            Type::Named(name) => self.context.index().get_class(name.resolved.clone()),
+            Type::Nullable(inner) => self.resolve_class_from_type(inner),
+            _ => None,
+        }
+    }
+
+    fn resolve_receiver(&self, target: &Expression) -> Option<ReflectionClass<'a>> {
+        if let ExpressionKind::Variable(variable) = &target.kind {
+            if variable.is_simple() && variable.to_simple().stripped == b"this" {
+                return self.current_class();
+            }
+        }
+
+        self.resolve_class_from_type(self.context.types().resolve(target.id))
+    }
+
+    fn member_name(member: &Expression) -> Option<&SimpleIdentifier> {
+        match &member.kind {
+            ExpressionKind::Identifier(identifier) if identifier.is_simple() => {
+                Some(identifier.to_simple())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'a> Visitor for DeprecationVisitor<'a> {
+    fn visit_unbraced_namespace(&mut self, node: &UnbracedNamespace) {
+        self.namespace = Some(node.name.symbol.clone());
+        walk_unbraced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_braced_namespace(&mut self, node: &BracedNamespace) {
+        self.namespace = node.name.as_ref().map(|name| name.symbol.clone());
+        walk_braced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_class_statement(&mut self, node: &ClassStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            if let Some(extends) = &node.extends {
+                visitor.check_class_name(&extends.parent);
+            }
+
+            if let Some(implements) = &node.implements {
+                for interface in implements.interfaces.iter() {
+                    visitor.check_class_name(interface);
+                }
+            }
+
+            walk_class_statement(visitor, node)
+        });
+    }
+
+    fn visit_interface_statement(&mut self, node: &InterfaceStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            if let Some(extends) = &node.extends {
+                for parent in extends.parents.iter() {
+                    visitor.check_class_name(parent);
+                }
+            }
+
+            walk_interface_statement(visitor, node)
+        });
+    }
+
+    fn visit_trait_statement(&mut self, node: &TraitStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_trait_statement(visitor, node)
+        });
+    }
+
+    fn visit_unit_enum_statement(&mut self, node: &UnitEnumStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            for interface in node.implements.iter() {
+                visitor.check_class_name(interface);
+            }
+
+            walk_unit_enum_statement(visitor, node)
+        });
+    }
+
+    fn visit_backed_enum_statement(&mut self, node: &BackedEnumStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            for interface in node.implements.iter() {
+                visitor.check_class_name(interface);
+            }
+
+            walk_backed_enum_statement(visitor, node)
+        });
+    }
+
+    fn visit_anonymous_class_expression(&mut self, node: &AnonymousClassExpression) {
+        self.with_class(None, |visitor| {
+            walk_anonymous_class_expression(visitor, node)
+        });
+    }
+
+    fn visit_method(&mut self, node: &Method) {
+        let deprecated = self.current_class().is_some_and(|class| {
+            class
+                .get_method(node.name.symbol.as_ref())
+                .is_some_and(|method| method.is_deprecated())
+        });
+
+        self.within(deprecated, |visitor| walk_method(visitor, node));
+    }
+
+    fn visit_function_statement(&mut self, node: &FunctionStatement) {
+        let deprecated = node
+            .name
+            .as_resolved()
+            .and_then(|name| self.context.index().get_function(name.resolved.clone()))
+            .is_some_and(|function| function.is_deprecated());
+
+        self.within(deprecated, |visitor| walk_function_statement(visitor, node));
+    }
+
+    fn visit_function_call_expression(&mut self, node: &FunctionCallExpression) {
+        walk_function_call_expression(self, node);
+
+        self.check_function(&node.target);
+    }
+
+    fn visit_new_expression(&mut self, node: &NewExpression) {
+        walk_new_expression(self, node);
+
+        self.check_class_expression(&node.target);
+
+        let class = self.resolve_class_expression(&node.target);
+        self.check_method(class, b"__construct".into(), node.target.span);
+    }
+
+    fn visit_clone_expression(&mut self, node: &CloneExpression) {
+        walk_clone_expression(self, node);
+
+        // Cloning an object calls its `__clone()` method.
+        let class = self.resolve_receiver(&node.target);
+        self.check_method(class, b"__clone".into(), node.clone);
+    }
+
+    fn visit_method_call_expression(&mut self, node: &MethodCallExpression) {
+        walk_method_call_expression(self, node);
+
+        if let Some(method) = Self::member_name(&node.method) {
+            let class = self.resolve_receiver(&node.target);
+            self.check_method(class, method.symbol.as_ref(), method.span);
+        }
+    }
+
+    fn visit_nullsafe_method_call_expression(&mut self, node: &NullsafeMethodCallExpression) {
+        walk_nullsafe_method_call_expression(self, node);
+
+        if let Some(method) = Self::member_name(&node.method) {
+            let class = self.resolve_receiver(&node.target);
+            self.check_method(class, method.symbol.as_ref(), method.span);
+        }
+    }
+
+    fn visit_static_method_call_expression(&mut self, node: &StaticMethodCallExpression) {
+        walk_static_method_call_expression(self, node);
+
+        self.check_class_expression(&node.target);
+
+        if node.method.is_simple() {
+            let method = node.method.to_simple();
+            let class = self.resolve_class_expression(&node.target);
+            self.check_method(class, method.symbol.as_ref(), method.span);
+        }
+    }
+
+    fn visit_constant_fetch_expression(&mut self, node: &ConstantFetchExpression) {
+        walk_constant_fetch_expression(self, node);
+
+        self.check_class_expression(&node.target);
+
+        if node.constant.is_simple() {
+            let constant = node.constant.to_simple();
+
+            if constant.symbol.eq_ignore_ascii_case(b"class") {
+                return;
+            }
+
+            let class = self.resolve_class_expression(&node.target);
+            self.check_constant(class, constant.symbol.as_ref(), constant.span);
+        }
+    }
+
+    fn visit_property_fetch_expression(&mut self, node: &PropertyFetchExpression) {
+        walk_property_fetch_expression(self, node);
+
+        if let Some(property) = Self::member_name(&node.property) {
+            let class = self.resolve_receiver(&node.target);
+            self.check_property(class, property.symbol.as_ref(), property.span);
+        }
+    }
+
+    fn visit_nullsafe_property_fetch_expression(&mut self, node: &NullsafePropertyFetchExpression) {
+        walk_nullsafe_property_fetch_expression(self, node);
+
+        if let Some(property) = Self::member_name(&node.property) {
+            let class = self.resolve_receiver(&node.target);
+            self.check_property(class, property.symbol.as_ref(), property.span);
+        }
+    }
+
+    fn visit_static_property_fetch_expression(&mut self, node: &StaticPropertyFetchExpression) {
+        walk_static_property_fetch_expression(self, node);
+
+        self.check_class_expression(&node.target);
+
+        if let Variable::SimpleVariable(property) = &node.property {
+            let class = self.resolve_class_expression(&node.target);
+            self.check_property(class, property.stripped.as_ref(), property.span);
+        }
+    }
+}
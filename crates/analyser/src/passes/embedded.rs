@@ -0,0 +1,165 @@
+use pxp_ast::{
+    literals::{decode_escapes_with_offsets, LiteralStringValue},
+    visitor::{
+        walk_braced_namespace, walk_eval_expression, walk_function_call_expression,
+        walk_unbraced_namespace, Visitor,
+    },
+    *,
+};
+use pxp_bytestring::ByteString;
+use pxp_diagnostics::{Diagnostic, DiagnosticKind};
+use pxp_parser::Parser;
+use pxp_span::Span;
+use pxp_token::TokenKind;
+use pxp_type::Type;
+
+use crate::{AnalyserContext, AnalyserDiagnostic};
+
+use super::{global_function_name, Pass};
+
+/// Parses the code that's passed to `eval()` and `create_function()` as a string and reports the
+/// syntax errors in it.
+///
+/// The code has to be inferred as a literal string. When it's written as a string literal, the
+/// errors point at the code inside the literal, with escape sequences accounted for. Otherwise,
+/// e.g. when the code is built by concatenating literals, they point at the whole argument.
+///
+/// Whether `eval()` should be used at all is up to the [`DisallowEval`](crate::DisallowEval) rule.
+#[derive(Debug, Default)]
+pub struct EmbeddedCodePass;
+
+impl EmbeddedCodePass {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Pass for EmbeddedCodePass {
+    fn codes(&self) -> &'static [&'static str] {
+        &["A041"]
+    }
+
+    fn run(
+        &mut self,
+        context: &AnalyserContext,
+        ast: &[Statement],
+    ) -> Vec<Diagnostic<AnalyserDiagnostic>> {
+        let mut visitor = EmbeddedCodeVisitor {
+            context,
+            namespace: None,
+            diagnostics: Vec::new(),
+        };
+
+        visitor.visit(ast);
+        visitor.diagnostics
+    }
+}
+
+struct EmbeddedCodeVisitor<'a> {
+    context: &'a AnalyserContext<'a>,
+    namespace: Option<ByteString>,
+    diagnostics: Vec<Diagnostic<AnalyserDiagnostic>>,
+}
+
+impl<'a> EmbeddedCodeVisitor<'a> {
+    fn check(&mut self, function: &[u8], code: &Expression) {
+        let Type::LiteralString(value) = self.context.types().resolve(code.id) else {
+            return;
+        };
+
+        let (result, offsets) = match string_literal(code) {
+            Some((literal, start)) => {
+                let (value, offsets) = decode_escapes_with_offsets(literal.raw(), literal.quote());
+
+                (
+                    Parser::parse_embedded(&value, start),
+                    Some((start, offsets)),
+                )
+            }
+            None => (Parser::parse_embedded(value, 0), None),
+        };
+
+        for diagnostic in result.diagnostics {
+            let span = match &offsets {
+                // The spans are in the decoded value, which is shorter than the literal when it
+                // contains escape sequences.
+                Some((start, offsets)) => {
+                    let map = |offset: usize| {
+                        let decoded = offset.saturating_sub(*start).min(offsets.len() - 1);
+
+                        start + offsets[decoded]
+                    };
+
+                    Span::new(map(diagnostic.span.start), map(diagnostic.span.end))
+                }
+                None => code.span,
+            };
+
+            self.diagnostics.push(Diagnostic::new(
+                AnalyserDiagnostic::EmbeddedCodeError {
+                    function: ByteString::from(function),
+                    message: ByteString::from(diagnostic.kind.get_message()),
+                },
+                diagnostic.severity,
+                span,
+            ));
+        }
+    }
+}
+
+/// The value of a string literal, along with the offset that its content starts at.
+fn string_literal(expression: &Expression) -> Option<(LiteralStringValue<'_>, usize)> {
+    let ExpressionKind::Literal(literal) = &expression.kind else {
+        return None;
+    };
+
+    let value = literal.string_value()?;
+    let token = &literal.token;
+
+    let start = match token.kind {
+        // The content starts after the opening quote, which can follow a `b` prefix.
+        TokenKind::LiteralSingleQuotedString => {
+            token.span.start + token.symbol.iter().position(|byte| *byte == b'\'')? + 1
+        }
+        TokenKind::LiteralDoubleQuotedString => token.span.start,
+        _ => return None,
+    };
+
+    Some((value, start))
+}
+
+impl<'a> Visitor for EmbeddedCodeVisitor<'a> {
+    fn visit_unbraced_namespace(&mut self, node: &UnbracedNamespace) {
+        self.namespace = Some(node.name.symbol.clone());
+        walk_unbraced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_braced_namespace(&mut self, node: &BracedNamespace) {
+        self.namespace = node.name.as_ref().map(|name| name.symbol.clone());
+        walk_braced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_eval_expression(&mut self, node: &EvalExpression) {
+        if let Some(Argument::Positional(argument)) = &node.argument.argument {
+            self.check(b"eval", &argument.value);
+        }
+
+        walk_eval_expression(self, node);
+    }
+
+    fn visit_function_call_expression(&mut self, node: &FunctionCallExpression) {
+        let function = global_function_name(self.context, self.namespace.as_ref(), &node.target);
+
+        // The code is the body of the function that's created, e.g.
+        // `create_function('$a', 'return $a * 2;')`.
+        if function.is_some_and(|function| &function[..] == b"create_function") {
+            if let Some(Argument::Positional(argument)) = node.arguments.arguments.get(1) {
+                self.check(b"create_function", &argument.value);
+            }
+        }
+
+        walk_function_call_expression(self, node);
+    }
+}
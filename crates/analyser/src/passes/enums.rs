@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+
+use pxp_ast::{
+    visitor::{walk_backed_enum_statement, walk_unit_enum_statement, Visitor},
+    *,
+};
+use pxp_bytestring::ByteString;
+use pxp_diagnostics::{Diagnostic, Severity};
+
+use crate::{AnalyserContext, AnalyserDiagnostic};
+
+use super::Pass;
+
+/// Checks the enum rules that the parser can't, because they depend on other declarations.
+///
+/// Enums can't have properties, so using a trait that declares them (directly or through
+/// another trait) is a fatal error.
+#[derive(Debug, Default)]
+pub struct EnumPass;
+
+impl EnumPass {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Pass for EnumPass {
+    fn codes(&self) -> &'static [&'static str] {
+        &["A010"]
+    }
+
+    fn run(
+        &mut self,
+        context: &AnalyserContext,
+        ast: &[Statement],
+    ) -> Vec<Diagnostic<AnalyserDiagnostic>> {
+        let mut visitor = EnumVisitor {
+            context,
+            diagnostics: Vec::new(),
+        };
+
+        visitor.visit(ast);
+        visitor.diagnostics
+    }
+}
+
+struct EnumVisitor<'a> {
+    context: &'a AnalyserContext<'a>,
+    diagnostics: Vec<Diagnostic<AnalyserDiagnostic>>,
+}
+
+impl<'a> EnumVisitor<'a> {
+    fn check<'b>(&mut self, name: &Name, members: impl Iterator<Item = &'b ClassishMember>) {
+        for member in members {
+            let ClassishMember::TraitUsage(usage) = member else {
+                continue;
+            };
+
+            for r#trait in usage.traits.iter() {
+                let Some(resolved) = r#trait.as_resolved() else {
+                    continue;
+                };
+
+                if !self.has_properties(resolved.resolved.clone()) {
+                    continue;
+                }
+
+                self.diagnostics.push(Diagnostic::new(
+                    AnalyserDiagnostic::EnumUsesTraitWithProperties {
+                        r#enum: name.to_resolved().resolved.clone(),
+                        r#trait: resolved.resolved.clone(),
+                    },
+                    Severity::Error,
+                    r#trait.span,
+                ));
+            }
+        }
+    }
+
+    fn has_properties(&self, r#trait: ByteString) -> bool {
+        let index = self.context.index();
+        let mut pending = vec![r#trait];
+        let mut visited = HashSet::new();
+
+        while let Some(name) = pending.pop() {
+            if !visited.insert(name.clone()) {
+                continue;
+            }
+
+            let Some(r#trait) = index.get_class(name) else {
+                continue;
+            };
+
+            if r#trait.has_properties() {
+                return true;
+            }
+
+            pending.extend(r#trait.get_trait_names().map(|name| name.to_bytestring()));
+        }
+
+        false
+    }
+}
+
+impl<'a> Visitor for EnumVisitor<'a> {
+    fn visit_unit_enum_statement(&mut self, node: &UnitEnumStatement) {
+        self.check(
+            &node.name,
+            node.body.members.iter().filter_map(|member| match member {
+                UnitEnumMember::Classish(member) => Some(member),
+                _ => None,
+            }),
+        );
+
+        walk_unit_enum_statement(self, node);
+    }
+
+    fn visit_backed_enum_statement(&mut self, node: &BackedEnumStatement) {
+        self.check(
+            &node.name,
+            node.body.members.iter().filter_map(|member| match member {
+                BackedEnumMember::Classish(member) => Some(member),
+                _ => None,
+            }),
+        );
+
+        walk_backed_enum_statement(self, node);
+    }
+}
@@ -0,0 +1,134 @@
+use pxp_ast::{
+    visitor::{walk_braced_namespace, walk_switch_statement, walk_unbraced_namespace, Visitor},
+    *,
+};
+use pxp_bytestring::ByteString;
+use pxp_diagnostics::{Diagnostic, Severity};
+
+use crate::{AnalyserContext, AnalyserDiagnostic};
+
+use super::{control_flow::ControlFlow, Pass};
+
+/// Comments that mark a fallthrough as intentional, compared case-insensitively.
+const FALLTHROUGH_MARKERS: [&[u8]; 3] = [b"no break", b"fallthrough", b"fall through"];
+
+/// Reports `case` bodies that implicitly fall through into the next case.
+///
+/// A fallthrough can be marked as intentional with a `// no break` or `// fallthrough` comment
+/// before the next case, or at the end of the last statement in the body. A body doesn't fall
+/// through when every path through it ends, e.g. with a `break`, or a `return` in every branch of
+/// an `if`.
+///
+/// The pass isn't part of [`passes()`](super::passes), so it has to be added by hand.
+#[derive(Debug, Default)]
+pub struct FallthroughPass;
+
+impl FallthroughPass {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Pass for FallthroughPass {
+    fn codes(&self) -> &'static [&'static str] {
+        &["A009"]
+    }
+
+    fn run(
+        &mut self,
+        context: &AnalyserContext,
+        ast: &[Statement],
+    ) -> Vec<Diagnostic<AnalyserDiagnostic>> {
+        let mut visitor = FallthroughVisitor {
+            context,
+            namespace: None,
+            diagnostics: Vec::new(),
+        };
+
+        visitor.visit(ast);
+        visitor.diagnostics
+    }
+}
+
+struct FallthroughVisitor<'a> {
+    context: &'a AnalyserContext<'a>,
+    // The namespace that we're currently inside of, used to resolve unqualified function calls.
+    namespace: Option<ByteString>,
+    diagnostics: Vec<Diagnostic<AnalyserDiagnostic>>,
+}
+
+impl<'a> Visitor for FallthroughVisitor<'a> {
+    fn visit_unbraced_namespace(&mut self, node: &UnbracedNamespace) {
+        self.namespace = Some(node.name.symbol.clone());
+        walk_unbraced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_braced_namespace(&mut self, node: &BracedNamespace) {
+        self.namespace = node.name.as_ref().map(|name| name.symbol.clone());
+        walk_braced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_switch_statement(&mut self, node: &SwitchStatement) {
+        let flow = ControlFlow::new(self.context, self.namespace.as_ref());
+
+        for pair in node.cases.windows(2) {
+            let [case, next] = pair else {
+                continue;
+            };
+
+            // Empty cases are the usual way of grouping multiple cases together.
+            let Some(last) = last_statement(&case.body) else {
+                continue;
+            };
+
+            if !flow.exits(&case.body).completes
+                || is_marked(&next.comments)
+                || is_marked(&last.trailing_comments)
+            {
+                continue;
+            }
+
+            self.diagnostics.push(Diagnostic::new(
+                AnalyserDiagnostic::ImplicitFallthrough,
+                Severity::Warning,
+                case.span,
+            ));
+        }
+
+        walk_switch_statement(self, node);
+    }
+}
+
+/// Find the last statement in the given body, ignoring tags, whitespace and comments.
+fn last_statement(body: &[Statement]) -> Option<&Statement> {
+    body.iter().rev().find(|statement| match &statement.kind {
+        StatementKind::FullOpeningTag(_)
+        | StatementKind::ShortOpeningTag(_)
+        | StatementKind::ClosingTag(_)
+        | StatementKind::Comment(_)
+        | StatementKind::Noop(_) => false,
+        StatementKind::InlineHtml(html) => !html.html.symbol.iter().all(u8::is_ascii_whitespace),
+        _ => true,
+    })
+}
+
+fn is_marked(comments: &CommentGroup) -> bool {
+    comments.iter().any(|comment| {
+        let content = match &comment.kind {
+            CommentKind::SingleLine(SingleLineComment { content, .. })
+            | CommentKind::MultiLine(MultiLineComment { content, .. })
+            | CommentKind::HashMark(HashMarkComment { content, .. }) => content,
+            CommentKind::DocBlock(_) => return false,
+        };
+
+        let content = content.to_ascii_lowercase();
+
+        FALLTHROUGH_MARKERS.iter().any(|marker| {
+            content
+                .windows(marker.len())
+                .any(|window| window == *marker)
+        })
+    })
+}
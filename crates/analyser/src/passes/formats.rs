@@ -0,0 +1,303 @@
+use pxp_ast::{
+    visitor::{
+        walk_braced_namespace, walk_function_call_expression, walk_unbraced_namespace, Visitor,
+    },
+    *,
+};
+use pxp_bytestring::ByteString;
+use pxp_diagnostics::{Diagnostic, Severity};
+use pxp_span::Span;
+use pxp_type::Type;
+
+use crate::{parse_format_string, AnalyserContext, AnalyserDiagnostic, FormatKind};
+
+use super::{global_function_name, Pass};
+
+/// The functions that take a format string, along with the position of the format string and
+/// whether the values are passed as a single array.
+const FORMAT_FUNCTIONS: [(&[u8], usize, bool); 6] = [
+    (b"sprintf", 0, false),
+    (b"printf", 0, false),
+    (b"fprintf", 1, false),
+    (b"vsprintf", 0, true),
+    (b"vprintf", 0, true),
+    (b"vfprintf", 1, true),
+];
+
+/// Checks the format strings passed to `sprintf()` and friends against the values that are
+/// passed along with them.
+///
+/// The format string has to be inferred as a literal string, which includes concatenations of
+/// literals. The values of the `v*printf()` functions are only checked when they're passed as an
+/// array literal. Calls that use named arguments or argument unpacking are skipped.
+///
+/// A value is only reported when none of its possible types can be formatted sensibly: arrays
+/// can't be formatted at all, and a numeric conversion like `%d` can't be given a literal string
+/// that isn't numeric.
+#[derive(Debug, Default)]
+pub struct FormatStringPass;
+
+impl FormatStringPass {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Pass for FormatStringPass {
+    fn codes(&self) -> &'static [&'static str] {
+        &["A029", "A030", "A031", "A032"]
+    }
+
+    fn run(
+        &mut self,
+        context: &AnalyserContext,
+        ast: &[Statement],
+    ) -> Vec<Diagnostic<AnalyserDiagnostic>> {
+        let mut visitor = FormatStringVisitor {
+            context,
+            namespace: None,
+            diagnostics: Vec::new(),
+        };
+
+        visitor.visit(ast);
+        visitor.diagnostics
+    }
+}
+
+struct FormatStringVisitor<'a> {
+    context: &'a AnalyserContext<'a>,
+    namespace: Option<ByteString>,
+    diagnostics: Vec<Diagnostic<AnalyserDiagnostic>>,
+}
+
+impl<'a> FormatStringVisitor<'a> {
+    fn check(&mut self, call: &FunctionCallExpression) {
+        let Some(function) =
+            global_function_name(self.context, self.namespace.as_ref(), &call.target)
+        else {
+            return;
+        };
+
+        let Some((_, format, array)) = FORMAT_FUNCTIONS
+            .iter()
+            .find(|(name, ..)| &function[..] == *name)
+        else {
+            return;
+        };
+
+        let mut arguments = Vec::new();
+
+        for argument in call.arguments.arguments.iter() {
+            match argument {
+                Argument::Positional(PositionalArgument {
+                    ellipsis: None,
+                    value,
+                    ..
+                }) => arguments.push(value),
+                _ => return,
+            }
+        }
+
+        let format = *format;
+
+        let Some(format_string) = arguments.get(format) else {
+            return;
+        };
+
+        let Type::LiteralString(literal) = self.context.types().resolve(format_string.id) else {
+            return;
+        };
+
+        let values = if *array {
+            let Some(ExpressionKind::Array(array)) = arguments.get(format + 1).map(|a| &a.kind)
+            else {
+                return;
+            };
+
+            match array_values(array) {
+                Some(values) => values,
+                None => return,
+            }
+        } else {
+            arguments[format + 1..].to_vec()
+        };
+
+        let specifiers = match parse_format_string(literal) {
+            Ok(specifiers) => specifiers,
+            Err(error) => {
+                self.report(
+                    AnalyserDiagnostic::InvalidFormatString {
+                        function,
+                        message: ByteString::from(error.to_string()),
+                    },
+                    Severity::Error,
+                    format_string.span,
+                );
+
+                return;
+            }
+        };
+
+        let required = specifiers
+            .iter()
+            .map(|specifier| specifier.position + 1)
+            .max()
+            .unwrap_or(0);
+
+        if values.len() < required {
+            self.report(
+                AnalyserDiagnostic::TooFewFormatArguments {
+                    function: function.clone(),
+                    given: values.len(),
+                    required,
+                },
+                Severity::Error,
+                call.arguments.span,
+            );
+        } else if values.len() > required {
+            self.report(
+                AnalyserDiagnostic::TooManyFormatArguments {
+                    function: function.clone(),
+                    given: values.len(),
+                    used: required,
+                },
+                Severity::Warning,
+                values[required].span,
+            );
+        }
+
+        for specifier in specifiers.iter() {
+            let Some(value) = values.get(specifier.position) else {
+                continue;
+            };
+
+            let r#type = self.context.types().resolve(value.id);
+
+            if !accepts(specifier.kind, r#type) {
+                self.report(
+                    AnalyserDiagnostic::FormatArgumentTypeMismatch {
+                        function: function.clone(),
+                        specifier: specifier.specifier.clone(),
+                        r#type: describe(r#type),
+                    },
+                    Severity::Warning,
+                    value.span,
+                );
+            }
+        }
+    }
+
+    fn report(&mut self, kind: AnalyserDiagnostic, severity: Severity, span: Span) {
+        self.diagnostics.push(Diagnostic::new(kind, severity, span));
+    }
+}
+
+/// The values of an array literal in order, unless it's unpacking another array.
+fn array_values(array: &ArrayExpression) -> Option<Vec<&Expression>> {
+    array
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            ArrayItem::Value(item) => Some(Some(&item.value)),
+            ArrayItem::ReferencedValue(item) => Some(Some(&item.value)),
+            ArrayItem::KeyValue(item) => Some(Some(&item.value)),
+            ArrayItem::ReferencedKeyValue(item) => Some(Some(&item.value)),
+            ArrayItem::SpreadValue(_) => Some(None),
+            ArrayItem::Skipped(_) => None,
+        })
+        .collect()
+}
+
+/// Whether a value of the given type could be formatted by a conversion of the given kind.
+fn accepts(kind: FormatKind, r#type: &Type<ResolvedName>) -> bool {
+    match r#type {
+        Type::Union(types) => types.iter().any(|r#type| accepts(kind, r#type)),
+        Type::Array
+        | Type::NonEmptyArray
+        | Type::List
+        | Type::NonEmptyList
+        | Type::TypedArray(..)
+        | Type::Shaped { .. } => false,
+        Type::Generic(base, _) => accepts(kind, base),
+        Type::LiteralString(value) => kind == FormatKind::String || is_numeric(value),
+        _ => true,
+    }
+}
+
+/// Whether a string is numeric, following the same rules as `is_numeric()`.
+fn is_numeric(value: &[u8]) -> bool {
+    let start = value
+        .iter()
+        .position(|byte| !byte.is_ascii_whitespace())
+        .unwrap_or(value.len());
+    let end = value
+        .iter()
+        .rposition(|byte| !byte.is_ascii_whitespace())
+        .map_or(start, |end| end + 1);
+    let value = &value[start..end];
+    let value = value
+        .strip_prefix(b"+")
+        .or_else(|| value.strip_prefix(b"-"))
+        .unwrap_or(value);
+
+    let (mantissa, exponent) = match value.iter().position(|byte| matches!(byte, b'e' | b'E')) {
+        Some(position) => (&value[..position], Some(&value[position + 1..])),
+        None => (value, None),
+    };
+
+    let (whole, fraction) = match mantissa.iter().position(|byte| *byte == b'.') {
+        Some(position) => (&mantissa[..position], &mantissa[position + 1..]),
+        None => (mantissa, &b""[..]),
+    };
+
+    let digits = |part: &[u8]| part.iter().all(u8::is_ascii_digit);
+
+    if whole.is_empty() && fraction.is_empty() || !digits(whole) || !digits(fraction) {
+        return false;
+    }
+
+    match exponent {
+        Some(exponent) => {
+            let exponent = exponent
+                .strip_prefix(b"+")
+                .or_else(|| exponent.strip_prefix(b"-"))
+                .unwrap_or(exponent);
+
+            !exponent.is_empty() && digits(exponent)
+        }
+        None => true,
+    }
+}
+
+/// Describe the type of a value in a diagnostic, showing the value of literal strings.
+fn describe(r#type: &Type<ResolvedName>) -> ByteString {
+    match r#type {
+        Type::LiteralString(value) => {
+            let mut described = ByteString::from("'");
+            described.extend(value);
+            described.extend_with_bytes(b"'");
+            described
+        }
+        r#type => ByteString::from(r#type.to_string()),
+    }
+}
+
+impl<'a> Visitor for FormatStringVisitor<'a> {
+    fn visit_unbraced_namespace(&mut self, node: &UnbracedNamespace) {
+        self.namespace = Some(node.name.symbol.clone());
+        walk_unbraced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_braced_namespace(&mut self, node: &BracedNamespace) {
+        self.namespace = node.name.as_ref().map(|name| name.symbol.clone());
+        walk_braced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_function_call_expression(&mut self, node: &FunctionCallExpression) {
+        self.check(node);
+
+        walk_function_call_expression(self, node);
+    }
+}
@@ -0,0 +1,56 @@
+use pxp_ast::{literals::IntegerBase, visitor::Visitor, *};
+use pxp_diagnostics::{Diagnostic, Severity};
+
+use crate::{AnalyserContext, AnalyserDiagnostic};
+
+use super::Pass;
+
+/// Checks numeric literals that the lexer accepts, but PHP refuses to compile.
+///
+/// Legacy octal literals like `0755` are lexed the same way as decimal integers, so a literal
+/// like `089` isn't an error until PHP tries to read its value.
+#[derive(Debug, Default)]
+pub struct LiteralPass;
+
+impl LiteralPass {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Pass for LiteralPass {
+    fn codes(&self) -> &'static [&'static str] {
+        &["A018"]
+    }
+
+    fn run(
+        &mut self,
+        _: &AnalyserContext,
+        ast: &[Statement],
+    ) -> Vec<Diagnostic<AnalyserDiagnostic>> {
+        let mut visitor = LiteralVisitor {
+            diagnostics: Vec::new(),
+        };
+
+        visitor.visit(ast);
+        visitor.diagnostics
+    }
+}
+
+struct LiteralVisitor {
+    diagnostics: Vec<Diagnostic<AnalyserDiagnostic>>,
+}
+
+impl Visitor for LiteralVisitor {
+    fn visit_literal(&mut self, node: &Literal) {
+        if node.integer_base() == Some(IntegerBase::Octal) && node.integer_value().is_none() {
+            self.diagnostics.push(Diagnostic::new(
+                AnalyserDiagnostic::InvalidOctalLiteral {
+                    literal: node.token.symbol.clone(),
+                },
+                Severity::Error,
+                node.span,
+            ));
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use pxp_ast::{
+    visitor::{
+        walk_anonymous_class_expression, walk_arrow_function_expression,
+        walk_backed_enum_statement, walk_class_statement, walk_closure_expression,
+        walk_function_statement, walk_interface_statement, walk_method, walk_property_hook,
+        walk_trait_statement, walk_unit_enum_statement, Visitor,
+    },
+    *,
+};
+use pxp_bytestring::ByteString;
+use pxp_diagnostics::{Diagnostic, Severity};
+
+use crate::{AnalyserContext, AnalyserDiagnostic};
+
+use super::Pass;
+
+/// Checks that magic constants are used inside of the declaration that they describe.
+///
+/// `__CLASS__`, `__TRAIT__`, `__FUNCTION__`, `__METHOD__` and `__PROPERTY__` are all empty strings
+/// when they're used outside of a class, trait, function or property hook respectively. A
+/// `__METHOD__` inside of a function or closure is reported too, since it's no different to
+/// `__FUNCTION__` there.
+#[derive(Debug, Default)]
+pub struct MagicConstantPass;
+
+impl MagicConstantPass {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Pass for MagicConstantPass {
+    fn codes(&self) -> &'static [&'static str] {
+        &["A033", "A034"]
+    }
+
+    fn run(
+        &mut self,
+        _: &AnalyserContext,
+        ast: &[Statement],
+    ) -> Vec<Diagnostic<AnalyserDiagnostic>> {
+        let mut visitor = MagicConstantVisitor {
+            declarations: Vec::new(),
+            diagnostics: Vec::new(),
+        };
+
+        visitor.visit(ast);
+        visitor.diagnostics
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Declaration {
+    /// A class, interface or enum.
+    Class,
+    Trait,
+    Function,
+    Method,
+    /// A closure or arrow function.
+    Closure,
+    PropertyHook,
+}
+
+struct MagicConstantVisitor {
+    declarations: Vec<Declaration>,
+    diagnostics: Vec<Diagnostic<AnalyserDiagnostic>>,
+}
+
+impl MagicConstantVisitor {
+    fn within(&mut self, declaration: Declaration, f: impl FnOnce(&mut Self)) {
+        self.declarations.push(declaration);
+        f(self);
+        self.declarations.pop();
+    }
+
+    fn classlike(&self) -> Option<Declaration> {
+        self.declarations
+            .iter()
+            .rev()
+            .find(|declaration| matches!(declaration, Declaration::Class | Declaration::Trait))
+            .copied()
+    }
+
+    fn function(&self) -> Option<Declaration> {
+        self.declarations
+            .iter()
+            .rev()
+            .find(|declaration| !matches!(declaration, Declaration::Class | Declaration::Trait))
+            .copied()
+    }
+
+    fn empty(&mut self, constant: &str, context: &str, node: &MagicConstantExpression) {
+        self.diagnostics.push(Diagnostic::new(
+            AnalyserDiagnostic::EmptyMagicConstant {
+                constant: ByteString::from(constant),
+                context: ByteString::from(context),
+            },
+            Severity::Warning,
+            node.span,
+        ));
+    }
+}
+
+impl Visitor for MagicConstantVisitor {
+    fn visit_class_statement(&mut self, node: &ClassStatement) {
+        self.within(Declaration::Class, |visitor| {
+            walk_class_statement(visitor, node)
+        });
+    }
+
+    fn visit_anonymous_class_expression(&mut self, node: &AnonymousClassExpression) {
+        self.within(Declaration::Class, |visitor| {
+            walk_anonymous_class_expression(visitor, node)
+        });
+    }
+
+    fn visit_interface_statement(&mut self, node: &InterfaceStatement) {
+        self.within(Declaration::Class, |visitor| {
+            walk_interface_statement(visitor, node)
+        });
+    }
+
+    fn visit_unit_enum_statement(&mut self, node: &UnitEnumStatement) {
+        self.within(Declaration::Class, |visitor| {
+            walk_unit_enum_statement(visitor, node)
+        });
+    }
+
+    fn visit_backed_enum_statement(&mut self, node: &BackedEnumStatement) {
+        self.within(Declaration::Class, |visitor| {
+            walk_backed_enum_statement(visitor, node)
+        });
+    }
+
+    fn visit_trait_statement(&mut self, node: &TraitStatement) {
+        self.within(Declaration::Trait, |visitor| {
+            walk_trait_statement(visitor, node)
+        });
+    }
+
+    fn visit_function_statement(&mut self, node: &FunctionStatement) {
+        self.within(Declaration::Function, |visitor| {
+            walk_function_statement(visitor, node)
+        });
+    }
+
+    fn visit_method(&mut self, node: &Method) {
+        self.within(Declaration::Method, |visitor| walk_method(visitor, node));
+    }
+
+    fn visit_closure_expression(&mut self, node: &ClosureExpression) {
+        self.within(Declaration::Closure, |visitor| {
+            walk_closure_expression(visitor, node)
+        });
+    }
+
+    fn visit_arrow_function_expression(&mut self, node: &ArrowFunctionExpression) {
+        self.within(Declaration::Closure, |visitor| {
+            walk_arrow_function_expression(visitor, node)
+        });
+    }
+
+    fn visit_property_hook(&mut self, node: &PropertyHook) {
+        self.within(Declaration::PropertyHook, |visitor| {
+            walk_property_hook(visitor, node)
+        });
+    }
+
+    fn visit_magic_constant_expression(&mut self, node: &MagicConstantExpression) {
+        match node.kind {
+            MagicConstantKind::Class if self.classlike().is_none() => {
+                self.empty("__CLASS__", "class", node)
+            }
+            MagicConstantKind::Trait if self.classlike() != Some(Declaration::Trait) => {
+                self.empty("__TRAIT__", "trait", node)
+            }
+            MagicConstantKind::Function if self.function().is_none() => {
+                self.empty("__FUNCTION__", "function", node)
+            }
+            MagicConstantKind::Method => match self.function() {
+                None => self.empty("__METHOD__", "function", node),
+                Some(Declaration::Function | Declaration::Closure) => {
+                    self.diagnostics.push(Diagnostic::new(
+                        AnalyserDiagnostic::MethodConstantOutsideMethod,
+                        Severity::Warning,
+                        node.span,
+                    ))
+                }
+                _ => {}
+            },
+            MagicConstantKind::Property
+                if !self.declarations.contains(&Declaration::PropertyHook) =>
+            {
+                self.empty("__PROPERTY__", "property hook", node)
+            }
+            _ => {}
+        }
+    }
+}
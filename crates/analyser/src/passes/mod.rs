@@ -0,0 +1,146 @@
+use pxp_ast::{name::NameQualification, Expression, ExpressionKind, Name, NameKind, Statement};
+use pxp_bytestring::ByteString;
+use pxp_diagnostics::Diagnostic;
+use pxp_index::{Index, ReflectionFunction};
+
+use crate::{AnalyserContext, AnalyserDiagnostic};
+
+mod arguments;
+mod arrays;
+mod compatibility;
+mod conditions;
+mod control_flow;
+mod deprecations;
+mod embedded;
+mod enums;
+mod fallthrough;
+mod formats;
+mod literals;
+mod magic;
+mod output;
+mod overrides;
+mod returns;
+mod traits;
+mod variables;
+
+pub use arguments::ArgumentsPass;
+pub use arrays::ArrayKeyPass;
+pub use compatibility::TypeCompatibilityPass;
+pub use conditions::ConditionPass;
+pub use deprecations::DeprecationPass;
+pub use embedded::EmbeddedCodePass;
+pub use enums::EnumPass;
+pub use fallthrough::FallthroughPass;
+pub use formats::FormatStringPass;
+pub use literals::LiteralPass;
+pub use magic::MagicConstantPass;
+pub use output::OutputPass;
+pub use overrides::OverridePass;
+pub use returns::ReturnPass;
+pub use traits::TraitPass;
+pub use variables::UndefinedVariablePass;
+
+/// A single analysis pass over an AST.
+pub trait Pass {
+    /// The codes of every diagnostic that the pass can report, which lets an
+    /// [`AnalysisProfile`](crate::AnalysisProfile) skip passes that can't report anything useful.
+    fn codes(&self) -> &'static [&'static str];
+
+    /// Analyse the given AST and return any diagnostics that were found.
+    fn run(
+        &mut self,
+        context: &AnalyserContext,
+        ast: &[Statement],
+    ) -> Vec<Diagnostic<AnalyserDiagnostic>>;
+}
+
+/// Every built-in pass that's enabled by default, in the order that they should be run.
+///
+/// [`FallthroughPass`] is opt-in, since fallthrough is often intentional.
+pub fn passes() -> Vec<Box<dyn Pass>> {
+    vec![
+        Box::new(ArgumentsPass::new()),
+        Box::new(ArrayKeyPass::new()),
+        Box::new(ConditionPass::new()),
+        Box::new(DeprecationPass::new()),
+        Box::new(EmbeddedCodePass::new()),
+        Box::new(EnumPass::new()),
+        Box::new(FormatStringPass::new()),
+        Box::new(LiteralPass::new()),
+        Box::new(MagicConstantPass::new()),
+        Box::new(OutputPass::new()),
+        Box::new(OverridePass::new()),
+        Box::new(ReturnPass::new()),
+        Box::new(TraitPass::new()),
+        Box::new(TypeCompatibilityPass::new()),
+        Box::new(UndefinedVariablePass::new()),
+    ]
+}
+
+/// Find the function that the given name refers to.
+///
+/// Unqualified names that weren't imported can only be resolved at runtime, so they prefer a
+/// function in the current namespace and fall back to the global one. Qualified and fully
+/// qualified names never fall back.
+pub(crate) fn resolve_function<'a>(
+    index: &'a Index,
+    namespace: Option<&ByteString>,
+    name: &Name,
+) -> Option<ReflectionFunction<'a>> {
+    match &name.kind {
+        NameKind::Resolved(name) => index.get_function(name.resolved.clone()),
+        NameKind::Unresolved(name) if name.qualification == NameQualification::Unqualified => {
+            namespace
+                .and_then(|namespace| {
+                    let mut qualified = namespace.clone();
+                    qualified.extend_with_bytes(b"\\");
+                    qualified.extend(&name.symbol);
+
+                    index.get_function(qualified)
+                })
+                .or_else(|| index.get_function(name.symbol.clone()))
+        }
+        NameKind::Unresolved(name) => index.get_function(name.symbol.clone()),
+        NameKind::Special(_) => None,
+    }
+}
+
+/// Get the lowercased name of a function in the global namespace that is being called, unless a
+/// function with the same name has been declared in the current namespace.
+fn global_function_name(
+    context: &AnalyserContext,
+    namespace: Option<&ByteString>,
+    target: &Expression,
+) -> Option<ByteString> {
+    let ExpressionKind::Name(name) = &target.kind else {
+        return None;
+    };
+
+    let name = match &name.kind {
+        NameKind::Resolved(name) => &name.resolved,
+        NameKind::Unresolved(name) => {
+            // Unqualified calls only fall back to the global function when there isn't one in
+            // the current namespace.
+            if let Some(namespace) = namespace {
+                let mut qualified = namespace.clone();
+                qualified.extend_with_bytes(b"\\");
+                qualified.extend(&name.symbol);
+
+                if context.index().get_function(qualified).is_some() {
+                    return None;
+                }
+            }
+
+            &name.symbol
+        }
+        NameKind::Special(_) => return None,
+    };
+
+    let name = name.strip_prefix(b"\\").unwrap_or(name);
+
+    if name.contains(&b'\\') {
+        return None;
+    }
+
+    Some(ByteString::from(name.to_ascii_lowercase()))
+}
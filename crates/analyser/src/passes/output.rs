@@ -0,0 +1,335 @@
+use std::collections::HashMap;
+
+use pxp_ast::{
+    visitor::{
+        walk_arrow_function_expression, walk_assignment_operation_expression,
+        walk_closure_expression, walk_echo_statement, walk_function_statement, walk_method,
+        walk_print_expression, walk_statement, Visitor,
+    },
+    *,
+};
+use pxp_bytestring::ByteString;
+use pxp_diagnostics::{Diagnostic, Severity};
+use pxp_type::Type;
+
+use crate::{AnalyserContext, AnalyserDiagnostic};
+
+use super::Pass;
+
+/// Reports user input that's written to the output without being escaped first, which is the
+/// usual way for templates to end up with cross-site scripting vulnerabilities.
+///
+/// Output is written by `echo`, `print` and `<?=` tags. Input comes from a configurable set of
+/// superglobals (`$_GET`, `$_POST`, `$_REQUEST` and `$_COOKIE` by default), and is considered
+/// safe once it has been passed through one of a configurable set of sanitizers
+/// (`htmlspecialchars()` and `htmlentities()` by default), cast to `int`, `float` or `bool`, or
+/// inferred as any other type that can't contain markup.
+///
+/// This is a bounded check rather than a full taint analysis:
+///
+/// * Input is only tracked through assignments to variables, concatenation, interpolation, the
+///   ternary and null coalescing operators, and function calls. It isn't tracked through method
+///   calls, properties, `foreach` loops or references.
+/// * Any function other than a sanitizer is assumed to return something derived from its
+///   arguments, unless its return type is known to be scalar.
+/// * Assignments are applied in source order, without regard to branches, so sanitizing a variable
+///   inside of an `if` statement is trusted on every path.
+/// * Each function, method and closure is checked on its own, without knowing about its callers.
+#[derive(Debug)]
+pub struct OutputPass {
+    sources: Vec<ByteString>,
+    sanitizers: Vec<ByteString>,
+}
+
+impl OutputPass {
+    pub fn new() -> Self {
+        Self {
+            sources: vec![
+                b"$_GET".into(),
+                b"$_POST".into(),
+                b"$_REQUEST".into(),
+                b"$_COOKIE".into(),
+            ],
+            sanitizers: vec![b"htmlspecialchars".into(), b"htmlentities".into()],
+        }
+    }
+
+    /// Treat the given superglobal, e.g. `$_SERVER`, as user input.
+    pub fn with_source(mut self, source: impl Into<ByteString>) -> Self {
+        self.sources.push(source.into());
+        self
+    }
+
+    /// Treat the return value of the given function as safe to output.
+    pub fn with_sanitizer(mut self, sanitizer: impl Into<ByteString>) -> Self {
+        self.sanitizers.push(sanitizer.into());
+        self
+    }
+}
+
+impl Default for OutputPass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Pass for OutputPass {
+    fn codes(&self) -> &'static [&'static str] {
+        &["A023"]
+    }
+
+    fn run(
+        &mut self,
+        context: &AnalyserContext,
+        ast: &[Statement],
+    ) -> Vec<Diagnostic<AnalyserDiagnostic>> {
+        let mut visitor = OutputVisitor {
+            pass: self,
+            context,
+            scopes: vec![HashMap::new()],
+            echo_tag: false,
+            diagnostics: Vec::new(),
+        };
+
+        visitor.visit(ast);
+        visitor.diagnostics
+    }
+}
+
+struct OutputVisitor<'a> {
+    pass: &'a OutputPass,
+    context: &'a AnalyserContext<'a>,
+    // The variables in each scope that hold user input, along with the source of that input.
+    scopes: Vec<HashMap<ByteString, ByteString>>,
+    // Whether the previous statement was a `<?=` tag, which echoes the statement that follows it.
+    echo_tag: bool,
+    diagnostics: Vec<Diagnostic<AnalyserDiagnostic>>,
+}
+
+impl<'a> OutputVisitor<'a> {
+    fn scope(&mut self) -> &mut HashMap<ByteString, ByteString> {
+        self.scopes.last_mut().unwrap()
+    }
+
+    fn within(&mut self, scope: HashMap<ByteString, ByteString>, f: impl FnOnce(&mut Self)) {
+        self.scopes.push(scope);
+        f(self);
+        self.scopes.pop();
+    }
+
+    fn output(&mut self, expression: &Expression, output: &[u8]) {
+        let Some(source) = self.source_of(expression) else {
+            return;
+        };
+
+        self.diagnostics.push(Diagnostic::new(
+            AnalyserDiagnostic::UnescapedOutput {
+                source,
+                output: output.into(),
+            },
+            Severity::Warning,
+            expression.span,
+        ));
+    }
+
+    /// Find the user input that the given expression could contain, if any.
+    fn source_of(&self, expression: &Expression) -> Option<ByteString> {
+        if self.is_safe_type(self.context.types().resolve(expression.id)) {
+            return None;
+        }
+
+        match &expression.kind {
+            ExpressionKind::Variable(variable) => {
+                let Variable::SimpleVariable(variable) = variable.as_ref() else {
+                    return None;
+                };
+
+                if self.pass.sources.contains(&variable.symbol) {
+                    return Some(variable.symbol.clone());
+                }
+
+                self.scopes.last()?.get(&variable.symbol).cloned()
+            }
+            ExpressionKind::ArrayIndex(index) => self.source_of(&index.array),
+            ExpressionKind::Parenthesized(parenthesized) => self.source_of(&parenthesized.expr),
+            ExpressionKind::Concat(concat) => self
+                .source_of(&concat.left)
+                .or_else(|| self.source_of(&concat.right)),
+            ExpressionKind::InterpolatedString(string) => self.source_of_parts(&string.parts),
+            ExpressionKind::Heredoc(heredoc) => self.source_of_parts(&heredoc.parts),
+            ExpressionKind::Ternary(ternary) => self
+                .source_of(&ternary.then)
+                .or_else(|| self.source_of(&ternary.r#else)),
+            ExpressionKind::ShortTernary(ternary) => self
+                .source_of(&ternary.condition)
+                .or_else(|| self.source_of(&ternary.r#else)),
+            ExpressionKind::Coalesce(coalesce) => self
+                .source_of(&coalesce.lhs)
+                .or_else(|| self.source_of(&coalesce.rhs)),
+            ExpressionKind::AssignmentOperation(assignment) => match assignment.kind {
+                AssignmentOperationKind::Assign(_) => self.source_of(&assignment.right),
+                AssignmentOperationKind::Concat(_) | AssignmentOperationKind::Coalesce(_) => self
+                    .source_of(&assignment.left)
+                    .or_else(|| self.source_of(&assignment.right)),
+                _ => None,
+            },
+            // Functions are assumed to return something derived from their arguments, unless
+            // they're a sanitizer or return a type that can't contain markup.
+            ExpressionKind::FunctionCall(call) if !self.is_sanitizer(&call.target) => call
+                .arguments
+                .arguments
+                .iter()
+                .find_map(|argument| self.source_of(argument_value(argument))),
+            ExpressionKind::Cast(cast) => match cast.kind {
+                CastKind::String(_) => self.source_of(&cast.value),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn source_of_parts(&self, parts: &[StringPart]) -> Option<ByteString> {
+        parts.iter().find_map(|part| match part {
+            StringPart::Expression(part) => self.source_of(&part.expression),
+            StringPart::Literal(_) => None,
+        })
+    }
+
+    fn is_safe_type(&self, r#type: &Type<ResolvedName>) -> bool {
+        match r#type {
+            Type::Integer
+            | Type::NonNegativeInteger
+            | Type::Float
+            | Type::Boolean
+            | Type::True
+            | Type::False
+            | Type::Null
+            | Type::Void
+            | Type::Never => true,
+            Type::Nullable(inner) => self.is_safe_type(inner),
+            Type::Union(types) => types.iter().all(|r#type| self.is_safe_type(r#type)),
+            _ => false,
+        }
+    }
+
+    fn is_sanitizer(&self, target: &Expression) -> bool {
+        let ExpressionKind::Name(name) = &target.kind else {
+            return false;
+        };
+
+        let function = name.symbol().as_bytestr().after_last(b'\\');
+
+        self.pass
+            .sanitizers
+            .iter()
+            .any(|sanitizer| function.eq_ignore_ascii_case(sanitizer))
+    }
+}
+
+fn argument_value(argument: &Argument) -> &Expression {
+    match argument {
+        Argument::Positional(argument) => &argument.value,
+        Argument::Named(argument) => &argument.value,
+    }
+}
+
+/// The plain variable that an assignment target writes to, looking through array accesses.
+fn assigned_variable(target: &Expression) -> Option<&SimpleVariable> {
+    match &target.kind {
+        ExpressionKind::Variable(variable) => match variable.as_ref() {
+            Variable::SimpleVariable(variable) => Some(variable),
+            _ => None,
+        },
+        ExpressionKind::ArrayIndex(index) => assigned_variable(&index.array),
+        _ => None,
+    }
+}
+
+impl<'a> Visitor for OutputVisitor<'a> {
+    fn visit_statement(&mut self, node: &Statement) {
+        let echo_tag = std::mem::take(&mut self.echo_tag);
+
+        match &node.kind {
+            StatementKind::EchoOpeningTag(_) => self.echo_tag = true,
+            StatementKind::Expression(statement) if echo_tag => {
+                self.output(&statement.expression, b"<?=")
+            }
+            _ => {}
+        }
+
+        walk_statement(self, node);
+    }
+
+    fn visit_echo_statement(&mut self, node: &EchoStatement) {
+        for value in node.values.iter() {
+            self.output(value, b"echo");
+        }
+
+        walk_echo_statement(self, node);
+    }
+
+    fn visit_print_expression(&mut self, node: &PrintExpression) {
+        self.output(&node.value, b"print");
+
+        walk_print_expression(self, node);
+    }
+
+    fn visit_assignment_operation_expression(&mut self, node: &AssignmentOperationExpression) {
+        walk_assignment_operation_expression(self, node);
+
+        let Some(variable) = assigned_variable(&node.left) else {
+            return;
+        };
+
+        let source = self.source_of(&node.right);
+        let whole = matches!(node.left.kind, ExpressionKind::Variable(_));
+
+        match (source, &node.kind) {
+            (Some(source), _) => {
+                self.scope().insert(variable.symbol.clone(), source);
+            }
+            // Appending to a variable, or writing to one of its elements, keeps whatever it held.
+            (None, AssignmentOperationKind::Concat(_) | AssignmentOperationKind::Coalesce(_)) => {}
+            (None, _) if whole => {
+                self.scope().remove(&variable.symbol);
+            }
+            (None, _) => {}
+        }
+    }
+
+    fn visit_function_statement(&mut self, node: &FunctionStatement) {
+        self.within(HashMap::new(), |visitor| {
+            walk_function_statement(visitor, node)
+        });
+    }
+
+    fn visit_method(&mut self, node: &Method) {
+        self.within(HashMap::new(), |visitor| walk_method(visitor, node));
+    }
+
+    fn visit_closure_expression(&mut self, node: &ClosureExpression) {
+        // Variables captured by value carry their input into the closure.
+        let mut scope = HashMap::new();
+
+        if let Some(uses) = &node.uses {
+            for variable in uses.variables.iter() {
+                let symbol = &variable.variable.symbol;
+
+                if let Some(source) = self.scope().get(symbol) {
+                    scope.insert(symbol.clone(), source.clone());
+                }
+            }
+        }
+
+        self.within(scope, |visitor| walk_closure_expression(visitor, node));
+    }
+
+    fn visit_arrow_function_expression(&mut self, node: &ArrowFunctionExpression) {
+        // Arrow functions capture the entire parent scope by value.
+        let scope = self.scope().clone();
+
+        self.within(scope, |visitor| {
+            walk_arrow_function_expression(visitor, node)
+        });
+    }
+}
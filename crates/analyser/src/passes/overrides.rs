@@ -0,0 +1,163 @@
+use std::collections::HashSet;
+
+use pxp_ast::{
+    visitor::{
+        walk_backed_enum_statement, walk_class_statement, walk_interface_statement,
+        walk_unit_enum_statement, Visitor,
+    },
+    *,
+};
+use pxp_bytestring::{ByteStr, ByteString};
+use pxp_diagnostics::{Diagnostic, Severity};
+use pxp_index::ReflectionClass;
+
+use crate::{AnalyserContext, AnalyserDiagnostic};
+
+use super::Pass;
+
+/// Checks that methods marked with `#[\Override]` actually override a method from a parent
+/// class or an implemented interface.
+#[derive(Debug, Default)]
+pub struct OverridePass;
+
+impl OverridePass {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Pass for OverridePass {
+    fn codes(&self) -> &'static [&'static str] {
+        &["A008"]
+    }
+
+    fn run(
+        &mut self,
+        context: &AnalyserContext,
+        ast: &[Statement],
+    ) -> Vec<Diagnostic<AnalyserDiagnostic>> {
+        let mut visitor = OverrideVisitor {
+            context,
+            diagnostics: Vec::new(),
+        };
+
+        visitor.visit(ast);
+        visitor.diagnostics
+    }
+}
+
+struct OverrideVisitor<'a> {
+    context: &'a AnalyserContext<'a>,
+    diagnostics: Vec<Diagnostic<AnalyserDiagnostic>>,
+}
+
+impl<'a> OverrideVisitor<'a> {
+    fn check<'b>(&mut self, name: &Name, members: impl Iterator<Item = &'b ClassishMember>) {
+        // Classes that aren't in the index can't be checked.
+        let Some(class) = name
+            .as_resolved()
+            .and_then(|name| self.context.index().get_class(name.resolved.clone()))
+        else {
+            return;
+        };
+
+        for member in members {
+            let ClassishMember::Method(method) = member else {
+                continue;
+            };
+
+            if !method.has_attribute(b"Override".into()) {
+                continue;
+            }
+
+            if self.overrides(class, method.name.symbol.as_ref()) {
+                continue;
+            }
+
+            self.diagnostics.push(Diagnostic::new(
+                AnalyserDiagnostic::OverrideWithoutParentMethod {
+                    class: class.name().to_bytestring(),
+                    method: method.name.symbol.clone(),
+                },
+                Severity::Error,
+                method.name.span,
+            ));
+        }
+    }
+
+    fn overrides(&self, class: ReflectionClass<'a>, method: &ByteStr) -> bool {
+        let index = self.context.index();
+
+        // Traits used by the class itself don't count, only those used by its ancestors.
+        let mut pending: Vec<ByteString> = class
+            .get_interface_names()
+            .chain(class.get_parent_name())
+            .map(|name| name.to_bytestring())
+            .collect();
+        let mut visited = HashSet::new();
+
+        while let Some(name) = pending.pop() {
+            if !visited.insert(name.clone()) {
+                continue;
+            }
+
+            let Some(ancestor) = index.get_class(name) else {
+                continue;
+            };
+
+            if ancestor.get_methods().iter().any(|candidate| {
+                !candidate.is_private() && candidate.get_name().eq_ignore_ascii_case(method)
+            }) {
+                return true;
+            }
+
+            pending.extend(
+                ancestor
+                    .get_interface_names()
+                    .chain(ancestor.get_trait_names())
+                    .chain(ancestor.get_parent_name())
+                    .map(|name| name.to_bytestring()),
+            );
+        }
+
+        false
+    }
+}
+
+impl<'a> Visitor for OverrideVisitor<'a> {
+    fn visit_class_statement(&mut self, node: &ClassStatement) {
+        self.check(&node.name, node.body.members.iter());
+
+        walk_class_statement(self, node);
+    }
+
+    fn visit_interface_statement(&mut self, node: &InterfaceStatement) {
+        self.check(&node.name, node.body.members.iter());
+
+        walk_interface_statement(self, node);
+    }
+
+    fn visit_unit_enum_statement(&mut self, node: &UnitEnumStatement) {
+        self.check(
+            &node.name,
+            node.body.members.iter().filter_map(|member| match member {
+                UnitEnumMember::Classish(member) => Some(member),
+                _ => None,
+            }),
+        );
+
+        walk_unit_enum_statement(self, node);
+    }
+
+    fn visit_backed_enum_statement(&mut self, node: &BackedEnumStatement) {
+        self.check(
+            &node.name,
+            node.body.members.iter().filter_map(|member| match member {
+                BackedEnumMember::Classish(member) => Some(member),
+                _ => None,
+            }),
+        );
+
+        walk_backed_enum_statement(self, node);
+    }
+}
@@ -0,0 +1,299 @@
+use pxp_ast::{
+    visitor::{
+        walk_anonymous_class_body, walk_arrow_function_expression, walk_backed_enum_statement,
+        walk_braced_namespace, walk_class_statement, walk_closure_expression,
+        walk_function_statement, walk_method, walk_property_hook, walk_return_statement,
+        walk_trait_statement, walk_unbraced_namespace, walk_unit_enum_statement, Visitor,
+    },
+    *,
+};
+use pxp_bytestring::ByteString;
+use pxp_diagnostics::{Diagnostic, Severity};
+use pxp_span::Span;
+use pxp_type::Type;
+
+use crate::{AnalyserContext, AnalyserDiagnostic};
+
+use super::{control_flow::ControlFlow, Pass};
+
+/// Checks `return` statements against the declared return type of the function, method or
+/// closure that contains them.
+///
+/// Three things are reported:
+///
+/// * A body that can reach its end without returning, when the return type requires a value,
+///   i.e. it isn't `void`, `never`, `mixed` or nullable.
+/// * A `return;` without a value, when the return type is anything other than `void` or `never`.
+/// * A `return` with a value inside of a `void` function.
+///
+/// A path ends at a `return`, a `throw`, `exit()` or `die()`, a call to a function that returns
+/// `never`, a `match` whose arms all end, or a loop that can't be left, e.g. `while (true)` without a
+/// `break`. A `goto` is assumed to end the path too. Generators are never reported, since their
+/// return type describes the generator rather than the values that are returned.
+#[derive(Debug, Default)]
+pub struct ReturnPass;
+
+impl ReturnPass {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Pass for ReturnPass {
+    fn codes(&self) -> &'static [&'static str] {
+        &["A024", "A025", "A026"]
+    }
+
+    fn run(
+        &mut self,
+        context: &AnalyserContext,
+        ast: &[Statement],
+    ) -> Vec<Diagnostic<AnalyserDiagnostic>> {
+        let mut visitor = ReturnVisitor {
+            context,
+            namespace: None,
+            classes: Vec::new(),
+            functions: Vec::new(),
+            diagnostics: Vec::new(),
+        };
+
+        visitor.visit(ast);
+        visitor.diagnostics
+    }
+}
+
+/// The function-like that `return` statements currently belong to.
+struct Function {
+    name: ByteString,
+    return_type: Type<ResolvedName>,
+}
+
+struct ReturnVisitor<'a> {
+    context: &'a AnalyserContext<'a>,
+    // The namespace that we're currently inside of, used to resolve unqualified function calls.
+    namespace: Option<ByteString>,
+    classes: Vec<ByteString>,
+    // Missing when there's nothing to check, e.g. for generators and untyped functions.
+    functions: Vec<Option<Function>>,
+    diagnostics: Vec<Diagnostic<AnalyserDiagnostic>>,
+}
+
+impl<'a> ReturnVisitor<'a> {
+    fn class(&mut self, name: ByteString, f: impl FnOnce(&mut Self)) {
+        self.classes.push(name);
+        f(self);
+        self.classes.pop();
+    }
+
+    fn function(
+        &mut self,
+        name: ByteString,
+        return_type: Option<&ReturnType>,
+        body: &[Statement],
+        end: Span,
+        f: impl FnOnce(&mut Self),
+    ) {
+        let return_type = return_type
+            .filter(|_| !is_generator(body))
+            .map(|return_type| &return_type.data_type);
+
+        if let Some(data_type) = return_type {
+            if requires_value(&data_type.kind)
+                && ControlFlow::new(self.context, self.namespace.as_ref())
+                    .exits(body)
+                    .completes
+            {
+                self.diagnostics.push(Diagnostic::new(
+                    AnalyserDiagnostic::MissingReturn {
+                        function: name.clone(),
+                        r#type: data_type.kind.to_string().into(),
+                    },
+                    Severity::Error,
+                    end,
+                ));
+            }
+        }
+
+        self.functions.push(return_type.map(|data_type| Function {
+            name,
+            return_type: data_type.kind.clone(),
+        }));
+
+        f(self);
+
+        self.functions.pop();
+    }
+
+    fn unchecked(&mut self, f: impl FnOnce(&mut Self)) {
+        self.functions.push(None);
+        f(self);
+        self.functions.pop();
+    }
+
+    fn method_name(&self, method: &SimpleIdentifier) -> ByteString {
+        match self.classes.last() {
+            Some(class) => format!("{}::{}", class, method.symbol).into(),
+            None => method.symbol.clone(),
+        }
+    }
+}
+
+impl<'a> Visitor for ReturnVisitor<'a> {
+    fn visit_unbraced_namespace(&mut self, node: &UnbracedNamespace) {
+        self.namespace = Some(node.name.symbol.clone());
+        walk_unbraced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_braced_namespace(&mut self, node: &BracedNamespace) {
+        self.namespace = node.name.as_ref().map(|name| name.symbol.clone());
+        walk_braced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_function_statement(&mut self, node: &FunctionStatement) {
+        self.function(
+            node.name.symbol().clone(),
+            node.return_type.as_ref(),
+            &node.body.statements,
+            node.body.right_brace,
+            |visitor| walk_function_statement(visitor, node),
+        );
+    }
+
+    fn visit_method(&mut self, node: &Method) {
+        let MethodBodyKind::Concrete(body) = &node.body.kind else {
+            return walk_method(self, node);
+        };
+
+        self.function(
+            self.method_name(&node.name),
+            node.return_type.as_ref(),
+            &body.statements,
+            body.right_brace,
+            |visitor| walk_method(visitor, node),
+        );
+    }
+
+    fn visit_closure_expression(&mut self, node: &ClosureExpression) {
+        self.function(
+            b"{closure}".into(),
+            node.return_type.as_ref(),
+            &node.body.statements,
+            node.body.right_brace,
+            |visitor| walk_closure_expression(visitor, node),
+        );
+    }
+
+    fn visit_arrow_function_expression(&mut self, node: &ArrowFunctionExpression) {
+        self.unchecked(|visitor| walk_arrow_function_expression(visitor, node));
+    }
+
+    fn visit_property_hook(&mut self, node: &PropertyHook) {
+        self.unchecked(|visitor| walk_property_hook(visitor, node));
+    }
+
+    fn visit_class_statement(&mut self, node: &ClassStatement) {
+        self.class(node.name.symbol().clone(), |visitor| {
+            walk_class_statement(visitor, node)
+        });
+    }
+
+    fn visit_anonymous_class_body(&mut self, node: &AnonymousClassBody) {
+        self.class(b"class@anonymous".into(), |visitor| {
+            walk_anonymous_class_body(visitor, node)
+        });
+    }
+
+    fn visit_trait_statement(&mut self, node: &TraitStatement) {
+        self.class(node.name.symbol().clone(), |visitor| {
+            walk_trait_statement(visitor, node)
+        });
+    }
+
+    fn visit_unit_enum_statement(&mut self, node: &UnitEnumStatement) {
+        self.class(node.name.symbol().clone(), |visitor| {
+            walk_unit_enum_statement(visitor, node)
+        });
+    }
+
+    fn visit_backed_enum_statement(&mut self, node: &BackedEnumStatement) {
+        self.class(node.name.symbol().clone(), |visitor| {
+            walk_backed_enum_statement(visitor, node)
+        });
+    }
+
+    fn visit_return_statement(&mut self, node: &ReturnStatement) {
+        walk_return_statement(self, node);
+
+        let Some(Some(Function { name, return_type })) = self.functions.last() else {
+            return;
+        };
+
+        let diagnostic = match (return_type, &node.value) {
+            (Type::Void, Some(_)) => Diagnostic::new(
+                AnalyserDiagnostic::VoidFunctionReturnsValue {
+                    function: name.clone(),
+                },
+                Severity::Error,
+                node.span,
+            ),
+            (Type::Void | Type::Never, _) | (_, Some(_)) => return,
+            (_, None) => Diagnostic::new(
+                AnalyserDiagnostic::ReturnWithoutValue {
+                    function: name.clone(),
+                    r#type: return_type.to_string().into(),
+                },
+                Severity::Warning,
+                node.span,
+            ),
+        };
+
+        self.diagnostics.push(diagnostic);
+    }
+}
+
+/// Whether a function with the given return type has to return a value on every path.
+fn requires_value(r#type: &Type<ResolvedName>) -> bool {
+    match r#type {
+        Type::Void | Type::Never | Type::Null | Type::Mixed | Type::Nullable(_) => false,
+        Type::Union(types) => types.iter().all(requires_value),
+        _ => true,
+    }
+}
+
+fn is_generator(body: &[Statement]) -> bool {
+    let mut finder = YieldFinder(false);
+    finder.visit(body);
+    finder.0
+}
+
+/// Looks for `yield` inside of a function body, without looking inside of nested functions or
+/// classes.
+struct YieldFinder(bool);
+
+impl Visitor for YieldFinder {
+    fn visit_yield_expression(&mut self, _: &YieldExpression) {
+        self.0 = true;
+    }
+
+    fn visit_yield_from_expression(&mut self, _: &YieldFromExpression) {
+        self.0 = true;
+    }
+
+    fn visit_function_statement(&mut self, _: &FunctionStatement) {}
+
+    fn visit_closure_expression(&mut self, _: &ClosureExpression) {}
+
+    fn visit_arrow_function_expression(&mut self, _: &ArrowFunctionExpression) {}
+
+    fn visit_class_statement(&mut self, _: &ClassStatement) {}
+
+    fn visit_anonymous_class_body(&mut self, _: &AnonymousClassBody) {}
+
+    fn visit_trait_statement(&mut self, _: &TraitStatement) {}
+
+    fn visit_unit_enum_statement(&mut self, _: &UnitEnumStatement) {}
+
+    fn visit_backed_enum_statement(&mut self, _: &BackedEnumStatement) {}
+}
@@ -0,0 +1,452 @@
+use std::collections::{HashMap, HashSet};
+
+use pxp_ast::{
+    visitor::{
+        walk_backed_enum_statement, walk_class_statement, walk_trait_statement,
+        walk_unit_enum_statement, Visitor,
+    },
+    *,
+};
+use pxp_bytestring::{ByteStr, ByteString};
+use pxp_diagnostics::{Diagnostic, Severity};
+use pxp_index::{ReflectionClass, ReflectionFunctionLike, ReflectionMethod};
+use pxp_type::Type;
+
+use crate::{AnalyserContext, AnalyserDiagnostic};
+
+use super::Pass;
+
+/// Checks the composition of traits into class-likes: methods that collide between traits
+/// without an `insteadof` resolution, abstract trait methods that are missing or implemented
+/// with an incompatible signature, and properties that are defined differently.
+#[derive(Debug, Default)]
+pub struct TraitPass;
+
+impl TraitPass {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Pass for TraitPass {
+    fn codes(&self) -> &'static [&'static str] {
+        &["A019", "A020", "A021", "A022"]
+    }
+
+    fn run(
+        &mut self,
+        context: &AnalyserContext,
+        ast: &[Statement],
+    ) -> Vec<Diagnostic<AnalyserDiagnostic>> {
+        let mut visitor = TraitVisitor {
+            context,
+            diagnostics: Vec::new(),
+        };
+
+        visitor.visit(ast);
+        visitor.diagnostics
+    }
+}
+
+struct TraitVisitor<'a> {
+    context: &'a AnalyserContext<'a>,
+    diagnostics: Vec<Diagnostic<AnalyserDiagnostic>>,
+}
+
+/// A trait named in a `use` statement, along with the traits that it uses itself.
+struct ComposedTrait<'a> {
+    name: &'a Name,
+    traits: Vec<ReflectionClass<'a>>,
+}
+
+impl<'a> ComposedTrait<'a> {
+    /// Check whether a name in an `insteadof` list refers to this trait.
+    fn is_named(&self, other: &Name) -> bool {
+        match (self.name.as_resolved(), other.as_resolved()) {
+            (Some(name), Some(other)) => name.resolved.eq_ignore_ascii_case(&other.resolved),
+            _ => false,
+        }
+    }
+}
+
+/// A method provided to the class-like by one of the traits in its `use` statements.
+struct ProvidedMethod<'a> {
+    usage: usize,
+    method: ReflectionMethod<'a>,
+}
+
+impl<'a> TraitVisitor<'a> {
+    fn check<'b>(&mut self, name: &Name, members: impl Iterator<Item = &'b ClassishMember>) {
+        let index = self.context.index();
+
+        // Class-likes that aren't in the index can't be checked.
+        let Some(class) = name
+            .as_resolved()
+            .and_then(|name| index.get_class(name.resolved.clone()))
+        else {
+            return;
+        };
+
+        let members = members.collect::<Vec<_>>();
+        let usages = members
+            .iter()
+            .filter_map(|member| match member {
+                ClassishMember::TraitUsage(usage) => Some(usage),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        if usages.is_empty() {
+            return;
+        }
+
+        let composed = usages
+            .iter()
+            .flat_map(|usage| usage.traits.iter())
+            .filter_map(|name| {
+                let r#trait = index.get_class(name.as_resolved()?.resolved.clone())?;
+
+                r#trait.is_trait().then(|| ComposedTrait {
+                    name,
+                    traits: self.flatten(r#trait),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let (excluded, aliases) = self.adaptations(&usages, &composed);
+        let own_methods = class.get_methods();
+
+        // Methods that the class-like declares itself take precedence over those from traits.
+        let declares = |method: &ByteStr| {
+            own_methods
+                .iter()
+                .find(|candidate| candidate.get_name().eq_ignore_ascii_case(method))
+        };
+
+        let mut provided: HashMap<ByteString, ProvidedMethod> = HashMap::new();
+        let mut abstracts: Vec<ProvidedMethod> = Vec::new();
+
+        for (usage, composed_trait) in composed.iter().enumerate() {
+            let mut seen = HashSet::new();
+
+            for method in composed_trait.traits.iter().flat_map(|t| t.get_methods()) {
+                let key: ByteString = method.get_name().to_ascii_lowercase().into();
+
+                // The first declaration wins when a trait is composed from other traits.
+                if !seen.insert(key.clone()) || excluded.contains(&(usage, key.clone())) {
+                    continue;
+                }
+
+                if method.is_abstract() {
+                    abstracts.push(ProvidedMethod { usage, method });
+                    continue;
+                }
+
+                if declares(method.get_name()).is_some() {
+                    continue;
+                }
+
+                match provided.get(&key) {
+                    Some(existing) if existing.usage != usage => {
+                        self.diagnostics.push(Diagnostic::new(
+                            AnalyserDiagnostic::TraitMethodCollision {
+                                class: class.name().to_bytestring(),
+                                method: method.get_name().to_bytestring(),
+                                r#trait: method.get_class().name().to_bytestring(),
+                                other: existing.method.get_class().name().to_bytestring(),
+                            },
+                            Severity::Error,
+                            composed_trait.name.span,
+                        ));
+                    }
+                    Some(_) => {}
+                    None => {
+                        provided.insert(key, ProvidedMethod { usage, method });
+                    }
+                }
+            }
+        }
+
+        for ProvidedMethod { usage, method } in abstracts {
+            let name = method.get_name();
+
+            if let Some(implementation) = declares(name) {
+                if !is_compatible(&method, implementation) {
+                    let span = members
+                        .iter()
+                        .find_map(|member| match member {
+                            ClassishMember::Method(candidate)
+                                if candidate.name.symbol.eq_ignore_ascii_case(name) =>
+                            {
+                                Some(candidate.name.span)
+                            }
+                            _ => None,
+                        })
+                        .unwrap_or(composed[usage].name.span);
+
+                    self.diagnostics.push(Diagnostic::new(
+                        AnalyserDiagnostic::IncompatibleTraitMethodSignature {
+                            class: class.name().to_bytestring(),
+                            r#trait: method.get_class().name().to_bytestring(),
+                            method: name.to_bytestring(),
+                        },
+                        Severity::Error,
+                        span,
+                    ));
+                }
+
+                continue;
+            }
+
+            let key: ByteString = name.to_ascii_lowercase().into();
+
+            if provided.contains_key(&key)
+                || aliases.contains(&key)
+                || class.is_abstract()
+                || class.is_trait()
+                || self.inherits(&class, name)
+            {
+                continue;
+            }
+
+            self.diagnostics.push(Diagnostic::new(
+                AnalyserDiagnostic::MissingTraitAbstractMethod {
+                    class: class.name().to_bytestring(),
+                    r#trait: method.get_class().name().to_bytestring(),
+                    method: name.to_bytestring(),
+                },
+                Severity::Error,
+                composed[usage].name.span,
+            ));
+        }
+
+        self.check_properties(&class, &composed);
+    }
+
+    /// Check that properties defined by more than one of the composed traits, or by a trait and
+    /// the class-like itself, have the same declaration.
+    fn check_properties(&mut self, class: &ReflectionClass, composed: &[ComposedTrait]) {
+        let own_properties = class.get_properties();
+        let mut defined = HashMap::new();
+
+        for (usage, composed_trait) in composed.iter().enumerate() {
+            for property in composed_trait
+                .traits
+                .iter()
+                .flat_map(|t| t.get_properties())
+            {
+                let other = match own_properties
+                    .iter()
+                    .find(|candidate| candidate.get_name() == property.get_name())
+                {
+                    Some(own) => Some(*own),
+                    None => defined
+                        .get(&property.get_name().to_bytestring())
+                        .filter(|(other_usage, _)| *other_usage != usage)
+                        .map(|(_, other)| *other),
+                };
+
+                match other {
+                    Some(other) if !property.is_compatible_with(&other) => {
+                        self.diagnostics.push(Diagnostic::new(
+                            AnalyserDiagnostic::TraitPropertyConflict {
+                                class: class.name().to_bytestring(),
+                                r#trait: property.get_class().name().to_bytestring(),
+                                other: other.get_class().name().to_bytestring(),
+                                property: property.get_name().to_bytestring(),
+                            },
+                            Severity::Warning,
+                            composed_trait.name.span,
+                        ));
+                    }
+                    Some(_) => {}
+                    None => {
+                        defined
+                            .entry(property.get_name().to_bytestring())
+                            .or_insert((usage, property));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Get the trait followed by every trait that it uses, directly or indirectly.
+    fn flatten(&self, r#trait: ReflectionClass<'a>) -> Vec<ReflectionClass<'a>> {
+        let index = self.context.index();
+        let mut traits = Vec::new();
+        let mut pending = vec![r#trait];
+        let mut visited = HashSet::new();
+
+        while let Some(r#trait) = pending.pop() {
+            if !visited.insert(r#trait.name().to_bytestring()) {
+                continue;
+            }
+
+            pending.extend(
+                r#trait
+                    .get_trait_names()
+                    .filter_map(|name| index.get_class(name.to_bytestring()))
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .rev(),
+            );
+
+            traits.push(r#trait);
+        }
+
+        traits
+    }
+
+    /// Collect the methods excluded through `insteadof`, keyed by the position of the trait in the
+    /// `use` statements, and the lowercased names introduced through `as`.
+    fn adaptations(
+        &self,
+        usages: &[&TraitUsage],
+        composed: &[ComposedTrait],
+    ) -> (HashSet<(usize, ByteString)>, HashSet<ByteString>) {
+        let mut excluded = HashSet::new();
+        let mut aliases = HashSet::new();
+
+        for adaptation in usages.iter().flat_map(|usage| usage.adaptations.iter()) {
+            match &adaptation.kind {
+                TraitUsageAdaptationKind::Precedence(precedence) => {
+                    let method: ByteString = precedence.method.symbol.to_ascii_lowercase().into();
+
+                    for name in precedence.insteadof.iter() {
+                        for (usage, _) in composed
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, composed)| composed.is_named(name))
+                        {
+                            excluded.insert((usage, method.clone()));
+                        }
+                    }
+                }
+                TraitUsageAdaptationKind::Alias(alias) => {
+                    aliases.insert(alias.alias.symbol.to_ascii_lowercase().into());
+                }
+                TraitUsageAdaptationKind::Visibility(_) => {}
+            }
+        }
+
+        (excluded, aliases)
+    }
+
+    /// Check whether a concrete method is inherited from a parent class, or one of its traits.
+    fn inherits(&self, class: &ReflectionClass, method: &ByteStr) -> bool {
+        let index = self.context.index();
+        let mut pending: Vec<ByteString> = class
+            .get_parent_name()
+            .map(|name| name.to_bytestring())
+            .into_iter()
+            .collect();
+        let mut visited = HashSet::new();
+
+        while let Some(name) = pending.pop() {
+            if !visited.insert(name.clone()) {
+                continue;
+            }
+
+            let Some(ancestor) = index.get_class(name) else {
+                continue;
+            };
+
+            if ancestor.get_methods().iter().any(|candidate| {
+                !candidate.is_abstract() && candidate.get_name().eq_ignore_ascii_case(method)
+            }) {
+                return true;
+            }
+
+            pending.extend(
+                ancestor
+                    .get_trait_names()
+                    .chain(ancestor.get_parent_name())
+                    .map(|name| name.to_bytestring()),
+            );
+        }
+
+        false
+    }
+}
+
+/// Check whether an implementation is compatible with an abstract method, using native types.
+///
+/// Parameter types are contravariant and return types are covariant. Class-likes aren't resolved
+/// when comparing types, so any class-like is considered compatible with any other.
+fn is_compatible(r#abstract: &ReflectionMethod, implementation: &ReflectionMethod) -> bool {
+    let expected = r#abstract.effective_signature().get_parameters();
+    let actual = implementation.effective_signature().get_parameters();
+
+    let required = actual
+        .iter()
+        .filter(|parameter| !parameter.is_optional() && !parameter.is_variadic())
+        .count();
+    let variadic = actual.last().filter(|parameter| parameter.is_variadic());
+
+    if required > expected.len() || (actual.len() < expected.len() && variadic.is_none()) {
+        return false;
+    }
+
+    let parameters_are_compatible = expected.iter().enumerate().all(|(i, parameter)| {
+        let Some(candidate) = actual.get(i).or(variadic) else {
+            return false;
+        };
+
+        match (parameter.get_native_type(), candidate.get_native_type()) {
+            (_, None) => true,
+            (None, Some(r#type)) => r#type == &Type::Mixed,
+            (Some(expected), Some(actual)) => expected.is_refinement_of(actual),
+        }
+    });
+
+    if !parameters_are_compatible {
+        return false;
+    }
+
+    match (
+        r#abstract.get_return_type(),
+        implementation.get_return_type(),
+    ) {
+        (None, _) => true,
+        (Some(_), None) => false,
+        (Some(expected), Some(actual)) => actual.to_type().is_refinement_of(expected.to_type()),
+    }
+}
+
+impl<'a> Visitor for TraitVisitor<'a> {
+    fn visit_class_statement(&mut self, node: &ClassStatement) {
+        self.check(&node.name, node.body.members.iter());
+
+        walk_class_statement(self, node);
+    }
+
+    fn visit_trait_statement(&mut self, node: &TraitStatement) {
+        self.check(&node.name, node.body.members.iter());
+
+        walk_trait_statement(self, node);
+    }
+
+    fn visit_unit_enum_statement(&mut self, node: &UnitEnumStatement) {
+        self.check(
+            &node.name,
+            node.body.members.iter().filter_map(|member| match member {
+                UnitEnumMember::Classish(member) => Some(member),
+                _ => None,
+            }),
+        );
+
+        walk_unit_enum_statement(self, node);
+    }
+
+    fn visit_backed_enum_statement(&mut self, node: &BackedEnumStatement) {
+        self.check(
+            &node.name,
+            node.body.members.iter().filter_map(|member| match member {
+                BackedEnumMember::Classish(member) => Some(member),
+                _ => None,
+            }),
+        );
+
+        walk_backed_enum_statement(self, node);
+    }
+}
@@ -0,0 +1,1347 @@
+use std::collections::{HashMap, HashSet};
+
+use pxp_ast::{
+    visitor::{
+        walk_anonymous_class_body, walk_arrow_function_expression, walk_backed_enum_statement,
+        walk_braced_namespace, walk_class_statement, walk_closure_expression, walk_die_expression,
+        walk_eval_expression, walk_exit_expression, walk_function_statement,
+        walk_include_expression, walk_include_once_expression, walk_interface_statement,
+        walk_method, walk_property_hook, walk_require_expression, walk_require_once_expression,
+        walk_return_statement, walk_throw_expression, walk_trait_statement,
+        walk_unbraced_namespace, walk_unit_enum_statement, Visitor,
+    },
+    *,
+};
+use pxp_bytestring::ByteString;
+use pxp_diagnostics::{Diagnostic, Severity};
+use pxp_index::{ReflectionFunctionLike, Signature};
+use pxp_span::Span;
+use pxp_type::{ShapeItemKey, Type};
+
+use crate::{AnalyserContext, AnalyserDiagnostic};
+
+use super::{resolve_function, Pass};
+
+/// Variables that are always available, so they're never reported.
+const PREDEFINED_VARIABLES: [&[u8]; 11] = [
+    b"$this",
+    b"$GLOBALS",
+    b"$_SERVER",
+    b"$_GET",
+    b"$_POST",
+    b"$_FILES",
+    b"$_COOKIE",
+    b"$_SESSION",
+    b"$_REQUEST",
+    b"$_ENV",
+    b"$http_response_header",
+];
+
+/// Reports variables that are read before they've been assigned inside of a function, method
+/// or closure body.
+///
+/// Variables that are only assigned in some branches are reported as possibly undefined. Reads
+/// inside of `isset()`, `empty()` and the left-hand side of `??` are allowed, and the names passed to
+/// `compact()` are read too. Scopes that use `include`, `eval()`, `goto` or `extract()` with an array
+/// whose keys aren't known can't be checked, so they produce a single note instead.
+#[derive(Debug, Default)]
+pub struct UndefinedVariablePass;
+
+impl UndefinedVariablePass {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Pass for UndefinedVariablePass {
+    fn codes(&self) -> &'static [&'static str] {
+        &["A011", "A012", "A013"]
+    }
+
+    fn run(
+        &mut self,
+        context: &AnalyserContext,
+        ast: &[Statement],
+    ) -> Vec<Diagnostic<AnalyserDiagnostic>> {
+        let mut visitor = UndefinedVariableVisitor {
+            context,
+            namespace: None,
+            scopes: vec![Scope::unchecked()],
+            silent: 0,
+            diagnostics: Vec::new(),
+        };
+
+        visitor.visit(ast);
+
+        // Nested scopes are flushed before their parent, so put everything back in source order.
+        visitor
+            .diagnostics
+            .sort_by_key(|diagnostic| diagnostic.span.start);
+        visitor.diagnostics
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Definedness {
+    Possibly,
+    Defined,
+}
+
+/// The variables that have been assigned at a given point in a function body.
+#[derive(Debug, Clone, Default)]
+struct Flow {
+    // Variables that are missing from the map are undefined.
+    variables: HashMap<ByteString, Definedness>,
+    // Set once the flow returns, throws, breaks, etc. since nothing after that point can run.
+    unreachable: bool,
+}
+
+impl Flow {
+    /// Merge the flows from multiple branches. Variables need to be defined in every branch that
+    /// can reach the end to be defined afterwards.
+    fn merge(flows: Vec<Flow>) -> Flow {
+        let reachable: Vec<Flow> = flows.into_iter().filter(|flow| !flow.unreachable).collect();
+
+        if reachable.is_empty() {
+            return Flow {
+                variables: HashMap::new(),
+                unreachable: true,
+            };
+        }
+
+        let mut variables = HashMap::new();
+
+        for name in reachable.iter().flat_map(|flow| flow.variables.keys()) {
+            let defined = reachable
+                .iter()
+                .all(|flow| flow.variables.get(name) == Some(&Definedness::Defined));
+
+            variables.insert(
+                name.clone(),
+                if defined {
+                    Definedness::Defined
+                } else {
+                    Definedness::Possibly
+                },
+            );
+        }
+
+        Flow {
+            variables,
+            unreachable: false,
+        }
+    }
+
+    /// Mark the given variables as possibly defined, unless they're already defined.
+    fn assume(&mut self, variables: HashSet<ByteString>) {
+        for name in variables {
+            self.variables.entry(name).or_insert(Definedness::Possibly);
+        }
+    }
+}
+
+struct Scope {
+    // Class bodies and top-level code aren't checked, but are still walked to find functions.
+    checked: bool,
+    flow: Flow,
+    // The flows that leave each enclosing loop or switch through `break` or `continue`, innermost last.
+    exits: Vec<Vec<Flow>>,
+    diagnostics: Vec<Diagnostic<AnalyserDiagnostic>>,
+    // The first construct that makes the scope's variables impossible to know, e.g. `extract()`.
+    dynamic: Option<(ByteString, Span)>,
+}
+
+impl Scope {
+    fn new(checked: bool, flow: Flow) -> Self {
+        Self {
+            checked,
+            flow,
+            exits: Vec::new(),
+            diagnostics: Vec::new(),
+            dynamic: None,
+        }
+    }
+
+    fn checked() -> Self {
+        Self::new(true, Flow::default())
+    }
+
+    fn unchecked() -> Self {
+        Self::new(false, Flow::default())
+    }
+}
+
+struct UndefinedVariableVisitor<'a> {
+    context: &'a AnalyserContext<'a>,
+    // The namespace that we're currently inside of, used to resolve unqualified function calls.
+    namespace: Option<ByteString>,
+    scopes: Vec<Scope>,
+    // Greater than zero while inside of `isset()`, `empty()`, etc. where reads aren't reported.
+    silent: usize,
+    diagnostics: Vec<Diagnostic<AnalyserDiagnostic>>,
+}
+
+impl<'a> UndefinedVariableVisitor<'a> {
+    fn scope(&mut self) -> &mut Scope {
+        self.scopes.last_mut().unwrap()
+    }
+
+    fn flow(&mut self) -> &mut Flow {
+        &mut self.scope().flow
+    }
+
+    fn fork(&mut self) -> Flow {
+        self.flow().clone()
+    }
+
+    fn replace_flow(&mut self, flow: Flow) -> Flow {
+        std::mem::replace(self.flow(), flow)
+    }
+
+    fn terminate(&mut self) {
+        self.flow().unreachable = true;
+    }
+
+    fn read(&mut self, variable: &SimpleVariable) {
+        self.read_named(&variable.symbol, &variable.stripped, variable.span);
+    }
+
+    /// Read a variable by name, where `span` points at whatever names it, e.g. a string passed to
+    /// `compact()`.
+    fn read_named(&mut self, symbol: &ByteString, stripped: &ByteString, span: Span) {
+        if PREDEFINED_VARIABLES.contains(&symbol.as_bytes()) {
+            return;
+        }
+
+        let silent = self.silent > 0;
+        let scope = self.scope();
+
+        if !scope.checked || scope.flow.unreachable || silent {
+            return;
+        }
+
+        let (kind, severity) = match scope.flow.variables.get(symbol) {
+            Some(Definedness::Defined) => return,
+            Some(Definedness::Possibly) => (
+                AnalyserDiagnostic::PossiblyUndefinedVariable {
+                    variable: stripped.clone(),
+                },
+                Severity::Information,
+            ),
+            None => (
+                AnalyserDiagnostic::UndefinedVariable {
+                    variable: stripped.clone(),
+                },
+                Severity::Warning,
+            ),
+        };
+
+        scope
+            .diagnostics
+            .push(Diagnostic::new(kind, severity, span));
+
+        // Only the first read is reported, until the variable is unset again.
+        scope
+            .flow
+            .variables
+            .insert(symbol.clone(), Definedness::Defined);
+    }
+
+    fn define(&mut self, variable: &SimpleVariable) {
+        self.define_named(variable.symbol.clone(), Definedness::Defined);
+    }
+
+    fn define_named(&mut self, symbol: ByteString, definedness: Definedness) {
+        let variables = &mut self.flow().variables;
+
+        // An optional key can't make a variable that is already defined any less defined.
+        if definedness == Definedness::Possibly && variables.contains_key(&symbol) {
+            return;
+        }
+
+        variables.insert(symbol, definedness);
+    }
+
+    fn mark_dynamic(&mut self, construct: &[u8], span: Span) {
+        let scope = self.scope();
+
+        if scope.dynamic.is_none() {
+            scope.dynamic = Some((construct.into(), span));
+        }
+    }
+
+    fn silently(&mut self, f: impl FnOnce(&mut Self)) {
+        self.silent += 1;
+        f(self);
+        self.silent -= 1;
+    }
+
+    fn enter(&mut self, scope: Scope) {
+        self.scopes.push(scope);
+    }
+
+    fn leave(&mut self) {
+        let scope = self.scopes.pop().unwrap();
+
+        if !scope.checked {
+            return;
+        }
+
+        match scope.dynamic {
+            Some((construct, span)) => self.diagnostics.push(Diagnostic::new(
+                AnalyserDiagnostic::UncheckedVariableScope { construct },
+                Severity::Information,
+                span,
+            )),
+            None => self.diagnostics.extend(scope.diagnostics),
+        }
+    }
+
+    fn unchecked(&mut self, f: impl FnOnce(&mut Self)) {
+        self.enter(Scope::unchecked());
+        f(self);
+        self.leave();
+    }
+
+    /// Assume that anything assigned inside of a loop might already be defined, since the loop
+    /// could have run before.
+    fn enter_loop(&mut self, collect: impl FnOnce(&mut AssignedVariables)) {
+        let mut assigned = AssignedVariables::default();
+        collect(&mut assigned);
+
+        self.flow().assume(assigned.0);
+        self.scope().exits.push(Vec::new());
+    }
+
+    fn leave_loop(&mut self) -> Vec<Flow> {
+        self.scope().exits.pop().unwrap()
+    }
+
+    fn exit(&mut self, level: Option<&Level>) {
+        let level = match level {
+            Some(Level::Literal(level)) => std::str::from_utf8(&level.literal.token.symbol)
+                .ok()
+                .and_then(|level| level.parse().ok())
+                .unwrap_or(1),
+            _ => 1,
+        };
+
+        let flow = self.fork();
+        let exits = &mut self.scope().exits;
+
+        if let Some(target) = exits
+            .len()
+            .checked_sub(level)
+            .and_then(|target| exits.get_mut(target))
+        {
+            target.push(flow);
+        }
+
+        self.terminate();
+    }
+
+    fn visit_body(&mut self, statements: &[Statement]) {
+        for statement in statements {
+            self.visit_statement(statement);
+        }
+    }
+
+    /// Visit an expression that is being written to.
+    fn assign(&mut self, target: &Expression) {
+        match &target.kind {
+            ExpressionKind::Variable(variable) => match variable.as_ref() {
+                Variable::SimpleVariable(variable) => self.define(variable),
+                _ => {
+                    self.visit_expression(target);
+                    self.mark_dynamic(b"variable variables", target.span);
+                }
+            },
+            // Writing to an index of an undefined variable creates the array.
+            ExpressionKind::ArrayIndex(index) => {
+                if let Some(index) = &index.index {
+                    self.visit_expression(index);
+                }
+
+                self.assign(&index.array);
+            }
+            ExpressionKind::Array(array) => {
+                for item in array.items.iter() {
+                    match item {
+                        ArrayItem::Value(ArrayItemValue { value, .. })
+                        | ArrayItem::ReferencedValue(ArrayItemReferencedValue { value, .. }) => {
+                            self.assign(value)
+                        }
+                        ArrayItem::KeyValue(ArrayItemKeyValue { key, value, .. })
+                        | ArrayItem::ReferencedKeyValue(ArrayItemReferencedKeyValue {
+                            key,
+                            value,
+                            ..
+                        }) => {
+                            self.visit_expression(key);
+                            self.assign(value);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            ExpressionKind::List(list) => {
+                for entry in list.items.iter() {
+                    match entry {
+                        ListEntry::Value(ListEntryValue { value, .. }) => self.assign(value),
+                        ListEntry::KeyValue(ListEntryKeyValue { key, value, .. }) => {
+                            self.visit_expression(key);
+                            self.assign(value);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            ExpressionKind::Parenthesized(inner) => self.assign(&inner.expr),
+            _ => self.visit_expression(target),
+        }
+    }
+
+    fn resolve_function(&self, target: &Expression) -> Option<&'a Signature> {
+        let ExpressionKind::Name(name) = &target.kind else {
+            return None;
+        };
+
+        let function = resolve_function(self.context.index(), self.namespace.as_ref(), name)?;
+
+        Some(function.effective_signature())
+    }
+
+    /// Visit the arguments to a call. Variables passed to by-reference parameters are assigned
+    /// rather than read, and so are plain variables passed to unknown callables.
+    fn visit_call_arguments(&mut self, arguments: &ArgumentList, signature: Option<&Signature>) {
+        for (position, argument) in arguments.arguments.iter().enumerate() {
+            let (value, ellipsis, parameter) = match argument {
+                Argument::Positional(argument) => (
+                    &argument.value,
+                    argument.ellipsis,
+                    signature.and_then(|signature| {
+                        let parameters = signature.get_parameters();
+
+                        parameters.get(position).or_else(|| {
+                            parameters
+                                .last()
+                                .filter(|parameter| parameter.is_variadic())
+                        })
+                    }),
+                ),
+                Argument::Named(argument) => (
+                    &argument.value,
+                    argument.ellipsis,
+                    signature.and_then(|signature| {
+                        signature.get_parameter(argument.name.symbol.as_ref())
+                    }),
+                ),
+            };
+
+            let by_reference = match signature {
+                Some(_) => parameter.is_some_and(|parameter| parameter.is_by_reference()),
+                None => matches!(
+                    &value.kind,
+                    ExpressionKind::Variable(variable) if variable.is_simple()
+                ),
+            };
+
+            if by_reference && ellipsis.is_none() {
+                self.assign(value);
+            } else {
+                self.visit_expression(value);
+            }
+        }
+    }
+
+    /// The names passed to `compact()` are read like variables, including those in nested arrays.
+    fn visit_compact_argument(&mut self, value: &Expression) {
+        match &value.kind {
+            ExpressionKind::Literal(literal) => match literal.string_value() {
+                Some(name) => {
+                    let stripped = name.value().to_bytestring();
+                    let mut symbol = ByteString::from(b"$");
+                    symbol.extend(&stripped);
+
+                    self.read_named(&symbol, &stripped, value.span);
+                }
+                None => self.visit_expression(value),
+            },
+            ExpressionKind::Array(array) => {
+                for item in array.items.iter() {
+                    match item {
+                        ArrayItem::Value(item) => self.visit_compact_argument(&item.value),
+                        _ => self.visit_array_item(item),
+                    }
+                }
+            }
+            _ => self.visit_expression(value),
+        }
+    }
+
+    /// The variables that `extract()` defines, if the keys of the array passed to it are known.
+    /// Optional keys in an array shape only possibly define their variable.
+    fn extracted_variables(
+        &self,
+        arguments: &ArgumentList,
+    ) -> Option<Vec<(ByteString, Definedness)>> {
+        // The flags change which variables are written and what they're called.
+        let [Argument::Positional(argument)] = arguments.arguments.as_slice() else {
+            return None;
+        };
+
+        if argument.ellipsis.is_some() {
+            return None;
+        }
+
+        let variables: Vec<(ByteString, Definedness)> = match &argument.value.kind {
+            ExpressionKind::Array(array) => array
+                .items
+                .iter()
+                .map(|item| match item {
+                    ArrayItem::KeyValue(ArrayItemKeyValue {
+                        key:
+                            Expression {
+                                kind: ExpressionKind::Literal(literal),
+                                ..
+                            },
+                        ..
+                    }) => Some((
+                        literal.string_value()?.value().to_bytestring(),
+                        Definedness::Defined,
+                    )),
+                    _ => None,
+                })
+                .collect::<Option<_>>()?,
+            _ => match self.context.types().resolve(argument.value.id) {
+                Type::Shaped {
+                    items,
+                    sealed: true,
+                    ..
+                } => items
+                    .iter()
+                    .map(|item| match &item.key_name {
+                        Some(ShapeItemKey::String(key)) => Some((
+                            key.as_bytestr().strip_string_quotes().to_bytestring(),
+                            if item.optional {
+                                Definedness::Possibly
+                            } else {
+                                Definedness::Defined
+                            },
+                        )),
+                        _ => None,
+                    })
+                    .collect::<Option<_>>()?,
+                _ => return None,
+            },
+        };
+
+        Some(
+            variables
+                .into_iter()
+                .filter(|(name, _)| SimpleVariable::is_valid_name(name) && name != b"this")
+                .collect(),
+        )
+    }
+
+    fn visit_branches<'b>(&mut self, branches: impl IntoIterator<Item = &'b Expression>) {
+        let base = self.fork();
+        let mut outcomes = Vec::new();
+
+        for branch in branches {
+            self.replace_flow(base.clone());
+            self.visit_expression(branch);
+            outcomes.push(self.fork());
+        }
+
+        self.replace_flow(Flow::merge(outcomes));
+    }
+
+    /// Visit an expression that might not be evaluated, e.g. the right-hand side of `&&`.
+    fn visit_conditionally(&mut self, expression: &Expression) {
+        let skipped = self.fork();
+
+        self.visit_expression(expression);
+
+        let evaluated = self.fork();
+        self.replace_flow(Flow::merge(vec![skipped, evaluated]));
+    }
+}
+
+impl<'a> Visitor for UndefinedVariableVisitor<'a> {
+    fn visit_unbraced_namespace(&mut self, node: &UnbracedNamespace) {
+        self.namespace = Some(node.name.symbol.clone());
+        walk_unbraced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_braced_namespace(&mut self, node: &BracedNamespace) {
+        self.namespace = node.name.as_ref().map(|name| name.symbol.clone());
+        walk_braced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_function_statement(&mut self, node: &FunctionStatement) {
+        self.enter(Scope::checked());
+        walk_function_statement(self, node);
+        self.leave();
+    }
+
+    fn visit_method(&mut self, node: &Method) {
+        self.enter(Scope::checked());
+        walk_method(self, node);
+        self.leave();
+    }
+
+    fn visit_property_hook(&mut self, node: &PropertyHook) {
+        self.enter(Scope::checked());
+
+        // Set hooks without a parameter list receive the value as `$value`.
+        if matches!(node.kind, PropertyHookKind::Set(_)) && node.parameters.is_none() {
+            self.flow()
+                .variables
+                .insert(b"$value".into(), Definedness::Defined);
+        }
+
+        walk_property_hook(self, node);
+        self.leave();
+    }
+
+    fn visit_closure_expression(&mut self, node: &ClosureExpression) {
+        // Captured variables are read when the closure is created, unless captured by reference.
+        if let Some(uses) = &node.uses {
+            for variable in uses.variables.iter() {
+                if variable.ampersand.is_some() {
+                    self.define(&variable.variable);
+                } else {
+                    self.read(&variable.variable);
+                }
+            }
+        }
+
+        self.enter(Scope::checked());
+        walk_closure_expression(self, node);
+        self.leave();
+    }
+
+    fn visit_closure_use_variable(&mut self, node: &ClosureUseVariable) {
+        self.define(&node.variable);
+    }
+
+    fn visit_arrow_function_expression(&mut self, node: &ArrowFunctionExpression) {
+        // Arrow functions capture the entire parent scope by value.
+        let parent = self.scope();
+        let scope = Scope::new(parent.checked, parent.flow.clone());
+
+        self.enter(scope);
+        walk_arrow_function_expression(self, node);
+
+        let scope = self.scopes.pop().unwrap();
+        let parent = self.scope();
+
+        parent.diagnostics.extend(scope.diagnostics);
+
+        if parent.dynamic.is_none() {
+            parent.dynamic = scope.dynamic;
+        }
+    }
+
+    fn visit_function_parameter(&mut self, node: &FunctionParameter) {
+        if let Some(default) = &node.default {
+            self.visit_expression(default);
+        }
+
+        self.define(&node.name);
+    }
+
+    fn visit_method_parameter(&mut self, node: &MethodParameter) {
+        if let Some(default) = &node.default {
+            self.visit_expression(default);
+        }
+
+        self.define(&node.name);
+    }
+
+    fn visit_class_statement(&mut self, node: &ClassStatement) {
+        self.unchecked(|visitor| walk_class_statement(visitor, node));
+    }
+
+    fn visit_anonymous_class_body(&mut self, node: &AnonymousClassBody) {
+        self.unchecked(|visitor| walk_anonymous_class_body(visitor, node));
+    }
+
+    fn visit_trait_statement(&mut self, node: &TraitStatement) {
+        self.unchecked(|visitor| walk_trait_statement(visitor, node));
+    }
+
+    fn visit_interface_statement(&mut self, node: &InterfaceStatement) {
+        self.unchecked(|visitor| walk_interface_statement(visitor, node));
+    }
+
+    fn visit_unit_enum_statement(&mut self, node: &UnitEnumStatement) {
+        self.unchecked(|visitor| walk_unit_enum_statement(visitor, node));
+    }
+
+    fn visit_backed_enum_statement(&mut self, node: &BackedEnumStatement) {
+        self.unchecked(|visitor| walk_backed_enum_statement(visitor, node));
+    }
+
+    fn visit_simple_variable(&mut self, node: &SimpleVariable) {
+        self.read(node);
+    }
+
+    fn visit_global_statement(&mut self, node: &GlobalStatement) {
+        for variable in node.variables.iter() {
+            match variable {
+                Variable::SimpleVariable(variable) => self.define(variable),
+                _ => self.visit_variable(variable),
+            }
+        }
+    }
+
+    fn visit_static_var(&mut self, node: &StaticVar) {
+        if let Some(default) = &node.default {
+            self.visit_expression(default);
+        }
+
+        match &node.var {
+            Variable::SimpleVariable(variable) => self.define(variable),
+            variable => self.visit_variable(variable),
+        }
+    }
+
+    fn visit_static_property_fetch_expression(&mut self, node: &StaticPropertyFetchExpression) {
+        self.visit_expression(&node.target);
+
+        // The property name in `Foo::$bar` isn't a local variable.
+        if !matches!(node.property, Variable::SimpleVariable(_)) {
+            self.visit_variable(&node.property);
+        }
+    }
+
+    fn visit_assignment_operation_expression(&mut self, node: &AssignmentOperationExpression) {
+        match node.kind {
+            AssignmentOperationKind::Assign(_) => {}
+            AssignmentOperationKind::Coalesce(_) => {
+                self.silently(|visitor| visitor.visit_expression(&node.left))
+            }
+            _ => self.visit_expression(&node.left),
+        }
+
+        self.visit_expression(&node.right);
+        self.assign(&node.left);
+    }
+
+    fn visit_reference_expression(&mut self, node: &ReferenceExpression) {
+        self.assign(&node.right);
+    }
+
+    fn visit_array_item_referenced_value(&mut self, node: &ArrayItemReferencedValue) {
+        self.assign(&node.value);
+    }
+
+    fn visit_array_item_referenced_key_value(&mut self, node: &ArrayItemReferencedKeyValue) {
+        self.visit_expression(&node.key);
+        self.assign(&node.value);
+    }
+
+    fn visit_isset_expression(&mut self, node: &IssetExpression) {
+        self.silently(|visitor| visitor.visit_argument_list(&node.arguments));
+    }
+
+    fn visit_empty_expression(&mut self, node: &EmptyExpression) {
+        self.silently(|visitor| visitor.visit_single_argument(&node.argument));
+    }
+
+    fn visit_unset_expression(&mut self, node: &UnsetExpression) {
+        for argument in node.arguments.arguments.iter() {
+            let value = match argument {
+                Argument::Positional(argument) => &argument.value,
+                Argument::Named(argument) => &argument.value,
+            };
+
+            match &value.kind {
+                ExpressionKind::Variable(variable) if variable.is_simple() => {
+                    self.flow().variables.remove(&variable.to_simple().symbol);
+                }
+                _ => self.silently(|visitor| visitor.visit_expression(value)),
+            }
+        }
+    }
+
+    fn visit_coalesce_expression(&mut self, node: &CoalesceExpression) {
+        self.silently(|visitor| visitor.visit_expression(&node.lhs));
+        self.visit_conditionally(&node.rhs);
+    }
+
+    fn visit_ternary_expression(&mut self, node: &TernaryExpression) {
+        self.visit_expression(&node.condition);
+        self.visit_branches([node.then.as_ref(), node.r#else.as_ref()]);
+    }
+
+    fn visit_short_ternary_expression(&mut self, node: &ShortTernaryExpression) {
+        self.visit_expression(&node.condition);
+        self.visit_conditionally(&node.r#else);
+    }
+
+    fn visit_logical_operation_expression(&mut self, node: &LogicalOperationExpression) {
+        match &node.kind {
+            LogicalOperationKind::And { left, right, .. }
+            | LogicalOperationKind::Or { left, right, .. }
+            | LogicalOperationKind::LogicalAnd { left, right, .. }
+            | LogicalOperationKind::LogicalOr { left, right, .. } => {
+                self.visit_expression(left);
+                self.visit_conditionally(right);
+            }
+            LogicalOperationKind::LogicalXor { left, right, .. } => {
+                self.visit_expression(left);
+                self.visit_expression(right);
+            }
+            LogicalOperationKind::Not { right, .. } => self.visit_expression(right),
+        }
+    }
+
+    fn visit_match_expression(&mut self, node: &MatchExpression) {
+        self.visit_expression(&node.condition);
+
+        let base = self.fork();
+        let mut outcomes = Vec::new();
+
+        for arm in node.arms.iter() {
+            self.replace_flow(base.clone());
+
+            for condition in arm.conditions.iter() {
+                self.visit_expression(condition);
+            }
+
+            self.visit_expression(&arm.body);
+            outcomes.push(self.fork());
+        }
+
+        if let Some(default) = &node.default {
+            self.replace_flow(base);
+            self.visit_expression(&default.body);
+            outcomes.push(self.fork());
+        }
+
+        // Without any arms, an `UnhandledMatchError` is always thrown.
+        self.replace_flow(Flow::merge(outcomes));
+    }
+
+    fn visit_function_call_expression(&mut self, node: &FunctionCallExpression) {
+        let function = match &node.target.kind {
+            ExpressionKind::Name(name) => name.symbol().as_bytestr().after_last(b'\\'),
+            _ => {
+                self.visit_expression(&node.target);
+
+                b"".into()
+            }
+        };
+
+        if function.eq_ignore_ascii_case(b"compact") {
+            for argument in node.arguments.arguments.iter() {
+                match argument {
+                    Argument::Positional(argument) if argument.ellipsis.is_none() => {
+                        self.visit_compact_argument(&argument.value)
+                    }
+                    _ => self.visit_argument(argument),
+                }
+            }
+
+            return;
+        }
+
+        let signature = self.resolve_function(&node.target);
+
+        if !function.eq_ignore_ascii_case(b"extract") {
+            self.visit_call_arguments(&node.arguments, signature);
+
+            return;
+        }
+
+        // The array is only read, even though `extract()` accepts it by reference.
+        for argument in node.arguments.arguments.iter() {
+            self.visit_argument(argument);
+        }
+
+        match self.extracted_variables(&node.arguments) {
+            Some(variables) => {
+                for (name, definedness) in variables {
+                    let mut symbol = ByteString::from(b"$");
+                    symbol.extend(&name);
+
+                    self.define_named(symbol, definedness);
+                }
+            }
+            None => self.mark_dynamic(b"extract()", node.span),
+        }
+    }
+
+    fn visit_method_call_expression(&mut self, node: &MethodCallExpression) {
+        self.visit_expression(&node.target);
+        self.visit_expression(&node.method);
+        self.visit_call_arguments(&node.arguments, None);
+    }
+
+    fn visit_nullsafe_method_call_expression(&mut self, node: &NullsafeMethodCallExpression) {
+        self.visit_expression(&node.target);
+        self.visit_expression(&node.method);
+        self.visit_call_arguments(&node.arguments, None);
+    }
+
+    fn visit_static_method_call_expression(&mut self, node: &StaticMethodCallExpression) {
+        self.visit_expression(&node.target);
+        self.visit_call_arguments(&node.arguments, None);
+    }
+
+    fn visit_static_variable_method_call_expression(
+        &mut self,
+        node: &StaticVariableMethodCallExpression,
+    ) {
+        self.visit_expression(&node.target);
+        self.visit_variable(&node.method);
+        self.visit_call_arguments(&node.arguments, None);
+    }
+
+    fn visit_new_expression(&mut self, node: &NewExpression) {
+        self.visit_expression(&node.target);
+
+        if let Some(arguments) = &node.arguments {
+            self.visit_call_arguments(arguments, None);
+        }
+    }
+
+    fn visit_include_expression(&mut self, node: &IncludeExpression) {
+        self.mark_dynamic(b"include", node.span);
+        walk_include_expression(self, node);
+    }
+
+    fn visit_include_once_expression(&mut self, node: &IncludeOnceExpression) {
+        self.mark_dynamic(b"include_once", node.span);
+        walk_include_once_expression(self, node);
+    }
+
+    fn visit_require_expression(&mut self, node: &RequireExpression) {
+        self.mark_dynamic(b"require", node.span);
+        walk_require_expression(self, node);
+    }
+
+    fn visit_require_once_expression(&mut self, node: &RequireOnceExpression) {
+        self.mark_dynamic(b"require_once", node.span);
+        walk_require_once_expression(self, node);
+    }
+
+    fn visit_eval_expression(&mut self, node: &EvalExpression) {
+        self.mark_dynamic(b"eval()", node.span);
+        walk_eval_expression(self, node);
+    }
+
+    fn visit_goto_statement(&mut self, node: &GotoStatement) {
+        self.mark_dynamic(b"goto", node.span);
+        self.terminate();
+    }
+
+    fn visit_throw_expression(&mut self, node: &ThrowExpression) {
+        walk_throw_expression(self, node);
+        self.terminate();
+    }
+
+    fn visit_exit_expression(&mut self, node: &ExitExpression) {
+        walk_exit_expression(self, node);
+        self.terminate();
+    }
+
+    fn visit_die_expression(&mut self, node: &DieExpression) {
+        walk_die_expression(self, node);
+        self.terminate();
+    }
+
+    fn visit_return_statement(&mut self, node: &ReturnStatement) {
+        walk_return_statement(self, node);
+        self.terminate();
+    }
+
+    fn visit_break_statement(&mut self, node: &BreakStatement) {
+        self.exit(node.level.as_ref());
+    }
+
+    fn visit_continue_statement(&mut self, node: &ContinueStatement) {
+        self.exit(node.level.as_ref());
+    }
+
+    fn visit_if_statement(&mut self, node: &IfStatement) {
+        self.visit_expression(&node.condition);
+
+        let mut outcomes = Vec::new();
+        let otherwise = self.fork();
+
+        match &node.body {
+            IfStatementBody::Statement(body) => {
+                self.visit_statement(&body.statement);
+                outcomes.push(self.replace_flow(otherwise));
+
+                for elseif in body.elseifs.iter() {
+                    self.visit_expression(&elseif.condition);
+
+                    let otherwise = self.fork();
+
+                    self.visit_statement(&elseif.statement);
+                    outcomes.push(self.replace_flow(otherwise));
+                }
+
+                if let Some(r#else) = &body.r#else {
+                    self.visit_statement(&r#else.statement);
+                }
+            }
+            IfStatementBody::Block(body) => {
+                self.visit_body(&body.statements);
+                outcomes.push(self.replace_flow(otherwise));
+
+                for elseif in body.elseifs.iter() {
+                    self.visit_expression(&elseif.condition);
+
+                    let otherwise = self.fork();
+
+                    self.visit_body(&elseif.statements);
+                    outcomes.push(self.replace_flow(otherwise));
+                }
+
+                if let Some(r#else) = &body.r#else {
+                    self.visit_body(&r#else.statements);
+                }
+            }
+        }
+
+        outcomes.push(self.fork());
+        self.replace_flow(Flow::merge(outcomes));
+    }
+
+    fn visit_while_statement(&mut self, node: &WhileStatement) {
+        self.enter_loop(|assigned| assigned.visit_while_statement(node));
+        self.visit_expression(&node.condition);
+
+        let skipped = self.fork();
+
+        match &node.body {
+            WhileStatementBody::Statement(body) => self.visit_statement(&body.statement),
+            WhileStatementBody::Block(body) => self.visit_body(&body.statements),
+        }
+
+        let mut outcomes = self.leave_loop();
+
+        // A `while (true)` loop can only be left with `break`.
+        if !is_true(&node.condition) {
+            outcomes.push(self.fork());
+            outcomes.push(skipped);
+        }
+
+        self.replace_flow(Flow::merge(outcomes));
+    }
+
+    fn visit_do_while_statement(&mut self, node: &DoWhileStatement) {
+        self.enter_loop(|assigned| assigned.visit_do_while_statement(node));
+        self.visit_statement(&node.body);
+        self.visit_expression(&node.condition);
+
+        let mut outcomes = self.leave_loop();
+
+        if !is_true(&node.condition) {
+            outcomes.push(self.fork());
+        }
+
+        self.replace_flow(Flow::merge(outcomes));
+    }
+
+    fn visit_for_statement(&mut self, node: &ForStatement) {
+        for initialization in node.iterator.initializations.iter() {
+            self.visit_expression(initialization);
+        }
+
+        self.enter_loop(|assigned| assigned.visit_for_statement(node));
+
+        for condition in node.iterator.conditions.iter() {
+            self.visit_expression(condition);
+        }
+
+        let skipped = self.fork();
+
+        match &node.body {
+            ForStatementBody::Statement(body) => self.visit_statement(&body.statement),
+            ForStatementBody::Block(body) => self.visit_body(&body.statements),
+        }
+
+        for r#loop in node.iterator.r#loop.iter() {
+            self.visit_expression(r#loop);
+        }
+
+        let mut outcomes = self.leave_loop();
+
+        // A `for` loop without any conditions can only be left with `break`.
+        if !node.iterator.conditions.is_empty() {
+            outcomes.push(self.fork());
+            outcomes.push(skipped);
+        }
+
+        self.replace_flow(Flow::merge(outcomes));
+    }
+
+    fn visit_foreach_statement(&mut self, node: &ForeachStatement) {
+        let (expression, key, value) = match &node.iterator {
+            ForeachStatementIterator::Value(iterator) => {
+                (&iterator.expression, None, &iterator.value)
+            }
+            ForeachStatementIterator::KeyAndValue(iterator) => {
+                (&iterator.expression, Some(&iterator.key), &iterator.value)
+            }
+        };
+
+        self.visit_expression(expression);
+        self.enter_loop(|assigned| assigned.visit_foreach_statement(node));
+
+        let skipped = self.fork();
+
+        if let Some(key) = key {
+            self.assign(key);
+        }
+
+        self.assign(value);
+
+        match &node.body {
+            ForeachStatementBody::Statement(body) => self.visit_statement(&body.statement),
+            ForeachStatementBody::Block(body) => self.visit_body(&body.statements),
+        }
+
+        let mut outcomes = self.leave_loop();
+
+        outcomes.push(self.fork());
+        outcomes.push(skipped);
+
+        self.replace_flow(Flow::merge(outcomes));
+    }
+
+    fn visit_switch_statement(&mut self, node: &SwitchStatement) {
+        self.visit_expression(&node.condition);
+
+        let base = self.fork();
+        let mut fallthrough: Option<Flow> = None;
+
+        self.scope().exits.push(Vec::new());
+
+        for case in node.cases.iter() {
+            let entry = match fallthrough.take() {
+                Some(previous) => Flow::merge(vec![base.clone(), previous]),
+                None => base.clone(),
+            };
+
+            self.replace_flow(entry);
+
+            if let Some(condition) = &case.condition {
+                self.visit_expression(condition);
+            }
+
+            self.visit_body(&case.body);
+            fallthrough = Some(self.fork());
+        }
+
+        let mut outcomes = self.leave_loop();
+
+        outcomes.extend(fallthrough);
+
+        if !node.cases.iter().any(|case| case.condition.is_none()) {
+            outcomes.push(base);
+        }
+
+        self.replace_flow(Flow::merge(outcomes));
+    }
+
+    fn visit_try_statement(&mut self, node: &TryStatement) {
+        let mut assigned = AssignedVariables::default();
+        assigned.visit(&node.body);
+
+        // An exception could be thrown at any point in the `try` block.
+        let mut caught = self.fork();
+        caught.assume(assigned.0);
+
+        self.visit_body(&node.body);
+
+        let mut outcomes = vec![self.fork()];
+
+        for catch in node.catches.iter() {
+            self.replace_flow(caught.clone());
+
+            if let Some(variable) = &catch.var {
+                self.define(variable);
+            }
+
+            self.visit_body(&catch.body);
+            outcomes.push(self.fork());
+        }
+
+        let mut after = Flow::merge(outcomes.clone());
+
+        if let Some(finally) = &node.finally {
+            outcomes.push(caught);
+
+            let mut entry = Flow::merge(outcomes);
+            entry.unreachable = false;
+
+            self.replace_flow(entry);
+            self.visit_body(&finally.body);
+
+            let finished = self.fork();
+
+            for (name, definedness) in finished.variables {
+                if definedness == Definedness::Defined {
+                    after.variables.insert(name, definedness);
+                }
+            }
+
+            after.unreachable |= finished.unreachable;
+        }
+
+        self.replace_flow(after);
+    }
+}
+
+fn is_true(expression: &Expression) -> bool {
+    match &expression.kind {
+        ExpressionKind::Bool(value) => value.value.symbol.eq_ignore_ascii_case(b"true"),
+        ExpressionKind::Parenthesized(inner) => is_true(&inner.expr),
+        _ => false,
+    }
+}
+
+/// Collects the variables that might be assigned somewhere inside of a statement, without
+/// looking inside of nested functions or classes.
+#[derive(Debug, Default)]
+struct AssignedVariables(HashSet<ByteString>);
+
+impl AssignedVariables {
+    fn target(&mut self, target: &Expression) {
+        match &target.kind {
+            ExpressionKind::Variable(variable) if variable.is_simple() => {
+                self.0.insert(variable.to_simple().symbol.clone());
+            }
+            ExpressionKind::ArrayIndex(index) => self.target(&index.array),
+            ExpressionKind::Parenthesized(inner) => self.target(&inner.expr),
+            ExpressionKind::Array(array) => {
+                for item in array.items.iter() {
+                    match item {
+                        ArrayItem::Value(ArrayItemValue { value, .. })
+                        | ArrayItem::ReferencedValue(ArrayItemReferencedValue { value, .. })
+                        | ArrayItem::KeyValue(ArrayItemKeyValue { value, .. })
+                        | ArrayItem::ReferencedKeyValue(ArrayItemReferencedKeyValue {
+                            value,
+                            ..
+                        }) => self.target(value),
+                        _ => {}
+                    }
+                }
+            }
+            ExpressionKind::List(list) => {
+                for entry in list.items.iter() {
+                    match entry {
+                        ListEntry::Value(ListEntryValue { value, .. })
+                        | ListEntry::KeyValue(ListEntryKeyValue { value, .. }) => {
+                            self.target(value)
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn insert(&mut self, variable: &SimpleVariable) {
+        self.0.insert(variable.symbol.clone());
+    }
+}
+
+impl Visitor for AssignedVariables {
+    fn visit_assignment_operation_expression(&mut self, node: &AssignmentOperationExpression) {
+        self.target(&node.left);
+        self.visit_expression(&node.right);
+    }
+
+    fn visit_reference_expression(&mut self, node: &ReferenceExpression) {
+        self.target(&node.right);
+    }
+
+    fn visit_array_item_referenced_value(&mut self, node: &ArrayItemReferencedValue) {
+        self.target(&node.value);
+    }
+
+    fn visit_foreach_statement(&mut self, node: &ForeachStatement) {
+        match &node.iterator {
+            ForeachStatementIterator::Value(iterator) => self.target(&iterator.value),
+            ForeachStatementIterator::KeyAndValue(iterator) => {
+                self.target(&iterator.key);
+                self.target(&iterator.value);
+            }
+        }
+
+        pxp_ast::visitor::walk_foreach_statement(self, node);
+    }
+
+    fn visit_catch_block(&mut self, node: &CatchBlock) {
+        if let Some(variable) = &node.var {
+            self.insert(variable);
+        }
+
+        self.visit(&node.body);
+    }
+
+    fn visit_global_statement(&mut self, node: &GlobalStatement) {
+        for variable in node.variables.iter() {
+            if let Variable::SimpleVariable(variable) = variable {
+                self.insert(variable);
+            }
+        }
+    }
+
+    fn visit_static_var(&mut self, node: &StaticVar) {
+        if let Variable::SimpleVariable(variable) = &node.var {
+            self.insert(variable);
+        }
+    }
+
+    fn visit_positional_argument(&mut self, node: &PositionalArgument) {
+        // Any plain variable argument could be passed by reference.
+        self.target(&node.value);
+        self.visit_expression(&node.value);
+    }
+
+    fn visit_named_argument(&mut self, node: &NamedArgument) {
+        self.target(&node.value);
+        self.visit_expression(&node.value);
+    }
+
+    fn visit_closure_expression(&mut self, node: &ClosureExpression) {
+        let Some(uses) = &node.uses else {
+            return;
+        };
+
+        for variable in uses.variables.iter() {
+            if variable.ampersand.is_some() {
+                self.insert(&variable.variable);
+            }
+        }
+    }
+
+    fn visit_arrow_function_expression(&mut self, _: &ArrowFunctionExpression) {}
+
+    fn visit_function_statement(&mut self, _: &FunctionStatement) {}
+
+    fn visit_class_statement(&mut self, _: &ClassStatement) {}
+
+    fn visit_anonymous_class_body(&mut self, _: &AnonymousClassBody) {}
+
+    fn visit_trait_statement(&mut self, _: &TraitStatement) {}
+
+    fn visit_interface_statement(&mut self, _: &InterfaceStatement) {}
+
+    fn visit_unit_enum_statement(&mut self, _: &UnitEnumStatement) {}
+
+    fn visit_backed_enum_statement(&mut self, _: &BackedEnumStatement) {}
+}
@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+use pxp_ast::Statement;
+use pxp_diagnostics::{Diagnostic, DiagnosticKind, Severity};
+
+use crate::{AnalyserContext, AnalyserDiagnostic, Pass};
+
+/// The overrides of the strict profile, on top of the severities that diagnostics are reported with.
+const STRICT: [(&str, Severity); 8] = [
+    ("A002", Severity::Error),
+    ("A009", Severity::Error),
+    ("A011", Severity::Error),
+    ("A012", Severity::Warning),
+    ("A013", Severity::Warning),
+    ("A023", Severity::Error),
+    ("A031", Severity::Error),
+    ("A032", Severity::Error),
+];
+
+/// A named bundle of severity overrides, which decides which diagnostics are reported and how
+/// severe they are.
+///
+/// Overrides are keyed by diagnostic code, e.g. `A011`, or by rule name for diagnostics reported
+/// by a [`Rule`](crate::Rule). A code that's turned off isn't reported at all, and a pass that can
+/// only report codes that are turned off isn't run.
+///
+/// ```
+/// use pxp_analyser::AnalysisProfile;
+/// use pxp_diagnostics::Severity;
+///
+/// let profile = AnalysisProfile::strict()
+///     .with("A011", Severity::Error)
+///     .without("A016");
+///
+/// assert_eq!(profile.severity("A011", Severity::Warning), Some(Severity::Error));
+/// assert_eq!(profile.severity("A016", Severity::Warning), None);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnalysisProfile {
+    name: String,
+    // `None` turns the code off.
+    overrides: HashMap<String, Option<Severity>>,
+    // Whether codes without an override are reported at all.
+    enabled: bool,
+}
+
+impl AnalysisProfile {
+    /// A profile that reports every diagnostic with the severity that it was reported with.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            overrides: HashMap::new(),
+            enabled: true,
+        }
+    }
+
+    /// Doesn't report anything unless it's turned on explicitly, which leaves only parse errors.
+    pub fn minimal() -> Self {
+        Self {
+            enabled: false,
+            ..Self::new("minimal")
+        }
+    }
+
+    /// Reports every diagnostic, treating anything that's likely to be a bug as an error.
+    pub fn strict() -> Self {
+        STRICT
+            .iter()
+            .fold(Self::new("strict"), |profile, (code, severity)| {
+                profile.with(*code, *severity)
+            })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Report diagnostics with the given code using the given severity.
+    pub fn with(mut self, code: impl Into<String>, severity: Severity) -> Self {
+        self.overrides.insert(code.into(), Some(severity));
+        self
+    }
+
+    /// Stop reporting diagnostics with the given code.
+    pub fn without(mut self, code: impl Into<String>) -> Self {
+        self.overrides.insert(code.into(), None);
+        self
+    }
+
+    pub fn is_enabled(&self, code: &str) -> bool {
+        match self.overrides.get(code) {
+            Some(severity) => severity.is_some(),
+            None => self.enabled,
+        }
+    }
+
+    /// The severity that a diagnostic with the given code and severity should be reported with,
+    /// or `None` if it shouldn't be reported.
+    pub fn severity(&self, code: &str, severity: Severity) -> Option<Severity> {
+        match self.overrides.get(code) {
+            Some(severity) => *severity,
+            None if self.enabled => Some(severity),
+            None => None,
+        }
+    }
+
+    /// Apply the profile to a set of diagnostics, dropping the ones that are turned off and
+    /// rewriting the severity of the rest.
+    pub fn apply<K: DiagnosticKind>(&self, diagnostics: Vec<Diagnostic<K>>) -> Vec<Diagnostic<K>> {
+        diagnostics
+            .into_iter()
+            .filter_map(|mut diagnostic| {
+                diagnostic.severity =
+                    self.severity(&diagnostic.kind.get_code(), diagnostic.severity)?;
+
+                Some(diagnostic)
+            })
+            .collect()
+    }
+
+    /// Apply the profile's overrides to the diagnostics that were reported by the parser.
+    ///
+    /// Only codes with an explicit override are affected, so a profile that doesn't report
+    /// anything by default doesn't hide parse errors. Errors are always reported, since the code
+    /// couldn't be parsed properly.
+    pub fn apply_to_parser<K: DiagnosticKind>(
+        &self,
+        diagnostics: Vec<Diagnostic<K>>,
+    ) -> Vec<Diagnostic<K>> {
+        diagnostics
+            .into_iter()
+            .filter_map(|mut diagnostic| {
+                if diagnostic.severity.is_error() {
+                    return Some(diagnostic);
+                }
+
+                if let Some(severity) = self.overrides.get(&diagnostic.kind.get_code()) {
+                    diagnostic.severity = (*severity)?;
+                }
+
+                Some(diagnostic)
+            })
+            .collect()
+    }
+
+    /// Run the given passes over an AST, skipping any pass that can only report diagnostics that
+    /// are turned off, and apply the profile to the diagnostics that they report.
+    ///
+    /// Diagnostics inside of branches that can't run in the context's
+    /// [`TargetEnvironment`](crate::TargetEnvironment) aren't reported.
+    pub fn analyse(
+        &self,
+        passes: &mut [Box<dyn Pass>],
+        context: &AnalyserContext,
+        ast: &[Statement],
+    ) -> Vec<Diagnostic<AnalyserDiagnostic>> {
+        let dead = context
+            .target()
+            .map(|target| target.dead_branches(ast))
+            .unwrap_or_default();
+
+        let mut diagnostics = Vec::new();
+
+        for pass in passes.iter_mut() {
+            if !pass.codes().iter().any(|code| self.is_enabled(code)) {
+                continue;
+            }
+
+            diagnostics.extend(
+                self.apply(pass.run(context, ast))
+                    .into_iter()
+                    .filter(|diagnostic| !dead.contains(diagnostic.span)),
+            );
+        }
+
+        diagnostics
+    }
+}
+
+/// Reports every diagnostic with the severity that it was reported with.
+impl Default for AnalysisProfile {
+    fn default() -> Self {
+        Self::new("default")
+    }
+}
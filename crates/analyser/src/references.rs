@@ -0,0 +1,783 @@
+use std::collections::HashMap;
+
+use pxp_ast::{
+    visitor::{
+        walk_anonymous_class_expression, walk_backed_enum_statement, walk_braced_namespace,
+        walk_class_statement, walk_classish_constant, walk_constant_entry,
+        walk_constant_fetch_expression, walk_doc_block, walk_expression,
+        walk_function_call_expression, walk_function_closure_creation_expression,
+        walk_function_statement, walk_hooked_property, walk_instanceof_expression,
+        walk_interface_statement, walk_method, walk_method_call_expression,
+        walk_method_closure_creation_expression, walk_name, walk_new_expression,
+        walk_nullsafe_method_call_expression, walk_nullsafe_property_fetch_expression,
+        walk_property_fetch_expression, walk_simple_property, walk_statement,
+        walk_static_method_call_expression, walk_static_method_closure_creation_expression,
+        walk_static_property_fetch_expression, walk_trait_statement, walk_unbraced_namespace,
+        walk_unit_enum_statement, walk_use, Visitor,
+    },
+    *,
+};
+use pxp_bytestring::{ByteStr, ByteString};
+use pxp_index::{AccessContext, FileId, Index, MemberKind};
+use pxp_inference::TypeMap;
+use pxp_span::Span;
+use pxp_type::{ConstExpr, Type};
+
+/// A declaration to find the references to. Class-like names are fully qualified, without a
+/// leading `\`, and property names don't include the leading `$`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SymbolRef {
+    Class(ByteString),
+    Function(ByteString),
+    Method { class: ByteString, name: ByteString },
+    Property { class: ByteString, name: ByteString },
+    Constant { class: ByteString, name: ByteString },
+    EnumCase { class: ByteString, name: ByteString },
+}
+
+/// A file that has been parsed and indexed, to be searched by [`find_references`].
+#[derive(Debug, Clone, Copy)]
+pub struct ParsedFile<'a> {
+    pub file: FileId,
+    pub ast: &'a [Statement],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferenceKind {
+    /// `new Foo`
+    Instantiation,
+    /// `Foo::bar()`, or `Foo::bar(...)`
+    StaticCall,
+    /// `$foo->bar()`, or `$foo->bar(...)`
+    InstanceCall,
+    /// `Foo::$bar`, `Foo::BAR` or `Foo::Bar`
+    StaticAccess,
+    /// `$foo->bar`
+    InstanceAccess,
+    /// `foo()`, or `foo(...)`
+    FunctionCall,
+    /// A parameter, return, property or constant type.
+    TypeHint,
+    /// A type in a docblock tag.
+    Docblock,
+    /// `use Foo;`
+    Import,
+    /// `Foo::class`
+    ClassString,
+    /// Any other mention of a class-like's name, such as `extends`, `implements`, `instanceof`,
+    /// `catch` or an attribute.
+    ClassName,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reference {
+    pub file: FileId,
+    pub span: Span,
+    pub kind: ReferenceKind,
+    /// Whether the reference is to a member with the right name on a receiver whose type couldn't
+    /// be inferred, and so might be a reference to a member of some other class-like.
+    pub possible: bool,
+}
+
+/// Find every reference to the given declaration in a set of files.
+///
+/// The `TypeMap` for each file is expected at the same position in `type_maps`, and is used to
+/// find the type of the receiver when accessing instance members, so that same-named members of
+/// unrelated class-likes can be told apart. A member reference matches when the member that the receiver
+/// resolves to is declared by the target class-like, so calls to an inherited method are
+/// references to the declaring class-like's method, and members imported from a trait are only
+/// references to the trait's member.
+///
+/// Docblock references are only found through the types in docblock tags.
+pub fn find_references(
+    target: SymbolRef,
+    files: &[ParsedFile],
+    index: &Index,
+    type_maps: &[TypeMap],
+) -> Vec<Reference> {
+    let mut references = Vec::new();
+
+    for (file, types) in files.iter().zip(type_maps.iter()) {
+        let mut visitor = ReferenceVisitor {
+            target: &target,
+            file: file.file,
+            index,
+            types,
+            namespace: None,
+            classes: Vec::new(),
+            usages: HashMap::new(),
+            docblock: false,
+            references: Vec::new(),
+        };
+
+        visitor.visit(file.ast);
+        references.append(&mut visitor.references);
+    }
+
+    references
+}
+
+/// How a name is being used, which is recorded by its parent node before the name is visited.
+#[derive(Debug, Clone, Copy)]
+enum Usage {
+    Class(ReferenceKind),
+    Function(ReferenceKind),
+    Ignored,
+}
+
+/// Whether a receiver has a member that's declared by the target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Match {
+    None,
+    Possible,
+    Definite,
+}
+
+struct ReferenceVisitor<'a> {
+    target: &'a SymbolRef,
+    file: FileId,
+    index: &'a Index,
+    types: &'a TypeMap,
+    namespace: Option<ByteString>,
+    classes: Vec<Option<ByteString>>,
+    usages: HashMap<NodeId, Usage>,
+    docblock: bool,
+    references: Vec<Reference>,
+}
+
+impl<'a> ReferenceVisitor<'a> {
+    fn push(&mut self, span: Span, kind: ReferenceKind, possible: bool) {
+        self.references.push(Reference {
+            file: self.file,
+            span,
+            kind,
+            possible,
+        });
+    }
+
+    fn with_class(&mut self, name: Option<&Name>, f: impl FnOnce(&mut Self)) {
+        self.classes.push(
+            name.and_then(|name| name.as_resolved())
+                .map(|name| name.resolved.clone()),
+        );
+        f(self);
+        self.classes.pop();
+    }
+
+    fn context(&self) -> AccessContext {
+        match self.classes.last() {
+            Some(Some(class)) => AccessContext::Class(class.clone()),
+            _ => AccessContext::Global,
+        }
+    }
+
+    fn mark(&mut self, expression: &Expression, usage: Usage) {
+        if let ExpressionKind::Name(name) = &expression.kind {
+            self.usages.insert(name.id, usage);
+        }
+    }
+
+    fn docblock(&mut self, comments: &CommentGroup) {
+        if let Some(docblock) = comments.docblock() {
+            self.docblock = true;
+            self.visit_doc_block(docblock);
+            self.docblock = false;
+        }
+    }
+
+    fn is_target_class(&self, name: &ByteStr) -> bool {
+        match self.target {
+            SymbolRef::Class(class) => same_name(name, class.as_ref()),
+            _ => false,
+        }
+    }
+
+    fn is_target_function(&self, name: &Name) -> bool {
+        let SymbolRef::Function(function) = self.target else {
+            return false;
+        };
+
+        let function = function.as_bytestr();
+
+        match &name.kind {
+            NameKind::Resolved(name) => same_name(name.resolved.as_ref(), function),
+            // Unqualified function calls only fall back to the global function when there isn't
+            // one in the current namespace.
+            NameKind::Unresolved(name) => {
+                if let Some(namespace) = &self.namespace {
+                    let mut qualified = namespace.clone();
+                    qualified.extend_with_bytes(b"\\");
+                    qualified.extend(&name.symbol);
+
+                    if self.index.get_function(qualified.clone()).is_some() {
+                        return same_name(qualified.as_ref(), function);
+                    }
+                }
+
+                same_name(name.symbol.as_ref(), function)
+            }
+            NameKind::Special(_) => false,
+        }
+    }
+
+    fn mentions_target(&self, r#type: &Type<ResolvedName>) -> bool {
+        match r#type {
+            Type::Named(name) => self.is_target_class(name.resolved.as_ref()),
+            Type::Generic(base, arguments) => {
+                self.mentions_target(base)
+                    || arguments
+                        .iter()
+                        .any(|argument| self.mentions_target(&argument.r#type))
+            }
+            Type::Nullable(inner) => self.mentions_target(inner),
+            Type::Union(types) | Type::Intersection(types) => {
+                types.iter().any(|r#type| self.mentions_target(r#type))
+            }
+            Type::TypedArray(key, value) => {
+                self.mentions_target(key) || self.mentions_target(value)
+            }
+            Type::CallableSignature(callable, parameters, r#return) => {
+                self.mentions_target(callable)
+                    || parameters
+                        .iter()
+                        .any(|parameter| self.mentions_target(&parameter.r#type))
+                    || self.mentions_target(r#return)
+            }
+            Type::Shaped {
+                base,
+                items,
+                unsealed_type,
+                ..
+            } => {
+                self.mentions_target(base)
+                    || items
+                        .iter()
+                        .any(|item| self.mentions_target(&item.value_type))
+                    || unsealed_type.as_ref().is_some_and(|unsealed| {
+                        unsealed
+                            .key_type
+                            .as_ref()
+                            .is_some_and(|key| self.mentions_target(key))
+                            || self.mentions_target(&unsealed.value_type)
+                    })
+            }
+            Type::ConditionalForParameter {
+                target,
+                then,
+                otherwise,
+                ..
+            } => {
+                self.mentions_target(target)
+                    || self.mentions_target(then)
+                    || self.mentions_target(otherwise)
+            }
+            Type::Conditional {
+                subject,
+                target,
+                then,
+                otherwise,
+                ..
+            } => {
+                self.mentions_target(subject)
+                    || self.mentions_target(target)
+                    || self.mentions_target(then)
+                    || self.mentions_target(otherwise)
+            }
+            Type::ConstExpr(expr) => match expr.as_ref() {
+                ConstExpr::ConstFetch(target, _) => self.mentions_target(target),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// The type of the receiver of a member access.
+    fn receiver(&self, target: &Expression) -> Type<ResolvedName> {
+        match &target.kind {
+            ExpressionKind::Name(name) => match &name.kind {
+                NameKind::Resolved(name) => Type::Named(name.clone()),
+                NameKind::Special(special) => match special.kind {
+                    SpecialNameKind::Self_ => Type::SelfReference,
+                    SpecialNameKind::Static => Type::StaticReference,
+                    SpecialNameKind::Parent => Type::ParentReference,
+                },
+                NameKind::Unresolved(name) => Type::Named(ResolvedName {
+                    resolved: name.symbol.clone(),
+                    original: name.symbol.clone(),
+                }),
+            },
+            ExpressionKind::Self_(_) => Type::SelfReference,
+            ExpressionKind::Static(_) => Type::StaticReference,
+            ExpressionKind::Parent(_) => Type::ParentReference,
+            ExpressionKind::Variable(variable)
+                if variable.is_simple() && variable.to_simple().symbol == b"$this" =>
+            {
+                Type::StaticReference
+            }
+            _ => match self.types.resolve(target.id) {
+                Type::This => Type::StaticReference,
+                r#type => r#type.clone(),
+            },
+        }
+    }
+
+    /// Check whether accessing the named member on the given receiver refers to the target.
+    fn matches(&self, receiver: &Type<ResolvedName>, kind: MemberKind, name: &ByteStr) -> Match {
+        let (class, member, expected) = match self.target {
+            SymbolRef::Method { class, name } => (class, name, MemberKind::Method),
+            SymbolRef::Property { class, name } => (class, name, MemberKind::Property),
+            SymbolRef::Constant { class, name } => (class, name, MemberKind::Constant),
+            SymbolRef::EnumCase { class, name } => (class, name, MemberKind::EnumCase),
+            _ => return Match::None,
+        };
+
+        // Constants and enum cases are accessed in the same way, so the kind of the access only
+        // needs to agree with the kind of the target for methods and properties.
+        let compatible = match kind {
+            MemberKind::Constant | MemberKind::EnumCase => {
+                matches!(expected, MemberKind::Constant | MemberKind::EnumCase)
+            }
+            _ => kind == expected,
+        };
+
+        let same_name = match expected {
+            MemberKind::Method => name.eq_ignore_ascii_case(member.as_ref()),
+            _ => name == member.as_bytestr(),
+        };
+
+        if !compatible || !same_name {
+            return Match::None;
+        }
+
+        self.matches_receiver(receiver, expected, name, class.as_ref())
+    }
+
+    fn matches_receiver(
+        &self,
+        receiver: &Type<ResolvedName>,
+        kind: MemberKind,
+        name: &ByteStr,
+        class: &ByteStr,
+    ) -> Match {
+        match receiver {
+            Type::Union(types) => types
+                .iter()
+                .map(|r#type| self.matches_receiver(r#type, kind, name, class))
+                .max()
+                .unwrap_or(Match::None),
+            Type::Nullable(inner) => self.matches_receiver(inner, kind, name, class),
+            Type::Named(named) if self.index.get_class(named.resolved.clone()).is_none() => {
+                Match::Possible
+            }
+            Type::Named(_)
+            | Type::Generic(..)
+            | Type::Intersection(_)
+            | Type::SelfReference
+            | Type::StaticReference
+            | Type::ParentReference => {
+                let declared = self
+                    .index
+                    .members_of(receiver, self.context())
+                    .into_iter()
+                    .any(|candidate| {
+                        candidate.get_kind() == kind
+                            && match kind {
+                                MemberKind::Method => {
+                                    candidate.get_name().eq_ignore_ascii_case(name)
+                                }
+                                _ => candidate.get_name() == name,
+                            }
+                            && same_name(candidate.get_declaring_class(), class)
+                    });
+
+                if declared {
+                    Match::Definite
+                } else {
+                    Match::None
+                }
+            }
+            Type::Mixed | Type::NonEmptyMixed | Type::Object | Type::Missing => Match::Possible,
+            _ => Match::None,
+        }
+    }
+
+    fn member(
+        &mut self,
+        target: &Expression,
+        member: Option<&ByteStr>,
+        span: Span,
+        kind: MemberKind,
+        reference: ReferenceKind,
+    ) {
+        let Some(member) = member else {
+            return;
+        };
+
+        match self.matches(&self.receiver(target), kind, member) {
+            Match::Definite => self.push(span, reference, false),
+            Match::Possible => self.push(span, reference, true),
+            Match::None => {}
+        }
+    }
+}
+
+fn same_name(left: &ByteStr, right: &ByteStr) -> bool {
+    let left = left.strip_prefix(b'\\');
+    let right = right.strip_prefix(b'\\');
+
+    left.eq_ignore_ascii_case(right)
+}
+
+fn identifier_name(method: &Expression) -> Option<&ByteStr> {
+    match &method.kind {
+        ExpressionKind::Identifier(identifier) if identifier.is_simple() => {
+            Some(identifier.to_simple().symbol.as_ref())
+        }
+        _ => None,
+    }
+}
+
+fn property_span(property: &Variable) -> Span {
+    match property {
+        Variable::SimpleVariable(variable) => variable.span,
+        Variable::VariableVariable(variable) => variable.span,
+        Variable::BracedVariableVariable(variable) => variable.span,
+    }
+}
+
+fn property_name(property: &Variable) -> Option<&ByteStr> {
+    match property {
+        Variable::SimpleVariable(variable) => Some(variable.symbol.as_bytestr().strip_prefix(b'$')),
+        _ => None,
+    }
+}
+
+impl<'a> Visitor for ReferenceVisitor<'a> {
+    fn visit_statement(&mut self, node: &Statement) {
+        self.docblock(&node.comments);
+
+        walk_statement(self, node);
+    }
+
+    fn visit_unbraced_namespace(&mut self, node: &UnbracedNamespace) {
+        self.namespace = Some(node.name.symbol.clone());
+        walk_unbraced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_braced_namespace(&mut self, node: &BracedNamespace) {
+        self.namespace = node.name.as_ref().map(|name| name.symbol.clone());
+        walk_braced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_class_statement(&mut self, node: &ClassStatement) {
+        self.usages.insert(node.name.id, Usage::Ignored);
+        self.with_class(Some(&node.name), |visitor| {
+            walk_class_statement(visitor, node)
+        });
+    }
+
+    fn visit_interface_statement(&mut self, node: &InterfaceStatement) {
+        self.usages.insert(node.name.id, Usage::Ignored);
+        self.with_class(Some(&node.name), |visitor| {
+            walk_interface_statement(visitor, node)
+        });
+    }
+
+    fn visit_unit_enum_statement(&mut self, node: &UnitEnumStatement) {
+        self.usages.insert(node.name.id, Usage::Ignored);
+        self.with_class(Some(&node.name), |visitor| {
+            walk_unit_enum_statement(visitor, node)
+        });
+    }
+
+    fn visit_backed_enum_statement(&mut self, node: &BackedEnumStatement) {
+        self.usages.insert(node.name.id, Usage::Ignored);
+        self.with_class(Some(&node.name), |visitor| {
+            walk_backed_enum_statement(visitor, node)
+        });
+    }
+
+    fn visit_trait_statement(&mut self, node: &TraitStatement) {
+        self.usages.insert(node.name.id, Usage::Ignored);
+        self.with_class(Some(&node.name), |visitor| {
+            walk_trait_statement(visitor, node)
+        });
+    }
+
+    fn visit_anonymous_class_expression(&mut self, node: &AnonymousClassExpression) {
+        self.with_class(None, |visitor| {
+            walk_anonymous_class_expression(visitor, node)
+        });
+    }
+
+    fn visit_function_statement(&mut self, node: &FunctionStatement) {
+        self.usages.insert(node.name.id, Usage::Ignored);
+
+        walk_function_statement(self, node);
+    }
+
+    fn visit_constant_entry(&mut self, node: &ConstantEntry) {
+        self.usages.insert(node.name.id, Usage::Ignored);
+
+        walk_constant_entry(self, node);
+    }
+
+    fn visit_method(&mut self, node: &Method) {
+        self.docblock(&node.comments);
+
+        walk_method(self, node);
+    }
+
+    fn visit_classish_constant(&mut self, node: &ClassishConstant) {
+        self.docblock(&node.comments);
+
+        walk_classish_constant(self, node);
+    }
+
+    fn visit_simple_property(&mut self, node: &SimpleProperty) {
+        self.docblock(&node.comments);
+
+        walk_simple_property(self, node);
+    }
+
+    fn visit_hooked_property(&mut self, node: &HookedProperty) {
+        self.docblock(&node.comments);
+
+        walk_hooked_property(self, node);
+    }
+
+    fn visit_doc_block(&mut self, node: &DocBlock) {
+        // Docblocks are only visited through the nodes that they're attached to.
+        if self.docblock {
+            walk_doc_block(self, node);
+        }
+    }
+
+    fn visit_data_type(&mut self, node: &DataType) {
+        if !self.mentions_target(&node.kind) {
+            return;
+        }
+
+        let kind = if self.docblock {
+            ReferenceKind::Docblock
+        } else {
+            ReferenceKind::TypeHint
+        };
+
+        self.push(node.span, kind, false);
+    }
+
+    fn visit_use(&mut self, node: &Use) {
+        let usage = match node.kind {
+            UseKind::Function => Usage::Function(ReferenceKind::Import),
+            UseKind::Const => Usage::Ignored,
+            _ => Usage::Class(ReferenceKind::Import),
+        };
+
+        self.usages.insert(node.name.id, usage);
+
+        walk_use(self, node);
+    }
+
+    fn visit_expression(&mut self, node: &Expression) {
+        // Any name that hasn't been claimed by its parent is a constant.
+        if let ExpressionKind::Name(name) = &node.kind {
+            self.usages.entry(name.id).or_insert(Usage::Ignored);
+        }
+
+        walk_expression(self, node);
+    }
+
+    fn visit_name(&mut self, node: &Name) {
+        let usage = self
+            .usages
+            .get(&node.id)
+            .copied()
+            .unwrap_or(Usage::Class(ReferenceKind::ClassName));
+
+        match usage {
+            Usage::Class(kind) => {
+                if let NameKind::Resolved(name) = &node.kind {
+                    if self.is_target_class(name.resolved.as_ref()) {
+                        self.push(node.span, kind, false);
+                    }
+                }
+            }
+            Usage::Function(kind) => {
+                if self.is_target_function(node) {
+                    self.push(node.span, kind, false);
+                }
+            }
+            Usage::Ignored => {}
+        }
+
+        walk_name(self, node);
+    }
+
+    fn visit_new_expression(&mut self, node: &NewExpression) {
+        self.mark(&node.target, Usage::Class(ReferenceKind::Instantiation));
+
+        walk_new_expression(self, node);
+    }
+
+    fn visit_instanceof_expression(&mut self, node: &InstanceofExpression) {
+        self.mark(&node.right, Usage::Class(ReferenceKind::ClassName));
+
+        walk_instanceof_expression(self, node);
+    }
+
+    fn visit_function_call_expression(&mut self, node: &FunctionCallExpression) {
+        self.mark(&node.target, Usage::Function(ReferenceKind::FunctionCall));
+
+        walk_function_call_expression(self, node);
+    }
+
+    fn visit_function_closure_creation_expression(
+        &mut self,
+        node: &FunctionClosureCreationExpression,
+    ) {
+        self.mark(&node.target, Usage::Function(ReferenceKind::FunctionCall));
+
+        walk_function_closure_creation_expression(self, node);
+    }
+
+    fn visit_static_method_call_expression(&mut self, node: &StaticMethodCallExpression) {
+        self.mark(&node.target, Usage::Class(ReferenceKind::StaticCall));
+
+        if node.method.is_simple() {
+            let method = node.method.to_simple();
+            self.member(
+                &node.target,
+                Some(method.symbol.as_ref()),
+                method.span,
+                MemberKind::Method,
+                ReferenceKind::StaticCall,
+            );
+        }
+
+        walk_static_method_call_expression(self, node);
+    }
+
+    fn visit_static_method_closure_creation_expression(
+        &mut self,
+        node: &StaticMethodClosureCreationExpression,
+    ) {
+        self.mark(&node.target, Usage::Class(ReferenceKind::StaticCall));
+
+        if node.method.is_simple() {
+            let method = node.method.to_simple();
+            self.member(
+                &node.target,
+                Some(method.symbol.as_ref()),
+                method.span,
+                MemberKind::Method,
+                ReferenceKind::StaticCall,
+            );
+        }
+
+        walk_static_method_closure_creation_expression(self, node);
+    }
+
+    fn visit_static_property_fetch_expression(&mut self, node: &StaticPropertyFetchExpression) {
+        self.mark(&node.target, Usage::Class(ReferenceKind::StaticAccess));
+        self.member(
+            &node.target,
+            property_name(&node.property),
+            property_span(&node.property),
+            MemberKind::Property,
+            ReferenceKind::StaticAccess,
+        );
+
+        walk_static_property_fetch_expression(self, node);
+    }
+
+    fn visit_constant_fetch_expression(&mut self, node: &ConstantFetchExpression) {
+        let class = node.constant.is_simple()
+            && node
+                .constant
+                .to_simple()
+                .symbol
+                .eq_ignore_ascii_case(b"class");
+
+        if class {
+            self.mark(&node.target, Usage::Class(ReferenceKind::ClassString));
+        } else {
+            self.mark(&node.target, Usage::Class(ReferenceKind::StaticAccess));
+
+            if node.constant.is_simple() {
+                let constant = node.constant.to_simple();
+                self.member(
+                    &node.target,
+                    Some(constant.symbol.as_ref()),
+                    constant.span,
+                    MemberKind::Constant,
+                    ReferenceKind::StaticAccess,
+                );
+            }
+        }
+
+        walk_constant_fetch_expression(self, node);
+    }
+
+    fn visit_method_call_expression(&mut self, node: &MethodCallExpression) {
+        self.member(
+            &node.target,
+            identifier_name(&node.method),
+            node.method.span,
+            MemberKind::Method,
+            ReferenceKind::InstanceCall,
+        );
+
+        walk_method_call_expression(self, node);
+    }
+
+    fn visit_nullsafe_method_call_expression(&mut self, node: &NullsafeMethodCallExpression) {
+        self.member(
+            &node.target,
+            identifier_name(&node.method),
+            node.method.span,
+            MemberKind::Method,
+            ReferenceKind::InstanceCall,
+        );
+
+        walk_nullsafe_method_call_expression(self, node);
+    }
+
+    fn visit_method_closure_creation_expression(&mut self, node: &MethodClosureCreationExpression) {
+        self.member(
+            &node.target,
+            identifier_name(&node.method),
+            node.method.span,
+            MemberKind::Method,
+            ReferenceKind::InstanceCall,
+        );
+
+        walk_method_closure_creation_expression(self, node);
+    }
+
+    fn visit_property_fetch_expression(&mut self, node: &PropertyFetchExpression) {
+        self.member(
+            &node.target,
+            identifier_name(&node.property),
+            node.property.span,
+            MemberKind::Property,
+            ReferenceKind::InstanceAccess,
+        );
+
+        walk_property_fetch_expression(self, node);
+    }
+
+    fn visit_nullsafe_property_fetch_expression(&mut self, node: &NullsafePropertyFetchExpression) {
+        self.member(
+            &node.target,
+            identifier_name(&node.property),
+            node.property.span,
+            MemberKind::Property,
+            ReferenceKind::InstanceAccess,
+        );
+
+        walk_nullsafe_property_fetch_expression(self, node);
+    }
+}
@@ -0,0 +1,60 @@
+use pxp_ast::{Expression, ExpressionKind};
+use pxp_bytestring::ByteString;
+use pxp_diagnostics::{Diagnostic, Severity};
+
+use super::{Rule, RuleContext, RuleDiagnostic};
+
+/// Reports the `@` operator, except in front of calls to the given functions. Functions like
+/// `unlink()` and `fopen()` are often silenced on purpose, since their warnings duplicate the
+/// return value.
+#[derive(Debug, Clone, Default)]
+pub struct DisallowErrorSuppression {
+    allowed: Vec<ByteString>,
+}
+
+impl DisallowErrorSuppression {
+    pub fn new<'a>(allowed: impl IntoIterator<Item = &'a str>) -> Self {
+        Self {
+            allowed: allowed.into_iter().map(ByteString::from).collect(),
+        }
+    }
+
+    fn is_allowed(&self, expression: &Expression) -> bool {
+        let ExpressionKind::FunctionCall(call) = &expression.kind else {
+            return false;
+        };
+
+        let ExpressionKind::Name(name) = &call.target.kind else {
+            return false;
+        };
+
+        let function = name.symbol().as_bytestr().after_last(b'\\');
+
+        self.allowed
+            .iter()
+            .any(|allowed| function.eq_ignore_ascii_case(allowed))
+    }
+}
+
+impl Rule for DisallowErrorSuppression {
+    fn name(&self) -> &str {
+        "disallow-error-suppression"
+    }
+
+    fn check_expression(
+        &mut self,
+        expression: &Expression,
+        _: &RuleContext,
+    ) -> Vec<Diagnostic<RuleDiagnostic>> {
+        match &expression.kind {
+            ExpressionKind::ErrorSuppress(inner) if !self.is_allowed(&inner.expr) => {
+                vec![Diagnostic::new(
+                    RuleDiagnostic::new("Errors should be handled instead of suppressed with @"),
+                    Severity::Warning,
+                    expression.span,
+                )]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use pxp_ast::{Expression, ExpressionKind};
+use pxp_diagnostics::{Diagnostic, Severity};
+
+use super::{Rule, RuleContext, RuleDiagnostic};
+
+/// Reports every use of `eval()`.
+#[derive(Debug, Clone)]
+pub struct DisallowEval {
+    severity: Severity,
+}
+
+impl DisallowEval {
+    pub fn new(severity: Severity) -> Self {
+        Self { severity }
+    }
+}
+
+impl Default for DisallowEval {
+    fn default() -> Self {
+        Self::new(Severity::Error)
+    }
+}
+
+impl Rule for DisallowEval {
+    fn name(&self) -> &str {
+        "disallow-eval"
+    }
+
+    fn check_expression(
+        &mut self,
+        expression: &Expression,
+        _: &RuleContext,
+    ) -> Vec<Diagnostic<RuleDiagnostic>> {
+        match &expression.kind {
+            ExpressionKind::Eval(_) => vec![Diagnostic::new(
+                RuleDiagnostic::new("eval() should not be used"),
+                self.severity,
+                expression.span,
+            )],
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,354 @@
+use pxp_ast::{
+    visitor::{
+        walk_anonymous_class_expression, walk_arrow_function_expression,
+        walk_backed_enum_statement, walk_class_statement, walk_closure_expression, walk_expression,
+        walk_function_statement, walk_interface_statement, walk_method, walk_statement,
+        walk_trait_statement, walk_unit_enum_statement, Visitor,
+    },
+    *,
+};
+use pxp_bytestring::{ByteStr, ByteString};
+use pxp_diagnostics::{Diagnostic, DiagnosticCategory, DiagnosticFix, DiagnosticKind};
+use pxp_index::{Index, ReflectionClass};
+use pxp_inference::TypeMap;
+use pxp_span::{LineIndex, Span};
+
+use crate::{AnalyserContext, DeadBranches, TargetEnvironment};
+
+mod error_suppression;
+mod eval;
+mod psr12;
+mod throws;
+
+pub use error_suppression::DisallowErrorSuppression;
+pub use eval::DisallowEval;
+pub use psr12::{
+    psr12_rules, BracePlacement, ExplicitVisibility, FileDocblockPlacement, ImportOrder,
+    MultilineTrailingComma, OneClassPerFile,
+};
+pub use throws::UndocumentedThrows;
+
+/// A project-specific check that runs alongside the built-in passes, e.g. "no `eval()`".
+///
+/// Rules only see one node at a time. Anything they need to know about the rest of the code should
+/// come from the [`RuleContext`], or be tracked by the rule itself. Configuration is passed to the
+/// rule's constructor.
+pub trait Rule {
+    /// The name of the rule, which is attached to every diagnostic that it reports.
+    fn name(&self) -> &str;
+
+    /// Check the file as a whole, before any of its nodes are checked. This is for rules that need
+    /// to look at more than one node at a time.
+    fn check_file(
+        &mut self,
+        ast: &[Statement],
+        context: &RuleContext,
+    ) -> Vec<Diagnostic<RuleDiagnostic>> {
+        let _ = (ast, context);
+
+        Vec::new()
+    }
+
+    fn check_statement(
+        &mut self,
+        statement: &Statement,
+        context: &RuleContext,
+    ) -> Vec<Diagnostic<RuleDiagnostic>> {
+        let _ = (statement, context);
+
+        Vec::new()
+    }
+
+    fn check_expression(
+        &mut self,
+        expression: &Expression,
+        context: &RuleContext,
+    ) -> Vec<Diagnostic<RuleDiagnostic>> {
+        let _ = (expression, context);
+
+        Vec::new()
+    }
+}
+
+/// The information available to a [`Rule`] about the node that it's checking.
+#[derive(Debug, Clone, Copy)]
+pub struct RuleContext<'a> {
+    context: &'a AnalyserContext<'a>,
+    line_index: &'a LineIndex<'a>,
+    class: Option<&'a ByteStr>,
+    function: Option<&'a ByteStr>,
+}
+
+impl<'a> RuleContext<'a> {
+    pub fn index(&self) -> &'a Index {
+        self.context.index()
+    }
+
+    pub fn types(&self) -> &'a TypeMap {
+        self.context.types()
+    }
+
+    pub fn target(&self) -> Option<&'a TargetEnvironment> {
+        self.context.target()
+    }
+
+    pub fn line_index(&self) -> &'a LineIndex<'a> {
+        self.line_index
+    }
+
+    /// The fully qualified name of the class-like that the node is inside of. Anonymous classes
+    /// don't have a name, so this is `None` inside of them.
+    pub fn class_name(&self) -> Option<&'a ByteStr> {
+        self.class
+    }
+
+    pub fn class(&self) -> Option<ReflectionClass<'a>> {
+        self.index().get_class(self.class?)
+    }
+
+    /// The name of the function or method that the node is inside of. Functions use their fully
+    /// qualified name, and closures and arrow functions are called `{closure}`.
+    pub fn function_name(&self) -> Option<&'a ByteStr> {
+        self.function
+    }
+}
+
+/// A diagnostic reported by a [`Rule`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleDiagnostic {
+    /// The name of the rule that reported the diagnostic. This is filled in by the [`Analyser`].
+    pub rule: String,
+    pub message: String,
+    pub category: DiagnosticCategory,
+    pub fix: Option<DiagnosticFix>,
+}
+
+impl RuleDiagnostic {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            rule: String::new(),
+            message: message.into(),
+            category: DiagnosticCategory::Correctness,
+            fix: None,
+        }
+    }
+
+    pub fn with_category(mut self, category: DiagnosticCategory) -> Self {
+        self.category = category;
+        self
+    }
+
+    pub fn with_fix(mut self, fix: DiagnosticFix) -> Self {
+        self.fix = Some(fix);
+        self
+    }
+}
+
+impl DiagnosticKind for RuleDiagnostic {
+    fn get_code(&self) -> String {
+        self.rule.clone()
+    }
+
+    fn get_identifier(&self) -> String {
+        format!("rule.{}", self.rule)
+    }
+
+    fn get_message(&self) -> String {
+        self.message.clone()
+    }
+
+    fn get_category(&self) -> DiagnosticCategory {
+        self.category
+    }
+
+    fn get_fix(&self, _: Span) -> Option<DiagnosticFix> {
+        self.fix.clone()
+    }
+}
+
+/// Runs a set of [`Rule`]s over an AST.
+///
+/// The AST is only walked once, no matter how many rules are registered. Each node is handed to the
+/// rules in the order that they were added.
+#[derive(Default)]
+pub struct Analyser {
+    rules: Vec<Box<dyn Rule>>,
+}
+
+impl Analyser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_rule(mut self, rule: impl Rule + 'static) -> Self {
+        self.add_rule(Box::new(rule));
+        self
+    }
+
+    pub fn add_rule(&mut self, rule: Box<dyn Rule>) {
+        self.rules.push(rule);
+    }
+
+    pub fn rules(&self) -> impl Iterator<Item = &str> {
+        self.rules.iter().map(|rule| rule.name())
+    }
+
+    /// The line index must have been built from the same source that the AST was parsed from.
+    ///
+    /// Nodes inside of branches that can't run in the context's [`TargetEnvironment`] aren't
+    /// checked.
+    pub fn analyse(
+        &mut self,
+        context: &AnalyserContext,
+        line_index: &LineIndex,
+        ast: &[Statement],
+    ) -> Vec<Diagnostic<RuleDiagnostic>> {
+        let mut visitor = RuleVisitor {
+            rules: &mut self.rules,
+            context,
+            line_index,
+            dead: context
+                .target()
+                .map(|target| target.dead_branches(ast))
+                .unwrap_or_default(),
+            classes: Vec::new(),
+            functions: Vec::new(),
+            diagnostics: Vec::new(),
+        };
+
+        visitor.check(|rule, context| rule.check_file(ast, context));
+        visitor.visit(ast);
+        visitor.diagnostics
+    }
+}
+
+struct RuleVisitor<'a, 'b> {
+    rules: &'b mut [Box<dyn Rule>],
+    context: &'b AnalyserContext<'a>,
+    line_index: &'b LineIndex<'a>,
+    dead: DeadBranches,
+    // Anonymous classes push `None`, since they don't have a name.
+    classes: Vec<Option<ByteString>>,
+    functions: Vec<ByteString>,
+    diagnostics: Vec<Diagnostic<RuleDiagnostic>>,
+}
+
+impl<'a, 'b> RuleVisitor<'a, 'b> {
+    fn check(
+        &mut self,
+        mut check: impl FnMut(&mut dyn Rule, &RuleContext) -> Vec<Diagnostic<RuleDiagnostic>>,
+    ) {
+        let context = RuleContext {
+            context: self.context,
+            line_index: self.line_index,
+            class: self
+                .classes
+                .last()
+                .and_then(|class| class.as_ref().map(|class| class.as_bytestr())),
+            function: self.functions.last().map(|function| function.as_bytestr()),
+        };
+
+        for rule in self.rules.iter_mut() {
+            let diagnostics = check(rule.as_mut(), &context);
+            let name = rule.name();
+
+            self.diagnostics
+                .extend(diagnostics.into_iter().map(|mut diagnostic| {
+                    diagnostic.kind.rule = name.to_string();
+                    diagnostic
+                }));
+        }
+    }
+
+    fn with_class(&mut self, name: Option<&Name>, f: impl FnOnce(&mut Self)) {
+        self.classes.push(name.map(|name| name.symbol().clone()));
+        f(self);
+        self.classes.pop();
+    }
+
+    fn with_function(&mut self, name: &[u8], f: impl FnOnce(&mut Self)) {
+        self.functions.push(name.into());
+        f(self);
+        self.functions.pop();
+    }
+}
+
+impl<'a, 'b> Visitor for RuleVisitor<'a, 'b> {
+    fn visit_statement(&mut self, node: &Statement) {
+        if self.dead.contains(node.span) {
+            return;
+        }
+
+        self.check(|rule, context| rule.check_statement(node, context));
+
+        walk_statement(self, node);
+    }
+
+    fn visit_expression(&mut self, node: &Expression) {
+        if self.dead.contains(node.span) {
+            return;
+        }
+
+        self.check(|rule, context| rule.check_expression(node, context));
+
+        walk_expression(self, node);
+    }
+
+    fn visit_class_statement(&mut self, node: &ClassStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_class_statement(visitor, node)
+        });
+    }
+
+    fn visit_interface_statement(&mut self, node: &InterfaceStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_interface_statement(visitor, node)
+        });
+    }
+
+    fn visit_trait_statement(&mut self, node: &TraitStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_trait_statement(visitor, node)
+        });
+    }
+
+    fn visit_unit_enum_statement(&mut self, node: &UnitEnumStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_unit_enum_statement(visitor, node)
+        });
+    }
+
+    fn visit_backed_enum_statement(&mut self, node: &BackedEnumStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_backed_enum_statement(visitor, node)
+        });
+    }
+
+    fn visit_anonymous_class_expression(&mut self, node: &AnonymousClassExpression) {
+        self.with_class(None, |visitor| {
+            walk_anonymous_class_expression(visitor, node)
+        });
+    }
+
+    fn visit_function_statement(&mut self, node: &FunctionStatement) {
+        self.with_function(node.name.symbol(), |visitor| {
+            walk_function_statement(visitor, node)
+        });
+    }
+
+    fn visit_method(&mut self, node: &Method) {
+        self.with_function(&node.name.symbol, |visitor| walk_method(visitor, node));
+    }
+
+    fn visit_closure_expression(&mut self, node: &ClosureExpression) {
+        self.with_function(b"{closure}", |visitor| {
+            walk_closure_expression(visitor, node)
+        });
+    }
+
+    fn visit_arrow_function_expression(&mut self, node: &ArrowFunctionExpression) {
+        self.with_function(b"{closure}", |visitor| {
+            walk_arrow_function_expression(visitor, node)
+        });
+    }
+}
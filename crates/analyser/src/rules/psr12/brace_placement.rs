@@ -0,0 +1,228 @@
+use pxp_ast::*;
+use pxp_diagnostics::{Diagnostic, DiagnosticCategory, DiagnosticFix, Severity, TextEdit};
+use pxp_span::{ByteOffset, IsSpanned, Span};
+
+use crate::rules::{Rule, RuleContext, RuleDiagnostic};
+
+use super::{anonymous_class_members, classish_members, indentation, is_multiline, is_whitespace};
+
+/// Reports opening braces of classes, functions and methods that aren't on the line after the
+/// declaration's header.
+///
+/// When the parameter list is split across multiple lines, the brace goes on the same line as the
+/// closing parenthesis instead, separated by a single space. Anonymous classes and closures keep
+/// their brace on the same line, so they aren't checked.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BracePlacement;
+
+impl BracePlacement {
+    fn check(
+        &self,
+        declaration: Span,
+        header_end: ByteOffset,
+        left_brace: Span,
+        multiline_parameters: bool,
+        context: &RuleContext,
+    ) -> Option<Diagnostic<RuleDiagnostic>> {
+        // Incomplete declarations can be missing their brace, or have it before the header.
+        if left_brace.is_empty() || left_brace.start < header_end {
+            return None;
+        }
+
+        let source = context.line_index().source();
+        let gap = source.get(header_end..left_brace.start)?;
+
+        // Comments between the header and the brace are left alone.
+        if !is_whitespace(gap) {
+            return None;
+        }
+
+        let (expected, message) = if multiline_parameters {
+            (
+                " ".to_string(),
+                "Opening brace should be on the same line as a multi-line parameter list",
+            )
+        } else {
+            let indentation = indentation(source, declaration.start);
+
+            (
+                format!("\n{}", String::from_utf8_lossy(indentation)),
+                "Opening brace should be on the line after the declaration",
+            )
+        };
+
+        if gap == expected.as_bytes() || gap == expected.replacen('\n', "\r\n", 1).as_bytes() {
+            return None;
+        }
+
+        Some(Diagnostic::new(
+            RuleDiagnostic::new(message)
+                .with_category(DiagnosticCategory::Style)
+                .with_fix(DiagnosticFix::new(
+                    "move the opening brace",
+                    vec![TextEdit::new(
+                        Span::new(header_end, left_brace.start),
+                        expected,
+                    )],
+                )),
+            Severity::Warning,
+            left_brace,
+        ))
+    }
+
+    fn check_method(
+        &self,
+        method: &Method,
+        context: &RuleContext,
+    ) -> Vec<Diagnostic<RuleDiagnostic>> {
+        let MethodBodyKind::Concrete(body) = &method.body.kind else {
+            return Vec::new();
+        };
+
+        let parameters = &method.parameters;
+        let header_end = method
+            .return_type
+            .as_ref()
+            .map(|return_type| return_type.span.end)
+            .unwrap_or(parameters.right_parenthesis.end);
+
+        self.check(
+            method.function,
+            header_end,
+            body.left_brace,
+            is_multiline(
+                parameters.left_parenthesis,
+                parameters.right_parenthesis,
+                context,
+            ),
+            context,
+        )
+        .into_iter()
+        .collect()
+    }
+
+    fn check_methods(
+        &self,
+        members: Vec<&ClassishMember>,
+        context: &RuleContext,
+    ) -> Vec<Diagnostic<RuleDiagnostic>> {
+        members
+            .into_iter()
+            .filter_map(|member| match member {
+                ClassishMember::Method(method) => Some(method),
+                _ => None,
+            })
+            .flat_map(|method| self.check_method(method, context))
+            .collect()
+    }
+}
+
+impl Rule for BracePlacement {
+    fn name(&self) -> &str {
+        "psr12-brace-placement"
+    }
+
+    fn check_statement(
+        &mut self,
+        statement: &Statement,
+        context: &RuleContext,
+    ) -> Vec<Diagnostic<RuleDiagnostic>> {
+        let declaration = match &statement.kind {
+            StatementKind::Class(class) => Some((
+                class.class,
+                class
+                    .implements
+                    .as_ref()
+                    .map(|implements| implements.span)
+                    .or(class.extends.as_ref().map(|extends| extends.span))
+                    .unwrap_or(class.name.span)
+                    .end,
+                class.body.left_brace,
+            )),
+            StatementKind::Interface(interface) => Some((
+                interface.interface,
+                interface
+                    .extends
+                    .as_ref()
+                    .map(|extends| extends.span)
+                    .unwrap_or(interface.name.span)
+                    .end,
+                interface.body.left_brace,
+            )),
+            StatementKind::Trait(r#trait) => Some((
+                r#trait.r#trait,
+                r#trait.name.span.end,
+                r#trait.body.left_brace,
+            )),
+            StatementKind::UnitEnum(r#enum) => Some((
+                r#enum.r#enum,
+                r#enum
+                    .implements
+                    .last()
+                    .map(|name| name.span)
+                    .unwrap_or(r#enum.name.span)
+                    .end,
+                r#enum.body.left_brace,
+            )),
+            StatementKind::BackedEnum(r#enum) => Some((
+                r#enum.r#enum,
+                r#enum
+                    .implements
+                    .last()
+                    .map(|name| name.span)
+                    .unwrap_or(r#enum.backed_type.span())
+                    .end,
+                r#enum.body.left_brace,
+            )),
+            StatementKind::Function(function) => {
+                let parameters = &function.parameters;
+                let header_end = function
+                    .return_type
+                    .as_ref()
+                    .map(|return_type| return_type.span.end)
+                    .unwrap_or(parameters.right_parenthesis.end);
+
+                return self
+                    .check(
+                        function.function,
+                        header_end,
+                        function.body.left_brace,
+                        is_multiline(
+                            parameters.left_parenthesis,
+                            parameters.right_parenthesis,
+                            context,
+                        ),
+                        context,
+                    )
+                    .into_iter()
+                    .collect();
+            }
+            _ => None,
+        };
+
+        let Some((keyword, header_end, left_brace)) = declaration else {
+            return Vec::new();
+        };
+
+        let mut diagnostics: Vec<_> = self
+            .check(keyword, header_end, left_brace, false, context)
+            .into_iter()
+            .collect();
+
+        if let Some(members) = classish_members(statement) {
+            diagnostics.extend(self.check_methods(members, context));
+        }
+
+        diagnostics
+    }
+
+    fn check_expression(
+        &mut self,
+        expression: &Expression,
+        context: &RuleContext,
+    ) -> Vec<Diagnostic<RuleDiagnostic>> {
+        anonymous_class_members(expression)
+            .map(|members| self.check_methods(members, context))
+            .unwrap_or_default()
+    }
+}
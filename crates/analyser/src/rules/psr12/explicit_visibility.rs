@@ -0,0 +1,144 @@
+use pxp_ast::*;
+use pxp_diagnostics::{Diagnostic, DiagnosticCategory, DiagnosticFix, Severity, TextEdit};
+use pxp_span::IsSpanned;
+
+use crate::rules::{Rule, RuleContext, RuleDiagnostic};
+
+use super::{anonymous_class_members, classish_members};
+
+/// Reports properties, methods and constants that don't declare their visibility.
+///
+/// The fix adds `public`, which is what PHP assumes, after any `abstract` or `final` modifier and
+/// before `static` or `readonly`. Properties declared with `var` have it replaced.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExplicitVisibility;
+
+impl ExplicitVisibility {
+    fn check_member(&self, member: &ClassishMember) -> Option<Diagnostic<RuleDiagnostic>> {
+        let (kind, span, edit) = match member {
+            ClassishMember::Method(method) => {
+                let modifiers = &method.modifiers;
+
+                if modifiers.is_public() || modifiers.is_protected() || modifiers.is_private() {
+                    return None;
+                }
+
+                let before = modifiers
+                    .modifiers
+                    .iter()
+                    .find_map(|modifier| match modifier {
+                        MethodModifier::Static(span) => Some(*span),
+                        _ => None,
+                    })
+                    .unwrap_or(method.function);
+
+                (
+                    "Method",
+                    method.name.span,
+                    TextEdit::insert(before.start, "public "),
+                )
+            }
+            ClassishMember::Property(property) => {
+                let (modifiers, var, r#type, entry) = match property {
+                    Property::Simple(property) => (
+                        &property.modifiers,
+                        property.var,
+                        &property.r#type,
+                        property.entries.first()?.span(),
+                    ),
+                    Property::Hooked(property) => (
+                        &property.modifiers,
+                        None,
+                        &property.r#type,
+                        property.entry.span(),
+                    ),
+                };
+
+                // `var` is parsed as an implicit `public` modifier.
+                if var.is_none()
+                    && (modifiers.is_public() || modifiers.is_protected() || modifiers.is_private())
+                {
+                    return None;
+                }
+
+                let edit = match var {
+                    Some(var) => TextEdit::new(var, "public"),
+                    None => {
+                        let before = modifiers
+                            .modifiers
+                            .iter()
+                            .map(|modifier| modifier.span())
+                            .chain(r#type.as_ref().map(|r#type| r#type.span()))
+                            .next()
+                            .unwrap_or(entry);
+
+                        TextEdit::insert(before.start, "public ")
+                    }
+                };
+
+                ("Property", entry, edit)
+            }
+            ClassishMember::Constant(constant) => {
+                let modifiers = &constant.modifiers;
+
+                if modifiers.is_public() || modifiers.is_protected() || modifiers.is_private() {
+                    return None;
+                }
+
+                let name = constant
+                    .entries
+                    .first()
+                    .map(|entry| entry.name.span)
+                    .unwrap_or(constant.r#const);
+
+                (
+                    "Constant",
+                    name,
+                    TextEdit::insert(constant.r#const.start, "public "),
+                )
+            }
+            _ => return None,
+        };
+
+        Some(Diagnostic::new(
+            RuleDiagnostic::new(format!("{} should declare its visibility", kind))
+                .with_category(DiagnosticCategory::Style)
+                .with_fix(DiagnosticFix::new("declare it public", vec![edit])),
+            Severity::Warning,
+            span,
+        ))
+    }
+
+    fn check_members(&self, members: Vec<&ClassishMember>) -> Vec<Diagnostic<RuleDiagnostic>> {
+        members
+            .into_iter()
+            .filter_map(|member| self.check_member(member))
+            .collect()
+    }
+}
+
+impl Rule for ExplicitVisibility {
+    fn name(&self) -> &str {
+        "psr12-explicit-visibility"
+    }
+
+    fn check_statement(
+        &mut self,
+        statement: &Statement,
+        _: &RuleContext,
+    ) -> Vec<Diagnostic<RuleDiagnostic>> {
+        classish_members(statement)
+            .map(|members| self.check_members(members))
+            .unwrap_or_default()
+    }
+
+    fn check_expression(
+        &mut self,
+        expression: &Expression,
+        _: &RuleContext,
+    ) -> Vec<Diagnostic<RuleDiagnostic>> {
+        anonymous_class_members(expression)
+            .map(|members| self.check_members(members))
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,62 @@
+use pxp_ast::*;
+use pxp_diagnostics::{Diagnostic, DiagnosticCategory, DiagnosticFix, Severity, TextEdit};
+use pxp_span::Span;
+
+use crate::rules::{Rule, RuleContext, RuleDiagnostic};
+
+/// Reports blank lines between the opening `<?php` tag and a docblock that directly follows it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FileDocblockPlacement;
+
+impl Rule for FileDocblockPlacement {
+    fn name(&self) -> &str {
+        "psr12-file-docblock-placement"
+    }
+
+    fn check_file(
+        &mut self,
+        ast: &[Statement],
+        context: &RuleContext,
+    ) -> Vec<Diagnostic<RuleDiagnostic>> {
+        let Some(Statement {
+            kind: StatementKind::FullOpeningTag(tag),
+            ..
+        }) = ast.first()
+        else {
+            return Vec::new();
+        };
+
+        let source = context.line_index().source();
+        let Some(rest) = source.get(tag.span.end..) else {
+            return Vec::new();
+        };
+
+        let whitespace = rest
+            .iter()
+            .take_while(|byte| byte.is_ascii_whitespace())
+            .count();
+        let gap = Span::new(tag.span.end, tag.span.end + whitespace);
+
+        let newlines = rest[..whitespace]
+            .iter()
+            .filter(|byte| **byte == b'\n')
+            .count();
+
+        if newlines < 2 || !rest[whitespace..].starts_with(b"/**") {
+            return Vec::new();
+        }
+
+        vec![Diagnostic::new(
+            RuleDiagnostic::new(
+                "There should be no blank line between the opening tag and the file docblock",
+            )
+            .with_category(DiagnosticCategory::Style)
+            .with_fix(DiagnosticFix::new(
+                "remove the blank line",
+                vec![TextEdit::new(gap, "\n")],
+            )),
+            Severity::Warning,
+            gap,
+        )]
+    }
+}
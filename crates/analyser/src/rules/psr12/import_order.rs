@@ -0,0 +1,172 @@
+use pxp_ast::*;
+use pxp_diagnostics::{Diagnostic, DiagnosticCategory, DiagnosticFix, Severity, TextEdit};
+use pxp_span::Span;
+
+use crate::rules::{Rule, RuleContext, RuleDiagnostic};
+
+use super::{indentation, is_whitespace};
+
+/// Reports `use` statements that aren't grouped by kind and sorted.
+///
+/// Class imports come first, then functions, then constants, with a blank line between each group.
+/// Inside of a group, the statements are sorted by name, ignoring case. Each run of consecutive
+/// `use` statements is checked on its own.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ImportOrder;
+
+/// A `use` statement, from the `use` keyword to the semicolon.
+struct Import {
+    span: Span,
+    kind: UseKind,
+    key: String,
+}
+
+impl Import {
+    fn new(statement: &Statement, source: &[u8]) -> Option<Self> {
+        let (span, kind, name) = match &statement.kind {
+            // The statement's span stops at the last import, before the semicolon.
+            StatementKind::Use(r#use) => {
+                let semicolon = source
+                    .get(statement.span.end..)?
+                    .iter()
+                    .position(|byte| *byte == b';')?;
+
+                (
+                    Span::new(statement.span.start, statement.span.end + semicolon + 1),
+                    r#use.kind,
+                    r#use.uses.first()?.name.span,
+                )
+            }
+            // The statement's span starts at the prefix, after the `use` keyword.
+            StatementKind::GroupUse(group) => {
+                let keyword = source
+                    .get(..statement.span.start)?
+                    .windows(3)
+                    .rposition(|window| window.eq_ignore_ascii_case(b"use"))?;
+
+                (
+                    Span::new(keyword, statement.span.end),
+                    group.kind,
+                    group.prefix.span,
+                )
+            }
+            _ => return None,
+        };
+
+        let name = String::from_utf8_lossy(source.get(name.to_range())?);
+
+        Some(Self {
+            span,
+            kind,
+            key: name.trim_start_matches('\\').to_ascii_lowercase(),
+        })
+    }
+
+    fn group(&self) -> u8 {
+        match self.kind {
+            UseKind::Normal => 0,
+            UseKind::Function => 1,
+            UseKind::Const => 2,
+        }
+    }
+}
+
+impl ImportOrder {
+    fn check_statements(
+        &self,
+        statements: &[Statement],
+        source: &[u8],
+        diagnostics: &mut Vec<Diagnostic<RuleDiagnostic>>,
+    ) {
+        let mut run = Vec::new();
+
+        for statement in statements {
+            if let Some(import) = Import::new(statement, source) {
+                run.push(import);
+                continue;
+            }
+
+            diagnostics.extend(self.check_run(std::mem::take(&mut run), source));
+
+            if let StatementKind::Namespace(namespace) = &statement.kind {
+                let statements = match namespace.as_ref() {
+                    NamespaceStatement::Unbraced(namespace) => &namespace.statements,
+                    NamespaceStatement::Braced(namespace) => &namespace.body.statements,
+                };
+
+                self.check_statements(statements, source, diagnostics);
+            }
+        }
+
+        diagnostics.extend(self.check_run(run, source));
+    }
+
+    fn check_run(&self, run: Vec<Import>, source: &[u8]) -> Option<Diagnostic<RuleDiagnostic>> {
+        let span = Span::new(run.first()?.span.start, run.last()?.span.end);
+        let indentation = String::from_utf8_lossy(indentation(source, span.start));
+
+        let mut sorted = run.iter().collect::<Vec<_>>();
+        sorted.sort_by(|a, b| (a.group(), &a.key).cmp(&(b.group(), &b.key)));
+
+        let mut expected = String::new();
+
+        for (i, import) in sorted.iter().enumerate() {
+            if i > 0 {
+                let separator = if sorted[i - 1].group() == import.group() {
+                    "\n"
+                } else {
+                    "\n\n"
+                };
+
+                expected.push_str(separator);
+                expected.push_str(&indentation);
+            }
+
+            expected.push_str(&String::from_utf8_lossy(
+                source.get(import.span.to_range())?,
+            ));
+        }
+
+        if expected.as_bytes() == source.get(span.to_range())? {
+            return None;
+        }
+
+        let mut diagnostic =
+            RuleDiagnostic::new("Imports should be grouped by kind and sorted alphabetically")
+                .with_category(DiagnosticCategory::Style);
+
+        // Comments between the imports would be lost, so they have to be moved by hand.
+        let movable = run.windows(2).all(|pair| {
+            source
+                .get(pair[0].span.end..pair[1].span.start)
+                .is_some_and(is_whitespace)
+        });
+
+        if movable {
+            diagnostic = diagnostic.with_fix(DiagnosticFix::new(
+                "sort the imports",
+                vec![TextEdit::new(span, expected)],
+            ));
+        }
+
+        Some(Diagnostic::new(diagnostic, Severity::Warning, span))
+    }
+}
+
+impl Rule for ImportOrder {
+    fn name(&self) -> &str {
+        "psr12-import-order"
+    }
+
+    fn check_file(
+        &mut self,
+        ast: &[Statement],
+        context: &RuleContext,
+    ) -> Vec<Diagnostic<RuleDiagnostic>> {
+        let mut diagnostics = Vec::new();
+
+        self.check_statements(ast, context.line_index().source(), &mut diagnostics);
+
+        diagnostics
+    }
+}
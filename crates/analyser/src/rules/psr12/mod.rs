@@ -0,0 +1,103 @@
+//! Rules for the parts of [PSR-12](https://www.php-fig.org/psr/psr-12/) that need to know about the
+//! structure of the code, rather than only its whitespace.
+//!
+//! None of them are enabled by default. Most of them report a [`DiagnosticFix`] when the change is
+//! mechanical, so that they can be applied in bulk.
+//!
+//! [`DiagnosticFix`]: pxp_diagnostics::DiagnosticFix
+
+use pxp_ast::*;
+use pxp_span::{ByteOffset, Span};
+
+use super::{Rule, RuleContext};
+
+mod brace_placement;
+mod explicit_visibility;
+mod file_docblock;
+mod import_order;
+mod one_class_per_file;
+mod trailing_comma;
+
+pub use brace_placement::BracePlacement;
+pub use explicit_visibility::ExplicitVisibility;
+pub use file_docblock::FileDocblockPlacement;
+pub use import_order::ImportOrder;
+pub use one_class_per_file::OneClassPerFile;
+pub use trailing_comma::MultilineTrailingComma;
+
+/// Every PSR-12 rule, ready to be added to an [`Analyser`](crate::Analyser).
+pub fn psr12_rules() -> Vec<Box<dyn Rule>> {
+    vec![
+        Box::new(BracePlacement),
+        Box::new(OneClassPerFile),
+        Box::new(ImportOrder),
+        Box::new(ExplicitVisibility),
+        Box::new(FileDocblockPlacement),
+        Box::new(MultilineTrailingComma),
+    ]
+}
+
+/// The members of a named class-like declaration.
+fn classish_members(statement: &Statement) -> Option<Vec<&ClassishMember>> {
+    let members = match &statement.kind {
+        StatementKind::Class(class) => class.body.members.iter().collect(),
+        StatementKind::Interface(interface) => interface.body.members.iter().collect(),
+        StatementKind::Trait(r#trait) => r#trait.body.members.iter().collect(),
+        StatementKind::UnitEnum(r#enum) => r#enum
+            .body
+            .members
+            .iter()
+            .filter_map(|member| match member {
+                UnitEnumMember::Classish(member) => Some(member),
+                UnitEnumMember::Case(_) => None,
+            })
+            .collect(),
+        StatementKind::BackedEnum(r#enum) => r#enum
+            .body
+            .members
+            .iter()
+            .filter_map(|member| match member {
+                BackedEnumMember::Classish(member) => Some(member),
+                BackedEnumMember::Case(_) => None,
+            })
+            .collect(),
+        _ => return None,
+    };
+
+    Some(members)
+}
+
+/// The members of an anonymous class.
+fn anonymous_class_members(expression: &Expression) -> Option<Vec<&ClassishMember>> {
+    match &expression.kind {
+        ExpressionKind::AnonymousClass(class) => Some(class.body.members.iter().collect()),
+        _ => None,
+    }
+}
+
+/// The whitespace at the start of the line that the offset is on.
+fn indentation(source: &[u8], offset: ByteOffset) -> &[u8] {
+    let offset = offset.min(source.len());
+    let start = source[..offset]
+        .iter()
+        .rposition(|byte| *byte == b'\n')
+        .map_or(0, |newline| newline + 1);
+
+    let length = source[start..]
+        .iter()
+        .take_while(|byte| matches!(byte, b' ' | b'\t'))
+        .count();
+
+    &source[start..start + length]
+}
+
+fn is_whitespace(bytes: &[u8]) -> bool {
+    bytes.iter().all(u8::is_ascii_whitespace)
+}
+
+/// Whether the two spans start on different lines.
+fn is_multiline(left: Span, right: Span, context: &RuleContext) -> bool {
+    let line_index = context.line_index();
+
+    line_index.line_column(left.start).line != line_index.line_column(right.start).line
+}
@@ -0,0 +1,62 @@
+use pxp_ast::*;
+use pxp_diagnostics::{Diagnostic, DiagnosticCategory, Severity};
+
+use crate::rules::{Rule, RuleContext, RuleDiagnostic};
+
+/// Reports every class, interface, trait and enum after the first one declared in a file.
+///
+/// Only declarations at the top level or directly inside of a namespace are counted.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OneClassPerFile;
+
+impl Rule for OneClassPerFile {
+    fn name(&self) -> &str {
+        "psr12-one-class-per-file"
+    }
+
+    fn check_file(
+        &mut self,
+        ast: &[Statement],
+        context: &RuleContext,
+    ) -> Vec<Diagnostic<RuleDiagnostic>> {
+        let source = context.line_index().source();
+        let mut names = Vec::new();
+
+        collect(ast, &mut names);
+
+        names
+            .into_iter()
+            .skip(1)
+            .map(|name| {
+                Diagnostic::new(
+                    RuleDiagnostic::new(format!(
+                        "{} should be declared in its own file",
+                        String::from_utf8_lossy(
+                            source.get(name.span.to_range()).unwrap_or_default()
+                        )
+                    ))
+                    .with_category(DiagnosticCategory::Style),
+                    Severity::Warning,
+                    name.span,
+                )
+            })
+            .collect()
+    }
+}
+
+fn collect<'a>(statements: &'a [Statement], names: &mut Vec<&'a Name>) {
+    for statement in statements {
+        match &statement.kind {
+            StatementKind::Class(class) => names.push(&class.name),
+            StatementKind::Interface(interface) => names.push(&interface.name),
+            StatementKind::Trait(r#trait) => names.push(&r#trait.name),
+            StatementKind::UnitEnum(r#enum) => names.push(&r#enum.name),
+            StatementKind::BackedEnum(r#enum) => names.push(&r#enum.name),
+            StatementKind::Namespace(namespace) => match namespace.as_ref() {
+                NamespaceStatement::Unbraced(namespace) => collect(&namespace.statements, names),
+                NamespaceStatement::Braced(namespace) => collect(&namespace.body.statements, names),
+            },
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use pxp_ast::{utils::CommaSeparated, *};
+use pxp_diagnostics::{Diagnostic, DiagnosticCategory, DiagnosticFix, Severity, TextEdit};
+use pxp_span::{IsSpanned, Span};
+
+use crate::rules::{Rule, RuleContext, RuleDiagnostic};
+
+use super::{anonymous_class_members, classish_members, is_multiline};
+
+/// Reports parameter lists that are split across multiple lines without a comma after the last
+/// parameter.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MultilineTrailingComma;
+
+impl MultilineTrailingComma {
+    fn check<T: IsSpanned>(
+        &self,
+        parameters: &CommaSeparated<T>,
+        right_parenthesis: Span,
+        context: &RuleContext,
+    ) -> Option<Diagnostic<RuleDiagnostic>> {
+        let last = parameters.inner.last()?.span();
+
+        if parameters.commas.len() >= parameters.inner.len()
+            || !is_multiline(last, right_parenthesis, context)
+        {
+            return None;
+        }
+
+        Some(Diagnostic::new(
+            RuleDiagnostic::new("Multi-line parameter lists should have a trailing comma")
+                .with_category(DiagnosticCategory::Style)
+                .with_fix(DiagnosticFix::new(
+                    "add a trailing comma",
+                    vec![TextEdit::insert(last.end, ",")],
+                )),
+            Severity::Warning,
+            last,
+        ))
+    }
+
+    fn check_methods(
+        &self,
+        members: Vec<&ClassishMember>,
+        context: &RuleContext,
+    ) -> Vec<Diagnostic<RuleDiagnostic>> {
+        members
+            .into_iter()
+            .filter_map(|member| match member {
+                ClassishMember::Method(method) => self.check(
+                    &method.parameters.parameters,
+                    method.parameters.right_parenthesis,
+                    context,
+                ),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl Rule for MultilineTrailingComma {
+    fn name(&self) -> &str {
+        "psr12-multiline-trailing-comma"
+    }
+
+    fn check_statement(
+        &mut self,
+        statement: &Statement,
+        context: &RuleContext,
+    ) -> Vec<Diagnostic<RuleDiagnostic>> {
+        if let StatementKind::Function(function) = &statement.kind {
+            return self
+                .check(
+                    &function.parameters.parameters,
+                    function.parameters.right_parenthesis,
+                    context,
+                )
+                .into_iter()
+                .collect();
+        }
+
+        classish_members(statement)
+            .map(|members| self.check_methods(members, context))
+            .unwrap_or_default()
+    }
+
+    fn check_expression(
+        &mut self,
+        expression: &Expression,
+        context: &RuleContext,
+    ) -> Vec<Diagnostic<RuleDiagnostic>> {
+        let parameters = match &expression.kind {
+            ExpressionKind::Closure(closure) => &closure.parameters,
+            ExpressionKind::ArrowFunction(function) => &function.parameters,
+            _ => {
+                return anonymous_class_members(expression)
+                    .map(|members| self.check_methods(members, context))
+                    .unwrap_or_default()
+            }
+        };
+
+        self.check(
+            &parameters.parameters,
+            parameters.right_parenthesis,
+            context,
+        )
+        .into_iter()
+        .collect()
+    }
+}
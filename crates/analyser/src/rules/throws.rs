@@ -0,0 +1,93 @@
+use pxp_ast::Statement;
+use pxp_diagnostics::{Diagnostic, Severity};
+
+use crate::ThrowAnalysis;
+
+use super::{Rule, RuleContext, RuleDiagnostic};
+
+/// Reports exceptions that can escape from a function without being documented with `@throws`,
+/// and documented exceptions that can't actually be thrown.
+///
+/// Exceptions that escape from the functions being called are included too, which makes this
+/// rule noisy on code that doesn't document exceptions consistently.
+#[derive(Debug, Clone)]
+pub struct UndocumentedThrows {
+    severity: Severity,
+}
+
+impl UndocumentedThrows {
+    pub fn new(severity: Severity) -> Self {
+        Self { severity }
+    }
+}
+
+impl Default for UndocumentedThrows {
+    fn default() -> Self {
+        Self::new(Severity::Warning)
+    }
+}
+
+impl Rule for UndocumentedThrows {
+    fn name(&self) -> &str {
+        "undocumented-throws"
+    }
+
+    fn check_file(
+        &mut self,
+        ast: &[Statement],
+        context: &RuleContext,
+    ) -> Vec<Diagnostic<RuleDiagnostic>> {
+        let mut analysis = ThrowAnalysis::new(context.context, ast);
+        let mut diagnostics = Vec::new();
+
+        for declaration in analysis.declarations() {
+            let throws = analysis.declaration(&declaration);
+
+            for thrown in throws.iter() {
+                let documented = declaration.documented.iter().any(|documented| {
+                    analysis.is_a(
+                        thrown.exception.resolved.as_ref(),
+                        documented.resolved.as_ref(),
+                    )
+                });
+
+                if !documented {
+                    diagnostics.push(Diagnostic::new(
+                        RuleDiagnostic::new(format!(
+                            "{}() can throw {}, which isn't caught or documented with @throws",
+                            declaration.name, thrown.exception.resolved
+                        )),
+                        self.severity,
+                        thrown.span,
+                    ));
+                }
+            }
+
+            for documented in declaration.documented.iter() {
+                // A broader exception from a callee might be the documented one, so it counts too.
+                let thrown = throws.iter().any(|thrown| {
+                    analysis.is_a(
+                        thrown.exception.resolved.as_ref(),
+                        documented.resolved.as_ref(),
+                    ) || analysis.is_a(
+                        documented.resolved.as_ref(),
+                        thrown.exception.resolved.as_ref(),
+                    )
+                });
+
+                if !thrown {
+                    diagnostics.push(Diagnostic::new(
+                        RuleDiagnostic::new(format!(
+                            "{}() is documented to throw {}, but never does",
+                            declaration.name, documented.resolved
+                        )),
+                        Severity::Information,
+                        declaration.span,
+                    ));
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
@@ -0,0 +1,894 @@
+use std::collections::{HashMap, HashSet};
+
+use pxp_ast::{
+    visitor::{
+        walk_anonymous_class_expression, walk_arrow_function_expression, walk_backed_enum_case,
+        walk_backed_enum_statement, walk_braced_namespace, walk_class_statement,
+        walk_classish_constant, walk_closure_expression, walk_constant_entry,
+        walk_constant_fetch_expression, walk_expression, walk_function_call_expression,
+        walk_function_closure_creation_expression, walk_function_parameter,
+        walk_function_statement, walk_group_use_statement, walk_hooked_property,
+        walk_instanceof_expression, walk_interface_statement, walk_method,
+        walk_method_call_expression, walk_method_closure_creation_expression,
+        walk_method_parameter, walk_new_expression, walk_nullsafe_method_call_expression,
+        walk_nullsafe_property_fetch_expression, walk_property_fetch_expression,
+        walk_simple_property, walk_static_method_call_expression,
+        walk_static_method_closure_creation_expression, walk_static_property_fetch_expression,
+        walk_trait_statement, walk_unbraced_namespace, walk_unit_enum_case,
+        walk_unit_enum_statement, walk_use, Visitor,
+    },
+    *,
+};
+use pxp_bytestring::{ByteStr, ByteString};
+use pxp_index::{AccessContext, Index, MemberCandidate, MemberKind, ReflectionClass};
+use pxp_inference::TypeMap;
+use pxp_span::Span;
+use pxp_type::Type;
+
+use crate::passes::resolve_function;
+
+/// The role of an identifier, as far as it can be resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SemanticTokenKind {
+    /// A segment of a qualified name other than the last, e.g. `App` in `App\User`.
+    Namespace,
+    /// A class, interface, trait or enum.
+    Class,
+    Function,
+    Method,
+    Property,
+    Parameter,
+    /// A local variable, or a global variable outside of any function.
+    Variable,
+    /// `$this`
+    This,
+    /// A global constant or a class constant.
+    Constant,
+    EnumCase,
+    /// A name whose role couldn't be resolved, e.g. a class that isn't in the index or a method
+    /// on a receiver whose type couldn't be inferred.
+    Identifier,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SemanticTokenModifier {
+    /// The token is the name of the symbol where it's declared.
+    Declaration,
+    Static,
+    Deprecated,
+    Readonly,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SemanticToken {
+    pub span: Span,
+    pub kind: SemanticTokenKind,
+    pub modifiers: Vec<SemanticTokenModifier>,
+}
+
+/// Classify the identifiers in a file by the role that they resolve to, for editor highlighting.
+///
+/// Names are resolved through the `Index`, and the `TypeMap` is used to find the type of the
+/// receiver when accessing instance members. Qualified names are split into a token for each
+/// segment. Only types that are a single class-like name produce a token, since the positions of
+/// the names inside of a union or intersection aren't known, and docblocks are skipped.
+///
+/// The tokens are sorted by their position and never overlap.
+pub fn semantic_tokens(ast: &[Statement], types: &TypeMap, index: &Index) -> Vec<SemanticToken> {
+    let mut visitor = SemanticTokenVisitor {
+        index,
+        types,
+        namespace: None,
+        classes: Vec::new(),
+        parameters: vec![HashSet::new()],
+        usages: HashMap::new(),
+        claimed: HashSet::new(),
+        tokens: Vec::new(),
+    };
+
+    visitor.visit(ast);
+
+    let mut tokens = visitor.tokens;
+    tokens.sort_by_key(|token| (token.span.start, token.span.end));
+
+    let mut end = 0;
+    tokens.retain(|token| {
+        if token.span.start < end {
+            return false;
+        }
+
+        end = token.span.end;
+        true
+    });
+
+    tokens
+}
+
+/// What a name refers to, which is recorded by its parent node before the name is visited.
+#[derive(Debug, Clone, Copy)]
+enum Usage {
+    Class,
+    Function,
+    Constant,
+    Declaration(SemanticTokenKind),
+}
+
+struct SemanticTokenVisitor<'a> {
+    index: &'a Index,
+    types: &'a TypeMap,
+    // The namespace that we're currently inside of, used to resolve unqualified function calls.
+    namespace: Option<ByteString>,
+    classes: Vec<Option<ByteString>>,
+    // The names of the parameters that are visible in each function scope.
+    parameters: Vec<HashSet<ByteString>>,
+    usages: HashMap<NodeId, Usage>,
+    // Variables that have already been classified by their parent node.
+    claimed: HashSet<NodeId>,
+    tokens: Vec<SemanticToken>,
+}
+
+impl<'a> SemanticTokenVisitor<'a> {
+    fn push(&mut self, span: Span, kind: SemanticTokenKind, modifiers: Vec<SemanticTokenModifier>) {
+        self.tokens.push(SemanticToken {
+            span,
+            kind,
+            modifiers,
+        });
+    }
+
+    /// Push a token for each segment of a name as it was written, e.g. `App\Models\User`.
+    fn push_name(
+        &mut self,
+        span: Span,
+        written: &[u8],
+        kind: SemanticTokenKind,
+        modifiers: Vec<SemanticTokenModifier>,
+    ) {
+        if written.len() != span.len() {
+            return self.push(span, kind, modifiers);
+        }
+
+        let segments = written
+            .split(|byte| *byte == b'\\')
+            .scan(span.start, |start, segment| {
+                let segment = Span::new(*start, *start + segment.len());
+                *start = segment.end + 1;
+
+                Some(segment)
+            })
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>();
+
+        let Some((last, namespaces)) = segments.split_last() else {
+            return;
+        };
+
+        // The segments of a namespace's own name share its modifiers.
+        let namespace_modifiers = match kind {
+            SemanticTokenKind::Namespace => modifiers.clone(),
+            _ => Vec::new(),
+        };
+
+        for namespace in namespaces {
+            self.push(
+                *namespace,
+                SemanticTokenKind::Namespace,
+                namespace_modifiers.clone(),
+            );
+        }
+
+        self.push(*last, kind, modifiers);
+    }
+
+    fn push_namespace(&mut self, name: &SimpleIdentifier) {
+        self.push_name(
+            name.span,
+            &name.symbol,
+            SemanticTokenKind::Namespace,
+            vec![SemanticTokenModifier::Declaration],
+        );
+    }
+
+    fn with_class(&mut self, name: Option<&Name>, f: impl FnOnce(&mut Self)) {
+        if let Some(name) = name {
+            self.usages
+                .insert(name.id, Usage::Declaration(SemanticTokenKind::Class));
+        }
+
+        self.classes.push(
+            name.and_then(|name| name.as_resolved())
+                .map(|name| name.resolved.clone()),
+        );
+        f(self);
+        self.classes.pop();
+    }
+
+    fn with_parameters(&mut self, parameters: HashSet<ByteString>, f: impl FnOnce(&mut Self)) {
+        self.parameters.push(parameters);
+        f(self);
+        self.parameters.pop();
+    }
+
+    fn is_parameter(&self, name: &ByteString) -> bool {
+        self.parameters
+            .last()
+            .is_some_and(|parameters| parameters.contains(name))
+    }
+
+    fn context(&self) -> AccessContext {
+        match self.classes.last() {
+            Some(Some(class)) => AccessContext::Class(class.clone()),
+            _ => AccessContext::Global,
+        }
+    }
+
+    fn current_class(&self) -> Option<ReflectionClass<'a>> {
+        let name = self.classes.last()?.as_ref()?;
+
+        self.index.get_class(name.clone())
+    }
+
+    fn mark(&mut self, expression: &Expression, usage: Usage) {
+        if let ExpressionKind::Name(name) = &expression.kind {
+            self.usages.insert(name.id, usage);
+        }
+    }
+
+    fn class(&self, name: &ByteString) -> (SemanticTokenKind, Vec<SemanticTokenModifier>) {
+        match self.index.get_class(name.clone()) {
+            Some(class) => (
+                SemanticTokenKind::Class,
+                deprecated(class.is_deprecated()).collect(),
+            ),
+            None => (SemanticTokenKind::Identifier, Vec::new()),
+        }
+    }
+
+    fn function(&self, name: &Name) -> (SemanticTokenKind, Vec<SemanticTokenModifier>) {
+        let function = resolve_function(self.index, self.namespace.as_ref(), name);
+
+        match function {
+            Some(function) => (
+                SemanticTokenKind::Function,
+                deprecated(function.is_deprecated()).collect(),
+            ),
+            None => (SemanticTokenKind::Identifier, Vec::new()),
+        }
+    }
+
+    /// The type of the receiver of a member access.
+    fn receiver(&self, target: &Expression) -> Type<ResolvedName> {
+        match &target.kind {
+            ExpressionKind::Name(name) => match &name.kind {
+                NameKind::Resolved(name) => Type::Named(name.clone()),
+                NameKind::Special(special) => match special.kind {
+                    SpecialNameKind::Self_ => Type::SelfReference,
+                    SpecialNameKind::Static => Type::StaticReference,
+                    SpecialNameKind::Parent => Type::ParentReference,
+                },
+                NameKind::Unresolved(_) => Type::Mixed,
+            },
+            ExpressionKind::Self_(_) => Type::SelfReference,
+            ExpressionKind::Static(_) => Type::StaticReference,
+            ExpressionKind::Parent(_) => Type::ParentReference,
+            ExpressionKind::Variable(variable)
+                if variable.is_simple() && variable.to_simple().symbol == b"$this" =>
+            {
+                Type::StaticReference
+            }
+            _ => match self.types.resolve(target.id) {
+                Type::This => Type::StaticReference,
+                r#type => r#type.clone(),
+            },
+        }
+    }
+
+    fn find_member(
+        &self,
+        target: &Expression,
+        kind: MemberKind,
+        name: &ByteStr,
+    ) -> Option<MemberCandidate> {
+        self.index
+            .members_of(&self.receiver(target), self.context())
+            .into_iter()
+            .find(|candidate| match kind {
+                MemberKind::Method => {
+                    candidate.get_kind() == kind && candidate.get_name().eq_ignore_ascii_case(name)
+                }
+                // Constants and enum cases are accessed in the same way.
+                MemberKind::Constant | MemberKind::EnumCase => {
+                    matches!(
+                        candidate.get_kind(),
+                        MemberKind::Constant | MemberKind::EnumCase
+                    ) && candidate.get_name() == name
+                }
+                MemberKind::Property => {
+                    candidate.get_kind() == kind && candidate.get_name() == name
+                }
+            })
+    }
+
+    /// Push a token for a member access, falling back to an identifier when the member can't be
+    /// found on the receiver.
+    fn member(&mut self, target: &Expression, kind: MemberKind, name: &ByteStr, span: Span) {
+        let Some(member) = self.find_member(target, kind, name) else {
+            return self.push(span, SemanticTokenKind::Identifier, Vec::new());
+        };
+
+        let readonly = member.get_kind() == MemberKind::Property
+            && self
+                .index
+                .get_class(member.get_declaring_class().to_bytestring())
+                .and_then(|class| {
+                    class
+                        .get_property(member.get_name())
+                        .map(|property| property.is_readonly())
+                })
+                .unwrap_or(false);
+
+        let kind = match member.get_kind() {
+            MemberKind::Method => SemanticTokenKind::Method,
+            MemberKind::Property => SemanticTokenKind::Property,
+            MemberKind::Constant => SemanticTokenKind::Constant,
+            MemberKind::EnumCase => SemanticTokenKind::EnumCase,
+        };
+
+        let r#static = member.is_static()
+            && matches!(
+                kind,
+                SemanticTokenKind::Method | SemanticTokenKind::Property
+            );
+
+        let modifiers = [
+            (r#static, SemanticTokenModifier::Static),
+            (member.is_deprecated(), SemanticTokenModifier::Deprecated),
+            (readonly, SemanticTokenModifier::Readonly),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, modifier)| enabled.then_some(modifier))
+        .collect();
+
+        self.push(span, kind, modifiers);
+    }
+
+    fn property_declaration(&mut self, modifiers: &PropertyModifierGroup, entry: &PropertyEntry) {
+        let variable = entry.kind.variable();
+        let (deprecated, readonly) = self
+            .current_class()
+            .and_then(|class| {
+                class
+                    .get_property(variable.stripped.as_ref())
+                    .map(|property| (property.is_deprecated(), property.is_readonly()))
+            })
+            .unwrap_or((false, modifiers.has_readonly()));
+
+        self.claimed.insert(variable.id);
+        self.push(
+            variable.span,
+            SemanticTokenKind::Property,
+            declaration([
+                (modifiers.has_static(), SemanticTokenModifier::Static),
+                (deprecated, SemanticTokenModifier::Deprecated),
+                (readonly, SemanticTokenModifier::Readonly),
+            ]),
+        );
+    }
+
+    fn constant_declaration(&mut self, name: &SimpleIdentifier, kind: SemanticTokenKind) {
+        let deprecated = match kind {
+            SemanticTokenKind::Constant => self
+                .current_class()
+                .and_then(|class| {
+                    class
+                        .get_constant(name.symbol.as_ref())
+                        .map(|constant| constant.is_deprecated())
+                })
+                .unwrap_or(false),
+            _ => false,
+        };
+
+        self.push(
+            name.span,
+            kind,
+            declaration([(deprecated, SemanticTokenModifier::Deprecated)]),
+        );
+    }
+}
+
+fn deprecated(deprecated: bool) -> impl Iterator<Item = SemanticTokenModifier> {
+    deprecated
+        .then_some(SemanticTokenModifier::Deprecated)
+        .into_iter()
+}
+
+/// The modifiers of a declaration, followed by the other modifiers that are enabled.
+fn declaration<const N: usize>(
+    modifiers: [(bool, SemanticTokenModifier); N],
+) -> Vec<SemanticTokenModifier> {
+    std::iter::once(SemanticTokenModifier::Declaration)
+        .chain(
+            modifiers
+                .into_iter()
+                .filter_map(|(enabled, modifier)| enabled.then_some(modifier)),
+        )
+        .collect()
+}
+
+fn parameter_names<'b>(names: impl Iterator<Item = &'b SimpleVariable>) -> HashSet<ByteString> {
+    names.map(|name| name.symbol.clone()).collect()
+}
+
+impl<'a> Visitor for SemanticTokenVisitor<'a> {
+    fn visit_doc_block(&mut self, _: &DocBlock) {}
+
+    fn visit_unbraced_namespace(&mut self, node: &UnbracedNamespace) {
+        self.push_namespace(&node.name);
+
+        self.namespace = Some(node.name.symbol.clone());
+        walk_unbraced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_braced_namespace(&mut self, node: &BracedNamespace) {
+        if let Some(name) = &node.name {
+            self.push_namespace(name);
+        }
+
+        self.namespace = node.name.as_ref().map(|name| name.symbol.clone());
+        walk_braced_namespace(self, node);
+        self.namespace = None;
+    }
+
+    fn visit_group_use_statement(&mut self, node: &GroupUseStatement) {
+        self.push_name(
+            node.prefix.span,
+            &node.prefix.symbol,
+            SemanticTokenKind::Namespace,
+            Vec::new(),
+        );
+
+        walk_group_use_statement(self, node);
+    }
+
+    fn visit_use(&mut self, node: &Use) {
+        let usage = match node.kind {
+            UseKind::Function => Usage::Function,
+            UseKind::Const => Usage::Constant,
+            _ => Usage::Class,
+        };
+
+        self.usages.insert(node.name.id, usage);
+
+        walk_use(self, node);
+    }
+
+    fn visit_class_statement(&mut self, node: &ClassStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_class_statement(visitor, node)
+        });
+    }
+
+    fn visit_interface_statement(&mut self, node: &InterfaceStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_interface_statement(visitor, node)
+        });
+    }
+
+    fn visit_trait_statement(&mut self, node: &TraitStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_trait_statement(visitor, node)
+        });
+    }
+
+    fn visit_unit_enum_statement(&mut self, node: &UnitEnumStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_unit_enum_statement(visitor, node)
+        });
+    }
+
+    fn visit_backed_enum_statement(&mut self, node: &BackedEnumStatement) {
+        self.with_class(Some(&node.name), |visitor| {
+            walk_backed_enum_statement(visitor, node)
+        });
+    }
+
+    fn visit_anonymous_class_expression(&mut self, node: &AnonymousClassExpression) {
+        self.with_class(None, |visitor| {
+            walk_anonymous_class_expression(visitor, node)
+        });
+    }
+
+    fn visit_function_statement(&mut self, node: &FunctionStatement) {
+        self.usages.insert(
+            node.name.id,
+            Usage::Declaration(SemanticTokenKind::Function),
+        );
+
+        let parameters = parameter_names(node.parameters.iter().map(|parameter| &parameter.name));
+
+        self.with_parameters(parameters, |visitor| walk_function_statement(visitor, node));
+    }
+
+    fn visit_function_parameter(&mut self, node: &FunctionParameter) {
+        self.claimed.insert(node.name.id);
+        self.push(
+            node.name.span,
+            SemanticTokenKind::Parameter,
+            declaration([]),
+        );
+
+        walk_function_parameter(self, node);
+    }
+
+    fn visit_method(&mut self, node: &Method) {
+        let deprecated = self
+            .current_class()
+            .and_then(|class| {
+                class
+                    .get_method(node.name.symbol.as_ref())
+                    .map(|method| method.is_deprecated())
+            })
+            .unwrap_or(false);
+
+        self.push(
+            node.name.span,
+            SemanticTokenKind::Method,
+            declaration([
+                (node.modifiers.has_static(), SemanticTokenModifier::Static),
+                (deprecated, SemanticTokenModifier::Deprecated),
+            ]),
+        );
+
+        let parameters = parameter_names(
+            node.parameters
+                .parameters
+                .iter()
+                .map(|parameter| &parameter.name),
+        );
+
+        self.with_parameters(parameters, |visitor| walk_method(visitor, node));
+    }
+
+    fn visit_method_parameter(&mut self, node: &MethodParameter) {
+        let readonly = node
+            .modifiers
+            .as_ref()
+            .is_some_and(|modifiers| modifiers.has_readonly());
+
+        self.claimed.insert(node.name.id);
+        self.push(
+            node.name.span,
+            SemanticTokenKind::Parameter,
+            declaration([(readonly, SemanticTokenModifier::Readonly)]),
+        );
+
+        walk_method_parameter(self, node);
+    }
+
+    fn visit_closure_expression(&mut self, node: &ClosureExpression) {
+        let mut parameters =
+            parameter_names(node.parameters.iter().map(|parameter| &parameter.name));
+
+        // Captured variables are references to the variables of the enclosing scope.
+        if let Some(uses) = &node.uses {
+            for variable in uses.variables.iter().map(|r#use| &r#use.variable) {
+                self.visit_simple_variable(variable);
+                self.claimed.insert(variable.id);
+
+                if self.is_parameter(&variable.symbol) {
+                    parameters.insert(variable.symbol.clone());
+                }
+            }
+        }
+
+        self.with_parameters(parameters, |visitor| walk_closure_expression(visitor, node));
+    }
+
+    fn visit_arrow_function_expression(&mut self, node: &ArrowFunctionExpression) {
+        let mut parameters = self.parameters.last().cloned().unwrap_or_default();
+        parameters.extend(parameter_names(
+            node.parameters.iter().map(|parameter| &parameter.name),
+        ));
+
+        self.with_parameters(parameters, |visitor| {
+            walk_arrow_function_expression(visitor, node)
+        });
+    }
+
+    fn visit_simple_property(&mut self, node: &SimpleProperty) {
+        for entry in &node.entries {
+            self.property_declaration(&node.modifiers, entry);
+        }
+
+        walk_simple_property(self, node);
+    }
+
+    fn visit_hooked_property(&mut self, node: &HookedProperty) {
+        self.property_declaration(&node.modifiers, &node.entry);
+
+        walk_hooked_property(self, node);
+    }
+
+    fn visit_classish_constant(&mut self, node: &ClassishConstant) {
+        for entry in &node.entries {
+            self.constant_declaration(&entry.name, SemanticTokenKind::Constant);
+        }
+
+        walk_classish_constant(self, node);
+    }
+
+    fn visit_unit_enum_case(&mut self, node: &UnitEnumCase) {
+        self.constant_declaration(&node.name, SemanticTokenKind::EnumCase);
+
+        walk_unit_enum_case(self, node);
+    }
+
+    fn visit_backed_enum_case(&mut self, node: &BackedEnumCase) {
+        self.constant_declaration(&node.name, SemanticTokenKind::EnumCase);
+
+        walk_backed_enum_case(self, node);
+    }
+
+    fn visit_constant_entry(&mut self, node: &ConstantEntry) {
+        self.usages.insert(
+            node.name.id,
+            Usage::Declaration(SemanticTokenKind::Constant),
+        );
+
+        walk_constant_entry(self, node);
+    }
+
+    fn visit_data_type(&mut self, node: &DataType) {
+        let name = match &node.kind {
+            Type::Named(name) => name,
+            Type::Nullable(inner) => match inner.as_ref() {
+                Type::Named(name) => name,
+                _ => return,
+            },
+            _ => return,
+        };
+
+        let Some(start) = node.span.end.checked_sub(name.original.len()) else {
+            return;
+        };
+
+        let (kind, modifiers) = self.class(&name.resolved);
+
+        self.push_name(
+            Span::new(start, node.span.end),
+            &name.original,
+            kind,
+            modifiers,
+        );
+    }
+
+    fn visit_expression(&mut self, node: &Expression) {
+        // Any name that hasn't been claimed by its parent is a constant.
+        if let ExpressionKind::Name(name) = &node.kind {
+            self.usages.entry(name.id).or_insert(Usage::Constant);
+        }
+
+        walk_expression(self, node);
+    }
+
+    fn visit_name(&mut self, node: &Name) {
+        let written = match &node.kind {
+            NameKind::Resolved(name) => &name.original,
+            NameKind::Unresolved(name) => &name.symbol,
+            // `self`, `static` and `parent` are keywords.
+            NameKind::Special(_) => return,
+        };
+
+        let (kind, modifiers) = match self.usages.get(&node.id).copied().unwrap_or(Usage::Class) {
+            Usage::Class => self.class(node.symbol()),
+            Usage::Function => self.function(node),
+            // Global constants aren't indexed, but a constant fetch can't refer to anything else.
+            Usage::Constant => (SemanticTokenKind::Constant, Vec::new()),
+            Usage::Declaration(kind) => {
+                let deprecated = match kind {
+                    SemanticTokenKind::Class => self
+                        .index
+                        .get_class(node.symbol().clone())
+                        .is_some_and(|class| class.is_deprecated()),
+                    SemanticTokenKind::Function => self
+                        .index
+                        .get_function(node.symbol().clone())
+                        .is_some_and(|function| function.is_deprecated()),
+                    _ => false,
+                };
+
+                (
+                    kind,
+                    declaration([(deprecated, SemanticTokenModifier::Deprecated)]),
+                )
+            }
+        };
+
+        self.push_name(node.span, written, kind, modifiers);
+    }
+
+    fn visit_simple_variable(&mut self, node: &SimpleVariable) {
+        if self.claimed.contains(&node.id) {
+            return;
+        }
+
+        let kind = if node.symbol == b"$this" {
+            SemanticTokenKind::This
+        } else if self.is_parameter(&node.symbol) {
+            SemanticTokenKind::Parameter
+        } else {
+            SemanticTokenKind::Variable
+        };
+
+        self.push(node.span, kind, Vec::new());
+    }
+
+    fn visit_new_expression(&mut self, node: &NewExpression) {
+        self.mark(&node.target, Usage::Class);
+
+        walk_new_expression(self, node);
+    }
+
+    fn visit_instanceof_expression(&mut self, node: &InstanceofExpression) {
+        self.mark(&node.right, Usage::Class);
+
+        walk_instanceof_expression(self, node);
+    }
+
+    fn visit_function_call_expression(&mut self, node: &FunctionCallExpression) {
+        self.mark(&node.target, Usage::Function);
+
+        walk_function_call_expression(self, node);
+    }
+
+    fn visit_function_closure_creation_expression(
+        &mut self,
+        node: &FunctionClosureCreationExpression,
+    ) {
+        self.mark(&node.target, Usage::Function);
+
+        walk_function_closure_creation_expression(self, node);
+    }
+
+    fn visit_static_method_call_expression(&mut self, node: &StaticMethodCallExpression) {
+        self.mark(&node.target, Usage::Class);
+
+        if node.method.is_simple() {
+            let method = node.method.to_simple();
+            self.member(
+                &node.target,
+                MemberKind::Method,
+                method.symbol.as_ref(),
+                method.span,
+            );
+        }
+
+        walk_static_method_call_expression(self, node);
+    }
+
+    fn visit_static_method_closure_creation_expression(
+        &mut self,
+        node: &StaticMethodClosureCreationExpression,
+    ) {
+        self.mark(&node.target, Usage::Class);
+
+        if node.method.is_simple() {
+            let method = node.method.to_simple();
+            self.member(
+                &node.target,
+                MemberKind::Method,
+                method.symbol.as_ref(),
+                method.span,
+            );
+        }
+
+        walk_static_method_closure_creation_expression(self, node);
+    }
+
+    fn visit_static_property_fetch_expression(&mut self, node: &StaticPropertyFetchExpression) {
+        self.mark(&node.target, Usage::Class);
+
+        if let Variable::SimpleVariable(property) = &node.property {
+            self.claimed.insert(property.id);
+            self.member(
+                &node.target,
+                MemberKind::Property,
+                property.stripped.as_ref(),
+                property.span,
+            );
+        }
+
+        walk_static_property_fetch_expression(self, node);
+    }
+
+    fn visit_constant_fetch_expression(&mut self, node: &ConstantFetchExpression) {
+        self.mark(&node.target, Usage::Class);
+
+        if node.constant.is_simple() && !node.is_class() {
+            let constant = node.constant.to_simple();
+            self.member(
+                &node.target,
+                MemberKind::Constant,
+                constant.symbol.as_ref(),
+                constant.span,
+            );
+        }
+
+        walk_constant_fetch_expression(self, node);
+    }
+
+    fn visit_method_call_expression(&mut self, node: &MethodCallExpression) {
+        if let Some(method) = identifier(&node.method) {
+            self.member(
+                &node.target,
+                MemberKind::Method,
+                method.symbol.as_ref(),
+                method.span,
+            );
+        }
+
+        walk_method_call_expression(self, node);
+    }
+
+    fn visit_nullsafe_method_call_expression(&mut self, node: &NullsafeMethodCallExpression) {
+        if let Some(method) = identifier(&node.method) {
+            self.member(
+                &node.target,
+                MemberKind::Method,
+                method.symbol.as_ref(),
+                method.span,
+            );
+        }
+
+        walk_nullsafe_method_call_expression(self, node);
+    }
+
+    fn visit_method_closure_creation_expression(&mut self, node: &MethodClosureCreationExpression) {
+        if let Some(method) = identifier(&node.method) {
+            self.member(
+                &node.target,
+                MemberKind::Method,
+                method.symbol.as_ref(),
+                method.span,
+            );
+        }
+
+        walk_method_closure_creation_expression(self, node);
+    }
+
+    fn visit_property_fetch_expression(&mut self, node: &PropertyFetchExpression) {
+        if let Some(property) = identifier(&node.property) {
+            self.member(
+                &node.target,
+                MemberKind::Property,
+                property.symbol.as_ref(),
+                property.span,
+            );
+        }
+
+        walk_property_fetch_expression(self, node);
+    }
+
+    fn visit_nullsafe_property_fetch_expression(&mut self, node: &NullsafePropertyFetchExpression) {
+        if let Some(property) = identifier(&node.property) {
+            self.member(
+                &node.target,
+                MemberKind::Property,
+                property.symbol.as_ref(),
+                property.span,
+            );
+        }
+
+        walk_nullsafe_property_fetch_expression(self, node);
+    }
+}
+
+fn identifier(member: &Expression) -> Option<&SimpleIdentifier> {
+    match &member.kind {
+        ExpressionKind::Identifier(identifier) if identifier.is_simple() => {
+            Some(identifier.to_simple())
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,250 @@
+use std::collections::HashSet;
+
+use pxp_ast::{Fingerprinter, IterNodes, MethodBodyKind, Node, Statement};
+use pxp_bytestring::ByteString;
+use pxp_index::FileId;
+use pxp_span::Span;
+
+use crate::ParsedFile;
+
+/// A function or method that's part of a [`SimilarPair`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimilarFunction {
+    pub file: FileId,
+    /// The name of the function, or `Class::method`.
+    pub name: ByteString,
+    pub span: Span,
+}
+
+/// Two functions or methods whose bodies have a similar structure.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimilarPair {
+    pub first: SimilarFunction,
+    pub second: SimilarFunction,
+    /// How similar the bodies are, between `0.0` and `1.0`.
+    pub similarity: f32,
+}
+
+/// Finds functions and methods that have probably been copied and pasted, using the default
+/// [`SimilarityDetector`].
+pub fn find_similar_functions(files: &[ParsedFile], threshold: f32) -> Vec<SimilarPair> {
+    SimilarityDetector::new().find(files, threshold)
+}
+
+/// Compares the structure of function and method bodies to find the ones that have probably been
+/// copied and pasted.
+///
+/// Each body is turned into the sequence of the kinds of nodes inside of it, along with their
+/// depth, so the names of variables and the values of literals don't matter but the shape of the
+/// code does. The sequences are fingerprinted by winnowing the hashes of their shingles (runs of
+/// consecutive nodes), and the similarity of two bodies is the proportion of fingerprints that
+/// they share.
+#[derive(Debug, Clone, Copy)]
+pub struct SimilarityDetector {
+    min_nodes: usize,
+    shingle_size: usize,
+    window_size: usize,
+}
+
+impl Default for SimilarityDetector {
+    fn default() -> Self {
+        Self {
+            min_nodes: 30,
+            shingle_size: 5,
+            window_size: 4,
+        }
+    }
+}
+
+impl SimilarityDetector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Skip bodies with fewer nodes than this, since small functions tend to look alike.
+    pub fn with_min_nodes(mut self, min_nodes: usize) -> Self {
+        self.min_nodes = min_nodes;
+        self
+    }
+
+    /// The number of consecutive nodes that are hashed together. Larger shingles make the
+    /// comparison stricter about the order of the code.
+    pub fn with_shingle_size(mut self, shingle_size: usize) -> Self {
+        self.shingle_size = shingle_size.max(1);
+        self
+    }
+
+    /// The number of shingles that a fingerprint is picked from. Larger windows keep fewer
+    /// fingerprints, which is faster but less precise.
+    pub fn with_window_size(mut self, window_size: usize) -> Self {
+        self.window_size = window_size.max(1);
+        self
+    }
+
+    /// Find every pair of functions and methods whose similarity is at least the threshold, from
+    /// most to least similar.
+    pub fn find(&self, files: &[ParsedFile], threshold: f32) -> Vec<SimilarPair> {
+        let bodies = files
+            .iter()
+            .flat_map(|file| bodies(file))
+            .filter_map(|(function, statements)| {
+                let sequence = sequence(statements);
+
+                if sequence.len() < self.min_nodes {
+                    return None;
+                }
+
+                Some((function, self.fingerprints(&sequence)))
+            })
+            .collect::<Vec<_>>();
+
+        let mut pairs = Vec::new();
+
+        for (i, (first, a)) in bodies.iter().enumerate() {
+            for (second, b) in bodies.iter().skip(i + 1) {
+                let similarity = similarity(a, b);
+
+                if similarity >= threshold {
+                    pairs.push(SimilarPair {
+                        first: first.clone(),
+                        second: second.clone(),
+                        similarity,
+                    });
+                }
+            }
+        }
+
+        pairs.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+        pairs
+    }
+
+    fn fingerprints(&self, sequence: &[u64]) -> HashSet<u64> {
+        if sequence.is_empty() {
+            return HashSet::new();
+        }
+
+        let shingles = sequence
+            .windows(self.shingle_size.min(sequence.len()))
+            .map(hash)
+            .collect::<Vec<_>>();
+
+        // Keep the smallest hash in every window of shingles, which guarantees that any run of
+        // matching nodes that's at least a window long shares a fingerprint.
+        shingles
+            .windows(self.window_size.min(shingles.len()))
+            .filter_map(|window| window.iter().min().copied())
+            .collect()
+    }
+}
+
+fn hash(values: &[u64]) -> u64 {
+    let mut fingerprinter = Fingerprinter::new();
+
+    for value in values {
+        fingerprinter.write(&value.to_le_bytes());
+    }
+
+    fingerprinter.finish()
+}
+
+fn similarity(a: &HashSet<u64>, b: &HashSet<u64>) -> f32 {
+    let union = a.union(b).count();
+
+    if union == 0 {
+        return 0.0;
+    }
+
+    a.intersection(b).count() as f32 / union as f32
+}
+
+/// The functions and methods with bodies in a file, including ones nested inside of other code.
+fn bodies<'a>(file: &ParsedFile<'a>) -> Vec<(SimilarFunction, &'a [Statement])> {
+    let mut bodies = Vec::new();
+    // The class-likes that we're inside of, along with their depth.
+    let mut classes: Vec<(ByteString, usize)> = Vec::new();
+
+    for (node, depth) in file.ast.iter_nodes() {
+        while classes.last().is_some_and(|(_, class)| *class >= depth) {
+            classes.pop();
+        }
+
+        if let Some(name) = class_name(node) {
+            classes.push((name, depth));
+        } else if let Some(function) = node.as_function_statement() {
+            bodies.push((
+                SimilarFunction {
+                    file: file.file,
+                    name: function.name.symbol().clone(),
+                    span: function.span,
+                },
+                function.body.statements.as_slice(),
+            ));
+        } else if let Some(method) = node.as_method() {
+            let MethodBodyKind::Concrete(body) = &method.body.kind else {
+                continue;
+            };
+
+            let mut name = classes
+                .last()
+                .map(|(class, _)| class.clone())
+                .unwrap_or_default();
+            name.extend_with_bytes(b"::");
+            name.extend(&method.name.symbol);
+
+            bodies.push((
+                SimilarFunction {
+                    file: file.file,
+                    name,
+                    span: method.span,
+                },
+                body.statements.as_slice(),
+            ));
+        }
+    }
+
+    bodies
+}
+
+fn class_name(node: Node) -> Option<ByteString> {
+    let name = if let Some(class) = node.as_class_statement() {
+        &class.name
+    } else if let Some(r#trait) = node.as_trait_statement() {
+        &r#trait.name
+    } else if let Some(interface) = node.as_interface_statement() {
+        &interface.name
+    } else if let Some(r#enum) = node.as_unit_enum_statement() {
+        &r#enum.name
+    } else if let Some(r#enum) = node.as_backed_enum_statement() {
+        &r#enum.name
+    } else if node.is_anonymous_class_expression() {
+        return Some(ByteString::from(b"class@anonymous"));
+    } else {
+        return None;
+    };
+
+    Some(name.symbol().clone())
+}
+
+/// The kinds of nodes in a body and their depth, in the order that they appear. Comments are left
+/// out, since they don't change what the code does.
+fn sequence(statements: &[Statement]) -> Vec<u64> {
+    let mut sequence = Vec::new();
+    let mut nodes = statements.iter_nodes();
+
+    while let Some((node, depth)) = nodes.next() {
+        let name = node.name();
+
+        if name.starts_with("Comment") || name.starts_with("DocBlock") {
+            nodes.skip_children();
+            continue;
+        }
+
+        let mut fingerprinter = Fingerprinter::new();
+        fingerprinter.write_tag(name);
+        fingerprinter.write_usize(depth);
+
+        sequence.push(fingerprinter.finish());
+    }
+
+    sequence
+}
@@ -0,0 +1,317 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use pxp_ast::{
+    constants::ConstantValue,
+    visitor::{walk_if_statement, walk_ternary_expression, Visitor},
+    *,
+};
+use pxp_bytestring::{ByteStr, ByteString};
+use pxp_span::{IsSpanned, Span};
+
+/// What's known about the environment that the code is going to run in.
+///
+/// Conditions that compare `PHP_VERSION_ID`, `PHP_OS_FAMILY` or a known constant against a
+/// literal, or check whether a known constant is `defined()`, are folded to `true` or `false`. The
+/// branches that can't run in the target are skipped by the [`AnalysisProfile`] and the
+/// [`Analyser`], so code that only runs on a different version or platform isn't reported.
+///
+/// Anything that isn't configured is unknown, so both sides of a check against it are assumed to
+/// run. Constants that aren't in `defined_constants` might or might not be defined.
+///
+/// [`AnalysisProfile`]: crate::AnalysisProfile
+/// [`Analyser`]: crate::Analyser
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TargetEnvironment {
+    /// The value of `PHP_VERSION_ID`, e.g. `80100` for PHP 8.1.
+    pub php_version_id: Option<i64>,
+    /// The value of `PHP_OS_FAMILY`, e.g. `Windows` or `Linux`.
+    pub os_family: Option<ByteString>,
+    /// The constants that are defined, along with their values.
+    pub defined_constants: HashMap<ByteString, ConstantValue>,
+}
+
+impl TargetEnvironment {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_php_version_id(mut self, php_version_id: i64) -> Self {
+        self.php_version_id = Some(php_version_id);
+        self
+    }
+
+    pub fn with_os_family(mut self, os_family: impl Into<ByteString>) -> Self {
+        self.os_family = Some(os_family.into());
+        self
+    }
+
+    pub fn with_constant(mut self, name: impl Into<ByteString>, value: ConstantValue) -> Self {
+        self.defined_constants.insert(name.into(), value);
+        self
+    }
+
+    /// The value of a global constant in the target, if it's known.
+    pub fn constant(&self, name: &[u8]) -> Option<ConstantValue> {
+        let name = name.strip_prefix(b"\\").unwrap_or(name);
+        let version = self.php_version_id;
+
+        match name {
+            b"PHP_VERSION_ID" => version.map(ConstantValue::Int),
+            b"PHP_MAJOR_VERSION" => version.map(|version| ConstantValue::Int(version / 10000)),
+            b"PHP_MINOR_VERSION" => version.map(|version| ConstantValue::Int(version / 100 % 100)),
+            b"PHP_OS_FAMILY" => self.os_family.clone().map(ConstantValue::String),
+            _ => self.defined_constants.get(ByteStr::new(name)).cloned(),
+        }
+    }
+
+    /// Fold a condition to `true` or `false`, or `None` if it depends on something that isn't
+    /// known about the target.
+    pub fn evaluate(&self, condition: &Expression) -> Option<bool> {
+        match &condition.kind {
+            ExpressionKind::Parenthesized(inner) => self.evaluate(&inner.expr),
+            ExpressionKind::LogicalOperation(operation) => match &operation.kind {
+                LogicalOperationKind::Not { right, .. } => self.evaluate(right).map(|value| !value),
+                LogicalOperationKind::And { left, right, .. }
+                | LogicalOperationKind::LogicalAnd { left, right, .. } => {
+                    match (self.evaluate(left), self.evaluate(right)) {
+                        (Some(false), _) | (_, Some(false)) => Some(false),
+                        (Some(true), Some(true)) => Some(true),
+                        _ => None,
+                    }
+                }
+                LogicalOperationKind::Or { left, right, .. }
+                | LogicalOperationKind::LogicalOr { left, right, .. } => {
+                    match (self.evaluate(left), self.evaluate(right)) {
+                        (Some(true), _) | (_, Some(true)) => Some(true),
+                        (Some(false), Some(false)) => Some(false),
+                        _ => None,
+                    }
+                }
+                LogicalOperationKind::LogicalXor { left, right, .. } => {
+                    Some(self.evaluate(left)? != self.evaluate(right)?)
+                }
+            },
+            ExpressionKind::ComparisonOperation(operation) => self.compare(&operation.kind),
+            ExpressionKind::FunctionCall(call) => self.defined(call),
+            _ => truthy(&self.value(condition)?),
+        }
+    }
+
+    /// Find the branches of `if` statements and ternaries that can't run in the target.
+    pub fn dead_branches(&self, ast: &[Statement]) -> DeadBranches {
+        let mut visitor = DeadBranchVisitor {
+            target: self,
+            spans: Vec::new(),
+        };
+
+        visitor.visit(ast);
+
+        DeadBranches {
+            spans: visitor.spans,
+        }
+    }
+
+    fn value(&self, expression: &Expression) -> Option<ConstantValue> {
+        match expression.evaluate_constant().ok()? {
+            ConstantValue::Constant(name) => self.constant(&name),
+            // The value of a class constant depends on the code, not on the target.
+            ConstantValue::ClassConstant { .. } => None,
+            value => Some(value),
+        }
+    }
+
+    fn compare(&self, operation: &ComparisonOperationKind) -> Option<bool> {
+        let (left, right) = match operation {
+            ComparisonOperationKind::Equal { left, right, .. }
+            | ComparisonOperationKind::Identical { left, right, .. }
+            | ComparisonOperationKind::NotEqual { left, right, .. }
+            | ComparisonOperationKind::AngledNotEqual { left, right, .. }
+            | ComparisonOperationKind::NotIdentical { left, right, .. }
+            | ComparisonOperationKind::LessThan { left, right, .. }
+            | ComparisonOperationKind::GreaterThan { left, right, .. }
+            | ComparisonOperationKind::LessThanOrEqual { left, right, .. }
+            | ComparisonOperationKind::GreaterThanOrEqual { left, right, .. } => (left, right),
+            ComparisonOperationKind::Spaceship { .. } => return None,
+        };
+
+        let (left, right) = (self.value(left)?, self.value(right)?);
+
+        match operation {
+            ComparisonOperationKind::Identical { .. } => identical(&left, &right),
+            ComparisonOperationKind::NotIdentical { .. } => identical(&left, &right).map(|v| !v),
+            ComparisonOperationKind::Equal { .. } => Some(order(&left, &right)?.is_eq()),
+            ComparisonOperationKind::NotEqual { .. }
+            | ComparisonOperationKind::AngledNotEqual { .. } => Some(order(&left, &right)?.is_ne()),
+            ComparisonOperationKind::LessThan { .. } => Some(order(&left, &right)?.is_lt()),
+            ComparisonOperationKind::GreaterThan { .. } => Some(order(&left, &right)?.is_gt()),
+            ComparisonOperationKind::LessThanOrEqual { .. } => Some(order(&left, &right)?.is_le()),
+            ComparisonOperationKind::GreaterThanOrEqual { .. } => {
+                Some(order(&left, &right)?.is_ge())
+            }
+            ComparisonOperationKind::Spaceship { .. } => None,
+        }
+    }
+
+    /// `defined('NAME')` is only known to be `true`, since a constant that isn't configured might
+    /// still be defined by the code.
+    fn defined(&self, call: &FunctionCallExpression) -> Option<bool> {
+        let ExpressionKind::Name(name) = &call.target.kind else {
+            return None;
+        };
+
+        let function = match &name.kind {
+            NameKind::Resolved(name) => &name.resolved,
+            NameKind::Unresolved(name) => &name.symbol,
+            NameKind::Special(_) => return None,
+        };
+
+        if !function
+            .strip_prefix(b"\\")
+            .unwrap_or(function)
+            .eq_ignore_ascii_case(b"defined")
+        {
+            return None;
+        }
+
+        let [Argument::Positional(argument)] = &call.arguments.arguments[..] else {
+            return None;
+        };
+
+        let constant = argument.value.evaluate_constant().ok()?;
+
+        self.constant(constant.as_string()?).map(|_| true)
+    }
+}
+
+/// Whether two values are identical, if the comparison doesn't depend on the rest of the code.
+fn identical(left: &ConstantValue, right: &ConstantValue) -> Option<bool> {
+    match (left, right) {
+        (ConstantValue::Array(_), _) | (_, ConstantValue::Array(_)) => None,
+        _ => Some(left == right),
+    }
+}
+
+/// Order two scalar values of the same kind. Loose comparisons between different kinds follow
+/// PHP's juggling rules, so they're left unknown.
+fn order(left: &ConstantValue, right: &ConstantValue) -> Option<Ordering> {
+    match (left, right) {
+        (ConstantValue::Int(left), ConstantValue::Int(right)) => Some(left.cmp(right)),
+        (ConstantValue::Int(left), ConstantValue::Float(right)) => {
+            (*left as f64).partial_cmp(right)
+        }
+        (ConstantValue::Float(left), ConstantValue::Int(right)) => {
+            left.partial_cmp(&(*right as f64))
+        }
+        (ConstantValue::Float(left), ConstantValue::Float(right)) => left.partial_cmp(right),
+        (ConstantValue::String(left), ConstantValue::String(right)) => Some(left.cmp(right)),
+        (ConstantValue::Bool(left), ConstantValue::Bool(right)) => Some(left.cmp(right)),
+        (ConstantValue::Null, ConstantValue::Null) => Some(Ordering::Equal),
+        _ => None,
+    }
+}
+
+fn truthy(value: &ConstantValue) -> Option<bool> {
+    match value {
+        ConstantValue::Null => Some(false),
+        ConstantValue::Bool(value) => Some(*value),
+        ConstantValue::Int(value) => Some(*value != 0),
+        ConstantValue::Float(value) => Some(*value != 0.0),
+        ConstantValue::String(value) => Some(!value.is_empty() && &value[..] != b"0"),
+        ConstantValue::Array(items) => Some(!items.is_empty()),
+        ConstantValue::Constant(_) | ConstantValue::ClassConstant { .. } => None,
+    }
+}
+
+/// The parts of a file that can't run in a [`TargetEnvironment`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeadBranches {
+    spans: Vec<Span>,
+}
+
+impl DeadBranches {
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Whether the given span is inside of a branch that can't run.
+    pub fn contains(&self, span: Span) -> bool {
+        self.spans
+            .iter()
+            .any(|dead| dead.start <= span.start && span.end <= dead.end)
+    }
+}
+
+struct DeadBranchVisitor<'a> {
+    target: &'a TargetEnvironment,
+    spans: Vec<Span>,
+}
+
+impl<'a> DeadBranchVisitor<'a> {
+    /// Mark the branches of an `if` statement that can't run. `None` is the `else` branch.
+    fn branches(&mut self, branches: Vec<(Option<&Expression>, Span)>) {
+        // Once a branch is known to be taken, none of the ones after it can run.
+        let mut taken = false;
+
+        for (condition, span) in branches {
+            let value = condition.map_or(Some(true), |condition| self.target.evaluate(condition));
+
+            if taken || value == Some(false) {
+                self.spans.push(span);
+            }
+
+            taken |= value == Some(true);
+        }
+    }
+}
+
+impl<'a> Visitor for DeadBranchVisitor<'a> {
+    fn visit_if_statement(&mut self, node: &IfStatement) {
+        let mut branches = vec![(Some(&node.condition), Span::missing())];
+
+        match &node.body {
+            IfStatementBody::Statement(body) => {
+                branches[0].1 = body.statement.span();
+                branches.extend(
+                    body.elseifs
+                        .iter()
+                        .map(|elseif| (Some(&elseif.condition), elseif.span)),
+                );
+                branches.extend(body.r#else.iter().map(|r#else| (None, r#else.span)));
+            }
+            IfStatementBody::Block(body) => {
+                let end = body
+                    .elseifs
+                    .first()
+                    .map(|elseif| elseif.span.start)
+                    .or(body.r#else.as_ref().map(|r#else| r#else.span.start))
+                    .unwrap_or(body.endif.start);
+
+                branches[0].1 = Span::new(body.colon.end, end);
+                branches.extend(
+                    body.elseifs
+                        .iter()
+                        .map(|elseif| (Some(&elseif.condition), elseif.span)),
+                );
+                branches.extend(body.r#else.iter().map(|r#else| (None, r#else.span)));
+            }
+        }
+
+        self.branches(branches);
+
+        walk_if_statement(self, node);
+    }
+
+    fn visit_ternary_expression(&mut self, node: &TernaryExpression) {
+        match self.target.evaluate(&node.condition) {
+            Some(true) => self.spans.push(node.r#else.span),
+            Some(false) => self.spans.push(node.then.span),
+            None => {}
+        }
+
+        walk_ternary_expression(self, node);
+    }
+}
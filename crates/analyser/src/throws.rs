@@ -0,0 +1,583 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use pxp_ast::{
+    visitor::{
+        walk_function_call_expression, walk_method_call_expression, walk_new_expression,
+        walk_nullsafe_method_call_expression, walk_static_method_call_expression,
+        walk_throw_expression, Visitor,
+    },
+    *,
+};
+use pxp_bytestring::{ByteStr, ByteString};
+use pxp_index::{ReflectionClass, ReflectionFunctionLike};
+use pxp_span::Span;
+use pxp_type::Type;
+
+use crate::{passes::resolve_function, AnalyserContext};
+
+/// An exception that can escape from a function.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Thrown {
+    pub exception: ResolvedName,
+    /// The `throw` expression or call inside of the function that the exception comes from.
+    pub span: Span,
+}
+
+/// A function or method whose body is part of the AST being analysed.
+#[derive(Debug, Clone)]
+pub(crate) struct Declaration<'a> {
+    key: ByteString,
+    /// The name of the function, or `Class::method`.
+    pub(crate) name: ByteString,
+    pub(crate) span: Span,
+    namespace: Option<ByteString>,
+    class: Option<ByteString>,
+    statements: &'a [Statement],
+    pub(crate) documented: Vec<ResolvedName>,
+}
+
+/// Works out which exceptions can escape from the functions and methods declared in an AST.
+///
+/// The throw set of a function is made up of the exceptions that it throws itself and the
+/// exceptions that the functions it calls can throw, minus the ones that are caught by an
+/// enclosing `try`. A function that is documented with `@throws` tags is trusted to throw exactly
+/// those exceptions when it's called. Otherwise its body is analysed if it's part of the AST, and
+/// it's assumed not to throw anything if it isn't.
+///
+/// Recursive calls are assumed not to throw anything new, so functions that call each other only
+/// report the exceptions that are thrown on the way into the cycle.
+pub struct ThrowAnalysis<'a> {
+    context: &'a AnalyserContext<'a>,
+    // Keyed by the lowercased name, since function and method names are case-insensitive.
+    declarations: HashMap<ByteString, Declaration<'a>>,
+    computed: HashMap<ByteString, Vec<Thrown>>,
+    visiting: HashSet<ByteString>,
+    // The functions that were still being visited when a recursive call to them was found. A throw
+    // set that depends on one of them is incomplete, so it isn't cached.
+    cycles: HashSet<ByteString>,
+}
+
+impl<'a> ThrowAnalysis<'a> {
+    pub fn new(context: &'a AnalyserContext<'a>, ast: &'a [Statement]) -> Self {
+        let mut collector = DeclarationCollector {
+            context,
+            namespace: None,
+            declarations: HashMap::new(),
+        };
+
+        collector.statements(ast);
+
+        Self {
+            context,
+            declarations: collector.declarations,
+            computed: HashMap::new(),
+            visiting: HashSet::new(),
+            cycles: HashSet::new(),
+        }
+    }
+
+    /// The exceptions that can escape from the given function, which has to be declared in the AST.
+    pub fn function(&mut self, name: &ByteStr) -> Vec<Thrown> {
+        self.throws(&function_key(name))
+    }
+
+    /// The exceptions that can escape from the given method, which has to be declared in the AST.
+    pub fn method(&mut self, class: &ByteStr, method: &ByteStr) -> Vec<Thrown> {
+        self.throws(&method_key(class, method))
+    }
+
+    pub(crate) fn declarations(&self) -> Vec<Declaration<'a>> {
+        let mut declarations = self.declarations.values().cloned().collect::<Vec<_>>();
+
+        declarations.sort_by_key(|declaration| declaration.span.start);
+        declarations
+    }
+
+    pub(crate) fn declaration(&mut self, declaration: &Declaration) -> Vec<Thrown> {
+        self.throws(&declaration.key)
+    }
+
+    /// Check whether an exception is the same class as, or a subclass of, the given class.
+    pub(crate) fn is_a(&self, exception: &ByteStr, class: &ByteStr) -> bool {
+        // Every exception implements `Throwable`, even if its ancestors aren't indexed.
+        exception.eq_ignore_ascii_case(class)
+            || class.eq_ignore_ascii_case(b"Throwable")
+            || self.context.index().is_subclass_of(exception, class)
+    }
+
+    fn throws(&mut self, key: &ByteString) -> Vec<Thrown> {
+        if let Some(throws) = self.computed.get(key) {
+            return throws.clone();
+        }
+
+        let Some(declaration) = self.declarations.get(key).cloned() else {
+            return Vec::new();
+        };
+
+        if !self.visiting.insert(key.clone()) {
+            self.cycles.insert(key.clone());
+
+            return Vec::new();
+        }
+
+        let outer = std::mem::take(&mut self.cycles);
+
+        let mut visitor = ThrowVisitor {
+            analysis: self,
+            namespace: declaration.namespace.clone(),
+            class: declaration.class.clone(),
+            throws: Vec::new(),
+        };
+
+        visitor.visit(declaration.statements);
+
+        let throws = visitor.throws;
+
+        self.visiting.remove(key);
+        self.cycles.remove(key);
+
+        if self.cycles.is_empty() {
+            self.computed.insert(key.clone(), throws.clone());
+        }
+
+        self.cycles.extend(outer);
+
+        throws
+    }
+
+    /// The exceptions that calling the given function can throw.
+    fn callee_throws(
+        &mut self,
+        documented: &[ResolvedName],
+        key: &ByteString,
+    ) -> Vec<ResolvedName> {
+        if !documented.is_empty() {
+            return documented.to_vec();
+        }
+
+        self.throws(key)
+            .into_iter()
+            .map(|thrown| thrown.exception)
+            .collect()
+    }
+
+    fn function_call_throws(
+        &mut self,
+        namespace: Option<&ByteString>,
+        name: &Name,
+    ) -> Vec<ResolvedName> {
+        let function = resolve_function(self.context.index(), namespace, name);
+
+        match function {
+            Some(function) => {
+                self.callee_throws(function.get_throws(), &function_key(function.get_name()))
+            }
+            None => Vec::new(),
+        }
+    }
+
+    fn method_call_throws(
+        &mut self,
+        class: ReflectionClass<'a>,
+        method: &ByteStr,
+    ) -> Vec<ResolvedName> {
+        let index = self.context.index();
+        let mut pending = VecDeque::from([class.name().to_bytestring()]);
+        let mut visited = HashSet::new();
+
+        // The method is looked up in the class itself, then the traits that it uses and its ancestors.
+        while let Some(name) = pending.pop_front() {
+            if !visited.insert(name.clone()) {
+                continue;
+            }
+
+            let Some(class) = index.get_class(name) else {
+                continue;
+            };
+
+            let declared = class
+                .get_methods()
+                .into_iter()
+                .find(|candidate| candidate.get_name().eq_ignore_ascii_case(method));
+
+            if let Some(declared) = declared {
+                return self.callee_throws(
+                    declared.get_throws(),
+                    &method_key(class.name(), declared.get_name()),
+                );
+            }
+
+            pending.extend(
+                class
+                    .get_trait_names()
+                    .chain(class.get_parent_name())
+                    .map(|name| name.to_bytestring()),
+            );
+        }
+
+        Vec::new()
+    }
+}
+
+fn function_key(name: &ByteStr) -> ByteString {
+    ByteString::from(name.to_ascii_lowercase())
+}
+
+fn method_key(class: &ByteStr, method: &ByteStr) -> ByteString {
+    let mut key = function_key(class);
+    key.extend_with_bytes(b"::");
+    key.extend(&function_key(method));
+    key
+}
+
+/// Finds the functions and methods declared in an AST. Declarations nested inside of other
+/// statements, like a function declared inside of an `if`, aren't found.
+struct DeclarationCollector<'a> {
+    context: &'a AnalyserContext<'a>,
+    namespace: Option<ByteString>,
+    declarations: HashMap<ByteString, Declaration<'a>>,
+}
+
+impl<'a> DeclarationCollector<'a> {
+    fn statements(&mut self, statements: &'a [Statement]) {
+        for statement in statements {
+            match &statement.kind {
+                StatementKind::Namespace(namespace) => match namespace.as_ref() {
+                    NamespaceStatement::Unbraced(namespace) => {
+                        self.namespace = Some(namespace.name.symbol.clone());
+                        self.statements(&namespace.statements);
+                        self.namespace = None;
+                    }
+                    NamespaceStatement::Braced(namespace) => {
+                        self.namespace = namespace.name.as_ref().map(|name| name.symbol.clone());
+                        self.statements(&namespace.body.statements);
+                        self.namespace = None;
+                    }
+                },
+                StatementKind::Block(block) => self.statements(&block.statements),
+                StatementKind::Function(function) => self.function(function),
+                StatementKind::Class(class) => self.members(&class.name, class.body.members.iter()),
+                StatementKind::Trait(r#trait) => {
+                    self.members(&r#trait.name, r#trait.body.members.iter())
+                }
+                StatementKind::Interface(interface) => {
+                    self.members(&interface.name, interface.body.members.iter())
+                }
+                StatementKind::UnitEnum(r#enum) => self.members(
+                    &r#enum.name,
+                    r#enum
+                        .body
+                        .members
+                        .iter()
+                        .filter_map(|member| match member {
+                            UnitEnumMember::Classish(member) => Some(member),
+                            UnitEnumMember::Case(_) => None,
+                        }),
+                ),
+                StatementKind::BackedEnum(r#enum) => self.members(
+                    &r#enum.name,
+                    r#enum
+                        .body
+                        .members
+                        .iter()
+                        .filter_map(|member| match member {
+                            BackedEnumMember::Classish(member) => Some(member),
+                            BackedEnumMember::Case(_) => None,
+                        }),
+                ),
+                _ => {}
+            }
+        }
+    }
+
+    fn function(&mut self, node: &'a FunctionStatement) {
+        let Some(name) = node.name.as_resolved() else {
+            return;
+        };
+
+        let documented = self
+            .context
+            .index()
+            .get_function(name.resolved.clone())
+            .map(|function| function.get_throws().to_vec())
+            .unwrap_or_default();
+
+        let key = function_key(name.resolved.as_ref());
+
+        self.declarations.insert(
+            key.clone(),
+            Declaration {
+                key,
+                name: name.resolved.clone(),
+                span: node.name.span,
+                namespace: self.namespace.clone(),
+                class: None,
+                statements: &node.body.statements,
+                documented,
+            },
+        );
+    }
+
+    fn members(&mut self, class: &Name, members: impl Iterator<Item = &'a ClassishMember>) {
+        let Some(class) = class.as_resolved() else {
+            return;
+        };
+
+        let reflection = self.context.index().get_class(class.resolved.clone());
+
+        for member in members {
+            let ClassishMember::Method(method) = member else {
+                continue;
+            };
+
+            let MethodBodyKind::Concrete(body) = &method.body.kind else {
+                continue;
+            };
+
+            let documented = reflection
+                .as_ref()
+                .and_then(|class| class.get_method(method.name.symbol.as_ref()))
+                .map(|method| method.get_throws().to_vec())
+                .unwrap_or_default();
+
+            let mut name = class.resolved.clone();
+            name.extend_with_bytes(b"::");
+            name.extend(&method.name.symbol);
+
+            let key = method_key(class.resolved.as_ref(), method.name.symbol.as_ref());
+
+            self.declarations.insert(
+                key.clone(),
+                Declaration {
+                    key,
+                    name,
+                    span: method.name.span,
+                    namespace: self.namespace.clone(),
+                    class: Some(class.resolved.clone()),
+                    statements: &body.statements,
+                    documented,
+                },
+            );
+        }
+    }
+}
+
+/// Collects the exceptions that can escape from the body of a single function.
+struct ThrowVisitor<'a, 'b> {
+    analysis: &'b mut ThrowAnalysis<'a>,
+    // The namespace that the function is declared in, used to resolve unqualified function calls.
+    namespace: Option<ByteString>,
+    // The class that the function is declared in, used to resolve `$this`, `self` and `static`.
+    class: Option<ByteString>,
+    throws: Vec<Thrown>,
+}
+
+impl<'a, 'b> ThrowVisitor<'a, 'b> {
+    fn add(&mut self, exceptions: impl IntoIterator<Item = ResolvedName>, span: Span) {
+        for exception in exceptions {
+            if self.throws.iter().any(|thrown| {
+                thrown
+                    .exception
+                    .resolved
+                    .eq_ignore_ascii_case(&exception.resolved)
+            }) {
+                continue;
+            }
+
+            self.throws.push(Thrown { exception, span });
+        }
+    }
+
+    fn current_class(&self) -> Option<ReflectionClass<'a>> {
+        self.analysis.context.index().get_class(self.class.clone()?)
+    }
+
+    fn resolve_class_expression(&self, target: &Expression) -> Option<ReflectionClass<'a>> {
+        let index = self.analysis.context.index();
+
+        match &target.kind {
+            ExpressionKind::Name(name) => match &name.kind {
+                NameKind::Resolved(name) => index.get_class(name.resolved.clone()),
+                NameKind::Special(special) => match special.kind {
+                    SpecialNameKind::Self_ | SpecialNameKind::Static => self.current_class(),
+                    SpecialNameKind::Parent => {
+                        index.get_class(self.current_class()?.get_parent_name()?.to_bytestring())
+                    }
+                },
+                NameKind::Unresolved(_) => None,
+            },
+            ExpressionKind::Self_(_) | ExpressionKind::Static(_) => self.current_class(),
+            ExpressionKind::Parent(_) => {
+                index.get_class(self.current_class()?.get_parent_name()?.to_bytestring())
+            }
+            _ => None,
+        }
+    }
+
+    fn resolve_receiver(&self, target: &Expression) -> Option<ReflectionClass<'a>> {
+        if let ExpressionKind::Variable(variable) = &target.kind {
+            if variable.is_simple() && variable.to_simple().stripped == b"this" {
+                return self.current_class();
+            }
+        }
+
+        match self.analysis.context.types().resolve(target.id) {
+            Type::Named(name) => self
+                .analysis
+                .context
+                .index()
+                .get_class(name.resolved.clone()),
+            _ => None,
+        }
+    }
+
+    fn add_method_call(
+        &mut self,
+        class: Option<ReflectionClass<'a>>,
+        method: &Expression,
+        span: Span,
+    ) {
+        let (Some(class), ExpressionKind::Identifier(identifier)) = (class, &method.kind) else {
+            return;
+        };
+
+        if !identifier.is_simple() {
+            return;
+        }
+
+        let throws = self
+            .analysis
+            .method_call_throws(class, identifier.to_simple().symbol.as_ref());
+
+        self.add(throws, span);
+    }
+}
+
+/// The classes that a thrown value can be an instance of.
+fn exception_classes(ty: &Type<ResolvedName>) -> Vec<ResolvedName> {
+    match ty {
+        Type::Named(name) => vec![name.clone()],
+        Type::Union(types) => types.iter().flat_map(exception_classes).collect(),
+        _ => Vec::new(),
+    }
+}
+
+impl<'a, 'b> Visitor for ThrowVisitor<'a, 'b> {
+    fn visit_throw_expression(&mut self, node: &ThrowExpression) {
+        let exceptions = exception_classes(self.analysis.context.types().resolve(node.value.id));
+
+        self.add(exceptions, node.span);
+
+        walk_throw_expression(self, node);
+    }
+
+    fn visit_try_statement(&mut self, node: &TryStatement) {
+        let outer = std::mem::take(&mut self.throws);
+
+        self.visit(&node.body);
+
+        let thrown = std::mem::replace(&mut self.throws, outer);
+
+        for thrown in thrown {
+            let caught = node.catches.iter().any(|catch| {
+                let names = match &catch.types.kind {
+                    CatchTypeKind::Identifier(identifier) => vec![&identifier.name],
+                    CatchTypeKind::Union(union) => union.names.iter().collect(),
+                };
+
+                names
+                    .into_iter()
+                    .filter_map(|name| name.as_resolved())
+                    .any(|name| {
+                        self.analysis
+                            .is_a(thrown.exception.resolved.as_ref(), name.resolved.as_ref())
+                    })
+            });
+
+            if !caught {
+                self.add([thrown.exception], thrown.span);
+            }
+        }
+
+        for catch in node.catches.iter() {
+            self.visit(&catch.body);
+        }
+
+        if let Some(finally) = &node.finally {
+            self.visit(&finally.body);
+        }
+    }
+
+    fn visit_function_call_expression(&mut self, node: &FunctionCallExpression) {
+        if let ExpressionKind::Name(name) = &node.target.kind {
+            let throws = self
+                .analysis
+                .function_call_throws(self.namespace.as_ref(), name);
+
+            self.add(throws, node.span);
+        }
+
+        walk_function_call_expression(self, node);
+    }
+
+    fn visit_method_call_expression(&mut self, node: &MethodCallExpression) {
+        let class = self.resolve_receiver(&node.target);
+
+        self.add_method_call(class, &node.method, node.span);
+
+        walk_method_call_expression(self, node);
+    }
+
+    fn visit_nullsafe_method_call_expression(&mut self, node: &NullsafeMethodCallExpression) {
+        let class = self.resolve_receiver(&node.target);
+
+        self.add_method_call(class, &node.method, node.span);
+
+        walk_nullsafe_method_call_expression(self, node);
+    }
+
+    fn visit_static_method_call_expression(&mut self, node: &StaticMethodCallExpression) {
+        if let (Some(class), Identifier::SimpleIdentifier(method)) =
+            (self.resolve_class_expression(&node.target), &node.method)
+        {
+            let throws = self
+                .analysis
+                .method_call_throws(class, method.symbol.as_ref());
+
+            self.add(throws, node.span);
+        }
+
+        walk_static_method_call_expression(self, node);
+    }
+
+    fn visit_new_expression(&mut self, node: &NewExpression) {
+        if let Some(class) = self.resolve_class_expression(&node.target) {
+            let throws = self
+                .analysis
+                .method_call_throws(class, b"__construct".into());
+
+            self.add(throws, node.span);
+        }
+
+        walk_new_expression(self, node);
+    }
+
+    // Exceptions thrown inside of nested functions and classes don't escape when they're declared.
+    fn visit_function_statement(&mut self, _: &FunctionStatement) {}
+
+    fn visit_closure_expression(&mut self, _: &ClosureExpression) {}
+
+    fn visit_arrow_function_expression(&mut self, _: &ArrowFunctionExpression) {}
+
+    fn visit_anonymous_class_expression(&mut self, _: &AnonymousClassExpression) {}
+
+    fn visit_class_statement(&mut self, _: &ClassStatement) {}
+
+    fn visit_interface_statement(&mut self, _: &InterfaceStatement) {}
+
+    fn visit_trait_statement(&mut self, _: &TraitStatement) {}
+
+    fn visit_unit_enum_statement(&mut self, _: &UnitEnumStatement) {}
+
+    fn visit_backed_enum_statement(&mut self, _: &BackedEnumStatement) {}
+}
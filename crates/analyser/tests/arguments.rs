@@ -0,0 +1,321 @@
+mod common;
+
+use common::analyse_with;
+use pxp_analyser::{AnalyserDiagnostic, ArgumentsPass};
+use pxp_diagnostics::{DiagnosticKind, Severity};
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+
+const API: &str = r#"
+function none() {}
+function one($a) {}
+function two($a, $b) {}
+function defaults($a, $b = 1, $c = 2) {}
+function variadic($a, ...$rest) {}
+function dynamic() { return func_get_args(); }
+function counted($a) { return func_num_args(); }
+function nested() { return function () { return func_get_args(); }; }
+
+class Greeter {
+    public function __construct(string $name, string $greeting = 'Hello') {}
+
+    public function greet(string $punctuation = '!') {}
+
+    public static function make(string $name) {}
+
+    public function merge() { return \func_get_arg(0); }
+}
+
+class NoConstructor {}
+"#;
+
+fn analyse(code: &str) -> Vec<AnalyserDiagnostic> {
+    analyse_with(ArgumentsPass::new(), &format!("<?php {}\n{}", API, code))
+        .into_iter()
+        .map(|diagnostic| diagnostic.kind)
+        .collect()
+}
+
+fn identifiers(code: &str) -> Vec<String> {
+    analyse(code)
+        .iter()
+        .map(|diagnostic| diagnostic.get_identifier())
+        .collect()
+}
+
+#[test]
+fn it_accepts_valid_calls() {
+    assert!(identifiers(
+        r#"
+        none();
+        one(1);
+        defaults(1);
+        defaults(1, 2, 3);
+        defaults(1, c: 3);
+        defaults(b: 2, a: 1);
+        variadic(1, 2, 3, 4);
+        variadic(1, foo: 2);
+        defaults(...[1, 2, 3, 4]);
+        $callback = 'one';
+        $callback(1, 2, 3);
+        new NoConstructor(1, 2);
+        Greeter::make('Ryan');
+        $greeter = new Greeter('Ryan');
+        $greeter->greet();
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_reports_too_few_arguments() {
+    assert_eq!(
+        analyse("defaults();"),
+        vec![AnalyserDiagnostic::TooFewArguments {
+            callable: b"defaults".into(),
+            given: 0,
+            required: 1,
+        }]
+    );
+
+    assert_eq!(
+        identifiers("variadic(); new Greeter; Greeter::make();"),
+        vec![
+            "analyser.too-few-arguments",
+            "analyser.too-few-arguments",
+            "analyser.too-few-arguments"
+        ]
+    );
+}
+
+#[test]
+fn it_reports_too_many_arguments() {
+    assert_eq!(
+        analyse("defaults(1, 2, 3, 4);"),
+        vec![AnalyserDiagnostic::TooManyArguments {
+            callable: b"defaults".into(),
+            given: 4,
+            maximum: 3,
+        }]
+    );
+
+    assert_eq!(
+        identifiers("none(1); (new Greeter('Ryan'))->greet('.', '!');"),
+        vec!["analyser.too-many-arguments", "analyser.too-many-arguments"]
+    );
+}
+
+#[test]
+fn it_reports_too_many_arguments_as_a_warning() {
+    let diagnostics = analyse_with(ArgumentsPass::new(), &format!("<?php {}\nnone(1);", API));
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, Severity::Warning);
+}
+
+#[test]
+fn it_accepts_extra_arguments_to_callables_that_read_them_with_func_get_args() {
+    assert!(identifiers(
+        r#"
+        dynamic(1, 2, 3);
+        counted(1, 2);
+        (new Greeter('Ryan'))->merge(['a'], ['b']);
+        "#
+    )
+    .is_empty());
+
+    // Closures read their own arguments, not the ones passed to the function around them.
+    assert_eq!(
+        identifiers("nested(1);"),
+        vec!["analyser.too-many-arguments"]
+    );
+}
+
+#[test]
+fn it_only_falls_back_to_global_functions_for_unqualified_names() {
+    let code = format!(
+        r#"<?php
+        namespace {{
+            {}
+        }}
+
+        namespace App {{
+            use function Missing\thing as two;
+
+            Missing\one(1, 2);
+            \Missing\one(1, 2);
+            two(1, 2, 3);
+            one(1, 2);
+        }}
+        "#,
+        API
+    );
+
+    assert_eq!(
+        analyse_with(ArgumentsPass::new(), &code)
+            .into_iter()
+            .map(|diagnostic| diagnostic.kind)
+            .collect::<Vec<_>>(),
+        vec![AnalyserDiagnostic::TooManyArguments {
+            callable: b"one".into(),
+            given: 2,
+            maximum: 1,
+        }]
+    );
+}
+
+#[test]
+fn it_prefers_functions_in_the_current_namespace_over_global_functions() {
+    let code = r#"<?php
+        namespace App {
+            function one($a, $b, $c) {}
+
+            one(1, 2, 3);
+            one(1);
+        }
+
+        namespace {
+            function one($a) {}
+
+            one(1);
+        }
+        "#;
+
+    assert_eq!(
+        analyse_with(ArgumentsPass::new(), code)
+            .into_iter()
+            .map(|diagnostic| diagnostic.kind)
+            .collect::<Vec<_>>(),
+        vec![AnalyserDiagnostic::TooFewArguments {
+            callable: b"App\\one".into(),
+            given: 1,
+            required: 3,
+        }]
+    );
+}
+
+#[test]
+fn it_only_counts_positional_arguments_as_too_many() {
+    assert_eq!(
+        analyse("two(1, 2, 3, x: 4);"),
+        vec![
+            AnalyserDiagnostic::UnknownNamedArgument {
+                callable: b"two".into(),
+                parameter: b"x".into(),
+            },
+            AnalyserDiagnostic::TooManyArguments {
+                callable: b"two".into(),
+                given: 3,
+                maximum: 2,
+            },
+        ]
+    );
+}
+
+#[test]
+fn it_reports_missing_arguments_when_using_named_arguments() {
+    assert_eq!(
+        analyse("defaults(b: 1);"),
+        vec![AnalyserDiagnostic::MissingArgument {
+            callable: b"defaults".into(),
+            parameter: b"a".into(),
+        }]
+    );
+}
+
+#[test]
+fn it_reports_unknown_named_arguments() {
+    assert_eq!(
+        analyse("one(b: 1);"),
+        vec![
+            AnalyserDiagnostic::UnknownNamedArgument {
+                callable: b"one".into(),
+                parameter: b"b".into(),
+            },
+            AnalyserDiagnostic::MissingArgument {
+                callable: b"one".into(),
+                parameter: b"a".into(),
+            }
+        ]
+    );
+
+    assert_eq!(
+        analyse("new Greeter('Ryan', greting: 'Hi');"),
+        vec![AnalyserDiagnostic::UnknownNamedArgument {
+            callable: b"Greeter::__construct".into(),
+            parameter: b"greting".into(),
+        }]
+    );
+}
+
+#[test]
+fn it_reports_named_arguments_that_overwrite_previous_arguments() {
+    assert_eq!(
+        analyse("defaults(1, a: 2);"),
+        vec![
+            AnalyserDiagnostic::NamedArgumentOverwritesPreviousArgument {
+                callable: b"defaults".into(),
+                parameter: b"a".into(),
+            }
+        ]
+    );
+
+    assert_eq!(
+        identifiers("defaults(1, 2, b: 3);"),
+        vec!["analyser.named-argument-overwrites-previous-argument"]
+    );
+}
+
+#[test]
+fn it_leaves_the_order_of_arguments_to_the_parser() {
+    for (code, parser) in [
+        ("variadic(...[1, 2], 3);", "P098"),
+        ("defaults(a: 1, ...[2, 3]);", "P097"),
+        ("defaults(1, b: 2, b: 3);", "P099"),
+    ] {
+        assert_eq!(analyse(code), vec![], "{code}");
+
+        let result = Parser::parse(Lexer::new(format!("<?php {}", code).as_bytes()));
+        let codes = result
+            .diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.kind.get_code())
+            .collect::<Vec<_>>();
+
+        assert_eq!(codes, vec![parser], "{code}");
+    }
+}
+
+#[test]
+fn it_resolves_this_self_and_parent() {
+    assert_eq!(
+        analyse(
+            r#"
+        class PoliteGreeter extends Greeter {
+            public function __construct() {
+                $this->greet('?', '!');
+                self::make();
+                parent::__construct();
+            }
+        }
+        "#
+        ),
+        vec![
+            AnalyserDiagnostic::TooManyArguments {
+                callable: b"Greeter::greet".into(),
+                given: 2,
+                maximum: 1,
+            },
+            AnalyserDiagnostic::TooFewArguments {
+                callable: b"Greeter::make".into(),
+                given: 0,
+                required: 1,
+            },
+            AnalyserDiagnostic::TooFewArguments {
+                callable: b"Greeter::__construct".into(),
+                given: 0,
+                required: 1,
+            },
+        ]
+    );
+}
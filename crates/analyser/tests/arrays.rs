@@ -0,0 +1,80 @@
+mod common;
+
+use common::analyse_with;
+use pxp_analyser::{AnalyserDiagnostic, ArrayKeyPass};
+
+/// Analyse the code, returning each diagnostic along with the source code that it points at.
+fn analyse(code: &str) -> Vec<(AnalyserDiagnostic, String)> {
+    let source = format!("<?php {}", code);
+
+    analyse_with(ArrayKeyPass::new(), &source)
+        .into_iter()
+        .map(|diagnostic| {
+            let text = source[diagnostic.span.to_range()].to_string();
+
+            (diagnostic.kind, text)
+        })
+        .collect()
+}
+
+fn duplicate(key: &str, text: &str) -> (AnalyserDiagnostic, String) {
+    (
+        AnalyserDiagnostic::DuplicateArrayKey { key: key.into() },
+        text.to_string(),
+    )
+}
+
+fn cast(key: &str, cast: &str, text: &str) -> (AnalyserDiagnostic, String) {
+    (
+        AnalyserDiagnostic::ImplicitArrayKeyCast {
+            key: key.into(),
+            cast: cast.into(),
+        },
+        text.to_string(),
+    )
+}
+
+#[test]
+fn it_reports_keys_that_are_duplicates_after_normalization() {
+    assert_eq!(
+        analyse("$a = ['5' => 'a', 5 => 'b'];"),
+        vec![duplicate("5", "5")]
+    );
+
+    assert_eq!(
+        analyse("$a = [null => 'a', '' => 'b', 'name' => 'c', 'name' => 'd'];"),
+        vec![duplicate("''", "''"), duplicate("'name'", "'name'")]
+    );
+}
+
+#[test]
+fn it_reports_explicit_keys_that_overwrite_implicit_ones() {
+    assert_eq!(
+        analyse("$a = ['a', 'b', 1 => 'c', 5 => 'd', 'e', '6' => 'f'];"),
+        vec![duplicate("1", "1"), duplicate("6", "'6'")]
+    );
+}
+
+#[test]
+fn it_reports_float_and_bool_keys_that_are_cast() {
+    assert_eq!(
+        analyse("$a = [5.9 => 'a', true => 'b', false => 'c', 1 => 'd'];"),
+        vec![
+            cast("5.9", "5", "5.9"),
+            cast("true", "1", "true"),
+            cast("false", "0", "false"),
+            duplicate("1", "1"),
+        ]
+    );
+}
+
+#[test]
+fn it_ignores_keys_that_cannot_be_known() {
+    assert!(analyse(
+        r#"
+        $a = [$key => 'a', $key => 'b', '05' => 'c', 5 => 'd', ...$rest, 0 => 'e'];
+        ['id' => $first, 'id' => $second] = $row;
+        "#
+    )
+    .is_empty());
+}
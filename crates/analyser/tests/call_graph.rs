@@ -0,0 +1,174 @@
+use pxp_analyser::{CallGraph, Callable, ParsedFile};
+use pxp_bytestring::ByteString;
+use pxp_index::{FileId, IndexBuilder};
+use pxp_inference::TypeEngine;
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+
+const SHAPES: &str = r#"<?php
+
+interface Shape {
+    public function area(): float;
+}
+
+class Circle implements Shape {
+    public function __construct(private float $radius) {}
+
+    public function area(): float {
+        return $this->square($this->radius) * 3.14;
+    }
+
+    private function square(float $value): float {
+        return $value * $value;
+    }
+
+    private function unused(): void {}
+}
+
+class Square implements Shape {
+    public function __construct(private float $side) {}
+
+    public function area(): float {
+        return $this->side * $this->side;
+    }
+}
+
+function total(Shape $shape): float {
+    return $shape->area();
+}
+
+function main(): void {
+    $shapes = array_map('make', [1, 2]);
+    $callback = total(...);
+    $callback(new Circle(1));
+}
+
+function make(int $size): Shape {
+    return new Square($size);
+}
+"#;
+
+const STUBS: &str = r#"<?php
+
+/** @param callable $callback */
+function array_map(callable $callback, array $array): array {}
+"#;
+
+fn graph(source: &str) -> (CallGraph, String) {
+    let stubs = Parser::parse(Lexer::new(STUBS.as_bytes()));
+    let result = Parser::parse(Lexer::new(source.as_bytes()));
+
+    let mut builder = IndexBuilder::new();
+    builder.index(FileId::new(0), &stubs.ast);
+    builder.index(FileId::new(1), &result.ast);
+    let index = builder.build();
+
+    let engine = TypeEngine::new(&index);
+    let maps = [engine.infer(&result.ast)];
+    let files = [ParsedFile {
+        file: FileId::new(1),
+        ast: &result.ast,
+    }];
+
+    (CallGraph::build(&files, &index, &maps), source.to_string())
+}
+
+fn function(name: &str) -> Callable {
+    Callable::Function(ByteString::from(name.as_bytes()))
+}
+
+fn method(class: &str, name: &str) -> Callable {
+    Callable::Method {
+        class: ByteString::from(class.as_bytes()),
+        name: ByteString::from(name.as_bytes()),
+    }
+}
+
+#[test]
+fn it_calls_the_interface_method_through_an_interface() {
+    let (graph, source) = graph(SHAPES);
+    let callees = graph.callees_of(&function("total"));
+
+    assert_eq!(callees.len(), 1);
+    assert_eq!(callees[0].callee, method("Shape", "area"));
+    assert_eq!(&source[callees[0].span.to_range()], "$shape->area()");
+
+    let callers = graph.callers_of(&method("Shape", "area"));
+
+    assert_eq!(callers.len(), 1);
+    assert_eq!(callers[0].caller, function("total"));
+}
+
+#[test]
+fn it_resolves_constructors_first_class_callables_and_string_callables() {
+    let (graph, source) = graph(SHAPES);
+    let callees = graph
+        .callees_of(&function("main"))
+        .into_iter()
+        .map(|edge| (edge.callee.clone(), &source[edge.span.to_range()]))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        callees,
+        vec![
+            (function("array_map"), "array_map('make', [1, 2])"),
+            (function("make"), "'make'"),
+            (function("total"), "total(...)"),
+            (Callable::Unknown, "$callback(new Circle(1))"),
+            (method("Circle", "__construct"), "new Circle(1)"),
+        ]
+    );
+}
+
+#[test]
+fn it_reaches_the_implementations_of_a_reachable_interface_method() {
+    let (graph, _) = graph(SHAPES);
+    let reachable = graph.reachable_from(&[function("total")]);
+
+    assert!(reachable.contains(&method("Shape", "area")));
+    assert!(reachable.contains(&method("Circle", "area")));
+    assert!(reachable.contains(&method("Circle", "square")));
+    assert!(reachable.contains(&method("Square", "area")));
+    assert!(!reachable.contains(&function("main")));
+}
+
+#[test]
+fn it_does_not_reach_orphaned_private_methods() {
+    let (graph, _) = graph(SHAPES);
+    let reachable = graph.reachable_from(&[function("main")]);
+
+    assert!(graph.nodes().contains(&method("Circle", "unused")));
+    assert!(!reachable.contains(&method("Circle", "unused")));
+
+    assert!(reachable.contains(&method("Circle", "__construct")));
+    assert!(reachable.contains(&method("Square", "__construct")));
+    assert!(reachable.contains(&Callable::Unknown));
+}
+
+#[test]
+fn it_adds_edges_from_top_level_code_and_to_closures() {
+    let (graph, source) = graph("<?php\n$f = fn ($x) => strlen($x);\n$f('a');");
+    let file = Callable::File(FileId::new(1));
+    let callees = graph.callees_of(&file);
+
+    assert_eq!(callees.len(), 2);
+    assert!(matches!(callees[0].callee, Callable::Closure { .. }));
+    assert_eq!(&source[callees[0].span.to_range()], "fn ($x) => strlen($x)");
+    assert_eq!(callees[1].callee, Callable::Unknown);
+
+    // `strlen` isn't in the index, so the closure calls something unknown.
+    let closure = graph.callees_of(&callees[0].callee);
+
+    assert_eq!(closure.len(), 1);
+    assert_eq!(closure[0].callee, Callable::Unknown);
+}
+
+#[test]
+fn it_exports_dot() {
+    let (graph, _) = graph("<?php\nfunction a() { b(); }\nfunction b() {}\na();");
+
+    assert_eq!(
+        graph.to_dot(),
+        "digraph calls {\n    n0 [label=\"{file}\"];\n    n1 [label=\"a\"];\n    n2 [label=\"b\"];\n    n3 [label=\"{unknown}\"];\n    n1 -> n2;\n    n0 -> n1;\n}\n"
+    );
+}
@@ -0,0 +1,154 @@
+use pxp_analyser::{
+    class_string_references,
+    ClassStringConfidence::{self, *},
+    ClassStringFinder,
+};
+use pxp_index::{FileId, IndexBuilder};
+use pxp_inference::TypeEngine;
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+
+const CONFIG: &str = r#"
+namespace App\Models {
+    class User {}
+    class Post {}
+}
+
+namespace App\Support {
+    class Container {
+        /** @param class-string $abstract */
+        public function make(string $abstract, array $parameters = []) {}
+
+        /** @param class-string ...$classes */
+        public function tag(string $tag, string ...$classes) {}
+    }
+
+    /** @param class-string $class */
+    function resolve(string $class, string $fallback = 'App\Models\Post') {}
+}
+
+namespace {
+    use App\Models\User;
+    use App\Support\Container;
+
+    return [
+        'name' => 'App',
+        'models' => [
+            'user' => User::class,
+            'post' => 'App\\Models\\Post',
+            'admin' => "\\App\\Models\\User",
+        ],
+        'jobs' => [
+            'App\Jobs\SendEmail',
+            'App\Models\Comment',
+            'Post',
+        ],
+        'bindings' => function (Container $container) {
+            $container->make('App\Contracts\Mailer');
+            $container->make(parameters: [], abstract: 'App\Models\User');
+            $container->tag('models', 'App\Models\User', 'App\Models\Tag');
+            App\Support\resolve('App\Services\Cache', 'App\Services\Fallback');
+        },
+    ];
+}
+"#;
+
+/// The source of each reference, along with the name it refers to and the confidence.
+fn references(finder: ClassStringFinder) -> Vec<(String, String, ClassStringConfidence)> {
+    let source = format!("<?php\n{CONFIG}");
+    let result = Parser::parse(Lexer::new(source.as_bytes()));
+
+    let mut builder = IndexBuilder::new();
+    builder.index(FileId::new(0), &result.ast);
+    let index = builder.build();
+
+    let types = TypeEngine::new(&index).infer(&result.ast);
+
+    finder
+        .find(&result.ast, &types, &index)
+        .into_iter()
+        .map(|reference| {
+            (
+                source[reference.span.to_range()].to_string(),
+                reference.name.to_string(),
+                reference.confidence,
+            )
+        })
+        .collect()
+}
+
+fn reference(
+    source: &str,
+    name: &str,
+    confidence: ClassStringConfidence,
+) -> (String, String, ClassStringConfidence) {
+    (source.to_string(), name.to_string(), confidence)
+}
+
+#[test]
+fn it_finds_class_strings_that_are_known_or_certain() {
+    assert_eq!(
+        references(ClassStringFinder::new()),
+        vec![
+            reference("'App\\Models\\Post'", "App\\Models\\Post", High),
+            reference("User::class", "App\\Models\\User", Certain),
+            reference("'App\\\\Models\\\\Post'", "App\\Models\\Post", High),
+            // The span of a double-quoted string doesn't include its quotes.
+            reference("\\\\App\\\\Models\\\\User", "App\\Models\\User", High),
+            reference(
+                "'App\\Contracts\\Mailer'",
+                "App\\Contracts\\Mailer",
+                Certain
+            ),
+            reference("'App\\Models\\User'", "App\\Models\\User", Certain),
+            reference("'App\\Models\\User'", "App\\Models\\User", Certain),
+            reference("'App\\Models\\Tag'", "App\\Models\\Tag", Certain),
+            reference("'App\\Services\\Cache'", "App\\Services\\Cache", Certain),
+        ]
+    );
+}
+
+#[test]
+fn it_finds_strings_that_look_like_unknown_classes_when_enabled() {
+    let references = references(ClassStringFinder::new().with_unknown_classes(true));
+    let low = references
+        .iter()
+        .filter(|(_, _, confidence)| *confidence == Low)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        low,
+        vec![
+            reference("'App\\Jobs\\SendEmail'", "App\\Jobs\\SendEmail", Low),
+            reference("'App\\Models\\Comment'", "App\\Models\\Comment", Low),
+            reference("'App\\Services\\Fallback'", "App\\Services\\Fallback", Low),
+        ]
+    );
+
+    // Every other reference is still found, with the same confidence.
+    assert_eq!(
+        references
+            .into_iter()
+            .filter(|(_, _, confidence)| *confidence != Low)
+            .collect::<Vec<_>>(),
+        self::references(ClassStringFinder::new())
+    );
+}
+
+#[test]
+fn it_reports_each_site_once_with_the_highest_confidence() {
+    let source = "<?php\nclass Foo {}\n/** @param class-string $class */\nfunction make(string $class) {}\nmake('Foo');";
+    let result = Parser::parse(Lexer::new(source.as_bytes()));
+
+    let mut builder = IndexBuilder::new();
+    builder.index(FileId::new(0), &result.ast);
+    let index = builder.build();
+
+    let types = TypeEngine::new(&index).infer(&result.ast);
+    let references = class_string_references(&result.ast, &types, &index);
+
+    assert_eq!(references.len(), 1);
+    assert_eq!(&source[references[0].span.to_range()], "'Foo'");
+    assert_eq!(references[0].confidence, Certain);
+}
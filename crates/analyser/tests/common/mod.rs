@@ -0,0 +1,32 @@
+//! Helpers that are shared between the analyser's integration tests.
+
+// Each test file is compiled as its own crate, and not all of them use every helper.
+#![allow(dead_code)]
+
+use pxp_analyser::{AnalyserContext, AnalyserDiagnostic, Pass};
+use pxp_ast::Statement;
+use pxp_diagnostics::Diagnostic;
+use pxp_index::{FileId, IndexBuilder};
+use pxp_inference::TypeEngine;
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+
+/// Parse, index and infer the types of the given code, then hand the context and AST to `f`.
+///
+/// The code is used as-is, so it needs to start with an opening tag.
+pub fn with_context<T>(code: &str, f: impl FnOnce(AnalyserContext, &[Statement]) -> T) -> T {
+    let result = Parser::parse(Lexer::new(code.as_bytes()));
+
+    let mut builder = IndexBuilder::new();
+    builder.index(FileId::new(0), &result.ast);
+    let index = builder.build();
+
+    let types = TypeEngine::new(&index).infer(&result.ast);
+
+    f(AnalyserContext::new(&index, &types), &result.ast)
+}
+
+/// Run a single pass over the given code.
+pub fn analyse_with(mut pass: impl Pass, code: &str) -> Vec<Diagnostic<AnalyserDiagnostic>> {
+    with_context(code, |context, ast| pass.run(&context, ast))
+}
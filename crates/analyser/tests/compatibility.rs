@@ -0,0 +1,195 @@
+mod common;
+
+use common::analyse_with;
+use pxp_analyser::TypeCompatibilityPass;
+use pxp_diagnostics::Severity;
+
+const CLASSES: &str = r#"
+final class Money {}
+final class Name { public function __toString(): string { return ''; } }
+class User {}
+enum Suit { case Hearts; }
+"#;
+
+fn messages(pass: TypeCompatibilityPass, code: &str) -> Vec<(String, Severity)> {
+    analyse_with(pass, &format!("<?php {CLASSES} {code}"))
+        .into_iter()
+        .map(|diagnostic| (diagnostic.kind.to_string(), diagnostic.severity))
+        .collect()
+}
+
+fn analyse(code: &str) -> Vec<(String, Severity)> {
+    messages(TypeCompatibilityPass::new(), code)
+}
+
+fn error(message: &str) -> (String, Severity) {
+    (message.to_string(), Severity::Error)
+}
+
+fn warning(message: &str) -> (String, Severity) {
+    (message.to_string(), Severity::Warning)
+}
+
+#[test]
+fn it_reports_operations_that_throw_type_errors_as_errors() {
+    assert_eq!(
+        analyse(
+            r#"
+            $list = [1, 2];
+            $list + 1;
+            2 * $list;
+            new Money() - 1;
+            $list++;
+            --$money;
+            $money = new Money();
+            $money--;
+            'Total: ' . $money;
+            "#
+        ),
+        vec![
+            error("unsupported operand types: array<int, int> + int"),
+            error("unsupported operand types: int * array<int, int>"),
+            error("unsupported operand types: Money - int"),
+            error("cannot increment array<int, int>"),
+            error("cannot decrement Money"),
+            error("Money cannot be converted to a string"),
+        ]
+    );
+}
+
+#[test]
+fn it_reports_division_and_modulo_by_a_literal_zero() {
+    assert_eq!(
+        analyse("$a = 10 % 0; $b = 10 / 0.0; $c = 10 % (-0); $d = 10 % 1;"),
+        vec![
+            error("modulo by zero always throws a DivisionByZeroError"),
+            error("division by zero always throws a DivisionByZeroError"),
+            error("modulo by zero always throws a DivisionByZeroError"),
+        ]
+    );
+}
+
+#[test]
+fn it_reports_operations_whose_result_does_not_depend_on_the_values_as_warnings() {
+    assert_eq!(
+        analyse(
+            r#"
+            $money = new Money();
+            $money === 1;
+            'a' !== Suit::Hearts;
+            $money < 'a';
+            [1] >= 1;
+            'Items: ' . [1];
+            "#
+        ),
+        vec![
+            warning("strict comparison between Money and int is always false"),
+            warning("strict comparison between 'a' and Suit is always true"),
+            warning(
+                "Money and 'a' cannot be compared with <, so the result doesn't depend on their values"
+            ),
+            warning(
+                "array<int, int> and int cannot be compared with >=, so the result doesn't depend on their values"
+            ),
+            warning("array<int, int> cannot be converted to a string"),
+        ]
+    );
+}
+
+#[test]
+fn it_allows_operations_that_php_allows() {
+    assert!(analyse(
+        r#"
+        $list = [1, 2];
+        $merged = $list + ['a' => 3];
+        $letter = 'a';
+        $letter++;
+        $letter--;
+        $count = 1;
+        $count++;
+        $total = $count * 1.5 + true - null;
+        $name = 'Hello ' . new Name() . 1 . 1.5 . null . false;
+        $user = new User();
+        'Hello ' . $user;
+        $user < 'a';
+        $user === null;
+        new Money() == 1;
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_only_reports_suspicious_loose_comparisons_when_pedantic() {
+    let code = "$a = true == 'yes'; $b = 1 != false; $c = true == false;";
+
+    assert!(analyse(code).is_empty());
+    assert_eq!(
+        messages(TypeCompatibilityPass::new().with_pedantic(true), code),
+        vec![
+            (
+                "loose comparison between true and 'yes' converts both sides to bool".to_string(),
+                Severity::Information
+            ),
+            (
+                "loose comparison between int and false converts both sides to bool".to_string(),
+                Severity::Information
+            ),
+        ]
+    );
+}
+
+#[test]
+fn it_does_not_report_mixed_or_unknown_operands() {
+    assert!(analyse(
+        r#"
+        function values(): mixed {}
+        $value = values();
+        $value + 1;
+        $value . 'a';
+        $value++;
+        $value === 1;
+        $unknown = new Unknown();
+        $unknown - 1;
+        $unknown . 'a';
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_only_reports_unions_when_every_type_is_invalid() {
+    assert_eq!(
+        analyse(
+            r#"
+            function check(array|int $maybe, array|Money $never) {
+                $maybe + 1;
+                $never - 1;
+            }
+            "#
+        ),
+        vec![error("unsupported operand types: array | Money - int")]
+    );
+}
+
+#[test]
+fn it_reports_clone_operands_that_could_be_null_or_are_not_objects() {
+    assert_eq!(
+        analyse(
+            r#"
+            function copy(?User $user, User $other, int $count, $unknown) {
+                clone $user;
+                clone($user);
+                clone $other;
+                clone $count;
+                clone $unknown;
+            }
+            "#
+        ),
+        vec![
+            warning("cannot clone ?User, only objects can be cloned"),
+            warning("cannot clone ?User, only objects can be cloned"),
+            error("cannot clone int, only objects can be cloned"),
+        ]
+    );
+}
@@ -0,0 +1,231 @@
+mod common;
+
+use common::analyse_with;
+use pxp_analyser::{AnalyserDiagnostic, ConditionPass};
+use pxp_diagnostics::Severity;
+
+fn analyse(code: &str) -> Vec<(AnalyserDiagnostic, Severity)> {
+    analyse_with(ConditionPass::new(), &format!("<?php {}", code))
+        .into_iter()
+        .map(|diagnostic| (diagnostic.kind, diagnostic.severity))
+        .collect()
+}
+
+fn messages(code: &str) -> Vec<String> {
+    analyse(code)
+        .into_iter()
+        .map(|(kind, _)| kind.to_string())
+        .collect()
+}
+
+#[test]
+fn it_reports_type_checks_that_are_always_true() {
+    assert_eq!(
+        messages(
+            r#"
+            function greet(string $name) {
+                if (is_string($name)) {}
+            }
+            "#
+        ),
+        vec!["call to is_string() is always true because the value is string"]
+    );
+}
+
+#[test]
+fn it_reports_type_checks_that_are_always_false() {
+    assert_eq!(
+        messages(
+            r#"
+            function total(int|float $amount) {
+                if (is_string($amount)) {}
+                if (IS_ARRAY($amount)) {}
+                if (is_int($amount)) {}
+            }
+            "#
+        ),
+        vec![
+            "call to is_string() is always false because the value is int | float",
+            "call to is_array() is always false because the value is int | float",
+        ]
+    );
+}
+
+#[test]
+fn it_reports_null_comparisons_with_values_that_cannot_be_null() {
+    assert_eq!(
+        messages(
+            r#"
+            function find(string $key, ?string $fallback) {
+                if ($key === null) {}
+                if (null !== $key) {}
+                if ($fallback === null) {}
+            }
+            "#
+        ),
+        vec![
+            "strict comparison between string and null is always false",
+            "strict comparison between null and string is always true",
+        ]
+    );
+}
+
+#[test]
+fn it_reports_strict_comparisons_of_different_literals() {
+    assert_eq!(
+        messages(
+            r#"
+            $mode = 'read';
+
+            if ($mode === 'write') {}
+            if ($mode === 'read') {}
+            if ('a' === 1) {}
+            "#
+        ),
+        vec![
+            "strict comparison between 'read' and 'write' is always false",
+            "strict comparison between 'read' and 'read' is always true",
+            "strict comparison between 'a' and int is always false",
+        ]
+    );
+}
+
+#[test]
+fn it_reports_in_array_with_an_empty_array() {
+    assert_eq!(
+        messages(
+            r#"
+            in_array($needle, []);
+            in_array(haystack: [], needle: $needle);
+            in_array($needle, [1]);
+            "#
+        ),
+        vec![
+            "in_array() is always false when searching an empty array",
+            "in_array() is always false when searching an empty array",
+        ]
+    );
+}
+
+#[test]
+fn it_ignores_loose_comparisons() {
+    assert!(analyse(
+        r#"
+        function find(string $key) {
+            if ($key == null) {}
+            if ('1' == 1) {}
+        }
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_ignores_values_with_unknown_types() {
+    assert!(analyse(
+        r#"
+        function find($key, Unknown $value, callable $callback) {
+            if (is_string($key)) {}
+            if ($value === null) {}
+            if (is_string($callback)) {}
+            if ($undefined === null) {}
+        }
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_ignores_variables_that_are_reassigned() {
+    assert!(analyse(
+        r#"
+        function find(string $key, array $items) {
+            if (rand()) {
+                $key = null;
+            }
+
+            if ($key === null) {}
+
+            $mode = 'read';
+
+            foreach ($items as $item) {
+                $mode = 'write';
+            }
+
+            if ($mode === 'write') {}
+
+            if (rand()) {
+                $value = 'a';
+            }
+
+            if ($value === 'a') {}
+        }
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_ignores_variables_that_could_be_passed_by_reference() {
+    assert!(analyse(
+        r#"
+        function parse(string $input) {
+            $matches = [];
+            preg_match('/a/', $input, $matches);
+
+            if (is_array($matches)) {}
+
+            $mode = 'read';
+            $callback = function () use (&$mode) {
+                $mode = 'write';
+            };
+
+            if ($mode === 'write') {}
+        }
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_ignores_functions_shadowed_by_namespaced_functions() {
+    assert!(analyse(
+        r#"
+        namespace App;
+
+        function is_string($value): bool {}
+
+        function greet(string $name) {
+            if (is_string($name)) {}
+        }
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_reports_notes_for_ignored_statements() {
+    assert_eq!(
+        analyse(
+            r#"
+            function greet(string $name) {
+                // @phpstan-ignore-next-line
+                if (is_string($name)) {}
+
+                /** @pxp-ignore */
+                if ($name === null) {}
+
+                if (is_int($name)) {}
+            }
+            "#
+        )
+        .into_iter()
+        .map(|(_, severity)| severity)
+        .collect::<Vec<_>>(),
+        vec![
+            Severity::Information,
+            Severity::Information,
+            Severity::Warning
+        ]
+    );
+}
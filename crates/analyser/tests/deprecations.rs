@@ -0,0 +1,213 @@
+mod common;
+
+use common::analyse_with;
+use pxp_analyser::{AnalyserDiagnostic, DeprecationPass};
+
+const SYMBOLS: &str = r#"
+/** @deprecated Use Client instead. */
+class LegacyClient {
+    public function send() {}
+}
+
+class Client {
+    /** @deprecated */
+    public $handle;
+
+    /** @deprecated Use the VERSION constant. */
+    public static $version;
+
+    /** @deprecated since 2.0 */
+    const LEGACY = 1;
+
+    const VERSION = 2;
+
+    /** @deprecated Use send() instead. */
+    public function post() {}
+
+    #[\Deprecated("Use send() instead")]
+    public static function make() {}
+
+    public function send() {}
+}
+
+class HttpClient extends Client {}
+
+/** @deprecated */
+function connect() {}
+
+function reconnect() {}
+"#;
+
+fn analyse(code: &str) -> Vec<AnalyserDiagnostic> {
+    analyse_with(
+        DeprecationPass::new(),
+        &format!("<?php {}\n{}", SYMBOLS, code),
+    )
+    .into_iter()
+    .map(|diagnostic| diagnostic.kind)
+    .collect()
+}
+
+fn deprecated(symbol: &str, message: Option<&str>) -> AnalyserDiagnostic {
+    AnalyserDiagnostic::DeprecatedSymbol {
+        symbol: symbol.into(),
+        message: message.map(|message| message.into()),
+    }
+}
+
+#[test]
+fn it_reports_deprecated_classes() {
+    assert_eq!(
+        analyse(
+            r#"
+        $client = new LegacyClient();
+        class Wrapper extends LegacyClient {}
+        "#
+        ),
+        vec![
+            deprecated("LegacyClient", Some("Use Client instead.")),
+            deprecated("LegacyClient", Some("Use Client instead.")),
+        ]
+    );
+}
+
+#[test]
+fn it_reports_deprecated_functions() {
+    assert_eq!(
+        analyse("connect(); reconnect();"),
+        vec![deprecated("connect()", None)]
+    );
+}
+
+#[test]
+fn it_reports_deprecated_methods() {
+    assert_eq!(
+        analyse(
+            r#"
+        $client = new Client();
+        $client->post();
+        $client->send();
+        Client::make();
+        "#
+        ),
+        vec![
+            deprecated("Client::post()", Some("Use send() instead.")),
+            deprecated("Client::make()", Some("Use send() instead")),
+        ]
+    );
+}
+
+#[test]
+fn it_reports_cloning_an_object_with_a_deprecated_clone_method() {
+    assert_eq!(
+        analyse(
+            r#"
+        class Connection {
+            /** @deprecated Connections can't be shared. */
+            public function __clone() {}
+        }
+
+        clone new Connection();
+        clone new Client();
+        "#
+        ),
+        vec![deprecated(
+            "Connection::__clone()",
+            Some("Connections can't be shared.")
+        )]
+    );
+}
+
+#[test]
+fn it_reports_deprecated_methods_inherited_from_parents() {
+    assert_eq!(
+        analyse(
+            r#"
+        function f(HttpClient $client) {
+            $client->POST();
+        }
+        "#
+        ),
+        vec![deprecated("Client::post()", Some("Use send() instead."))]
+    );
+}
+
+#[test]
+fn it_reports_deprecated_constants() {
+    assert_eq!(
+        analyse("Client::LEGACY; Client::VERSION; HttpClient::LEGACY; Client::class;"),
+        vec![
+            deprecated("Client::LEGACY", Some("since 2.0")),
+            deprecated("Client::LEGACY", Some("since 2.0")),
+        ]
+    );
+}
+
+#[test]
+fn it_reports_deprecated_properties() {
+    assert_eq!(
+        analyse(
+            r#"
+        $client = new Client();
+        $client->handle;
+        Client::$version;
+        "#
+        ),
+        vec![
+            deprecated("Client::$handle", None),
+            deprecated("Client::$version", Some("Use the VERSION constant.")),
+        ]
+    );
+}
+
+#[test]
+fn it_does_not_report_usages_inside_deprecated_symbols() {
+    assert!(analyse(
+        r#"
+        /** @deprecated */
+        function legacy() {
+            connect();
+            return new LegacyClient();
+        }
+
+        class Service {
+            /** @deprecated */
+            public function legacy() {
+                $this->client()->post();
+            }
+
+            public function client(): Client {}
+        }
+
+        /** @deprecated */
+        class LegacyService extends LegacyClient {
+            public function run() {
+                connect();
+            }
+        }
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_reports_usages_through_this() {
+    assert_eq!(
+        analyse(
+            r#"
+        class Api extends Client {
+            public function run() {
+                $this->post();
+                self::make();
+                $this->handle;
+            }
+        }
+        "#
+        ),
+        vec![
+            deprecated("Client::post()", Some("Use send() instead.")),
+            deprecated("Client::make()", Some("Use send() instead")),
+            deprecated("Client::$handle", None),
+        ]
+    );
+}
@@ -0,0 +1,82 @@
+mod common;
+
+use common::analyse_with;
+use pxp_analyser::EmbeddedCodePass;
+use pxp_diagnostics::Severity;
+use pxp_span::Span;
+
+fn analyse(code: &str) -> Vec<(String, Severity, Span)> {
+    analyse_with(EmbeddedCodePass::new(), code)
+        .into_iter()
+        .map(|diagnostic| {
+            (
+                diagnostic.kind.to_string(),
+                diagnostic.severity,
+                diagnostic.span,
+            )
+        })
+        .collect()
+}
+
+const UNEXPECTED_SEMICOLON: &str = "in the code passed to eval(): unexpected token ;";
+
+#[test]
+fn it_reports_syntax_errors_at_their_position_in_the_outer_file() {
+    let code = "<?php\n$a = 1;\neval('$b = 2; $c = ;');";
+    let diagnostics = analyse(code);
+    let offset = code.find(" ;").unwrap() + 1;
+
+    assert_eq!(
+        diagnostics[0],
+        (
+            UNEXPECTED_SEMICOLON.to_string(),
+            Severity::Error,
+            Span::new(offset, offset + 1)
+        )
+    );
+}
+
+#[test]
+fn it_accounts_for_escape_sequences_in_double_quoted_strings() {
+    let code = r#"<?php eval("\$b = \"\x41\";\n\$c = ;");"#;
+    let diagnostics = analyse(code);
+    let offset = code.find(" ;").unwrap() + 1;
+
+    assert_eq!(diagnostics[0].0, UNEXPECTED_SEMICOLON);
+    assert_eq!(diagnostics[0].2, Span::new(offset, offset + 1));
+}
+
+#[test]
+fn it_reports_syntax_errors_in_the_body_passed_to_create_function() {
+    let code = "<?php create_function('$a', 'return $a +;');";
+    let diagnostics = analyse(code);
+    let offset = code.find("+;").unwrap() + 1;
+
+    assert_eq!(
+        diagnostics[0].0,
+        "in the code passed to create_function(): unexpected token ;"
+    );
+    assert_eq!(diagnostics[0].2, Span::new(offset, offset + 1));
+}
+
+#[test]
+fn it_reports_code_that_is_not_a_single_literal_at_the_argument() {
+    let code = "<?php eval('$b = ' . '2 +;');";
+    let diagnostics = analyse(code);
+
+    assert_eq!(diagnostics[0].0, UNEXPECTED_SEMICOLON);
+    assert_eq!(&code[diagnostics[0].2.to_range()], "'$b = ' . '2 +;'");
+}
+
+#[test]
+fn it_does_not_report_valid_or_unknown_code() {
+    assert!(analyse(
+        r#"<?php
+        eval('$b = 2;');
+        eval("echo \"{$b}\";");
+        eval($_GET['code']);
+        create_function('$a', 'return $a * 2;');
+        "#
+    )
+    .is_empty());
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use common::analyse_with;
+use pxp_analyser::{AnalyserDiagnostic, EnumPass};
+
+const TRAITS: &str = r#"
+trait HasLabel {
+    public function label(): string {}
+}
+
+trait HasPrefix {
+    protected string $prefix = '';
+}
+
+trait HasPrefixedLabel {
+    use HasLabel, HasPrefix;
+}
+"#;
+
+fn analyse(code: &str) -> Vec<AnalyserDiagnostic> {
+    analyse_with(EnumPass::new(), &format!("<?php {}\n{}", TRAITS, code))
+        .into_iter()
+        .map(|diagnostic| diagnostic.kind)
+        .collect()
+}
+
+#[test]
+fn it_accepts_enums_using_traits_without_properties() {
+    assert!(analyse(
+        r#"
+        enum Suit: string {
+            use HasLabel, Unknown;
+
+            case Hearts = 'H';
+        }
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_reports_enums_using_traits_with_properties() {
+    assert_eq!(
+        analyse(
+            r#"
+        enum Suit {
+            use HasLabel;
+            use HasPrefix;
+
+            case Hearts;
+        }
+
+        enum Size: int {
+            use HasPrefixedLabel;
+
+            case Small = 1;
+        }
+        "#
+        ),
+        vec![
+            AnalyserDiagnostic::EnumUsesTraitWithProperties {
+                r#enum: b"Suit".into(),
+                r#trait: b"HasPrefix".into(),
+            },
+            AnalyserDiagnostic::EnumUsesTraitWithProperties {
+                r#enum: b"Size".into(),
+                r#trait: b"HasPrefixedLabel".into(),
+            },
+        ]
+    );
+}
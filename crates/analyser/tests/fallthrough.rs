@@ -0,0 +1,189 @@
+mod common;
+
+use common::analyse_with;
+use pxp_analyser::{passes, FallthroughPass};
+
+/// Analyse the given code and return the source code of each case that falls through.
+fn analyse(code: &str) -> Vec<String> {
+    let source = format!("<?php {}", code);
+
+    analyse_with(FallthroughPass::new(), &source)
+        .into_iter()
+        .map(|diagnostic| {
+            source[diagnostic.span.start..diagnostic.span.end]
+                .lines()
+                .next()
+                .unwrap()
+                .to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn it_accepts_cases_that_do_not_fall_through() {
+    assert!(analyse(
+        r#"
+        function example($a) {
+            foreach ([1, 2] as $b) {
+                switch ($a) {
+                    case 1:
+                    case 2:
+                        echo 'one or two';
+                        break;
+                    case 3:
+                        return 3;
+                    case 4:
+                        continue 2;
+                    case 5:
+                        throw new Exception();
+                    case 6:
+                        exit(1);
+                    case 7: {
+                        echo 'seven';
+                        break;
+                    }
+                    default:
+                        echo 'other';
+                }
+            }
+        }
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_accepts_cases_that_end_on_every_path() {
+    assert!(analyse(
+        r#"
+        function example($a, $b) {
+            switch ($a) {
+                case 1:
+                    if ($b) {
+                        return 1;
+                    } else {
+                        return 2;
+                    }
+                case 2:
+                    try {
+                        return 3;
+                    } catch (Exception $e) {
+                        throw $e;
+                    }
+                case 3:
+                    switch ($b) {
+                        case 1:
+                            return 4;
+                        default:
+                            return 5;
+                    }
+                case 4:
+                    while (true) {
+                        echo 'forever';
+                    }
+                default:
+                    echo 'other';
+            }
+        }
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_reports_cases_that_only_end_on_some_paths() {
+    assert_eq!(
+        analyse(
+            r#"
+        switch ($a) {
+            case 1:
+                if ($b) {
+                    return 1;
+                }
+            case 2:
+                try {
+                    return 2;
+                } catch (Exception $e) {
+                    echo 'caught';
+                }
+            case 3:
+                switch ($b) {
+                    case 1:
+                        return 3;
+                }
+            case 4:
+                while (true) {
+                    break;
+                }
+            default:
+                echo 'other';
+        }
+        "#
+        ),
+        vec!["case 1:", "case 2:", "case 3:", "case 4:"]
+    );
+}
+
+#[test]
+fn it_is_not_enabled_by_default() {
+    assert!(passes().iter().all(|pass| !pass.codes().contains(&"A009")));
+}
+
+#[test]
+fn it_reports_cases_that_fall_through() {
+    assert_eq!(
+        analyse(
+            r#"
+        switch ($a) {
+            case 1:
+                echo 'one';
+            case 2;
+                echo 'two';
+            default:
+                echo 'other';
+        }
+        "#
+        ),
+        vec!["case 1:", "case 2;"]
+    );
+}
+
+#[test]
+fn it_does_not_report_fallthrough_marked_with_a_comment() {
+    assert!(analyse(
+        r#"
+        switch ($a) {
+            case 1:
+                echo 'one';
+                // no break
+            case 2:
+                echo 'two';
+                # Fallthrough
+            case 3:
+                echo 'three'; /* fall through */
+            default:
+                echo 'other';
+        }
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_reports_fall_through_in_templates() {
+    assert_eq!(
+        analyse(
+            r#"switch ($a): ?>
+<?php case 1: ?>
+    <p>One</p>
+<?php break; ?>
+<?php case 2: ?>
+    <p>Two</p>
+<?php case 3: ?>
+    <p>Three</p>
+<?php endswitch; ?>
+"#
+        ),
+        vec!["case 2: ?>"]
+    );
+}
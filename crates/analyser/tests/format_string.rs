@@ -0,0 +1,87 @@
+use pxp_analyser::{parse_format_string, FormatArgument, FormatKind, FormatStringError};
+
+fn arguments(format: &str) -> Vec<(usize, FormatKind, String)> {
+    parse_format_string(format.as_bytes())
+        .unwrap()
+        .into_iter()
+        .map(
+            |FormatArgument {
+                 position,
+                 kind,
+                 specifier,
+             }| (position, kind, specifier.to_string()),
+        )
+        .collect()
+}
+
+fn error(format: &str) -> FormatStringError {
+    parse_format_string(format.as_bytes()).unwrap_err()
+}
+
+#[test]
+fn it_parses_sequential_specifiers() {
+    assert_eq!(
+        arguments("%s is %d years and %.2f%% done"),
+        vec![
+            (0, FormatKind::String, "%s".to_string()),
+            (1, FormatKind::Integer, "%d".to_string()),
+            (2, FormatKind::Float, "%.2f".to_string()),
+        ]
+    );
+
+    assert!(arguments("100%% literal").is_empty());
+}
+
+#[test]
+fn it_parses_flags_width_and_precision() {
+    assert_eq!(
+        arguments("%-10s|%+05d|%'*10.3F|% x|%lu"),
+        vec![
+            (0, FormatKind::String, "%-10s".to_string()),
+            (1, FormatKind::Integer, "%+05d".to_string()),
+            (2, FormatKind::Float, "%'*10.3F".to_string()),
+            (3, FormatKind::Integer, "% x".to_string()),
+            (4, FormatKind::Integer, "%lu".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn it_parses_positional_specifiers() {
+    assert_eq!(
+        arguments("%2$s %1$s %s %10s"),
+        vec![
+            (1, FormatKind::String, "%2$s".to_string()),
+            (0, FormatKind::String, "%1$s".to_string()),
+            (0, FormatKind::String, "%s".to_string()),
+            (1, FormatKind::String, "%10s".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn it_parses_widths_and_precisions_that_consume_values() {
+    assert_eq!(
+        arguments("%*.*f %1$*3$s"),
+        vec![
+            (0, FormatKind::Integer, "%*.*f".to_string()),
+            (1, FormatKind::Integer, "%*.*f".to_string()),
+            (2, FormatKind::Float, "%*.*f".to_string()),
+            (2, FormatKind::Integer, "%1$*3$s".to_string()),
+            (0, FormatKind::String, "%1$*3$s".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn it_reports_invalid_specifiers() {
+    assert_eq!(error("%y"), FormatStringError::UnknownSpecifier(b'y'));
+    assert_eq!(error("%5.2"), FormatStringError::MissingSpecifier);
+    assert_eq!(error("100%"), FormatStringError::MissingSpecifier);
+    assert_eq!(error("%0$s"), FormatStringError::InvalidArgumentNumber);
+    assert_eq!(error("%'"), FormatStringError::MissingPaddingCharacter);
+    assert_eq!(
+        error("%y").to_string(),
+        "unknown format specifier \"y\"".to_string()
+    );
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use common::analyse_with;
+use pxp_analyser::{AnalyserDiagnostic, FormatStringPass};
+
+/// Analyse the code, returning each diagnostic along with the source code that it points at.
+fn analyse(code: &str) -> Vec<(AnalyserDiagnostic, String)> {
+    let source = format!("<?php {}", code);
+
+    analyse_with(FormatStringPass::new(), &source)
+        .into_iter()
+        .map(|diagnostic| {
+            let text = source[diagnostic.span.to_range()].to_string();
+
+            (diagnostic.kind, text)
+        })
+        .collect()
+}
+
+fn mismatch(
+    function: &str,
+    specifier: &str,
+    r#type: &str,
+    text: &str,
+) -> (AnalyserDiagnostic, String) {
+    (
+        AnalyserDiagnostic::FormatArgumentTypeMismatch {
+            function: function.into(),
+            specifier: specifier.into(),
+            r#type: r#type.into(),
+        },
+        text.to_string(),
+    )
+}
+
+#[test]
+fn it_ignores_calls_that_match_their_format_string() {
+    assert!(analyse(
+        r#"
+        sprintf('%s is %d years old', $name, $age);
+        printf('%2$s %1$s', 'a', 'b');
+        fprintf($stream, '%05.2f%%', 1.5);
+        sprintf('%d', '42');
+        vsprintf('%s-%s', ['a', 'b']);
+        sprintf($format, 1, 2, 3);
+        vsprintf('%s', $values);
+        sprintf(...$arguments);
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_reports_the_wrong_number_of_values() {
+    assert_eq!(
+        analyse("sprintf('%s and %s', 'a'); printf('%1$s', 'a', 'b', 'c');"),
+        vec![
+            (
+                AnalyserDiagnostic::TooFewFormatArguments {
+                    function: "sprintf".into(),
+                    given: 1,
+                    required: 2,
+                },
+                "('%s and %s', 'a')".to_string()
+            ),
+            (
+                AnalyserDiagnostic::TooManyFormatArguments {
+                    function: "printf".into(),
+                    given: 3,
+                    used: 1,
+                },
+                "'b'".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn it_checks_the_values_of_array_literals() {
+    assert_eq!(
+        analyse("vsprintf('%s %s %s', ['a', 'b']); vprintf('%d', [[1]]);"),
+        vec![
+            (
+                AnalyserDiagnostic::TooFewFormatArguments {
+                    function: "vsprintf".into(),
+                    given: 2,
+                    required: 3,
+                },
+                "('%s %s %s', ['a', 'b'])".to_string()
+            ),
+            mismatch("vprintf", "%d", "array<int, int>", "[1]"),
+        ]
+    );
+}
+
+#[test]
+fn it_reports_values_that_cannot_be_formatted() {
+    assert_eq!(
+        analyse(
+            r#"
+            sprintf('%s', [1, 2]);
+            sprintf('%d items', 'many');
+            sprintf('%.1f', ' 1.5e3 ');
+            sprintf('%s', 'many');
+            "#
+        ),
+        vec![
+            mismatch("sprintf", "%s", "array<int, int>", "[1, 2]"),
+            mismatch("sprintf", "%d", "'many'", "'many'"),
+        ]
+    );
+}
+
+#[test]
+fn it_reports_invalid_format_strings() {
+    assert_eq!(
+        analyse("sprintf('%q' . ' and %s', 'a');"),
+        vec![(
+            AnalyserDiagnostic::InvalidFormatString {
+                function: "sprintf".into(),
+                message: "unknown format specifier \"q\"".into(),
+            },
+            "'%q' . ' and %s'".to_string()
+        )]
+    );
+}
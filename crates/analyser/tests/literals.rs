@@ -0,0 +1,34 @@
+mod common;
+
+use common::analyse_with;
+use pxp_analyser::{AnalyserDiagnostic, LiteralPass};
+
+fn analyse(code: &str) -> Vec<AnalyserDiagnostic> {
+    analyse_with(LiteralPass::new(), &format!("<?php {}", code))
+        .into_iter()
+        .map(|diagnostic| diagnostic.kind)
+        .collect()
+}
+
+#[test]
+fn it_reports_legacy_octal_literals_with_invalid_digits() {
+    assert_eq!(
+        analyse("$a = 089; $b = 0_9;"),
+        vec![
+            AnalyserDiagnostic::InvalidOctalLiteral {
+                literal: "089".into()
+            },
+            AnalyserDiagnostic::InvalidOctalLiteral {
+                literal: "0_9".into()
+            },
+        ]
+    );
+}
+
+#[test]
+fn it_accepts_valid_numeric_literals() {
+    assert!(
+        analyse("$a = [0, 0755, 0o755, 0x1F, 0b11, 1_000, 0.9, 09.5, 9223372036854775808];")
+            .is_empty()
+    );
+}
@@ -0,0 +1,153 @@
+mod common;
+
+use common::analyse_with;
+use pxp_analyser::{AnalyserDiagnostic, MagicConstantPass};
+
+/// Analyse the code, returning each diagnostic along with the source code that it points at.
+fn analyse(code: &str) -> Vec<(AnalyserDiagnostic, String)> {
+    let source = format!("<?php {}", code);
+
+    analyse_with(MagicConstantPass::new(), &source)
+        .into_iter()
+        .map(|diagnostic| {
+            let text = source[diagnostic.span.to_range()].to_string();
+
+            (diagnostic.kind, text)
+        })
+        .collect()
+}
+
+fn empty(constant: &str, context: &str) -> Vec<(AnalyserDiagnostic, String)> {
+    vec![(
+        AnalyserDiagnostic::EmptyMagicConstant {
+            constant: constant.into(),
+            context: context.into(),
+        },
+        constant.to_string(),
+    )]
+}
+
+/// Wrap a magic constant in each of the contexts that it can be used in.
+fn contexts(constant: &str) -> [String; 8] {
+    [
+        format!("{constant};"),
+        format!("function a() {{ {constant}; }}"),
+        format!("$a = function () {{ {constant}; }};"),
+        format!("class A {{ function b() {{ {constant}; }} }}"),
+        format!("class A {{ function b() {{ return fn () => {constant}; }} }}"),
+        format!("new class {{ function b() {{ {constant}; }} }};"),
+        format!("trait T {{ function b() {{ {constant}; }} }}"),
+        format!("class A {{ public int $b {{ get => strlen({constant}); }} }}"),
+    ]
+}
+
+fn assert_contexts(constant: &str, expected: [Vec<(AnalyserDiagnostic, String)>; 8]) {
+    for (code, expected) in contexts(constant).iter().zip(expected) {
+        assert_eq!(analyse(code), expected, "{code}");
+    }
+}
+
+fn method() -> Vec<(AnalyserDiagnostic, String)> {
+    vec![(
+        AnalyserDiagnostic::MethodConstantOutsideMethod,
+        "__METHOD__".to_string(),
+    )]
+}
+
+#[test]
+fn it_ignores_constants_that_are_valid_everywhere() {
+    for constant in [
+        "__LINE__",
+        "__FILE__",
+        "__DIR__",
+        "__NAMESPACE__",
+        "__COMPILER_HALT_OFFSET__",
+    ] {
+        assert_contexts(constant, Default::default());
+    }
+}
+
+#[test]
+fn it_reports_class_outside_of_a_class() {
+    assert_contexts(
+        "__CLASS__",
+        [
+            empty("__CLASS__", "class"),
+            empty("__CLASS__", "class"),
+            empty("__CLASS__", "class"),
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+        ],
+    );
+}
+
+#[test]
+fn it_reports_trait_outside_of_a_trait() {
+    assert_contexts(
+        "__TRAIT__",
+        [
+            empty("__TRAIT__", "trait"),
+            empty("__TRAIT__", "trait"),
+            empty("__TRAIT__", "trait"),
+            empty("__TRAIT__", "trait"),
+            empty("__TRAIT__", "trait"),
+            empty("__TRAIT__", "trait"),
+            vec![],
+            empty("__TRAIT__", "trait"),
+        ],
+    );
+}
+
+#[test]
+fn it_reports_function_outside_of_a_function() {
+    assert_contexts(
+        "__FUNCTION__",
+        [
+            empty("__FUNCTION__", "function"),
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+        ],
+    );
+}
+
+#[test]
+fn it_reports_method_outside_of_a_method() {
+    assert_contexts(
+        "__METHOD__",
+        [
+            empty("__METHOD__", "function"),
+            method(),
+            method(),
+            vec![],
+            method(),
+            vec![],
+            vec![],
+            vec![],
+        ],
+    );
+}
+
+#[test]
+fn it_reports_property_outside_of_a_property_hook() {
+    assert_contexts(
+        "__PROPERTY__",
+        [
+            empty("__PROPERTY__", "property hook"),
+            empty("__PROPERTY__", "property hook"),
+            empty("__PROPERTY__", "property hook"),
+            empty("__PROPERTY__", "property hook"),
+            empty("__PROPERTY__", "property hook"),
+            empty("__PROPERTY__", "property hook"),
+            empty("__PROPERTY__", "property hook"),
+            vec![],
+        ],
+    );
+}
@@ -0,0 +1,121 @@
+mod common;
+
+use common::analyse_with;
+use pxp_analyser::{AnalyserDiagnostic, OutputPass};
+
+fn analyse(code: &str) -> Vec<AnalyserDiagnostic> {
+    kinds(OutputPass::new(), code)
+}
+
+fn kinds(pass: OutputPass, code: &str) -> Vec<AnalyserDiagnostic> {
+    analyse_with(pass, code)
+        .into_iter()
+        .map(|diagnostic| diagnostic.kind)
+        .collect()
+}
+
+fn unescaped(source: &str, output: &str) -> AnalyserDiagnostic {
+    AnalyserDiagnostic::UnescapedOutput {
+        source: source.into(),
+        output: output.into(),
+    }
+}
+
+#[test]
+fn it_reports_input_that_is_output_without_being_escaped() {
+    assert_eq!(
+        analyse(
+            r#"<?php
+            echo $_GET['name'];
+            print 'Hello, ' . $_POST['name'];
+            echo "Hello, {$_COOKIE['name']}";
+            "#
+        ),
+        vec![
+            unescaped("$_GET", "echo"),
+            unescaped("$_POST", "print"),
+            unescaped("$_COOKIE", "echo"),
+        ]
+    );
+}
+
+#[test]
+fn it_reports_input_in_echo_tags() {
+    assert_eq!(
+        analyse(r#"<h1><?= $_REQUEST['title'] ?></h1><p><?= 'Welcome' ?></p>"#),
+        vec![unescaped("$_REQUEST", "<?=")]
+    );
+}
+
+#[test]
+fn it_does_not_report_sanitized_input() {
+    assert!(analyse(
+        r#"<?php
+        echo htmlspecialchars($_GET['name']);
+        echo 'Hello, ' . htmlentities($_POST['name'], ENT_QUOTES);
+        echo (int) $_GET['page'];
+        ?>
+        <p><?= htmlspecialchars($_GET['name']) ?></p>
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_tracks_input_through_variables() {
+    assert_eq!(
+        analyse(
+            r#"<?php
+            $name = $_GET['name'];
+            $greeting = "Hello, $name";
+            echo $greeting;
+
+            $safe = htmlspecialchars($name);
+            echo $safe;
+
+            $name = 'world';
+            echo $name;
+            "#
+        ),
+        vec![unescaped("$_GET", "echo")]
+    );
+}
+
+#[test]
+fn it_tracks_input_within_each_function_separately() {
+    assert_eq!(
+        analyse(
+            r#"<?php
+            $name = $_GET['name'];
+
+            function greet($name) {
+                echo $name;
+            }
+
+            $greet = function () use ($name) {
+                echo $name;
+            };
+
+            $page = (int) $_GET['page'];
+            $link = fn () => print "?page=$page&name=$name";
+            "#
+        ),
+        vec![unescaped("$_GET", "echo"), unescaped("$_GET", "print")]
+    );
+}
+
+#[test]
+fn it_uses_the_configured_sources_and_sanitizers() {
+    assert_eq!(
+        kinds(
+            OutputPass::new()
+                .with_source("$_SERVER")
+                .with_sanitizer("e"),
+            r#"<?php
+            echo $_SERVER['PHP_SELF'];
+            echo e($_GET['name']);
+            "#
+        ),
+        vec![unescaped("$_SERVER", "echo")]
+    );
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use common::analyse_with;
+use pxp_analyser::{AnalyserDiagnostic, OverridePass};
+
+const HIERARCHY: &str = r#"
+interface Shape {
+    public function area(): float;
+}
+
+interface Polygon extends Shape {}
+
+trait HasName {
+    public function name(): string {}
+}
+
+abstract class Base implements Polygon {
+    use HasName;
+
+    public function describe(): string {}
+
+    private function secret() {}
+}
+"#;
+
+fn analyse(code: &str) -> Vec<AnalyserDiagnostic> {
+    analyse_with(
+        OverridePass::new(),
+        &format!("<?php {}\n{}", HIERARCHY, code),
+    )
+    .into_iter()
+    .map(|diagnostic| diagnostic.kind)
+    .collect()
+}
+
+#[test]
+fn it_accepts_methods_that_override_something() {
+    assert!(analyse(
+        r#"
+        class Square extends Base {
+            #[\Override]
+            public function describe(): string {}
+
+            #[Override]
+            public function AREA(): float {}
+
+            #[\Override]
+            public function name(): string {}
+        }
+
+        enum Circle implements Shape {
+            #[\Override]
+            public function area(): float {}
+        }
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_reports_methods_that_do_not_override_anything() {
+    assert_eq!(
+        analyse(
+            r#"
+        class Square extends Base {
+            #[\Override]
+            public function perimeter(): float {}
+
+            #[\Override]
+            public function secret() {}
+
+            public function sides(): int {}
+        }
+        "#
+        ),
+        vec![
+            AnalyserDiagnostic::OverrideWithoutParentMethod {
+                class: b"Square".into(),
+                method: b"perimeter".into(),
+            },
+            AnalyserDiagnostic::OverrideWithoutParentMethod {
+                class: b"Square".into(),
+                method: b"secret".into(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn it_does_not_treat_methods_from_the_classes_own_traits_as_overridden() {
+    assert_eq!(
+        analyse(
+            r#"
+        class Person {
+            use HasName;
+
+            #[\Override]
+            public function name(): string {}
+        }
+        "#
+        ),
+        vec![AnalyserDiagnostic::OverrideWithoutParentMethod {
+            class: b"Person".into(),
+            method: b"name".into(),
+        }]
+    );
+}
@@ -0,0 +1,194 @@
+mod common;
+
+use std::{cell::Cell, rc::Rc};
+
+use common::with_context;
+use pxp_analyser::{passes, AnalyserContext, AnalyserDiagnostic, AnalysisProfile, Pass};
+use pxp_ast::Statement;
+use pxp_diagnostics::{Diagnostic, DiagnosticKind, Severity};
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+
+const CODE: &str = r#"
+function greet(string $name) {
+    if ($name === '') {
+        $greeting = 'Hello';
+    }
+
+    echo $greeting . $_GET['suffix'];
+
+    return $nmae;
+}
+
+greet('World', 'extra');
+"#;
+
+fn analyse(profile: &AnalysisProfile, passes: &mut [Box<dyn Pass>]) -> Vec<(String, Severity)> {
+    let mut diagnostics = with_context(&format!("<?php {}", CODE), |context, ast| {
+        profile.analyse(passes, &context, ast)
+    })
+    .into_iter()
+    .map(|diagnostic| (diagnostic.kind.get_code(), diagnostic.severity))
+    .collect::<Vec<_>>();
+
+    diagnostics.sort_by(|a, b| a.0.cmp(&b.0));
+    diagnostics
+}
+
+fn diagnostics(codes: &[(&str, Severity)]) -> Vec<(String, Severity)> {
+    codes
+        .iter()
+        .map(|(code, severity)| (code.to_string(), *severity))
+        .collect()
+}
+
+#[test]
+fn it_reports_diagnostics_with_their_own_severity_by_default() {
+    assert_eq!(
+        analyse(&AnalysisProfile::default(), &mut passes()),
+        diagnostics(&[
+            ("A002", Severity::Warning),
+            ("A011", Severity::Warning),
+            ("A012", Severity::Information),
+            ("A023", Severity::Warning),
+        ])
+    );
+}
+
+#[test]
+fn it_rewrites_severities_under_the_strict_profile() {
+    assert_eq!(
+        analyse(&AnalysisProfile::strict(), &mut passes()),
+        diagnostics(&[
+            ("A002", Severity::Error),
+            ("A011", Severity::Error),
+            ("A012", Severity::Warning),
+            ("A023", Severity::Error),
+        ])
+    );
+}
+
+#[test]
+fn it_reports_nothing_under_the_minimal_profile() {
+    assert_eq!(
+        analyse(&AnalysisProfile::minimal(), &mut passes()),
+        diagnostics(&[])
+    );
+}
+
+#[test]
+fn it_applies_overrides_on_top_of_a_built_in_profile() {
+    let profile = AnalysisProfile::strict()
+        .with("A012", Severity::Error)
+        .without("A023");
+
+    assert_eq!(
+        analyse(&profile, &mut passes()),
+        diagnostics(&[
+            ("A002", Severity::Error),
+            ("A011", Severity::Error),
+            ("A012", Severity::Error),
+        ])
+    );
+
+    let profile = AnalysisProfile::minimal().with("A011", Severity::Error);
+
+    assert_eq!(
+        analyse(&profile, &mut passes()),
+        diagnostics(&[("A011", Severity::Error)])
+    );
+}
+
+/// Counts how often the wrapped pass is run.
+struct CountingPass {
+    pass: Box<dyn Pass>,
+    runs: Rc<Cell<usize>>,
+}
+
+impl Pass for CountingPass {
+    fn codes(&self) -> &'static [&'static str] {
+        self.pass.codes()
+    }
+
+    fn run(
+        &mut self,
+        context: &AnalyserContext,
+        ast: &[Statement],
+    ) -> Vec<Diagnostic<AnalyserDiagnostic>> {
+        self.runs.set(self.runs.get() + 1);
+        self.pass.run(context, ast)
+    }
+}
+
+#[test]
+fn it_skips_passes_whose_diagnostics_are_all_turned_off() {
+    let runs = Rc::new(Cell::new(0));
+    let mut passes = passes()
+        .into_iter()
+        .filter(|pass| pass.codes().contains(&"A011"))
+        .map(|pass| {
+            Box::new(CountingPass {
+                pass,
+                runs: runs.clone(),
+            }) as Box<dyn Pass>
+        })
+        .collect::<Vec<_>>();
+
+    analyse(&AnalysisProfile::default(), &mut passes);
+    assert_eq!(runs.get(), 1);
+
+    // The pass can still report possibly undefined variables.
+    analyse(&AnalysisProfile::default().without("A011"), &mut passes);
+    assert_eq!(runs.get(), 2);
+
+    let profile = AnalysisProfile::default()
+        .without("A011")
+        .without("A012")
+        .without("A013");
+
+    analyse(&profile, &mut passes);
+    assert_eq!(runs.get(), 2);
+
+    analyse(&AnalysisProfile::minimal(), &mut passes);
+    assert_eq!(runs.get(), 2);
+}
+
+#[test]
+fn it_applies_overrides_to_parser_warnings_when_asked_to() {
+    let result = Parser::parse(Lexer::new(
+        b"<?php $a = function () use ($_GET) {}; $b = function () use ($this) {};",
+    ));
+    let codes = result
+        .diagnostics
+        .iter()
+        .map(|diagnostic| (diagnostic.kind.get_code(), diagnostic.severity))
+        .collect::<Vec<_>>();
+
+    assert_eq!(codes.len(), 2, "{codes:?}");
+    assert_eq!(codes[0].1, Severity::Warning);
+    assert_eq!(codes[1].1, Severity::Error);
+
+    let (warning, error) = (codes[0].0.clone(), codes[1].0.clone());
+
+    // Profiles that turn everything off by default still report parse errors and warnings.
+    let diagnostics = AnalysisProfile::minimal().apply_to_parser(result.diagnostics.clone());
+    assert_eq!(diagnostics.len(), 2);
+
+    let diagnostics = AnalysisProfile::default()
+        .with(warning.clone(), Severity::Error)
+        .apply_to_parser(result.diagnostics.clone());
+    assert_eq!(diagnostics[0].severity, Severity::Error);
+
+    // Errors can't be turned off.
+    let diagnostics = AnalysisProfile::default()
+        .without(warning)
+        .without(error.clone())
+        .apply_to_parser(result.diagnostics);
+    assert_eq!(
+        diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.kind.get_code())
+            .collect::<Vec<_>>(),
+        vec![error]
+    );
+}
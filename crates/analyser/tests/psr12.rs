@@ -0,0 +1,627 @@
+use pxp_analyser::{
+    psr12_rules, Analyser, AnalyserContext, BracePlacement, ExplicitVisibility,
+    FileDocblockPlacement, ImportOrder, MultilineTrailingComma, OneClassPerFile, Rule,
+    RuleDiagnostic,
+};
+use pxp_ast::Statement;
+use pxp_diagnostics::{Diagnostic, DiagnosticFix, DiagnosticKind, TextEdit};
+use pxp_index::{FileId, IndexBuilder};
+use pxp_inference::TypeEngine;
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+use pxp_span::LineIndex;
+
+fn lint(rules: Vec<Box<dyn Rule>>, source: &str) -> Vec<Diagnostic<RuleDiagnostic>> {
+    let result = Parser::parse(Lexer::new(source.as_bytes()));
+
+    assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+
+    run(rules, source, &result.ast)
+}
+
+fn run(
+    rules: Vec<Box<dyn Rule>>,
+    source: &str,
+    ast: &[Statement],
+) -> Vec<Diagnostic<RuleDiagnostic>> {
+    let mut builder = IndexBuilder::new();
+    builder.index(FileId::new(0), ast);
+    let index = builder.build();
+
+    let types = TypeEngine::new(&index).infer(ast);
+    let context = AnalyserContext::new(&index, &types);
+    let line_index = LineIndex::new(source.as_bytes());
+
+    let mut analyser = Analyser::new();
+
+    for rule in rules {
+        analyser.add_rule(rule);
+    }
+
+    analyser.analyse(&context, &line_index, ast)
+}
+
+fn check(rule: impl Rule + 'static, source: &str) -> Vec<(String, usize)> {
+    let line_index = LineIndex::new(source.as_bytes());
+
+    lint(vec![Box::new(rule)], source)
+        .into_iter()
+        .map(|diagnostic| {
+            (
+                diagnostic.kind.get_message(),
+                line_index.line_column(diagnostic.span.start).line + 1,
+            )
+        })
+        .collect()
+}
+
+/// Apply every fix that doesn't overlap with one before it.
+fn apply(diagnostics: &[Diagnostic<RuleDiagnostic>], source: &str) -> String {
+    let mut edits = Vec::new();
+
+    for fix in diagnostics
+        .iter()
+        .filter_map(|diagnostic| diagnostic.kind.get_fix(diagnostic.span))
+    {
+        let overlaps = fix.edits.iter().any(|edit| {
+            edits.iter().any(|other: &TextEdit| {
+                edit.span.start < other.span.end.max(other.span.start + 1)
+                    && other.span.start < edit.span.end.max(edit.span.start + 1)
+            })
+        });
+
+        if !overlaps {
+            edits.extend(fix.edits);
+        }
+    }
+
+    String::from_utf8(DiagnosticFix::new("", edits).apply(source.as_bytes())).unwrap()
+}
+
+fn fix(rule: impl Rule + 'static, source: &str) -> String {
+    apply(&lint(vec![Box::new(rule)], source), source)
+}
+
+#[test]
+fn it_reports_braces_that_are_not_on_the_line_after_the_declaration() {
+    let source = r#"<?php
+
+abstract class Foo extends Bar implements Baz {
+    public function handle(): void {
+    }
+
+    abstract public function run();
+}
+
+function greet()
+
+{
+}
+"#;
+
+    assert_eq!(
+        check(BracePlacement, source),
+        vec![
+            (
+                "Opening brace should be on the line after the declaration".to_string(),
+                3
+            ),
+            (
+                "Opening brace should be on the line after the declaration".to_string(),
+                4
+            ),
+            (
+                "Opening brace should be on the line after the declaration".to_string(),
+                12
+            ),
+        ]
+    );
+
+    assert_eq!(
+        fix(BracePlacement, source),
+        r#"<?php
+
+abstract class Foo extends Bar implements Baz
+{
+    public function handle(): void
+    {
+    }
+
+    abstract public function run();
+}
+
+function greet()
+{
+}
+"#
+    );
+}
+
+#[test]
+fn it_keeps_the_brace_on_the_same_line_as_a_multiline_parameter_list() {
+    let source = r#"<?php
+
+interface Handler
+{
+}
+
+enum Status: string implements Handler
+{
+    public function label(
+        string $prefix,
+    ): string
+    {
+        return $prefix;
+    }
+}
+
+$handler = new class {
+    public function handle(
+        int $a,
+    ) {
+    }
+};
+
+$closure = function () {
+};
+"#;
+
+    assert_eq!(
+        check(BracePlacement, source),
+        vec![(
+            "Opening brace should be on the same line as a multi-line parameter list".to_string(),
+            12
+        )]
+    );
+
+    assert!(fix(BracePlacement, source).contains("    ): string {\n        return $prefix;"));
+}
+
+#[test]
+fn it_reports_every_class_after_the_first_one_in_a_file() {
+    assert_eq!(
+        check(
+            OneClassPerFile,
+            r#"<?php
+
+namespace App;
+
+class Foo
+{
+}
+
+interface Bar
+{
+}
+
+enum Baz
+{
+}
+"#
+        ),
+        vec![
+            ("Bar should be declared in its own file".to_string(), 9),
+            ("Baz should be declared in its own file".to_string(), 13),
+        ]
+    );
+
+    assert!(check(
+        OneClassPerFile,
+        r#"<?php
+
+namespace App;
+
+class Foo
+{
+    public function make(): object
+    {
+        return new class {
+        };
+    }
+}
+"#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_reports_imports_that_are_not_grouped_and_sorted() {
+    let source = r#"<?php
+
+namespace App;
+
+use function strlen;
+use Zeta\Thing;
+use const PHP_EOL;
+use Alpha\{One, Two};
+use alpha\Other;
+
+class Foo
+{
+}
+"#;
+
+    assert_eq!(
+        check(ImportOrder, source),
+        vec![(
+            "Imports should be grouped by kind and sorted alphabetically".to_string(),
+            5
+        )]
+    );
+
+    assert_eq!(
+        fix(ImportOrder, source),
+        r#"<?php
+
+namespace App;
+
+use Alpha\{One, Two};
+use alpha\Other;
+use Zeta\Thing;
+
+use function strlen;
+
+use const PHP_EOL;
+
+class Foo
+{
+}
+"#
+    );
+}
+
+#[test]
+fn it_accepts_imports_that_are_grouped_and_sorted() {
+    assert!(check(
+        ImportOrder,
+        r#"<?php
+
+namespace App
+{
+    use Alpha\Thing;
+    use Beta\Thing as Other;
+
+    use function Alpha\helper;
+}
+"#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_does_not_fix_imports_that_have_comments_between_them() {
+    let diagnostics = lint(
+        vec![Box::new(ImportOrder)],
+        r#"<?php
+
+use Zeta\Thing;
+// Needed for the alpha integration.
+use Alpha\Thing as Other;
+"#,
+    );
+
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].kind.fix.is_none());
+}
+
+#[test]
+fn it_reports_members_without_a_visibility() {
+    let source = r#"<?php
+
+abstract class Foo
+{
+    var $name;
+    static $count = 0;
+    readonly int $id;
+    const LIMIT = 10;
+    final const MAX = 20;
+    protected $visible;
+
+    function handle()
+    {
+    }
+
+    abstract static function make();
+
+    final function run()
+    {
+    }
+}
+"#;
+
+    assert_eq!(
+        check(ExplicitVisibility, source),
+        vec![
+            ("Property should declare its visibility".to_string(), 5),
+            ("Property should declare its visibility".to_string(), 6),
+            ("Property should declare its visibility".to_string(), 7),
+            ("Constant should declare its visibility".to_string(), 8),
+            ("Constant should declare its visibility".to_string(), 9),
+            ("Method should declare its visibility".to_string(), 12),
+            ("Method should declare its visibility".to_string(), 16),
+            ("Method should declare its visibility".to_string(), 18),
+        ]
+    );
+
+    assert_eq!(
+        fix(ExplicitVisibility, source),
+        r#"<?php
+
+abstract class Foo
+{
+    public $name;
+    public static $count = 0;
+    public readonly int $id;
+    public const LIMIT = 10;
+    final public const MAX = 20;
+    protected $visible;
+
+    public function handle()
+    {
+    }
+
+    abstract public static function make();
+
+    final public function run()
+    {
+    }
+}
+"#
+    );
+}
+
+#[test]
+fn it_reports_members_of_interfaces_enums_and_anonymous_classes_without_a_visibility() {
+    assert_eq!(
+        check(
+            ExplicitVisibility,
+            r#"<?php
+
+interface Foo
+{
+    function handle();
+}
+
+enum Bar
+{
+    case Baz;
+
+    const DEFAULT = self::Baz;
+}
+
+$foo = new class {
+    private $name;
+
+    function name()
+    {
+        return $this->name;
+    }
+};
+"#
+        ),
+        vec![
+            ("Method should declare its visibility".to_string(), 5),
+            ("Constant should declare its visibility".to_string(), 12),
+            ("Method should declare its visibility".to_string(), 18),
+        ]
+    );
+}
+
+#[test]
+fn it_reports_a_blank_line_between_the_opening_tag_and_the_file_docblock() {
+    let source =
+        "<?php\n\n/**\n * This file is part of the app.\n */\n\ndeclare(strict_types=1);\n";
+
+    assert_eq!(
+        check(FileDocblockPlacement, source),
+        vec![(
+            "There should be no blank line between the opening tag and the file docblock"
+                .to_string(),
+            1
+        )]
+    );
+
+    assert_eq!(
+        fix(FileDocblockPlacement, source),
+        "<?php\n/**\n * This file is part of the app.\n */\n\ndeclare(strict_types=1);\n"
+    );
+
+    assert!(check(
+        FileDocblockPlacement,
+        "<?php\n/**\n * This file is part of the app.\n */\n"
+    )
+    .is_empty());
+    assert!(check(
+        FileDocblockPlacement,
+        "<?php\n\n// Not a docblock.\n\ndeclare(strict_types=1);\n"
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_reports_multiline_parameter_lists_without_a_trailing_comma() {
+    let source = r#"<?php
+
+function greet(
+    string $greeting,
+    string $name
+) {
+}
+
+function inline(string $a, string $b)
+{
+}
+
+function trailing(
+    string $a,
+) {
+}
+
+class Foo
+{
+    public function __construct(
+        private string $name
+    ) {
+    }
+}
+
+$closure = function (
+    $a
+) {
+};
+
+$arrow = fn (
+    $a
+) => $a;
+"#;
+
+    assert_eq!(
+        check(MultilineTrailingComma, source),
+        vec![
+            (
+                "Multi-line parameter lists should have a trailing comma".to_string(),
+                5
+            ),
+            (
+                "Multi-line parameter lists should have a trailing comma".to_string(),
+                21
+            ),
+            (
+                "Multi-line parameter lists should have a trailing comma".to_string(),
+                27
+            ),
+            (
+                "Multi-line parameter lists should have a trailing comma".to_string(),
+                32
+            ),
+        ]
+    );
+
+    let fixed = fix(MultilineTrailingComma, source);
+
+    assert!(fixed.contains("    string $name,\n) {"));
+    assert!(fixed.contains("        private string $name,\n    ) {"));
+    assert!(check(MultilineTrailingComma, &fixed).is_empty());
+}
+
+#[test]
+fn it_converges_after_applying_every_fix() {
+    let mut source = r#"<?php
+
+
+/**
+ * A file that breaks most of the rules.
+ */
+
+namespace App;
+
+use function sprintf;
+use Zeta\Thing;
+use Alpha\Other;
+
+class Greeter extends Other {
+    var $greeting = 'Hello';
+    const NAME = 'greeter';
+
+    function greet(
+        string $name
+    ): string
+    {
+        return sprintf('%s, %s', $this->greeting, $name);
+    }
+
+    static function make(Thing $thing) {
+        return new static();
+    }
+}
+"#
+    .to_string();
+
+    for _ in 0..5 {
+        let diagnostics = lint(psr12_rules(), &source);
+
+        if diagnostics.is_empty() {
+            break;
+        }
+
+        source = apply(&diagnostics, &source);
+    }
+
+    assert!(lint(psr12_rules(), &source).is_empty());
+    assert_eq!(
+        source,
+        r#"<?php
+/**
+ * A file that breaks most of the rules.
+ */
+
+namespace App;
+
+use Alpha\Other;
+use Zeta\Thing;
+
+use function sprintf;
+
+class Greeter extends Other
+{
+    public $greeting = 'Hello';
+    public const NAME = 'greeter';
+
+    public function greet(
+        string $name,
+    ): string {
+        return sprintf('%s, %s', $this->greeting, $name);
+    }
+
+    public static function make(Thing $thing)
+    {
+        return new static();
+    }
+}
+"#
+    );
+}
+
+#[test]
+fn it_does_not_panic_on_truncated_input() {
+    let source = r#"<?php
+
+/**
+ * A file with a bit of everything.
+ */
+
+namespace App;
+
+use function sprintf;
+use Zeta\Thing;
+use Alpha\{Other, Another};
+
+class Greeter extends Other implements Thing {
+    var $greeting = 'Hello';
+
+    function greet(
+        string $name
+    ): string
+    {
+        return sprintf('%s, %s', $this->greeting, $name);
+    }
+}
+
+interface Named extends Thing {}
+
+enum Suit: string implements Named {
+    case Hearts = 'H';
+}
+
+function make(Thing $thing) {
+    return new class { function make() {} };
+}
+"#;
+
+    for end in 0..=source.len() {
+        let truncated = &source[..end];
+        let result = Parser::parse(Lexer::new(truncated.as_bytes()));
+
+        run(psr12_rules(), truncated, &result.ast);
+    }
+}
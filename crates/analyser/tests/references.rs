@@ -0,0 +1,187 @@
+use pxp_analyser::{find_references, ParsedFile, ReferenceKind, SymbolRef};
+use pxp_index::{FileId, IndexBuilder};
+use pxp_inference::TypeEngine;
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+
+const MODELS: &str = r#"<?php
+namespace App\Models;
+
+class User {
+    public string $name;
+    const TABLE = 'users';
+
+    public static function find(int $id): static {
+        return new static;
+    }
+
+    public function save(): void {
+        $this->save();
+    }
+}
+
+class Post {
+    public function save(): void {}
+}
+"#;
+
+const CONTROLLER: &str = r#"<?php
+namespace App\Http;
+
+use App\Models\User;
+use App\Models\Post;
+
+/** @param User $user */
+function store(User $user, Post $post, $unknown) {
+    $user->save();
+    $post->save();
+    $unknown->save();
+    User::find(1)->save();
+    (new User)->save();
+    $user->save(...);
+    $user?->save();
+    echo $user->name, User::TABLE, User::class;
+
+    if ($post instanceof User) {}
+}
+
+store(new User, new Post, null);
+$store = store(...);
+"#;
+
+/// Find the references in each file, returning the file, the referenced text, the kind of
+/// reference and whether it's only a possible reference.
+fn find(target: SymbolRef) -> Vec<(usize, String, ReferenceKind, bool)> {
+    let sources = [MODELS, CONTROLLER];
+    let asts: Vec<_> = sources
+        .iter()
+        .map(|source| Parser::parse(Lexer::new(source.as_bytes())).ast)
+        .collect();
+
+    let mut builder = IndexBuilder::new();
+
+    for (id, ast) in asts.iter().enumerate() {
+        builder.index(FileId::new(id), ast);
+    }
+
+    let index = builder.build();
+
+    let types: Vec<_> = asts
+        .iter()
+        .map(|ast| TypeEngine::new(&index).infer(ast))
+        .collect();
+
+    let files: Vec<_> = asts
+        .iter()
+        .enumerate()
+        .map(|(id, ast)| ParsedFile {
+            file: FileId::new(id),
+            ast,
+        })
+        .collect();
+
+    find_references(target, &files, &index, &types)
+        .into_iter()
+        .map(|reference| {
+            let id = files
+                .iter()
+                .position(|file| file.file == reference.file)
+                .unwrap();
+
+            (
+                id,
+                sources[id][reference.span.start..reference.span.end].to_string(),
+                reference.kind,
+                reference.possible,
+            )
+        })
+        .collect()
+}
+
+fn method(class: &str, name: &str) -> SymbolRef {
+    SymbolRef::Method {
+        class: class.into(),
+        name: name.into(),
+    }
+}
+
+#[test]
+fn it_only_finds_calls_on_receivers_of_the_right_class() {
+    let references = find(method("App\\Models\\User", "save"));
+
+    assert_eq!(
+        references,
+        vec![
+            (0, "save".into(), ReferenceKind::InstanceCall, false),
+            (1, "save".into(), ReferenceKind::InstanceCall, false),
+            (1, "save".into(), ReferenceKind::InstanceCall, true),
+            (1, "save".into(), ReferenceKind::InstanceCall, false),
+            (1, "save".into(), ReferenceKind::InstanceCall, false),
+            (1, "save".into(), ReferenceKind::InstanceCall, false),
+            (1, "save".into(), ReferenceKind::InstanceCall, false),
+        ]
+    );
+
+    let references = find(method("App\\Models\\Post", "save"));
+
+    assert_eq!(
+        references,
+        vec![
+            (1, "save".into(), ReferenceKind::InstanceCall, false),
+            (1, "save".into(), ReferenceKind::InstanceCall, true),
+        ]
+    );
+}
+
+#[test]
+fn it_finds_references_to_a_class() {
+    assert_eq!(
+        find(SymbolRef::Class("App\\Models\\User".into())),
+        vec![
+            (1, "App\\Models\\User".into(), ReferenceKind::Import, false),
+            (1, "User".into(), ReferenceKind::Docblock, false),
+            (1, "User".into(), ReferenceKind::TypeHint, false),
+            (1, "User".into(), ReferenceKind::StaticCall, false),
+            (1, "User".into(), ReferenceKind::Instantiation, false),
+            (1, "User".into(), ReferenceKind::StaticAccess, false),
+            (1, "User".into(), ReferenceKind::ClassString, false),
+            (1, "User".into(), ReferenceKind::ClassName, false),
+            (1, "User".into(), ReferenceKind::Instantiation, false),
+        ]
+    );
+}
+
+#[test]
+fn it_finds_references_to_static_members_and_properties() {
+    assert_eq!(
+        find(method("App\\Models\\User", "find")),
+        vec![(1, "find".into(), ReferenceKind::StaticCall, false)]
+    );
+
+    assert_eq!(
+        find(SymbolRef::Property {
+            class: "App\\Models\\User".into(),
+            name: "name".into(),
+        }),
+        vec![(1, "name".into(), ReferenceKind::InstanceAccess, false)]
+    );
+
+    assert_eq!(
+        find(SymbolRef::Constant {
+            class: "App\\Models\\User".into(),
+            name: "TABLE".into(),
+        }),
+        vec![(1, "TABLE".into(), ReferenceKind::StaticAccess, false)]
+    );
+}
+
+#[test]
+fn it_finds_references_to_a_function() {
+    assert_eq!(
+        find(SymbolRef::Function("App\\Http\\store".into())),
+        vec![
+            (1, "store".into(), ReferenceKind::FunctionCall, false),
+            (1, "store".into(), ReferenceKind::FunctionCall, false),
+        ]
+    );
+}
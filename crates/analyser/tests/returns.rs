@@ -0,0 +1,281 @@
+mod common;
+
+use common::analyse_with;
+use pxp_analyser::ReturnPass;
+use pxp_diagnostics::{DiagnosticKind, Severity};
+
+/// Analyse the given code and return the severity and message of each diagnostic.
+fn analyse(code: &str) -> Vec<(Severity, String)> {
+    let source = format!("<?php {}", code);
+
+    analyse_with(ReturnPass::new(), &source)
+        .into_iter()
+        .map(|diagnostic| (diagnostic.severity, diagnostic.kind.get_message()))
+        .collect()
+}
+
+fn missing(function: &str, r#type: &str) -> (Severity, String) {
+    (
+        Severity::Error,
+        format!(
+            "{}() must return a value of type {}, but can reach the end without returning",
+            function, r#type
+        ),
+    )
+}
+
+#[test]
+fn it_accepts_bodies_that_always_return() {
+    assert!(analyse(
+        r#"
+        function a(int $a): string {
+            if ($a > 1) {
+                return 'many';
+            } elseif ($a === 1) {
+                return 'one';
+            } else {
+                return 'none';
+            }
+        }
+
+        function b(int $a): string {
+            switch ($a) {
+                case 1:
+                case 2:
+                    return 'few';
+                default:
+                    throw new Exception();
+            }
+        }
+
+        function c(int $a): string {
+            match ($a) {
+                1 => throw new Exception(),
+                2 => exit(1),
+            };
+        }
+
+        function d(): string {
+            try {
+                return 'value';
+            } catch (Exception $e) {
+                throw $e;
+            } finally {
+                echo 'done';
+            }
+        }
+
+        function e(): string {
+            fail();
+        }
+
+        function fail(): never {
+            throw new Exception();
+        }
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_reports_bodies_that_can_reach_the_end() {
+    assert_eq!(
+        analyse(
+            r#"
+            function a(int $a): string {
+                if ($a > 1) {
+                    return 'many';
+                } elseif ($a === 1) {
+                    return 'one';
+                }
+            }
+
+            function b(int $a): string {
+                switch ($a) {
+                    case 1:
+                        return 'one';
+                    case 2:
+                        break;
+                    default:
+                        return 'other';
+                }
+            }
+
+            function c(): int {}
+
+            class Example {
+                public function d(): array {
+                    $f = function (): bool {
+                        echo 'no return';
+                    };
+
+                    return [];
+                }
+            }
+            "#
+        ),
+        vec![
+            missing("a", "string"),
+            missing("b", "string"),
+            missing("c", "int"),
+            missing("{closure}", "bool"),
+        ]
+    );
+}
+
+#[test]
+fn it_follows_returns_inside_of_loops() {
+    assert_eq!(
+        analyse(
+            r#"
+            function a(array $items): string {
+                foreach ($items as $item) {
+                    if ($item) {
+                        return $item;
+                    }
+                }
+            }
+
+            function b(): string {
+                while (true) {
+                    if (rand()) {
+                        return 'found';
+                    }
+                }
+            }
+
+            function c(): string {
+                for (;;) {
+                    foreach ([1, 2] as $item) {
+                        continue 2;
+                    }
+                }
+            }
+
+            function d(): string {
+                do {
+                    return 'once';
+                } while (rand());
+            }
+
+            function e(): string {
+                while (true) {
+                    if (rand()) {
+                        break;
+                    }
+                }
+            }
+
+            function f(): string {
+                while (true) {
+                    foreach ([1, 2] as $item) {
+                        break 2;
+                    }
+                }
+            }
+            "#
+        ),
+        vec![
+            missing("a", "string"),
+            missing("e", "string"),
+            missing("f", "string")
+        ]
+    );
+}
+
+#[test]
+fn it_does_not_require_a_value_for_void_nullable_or_mixed_return_types() {
+    assert!(analyse(
+        r#"
+        function a(): void {}
+        function b(): ?string {}
+        function c(): string|null {}
+        function d(): mixed {}
+        function e() {}
+        function f(): never { throw new Exception(); }
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_ignores_generators() {
+    assert!(analyse(
+        r#"
+        function a(): Generator {
+            yield 1;
+        }
+
+        function b(): iterable {
+            if (rand()) {
+                return;
+            }
+
+            yield from [1, 2];
+        }
+        "#
+    )
+    .is_empty());
+
+    // A `yield` inside of a closure doesn't make the outer function a generator.
+    assert_eq!(
+        analyse(
+            r#"
+            function a(): Generator {
+                $f = function () {
+                    yield 1;
+                };
+            }
+            "#
+        ),
+        vec![missing("a", "Generator")]
+    );
+}
+
+#[test]
+fn it_reports_returns_that_do_not_match_the_return_type() {
+    assert_eq!(
+        analyse(
+            r#"
+            function a(): string {
+                if (rand()) {
+                    return;
+                }
+
+                return 'value';
+            }
+
+            class Example {
+                public function b(): void {
+                    if (rand()) {
+                        return;
+                    }
+
+                    return 1;
+                }
+
+                public function c(): ?int {
+                    $f = fn () => 1;
+
+                    return;
+                }
+            }
+            "#
+        ),
+        vec![
+            (
+                Severity::Warning,
+                "a() must return a value of type string, but returns without one".to_string()
+            ),
+            (
+                Severity::Error,
+                "Example::b() has a void return type, so it cannot return a value".to_string()
+            ),
+            (
+                Severity::Warning,
+                "Example::c() must return a value of type ?int, but returns without one"
+                    .to_string()
+            ),
+        ]
+    );
+}
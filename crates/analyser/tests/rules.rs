@@ -0,0 +1,225 @@
+mod common;
+
+use common::with_context;
+use pxp_analyser::{
+    Analyser, DisallowErrorSuppression, DisallowEval, Rule, RuleContext, RuleDiagnostic,
+};
+use pxp_ast::{Expression, ExpressionKind, Statement};
+use pxp_diagnostics::{Diagnostic, DiagnosticKind, Severity};
+use pxp_span::LineIndex;
+
+fn analyse(analyser: &mut Analyser, code: &str) -> Vec<(String, String, Severity, usize)> {
+    let source = format!("<?php {}", code);
+    let line_index = LineIndex::new(source.as_bytes());
+
+    with_context(&source, |context, ast| {
+        analyser.analyse(&context, &line_index, ast)
+    })
+    .into_iter()
+    .map(|diagnostic| {
+        (
+            diagnostic.kind.get_code(),
+            diagnostic.kind.get_message(),
+            diagnostic.severity,
+            line_index.line_column(diagnostic.span.start).line + 1,
+        )
+    })
+    .collect()
+}
+
+#[test]
+fn it_reports_eval() {
+    let mut analyser = Analyser::new().with_rule(DisallowEval::default());
+
+    assert_eq!(
+        analyse(
+            &mut analyser,
+            r#"
+        $code = 'return 1;';
+        eval($code);
+        "#
+        ),
+        vec![(
+            "disallow-eval".to_string(),
+            "eval() should not be used".to_string(),
+            Severity::Error,
+            3
+        )]
+    );
+}
+
+#[test]
+fn it_reports_error_suppression_except_for_allowed_functions() {
+    let mut analyser = Analyser::new().with_rule(DisallowErrorSuppression::new(["unlink"]));
+
+    assert_eq!(
+        analyse(
+            &mut analyser,
+            r#"
+        @unlink('file.txt');
+        @\UNLINK('file.txt');
+        @file_get_contents('file.txt');
+        $value = @$array['key'];
+        "#
+        ),
+        [4, 5]
+            .into_iter()
+            .map(|line| (
+                "disallow-error-suppression".to_string(),
+                "Errors should be handled instead of suppressed with @".to_string(),
+                Severity::Warning,
+                line
+            ))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn it_configures_rules_through_their_constructors() {
+    let mut analyser = Analyser::new()
+        .with_rule(DisallowEval::new(Severity::Warning))
+        .with_rule(DisallowErrorSuppression::default());
+
+    assert_eq!(
+        analyser.rules().collect::<Vec<_>>(),
+        vec!["disallow-eval", "disallow-error-suppression"]
+    );
+
+    assert_eq!(
+        analyse(&mut analyser, "@eval('return 1;');")
+            .into_iter()
+            .map(|(rule, _, severity, _)| (rule, severity))
+            .collect::<Vec<_>>(),
+        vec![
+            ("disallow-error-suppression".to_string(), Severity::Warning),
+            ("disallow-eval".to_string(), Severity::Warning),
+        ]
+    );
+}
+
+/// Reports calls to `query()` outside of the class that is allowed to make them.
+struct QueryRule {
+    class: &'static str,
+}
+
+impl Rule for QueryRule {
+    fn name(&self) -> &str {
+        "query"
+    }
+
+    fn check_expression(
+        &mut self,
+        expression: &Expression,
+        context: &RuleContext,
+    ) -> Vec<Diagnostic<RuleDiagnostic>> {
+        let ExpressionKind::FunctionCall(call) = &expression.kind else {
+            return Vec::new();
+        };
+
+        if !matches!(&call.target.kind, ExpressionKind::Name(name) if name.symbol() == b"query")
+            || context
+                .class_name()
+                .is_some_and(|class| **class == *self.class.as_bytes())
+        {
+            return Vec::new();
+        }
+
+        vec![Diagnostic::new(
+            RuleDiagnostic::new(format!(
+                "query() called from {} in {}",
+                context
+                    .function_name()
+                    .map_or("top-level code".to_string(), |function| function
+                        .to_string()),
+                context
+                    .class_name()
+                    .map_or("no class".to_string(), |class| class.to_string()),
+            )),
+            Severity::Error,
+            expression.span,
+        )]
+    }
+}
+
+#[test]
+fn it_gives_rules_the_enclosing_class_and_function() {
+    let mut analyser = Analyser::new().with_rule(QueryRule {
+        class: "App\\Query",
+    });
+
+    assert_eq!(
+        analyse(
+            &mut analyser,
+            r#"
+        namespace App;
+
+        class Query {
+            public function run() { query(); }
+        }
+
+        class Controller {
+            public function index() {
+                return array_map(fn () => query(), []);
+            }
+        }
+
+        function helper() { query(); }
+
+        query();
+        "#
+        )
+        .into_iter()
+        .map(|(_, message, _, _)| message)
+        .collect::<Vec<_>>(),
+        vec![
+            "query() called from {closure} in App\\Controller",
+            "query() called from App\\helper in no class",
+            "query() called from top-level code in no class",
+        ]
+    );
+}
+
+#[test]
+fn it_walks_the_ast_once_for_all_rules() {
+    struct Counter(std::rc::Rc<std::cell::Cell<(usize, usize)>>);
+
+    impl Rule for Counter {
+        fn name(&self) -> &str {
+            "counter"
+        }
+
+        fn check_statement(
+            &mut self,
+            _: &Statement,
+            _: &RuleContext,
+        ) -> Vec<Diagnostic<RuleDiagnostic>> {
+            let (statements, expressions) = self.0.get();
+            self.0.set((statements + 1, expressions));
+
+            Vec::new()
+        }
+
+        fn check_expression(
+            &mut self,
+            _: &Expression,
+            _: &RuleContext,
+        ) -> Vec<Diagnostic<RuleDiagnostic>> {
+            let (statements, expressions) = self.0.get();
+            self.0.set((statements, expressions + 1));
+
+            Vec::new()
+        }
+    }
+
+    let first = std::rc::Rc::default();
+    let second = std::rc::Rc::default();
+    let mut analyser = Analyser::new()
+        .with_rule(Counter(std::rc::Rc::clone(&first)))
+        .with_rule(Counter(std::rc::Rc::clone(&second)));
+
+    analyse(&mut analyser, "$a = 1 + 2; echo $a;");
+
+    // `<?php`, the assignment and `echo`, then `$a = 1 + 2`, `$a`, `1 + 2`, `1`, `2` and `$a`.
+    assert_eq!(first.get(), (3, 6));
+    assert_eq!(second.get(), (3, 6));
+}
@@ -0,0 +1,149 @@
+use pxp_analyser::{
+    semantic_tokens, SemanticTokenKind,
+    SemanticTokenModifier::{self, *},
+};
+use pxp_index::{FileId, IndexBuilder};
+use pxp_inference::TypeEngine;
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+
+use SemanticTokenKind::*;
+
+/// Get the text, kind and modifiers of each semantic token in the code.
+fn tokens(code: &str) -> Vec<(String, SemanticTokenKind, Vec<SemanticTokenModifier>)> {
+    let source = format!("<?php\n{code}");
+    let result = Parser::parse(Lexer::new(source.as_bytes()));
+
+    let mut builder = IndexBuilder::new();
+    builder.index(FileId::new(0), &result.ast);
+    let index = builder.build();
+
+    let types = TypeEngine::new(&index).infer(&result.ast);
+
+    semantic_tokens(&result.ast, &types, &index)
+        .into_iter()
+        .map(|token| {
+            (
+                source[token.span.to_range()].to_string(),
+                token.kind,
+                token.modifiers,
+            )
+        })
+        .collect()
+}
+
+fn token(
+    text: &str,
+    kind: SemanticTokenKind,
+    modifiers: &[SemanticTokenModifier],
+) -> (String, SemanticTokenKind, Vec<SemanticTokenModifier>) {
+    (text.to_string(), kind, modifiers.to_vec())
+}
+
+#[test]
+fn it_classifies_the_identifiers_in_a_class() {
+    assert_eq!(
+        tokens(
+            r#"
+namespace App\Models;
+
+class User {
+    const TABLE = 'users';
+
+    public function __construct(public readonly string $name) {}
+
+    public static function find(int $id): ?User {
+        return new static;
+    }
+
+    /** @deprecated Use save() instead. */
+    public function persist(): void {}
+
+    public function save(): void {
+        $name = $this->name;
+        $this->persist();
+        static::find(1);
+        echo self::TABLE, PHP_EOL, undefined(), $this->missing();
+    }
+}
+"#
+        ),
+        vec![
+            token("App", Namespace, &[Declaration]),
+            token("Models", Namespace, &[Declaration]),
+            token("User", Class, &[Declaration]),
+            token("TABLE", Constant, &[Declaration]),
+            token("__construct", Method, &[Declaration]),
+            token("$name", Parameter, &[Declaration, Readonly]),
+            token("find", Method, &[Declaration, Static]),
+            token("$id", Parameter, &[Declaration]),
+            token("User", Class, &[]),
+            token("persist", Method, &[Declaration, Deprecated]),
+            token("save", Method, &[Declaration]),
+            token("$name", Variable, &[]),
+            token("$this", This, &[]),
+            token("name", Property, &[Readonly]),
+            token("$this", This, &[]),
+            token("persist", Method, &[Deprecated]),
+            token("find", Method, &[Static]),
+            token("TABLE", Constant, &[]),
+            token("PHP_EOL", Constant, &[]),
+            token("undefined", Identifier, &[]),
+            token("$this", This, &[]),
+            token("missing", Identifier, &[]),
+        ]
+    );
+}
+
+#[test]
+fn it_distinguishes_parameters_from_local_variables() {
+    assert_eq!(
+        tokens(
+            r#"
+function greet($name) {
+    $greeting = 'Hello';
+    $f = function () use ($name, $greeting) { return $name; };
+    $g = fn ($suffix) => $name . $suffix;
+}
+"#
+        ),
+        vec![
+            token("greet", Function, &[Declaration]),
+            token("$name", Parameter, &[Declaration]),
+            token("$greeting", Variable, &[]),
+            token("$f", Variable, &[]),
+            token("$name", Parameter, &[]),
+            token("$greeting", Variable, &[]),
+            token("$name", Parameter, &[]),
+            token("$g", Variable, &[]),
+            token("$suffix", Parameter, &[Declaration]),
+            token("$name", Parameter, &[]),
+            token("$suffix", Parameter, &[]),
+        ]
+    );
+}
+
+#[test]
+fn it_splits_qualified_names_and_falls_back_to_identifiers() {
+    assert_eq!(
+        tokens(
+            r#"
+enum Status { case Active; }
+
+$status = \Status::Active;
+$user = new \App\Models\User();
+"#
+        ),
+        vec![
+            token("Status", Class, &[Declaration]),
+            token("Active", EnumCase, &[Declaration]),
+            token("$status", Variable, &[]),
+            token("Status", Class, &[]),
+            token("Active", EnumCase, &[]),
+            token("$user", Variable, &[]),
+            token("App", Namespace, &[]),
+            token("Models", Namespace, &[]),
+            token("User", Identifier, &[]),
+        ]
+    );
+}
@@ -0,0 +1,141 @@
+use pxp_analyser::{find_similar_functions, ParsedFile, SimilarityDetector};
+use pxp_index::FileId;
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+
+const ORIGINAL: &str = r#"<?php
+function total(array $items): int {
+    $total = 0;
+
+    foreach ($items as $item) {
+        if ($item->price > 100) {
+            $total += $item->price * 2;
+        }
+    }
+
+    return $total;
+}
+"#;
+
+const RENAMED: &str = r#"<?php
+class Cart {
+    public function sum(array $lines): int {
+        $sum = 10;
+
+        foreach ($lines as $line) {
+            if ($line->cost > 5) {
+                $sum += $line->cost * 3;
+            }
+        }
+
+        return $sum;
+    }
+}
+"#;
+
+const DIFFERENT: &str = r#"<?php
+function describe(array $items): string {
+    $names = [];
+
+    while ($item = array_shift($items)) {
+        try {
+            $names[] = strtoupper($item->name());
+        } catch (Exception $e) {
+            return 'error';
+        }
+    }
+
+    return implode(', ', $names);
+}
+"#;
+
+/// Find the similar functions in the given sources, returning the names of each pair and their
+/// similarity.
+fn find(sources: &[&str], detector: SimilarityDetector) -> Vec<(String, String, f32)> {
+    let asts: Vec<_> = sources
+        .iter()
+        .map(|source| Parser::parse(Lexer::new(source.as_bytes())).ast)
+        .collect();
+
+    let files: Vec<_> = asts
+        .iter()
+        .enumerate()
+        .map(|(id, ast)| ParsedFile {
+            file: FileId::new(id),
+            ast,
+        })
+        .collect();
+
+    detector
+        .find(&files, 0.5)
+        .into_iter()
+        .map(|pair| {
+            (
+                pair.first.name.to_string(),
+                pair.second.name.to_string(),
+                pair.similarity,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn it_finds_copies_with_renamed_variables_and_different_literals() {
+    assert_eq!(
+        find(&[ORIGINAL, RENAMED], SimilarityDetector::new()),
+        vec![("total".into(), "Cart::sum".into(), 1.0)]
+    );
+}
+
+#[test]
+fn it_does_not_report_structurally_different_functions() {
+    assert!(find(&[ORIGINAL, DIFFERENT], SimilarityDetector::new()).is_empty());
+    assert!(find(&[RENAMED, DIFFERENT], SimilarityDetector::new()).is_empty());
+}
+
+#[test]
+fn it_tells_apart_conditions_and_loops() {
+    let condition = "<?php function a($a) { if ($a) { echo $a; echo $a; } return $a; }";
+    let r#loop = "<?php function b($b) { while ($b) { echo $b; echo $b; } return $b; }";
+
+    let pairs = find(
+        &[condition, r#loop],
+        SimilarityDetector::new().with_min_nodes(0),
+    );
+
+    assert!(pairs.iter().all(|(_, _, similarity)| *similarity < 1.0));
+}
+
+#[test]
+fn it_skips_functions_below_the_minimum_number_of_nodes() {
+    let a = "<?php function a($a) { return $a + 1; }";
+    let b = "<?php function b($b) { return $b + 2; }";
+
+    assert!(find(&[a, b], SimilarityDetector::new()).is_empty());
+    assert_eq!(
+        find(&[a, b], SimilarityDetector::new().with_min_nodes(5)),
+        vec![("a".into(), "b".into(), 1.0)]
+    );
+    assert!(find(
+        &[ORIGINAL, RENAMED],
+        SimilarityDetector::new().with_min_nodes(1000)
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_uses_the_default_detector() {
+    let files = [ParsedFile {
+        file: FileId::new(0),
+        ast: &Parser::parse(Lexer::new(
+            format!("{ORIGINAL}{}", &RENAMED[5..]).as_bytes(),
+        ))
+        .ast,
+    }];
+
+    let pairs = find_similar_functions(&files, 0.9);
+
+    assert_eq!(pairs.len(), 1);
+    assert_eq!(pairs[0].first.file, pairs[0].second.file);
+    assert!(pairs[0].first.span.end <= pairs[0].second.span.start);
+}
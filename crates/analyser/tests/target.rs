@@ -0,0 +1,224 @@
+mod common;
+
+use common::with_context;
+use pxp_analyser::{
+    passes, Analyser, AnalysisProfile, Rule, RuleContext, RuleDiagnostic, TargetEnvironment,
+};
+use pxp_ast::{constants::ConstantValue, Expression, ExpressionKind, Statement, StatementKind};
+use pxp_diagnostics::{Diagnostic, DiagnosticKind, Severity};
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+use pxp_span::LineIndex;
+
+/// Reports calls to `json_validate()`, which was added in PHP 8.3, unless the target has it.
+struct RequiresJsonValidate;
+
+impl Rule for RequiresJsonValidate {
+    fn name(&self) -> &str {
+        "requires-json-validate"
+    }
+
+    fn check_expression(
+        &mut self,
+        expression: &Expression,
+        context: &RuleContext,
+    ) -> Vec<Diagnostic<RuleDiagnostic>> {
+        let ExpressionKind::FunctionCall(call) = &expression.kind else {
+            return Vec::new();
+        };
+
+        let ExpressionKind::Name(name) = &call.target.kind else {
+            return Vec::new();
+        };
+
+        let available = context
+            .target()
+            .and_then(|target| target.php_version_id)
+            .is_some_and(|version| version >= 80300);
+
+        if name.symbol() != b"json_validate" || available {
+            return Vec::new();
+        }
+
+        vec![Diagnostic::new(
+            RuleDiagnostic::new("json_validate() requires PHP 8.3"),
+            Severity::Error,
+            expression.span,
+        )]
+    }
+}
+
+const GATED: &str = r#"
+function valid(string $json): bool {
+    if (PHP_VERSION_ID >= 80300) {
+        return json_validate($json);
+    }
+
+    return json_decode($json) !== null;
+}
+"#;
+
+fn rules(target: Option<TargetEnvironment>, code: &str) -> Vec<(String, usize)> {
+    let source = format!("<?php {}", code);
+    let line_index = LineIndex::new(source.as_bytes());
+
+    with_context(&source, |context, ast| {
+        let context = match &target {
+            Some(target) => context.with_target(target),
+            None => context,
+        };
+
+        Analyser::new()
+            .with_rule(RequiresJsonValidate)
+            .analyse(&context, &line_index, ast)
+    })
+    .into_iter()
+    .map(|diagnostic| {
+        (
+            diagnostic.kind.get_message(),
+            line_index.line_column(diagnostic.span.start).line + 1,
+        )
+    })
+    .collect()
+}
+
+fn undefined_variables(target: Option<TargetEnvironment>, code: &str) -> Vec<String> {
+    with_context(&format!("<?php {}", code), |context, ast| {
+        let context = match &target {
+            Some(target) => context.with_target(target),
+            None => context,
+        };
+
+        AnalysisProfile::minimal()
+            .with("A011", Severity::Error)
+            .analyse(&mut passes(), &context, ast)
+    })
+    .into_iter()
+    .map(|diagnostic| diagnostic.kind.get_message())
+    .collect()
+}
+
+fn evaluate(target: &TargetEnvironment, condition: &str) -> Option<bool> {
+    let result = Parser::parse(Lexer::new(format!("<?php {};", condition).as_bytes()));
+
+    let Some(Statement {
+        kind: StatementKind::Expression(statement),
+        ..
+    }) = result
+        .ast
+        .iter()
+        .find(|statement| matches!(statement.kind, StatementKind::Expression(_)))
+    else {
+        panic!("expected an expression statement");
+    };
+
+    target.evaluate(&statement.expression)
+}
+
+#[test]
+fn it_does_not_report_version_gated_calls_when_targeting_a_version_that_has_them() {
+    let target = TargetEnvironment::new().with_php_version_id(80300);
+
+    assert!(rules(Some(target), &format!("{GATED} json_validate('{{}}');")).is_empty());
+}
+
+#[test]
+fn it_only_reports_calls_that_are_not_gated_when_targeting_an_older_version() {
+    let target = TargetEnvironment::new().with_php_version_id(80100);
+
+    assert_eq!(
+        rules(Some(target), &format!("{GATED}\njson_validate('{{}}');")),
+        vec![("json_validate() requires PHP 8.3".to_string(), 10)]
+    );
+}
+
+#[test]
+fn it_treats_both_branches_as_live_without_a_target() {
+    assert_eq!(
+        rules(None, GATED),
+        vec![("json_validate() requires PHP 8.3".to_string(), 4)]
+    );
+
+    let code = r#"
+    namespace App;
+
+    function greet() {
+        if (PHP_OS_FAMILY === 'Windows') {
+            echo $a;
+        } else {
+            echo $b;
+        }
+    }
+    "#;
+
+    assert_eq!(
+        undefined_variables(None, code),
+        vec!["undefined variable $a", "undefined variable $b"]
+    );
+    assert_eq!(
+        undefined_variables(Some(TargetEnvironment::new().with_os_family("Linux")), code),
+        vec!["undefined variable $b"]
+    );
+}
+
+#[test]
+fn it_skips_the_branches_after_one_that_is_always_taken() {
+    let code = r#"
+    function greet($unknown) {
+        if (PHP_VERSION_ID < 80000) {
+            echo $a;
+        } elseif (PHP_VERSION_ID < 80200) {
+            echo $b;
+        } elseif ($unknown) {
+            echo $c;
+        } else {
+            echo $d;
+        }
+
+        return PHP_VERSION_ID >= 80200 ? $e : $f;
+    }
+    "#;
+
+    assert_eq!(
+        undefined_variables(
+            Some(TargetEnvironment::new().with_php_version_id(80100)),
+            code
+        ),
+        vec!["undefined variable $b", "undefined variable $f"]
+    );
+}
+
+#[test]
+fn it_folds_conditions_on_the_target() {
+    let target = TargetEnvironment::new()
+        .with_php_version_id(80112)
+        .with_os_family("Windows")
+        .with_constant("APP_DEBUG", ConstantValue::Bool(false));
+
+    assert_eq!(evaluate(&target, "PHP_VERSION_ID >= 80100"), Some(true));
+    assert_eq!(evaluate(&target, "\\PHP_VERSION_ID < 80100"), Some(false));
+    assert_eq!(evaluate(&target, "PHP_MAJOR_VERSION === 8"), Some(true));
+    assert_eq!(evaluate(&target, "PHP_MINOR_VERSION == 2"), Some(false));
+    assert_eq!(
+        evaluate(&target, "PHP_OS_FAMILY !== 'Windows'"),
+        Some(false)
+    );
+    assert_eq!(evaluate(&target, "defined('APP_DEBUG')"), Some(true));
+    assert_eq!(evaluate(&target, "!APP_DEBUG"), Some(true));
+    assert_eq!(
+        evaluate(&target, "PHP_VERSION_ID < 80000 && $unknown"),
+        Some(false)
+    );
+    assert_eq!(
+        evaluate(&target, "PHP_VERSION_ID < 80000 || $unknown"),
+        None
+    );
+    assert_eq!(evaluate(&target, "defined('OTHER')"), None);
+    assert_eq!(evaluate(&target, "PHP_VERSION_ID === '80112'"), Some(false));
+    assert_eq!(evaluate(&target, "PHP_VERSION_ID == '80112'"), None);
+
+    let unknown = TargetEnvironment::new();
+
+    assert_eq!(evaluate(&unknown, "PHP_VERSION_ID >= 80100"), None);
+    assert_eq!(evaluate(&unknown, "PHP_OS_FAMILY === 'Windows'"), None);
+}
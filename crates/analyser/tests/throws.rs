@@ -0,0 +1,174 @@
+mod common;
+
+use common::with_context;
+use pxp_analyser::{Analyser, ThrowAnalysis, UndocumentedThrows};
+use pxp_diagnostics::{DiagnosticKind, Severity};
+use pxp_span::LineIndex;
+
+const CODE: &str = r#"
+namespace App;
+
+class StorageException extends \RuntimeException {}
+class DiskFullException extends StorageException {}
+class NetworkException extends \RuntimeException {}
+
+/**
+ * @throws DiskFullException
+ */
+function write() {
+    if (rand()) {
+        throw new DiskFullException();
+    }
+
+    throw new NetworkException();
+}
+
+function save() {
+    try {
+        write();
+    } catch (StorageException $e) {
+        log($e);
+    }
+
+    upload();
+}
+
+function upload() {
+    throw new NetworkException();
+}
+
+function log($e) {}
+
+class Controller
+{
+    public function store() {
+        try {
+            save();
+        } catch (\Throwable $e) {
+            throw new StorageException();
+        } finally {
+            $this->flush();
+        }
+    }
+
+    public function flush() {
+        save();
+    }
+}
+
+function ping() {
+    pong();
+    throw new NetworkException();
+}
+
+function pong() {
+    ping();
+}
+
+/**
+ * @throws StorageException
+ */
+function noop() {}
+"#;
+
+fn throws(check: impl FnOnce(&mut ThrowAnalysis)) {
+    with_context(&format!("<?php {}", CODE), |context, ast| {
+        check(&mut ThrowAnalysis::new(&context, ast))
+    });
+}
+
+fn names(throws: Vec<pxp_analyser::Thrown>) -> Vec<String> {
+    throws
+        .into_iter()
+        .map(|thrown| thrown.exception.resolved.to_string())
+        .collect()
+}
+
+#[test]
+fn it_computes_the_exceptions_that_escape_from_each_function_in_a_call_chain() {
+    throws(|analysis| {
+        assert_eq!(
+            names(analysis.function(b"App\\write".into())),
+            vec!["App\\DiskFullException", "App\\NetworkException"]
+        );
+        // The documented exception is caught, and the undocumented one is never seen by callers.
+        assert_eq!(
+            names(analysis.function(b"App\\save".into())),
+            vec!["App\\NetworkException"]
+        );
+        assert_eq!(
+            names(analysis.method(b"App\\Controller".into(), b"store".into())),
+            vec!["App\\StorageException", "App\\NetworkException"]
+        );
+        assert_eq!(
+            names(analysis.method(b"app\\controller".into(), b"FLUSH".into())),
+            vec!["App\\NetworkException"]
+        );
+    });
+}
+
+#[test]
+fn it_stops_at_recursive_calls() {
+    throws(|analysis| {
+        assert_eq!(
+            names(analysis.function(b"App\\ping".into())),
+            vec!["App\\NetworkException"]
+        );
+        assert_eq!(
+            names(analysis.function(b"App\\pong".into())),
+            vec!["App\\NetworkException"]
+        );
+    });
+}
+
+#[test]
+fn it_reports_undocumented_and_unthrown_exceptions() {
+    let source = format!("<?php {}", CODE);
+    let line_index = LineIndex::new(source.as_bytes());
+
+    let diagnostics = with_context(&source, |context, ast| {
+        Analyser::new()
+            .with_rule(UndocumentedThrows::default())
+            .analyse(&context, &line_index, ast)
+    })
+    .into_iter()
+    .map(|diagnostic| {
+        (
+            diagnostic.kind.get_message(),
+            diagnostic.severity,
+            line_index.line_column(diagnostic.span.start).line + 1,
+        )
+    })
+    .collect::<Vec<_>>();
+
+    let warning = |function: &str, exception: &str, line: usize| {
+        (
+            format!(
+                "App\\{}() can throw App\\{}, which isn't caught or documented with @throws",
+                function, exception
+            ),
+            Severity::Warning,
+            line,
+        )
+    };
+
+    assert_eq!(
+        diagnostics,
+        vec![
+            warning("write", "NetworkException", 16),
+            warning("save", "NetworkException", 26),
+            warning("upload", "NetworkException", 30),
+            warning("Controller::store", "StorageException", 41),
+            warning("Controller::store", "NetworkException", 43),
+            warning("Controller::flush", "NetworkException", 48),
+            warning("ping", "NetworkException", 54),
+            warning("pong", "NetworkException", 58),
+            (
+                "App\\noop() is documented to throw App\\StorageException, but never does"
+                    .to_string(),
+                Severity::Information,
+                64
+            ),
+        ]
+    );
+}
@@ -0,0 +1,263 @@
+mod common;
+
+use common::analyse_with;
+use pxp_analyser::{AnalyserDiagnostic, TraitPass};
+
+fn analyse(code: &str) -> Vec<AnalyserDiagnostic> {
+    analyse_with(TraitPass::new(), &format!("<?php {}", code))
+        .into_iter()
+        .map(|diagnostic| diagnostic.kind)
+        .collect()
+}
+
+#[test]
+fn it_reports_methods_that_collide_between_traits() {
+    assert_eq!(
+        analyse(
+            r#"
+        trait Hello { public function greet() {} }
+        trait World { public function greet() {} }
+
+        class Greeter {
+            use Hello, World;
+        }
+        "#
+        ),
+        vec![AnalyserDiagnostic::TraitMethodCollision {
+            class: "Greeter".into(),
+            method: "greet".into(),
+            r#trait: "World".into(),
+            other: "Hello".into(),
+        }]
+    );
+}
+
+#[test]
+fn it_does_not_report_collisions_resolved_with_insteadof() {
+    assert!(analyse(
+        r#"
+        trait Hello { public function greet() {} }
+        trait World { public function greet() {} }
+
+        class Greeter {
+            use Hello, World {
+                Hello::greet insteadof World;
+                World::greet as greetWorld;
+            }
+        }
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_resolves_the_names_in_insteadof_lists() {
+    assert!(analyse(
+        r#"
+        namespace App\Traits {
+            trait Hello { public function greet() {} }
+            trait World { public function greet() {} }
+        }
+
+        namespace App {
+            use App\Traits\World as Planet;
+
+            class Greeter {
+                use Traits\Hello, \App\Traits\World {
+                    Traits\Hello::greet insteadof Planet;
+                }
+            }
+        }
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_does_not_report_collisions_with_methods_declared_by_the_class() {
+    assert!(analyse(
+        r#"
+        trait Hello { public function greet() {} }
+        trait World { public function GREET() {} }
+
+        class Greeter {
+            use Hello;
+            use World;
+
+            public function greet() {}
+        }
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_reports_missing_implementations_of_abstract_trait_methods() {
+    assert_eq!(
+        analyse(
+            r#"
+        trait Greets {
+            abstract public function name(): string;
+
+            public function greet() {}
+        }
+
+        class Greeter {
+            use Greets;
+        }
+
+        abstract class AbstractGreeter {
+            use Greets;
+        }
+
+        class Named { public function name(): string {} }
+
+        class NamedGreeter extends Named {
+            use Greets;
+        }
+        "#
+        ),
+        vec![AnalyserDiagnostic::MissingTraitAbstractMethod {
+            class: "Greeter".into(),
+            r#trait: "Greets".into(),
+            method: "name".into(),
+        }]
+    );
+}
+
+#[test]
+fn it_allows_abstract_methods_to_be_implemented_by_another_trait() {
+    assert!(analyse(
+        r#"
+        trait Greets { abstract public function name(): string; }
+        trait Named { public function name(): string {} }
+
+        class Greeter {
+            use Greets, Named;
+        }
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_reports_incompatible_implementations_of_abstract_trait_methods() {
+    assert_eq!(
+        analyse(
+            r#"
+        trait Greets {
+            abstract public function greet(string $name, int $times = 1): string;
+        }
+
+        class FewerParameters {
+            use Greets;
+            public function greet(string $name): string {}
+        }
+
+        class NarrowerParameter {
+            use Greets;
+            public function greet(int $name, int $times = 1): string {}
+        }
+
+        class WiderReturn {
+            use Greets;
+            public function greet(string $name, int $times = 1): ?string {}
+        }
+
+        class MissingReturn {
+            use Greets;
+            public function greet(string $name, int $times = 1) {}
+        }
+
+        class Compatible {
+            use Greets;
+            public function greet(string|int $name, $times = 1, ...$rest): string {}
+        }
+
+        class Variadic {
+            use Greets;
+            public function greet(string|int ...$arguments): string {}
+        }
+        "#
+        ),
+        [
+            "FewerParameters",
+            "NarrowerParameter",
+            "WiderReturn",
+            "MissingReturn"
+        ]
+        .into_iter()
+        .map(
+            |class| AnalyserDiagnostic::IncompatibleTraitMethodSignature {
+                class: class.into(),
+                r#trait: "Greets".into(),
+                method: "greet".into(),
+            }
+        )
+        .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn it_reports_properties_that_are_defined_differently() {
+    assert_eq!(
+        analyse(
+            r#"
+        trait Counts { public int $count = 0; }
+        trait AlsoCounts { public int $count = 0; }
+        trait CountsFromOne { public int $count = 1; }
+
+        class Same {
+            use Counts, AlsoCounts;
+        }
+
+        class Different {
+            use Counts, CountsFromOne;
+        }
+
+        class DifferentFromClass {
+            use Counts;
+
+            protected int $count = 0;
+        }
+        "#
+        ),
+        vec![
+            AnalyserDiagnostic::TraitPropertyConflict {
+                class: "Different".into(),
+                r#trait: "CountsFromOne".into(),
+                other: "Counts".into(),
+                property: "count".into(),
+            },
+            AnalyserDiagnostic::TraitPropertyConflict {
+                class: "DifferentFromClass".into(),
+                r#trait: "Counts".into(),
+                other: "DifferentFromClass".into(),
+                property: "count".into(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn it_flattens_traits_used_by_other_traits() {
+    assert_eq!(
+        analyse(
+            r#"
+        trait Hello { public function greet() {} }
+        trait Greets { use Hello; }
+        trait World { public function greet() {} }
+
+        class Greeter {
+            use Greets, World;
+        }
+        "#
+        ),
+        vec![AnalyserDiagnostic::TraitMethodCollision {
+            class: "Greeter".into(),
+            method: "greet".into(),
+            r#trait: "World".into(),
+            other: "Hello".into(),
+        }]
+    );
+}
@@ -0,0 +1,413 @@
+mod common;
+
+use common::analyse_with;
+use pxp_analyser::{AnalyserDiagnostic, UndefinedVariablePass};
+
+fn analyse(code: &str) -> Vec<AnalyserDiagnostic> {
+    analyse_with(UndefinedVariablePass::new(), &format!("<?php {}", code))
+        .into_iter()
+        .map(|diagnostic| diagnostic.kind)
+        .collect()
+}
+
+fn undefined(variable: &str) -> AnalyserDiagnostic {
+    AnalyserDiagnostic::UndefinedVariable {
+        variable: variable.as_bytes().into(),
+    }
+}
+
+fn possibly_undefined(variable: &str) -> AnalyserDiagnostic {
+    AnalyserDiagnostic::PossiblyUndefinedVariable {
+        variable: variable.as_bytes().into(),
+    }
+}
+
+#[test]
+fn it_reports_variables_read_before_assignment() {
+    assert_eq!(
+        analyse(
+            r#"
+        function total(array $items) {
+            foreach ($items as $item) {
+                $count = $item;
+            }
+
+            return $total + 1;
+        }
+        "#
+        ),
+        vec![undefined("total")]
+    );
+}
+
+#[test]
+fn it_only_reports_the_first_read_of_a_variable() {
+    assert_eq!(
+        analyse(
+            r#"
+        function example() {
+            echo $a;
+            echo $a;
+        }
+        "#
+        ),
+        vec![undefined("a")]
+    );
+}
+
+#[test]
+fn it_accepts_every_kind_of_binding() {
+    assert!(analyse(
+        r#"
+        function by_reference(&$out) {}
+
+        function example($parameter, ...$rest) {
+            global $config;
+            static $cache = [];
+
+            $assigned = 1;
+            [$first, 'key' => $second] = $rest;
+            list($third) = $rest;
+            $array[] = $parameter;
+            $alias = &$reference;
+            by_reference($written);
+
+            foreach ($rest as $key => $value) {
+                echo $key, $value;
+            }
+
+            try {
+                echo $config, $cache, $assigned, $first, $second, $third, $array, $alias, $written;
+            } catch (Exception $e) {
+                echo $e;
+            }
+
+            $closure = function () use ($assigned, &$captured) {
+                return [$assigned, $captured, $this];
+            };
+
+            return fn ($x) => $x + $parameter;
+        }
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_treats_variables_assigned_in_every_branch_as_defined() {
+    assert!(analyse(
+        r#"
+        function example($a) {
+            if ($a) {
+                $b = 1;
+            } elseif ($a > 1) {
+                $b = 2;
+            } else {
+                $b = 3;
+            }
+
+            switch ($a) {
+                case 1:
+                    $c = 1;
+                    break;
+                default:
+                    $c = 2;
+            }
+
+            $d = $a ? ($e = 1) : ($e = 2);
+
+            if (! $a) {
+                return;
+            } else {
+                $f = 1;
+            }
+
+            return $b + $c + $e + $f;
+        }
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_reports_variables_assigned_in_some_branches_as_possibly_undefined() {
+    assert_eq!(
+        analyse(
+            r#"
+        function example($a) {
+            if ($a) {
+                $b = 1;
+            }
+
+            switch ($a) {
+                case 1:
+                    $c = 1;
+                    break;
+            }
+
+            $a && ($d = 1);
+
+            foreach ([] as $item) {
+                $e = $item;
+            }
+
+            return [$b, $c, $d, $e];
+        }
+        "#
+        ),
+        vec![
+            possibly_undefined("b"),
+            possibly_undefined("c"),
+            possibly_undefined("d"),
+            possibly_undefined("e"),
+        ]
+    );
+}
+
+#[test]
+fn it_tracks_assignments_across_loop_iterations() {
+    assert_eq!(
+        analyse(
+            r#"
+        function example() {
+            while (true) {
+                $line = read();
+
+                if ($line) {
+                    break;
+                }
+            }
+
+            for ($i = 0; $i < 10; $i++) {
+                echo $previous;
+                $previous = $i;
+            }
+
+            return $line . $i;
+        }
+        "#
+        ),
+        vec![possibly_undefined("previous")]
+    );
+}
+
+#[test]
+fn it_assumes_variables_might_be_assigned_in_try_blocks() {
+    assert_eq!(
+        analyse(
+            r#"
+        function example() {
+            try {
+                $a = risky();
+                $b = risky();
+            } catch (Exception $e) {
+                echo $a;
+                $b = null;
+            } finally {
+                $c = 1;
+            }
+
+            return [$b, $c];
+        }
+        "#
+        ),
+        vec![possibly_undefined("a")]
+    );
+}
+
+#[test]
+fn it_does_not_report_variables_inside_of_isset_empty_or_coalesce() {
+    assert!(analyse(
+        r#"
+        function example() {
+            if (isset($a['key']) || empty($b)) {
+                return $c ?? null;
+            }
+
+            $d ??= [];
+
+            return $d;
+        }
+        "#
+    )
+    .is_empty());
+}
+
+#[test]
+fn it_reports_variables_again_after_they_are_unset() {
+    assert_eq!(
+        analyse(
+            r#"
+        function example() {
+            $a = 1;
+            unset($a);
+            echo $a;
+        }
+        "#
+        ),
+        vec![undefined("a")]
+    );
+}
+
+#[test]
+fn it_reports_variables_unset_in_one_branch_as_possibly_undefined() {
+    assert_eq!(
+        analyse(
+            r#"
+        function example($condition) {
+            $a = 1;
+
+            if ($condition) {
+                unset($a);
+            }
+
+            echo $a;
+        }
+        "#
+        ),
+        vec![possibly_undefined("a")]
+    );
+
+    assert_eq!(
+        analyse(
+            r#"
+        function example($condition) {
+            $a = 1;
+
+            if ($condition) {
+                unset($a);
+                $a = 2;
+            }
+
+            echo $a;
+        }
+        "#
+        ),
+        vec![]
+    );
+}
+
+#[test]
+fn it_checks_closures_and_methods_separately() {
+    assert_eq!(
+        analyse(
+            r#"
+        $global = 1;
+        echo $undefined;
+
+        class Example {
+            public $property;
+
+            public function method($a) {
+                return function () use ($a, $b) {
+                    return $a . $c . $global;
+                };
+            }
+
+            public string $hooked {
+                set {
+                    $this->hooked = $value . $d;
+                }
+            }
+        }
+
+        function outer() {
+            $a = 1;
+
+            return fn () => $a . $b;
+        }
+        "#
+        ),
+        vec![
+            undefined("b"),
+            undefined("c"),
+            undefined("global"),
+            undefined("d"),
+            undefined("b"),
+        ]
+    );
+}
+
+#[test]
+fn it_does_not_check_scopes_that_define_variables_dynamically() {
+    assert_eq!(
+        analyse(
+            r#"
+        function with_extract(array $data) {
+            extract($data);
+
+            return $name;
+        }
+
+        function with_include() {
+            include 'variables.php';
+
+            return $name;
+        }
+        "#
+        ),
+        vec![
+            AnalyserDiagnostic::UncheckedVariableScope {
+                construct: b"extract()".into(),
+            },
+            AnalyserDiagnostic::UncheckedVariableScope {
+                construct: b"include".into(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn it_defines_the_keys_of_literal_arrays_passed_to_extract() {
+    assert_eq!(
+        analyse(
+            r#"
+        function literal() {
+            extract(['name' => 'Ryan', 'not-valid' => true]);
+
+            return [$name, $missing];
+        }
+
+        /** @param array{title: string, views?: int} $post */
+        function shaped(array $post) {
+            extract($post);
+
+            return [$title, $views];
+        }
+
+        function flags() {
+            extract(['name' => 'Ryan'], EXTR_PREFIX_ALL, 'user');
+
+            return $user_name;
+        }
+        "#
+        ),
+        vec![
+            undefined("missing"),
+            possibly_undefined("views"),
+            AnalyserDiagnostic::UncheckedVariableScope {
+                construct: b"extract()".into(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn it_reads_the_variables_named_by_compact() {
+    assert_eq!(
+        analyse(
+            r#"
+        function literal() {
+            $name = 'Ryan';
+
+            return compact('name', ['age', 'name']);
+        }
+
+        function non_literal(string $key) {
+            return compact($key, $other);
+        }
+        "#
+        ),
+        vec![undefined("age"), undefined("other")]
+    );
+}
@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+use pxp_bytestring::ByteString;
+
+use crate::{Argument, ArgumentList, NamedArgument, PositionalArgument};
+
+impl ArgumentList {
+    /// The positional arguments, including the ones that are unpacked, in the order they're passed.
+    pub fn positional(&self) -> impl Iterator<Item = &PositionalArgument> {
+        self.arguments.iter().filter_map(|argument| match argument {
+            Argument::Positional(argument) => Some(argument),
+            Argument::Named(_) => None,
+        })
+    }
+
+    /// The named arguments, keyed by name. A name that's passed more than once maps to the first
+    /// argument with that name.
+    pub fn named(&self) -> HashMap<&ByteString, &NamedArgument> {
+        let mut named = HashMap::new();
+
+        for argument in self.arguments.iter() {
+            if let Argument::Named(argument) = argument {
+                named.entry(&argument.name.symbol).or_insert(argument);
+            }
+        }
+
+        named
+    }
+
+    /// Whether any of the arguments are unpacked, e.g. `...$arguments`, in which case the number
+    /// of arguments can't be known.
+    pub fn has_unpacking(&self) -> bool {
+        self.positional()
+            .any(|argument| argument.ellipsis.is_some())
+    }
+
+    /// Find the argument that is passed to the parameter at the given position with the given name,
+    /// which can be written with or without the leading `$`.
+    ///
+    /// Returns `None` if the parameter isn't given an argument, or if it could be given one by an
+    /// unpacked argument.
+    pub fn argument_for_parameter(&self, position: usize, name: &[u8]) -> Option<&Argument> {
+        let name = name.strip_prefix(b"$").unwrap_or(name);
+
+        if let Some(argument) = self.arguments.iter().find(|argument| {
+            matches!(argument, Argument::Named(named) if named.name.symbol.as_bytes() == name)
+        }) {
+            return Some(argument);
+        }
+
+        for (index, argument) in self.arguments.iter().enumerate() {
+            match argument {
+                Argument::Positional(argument) if argument.ellipsis.is_some() => return None,
+                Argument::Positional(_) if index == position => return Some(argument),
+                _ => {}
+            }
+        }
+
+        None
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{ArrayExpression, ArrayItem, Expression, HasId};
+use crate::{ArrayExpression, ArrayIndexExpression, ArrayItem};
 
 impl ArrayExpression {
     pub fn is_list(&self) -> bool {
@@ -7,3 +7,10 @@ impl ArrayExpression {
             .all(|item| matches!(item, ArrayItem::Value(_)))
     }
 }
+
+impl ArrayIndexExpression {
+    /// Whether the index is empty, e.g. `$a[] = 1`, which appends a value to the array.
+    pub fn is_append(&self) -> bool {
+        self.index.is_none()
+    }
+}
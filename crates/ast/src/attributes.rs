@@ -0,0 +1,157 @@
+use pxp_bytestring::ByteStr;
+
+use crate::{
+    constants::{ConstantValue, NotConstant},
+    Argument, ArrowFunctionExpression, Attribute, AttributeGroup, BackedEnumCase, ClassStatement,
+    ClassishConstant, ClosureExpression, ConstantStatement, FunctionParameter, FunctionStatement,
+    HookedProperty, Method, MethodParameter, SimpleIdentifier, SimpleProperty, UnitEnumCase,
+};
+
+/// An argument passed to an attribute, evaluated as a constant expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstantArgument<'a> {
+    /// The name of a named argument, e.g. `methods` in `#[Route('/', methods: ['GET'])]`.
+    pub name: Option<&'a SimpleIdentifier>,
+    pub value: Result<ConstantValue, NotConstant>,
+}
+
+impl Attribute {
+    /// Evaluate each of the attribute's arguments, in source order. Spread arguments can't be
+    /// evaluated, so they're never constant.
+    pub fn arguments_as_const(&self) -> Vec<ConstantArgument<'_>> {
+        let Some(arguments) = &self.arguments else {
+            return Vec::new();
+        };
+
+        arguments
+            .arguments
+            .iter()
+            .map(|argument| {
+                let (name, ellipsis, value) = match argument {
+                    Argument::Positional(argument) => (None, argument.ellipsis, &argument.value),
+                    Argument::Named(argument) => {
+                        (Some(&argument.name), argument.ellipsis, &argument.value)
+                    }
+                };
+
+                let value = match ellipsis {
+                    Some(_) => Err(NotConstant { span: value.span }),
+                    None => value.evaluate_constant(),
+                };
+
+                ConstantArgument { name, value }
+            })
+            .collect()
+    }
+}
+
+/// Implemented by nodes that can be decorated with attributes, e.g. `#[SensitiveParameter]`.
+pub trait HasAttributes {
+    fn attribute_groups(&self) -> &[AttributeGroup];
+
+    /// Get the resolved names of every attribute on the node, in source order.
+    fn attributes(&self) -> impl Iterator<Item = &ByteStr> {
+        self.attribute_groups()
+            .iter()
+            .flat_map(|group| group.members.iter())
+            .map(|attribute| attribute.name.symbol().as_ref())
+    }
+
+    /// Check if the node has an attribute with the given name. The comparison is case-insensitive
+    /// and ignores any leading backslash, so `SensitiveParameter` matches `#[\SensitiveParameter]`.
+    fn has_attribute(&self, name: &ByteStr) -> bool {
+        self.get_attribute(name).is_some()
+    }
+
+    /// Get the first attribute with the given name, using the same comparison as `has_attribute`.
+    fn get_attribute(&self, name: &ByteStr) -> Option<&Attribute> {
+        let name = name.strip_prefix(b'\\');
+
+        self.attribute_groups()
+            .iter()
+            .flat_map(|group| group.members.iter())
+            .find(|attribute| {
+                let symbol: &ByteStr = attribute.name.symbol().as_ref();
+
+                symbol.strip_prefix(b'\\').eq_ignore_ascii_case(name)
+            })
+    }
+}
+
+impl HasAttributes for FunctionParameter {
+    fn attribute_groups(&self) -> &[AttributeGroup] {
+        &self.attributes
+    }
+}
+
+impl HasAttributes for MethodParameter {
+    fn attribute_groups(&self) -> &[AttributeGroup] {
+        &self.attributes
+    }
+}
+
+impl HasAttributes for Method {
+    fn attribute_groups(&self) -> &[AttributeGroup] {
+        &self.attributes
+    }
+}
+
+impl HasAttributes for ClassStatement {
+    fn attribute_groups(&self) -> &[AttributeGroup] {
+        &self.attributes
+    }
+}
+
+impl HasAttributes for FunctionStatement {
+    fn attribute_groups(&self) -> &[AttributeGroup] {
+        &self.attributes
+    }
+}
+
+impl HasAttributes for SimpleProperty {
+    fn attribute_groups(&self) -> &[AttributeGroup] {
+        &self.attributes
+    }
+}
+
+impl HasAttributes for HookedProperty {
+    fn attribute_groups(&self) -> &[AttributeGroup] {
+        &self.attributes
+    }
+}
+
+impl HasAttributes for ClassishConstant {
+    fn attribute_groups(&self) -> &[AttributeGroup] {
+        &self.attributes
+    }
+}
+
+impl HasAttributes for ConstantStatement {
+    fn attribute_groups(&self) -> &[AttributeGroup] {
+        &self.attributes
+    }
+}
+
+impl HasAttributes for UnitEnumCase {
+    fn attribute_groups(&self) -> &[AttributeGroup] {
+        &self.attributes
+    }
+}
+
+impl HasAttributes for BackedEnumCase {
+    fn attribute_groups(&self) -> &[AttributeGroup] {
+        &self.attributes
+    }
+}
+
+impl HasAttributes for ClosureExpression {
+    fn attribute_groups(&self) -> &[AttributeGroup] {
+        &self.attributes
+    }
+}
+
+impl HasAttributes for ArrowFunctionExpression {
+    fn attribute_groups(&self) -> &[AttributeGroup] {
+        &self.attributes
+    }
+}
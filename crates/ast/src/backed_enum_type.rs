@@ -21,6 +21,7 @@ impl Display for BackedEnumType {
     }
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for BackedEnumType {
     fn default() -> Self {
         Self::Invalid
@@ -0,0 +1,933 @@
+//! Build ASTs in code, e.g. to generate PHP from a scaffolder or macro expander.
+//!
+//! The nodes have the same shape as the ones produced by the parser for the equivalent code, but
+//! every span is empty and ids are taken from an [`IdAllocator`]. Names are treated as though the
+//! code was written in the global namespace, so they're resolved to themselves.
+//!
+//! ```
+//! use pxp_ast::builder::{Builder, IdAllocator};
+//! use pxp_type::Type;
+//!
+//! let ids = IdAllocator::new();
+//! let b = Builder::new(&ids);
+//!
+//! let class = b
+//!     .class("Foo")
+//!     .extends("Bar")
+//!     .method(
+//!         b.method("baz")
+//!             .public()
+//!             .returns(Type::Void)
+//!             .body([b.expression(b.call("qux", [b.variable("a")]))]),
+//!     )
+//!     .build();
+//! ```
+
+use std::cell::Cell;
+
+use pxp_bytestring::ByteString;
+use pxp_span::Span;
+use pxp_token::{OwnedToken, TokenKind};
+use pxp_type::Type;
+
+use crate::{utils::CommaSeparated, *};
+
+/// Hands out the ids of built nodes.
+///
+/// Ids only need to be unique within a tree, so an allocator that starts after the largest id in
+/// an existing AST can be used to build nodes that are inserted into it.
+#[derive(Debug)]
+pub struct IdAllocator {
+    next: Cell<NodeId>,
+}
+
+impl IdAllocator {
+    pub fn new() -> Self {
+        Self::starting_at(1)
+    }
+
+    pub fn starting_at(id: NodeId) -> Self {
+        Self {
+            next: Cell::new(id),
+        }
+    }
+
+    pub fn next(&self) -> NodeId {
+        let id = self.next.get();
+        self.next.set(id + 1);
+        id
+    }
+}
+
+impl Default for IdAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Creates nodes, taking their ids from the given allocator.
+#[derive(Debug, Clone, Copy)]
+pub struct Builder<'a> {
+    ids: &'a IdAllocator,
+}
+
+impl<'a> Builder<'a> {
+    pub fn new(ids: &'a IdAllocator) -> Self {
+        Self { ids }
+    }
+
+    fn id(&self) -> NodeId {
+        self.ids.next()
+    }
+
+    fn comments(&self) -> CommentGroup {
+        CommentGroup {
+            id: self.id(),
+            comments: Vec::new(),
+        }
+    }
+
+    fn token(kind: TokenKind, symbol: impl Into<ByteString>) -> OwnedToken {
+        OwnedToken {
+            kind,
+            span: Span::default(),
+            symbol: symbol.into(),
+        }
+    }
+
+    /// A name, e.g. `Foo\Bar`. A leading backslash is removed from the resolved name.
+    pub fn name(&self, name: &str) -> Name {
+        Name::resolved(
+            self.id(),
+            name.strip_prefix('\\').unwrap_or(name).into(),
+            name.into(),
+            Span::default(),
+        )
+    }
+
+    pub fn identifier(&self, symbol: &str) -> SimpleIdentifier {
+        SimpleIdentifier::new(self.id(), symbol.into(), Span::default())
+    }
+
+    /// A variable, with or without the leading `$`.
+    pub fn simple_variable(&self, name: &str) -> SimpleVariable {
+        let stripped = name.strip_prefix('$').unwrap_or(name);
+
+        SimpleVariable {
+            id: self.id(),
+            symbol: format!("${stripped}").into(),
+            stripped: stripped.into(),
+            span: Span::default(),
+        }
+    }
+
+    pub fn data_type(&self, r#type: Type<ResolvedName>) -> DataType {
+        DataType {
+            id: self.id(),
+            kind: r#type,
+            span: Span::default(),
+        }
+    }
+
+    fn return_type(&self, r#type: Type<ResolvedName>) -> ReturnType {
+        ReturnType {
+            id: self.id(),
+            span: Span::default(),
+            colon: Span::default(),
+            data_type: self.data_type(r#type),
+        }
+    }
+
+    fn comma_separated<T>(items: Vec<T>) -> CommaSeparated<T> {
+        CommaSeparated {
+            commas: vec![Span::default(); items.len().saturating_sub(1)],
+            inner: items,
+        }
+    }
+
+    // Expressions
+
+    pub fn expr(&self, kind: ExpressionKind) -> Expression {
+        Expression::new(self.id(), kind, Span::default(), self.comments())
+    }
+
+    pub fn variable(&self, name: &str) -> Expression {
+        self.expr(ExpressionKind::Variable(Box::new(
+            Variable::SimpleVariable(self.simple_variable(name)),
+        )))
+    }
+
+    fn literal(&self, kind: LiteralKind, token: OwnedToken) -> Expression {
+        self.expr(ExpressionKind::Literal(Box::new(Literal::new(
+            self.id(),
+            kind,
+            token,
+            Span::default(),
+        ))))
+    }
+
+    /// A single-quoted string containing the given value.
+    pub fn string(&self, value: &str) -> Expression {
+        let escaped = value.replace('\\', "\\\\").replace('\'', "\\'");
+
+        self.literal(
+            LiteralKind::String,
+            Self::token(TokenKind::LiteralSingleQuotedString, format!("'{escaped}'")),
+        )
+    }
+
+    pub fn int(&self, value: i64) -> Expression {
+        self.literal(
+            LiteralKind::Integer,
+            Self::token(TokenKind::LiteralInteger, value.to_string()),
+        )
+    }
+
+    pub fn float(&self, value: f64) -> Expression {
+        // `Debug` always includes a decimal point, so `1.0` doesn't turn into an integer.
+        self.literal(
+            LiteralKind::Float,
+            Self::token(TokenKind::LiteralFloat, format!("{value:?}")),
+        )
+    }
+
+    pub fn bool(&self, value: bool) -> Expression {
+        let (kind, symbol) = if value {
+            (TokenKind::True, "true")
+        } else {
+            (TokenKind::False, "false")
+        };
+
+        self.expr(ExpressionKind::Bool(Box::new(BoolExpression {
+            id: self.id(),
+            span: Span::default(),
+            value: Self::token(kind, symbol),
+        })))
+    }
+
+    pub fn null(&self) -> Expression {
+        self.expr(ExpressionKind::Null(Span::default()))
+    }
+
+    pub fn assign(&self, left: Expression, right: Expression) -> Expression {
+        self.expr(ExpressionKind::AssignmentOperation(Box::new(
+            AssignmentOperationExpression {
+                id: self.id(),
+                span: Span::default(),
+                left: Box::new(left),
+                kind: AssignmentOperationKind::Assign(Span::default()),
+                right: Box::new(right),
+            },
+        )))
+    }
+
+    fn arguments(&self, arguments: impl IntoIterator<Item = Expression>) -> ArgumentList {
+        ArgumentList {
+            id: self.id(),
+            span: Span::default(),
+            comments: self.comments(),
+            left_parenthesis: Span::default(),
+            arguments: arguments
+                .into_iter()
+                .map(|value| {
+                    Argument::Positional(PositionalArgument {
+                        id: self.id(),
+                        span: Span::default(),
+                        comments: self.comments(),
+                        ellipsis: None,
+                        value,
+                    })
+                })
+                .collect(),
+            right_parenthesis: Span::default(),
+        }
+    }
+
+    /// A call to the named function, e.g. `foo($a)`.
+    pub fn call(
+        &self,
+        function: &str,
+        arguments: impl IntoIterator<Item = Expression>,
+    ) -> Expression {
+        let target = self.expr(ExpressionKind::Name(Box::new(self.name(function))));
+
+        self.expr(ExpressionKind::FunctionCall(Box::new(
+            FunctionCallExpression {
+                id: self.id(),
+                span: Span::default(),
+                target: Box::new(target),
+                arguments: self.arguments(arguments),
+            },
+        )))
+    }
+
+    /// A call to a method on the target, e.g. `$a->foo($b)`.
+    pub fn method_call(
+        &self,
+        target: Expression,
+        method: &str,
+        arguments: impl IntoIterator<Item = Expression>,
+    ) -> Expression {
+        let method = self.expr(ExpressionKind::Identifier(Box::new(
+            Identifier::SimpleIdentifier(self.identifier(method)),
+        )));
+
+        self.expr(ExpressionKind::MethodCall(Box::new(MethodCallExpression {
+            id: self.id(),
+            span: Span::default(),
+            target: Box::new(target),
+            arrow: Span::default(),
+            method: Box::new(method),
+            arguments: self.arguments(arguments),
+        })))
+    }
+
+    // Statements
+
+    pub fn stmt(&self, kind: StatementKind) -> Statement {
+        Statement::new(
+            self.id(),
+            kind,
+            Span::default(),
+            self.comments(),
+            self.comments(),
+        )
+    }
+
+    fn block(&self, statements: impl IntoIterator<Item = Statement>) -> Statement {
+        self.stmt(StatementKind::Block(Box::new(BlockStatement {
+            id: self.id(),
+            span: Span::default(),
+            left_brace: Span::default(),
+            statements: statements.into_iter().collect(),
+            trailing_comments: self.comments(),
+            right_brace: Span::default(),
+        })))
+    }
+
+    /// An expression followed by a semicolon.
+    pub fn expression(&self, expression: Expression) -> Statement {
+        self.stmt(StatementKind::Expression(Box::new(ExpressionStatement {
+            id: self.id(),
+            span: Span::default(),
+            expression,
+            ending: Ending::Semicolon(Span::default()),
+        })))
+    }
+
+    pub fn r#return(&self, value: Option<Expression>) -> Statement {
+        self.stmt(StatementKind::Return(Box::new(ReturnStatement {
+            id: self.id(),
+            span: Span::default(),
+            r#return: Span::default(),
+            value,
+            ending: Ending::Semicolon(Span::default()),
+        })))
+    }
+
+    /// An `if` statement with a block body, e.g. `if ($a) { ... }`.
+    pub fn r#if(
+        &self,
+        condition: Expression,
+        body: impl IntoIterator<Item = Statement>,
+    ) -> IfBuilder<'a> {
+        IfBuilder {
+            builder: *self,
+            condition,
+            body: body.into_iter().collect(),
+            elseifs: Vec::new(),
+            r#else: None,
+        }
+    }
+
+    /// A `foreach` statement with a block body, e.g. `foreach ($items as $key => $value) { ... }`.
+    pub fn foreach(
+        &self,
+        iterable: Expression,
+        key: Option<Expression>,
+        value: Expression,
+        body: impl IntoIterator<Item = Statement>,
+    ) -> Statement {
+        let iterator = match key {
+            Some(key) => {
+                ForeachStatementIterator::KeyAndValue(ForeachStatementIteratorKeyAndValue {
+                    id: self.id(),
+                    span: Span::default(),
+                    expression: iterable,
+                    r#as: Span::default(),
+                    ampersand: None,
+                    key,
+                    double_arrow: Span::default(),
+                    value,
+                })
+            }
+            None => ForeachStatementIterator::Value(ForeachStatementIteratorValue {
+                id: self.id(),
+                span: Span::default(),
+                expression: iterable,
+                r#as: Span::default(),
+                ampersand: None,
+                value,
+            }),
+        };
+
+        self.stmt(StatementKind::Foreach(Box::new(ForeachStatement {
+            id: self.id(),
+            span: Span::default(),
+            foreach: Span::default(),
+            left_parenthesis: Span::default(),
+            iterator,
+            right_parenthesis: Span::default(),
+            body: ForeachStatementBody::Statement(ForeachStatementBodyStatement {
+                id: self.id(),
+                span: Span::default(),
+                statement: Box::new(self.block(body)),
+            }),
+        })))
+    }
+
+    /// A `use` statement importing a single name, e.g. `use function Foo\bar as baz;`.
+    pub fn r#use(&self, name: &str, alias: Option<&str>, kind: UseKind) -> Statement {
+        let name = self.name(name);
+        let alias = alias.map(|alias| self.identifier(alias));
+
+        self.stmt(StatementKind::Use(Box::new(UseStatement {
+            id: self.id(),
+            span: Span::default(),
+            kind,
+            uses: vec![Use {
+                id: self.id(),
+                span: Span::default(),
+                name,
+                alias,
+                kind,
+            }],
+        })))
+    }
+
+    // Declarations
+
+    pub fn class(&self, name: &str) -> ClassBuilder<'a> {
+        ClassBuilder {
+            builder: *self,
+            name: self.name(name),
+            modifiers: Vec::new(),
+            extends: None,
+            implements: Vec::new(),
+            members: Vec::new(),
+        }
+    }
+
+    pub fn method(&self, name: &str) -> MethodBuilder<'a> {
+        MethodBuilder {
+            builder: *self,
+            name: self.identifier(name),
+            modifiers: Vec::new(),
+            parameters: Vec::new(),
+            return_type: None,
+            body: None,
+        }
+    }
+
+    pub fn property(&self, name: &str) -> PropertyBuilder<'a> {
+        PropertyBuilder {
+            builder: *self,
+            variable: self.simple_variable(name),
+            modifiers: Vec::new(),
+            r#type: None,
+            default: None,
+        }
+    }
+
+    pub fn function(&self, name: &str) -> FunctionBuilder<'a> {
+        FunctionBuilder {
+            builder: *self,
+            name: self.name(name),
+            parameters: Vec::new(),
+            return_type: None,
+            body: Vec::new(),
+        }
+    }
+
+    pub fn parameter(&self, name: &str) -> ParameterBuilder {
+        ParameterBuilder {
+            name: self.simple_variable(name),
+            r#type: None,
+            default: None,
+            variadic: false,
+            by_reference: false,
+        }
+    }
+}
+
+pub struct IfBuilder<'a> {
+    builder: Builder<'a>,
+    condition: Expression,
+    body: Vec<Statement>,
+    elseifs: Vec<(Expression, Vec<Statement>)>,
+    r#else: Option<Vec<Statement>>,
+}
+
+impl<'a> IfBuilder<'a> {
+    pub fn elseif(
+        mut self,
+        condition: Expression,
+        body: impl IntoIterator<Item = Statement>,
+    ) -> Self {
+        self.elseifs.push((condition, body.into_iter().collect()));
+        self
+    }
+
+    pub fn r#else(mut self, body: impl IntoIterator<Item = Statement>) -> Self {
+        self.r#else = Some(body.into_iter().collect());
+        self
+    }
+
+    pub fn build(self) -> Statement {
+        let b = self.builder;
+
+        let elseifs = self
+            .elseifs
+            .into_iter()
+            .map(|(condition, body)| IfStatementElseIf {
+                id: b.id(),
+                span: Span::default(),
+                elseif: Span::default(),
+                left_parenthesis: Span::default(),
+                condition,
+                right_parenthesis: Span::default(),
+                statement: Box::new(b.block(body)),
+            })
+            .collect();
+
+        let r#else = self.r#else.map(|body| IfStatementElse {
+            id: b.id(),
+            span: Span::default(),
+            r#else: Span::default(),
+            statement: Box::new(b.block(body)),
+        });
+
+        b.stmt(StatementKind::If(Box::new(IfStatement {
+            id: b.id(),
+            span: Span::default(),
+            r#if: Span::default(),
+            left_parenthesis: Span::default(),
+            condition: self.condition,
+            right_parenthesis: Span::default(),
+            body: IfStatementBody::Statement(IfStatementBodyStatement {
+                id: b.id(),
+                span: Span::default(),
+                statement: Box::new(b.block(self.body)),
+                elseifs,
+                r#else,
+            }),
+        })))
+    }
+}
+
+pub struct ClassBuilder<'a> {
+    builder: Builder<'a>,
+    name: Name,
+    modifiers: Vec<ClassModifier>,
+    extends: Option<Name>,
+    implements: Vec<Name>,
+    members: Vec<ClassishMember>,
+}
+
+impl<'a> ClassBuilder<'a> {
+    pub fn r#abstract(mut self) -> Self {
+        self.modifiers
+            .push(ClassModifier::Abstract(Span::default()));
+        self
+    }
+
+    pub fn r#final(mut self) -> Self {
+        self.modifiers.push(ClassModifier::Final(Span::default()));
+        self
+    }
+
+    pub fn readonly(mut self) -> Self {
+        self.modifiers
+            .push(ClassModifier::Readonly(Span::default()));
+        self
+    }
+
+    pub fn extends(mut self, parent: &str) -> Self {
+        self.extends = Some(self.builder.name(parent));
+        self
+    }
+
+    pub fn implements(mut self, interface: &str) -> Self {
+        self.implements.push(self.builder.name(interface));
+        self
+    }
+
+    pub fn property(mut self, property: PropertyBuilder) -> Self {
+        self.members
+            .push(ClassishMember::Property(property.build()));
+        self
+    }
+
+    pub fn method(mut self, method: MethodBuilder) -> Self {
+        self.members.push(ClassishMember::Method(method.build()));
+        self
+    }
+
+    pub fn build(self) -> Statement {
+        let b = self.builder;
+
+        let extends = self.extends.map(|parent| ClassExtends {
+            id: b.id(),
+            span: Span::default(),
+            extends: Span::default(),
+            parent,
+        });
+
+        let implements = (!self.implements.is_empty()).then(|| ClassImplements {
+            id: b.id(),
+            span: Span::default(),
+            implements: Span::default(),
+            interfaces: Builder::comma_separated(self.implements),
+        });
+
+        b.stmt(StatementKind::Class(Box::new(ClassStatement {
+            id: b.id(),
+            span: Span::default(),
+            attributes: Vec::new(),
+            modifiers: ClassModifierGroup {
+                id: b.id(),
+                span: Span::default(),
+                modifiers: self.modifiers,
+            },
+            class: Span::default(),
+            name: self.name,
+            extends,
+            implements,
+            body: ClassBody {
+                id: b.id(),
+                span: Span::default(),
+                left_brace: Span::default(),
+                members: self.members,
+                trailing_comments: b.comments(),
+                right_brace: Span::default(),
+            },
+        })))
+    }
+}
+
+pub struct MethodBuilder<'a> {
+    builder: Builder<'a>,
+    name: SimpleIdentifier,
+    modifiers: Vec<MethodModifier>,
+    parameters: Vec<ParameterBuilder>,
+    return_type: Option<Type<ResolvedName>>,
+    body: Option<Vec<Statement>>,
+}
+
+impl<'a> MethodBuilder<'a> {
+    fn modifier(mut self, modifier: MethodModifier) -> Self {
+        self.modifiers.push(modifier);
+        self
+    }
+
+    pub fn public(self) -> Self {
+        self.modifier(MethodModifier::Public(Span::default()))
+    }
+
+    pub fn protected(self) -> Self {
+        self.modifier(MethodModifier::Protected(Span::default()))
+    }
+
+    pub fn private(self) -> Self {
+        self.modifier(MethodModifier::Private(Span::default()))
+    }
+
+    pub fn r#static(self) -> Self {
+        self.modifier(MethodModifier::Static(Span::default()))
+    }
+
+    pub fn r#final(self) -> Self {
+        self.modifier(MethodModifier::Final(Span::default()))
+    }
+
+    /// Mark the method as abstract. Any method without a `body` is built with an abstract body.
+    pub fn r#abstract(self) -> Self {
+        self.modifier(MethodModifier::Abstract(Span::default()))
+    }
+
+    pub fn parameter(mut self, parameter: ParameterBuilder) -> Self {
+        self.parameters.push(parameter);
+        self
+    }
+
+    pub fn returns(mut self, r#type: Type<ResolvedName>) -> Self {
+        self.return_type = Some(r#type);
+        self
+    }
+
+    pub fn body(mut self, statements: impl IntoIterator<Item = Statement>) -> Self {
+        self.body = Some(statements.into_iter().collect());
+        self
+    }
+
+    pub fn build(self) -> Method {
+        let b = self.builder;
+
+        let parameters = self
+            .parameters
+            .into_iter()
+            .map(|parameter| parameter.build_method_parameter(b))
+            .collect();
+
+        let kind = match self.body {
+            Some(statements) => MethodBodyKind::Concrete(ConcreteMethodBody {
+                id: b.id(),
+                span: Span::default(),
+                left_brace: Span::default(),
+                statements,
+                trailing_comments: b.comments(),
+                right_brace: Span::default(),
+            }),
+            None => MethodBodyKind::Abstract(AbstractMethodBody {
+                id: b.id(),
+                span: Span::default(),
+                semicolon: Span::default(),
+            }),
+        };
+
+        Method {
+            id: b.id(),
+            span: Span::default(),
+            comments: b.comments(),
+            attributes: Vec::new(),
+            modifiers: MethodModifierGroup {
+                id: b.id(),
+                span: Span::default(),
+                modifiers: self.modifiers,
+            },
+            function: Span::default(),
+            ampersand: None,
+            name: self.name,
+            parameters: MethodParameterList {
+                id: b.id(),
+                span: Span::default(),
+                left_parenthesis: Span::default(),
+                parameters: Builder::comma_separated(parameters),
+                right_parenthesis: Span::default(),
+            },
+            return_type: self.return_type.map(|r#type| b.return_type(r#type)),
+            body: MethodBody {
+                id: b.id(),
+                span: Span::default(),
+                kind,
+            },
+        }
+    }
+}
+
+pub struct PropertyBuilder<'a> {
+    builder: Builder<'a>,
+    variable: SimpleVariable,
+    modifiers: Vec<PropertyModifier>,
+    r#type: Option<Type<ResolvedName>>,
+    default: Option<Expression>,
+}
+
+impl<'a> PropertyBuilder<'a> {
+    fn modifier(mut self, modifier: PropertyModifier) -> Self {
+        self.modifiers.push(modifier);
+        self
+    }
+
+    pub fn public(self) -> Self {
+        self.modifier(PropertyModifier::Public(Span::default()))
+    }
+
+    pub fn protected(self) -> Self {
+        self.modifier(PropertyModifier::Protected(Span::default()))
+    }
+
+    pub fn private(self) -> Self {
+        self.modifier(PropertyModifier::Private(Span::default()))
+    }
+
+    pub fn r#static(self) -> Self {
+        self.modifier(PropertyModifier::Static(Span::default()))
+    }
+
+    pub fn readonly(self) -> Self {
+        self.modifier(PropertyModifier::Readonly(Span::default()))
+    }
+
+    pub fn r#type(mut self, r#type: Type<ResolvedName>) -> Self {
+        self.r#type = Some(r#type);
+        self
+    }
+
+    pub fn default(mut self, value: Expression) -> Self {
+        self.default = Some(value);
+        self
+    }
+
+    pub fn build(self) -> Property {
+        let b = self.builder;
+
+        let kind = match self.default {
+            Some(value) => PropertyEntryKind::Initialized(InitializedPropertyEntry {
+                id: b.id(),
+                span: Span::default(),
+                variable: self.variable,
+                equals: Span::default(),
+                value,
+            }),
+            None => PropertyEntryKind::Uninitialized(UninitializedPropertyEntry {
+                id: b.id(),
+                span: Span::default(),
+                variable: self.variable,
+            }),
+        };
+
+        Property::Simple(SimpleProperty {
+            id: b.id(),
+            span: Span::default(),
+            comments: b.comments(),
+            attributes: Vec::new(),
+            modifiers: PropertyModifierGroup {
+                id: b.id(),
+                span: Span::default(),
+                modifiers: self.modifiers,
+            },
+            var: None,
+            r#type: self.r#type.map(|r#type| b.data_type(r#type)),
+            entries: vec![PropertyEntry {
+                id: b.id(),
+                span: Span::default(),
+                kind,
+            }],
+            semicolon: Span::default(),
+        })
+    }
+}
+
+pub struct FunctionBuilder<'a> {
+    builder: Builder<'a>,
+    name: Name,
+    parameters: Vec<ParameterBuilder>,
+    return_type: Option<Type<ResolvedName>>,
+    body: Vec<Statement>,
+}
+
+impl<'a> FunctionBuilder<'a> {
+    pub fn parameter(mut self, parameter: ParameterBuilder) -> Self {
+        self.parameters.push(parameter);
+        self
+    }
+
+    pub fn returns(mut self, r#type: Type<ResolvedName>) -> Self {
+        self.return_type = Some(r#type);
+        self
+    }
+
+    pub fn body(mut self, statements: impl IntoIterator<Item = Statement>) -> Self {
+        self.body = statements.into_iter().collect();
+        self
+    }
+
+    pub fn build(self) -> Statement {
+        let b = self.builder;
+
+        let parameters = self
+            .parameters
+            .into_iter()
+            .map(|parameter| parameter.build_function_parameter(b))
+            .collect();
+
+        b.stmt(StatementKind::Function(Box::new(FunctionStatement {
+            id: b.id(),
+            span: Span::default(),
+            comments: b.comments(),
+            attributes: Vec::new(),
+            function: Span::default(),
+            ampersand: None,
+            name: self.name,
+            parameters: FunctionParameterList {
+                id: b.id(),
+                span: Span::default(),
+                comments: b.comments(),
+                left_parenthesis: Span::default(),
+                parameters: Builder::comma_separated(parameters),
+                right_parenthesis: Span::default(),
+            },
+            return_type: self.return_type.map(|r#type| b.return_type(r#type)),
+            body: FunctionBody {
+                id: b.id(),
+                span: Span::default(),
+                comments: b.comments(),
+                left_brace: Span::default(),
+                statements: self.body,
+                trailing_comments: b.comments(),
+                right_brace: Span::default(),
+            },
+        })))
+    }
+}
+
+/// A parameter of a function or method. Its nodes are only created once the function or method is
+/// built, since the two use different node types.
+pub struct ParameterBuilder {
+    name: SimpleVariable,
+    r#type: Option<Type<ResolvedName>>,
+    default: Option<Expression>,
+    variadic: bool,
+    by_reference: bool,
+}
+
+impl ParameterBuilder {
+    pub fn r#type(mut self, r#type: Type<ResolvedName>) -> Self {
+        self.r#type = Some(r#type);
+        self
+    }
+
+    pub fn default(mut self, value: Expression) -> Self {
+        self.default = Some(value);
+        self
+    }
+
+    pub fn variadic(mut self) -> Self {
+        self.variadic = true;
+        self
+    }
+
+    pub fn by_reference(mut self) -> Self {
+        self.by_reference = true;
+        self
+    }
+
+    fn build_function_parameter(self, b: Builder) -> FunctionParameter {
+        FunctionParameter {
+            id: b.id(),
+            span: Span::default(),
+            comments: b.comments(),
+            name: self.name,
+            attributes: Vec::new(),
+            data_type: self.r#type.map(|r#type| b.data_type(r#type)),
+            ellipsis: self.variadic.then(Span::default),
+            default: self.default,
+            ampersand: self.by_reference.then(Span::default),
+        }
+    }
+
+    fn build_method_parameter(self, b: Builder) -> MethodParameter {
+        MethodParameter {
+            id: b.id(),
+            span: Span::default(),
+            modifiers: None,
+            name: self.name,
+            attributes: Vec::new(),
+            data_type: self.r#type.map(|r#type| b.data_type(r#type)),
+            ellipsis: self.variadic.then(Span::default),
+            default: self.default,
+            ampersand: self.by_reference.then(Span::default),
+        }
+    }
+}
@@ -1,19 +1,22 @@
 use std::slice::Iter;
 
-use pxp_span::{IsSpanned, Span};
-
-use crate::{Comment, CommentGroup};
-
-impl IsSpanned for CommentGroup {
-    fn span(&self) -> Span {
-        self.comments.span()
-    }
-}
+use crate::{Comment, CommentGroup, CommentKind, DocBlock};
 
 impl CommentGroup {
     pub fn iter(&self) -> Iter<'_, Comment> {
         self.comments.iter()
     }
+
+    /// Get the DocBlock that documents the node this group is attached to, i.e. the last one.
+    pub fn docblock(&self) -> Option<&DocBlock> {
+        self.comments
+            .iter()
+            .rev()
+            .find_map(|comment| match &comment.kind {
+                CommentKind::DocBlock(docblock) => Some(&docblock.doc),
+                _ => None,
+            })
+    }
 }
 
 impl IntoIterator for CommentGroup {
@@ -0,0 +1,167 @@
+use pxp_bytestring::{ByteStr, ByteString};
+use pxp_span::Span;
+
+use crate::{
+    literals::IntegerValue, ArithmeticOperationKind, ArrayItem, Expression, ExpressionKind,
+    Identifier, LiteralKind, NameKind,
+};
+
+/// The value of a constant expression, as far as it can be known from the expression itself.
+///
+/// References to other constants can't be followed without an index, so they're kept as
+/// references instead of being replaced with their values.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstantValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(ByteString),
+    /// The items of an array in source order, with the key of each item if it was written.
+    Array(Vec<(Option<ConstantValue>, ConstantValue)>),
+    /// A global constant, e.g. `PHP_EOL`.
+    Constant(ByteString),
+    /// A class constant or an enum case, e.g. `Status::Active`. The class is kept as written when
+    /// it's `self`, `static` or `parent`.
+    ClassConstant {
+        class: ByteString,
+        constant: ByteString,
+    },
+}
+
+impl ConstantValue {
+    pub fn as_string(&self) -> Option<&ByteStr> {
+        match self {
+            ConstantValue::String(value) => Some(value.as_bytestr()),
+            _ => None,
+        }
+    }
+}
+
+/// An expression that couldn't be evaluated, pointing at the part that isn't constant.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NotConstant {
+    pub span: Span,
+}
+
+impl Expression {
+    /// Evaluate the expression as a constant expression.
+    ///
+    /// Literals, `true`, `false`, `null`, arrays, `Foo::class`, references to constants and enum
+    /// cases, signed numbers and the concatenation of strings are supported. Anything else,
+    /// including other operators, heredocs and `new` expressions, isn't constant.
+    pub fn evaluate_constant(&self) -> Result<ConstantValue, NotConstant> {
+        let not_constant = Err(NotConstant { span: self.span });
+
+        match &self.kind {
+            ExpressionKind::Null(_) => Ok(ConstantValue::Null),
+            ExpressionKind::Bool(value) => Ok(ConstantValue::Bool(
+                value.value.symbol.eq_ignore_ascii_case(b"true"),
+            )),
+            ExpressionKind::Literal(literal) => match literal.kind {
+                LiteralKind::Integer => match literal.integer_value() {
+                    Some(IntegerValue::Int(value)) => Ok(ConstantValue::Int(value)),
+                    Some(IntegerValue::Overflow(value)) => Ok(ConstantValue::Float(value)),
+                    None => not_constant,
+                },
+                LiteralKind::Float => literal
+                    .float_value()
+                    .map(ConstantValue::Float)
+                    .ok_or(NotConstant { span: self.span }),
+                LiteralKind::String => literal
+                    .string_value()
+                    .map(|value| ConstantValue::String(value.value().to_bytestring()))
+                    .ok_or(NotConstant { span: self.span }),
+                LiteralKind::Missing => not_constant,
+            },
+            ExpressionKind::Parenthesized(inner) => inner.expr.evaluate_constant(),
+            ExpressionKind::ArithmeticOperation(operation) => match &operation.kind {
+                ArithmeticOperationKind::Negative { right, .. } => {
+                    match right.evaluate_constant()? {
+                        ConstantValue::Int(value) => Ok(value
+                            .checked_neg()
+                            .map(ConstantValue::Int)
+                            .unwrap_or(ConstantValue::Float(-(value as f64)))),
+                        ConstantValue::Float(value) => Ok(ConstantValue::Float(-value)),
+                        _ => not_constant,
+                    }
+                }
+                ArithmeticOperationKind::Positive { right, .. } => {
+                    match right.evaluate_constant()? {
+                        value @ (ConstantValue::Int(_) | ConstantValue::Float(_)) => Ok(value),
+                        _ => not_constant,
+                    }
+                }
+                _ => not_constant,
+            },
+            ExpressionKind::Concat(concat) => {
+                match (
+                    concat.left.evaluate_constant()?,
+                    concat.right.evaluate_constant()?,
+                ) {
+                    (ConstantValue::String(left), ConstantValue::String(right)) => {
+                        let mut value = left.to_vec();
+                        value.extend_from_slice(&right);
+
+                        Ok(ConstantValue::String(ByteString::new(value)))
+                    }
+                    _ => not_constant,
+                }
+            }
+            ExpressionKind::Array(array) => {
+                let mut items = Vec::new();
+
+                for item in array.items.iter() {
+                    match item {
+                        ArrayItem::Value(item) => {
+                            items.push((None, item.value.evaluate_constant()?));
+                        }
+                        ArrayItem::KeyValue(item) => {
+                            items.push((
+                                Some(item.key.evaluate_constant()?),
+                                item.value.evaluate_constant()?,
+                            ));
+                        }
+                        ArrayItem::Skipped(_) => {}
+                        // References and spreads can't be evaluated without knowing the values.
+                        _ => return not_constant,
+                    }
+                }
+
+                Ok(ConstantValue::Array(items))
+            }
+            ExpressionKind::Name(name) => match &name.kind {
+                NameKind::Special(_) => not_constant,
+                _ => Ok(ConstantValue::Constant(name.symbol().clone())),
+            },
+            ExpressionKind::ConstantFetch(fetch) => {
+                let (class, special) = match &fetch.target.kind {
+                    ExpressionKind::Name(name) => (name.symbol().clone(), name.is_special()),
+                    ExpressionKind::Self_(_) => (b"self".into(), true),
+                    ExpressionKind::Static(_) => (b"static".into(), true),
+                    ExpressionKind::Parent(_) => (b"parent".into(), true),
+                    _ => return not_constant,
+                };
+
+                let Identifier::SimpleIdentifier(constant) = &fetch.constant else {
+                    return not_constant;
+                };
+
+                // The name behind `self::class` depends on where the expression is used.
+                if constant.symbol.eq_ignore_ascii_case(b"class") {
+                    return if special {
+                        not_constant
+                    } else {
+                        Ok(ConstantValue::String(class))
+                    };
+                }
+
+                Ok(ConstantValue::ClassConstant {
+                    class,
+                    constant: constant.symbol.clone(),
+                })
+            }
+            _ => not_constant,
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use std::slice;
+
+use crate::{Expression, IfStatement, IfStatementBody, IfStatementElse, Statement, StatementKind};
+
+/// How a branch of an if statement was written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IfBranchKind {
+    /// The first branch, `if (...)`.
+    If,
+    /// `elseif (...)`
+    ElseIf,
+    /// `else if (...)`, which is an if statement nested inside of an `else`.
+    ElseNestedIf,
+}
+
+/// A condition of an if statement, along with the statements that run when it's true.
+#[derive(Debug, Clone, Copy)]
+pub struct IfBranch<'a> {
+    pub kind: IfBranchKind,
+    pub condition: &'a Expression,
+    /// The body of the branch. A branch that isn't written with the alternative syntax has a
+    /// single statement, which is a block if it's wrapped in braces.
+    pub statements: &'a [Statement],
+}
+
+/// An if statement flattened into its conditional branches and the final `else`, so that `elseif`
+/// and `else if` chains can be treated the same way.
+#[derive(Debug, Clone)]
+pub struct IfBranches<'a> {
+    pub branches: Vec<IfBranch<'a>>,
+    pub r#else: Option<&'a [Statement]>,
+}
+
+impl IfStatement {
+    /// The branches of the if statement, following `else if` into the nested if statement.
+    pub fn branches(&self) -> IfBranches<'_> {
+        let mut branches = IfBranches {
+            branches: Vec::new(),
+            r#else: None,
+        };
+
+        self.collect_branches(IfBranchKind::If, &mut branches);
+        branches
+    }
+
+    fn collect_branches<'a>(&'a self, kind: IfBranchKind, branches: &mut IfBranches<'a>) {
+        match &self.body {
+            IfStatementBody::Statement(body) => {
+                branches.branches.push(IfBranch {
+                    kind,
+                    condition: &self.condition,
+                    statements: slice::from_ref(body.statement.as_ref()),
+                });
+
+                branches
+                    .branches
+                    .extend(body.elseifs.iter().map(|elseif| IfBranch {
+                        kind: IfBranchKind::ElseIf,
+                        condition: &elseif.condition,
+                        statements: slice::from_ref(elseif.statement.as_ref()),
+                    }));
+
+                if let Some(r#else) = &body.r#else {
+                    match r#else.nested_if() {
+                        Some(nested) => {
+                            nested.collect_branches(IfBranchKind::ElseNestedIf, branches)
+                        }
+                        None => branches.r#else = Some(slice::from_ref(r#else.statement.as_ref())),
+                    }
+                }
+            }
+            IfStatementBody::Block(body) => {
+                branches.branches.push(IfBranch {
+                    kind,
+                    condition: &self.condition,
+                    statements: &body.statements,
+                });
+
+                branches
+                    .branches
+                    .extend(body.elseifs.iter().map(|elseif| IfBranch {
+                        kind: IfBranchKind::ElseIf,
+                        condition: &elseif.condition,
+                        statements: &elseif.statements,
+                    }));
+
+                branches.r#else = body
+                    .r#else
+                    .as_ref()
+                    .map(|r#else| r#else.statements.as_slice());
+            }
+        }
+    }
+}
+
+impl IfStatementElse {
+    /// The if statement that's nested directly inside of the `else`, when it's written as
+    /// `else if (...)`. An if statement inside of braces, e.g. `else { if (...) }`, isn't counted.
+    pub fn nested_if(&self) -> Option<&IfStatement> {
+        match &self.statement.kind {
+            StatementKind::If(statement) => Some(statement),
+            _ => None,
+        }
+    }
+}
@@ -31,4 +31,62 @@ impl DataType {
     pub fn is_bottom(&self) -> bool {
         self.kind.is_bottom()
     }
+
+    /// Whether the type only uses types that are built in to PHP, i.e. it doesn't refer to any class-likes.
+    pub fn is_builtin(&self) -> bool {
+        self.kind.is_builtin()
+    }
+
+    /// Get the names of the class-likes that the type refers to, ignoring built-in types like `iterable`.
+    pub fn get_class_names(&self) -> Vec<&ResolvedName> {
+        self.flattened_members()
+            .into_iter()
+            .filter_map(|member| match member {
+                Type::Named(name) => Some(name),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Whether the type is explicitly nullable, either through the `?T` shorthand
+    /// or by including `null` in a union.
+    pub fn is_nullable(&self) -> bool {
+        match &self.kind {
+            Type::Nullable(_) => true,
+            Type::Union(members) => members.iter().any(|member| member.is_null()),
+            _ => false,
+        }
+    }
+
+    /// Whether `null` is an acceptable value for the type.
+    pub fn allows_null(&self) -> bool {
+        self.is_nullable() || matches!(self.kind, Type::Null | Type::Mixed)
+    }
+
+    /// Get the individual members of the type, with any unions, intersections and
+    /// nullable shorthands flattened out, e.g. `(A&B)|?C` produces `[A, B, C, null]`.
+    pub fn flattened_members(&self) -> Vec<&Type<ResolvedName>> {
+        let mut members = Vec::new();
+
+        flatten(&self.kind, &mut members);
+
+        members
+    }
+}
+
+static NULL: Type<ResolvedName> = Type::Null;
+
+fn flatten<'a>(ty: &'a Type<ResolvedName>, members: &mut Vec<&'a Type<ResolvedName>>) {
+    match ty {
+        Type::Union(inner) | Type::Intersection(inner) => {
+            for member in inner {
+                flatten(member, members);
+            }
+        }
+        Type::Nullable(inner) => {
+            flatten(inner, members);
+            members.push(&NULL);
+        }
+        _ => members.push(ty),
+    }
 }
@@ -1,12 +1,18 @@
-use pxp_bytestring::{ByteStr, ByteString};
+use pxp_bytestring::ByteStr;
 
 use crate::{
     DocBlock, DocBlockDeprecatedTag, DocBlockExtendsTag, DocBlockGenericTag, DocBlockImplementsTag,
     DocBlockMethodTag, DocBlockNode, DocBlockParamTag, DocBlockPropertyTag, DocBlockReturnTag,
-    DocBlockTag, DocBlockTagNode, DocBlockTemplateTag, DocBlockTextNode, DocBlockUsesTag,
-    DocBlockVarTag,
+    DocBlockTag, DocBlockTagNode, DocBlockTemplateTag, DocBlockTextNode, DocBlockThrowsTag,
+    DocBlockUsesTag, DocBlockVarTag,
 };
 
+#[cfg(feature = "docblock-formatter")]
+mod formatter;
+
+#[cfg(feature = "docblock-formatter")]
+pub use formatter::{DocBlockFormatter, DocBlockFormatterOptions};
+
 pub struct DocBlockTagCollection<'a> {
     tags: Vec<&'a DocBlockTagNode>,
 }
@@ -80,6 +86,13 @@ impl<'a> DocBlockTagCollection<'a> {
             .collect()
     }
 
+    pub fn get_throws_tags(&self) -> Vec<&DocBlockThrowsTag> {
+        self.tags
+            .iter()
+            .filter_map(|t| t.tag().as_throws())
+            .collect()
+    }
+
     pub fn get_generic_tags(&self) -> Vec<&DocBlockGenericTag> {
         self.tags
             .iter()
@@ -131,6 +144,13 @@ impl DocBlockTag {
         }
     }
 
+    pub fn as_throws(&self) -> Option<&DocBlockThrowsTag> {
+        match self {
+            DocBlockTag::Throws(tag) => Some(tag),
+            _ => None,
+        }
+    }
+
     pub fn as_generic(&self) -> Option<&DocBlockGenericTag> {
         match self {
             DocBlockTag::Generic(node) => Some(node),
@@ -172,7 +192,7 @@ impl DocBlock {
         &self.nodes
     }
 
-    pub fn tags(&self) -> DocBlockTagCollection {
+    pub fn tags(&self) -> DocBlockTagCollection<'_> {
         DocBlockTagCollection {
             tags: self.tag_nodes(),
         }
@@ -0,0 +1,577 @@
+use pxp_bytestring::ByteString;
+use pxp_span::IsSpanned;
+use pxp_type::Type;
+
+use crate::{DataType, DocBlockComment, DocBlockNode, DocBlockTag, DocBlockTagNode};
+
+/// Configuration that controls how the `DocBlockFormatter` normalizes a docblock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DocBlockFormatterOptions {
+    /// Align the types, variables and descriptions of `@param`, `@return`, `@throws`, `@var` and
+    /// `@property` tags into columns, across each group of tags.
+    pub align: bool,
+    /// Wrap the descriptions of tags that make a line longer than the given number of columns.
+    pub wrap_at: Option<usize>,
+    /// Order the tags in each group as `@param`, `@return`, `@throws` and then any other tags, in
+    /// the order they were written.
+    pub reorder_tags: bool,
+    /// Remove tags that only repeat the native type, i.e. a `@return void` without a description.
+    pub remove_redundant_tags: bool,
+}
+
+impl Default for DocBlockFormatterOptions {
+    fn default() -> Self {
+        Self {
+            align: true,
+            wrap_at: None,
+            reorder_tags: true,
+            remove_redundant_tags: false,
+        }
+    }
+}
+
+impl DocBlockFormatterOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_align(mut self, align: bool) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn with_wrap_at(mut self, wrap_at: usize) -> Self {
+        self.wrap_at = Some(wrap_at);
+        self
+    }
+
+    pub fn with_reorder_tags(mut self, reorder_tags: bool) -> Self {
+        self.reorder_tags = reorder_tags;
+        self
+    }
+
+    pub fn with_remove_redundant_tags(mut self, remove_redundant_tags: bool) -> Self {
+        self.remove_redundant_tags = remove_redundant_tags;
+        self
+    }
+}
+
+/// Re-emits parsed docblocks as normalized text.
+///
+/// Groups of tags are separated by blank lines or text, and tags are only ever moved or aligned
+/// within their own group. Text, along with tags that don't have a type or variable to align, is
+/// emitted exactly as it was written, so generic tags and inline tags like `{@see Foo}` survive
+/// untouched. Descriptions that continue onto the following lines are indented to line up with
+/// the start of the description.
+///
+/// Types and text are read from the source that the docblock was parsed from, and the result
+/// replaces the span of the `DocBlockComment`, so it can be used as the replacement of a text edit.
+#[derive(Debug, Clone, Copy)]
+pub struct DocBlockFormatter<'a> {
+    source: &'a [u8],
+    options: DocBlockFormatterOptions,
+}
+
+/// A line of the docblock, before it's been formatted.
+enum Line<'a> {
+    Blank,
+    Text(&'a [u8]),
+    Tag(Tag<'a>),
+}
+
+struct Tag<'a> {
+    node: &'a DocBlockTagNode,
+    /// The tag as written, for tags that aren't split into columns.
+    verbatim: &'a [u8],
+    columns: Option<Columns<'a>>,
+    /// The lines following the tag that continue its description.
+    continuation: Vec<&'a [u8]>,
+}
+
+struct Columns<'a> {
+    name: &'a [u8],
+    r#type: Option<&'a [u8]>,
+    variable: Option<Vec<u8>>,
+    description: &'a [u8],
+}
+
+impl<'a> DocBlockFormatter<'a> {
+    pub fn new(source: &'a [u8], options: DocBlockFormatterOptions) -> Self {
+        Self { source, options }
+    }
+
+    /// Format a docblock, given the native return type of the function-like that it documents.
+    pub fn format(&self, comment: &DocBlockComment, return_type: Option<&DataType>) -> ByteString {
+        let indent = self.indent(comment);
+        let mut lines = self.lines(comment, return_type);
+
+        if self.options.reorder_tags {
+            for group in tag_groups(&mut lines) {
+                // The sort is stable, so other tags keep their relative order.
+                group.sort_by_key(|line| match line {
+                    Line::Tag(tag) => rank(tag.node),
+                    _ => unreachable!(),
+                });
+            }
+        }
+
+        let mut tags = Vec::new();
+
+        for group in tag_groups(&mut lines) {
+            self.format_group(group, indent.len(), &mut tags);
+        }
+
+        let mut tags = tags.into_iter();
+        let mut formatted = lines
+            .iter()
+            .map(|line| match line {
+                Line::Blank => Vec::new(),
+                Line::Text(text) => text.to_vec(),
+                Line::Tag(_) => tags.next().unwrap(),
+            })
+            .collect::<Vec<_>>();
+
+        // Removing tags can leave blank lines behind.
+        formatted.dedup_by(|a, b| a.is_empty() && b.is_empty());
+
+        while formatted.first().is_some_and(Vec::is_empty) {
+            formatted.remove(0);
+        }
+
+        while formatted.last().is_some_and(Vec::is_empty) {
+            formatted.pop();
+        }
+
+        self.write(comment, indent, formatted)
+    }
+
+    fn lines<'b>(
+        &self,
+        comment: &'b DocBlockComment,
+        return_type: Option<&DataType>,
+    ) -> Vec<Line<'b>>
+    where
+        'a: 'b,
+    {
+        let mut lines = Vec::new();
+        let mut previous = None;
+
+        for node in comment.doc.nodes() {
+            let start = match node {
+                DocBlockNode::Text(text) => text.span.start,
+                DocBlockNode::Tag(tag) => tag.tag().span().start,
+            };
+            let line = self.line_number(comment.span.start, start);
+            let blank = previous.is_some_and(|previous| line > previous + 1);
+
+            previous = Some(line);
+
+            if blank {
+                lines.push(Line::Blank);
+            }
+
+            match node {
+                DocBlockNode::Text(_) => {
+                    let text = self.line_at(start);
+
+                    match lines.last_mut() {
+                        Some(Line::Tag(tag)) if !blank => tag.continuation.push(text),
+                        _ => lines.push(Line::Text(text)),
+                    }
+                }
+                DocBlockNode::Tag(node) => {
+                    lines.push(Line::Tag(Tag {
+                        node,
+                        verbatim: trim(self.line_at(start)),
+                        columns: self.columns(node),
+                        continuation: Vec::new(),
+                    }));
+                }
+            }
+        }
+
+        if self.options.remove_redundant_tags {
+            lines.retain(|line| match line {
+                Line::Tag(tag) => !is_redundant(tag, return_type),
+                _ => true,
+            });
+        }
+
+        lines
+    }
+
+    /// Split a tag into the columns that are aligned, unless it's emitted as written.
+    fn columns<'b>(&self, node: &'b DocBlockTagNode) -> Option<Columns<'b>>
+    where
+        'a: 'b,
+    {
+        let (tag, data_type, variable, text) = match node.tag() {
+            DocBlockTag::Param(tag) => {
+                let variable = tag.variable.as_ref().map(|variable| {
+                    let mut prefix = Vec::new();
+
+                    if tag.ampersand.is_some() {
+                        prefix.push(b'&');
+                    }
+
+                    if tag.ellipsis.is_some() {
+                        prefix.extend_from_slice(b"...");
+                    }
+
+                    prefix.extend_from_slice(&variable.symbol);
+                    prefix
+                });
+
+                (&tag.tag, tag.data_type.as_ref(), variable, &tag.text)
+            }
+            DocBlockTag::ParamClosureThis(tag) => (
+                &tag.tag,
+                Some(&tag.r#type),
+                Some(tag.variable.symbol.to_vec()),
+                &tag.text,
+            ),
+            DocBlockTag::Return(tag) => (&tag.tag, tag.data_type.as_ref(), None, &tag.text),
+            DocBlockTag::Throws(tag) => (&tag.tag, tag.data_type.as_ref(), None, &tag.text),
+            DocBlockTag::Var(tag) => (
+                &tag.tag,
+                tag.data_type.as_ref(),
+                tag.variable
+                    .as_ref()
+                    .map(|variable| variable.symbol.to_vec()),
+                &tag.text,
+            ),
+            DocBlockTag::Property(tag) => (
+                &tag.tag,
+                tag.data_type.as_ref(),
+                Some(tag.variable.symbol.to_vec()),
+                &tag.text,
+            ),
+            _ => return None,
+        };
+
+        // Anything that couldn't be parsed ends up in the text, so it's left alone.
+        if data_type.is_none() && variable.is_none() {
+            return None;
+        }
+
+        Some(Columns {
+            name: &tag.symbol,
+            r#type: data_type.map(|data_type| trim(&self.source[data_type.span.to_range()])),
+            variable,
+            description: text.as_ref().map_or(&b""[..], |text| trim(text)),
+        })
+    }
+
+    /// Format a group of tags, pushing a line for each of them.
+    fn format_group(&self, group: &[Line], indent: usize, output: &mut Vec<Vec<u8>>) {
+        let tags = group.iter().filter_map(|line| match line {
+            Line::Tag(tag) => tag.columns.as_ref(),
+            _ => None,
+        });
+
+        let mut widths = [0; 3];
+        let mut present = [false; 3];
+
+        for columns in tags {
+            let cells = [
+                Some(columns.name.len()),
+                columns.r#type.map(<[u8]>::len),
+                columns.variable.as_ref().map(Vec::len),
+            ];
+
+            for (index, cell) in cells.into_iter().enumerate() {
+                if let Some(width) = cell {
+                    widths[index] = widths[index].max(width);
+                    present[index] = true;
+                }
+            }
+        }
+
+        for line in group {
+            let Line::Tag(tag) = line else {
+                continue;
+            };
+
+            let Some(columns) = &tag.columns else {
+                let mut lines = vec![tag.verbatim];
+                lines.extend(tag.continuation.iter());
+
+                output.push(lines.join(&b'\n'));
+                continue;
+            };
+
+            let cells = [
+                Some(columns.name),
+                columns.r#type,
+                columns.variable.as_deref(),
+            ];
+            let mut prefix = Vec::new();
+
+            for (index, cell) in cells.into_iter().enumerate() {
+                if !present[index] {
+                    continue;
+                }
+
+                let cell = cell.unwrap_or_default();
+
+                prefix.extend_from_slice(cell);
+
+                if self.options.align {
+                    prefix.resize(prefix.len() + widths[index] - cell.len(), b' ');
+                }
+
+                if self.options.align || !cell.is_empty() {
+                    prefix.push(b' ');
+                }
+            }
+
+            let mut description = vec![columns.description];
+            description.extend(tag.continuation.iter().map(|line| trim(line)));
+
+            output.push(self.describe(prefix, &description, indent));
+        }
+    }
+
+    /// Append a description to the columns that precede it, continuing it onto following lines
+    /// that are indented to the start of the description.
+    fn describe(&self, prefix: Vec<u8>, description: &[&[u8]], indent: usize) -> Vec<u8> {
+        // The ` * ` that starts each line, along with the indentation of the docblock.
+        let available = self
+            .options
+            .wrap_at
+            .map(|wrap_at| wrap_at.saturating_sub(indent + 3));
+        let indent = vec![b' '; prefix.len()];
+        let mut lines = vec![prefix];
+
+        match available {
+            Some(available) => {
+                for word in description.iter().flat_map(|line| words(line)) {
+                    let line = lines.last_mut().unwrap();
+                    let starts_line = line.len() == indent.len();
+
+                    if !starts_line && line.len() + 1 + word.len() > available {
+                        lines.push(indent.clone());
+                    } else if !starts_line {
+                        line.push(b' ');
+                    }
+
+                    lines.last_mut().unwrap().extend_from_slice(word);
+                }
+            }
+            None => {
+                for (index, line) in description.iter().enumerate() {
+                    if index > 0 {
+                        lines.push(indent.clone());
+                    }
+
+                    lines.last_mut().unwrap().extend_from_slice(line);
+                }
+            }
+        }
+
+        lines
+            .iter()
+            .map(|line| trim_end(line))
+            .collect::<Vec<_>>()
+            .join(&b'\n')
+    }
+
+    fn write(&self, comment: &DocBlockComment, indent: &[u8], lines: Vec<Vec<u8>>) -> ByteString {
+        let lines = lines
+            .iter()
+            .flat_map(|line| line.split(|byte| *byte == b'\n'))
+            .collect::<Vec<_>>();
+
+        let inline = !self.source[comment.span.to_range()].contains(&b'\n');
+        let mut output = ByteString::from("/**");
+
+        if lines.is_empty() {
+            output.extend_with_bytes(b" */");
+            return output;
+        }
+
+        if inline && lines.len() == 1 {
+            output.extend_with_bytes(b" ");
+            output.extend_with_bytes(lines[0]);
+            output.extend_with_bytes(b" */");
+            return output;
+        }
+
+        for line in lines {
+            output.extend_with_bytes(b"\n");
+            output.extend_with_bytes(indent);
+            output.extend_with_bytes(b" *");
+
+            if !line.is_empty() {
+                output.extend_with_bytes(b" ");
+                output.extend_with_bytes(line);
+            }
+        }
+
+        output.extend_with_bytes(b"\n");
+        output.extend_with_bytes(indent);
+        output.extend_with_bytes(b" */");
+        output
+    }
+
+    /// The whitespace that the docblock is indented by, which every line after the first needs.
+    fn indent(&self, comment: &DocBlockComment) -> &'a [u8] {
+        let before = &self.source[..comment.span.start];
+        let start = before
+            .iter()
+            .rposition(|byte| *byte == b'\n')
+            .map_or(0, |start| start + 1);
+        let indent = &before[start..];
+
+        if indent.iter().all(u8::is_ascii_whitespace) {
+            indent
+        } else {
+            &[]
+        }
+    }
+
+    /// The zero-based line that an offset is on, relative to the start of the docblock.
+    fn line_number(&self, start: usize, offset: usize) -> usize {
+        self.source[start..offset]
+            .iter()
+            .filter(|byte| **byte == b'\n')
+            .count()
+    }
+
+    /// The content of the line that the given offset is on, without the leading `*` and the end
+    /// of the docblock. Any indentation after the `*` is kept.
+    fn line_at(&self, offset: usize) -> &'a [u8] {
+        let start = self.source[..offset]
+            .iter()
+            .rposition(|byte| *byte == b'\n')
+            .map_or(0, |start| start + 1);
+        let mut line = &self.source[start..];
+
+        while line
+            .first()
+            .is_some_and(|byte| matches!(byte, b' ' | b'\t'))
+        {
+            line = &line[1..];
+        }
+
+        if let Some(rest) = line.strip_prefix(b"/**") {
+            line = rest;
+        } else if !line.starts_with(b"*/") {
+            line = line.strip_prefix(b"*").unwrap_or(line);
+        }
+
+        line = line.strip_prefix(b" ").unwrap_or(line);
+
+        let end = line
+            .iter()
+            .enumerate()
+            .position(|(index, byte)| *byte == b'\n' || line[index..].starts_with(b"*/"))
+            .unwrap_or(line.len());
+
+        trim_end(&line[..end])
+    }
+}
+
+/// The groups of consecutive tags, which tags can be moved and aligned within.
+fn tag_groups<'a, 'b>(lines: &'b mut [Line<'a>]) -> impl Iterator<Item = &'b mut [Line<'a>]> {
+    lines
+        .split_mut(|line| !matches!(line, Line::Tag(_)))
+        .filter(|group| !group.is_empty())
+}
+
+/// The position of a tag in the canonical order, which ignores any tool-specific prefix.
+fn rank(node: &DocBlockTagNode) -> usize {
+    let symbol = match node.tag() {
+        DocBlockTag::ParamClosureThis(tag) => &tag.tag.symbol,
+        DocBlockTag::Param(tag) => &tag.tag.symbol,
+        DocBlockTag::Return(tag) => &tag.tag.symbol,
+        DocBlockTag::Throws(tag) => &tag.tag.symbol,
+        DocBlockTag::Var(tag) => &tag.tag.symbol,
+        DocBlockTag::Property(tag) => &tag.tag.symbol,
+        DocBlockTag::Method(tag) => &tag.tag.symbol,
+        DocBlockTag::Template(tag) => &tag.tag.symbol,
+        DocBlockTag::Extends(tag) => &tag.tag.symbol,
+        DocBlockTag::Implements(tag) => &tag.tag.symbol,
+        DocBlockTag::Uses(tag) => &tag.tag.symbol,
+        DocBlockTag::Deprecated(tag) => &tag.tag.symbol,
+        DocBlockTag::Generic(tag) => &tag.tag.symbol,
+    };
+
+    let name = [&b"@phpstan-"[..], b"@psalm-", b"@phan-", b"@"]
+        .iter()
+        .find_map(|prefix| symbol.strip_prefix(*prefix))
+        .unwrap_or(symbol);
+
+    match name {
+        b"param" | b"param-closure-this" => 0,
+        b"return" => 1,
+        b"throws" => 2,
+        _ => 3,
+    }
+}
+
+/// Whether a tag only repeats the native return type.
+fn is_redundant(tag: &Tag, return_type: Option<&DataType>) -> bool {
+    let DocBlockTag::Return(node) = tag.node.tag() else {
+        return false;
+    };
+
+    matches!(
+        (&node.data_type, return_type),
+        (Some(documented), Some(native))
+            if documented.kind == Type::Void && native.kind == Type::Void
+    ) && node.text.is_none()
+        && tag.continuation.is_empty()
+}
+
+/// Split a description into words, keeping inline tags like `{@see Foo}` in one piece.
+fn words(text: &[u8]) -> Vec<&[u8]> {
+    let mut words = Vec::new();
+    let mut start = None;
+    let mut depth = 0;
+
+    for (index, byte) in text.iter().enumerate() {
+        match byte {
+            b'{' if text[index + 1..].starts_with(b"@") => depth += 1,
+            b'}' if depth > 0 => depth -= 1,
+            byte if byte.is_ascii_whitespace() && depth == 0 => {
+                if let Some(start) = start.take() {
+                    words.push(&text[start..index]);
+                }
+
+                continue;
+            }
+            _ => {}
+        }
+
+        start.get_or_insert(index);
+    }
+
+    if let Some(start) = start {
+        words.push(&text[start..]);
+    }
+
+    words
+}
+
+fn trim_end(bytes: &[u8]) -> &[u8] {
+    let end = bytes
+        .iter()
+        .rposition(|byte| !byte.is_ascii_whitespace())
+        .map_or(0, |end| end + 1);
+
+    &bytes[..end]
+}
+
+fn trim(bytes: &[u8]) -> &[u8] {
+    let start = bytes
+        .iter()
+        .position(|byte| !byte.is_ascii_whitespace())
+        .unwrap_or(bytes.len());
+    let end = bytes
+        .iter()
+        .rposition(|byte| !byte.is_ascii_whitespace())
+        .map_or(start, |end| end + 1);
+
+    &bytes[start..end]
+}
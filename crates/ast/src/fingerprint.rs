@@ -0,0 +1,182 @@
+use pxp_bytestring::ByteString;
+use pxp_span::Span;
+use pxp_token::OwnedToken;
+use pxp_type::Type;
+
+use crate::{
+    name::NameQualification, utils::CommaSeparated, CommentGroup, ResolvedName, Statement,
+    StatementKind,
+};
+
+/// Compute a content hash of the given AST, suitable as a cache key for analysis results.
+///
+/// The hash covers the structure of the tree along with the text of every symbol, literal and
+/// name. Node ids, spans and comments (docblocks included) are ignored, so whitespace-only and
+/// comment-only edits produce the same fingerprint.
+///
+/// The fingerprint is stable across runs and platforms, and across parser versions as long as the
+/// shape of the AST doesn't change. New nodes or fields can change the fingerprint of otherwise
+/// identical code, so caches keyed on it should be invalidated when upgrading.
+pub fn fingerprint(ast: &[Statement]) -> u64 {
+    let mut fingerprinter = Fingerprinter::new();
+
+    ast.fingerprint(&mut fingerprinter);
+    fingerprinter.finish()
+}
+
+pub trait Fingerprint {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter);
+
+    /// Whether the node should be left out of the fingerprint entirely, e.g. a comment statement.
+    fn is_trivia(&self) -> bool {
+        false
+    }
+}
+
+/// A 64-bit FNV-1a hasher.
+///
+/// `std::hash::DefaultHasher` makes no guarantees about its output between Rust releases, so it
+/// can't be used for anything that's persisted.
+#[derive(Debug, Clone, Copy)]
+pub struct Fingerprinter {
+    state: u64,
+}
+
+impl Fingerprinter {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    pub fn new() -> Self {
+        Self {
+            state: Self::OFFSET_BASIS,
+        }
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= *byte as u64;
+            self.state = self.state.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    pub fn write_usize(&mut self, value: usize) {
+        self.write(&(value as u64).to_le_bytes());
+    }
+
+    /// Write a length-prefixed string, so that adjacent strings can't run into each other.
+    pub fn write_str(&mut self, bytes: &[u8]) {
+        self.write_usize(bytes.len());
+        self.write(bytes);
+    }
+
+    /// Write the name of an enum variant.
+    pub fn write_tag(&mut self, tag: &str) {
+        self.write_str(tag.as_bytes());
+    }
+
+    pub fn finish(&self) -> u64 {
+        self.state
+    }
+}
+
+impl Default for Fingerprinter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Fingerprint for Statement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.kind.fingerprint(fingerprinter);
+    }
+
+    fn is_trivia(&self) -> bool {
+        matches!(self.kind, StatementKind::Comment(_))
+    }
+}
+
+impl Fingerprint for CommentGroup {
+    fn fingerprint(&self, _: &mut Fingerprinter) {}
+}
+
+impl Fingerprint for Span {
+    fn fingerprint(&self, _: &mut Fingerprinter) {}
+}
+
+impl Fingerprint for ByteString {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        fingerprinter.write_str(self);
+    }
+}
+
+impl Fingerprint for OwnedToken {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.symbol.fingerprint(fingerprinter);
+    }
+}
+
+impl Fingerprint for Type<ResolvedName> {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        fingerprinter.write_str(self.to_string().as_bytes());
+    }
+}
+
+impl Fingerprint for NameQualification {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        fingerprinter.write_tag(match self {
+            NameQualification::Unqualified => "Unqualified",
+            NameQualification::Qualified => "Qualified",
+            NameQualification::FullyQualified => "FullyQualified",
+        });
+    }
+}
+
+impl Fingerprint for bool {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        fingerprinter.write(&[*self as u8]);
+    }
+}
+
+impl<T: Fingerprint> Fingerprint for [T] {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        fingerprinter.write_usize(self.iter().filter(|item| !item.is_trivia()).count());
+
+        for item in self.iter().filter(|item| !item.is_trivia()) {
+            item.fingerprint(fingerprinter);
+        }
+    }
+}
+
+impl<T: Fingerprint> Fingerprint for Vec<T> {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.as_slice().fingerprint(fingerprinter);
+    }
+}
+
+impl<T: Fingerprint> Fingerprint for Option<T> {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            Some(inner) => {
+                fingerprinter.write(&[1]);
+                inner.fingerprint(fingerprinter);
+            }
+            None => fingerprinter.write(&[0]),
+        }
+    }
+}
+
+impl<T: Fingerprint> Fingerprint for Box<T> {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.as_ref().fingerprint(fingerprinter);
+    }
+
+    fn is_trivia(&self) -> bool {
+        self.as_ref().is_trivia()
+    }
+}
+
+impl<T: Fingerprint> Fingerprint for CommaSeparated<T> {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.inner.fingerprint(fingerprinter);
+    }
+}
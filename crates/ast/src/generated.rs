@@ -8,7 +8,10 @@
 // This file is generated by meta/scripts/generate-ast.php.
 // Do not make modifications to this file directly.
 
-use crate::{name::NameQualification, utils::CommaSeparated, HasId, Node};
+use crate::{
+    name::NameQualification, utils::CommaSeparated, Downcast, Fingerprint, Fingerprinter, HasId,
+    Node,
+};
 use pxp_bytestring::ByteString;
 use pxp_span::{IsSpanned, Span};
 use pxp_token::OwnedToken;
@@ -25,6 +28,7 @@ pub struct Statement {
     pub kind: StatementKind,
     pub span: Span,
     pub comments: CommentGroup,
+    pub trailing_comments: CommentGroup,
 }
 
 impl HasId for Statement {
@@ -122,6 +126,197 @@ impl HasId for StatementKind {
     }
 }
 
+impl Fingerprint for StatementKind {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            StatementKind::FullOpeningTag(inner) => {
+                fingerprinter.write_tag("FullOpeningTag");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::ShortOpeningTag(inner) => {
+                fingerprinter.write_tag("ShortOpeningTag");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::EchoOpeningTag(inner) => {
+                fingerprinter.write_tag("EchoOpeningTag");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::ClosingTag(inner) => {
+                fingerprinter.write_tag("ClosingTag");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::InlineHtml(inner) => {
+                fingerprinter.write_tag("InlineHtml");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Label(inner) => {
+                fingerprinter.write_tag("Label");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Goto(inner) => {
+                fingerprinter.write_tag("Goto");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::HaltCompiler(inner) => {
+                fingerprinter.write_tag("HaltCompiler");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Static(inner) => {
+                fingerprinter.write_tag("Static");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::DoWhile(inner) => {
+                fingerprinter.write_tag("DoWhile");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::While(inner) => {
+                fingerprinter.write_tag("While");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::For(inner) => {
+                fingerprinter.write_tag("For");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Foreach(inner) => {
+                fingerprinter.write_tag("Foreach");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Break(inner) => {
+                fingerprinter.write_tag("Break");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Continue(inner) => {
+                fingerprinter.write_tag("Continue");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Constant(inner) => {
+                fingerprinter.write_tag("Constant");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Function(inner) => {
+                fingerprinter.write_tag("Function");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Class(inner) => {
+                fingerprinter.write_tag("Class");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Trait(inner) => {
+                fingerprinter.write_tag("Trait");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Interface(inner) => {
+                fingerprinter.write_tag("Interface");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::If(inner) => {
+                fingerprinter.write_tag("If");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Switch(inner) => {
+                fingerprinter.write_tag("Switch");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Echo(inner) => {
+                fingerprinter.write_tag("Echo");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Expression(inner) => {
+                fingerprinter.write_tag("Expression");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Return(inner) => {
+                fingerprinter.write_tag("Return");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Namespace(inner) => {
+                fingerprinter.write_tag("Namespace");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Use(inner) => {
+                fingerprinter.write_tag("Use");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::GroupUse(inner) => {
+                fingerprinter.write_tag("GroupUse");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Comment(inner) => {
+                fingerprinter.write_tag("Comment");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Try(inner) => {
+                fingerprinter.write_tag("Try");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::UnitEnum(inner) => {
+                fingerprinter.write_tag("UnitEnum");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::BackedEnum(inner) => {
+                fingerprinter.write_tag("BackedEnum");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Block(inner) => {
+                fingerprinter.write_tag("Block");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Global(inner) => {
+                fingerprinter.write_tag("Global");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Declare(inner) => {
+                fingerprinter.write_tag("Declare");
+                inner.fingerprint(fingerprinter);
+            }
+            StatementKind::Noop(_) => fingerprinter.write_tag("Noop"),
+        }
+    }
+}
+
+impl IsSpanned for StatementKind {
+    fn span(&self) -> Span {
+        match self {
+            StatementKind::FullOpeningTag(inner) => inner.span(),
+            StatementKind::ShortOpeningTag(inner) => inner.span(),
+            StatementKind::EchoOpeningTag(inner) => inner.span(),
+            StatementKind::ClosingTag(inner) => inner.span(),
+            StatementKind::InlineHtml(inner) => inner.span(),
+            StatementKind::Label(inner) => inner.span(),
+            StatementKind::Goto(inner) => inner.span(),
+            StatementKind::HaltCompiler(inner) => inner.span(),
+            StatementKind::Static(inner) => inner.span(),
+            StatementKind::DoWhile(inner) => inner.span(),
+            StatementKind::While(inner) => inner.span(),
+            StatementKind::For(inner) => inner.span(),
+            StatementKind::Foreach(inner) => inner.span(),
+            StatementKind::Break(inner) => inner.span(),
+            StatementKind::Continue(inner) => inner.span(),
+            StatementKind::Constant(inner) => inner.span(),
+            StatementKind::Function(inner) => inner.span(),
+            StatementKind::Class(inner) => inner.span(),
+            StatementKind::Trait(inner) => inner.span(),
+            StatementKind::Interface(inner) => inner.span(),
+            StatementKind::If(inner) => inner.span(),
+            StatementKind::Switch(inner) => inner.span(),
+            StatementKind::Echo(inner) => inner.span(),
+            StatementKind::Expression(inner) => inner.span(),
+            StatementKind::Return(inner) => inner.span(),
+            StatementKind::Namespace(inner) => inner.span(),
+            StatementKind::Use(inner) => inner.span(),
+            StatementKind::GroupUse(inner) => inner.span(),
+            StatementKind::Comment(inner) => inner.span(),
+            StatementKind::Try(inner) => inner.span(),
+            StatementKind::UnitEnum(inner) => inner.span(),
+            StatementKind::BackedEnum(inner) => inner.span(),
+            StatementKind::Block(inner) => inner.span(),
+            StatementKind::Global(inner) => inner.span(),
+            StatementKind::Declare(inner) => inner.span(),
+            StatementKind::Noop(span) => *span,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Expression {
     pub id: NodeId,
@@ -136,6 +331,12 @@ impl HasId for Expression {
     }
 }
 
+impl Fingerprint for Expression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.kind.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for Expression {
     fn span(&self) -> Span {
         self.span
@@ -285,6 +486,344 @@ impl HasId for ExpressionKind {
     }
 }
 
+impl Fingerprint for ExpressionKind {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            ExpressionKind::Missing(inner) => {
+                fingerprinter.write_tag("Missing");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Eval(inner) => {
+                fingerprinter.write_tag("Eval");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Empty(inner) => {
+                fingerprinter.write_tag("Empty");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Die(inner) => {
+                fingerprinter.write_tag("Die");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Exit(inner) => {
+                fingerprinter.write_tag("Exit");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Isset(inner) => {
+                fingerprinter.write_tag("Isset");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Unset(inner) => {
+                fingerprinter.write_tag("Unset");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Print(inner) => {
+                fingerprinter.write_tag("Print");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Literal(inner) => {
+                fingerprinter.write_tag("Literal");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::ArithmeticOperation(inner) => {
+                fingerprinter.write_tag("ArithmeticOperation");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::AssignmentOperation(inner) => {
+                fingerprinter.write_tag("AssignmentOperation");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::BitwiseOperation(inner) => {
+                fingerprinter.write_tag("BitwiseOperation");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::ComparisonOperation(inner) => {
+                fingerprinter.write_tag("ComparisonOperation");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::LogicalOperation(inner) => {
+                fingerprinter.write_tag("LogicalOperation");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Concat(inner) => {
+                fingerprinter.write_tag("Concat");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Instanceof(inner) => {
+                fingerprinter.write_tag("Instanceof");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Reference(inner) => {
+                fingerprinter.write_tag("Reference");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Parenthesized(inner) => {
+                fingerprinter.write_tag("Parenthesized");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::ErrorSuppress(inner) => {
+                fingerprinter.write_tag("ErrorSuppress");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Identifier(inner) => {
+                fingerprinter.write_tag("Identifier");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Variable(inner) => {
+                fingerprinter.write_tag("Variable");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Include(inner) => {
+                fingerprinter.write_tag("Include");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::IncludeOnce(inner) => {
+                fingerprinter.write_tag("IncludeOnce");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Require(inner) => {
+                fingerprinter.write_tag("Require");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::RequireOnce(inner) => {
+                fingerprinter.write_tag("RequireOnce");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::FunctionCall(inner) => {
+                fingerprinter.write_tag("FunctionCall");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::FunctionClosureCreation(inner) => {
+                fingerprinter.write_tag("FunctionClosureCreation");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::MethodCall(inner) => {
+                fingerprinter.write_tag("MethodCall");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::MethodClosureCreation(inner) => {
+                fingerprinter.write_tag("MethodClosureCreation");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::NullsafeMethodCall(inner) => {
+                fingerprinter.write_tag("NullsafeMethodCall");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::StaticMethodCall(inner) => {
+                fingerprinter.write_tag("StaticMethodCall");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::StaticVariableMethodCall(inner) => {
+                fingerprinter.write_tag("StaticVariableMethodCall");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::StaticMethodClosureCreation(inner) => {
+                fingerprinter.write_tag("StaticMethodClosureCreation");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::StaticVariableMethodClosureCreation(inner) => {
+                fingerprinter.write_tag("StaticVariableMethodClosureCreation");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::PropertyFetch(inner) => {
+                fingerprinter.write_tag("PropertyFetch");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::NullsafePropertyFetch(inner) => {
+                fingerprinter.write_tag("NullsafePropertyFetch");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::StaticPropertyFetch(inner) => {
+                fingerprinter.write_tag("StaticPropertyFetch");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::ConstantFetch(inner) => {
+                fingerprinter.write_tag("ConstantFetch");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Static(inner) => {
+                fingerprinter.write_tag("Static");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Self_(inner) => {
+                fingerprinter.write_tag("Self_");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Parent(inner) => {
+                fingerprinter.write_tag("Parent");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Array(inner) => {
+                fingerprinter.write_tag("Array");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::List(inner) => {
+                fingerprinter.write_tag("List");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Closure(inner) => {
+                fingerprinter.write_tag("Closure");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::ArrowFunction(inner) => {
+                fingerprinter.write_tag("ArrowFunction");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::New(inner) => {
+                fingerprinter.write_tag("New");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::InterpolatedString(inner) => {
+                fingerprinter.write_tag("InterpolatedString");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Heredoc(inner) => {
+                fingerprinter.write_tag("Heredoc");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Nowdoc(inner) => {
+                fingerprinter.write_tag("Nowdoc");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::ShellExec(inner) => {
+                fingerprinter.write_tag("ShellExec");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::AnonymousClass(inner) => {
+                fingerprinter.write_tag("AnonymousClass");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Bool(inner) => {
+                fingerprinter.write_tag("Bool");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::ArrayIndex(inner) => {
+                fingerprinter.write_tag("ArrayIndex");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Null(_) => fingerprinter.write_tag("Null"),
+            ExpressionKind::MagicConstant(inner) => {
+                fingerprinter.write_tag("MagicConstant");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::ShortTernary(inner) => {
+                fingerprinter.write_tag("ShortTernary");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Ternary(inner) => {
+                fingerprinter.write_tag("Ternary");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Coalesce(inner) => {
+                fingerprinter.write_tag("Coalesce");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Clone(inner) => {
+                fingerprinter.write_tag("Clone");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Match(inner) => {
+                fingerprinter.write_tag("Match");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Throw(inner) => {
+                fingerprinter.write_tag("Throw");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Yield(inner) => {
+                fingerprinter.write_tag("Yield");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::YieldFrom(inner) => {
+                fingerprinter.write_tag("YieldFrom");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Cast(inner) => {
+                fingerprinter.write_tag("Cast");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Name(inner) => {
+                fingerprinter.write_tag("Name");
+                inner.fingerprint(fingerprinter);
+            }
+            ExpressionKind::Noop(_) => fingerprinter.write_tag("Noop"),
+        }
+    }
+}
+
+impl IsSpanned for ExpressionKind {
+    fn span(&self) -> Span {
+        match self {
+            ExpressionKind::Missing(inner) => inner.span(),
+            ExpressionKind::Eval(inner) => inner.span(),
+            ExpressionKind::Empty(inner) => inner.span(),
+            ExpressionKind::Die(inner) => inner.span(),
+            ExpressionKind::Exit(inner) => inner.span(),
+            ExpressionKind::Isset(inner) => inner.span(),
+            ExpressionKind::Unset(inner) => inner.span(),
+            ExpressionKind::Print(inner) => inner.span(),
+            ExpressionKind::Literal(inner) => inner.span(),
+            ExpressionKind::ArithmeticOperation(inner) => inner.span(),
+            ExpressionKind::AssignmentOperation(inner) => inner.span(),
+            ExpressionKind::BitwiseOperation(inner) => inner.span(),
+            ExpressionKind::ComparisonOperation(inner) => inner.span(),
+            ExpressionKind::LogicalOperation(inner) => inner.span(),
+            ExpressionKind::Concat(inner) => inner.span(),
+            ExpressionKind::Instanceof(inner) => inner.span(),
+            ExpressionKind::Reference(inner) => inner.span(),
+            ExpressionKind::Parenthesized(inner) => inner.span(),
+            ExpressionKind::ErrorSuppress(inner) => inner.span(),
+            ExpressionKind::Identifier(inner) => inner.span(),
+            ExpressionKind::Variable(inner) => inner.span(),
+            ExpressionKind::Include(inner) => inner.span(),
+            ExpressionKind::IncludeOnce(inner) => inner.span(),
+            ExpressionKind::Require(inner) => inner.span(),
+            ExpressionKind::RequireOnce(inner) => inner.span(),
+            ExpressionKind::FunctionCall(inner) => inner.span(),
+            ExpressionKind::FunctionClosureCreation(inner) => inner.span(),
+            ExpressionKind::MethodCall(inner) => inner.span(),
+            ExpressionKind::MethodClosureCreation(inner) => inner.span(),
+            ExpressionKind::NullsafeMethodCall(inner) => inner.span(),
+            ExpressionKind::StaticMethodCall(inner) => inner.span(),
+            ExpressionKind::StaticVariableMethodCall(inner) => inner.span(),
+            ExpressionKind::StaticMethodClosureCreation(inner) => inner.span(),
+            ExpressionKind::StaticVariableMethodClosureCreation(inner) => inner.span(),
+            ExpressionKind::PropertyFetch(inner) => inner.span(),
+            ExpressionKind::NullsafePropertyFetch(inner) => inner.span(),
+            ExpressionKind::StaticPropertyFetch(inner) => inner.span(),
+            ExpressionKind::ConstantFetch(inner) => inner.span(),
+            ExpressionKind::Static(inner) => inner.span(),
+            ExpressionKind::Self_(inner) => inner.span(),
+            ExpressionKind::Parent(inner) => inner.span(),
+            ExpressionKind::Array(inner) => inner.span(),
+            ExpressionKind::List(inner) => inner.span(),
+            ExpressionKind::Closure(inner) => inner.span(),
+            ExpressionKind::ArrowFunction(inner) => inner.span(),
+            ExpressionKind::New(inner) => inner.span(),
+            ExpressionKind::InterpolatedString(inner) => inner.span(),
+            ExpressionKind::Heredoc(inner) => inner.span(),
+            ExpressionKind::Nowdoc(inner) => inner.span(),
+            ExpressionKind::ShellExec(inner) => inner.span(),
+            ExpressionKind::AnonymousClass(inner) => inner.span(),
+            ExpressionKind::Bool(inner) => inner.span(),
+            ExpressionKind::ArrayIndex(inner) => inner.span(),
+            ExpressionKind::Null(span) => *span,
+            ExpressionKind::MagicConstant(inner) => inner.span(),
+            ExpressionKind::ShortTernary(inner) => inner.span(),
+            ExpressionKind::Ternary(inner) => inner.span(),
+            ExpressionKind::Coalesce(inner) => inner.span(),
+            ExpressionKind::Clone(inner) => inner.span(),
+            ExpressionKind::Match(inner) => inner.span(),
+            ExpressionKind::Throw(inner) => inner.span(),
+            ExpressionKind::Yield(inner) => inner.span(),
+            ExpressionKind::YieldFrom(inner) => inner.span(),
+            ExpressionKind::Cast(inner) => inner.span(),
+            ExpressionKind::Name(inner) => inner.span(),
+            ExpressionKind::Noop(span) => *span,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct MissingExpression {
     pub id: NodeId,
@@ -297,6 +836,10 @@ impl HasId for MissingExpression {
     }
 }
 
+impl Fingerprint for MissingExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {}
+}
+
 impl IsSpanned for MissingExpression {
     fn span(&self) -> Span {
         self.span
@@ -315,6 +858,10 @@ impl HasId for StaticExpression {
     }
 }
 
+impl Fingerprint for StaticExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {}
+}
+
 impl IsSpanned for StaticExpression {
     fn span(&self) -> Span {
         self.span
@@ -333,6 +880,10 @@ impl HasId for SelfExpression {
     }
 }
 
+impl Fingerprint for SelfExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {}
+}
+
 impl IsSpanned for SelfExpression {
     fn span(&self) -> Span {
         self.span
@@ -351,6 +902,10 @@ impl HasId for ParentExpression {
     }
 }
 
+impl Fingerprint for ParentExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {}
+}
+
 impl IsSpanned for ParentExpression {
     fn span(&self) -> Span {
         self.span
@@ -370,6 +925,12 @@ impl HasId for CommentStatement {
     }
 }
 
+impl Fingerprint for CommentStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.comment.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for CommentStatement {
     fn span(&self) -> Span {
         self.span
@@ -389,6 +950,12 @@ impl HasId for InlineHtmlStatement {
     }
 }
 
+impl Fingerprint for InlineHtmlStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.html.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for InlineHtmlStatement {
     fn span(&self) -> Span {
         self.span
@@ -407,6 +974,10 @@ impl HasId for FullOpeningTagStatement {
     }
 }
 
+impl Fingerprint for FullOpeningTagStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {}
+}
+
 impl IsSpanned for FullOpeningTagStatement {
     fn span(&self) -> Span {
         self.span
@@ -425,6 +996,10 @@ impl HasId for ShortOpeningTagStatement {
     }
 }
 
+impl Fingerprint for ShortOpeningTagStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {}
+}
+
 impl IsSpanned for ShortOpeningTagStatement {
     fn span(&self) -> Span {
         self.span
@@ -443,6 +1018,10 @@ impl HasId for EchoOpeningTagStatement {
     }
 }
 
+impl Fingerprint for EchoOpeningTagStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {}
+}
+
 impl IsSpanned for EchoOpeningTagStatement {
     fn span(&self) -> Span {
         self.span
@@ -461,6 +1040,10 @@ impl HasId for ClosingTagStatement {
     }
 }
 
+impl Fingerprint for ClosingTagStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {}
+}
+
 impl IsSpanned for ClosingTagStatement {
     fn span(&self) -> Span {
         self.span
@@ -481,6 +1064,13 @@ impl HasId for ExpressionStatement {
     }
 }
 
+impl Fingerprint for ExpressionStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.expression.fingerprint(fingerprinter);
+        self.ending.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ExpressionStatement {
     fn span(&self) -> Span {
         self.span
@@ -502,6 +1092,12 @@ impl HasId for GlobalStatement {
     }
 }
 
+impl Fingerprint for GlobalStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.variables.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for GlobalStatement {
     fn span(&self) -> Span {
         self.span
@@ -514,6 +1110,7 @@ pub struct BlockStatement {
     pub span: Span,
     pub left_brace: Span,
     pub statements: Vec<Statement>,
+    pub trailing_comments: CommentGroup,
     pub right_brace: Span,
 }
 
@@ -523,6 +1120,12 @@ impl HasId for BlockStatement {
     }
 }
 
+impl Fingerprint for BlockStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.statements.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for BlockStatement {
     fn span(&self) -> Span {
         self.span
@@ -540,6 +1143,20 @@ pub enum CastKind {
     Unset(Span),
 }
 
+impl Fingerprint for CastKind {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            CastKind::Int(_) => fingerprinter.write_tag("Int"),
+            CastKind::Bool(_) => fingerprinter.write_tag("Bool"),
+            CastKind::Float(_) => fingerprinter.write_tag("Float"),
+            CastKind::String(_) => fingerprinter.write_tag("String"),
+            CastKind::Array(_) => fingerprinter.write_tag("Array"),
+            CastKind::Object(_) => fingerprinter.write_tag("Object"),
+            CastKind::Unset(_) => fingerprinter.write_tag("Unset"),
+        }
+    }
+}
+
 impl IsSpanned for CastKind {
     fn span(&self) -> Span {
         match self {
@@ -550,7 +1167,6 @@ impl IsSpanned for CastKind {
             CastKind::Array(span) => *span,
             CastKind::Object(span) => *span,
             CastKind::Unset(span) => *span,
-            _ => Span::default(),
         }
     }
 }
@@ -559,7 +1175,9 @@ impl IsSpanned for CastKind {
 pub struct Case {
     pub id: NodeId,
     pub span: Span,
+    pub comments: CommentGroup,
     pub condition: Option<Expression>,
+    pub separator: CaseSeparator,
     pub body: Block,
 }
 
@@ -569,12 +1187,47 @@ impl HasId for Case {
     }
 }
 
+impl Fingerprint for Case {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.condition.fingerprint(fingerprinter);
+        self.separator.fingerprint(fingerprinter);
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for Case {
     fn span(&self) -> Span {
         self.span
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum CaseSeparator {
+    Missing(Span),
+    Colon(Span),
+    SemiColon(Span),
+}
+
+impl Fingerprint for CaseSeparator {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            CaseSeparator::Missing(_) => fingerprinter.write_tag("Missing"),
+            CaseSeparator::Colon(_) => fingerprinter.write_tag("Colon"),
+            CaseSeparator::SemiColon(_) => fingerprinter.write_tag("SemiColon"),
+        }
+    }
+}
+
+impl IsSpanned for CaseSeparator {
+    fn span(&self) -> Span {
+        match self {
+            CaseSeparator::Missing(span) => *span,
+            CaseSeparator::Colon(span) => *span,
+            CaseSeparator::SemiColon(span) => *span,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Use {
     pub id: NodeId,
@@ -590,6 +1243,14 @@ impl HasId for Use {
     }
 }
 
+impl Fingerprint for Use {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.name.fingerprint(fingerprinter);
+        self.alias.fingerprint(fingerprinter);
+        self.kind.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for Use {
     fn span(&self) -> Span {
         self.span
@@ -603,6 +1264,16 @@ pub enum UseKind {
     Const,
 }
 
+impl Fingerprint for UseKind {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            UseKind::Normal => fingerprinter.write_tag("Normal"),
+            UseKind::Function => fingerprinter.write_tag("Function"),
+            UseKind::Const => fingerprinter.write_tag("Const"),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct EvalExpression {
     pub id: NodeId,
@@ -617,6 +1288,12 @@ impl HasId for EvalExpression {
     }
 }
 
+impl Fingerprint for EvalExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.argument.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for EvalExpression {
     fn span(&self) -> Span {
         self.span
@@ -637,6 +1314,12 @@ impl HasId for EmptyExpression {
     }
 }
 
+impl Fingerprint for EmptyExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.argument.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for EmptyExpression {
     fn span(&self) -> Span {
         self.span
@@ -657,6 +1340,12 @@ impl HasId for DieExpression {
     }
 }
 
+impl Fingerprint for DieExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.argument.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DieExpression {
     fn span(&self) -> Span {
         self.span
@@ -677,6 +1366,12 @@ impl HasId for ExitExpression {
     }
 }
 
+impl Fingerprint for ExitExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.argument.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ExitExpression {
     fn span(&self) -> Span {
         self.span
@@ -697,6 +1392,12 @@ impl HasId for IssetExpression {
     }
 }
 
+impl Fingerprint for IssetExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.arguments.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for IssetExpression {
     fn span(&self) -> Span {
         self.span
@@ -717,6 +1418,12 @@ impl HasId for UnsetExpression {
     }
 }
 
+impl Fingerprint for UnsetExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.arguments.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for UnsetExpression {
     fn span(&self) -> Span {
         self.span
@@ -728,8 +1435,7 @@ pub struct PrintExpression {
     pub id: NodeId,
     pub span: Span,
     pub print: Span,
-    pub value: Option<Box<Expression>>,
-    pub argument: Option<Box<SingleArgument>>,
+    pub value: Box<Expression>,
 }
 
 impl HasId for PrintExpression {
@@ -738,6 +1444,12 @@ impl HasId for PrintExpression {
     }
 }
 
+impl Fingerprint for PrintExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for PrintExpression {
     fn span(&self) -> Span {
         self.span
@@ -759,6 +1471,13 @@ impl HasId for ConcatExpression {
     }
 }
 
+impl Fingerprint for ConcatExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.left.fingerprint(fingerprinter);
+        self.right.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ConcatExpression {
     fn span(&self) -> Span {
         self.span
@@ -780,6 +1499,13 @@ impl HasId for InstanceofExpression {
     }
 }
 
+impl Fingerprint for InstanceofExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.left.fingerprint(fingerprinter);
+        self.right.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for InstanceofExpression {
     fn span(&self) -> Span {
         self.span
@@ -800,6 +1526,12 @@ impl HasId for ReferenceExpression {
     }
 }
 
+impl Fingerprint for ReferenceExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.right.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ReferenceExpression {
     fn span(&self) -> Span {
         self.span
@@ -821,6 +1553,12 @@ impl HasId for ParenthesizedExpression {
     }
 }
 
+impl Fingerprint for ParenthesizedExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.expr.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ParenthesizedExpression {
     fn span(&self) -> Span {
         self.span
@@ -841,6 +1579,12 @@ impl HasId for ErrorSuppressExpression {
     }
 }
 
+impl Fingerprint for ErrorSuppressExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.expr.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ErrorSuppressExpression {
     fn span(&self) -> Span {
         self.span
@@ -861,6 +1605,12 @@ impl HasId for IncludeExpression {
     }
 }
 
+impl Fingerprint for IncludeExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.path.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for IncludeExpression {
     fn span(&self) -> Span {
         self.span
@@ -881,6 +1631,12 @@ impl HasId for IncludeOnceExpression {
     }
 }
 
+impl Fingerprint for IncludeOnceExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.path.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for IncludeOnceExpression {
     fn span(&self) -> Span {
         self.span
@@ -901,6 +1657,12 @@ impl HasId for RequireExpression {
     }
 }
 
+impl Fingerprint for RequireExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.path.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for RequireExpression {
     fn span(&self) -> Span {
         self.span
@@ -921,6 +1683,12 @@ impl HasId for RequireOnceExpression {
     }
 }
 
+impl Fingerprint for RequireOnceExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.path.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for RequireOnceExpression {
     fn span(&self) -> Span {
         self.span
@@ -941,6 +1709,13 @@ impl HasId for FunctionCallExpression {
     }
 }
 
+impl Fingerprint for FunctionCallExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.target.fingerprint(fingerprinter);
+        self.arguments.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for FunctionCallExpression {
     fn span(&self) -> Span {
         self.span
@@ -961,6 +1736,13 @@ impl HasId for FunctionClosureCreationExpression {
     }
 }
 
+impl Fingerprint for FunctionClosureCreationExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.target.fingerprint(fingerprinter);
+        self.placeholder.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for FunctionClosureCreationExpression {
     fn span(&self) -> Span {
         self.span
@@ -983,6 +1765,14 @@ impl HasId for MethodCallExpression {
     }
 }
 
+impl Fingerprint for MethodCallExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.target.fingerprint(fingerprinter);
+        self.method.fingerprint(fingerprinter);
+        self.arguments.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for MethodCallExpression {
     fn span(&self) -> Span {
         self.span
@@ -1005,6 +1795,14 @@ impl HasId for MethodClosureCreationExpression {
     }
 }
 
+impl Fingerprint for MethodClosureCreationExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.target.fingerprint(fingerprinter);
+        self.method.fingerprint(fingerprinter);
+        self.placeholder.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for MethodClosureCreationExpression {
     fn span(&self) -> Span {
         self.span
@@ -1027,6 +1825,14 @@ impl HasId for NullsafeMethodCallExpression {
     }
 }
 
+impl Fingerprint for NullsafeMethodCallExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.target.fingerprint(fingerprinter);
+        self.method.fingerprint(fingerprinter);
+        self.arguments.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for NullsafeMethodCallExpression {
     fn span(&self) -> Span {
         self.span
@@ -1049,6 +1855,14 @@ impl HasId for StaticMethodCallExpression {
     }
 }
 
+impl Fingerprint for StaticMethodCallExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.target.fingerprint(fingerprinter);
+        self.method.fingerprint(fingerprinter);
+        self.arguments.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for StaticMethodCallExpression {
     fn span(&self) -> Span {
         self.span
@@ -1071,6 +1885,14 @@ impl HasId for StaticVariableMethodCallExpression {
     }
 }
 
+impl Fingerprint for StaticVariableMethodCallExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.target.fingerprint(fingerprinter);
+        self.method.fingerprint(fingerprinter);
+        self.arguments.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for StaticVariableMethodCallExpression {
     fn span(&self) -> Span {
         self.span
@@ -1093,6 +1915,14 @@ impl HasId for StaticMethodClosureCreationExpression {
     }
 }
 
+impl Fingerprint for StaticMethodClosureCreationExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.target.fingerprint(fingerprinter);
+        self.method.fingerprint(fingerprinter);
+        self.placeholder.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for StaticMethodClosureCreationExpression {
     fn span(&self) -> Span {
         self.span
@@ -1115,6 +1945,14 @@ impl HasId for StaticVariableMethodClosureCreationExpression {
     }
 }
 
+impl Fingerprint for StaticVariableMethodClosureCreationExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.target.fingerprint(fingerprinter);
+        self.method.fingerprint(fingerprinter);
+        self.placeholder.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for StaticVariableMethodClosureCreationExpression {
     fn span(&self) -> Span {
         self.span
@@ -1136,6 +1974,13 @@ impl HasId for PropertyFetchExpression {
     }
 }
 
+impl Fingerprint for PropertyFetchExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.target.fingerprint(fingerprinter);
+        self.property.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for PropertyFetchExpression {
     fn span(&self) -> Span {
         self.span
@@ -1157,6 +2002,13 @@ impl HasId for NullsafePropertyFetchExpression {
     }
 }
 
+impl Fingerprint for NullsafePropertyFetchExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.target.fingerprint(fingerprinter);
+        self.property.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for NullsafePropertyFetchExpression {
     fn span(&self) -> Span {
         self.span
@@ -1178,6 +2030,13 @@ impl HasId for StaticPropertyFetchExpression {
     }
 }
 
+impl Fingerprint for StaticPropertyFetchExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.target.fingerprint(fingerprinter);
+        self.property.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for StaticPropertyFetchExpression {
     fn span(&self) -> Span {
         self.span
@@ -1199,6 +2058,13 @@ impl HasId for ConstantFetchExpression {
     }
 }
 
+impl Fingerprint for ConstantFetchExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.target.fingerprint(fingerprinter);
+        self.constant.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ConstantFetchExpression {
     fn span(&self) -> Span {
         self.span
@@ -1219,6 +2085,13 @@ impl HasId for ArrayExpression {
     }
 }
 
+impl Fingerprint for ArrayExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.kind.fingerprint(fingerprinter);
+        self.items.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ArrayExpression {
     fn span(&self) -> Span {
         self.span
@@ -1231,6 +2104,30 @@ pub enum ArrayKind {
     Long(ArrayKindLong),
 }
 
+impl Fingerprint for ArrayKind {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            ArrayKind::Short(inner) => {
+                fingerprinter.write_tag("Short");
+                inner.fingerprint(fingerprinter);
+            }
+            ArrayKind::Long(inner) => {
+                fingerprinter.write_tag("Long");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for ArrayKind {
+    fn span(&self) -> Span {
+        match self {
+            ArrayKind::Short(inner) => inner.span(),
+            ArrayKind::Long(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ArrayKindShort {
     pub span: Span,
@@ -1238,6 +2135,10 @@ pub struct ArrayKindShort {
     pub right_bracket: Span,
 }
 
+impl Fingerprint for ArrayKindShort {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {}
+}
+
 impl IsSpanned for ArrayKindShort {
     fn span(&self) -> Span {
         self.span
@@ -1252,6 +2153,10 @@ pub struct ArrayKindLong {
     pub right_parenthesis: Span,
 }
 
+impl Fingerprint for ArrayKindLong {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {}
+}
+
 impl IsSpanned for ArrayKindLong {
     fn span(&self) -> Span {
         self.span
@@ -1274,6 +2179,12 @@ impl HasId for ListExpression {
     }
 }
 
+impl Fingerprint for ListExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.items.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ListExpression {
     fn span(&self) -> Span {
         self.span
@@ -1295,6 +2206,13 @@ impl HasId for NewExpression {
     }
 }
 
+impl Fingerprint for NewExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.target.fingerprint(fingerprinter);
+        self.arguments.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for NewExpression {
     fn span(&self) -> Span {
         self.span
@@ -1314,6 +2232,12 @@ impl HasId for InterpolatedStringExpression {
     }
 }
 
+impl Fingerprint for InterpolatedStringExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.parts.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for InterpolatedStringExpression {
     fn span(&self) -> Span {
         self.span
@@ -1334,6 +2258,13 @@ impl HasId for HeredocExpression {
     }
 }
 
+impl Fingerprint for HeredocExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.label.fingerprint(fingerprinter);
+        self.parts.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for HeredocExpression {
     fn span(&self) -> Span {
         self.span
@@ -1354,6 +2285,13 @@ impl HasId for NowdocExpression {
     }
 }
 
+impl Fingerprint for NowdocExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.label.fingerprint(fingerprinter);
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for NowdocExpression {
     fn span(&self) -> Span {
         self.span
@@ -1373,6 +2311,12 @@ impl HasId for ShellExecExpression {
     }
 }
 
+impl Fingerprint for ShellExecExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.parts.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ShellExecExpression {
     fn span(&self) -> Span {
         self.span
@@ -1392,6 +2336,12 @@ impl HasId for BoolExpression {
     }
 }
 
+impl Fingerprint for BoolExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for BoolExpression {
     fn span(&self) -> Span {
         self.span
@@ -1414,6 +2364,13 @@ impl HasId for ArrayIndexExpression {
     }
 }
 
+impl Fingerprint for ArrayIndexExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.array.fingerprint(fingerprinter);
+        self.index.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ArrayIndexExpression {
     fn span(&self) -> Span {
         self.span
@@ -1435,6 +2392,13 @@ impl HasId for ShortTernaryExpression {
     }
 }
 
+impl Fingerprint for ShortTernaryExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.condition.fingerprint(fingerprinter);
+        self.r#else.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ShortTernaryExpression {
     fn span(&self) -> Span {
         self.span
@@ -1458,6 +2422,14 @@ impl HasId for TernaryExpression {
     }
 }
 
+impl Fingerprint for TernaryExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.condition.fingerprint(fingerprinter);
+        self.then.fingerprint(fingerprinter);
+        self.r#else.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for TernaryExpression {
     fn span(&self) -> Span {
         self.span
@@ -1479,6 +2451,13 @@ impl HasId for CoalesceExpression {
     }
 }
 
+impl Fingerprint for CoalesceExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.lhs.fingerprint(fingerprinter);
+        self.rhs.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for CoalesceExpression {
     fn span(&self) -> Span {
         self.span
@@ -1490,7 +2469,9 @@ pub struct CloneExpression {
     pub id: NodeId,
     pub span: Span,
     pub clone: Span,
+    pub left_parenthesis: Option<Span>,
     pub target: Box<Expression>,
+    pub right_parenthesis: Option<Span>,
 }
 
 impl HasId for CloneExpression {
@@ -1499,6 +2480,14 @@ impl HasId for CloneExpression {
     }
 }
 
+impl Fingerprint for CloneExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.left_parenthesis.fingerprint(fingerprinter);
+        self.target.fingerprint(fingerprinter);
+        self.right_parenthesis.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for CloneExpression {
     fn span(&self) -> Span {
         self.span
@@ -1525,6 +2514,14 @@ impl HasId for MatchExpression {
     }
 }
 
+impl Fingerprint for MatchExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.condition.fingerprint(fingerprinter);
+        self.default.fingerprint(fingerprinter);
+        self.arms.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for MatchExpression {
     fn span(&self) -> Span {
         self.span
@@ -1544,6 +2541,12 @@ impl HasId for ThrowExpression {
     }
 }
 
+impl Fingerprint for ThrowExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ThrowExpression {
     fn span(&self) -> Span {
         self.span
@@ -1565,6 +2568,13 @@ impl HasId for YieldExpression {
     }
 }
 
+impl Fingerprint for YieldExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.key.fingerprint(fingerprinter);
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for YieldExpression {
     fn span(&self) -> Span {
         self.span
@@ -1586,6 +2596,12 @@ impl HasId for YieldFromExpression {
     }
 }
 
+impl Fingerprint for YieldFromExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for YieldFromExpression {
     fn span(&self) -> Span {
         self.span
@@ -1606,6 +2622,13 @@ impl HasId for CastExpression {
     }
 }
 
+impl Fingerprint for CastExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.kind.fingerprint(fingerprinter);
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for CastExpression {
     fn span(&self) -> Span {
         self.span
@@ -1627,6 +2650,12 @@ impl HasId for DefaultMatchArm {
     }
 }
 
+impl Fingerprint for DefaultMatchArm {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DefaultMatchArm {
     fn span(&self) -> Span {
         self.span
@@ -1648,6 +2677,13 @@ impl HasId for MatchArm {
     }
 }
 
+impl Fingerprint for MatchArm {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.conditions.fingerprint(fingerprinter);
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for MatchArm {
     fn span(&self) -> Span {
         self.span
@@ -1667,6 +2703,12 @@ impl HasId for MagicConstantExpression {
     }
 }
 
+impl Fingerprint for MagicConstantExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.kind.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for MagicConstantExpression {
     fn span(&self) -> Span {
         self.span
@@ -1681,11 +2723,29 @@ pub enum MagicConstantKind {
     Function,
     Class,
     Method,
+    Property,
     Namespace,
     Trait,
     CompilerHaltOffset,
 }
 
+impl Fingerprint for MagicConstantKind {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            MagicConstantKind::Directory => fingerprinter.write_tag("Directory"),
+            MagicConstantKind::File => fingerprinter.write_tag("File"),
+            MagicConstantKind::Line => fingerprinter.write_tag("Line"),
+            MagicConstantKind::Function => fingerprinter.write_tag("Function"),
+            MagicConstantKind::Class => fingerprinter.write_tag("Class"),
+            MagicConstantKind::Method => fingerprinter.write_tag("Method"),
+            MagicConstantKind::Property => fingerprinter.write_tag("Property"),
+            MagicConstantKind::Namespace => fingerprinter.write_tag("Namespace"),
+            MagicConstantKind::Trait => fingerprinter.write_tag("Trait"),
+            MagicConstantKind::CompilerHaltOffset => fingerprinter.write_tag("CompilerHaltOffset"),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum StringPart {
     Literal(LiteralStringPart),
@@ -1701,6 +2761,30 @@ impl HasId for StringPart {
     }
 }
 
+impl Fingerprint for StringPart {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            StringPart::Literal(inner) => {
+                fingerprinter.write_tag("Literal");
+                inner.fingerprint(fingerprinter);
+            }
+            StringPart::Expression(inner) => {
+                fingerprinter.write_tag("Expression");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for StringPart {
+    fn span(&self) -> Span {
+        match self {
+            StringPart::Literal(inner) => inner.span(),
+            StringPart::Expression(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct LiteralStringPart {
     pub id: NodeId,
@@ -1714,6 +2798,12 @@ impl HasId for LiteralStringPart {
     }
 }
 
+impl Fingerprint for LiteralStringPart {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for LiteralStringPart {
     fn span(&self) -> Span {
         self.span
@@ -1733,6 +2823,12 @@ impl HasId for ExpressionStringPart {
     }
 }
 
+impl Fingerprint for ExpressionStringPart {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.expression.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ExpressionStringPart {
     fn span(&self) -> Span {
         self.span
@@ -1762,11 +2858,43 @@ impl HasId for ArrayItem {
     }
 }
 
+impl Fingerprint for ArrayItem {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            ArrayItem::Skipped(_) => fingerprinter.write_tag("Skipped"),
+            ArrayItem::Value(inner) => {
+                fingerprinter.write_tag("Value");
+                inner.fingerprint(fingerprinter);
+            }
+            ArrayItem::ReferencedValue(inner) => {
+                fingerprinter.write_tag("ReferencedValue");
+                inner.fingerprint(fingerprinter);
+            }
+            ArrayItem::SpreadValue(inner) => {
+                fingerprinter.write_tag("SpreadValue");
+                inner.fingerprint(fingerprinter);
+            }
+            ArrayItem::KeyValue(inner) => {
+                fingerprinter.write_tag("KeyValue");
+                inner.fingerprint(fingerprinter);
+            }
+            ArrayItem::ReferencedKeyValue(inner) => {
+                fingerprinter.write_tag("ReferencedKeyValue");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
 impl IsSpanned for ArrayItem {
     fn span(&self) -> Span {
         match self {
             ArrayItem::Skipped(span) => *span,
-            _ => Span::default(),
+            ArrayItem::Value(inner) => inner.span(),
+            ArrayItem::ReferencedValue(inner) => inner.span(),
+            ArrayItem::SpreadValue(inner) => inner.span(),
+            ArrayItem::KeyValue(inner) => inner.span(),
+            ArrayItem::ReferencedKeyValue(inner) => inner.span(),
         }
     }
 }
@@ -1784,6 +2912,12 @@ impl HasId for ArrayItemValue {
     }
 }
 
+impl Fingerprint for ArrayItemValue {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ArrayItemValue {
     fn span(&self) -> Span {
         self.span
@@ -1804,6 +2938,12 @@ impl HasId for ArrayItemReferencedValue {
     }
 }
 
+impl Fingerprint for ArrayItemReferencedValue {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ArrayItemReferencedValue {
     fn span(&self) -> Span {
         self.span
@@ -1824,6 +2964,12 @@ impl HasId for ArrayItemSpreadValue {
     }
 }
 
+impl Fingerprint for ArrayItemSpreadValue {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ArrayItemSpreadValue {
     fn span(&self) -> Span {
         self.span
@@ -1845,6 +2991,13 @@ impl HasId for ArrayItemKeyValue {
     }
 }
 
+impl Fingerprint for ArrayItemKeyValue {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.key.fingerprint(fingerprinter);
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ArrayItemKeyValue {
     fn span(&self) -> Span {
         self.span
@@ -1867,6 +3020,13 @@ impl HasId for ArrayItemReferencedKeyValue {
     }
 }
 
+impl Fingerprint for ArrayItemReferencedKeyValue {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.key.fingerprint(fingerprinter);
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ArrayItemReferencedKeyValue {
     fn span(&self) -> Span {
         self.span
@@ -1890,11 +3050,28 @@ impl HasId for ListEntry {
     }
 }
 
+impl Fingerprint for ListEntry {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            ListEntry::Skipped(_) => fingerprinter.write_tag("Skipped"),
+            ListEntry::Value(inner) => {
+                fingerprinter.write_tag("Value");
+                inner.fingerprint(fingerprinter);
+            }
+            ListEntry::KeyValue(inner) => {
+                fingerprinter.write_tag("KeyValue");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
 impl IsSpanned for ListEntry {
     fn span(&self) -> Span {
         match self {
             ListEntry::Skipped(span) => *span,
-            _ => Span::default(),
+            ListEntry::Value(inner) => inner.span(),
+            ListEntry::KeyValue(inner) => inner.span(),
         }
     }
 }
@@ -1903,6 +3080,7 @@ impl IsSpanned for ListEntry {
 pub struct ListEntryValue {
     pub id: NodeId,
     pub span: Span,
+    pub ampersand: Option<Span>,
     pub value: Expression,
 }
 
@@ -1912,6 +3090,13 @@ impl HasId for ListEntryValue {
     }
 }
 
+impl Fingerprint for ListEntryValue {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.ampersand.fingerprint(fingerprinter);
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ListEntryValue {
     fn span(&self) -> Span {
         self.span
@@ -1924,6 +3109,7 @@ pub struct ListEntryKeyValue {
     pub span: Span,
     pub key: Expression,
     pub double_arrow: Span,
+    pub ampersand: Option<Span>,
     pub value: Expression,
 }
 
@@ -1933,6 +3119,14 @@ impl HasId for ListEntryKeyValue {
     }
 }
 
+impl Fingerprint for ListEntryKeyValue {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.key.fingerprint(fingerprinter);
+        self.ampersand.fingerprint(fingerprinter);
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ListEntryKeyValue {
     fn span(&self) -> Span {
         self.span
@@ -1954,6 +3148,13 @@ impl HasId for PositionalArgument {
     }
 }
 
+impl Fingerprint for PositionalArgument {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.ellipsis.fingerprint(fingerprinter);
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for PositionalArgument {
     fn span(&self) -> Span {
         self.span
@@ -1977,6 +3178,14 @@ impl HasId for NamedArgument {
     }
 }
 
+impl Fingerprint for NamedArgument {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.name.fingerprint(fingerprinter);
+        self.ellipsis.fingerprint(fingerprinter);
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for NamedArgument {
     fn span(&self) -> Span {
         self.span
@@ -1998,6 +3207,30 @@ impl HasId for Argument {
     }
 }
 
+impl Fingerprint for Argument {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            Argument::Positional(inner) => {
+                fingerprinter.write_tag("Positional");
+                inner.fingerprint(fingerprinter);
+            }
+            Argument::Named(inner) => {
+                fingerprinter.write_tag("Named");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for Argument {
+    fn span(&self) -> Span {
+        match self {
+            Argument::Positional(inner) => inner.span(),
+            Argument::Named(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ArgumentList {
     pub id: NodeId,
@@ -2014,6 +3247,12 @@ impl HasId for ArgumentList {
     }
 }
 
+impl Fingerprint for ArgumentList {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.arguments.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ArgumentList {
     fn span(&self) -> Span {
         self.span
@@ -2036,6 +3275,12 @@ impl HasId for SingleArgument {
     }
 }
 
+impl Fingerprint for SingleArgument {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.argument.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for SingleArgument {
     fn span(&self) -> Span {
         self.span
@@ -2058,6 +3303,10 @@ impl HasId for ArgumentPlaceholder {
     }
 }
 
+impl Fingerprint for ArgumentPlaceholder {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {}
+}
+
 impl IsSpanned for ArgumentPlaceholder {
     fn span(&self) -> Span {
         self.span
@@ -2078,6 +3327,13 @@ impl HasId for Attribute {
     }
 }
 
+impl Fingerprint for Attribute {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.name.fingerprint(fingerprinter);
+        self.arguments.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for Attribute {
     fn span(&self) -> Span {
         self.span
@@ -2097,6 +3353,12 @@ impl HasId for AttributeGroup {
     }
 }
 
+impl Fingerprint for AttributeGroup {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.members.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for AttributeGroup {
     fn span(&self) -> Span {
         self.span
@@ -2109,6 +3371,7 @@ pub struct ClassBody {
     pub span: Span,
     pub left_brace: Span,
     pub members: Vec<ClassishMember>,
+    pub trailing_comments: CommentGroup,
     pub right_brace: Span,
 }
 
@@ -2118,6 +3381,12 @@ impl HasId for ClassBody {
     }
 }
 
+impl Fingerprint for ClassBody {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.members.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ClassBody {
     fn span(&self) -> Span {
         self.span
@@ -2143,6 +3412,17 @@ impl HasId for ClassStatement {
     }
 }
 
+impl Fingerprint for ClassStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.attributes.fingerprint(fingerprinter);
+        self.modifiers.fingerprint(fingerprinter);
+        self.name.fingerprint(fingerprinter);
+        self.extends.fingerprint(fingerprinter);
+        self.implements.fingerprint(fingerprinter);
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ClassStatement {
     fn span(&self) -> Span {
         self.span
@@ -2155,6 +3435,7 @@ pub struct AnonymousClassBody {
     pub span: Span,
     pub left_brace: Span,
     pub members: Vec<ClassishMember>,
+    pub trailing_comments: CommentGroup,
     pub right_brace: Span,
 }
 
@@ -2164,6 +3445,12 @@ impl HasId for AnonymousClassBody {
     }
 }
 
+impl Fingerprint for AnonymousClassBody {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.members.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for AnonymousClassBody {
     fn span(&self) -> Span {
         self.span
@@ -2187,6 +3474,15 @@ impl HasId for AnonymousClassExpression {
     }
 }
 
+impl Fingerprint for AnonymousClassExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.attributes.fingerprint(fingerprinter);
+        self.extends.fingerprint(fingerprinter);
+        self.implements.fingerprint(fingerprinter);
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for AnonymousClassExpression {
     fn span(&self) -> Span {
         self.span
@@ -2207,6 +3503,12 @@ impl HasId for ClassExtends {
     }
 }
 
+impl Fingerprint for ClassExtends {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.parent.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ClassExtends {
     fn span(&self) -> Span {
         self.span
@@ -2227,6 +3529,12 @@ impl HasId for ClassImplements {
     }
 }
 
+impl Fingerprint for ClassImplements {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.interfaces.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ClassImplements {
     fn span(&self) -> Span {
         self.span
@@ -2254,6 +3562,45 @@ impl HasId for ClassishMember {
     }
 }
 
+impl Fingerprint for ClassishMember {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            ClassishMember::Constant(inner) => {
+                fingerprinter.write_tag("Constant");
+                inner.fingerprint(fingerprinter);
+            }
+            ClassishMember::TraitUsage(inner) => {
+                fingerprinter.write_tag("TraitUsage");
+                inner.fingerprint(fingerprinter);
+            }
+            ClassishMember::Property(inner) => {
+                fingerprinter.write_tag("Property");
+                inner.fingerprint(fingerprinter);
+            }
+            ClassishMember::Method(inner) => {
+                fingerprinter.write_tag("Method");
+                inner.fingerprint(fingerprinter);
+            }
+            ClassishMember::Missing(inner) => {
+                fingerprinter.write_tag("Missing");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for ClassishMember {
+    fn span(&self) -> Span {
+        match self {
+            ClassishMember::Constant(inner) => inner.span(),
+            ClassishMember::TraitUsage(inner) => inner.span(),
+            ClassishMember::Property(inner) => inner.span(),
+            ClassishMember::Method(inner) => inner.span(),
+            ClassishMember::Missing(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Method {
     pub id: NodeId,
@@ -2275,6 +3622,18 @@ impl HasId for Method {
     }
 }
 
+impl Fingerprint for Method {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.attributes.fingerprint(fingerprinter);
+        self.modifiers.fingerprint(fingerprinter);
+        self.ampersand.fingerprint(fingerprinter);
+        self.name.fingerprint(fingerprinter);
+        self.parameters.fingerprint(fingerprinter);
+        self.return_type.fingerprint(fingerprinter);
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for Method {
     fn span(&self) -> Span {
         self.span
@@ -2294,6 +3653,12 @@ impl HasId for MethodBody {
     }
 }
 
+impl Fingerprint for MethodBody {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.kind.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for MethodBody {
     fn span(&self) -> Span {
         self.span
@@ -2317,6 +3682,35 @@ impl HasId for MethodBodyKind {
     }
 }
 
+impl Fingerprint for MethodBodyKind {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            MethodBodyKind::Abstract(inner) => {
+                fingerprinter.write_tag("Abstract");
+                inner.fingerprint(fingerprinter);
+            }
+            MethodBodyKind::Concrete(inner) => {
+                fingerprinter.write_tag("Concrete");
+                inner.fingerprint(fingerprinter);
+            }
+            MethodBodyKind::Missing(inner) => {
+                fingerprinter.write_tag("Missing");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for MethodBodyKind {
+    fn span(&self) -> Span {
+        match self {
+            MethodBodyKind::Abstract(inner) => inner.span(),
+            MethodBodyKind::Concrete(inner) => inner.span(),
+            MethodBodyKind::Missing(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct MissingMethodBody {
     pub id: NodeId,
@@ -2329,6 +3723,10 @@ impl HasId for MissingMethodBody {
     }
 }
 
+impl Fingerprint for MissingMethodBody {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {}
+}
+
 impl IsSpanned for MissingMethodBody {
     fn span(&self) -> Span {
         self.span
@@ -2348,6 +3746,10 @@ impl HasId for AbstractMethodBody {
     }
 }
 
+impl Fingerprint for AbstractMethodBody {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {}
+}
+
 impl IsSpanned for AbstractMethodBody {
     fn span(&self) -> Span {
         self.span
@@ -2360,6 +3762,7 @@ pub struct ConcreteMethodBody {
     pub span: Span,
     pub left_brace: Span,
     pub statements: Vec<Statement>,
+    pub trailing_comments: CommentGroup,
     pub right_brace: Span,
 }
 
@@ -2369,6 +3772,12 @@ impl HasId for ConcreteMethodBody {
     }
 }
 
+impl Fingerprint for ConcreteMethodBody {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.statements.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ConcreteMethodBody {
     fn span(&self) -> Span {
         self.span
@@ -2390,6 +3799,12 @@ impl HasId for MethodParameterList {
     }
 }
 
+impl Fingerprint for MethodParameterList {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.parameters.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for MethodParameterList {
     fn span(&self) -> Span {
         self.span
@@ -2415,6 +3830,18 @@ impl HasId for MethodParameter {
     }
 }
 
+impl Fingerprint for MethodParameter {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.modifiers.fingerprint(fingerprinter);
+        self.name.fingerprint(fingerprinter);
+        self.attributes.fingerprint(fingerprinter);
+        self.data_type.fingerprint(fingerprinter);
+        self.ellipsis.fingerprint(fingerprinter);
+        self.default.fingerprint(fingerprinter);
+        self.ampersand.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for MethodParameter {
     fn span(&self) -> Span {
         self.span
@@ -2433,6 +3860,10 @@ impl HasId for MissingClassishMember {
     }
 }
 
+impl Fingerprint for MissingClassishMember {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {}
+}
+
 impl IsSpanned for MissingClassishMember {
     fn span(&self) -> Span {
         self.span
@@ -2454,6 +3885,13 @@ impl HasId for ConstantEntry {
     }
 }
 
+impl Fingerprint for ConstantEntry {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.name.fingerprint(fingerprinter);
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ConstantEntry {
     fn span(&self) -> Span {
         self.span
@@ -2475,6 +3913,13 @@ impl HasId for ClassishConstantEntry {
     }
 }
 
+impl Fingerprint for ClassishConstantEntry {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.name.fingerprint(fingerprinter);
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ClassishConstantEntry {
     fn span(&self) -> Span {
         self.span
@@ -2486,6 +3931,7 @@ pub struct ConstantStatement {
     pub id: NodeId,
     pub span: Span,
     pub comments: CommentGroup,
+    pub attributes: Vec<AttributeGroup>,
     pub r#const: Span,
     pub entries: Vec<ConstantEntry>,
     pub semicolon: Span,
@@ -2497,6 +3943,13 @@ impl HasId for ConstantStatement {
     }
 }
 
+impl Fingerprint for ConstantStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.attributes.fingerprint(fingerprinter);
+        self.entries.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ConstantStatement {
     fn span(&self) -> Span {
         self.span
@@ -2522,6 +3975,15 @@ impl HasId for ClassishConstant {
     }
 }
 
+impl Fingerprint for ClassishConstant {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.attributes.fingerprint(fingerprinter);
+        self.modifiers.fingerprint(fingerprinter);
+        self.data_type.fingerprint(fingerprinter);
+        self.entries.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ClassishConstant {
     fn span(&self) -> Span {
         self.span
@@ -2545,6 +4007,13 @@ impl HasId for IfStatement {
     }
 }
 
+impl Fingerprint for IfStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.condition.fingerprint(fingerprinter);
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for IfStatement {
     fn span(&self) -> Span {
         self.span
@@ -2566,6 +4035,30 @@ impl HasId for IfStatementBody {
     }
 }
 
+impl Fingerprint for IfStatementBody {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            IfStatementBody::Statement(inner) => {
+                fingerprinter.write_tag("Statement");
+                inner.fingerprint(fingerprinter);
+            }
+            IfStatementBody::Block(inner) => {
+                fingerprinter.write_tag("Block");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for IfStatementBody {
+    fn span(&self) -> Span {
+        match self {
+            IfStatementBody::Statement(inner) => inner.span(),
+            IfStatementBody::Block(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct IfStatementBodyStatement {
     pub id: NodeId,
@@ -2581,6 +4074,14 @@ impl HasId for IfStatementBodyStatement {
     }
 }
 
+impl Fingerprint for IfStatementBodyStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.statement.fingerprint(fingerprinter);
+        self.elseifs.fingerprint(fingerprinter);
+        self.r#else.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for IfStatementBodyStatement {
     fn span(&self) -> Span {
         self.span
@@ -2605,6 +4106,15 @@ impl HasId for IfStatementBodyBlock {
     }
 }
 
+impl Fingerprint for IfStatementBodyBlock {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.statements.fingerprint(fingerprinter);
+        self.elseifs.fingerprint(fingerprinter);
+        self.r#else.fingerprint(fingerprinter);
+        self.ending.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for IfStatementBodyBlock {
     fn span(&self) -> Span {
         self.span
@@ -2628,6 +4138,13 @@ impl HasId for IfStatementElseIf {
     }
 }
 
+impl Fingerprint for IfStatementElseIf {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.condition.fingerprint(fingerprinter);
+        self.statement.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for IfStatementElseIf {
     fn span(&self) -> Span {
         self.span
@@ -2648,6 +4165,12 @@ impl HasId for IfStatementElse {
     }
 }
 
+impl Fingerprint for IfStatementElse {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.statement.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for IfStatementElse {
     fn span(&self) -> Span {
         self.span
@@ -2672,6 +4195,13 @@ impl HasId for IfStatementElseIfBlock {
     }
 }
 
+impl Fingerprint for IfStatementElseIfBlock {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.condition.fingerprint(fingerprinter);
+        self.statements.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for IfStatementElseIfBlock {
     fn span(&self) -> Span {
         self.span
@@ -2693,6 +4223,12 @@ impl HasId for IfStatementElseBlock {
     }
 }
 
+impl Fingerprint for IfStatementElseBlock {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.statements.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for IfStatementElseBlock {
     fn span(&self) -> Span {
         self.span
@@ -2712,6 +4248,12 @@ impl HasId for DataType {
     }
 }
 
+impl Fingerprint for DataType {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.kind.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DataType {
     fn span(&self) -> Span {
         self.span
@@ -2733,6 +4275,13 @@ impl HasId for DeclareEntry {
     }
 }
 
+impl Fingerprint for DeclareEntry {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.key.fingerprint(fingerprinter);
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DeclareEntry {
     fn span(&self) -> Span {
         self.span
@@ -2754,6 +4303,12 @@ impl HasId for DeclareEntryGroup {
     }
 }
 
+impl Fingerprint for DeclareEntryGroup {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.entries.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DeclareEntryGroup {
     fn span(&self) -> Span {
         self.span
@@ -2764,7 +4319,7 @@ impl IsSpanned for DeclareEntryGroup {
 pub enum DeclareBody {
     Noop(DeclareBodyNoop),
     Braced(DeclareBodyBraced),
-    Expression(DeclareBodyExpression),
+    Statement(DeclareBodyStatement),
     Block(DeclareBodyBlock),
 }
 
@@ -2773,12 +4328,46 @@ impl HasId for DeclareBody {
         match self {
             DeclareBody::Noop(inner) => inner.id(),
             DeclareBody::Braced(inner) => inner.id(),
-            DeclareBody::Expression(inner) => inner.id(),
+            DeclareBody::Statement(inner) => inner.id(),
             DeclareBody::Block(inner) => inner.id(),
         }
     }
 }
 
+impl Fingerprint for DeclareBody {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            DeclareBody::Noop(inner) => {
+                fingerprinter.write_tag("Noop");
+                inner.fingerprint(fingerprinter);
+            }
+            DeclareBody::Braced(inner) => {
+                fingerprinter.write_tag("Braced");
+                inner.fingerprint(fingerprinter);
+            }
+            DeclareBody::Statement(inner) => {
+                fingerprinter.write_tag("Expression");
+                inner.fingerprint(fingerprinter);
+            }
+            DeclareBody::Block(inner) => {
+                fingerprinter.write_tag("Block");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for DeclareBody {
+    fn span(&self) -> Span {
+        match self {
+            DeclareBody::Noop(inner) => inner.span(),
+            DeclareBody::Braced(inner) => inner.span(),
+            DeclareBody::Statement(inner) => inner.span(),
+            DeclareBody::Block(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct DeclareBodyNoop {
     pub id: NodeId,
@@ -2792,6 +4381,10 @@ impl HasId for DeclareBodyNoop {
     }
 }
 
+impl Fingerprint for DeclareBodyNoop {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {}
+}
+
 impl IsSpanned for DeclareBodyNoop {
     fn span(&self) -> Span {
         self.span
@@ -2804,6 +4397,7 @@ pub struct DeclareBodyBraced {
     pub span: Span,
     pub left_brace: Span,
     pub statements: Vec<Statement>,
+    pub trailing_comments: CommentGroup,
     pub right_brace: Span,
 }
 
@@ -2813,6 +4407,12 @@ impl HasId for DeclareBodyBraced {
     }
 }
 
+impl Fingerprint for DeclareBodyBraced {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.statements.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DeclareBodyBraced {
     fn span(&self) -> Span {
         self.span
@@ -2820,20 +4420,25 @@ impl IsSpanned for DeclareBodyBraced {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub struct DeclareBodyExpression {
+pub struct DeclareBodyStatement {
     pub id: NodeId,
     pub span: Span,
-    pub expression: Expression,
-    pub semicolon: Span,
+    pub statement: Box<Statement>,
 }
 
-impl HasId for DeclareBodyExpression {
+impl HasId for DeclareBodyStatement {
     fn id(&self) -> NodeId {
         self.id
     }
 }
 
-impl IsSpanned for DeclareBodyExpression {
+impl Fingerprint for DeclareBodyStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.statement.fingerprint(fingerprinter);
+    }
+}
+
+impl IsSpanned for DeclareBodyStatement {
     fn span(&self) -> Span {
         self.span
     }
@@ -2855,6 +4460,12 @@ impl HasId for DeclareBodyBlock {
     }
 }
 
+impl Fingerprint for DeclareBodyBlock {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.statements.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DeclareBodyBlock {
     fn span(&self) -> Span {
         self.span
@@ -2876,6 +4487,13 @@ impl HasId for DeclareStatement {
     }
 }
 
+impl Fingerprint for DeclareStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.entries.fingerprint(fingerprinter);
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DeclareStatement {
     fn span(&self) -> Span {
         self.span
@@ -2898,6 +4516,13 @@ impl HasId for UnitEnumCase {
     }
 }
 
+impl Fingerprint for UnitEnumCase {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.attributes.fingerprint(fingerprinter);
+        self.name.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for UnitEnumCase {
     fn span(&self) -> Span {
         self.span
@@ -2919,12 +4544,37 @@ impl HasId for UnitEnumMember {
     }
 }
 
+impl Fingerprint for UnitEnumMember {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            UnitEnumMember::Case(inner) => {
+                fingerprinter.write_tag("Case");
+                inner.fingerprint(fingerprinter);
+            }
+            UnitEnumMember::Classish(inner) => {
+                fingerprinter.write_tag("Classish");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for UnitEnumMember {
+    fn span(&self) -> Span {
+        match self {
+            UnitEnumMember::Case(inner) => inner.span(),
+            UnitEnumMember::Classish(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct UnitEnumBody {
     pub id: NodeId,
     pub span: Span,
     pub left_brace: Span,
     pub members: Vec<UnitEnumMember>,
+    pub trailing_comments: CommentGroup,
     pub right_brace: Span,
 }
 
@@ -2934,6 +4584,12 @@ impl HasId for UnitEnumBody {
     }
 }
 
+impl Fingerprint for UnitEnumBody {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.members.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for UnitEnumBody {
     fn span(&self) -> Span {
         self.span
@@ -2957,6 +4613,15 @@ impl HasId for UnitEnumStatement {
     }
 }
 
+impl Fingerprint for UnitEnumStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.attributes.fingerprint(fingerprinter);
+        self.name.fingerprint(fingerprinter);
+        self.implements.fingerprint(fingerprinter);
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for UnitEnumStatement {
     fn span(&self) -> Span {
         self.span
@@ -2981,6 +4646,14 @@ impl HasId for BackedEnumCase {
     }
 }
 
+impl Fingerprint for BackedEnumCase {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.attributes.fingerprint(fingerprinter);
+        self.name.fingerprint(fingerprinter);
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for BackedEnumCase {
     fn span(&self) -> Span {
         self.span
@@ -3002,12 +4675,37 @@ impl HasId for BackedEnumMember {
     }
 }
 
+impl Fingerprint for BackedEnumMember {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            BackedEnumMember::Case(inner) => {
+                fingerprinter.write_tag("Case");
+                inner.fingerprint(fingerprinter);
+            }
+            BackedEnumMember::Classish(inner) => {
+                fingerprinter.write_tag("Classish");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for BackedEnumMember {
+    fn span(&self) -> Span {
+        match self {
+            BackedEnumMember::Case(inner) => inner.span(),
+            BackedEnumMember::Classish(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct BackedEnumBody {
     pub id: NodeId,
     pub span: Span,
     pub left_brace: Span,
     pub members: Vec<BackedEnumMember>,
+    pub trailing_comments: CommentGroup,
     pub right_brace: Span,
 }
 
@@ -3017,6 +4715,12 @@ impl HasId for BackedEnumBody {
     }
 }
 
+impl Fingerprint for BackedEnumBody {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.members.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for BackedEnumBody {
     fn span(&self) -> Span {
         self.span
@@ -3042,6 +4746,16 @@ impl HasId for BackedEnumStatement {
     }
 }
 
+impl Fingerprint for BackedEnumStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.attributes.fingerprint(fingerprinter);
+        self.name.fingerprint(fingerprinter);
+        self.backed_type.fingerprint(fingerprinter);
+        self.implements.fingerprint(fingerprinter);
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for BackedEnumStatement {
     fn span(&self) -> Span {
         self.span
@@ -3055,12 +4769,22 @@ pub enum BackedEnumType {
     Invalid,
 }
 
+impl Fingerprint for BackedEnumType {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            BackedEnumType::String(_) => fingerprinter.write_tag("String"),
+            BackedEnumType::Int(_) => fingerprinter.write_tag("Int"),
+            BackedEnumType::Invalid => fingerprinter.write_tag("Invalid"),
+        }
+    }
+}
+
 impl IsSpanned for BackedEnumType {
     fn span(&self) -> Span {
         match self {
             BackedEnumType::String(span) => *span,
             BackedEnumType::Int(span) => *span,
-            _ => Span::default(),
+            BackedEnumType::Invalid => Span::default(),
         }
     }
 }
@@ -3079,6 +4803,12 @@ impl HasId for ReturnType {
     }
 }
 
+impl Fingerprint for ReturnType {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.data_type.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ReturnType {
     fn span(&self) -> Span {
         self.span
@@ -3104,6 +4834,17 @@ impl HasId for FunctionParameter {
     }
 }
 
+impl Fingerprint for FunctionParameter {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.name.fingerprint(fingerprinter);
+        self.attributes.fingerprint(fingerprinter);
+        self.data_type.fingerprint(fingerprinter);
+        self.ellipsis.fingerprint(fingerprinter);
+        self.default.fingerprint(fingerprinter);
+        self.ampersand.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for FunctionParameter {
     fn span(&self) -> Span {
         self.span
@@ -3126,6 +4867,12 @@ impl HasId for FunctionParameterList {
     }
 }
 
+impl Fingerprint for FunctionParameterList {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.parameters.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for FunctionParameterList {
     fn span(&self) -> Span {
         self.span
@@ -3139,6 +4886,7 @@ pub struct FunctionBody {
     pub comments: CommentGroup,
     pub left_brace: Span,
     pub statements: Vec<Statement>,
+    pub trailing_comments: CommentGroup,
     pub right_brace: Span,
 }
 
@@ -3148,6 +4896,12 @@ impl HasId for FunctionBody {
     }
 }
 
+impl Fingerprint for FunctionBody {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.statements.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for FunctionBody {
     fn span(&self) -> Span {
         self.span
@@ -3174,6 +4928,17 @@ impl HasId for FunctionStatement {
     }
 }
 
+impl Fingerprint for FunctionStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.attributes.fingerprint(fingerprinter);
+        self.ampersand.fingerprint(fingerprinter);
+        self.name.fingerprint(fingerprinter);
+        self.parameters.fingerprint(fingerprinter);
+        self.return_type.fingerprint(fingerprinter);
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for FunctionStatement {
     fn span(&self) -> Span {
         self.span
@@ -3195,6 +4960,13 @@ impl HasId for ClosureUseVariable {
     }
 }
 
+impl Fingerprint for ClosureUseVariable {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.ampersand.fingerprint(fingerprinter);
+        self.variable.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ClosureUseVariable {
     fn span(&self) -> Span {
         self.span
@@ -3218,6 +4990,12 @@ impl HasId for ClosureUse {
     }
 }
 
+impl Fingerprint for ClosureUse {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.variables.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ClosureUse {
     fn span(&self) -> Span {
         self.span
@@ -3245,6 +5023,18 @@ impl HasId for ClosureExpression {
     }
 }
 
+impl Fingerprint for ClosureExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.attributes.fingerprint(fingerprinter);
+        self.r#static.fingerprint(fingerprinter);
+        self.ampersand.fingerprint(fingerprinter);
+        self.parameters.fingerprint(fingerprinter);
+        self.uses.fingerprint(fingerprinter);
+        self.return_type.fingerprint(fingerprinter);
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ClosureExpression {
     fn span(&self) -> Span {
         self.span
@@ -3272,6 +5062,17 @@ impl HasId for ArrowFunctionExpression {
     }
 }
 
+impl Fingerprint for ArrowFunctionExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.r#static.fingerprint(fingerprinter);
+        self.ampersand.fingerprint(fingerprinter);
+        self.attributes.fingerprint(fingerprinter);
+        self.parameters.fingerprint(fingerprinter);
+        self.return_type.fingerprint(fingerprinter);
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ArrowFunctionExpression {
     fn span(&self) -> Span {
         self.span
@@ -3293,6 +5094,12 @@ impl HasId for LabelStatement {
     }
 }
 
+impl Fingerprint for LabelStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.label.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for LabelStatement {
     fn span(&self) -> Span {
         self.span
@@ -3315,6 +5122,12 @@ impl HasId for GotoStatement {
     }
 }
 
+impl Fingerprint for GotoStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.label.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for GotoStatement {
     fn span(&self) -> Span {
         self.span
@@ -3336,6 +5149,30 @@ impl HasId for Identifier {
     }
 }
 
+impl Fingerprint for Identifier {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            Identifier::SimpleIdentifier(inner) => {
+                fingerprinter.write_tag("SimpleIdentifier");
+                inner.fingerprint(fingerprinter);
+            }
+            Identifier::DynamicIdentifier(inner) => {
+                fingerprinter.write_tag("DynamicIdentifier");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for Identifier {
+    fn span(&self) -> Span {
+        match self {
+            Identifier::SimpleIdentifier(inner) => inner.span(),
+            Identifier::DynamicIdentifier(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct SimpleIdentifier {
     pub id: NodeId,
@@ -3349,6 +5186,12 @@ impl HasId for SimpleIdentifier {
     }
 }
 
+impl Fingerprint for SimpleIdentifier {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.symbol.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for SimpleIdentifier {
     fn span(&self) -> Span {
         self.span
@@ -3368,6 +5211,12 @@ impl HasId for DynamicIdentifier {
     }
 }
 
+impl Fingerprint for DynamicIdentifier {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.expr.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DynamicIdentifier {
     fn span(&self) -> Span {
         self.span
@@ -3388,6 +5237,12 @@ impl HasId for InterfaceExtends {
     }
 }
 
+impl Fingerprint for InterfaceExtends {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.parents.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for InterfaceExtends {
     fn span(&self) -> Span {
         self.span
@@ -3400,6 +5255,7 @@ pub struct InterfaceBody {
     pub span: Span,
     pub left_brace: Span,
     pub members: Vec<ClassishMember>,
+    pub trailing_comments: CommentGroup,
     pub right_brace: Span,
 }
 
@@ -3409,6 +5265,12 @@ impl HasId for InterfaceBody {
     }
 }
 
+impl Fingerprint for InterfaceBody {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.members.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for InterfaceBody {
     fn span(&self) -> Span {
         self.span
@@ -3432,6 +5294,15 @@ impl HasId for InterfaceStatement {
     }
 }
 
+impl Fingerprint for InterfaceStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.attributes.fingerprint(fingerprinter);
+        self.name.fingerprint(fingerprinter);
+        self.extends.fingerprint(fingerprinter);
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for InterfaceStatement {
     fn span(&self) -> Span {
         self.span
@@ -3452,6 +5323,13 @@ impl HasId for Literal {
     }
 }
 
+impl Fingerprint for Literal {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.kind.fingerprint(fingerprinter);
+        self.token.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for Literal {
     fn span(&self) -> Span {
         self.span
@@ -3466,6 +5344,17 @@ pub enum LiteralKind {
     Missing,
 }
 
+impl Fingerprint for LiteralKind {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            LiteralKind::Integer => fingerprinter.write_tag("Integer"),
+            LiteralKind::Float => fingerprinter.write_tag("Float"),
+            LiteralKind::String => fingerprinter.write_tag("String"),
+            LiteralKind::Missing => fingerprinter.write_tag("Missing"),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ForeachStatement {
     pub id: NodeId,
@@ -3483,6 +5372,13 @@ impl HasId for ForeachStatement {
     }
 }
 
+impl Fingerprint for ForeachStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.iterator.fingerprint(fingerprinter);
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ForeachStatement {
     fn span(&self) -> Span {
         self.span
@@ -3504,6 +5400,30 @@ impl HasId for ForeachStatementIterator {
     }
 }
 
+impl Fingerprint for ForeachStatementIterator {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            ForeachStatementIterator::Value(inner) => {
+                fingerprinter.write_tag("Value");
+                inner.fingerprint(fingerprinter);
+            }
+            ForeachStatementIterator::KeyAndValue(inner) => {
+                fingerprinter.write_tag("KeyAndValue");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for ForeachStatementIterator {
+    fn span(&self) -> Span {
+        match self {
+            ForeachStatementIterator::Value(inner) => inner.span(),
+            ForeachStatementIterator::KeyAndValue(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ForeachStatementIteratorValue {
     pub id: NodeId,
@@ -3520,6 +5440,14 @@ impl HasId for ForeachStatementIteratorValue {
     }
 }
 
+impl Fingerprint for ForeachStatementIteratorValue {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.expression.fingerprint(fingerprinter);
+        self.ampersand.fingerprint(fingerprinter);
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ForeachStatementIteratorValue {
     fn span(&self) -> Span {
         self.span
@@ -3544,6 +5472,15 @@ impl HasId for ForeachStatementIteratorKeyAndValue {
     }
 }
 
+impl Fingerprint for ForeachStatementIteratorKeyAndValue {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.expression.fingerprint(fingerprinter);
+        self.ampersand.fingerprint(fingerprinter);
+        self.key.fingerprint(fingerprinter);
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ForeachStatementIteratorKeyAndValue {
     fn span(&self) -> Span {
         self.span
@@ -3565,6 +5502,30 @@ impl HasId for ForeachStatementBody {
     }
 }
 
+impl Fingerprint for ForeachStatementBody {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            ForeachStatementBody::Statement(inner) => {
+                fingerprinter.write_tag("Statement");
+                inner.fingerprint(fingerprinter);
+            }
+            ForeachStatementBody::Block(inner) => {
+                fingerprinter.write_tag("Block");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for ForeachStatementBody {
+    fn span(&self) -> Span {
+        match self {
+            ForeachStatementBody::Statement(inner) => inner.span(),
+            ForeachStatementBody::Block(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ForeachStatementBodyStatement {
     pub id: NodeId,
@@ -3578,6 +5539,12 @@ impl HasId for ForeachStatementBodyStatement {
     }
 }
 
+impl Fingerprint for ForeachStatementBodyStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.statement.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ForeachStatementBodyStatement {
     fn span(&self) -> Span {
         self.span
@@ -3600,7 +5567,14 @@ impl HasId for ForeachStatementBodyBlock {
     }
 }
 
-impl IsSpanned for ForeachStatementBodyBlock {
+impl Fingerprint for ForeachStatementBodyBlock {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.statements.fingerprint(fingerprinter);
+        self.ending.fingerprint(fingerprinter);
+    }
+}
+
+impl IsSpanned for ForeachStatementBodyBlock {
     fn span(&self) -> Span {
         self.span
     }
@@ -3623,6 +5597,13 @@ impl HasId for ForStatement {
     }
 }
 
+impl Fingerprint for ForStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.iterator.fingerprint(fingerprinter);
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ForStatement {
     fn span(&self) -> Span {
         self.span
@@ -3646,6 +5627,14 @@ impl HasId for ForStatementIterator {
     }
 }
 
+impl Fingerprint for ForStatementIterator {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.initializations.fingerprint(fingerprinter);
+        self.conditions.fingerprint(fingerprinter);
+        self.r#loop.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ForStatementIterator {
     fn span(&self) -> Span {
         self.span
@@ -3667,6 +5656,30 @@ impl HasId for ForStatementBody {
     }
 }
 
+impl Fingerprint for ForStatementBody {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            ForStatementBody::Statement(inner) => {
+                fingerprinter.write_tag("Statement");
+                inner.fingerprint(fingerprinter);
+            }
+            ForStatementBody::Block(inner) => {
+                fingerprinter.write_tag("Block");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for ForStatementBody {
+    fn span(&self) -> Span {
+        match self {
+            ForStatementBody::Statement(inner) => inner.span(),
+            ForStatementBody::Block(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ForStatementBodyStatement {
     pub id: NodeId,
@@ -3680,6 +5693,12 @@ impl HasId for ForStatementBodyStatement {
     }
 }
 
+impl Fingerprint for ForStatementBodyStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.statement.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ForStatementBodyStatement {
     fn span(&self) -> Span {
         self.span
@@ -3702,6 +5721,13 @@ impl HasId for ForStatementBodyBlock {
     }
 }
 
+impl Fingerprint for ForStatementBodyBlock {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.statements.fingerprint(fingerprinter);
+        self.ending.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ForStatementBodyBlock {
     fn span(&self) -> Span {
         self.span
@@ -3727,6 +5753,13 @@ impl HasId for DoWhileStatement {
     }
 }
 
+impl Fingerprint for DoWhileStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.body.fingerprint(fingerprinter);
+        self.condition.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DoWhileStatement {
     fn span(&self) -> Span {
         self.span
@@ -3750,6 +5783,13 @@ impl HasId for WhileStatement {
     }
 }
 
+impl Fingerprint for WhileStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.condition.fingerprint(fingerprinter);
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for WhileStatement {
     fn span(&self) -> Span {
         self.span
@@ -3771,6 +5811,30 @@ impl HasId for WhileStatementBody {
     }
 }
 
+impl Fingerprint for WhileStatementBody {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            WhileStatementBody::Statement(inner) => {
+                fingerprinter.write_tag("Statement");
+                inner.fingerprint(fingerprinter);
+            }
+            WhileStatementBody::Block(inner) => {
+                fingerprinter.write_tag("Block");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for WhileStatementBody {
+    fn span(&self) -> Span {
+        match self {
+            WhileStatementBody::Statement(inner) => inner.span(),
+            WhileStatementBody::Block(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct WhileStatementBodyStatement {
     pub id: NodeId,
@@ -3784,6 +5848,12 @@ impl HasId for WhileStatementBodyStatement {
     }
 }
 
+impl Fingerprint for WhileStatementBodyStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.statement.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for WhileStatementBodyStatement {
     fn span(&self) -> Span {
         self.span
@@ -3806,6 +5876,13 @@ impl HasId for WhileStatementBodyBlock {
     }
 }
 
+impl Fingerprint for WhileStatementBodyBlock {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.statements.fingerprint(fingerprinter);
+        self.ending.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for WhileStatementBodyBlock {
     fn span(&self) -> Span {
         self.span
@@ -3827,6 +5904,30 @@ impl HasId for Level {
     }
 }
 
+impl Fingerprint for Level {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            Level::Literal(inner) => {
+                fingerprinter.write_tag("Literal");
+                inner.fingerprint(fingerprinter);
+            }
+            Level::Parenthesized(inner) => {
+                fingerprinter.write_tag("Parenthesized");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for Level {
+    fn span(&self) -> Span {
+        match self {
+            Level::Literal(inner) => inner.span(),
+            Level::Parenthesized(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct LiteralLevel {
     pub id: NodeId,
@@ -3839,6 +5940,18 @@ impl HasId for LiteralLevel {
     }
 }
 
+impl Fingerprint for LiteralLevel {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.literal.fingerprint(fingerprinter);
+    }
+}
+
+impl IsSpanned for LiteralLevel {
+    fn span(&self) -> Span {
+        self.literal.span()
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ParenthesizedLevel {
     pub id: NodeId,
@@ -3854,6 +5967,12 @@ impl HasId for ParenthesizedLevel {
     }
 }
 
+impl Fingerprint for ParenthesizedLevel {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.level.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ParenthesizedLevel {
     fn span(&self) -> Span {
         self.span
@@ -3875,6 +5994,13 @@ impl HasId for BreakStatement {
     }
 }
 
+impl Fingerprint for BreakStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.level.fingerprint(fingerprinter);
+        self.ending.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for BreakStatement {
     fn span(&self) -> Span {
         self.span
@@ -3896,6 +6022,13 @@ impl HasId for ContinueStatement {
     }
 }
 
+impl Fingerprint for ContinueStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.level.fingerprint(fingerprinter);
+        self.ending.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ContinueStatement {
     fn span(&self) -> Span {
         self.span
@@ -3909,13 +6042,22 @@ pub enum VisibilityModifier {
     Private(Span),
 }
 
+impl Fingerprint for VisibilityModifier {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            VisibilityModifier::Public(_) => fingerprinter.write_tag("Public"),
+            VisibilityModifier::Protected(_) => fingerprinter.write_tag("Protected"),
+            VisibilityModifier::Private(_) => fingerprinter.write_tag("Private"),
+        }
+    }
+}
+
 impl IsSpanned for VisibilityModifier {
     fn span(&self) -> Span {
         match self {
             VisibilityModifier::Public(span) => *span,
             VisibilityModifier::Protected(span) => *span,
             VisibilityModifier::Private(span) => *span,
-            _ => Span::default(),
         }
     }
 }
@@ -3931,6 +6073,20 @@ pub enum PromotedPropertyModifier {
     Readonly(Span),
 }
 
+impl Fingerprint for PromotedPropertyModifier {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            PromotedPropertyModifier::Public(_) => fingerprinter.write_tag("Public"),
+            PromotedPropertyModifier::Protected(_) => fingerprinter.write_tag("Protected"),
+            PromotedPropertyModifier::Private(_) => fingerprinter.write_tag("Private"),
+            PromotedPropertyModifier::PublicSet(_) => fingerprinter.write_tag("PublicSet"),
+            PromotedPropertyModifier::ProtectedSet(_) => fingerprinter.write_tag("ProtectedSet"),
+            PromotedPropertyModifier::PrivateSet(_) => fingerprinter.write_tag("PrivateSet"),
+            PromotedPropertyModifier::Readonly(_) => fingerprinter.write_tag("Readonly"),
+        }
+    }
+}
+
 impl IsSpanned for PromotedPropertyModifier {
     fn span(&self) -> Span {
         match self {
@@ -3941,7 +6097,6 @@ impl IsSpanned for PromotedPropertyModifier {
             PromotedPropertyModifier::ProtectedSet(span) => *span,
             PromotedPropertyModifier::PrivateSet(span) => *span,
             PromotedPropertyModifier::Readonly(span) => *span,
-            _ => Span::default(),
         }
     }
 }
@@ -3959,6 +6114,12 @@ impl HasId for PromotedPropertyModifierGroup {
     }
 }
 
+impl Fingerprint for PromotedPropertyModifierGroup {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.modifiers.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for PromotedPropertyModifierGroup {
     fn span(&self) -> Span {
         self.span
@@ -3977,6 +6138,21 @@ pub enum PropertyModifier {
     Readonly(Span),
 }
 
+impl Fingerprint for PropertyModifier {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            PropertyModifier::Public(_) => fingerprinter.write_tag("Public"),
+            PropertyModifier::Protected(_) => fingerprinter.write_tag("Protected"),
+            PropertyModifier::Private(_) => fingerprinter.write_tag("Private"),
+            PropertyModifier::PublicSet(_) => fingerprinter.write_tag("PublicSet"),
+            PropertyModifier::ProtectedSet(_) => fingerprinter.write_tag("ProtectedSet"),
+            PropertyModifier::PrivateSet(_) => fingerprinter.write_tag("PrivateSet"),
+            PropertyModifier::Static(_) => fingerprinter.write_tag("Static"),
+            PropertyModifier::Readonly(_) => fingerprinter.write_tag("Readonly"),
+        }
+    }
+}
+
 impl IsSpanned for PropertyModifier {
     fn span(&self) -> Span {
         match self {
@@ -3988,7 +6164,6 @@ impl IsSpanned for PropertyModifier {
             PropertyModifier::PrivateSet(span) => *span,
             PropertyModifier::Static(span) => *span,
             PropertyModifier::Readonly(span) => *span,
-            _ => Span::default(),
         }
     }
 }
@@ -4006,6 +6181,12 @@ impl HasId for PropertyModifierGroup {
     }
 }
 
+impl Fingerprint for PropertyModifierGroup {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.modifiers.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for PropertyModifierGroup {
     fn span(&self) -> Span {
         self.span
@@ -4022,6 +6203,19 @@ pub enum MethodModifier {
     Final(Span),
 }
 
+impl Fingerprint for MethodModifier {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            MethodModifier::Public(_) => fingerprinter.write_tag("Public"),
+            MethodModifier::Protected(_) => fingerprinter.write_tag("Protected"),
+            MethodModifier::Private(_) => fingerprinter.write_tag("Private"),
+            MethodModifier::Static(_) => fingerprinter.write_tag("Static"),
+            MethodModifier::Abstract(_) => fingerprinter.write_tag("Abstract"),
+            MethodModifier::Final(_) => fingerprinter.write_tag("Final"),
+        }
+    }
+}
+
 impl IsSpanned for MethodModifier {
     fn span(&self) -> Span {
         match self {
@@ -4031,7 +6225,6 @@ impl IsSpanned for MethodModifier {
             MethodModifier::Static(span) => *span,
             MethodModifier::Abstract(span) => *span,
             MethodModifier::Final(span) => *span,
-            _ => Span::default(),
         }
     }
 }
@@ -4049,6 +6242,12 @@ impl HasId for MethodModifierGroup {
     }
 }
 
+impl Fingerprint for MethodModifierGroup {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.modifiers.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for MethodModifierGroup {
     fn span(&self) -> Span {
         self.span
@@ -4062,13 +6261,22 @@ pub enum ClassModifier {
     Readonly(Span),
 }
 
+impl Fingerprint for ClassModifier {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            ClassModifier::Abstract(_) => fingerprinter.write_tag("Abstract"),
+            ClassModifier::Final(_) => fingerprinter.write_tag("Final"),
+            ClassModifier::Readonly(_) => fingerprinter.write_tag("Readonly"),
+        }
+    }
+}
+
 impl IsSpanned for ClassModifier {
     fn span(&self) -> Span {
         match self {
             ClassModifier::Abstract(span) => *span,
             ClassModifier::Final(span) => *span,
             ClassModifier::Readonly(span) => *span,
-            _ => Span::default(),
         }
     }
 }
@@ -4086,6 +6294,12 @@ impl HasId for ClassModifierGroup {
     }
 }
 
+impl Fingerprint for ClassModifierGroup {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.modifiers.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ClassModifierGroup {
     fn span(&self) -> Span {
         self.span
@@ -4100,6 +6314,17 @@ pub enum ConstantModifier {
     Final(Span),
 }
 
+impl Fingerprint for ConstantModifier {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            ConstantModifier::Public(_) => fingerprinter.write_tag("Public"),
+            ConstantModifier::Protected(_) => fingerprinter.write_tag("Protected"),
+            ConstantModifier::Private(_) => fingerprinter.write_tag("Private"),
+            ConstantModifier::Final(_) => fingerprinter.write_tag("Final"),
+        }
+    }
+}
+
 impl IsSpanned for ConstantModifier {
     fn span(&self) -> Span {
         match self {
@@ -4107,7 +6332,6 @@ impl IsSpanned for ConstantModifier {
             ConstantModifier::Protected(span) => *span,
             ConstantModifier::Private(span) => *span,
             ConstantModifier::Final(span) => *span,
-            _ => Span::default(),
         }
     }
 }
@@ -4125,6 +6349,12 @@ impl HasId for ConstantModifierGroup {
     }
 }
 
+impl Fingerprint for ConstantModifierGroup {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.modifiers.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ConstantModifierGroup {
     fn span(&self) -> Span {
         self.span
@@ -4147,6 +6377,13 @@ impl HasId for UnbracedNamespace {
     }
 }
 
+impl Fingerprint for UnbracedNamespace {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.name.fingerprint(fingerprinter);
+        self.statements.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for UnbracedNamespace {
     fn span(&self) -> Span {
         self.span
@@ -4168,6 +6405,13 @@ impl HasId for BracedNamespace {
     }
 }
 
+impl Fingerprint for BracedNamespace {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.name.fingerprint(fingerprinter);
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for BracedNamespace {
     fn span(&self) -> Span {
         self.span
@@ -4181,6 +6425,7 @@ pub struct BracedNamespaceBody {
     pub start: Span,
     pub end: Span,
     pub statements: Vec<Statement>,
+    pub trailing_comments: CommentGroup,
 }
 
 impl HasId for BracedNamespaceBody {
@@ -4189,6 +6434,12 @@ impl HasId for BracedNamespaceBody {
     }
 }
 
+impl Fingerprint for BracedNamespaceBody {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.statements.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for BracedNamespaceBody {
     fn span(&self) -> Span {
         self.span
@@ -4210,6 +6461,30 @@ impl HasId for NamespaceStatement {
     }
 }
 
+impl Fingerprint for NamespaceStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            NamespaceStatement::Unbraced(inner) => {
+                fingerprinter.write_tag("Unbraced");
+                inner.fingerprint(fingerprinter);
+            }
+            NamespaceStatement::Braced(inner) => {
+                fingerprinter.write_tag("Braced");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for NamespaceStatement {
+    fn span(&self) -> Span {
+        match self {
+            NamespaceStatement::Unbraced(inner) => inner.span(),
+            NamespaceStatement::Braced(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ArithmeticOperationExpression {
     pub id: NodeId,
@@ -4223,6 +6498,12 @@ impl HasId for ArithmeticOperationExpression {
     }
 }
 
+impl Fingerprint for ArithmeticOperationExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.kind.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ArithmeticOperationExpression {
     fn span(&self) -> Span {
         self.span
@@ -4318,6 +6599,110 @@ impl HasId for ArithmeticOperationKind {
     }
 }
 
+impl Fingerprint for ArithmeticOperationKind {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            ArithmeticOperationKind::Addition { left, right, .. } => {
+                fingerprinter.write_tag("Addition");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            ArithmeticOperationKind::Subtraction { left, right, .. } => {
+                fingerprinter.write_tag("Subtraction");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            ArithmeticOperationKind::Multiplication { left, right, .. } => {
+                fingerprinter.write_tag("Multiplication");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            ArithmeticOperationKind::Division { left, right, .. } => {
+                fingerprinter.write_tag("Division");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            ArithmeticOperationKind::Modulo { left, right, .. } => {
+                fingerprinter.write_tag("Modulo");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            ArithmeticOperationKind::Exponentiation { left, right, .. } => {
+                fingerprinter.write_tag("Exponentiation");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            ArithmeticOperationKind::Negative { right, .. } => {
+                fingerprinter.write_tag("Negative");
+                right.fingerprint(fingerprinter);
+            }
+            ArithmeticOperationKind::Positive { right, .. } => {
+                fingerprinter.write_tag("Positive");
+                right.fingerprint(fingerprinter);
+            }
+            ArithmeticOperationKind::PreIncrement { right, .. } => {
+                fingerprinter.write_tag("PreIncrement");
+                right.fingerprint(fingerprinter);
+            }
+            ArithmeticOperationKind::PostIncrement { left, .. } => {
+                fingerprinter.write_tag("PostIncrement");
+                left.fingerprint(fingerprinter);
+            }
+            ArithmeticOperationKind::PreDecrement { right, .. } => {
+                fingerprinter.write_tag("PreDecrement");
+                right.fingerprint(fingerprinter);
+            }
+            ArithmeticOperationKind::PostDecrement { left, .. } => {
+                fingerprinter.write_tag("PostDecrement");
+                left.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for ArithmeticOperationKind {
+    fn span(&self) -> Span {
+        match self {
+            ArithmeticOperationKind::Addition { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            ArithmeticOperationKind::Subtraction { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            ArithmeticOperationKind::Multiplication { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            ArithmeticOperationKind::Division { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            ArithmeticOperationKind::Modulo { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            ArithmeticOperationKind::Exponentiation { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            ArithmeticOperationKind::Negative { minus, right, .. } => {
+                Span::combine(minus.span(), right.span())
+            }
+            ArithmeticOperationKind::Positive { plus, right, .. } => {
+                Span::combine(plus.span(), right.span())
+            }
+            ArithmeticOperationKind::PreIncrement {
+                increment, right, ..
+            } => Span::combine(increment.span(), right.span()),
+            ArithmeticOperationKind::PostIncrement {
+                left, increment, ..
+            } => Span::combine(left.span(), increment.span()),
+            ArithmeticOperationKind::PreDecrement {
+                decrement, right, ..
+            } => Span::combine(decrement.span(), right.span()),
+            ArithmeticOperationKind::PostDecrement {
+                left, decrement, ..
+            } => Span::combine(left.span(), decrement.span()),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct AssignmentOperationExpression {
     pub id: NodeId,
@@ -4333,6 +6718,14 @@ impl HasId for AssignmentOperationExpression {
     }
 }
 
+impl Fingerprint for AssignmentOperationExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.left.fingerprint(fingerprinter);
+        self.kind.fingerprint(fingerprinter);
+        self.right.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for AssignmentOperationExpression {
     fn span(&self) -> Span {
         self.span
@@ -4357,6 +6750,27 @@ pub enum AssignmentOperationKind {
     Coalesce(Span),
 }
 
+impl Fingerprint for AssignmentOperationKind {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            AssignmentOperationKind::Assign(_) => fingerprinter.write_tag("Assign"),
+            AssignmentOperationKind::Addition(_) => fingerprinter.write_tag("Addition"),
+            AssignmentOperationKind::Subtraction(_) => fingerprinter.write_tag("Subtraction"),
+            AssignmentOperationKind::Multiplication(_) => fingerprinter.write_tag("Multiplication"),
+            AssignmentOperationKind::Division(_) => fingerprinter.write_tag("Division"),
+            AssignmentOperationKind::Modulo(_) => fingerprinter.write_tag("Modulo"),
+            AssignmentOperationKind::Exponentiation(_) => fingerprinter.write_tag("Exponentiation"),
+            AssignmentOperationKind::Concat(_) => fingerprinter.write_tag("Concat"),
+            AssignmentOperationKind::BitwiseAnd(_) => fingerprinter.write_tag("BitwiseAnd"),
+            AssignmentOperationKind::BitwiseOr(_) => fingerprinter.write_tag("BitwiseOr"),
+            AssignmentOperationKind::BitwiseXor(_) => fingerprinter.write_tag("BitwiseXor"),
+            AssignmentOperationKind::LeftShift(_) => fingerprinter.write_tag("LeftShift"),
+            AssignmentOperationKind::RightShift(_) => fingerprinter.write_tag("RightShift"),
+            AssignmentOperationKind::Coalesce(_) => fingerprinter.write_tag("Coalesce"),
+        }
+    }
+}
+
 impl IsSpanned for AssignmentOperationKind {
     fn span(&self) -> Span {
         match self {
@@ -4374,7 +6788,6 @@ impl IsSpanned for AssignmentOperationKind {
             AssignmentOperationKind::LeftShift(span) => *span,
             AssignmentOperationKind::RightShift(span) => *span,
             AssignmentOperationKind::Coalesce(span) => *span,
-            _ => Span::default(),
         }
     }
 }
@@ -4392,6 +6805,12 @@ impl HasId for BitwiseOperationExpression {
     }
 }
 
+impl Fingerprint for BitwiseOperationExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.kind.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for BitwiseOperationExpression {
     fn span(&self) -> Span {
         self.span
@@ -4450,6 +6869,65 @@ impl HasId for BitwiseOperationKind {
     }
 }
 
+impl Fingerprint for BitwiseOperationKind {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            BitwiseOperationKind::And { left, right, .. } => {
+                fingerprinter.write_tag("And");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            BitwiseOperationKind::Or { left, right, .. } => {
+                fingerprinter.write_tag("Or");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            BitwiseOperationKind::Xor { left, right, .. } => {
+                fingerprinter.write_tag("Xor");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            BitwiseOperationKind::LeftShift { left, right, .. } => {
+                fingerprinter.write_tag("LeftShift");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            BitwiseOperationKind::RightShift { left, right, .. } => {
+                fingerprinter.write_tag("RightShift");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            BitwiseOperationKind::Not { right, .. } => {
+                fingerprinter.write_tag("Not");
+                right.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for BitwiseOperationKind {
+    fn span(&self) -> Span {
+        match self {
+            BitwiseOperationKind::And { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            BitwiseOperationKind::Or { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            BitwiseOperationKind::Xor { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            BitwiseOperationKind::LeftShift { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            BitwiseOperationKind::RightShift { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            BitwiseOperationKind::Not { not, right, .. } => Span::combine(not.span(), right.span()),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ComparisonOperationExpression {
     pub id: NodeId,
@@ -4463,6 +6941,12 @@ impl HasId for ComparisonOperationExpression {
     }
 }
 
+impl Fingerprint for ComparisonOperationExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.kind.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ComparisonOperationExpression {
     fn span(&self) -> Span {
         self.span
@@ -4550,6 +7034,100 @@ impl HasId for ComparisonOperationKind {
     }
 }
 
+impl Fingerprint for ComparisonOperationKind {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            ComparisonOperationKind::Equal { left, right, .. } => {
+                fingerprinter.write_tag("Equal");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            ComparisonOperationKind::Identical { left, right, .. } => {
+                fingerprinter.write_tag("Identical");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            ComparisonOperationKind::NotEqual { left, right, .. } => {
+                fingerprinter.write_tag("NotEqual");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            ComparisonOperationKind::AngledNotEqual { left, right, .. } => {
+                fingerprinter.write_tag("AngledNotEqual");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            ComparisonOperationKind::NotIdentical { left, right, .. } => {
+                fingerprinter.write_tag("NotIdentical");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            ComparisonOperationKind::LessThan { left, right, .. } => {
+                fingerprinter.write_tag("LessThan");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            ComparisonOperationKind::GreaterThan { left, right, .. } => {
+                fingerprinter.write_tag("GreaterThan");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            ComparisonOperationKind::LessThanOrEqual { left, right, .. } => {
+                fingerprinter.write_tag("LessThanOrEqual");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            ComparisonOperationKind::GreaterThanOrEqual { left, right, .. } => {
+                fingerprinter.write_tag("GreaterThanOrEqual");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            ComparisonOperationKind::Spaceship { left, right, .. } => {
+                fingerprinter.write_tag("Spaceship");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for ComparisonOperationKind {
+    fn span(&self) -> Span {
+        match self {
+            ComparisonOperationKind::Equal { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            ComparisonOperationKind::Identical { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            ComparisonOperationKind::NotEqual { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            ComparisonOperationKind::AngledNotEqual { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            ComparisonOperationKind::NotIdentical { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            ComparisonOperationKind::LessThan { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            ComparisonOperationKind::GreaterThan { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            ComparisonOperationKind::LessThanOrEqual { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            ComparisonOperationKind::GreaterThanOrEqual { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            ComparisonOperationKind::Spaceship { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct LogicalOperationExpression {
     pub id: NodeId,
@@ -4563,6 +7141,12 @@ impl HasId for LogicalOperationExpression {
     }
 }
 
+impl Fingerprint for LogicalOperationExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.kind.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for LogicalOperationExpression {
     fn span(&self) -> Span {
         self.span
@@ -4621,8 +7205,69 @@ impl HasId for LogicalOperationKind {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-pub struct Name {
+impl Fingerprint for LogicalOperationKind {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            LogicalOperationKind::And { left, right, .. } => {
+                fingerprinter.write_tag("And");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            LogicalOperationKind::Or { left, right, .. } => {
+                fingerprinter.write_tag("Or");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            LogicalOperationKind::Not { right, .. } => {
+                fingerprinter.write_tag("Not");
+                right.fingerprint(fingerprinter);
+            }
+            LogicalOperationKind::LogicalAnd { left, right, .. } => {
+                fingerprinter.write_tag("LogicalAnd");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            LogicalOperationKind::LogicalOr { left, right, .. } => {
+                fingerprinter.write_tag("LogicalOr");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+            LogicalOperationKind::LogicalXor { left, right, .. } => {
+                fingerprinter.write_tag("LogicalXor");
+                left.fingerprint(fingerprinter);
+                right.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for LogicalOperationKind {
+    fn span(&self) -> Span {
+        match self {
+            LogicalOperationKind::And { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            LogicalOperationKind::Or { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            LogicalOperationKind::Not { bang, right, .. } => {
+                Span::combine(bang.span(), right.span())
+            }
+            LogicalOperationKind::LogicalAnd { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            LogicalOperationKind::LogicalOr { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+            LogicalOperationKind::LogicalXor { left, right, .. } => {
+                Span::combine(left.span(), right.span())
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Name {
     pub id: NodeId,
     pub kind: NameKind,
     pub span: Span,
@@ -4634,6 +7279,12 @@ impl HasId for Name {
     }
 }
 
+impl Fingerprint for Name {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.kind.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for Name {
     fn span(&self) -> Span {
         self.span
@@ -4647,12 +7298,38 @@ pub enum NameKind {
     Resolved(ResolvedName),
 }
 
+impl Fingerprint for NameKind {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            NameKind::Special(inner) => {
+                fingerprinter.write_tag("Special");
+                inner.fingerprint(fingerprinter);
+            }
+            NameKind::Unresolved(inner) => {
+                fingerprinter.write_tag("Unresolved");
+                inner.fingerprint(fingerprinter);
+            }
+            NameKind::Resolved(inner) => {
+                fingerprinter.write_tag("Resolved");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct SpecialName {
     pub kind: SpecialNameKind,
     pub symbol: ByteString,
 }
 
+impl Fingerprint for SpecialName {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.kind.fingerprint(fingerprinter);
+        self.symbol.fingerprint(fingerprinter);
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum SpecialNameKind {
     Self_,
@@ -4660,18 +7337,42 @@ pub enum SpecialNameKind {
     Static,
 }
 
+impl Fingerprint for SpecialNameKind {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            SpecialNameKind::Self_ => fingerprinter.write_tag("Self_"),
+            SpecialNameKind::Parent => fingerprinter.write_tag("Parent"),
+            SpecialNameKind::Static => fingerprinter.write_tag("Static"),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub struct UnresolvedName {
     pub symbol: ByteString,
     pub qualification: NameQualification,
 }
 
+impl Fingerprint for UnresolvedName {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.symbol.fingerprint(fingerprinter);
+        self.qualification.fingerprint(fingerprinter);
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub struct ResolvedName {
     pub resolved: ByteString,
     pub original: ByteString,
 }
 
+impl Fingerprint for ResolvedName {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.resolved.fingerprint(fingerprinter);
+        self.original.fingerprint(fingerprinter);
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Property {
     Simple(SimpleProperty),
@@ -4687,10 +7388,35 @@ impl HasId for Property {
     }
 }
 
+impl Fingerprint for Property {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            Property::Simple(inner) => {
+                fingerprinter.write_tag("Simple");
+                inner.fingerprint(fingerprinter);
+            }
+            Property::Hooked(inner) => {
+                fingerprinter.write_tag("Hooked");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for Property {
+    fn span(&self) -> Span {
+        match self {
+            Property::Simple(inner) => inner.span(),
+            Property::Hooked(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct SimpleProperty {
     pub id: NodeId,
     pub span: Span,
+    pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,
     pub modifiers: PropertyModifierGroup,
     pub var: Option<Span>,
@@ -4705,6 +7431,16 @@ impl HasId for SimpleProperty {
     }
 }
 
+impl Fingerprint for SimpleProperty {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.attributes.fingerprint(fingerprinter);
+        self.modifiers.fingerprint(fingerprinter);
+        self.var.fingerprint(fingerprinter);
+        self.r#type.fingerprint(fingerprinter);
+        self.entries.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for SimpleProperty {
     fn span(&self) -> Span {
         self.span
@@ -4715,6 +7451,7 @@ impl IsSpanned for SimpleProperty {
 pub struct HookedProperty {
     pub id: NodeId,
     pub span: Span,
+    pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,
     pub modifiers: PropertyModifierGroup,
     pub r#type: Option<DataType>,
@@ -4728,6 +7465,16 @@ impl HasId for HookedProperty {
     }
 }
 
+impl Fingerprint for HookedProperty {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.attributes.fingerprint(fingerprinter);
+        self.modifiers.fingerprint(fingerprinter);
+        self.r#type.fingerprint(fingerprinter);
+        self.entry.fingerprint(fingerprinter);
+        self.hooks.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for HookedProperty {
     fn span(&self) -> Span {
         self.span
@@ -4749,6 +7496,12 @@ impl HasId for PropertyHookList {
     }
 }
 
+impl Fingerprint for PropertyHookList {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.hooks.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for PropertyHookList {
     fn span(&self) -> Span {
         self.span
@@ -4770,6 +7523,14 @@ impl HasId for PropertyHook {
     }
 }
 
+impl Fingerprint for PropertyHook {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.kind.fingerprint(fingerprinter);
+        self.parameters.fingerprint(fingerprinter);
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for PropertyHook {
     fn span(&self) -> Span {
         self.span
@@ -4793,12 +7554,25 @@ impl HasId for PropertyHookBody {
     }
 }
 
+impl Fingerprint for PropertyHookBody {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            PropertyHookBody::Abstract(_) => fingerprinter.write_tag("Abstract"),
+            PropertyHookBody::Concrete(inner) => {
+                fingerprinter.write_tag("Concrete");
+                inner.fingerprint(fingerprinter);
+            }
+            PropertyHookBody::Invalid(_) => fingerprinter.write_tag("Invalid"),
+        }
+    }
+}
+
 impl IsSpanned for PropertyHookBody {
     fn span(&self) -> Span {
         match self {
             PropertyHookBody::Abstract(span) => *span,
+            PropertyHookBody::Concrete(inner) => inner.span(),
             PropertyHookBody::Invalid(span) => *span,
-            _ => Span::default(),
         }
     }
 }
@@ -4818,12 +7592,37 @@ impl HasId for ConcretePropertyHookBody {
     }
 }
 
+impl Fingerprint for ConcretePropertyHookBody {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            ConcretePropertyHookBody::Block(inner) => {
+                fingerprinter.write_tag("Block");
+                inner.fingerprint(fingerprinter);
+            }
+            ConcretePropertyHookBody::Expression(inner) => {
+                fingerprinter.write_tag("Expression");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for ConcretePropertyHookBody {
+    fn span(&self) -> Span {
+        match self {
+            ConcretePropertyHookBody::Block(inner) => inner.span(),
+            ConcretePropertyHookBody::Expression(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ConcretePropertyHookBodyBlock {
     pub id: NodeId,
     pub span: Span,
     pub left_brace: Span,
     pub body: Vec<Statement>,
+    pub trailing_comments: CommentGroup,
     pub right_brace: Span,
 }
 
@@ -4833,6 +7632,12 @@ impl HasId for ConcretePropertyHookBodyBlock {
     }
 }
 
+impl Fingerprint for ConcretePropertyHookBodyBlock {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ConcretePropertyHookBodyBlock {
     fn span(&self) -> Span {
         self.span
@@ -4854,6 +7659,12 @@ impl HasId for ConcretePropertyHookBodyExpression {
     }
 }
 
+impl Fingerprint for ConcretePropertyHookBodyExpression {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.expression.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ConcretePropertyHookBodyExpression {
     fn span(&self) -> Span {
         self.span
@@ -4867,13 +7678,22 @@ pub enum PropertyHookKind {
     Invalid(Span),
 }
 
+impl Fingerprint for PropertyHookKind {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            PropertyHookKind::Get(_) => fingerprinter.write_tag("Get"),
+            PropertyHookKind::Set(_) => fingerprinter.write_tag("Set"),
+            PropertyHookKind::Invalid(_) => fingerprinter.write_tag("Invalid"),
+        }
+    }
+}
+
 impl IsSpanned for PropertyHookKind {
     fn span(&self) -> Span {
         match self {
             PropertyHookKind::Get(span) => *span,
             PropertyHookKind::Set(span) => *span,
             PropertyHookKind::Invalid(span) => *span,
-            _ => Span::default(),
         }
     }
 }
@@ -4891,6 +7711,12 @@ impl HasId for PropertyEntry {
     }
 }
 
+impl Fingerprint for PropertyEntry {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.kind.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for PropertyEntry {
     fn span(&self) -> Span {
         self.span
@@ -4912,6 +7738,30 @@ impl HasId for PropertyEntryKind {
     }
 }
 
+impl Fingerprint for PropertyEntryKind {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            PropertyEntryKind::Uninitialized(inner) => {
+                fingerprinter.write_tag("Uninitialized");
+                inner.fingerprint(fingerprinter);
+            }
+            PropertyEntryKind::Initialized(inner) => {
+                fingerprinter.write_tag("Initialized");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for PropertyEntryKind {
+    fn span(&self) -> Span {
+        match self {
+            PropertyEntryKind::Uninitialized(inner) => inner.span(),
+            PropertyEntryKind::Initialized(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct UninitializedPropertyEntry {
     pub id: NodeId,
@@ -4925,6 +7775,12 @@ impl HasId for UninitializedPropertyEntry {
     }
 }
 
+impl Fingerprint for UninitializedPropertyEntry {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.variable.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for UninitializedPropertyEntry {
     fn span(&self) -> Span {
         self.span
@@ -4946,6 +7802,13 @@ impl HasId for InitializedPropertyEntry {
     }
 }
 
+impl Fingerprint for InitializedPropertyEntry {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.variable.fingerprint(fingerprinter);
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for InitializedPropertyEntry {
     fn span(&self) -> Span {
         self.span
@@ -4958,6 +7821,7 @@ pub struct TraitBody {
     pub span: Span,
     pub left_brace: Span,
     pub members: Vec<ClassishMember>,
+    pub trailing_comments: CommentGroup,
     pub right_brace: Span,
 }
 
@@ -4967,6 +7831,12 @@ impl HasId for TraitBody {
     }
 }
 
+impl Fingerprint for TraitBody {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.members.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for TraitBody {
     fn span(&self) -> Span {
         self.span
@@ -4989,6 +7859,14 @@ impl HasId for TraitStatement {
     }
 }
 
+impl Fingerprint for TraitStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.name.fingerprint(fingerprinter);
+        self.attributes.fingerprint(fingerprinter);
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for TraitStatement {
     fn span(&self) -> Span {
         self.span
@@ -5010,6 +7888,13 @@ impl HasId for TraitUsage {
     }
 }
 
+impl Fingerprint for TraitUsage {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.traits.fingerprint(fingerprinter);
+        self.adaptations.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for TraitUsage {
     fn span(&self) -> Span {
         self.span
@@ -5029,6 +7914,12 @@ impl HasId for TraitUsageAdaptation {
     }
 }
 
+impl Fingerprint for TraitUsageAdaptation {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.kind.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for TraitUsageAdaptation {
     fn span(&self) -> Span {
         self.span
@@ -5052,6 +7943,35 @@ impl HasId for TraitUsageAdaptationKind {
     }
 }
 
+impl Fingerprint for TraitUsageAdaptationKind {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            TraitUsageAdaptationKind::Alias(inner) => {
+                fingerprinter.write_tag("Alias");
+                inner.fingerprint(fingerprinter);
+            }
+            TraitUsageAdaptationKind::Visibility(inner) => {
+                fingerprinter.write_tag("Visibility");
+                inner.fingerprint(fingerprinter);
+            }
+            TraitUsageAdaptationKind::Precedence(inner) => {
+                fingerprinter.write_tag("Precedence");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for TraitUsageAdaptationKind {
+    fn span(&self) -> Span {
+        match self {
+            TraitUsageAdaptationKind::Alias(inner) => inner.span(),
+            TraitUsageAdaptationKind::Visibility(inner) => inner.span(),
+            TraitUsageAdaptationKind::Precedence(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct TraitUsageAdaptationAlias {
     pub id: NodeId,
@@ -5068,6 +7988,15 @@ impl HasId for TraitUsageAdaptationAlias {
     }
 }
 
+impl Fingerprint for TraitUsageAdaptationAlias {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.r#trait.fingerprint(fingerprinter);
+        self.method.fingerprint(fingerprinter);
+        self.alias.fingerprint(fingerprinter);
+        self.visibility.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for TraitUsageAdaptationAlias {
     fn span(&self) -> Span {
         self.span
@@ -5089,6 +8018,14 @@ impl HasId for TraitUsageAdaptationVisibility {
     }
 }
 
+impl Fingerprint for TraitUsageAdaptationVisibility {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.r#trait.fingerprint(fingerprinter);
+        self.method.fingerprint(fingerprinter);
+        self.visibility.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for TraitUsageAdaptationVisibility {
     fn span(&self) -> Span {
         self.span
@@ -5101,7 +8038,7 @@ pub struct TraitUsageAdaptationPrecedence {
     pub span: Span,
     pub r#trait: Option<Name>,
     pub method: SimpleIdentifier,
-    pub insteadof: Vec<SimpleIdentifier>,
+    pub insteadof: Vec<Name>,
 }
 
 impl HasId for TraitUsageAdaptationPrecedence {
@@ -5110,6 +8047,14 @@ impl HasId for TraitUsageAdaptationPrecedence {
     }
 }
 
+impl Fingerprint for TraitUsageAdaptationPrecedence {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.r#trait.fingerprint(fingerprinter);
+        self.method.fingerprint(fingerprinter);
+        self.insteadof.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for TraitUsageAdaptationPrecedence {
     fn span(&self) -> Span {
         self.span
@@ -5129,6 +8074,12 @@ impl HasId for CatchType {
     }
 }
 
+impl Fingerprint for CatchType {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.kind.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for CatchType {
     fn span(&self) -> Span {
         self.span
@@ -5150,11 +8101,35 @@ impl HasId for CatchTypeKind {
     }
 }
 
+impl Fingerprint for CatchTypeKind {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            CatchTypeKind::Identifier(inner) => {
+                fingerprinter.write_tag("Identifier");
+                inner.fingerprint(fingerprinter);
+            }
+            CatchTypeKind::Union(inner) => {
+                fingerprinter.write_tag("Union");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for CatchTypeKind {
+    fn span(&self) -> Span {
+        match self {
+            CatchTypeKind::Identifier(inner) => inner.span(),
+            CatchTypeKind::Union(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct CatchTypeKindIdentifier {
     pub id: NodeId,
     pub span: Span,
-    pub identifier: SimpleIdentifier,
+    pub name: Name,
 }
 
 impl HasId for CatchTypeKindIdentifier {
@@ -5163,6 +8138,12 @@ impl HasId for CatchTypeKindIdentifier {
     }
 }
 
+impl Fingerprint for CatchTypeKindIdentifier {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.name.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for CatchTypeKindIdentifier {
     fn span(&self) -> Span {
         self.span
@@ -5173,7 +8154,7 @@ impl IsSpanned for CatchTypeKindIdentifier {
 pub struct CatchTypeKindUnion {
     pub id: NodeId,
     pub span: Span,
-    pub identifiers: Vec<SimpleIdentifier>,
+    pub names: Vec<Name>,
 }
 
 impl HasId for CatchTypeKindUnion {
@@ -5182,6 +8163,12 @@ impl HasId for CatchTypeKindUnion {
     }
 }
 
+impl Fingerprint for CatchTypeKindUnion {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.names.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for CatchTypeKindUnion {
     fn span(&self) -> Span {
         self.span
@@ -5205,6 +8192,14 @@ impl HasId for TryStatement {
     }
 }
 
+impl Fingerprint for TryStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.body.fingerprint(fingerprinter);
+        self.catches.fingerprint(fingerprinter);
+        self.finally.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for TryStatement {
     fn span(&self) -> Span {
         self.span
@@ -5228,6 +8223,14 @@ impl HasId for CatchBlock {
     }
 }
 
+impl Fingerprint for CatchBlock {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.types.fingerprint(fingerprinter);
+        self.var.fingerprint(fingerprinter);
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for CatchBlock {
     fn span(&self) -> Span {
         self.span
@@ -5249,6 +8252,12 @@ impl HasId for FinallyBlock {
     }
 }
 
+impl Fingerprint for FinallyBlock {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.body.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for FinallyBlock {
     fn span(&self) -> Span {
         self.span
@@ -5272,6 +8281,35 @@ impl HasId for Variable {
     }
 }
 
+impl Fingerprint for Variable {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            Variable::SimpleVariable(inner) => {
+                fingerprinter.write_tag("SimpleVariable");
+                inner.fingerprint(fingerprinter);
+            }
+            Variable::VariableVariable(inner) => {
+                fingerprinter.write_tag("VariableVariable");
+                inner.fingerprint(fingerprinter);
+            }
+            Variable::BracedVariableVariable(inner) => {
+                fingerprinter.write_tag("BracedVariableVariable");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for Variable {
+    fn span(&self) -> Span {
+        match self {
+            Variable::SimpleVariable(inner) => inner.span(),
+            Variable::VariableVariable(inner) => inner.span(),
+            Variable::BracedVariableVariable(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub struct SimpleVariable {
     pub id: NodeId,
@@ -5286,6 +8324,13 @@ impl HasId for SimpleVariable {
     }
 }
 
+impl Fingerprint for SimpleVariable {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.symbol.fingerprint(fingerprinter);
+        self.stripped.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for SimpleVariable {
     fn span(&self) -> Span {
         self.span
@@ -5305,6 +8350,12 @@ impl HasId for VariableVariable {
     }
 }
 
+impl Fingerprint for VariableVariable {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.variable.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for VariableVariable {
     fn span(&self) -> Span {
         self.span
@@ -5326,6 +8377,12 @@ impl HasId for BracedVariableVariable {
     }
 }
 
+impl Fingerprint for BracedVariableVariable {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.variable.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for BracedVariableVariable {
     fn span(&self) -> Span {
         self.span
@@ -5339,13 +8396,22 @@ pub enum Ending {
     CloseTag(Span),
 }
 
+impl Fingerprint for Ending {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            Ending::Missing(_) => fingerprinter.write_tag("Missing"),
+            Ending::Semicolon(_) => fingerprinter.write_tag("Semicolon"),
+            Ending::CloseTag(_) => fingerprinter.write_tag("CloseTag"),
+        }
+    }
+}
+
 impl IsSpanned for Ending {
     fn span(&self) -> Span {
         match self {
             Ending::Missing(span) => *span,
             Ending::Semicolon(span) => *span,
             Ending::CloseTag(span) => *span,
-            _ => Span::default(),
         }
     }
 }
@@ -5364,6 +8430,12 @@ impl HasId for StaticStatement {
     }
 }
 
+impl Fingerprint for StaticStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.vars.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for StaticStatement {
     fn span(&self) -> Span {
         self.span
@@ -5387,6 +8459,13 @@ impl HasId for SwitchStatement {
     }
 }
 
+impl Fingerprint for SwitchStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.condition.fingerprint(fingerprinter);
+        self.cases.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for SwitchStatement {
     fn span(&self) -> Span {
         self.span
@@ -5408,6 +8487,13 @@ impl HasId for EchoStatement {
     }
 }
 
+impl Fingerprint for EchoStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.values.fingerprint(fingerprinter);
+        self.ending.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for EchoStatement {
     fn span(&self) -> Span {
         self.span
@@ -5429,6 +8515,13 @@ impl HasId for ReturnStatement {
     }
 }
 
+impl Fingerprint for ReturnStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.value.fingerprint(fingerprinter);
+        self.ending.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for ReturnStatement {
     fn span(&self) -> Span {
         self.span
@@ -5449,6 +8542,13 @@ impl HasId for UseStatement {
     }
 }
 
+impl Fingerprint for UseStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.kind.fingerprint(fingerprinter);
+        self.uses.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for UseStatement {
     fn span(&self) -> Span {
         self.span
@@ -5470,6 +8570,14 @@ impl HasId for GroupUseStatement {
     }
 }
 
+impl Fingerprint for GroupUseStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.prefix.fingerprint(fingerprinter);
+        self.kind.fingerprint(fingerprinter);
+        self.uses.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for GroupUseStatement {
     fn span(&self) -> Span {
         self.span
@@ -5489,6 +8597,12 @@ impl HasId for HaltCompilerStatement {
     }
 }
 
+impl Fingerprint for HaltCompilerStatement {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.content.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for HaltCompilerStatement {
     fn span(&self) -> Span {
         self.span
@@ -5509,6 +8623,13 @@ impl HasId for StaticVar {
     }
 }
 
+impl Fingerprint for StaticVar {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.var.fingerprint(fingerprinter);
+        self.default.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for StaticVar {
     fn span(&self) -> Span {
         self.span
@@ -5528,6 +8649,12 @@ impl HasId for Comment {
     }
 }
 
+impl Fingerprint for Comment {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.kind.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for Comment {
     fn span(&self) -> Span {
         self.span
@@ -5553,11 +8680,45 @@ impl HasId for CommentKind {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-pub struct SingleLineComment {
-    pub id: NodeId,
-    pub span: Span,
-    pub content: ByteString,
+impl Fingerprint for CommentKind {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            CommentKind::SingleLine(inner) => {
+                fingerprinter.write_tag("SingleLine");
+                inner.fingerprint(fingerprinter);
+            }
+            CommentKind::MultiLine(inner) => {
+                fingerprinter.write_tag("MultiLine");
+                inner.fingerprint(fingerprinter);
+            }
+            CommentKind::HashMark(inner) => {
+                fingerprinter.write_tag("HashMark");
+                inner.fingerprint(fingerprinter);
+            }
+            CommentKind::DocBlock(inner) => {
+                fingerprinter.write_tag("DocBlock");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for CommentKind {
+    fn span(&self) -> Span {
+        match self {
+            CommentKind::SingleLine(inner) => inner.span(),
+            CommentKind::MultiLine(inner) => inner.span(),
+            CommentKind::HashMark(inner) => inner.span(),
+            CommentKind::DocBlock(inner) => inner.span(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SingleLineComment {
+    pub id: NodeId,
+    pub span: Span,
+    pub content: ByteString,
 }
 
 impl HasId for SingleLineComment {
@@ -5566,6 +8727,12 @@ impl HasId for SingleLineComment {
     }
 }
 
+impl Fingerprint for SingleLineComment {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.content.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for SingleLineComment {
     fn span(&self) -> Span {
         self.span
@@ -5585,6 +8752,12 @@ impl HasId for MultiLineComment {
     }
 }
 
+impl Fingerprint for MultiLineComment {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.content.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for MultiLineComment {
     fn span(&self) -> Span {
         self.span
@@ -5604,6 +8777,12 @@ impl HasId for HashMarkComment {
     }
 }
 
+impl Fingerprint for HashMarkComment {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.content.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for HashMarkComment {
     fn span(&self) -> Span {
         self.span
@@ -5623,6 +8802,12 @@ impl HasId for DocBlockComment {
     }
 }
 
+impl Fingerprint for DocBlockComment {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.doc.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DocBlockComment {
     fn span(&self) -> Span {
         self.span
@@ -5642,6 +8827,12 @@ impl HasId for DocBlock {
     }
 }
 
+impl Fingerprint for DocBlock {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.nodes.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DocBlock {
     fn span(&self) -> Span {
         self.span
@@ -5663,6 +8854,30 @@ impl HasId for DocBlockNode {
     }
 }
 
+impl Fingerprint for DocBlockNode {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            DocBlockNode::Text(inner) => {
+                fingerprinter.write_tag("Text");
+                inner.fingerprint(fingerprinter);
+            }
+            DocBlockNode::Tag(inner) => {
+                fingerprinter.write_tag("Tag");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for DocBlockNode {
+    fn span(&self) -> Span {
+        match self {
+            DocBlockNode::Text(inner) => inner.span(),
+            DocBlockNode::Tag(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct DocBlockTextNode {
     pub id: NodeId,
@@ -5676,6 +8891,12 @@ impl HasId for DocBlockTextNode {
     }
 }
 
+impl Fingerprint for DocBlockTextNode {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.content.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DocBlockTextNode {
     fn span(&self) -> Span {
         self.span
@@ -5695,6 +8916,12 @@ impl HasId for DocBlockTagNode {
     }
 }
 
+impl Fingerprint for DocBlockTagNode {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.tag.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DocBlockTagNode {
     fn span(&self) -> Span {
         self.span
@@ -5738,6 +8965,85 @@ impl HasId for DocBlockTag {
     }
 }
 
+impl Fingerprint for DocBlockTag {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        match self {
+            DocBlockTag::ParamClosureThis(inner) => {
+                fingerprinter.write_tag("ParamClosureThis");
+                inner.fingerprint(fingerprinter);
+            }
+            DocBlockTag::Param(inner) => {
+                fingerprinter.write_tag("Param");
+                inner.fingerprint(fingerprinter);
+            }
+            DocBlockTag::Return(inner) => {
+                fingerprinter.write_tag("Return");
+                inner.fingerprint(fingerprinter);
+            }
+            DocBlockTag::Throws(inner) => {
+                fingerprinter.write_tag("Throws");
+                inner.fingerprint(fingerprinter);
+            }
+            DocBlockTag::Var(inner) => {
+                fingerprinter.write_tag("Var");
+                inner.fingerprint(fingerprinter);
+            }
+            DocBlockTag::Property(inner) => {
+                fingerprinter.write_tag("Property");
+                inner.fingerprint(fingerprinter);
+            }
+            DocBlockTag::Method(inner) => {
+                fingerprinter.write_tag("Method");
+                inner.fingerprint(fingerprinter);
+            }
+            DocBlockTag::Template(inner) => {
+                fingerprinter.write_tag("Template");
+                inner.fingerprint(fingerprinter);
+            }
+            DocBlockTag::Extends(inner) => {
+                fingerprinter.write_tag("Extends");
+                inner.fingerprint(fingerprinter);
+            }
+            DocBlockTag::Implements(inner) => {
+                fingerprinter.write_tag("Implements");
+                inner.fingerprint(fingerprinter);
+            }
+            DocBlockTag::Uses(inner) => {
+                fingerprinter.write_tag("Uses");
+                inner.fingerprint(fingerprinter);
+            }
+            DocBlockTag::Deprecated(inner) => {
+                fingerprinter.write_tag("Deprecated");
+                inner.fingerprint(fingerprinter);
+            }
+            DocBlockTag::Generic(inner) => {
+                fingerprinter.write_tag("Generic");
+                inner.fingerprint(fingerprinter);
+            }
+        }
+    }
+}
+
+impl IsSpanned for DocBlockTag {
+    fn span(&self) -> Span {
+        match self {
+            DocBlockTag::ParamClosureThis(inner) => inner.span(),
+            DocBlockTag::Param(inner) => inner.span(),
+            DocBlockTag::Return(inner) => inner.span(),
+            DocBlockTag::Throws(inner) => inner.span(),
+            DocBlockTag::Var(inner) => inner.span(),
+            DocBlockTag::Property(inner) => inner.span(),
+            DocBlockTag::Method(inner) => inner.span(),
+            DocBlockTag::Template(inner) => inner.span(),
+            DocBlockTag::Extends(inner) => inner.span(),
+            DocBlockTag::Implements(inner) => inner.span(),
+            DocBlockTag::Uses(inner) => inner.span(),
+            DocBlockTag::Deprecated(inner) => inner.span(),
+            DocBlockTag::Generic(inner) => inner.span(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct DocBlockParamClosureThisTag {
     pub id: NodeId,
@@ -5754,6 +9060,15 @@ impl HasId for DocBlockParamClosureThisTag {
     }
 }
 
+impl Fingerprint for DocBlockParamClosureThisTag {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.tag.fingerprint(fingerprinter);
+        self.r#type.fingerprint(fingerprinter);
+        self.variable.fingerprint(fingerprinter);
+        self.text.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DocBlockParamClosureThisTag {
     fn span(&self) -> Span {
         self.span
@@ -5778,6 +9093,17 @@ impl HasId for DocBlockParamTag {
     }
 }
 
+impl Fingerprint for DocBlockParamTag {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.tag.fingerprint(fingerprinter);
+        self.data_type.fingerprint(fingerprinter);
+        self.ampersand.fingerprint(fingerprinter);
+        self.ellipsis.fingerprint(fingerprinter);
+        self.variable.fingerprint(fingerprinter);
+        self.text.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DocBlockParamTag {
     fn span(&self) -> Span {
         self.span
@@ -5799,6 +9125,14 @@ impl HasId for DocBlockReturnTag {
     }
 }
 
+impl Fingerprint for DocBlockReturnTag {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.tag.fingerprint(fingerprinter);
+        self.data_type.fingerprint(fingerprinter);
+        self.text.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DocBlockReturnTag {
     fn span(&self) -> Span {
         self.span
@@ -5820,6 +9154,14 @@ impl HasId for DocBlockThrowsTag {
     }
 }
 
+impl Fingerprint for DocBlockThrowsTag {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.tag.fingerprint(fingerprinter);
+        self.data_type.fingerprint(fingerprinter);
+        self.text.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DocBlockThrowsTag {
     fn span(&self) -> Span {
         self.span
@@ -5842,6 +9184,15 @@ impl HasId for DocBlockVarTag {
     }
 }
 
+impl Fingerprint for DocBlockVarTag {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.tag.fingerprint(fingerprinter);
+        self.data_type.fingerprint(fingerprinter);
+        self.variable.fingerprint(fingerprinter);
+        self.text.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DocBlockVarTag {
     fn span(&self) -> Span {
         self.span
@@ -5864,6 +9215,15 @@ impl HasId for DocBlockPropertyTag {
     }
 }
 
+impl Fingerprint for DocBlockPropertyTag {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.tag.fingerprint(fingerprinter);
+        self.data_type.fingerprint(fingerprinter);
+        self.variable.fingerprint(fingerprinter);
+        self.text.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DocBlockPropertyTag {
     fn span(&self) -> Span {
         self.span
@@ -5889,6 +9249,18 @@ impl HasId for DocBlockMethodTag {
     }
 }
 
+impl Fingerprint for DocBlockMethodTag {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.tag.fingerprint(fingerprinter);
+        self.r#static.fingerprint(fingerprinter);
+        self.return_type.fingerprint(fingerprinter);
+        self.name.fingerprint(fingerprinter);
+        self.templates.fingerprint(fingerprinter);
+        self.parameters.fingerprint(fingerprinter);
+        self.text.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DocBlockMethodTag {
     fn span(&self) -> Span {
         self.span
@@ -5912,6 +9284,16 @@ impl HasId for DocBlockTemplateTagValue {
     }
 }
 
+impl Fingerprint for DocBlockTemplateTagValue {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.template.fingerprint(fingerprinter);
+        self.bound.fingerprint(fingerprinter);
+        self.default.fingerprint(fingerprinter);
+        self.lower_bound.fingerprint(fingerprinter);
+        self.description.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DocBlockTemplateTagValue {
     fn span(&self) -> Span {
         self.span
@@ -5932,6 +9314,13 @@ impl HasId for DocBlockTemplateTag {
     }
 }
 
+impl Fingerprint for DocBlockTemplateTag {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.tag.fingerprint(fingerprinter);
+        self.value.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DocBlockTemplateTag {
     fn span(&self) -> Span {
         self.span
@@ -5953,6 +9342,14 @@ impl HasId for DocBlockExtendsTag {
     }
 }
 
+impl Fingerprint for DocBlockExtendsTag {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.tag.fingerprint(fingerprinter);
+        self.data_type.fingerprint(fingerprinter);
+        self.text.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DocBlockExtendsTag {
     fn span(&self) -> Span {
         self.span
@@ -5974,6 +9371,14 @@ impl HasId for DocBlockImplementsTag {
     }
 }
 
+impl Fingerprint for DocBlockImplementsTag {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.tag.fingerprint(fingerprinter);
+        self.data_type.fingerprint(fingerprinter);
+        self.text.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DocBlockImplementsTag {
     fn span(&self) -> Span {
         self.span
@@ -5995,6 +9400,14 @@ impl HasId for DocBlockUsesTag {
     }
 }
 
+impl Fingerprint for DocBlockUsesTag {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.tag.fingerprint(fingerprinter);
+        self.data_type.fingerprint(fingerprinter);
+        self.text.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DocBlockUsesTag {
     fn span(&self) -> Span {
         self.span
@@ -6015,6 +9428,13 @@ impl HasId for DocBlockDeprecatedTag {
     }
 }
 
+impl Fingerprint for DocBlockDeprecatedTag {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.tag.fingerprint(fingerprinter);
+        self.text.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DocBlockDeprecatedTag {
     fn span(&self) -> Span {
         self.span
@@ -6035,6 +9455,13 @@ impl HasId for DocBlockGenericTag {
     }
 }
 
+impl Fingerprint for DocBlockGenericTag {
+    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {
+        self.tag.fingerprint(fingerprinter);
+        self.text.fingerprint(fingerprinter);
+    }
+}
+
 impl IsSpanned for DocBlockGenericTag {
     fn span(&self) -> Span {
         self.span
@@ -6053,6 +9480,12 @@ impl HasId for CommentGroup {
     }
 }
 
+impl IsSpanned for CommentGroup {
+    fn span(&self) -> Span {
+        self.comments.span()
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum NodeKind<'a> {
     Block(&'a Block),
@@ -6181,7 +9614,7 @@ pub enum NodeKind<'a> {
     DeclareBody(&'a DeclareBody),
     DeclareBodyNoop(&'a DeclareBodyNoop),
     DeclareBodyBraced(&'a DeclareBodyBraced),
-    DeclareBodyExpression(&'a DeclareBodyExpression),
+    DeclareBodyStatement(&'a DeclareBodyStatement),
     DeclareBodyBlock(&'a DeclareBodyBlock),
     DeclareStatement(&'a DeclareStatement),
     UnitEnumCase(&'a UnitEnumCase),
@@ -7702,15 +11135,15 @@ impl<'a> Node<'a> {
         matches!(&self.kind, NodeKind::DeclareBodyBraced(_))
     }
 
-    pub fn as_declare_body_expression(self) -> Option<&'a DeclareBodyExpression> {
+    pub fn as_declare_body_statement(self) -> Option<&'a DeclareBodyStatement> {
         match &self.kind {
-            NodeKind::DeclareBodyExpression(node) => Some(node),
+            NodeKind::DeclareBodyStatement(node) => Some(node),
             _ => None,
         }
     }
 
-    pub fn is_declare_body_expression(&self) -> bool {
-        matches!(&self.kind, NodeKind::DeclareBodyExpression(_))
+    pub fn is_declare_body_statement(&self) -> bool {
+        matches!(&self.kind, NodeKind::DeclareBodyStatement(_))
     }
 
     pub fn as_declare_body_block(self) -> Option<&'a DeclareBodyBlock> {
@@ -9336,7 +12769,7 @@ impl<'a> Node<'a> {
             NodeKind::DeclareBody(_) => "DeclareBody",
             NodeKind::DeclareBodyNoop(_) => "DeclareBodyNoop",
             NodeKind::DeclareBodyBraced(_) => "DeclareBodyBraced",
-            NodeKind::DeclareBodyExpression(_) => "DeclareBodyExpression",
+            NodeKind::DeclareBodyStatement(_) => "DeclareBodyStatement",
             NodeKind::DeclareBodyBlock(_) => "DeclareBodyBlock",
             NodeKind::DeclareStatement(_) => "DeclareStatement",
             NodeKind::UnitEnumCase(_) => "UnitEnumCase",
@@ -9946,14 +13379,8 @@ impl<'a> Node<'a> {
                 children.push(x.into());
             }
             NodeKind::PrintExpression(node) => {
-                if let Some(child) = &node.value {
-                    let child = child.as_ref();
-                    children.push(child.into());
-                }
-                if let Some(child) = &node.argument {
-                    let child = child.as_ref();
-                    children.push(child.into());
-                }
+                let x = node.value.as_ref();
+                children.push(x.into());
             }
             NodeKind::ConcatExpression(node) => {
                 let x = node.left.as_ref();
@@ -10426,6 +13853,9 @@ impl<'a> Node<'a> {
                 children.push(x.into());
             }
             NodeKind::ConstantStatement(node) => {
+                for x in &node.attributes {
+                    children.push(x.into());
+                }
                 for x in &node.entries {
                     children.push(x.into());
                 }
@@ -10518,7 +13948,7 @@ impl<'a> Node<'a> {
                 DeclareBody::Braced(inner) => {
                     children.push(inner.into());
                 }
-                DeclareBody::Expression(inner) => {
+                DeclareBody::Statement(inner) => {
                     children.push(inner.into());
                 }
                 DeclareBody::Block(inner) => {
@@ -10531,8 +13961,8 @@ impl<'a> Node<'a> {
                     children.push(x.into());
                 }
             }
-            NodeKind::DeclareBodyExpression(node) => {
-                let x = &node.expression;
+            NodeKind::DeclareBodyStatement(node) => {
+                let x = node.statement.as_ref();
                 children.push(x.into());
             }
             NodeKind::DeclareBodyBlock(node) => {
@@ -10757,6 +14187,15 @@ impl<'a> Node<'a> {
                 let x = &node.value;
                 children.push(x.into());
             }
+            NodeKind::ForeachStatementBody(node) => match node {
+                ForeachStatementBody::Statement(inner) => {
+                    children.push(inner.into());
+                }
+                ForeachStatementBody::Block(inner) => {
+                    children.push(inner.into());
+                }
+                _ => {}
+            },
             NodeKind::ForeachStatementBodyStatement(node) => {
                 let x = node.statement.as_ref();
                 children.push(x.into());
@@ -10783,6 +14222,15 @@ impl<'a> Node<'a> {
                     children.push(x.into());
                 }
             }
+            NodeKind::ForStatementBody(node) => match node {
+                ForStatementBody::Statement(inner) => {
+                    children.push(inner.into());
+                }
+                ForStatementBody::Block(inner) => {
+                    children.push(inner.into());
+                }
+                _ => {}
+            },
             NodeKind::ForStatementBodyStatement(node) => {
                 let x = node.statement.as_ref();
                 children.push(x.into());
@@ -11327,11 +14775,11 @@ impl<'a> Node<'a> {
                 children.push(x.into());
             }
             NodeKind::CatchTypeKindIdentifier(node) => {
-                let x = &node.identifier;
+                let x = &node.name;
                 children.push(x.into());
             }
             NodeKind::CatchTypeKindUnion(node) => {
-                for x in &node.identifiers {
+                for x in &node.names {
                     children.push(x.into());
                 }
             }
@@ -11634,7 +15082,7 @@ impl<'a> Node<'a> {
             NodeKind::DeclareBody(node) => NonNull::from(node).cast(),
             NodeKind::DeclareBodyNoop(node) => NonNull::from(node).cast(),
             NodeKind::DeclareBodyBraced(node) => NonNull::from(node).cast(),
-            NodeKind::DeclareBodyExpression(node) => NonNull::from(node).cast(),
+            NodeKind::DeclareBodyStatement(node) => NonNull::from(node).cast(),
             NodeKind::DeclareBodyBlock(node) => NonNull::from(node).cast(),
             NodeKind::DeclareStatement(node) => NonNull::from(node).cast(),
             NodeKind::UnitEnumCase(node) => NonNull::from(node).cast(),
@@ -12628,13 +16076,9 @@ impl<'a> From<&'a DeclareBodyBraced> for Node<'a> {
     }
 }
 
-impl<'a> From<&'a DeclareBodyExpression> for Node<'a> {
-    fn from(node: &'a DeclareBodyExpression) -> Self {
-        Node::new(
-            node.id(),
-            NodeKind::DeclareBodyExpression(node),
-            node.span(),
-        )
+impl<'a> From<&'a DeclareBodyStatement> for Node<'a> {
+    fn from(node: &'a DeclareBodyStatement) -> Self {
+        Node::new(node.id(), NodeKind::DeclareBodyStatement(node), node.span())
     }
 }
 
@@ -13595,3 +17039,1503 @@ impl<'a> From<&'a CommentGroup> for Node<'a> {
         Node::new(node.id(), NodeKind::CommentGroup(node), node.span())
     }
 }
+
+impl Downcast for Block {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_block()
+    }
+}
+
+impl Downcast for Statement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_statement()
+    }
+}
+
+impl Downcast for StatementKind {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_statement_kind()
+    }
+}
+
+impl Downcast for Expression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_expression()
+    }
+}
+
+impl Downcast for ExpressionKind {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_expression_kind()
+    }
+}
+
+impl Downcast for MissingExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_missing_expression()
+    }
+}
+
+impl Downcast for StaticExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_static_expression()
+    }
+}
+
+impl Downcast for SelfExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_self_expression()
+    }
+}
+
+impl Downcast for ParentExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_parent_expression()
+    }
+}
+
+impl Downcast for CommentStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_comment_statement()
+    }
+}
+
+impl Downcast for InlineHtmlStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_inline_html_statement()
+    }
+}
+
+impl Downcast for FullOpeningTagStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_full_opening_tag_statement()
+    }
+}
+
+impl Downcast for ShortOpeningTagStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_short_opening_tag_statement()
+    }
+}
+
+impl Downcast for EchoOpeningTagStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_echo_opening_tag_statement()
+    }
+}
+
+impl Downcast for ClosingTagStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_closing_tag_statement()
+    }
+}
+
+impl Downcast for ExpressionStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_expression_statement()
+    }
+}
+
+impl Downcast for GlobalStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_global_statement()
+    }
+}
+
+impl Downcast for BlockStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_block_statement()
+    }
+}
+
+impl Downcast for Case {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_case()
+    }
+}
+
+impl Downcast for Use {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_use()
+    }
+}
+
+impl Downcast for EvalExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_eval_expression()
+    }
+}
+
+impl Downcast for EmptyExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_empty_expression()
+    }
+}
+
+impl Downcast for DieExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_die_expression()
+    }
+}
+
+impl Downcast for ExitExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_exit_expression()
+    }
+}
+
+impl Downcast for IssetExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_isset_expression()
+    }
+}
+
+impl Downcast for UnsetExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_unset_expression()
+    }
+}
+
+impl Downcast for PrintExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_print_expression()
+    }
+}
+
+impl Downcast for ConcatExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_concat_expression()
+    }
+}
+
+impl Downcast for InstanceofExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_instanceof_expression()
+    }
+}
+
+impl Downcast for ReferenceExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_reference_expression()
+    }
+}
+
+impl Downcast for ParenthesizedExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_parenthesized_expression()
+    }
+}
+
+impl Downcast for ErrorSuppressExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_error_suppress_expression()
+    }
+}
+
+impl Downcast for IncludeExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_include_expression()
+    }
+}
+
+impl Downcast for IncludeOnceExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_include_once_expression()
+    }
+}
+
+impl Downcast for RequireExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_require_expression()
+    }
+}
+
+impl Downcast for RequireOnceExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_require_once_expression()
+    }
+}
+
+impl Downcast for FunctionCallExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_function_call_expression()
+    }
+}
+
+impl Downcast for MethodCallExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_method_call_expression()
+    }
+}
+
+impl Downcast for NullsafeMethodCallExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_nullsafe_method_call_expression()
+    }
+}
+
+impl Downcast for StaticMethodCallExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_static_method_call_expression()
+    }
+}
+
+impl Downcast for PropertyFetchExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_property_fetch_expression()
+    }
+}
+
+impl Downcast for StaticPropertyFetchExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_static_property_fetch_expression()
+    }
+}
+
+impl Downcast for ConstantFetchExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_constant_fetch_expression()
+    }
+}
+
+impl Downcast for ArrayExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_array_expression()
+    }
+}
+
+impl Downcast for ListExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_list_expression()
+    }
+}
+
+impl Downcast for NewExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_new_expression()
+    }
+}
+
+impl Downcast for InterpolatedStringExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_interpolated_string_expression()
+    }
+}
+
+impl Downcast for HeredocExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_heredoc_expression()
+    }
+}
+
+impl Downcast for NowdocExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_nowdoc_expression()
+    }
+}
+
+impl Downcast for ShellExecExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_shell_exec_expression()
+    }
+}
+
+impl Downcast for BoolExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_bool_expression()
+    }
+}
+
+impl Downcast for ArrayIndexExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_array_index_expression()
+    }
+}
+
+impl Downcast for ShortTernaryExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_short_ternary_expression()
+    }
+}
+
+impl Downcast for TernaryExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_ternary_expression()
+    }
+}
+
+impl Downcast for CoalesceExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_coalesce_expression()
+    }
+}
+
+impl Downcast for CloneExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_clone_expression()
+    }
+}
+
+impl Downcast for MatchExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_match_expression()
+    }
+}
+
+impl Downcast for ThrowExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_throw_expression()
+    }
+}
+
+impl Downcast for YieldExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_yield_expression()
+    }
+}
+
+impl Downcast for YieldFromExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_yield_from_expression()
+    }
+}
+
+impl Downcast for CastExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_cast_expression()
+    }
+}
+
+impl Downcast for DefaultMatchArm {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_default_match_arm()
+    }
+}
+
+impl Downcast for MatchArm {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_match_arm()
+    }
+}
+
+impl Downcast for MagicConstantExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_magic_constant_expression()
+    }
+}
+
+impl Downcast for StringPart {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_string_part()
+    }
+}
+
+impl Downcast for LiteralStringPart {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_literal_string_part()
+    }
+}
+
+impl Downcast for ExpressionStringPart {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_expression_string_part()
+    }
+}
+
+impl Downcast for ArrayItem {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_array_item()
+    }
+}
+
+impl Downcast for ArrayItemValue {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_array_item_value()
+    }
+}
+
+impl Downcast for ArrayItemReferencedValue {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_array_item_referenced_value()
+    }
+}
+
+impl Downcast for ArrayItemSpreadValue {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_array_item_spread_value()
+    }
+}
+
+impl Downcast for ArrayItemKeyValue {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_array_item_key_value()
+    }
+}
+
+impl Downcast for ArrayItemReferencedKeyValue {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_array_item_referenced_key_value()
+    }
+}
+
+impl Downcast for ListEntry {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_list_entry()
+    }
+}
+
+impl Downcast for ListEntryValue {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_list_entry_value()
+    }
+}
+
+impl Downcast for ListEntryKeyValue {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_list_entry_key_value()
+    }
+}
+
+impl Downcast for PositionalArgument {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_positional_argument()
+    }
+}
+
+impl Downcast for NamedArgument {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_named_argument()
+    }
+}
+
+impl Downcast for Argument {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_argument()
+    }
+}
+
+impl Downcast for ArgumentList {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_argument_list()
+    }
+}
+
+impl Downcast for SingleArgument {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_single_argument()
+    }
+}
+
+impl Downcast for ArgumentPlaceholder {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_argument_placeholder()
+    }
+}
+
+impl Downcast for Attribute {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_attribute()
+    }
+}
+
+impl Downcast for AttributeGroup {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_attribute_group()
+    }
+}
+
+impl Downcast for ClassBody {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_class_body()
+    }
+}
+
+impl Downcast for ClassStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_class_statement()
+    }
+}
+
+impl Downcast for AnonymousClassBody {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_anonymous_class_body()
+    }
+}
+
+impl Downcast for AnonymousClassExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_anonymous_class_expression()
+    }
+}
+
+impl Downcast for ClassExtends {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_class_extends()
+    }
+}
+
+impl Downcast for ClassImplements {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_class_implements()
+    }
+}
+
+impl Downcast for ClassishMember {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_classish_member()
+    }
+}
+
+impl Downcast for Method {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_method()
+    }
+}
+
+impl Downcast for MethodBody {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_method_body()
+    }
+}
+
+impl Downcast for MethodBodyKind {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_method_body_kind()
+    }
+}
+
+impl Downcast for MissingMethodBody {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_missing_method_body()
+    }
+}
+
+impl Downcast for AbstractMethodBody {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_abstract_method_body()
+    }
+}
+
+impl Downcast for ConcreteMethodBody {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_concrete_method_body()
+    }
+}
+
+impl Downcast for MethodParameterList {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_method_parameter_list()
+    }
+}
+
+impl Downcast for MethodParameter {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_method_parameter()
+    }
+}
+
+impl Downcast for MissingClassishMember {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_missing_classish_member()
+    }
+}
+
+impl Downcast for ConstantEntry {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_constant_entry()
+    }
+}
+
+impl Downcast for ClassishConstantEntry {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_classish_constant_entry()
+    }
+}
+
+impl Downcast for ConstantStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_constant_statement()
+    }
+}
+
+impl Downcast for ClassishConstant {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_classish_constant()
+    }
+}
+
+impl Downcast for IfStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_if_statement()
+    }
+}
+
+impl Downcast for IfStatementBody {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_if_statement_body()
+    }
+}
+
+impl Downcast for IfStatementBodyStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_if_statement_body_statement()
+    }
+}
+
+impl Downcast for IfStatementBodyBlock {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_if_statement_body_block()
+    }
+}
+
+impl Downcast for IfStatementElseIf {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_if_statement_else_if()
+    }
+}
+
+impl Downcast for IfStatementElse {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_if_statement_else()
+    }
+}
+
+impl Downcast for IfStatementElseIfBlock {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_if_statement_else_if_block()
+    }
+}
+
+impl Downcast for IfStatementElseBlock {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_if_statement_else_block()
+    }
+}
+
+impl Downcast for DataType {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_data_type()
+    }
+}
+
+impl Downcast for DeclareEntry {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_declare_entry()
+    }
+}
+
+impl Downcast for DeclareEntryGroup {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_declare_entry_group()
+    }
+}
+
+impl Downcast for DeclareBody {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_declare_body()
+    }
+}
+
+impl Downcast for DeclareBodyNoop {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_declare_body_noop()
+    }
+}
+
+impl Downcast for DeclareBodyBraced {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_declare_body_braced()
+    }
+}
+
+impl Downcast for DeclareBodyStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_declare_body_statement()
+    }
+}
+
+impl Downcast for DeclareBodyBlock {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_declare_body_block()
+    }
+}
+
+impl Downcast for DeclareStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_declare_statement()
+    }
+}
+
+impl Downcast for UnitEnumCase {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_unit_enum_case()
+    }
+}
+
+impl Downcast for UnitEnumMember {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_unit_enum_member()
+    }
+}
+
+impl Downcast for UnitEnumBody {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_unit_enum_body()
+    }
+}
+
+impl Downcast for UnitEnumStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_unit_enum_statement()
+    }
+}
+
+impl Downcast for BackedEnumCase {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_backed_enum_case()
+    }
+}
+
+impl Downcast for BackedEnumMember {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_backed_enum_member()
+    }
+}
+
+impl Downcast for BackedEnumBody {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_backed_enum_body()
+    }
+}
+
+impl Downcast for BackedEnumStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_backed_enum_statement()
+    }
+}
+
+impl Downcast for ReturnType {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_return_type()
+    }
+}
+
+impl Downcast for FunctionParameter {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_function_parameter()
+    }
+}
+
+impl Downcast for FunctionParameterList {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_function_parameter_list()
+    }
+}
+
+impl Downcast for FunctionBody {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_function_body()
+    }
+}
+
+impl Downcast for FunctionStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_function_statement()
+    }
+}
+
+impl Downcast for ClosureUseVariable {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_closure_use_variable()
+    }
+}
+
+impl Downcast for ClosureUse {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_closure_use()
+    }
+}
+
+impl Downcast for ClosureExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_closure_expression()
+    }
+}
+
+impl Downcast for ArrowFunctionExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_arrow_function_expression()
+    }
+}
+
+impl Downcast for LabelStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_label_statement()
+    }
+}
+
+impl Downcast for GotoStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_goto_statement()
+    }
+}
+
+impl Downcast for Identifier {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_identifier()
+    }
+}
+
+impl Downcast for SimpleIdentifier {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_simple_identifier()
+    }
+}
+
+impl Downcast for DynamicIdentifier {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_dynamic_identifier()
+    }
+}
+
+impl Downcast for InterfaceExtends {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_interface_extends()
+    }
+}
+
+impl Downcast for InterfaceBody {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_interface_body()
+    }
+}
+
+impl Downcast for InterfaceStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_interface_statement()
+    }
+}
+
+impl Downcast for Literal {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_literal()
+    }
+}
+
+impl Downcast for ForeachStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_foreach_statement()
+    }
+}
+
+impl Downcast for ForeachStatementIterator {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_foreach_statement_iterator()
+    }
+}
+
+impl Downcast for ForeachStatementIteratorValue {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_foreach_statement_iterator_value()
+    }
+}
+
+impl Downcast for ForeachStatementBody {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_foreach_statement_body()
+    }
+}
+
+impl Downcast for ForeachStatementBodyStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_foreach_statement_body_statement()
+    }
+}
+
+impl Downcast for ForeachStatementBodyBlock {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_foreach_statement_body_block()
+    }
+}
+
+impl Downcast for ForStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_for_statement()
+    }
+}
+
+impl Downcast for ForStatementIterator {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_for_statement_iterator()
+    }
+}
+
+impl Downcast for ForStatementBody {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_for_statement_body()
+    }
+}
+
+impl Downcast for ForStatementBodyStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_for_statement_body_statement()
+    }
+}
+
+impl Downcast for ForStatementBodyBlock {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_for_statement_body_block()
+    }
+}
+
+impl Downcast for DoWhileStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_do_while_statement()
+    }
+}
+
+impl Downcast for WhileStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_while_statement()
+    }
+}
+
+impl Downcast for WhileStatementBody {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_while_statement_body()
+    }
+}
+
+impl Downcast for WhileStatementBodyStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_while_statement_body_statement()
+    }
+}
+
+impl Downcast for WhileStatementBodyBlock {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_while_statement_body_block()
+    }
+}
+
+impl Downcast for Level {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_level()
+    }
+}
+
+impl Downcast for LiteralLevel {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_literal_level()
+    }
+}
+
+impl Downcast for ParenthesizedLevel {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_parenthesized_level()
+    }
+}
+
+impl Downcast for BreakStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_break_statement()
+    }
+}
+
+impl Downcast for ContinueStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_continue_statement()
+    }
+}
+
+impl Downcast for PromotedPropertyModifierGroup {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_promoted_property_modifier_group()
+    }
+}
+
+impl Downcast for PropertyModifierGroup {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_property_modifier_group()
+    }
+}
+
+impl Downcast for MethodModifierGroup {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_method_modifier_group()
+    }
+}
+
+impl Downcast for ClassModifierGroup {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_class_modifier_group()
+    }
+}
+
+impl Downcast for ConstantModifierGroup {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_constant_modifier_group()
+    }
+}
+
+impl Downcast for UnbracedNamespace {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_unbraced_namespace()
+    }
+}
+
+impl Downcast for BracedNamespace {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_braced_namespace()
+    }
+}
+
+impl Downcast for BracedNamespaceBody {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_braced_namespace_body()
+    }
+}
+
+impl Downcast for NamespaceStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_namespace_statement()
+    }
+}
+
+impl Downcast for ArithmeticOperationExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_arithmetic_operation_expression()
+    }
+}
+
+impl Downcast for ArithmeticOperationKind {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_arithmetic_operation_kind()
+    }
+}
+
+impl Downcast for AssignmentOperationExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_assignment_operation_expression()
+    }
+}
+
+impl Downcast for BitwiseOperationExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_bitwise_operation_expression()
+    }
+}
+
+impl Downcast for BitwiseOperationKind {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_bitwise_operation_kind()
+    }
+}
+
+impl Downcast for ComparisonOperationExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_comparison_operation_expression()
+    }
+}
+
+impl Downcast for ComparisonOperationKind {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_comparison_operation_kind()
+    }
+}
+
+impl Downcast for LogicalOperationExpression {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_logical_operation_expression()
+    }
+}
+
+impl Downcast for LogicalOperationKind {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_logical_operation_kind()
+    }
+}
+
+impl Downcast for Name {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_name()
+    }
+}
+
+impl Downcast for Property {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_property()
+    }
+}
+
+impl Downcast for SimpleProperty {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_simple_property()
+    }
+}
+
+impl Downcast for HookedProperty {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_hooked_property()
+    }
+}
+
+impl Downcast for PropertyHookList {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_property_hook_list()
+    }
+}
+
+impl Downcast for PropertyHook {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_property_hook()
+    }
+}
+
+impl Downcast for PropertyHookBody {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_property_hook_body()
+    }
+}
+
+impl Downcast for ConcretePropertyHookBody {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_concrete_property_hook_body()
+    }
+}
+
+impl Downcast for ConcretePropertyHookBodyBlock {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_concrete_property_hook_body_block()
+    }
+}
+
+impl Downcast for PropertyEntry {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_property_entry()
+    }
+}
+
+impl Downcast for PropertyEntryKind {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_property_entry_kind()
+    }
+}
+
+impl Downcast for UninitializedPropertyEntry {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_uninitialized_property_entry()
+    }
+}
+
+impl Downcast for InitializedPropertyEntry {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_initialized_property_entry()
+    }
+}
+
+impl Downcast for TraitBody {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_trait_body()
+    }
+}
+
+impl Downcast for TraitStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_trait_statement()
+    }
+}
+
+impl Downcast for TraitUsage {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_trait_usage()
+    }
+}
+
+impl Downcast for TraitUsageAdaptation {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_trait_usage_adaptation()
+    }
+}
+
+impl Downcast for TraitUsageAdaptationKind {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_trait_usage_adaptation_kind()
+    }
+}
+
+impl Downcast for TraitUsageAdaptationAlias {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_trait_usage_adaptation_alias()
+    }
+}
+
+impl Downcast for CatchType {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_catch_type()
+    }
+}
+
+impl Downcast for CatchTypeKind {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_catch_type_kind()
+    }
+}
+
+impl Downcast for CatchTypeKindIdentifier {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_catch_type_kind_identifier()
+    }
+}
+
+impl Downcast for CatchTypeKindUnion {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_catch_type_kind_union()
+    }
+}
+
+impl Downcast for TryStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_try_statement()
+    }
+}
+
+impl Downcast for CatchBlock {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_catch_block()
+    }
+}
+
+impl Downcast for FinallyBlock {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_finally_block()
+    }
+}
+
+impl Downcast for Variable {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_variable()
+    }
+}
+
+impl Downcast for SimpleVariable {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_simple_variable()
+    }
+}
+
+impl Downcast for VariableVariable {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_variable_variable()
+    }
+}
+
+impl Downcast for BracedVariableVariable {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_braced_variable_variable()
+    }
+}
+
+impl Downcast for StaticStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_static_statement()
+    }
+}
+
+impl Downcast for SwitchStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_switch_statement()
+    }
+}
+
+impl Downcast for EchoStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_echo_statement()
+    }
+}
+
+impl Downcast for ReturnStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_return_statement()
+    }
+}
+
+impl Downcast for UseStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_use_statement()
+    }
+}
+
+impl Downcast for GroupUseStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_group_use_statement()
+    }
+}
+
+impl Downcast for HaltCompilerStatement {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_halt_compiler_statement()
+    }
+}
+
+impl Downcast for StaticVar {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_static_var()
+    }
+}
+
+impl Downcast for Comment {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_comment()
+    }
+}
+
+impl Downcast for CommentKind {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_comment_kind()
+    }
+}
+
+impl Downcast for SingleLineComment {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_single_line_comment()
+    }
+}
+
+impl Downcast for MultiLineComment {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_multi_line_comment()
+    }
+}
+
+impl Downcast for HashMarkComment {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_hash_mark_comment()
+    }
+}
+
+impl Downcast for DocBlockComment {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_doc_block_comment()
+    }
+}
+
+impl Downcast for DocBlock {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_doc_block()
+    }
+}
+
+impl Downcast for DocBlockNode {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_doc_block_node()
+    }
+}
+
+impl Downcast for DocBlockTextNode {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_doc_block_text_node()
+    }
+}
+
+impl Downcast for DocBlockTagNode {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_doc_block_tag_node()
+    }
+}
+
+impl Downcast for DocBlockTag {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_doc_block_tag()
+    }
+}
+
+impl Downcast for DocBlockParamClosureThisTag {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_doc_block_param_closure_this_tag()
+    }
+}
+
+impl Downcast for DocBlockParamTag {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_doc_block_param_tag()
+    }
+}
+
+impl Downcast for DocBlockReturnTag {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_doc_block_return_tag()
+    }
+}
+
+impl Downcast for DocBlockThrowsTag {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_doc_block_throws_tag()
+    }
+}
+
+impl Downcast for DocBlockVarTag {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_doc_block_var_tag()
+    }
+}
+
+impl Downcast for DocBlockPropertyTag {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_doc_block_property_tag()
+    }
+}
+
+impl Downcast for DocBlockMethodTag {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_doc_block_method_tag()
+    }
+}
+
+impl Downcast for DocBlockTemplateTagValue {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_doc_block_template_tag_value()
+    }
+}
+
+impl Downcast for DocBlockTemplateTag {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_doc_block_template_tag()
+    }
+}
+
+impl Downcast for DocBlockExtendsTag {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_doc_block_extends_tag()
+    }
+}
+
+impl Downcast for DocBlockImplementsTag {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_doc_block_implements_tag()
+    }
+}
+
+impl Downcast for DocBlockUsesTag {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_doc_block_uses_tag()
+    }
+}
+
+impl Downcast for DocBlockDeprecatedTag {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_doc_block_deprecated_tag()
+    }
+}
+
+impl Downcast for DocBlockGenericTag {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_doc_block_generic_tag()
+    }
+}
+
+impl Downcast for CommentGroup {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {
+        node.as_comment_group()
+    }
+}
@@ -1,5 +1,5 @@
 use pxp_bytestring::ByteString;
-use pxp_span::{IsSpanned, Span};
+use pxp_span::Span;
 
 use crate::{Identifier, NodeId, SimpleIdentifier};
 
@@ -34,15 +34,6 @@ impl Identifier {
     }
 }
 
-impl IsSpanned for Identifier {
-    fn span(&self) -> Span {
-        match self {
-            Self::SimpleIdentifier(simple) => simple.span,
-            Self::DynamicIdentifier(dynamic) => dynamic.span,
-        }
-    }
-}
-
 impl SimpleIdentifier {
     pub fn new(id: NodeId, symbol: ByteString, span: Span) -> Self {
         Self { id, symbol, span }
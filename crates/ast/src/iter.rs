@@ -0,0 +1,76 @@
+use std::iter::FusedIterator;
+
+use crate::{Downcast, Node, Statement};
+
+/// Walks an AST depth-first, yielding every node along with its depth, in the same order as
+/// [`NodeVisitor`](crate::visitor::NodeVisitor) enters them.
+///
+/// Unlike the visitors, the iterator keeps track of where it is with a stack on the heap instead
+/// of recursing, so it can be stopped and resumed at any point and doesn't overflow the stack on
+/// deeply nested trees.
+#[derive(Debug, Clone, Default)]
+pub struct AstIterator<'a> {
+    stack: Vec<(Node<'a>, usize)>,
+    // The children of the last node are only pushed once we move past it, so that they can be skipped.
+    last: Option<(Node<'a>, usize)>,
+}
+
+impl<'a> AstIterator<'a> {
+    pub fn new(ast: &'a [Statement]) -> Self {
+        let mut iterator = Self::default();
+        iterator.reset(ast);
+        iterator
+    }
+
+    /// Start walking another AST, reusing the stack that has already been allocated.
+    pub fn reset(&mut self, ast: &'a [Statement]) {
+        self.stack.clear();
+        self.stack
+            .extend(ast.iter().rev().map(|statement| (Node::from(statement), 0)));
+        self.last = None;
+    }
+
+    /// Don't descend into the children of the node that was yielded last.
+    pub fn skip_children(&mut self) {
+        self.last = None;
+    }
+}
+
+impl<'a> Iterator for AstIterator<'a> {
+    type Item = (Node<'a>, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((node, depth)) = self.last.take() {
+            self.stack.extend(
+                node.children()
+                    .into_iter()
+                    .rev()
+                    .map(|child| (child, depth + 1)),
+            );
+        }
+
+        let next = self.stack.pop()?;
+        self.last = Some(next);
+
+        Some(next)
+    }
+}
+
+impl FusedIterator for AstIterator<'_> {}
+
+pub trait IterNodes {
+    /// Iterate over every node in document order, along with its depth.
+    fn iter_nodes(&self) -> AstIterator<'_>;
+
+    /// Iterate over every node of the given type in document order, along with its depth.
+    fn iter_nodes_of<T: Downcast>(&self) -> impl Iterator<Item = (&T, usize)> {
+        self.iter_nodes()
+            .filter_map(|(node, depth)| Some((node.downcast::<T>()?, depth)))
+    }
+}
+
+impl IterNodes for [Statement] {
+    fn iter_nodes(&self) -> AstIterator<'_> {
+        AstIterator::new(self)
+    }
+}
@@ -1,22 +1,36 @@
 use std::fmt::{Display, Formatter};
 
+mod arguments;
 mod array;
+mod attributes;
 mod backed_enum_type;
 mod comments;
+mod control_flow;
 mod docblock;
+mod fingerprint;
+#[allow(clippy::large_enum_variant)]
 mod generated;
 mod id;
+mod iter;
 mod node;
 mod visibility;
 pub mod visitor;
 
+pub use attributes::{ConstantArgument, HasAttributes};
+pub use control_flow::{IfBranch, IfBranchKind, IfBranches};
+#[cfg(feature = "docblock-formatter")]
+pub use docblock::{DocBlockFormatter, DocBlockFormatterOptions};
+pub use fingerprint::{fingerprint, Fingerprint, Fingerprinter};
 pub use generated::*;
 pub use id::HasId;
-pub use node::Node;
-use pxp_span::{IsSpanned, Span};
+pub use iter::{AstIterator, IterNodes};
+pub use node::{Downcast, Node};
+use pxp_span::Span;
 use pxp_token::{Token, TokenKind};
 pub use visibility::*;
 
+pub mod builder;
+pub mod constants;
 pub mod data_type;
 pub mod identifiers;
 pub mod literals;
@@ -24,10 +38,20 @@ pub mod modifiers;
 pub mod name;
 pub mod operators;
 pub mod properties;
-mod spanned;
 pub mod utils;
 pub mod variables;
 
+// Every expression and statement in a tree is as large as the largest variant of its kind, so the
+// nodes inside of `ExpressionKind` and `StatementKind` are boxed. These budgets stop an unboxed
+// variant from sneaking back in and growing the whole tree.
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+    assert!(std::mem::size_of::<ExpressionKind>() <= 32);
+    assert!(std::mem::size_of::<Expression>() <= 88);
+    assert!(std::mem::size_of::<StatementKind>() <= 24);
+    assert!(std::mem::size_of::<Statement>() <= 112);
+};
+
 impl Display for UseKind {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -39,12 +63,19 @@ impl Display for UseKind {
 }
 
 impl Statement {
-    pub fn new(id: NodeId, kind: StatementKind, span: Span, comments: CommentGroup) -> Self {
+    pub fn new(
+        id: NodeId,
+        kind: StatementKind,
+        span: Span,
+        comments: CommentGroup,
+        trailing_comments: CommentGroup,
+    ) -> Self {
         Self {
             id,
             span,
             kind,
             comments,
+            trailing_comments,
         }
     }
 }
@@ -112,286 +143,49 @@ impl<'a> Node<'a> {
     }
 }
 
-impl IsSpanned for Argument {
-    fn span(&self) -> Span {
-        match self {
-            Argument::Positional(arg) => arg.span(),
-            Argument::Named(arg) => arg.span(),
-        }
-    }
-}
-
-impl IsSpanned for StringPart {
-    fn span(&self) -> Span {
-        match self {
-            StringPart::Literal(LiteralStringPart { span, .. }) => *span,
-            StringPart::Expression(ExpressionStringPart { span, .. }) => *span,
-        }
-    }
-}
-
-impl IsSpanned for ClassishMember {
-    fn span(&self) -> Span {
-        match self {
-            ClassishMember::Constant(inner) => inner.span(),
-            ClassishMember::TraitUsage(inner) => inner.span(),
-            ClassishMember::Property(inner) => inner.span(),
-            ClassishMember::Method(inner) => inner.span(),
-            ClassishMember::Missing(inner) => inner.span(),
-        }
-    }
-}
-
-impl IsSpanned for IfStatementBody {
-    fn span(&self) -> Span {
-        match self {
-            IfStatementBody::Statement(IfStatementBodyStatement { span, .. }) => *span,
-            IfStatementBody::Block(IfStatementBodyBlock { span, .. }) => *span,
-        }
-    }
-}
-
-impl IsSpanned for DeclareBody {
-    fn span(&self) -> Span {
-        match self {
-            DeclareBody::Noop(DeclareBodyNoop { span, .. }) => *span,
-            DeclareBody::Braced(DeclareBodyBraced { span, .. }) => *span,
-            DeclareBody::Expression(DeclareBodyExpression { span, .. }) => *span,
-            DeclareBody::Block(DeclareBodyBlock { span, .. }) => *span,
-        }
-    }
-}
-
-impl IsSpanned for UnitEnumMember {
-    fn span(&self) -> Span {
-        match self {
-            UnitEnumMember::Case(inner) => inner.span(),
-            UnitEnumMember::Classish(inner) => inner.span(),
-        }
-    }
-}
-
-impl IsSpanned for BackedEnumMember {
-    fn span(&self) -> Span {
-        match self {
-            BackedEnumMember::Case(inner) => inner.span(),
-            BackedEnumMember::Classish(inner) => inner.span(),
-        }
-    }
-}
-
-impl IsSpanned for ForeachStatementIterator {
-    fn span(&self) -> Span {
-        match self {
-            ForeachStatementIterator::Value(ForeachStatementIteratorValue { span, .. }) => *span,
-            ForeachStatementIterator::KeyAndValue(ForeachStatementIteratorKeyAndValue {
-                span,
-                ..
-            }) => *span,
-        }
-    }
-}
-
-impl IsSpanned for ForeachStatementBody {
-    fn span(&self) -> Span {
-        match self {
-            ForeachStatementBody::Statement(inner) => inner.span(),
-            ForeachStatementBody::Block(inner) => inner.span(),
-        }
-    }
-}
-
-impl IsSpanned for ForStatementBody {
-    fn span(&self) -> Span {
-        match self {
-            ForStatementBody::Statement(inner) => inner.span(),
-            ForStatementBody::Block(inner) => inner.span(),
-        }
-    }
-}
-
-impl IsSpanned for WhileStatementBody {
-    fn span(&self) -> Span {
-        match self {
-            WhileStatementBody::Statement(inner) => inner.span(),
-            WhileStatementBody::Block(inner) => inner.span(),
-        }
-    }
-}
-
-impl IsSpanned for Level {
-    fn span(&self) -> Span {
-        match self {
-            Level::Literal(LiteralLevel { literal, .. }) => literal.span(),
-            Level::Parenthesized(inner) => inner.span(),
-        }
-    }
-}
-
-impl IsSpanned for LiteralLevel {
-    fn span(&self) -> Span {
-        self.literal.span()
-    }
-}
-
-impl IsSpanned for ArithmeticOperationKind {
-    fn span(&self) -> Span {
-        match self {
-            ArithmeticOperationKind::Addition { left, right, .. } => {
-                Span::combine(left.span, right.span)
-            }
-            ArithmeticOperationKind::Subtraction { left, right, .. } => {
-                Span::combine(left.span, right.span)
-            }
-            ArithmeticOperationKind::Multiplication { left, right, .. } => {
-                Span::combine(left.span, right.span)
-            }
-            ArithmeticOperationKind::Division { left, right, .. } => {
-                Span::combine(left.span, right.span)
-            }
-            ArithmeticOperationKind::Modulo { left, right, .. } => {
-                Span::combine(left.span, right.span)
-            }
-            ArithmeticOperationKind::Exponentiation { left, right, .. } => {
-                Span::combine(left.span, right.span)
-            }
-            ArithmeticOperationKind::Negative { minus, right, .. } => {
-                Span::combine(*minus, right.span)
-            }
-            ArithmeticOperationKind::Positive { plus, right, .. } => {
-                Span::combine(*plus, right.span)
-            }
-            ArithmeticOperationKind::PreIncrement {
-                increment, right, ..
-            } => Span::combine(*increment, right.span),
-            ArithmeticOperationKind::PostIncrement {
-                left, increment, ..
-            } => Span::combine(left.span, *increment),
-            ArithmeticOperationKind::PreDecrement {
-                decrement, right, ..
-            } => Span::combine(*decrement, right.span),
-            ArithmeticOperationKind::PostDecrement {
-                left, decrement, ..
-            } => Span::combine(left.span, *decrement),
-        }
-    }
-}
-
-impl IsSpanned for BitwiseOperationKind {
-    fn span(&self) -> Span {
-        match self {
-            BitwiseOperationKind::And { left, right, .. } => Span::combine(left.span, right.span),
-            BitwiseOperationKind::Or { left, right, .. } => Span::combine(left.span, right.span),
-            BitwiseOperationKind::Xor { left, right, .. } => Span::combine(left.span, right.span),
-            BitwiseOperationKind::LeftShift { left, right, .. } => {
-                Span::combine(left.span, right.span)
-            }
-            BitwiseOperationKind::RightShift { left, right, .. } => {
-                Span::combine(left.span, right.span)
-            }
-            BitwiseOperationKind::Not { not, right, .. } => Span::combine(*not, right.span),
-        }
+impl ConstantFetchExpression {
+    /// Whether this is a `::class` fetch, e.g. `Foo::class` or `$foo::class`.
+    pub fn is_class(&self) -> bool {
+        self.constant.is_simple()
+            && self
+                .constant
+                .to_simple()
+                .symbol
+                .eq_ignore_ascii_case(b"class")
     }
-}
 
-impl IsSpanned for ComparisonOperationKind {
-    fn span(&self) -> Span {
-        match self {
-            ComparisonOperationKind::Equal { left, right, .. } => {
-                Span::combine(left.span, right.span)
-            }
-            ComparisonOperationKind::Identical { left, right, .. } => {
-                Span::combine(left.span, right.span)
-            }
-            ComparisonOperationKind::NotEqual { left, right, .. } => {
-                Span::combine(left.span, right.span)
-            }
-            ComparisonOperationKind::AngledNotEqual { left, right, .. } => {
-                Span::combine(left.span, right.span)
-            }
-            ComparisonOperationKind::NotIdentical { left, right, .. } => {
-                Span::combine(left.span, right.span)
-            }
-            ComparisonOperationKind::LessThan { left, right, .. } => {
-                Span::combine(left.span, right.span)
-            }
-            ComparisonOperationKind::GreaterThan { left, right, .. } => {
-                Span::combine(left.span, right.span)
-            }
-            ComparisonOperationKind::LessThanOrEqual { left, right, .. } => {
-                Span::combine(left.span, right.span)
-            }
-            ComparisonOperationKind::GreaterThanOrEqual { left, right, .. } => {
-                Span::combine(left.span, right.span)
-            }
-            ComparisonOperationKind::Spaceship { left, right, .. } => {
-                Span::combine(left.span, right.span)
-            }
-        }
+    /// Whether the constant name is computed at runtime, e.g. `Foo::{$name}`.
+    pub fn is_dynamic(&self) -> bool {
+        self.constant.is_dynamic()
     }
 }
 
-impl IsSpanned for LogicalOperationKind {
-    fn span(&self) -> Span {
-        match self {
-            LogicalOperationKind::And { left, right, .. } => Span::combine(left.span, right.span),
-            LogicalOperationKind::Or { left, right, .. } => Span::combine(left.span, right.span),
-            LogicalOperationKind::Not { bang, right, .. } => Span::combine(*bang, right.span),
-            LogicalOperationKind::LogicalAnd { left, right, .. } => {
-                Span::combine(left.span, right.span)
-            }
-            LogicalOperationKind::LogicalOr { left, right, .. } => {
-                Span::combine(left.span, right.span)
-            }
-            LogicalOperationKind::LogicalXor { left, right, .. } => {
-                Span::combine(left.span, right.span)
-            }
-        }
+impl CloneExpression {
+    /// Whether the object is passed to `clone` like an argument to a function, e.g. `clone($foo)`.
+    pub fn is_call(&self) -> bool {
+        self.left_parenthesis.is_some()
     }
 }
 
-impl IsSpanned for PropertyEntryKind {
-    fn span(&self) -> Span {
-        match self {
-            PropertyEntryKind::Uninitialized(inner) => inner.span(),
-            PropertyEntryKind::Initialized(inner) => inner.span(),
-        }
+impl DeclareStatement {
+    /// Get the entry for the given directive, e.g. `strict_types`.
+    pub fn directive(&self, name: &[u8]) -> Option<&DeclareEntry> {
+        self.entries
+            .entries
+            .iter()
+            .find(|entry| entry.key.symbol.eq_ignore_ascii_case(name))
     }
-}
 
-impl IsSpanned for TraitUsageAdaptationKind {
-    fn span(&self) -> Span {
-        match self {
-            TraitUsageAdaptationKind::Alias(inner) => inner.span(),
-            TraitUsageAdaptationKind::Visibility(inner) => inner.span(),
-            TraitUsageAdaptationKind::Precedence(inner) => inner.span(),
-        }
-    }
-}
-
-impl IsSpanned for CatchTypeKind {
-    fn span(&self) -> Span {
-        match self {
-            CatchTypeKind::Identifier(inner) => inner.span(),
-            CatchTypeKind::Union(inner) => inner.span(),
-        }
-    }
-}
-
-impl IsSpanned for Property {
-    fn span(&self) -> Span {
-        match self {
-            Property::Simple(inner) => inner.span(),
-            Property::Hooked(inner) => inner.span(),
-        }
-    }
-}
-
-impl IsSpanned for ConcretePropertyHookBody {
-    fn span(&self) -> Span {
-        match self {
-            ConcretePropertyHookBody::Block(inner) => inner.span(),
-            ConcretePropertyHookBody::Expression(inner) => inner.span(),
+    /// Get the statements that the directives apply to.
+    ///
+    /// A declare without a body, e.g. `declare(ticks=1);`, applies to the rest of the file, so the
+    /// statements that follow it need to be provided.
+    pub fn governed_statements<'a>(&'a self, following: &'a [Statement]) -> &'a [Statement] {
+        match &self.body {
+            DeclareBody::Noop(_) => following,
+            DeclareBody::Braced(body) => &body.statements,
+            DeclareBody::Block(body) => &body.statements,
+            DeclareBody::Statement(body) => std::slice::from_ref(body.statement.as_ref()),
         }
     }
 }
@@ -408,46 +202,6 @@ impl FunctionParameter {
     }
 }
 
-impl IsSpanned for CommentKind {
-    fn span(&self) -> Span {
-        match self {
-            CommentKind::SingleLine(inner) => inner.span,
-            CommentKind::MultiLine(inner) => inner.span,
-            CommentKind::HashMark(inner) => inner.span,
-            CommentKind::DocBlock(inner) => inner.span,
-        }
-    }
-}
-
-impl IsSpanned for DocBlockNode {
-    fn span(&self) -> Span {
-        match self {
-            DocBlockNode::Text(inner) => inner.span,
-            DocBlockNode::Tag(inner) => inner.span(),
-        }
-    }
-}
-
-impl IsSpanned for DocBlockTag {
-    fn span(&self) -> Span {
-        match self {
-            DocBlockTag::ParamClosureThis(inner) => inner.span,
-            DocBlockTag::Param(inner) => inner.span,
-            DocBlockTag::Return(inner) => inner.span,
-            DocBlockTag::Throws(inner) => inner.span,
-            DocBlockTag::Var(inner) => inner.span,
-            DocBlockTag::Property(inner) => inner.span,
-            DocBlockTag::Method(inner) => inner.span,
-            DocBlockTag::Template(inner) => inner.span,
-            DocBlockTag::Extends(inner) => inner.span,
-            DocBlockTag::Implements(inner) => inner.span,
-            DocBlockTag::Uses(inner) => inner.span,
-            DocBlockTag::Deprecated(inner) => inner.span,
-            DocBlockTag::Generic(inner) => inner.span,
-        }
-    }
-}
-
 impl Method {
     pub fn is_abstract(&self) -> bool {
         self.modifiers.has_abstract() || matches!(self.body.kind, MethodBodyKind::Abstract(_))
@@ -457,13 +211,3 @@ impl Method {
         matches!(self.body.kind, MethodBodyKind::Concrete(_))
     }
 }
-
-impl IsSpanned for MethodBodyKind {
-    fn span(&self) -> Span {
-        match self {
-            MethodBodyKind::Concrete(inner) => inner.span(),
-            MethodBodyKind::Abstract(inner) => inner.span(),
-            MethodBodyKind::Missing(inner) => inner.span(),
-        }
-    }
-}
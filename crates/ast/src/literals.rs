@@ -1,5 +1,8 @@
+use std::cell::OnceCell;
+
+use pxp_bytestring::{ByteStr, ByteString};
 use pxp_span::Span;
-use pxp_token::{OwnedToken, Token};
+use pxp_token::{OwnedToken, Token, TokenKind};
 
 use crate::{Literal, LiteralKind, NodeId};
 
@@ -21,4 +24,495 @@ impl Literal {
             span,
         }
     }
+
+    /// The value of a string literal, or `None` if the literal isn't a string.
+    pub fn string_value(&self) -> Option<LiteralStringValue<'_>> {
+        if self.kind != LiteralKind::String {
+            return None;
+        }
+
+        let symbol = self.token.symbol.as_bytestr();
+
+        match self.token.kind {
+            TokenKind::LiteralSingleQuotedString => {
+                // Binary strings, e.g. `b'foo'`, keep their prefix in the symbol.
+                let symbol = match symbol.first() {
+                    Some(b'b' | b'B') => &symbol[1..],
+                    _ => symbol,
+                };
+
+                let content = symbol.strip_prefix(b"'").unwrap_or(symbol);
+                // An unterminated string doesn't have a closing quote.
+                let content = content.strip_suffix(b"'").unwrap_or(content);
+
+                Some(LiteralStringValue::new(
+                    ByteStr::new(content),
+                    StringQuote::Single,
+                ))
+            }
+            // The symbol of a double-quoted string is already just its content.
+            TokenKind::LiteralDoubleQuotedString => {
+                Some(LiteralStringValue::new(symbol, StringQuote::Double))
+            }
+            // Unquoted array keys inside of interpolated strings, e.g. `"$a[key]"`.
+            _ => Some(LiteralStringValue::new(symbol, StringQuote::Single)),
+        }
+    }
+
+    /// The base that an integer literal is written in, or `None` if the literal isn't an integer.
+    pub fn integer_base(&self) -> Option<IntegerBase> {
+        if self.kind != LiteralKind::Integer {
+            return None;
+        }
+
+        Some(split_integer(self.token.symbol.as_bytes()).0)
+    }
+
+    /// The value of an integer literal, or `None` if the literal isn't an integer or contains
+    /// digits that aren't valid in its base, e.g. `089`.
+    pub fn integer_value(&self) -> Option<IntegerValue> {
+        if self.kind != LiteralKind::Integer {
+            return None;
+        }
+
+        let (base, digits) = split_integer(self.token.symbol.as_bytes());
+        let radix = base.radix();
+        let digits = digits
+            .iter()
+            .copied()
+            .filter(|digit| *digit != b'_')
+            .map(|digit| (digit as char).to_digit(radix))
+            .collect::<Option<Vec<_>>>()?;
+
+        let mut value: i64 = 0;
+
+        for (i, digit) in digits.iter().enumerate() {
+            match value
+                .checked_mul(radix as i64)
+                .and_then(|value| value.checked_add(*digit as i64))
+            {
+                Some(next) => value = next,
+                // Integers that don't fit are converted to floats. Decimal literals are parsed
+                // again as a float to keep as much precision as possible.
+                None if base == IntegerBase::Decimal => {
+                    let source = self
+                        .token
+                        .symbol
+                        .iter()
+                        .filter(|byte| **byte != b'_')
+                        .map(|byte| *byte as char)
+                        .collect::<String>();
+
+                    return source.parse().ok().map(IntegerValue::Overflow);
+                }
+                None => {
+                    let value = digits[i..].iter().fold(value as f64, |value, digit| {
+                        value * radix as f64 + *digit as f64
+                    });
+
+                    return Some(IntegerValue::Overflow(value));
+                }
+            }
+        }
+
+        Some(IntegerValue::Int(value))
+    }
+
+    /// The value of a float literal, or `None` if the literal isn't a float.
+    pub fn float_value(&self) -> Option<f64> {
+        if self.kind != LiteralKind::Float {
+            return None;
+        }
+
+        self.token
+            .symbol
+            .iter()
+            .filter(|byte| **byte != b'_')
+            .map(|byte| *byte as char)
+            .collect::<String>()
+            .parse()
+            .ok()
+    }
+}
+
+/// The base that an integer literal is written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegerBase {
+    Binary,
+    /// Either `0o17` or the legacy `017`.
+    Octal,
+    Decimal,
+    Hexadecimal,
+}
+
+impl IntegerBase {
+    pub fn radix(&self) -> u32 {
+        match self {
+            IntegerBase::Binary => 2,
+            IntegerBase::Octal => 8,
+            IntegerBase::Decimal => 10,
+            IntegerBase::Hexadecimal => 16,
+        }
+    }
+}
+
+/// The value of an integer literal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IntegerValue {
+    Int(i64),
+    /// A literal that is too large for an integer, which PHP converts to a float.
+    Overflow(f64),
+}
+
+/// Split an integer literal into its base and the digits that follow the prefix.
+fn split_integer(symbol: &[u8]) -> (IntegerBase, &[u8]) {
+    match symbol {
+        [b'0', b'x' | b'X', digits @ ..] => (IntegerBase::Hexadecimal, digits),
+        [b'0', b'b' | b'B', digits @ ..] => (IntegerBase::Binary, digits),
+        [b'0', b'o' | b'O', digits @ ..] => (IntegerBase::Octal, digits),
+        [b'0', digits @ ..] if !digits.is_empty() => (IntegerBase::Octal, digits),
+        digits => (IntegerBase::Decimal, digits),
+    }
+}
+
+/// The kind of quotes around a string, which decides the escape sequences it supports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringQuote {
+    Single,
+    Double,
+    /// Heredocs support the same escape sequences as double-quoted strings, apart from `\"`.
+    Heredoc,
+    /// Nowdocs don't support any escape sequences.
+    Nowdoc,
+    /// Shell commands, e.g. `` `ls` ``, support `` \` `` instead of `\"`.
+    Backtick,
+}
+
+/// The value of a string literal.
+///
+/// Escape sequences are decoded the first time the value is needed and the result is cached. A
+/// literal without any escape sequences is never copied, which keeps very large literals cheap.
+#[derive(Debug, Clone)]
+pub struct LiteralStringValue<'a> {
+    raw: &'a ByteStr,
+    quote: StringQuote,
+    decoded: OnceCell<ByteString>,
+}
+
+impl<'a> LiteralStringValue<'a> {
+    /// Create a value from the content of a string, without its quotes.
+    pub fn new(raw: &'a ByteStr, quote: StringQuote) -> Self {
+        Self {
+            raw,
+            quote,
+            decoded: OnceCell::new(),
+        }
+    }
+
+    /// The content of the string as it was written.
+    pub fn raw(&self) -> &'a ByteStr {
+        self.raw
+    }
+
+    pub fn quote(&self) -> StringQuote {
+        self.quote
+    }
+
+    /// The value of the string at runtime, with any escape sequences decoded.
+    pub fn value(&self) -> &ByteStr {
+        if self.quote == StringQuote::Nowdoc || !<[u8]>::contains(self.raw, &b'\\') {
+            return self.raw;
+        }
+
+        self.decoded
+            .get_or_init(|| decode_escapes(self.raw, self.quote).0)
+            .as_bytestr()
+    }
+
+    pub fn len(&self) -> usize {
+        self.value().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value().is_empty()
+    }
+}
+
+/// Single-quoted strings only support escaping the quote and the backslash itself.
+fn decode_single_quoted(raw: &[u8], mut offsets: Option<&mut Vec<usize>>) -> ByteString {
+    let mut bytes = Vec::with_capacity(raw.len());
+    let mut i = 0;
+
+    while i < raw.len() {
+        record_offset(&mut offsets, bytes.len(), i);
+
+        match &raw[i..] {
+            [b'\\', escaped @ (b'\'' | b'\\'), ..] => {
+                bytes.push(*escaped);
+                i += 2;
+            }
+            [byte, ..] => {
+                bytes.push(*byte);
+                i += 1;
+            }
+            [] => unreachable!(),
+        }
+    }
+
+    record_offset(&mut offsets, bytes.len(), raw.len());
+
+    ByteString::new(bytes)
+}
+
+/// Decode the escape sequences in the content of a string, using the rules of the given quotes.
+///
+/// Invalid escape sequences are kept as they were written and returned alongside the decoded
+/// value. Backslashes that don't start an escape sequence, e.g. `\q`, are kept as they are, which
+/// is what PHP does too.
+///
+/// ```
+/// use pxp_ast::literals::{decode_escapes, EscapeError, StringQuote};
+///
+/// let (value, errors) = decode_escapes(br"\u{1F418} \x41\101 \u{}", StringQuote::Double);
+///
+/// assert_eq!(value.as_bytes(), "🐘 AA \\u{}".as_bytes());
+/// assert_eq!(errors[0].error, EscapeError::EmptyCodepoint);
+/// assert_eq!((errors[0].span.start, errors[0].span.end), (19, 23));
+/// ```
+pub fn decode_escapes(raw: &[u8], quote: StringQuote) -> (ByteString, Vec<InvalidEscape>) {
+    let mut errors = Vec::new();
+
+    let value = match quote {
+        StringQuote::Single => decode_single_quoted(raw, None),
+        StringQuote::Nowdoc => ByteString::from(raw),
+        quote => decode_double_quoted(raw, quote, &mut errors, None),
+    };
+
+    (value, errors)
+}
+
+/// Decode the escape sequences in the content of a string like [`decode_escapes`], along with the
+/// offset in `raw` that each byte of the value was written at. There's one more offset than there
+/// are bytes, for the end of the value, so that spans in the value can be mapped back to `raw`.
+///
+/// ```
+/// use pxp_ast::literals::{decode_escapes_with_offsets, StringQuote};
+///
+/// let (value, offsets) = decode_escapes_with_offsets(br"a\tb", StringQuote::Double);
+///
+/// assert_eq!(value.as_bytes(), b"a\tb");
+/// assert_eq!(offsets, [0, 1, 3, 4]);
+/// ```
+pub fn decode_escapes_with_offsets(raw: &[u8], quote: StringQuote) -> (ByteString, Vec<usize>) {
+    let mut offsets = Vec::with_capacity(raw.len() + 1);
+
+    let value = match quote {
+        StringQuote::Single => decode_single_quoted(raw, Some(&mut offsets)),
+        StringQuote::Nowdoc => {
+            offsets.extend(0..=raw.len());
+            ByteString::from(raw)
+        }
+        quote => decode_double_quoted(raw, quote, &mut Vec::new(), Some(&mut offsets)),
+    };
+
+    (value, offsets)
+}
+
+/// Record the offset that the next decoded byte starts at. The last offset is a placeholder for
+/// the bytes that haven't been decoded yet, so it's used for everything decoded since.
+fn record_offset(offsets: &mut Option<&mut Vec<usize>>, decoded: usize, offset: usize) {
+    if let Some(offsets) = offsets {
+        if let Some(previous) = offsets.pop() {
+            offsets.resize(decoded, previous);
+        }
+
+        offsets.push(offset);
+    }
+}
+
+/// An escape sequence that PHP warns about or refuses to compile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidEscape {
+    pub error: EscapeError,
+    /// The span of the escape sequence, relative to the start of the string's content.
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscapeError {
+    /// `\u{}`
+    EmptyCodepoint,
+    /// A codepoint escape sequence with something other than hexadecimal digits, or without a
+    /// closing brace, e.g. `\u{zz}`.
+    InvalidCodepoint,
+    /// A codepoint above `U+10FFFF`, e.g. `\u{110000}`.
+    CodepointTooLarge,
+    /// An octal escape sequence above `\377`, which overflows a byte.
+    OctalOverflow,
+    /// A backslash at the very end of the string, which doesn't escape anything.
+    TrailingBackslash,
+}
+
+/// Decode the escape sequences that PHP supports in double-quoted strings, heredocs and shell
+/// commands. Backslashes that don't start a valid escape sequence are kept as they are.
+fn decode_double_quoted(
+    raw: &[u8],
+    quote: StringQuote,
+    errors: &mut Vec<InvalidEscape>,
+    mut offsets: Option<&mut Vec<usize>>,
+) -> ByteString {
+    let mut bytes = Vec::with_capacity(raw.len());
+    let mut i = 0;
+
+    let mut invalid = |error, start, end| {
+        errors.push(InvalidEscape {
+            error,
+            span: Span::new(start, end),
+        })
+    };
+
+    while i < raw.len() {
+        record_offset(&mut offsets, bytes.len(), i);
+
+        if raw[i] != b'\\' {
+            bytes.push(raw[i]);
+            i += 1;
+            continue;
+        }
+
+        let Some(&escaped) = raw.get(i + 1) else {
+            invalid(EscapeError::TrailingBackslash, i, i + 1);
+            bytes.push(b'\\');
+            i += 1;
+            continue;
+        };
+
+        let simple = match escaped {
+            b'n' => Some(b'\n'),
+            b't' => Some(b'\t'),
+            b'r' => Some(b'\r'),
+            b'v' => Some(0x0B),
+            b'e' => Some(0x1B),
+            b'f' => Some(0x0C),
+            b'\\' | b'$' => Some(escaped),
+            // Only the string's own quote can be escaped.
+            b'"' if quote == StringQuote::Double => Some(escaped),
+            b'`' if quote == StringQuote::Backtick => Some(escaped),
+            _ => None,
+        };
+
+        if let Some(byte) = simple {
+            bytes.push(byte);
+            i += 2;
+            continue;
+        }
+
+        match escaped {
+            // Up to three octal digits, where values above `\377` overflow.
+            b'0'..=b'7' => {
+                let digits = digits(&raw[i + 1..], 3, |byte| matches!(byte, b'0'..=b'7'));
+                let value = parse(&raw[i + 1..i + 1 + digits], 8);
+
+                if value > 0xFF {
+                    invalid(EscapeError::OctalOverflow, i, i + 1 + digits);
+                }
+
+                bytes.push(value as u8);
+                i += 1 + digits;
+            }
+            // Up to two hexadecimal digits.
+            b'x' if raw.get(i + 2).is_some_and(u8::is_ascii_hexdigit) => {
+                let digits = digits(&raw[i + 2..], 2, u8::is_ascii_hexdigit);
+                let value = parse(&raw[i + 2..i + 2 + digits], 16);
+
+                bytes.push(value as u8);
+                i += 2 + digits;
+            }
+            // A Unicode codepoint, e.g. `\u{1F418}`, encoded as UTF-8. Without the opening brace,
+            // `\u` isn't an escape sequence at all.
+            b'u' if raw.get(i + 2) == Some(&b'{') => {
+                let digits = digits(&raw[i + 3..], usize::MAX, u8::is_ascii_hexdigit);
+                let end = i + 3 + digits;
+
+                if raw.get(end) != Some(&b'}') {
+                    let end = raw[end..]
+                        .iter()
+                        .position(|byte| *byte == b'}')
+                        .map_or(raw.len(), |position| end + position + 1);
+
+                    invalid(EscapeError::InvalidCodepoint, i, end);
+                    bytes.push(b'\\');
+                    i += 1;
+                    continue;
+                }
+
+                let codepoint = parse(&raw[i + 3..end], 16);
+
+                if digits == 0 || codepoint > 0x10FFFF {
+                    let error = if digits == 0 {
+                        EscapeError::EmptyCodepoint
+                    } else {
+                        EscapeError::CodepointTooLarge
+                    };
+
+                    invalid(error, i, end + 1);
+                    bytes.push(b'\\');
+                    i += 1;
+                    continue;
+                }
+
+                encode_utf8(codepoint, &mut bytes);
+                i = end + 1;
+            }
+            _ => {
+                bytes.push(b'\\');
+                i += 1;
+            }
+        }
+    }
+
+    record_offset(&mut offsets, bytes.len(), raw.len());
+
+    ByteString::new(bytes)
+}
+
+fn digits(bytes: &[u8], max: usize, is_digit: impl Fn(&u8) -> bool) -> usize {
+    bytes
+        .iter()
+        .take(max)
+        .take_while(|byte| is_digit(byte))
+        .count()
+}
+
+/// Parse the given digits, saturating rather than overflowing since anything that large is invalid
+/// anyway.
+fn parse(digits: &[u8], radix: u32) -> u32 {
+    digits.iter().fold(0u32, |value, digit| {
+        value
+            .saturating_mul(radix)
+            .saturating_add((*digit as char).to_digit(radix).unwrap())
+    })
+}
+
+/// PHP encodes any codepoint up to `0x10FFFF` as UTF-8, including surrogates, so this can't use
+/// `char::encode_utf8`.
+fn encode_utf8(codepoint: u32, bytes: &mut Vec<u8>) {
+    match codepoint {
+        0..=0x7F => bytes.push(codepoint as u8),
+        0x80..=0x7FF => bytes.extend([
+            0xC0 | (codepoint >> 6) as u8,
+            0x80 | (codepoint & 0x3F) as u8,
+        ]),
+        0x800..=0xFFFF => bytes.extend([
+            0xE0 | (codepoint >> 12) as u8,
+            0x80 | ((codepoint >> 6) & 0x3F) as u8,
+            0x80 | (codepoint & 0x3F) as u8,
+        ]),
+        _ => bytes.extend([
+            0xF0 | (codepoint >> 18) as u8,
+            0x80 | ((codepoint >> 12) & 0x3F) as u8,
+            0x80 | ((codepoint >> 6) & 0x3F) as u8,
+            0x80 | (codepoint & 0x3F) as u8,
+        ]),
+    }
 }
@@ -1,25 +1,9 @@
-use pxp_span::Span;
-
 use crate::{
     ClassModifier, ClassModifierGroup, ConstantModifier, ConstantModifierGroup, MethodModifier,
     MethodModifierGroup, PromotedPropertyModifier, PromotedPropertyModifierGroup, PropertyModifier,
     PropertyModifierGroup, Visibility,
 };
 
-impl PromotedPropertyModifier {
-    pub fn span(&self) -> Span {
-        match self {
-            PromotedPropertyModifier::Public(span) => *span,
-            PromotedPropertyModifier::Protected(span) => *span,
-            PromotedPropertyModifier::Private(span) => *span,
-            PromotedPropertyModifier::Readonly(span) => *span,
-            PromotedPropertyModifier::PrivateSet(span) => *span,
-            PromotedPropertyModifier::ProtectedSet(span) => *span,
-            PromotedPropertyModifier::PublicSet(span) => *span,
-        }
-    }
-}
-
 impl std::fmt::Display for PromotedPropertyModifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -64,21 +48,6 @@ impl PromotedPropertyModifierGroup {
     }
 }
 
-impl PropertyModifier {
-    pub fn span(&self) -> Span {
-        match self {
-            PropertyModifier::Public(span) => *span,
-            PropertyModifier::Protected(span) => *span,
-            PropertyModifier::Private(span) => *span,
-            PropertyModifier::Static(span) => *span,
-            PropertyModifier::Readonly(span) => *span,
-            PropertyModifier::PrivateSet(span) => *span,
-            PropertyModifier::ProtectedSet(span) => *span,
-            PropertyModifier::PublicSet(span) => *span,
-        }
-    }
-}
-
 impl PropertyModifierGroup {
     pub fn is_empty(&self) -> bool {
         self.modifiers.is_empty()
@@ -139,19 +108,6 @@ impl PropertyModifierGroup {
     }
 }
 
-impl MethodModifier {
-    pub fn span(&self) -> Span {
-        match self {
-            MethodModifier::Final(span) => *span,
-            MethodModifier::Static(span) => *span,
-            MethodModifier::Abstract(span) => *span,
-            MethodModifier::Public(span) => *span,
-            MethodModifier::Protected(span) => *span,
-            MethodModifier::Private(span) => *span,
-        }
-    }
-}
-
 impl MethodModifierGroup {
     pub fn is_empty(&self) -> bool {
         self.modifiers.is_empty()
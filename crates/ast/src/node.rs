@@ -8,3 +8,15 @@ pub struct Node<'a> {
     pub kind: NodeKind<'a>,
     pub span: Span,
 }
+
+impl<'a> Node<'a> {
+    /// Get the concrete AST node that this node wraps, if it's a `T`.
+    pub fn downcast<T: Downcast>(self) -> Option<&'a T> {
+        T::downcast(self)
+    }
+}
+
+/// An AST node that can be recovered from a [`Node`].
+pub trait Downcast: 'static {
+    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self>;
+}
@@ -1,9 +1,17 @@
+use std::borrow::Cow;
 use std::slice::Iter;
 use std::slice::IterMut;
 
+use pxp_bytestring::ByteString;
 use pxp_span::IsSpanned;
 use pxp_span::Span;
 
+use crate::literals::{LiteralStringValue, StringQuote};
+use crate::{
+    Expression, ExpressionKind, HeredocExpression, InterpolatedStringExpression, Literal,
+    LiteralStringPart, NodeId, NowdocExpression, ShellExecExpression, StringPart,
+};
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 
 pub struct CommaSeparated<T> {
@@ -43,3 +51,229 @@ impl<T> IntoIterator for CommaSeparated<T> {
         self.inner.into_iter()
     }
 }
+
+/// Uniform access to the parts of string-like expressions, i.e. quoted strings, interpolated
+/// strings, heredocs, nowdocs and shell commands.
+///
+/// The literal parts are normalized to their runtime values: escape sequences are decoded
+/// according to the string's quoting rules and heredocs and nowdocs are dedented by the
+/// indentation of their closing label. Empty literal parts are skipped, so strings with the same
+/// content produce the same parts regardless of how they were written.
+pub trait StringLike {
+    /// The parts of the string, or `None` if it isn't a string.
+    fn parts(&self) -> Option<StringParts<'_>>;
+}
+
+impl StringLike for Expression {
+    fn parts(&self) -> Option<StringParts<'_>> {
+        match &self.kind {
+            ExpressionKind::Literal(literal) => literal.parts(),
+            ExpressionKind::InterpolatedString(string) => string.parts(),
+            ExpressionKind::Heredoc(heredoc) => heredoc.parts(),
+            ExpressionKind::Nowdoc(nowdoc) => nowdoc.parts(),
+            ExpressionKind::ShellExec(command) => command.parts(),
+            ExpressionKind::Parenthesized(inner) => inner.expr.parts(),
+            _ => None,
+        }
+    }
+}
+
+impl StringLike for Literal {
+    fn parts(&self) -> Option<StringParts<'_>> {
+        let value = self.string_value()?;
+
+        Some(StringParts::new(
+            literal_part(self.id, self.span, value.value().to_bytestring())
+                .map(Cow::Owned)
+                .into_iter()
+                .collect(),
+        ))
+    }
+}
+
+impl StringLike for InterpolatedStringExpression {
+    fn parts(&self) -> Option<StringParts<'_>> {
+        Some(normalize(&self.parts, StringQuote::Double, None))
+    }
+}
+
+impl StringLike for HeredocExpression {
+    fn parts(&self) -> Option<StringParts<'_>> {
+        let indentation = closing_indentation(&self.parts);
+
+        Some(normalize(
+            &self.parts,
+            StringQuote::Heredoc,
+            Some(indentation),
+        ))
+    }
+}
+
+impl StringLike for NowdocExpression {
+    fn parts(&self) -> Option<StringParts<'_>> {
+        let part = StringPart::Literal(LiteralStringPart {
+            id: self.id,
+            span: self.value.span,
+            value: self.value.symbol.clone(),
+        });
+
+        let parts = std::slice::from_ref(&part);
+        let indentation = closing_indentation(parts);
+        let normalized = normalize(parts, StringQuote::Nowdoc, Some(indentation));
+
+        // The normalized parts can't borrow from the part that we've just created, but they
+        // never need to since nowdocs don't have any expression parts.
+        Some(StringParts::new(
+            normalized
+                .map(|part| Cow::Owned(part.into_owned()))
+                .collect(),
+        ))
+    }
+}
+
+impl StringLike for ShellExecExpression {
+    fn parts(&self) -> Option<StringParts<'_>> {
+        Some(normalize(&self.parts, StringQuote::Backtick, None))
+    }
+}
+
+/// An iterator over the parts of a string-like expression. See [`StringLike`].
+#[derive(Debug, Clone)]
+pub struct StringParts<'a> {
+    parts: std::vec::IntoIter<Cow<'a, StringPart>>,
+}
+
+impl<'a> StringParts<'a> {
+    fn new(parts: Vec<Cow<'a, StringPart>>) -> Self {
+        Self {
+            parts: parts.into_iter(),
+        }
+    }
+
+    /// Whether the remaining parts are all literals, i.e. the string doesn't interpolate anything.
+    pub fn is_constant(&self) -> bool {
+        self.parts
+            .as_slice()
+            .iter()
+            .all(|part| matches!(part.as_ref(), StringPart::Literal(_)))
+    }
+
+    /// The value of the remaining parts at runtime, or `None` if any of them is an expression.
+    pub fn constant_value(&self) -> Option<ByteString> {
+        let mut value = ByteString::empty();
+
+        for part in self.parts.as_slice() {
+            match part.as_ref() {
+                StringPart::Literal(literal) => value.extend(&literal.value),
+                StringPart::Expression(_) => return None,
+            }
+        }
+
+        Some(value)
+    }
+}
+
+impl<'a> Iterator for StringParts<'a> {
+    type Item = Cow<'a, StringPart>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.parts.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.parts.size_hint()
+    }
+}
+
+impl ExactSizeIterator for StringParts<'_> {}
+
+fn literal_part(id: NodeId, span: Span, value: ByteString) -> Option<StringPart> {
+    if value.is_empty() {
+        return None;
+    }
+
+    Some(StringPart::Literal(LiteralStringPart { id, span, value }))
+}
+
+/// The indentation of a heredoc or nowdoc's closing label, which is whatever follows the last
+/// newline of the body.
+fn closing_indentation(parts: &[StringPart]) -> usize {
+    let Some(StringPart::Literal(last)) = parts.last() else {
+        return 0;
+    };
+
+    let bytes = last.value.as_bytes();
+
+    match bytes.iter().rposition(|byte| *byte == b'\n') {
+        Some(newline) => bytes.len() - newline - 1,
+        None => 0,
+    }
+}
+
+/// Decode the raw literal parts of a string. When `indentation` is given, the string is a heredoc
+/// or nowdoc whose lines need to be dedented and whose last newline belongs to the closing label.
+fn normalize(
+    parts: &[StringPart],
+    quote: StringQuote,
+    indentation: Option<usize>,
+) -> StringParts<'_> {
+    let mut normalized = Vec::with_capacity(parts.len());
+    let mut at_line_start = true;
+
+    for (i, part) in parts.iter().enumerate() {
+        let literal = match part {
+            StringPart::Literal(literal) => literal,
+            StringPart::Expression(_) => {
+                normalized.push(Cow::Borrowed(part));
+                at_line_start = false;
+                continue;
+            }
+        };
+
+        let mut raw = literal.value.as_bytes();
+
+        let raw = match indentation {
+            Some(indentation) => {
+                if i == parts.len() - 1 {
+                    raw = match raw.iter().rposition(|byte| *byte == b'\n') {
+                        Some(newline) => &raw[..newline],
+                        None => &[],
+                    };
+                }
+
+                let dedented = dedent(raw, indentation, at_line_start);
+                at_line_start = literal.value.as_bytes().last() == Some(&b'\n');
+                Cow::Owned(dedented)
+            }
+            None => Cow::Borrowed(raw),
+        };
+
+        let value = LiteralStringValue::new(raw.as_ref().into(), quote)
+            .value()
+            .to_bytestring();
+
+        if let Some(part) = literal_part(literal.id, literal.span, value) {
+            normalized.push(Cow::Owned(part));
+        }
+    }
+
+    StringParts::new(normalized)
+}
+
+/// Remove up to `indentation` spaces or tabs from the start of every line.
+fn dedent(raw: &[u8], indentation: usize, at_line_start: bool) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(raw.len());
+    let mut skip = if at_line_start { indentation } else { 0 };
+
+    for byte in raw {
+        if skip > 0 && matches!(byte, b' ' | b'\t') {
+            skip -= 1;
+            continue;
+        }
+
+        skip = if *byte == b'\n' { indentation } else { 0 };
+        bytes.push(*byte);
+    }
+
+    bytes
+}
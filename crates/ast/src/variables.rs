@@ -1,6 +1,6 @@
 use crate::{NodeId, SimpleVariable, Variable};
 use pxp_bytestring::ByteString;
-use pxp_span::{IsSpanned, Span};
+use pxp_span::Span;
 
 impl SimpleVariable {
     pub fn missing(id: NodeId, span: Span) -> Self {
@@ -15,14 +15,20 @@ impl SimpleVariable {
     pub fn is_missing(&self) -> bool {
         self.symbol.is_empty()
     }
-}
 
-impl IsSpanned for Variable {
-    fn span(&self) -> Span {
-        match self {
-            Self::SimpleVariable(simple) => simple.span,
-            Self::VariableVariable(dynamic) => dynamic.span,
-            Self::BracedVariableVariable(dynamic) => dynamic.span,
+    /// Check if the given name, without the leading `$`, could be written as a simple variable.
+    /// Functions like `extract()` skip names that couldn't.
+    pub fn is_valid_name(name: &[u8]) -> bool {
+        let is_start = |byte: &u8| byte.is_ascii_alphabetic() || *byte == b'_' || *byte >= 0x80;
+
+        match name.split_first() {
+            Some((first, rest)) => {
+                is_start(first)
+                    && rest
+                        .iter()
+                        .all(|byte| is_start(byte) || byte.is_ascii_digit())
+            }
+            None => false,
         }
     }
 }
@@ -0,0 +1,1233 @@
+// This file is automatically generated by the generate-visitor.php script.
+// Do not modify this file directly.
+#![allow(unused, clippy::single_match)]
+
+use super::walk_control_flow::*;
+use super::Traversal;
+use crate::*;
+use pxp_span::Span;
+use pxp_type::Type;
+
+pub trait ControlFlowVisitor {
+    fn visit(&mut self, node: &[Statement]) -> Traversal {
+        walk(self, node)
+    }
+
+    fn visit_statement(&mut self, node: &Statement) -> Traversal {
+        walk_statement(self, node)
+    }
+
+    fn visit_statement_kind(&mut self, node: &StatementKind) -> Traversal {
+        walk_statement_kind(self, node)
+    }
+
+    fn visit_expression(&mut self, node: &Expression) -> Traversal {
+        walk_expression(self, node)
+    }
+
+    fn visit_expression_kind(&mut self, node: &ExpressionKind) -> Traversal {
+        walk_expression_kind(self, node)
+    }
+
+    fn visit_missing_expression(&mut self, node: &MissingExpression) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_static_expression(&mut self, node: &StaticExpression) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_self_expression(&mut self, node: &SelfExpression) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_parent_expression(&mut self, node: &ParentExpression) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_comment_statement(&mut self, node: &CommentStatement) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_inline_html_statement(&mut self, node: &InlineHtmlStatement) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_full_opening_tag_statement(&mut self, node: &FullOpeningTagStatement) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_short_opening_tag_statement(&mut self, node: &ShortOpeningTagStatement) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_echo_opening_tag_statement(&mut self, node: &EchoOpeningTagStatement) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_closing_tag_statement(&mut self, node: &ClosingTagStatement) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_expression_statement(&mut self, node: &ExpressionStatement) -> Traversal {
+        walk_expression_statement(self, node)
+    }
+
+    fn visit_global_statement(&mut self, node: &GlobalStatement) -> Traversal {
+        walk_global_statement(self, node)
+    }
+
+    fn visit_block_statement(&mut self, node: &BlockStatement) -> Traversal {
+        walk_block_statement(self, node)
+    }
+
+    fn visit_cast_kind(&mut self, node: &CastKind) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_case(&mut self, node: &Case) -> Traversal {
+        walk_case(self, node)
+    }
+
+    fn visit_case_separator(&mut self, node: &CaseSeparator) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_use(&mut self, node: &Use) -> Traversal {
+        walk_use(self, node)
+    }
+
+    fn visit_use_kind(&mut self, node: &UseKind) -> Traversal {
+        walk_use_kind(self, node)
+    }
+
+    fn visit_eval_expression(&mut self, node: &EvalExpression) -> Traversal {
+        walk_eval_expression(self, node)
+    }
+
+    fn visit_empty_expression(&mut self, node: &EmptyExpression) -> Traversal {
+        walk_empty_expression(self, node)
+    }
+
+    fn visit_die_expression(&mut self, node: &DieExpression) -> Traversal {
+        walk_die_expression(self, node)
+    }
+
+    fn visit_exit_expression(&mut self, node: &ExitExpression) -> Traversal {
+        walk_exit_expression(self, node)
+    }
+
+    fn visit_isset_expression(&mut self, node: &IssetExpression) -> Traversal {
+        walk_isset_expression(self, node)
+    }
+
+    fn visit_unset_expression(&mut self, node: &UnsetExpression) -> Traversal {
+        walk_unset_expression(self, node)
+    }
+
+    fn visit_print_expression(&mut self, node: &PrintExpression) -> Traversal {
+        walk_print_expression(self, node)
+    }
+
+    fn visit_concat_expression(&mut self, node: &ConcatExpression) -> Traversal {
+        walk_concat_expression(self, node)
+    }
+
+    fn visit_instanceof_expression(&mut self, node: &InstanceofExpression) -> Traversal {
+        walk_instanceof_expression(self, node)
+    }
+
+    fn visit_reference_expression(&mut self, node: &ReferenceExpression) -> Traversal {
+        walk_reference_expression(self, node)
+    }
+
+    fn visit_parenthesized_expression(&mut self, node: &ParenthesizedExpression) -> Traversal {
+        walk_parenthesized_expression(self, node)
+    }
+
+    fn visit_error_suppress_expression(&mut self, node: &ErrorSuppressExpression) -> Traversal {
+        walk_error_suppress_expression(self, node)
+    }
+
+    fn visit_include_expression(&mut self, node: &IncludeExpression) -> Traversal {
+        walk_include_expression(self, node)
+    }
+
+    fn visit_include_once_expression(&mut self, node: &IncludeOnceExpression) -> Traversal {
+        walk_include_once_expression(self, node)
+    }
+
+    fn visit_require_expression(&mut self, node: &RequireExpression) -> Traversal {
+        walk_require_expression(self, node)
+    }
+
+    fn visit_require_once_expression(&mut self, node: &RequireOnceExpression) -> Traversal {
+        walk_require_once_expression(self, node)
+    }
+
+    fn visit_function_call_expression(&mut self, node: &FunctionCallExpression) -> Traversal {
+        walk_function_call_expression(self, node)
+    }
+
+    fn visit_function_closure_creation_expression(
+        &mut self,
+        node: &FunctionClosureCreationExpression,
+    ) -> Traversal {
+        walk_function_closure_creation_expression(self, node)
+    }
+
+    fn visit_method_call_expression(&mut self, node: &MethodCallExpression) -> Traversal {
+        walk_method_call_expression(self, node)
+    }
+
+    fn visit_method_closure_creation_expression(
+        &mut self,
+        node: &MethodClosureCreationExpression,
+    ) -> Traversal {
+        walk_method_closure_creation_expression(self, node)
+    }
+
+    fn visit_nullsafe_method_call_expression(
+        &mut self,
+        node: &NullsafeMethodCallExpression,
+    ) -> Traversal {
+        walk_nullsafe_method_call_expression(self, node)
+    }
+
+    fn visit_static_method_call_expression(
+        &mut self,
+        node: &StaticMethodCallExpression,
+    ) -> Traversal {
+        walk_static_method_call_expression(self, node)
+    }
+
+    fn visit_static_variable_method_call_expression(
+        &mut self,
+        node: &StaticVariableMethodCallExpression,
+    ) -> Traversal {
+        walk_static_variable_method_call_expression(self, node)
+    }
+
+    fn visit_static_method_closure_creation_expression(
+        &mut self,
+        node: &StaticMethodClosureCreationExpression,
+    ) -> Traversal {
+        walk_static_method_closure_creation_expression(self, node)
+    }
+
+    fn visit_static_variable_method_closure_creation_expression(
+        &mut self,
+        node: &StaticVariableMethodClosureCreationExpression,
+    ) -> Traversal {
+        walk_static_variable_method_closure_creation_expression(self, node)
+    }
+
+    fn visit_property_fetch_expression(&mut self, node: &PropertyFetchExpression) -> Traversal {
+        walk_property_fetch_expression(self, node)
+    }
+
+    fn visit_nullsafe_property_fetch_expression(
+        &mut self,
+        node: &NullsafePropertyFetchExpression,
+    ) -> Traversal {
+        walk_nullsafe_property_fetch_expression(self, node)
+    }
+
+    fn visit_static_property_fetch_expression(
+        &mut self,
+        node: &StaticPropertyFetchExpression,
+    ) -> Traversal {
+        walk_static_property_fetch_expression(self, node)
+    }
+
+    fn visit_constant_fetch_expression(&mut self, node: &ConstantFetchExpression) -> Traversal {
+        walk_constant_fetch_expression(self, node)
+    }
+
+    fn visit_array_expression(&mut self, node: &ArrayExpression) -> Traversal {
+        walk_array_expression(self, node)
+    }
+
+    fn visit_array_kind(&mut self, node: &ArrayKind) -> Traversal {
+        walk_array_kind(self, node)
+    }
+
+    fn visit_array_kind_short(&mut self, node: &ArrayKindShort) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_array_kind_long(&mut self, node: &ArrayKindLong) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_list_expression(&mut self, node: &ListExpression) -> Traversal {
+        walk_list_expression(self, node)
+    }
+
+    fn visit_new_expression(&mut self, node: &NewExpression) -> Traversal {
+        walk_new_expression(self, node)
+    }
+
+    fn visit_interpolated_string_expression(
+        &mut self,
+        node: &InterpolatedStringExpression,
+    ) -> Traversal {
+        walk_interpolated_string_expression(self, node)
+    }
+
+    fn visit_heredoc_expression(&mut self, node: &HeredocExpression) -> Traversal {
+        walk_heredoc_expression(self, node)
+    }
+
+    fn visit_nowdoc_expression(&mut self, node: &NowdocExpression) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_shell_exec_expression(&mut self, node: &ShellExecExpression) -> Traversal {
+        walk_shell_exec_expression(self, node)
+    }
+
+    fn visit_bool_expression(&mut self, node: &BoolExpression) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_array_index_expression(&mut self, node: &ArrayIndexExpression) -> Traversal {
+        walk_array_index_expression(self, node)
+    }
+
+    fn visit_short_ternary_expression(&mut self, node: &ShortTernaryExpression) -> Traversal {
+        walk_short_ternary_expression(self, node)
+    }
+
+    fn visit_ternary_expression(&mut self, node: &TernaryExpression) -> Traversal {
+        walk_ternary_expression(self, node)
+    }
+
+    fn visit_coalesce_expression(&mut self, node: &CoalesceExpression) -> Traversal {
+        walk_coalesce_expression(self, node)
+    }
+
+    fn visit_clone_expression(&mut self, node: &CloneExpression) -> Traversal {
+        walk_clone_expression(self, node)
+    }
+
+    fn visit_match_expression(&mut self, node: &MatchExpression) -> Traversal {
+        walk_match_expression(self, node)
+    }
+
+    fn visit_throw_expression(&mut self, node: &ThrowExpression) -> Traversal {
+        walk_throw_expression(self, node)
+    }
+
+    fn visit_yield_expression(&mut self, node: &YieldExpression) -> Traversal {
+        walk_yield_expression(self, node)
+    }
+
+    fn visit_yield_from_expression(&mut self, node: &YieldFromExpression) -> Traversal {
+        walk_yield_from_expression(self, node)
+    }
+
+    fn visit_cast_expression(&mut self, node: &CastExpression) -> Traversal {
+        walk_cast_expression(self, node)
+    }
+
+    fn visit_default_match_arm(&mut self, node: &DefaultMatchArm) -> Traversal {
+        walk_default_match_arm(self, node)
+    }
+
+    fn visit_match_arm(&mut self, node: &MatchArm) -> Traversal {
+        walk_match_arm(self, node)
+    }
+
+    fn visit_magic_constant_expression(&mut self, node: &MagicConstantExpression) -> Traversal {
+        walk_magic_constant_expression(self, node)
+    }
+
+    fn visit_magic_constant_kind(&mut self, node: &MagicConstantKind) -> Traversal {
+        walk_magic_constant_kind(self, node)
+    }
+
+    fn visit_string_part(&mut self, node: &StringPart) -> Traversal {
+        walk_string_part(self, node)
+    }
+
+    fn visit_literal_string_part(&mut self, node: &LiteralStringPart) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_expression_string_part(&mut self, node: &ExpressionStringPart) -> Traversal {
+        walk_expression_string_part(self, node)
+    }
+
+    fn visit_array_item(&mut self, node: &ArrayItem) -> Traversal {
+        walk_array_item(self, node)
+    }
+
+    fn visit_array_item_value(&mut self, node: &ArrayItemValue) -> Traversal {
+        walk_array_item_value(self, node)
+    }
+
+    fn visit_array_item_referenced_value(&mut self, node: &ArrayItemReferencedValue) -> Traversal {
+        walk_array_item_referenced_value(self, node)
+    }
+
+    fn visit_array_item_spread_value(&mut self, node: &ArrayItemSpreadValue) -> Traversal {
+        walk_array_item_spread_value(self, node)
+    }
+
+    fn visit_array_item_key_value(&mut self, node: &ArrayItemKeyValue) -> Traversal {
+        walk_array_item_key_value(self, node)
+    }
+
+    fn visit_array_item_referenced_key_value(
+        &mut self,
+        node: &ArrayItemReferencedKeyValue,
+    ) -> Traversal {
+        walk_array_item_referenced_key_value(self, node)
+    }
+
+    fn visit_list_entry(&mut self, node: &ListEntry) -> Traversal {
+        walk_list_entry(self, node)
+    }
+
+    fn visit_list_entry_value(&mut self, node: &ListEntryValue) -> Traversal {
+        walk_list_entry_value(self, node)
+    }
+
+    fn visit_list_entry_key_value(&mut self, node: &ListEntryKeyValue) -> Traversal {
+        walk_list_entry_key_value(self, node)
+    }
+
+    fn visit_positional_argument(&mut self, node: &PositionalArgument) -> Traversal {
+        walk_positional_argument(self, node)
+    }
+
+    fn visit_named_argument(&mut self, node: &NamedArgument) -> Traversal {
+        walk_named_argument(self, node)
+    }
+
+    fn visit_argument(&mut self, node: &Argument) -> Traversal {
+        walk_argument(self, node)
+    }
+
+    fn visit_argument_list(&mut self, node: &ArgumentList) -> Traversal {
+        walk_argument_list(self, node)
+    }
+
+    fn visit_single_argument(&mut self, node: &SingleArgument) -> Traversal {
+        walk_single_argument(self, node)
+    }
+
+    fn visit_argument_placeholder(&mut self, node: &ArgumentPlaceholder) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_attribute(&mut self, node: &Attribute) -> Traversal {
+        walk_attribute(self, node)
+    }
+
+    fn visit_attribute_group(&mut self, node: &AttributeGroup) -> Traversal {
+        walk_attribute_group(self, node)
+    }
+
+    fn visit_class_body(&mut self, node: &ClassBody) -> Traversal {
+        walk_class_body(self, node)
+    }
+
+    fn visit_class_statement(&mut self, node: &ClassStatement) -> Traversal {
+        walk_class_statement(self, node)
+    }
+
+    fn visit_anonymous_class_body(&mut self, node: &AnonymousClassBody) -> Traversal {
+        walk_anonymous_class_body(self, node)
+    }
+
+    fn visit_anonymous_class_expression(&mut self, node: &AnonymousClassExpression) -> Traversal {
+        walk_anonymous_class_expression(self, node)
+    }
+
+    fn visit_class_extends(&mut self, node: &ClassExtends) -> Traversal {
+        walk_class_extends(self, node)
+    }
+
+    fn visit_class_implements(&mut self, node: &ClassImplements) -> Traversal {
+        walk_class_implements(self, node)
+    }
+
+    fn visit_classish_member(&mut self, node: &ClassishMember) -> Traversal {
+        walk_classish_member(self, node)
+    }
+
+    fn visit_method(&mut self, node: &Method) -> Traversal {
+        walk_method(self, node)
+    }
+
+    fn visit_method_body(&mut self, node: &MethodBody) -> Traversal {
+        walk_method_body(self, node)
+    }
+
+    fn visit_method_body_kind(&mut self, node: &MethodBodyKind) -> Traversal {
+        walk_method_body_kind(self, node)
+    }
+
+    fn visit_missing_method_body(&mut self, node: &MissingMethodBody) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_abstract_method_body(&mut self, node: &AbstractMethodBody) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_concrete_method_body(&mut self, node: &ConcreteMethodBody) -> Traversal {
+        walk_concrete_method_body(self, node)
+    }
+
+    fn visit_method_parameter_list(&mut self, node: &MethodParameterList) -> Traversal {
+        walk_method_parameter_list(self, node)
+    }
+
+    fn visit_method_parameter(&mut self, node: &MethodParameter) -> Traversal {
+        walk_method_parameter(self, node)
+    }
+
+    fn visit_missing_classish_member(&mut self, node: &MissingClassishMember) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_constant_entry(&mut self, node: &ConstantEntry) -> Traversal {
+        walk_constant_entry(self, node)
+    }
+
+    fn visit_classish_constant_entry(&mut self, node: &ClassishConstantEntry) -> Traversal {
+        walk_classish_constant_entry(self, node)
+    }
+
+    fn visit_constant_statement(&mut self, node: &ConstantStatement) -> Traversal {
+        walk_constant_statement(self, node)
+    }
+
+    fn visit_classish_constant(&mut self, node: &ClassishConstant) -> Traversal {
+        walk_classish_constant(self, node)
+    }
+
+    fn visit_if_statement(&mut self, node: &IfStatement) -> Traversal {
+        walk_if_statement(self, node)
+    }
+
+    fn visit_if_statement_body(&mut self, node: &IfStatementBody) -> Traversal {
+        walk_if_statement_body(self, node)
+    }
+
+    fn visit_if_statement_body_statement(&mut self, node: &IfStatementBodyStatement) -> Traversal {
+        walk_if_statement_body_statement(self, node)
+    }
+
+    fn visit_if_statement_body_block(&mut self, node: &IfStatementBodyBlock) -> Traversal {
+        walk_if_statement_body_block(self, node)
+    }
+
+    fn visit_if_statement_else_if(&mut self, node: &IfStatementElseIf) -> Traversal {
+        walk_if_statement_else_if(self, node)
+    }
+
+    fn visit_if_statement_else(&mut self, node: &IfStatementElse) -> Traversal {
+        walk_if_statement_else(self, node)
+    }
+
+    fn visit_if_statement_else_if_block(&mut self, node: &IfStatementElseIfBlock) -> Traversal {
+        walk_if_statement_else_if_block(self, node)
+    }
+
+    fn visit_if_statement_else_block(&mut self, node: &IfStatementElseBlock) -> Traversal {
+        walk_if_statement_else_block(self, node)
+    }
+
+    fn visit_data_type(&mut self, node: &DataType) -> Traversal {
+        walk_data_type(self, node)
+    }
+
+    fn visit_declare_entry(&mut self, node: &DeclareEntry) -> Traversal {
+        walk_declare_entry(self, node)
+    }
+
+    fn visit_declare_entry_group(&mut self, node: &DeclareEntryGroup) -> Traversal {
+        walk_declare_entry_group(self, node)
+    }
+
+    fn visit_declare_body(&mut self, node: &DeclareBody) -> Traversal {
+        walk_declare_body(self, node)
+    }
+
+    fn visit_declare_body_noop(&mut self, node: &DeclareBodyNoop) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_declare_body_braced(&mut self, node: &DeclareBodyBraced) -> Traversal {
+        walk_declare_body_braced(self, node)
+    }
+
+    fn visit_declare_body_statement(&mut self, node: &DeclareBodyStatement) -> Traversal {
+        walk_declare_body_statement(self, node)
+    }
+
+    fn visit_declare_body_block(&mut self, node: &DeclareBodyBlock) -> Traversal {
+        walk_declare_body_block(self, node)
+    }
+
+    fn visit_declare_statement(&mut self, node: &DeclareStatement) -> Traversal {
+        walk_declare_statement(self, node)
+    }
+
+    fn visit_unit_enum_case(&mut self, node: &UnitEnumCase) -> Traversal {
+        walk_unit_enum_case(self, node)
+    }
+
+    fn visit_unit_enum_member(&mut self, node: &UnitEnumMember) -> Traversal {
+        walk_unit_enum_member(self, node)
+    }
+
+    fn visit_unit_enum_body(&mut self, node: &UnitEnumBody) -> Traversal {
+        walk_unit_enum_body(self, node)
+    }
+
+    fn visit_unit_enum_statement(&mut self, node: &UnitEnumStatement) -> Traversal {
+        walk_unit_enum_statement(self, node)
+    }
+
+    fn visit_backed_enum_case(&mut self, node: &BackedEnumCase) -> Traversal {
+        walk_backed_enum_case(self, node)
+    }
+
+    fn visit_backed_enum_member(&mut self, node: &BackedEnumMember) -> Traversal {
+        walk_backed_enum_member(self, node)
+    }
+
+    fn visit_backed_enum_body(&mut self, node: &BackedEnumBody) -> Traversal {
+        walk_backed_enum_body(self, node)
+    }
+
+    fn visit_backed_enum_statement(&mut self, node: &BackedEnumStatement) -> Traversal {
+        walk_backed_enum_statement(self, node)
+    }
+
+    fn visit_backed_enum_type(&mut self, node: &BackedEnumType) -> Traversal {
+        walk_backed_enum_type(self, node)
+    }
+
+    fn visit_return_type(&mut self, node: &ReturnType) -> Traversal {
+        walk_return_type(self, node)
+    }
+
+    fn visit_function_parameter(&mut self, node: &FunctionParameter) -> Traversal {
+        walk_function_parameter(self, node)
+    }
+
+    fn visit_function_parameter_list(&mut self, node: &FunctionParameterList) -> Traversal {
+        walk_function_parameter_list(self, node)
+    }
+
+    fn visit_function_body(&mut self, node: &FunctionBody) -> Traversal {
+        walk_function_body(self, node)
+    }
+
+    fn visit_function_statement(&mut self, node: &FunctionStatement) -> Traversal {
+        walk_function_statement(self, node)
+    }
+
+    fn visit_closure_use_variable(&mut self, node: &ClosureUseVariable) -> Traversal {
+        walk_closure_use_variable(self, node)
+    }
+
+    fn visit_closure_use(&mut self, node: &ClosureUse) -> Traversal {
+        walk_closure_use(self, node)
+    }
+
+    fn visit_closure_expression(&mut self, node: &ClosureExpression) -> Traversal {
+        walk_closure_expression(self, node)
+    }
+
+    fn visit_arrow_function_expression(&mut self, node: &ArrowFunctionExpression) -> Traversal {
+        walk_arrow_function_expression(self, node)
+    }
+
+    fn visit_label_statement(&mut self, node: &LabelStatement) -> Traversal {
+        walk_label_statement(self, node)
+    }
+
+    fn visit_goto_statement(&mut self, node: &GotoStatement) -> Traversal {
+        walk_goto_statement(self, node)
+    }
+
+    fn visit_identifier(&mut self, node: &Identifier) -> Traversal {
+        walk_identifier(self, node)
+    }
+
+    fn visit_simple_identifier(&mut self, node: &SimpleIdentifier) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_dynamic_identifier(&mut self, node: &DynamicIdentifier) -> Traversal {
+        walk_dynamic_identifier(self, node)
+    }
+
+    fn visit_interface_extends(&mut self, node: &InterfaceExtends) -> Traversal {
+        walk_interface_extends(self, node)
+    }
+
+    fn visit_interface_body(&mut self, node: &InterfaceBody) -> Traversal {
+        walk_interface_body(self, node)
+    }
+
+    fn visit_interface_statement(&mut self, node: &InterfaceStatement) -> Traversal {
+        walk_interface_statement(self, node)
+    }
+
+    fn visit_literal(&mut self, node: &Literal) -> Traversal {
+        walk_literal(self, node)
+    }
+
+    fn visit_literal_kind(&mut self, node: &LiteralKind) -> Traversal {
+        walk_literal_kind(self, node)
+    }
+
+    fn visit_foreach_statement(&mut self, node: &ForeachStatement) -> Traversal {
+        walk_foreach_statement(self, node)
+    }
+
+    fn visit_foreach_statement_iterator(&mut self, node: &ForeachStatementIterator) -> Traversal {
+        walk_foreach_statement_iterator(self, node)
+    }
+
+    fn visit_foreach_statement_iterator_value(
+        &mut self,
+        node: &ForeachStatementIteratorValue,
+    ) -> Traversal {
+        walk_foreach_statement_iterator_value(self, node)
+    }
+
+    fn visit_foreach_statement_iterator_key_and_value(
+        &mut self,
+        node: &ForeachStatementIteratorKeyAndValue,
+    ) -> Traversal {
+        walk_foreach_statement_iterator_key_and_value(self, node)
+    }
+
+    fn visit_foreach_statement_body(&mut self, node: &ForeachStatementBody) -> Traversal {
+        walk_foreach_statement_body(self, node)
+    }
+
+    fn visit_foreach_statement_body_statement(
+        &mut self,
+        node: &ForeachStatementBodyStatement,
+    ) -> Traversal {
+        walk_foreach_statement_body_statement(self, node)
+    }
+
+    fn visit_foreach_statement_body_block(
+        &mut self,
+        node: &ForeachStatementBodyBlock,
+    ) -> Traversal {
+        walk_foreach_statement_body_block(self, node)
+    }
+
+    fn visit_for_statement(&mut self, node: &ForStatement) -> Traversal {
+        walk_for_statement(self, node)
+    }
+
+    fn visit_for_statement_iterator(&mut self, node: &ForStatementIterator) -> Traversal {
+        walk_for_statement_iterator(self, node)
+    }
+
+    fn visit_for_statement_body(&mut self, node: &ForStatementBody) -> Traversal {
+        walk_for_statement_body(self, node)
+    }
+
+    fn visit_for_statement_body_statement(
+        &mut self,
+        node: &ForStatementBodyStatement,
+    ) -> Traversal {
+        walk_for_statement_body_statement(self, node)
+    }
+
+    fn visit_for_statement_body_block(&mut self, node: &ForStatementBodyBlock) -> Traversal {
+        walk_for_statement_body_block(self, node)
+    }
+
+    fn visit_do_while_statement(&mut self, node: &DoWhileStatement) -> Traversal {
+        walk_do_while_statement(self, node)
+    }
+
+    fn visit_while_statement(&mut self, node: &WhileStatement) -> Traversal {
+        walk_while_statement(self, node)
+    }
+
+    fn visit_while_statement_body(&mut self, node: &WhileStatementBody) -> Traversal {
+        walk_while_statement_body(self, node)
+    }
+
+    fn visit_while_statement_body_statement(
+        &mut self,
+        node: &WhileStatementBodyStatement,
+    ) -> Traversal {
+        walk_while_statement_body_statement(self, node)
+    }
+
+    fn visit_while_statement_body_block(&mut self, node: &WhileStatementBodyBlock) -> Traversal {
+        walk_while_statement_body_block(self, node)
+    }
+
+    fn visit_level(&mut self, node: &Level) -> Traversal {
+        walk_level(self, node)
+    }
+
+    fn visit_literal_level(&mut self, node: &LiteralLevel) -> Traversal {
+        walk_literal_level(self, node)
+    }
+
+    fn visit_parenthesized_level(&mut self, node: &ParenthesizedLevel) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_break_statement(&mut self, node: &BreakStatement) -> Traversal {
+        walk_break_statement(self, node)
+    }
+
+    fn visit_continue_statement(&mut self, node: &ContinueStatement) -> Traversal {
+        walk_continue_statement(self, node)
+    }
+
+    fn visit_visibility_modifier(&mut self, node: &VisibilityModifier) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_promoted_property_modifier(&mut self, node: &PromotedPropertyModifier) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_promoted_property_modifier_group(
+        &mut self,
+        node: &PromotedPropertyModifierGroup,
+    ) -> Traversal {
+        walk_promoted_property_modifier_group(self, node)
+    }
+
+    fn visit_property_modifier(&mut self, node: &PropertyModifier) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_property_modifier_group(&mut self, node: &PropertyModifierGroup) -> Traversal {
+        walk_property_modifier_group(self, node)
+    }
+
+    fn visit_method_modifier(&mut self, node: &MethodModifier) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_method_modifier_group(&mut self, node: &MethodModifierGroup) -> Traversal {
+        walk_method_modifier_group(self, node)
+    }
+
+    fn visit_class_modifier(&mut self, node: &ClassModifier) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_class_modifier_group(&mut self, node: &ClassModifierGroup) -> Traversal {
+        walk_class_modifier_group(self, node)
+    }
+
+    fn visit_constant_modifier(&mut self, node: &ConstantModifier) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_constant_modifier_group(&mut self, node: &ConstantModifierGroup) -> Traversal {
+        walk_constant_modifier_group(self, node)
+    }
+
+    fn visit_unbraced_namespace(&mut self, node: &UnbracedNamespace) -> Traversal {
+        walk_unbraced_namespace(self, node)
+    }
+
+    fn visit_braced_namespace(&mut self, node: &BracedNamespace) -> Traversal {
+        walk_braced_namespace(self, node)
+    }
+
+    fn visit_braced_namespace_body(&mut self, node: &BracedNamespaceBody) -> Traversal {
+        walk_braced_namespace_body(self, node)
+    }
+
+    fn visit_namespace_statement(&mut self, node: &NamespaceStatement) -> Traversal {
+        walk_namespace_statement(self, node)
+    }
+
+    fn visit_arithmetic_operation_expression(
+        &mut self,
+        node: &ArithmeticOperationExpression,
+    ) -> Traversal {
+        walk_arithmetic_operation_expression(self, node)
+    }
+
+    fn visit_arithmetic_operation_kind(&mut self, node: &ArithmeticOperationKind) -> Traversal {
+        walk_arithmetic_operation_kind(self, node)
+    }
+
+    fn visit_assignment_operation_expression(
+        &mut self,
+        node: &AssignmentOperationExpression,
+    ) -> Traversal {
+        walk_assignment_operation_expression(self, node)
+    }
+
+    fn visit_assignment_operation_kind(&mut self, node: &AssignmentOperationKind) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_bitwise_operation_expression(
+        &mut self,
+        node: &BitwiseOperationExpression,
+    ) -> Traversal {
+        walk_bitwise_operation_expression(self, node)
+    }
+
+    fn visit_bitwise_operation_kind(&mut self, node: &BitwiseOperationKind) -> Traversal {
+        walk_bitwise_operation_kind(self, node)
+    }
+
+    fn visit_comparison_operation_expression(
+        &mut self,
+        node: &ComparisonOperationExpression,
+    ) -> Traversal {
+        walk_comparison_operation_expression(self, node)
+    }
+
+    fn visit_comparison_operation_kind(&mut self, node: &ComparisonOperationKind) -> Traversal {
+        walk_comparison_operation_kind(self, node)
+    }
+
+    fn visit_logical_operation_expression(
+        &mut self,
+        node: &LogicalOperationExpression,
+    ) -> Traversal {
+        walk_logical_operation_expression(self, node)
+    }
+
+    fn visit_logical_operation_kind(&mut self, node: &LogicalOperationKind) -> Traversal {
+        walk_logical_operation_kind(self, node)
+    }
+
+    fn visit_name(&mut self, node: &Name) -> Traversal {
+        walk_name(self, node)
+    }
+
+    fn visit_name_kind(&mut self, node: &NameKind) -> Traversal {
+        walk_name_kind(self, node)
+    }
+
+    fn visit_special_name(&mut self, node: &SpecialName) -> Traversal {
+        walk_special_name(self, node)
+    }
+
+    fn visit_special_name_kind(&mut self, node: &SpecialNameKind) -> Traversal {
+        walk_special_name_kind(self, node)
+    }
+
+    fn visit_unresolved_name(&mut self, node: &UnresolvedName) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_resolved_name(&mut self, node: &ResolvedName) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_property(&mut self, node: &Property) -> Traversal {
+        walk_property(self, node)
+    }
+
+    fn visit_simple_property(&mut self, node: &SimpleProperty) -> Traversal {
+        walk_simple_property(self, node)
+    }
+
+    fn visit_hooked_property(&mut self, node: &HookedProperty) -> Traversal {
+        walk_hooked_property(self, node)
+    }
+
+    fn visit_property_hook_list(&mut self, node: &PropertyHookList) -> Traversal {
+        walk_property_hook_list(self, node)
+    }
+
+    fn visit_property_hook(&mut self, node: &PropertyHook) -> Traversal {
+        walk_property_hook(self, node)
+    }
+
+    fn visit_property_hook_body(&mut self, node: &PropertyHookBody) -> Traversal {
+        walk_property_hook_body(self, node)
+    }
+
+    fn visit_concrete_property_hook_body(&mut self, node: &ConcretePropertyHookBody) -> Traversal {
+        walk_concrete_property_hook_body(self, node)
+    }
+
+    fn visit_concrete_property_hook_body_block(
+        &mut self,
+        node: &ConcretePropertyHookBodyBlock,
+    ) -> Traversal {
+        walk_concrete_property_hook_body_block(self, node)
+    }
+
+    fn visit_concrete_property_hook_body_expression(
+        &mut self,
+        node: &ConcretePropertyHookBodyExpression,
+    ) -> Traversal {
+        walk_concrete_property_hook_body_expression(self, node)
+    }
+
+    fn visit_property_hook_kind(&mut self, node: &PropertyHookKind) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_property_entry(&mut self, node: &PropertyEntry) -> Traversal {
+        walk_property_entry(self, node)
+    }
+
+    fn visit_property_entry_kind(&mut self, node: &PropertyEntryKind) -> Traversal {
+        walk_property_entry_kind(self, node)
+    }
+
+    fn visit_uninitialized_property_entry(
+        &mut self,
+        node: &UninitializedPropertyEntry,
+    ) -> Traversal {
+        walk_uninitialized_property_entry(self, node)
+    }
+
+    fn visit_initialized_property_entry(&mut self, node: &InitializedPropertyEntry) -> Traversal {
+        walk_initialized_property_entry(self, node)
+    }
+
+    fn visit_trait_body(&mut self, node: &TraitBody) -> Traversal {
+        walk_trait_body(self, node)
+    }
+
+    fn visit_trait_statement(&mut self, node: &TraitStatement) -> Traversal {
+        walk_trait_statement(self, node)
+    }
+
+    fn visit_trait_usage(&mut self, node: &TraitUsage) -> Traversal {
+        walk_trait_usage(self, node)
+    }
+
+    fn visit_trait_usage_adaptation(&mut self, node: &TraitUsageAdaptation) -> Traversal {
+        walk_trait_usage_adaptation(self, node)
+    }
+
+    fn visit_trait_usage_adaptation_kind(&mut self, node: &TraitUsageAdaptationKind) -> Traversal {
+        walk_trait_usage_adaptation_kind(self, node)
+    }
+
+    fn visit_trait_usage_adaptation_alias(
+        &mut self,
+        node: &TraitUsageAdaptationAlias,
+    ) -> Traversal {
+        walk_trait_usage_adaptation_alias(self, node)
+    }
+
+    fn visit_trait_usage_adaptation_visibility(
+        &mut self,
+        node: &TraitUsageAdaptationVisibility,
+    ) -> Traversal {
+        walk_trait_usage_adaptation_visibility(self, node)
+    }
+
+    fn visit_trait_usage_adaptation_precedence(
+        &mut self,
+        node: &TraitUsageAdaptationPrecedence,
+    ) -> Traversal {
+        walk_trait_usage_adaptation_precedence(self, node)
+    }
+
+    fn visit_catch_type(&mut self, node: &CatchType) -> Traversal {
+        walk_catch_type(self, node)
+    }
+
+    fn visit_catch_type_kind(&mut self, node: &CatchTypeKind) -> Traversal {
+        walk_catch_type_kind(self, node)
+    }
+
+    fn visit_catch_type_kind_identifier(&mut self, node: &CatchTypeKindIdentifier) -> Traversal {
+        walk_catch_type_kind_identifier(self, node)
+    }
+
+    fn visit_catch_type_kind_union(&mut self, node: &CatchTypeKindUnion) -> Traversal {
+        walk_catch_type_kind_union(self, node)
+    }
+
+    fn visit_try_statement(&mut self, node: &TryStatement) -> Traversal {
+        walk_try_statement(self, node)
+    }
+
+    fn visit_catch_block(&mut self, node: &CatchBlock) -> Traversal {
+        walk_catch_block(self, node)
+    }
+
+    fn visit_finally_block(&mut self, node: &FinallyBlock) -> Traversal {
+        walk_finally_block(self, node)
+    }
+
+    fn visit_variable(&mut self, node: &Variable) -> Traversal {
+        walk_variable(self, node)
+    }
+
+    fn visit_simple_variable(&mut self, node: &SimpleVariable) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_variable_variable(&mut self, node: &VariableVariable) -> Traversal {
+        walk_variable_variable(self, node)
+    }
+
+    fn visit_braced_variable_variable(&mut self, node: &BracedVariableVariable) -> Traversal {
+        walk_braced_variable_variable(self, node)
+    }
+
+    fn visit_ending(&mut self, node: &Ending) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_static_statement(&mut self, node: &StaticStatement) -> Traversal {
+        walk_static_statement(self, node)
+    }
+
+    fn visit_switch_statement(&mut self, node: &SwitchStatement) -> Traversal {
+        walk_switch_statement(self, node)
+    }
+
+    fn visit_echo_statement(&mut self, node: &EchoStatement) -> Traversal {
+        walk_echo_statement(self, node)
+    }
+
+    fn visit_return_statement(&mut self, node: &ReturnStatement) -> Traversal {
+        walk_return_statement(self, node)
+    }
+
+    fn visit_use_statement(&mut self, node: &UseStatement) -> Traversal {
+        walk_use_statement(self, node)
+    }
+
+    fn visit_group_use_statement(&mut self, node: &GroupUseStatement) -> Traversal {
+        walk_group_use_statement(self, node)
+    }
+
+    fn visit_halt_compiler_statement(&mut self, node: &HaltCompilerStatement) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_static_var(&mut self, node: &StaticVar) -> Traversal {
+        walk_static_var(self, node)
+    }
+
+    fn visit_comment(&mut self, node: &Comment) -> Traversal {
+        walk_comment(self, node)
+    }
+
+    fn visit_comment_kind(&mut self, node: &CommentKind) -> Traversal {
+        walk_comment_kind(self, node)
+    }
+
+    fn visit_single_line_comment(&mut self, node: &SingleLineComment) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_multi_line_comment(&mut self, node: &MultiLineComment) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_hash_mark_comment(&mut self, node: &HashMarkComment) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_doc_block_comment(&mut self, node: &DocBlockComment) -> Traversal {
+        walk_doc_block_comment(self, node)
+    }
+
+    fn visit_doc_block(&mut self, node: &DocBlock) -> Traversal {
+        walk_doc_block(self, node)
+    }
+
+    fn visit_doc_block_node(&mut self, node: &DocBlockNode) -> Traversal {
+        walk_doc_block_node(self, node)
+    }
+
+    fn visit_doc_block_text_node(&mut self, node: &DocBlockTextNode) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_doc_block_tag_node(&mut self, node: &DocBlockTagNode) -> Traversal {
+        walk_doc_block_tag_node(self, node)
+    }
+
+    fn visit_doc_block_tag(&mut self, node: &DocBlockTag) -> Traversal {
+        walk_doc_block_tag(self, node)
+    }
+
+    fn visit_doc_block_param_closure_this_tag(
+        &mut self,
+        node: &DocBlockParamClosureThisTag,
+    ) -> Traversal {
+        walk_doc_block_param_closure_this_tag(self, node)
+    }
+
+    fn visit_doc_block_param_tag(&mut self, node: &DocBlockParamTag) -> Traversal {
+        walk_doc_block_param_tag(self, node)
+    }
+
+    fn visit_doc_block_return_tag(&mut self, node: &DocBlockReturnTag) -> Traversal {
+        walk_doc_block_return_tag(self, node)
+    }
+
+    fn visit_doc_block_throws_tag(&mut self, node: &DocBlockThrowsTag) -> Traversal {
+        walk_doc_block_throws_tag(self, node)
+    }
+
+    fn visit_doc_block_var_tag(&mut self, node: &DocBlockVarTag) -> Traversal {
+        walk_doc_block_var_tag(self, node)
+    }
+
+    fn visit_doc_block_property_tag(&mut self, node: &DocBlockPropertyTag) -> Traversal {
+        walk_doc_block_property_tag(self, node)
+    }
+
+    fn visit_doc_block_method_tag(&mut self, node: &DocBlockMethodTag) -> Traversal {
+        walk_doc_block_method_tag(self, node)
+    }
+
+    fn visit_doc_block_template_tag_value(&mut self, node: &DocBlockTemplateTagValue) -> Traversal {
+        walk_doc_block_template_tag_value(self, node)
+    }
+
+    fn visit_doc_block_template_tag(&mut self, node: &DocBlockTemplateTag) -> Traversal {
+        walk_doc_block_template_tag(self, node)
+    }
+
+    fn visit_doc_block_extends_tag(&mut self, node: &DocBlockExtendsTag) -> Traversal {
+        walk_doc_block_extends_tag(self, node)
+    }
+
+    fn visit_doc_block_implements_tag(&mut self, node: &DocBlockImplementsTag) -> Traversal {
+        walk_doc_block_implements_tag(self, node)
+    }
+
+    fn visit_doc_block_uses_tag(&mut self, node: &DocBlockUsesTag) -> Traversal {
+        walk_doc_block_uses_tag(self, node)
+    }
+
+    fn visit_doc_block_deprecated_tag(&mut self, node: &DocBlockDeprecatedTag) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_doc_block_generic_tag(&mut self, node: &DocBlockGenericTag) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_comment_group(&mut self, node: &CommentGroup) -> Traversal {
+        Traversal::Continue
+    }
+}
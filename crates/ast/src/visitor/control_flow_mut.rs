@@ -0,0 +1,1275 @@
+// This file is automatically generated by the generate-visitor.php script.
+// Do not modify this file directly.
+#![allow(unused, clippy::single_match)]
+
+use super::walk_control_flow_mut::*;
+use super::Traversal;
+use crate::*;
+use pxp_span::Span;
+use pxp_type::Type;
+
+pub trait ControlFlowVisitorMut {
+    fn visit(&mut self, node: &mut [Statement]) -> Traversal {
+        walk_mut(self, node)
+    }
+
+    fn visit_statement(&mut self, node: &mut Statement) -> Traversal {
+        walk_statement_mut(self, node)
+    }
+
+    fn visit_statement_kind(&mut self, node: &mut StatementKind) -> Traversal {
+        walk_statement_kind_mut(self, node)
+    }
+
+    fn visit_expression(&mut self, node: &mut Expression) -> Traversal {
+        walk_expression_mut(self, node)
+    }
+
+    fn visit_expression_kind(&mut self, node: &mut ExpressionKind) -> Traversal {
+        walk_expression_kind_mut(self, node)
+    }
+
+    fn visit_missing_expression(&mut self, node: &mut MissingExpression) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_static_expression(&mut self, node: &mut StaticExpression) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_self_expression(&mut self, node: &mut SelfExpression) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_parent_expression(&mut self, node: &mut ParentExpression) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_comment_statement(&mut self, node: &mut CommentStatement) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_inline_html_statement(&mut self, node: &mut InlineHtmlStatement) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_full_opening_tag_statement(
+        &mut self,
+        node: &mut FullOpeningTagStatement,
+    ) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_short_opening_tag_statement(
+        &mut self,
+        node: &mut ShortOpeningTagStatement,
+    ) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_echo_opening_tag_statement(
+        &mut self,
+        node: &mut EchoOpeningTagStatement,
+    ) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_closing_tag_statement(&mut self, node: &mut ClosingTagStatement) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_expression_statement(&mut self, node: &mut ExpressionStatement) -> Traversal {
+        walk_expression_statement_mut(self, node)
+    }
+
+    fn visit_global_statement(&mut self, node: &mut GlobalStatement) -> Traversal {
+        walk_global_statement_mut(self, node)
+    }
+
+    fn visit_block_statement(&mut self, node: &mut BlockStatement) -> Traversal {
+        walk_block_statement_mut(self, node)
+    }
+
+    fn visit_cast_kind(&mut self, node: &mut CastKind) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_case(&mut self, node: &mut Case) -> Traversal {
+        walk_case_mut(self, node)
+    }
+
+    fn visit_case_separator(&mut self, node: &mut CaseSeparator) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_use(&mut self, node: &mut Use) -> Traversal {
+        walk_use_mut(self, node)
+    }
+
+    fn visit_use_kind(&mut self, node: &mut UseKind) -> Traversal {
+        walk_use_kind_mut(self, node)
+    }
+
+    fn visit_eval_expression(&mut self, node: &mut EvalExpression) -> Traversal {
+        walk_eval_expression_mut(self, node)
+    }
+
+    fn visit_empty_expression(&mut self, node: &mut EmptyExpression) -> Traversal {
+        walk_empty_expression_mut(self, node)
+    }
+
+    fn visit_die_expression(&mut self, node: &mut DieExpression) -> Traversal {
+        walk_die_expression_mut(self, node)
+    }
+
+    fn visit_exit_expression(&mut self, node: &mut ExitExpression) -> Traversal {
+        walk_exit_expression_mut(self, node)
+    }
+
+    fn visit_isset_expression(&mut self, node: &mut IssetExpression) -> Traversal {
+        walk_isset_expression_mut(self, node)
+    }
+
+    fn visit_unset_expression(&mut self, node: &mut UnsetExpression) -> Traversal {
+        walk_unset_expression_mut(self, node)
+    }
+
+    fn visit_print_expression(&mut self, node: &mut PrintExpression) -> Traversal {
+        walk_print_expression_mut(self, node)
+    }
+
+    fn visit_concat_expression(&mut self, node: &mut ConcatExpression) -> Traversal {
+        walk_concat_expression_mut(self, node)
+    }
+
+    fn visit_instanceof_expression(&mut self, node: &mut InstanceofExpression) -> Traversal {
+        walk_instanceof_expression_mut(self, node)
+    }
+
+    fn visit_reference_expression(&mut self, node: &mut ReferenceExpression) -> Traversal {
+        walk_reference_expression_mut(self, node)
+    }
+
+    fn visit_parenthesized_expression(&mut self, node: &mut ParenthesizedExpression) -> Traversal {
+        walk_parenthesized_expression_mut(self, node)
+    }
+
+    fn visit_error_suppress_expression(&mut self, node: &mut ErrorSuppressExpression) -> Traversal {
+        walk_error_suppress_expression_mut(self, node)
+    }
+
+    fn visit_include_expression(&mut self, node: &mut IncludeExpression) -> Traversal {
+        walk_include_expression_mut(self, node)
+    }
+
+    fn visit_include_once_expression(&mut self, node: &mut IncludeOnceExpression) -> Traversal {
+        walk_include_once_expression_mut(self, node)
+    }
+
+    fn visit_require_expression(&mut self, node: &mut RequireExpression) -> Traversal {
+        walk_require_expression_mut(self, node)
+    }
+
+    fn visit_require_once_expression(&mut self, node: &mut RequireOnceExpression) -> Traversal {
+        walk_require_once_expression_mut(self, node)
+    }
+
+    fn visit_function_call_expression(&mut self, node: &mut FunctionCallExpression) -> Traversal {
+        walk_function_call_expression_mut(self, node)
+    }
+
+    fn visit_function_closure_creation_expression(
+        &mut self,
+        node: &mut FunctionClosureCreationExpression,
+    ) -> Traversal {
+        walk_function_closure_creation_expression_mut(self, node)
+    }
+
+    fn visit_method_call_expression(&mut self, node: &mut MethodCallExpression) -> Traversal {
+        walk_method_call_expression_mut(self, node)
+    }
+
+    fn visit_method_closure_creation_expression(
+        &mut self,
+        node: &mut MethodClosureCreationExpression,
+    ) -> Traversal {
+        walk_method_closure_creation_expression_mut(self, node)
+    }
+
+    fn visit_nullsafe_method_call_expression(
+        &mut self,
+        node: &mut NullsafeMethodCallExpression,
+    ) -> Traversal {
+        walk_nullsafe_method_call_expression_mut(self, node)
+    }
+
+    fn visit_static_method_call_expression(
+        &mut self,
+        node: &mut StaticMethodCallExpression,
+    ) -> Traversal {
+        walk_static_method_call_expression_mut(self, node)
+    }
+
+    fn visit_static_variable_method_call_expression(
+        &mut self,
+        node: &mut StaticVariableMethodCallExpression,
+    ) -> Traversal {
+        walk_static_variable_method_call_expression_mut(self, node)
+    }
+
+    fn visit_static_method_closure_creation_expression(
+        &mut self,
+        node: &mut StaticMethodClosureCreationExpression,
+    ) -> Traversal {
+        walk_static_method_closure_creation_expression_mut(self, node)
+    }
+
+    fn visit_static_variable_method_closure_creation_expression(
+        &mut self,
+        node: &mut StaticVariableMethodClosureCreationExpression,
+    ) -> Traversal {
+        walk_static_variable_method_closure_creation_expression_mut(self, node)
+    }
+
+    fn visit_property_fetch_expression(&mut self, node: &mut PropertyFetchExpression) -> Traversal {
+        walk_property_fetch_expression_mut(self, node)
+    }
+
+    fn visit_nullsafe_property_fetch_expression(
+        &mut self,
+        node: &mut NullsafePropertyFetchExpression,
+    ) -> Traversal {
+        walk_nullsafe_property_fetch_expression_mut(self, node)
+    }
+
+    fn visit_static_property_fetch_expression(
+        &mut self,
+        node: &mut StaticPropertyFetchExpression,
+    ) -> Traversal {
+        walk_static_property_fetch_expression_mut(self, node)
+    }
+
+    fn visit_constant_fetch_expression(&mut self, node: &mut ConstantFetchExpression) -> Traversal {
+        walk_constant_fetch_expression_mut(self, node)
+    }
+
+    fn visit_array_expression(&mut self, node: &mut ArrayExpression) -> Traversal {
+        walk_array_expression_mut(self, node)
+    }
+
+    fn visit_array_kind(&mut self, node: &mut ArrayKind) -> Traversal {
+        walk_array_kind_mut(self, node)
+    }
+
+    fn visit_array_kind_short(&mut self, node: &mut ArrayKindShort) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_array_kind_long(&mut self, node: &mut ArrayKindLong) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_list_expression(&mut self, node: &mut ListExpression) -> Traversal {
+        walk_list_expression_mut(self, node)
+    }
+
+    fn visit_new_expression(&mut self, node: &mut NewExpression) -> Traversal {
+        walk_new_expression_mut(self, node)
+    }
+
+    fn visit_interpolated_string_expression(
+        &mut self,
+        node: &mut InterpolatedStringExpression,
+    ) -> Traversal {
+        walk_interpolated_string_expression_mut(self, node)
+    }
+
+    fn visit_heredoc_expression(&mut self, node: &mut HeredocExpression) -> Traversal {
+        walk_heredoc_expression_mut(self, node)
+    }
+
+    fn visit_nowdoc_expression(&mut self, node: &mut NowdocExpression) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_shell_exec_expression(&mut self, node: &mut ShellExecExpression) -> Traversal {
+        walk_shell_exec_expression_mut(self, node)
+    }
+
+    fn visit_bool_expression(&mut self, node: &mut BoolExpression) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_array_index_expression(&mut self, node: &mut ArrayIndexExpression) -> Traversal {
+        walk_array_index_expression_mut(self, node)
+    }
+
+    fn visit_short_ternary_expression(&mut self, node: &mut ShortTernaryExpression) -> Traversal {
+        walk_short_ternary_expression_mut(self, node)
+    }
+
+    fn visit_ternary_expression(&mut self, node: &mut TernaryExpression) -> Traversal {
+        walk_ternary_expression_mut(self, node)
+    }
+
+    fn visit_coalesce_expression(&mut self, node: &mut CoalesceExpression) -> Traversal {
+        walk_coalesce_expression_mut(self, node)
+    }
+
+    fn visit_clone_expression(&mut self, node: &mut CloneExpression) -> Traversal {
+        walk_clone_expression_mut(self, node)
+    }
+
+    fn visit_match_expression(&mut self, node: &mut MatchExpression) -> Traversal {
+        walk_match_expression_mut(self, node)
+    }
+
+    fn visit_throw_expression(&mut self, node: &mut ThrowExpression) -> Traversal {
+        walk_throw_expression_mut(self, node)
+    }
+
+    fn visit_yield_expression(&mut self, node: &mut YieldExpression) -> Traversal {
+        walk_yield_expression_mut(self, node)
+    }
+
+    fn visit_yield_from_expression(&mut self, node: &mut YieldFromExpression) -> Traversal {
+        walk_yield_from_expression_mut(self, node)
+    }
+
+    fn visit_cast_expression(&mut self, node: &mut CastExpression) -> Traversal {
+        walk_cast_expression_mut(self, node)
+    }
+
+    fn visit_default_match_arm(&mut self, node: &mut DefaultMatchArm) -> Traversal {
+        walk_default_match_arm_mut(self, node)
+    }
+
+    fn visit_match_arm(&mut self, node: &mut MatchArm) -> Traversal {
+        walk_match_arm_mut(self, node)
+    }
+
+    fn visit_magic_constant_expression(&mut self, node: &mut MagicConstantExpression) -> Traversal {
+        walk_magic_constant_expression_mut(self, node)
+    }
+
+    fn visit_magic_constant_kind(&mut self, node: &mut MagicConstantKind) -> Traversal {
+        walk_magic_constant_kind_mut(self, node)
+    }
+
+    fn visit_string_part(&mut self, node: &mut StringPart) -> Traversal {
+        walk_string_part_mut(self, node)
+    }
+
+    fn visit_literal_string_part(&mut self, node: &mut LiteralStringPart) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_expression_string_part(&mut self, node: &mut ExpressionStringPart) -> Traversal {
+        walk_expression_string_part_mut(self, node)
+    }
+
+    fn visit_array_item(&mut self, node: &mut ArrayItem) -> Traversal {
+        walk_array_item_mut(self, node)
+    }
+
+    fn visit_array_item_value(&mut self, node: &mut ArrayItemValue) -> Traversal {
+        walk_array_item_value_mut(self, node)
+    }
+
+    fn visit_array_item_referenced_value(
+        &mut self,
+        node: &mut ArrayItemReferencedValue,
+    ) -> Traversal {
+        walk_array_item_referenced_value_mut(self, node)
+    }
+
+    fn visit_array_item_spread_value(&mut self, node: &mut ArrayItemSpreadValue) -> Traversal {
+        walk_array_item_spread_value_mut(self, node)
+    }
+
+    fn visit_array_item_key_value(&mut self, node: &mut ArrayItemKeyValue) -> Traversal {
+        walk_array_item_key_value_mut(self, node)
+    }
+
+    fn visit_array_item_referenced_key_value(
+        &mut self,
+        node: &mut ArrayItemReferencedKeyValue,
+    ) -> Traversal {
+        walk_array_item_referenced_key_value_mut(self, node)
+    }
+
+    fn visit_list_entry(&mut self, node: &mut ListEntry) -> Traversal {
+        walk_list_entry_mut(self, node)
+    }
+
+    fn visit_list_entry_value(&mut self, node: &mut ListEntryValue) -> Traversal {
+        walk_list_entry_value_mut(self, node)
+    }
+
+    fn visit_list_entry_key_value(&mut self, node: &mut ListEntryKeyValue) -> Traversal {
+        walk_list_entry_key_value_mut(self, node)
+    }
+
+    fn visit_positional_argument(&mut self, node: &mut PositionalArgument) -> Traversal {
+        walk_positional_argument_mut(self, node)
+    }
+
+    fn visit_named_argument(&mut self, node: &mut NamedArgument) -> Traversal {
+        walk_named_argument_mut(self, node)
+    }
+
+    fn visit_argument(&mut self, node: &mut Argument) -> Traversal {
+        walk_argument_mut(self, node)
+    }
+
+    fn visit_argument_list(&mut self, node: &mut ArgumentList) -> Traversal {
+        walk_argument_list_mut(self, node)
+    }
+
+    fn visit_single_argument(&mut self, node: &mut SingleArgument) -> Traversal {
+        walk_single_argument_mut(self, node)
+    }
+
+    fn visit_argument_placeholder(&mut self, node: &mut ArgumentPlaceholder) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_attribute(&mut self, node: &mut Attribute) -> Traversal {
+        walk_attribute_mut(self, node)
+    }
+
+    fn visit_attribute_group(&mut self, node: &mut AttributeGroup) -> Traversal {
+        walk_attribute_group_mut(self, node)
+    }
+
+    fn visit_class_body(&mut self, node: &mut ClassBody) -> Traversal {
+        walk_class_body_mut(self, node)
+    }
+
+    fn visit_class_statement(&mut self, node: &mut ClassStatement) -> Traversal {
+        walk_class_statement_mut(self, node)
+    }
+
+    fn visit_anonymous_class_body(&mut self, node: &mut AnonymousClassBody) -> Traversal {
+        walk_anonymous_class_body_mut(self, node)
+    }
+
+    fn visit_anonymous_class_expression(
+        &mut self,
+        node: &mut AnonymousClassExpression,
+    ) -> Traversal {
+        walk_anonymous_class_expression_mut(self, node)
+    }
+
+    fn visit_class_extends(&mut self, node: &mut ClassExtends) -> Traversal {
+        walk_class_extends_mut(self, node)
+    }
+
+    fn visit_class_implements(&mut self, node: &mut ClassImplements) -> Traversal {
+        walk_class_implements_mut(self, node)
+    }
+
+    fn visit_classish_member(&mut self, node: &mut ClassishMember) -> Traversal {
+        walk_classish_member_mut(self, node)
+    }
+
+    fn visit_method(&mut self, node: &mut Method) -> Traversal {
+        walk_method_mut(self, node)
+    }
+
+    fn visit_method_body(&mut self, node: &mut MethodBody) -> Traversal {
+        walk_method_body_mut(self, node)
+    }
+
+    fn visit_method_body_kind(&mut self, node: &mut MethodBodyKind) -> Traversal {
+        walk_method_body_kind_mut(self, node)
+    }
+
+    fn visit_missing_method_body(&mut self, node: &mut MissingMethodBody) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_abstract_method_body(&mut self, node: &mut AbstractMethodBody) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_concrete_method_body(&mut self, node: &mut ConcreteMethodBody) -> Traversal {
+        walk_concrete_method_body_mut(self, node)
+    }
+
+    fn visit_method_parameter_list(&mut self, node: &mut MethodParameterList) -> Traversal {
+        walk_method_parameter_list_mut(self, node)
+    }
+
+    fn visit_method_parameter(&mut self, node: &mut MethodParameter) -> Traversal {
+        walk_method_parameter_mut(self, node)
+    }
+
+    fn visit_missing_classish_member(&mut self, node: &mut MissingClassishMember) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_constant_entry(&mut self, node: &mut ConstantEntry) -> Traversal {
+        walk_constant_entry_mut(self, node)
+    }
+
+    fn visit_classish_constant_entry(&mut self, node: &mut ClassishConstantEntry) -> Traversal {
+        walk_classish_constant_entry_mut(self, node)
+    }
+
+    fn visit_constant_statement(&mut self, node: &mut ConstantStatement) -> Traversal {
+        walk_constant_statement_mut(self, node)
+    }
+
+    fn visit_classish_constant(&mut self, node: &mut ClassishConstant) -> Traversal {
+        walk_classish_constant_mut(self, node)
+    }
+
+    fn visit_if_statement(&mut self, node: &mut IfStatement) -> Traversal {
+        walk_if_statement_mut(self, node)
+    }
+
+    fn visit_if_statement_body(&mut self, node: &mut IfStatementBody) -> Traversal {
+        walk_if_statement_body_mut(self, node)
+    }
+
+    fn visit_if_statement_body_statement(
+        &mut self,
+        node: &mut IfStatementBodyStatement,
+    ) -> Traversal {
+        walk_if_statement_body_statement_mut(self, node)
+    }
+
+    fn visit_if_statement_body_block(&mut self, node: &mut IfStatementBodyBlock) -> Traversal {
+        walk_if_statement_body_block_mut(self, node)
+    }
+
+    fn visit_if_statement_else_if(&mut self, node: &mut IfStatementElseIf) -> Traversal {
+        walk_if_statement_else_if_mut(self, node)
+    }
+
+    fn visit_if_statement_else(&mut self, node: &mut IfStatementElse) -> Traversal {
+        walk_if_statement_else_mut(self, node)
+    }
+
+    fn visit_if_statement_else_if_block(&mut self, node: &mut IfStatementElseIfBlock) -> Traversal {
+        walk_if_statement_else_if_block_mut(self, node)
+    }
+
+    fn visit_if_statement_else_block(&mut self, node: &mut IfStatementElseBlock) -> Traversal {
+        walk_if_statement_else_block_mut(self, node)
+    }
+
+    fn visit_data_type(&mut self, node: &mut DataType) -> Traversal {
+        walk_data_type_mut(self, node)
+    }
+
+    fn visit_declare_entry(&mut self, node: &mut DeclareEntry) -> Traversal {
+        walk_declare_entry_mut(self, node)
+    }
+
+    fn visit_declare_entry_group(&mut self, node: &mut DeclareEntryGroup) -> Traversal {
+        walk_declare_entry_group_mut(self, node)
+    }
+
+    fn visit_declare_body(&mut self, node: &mut DeclareBody) -> Traversal {
+        walk_declare_body_mut(self, node)
+    }
+
+    fn visit_declare_body_noop(&mut self, node: &mut DeclareBodyNoop) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_declare_body_braced(&mut self, node: &mut DeclareBodyBraced) -> Traversal {
+        walk_declare_body_braced_mut(self, node)
+    }
+
+    fn visit_declare_body_statement(&mut self, node: &mut DeclareBodyStatement) -> Traversal {
+        walk_declare_body_statement_mut(self, node)
+    }
+
+    fn visit_declare_body_block(&mut self, node: &mut DeclareBodyBlock) -> Traversal {
+        walk_declare_body_block_mut(self, node)
+    }
+
+    fn visit_declare_statement(&mut self, node: &mut DeclareStatement) -> Traversal {
+        walk_declare_statement_mut(self, node)
+    }
+
+    fn visit_unit_enum_case(&mut self, node: &mut UnitEnumCase) -> Traversal {
+        walk_unit_enum_case_mut(self, node)
+    }
+
+    fn visit_unit_enum_member(&mut self, node: &mut UnitEnumMember) -> Traversal {
+        walk_unit_enum_member_mut(self, node)
+    }
+
+    fn visit_unit_enum_body(&mut self, node: &mut UnitEnumBody) -> Traversal {
+        walk_unit_enum_body_mut(self, node)
+    }
+
+    fn visit_unit_enum_statement(&mut self, node: &mut UnitEnumStatement) -> Traversal {
+        walk_unit_enum_statement_mut(self, node)
+    }
+
+    fn visit_backed_enum_case(&mut self, node: &mut BackedEnumCase) -> Traversal {
+        walk_backed_enum_case_mut(self, node)
+    }
+
+    fn visit_backed_enum_member(&mut self, node: &mut BackedEnumMember) -> Traversal {
+        walk_backed_enum_member_mut(self, node)
+    }
+
+    fn visit_backed_enum_body(&mut self, node: &mut BackedEnumBody) -> Traversal {
+        walk_backed_enum_body_mut(self, node)
+    }
+
+    fn visit_backed_enum_statement(&mut self, node: &mut BackedEnumStatement) -> Traversal {
+        walk_backed_enum_statement_mut(self, node)
+    }
+
+    fn visit_backed_enum_type(&mut self, node: &mut BackedEnumType) -> Traversal {
+        walk_backed_enum_type_mut(self, node)
+    }
+
+    fn visit_return_type(&mut self, node: &mut ReturnType) -> Traversal {
+        walk_return_type_mut(self, node)
+    }
+
+    fn visit_function_parameter(&mut self, node: &mut FunctionParameter) -> Traversal {
+        walk_function_parameter_mut(self, node)
+    }
+
+    fn visit_function_parameter_list(&mut self, node: &mut FunctionParameterList) -> Traversal {
+        walk_function_parameter_list_mut(self, node)
+    }
+
+    fn visit_function_body(&mut self, node: &mut FunctionBody) -> Traversal {
+        walk_function_body_mut(self, node)
+    }
+
+    fn visit_function_statement(&mut self, node: &mut FunctionStatement) -> Traversal {
+        walk_function_statement_mut(self, node)
+    }
+
+    fn visit_closure_use_variable(&mut self, node: &mut ClosureUseVariable) -> Traversal {
+        walk_closure_use_variable_mut(self, node)
+    }
+
+    fn visit_closure_use(&mut self, node: &mut ClosureUse) -> Traversal {
+        walk_closure_use_mut(self, node)
+    }
+
+    fn visit_closure_expression(&mut self, node: &mut ClosureExpression) -> Traversal {
+        walk_closure_expression_mut(self, node)
+    }
+
+    fn visit_arrow_function_expression(&mut self, node: &mut ArrowFunctionExpression) -> Traversal {
+        walk_arrow_function_expression_mut(self, node)
+    }
+
+    fn visit_label_statement(&mut self, node: &mut LabelStatement) -> Traversal {
+        walk_label_statement_mut(self, node)
+    }
+
+    fn visit_goto_statement(&mut self, node: &mut GotoStatement) -> Traversal {
+        walk_goto_statement_mut(self, node)
+    }
+
+    fn visit_identifier(&mut self, node: &mut Identifier) -> Traversal {
+        walk_identifier_mut(self, node)
+    }
+
+    fn visit_simple_identifier(&mut self, node: &mut SimpleIdentifier) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_dynamic_identifier(&mut self, node: &mut DynamicIdentifier) -> Traversal {
+        walk_dynamic_identifier_mut(self, node)
+    }
+
+    fn visit_interface_extends(&mut self, node: &mut InterfaceExtends) -> Traversal {
+        walk_interface_extends_mut(self, node)
+    }
+
+    fn visit_interface_body(&mut self, node: &mut InterfaceBody) -> Traversal {
+        walk_interface_body_mut(self, node)
+    }
+
+    fn visit_interface_statement(&mut self, node: &mut InterfaceStatement) -> Traversal {
+        walk_interface_statement_mut(self, node)
+    }
+
+    fn visit_literal(&mut self, node: &mut Literal) -> Traversal {
+        walk_literal_mut(self, node)
+    }
+
+    fn visit_literal_kind(&mut self, node: &mut LiteralKind) -> Traversal {
+        walk_literal_kind_mut(self, node)
+    }
+
+    fn visit_foreach_statement(&mut self, node: &mut ForeachStatement) -> Traversal {
+        walk_foreach_statement_mut(self, node)
+    }
+
+    fn visit_foreach_statement_iterator(
+        &mut self,
+        node: &mut ForeachStatementIterator,
+    ) -> Traversal {
+        walk_foreach_statement_iterator_mut(self, node)
+    }
+
+    fn visit_foreach_statement_iterator_value(
+        &mut self,
+        node: &mut ForeachStatementIteratorValue,
+    ) -> Traversal {
+        walk_foreach_statement_iterator_value_mut(self, node)
+    }
+
+    fn visit_foreach_statement_iterator_key_and_value(
+        &mut self,
+        node: &mut ForeachStatementIteratorKeyAndValue,
+    ) -> Traversal {
+        walk_foreach_statement_iterator_key_and_value_mut(self, node)
+    }
+
+    fn visit_foreach_statement_body(&mut self, node: &mut ForeachStatementBody) -> Traversal {
+        walk_foreach_statement_body_mut(self, node)
+    }
+
+    fn visit_foreach_statement_body_statement(
+        &mut self,
+        node: &mut ForeachStatementBodyStatement,
+    ) -> Traversal {
+        walk_foreach_statement_body_statement_mut(self, node)
+    }
+
+    fn visit_foreach_statement_body_block(
+        &mut self,
+        node: &mut ForeachStatementBodyBlock,
+    ) -> Traversal {
+        walk_foreach_statement_body_block_mut(self, node)
+    }
+
+    fn visit_for_statement(&mut self, node: &mut ForStatement) -> Traversal {
+        walk_for_statement_mut(self, node)
+    }
+
+    fn visit_for_statement_iterator(&mut self, node: &mut ForStatementIterator) -> Traversal {
+        walk_for_statement_iterator_mut(self, node)
+    }
+
+    fn visit_for_statement_body(&mut self, node: &mut ForStatementBody) -> Traversal {
+        walk_for_statement_body_mut(self, node)
+    }
+
+    fn visit_for_statement_body_statement(
+        &mut self,
+        node: &mut ForStatementBodyStatement,
+    ) -> Traversal {
+        walk_for_statement_body_statement_mut(self, node)
+    }
+
+    fn visit_for_statement_body_block(&mut self, node: &mut ForStatementBodyBlock) -> Traversal {
+        walk_for_statement_body_block_mut(self, node)
+    }
+
+    fn visit_do_while_statement(&mut self, node: &mut DoWhileStatement) -> Traversal {
+        walk_do_while_statement_mut(self, node)
+    }
+
+    fn visit_while_statement(&mut self, node: &mut WhileStatement) -> Traversal {
+        walk_while_statement_mut(self, node)
+    }
+
+    fn visit_while_statement_body(&mut self, node: &mut WhileStatementBody) -> Traversal {
+        walk_while_statement_body_mut(self, node)
+    }
+
+    fn visit_while_statement_body_statement(
+        &mut self,
+        node: &mut WhileStatementBodyStatement,
+    ) -> Traversal {
+        walk_while_statement_body_statement_mut(self, node)
+    }
+
+    fn visit_while_statement_body_block(
+        &mut self,
+        node: &mut WhileStatementBodyBlock,
+    ) -> Traversal {
+        walk_while_statement_body_block_mut(self, node)
+    }
+
+    fn visit_level(&mut self, node: &mut Level) -> Traversal {
+        walk_level_mut(self, node)
+    }
+
+    fn visit_literal_level(&mut self, node: &mut LiteralLevel) -> Traversal {
+        walk_literal_level_mut(self, node)
+    }
+
+    fn visit_parenthesized_level(&mut self, node: &mut ParenthesizedLevel) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_break_statement(&mut self, node: &mut BreakStatement) -> Traversal {
+        walk_break_statement_mut(self, node)
+    }
+
+    fn visit_continue_statement(&mut self, node: &mut ContinueStatement) -> Traversal {
+        walk_continue_statement_mut(self, node)
+    }
+
+    fn visit_visibility_modifier(&mut self, node: &mut VisibilityModifier) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_promoted_property_modifier(
+        &mut self,
+        node: &mut PromotedPropertyModifier,
+    ) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_promoted_property_modifier_group(
+        &mut self,
+        node: &mut PromotedPropertyModifierGroup,
+    ) -> Traversal {
+        walk_promoted_property_modifier_group_mut(self, node)
+    }
+
+    fn visit_property_modifier(&mut self, node: &mut PropertyModifier) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_property_modifier_group(&mut self, node: &mut PropertyModifierGroup) -> Traversal {
+        walk_property_modifier_group_mut(self, node)
+    }
+
+    fn visit_method_modifier(&mut self, node: &mut MethodModifier) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_method_modifier_group(&mut self, node: &mut MethodModifierGroup) -> Traversal {
+        walk_method_modifier_group_mut(self, node)
+    }
+
+    fn visit_class_modifier(&mut self, node: &mut ClassModifier) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_class_modifier_group(&mut self, node: &mut ClassModifierGroup) -> Traversal {
+        walk_class_modifier_group_mut(self, node)
+    }
+
+    fn visit_constant_modifier(&mut self, node: &mut ConstantModifier) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_constant_modifier_group(&mut self, node: &mut ConstantModifierGroup) -> Traversal {
+        walk_constant_modifier_group_mut(self, node)
+    }
+
+    fn visit_unbraced_namespace(&mut self, node: &mut UnbracedNamespace) -> Traversal {
+        walk_unbraced_namespace_mut(self, node)
+    }
+
+    fn visit_braced_namespace(&mut self, node: &mut BracedNamespace) -> Traversal {
+        walk_braced_namespace_mut(self, node)
+    }
+
+    fn visit_braced_namespace_body(&mut self, node: &mut BracedNamespaceBody) -> Traversal {
+        walk_braced_namespace_body_mut(self, node)
+    }
+
+    fn visit_namespace_statement(&mut self, node: &mut NamespaceStatement) -> Traversal {
+        walk_namespace_statement_mut(self, node)
+    }
+
+    fn visit_arithmetic_operation_expression(
+        &mut self,
+        node: &mut ArithmeticOperationExpression,
+    ) -> Traversal {
+        walk_arithmetic_operation_expression_mut(self, node)
+    }
+
+    fn visit_arithmetic_operation_kind(&mut self, node: &mut ArithmeticOperationKind) -> Traversal {
+        walk_arithmetic_operation_kind_mut(self, node)
+    }
+
+    fn visit_assignment_operation_expression(
+        &mut self,
+        node: &mut AssignmentOperationExpression,
+    ) -> Traversal {
+        walk_assignment_operation_expression_mut(self, node)
+    }
+
+    fn visit_assignment_operation_kind(&mut self, node: &mut AssignmentOperationKind) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_bitwise_operation_expression(
+        &mut self,
+        node: &mut BitwiseOperationExpression,
+    ) -> Traversal {
+        walk_bitwise_operation_expression_mut(self, node)
+    }
+
+    fn visit_bitwise_operation_kind(&mut self, node: &mut BitwiseOperationKind) -> Traversal {
+        walk_bitwise_operation_kind_mut(self, node)
+    }
+
+    fn visit_comparison_operation_expression(
+        &mut self,
+        node: &mut ComparisonOperationExpression,
+    ) -> Traversal {
+        walk_comparison_operation_expression_mut(self, node)
+    }
+
+    fn visit_comparison_operation_kind(&mut self, node: &mut ComparisonOperationKind) -> Traversal {
+        walk_comparison_operation_kind_mut(self, node)
+    }
+
+    fn visit_logical_operation_expression(
+        &mut self,
+        node: &mut LogicalOperationExpression,
+    ) -> Traversal {
+        walk_logical_operation_expression_mut(self, node)
+    }
+
+    fn visit_logical_operation_kind(&mut self, node: &mut LogicalOperationKind) -> Traversal {
+        walk_logical_operation_kind_mut(self, node)
+    }
+
+    fn visit_name(&mut self, node: &mut Name) -> Traversal {
+        walk_name_mut(self, node)
+    }
+
+    fn visit_name_kind(&mut self, node: &mut NameKind) -> Traversal {
+        walk_name_kind_mut(self, node)
+    }
+
+    fn visit_special_name(&mut self, node: &mut SpecialName) -> Traversal {
+        walk_special_name_mut(self, node)
+    }
+
+    fn visit_special_name_kind(&mut self, node: &mut SpecialNameKind) -> Traversal {
+        walk_special_name_kind_mut(self, node)
+    }
+
+    fn visit_unresolved_name(&mut self, node: &mut UnresolvedName) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_resolved_name(&mut self, node: &mut ResolvedName) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_property(&mut self, node: &mut Property) -> Traversal {
+        walk_property_mut(self, node)
+    }
+
+    fn visit_simple_property(&mut self, node: &mut SimpleProperty) -> Traversal {
+        walk_simple_property_mut(self, node)
+    }
+
+    fn visit_hooked_property(&mut self, node: &mut HookedProperty) -> Traversal {
+        walk_hooked_property_mut(self, node)
+    }
+
+    fn visit_property_hook_list(&mut self, node: &mut PropertyHookList) -> Traversal {
+        walk_property_hook_list_mut(self, node)
+    }
+
+    fn visit_property_hook(&mut self, node: &mut PropertyHook) -> Traversal {
+        walk_property_hook_mut(self, node)
+    }
+
+    fn visit_property_hook_body(&mut self, node: &mut PropertyHookBody) -> Traversal {
+        walk_property_hook_body_mut(self, node)
+    }
+
+    fn visit_concrete_property_hook_body(
+        &mut self,
+        node: &mut ConcretePropertyHookBody,
+    ) -> Traversal {
+        walk_concrete_property_hook_body_mut(self, node)
+    }
+
+    fn visit_concrete_property_hook_body_block(
+        &mut self,
+        node: &mut ConcretePropertyHookBodyBlock,
+    ) -> Traversal {
+        walk_concrete_property_hook_body_block_mut(self, node)
+    }
+
+    fn visit_concrete_property_hook_body_expression(
+        &mut self,
+        node: &mut ConcretePropertyHookBodyExpression,
+    ) -> Traversal {
+        walk_concrete_property_hook_body_expression_mut(self, node)
+    }
+
+    fn visit_property_hook_kind(&mut self, node: &mut PropertyHookKind) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_property_entry(&mut self, node: &mut PropertyEntry) -> Traversal {
+        walk_property_entry_mut(self, node)
+    }
+
+    fn visit_property_entry_kind(&mut self, node: &mut PropertyEntryKind) -> Traversal {
+        walk_property_entry_kind_mut(self, node)
+    }
+
+    fn visit_uninitialized_property_entry(
+        &mut self,
+        node: &mut UninitializedPropertyEntry,
+    ) -> Traversal {
+        walk_uninitialized_property_entry_mut(self, node)
+    }
+
+    fn visit_initialized_property_entry(
+        &mut self,
+        node: &mut InitializedPropertyEntry,
+    ) -> Traversal {
+        walk_initialized_property_entry_mut(self, node)
+    }
+
+    fn visit_trait_body(&mut self, node: &mut TraitBody) -> Traversal {
+        walk_trait_body_mut(self, node)
+    }
+
+    fn visit_trait_statement(&mut self, node: &mut TraitStatement) -> Traversal {
+        walk_trait_statement_mut(self, node)
+    }
+
+    fn visit_trait_usage(&mut self, node: &mut TraitUsage) -> Traversal {
+        walk_trait_usage_mut(self, node)
+    }
+
+    fn visit_trait_usage_adaptation(&mut self, node: &mut TraitUsageAdaptation) -> Traversal {
+        walk_trait_usage_adaptation_mut(self, node)
+    }
+
+    fn visit_trait_usage_adaptation_kind(
+        &mut self,
+        node: &mut TraitUsageAdaptationKind,
+    ) -> Traversal {
+        walk_trait_usage_adaptation_kind_mut(self, node)
+    }
+
+    fn visit_trait_usage_adaptation_alias(
+        &mut self,
+        node: &mut TraitUsageAdaptationAlias,
+    ) -> Traversal {
+        walk_trait_usage_adaptation_alias_mut(self, node)
+    }
+
+    fn visit_trait_usage_adaptation_visibility(
+        &mut self,
+        node: &mut TraitUsageAdaptationVisibility,
+    ) -> Traversal {
+        walk_trait_usage_adaptation_visibility_mut(self, node)
+    }
+
+    fn visit_trait_usage_adaptation_precedence(
+        &mut self,
+        node: &mut TraitUsageAdaptationPrecedence,
+    ) -> Traversal {
+        walk_trait_usage_adaptation_precedence_mut(self, node)
+    }
+
+    fn visit_catch_type(&mut self, node: &mut CatchType) -> Traversal {
+        walk_catch_type_mut(self, node)
+    }
+
+    fn visit_catch_type_kind(&mut self, node: &mut CatchTypeKind) -> Traversal {
+        walk_catch_type_kind_mut(self, node)
+    }
+
+    fn visit_catch_type_kind_identifier(
+        &mut self,
+        node: &mut CatchTypeKindIdentifier,
+    ) -> Traversal {
+        walk_catch_type_kind_identifier_mut(self, node)
+    }
+
+    fn visit_catch_type_kind_union(&mut self, node: &mut CatchTypeKindUnion) -> Traversal {
+        walk_catch_type_kind_union_mut(self, node)
+    }
+
+    fn visit_try_statement(&mut self, node: &mut TryStatement) -> Traversal {
+        walk_try_statement_mut(self, node)
+    }
+
+    fn visit_catch_block(&mut self, node: &mut CatchBlock) -> Traversal {
+        walk_catch_block_mut(self, node)
+    }
+
+    fn visit_finally_block(&mut self, node: &mut FinallyBlock) -> Traversal {
+        walk_finally_block_mut(self, node)
+    }
+
+    fn visit_variable(&mut self, node: &mut Variable) -> Traversal {
+        walk_variable_mut(self, node)
+    }
+
+    fn visit_simple_variable(&mut self, node: &mut SimpleVariable) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_variable_variable(&mut self, node: &mut VariableVariable) -> Traversal {
+        walk_variable_variable_mut(self, node)
+    }
+
+    fn visit_braced_variable_variable(&mut self, node: &mut BracedVariableVariable) -> Traversal {
+        walk_braced_variable_variable_mut(self, node)
+    }
+
+    fn visit_ending(&mut self, node: &mut Ending) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_static_statement(&mut self, node: &mut StaticStatement) -> Traversal {
+        walk_static_statement_mut(self, node)
+    }
+
+    fn visit_switch_statement(&mut self, node: &mut SwitchStatement) -> Traversal {
+        walk_switch_statement_mut(self, node)
+    }
+
+    fn visit_echo_statement(&mut self, node: &mut EchoStatement) -> Traversal {
+        walk_echo_statement_mut(self, node)
+    }
+
+    fn visit_return_statement(&mut self, node: &mut ReturnStatement) -> Traversal {
+        walk_return_statement_mut(self, node)
+    }
+
+    fn visit_use_statement(&mut self, node: &mut UseStatement) -> Traversal {
+        walk_use_statement_mut(self, node)
+    }
+
+    fn visit_group_use_statement(&mut self, node: &mut GroupUseStatement) -> Traversal {
+        walk_group_use_statement_mut(self, node)
+    }
+
+    fn visit_halt_compiler_statement(&mut self, node: &mut HaltCompilerStatement) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_static_var(&mut self, node: &mut StaticVar) -> Traversal {
+        walk_static_var_mut(self, node)
+    }
+
+    fn visit_comment(&mut self, node: &mut Comment) -> Traversal {
+        walk_comment_mut(self, node)
+    }
+
+    fn visit_comment_kind(&mut self, node: &mut CommentKind) -> Traversal {
+        walk_comment_kind_mut(self, node)
+    }
+
+    fn visit_single_line_comment(&mut self, node: &mut SingleLineComment) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_multi_line_comment(&mut self, node: &mut MultiLineComment) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_hash_mark_comment(&mut self, node: &mut HashMarkComment) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_doc_block_comment(&mut self, node: &mut DocBlockComment) -> Traversal {
+        walk_doc_block_comment_mut(self, node)
+    }
+
+    fn visit_doc_block(&mut self, node: &mut DocBlock) -> Traversal {
+        walk_doc_block_mut(self, node)
+    }
+
+    fn visit_doc_block_node(&mut self, node: &mut DocBlockNode) -> Traversal {
+        walk_doc_block_node_mut(self, node)
+    }
+
+    fn visit_doc_block_text_node(&mut self, node: &mut DocBlockTextNode) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_doc_block_tag_node(&mut self, node: &mut DocBlockTagNode) -> Traversal {
+        walk_doc_block_tag_node_mut(self, node)
+    }
+
+    fn visit_doc_block_tag(&mut self, node: &mut DocBlockTag) -> Traversal {
+        walk_doc_block_tag_mut(self, node)
+    }
+
+    fn visit_doc_block_param_closure_this_tag(
+        &mut self,
+        node: &mut DocBlockParamClosureThisTag,
+    ) -> Traversal {
+        walk_doc_block_param_closure_this_tag_mut(self, node)
+    }
+
+    fn visit_doc_block_param_tag(&mut self, node: &mut DocBlockParamTag) -> Traversal {
+        walk_doc_block_param_tag_mut(self, node)
+    }
+
+    fn visit_doc_block_return_tag(&mut self, node: &mut DocBlockReturnTag) -> Traversal {
+        walk_doc_block_return_tag_mut(self, node)
+    }
+
+    fn visit_doc_block_throws_tag(&mut self, node: &mut DocBlockThrowsTag) -> Traversal {
+        walk_doc_block_throws_tag_mut(self, node)
+    }
+
+    fn visit_doc_block_var_tag(&mut self, node: &mut DocBlockVarTag) -> Traversal {
+        walk_doc_block_var_tag_mut(self, node)
+    }
+
+    fn visit_doc_block_property_tag(&mut self, node: &mut DocBlockPropertyTag) -> Traversal {
+        walk_doc_block_property_tag_mut(self, node)
+    }
+
+    fn visit_doc_block_method_tag(&mut self, node: &mut DocBlockMethodTag) -> Traversal {
+        walk_doc_block_method_tag_mut(self, node)
+    }
+
+    fn visit_doc_block_template_tag_value(
+        &mut self,
+        node: &mut DocBlockTemplateTagValue,
+    ) -> Traversal {
+        walk_doc_block_template_tag_value_mut(self, node)
+    }
+
+    fn visit_doc_block_template_tag(&mut self, node: &mut DocBlockTemplateTag) -> Traversal {
+        walk_doc_block_template_tag_mut(self, node)
+    }
+
+    fn visit_doc_block_extends_tag(&mut self, node: &mut DocBlockExtendsTag) -> Traversal {
+        walk_doc_block_extends_tag_mut(self, node)
+    }
+
+    fn visit_doc_block_implements_tag(&mut self, node: &mut DocBlockImplementsTag) -> Traversal {
+        walk_doc_block_implements_tag_mut(self, node)
+    }
+
+    fn visit_doc_block_uses_tag(&mut self, node: &mut DocBlockUsesTag) -> Traversal {
+        walk_doc_block_uses_tag_mut(self, node)
+    }
+
+    fn visit_doc_block_deprecated_tag(&mut self, node: &mut DocBlockDeprecatedTag) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_doc_block_generic_tag(&mut self, node: &mut DocBlockGenericTag) -> Traversal {
+        Traversal::Continue
+    }
+
+    fn visit_comment_group(&mut self, node: &mut CommentGroup) -> Traversal {
+        Traversal::Continue
+    }
+}
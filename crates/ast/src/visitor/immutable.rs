@@ -66,6 +66,8 @@ pub trait Visitor {
         walk_case(self, node);
     }
 
+    fn visit_case_separator(&mut self, node: &CaseSeparator) {}
+
     fn visit_use(&mut self, node: &Use) {
         walk_use(self, node);
     }
@@ -498,8 +500,8 @@ pub trait Visitor {
         walk_declare_body_braced(self, node);
     }
 
-    fn visit_declare_body_expression(&mut self, node: &DeclareBodyExpression) {
-        walk_declare_body_expression(self, node);
+    fn visit_declare_body_statement(&mut self, node: &DeclareBodyStatement) {
+        walk_declare_body_statement(self, node);
     }
 
     fn visit_declare_body_block(&mut self, node: &DeclareBodyBlock) {
@@ -1,11 +1,19 @@
+mod control_flow;
+mod control_flow_mut;
 mod immutable;
 mod mutable;
 mod node;
+mod traversal;
 mod walk;
+pub mod walk_control_flow;
+pub mod walk_control_flow_mut;
 mod walk_mut;
 
+pub use control_flow::ControlFlowVisitor;
+pub use control_flow_mut::ControlFlowVisitorMut;
 pub use immutable::Visitor;
 pub use mutable::VisitorMut;
 pub use node::{Ancestors, NodeVisitor, NodeVisitorEscapeHatch};
+pub use traversal::Traversal;
 pub use walk::*;
 pub use walk_mut::*;
@@ -66,6 +66,8 @@ pub trait VisitorMut {
         walk_case_mut(self, node);
     }
 
+    fn visit_case_separator(&mut self, node: &mut CaseSeparator) {}
+
     fn visit_use(&mut self, node: &mut Use) {
         walk_use_mut(self, node);
     }
@@ -504,8 +506,8 @@ pub trait VisitorMut {
         walk_declare_body_braced_mut(self, node);
     }
 
-    fn visit_declare_body_expression(&mut self, node: &mut DeclareBodyExpression) {
-        walk_declare_body_expression_mut(self, node);
+    fn visit_declare_body_statement(&mut self, node: &mut DeclareBodyStatement) {
+        walk_declare_body_statement_mut(self, node);
     }
 
     fn visit_declare_body_block(&mut self, node: &mut DeclareBodyBlock) {
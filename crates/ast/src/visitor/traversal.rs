@@ -0,0 +1,17 @@
+/// What a [`ControlFlowVisitor`](super::ControlFlowVisitor) wants the walk to do after visiting a node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Traversal {
+    /// Keep walking, including the children of the node.
+    #[default]
+    Continue,
+    /// Keep walking, but don't descend into the children of the node.
+    SkipChildren,
+    /// Stop walking the tree entirely.
+    Stop,
+}
+
+impl Traversal {
+    pub fn is_stop(&self) -> bool {
+        matches!(self, Traversal::Stop)
+    }
+}
@@ -180,6 +180,7 @@ pub fn walk_case<V: Visitor + ?Sized>(visitor: &mut V, node: &Case) {
     if let Some(item) = &node.condition {
         visitor.visit_expression(item);
     }
+    visitor.visit_case_separator(&node.separator);
     visitor.visit(&node.body);
 }
 
@@ -229,12 +230,7 @@ pub fn walk_unset_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &UnsetE
 }
 
 pub fn walk_print_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &PrintExpression) {
-    if let Some(item) = &node.value {
-        visitor.visit_expression(item);
-    }
-    if let Some(item) = &node.argument {
-        visitor.visit_single_argument(item);
-    }
+    visitor.visit_expression(&node.value);
 }
 
 pub fn walk_concat_expression<V: Visitor + ?Sized>(visitor: &mut V, node: &ConcatExpression) {
@@ -547,6 +543,7 @@ pub fn walk_magic_constant_kind<V: Visitor + ?Sized>(visitor: &mut V, node: &Mag
         MagicConstantKind::Function => {}
         MagicConstantKind::Class => {}
         MagicConstantKind::Method => {}
+        MagicConstantKind::Property => {}
         MagicConstantKind::Namespace => {}
         MagicConstantKind::Trait => {}
         MagicConstantKind::CompilerHaltOffset => {}
@@ -807,6 +804,9 @@ pub fn walk_classish_constant_entry<V: Visitor + ?Sized>(
 }
 
 pub fn walk_constant_statement<V: Visitor + ?Sized>(visitor: &mut V, node: &ConstantStatement) {
+    for item in &node.attributes {
+        visitor.visit_attribute_group(item);
+    }
     for item in &node.entries {
         visitor.visit_constant_entry(item);
     }
@@ -912,7 +912,7 @@ pub fn walk_declare_body<V: Visitor + ?Sized>(visitor: &mut V, node: &DeclareBod
     match node {
         DeclareBody::Noop(inner) => visitor.visit_declare_body_noop(inner),
         DeclareBody::Braced(inner) => visitor.visit_declare_body_braced(inner),
-        DeclareBody::Expression(inner) => visitor.visit_declare_body_expression(inner),
+        DeclareBody::Statement(inner) => visitor.visit_declare_body_statement(inner),
         DeclareBody::Block(inner) => visitor.visit_declare_body_block(inner),
         _ => {}
     }
@@ -924,11 +924,11 @@ pub fn walk_declare_body_braced<V: Visitor + ?Sized>(visitor: &mut V, node: &Dec
     }
 }
 
-pub fn walk_declare_body_expression<V: Visitor + ?Sized>(
+pub fn walk_declare_body_statement<V: Visitor + ?Sized>(
     visitor: &mut V,
-    node: &DeclareBodyExpression,
+    node: &DeclareBodyStatement,
 ) {
-    visitor.visit_expression(&node.expression);
+    visitor.visit_statement(&node.statement);
 }
 
 pub fn walk_declare_body_block<V: Visitor + ?Sized>(visitor: &mut V, node: &DeclareBodyBlock) {
@@ -1925,7 +1925,7 @@ pub fn walk_trait_usage_adaptation_precedence<V: Visitor + ?Sized>(
     }
     visitor.visit_simple_identifier(&node.method);
     for item in &node.insteadof {
-        visitor.visit_simple_identifier(item);
+        visitor.visit_name(item);
     }
 }
 
@@ -1945,12 +1945,12 @@ pub fn walk_catch_type_kind_identifier<V: Visitor + ?Sized>(
     visitor: &mut V,
     node: &CatchTypeKindIdentifier,
 ) {
-    visitor.visit_simple_identifier(&node.identifier);
+    visitor.visit_name(&node.name);
 }
 
 pub fn walk_catch_type_kind_union<V: Visitor + ?Sized>(visitor: &mut V, node: &CatchTypeKindUnion) {
-    for item in &node.identifiers {
-        visitor.visit_simple_identifier(item);
+    for item in &node.names {
+        visitor.visit_name(item);
     }
 }
 
@@ -0,0 +1,4534 @@
+// This file is automatically generated by the generate-visitor.php script.
+// Do not modify this file directly.
+#![allow(unused, clippy::single_match, clippy::collapsible_match)]
+
+use super::{ControlFlowVisitor, Traversal};
+use crate::*;
+
+pub fn walk<V: ControlFlowVisitor + ?Sized>(visitor: &mut V, node: &[Statement]) -> Traversal {
+    for statement in node {
+        if visitor.visit_statement(statement).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+
+    Traversal::Continue
+}
+
+pub fn walk_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &Statement,
+) -> Traversal {
+    if visitor.visit_statement_kind(&node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_statement_kind<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &StatementKind,
+) -> Traversal {
+    match node {
+        StatementKind::FullOpeningTag(inner) => {
+            if visitor.visit_full_opening_tag_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::ShortOpeningTag(inner) => {
+            if visitor.visit_short_opening_tag_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::EchoOpeningTag(inner) => {
+            if visitor.visit_echo_opening_tag_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::ClosingTag(inner) => {
+            if visitor.visit_closing_tag_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::InlineHtml(inner) => {
+            if visitor.visit_inline_html_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Label(inner) => {
+            if visitor.visit_label_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Goto(inner) => {
+            if visitor.visit_goto_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::HaltCompiler(inner) => {
+            if visitor.visit_halt_compiler_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Static(inner) => {
+            if visitor.visit_static_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::DoWhile(inner) => {
+            if visitor.visit_do_while_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::While(inner) => {
+            if visitor.visit_while_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::For(inner) => {
+            if visitor.visit_for_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Foreach(inner) => {
+            if visitor.visit_foreach_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Break(inner) => {
+            if visitor.visit_break_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Continue(inner) => {
+            if visitor.visit_continue_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Constant(inner) => {
+            if visitor.visit_constant_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Function(inner) => {
+            if visitor.visit_function_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Class(inner) => {
+            if visitor.visit_class_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Trait(inner) => {
+            if visitor.visit_trait_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Interface(inner) => {
+            if visitor.visit_interface_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::If(inner) => {
+            if visitor.visit_if_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Switch(inner) => {
+            if visitor.visit_switch_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Echo(inner) => {
+            if visitor.visit_echo_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Expression(inner) => {
+            if visitor.visit_expression_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Return(inner) => {
+            if visitor.visit_return_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Namespace(inner) => {
+            if visitor.visit_namespace_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Use(inner) => {
+            if visitor.visit_use_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::GroupUse(inner) => {
+            if visitor.visit_group_use_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Try(inner) => {
+            if visitor.visit_try_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::UnitEnum(inner) => {
+            if visitor.visit_unit_enum_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::BackedEnum(inner) => {
+            if visitor.visit_backed_enum_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Block(inner) => {
+            if visitor.visit_block_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Global(inner) => {
+            if visitor.visit_global_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Declare(inner) => {
+            if visitor.visit_declare_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &Expression,
+) -> Traversal {
+    if visitor.visit_expression_kind(&node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_expression_kind<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ExpressionKind,
+) -> Traversal {
+    match node {
+        ExpressionKind::Missing(inner) => {
+            if visitor.visit_missing_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Eval(inner) => {
+            if visitor.visit_eval_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Empty(inner) => {
+            if visitor.visit_empty_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Die(inner) => {
+            if visitor.visit_die_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Exit(inner) => {
+            if visitor.visit_exit_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Isset(inner) => {
+            if visitor.visit_isset_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Unset(inner) => {
+            if visitor.visit_unset_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Print(inner) => {
+            if visitor.visit_print_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Literal(inner) => {
+            if visitor.visit_literal(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::ArithmeticOperation(inner) => {
+            if visitor
+                .visit_arithmetic_operation_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::AssignmentOperation(inner) => {
+            if visitor
+                .visit_assignment_operation_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::BitwiseOperation(inner) => {
+            if visitor.visit_bitwise_operation_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::ComparisonOperation(inner) => {
+            if visitor
+                .visit_comparison_operation_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::LogicalOperation(inner) => {
+            if visitor.visit_logical_operation_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Concat(inner) => {
+            if visitor.visit_concat_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Instanceof(inner) => {
+            if visitor.visit_instanceof_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Reference(inner) => {
+            if visitor.visit_reference_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Parenthesized(inner) => {
+            if visitor.visit_parenthesized_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::ErrorSuppress(inner) => {
+            if visitor.visit_error_suppress_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Identifier(inner) => {
+            if visitor.visit_identifier(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Variable(inner) => {
+            if visitor.visit_variable(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Include(inner) => {
+            if visitor.visit_include_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::IncludeOnce(inner) => {
+            if visitor.visit_include_once_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Require(inner) => {
+            if visitor.visit_require_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::RequireOnce(inner) => {
+            if visitor.visit_require_once_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::FunctionCall(inner) => {
+            if visitor.visit_function_call_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::FunctionClosureCreation(inner) => {
+            if visitor
+                .visit_function_closure_creation_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::MethodCall(inner) => {
+            if visitor.visit_method_call_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::MethodClosureCreation(inner) => {
+            if visitor
+                .visit_method_closure_creation_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::NullsafeMethodCall(inner) => {
+            if visitor
+                .visit_nullsafe_method_call_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::StaticMethodCall(inner) => {
+            if visitor.visit_static_method_call_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::StaticVariableMethodCall(inner) => {
+            if visitor
+                .visit_static_variable_method_call_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::StaticMethodClosureCreation(inner) => {
+            if visitor
+                .visit_static_method_closure_creation_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::StaticVariableMethodClosureCreation(inner) => {
+            if visitor
+                .visit_static_variable_method_closure_creation_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::PropertyFetch(inner) => {
+            if visitor.visit_property_fetch_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::NullsafePropertyFetch(inner) => {
+            if visitor
+                .visit_nullsafe_property_fetch_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::StaticPropertyFetch(inner) => {
+            if visitor
+                .visit_static_property_fetch_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::ConstantFetch(inner) => {
+            if visitor.visit_constant_fetch_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Static(inner) => {
+            if visitor.visit_static_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Self_(inner) => {
+            if visitor.visit_self_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Parent(inner) => {
+            if visitor.visit_parent_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Array(inner) => {
+            if visitor.visit_array_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::List(inner) => {
+            if visitor.visit_list_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Closure(inner) => {
+            if visitor.visit_closure_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::ArrowFunction(inner) => {
+            if visitor.visit_arrow_function_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::New(inner) => {
+            if visitor.visit_new_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::InterpolatedString(inner) => {
+            if visitor
+                .visit_interpolated_string_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Heredoc(inner) => {
+            if visitor.visit_heredoc_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Nowdoc(inner) => {
+            if visitor.visit_nowdoc_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::ShellExec(inner) => {
+            if visitor.visit_shell_exec_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::AnonymousClass(inner) => {
+            if visitor.visit_anonymous_class_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Bool(inner) => {
+            if visitor.visit_bool_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::ArrayIndex(inner) => {
+            if visitor.visit_array_index_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::MagicConstant(inner) => {
+            if visitor.visit_magic_constant_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::ShortTernary(inner) => {
+            if visitor.visit_short_ternary_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Ternary(inner) => {
+            if visitor.visit_ternary_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Coalesce(inner) => {
+            if visitor.visit_coalesce_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Clone(inner) => {
+            if visitor.visit_clone_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Match(inner) => {
+            if visitor.visit_match_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Throw(inner) => {
+            if visitor.visit_throw_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Yield(inner) => {
+            if visitor.visit_yield_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::YieldFrom(inner) => {
+            if visitor.visit_yield_from_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Cast(inner) => {
+            if visitor.visit_cast_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Name(inner) => {
+            if visitor.visit_name(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_expression_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ExpressionStatement,
+) -> Traversal {
+    if visitor.visit_expression(&node.expression).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_ending(&node.ending).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_global_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &GlobalStatement,
+) -> Traversal {
+    for item in &node.variables {
+        if visitor.visit_variable(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_block_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &BlockStatement,
+) -> Traversal {
+    for item in &node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_case<V: ControlFlowVisitor + ?Sized>(visitor: &mut V, node: &Case) -> Traversal {
+    if let Some(item) = &node.condition {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_case_separator(&node.separator).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_use<V: ControlFlowVisitor + ?Sized>(visitor: &mut V, node: &Use) -> Traversal {
+    if visitor.visit_name(&node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &node.alias {
+        if visitor.visit_simple_identifier(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_use_kind(&node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_use_kind<V: ControlFlowVisitor + ?Sized>(visitor: &mut V, node: &UseKind) -> Traversal {
+    match node {
+        UseKind::Normal => {}
+        UseKind::Function => {}
+        UseKind::Const => {}
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_eval_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &EvalExpression,
+) -> Traversal {
+    if visitor.visit_single_argument(&node.argument).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_empty_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &EmptyExpression,
+) -> Traversal {
+    if visitor.visit_single_argument(&node.argument).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_die_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DieExpression,
+) -> Traversal {
+    if let Some(item) = &node.argument {
+        if visitor.visit_single_argument(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_exit_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ExitExpression,
+) -> Traversal {
+    if let Some(item) = &node.argument {
+        if visitor.visit_single_argument(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_isset_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &IssetExpression,
+) -> Traversal {
+    if visitor.visit_argument_list(&node.arguments).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_unset_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &UnsetExpression,
+) -> Traversal {
+    if visitor.visit_argument_list(&node.arguments).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_print_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &PrintExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_concat_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ConcatExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.left).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.right).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_instanceof_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &InstanceofExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.left).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.right).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_reference_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ReferenceExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.right).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_parenthesized_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ParenthesizedExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.expr).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_error_suppress_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ErrorSuppressExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.expr).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_include_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &IncludeExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.path).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_include_once_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &IncludeOnceExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.path).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_require_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &RequireExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.path).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_require_once_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &RequireOnceExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.path).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_function_call_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &FunctionCallExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_argument_list(&node.arguments).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_function_closure_creation_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &FunctionClosureCreationExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor
+        .visit_argument_placeholder(&node.placeholder)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_method_call_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &MethodCallExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.method).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_argument_list(&node.arguments).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_method_closure_creation_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &MethodClosureCreationExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.method).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor
+        .visit_argument_placeholder(&node.placeholder)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_nullsafe_method_call_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &NullsafeMethodCallExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.method).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_argument_list(&node.arguments).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_static_method_call_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &StaticMethodCallExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_identifier(&node.method).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_argument_list(&node.arguments).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_static_variable_method_call_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &StaticVariableMethodCallExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_variable(&node.method).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_argument_list(&node.arguments).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_static_method_closure_creation_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &StaticMethodClosureCreationExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_identifier(&node.method).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor
+        .visit_argument_placeholder(&node.placeholder)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_static_variable_method_closure_creation_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &StaticVariableMethodClosureCreationExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_variable(&node.method).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor
+        .visit_argument_placeholder(&node.placeholder)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_property_fetch_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &PropertyFetchExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.property).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_nullsafe_property_fetch_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &NullsafePropertyFetchExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.property).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_static_property_fetch_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &StaticPropertyFetchExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_variable(&node.property).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_constant_fetch_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ConstantFetchExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_identifier(&node.constant).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_array_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ArrayExpression,
+) -> Traversal {
+    if visitor.visit_array_kind(&node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &node.items.inner {
+        if visitor.visit_array_item(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_array_kind<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ArrayKind,
+) -> Traversal {
+    match node {
+        ArrayKind::Short(inner) => {
+            if visitor.visit_array_kind_short(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArrayKind::Long(inner) => {
+            if visitor.visit_array_kind_long(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_list_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ListExpression,
+) -> Traversal {
+    for item in &node.items {
+        if visitor.visit_list_entry(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_new_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &NewExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &node.arguments {
+        if visitor.visit_argument_list(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_interpolated_string_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &InterpolatedStringExpression,
+) -> Traversal {
+    for item in &node.parts {
+        if visitor.visit_string_part(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_heredoc_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &HeredocExpression,
+) -> Traversal {
+    for item in &node.parts {
+        if visitor.visit_string_part(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_shell_exec_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ShellExecExpression,
+) -> Traversal {
+    for item in &node.parts {
+        if visitor.visit_string_part(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_array_index_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ArrayIndexExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.array).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &node.index {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_short_ternary_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ShortTernaryExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.condition).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.r#else).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_ternary_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &TernaryExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.condition).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.then).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.r#else).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_coalesce_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &CoalesceExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.lhs).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.rhs).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_clone_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &CloneExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_match_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &MatchExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.condition).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &node.default {
+        if visitor.visit_default_match_arm(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    for item in &node.arms {
+        if visitor.visit_match_arm(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_throw_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ThrowExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_yield_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &YieldExpression,
+) -> Traversal {
+    if let Some(item) = &node.key {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &node.value {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_yield_from_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &YieldFromExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_cast_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &CastExpression,
+) -> Traversal {
+    if visitor.visit_cast_kind(&node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_default_match_arm<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DefaultMatchArm,
+) -> Traversal {
+    if visitor.visit_expression(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_match_arm<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &MatchArm,
+) -> Traversal {
+    for item in &node.conditions {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_expression(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_magic_constant_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &MagicConstantExpression,
+) -> Traversal {
+    if visitor.visit_magic_constant_kind(&node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_magic_constant_kind<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &MagicConstantKind,
+) -> Traversal {
+    match node {
+        MagicConstantKind::Directory => {}
+        MagicConstantKind::File => {}
+        MagicConstantKind::Line => {}
+        MagicConstantKind::Function => {}
+        MagicConstantKind::Class => {}
+        MagicConstantKind::Method => {}
+        MagicConstantKind::Property => {}
+        MagicConstantKind::Namespace => {}
+        MagicConstantKind::Trait => {}
+        MagicConstantKind::CompilerHaltOffset => {}
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_string_part<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &StringPart,
+) -> Traversal {
+    match node {
+        StringPart::Literal(inner) => {
+            if visitor.visit_literal_string_part(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StringPart::Expression(inner) => {
+            if visitor.visit_expression_string_part(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_expression_string_part<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ExpressionStringPart,
+) -> Traversal {
+    if visitor.visit_expression(&node.expression).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_array_item<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ArrayItem,
+) -> Traversal {
+    match node {
+        ArrayItem::Value(inner) => {
+            if visitor.visit_array_item_value(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArrayItem::ReferencedValue(inner) => {
+            if visitor.visit_array_item_referenced_value(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArrayItem::SpreadValue(inner) => {
+            if visitor.visit_array_item_spread_value(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArrayItem::KeyValue(inner) => {
+            if visitor.visit_array_item_key_value(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArrayItem::ReferencedKeyValue(inner) => {
+            if visitor
+                .visit_array_item_referenced_key_value(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_array_item_value<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ArrayItemValue,
+) -> Traversal {
+    if visitor.visit_expression(&node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_array_item_referenced_value<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ArrayItemReferencedValue,
+) -> Traversal {
+    if visitor.visit_expression(&node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_array_item_spread_value<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ArrayItemSpreadValue,
+) -> Traversal {
+    if visitor.visit_expression(&node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_array_item_key_value<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ArrayItemKeyValue,
+) -> Traversal {
+    if visitor.visit_expression(&node.key).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_array_item_referenced_key_value<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ArrayItemReferencedKeyValue,
+) -> Traversal {
+    if visitor.visit_expression(&node.key).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_list_entry<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ListEntry,
+) -> Traversal {
+    match node {
+        ListEntry::Value(inner) => {
+            if visitor.visit_list_entry_value(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ListEntry::KeyValue(inner) => {
+            if visitor.visit_list_entry_key_value(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_list_entry_value<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ListEntryValue,
+) -> Traversal {
+    if visitor.visit_expression(&node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_list_entry_key_value<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ListEntryKeyValue,
+) -> Traversal {
+    if visitor.visit_expression(&node.key).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_positional_argument<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &PositionalArgument,
+) -> Traversal {
+    if visitor.visit_expression(&node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_named_argument<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &NamedArgument,
+) -> Traversal {
+    if visitor.visit_simple_identifier(&node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_argument<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &Argument,
+) -> Traversal {
+    match node {
+        Argument::Positional(inner) => {
+            if visitor.visit_positional_argument(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        Argument::Named(inner) => {
+            if visitor.visit_named_argument(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_argument_list<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ArgumentList,
+) -> Traversal {
+    for item in &node.arguments {
+        if visitor.visit_argument(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_single_argument<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &SingleArgument,
+) -> Traversal {
+    if let Some(item) = &node.argument {
+        if visitor.visit_argument(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_attribute<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &Attribute,
+) -> Traversal {
+    if visitor.visit_name(&node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &node.arguments {
+        if visitor.visit_argument_list(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_attribute_group<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &AttributeGroup,
+) -> Traversal {
+    for item in &node.members {
+        if visitor.visit_attribute(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_class_body<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ClassBody,
+) -> Traversal {
+    for item in &node.members {
+        if visitor.visit_classish_member(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_class_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ClassStatement,
+) -> Traversal {
+    for item in &node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor
+        .visit_class_modifier_group(&node.modifiers)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if visitor.visit_name(&node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &node.extends {
+        if visitor.visit_class_extends(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &node.implements {
+        if visitor.visit_class_implements(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_class_body(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_anonymous_class_body<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &AnonymousClassBody,
+) -> Traversal {
+    for item in &node.members {
+        if visitor.visit_classish_member(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_anonymous_class_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &AnonymousClassExpression,
+) -> Traversal {
+    for item in &node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &node.extends {
+        if visitor.visit_class_extends(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &node.implements {
+        if visitor.visit_class_implements(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_anonymous_class_body(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_class_extends<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ClassExtends,
+) -> Traversal {
+    if visitor.visit_name(&node.parent).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_class_implements<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ClassImplements,
+) -> Traversal {
+    for item in &node.interfaces.inner {
+        if visitor.visit_name(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_classish_member<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ClassishMember,
+) -> Traversal {
+    match node {
+        ClassishMember::Constant(inner) => {
+            if visitor.visit_classish_constant(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ClassishMember::TraitUsage(inner) => {
+            if visitor.visit_trait_usage(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ClassishMember::Property(inner) => {
+            if visitor.visit_property(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ClassishMember::Method(inner) => {
+            if visitor.visit_method(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ClassishMember::Missing(inner) => {
+            if visitor.visit_missing_classish_member(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_method<V: ControlFlowVisitor + ?Sized>(visitor: &mut V, node: &Method) -> Traversal {
+    for item in &node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor
+        .visit_method_modifier_group(&node.modifiers)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if visitor.visit_simple_identifier(&node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor
+        .visit_method_parameter_list(&node.parameters)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &node.return_type {
+        if visitor.visit_return_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_method_body(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_method_body<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &MethodBody,
+) -> Traversal {
+    if visitor.visit_method_body_kind(&node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_method_body_kind<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &MethodBodyKind,
+) -> Traversal {
+    match node {
+        MethodBodyKind::Abstract(inner) => {
+            if visitor.visit_abstract_method_body(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        MethodBodyKind::Concrete(inner) => {
+            if visitor.visit_concrete_method_body(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        MethodBodyKind::Missing(inner) => {
+            if visitor.visit_missing_method_body(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_concrete_method_body<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ConcreteMethodBody,
+) -> Traversal {
+    for item in &node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_method_parameter_list<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &MethodParameterList,
+) -> Traversal {
+    for item in &node.parameters.inner {
+        if visitor.visit_method_parameter(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_method_parameter<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &MethodParameter,
+) -> Traversal {
+    if let Some(item) = &node.modifiers {
+        if visitor
+            .visit_promoted_property_modifier_group(item)
+            .is_stop()
+        {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_simple_variable(&node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &node.data_type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &node.default {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_constant_entry<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ConstantEntry,
+) -> Traversal {
+    if visitor.visit_name(&node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_classish_constant_entry<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ClassishConstantEntry,
+) -> Traversal {
+    if visitor.visit_simple_identifier(&node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_constant_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ConstantStatement,
+) -> Traversal {
+    for item in &node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    for item in &node.entries {
+        if visitor.visit_constant_entry(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_classish_constant<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ClassishConstant,
+) -> Traversal {
+    for item in &node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor
+        .visit_constant_modifier_group(&node.modifiers)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &node.data_type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    for item in &node.entries {
+        if visitor.visit_classish_constant_entry(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_if_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &IfStatement,
+) -> Traversal {
+    if visitor.visit_expression(&node.condition).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_if_statement_body(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_if_statement_body<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &IfStatementBody,
+) -> Traversal {
+    match node {
+        IfStatementBody::Statement(inner) => {
+            if visitor.visit_if_statement_body_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        IfStatementBody::Block(inner) => {
+            if visitor.visit_if_statement_body_block(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_if_statement_body_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &IfStatementBodyStatement,
+) -> Traversal {
+    if visitor.visit_statement(&node.statement).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &node.elseifs {
+        if visitor.visit_if_statement_else_if(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &node.r#else {
+        if visitor.visit_if_statement_else(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_if_statement_body_block<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &IfStatementBodyBlock,
+) -> Traversal {
+    for item in &node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    for item in &node.elseifs {
+        if visitor.visit_if_statement_else_if_block(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &node.r#else {
+        if visitor.visit_if_statement_else_block(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_ending(&node.ending).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_if_statement_else_if<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &IfStatementElseIf,
+) -> Traversal {
+    if visitor.visit_expression(&node.condition).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_statement(&node.statement).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_if_statement_else<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &IfStatementElse,
+) -> Traversal {
+    if visitor.visit_statement(&node.statement).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_if_statement_else_if_block<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &IfStatementElseIfBlock,
+) -> Traversal {
+    if visitor.visit_expression(&node.condition).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_if_statement_else_block<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &IfStatementElseBlock,
+) -> Traversal {
+    for item in &node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_data_type<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DataType,
+) -> Traversal {
+    Traversal::Continue
+}
+
+pub fn walk_declare_entry<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DeclareEntry,
+) -> Traversal {
+    if visitor.visit_simple_identifier(&node.key).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_literal(&node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_declare_entry_group<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DeclareEntryGroup,
+) -> Traversal {
+    for item in &node.entries {
+        if visitor.visit_declare_entry(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_declare_body<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DeclareBody,
+) -> Traversal {
+    match node {
+        DeclareBody::Noop(inner) => {
+            if visitor.visit_declare_body_noop(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DeclareBody::Braced(inner) => {
+            if visitor.visit_declare_body_braced(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DeclareBody::Statement(inner) => {
+            if visitor.visit_declare_body_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DeclareBody::Block(inner) => {
+            if visitor.visit_declare_body_block(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_declare_body_braced<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DeclareBodyBraced,
+) -> Traversal {
+    for item in &node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_declare_body_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DeclareBodyStatement,
+) -> Traversal {
+    if visitor.visit_statement(&node.statement).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_declare_body_block<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DeclareBodyBlock,
+) -> Traversal {
+    for item in &node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_declare_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DeclareStatement,
+) -> Traversal {
+    if visitor.visit_declare_entry_group(&node.entries).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_declare_body(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_unit_enum_case<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &UnitEnumCase,
+) -> Traversal {
+    for item in &node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_simple_identifier(&node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_unit_enum_member<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &UnitEnumMember,
+) -> Traversal {
+    match node {
+        UnitEnumMember::Case(inner) => {
+            if visitor.visit_unit_enum_case(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        UnitEnumMember::Classish(inner) => {
+            if visitor.visit_classish_member(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_unit_enum_body<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &UnitEnumBody,
+) -> Traversal {
+    for item in &node.members {
+        if visitor.visit_unit_enum_member(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_unit_enum_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &UnitEnumStatement,
+) -> Traversal {
+    for item in &node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_name(&node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &node.implements {
+        if visitor.visit_name(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_unit_enum_body(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_backed_enum_case<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &BackedEnumCase,
+) -> Traversal {
+    for item in &node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_simple_identifier(&node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_backed_enum_member<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &BackedEnumMember,
+) -> Traversal {
+    match node {
+        BackedEnumMember::Case(inner) => {
+            if visitor.visit_backed_enum_case(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        BackedEnumMember::Classish(inner) => {
+            if visitor.visit_classish_member(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_backed_enum_body<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &BackedEnumBody,
+) -> Traversal {
+    for item in &node.members {
+        if visitor.visit_backed_enum_member(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_backed_enum_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &BackedEnumStatement,
+) -> Traversal {
+    for item in &node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_name(&node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &node.implements {
+        if visitor.visit_name(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_backed_enum_body(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_backed_enum_type<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &BackedEnumType,
+) -> Traversal {
+    match node {
+        BackedEnumType::Invalid => {}
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_return_type<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ReturnType,
+) -> Traversal {
+    if visitor.visit_data_type(&node.data_type).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_function_parameter<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &FunctionParameter,
+) -> Traversal {
+    if visitor.visit_simple_variable(&node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &node.data_type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &node.default {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_function_parameter_list<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &FunctionParameterList,
+) -> Traversal {
+    for item in &node.parameters.inner {
+        if visitor.visit_function_parameter(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_function_body<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &FunctionBody,
+) -> Traversal {
+    for item in &node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_function_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &FunctionStatement,
+) -> Traversal {
+    for item in &node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_name(&node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor
+        .visit_function_parameter_list(&node.parameters)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &node.return_type {
+        if visitor.visit_return_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_function_body(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_closure_use_variable<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ClosureUseVariable,
+) -> Traversal {
+    if visitor.visit_simple_variable(&node.variable).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_closure_use<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ClosureUse,
+) -> Traversal {
+    for item in &node.variables.inner {
+        if visitor.visit_closure_use_variable(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_closure_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ClosureExpression,
+) -> Traversal {
+    for item in &node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor
+        .visit_function_parameter_list(&node.parameters)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &node.uses {
+        if visitor.visit_closure_use(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &node.return_type {
+        if visitor.visit_return_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_function_body(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_arrow_function_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ArrowFunctionExpression,
+) -> Traversal {
+    for item in &node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor
+        .visit_function_parameter_list(&node.parameters)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &node.return_type {
+        if visitor.visit_return_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_expression(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_label_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &LabelStatement,
+) -> Traversal {
+    if visitor.visit_simple_identifier(&node.label).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_goto_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &GotoStatement,
+) -> Traversal {
+    if visitor.visit_simple_identifier(&node.label).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_identifier<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &Identifier,
+) -> Traversal {
+    match node {
+        Identifier::SimpleIdentifier(inner) => {
+            if visitor.visit_simple_identifier(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        Identifier::DynamicIdentifier(inner) => {
+            if visitor.visit_dynamic_identifier(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_dynamic_identifier<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DynamicIdentifier,
+) -> Traversal {
+    if visitor.visit_expression(&node.expr).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_interface_extends<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &InterfaceExtends,
+) -> Traversal {
+    for item in &node.parents.inner {
+        if visitor.visit_name(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_interface_body<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &InterfaceBody,
+) -> Traversal {
+    for item in &node.members {
+        if visitor.visit_classish_member(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_interface_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &InterfaceStatement,
+) -> Traversal {
+    for item in &node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_name(&node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &node.extends {
+        if visitor.visit_interface_extends(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_interface_body(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_literal<V: ControlFlowVisitor + ?Sized>(visitor: &mut V, node: &Literal) -> Traversal {
+    if visitor.visit_literal_kind(&node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_literal_kind<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &LiteralKind,
+) -> Traversal {
+    match node {
+        LiteralKind::Integer => {}
+        LiteralKind::Float => {}
+        LiteralKind::String => {}
+        LiteralKind::Missing => {}
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_foreach_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ForeachStatement,
+) -> Traversal {
+    if visitor
+        .visit_foreach_statement_iterator(&node.iterator)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if visitor.visit_foreach_statement_body(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_foreach_statement_iterator<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ForeachStatementIterator,
+) -> Traversal {
+    match node {
+        ForeachStatementIterator::Value(inner) => {
+            if visitor
+                .visit_foreach_statement_iterator_value(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ForeachStatementIterator::KeyAndValue(inner) => {
+            if visitor
+                .visit_foreach_statement_iterator_key_and_value(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_foreach_statement_iterator_value<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ForeachStatementIteratorValue,
+) -> Traversal {
+    if visitor.visit_expression(&node.expression).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_foreach_statement_iterator_key_and_value<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ForeachStatementIteratorKeyAndValue,
+) -> Traversal {
+    if visitor.visit_expression(&node.expression).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.key).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_foreach_statement_body<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ForeachStatementBody,
+) -> Traversal {
+    match node {
+        ForeachStatementBody::Statement(inner) => {
+            if visitor
+                .visit_foreach_statement_body_statement(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ForeachStatementBody::Block(inner) => {
+            if visitor.visit_foreach_statement_body_block(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_foreach_statement_body_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ForeachStatementBodyStatement,
+) -> Traversal {
+    if visitor.visit_statement(&node.statement).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_foreach_statement_body_block<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ForeachStatementBodyBlock,
+) -> Traversal {
+    for item in &node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_ending(&node.ending).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_for_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ForStatement,
+) -> Traversal {
+    if visitor
+        .visit_for_statement_iterator(&node.iterator)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if visitor.visit_for_statement_body(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_for_statement_iterator<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ForStatementIterator,
+) -> Traversal {
+    for item in &node.initializations.inner {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    for item in &node.conditions.inner {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    for item in &node.r#loop.inner {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_for_statement_body<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ForStatementBody,
+) -> Traversal {
+    match node {
+        ForStatementBody::Statement(inner) => {
+            if visitor.visit_for_statement_body_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ForStatementBody::Block(inner) => {
+            if visitor.visit_for_statement_body_block(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_for_statement_body_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ForStatementBodyStatement,
+) -> Traversal {
+    if visitor.visit_statement(&node.statement).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_for_statement_body_block<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ForStatementBodyBlock,
+) -> Traversal {
+    for item in &node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_ending(&node.ending).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_do_while_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DoWhileStatement,
+) -> Traversal {
+    if visitor.visit_statement(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.condition).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_while_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &WhileStatement,
+) -> Traversal {
+    if visitor.visit_expression(&node.condition).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_while_statement_body(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_while_statement_body<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &WhileStatementBody,
+) -> Traversal {
+    match node {
+        WhileStatementBody::Statement(inner) => {
+            if visitor
+                .visit_while_statement_body_statement(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        WhileStatementBody::Block(inner) => {
+            if visitor.visit_while_statement_body_block(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_while_statement_body_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &WhileStatementBodyStatement,
+) -> Traversal {
+    if visitor.visit_statement(&node.statement).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_while_statement_body_block<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &WhileStatementBodyBlock,
+) -> Traversal {
+    for item in &node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_ending(&node.ending).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_level<V: ControlFlowVisitor + ?Sized>(visitor: &mut V, node: &Level) -> Traversal {
+    match node {
+        Level::Literal(inner) => {
+            if visitor.visit_literal_level(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        Level::Parenthesized(inner) => {
+            if visitor.visit_parenthesized_level(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_literal_level<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &LiteralLevel,
+) -> Traversal {
+    if visitor.visit_literal(&node.literal).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_break_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &BreakStatement,
+) -> Traversal {
+    if visitor.visit_ending(&node.ending).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_continue_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ContinueStatement,
+) -> Traversal {
+    if visitor.visit_ending(&node.ending).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_promoted_property_modifier_group<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &PromotedPropertyModifierGroup,
+) -> Traversal {
+    for item in &node.modifiers {
+        if visitor.visit_promoted_property_modifier(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_property_modifier_group<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &PropertyModifierGroup,
+) -> Traversal {
+    for item in &node.modifiers {
+        if visitor.visit_property_modifier(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_method_modifier_group<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &MethodModifierGroup,
+) -> Traversal {
+    for item in &node.modifiers {
+        if visitor.visit_method_modifier(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_class_modifier_group<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ClassModifierGroup,
+) -> Traversal {
+    for item in &node.modifiers {
+        if visitor.visit_class_modifier(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_constant_modifier_group<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ConstantModifierGroup,
+) -> Traversal {
+    for item in &node.modifiers {
+        if visitor.visit_constant_modifier(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_unbraced_namespace<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &UnbracedNamespace,
+) -> Traversal {
+    if visitor.visit_simple_identifier(&node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_braced_namespace<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &BracedNamespace,
+) -> Traversal {
+    if let Some(item) = &node.name {
+        if visitor.visit_simple_identifier(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_braced_namespace_body(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_braced_namespace_body<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &BracedNamespaceBody,
+) -> Traversal {
+    for item in &node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_namespace_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &NamespaceStatement,
+) -> Traversal {
+    match node {
+        NamespaceStatement::Unbraced(inner) => {
+            if visitor.visit_unbraced_namespace(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        NamespaceStatement::Braced(inner) => {
+            if visitor.visit_braced_namespace(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_arithmetic_operation_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ArithmeticOperationExpression,
+) -> Traversal {
+    if visitor
+        .visit_arithmetic_operation_kind(&node.kind)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_arithmetic_operation_kind<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ArithmeticOperationKind,
+) -> Traversal {
+    match node {
+        ArithmeticOperationKind::Addition {
+            left, plus, right, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::Subtraction {
+            left, minus, right, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::Multiplication {
+            left,
+            asterisk,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::Division {
+            left, slash, right, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::Modulo {
+            left,
+            percent,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::Exponentiation {
+            left, pow, right, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::Negative { minus, right, .. } => {
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::Positive { plus, right, .. } => {
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::PreIncrement {
+            increment, right, ..
+        } => {
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::PostIncrement {
+            left, increment, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::PreDecrement {
+            decrement, right, ..
+        } => {
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::PostDecrement {
+            left, decrement, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_assignment_operation_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &AssignmentOperationExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.left).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor
+        .visit_assignment_operation_kind(&node.kind)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.right).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_bitwise_operation_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &BitwiseOperationExpression,
+) -> Traversal {
+    if visitor.visit_bitwise_operation_kind(&node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_bitwise_operation_kind<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &BitwiseOperationKind,
+) -> Traversal {
+    match node {
+        BitwiseOperationKind::And {
+            left, and, right, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        BitwiseOperationKind::Or {
+            left, or, right, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        BitwiseOperationKind::Xor {
+            left, xor, right, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        BitwiseOperationKind::LeftShift {
+            left,
+            left_shift,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        BitwiseOperationKind::RightShift {
+            left,
+            right_shift,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        BitwiseOperationKind::Not { not, right, .. } => {
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_comparison_operation_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ComparisonOperationExpression,
+) -> Traversal {
+    if visitor
+        .visit_comparison_operation_kind(&node.kind)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_comparison_operation_kind<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ComparisonOperationKind,
+) -> Traversal {
+    match node {
+        ComparisonOperationKind::Equal {
+            left,
+            double_equals,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ComparisonOperationKind::Identical {
+            left,
+            triple_equals,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ComparisonOperationKind::NotEqual {
+            left,
+            bang_equals,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ComparisonOperationKind::AngledNotEqual {
+            left,
+            angled_left_right,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ComparisonOperationKind::NotIdentical {
+            left,
+            bang_double_equals,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ComparisonOperationKind::LessThan {
+            left,
+            less_than,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ComparisonOperationKind::GreaterThan {
+            left,
+            greater_than,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ComparisonOperationKind::LessThanOrEqual {
+            left,
+            less_than_equals,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ComparisonOperationKind::GreaterThanOrEqual {
+            left,
+            greater_than_equals,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ComparisonOperationKind::Spaceship {
+            left,
+            spaceship,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_logical_operation_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &LogicalOperationExpression,
+) -> Traversal {
+    if visitor.visit_logical_operation_kind(&node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_logical_operation_kind<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &LogicalOperationKind,
+) -> Traversal {
+    match node {
+        LogicalOperationKind::And {
+            left,
+            double_ampersand,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        LogicalOperationKind::Or {
+            left,
+            double_pipe,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        LogicalOperationKind::Not { bang, right, .. } => {
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        LogicalOperationKind::LogicalAnd {
+            left, and, right, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        LogicalOperationKind::LogicalOr {
+            left, or, right, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        LogicalOperationKind::LogicalXor {
+            left, xor, right, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_name<V: ControlFlowVisitor + ?Sized>(visitor: &mut V, node: &Name) -> Traversal {
+    if visitor.visit_name_kind(&node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_name_kind<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &NameKind,
+) -> Traversal {
+    match node {
+        NameKind::Special(inner) => {
+            if visitor.visit_special_name(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        NameKind::Unresolved(inner) => {
+            if visitor.visit_unresolved_name(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        NameKind::Resolved(inner) => {
+            if visitor.visit_resolved_name(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_special_name<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &SpecialName,
+) -> Traversal {
+    if visitor.visit_special_name_kind(&node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_special_name_kind<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &SpecialNameKind,
+) -> Traversal {
+    match node {
+        SpecialNameKind::Self_ => {}
+        SpecialNameKind::Parent => {}
+        SpecialNameKind::Static => {}
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_property<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &Property,
+) -> Traversal {
+    match node {
+        Property::Simple(inner) => {
+            if visitor.visit_simple_property(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        Property::Hooked(inner) => {
+            if visitor.visit_hooked_property(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_simple_property<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &SimpleProperty,
+) -> Traversal {
+    for item in &node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor
+        .visit_property_modifier_group(&node.modifiers)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &node.r#type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    for item in &node.entries {
+        if visitor.visit_property_entry(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_hooked_property<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &HookedProperty,
+) -> Traversal {
+    for item in &node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor
+        .visit_property_modifier_group(&node.modifiers)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &node.r#type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_property_entry(&node.entry).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_property_hook_list(&node.hooks).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_property_hook_list<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &PropertyHookList,
+) -> Traversal {
+    for item in &node.hooks {
+        if visitor.visit_property_hook(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_property_hook<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &PropertyHook,
+) -> Traversal {
+    if visitor.visit_property_hook_kind(&node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &node.parameters {
+        if visitor.visit_function_parameter_list(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_property_hook_body(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_property_hook_body<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &PropertyHookBody,
+) -> Traversal {
+    match node {
+        PropertyHookBody::Concrete(inner) => {
+            if visitor.visit_concrete_property_hook_body(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_concrete_property_hook_body<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ConcretePropertyHookBody,
+) -> Traversal {
+    match node {
+        ConcretePropertyHookBody::Block(inner) => {
+            if visitor
+                .visit_concrete_property_hook_body_block(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ConcretePropertyHookBody::Expression(inner) => {
+            if visitor
+                .visit_concrete_property_hook_body_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_concrete_property_hook_body_block<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ConcretePropertyHookBodyBlock,
+) -> Traversal {
+    for item in &node.body {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_concrete_property_hook_body_expression<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ConcretePropertyHookBodyExpression,
+) -> Traversal {
+    if visitor.visit_expression(&node.expression).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_property_entry<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &PropertyEntry,
+) -> Traversal {
+    if visitor.visit_property_entry_kind(&node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_property_entry_kind<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &PropertyEntryKind,
+) -> Traversal {
+    match node {
+        PropertyEntryKind::Uninitialized(inner) => {
+            if visitor.visit_uninitialized_property_entry(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        PropertyEntryKind::Initialized(inner) => {
+            if visitor.visit_initialized_property_entry(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_uninitialized_property_entry<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &UninitializedPropertyEntry,
+) -> Traversal {
+    if visitor.visit_simple_variable(&node.variable).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_initialized_property_entry<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &InitializedPropertyEntry,
+) -> Traversal {
+    if visitor.visit_simple_variable(&node.variable).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_trait_body<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &TraitBody,
+) -> Traversal {
+    for item in &node.members {
+        if visitor.visit_classish_member(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_trait_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &TraitStatement,
+) -> Traversal {
+    if visitor.visit_name(&node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_trait_body(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_trait_usage<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &TraitUsage,
+) -> Traversal {
+    for item in &node.traits {
+        if visitor.visit_name(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    for item in &node.adaptations {
+        if visitor.visit_trait_usage_adaptation(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_trait_usage_adaptation<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &TraitUsageAdaptation,
+) -> Traversal {
+    if visitor
+        .visit_trait_usage_adaptation_kind(&node.kind)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_trait_usage_adaptation_kind<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &TraitUsageAdaptationKind,
+) -> Traversal {
+    match node {
+        TraitUsageAdaptationKind::Alias(inner) => {
+            if visitor.visit_trait_usage_adaptation_alias(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        TraitUsageAdaptationKind::Visibility(inner) => {
+            if visitor
+                .visit_trait_usage_adaptation_visibility(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        TraitUsageAdaptationKind::Precedence(inner) => {
+            if visitor
+                .visit_trait_usage_adaptation_precedence(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_trait_usage_adaptation_alias<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &TraitUsageAdaptationAlias,
+) -> Traversal {
+    if let Some(item) = &node.r#trait {
+        if visitor.visit_name(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_simple_identifier(&node.method).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_simple_identifier(&node.alias).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &node.visibility {
+        if visitor.visit_visibility_modifier(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_trait_usage_adaptation_visibility<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &TraitUsageAdaptationVisibility,
+) -> Traversal {
+    if let Some(item) = &node.r#trait {
+        if visitor.visit_name(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_simple_identifier(&node.method).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor
+        .visit_visibility_modifier(&node.visibility)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_trait_usage_adaptation_precedence<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &TraitUsageAdaptationPrecedence,
+) -> Traversal {
+    if let Some(item) = &node.r#trait {
+        if visitor.visit_name(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_simple_identifier(&node.method).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &node.insteadof {
+        if visitor.visit_name(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_catch_type<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &CatchType,
+) -> Traversal {
+    if visitor.visit_catch_type_kind(&node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_catch_type_kind<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &CatchTypeKind,
+) -> Traversal {
+    match node {
+        CatchTypeKind::Identifier(inner) => {
+            if visitor.visit_catch_type_kind_identifier(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        CatchTypeKind::Union(inner) => {
+            if visitor.visit_catch_type_kind_union(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_catch_type_kind_identifier<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &CatchTypeKindIdentifier,
+) -> Traversal {
+    if visitor.visit_name(&node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_catch_type_kind_union<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &CatchTypeKindUnion,
+) -> Traversal {
+    for item in &node.names {
+        if visitor.visit_name(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_try_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &TryStatement,
+) -> Traversal {
+    if visitor.visit(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &node.catches {
+        if visitor.visit_catch_block(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &node.finally {
+        if visitor.visit_finally_block(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_catch_block<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &CatchBlock,
+) -> Traversal {
+    if visitor.visit_catch_type(&node.types).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &node.var {
+        if visitor.visit_simple_variable(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_finally_block<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &FinallyBlock,
+) -> Traversal {
+    if visitor.visit(&node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_variable<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &Variable,
+) -> Traversal {
+    match node {
+        Variable::SimpleVariable(inner) => {
+            if visitor.visit_simple_variable(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        Variable::VariableVariable(inner) => {
+            if visitor.visit_variable_variable(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        Variable::BracedVariableVariable(inner) => {
+            if visitor.visit_braced_variable_variable(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_variable_variable<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &VariableVariable,
+) -> Traversal {
+    if visitor.visit_variable(&node.variable).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_braced_variable_variable<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &BracedVariableVariable,
+) -> Traversal {
+    if visitor.visit_expression(&node.variable).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_static_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &StaticStatement,
+) -> Traversal {
+    for item in &node.vars {
+        if visitor.visit_static_var(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_switch_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &SwitchStatement,
+) -> Traversal {
+    if visitor.visit_expression(&node.condition).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &node.cases {
+        if visitor.visit_case(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_echo_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &EchoStatement,
+) -> Traversal {
+    for item in &node.values {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_ending(&node.ending).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_return_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &ReturnStatement,
+) -> Traversal {
+    if let Some(item) = &node.value {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_ending(&node.ending).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_use_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &UseStatement,
+) -> Traversal {
+    if visitor.visit_use_kind(&node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &node.uses {
+        if visitor.visit_use(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_group_use_statement<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &GroupUseStatement,
+) -> Traversal {
+    if visitor.visit_simple_identifier(&node.prefix).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_use_kind(&node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &node.uses {
+        if visitor.visit_use(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_static_var<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &StaticVar,
+) -> Traversal {
+    if visitor.visit_variable(&node.var).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &node.default {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_comment<V: ControlFlowVisitor + ?Sized>(visitor: &mut V, node: &Comment) -> Traversal {
+    if visitor.visit_comment_kind(&node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_comment_kind<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &CommentKind,
+) -> Traversal {
+    match node {
+        CommentKind::SingleLine(inner) => {
+            if visitor.visit_single_line_comment(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        CommentKind::MultiLine(inner) => {
+            if visitor.visit_multi_line_comment(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        CommentKind::HashMark(inner) => {
+            if visitor.visit_hash_mark_comment(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        CommentKind::DocBlock(inner) => {
+            if visitor.visit_doc_block_comment(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_comment<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DocBlockComment,
+) -> Traversal {
+    if visitor.visit_doc_block(&node.doc).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DocBlock,
+) -> Traversal {
+    for item in &node.nodes {
+        if visitor.visit_doc_block_node(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_node<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DocBlockNode,
+) -> Traversal {
+    match node {
+        DocBlockNode::Text(inner) => {
+            if visitor.visit_doc_block_text_node(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockNode::Tag(inner) => {
+            if visitor.visit_doc_block_tag_node(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_tag_node<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DocBlockTagNode,
+) -> Traversal {
+    if visitor.visit_doc_block_tag(&node.tag).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_tag<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DocBlockTag,
+) -> Traversal {
+    match node {
+        DocBlockTag::ParamClosureThis(inner) => {
+            if visitor
+                .visit_doc_block_param_closure_this_tag(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Param(inner) => {
+            if visitor.visit_doc_block_param_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Return(inner) => {
+            if visitor.visit_doc_block_return_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Throws(inner) => {
+            if visitor.visit_doc_block_throws_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Var(inner) => {
+            if visitor.visit_doc_block_var_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Property(inner) => {
+            if visitor.visit_doc_block_property_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Method(inner) => {
+            if visitor.visit_doc_block_method_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Template(inner) => {
+            if visitor.visit_doc_block_template_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Extends(inner) => {
+            if visitor.visit_doc_block_extends_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Implements(inner) => {
+            if visitor.visit_doc_block_implements_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Uses(inner) => {
+            if visitor.visit_doc_block_uses_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Deprecated(inner) => {
+            if visitor.visit_doc_block_deprecated_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Generic(inner) => {
+            if visitor.visit_doc_block_generic_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_param_closure_this_tag<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DocBlockParamClosureThisTag,
+) -> Traversal {
+    if visitor.visit_data_type(&node.r#type).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_simple_variable(&node.variable).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_param_tag<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DocBlockParamTag,
+) -> Traversal {
+    if let Some(item) = &node.data_type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &node.variable {
+        if visitor.visit_simple_variable(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_return_tag<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DocBlockReturnTag,
+) -> Traversal {
+    if let Some(item) = &node.data_type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_throws_tag<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DocBlockThrowsTag,
+) -> Traversal {
+    if let Some(item) = &node.data_type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_var_tag<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DocBlockVarTag,
+) -> Traversal {
+    if let Some(item) = &node.data_type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &node.variable {
+        if visitor.visit_simple_variable(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_property_tag<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DocBlockPropertyTag,
+) -> Traversal {
+    if let Some(item) = &node.data_type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_simple_variable(&node.variable).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_method_tag<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DocBlockMethodTag,
+) -> Traversal {
+    if let Some(item) = &node.return_type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_simple_identifier(&node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &node.templates {
+        if visitor.visit_doc_block_template_tag_value(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor
+        .visit_function_parameter_list(&node.parameters)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_template_tag_value<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DocBlockTemplateTagValue,
+) -> Traversal {
+    if visitor.visit_simple_identifier(&node.template).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &node.bound {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &node.default {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &node.lower_bound {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &node.description {
+        if visitor.visit_doc_block_text_node(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_template_tag<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DocBlockTemplateTag,
+) -> Traversal {
+    if visitor
+        .visit_doc_block_template_tag_value(&node.value)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_extends_tag<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DocBlockExtendsTag,
+) -> Traversal {
+    if visitor.visit_data_type(&node.data_type).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_implements_tag<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DocBlockImplementsTag,
+) -> Traversal {
+    if visitor.visit_data_type(&node.data_type).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_uses_tag<V: ControlFlowVisitor + ?Sized>(
+    visitor: &mut V,
+    node: &DocBlockUsesTag,
+) -> Traversal {
+    if visitor.visit_data_type(&node.data_type).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
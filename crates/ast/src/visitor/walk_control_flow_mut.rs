@@ -0,0 +1,4581 @@
+// This file is automatically generated by the generate-visitor.php script.
+// Do not modify this file directly.
+#![allow(unused, clippy::single_match, clippy::collapsible_match)]
+
+use super::{ControlFlowVisitorMut, Traversal};
+use crate::*;
+
+pub fn walk_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut [Statement],
+) -> Traversal {
+    for statement in node {
+        if visitor.visit_statement(statement).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+
+    Traversal::Continue
+}
+
+pub fn walk_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Statement,
+) -> Traversal {
+    if visitor.visit_statement_kind(&mut node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_statement_kind_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut StatementKind,
+) -> Traversal {
+    match node {
+        StatementKind::FullOpeningTag(inner) => {
+            if visitor.visit_full_opening_tag_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::ShortOpeningTag(inner) => {
+            if visitor.visit_short_opening_tag_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::EchoOpeningTag(inner) => {
+            if visitor.visit_echo_opening_tag_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::ClosingTag(inner) => {
+            if visitor.visit_closing_tag_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::InlineHtml(inner) => {
+            if visitor.visit_inline_html_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Label(inner) => {
+            if visitor.visit_label_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Goto(inner) => {
+            if visitor.visit_goto_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::HaltCompiler(inner) => {
+            if visitor.visit_halt_compiler_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Static(inner) => {
+            if visitor.visit_static_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::DoWhile(inner) => {
+            if visitor.visit_do_while_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::While(inner) => {
+            if visitor.visit_while_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::For(inner) => {
+            if visitor.visit_for_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Foreach(inner) => {
+            if visitor.visit_foreach_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Break(inner) => {
+            if visitor.visit_break_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Continue(inner) => {
+            if visitor.visit_continue_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Constant(inner) => {
+            if visitor.visit_constant_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Function(inner) => {
+            if visitor.visit_function_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Class(inner) => {
+            if visitor.visit_class_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Trait(inner) => {
+            if visitor.visit_trait_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Interface(inner) => {
+            if visitor.visit_interface_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::If(inner) => {
+            if visitor.visit_if_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Switch(inner) => {
+            if visitor.visit_switch_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Echo(inner) => {
+            if visitor.visit_echo_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Expression(inner) => {
+            if visitor.visit_expression_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Return(inner) => {
+            if visitor.visit_return_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Namespace(inner) => {
+            if visitor.visit_namespace_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Use(inner) => {
+            if visitor.visit_use_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::GroupUse(inner) => {
+            if visitor.visit_group_use_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Try(inner) => {
+            if visitor.visit_try_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::UnitEnum(inner) => {
+            if visitor.visit_unit_enum_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::BackedEnum(inner) => {
+            if visitor.visit_backed_enum_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Block(inner) => {
+            if visitor.visit_block_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Global(inner) => {
+            if visitor.visit_global_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StatementKind::Declare(inner) => {
+            if visitor.visit_declare_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Expression,
+) -> Traversal {
+    if visitor.visit_expression_kind(&mut node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_expression_kind_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ExpressionKind,
+) -> Traversal {
+    match node {
+        ExpressionKind::Missing(inner) => {
+            if visitor.visit_missing_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Eval(inner) => {
+            if visitor.visit_eval_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Empty(inner) => {
+            if visitor.visit_empty_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Die(inner) => {
+            if visitor.visit_die_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Exit(inner) => {
+            if visitor.visit_exit_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Isset(inner) => {
+            if visitor.visit_isset_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Unset(inner) => {
+            if visitor.visit_unset_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Print(inner) => {
+            if visitor.visit_print_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Literal(inner) => {
+            if visitor.visit_literal(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::ArithmeticOperation(inner) => {
+            if visitor
+                .visit_arithmetic_operation_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::AssignmentOperation(inner) => {
+            if visitor
+                .visit_assignment_operation_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::BitwiseOperation(inner) => {
+            if visitor.visit_bitwise_operation_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::ComparisonOperation(inner) => {
+            if visitor
+                .visit_comparison_operation_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::LogicalOperation(inner) => {
+            if visitor.visit_logical_operation_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Concat(inner) => {
+            if visitor.visit_concat_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Instanceof(inner) => {
+            if visitor.visit_instanceof_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Reference(inner) => {
+            if visitor.visit_reference_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Parenthesized(inner) => {
+            if visitor.visit_parenthesized_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::ErrorSuppress(inner) => {
+            if visitor.visit_error_suppress_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Identifier(inner) => {
+            if visitor.visit_identifier(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Variable(inner) => {
+            if visitor.visit_variable(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Include(inner) => {
+            if visitor.visit_include_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::IncludeOnce(inner) => {
+            if visitor.visit_include_once_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Require(inner) => {
+            if visitor.visit_require_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::RequireOnce(inner) => {
+            if visitor.visit_require_once_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::FunctionCall(inner) => {
+            if visitor.visit_function_call_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::FunctionClosureCreation(inner) => {
+            if visitor
+                .visit_function_closure_creation_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::MethodCall(inner) => {
+            if visitor.visit_method_call_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::MethodClosureCreation(inner) => {
+            if visitor
+                .visit_method_closure_creation_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::NullsafeMethodCall(inner) => {
+            if visitor
+                .visit_nullsafe_method_call_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::StaticMethodCall(inner) => {
+            if visitor.visit_static_method_call_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::StaticVariableMethodCall(inner) => {
+            if visitor
+                .visit_static_variable_method_call_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::StaticMethodClosureCreation(inner) => {
+            if visitor
+                .visit_static_method_closure_creation_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::StaticVariableMethodClosureCreation(inner) => {
+            if visitor
+                .visit_static_variable_method_closure_creation_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::PropertyFetch(inner) => {
+            if visitor.visit_property_fetch_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::NullsafePropertyFetch(inner) => {
+            if visitor
+                .visit_nullsafe_property_fetch_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::StaticPropertyFetch(inner) => {
+            if visitor
+                .visit_static_property_fetch_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::ConstantFetch(inner) => {
+            if visitor.visit_constant_fetch_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Static(inner) => {
+            if visitor.visit_static_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Self_(inner) => {
+            if visitor.visit_self_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Parent(inner) => {
+            if visitor.visit_parent_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Array(inner) => {
+            if visitor.visit_array_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::List(inner) => {
+            if visitor.visit_list_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Closure(inner) => {
+            if visitor.visit_closure_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::ArrowFunction(inner) => {
+            if visitor.visit_arrow_function_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::New(inner) => {
+            if visitor.visit_new_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::InterpolatedString(inner) => {
+            if visitor
+                .visit_interpolated_string_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Heredoc(inner) => {
+            if visitor.visit_heredoc_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Nowdoc(inner) => {
+            if visitor.visit_nowdoc_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::ShellExec(inner) => {
+            if visitor.visit_shell_exec_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::AnonymousClass(inner) => {
+            if visitor.visit_anonymous_class_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Bool(inner) => {
+            if visitor.visit_bool_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::ArrayIndex(inner) => {
+            if visitor.visit_array_index_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::MagicConstant(inner) => {
+            if visitor.visit_magic_constant_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::ShortTernary(inner) => {
+            if visitor.visit_short_ternary_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Ternary(inner) => {
+            if visitor.visit_ternary_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Coalesce(inner) => {
+            if visitor.visit_coalesce_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Clone(inner) => {
+            if visitor.visit_clone_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Match(inner) => {
+            if visitor.visit_match_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Throw(inner) => {
+            if visitor.visit_throw_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Yield(inner) => {
+            if visitor.visit_yield_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::YieldFrom(inner) => {
+            if visitor.visit_yield_from_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Cast(inner) => {
+            if visitor.visit_cast_expression(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ExpressionKind::Name(inner) => {
+            if visitor.visit_name(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_expression_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ExpressionStatement,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.expression).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_ending(&mut node.ending).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_global_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut GlobalStatement,
+) -> Traversal {
+    for item in &mut node.variables {
+        if visitor.visit_variable(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_block_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut BlockStatement,
+) -> Traversal {
+    for item in &mut node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_case_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Case,
+) -> Traversal {
+    if let Some(item) = &mut node.condition {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_case_separator(&mut node.separator).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_use_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Use,
+) -> Traversal {
+    if visitor.visit_name(&mut node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &mut node.alias {
+        if visitor.visit_simple_identifier(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_use_kind(&mut node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_use_kind_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut UseKind,
+) -> Traversal {
+    match node {
+        UseKind::Normal => {}
+        UseKind::Function => {}
+        UseKind::Const => {}
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_eval_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut EvalExpression,
+) -> Traversal {
+    if visitor.visit_single_argument(&mut node.argument).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_empty_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut EmptyExpression,
+) -> Traversal {
+    if visitor.visit_single_argument(&mut node.argument).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_die_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DieExpression,
+) -> Traversal {
+    if let Some(item) = &mut node.argument {
+        if visitor.visit_single_argument(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_exit_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ExitExpression,
+) -> Traversal {
+    if let Some(item) = &mut node.argument {
+        if visitor.visit_single_argument(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_isset_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut IssetExpression,
+) -> Traversal {
+    if visitor.visit_argument_list(&mut node.arguments).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_unset_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut UnsetExpression,
+) -> Traversal {
+    if visitor.visit_argument_list(&mut node.arguments).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_print_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut PrintExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_concat_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ConcatExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.left).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.right).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_instanceof_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut InstanceofExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.left).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.right).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_reference_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ReferenceExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.right).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_parenthesized_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ParenthesizedExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.expr).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_error_suppress_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ErrorSuppressExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.expr).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_include_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut IncludeExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.path).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_include_once_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut IncludeOnceExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.path).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_require_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut RequireExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.path).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_require_once_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut RequireOnceExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.path).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_function_call_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut FunctionCallExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_argument_list(&mut node.arguments).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_function_closure_creation_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut FunctionClosureCreationExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor
+        .visit_argument_placeholder(&mut node.placeholder)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_method_call_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut MethodCallExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.method).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_argument_list(&mut node.arguments).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_method_closure_creation_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut MethodClosureCreationExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.method).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor
+        .visit_argument_placeholder(&mut node.placeholder)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_nullsafe_method_call_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut NullsafeMethodCallExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.method).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_argument_list(&mut node.arguments).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_static_method_call_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut StaticMethodCallExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_identifier(&mut node.method).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_argument_list(&mut node.arguments).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_static_variable_method_call_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut StaticVariableMethodCallExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_variable(&mut node.method).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_argument_list(&mut node.arguments).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_static_method_closure_creation_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut StaticMethodClosureCreationExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_identifier(&mut node.method).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor
+        .visit_argument_placeholder(&mut node.placeholder)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_static_variable_method_closure_creation_expression_mut<
+    V: ControlFlowVisitorMut + ?Sized,
+>(
+    visitor: &mut V,
+    node: &mut StaticVariableMethodClosureCreationExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_variable(&mut node.method).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor
+        .visit_argument_placeholder(&mut node.placeholder)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_property_fetch_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut PropertyFetchExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.property).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_nullsafe_property_fetch_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut NullsafePropertyFetchExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.property).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_static_property_fetch_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut StaticPropertyFetchExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_variable(&mut node.property).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_constant_fetch_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ConstantFetchExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_identifier(&mut node.constant).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_array_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ArrayExpression,
+) -> Traversal {
+    if visitor.visit_array_kind(&mut node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &mut node.items.inner {
+        if visitor.visit_array_item(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_array_kind_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ArrayKind,
+) -> Traversal {
+    match node {
+        ArrayKind::Short(inner) => {
+            if visitor.visit_array_kind_short(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArrayKind::Long(inner) => {
+            if visitor.visit_array_kind_long(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_list_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ListExpression,
+) -> Traversal {
+    for item in &mut node.items {
+        if visitor.visit_list_entry(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_new_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut NewExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &mut node.arguments {
+        if visitor.visit_argument_list(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_interpolated_string_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut InterpolatedStringExpression,
+) -> Traversal {
+    for item in &mut node.parts {
+        if visitor.visit_string_part(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_heredoc_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut HeredocExpression,
+) -> Traversal {
+    for item in &mut node.parts {
+        if visitor.visit_string_part(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_shell_exec_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ShellExecExpression,
+) -> Traversal {
+    for item in &mut node.parts {
+        if visitor.visit_string_part(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_array_index_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ArrayIndexExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.array).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &mut node.index {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_short_ternary_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ShortTernaryExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.condition).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.r#else).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_ternary_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut TernaryExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.condition).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.then).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.r#else).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_coalesce_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut CoalesceExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.lhs).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.rhs).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_clone_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut CloneExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.target).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_match_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut MatchExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.condition).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &mut node.default {
+        if visitor.visit_default_match_arm(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    for item in &mut node.arms {
+        if visitor.visit_match_arm(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_throw_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ThrowExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_yield_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut YieldExpression,
+) -> Traversal {
+    if let Some(item) = &mut node.key {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &mut node.value {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_yield_from_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut YieldFromExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_cast_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut CastExpression,
+) -> Traversal {
+    if visitor.visit_cast_kind(&mut node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_default_match_arm_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DefaultMatchArm,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_match_arm_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut MatchArm,
+) -> Traversal {
+    for item in &mut node.conditions {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_expression(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_magic_constant_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut MagicConstantExpression,
+) -> Traversal {
+    if visitor.visit_magic_constant_kind(&mut node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_magic_constant_kind_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut MagicConstantKind,
+) -> Traversal {
+    match node {
+        MagicConstantKind::Directory => {}
+        MagicConstantKind::File => {}
+        MagicConstantKind::Line => {}
+        MagicConstantKind::Function => {}
+        MagicConstantKind::Class => {}
+        MagicConstantKind::Method => {}
+        MagicConstantKind::Property => {}
+        MagicConstantKind::Namespace => {}
+        MagicConstantKind::Trait => {}
+        MagicConstantKind::CompilerHaltOffset => {}
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_string_part_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut StringPart,
+) -> Traversal {
+    match node {
+        StringPart::Literal(inner) => {
+            if visitor.visit_literal_string_part(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        StringPart::Expression(inner) => {
+            if visitor.visit_expression_string_part(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_expression_string_part_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ExpressionStringPart,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.expression).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_array_item_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ArrayItem,
+) -> Traversal {
+    match node {
+        ArrayItem::Value(inner) => {
+            if visitor.visit_array_item_value(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArrayItem::ReferencedValue(inner) => {
+            if visitor.visit_array_item_referenced_value(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArrayItem::SpreadValue(inner) => {
+            if visitor.visit_array_item_spread_value(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArrayItem::KeyValue(inner) => {
+            if visitor.visit_array_item_key_value(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArrayItem::ReferencedKeyValue(inner) => {
+            if visitor
+                .visit_array_item_referenced_key_value(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_array_item_value_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ArrayItemValue,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_array_item_referenced_value_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ArrayItemReferencedValue,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_array_item_spread_value_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ArrayItemSpreadValue,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_array_item_key_value_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ArrayItemKeyValue,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.key).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_array_item_referenced_key_value_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ArrayItemReferencedKeyValue,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.key).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_list_entry_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ListEntry,
+) -> Traversal {
+    match node {
+        ListEntry::Value(inner) => {
+            if visitor.visit_list_entry_value(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ListEntry::KeyValue(inner) => {
+            if visitor.visit_list_entry_key_value(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_list_entry_value_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ListEntryValue,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_list_entry_key_value_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ListEntryKeyValue,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.key).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_positional_argument_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut PositionalArgument,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_named_argument_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut NamedArgument,
+) -> Traversal {
+    if visitor.visit_simple_identifier(&mut node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_argument_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Argument,
+) -> Traversal {
+    match node {
+        Argument::Positional(inner) => {
+            if visitor.visit_positional_argument(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        Argument::Named(inner) => {
+            if visitor.visit_named_argument(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_argument_list_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ArgumentList,
+) -> Traversal {
+    for item in &mut node.arguments {
+        if visitor.visit_argument(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_single_argument_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut SingleArgument,
+) -> Traversal {
+    if let Some(item) = &mut node.argument {
+        if visitor.visit_argument(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_attribute_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Attribute,
+) -> Traversal {
+    if visitor.visit_name(&mut node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &mut node.arguments {
+        if visitor.visit_argument_list(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_attribute_group_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut AttributeGroup,
+) -> Traversal {
+    for item in &mut node.members {
+        if visitor.visit_attribute(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_class_body_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ClassBody,
+) -> Traversal {
+    for item in &mut node.members {
+        if visitor.visit_classish_member(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_class_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ClassStatement,
+) -> Traversal {
+    for item in &mut node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor
+        .visit_class_modifier_group(&mut node.modifiers)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if visitor.visit_name(&mut node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &mut node.extends {
+        if visitor.visit_class_extends(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &mut node.implements {
+        if visitor.visit_class_implements(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_class_body(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_anonymous_class_body_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut AnonymousClassBody,
+) -> Traversal {
+    for item in &mut node.members {
+        if visitor.visit_classish_member(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_anonymous_class_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut AnonymousClassExpression,
+) -> Traversal {
+    for item in &mut node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &mut node.extends {
+        if visitor.visit_class_extends(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &mut node.implements {
+        if visitor.visit_class_implements(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_anonymous_class_body(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_class_extends_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ClassExtends,
+) -> Traversal {
+    if visitor.visit_name(&mut node.parent).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_class_implements_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ClassImplements,
+) -> Traversal {
+    for item in &mut node.interfaces.inner {
+        if visitor.visit_name(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_classish_member_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ClassishMember,
+) -> Traversal {
+    match node {
+        ClassishMember::Constant(inner) => {
+            if visitor.visit_classish_constant(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ClassishMember::TraitUsage(inner) => {
+            if visitor.visit_trait_usage(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ClassishMember::Property(inner) => {
+            if visitor.visit_property(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ClassishMember::Method(inner) => {
+            if visitor.visit_method(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ClassishMember::Missing(inner) => {
+            if visitor.visit_missing_classish_member(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_method_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Method,
+) -> Traversal {
+    for item in &mut node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor
+        .visit_method_modifier_group(&mut node.modifiers)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if visitor.visit_simple_identifier(&mut node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor
+        .visit_method_parameter_list(&mut node.parameters)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &mut node.return_type {
+        if visitor.visit_return_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_method_body(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_method_body_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut MethodBody,
+) -> Traversal {
+    if visitor.visit_method_body_kind(&mut node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_method_body_kind_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut MethodBodyKind,
+) -> Traversal {
+    match node {
+        MethodBodyKind::Abstract(inner) => {
+            if visitor.visit_abstract_method_body(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        MethodBodyKind::Concrete(inner) => {
+            if visitor.visit_concrete_method_body(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        MethodBodyKind::Missing(inner) => {
+            if visitor.visit_missing_method_body(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_concrete_method_body_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ConcreteMethodBody,
+) -> Traversal {
+    for item in &mut node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_method_parameter_list_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut MethodParameterList,
+) -> Traversal {
+    for item in &mut node.parameters.inner {
+        if visitor.visit_method_parameter(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_method_parameter_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut MethodParameter,
+) -> Traversal {
+    if let Some(item) = &mut node.modifiers {
+        if visitor
+            .visit_promoted_property_modifier_group(item)
+            .is_stop()
+        {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_simple_variable(&mut node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &mut node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &mut node.data_type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &mut node.default {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_constant_entry_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ConstantEntry,
+) -> Traversal {
+    if visitor.visit_name(&mut node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_classish_constant_entry_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ClassishConstantEntry,
+) -> Traversal {
+    if visitor.visit_simple_identifier(&mut node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_constant_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ConstantStatement,
+) -> Traversal {
+    for item in &mut node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    for item in &mut node.entries {
+        if visitor.visit_constant_entry(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_classish_constant_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ClassishConstant,
+) -> Traversal {
+    for item in &mut node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor
+        .visit_constant_modifier_group(&mut node.modifiers)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &mut node.data_type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    for item in &mut node.entries {
+        if visitor.visit_classish_constant_entry(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_if_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut IfStatement,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.condition).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_if_statement_body(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_if_statement_body_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut IfStatementBody,
+) -> Traversal {
+    match node {
+        IfStatementBody::Statement(inner) => {
+            if visitor.visit_if_statement_body_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        IfStatementBody::Block(inner) => {
+            if visitor.visit_if_statement_body_block(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_if_statement_body_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut IfStatementBodyStatement,
+) -> Traversal {
+    if visitor.visit_statement(&mut node.statement).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &mut node.elseifs {
+        if visitor.visit_if_statement_else_if(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &mut node.r#else {
+        if visitor.visit_if_statement_else(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_if_statement_body_block_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut IfStatementBodyBlock,
+) -> Traversal {
+    for item in &mut node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    for item in &mut node.elseifs {
+        if visitor.visit_if_statement_else_if_block(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &mut node.r#else {
+        if visitor.visit_if_statement_else_block(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_ending(&mut node.ending).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_if_statement_else_if_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut IfStatementElseIf,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.condition).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_statement(&mut node.statement).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_if_statement_else_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut IfStatementElse,
+) -> Traversal {
+    if visitor.visit_statement(&mut node.statement).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_if_statement_else_if_block_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut IfStatementElseIfBlock,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.condition).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &mut node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_if_statement_else_block_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut IfStatementElseBlock,
+) -> Traversal {
+    for item in &mut node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_data_type_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DataType,
+) -> Traversal {
+    Traversal::Continue
+}
+
+pub fn walk_declare_entry_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DeclareEntry,
+) -> Traversal {
+    if visitor.visit_simple_identifier(&mut node.key).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_literal(&mut node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_declare_entry_group_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DeclareEntryGroup,
+) -> Traversal {
+    for item in &mut node.entries {
+        if visitor.visit_declare_entry(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_declare_body_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DeclareBody,
+) -> Traversal {
+    match node {
+        DeclareBody::Noop(inner) => {
+            if visitor.visit_declare_body_noop(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DeclareBody::Braced(inner) => {
+            if visitor.visit_declare_body_braced(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DeclareBody::Statement(inner) => {
+            if visitor.visit_declare_body_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DeclareBody::Block(inner) => {
+            if visitor.visit_declare_body_block(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_declare_body_braced_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DeclareBodyBraced,
+) -> Traversal {
+    for item in &mut node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_declare_body_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DeclareBodyStatement,
+) -> Traversal {
+    if visitor.visit_statement(&mut node.statement).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_declare_body_block_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DeclareBodyBlock,
+) -> Traversal {
+    for item in &mut node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_declare_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DeclareStatement,
+) -> Traversal {
+    if visitor
+        .visit_declare_entry_group(&mut node.entries)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if visitor.visit_declare_body(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_unit_enum_case_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut UnitEnumCase,
+) -> Traversal {
+    for item in &mut node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_simple_identifier(&mut node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_unit_enum_member_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut UnitEnumMember,
+) -> Traversal {
+    match node {
+        UnitEnumMember::Case(inner) => {
+            if visitor.visit_unit_enum_case(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        UnitEnumMember::Classish(inner) => {
+            if visitor.visit_classish_member(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_unit_enum_body_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut UnitEnumBody,
+) -> Traversal {
+    for item in &mut node.members {
+        if visitor.visit_unit_enum_member(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_unit_enum_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut UnitEnumStatement,
+) -> Traversal {
+    for item in &mut node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_name(&mut node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &mut node.implements {
+        if visitor.visit_name(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_unit_enum_body(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_backed_enum_case_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut BackedEnumCase,
+) -> Traversal {
+    for item in &mut node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_simple_identifier(&mut node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_backed_enum_member_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut BackedEnumMember,
+) -> Traversal {
+    match node {
+        BackedEnumMember::Case(inner) => {
+            if visitor.visit_backed_enum_case(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        BackedEnumMember::Classish(inner) => {
+            if visitor.visit_classish_member(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_backed_enum_body_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut BackedEnumBody,
+) -> Traversal {
+    for item in &mut node.members {
+        if visitor.visit_backed_enum_member(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_backed_enum_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut BackedEnumStatement,
+) -> Traversal {
+    for item in &mut node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_name(&mut node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &mut node.implements {
+        if visitor.visit_name(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_backed_enum_body(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_backed_enum_type_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut BackedEnumType,
+) -> Traversal {
+    match node {
+        BackedEnumType::Invalid => {}
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_return_type_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ReturnType,
+) -> Traversal {
+    if visitor.visit_data_type(&mut node.data_type).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_function_parameter_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut FunctionParameter,
+) -> Traversal {
+    if visitor.visit_simple_variable(&mut node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &mut node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &mut node.data_type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &mut node.default {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_function_parameter_list_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut FunctionParameterList,
+) -> Traversal {
+    for item in &mut node.parameters.inner {
+        if visitor.visit_function_parameter(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_function_body_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut FunctionBody,
+) -> Traversal {
+    for item in &mut node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_function_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut FunctionStatement,
+) -> Traversal {
+    for item in &mut node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_name(&mut node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor
+        .visit_function_parameter_list(&mut node.parameters)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &mut node.return_type {
+        if visitor.visit_return_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_function_body(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_closure_use_variable_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ClosureUseVariable,
+) -> Traversal {
+    if visitor.visit_simple_variable(&mut node.variable).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_closure_use_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ClosureUse,
+) -> Traversal {
+    for item in &mut node.variables.inner {
+        if visitor.visit_closure_use_variable(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_closure_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ClosureExpression,
+) -> Traversal {
+    for item in &mut node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor
+        .visit_function_parameter_list(&mut node.parameters)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &mut node.uses {
+        if visitor.visit_closure_use(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &mut node.return_type {
+        if visitor.visit_return_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_function_body(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_arrow_function_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ArrowFunctionExpression,
+) -> Traversal {
+    for item in &mut node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor
+        .visit_function_parameter_list(&mut node.parameters)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &mut node.return_type {
+        if visitor.visit_return_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_expression(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_label_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut LabelStatement,
+) -> Traversal {
+    if visitor.visit_simple_identifier(&mut node.label).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_goto_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut GotoStatement,
+) -> Traversal {
+    if visitor.visit_simple_identifier(&mut node.label).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_identifier_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Identifier,
+) -> Traversal {
+    match node {
+        Identifier::SimpleIdentifier(inner) => {
+            if visitor.visit_simple_identifier(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        Identifier::DynamicIdentifier(inner) => {
+            if visitor.visit_dynamic_identifier(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_dynamic_identifier_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DynamicIdentifier,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.expr).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_interface_extends_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut InterfaceExtends,
+) -> Traversal {
+    for item in &mut node.parents.inner {
+        if visitor.visit_name(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_interface_body_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut InterfaceBody,
+) -> Traversal {
+    for item in &mut node.members {
+        if visitor.visit_classish_member(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_interface_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut InterfaceStatement,
+) -> Traversal {
+    for item in &mut node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_name(&mut node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &mut node.extends {
+        if visitor.visit_interface_extends(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_interface_body(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_literal_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Literal,
+) -> Traversal {
+    if visitor.visit_literal_kind(&mut node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_literal_kind_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut LiteralKind,
+) -> Traversal {
+    match node {
+        LiteralKind::Integer => {}
+        LiteralKind::Float => {}
+        LiteralKind::String => {}
+        LiteralKind::Missing => {}
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_foreach_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ForeachStatement,
+) -> Traversal {
+    if visitor
+        .visit_foreach_statement_iterator(&mut node.iterator)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if visitor
+        .visit_foreach_statement_body(&mut node.body)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_foreach_statement_iterator_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ForeachStatementIterator,
+) -> Traversal {
+    match node {
+        ForeachStatementIterator::Value(inner) => {
+            if visitor
+                .visit_foreach_statement_iterator_value(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ForeachStatementIterator::KeyAndValue(inner) => {
+            if visitor
+                .visit_foreach_statement_iterator_key_and_value(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_foreach_statement_iterator_value_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ForeachStatementIteratorValue,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.expression).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_foreach_statement_iterator_key_and_value_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ForeachStatementIteratorKeyAndValue,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.expression).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.key).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_foreach_statement_body_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ForeachStatementBody,
+) -> Traversal {
+    match node {
+        ForeachStatementBody::Statement(inner) => {
+            if visitor
+                .visit_foreach_statement_body_statement(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ForeachStatementBody::Block(inner) => {
+            if visitor.visit_foreach_statement_body_block(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_foreach_statement_body_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ForeachStatementBodyStatement,
+) -> Traversal {
+    if visitor.visit_statement(&mut node.statement).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_foreach_statement_body_block_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ForeachStatementBodyBlock,
+) -> Traversal {
+    for item in &mut node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_ending(&mut node.ending).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_for_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ForStatement,
+) -> Traversal {
+    if visitor
+        .visit_for_statement_iterator(&mut node.iterator)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if visitor.visit_for_statement_body(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_for_statement_iterator_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ForStatementIterator,
+) -> Traversal {
+    for item in &mut node.initializations.inner {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    for item in &mut node.conditions.inner {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    for item in &mut node.r#loop.inner {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_for_statement_body_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ForStatementBody,
+) -> Traversal {
+    match node {
+        ForStatementBody::Statement(inner) => {
+            if visitor.visit_for_statement_body_statement(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ForStatementBody::Block(inner) => {
+            if visitor.visit_for_statement_body_block(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_for_statement_body_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ForStatementBodyStatement,
+) -> Traversal {
+    if visitor.visit_statement(&mut node.statement).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_for_statement_body_block_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ForStatementBodyBlock,
+) -> Traversal {
+    for item in &mut node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_ending(&mut node.ending).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_do_while_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DoWhileStatement,
+) -> Traversal {
+    if visitor.visit_statement(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.condition).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_while_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut WhileStatement,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.condition).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_while_statement_body(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_while_statement_body_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut WhileStatementBody,
+) -> Traversal {
+    match node {
+        WhileStatementBody::Statement(inner) => {
+            if visitor
+                .visit_while_statement_body_statement(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        WhileStatementBody::Block(inner) => {
+            if visitor.visit_while_statement_body_block(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_while_statement_body_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut WhileStatementBodyStatement,
+) -> Traversal {
+    if visitor.visit_statement(&mut node.statement).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_while_statement_body_block_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut WhileStatementBodyBlock,
+) -> Traversal {
+    for item in &mut node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_ending(&mut node.ending).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_level_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Level,
+) -> Traversal {
+    match node {
+        Level::Literal(inner) => {
+            if visitor.visit_literal_level(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        Level::Parenthesized(inner) => {
+            if visitor.visit_parenthesized_level(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_literal_level_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut LiteralLevel,
+) -> Traversal {
+    if visitor.visit_literal(&mut node.literal).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_break_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut BreakStatement,
+) -> Traversal {
+    if visitor.visit_ending(&mut node.ending).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_continue_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ContinueStatement,
+) -> Traversal {
+    if visitor.visit_ending(&mut node.ending).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_promoted_property_modifier_group_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut PromotedPropertyModifierGroup,
+) -> Traversal {
+    for item in &mut node.modifiers {
+        if visitor.visit_promoted_property_modifier(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_property_modifier_group_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut PropertyModifierGroup,
+) -> Traversal {
+    for item in &mut node.modifiers {
+        if visitor.visit_property_modifier(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_method_modifier_group_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut MethodModifierGroup,
+) -> Traversal {
+    for item in &mut node.modifiers {
+        if visitor.visit_method_modifier(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_class_modifier_group_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ClassModifierGroup,
+) -> Traversal {
+    for item in &mut node.modifiers {
+        if visitor.visit_class_modifier(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_constant_modifier_group_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ConstantModifierGroup,
+) -> Traversal {
+    for item in &mut node.modifiers {
+        if visitor.visit_constant_modifier(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_unbraced_namespace_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut UnbracedNamespace,
+) -> Traversal {
+    if visitor.visit_simple_identifier(&mut node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &mut node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_braced_namespace_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut BracedNamespace,
+) -> Traversal {
+    if let Some(item) = &mut node.name {
+        if visitor.visit_simple_identifier(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor
+        .visit_braced_namespace_body(&mut node.body)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_braced_namespace_body_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut BracedNamespaceBody,
+) -> Traversal {
+    for item in &mut node.statements {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_namespace_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut NamespaceStatement,
+) -> Traversal {
+    match node {
+        NamespaceStatement::Unbraced(inner) => {
+            if visitor.visit_unbraced_namespace(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        NamespaceStatement::Braced(inner) => {
+            if visitor.visit_braced_namespace(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_arithmetic_operation_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ArithmeticOperationExpression,
+) -> Traversal {
+    if visitor
+        .visit_arithmetic_operation_kind(&mut node.kind)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_arithmetic_operation_kind_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ArithmeticOperationKind,
+) -> Traversal {
+    match node {
+        ArithmeticOperationKind::Addition {
+            left, plus, right, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::Subtraction {
+            left, minus, right, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::Multiplication {
+            left,
+            asterisk,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::Division {
+            left, slash, right, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::Modulo {
+            left,
+            percent,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::Exponentiation {
+            left, pow, right, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::Negative { minus, right, .. } => {
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::Positive { plus, right, .. } => {
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::PreIncrement {
+            increment, right, ..
+        } => {
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::PostIncrement {
+            left, increment, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::PreDecrement {
+            decrement, right, ..
+        } => {
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ArithmeticOperationKind::PostDecrement {
+            left, decrement, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_assignment_operation_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut AssignmentOperationExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.left).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor
+        .visit_assignment_operation_kind(&mut node.kind)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.right).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_bitwise_operation_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut BitwiseOperationExpression,
+) -> Traversal {
+    if visitor
+        .visit_bitwise_operation_kind(&mut node.kind)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_bitwise_operation_kind_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut BitwiseOperationKind,
+) -> Traversal {
+    match node {
+        BitwiseOperationKind::And {
+            left, and, right, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        BitwiseOperationKind::Or {
+            left, or, right, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        BitwiseOperationKind::Xor {
+            left, xor, right, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        BitwiseOperationKind::LeftShift {
+            left,
+            left_shift,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        BitwiseOperationKind::RightShift {
+            left,
+            right_shift,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        BitwiseOperationKind::Not { not, right, .. } => {
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_comparison_operation_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ComparisonOperationExpression,
+) -> Traversal {
+    if visitor
+        .visit_comparison_operation_kind(&mut node.kind)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_comparison_operation_kind_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ComparisonOperationKind,
+) -> Traversal {
+    match node {
+        ComparisonOperationKind::Equal {
+            left,
+            double_equals,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ComparisonOperationKind::Identical {
+            left,
+            triple_equals,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ComparisonOperationKind::NotEqual {
+            left,
+            bang_equals,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ComparisonOperationKind::AngledNotEqual {
+            left,
+            angled_left_right,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ComparisonOperationKind::NotIdentical {
+            left,
+            bang_double_equals,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ComparisonOperationKind::LessThan {
+            left,
+            less_than,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ComparisonOperationKind::GreaterThan {
+            left,
+            greater_than,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ComparisonOperationKind::LessThanOrEqual {
+            left,
+            less_than_equals,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ComparisonOperationKind::GreaterThanOrEqual {
+            left,
+            greater_than_equals,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        ComparisonOperationKind::Spaceship {
+            left,
+            spaceship,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_logical_operation_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut LogicalOperationExpression,
+) -> Traversal {
+    if visitor
+        .visit_logical_operation_kind(&mut node.kind)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_logical_operation_kind_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut LogicalOperationKind,
+) -> Traversal {
+    match node {
+        LogicalOperationKind::And {
+            left,
+            double_ampersand,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        LogicalOperationKind::Or {
+            left,
+            double_pipe,
+            right,
+            ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        LogicalOperationKind::Not { bang, right, .. } => {
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        LogicalOperationKind::LogicalAnd {
+            left, and, right, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        LogicalOperationKind::LogicalOr {
+            left, or, right, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        LogicalOperationKind::LogicalXor {
+            left, xor, right, ..
+        } => {
+            if visitor.visit_expression(left).is_stop() {
+                return Traversal::Stop;
+            }
+            if visitor.visit_expression(right).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_name_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Name,
+) -> Traversal {
+    if visitor.visit_name_kind(&mut node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_name_kind_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut NameKind,
+) -> Traversal {
+    match node {
+        NameKind::Special(inner) => {
+            if visitor.visit_special_name(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        NameKind::Unresolved(inner) => {
+            if visitor.visit_unresolved_name(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        NameKind::Resolved(inner) => {
+            if visitor.visit_resolved_name(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_special_name_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut SpecialName,
+) -> Traversal {
+    if visitor.visit_special_name_kind(&mut node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_special_name_kind_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut SpecialNameKind,
+) -> Traversal {
+    match node {
+        SpecialNameKind::Self_ => {}
+        SpecialNameKind::Parent => {}
+        SpecialNameKind::Static => {}
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_property_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Property,
+) -> Traversal {
+    match node {
+        Property::Simple(inner) => {
+            if visitor.visit_simple_property(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        Property::Hooked(inner) => {
+            if visitor.visit_hooked_property(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_simple_property_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut SimpleProperty,
+) -> Traversal {
+    for item in &mut node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor
+        .visit_property_modifier_group(&mut node.modifiers)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &mut node.r#type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    for item in &mut node.entries {
+        if visitor.visit_property_entry(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_hooked_property_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut HookedProperty,
+) -> Traversal {
+    for item in &mut node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor
+        .visit_property_modifier_group(&mut node.modifiers)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &mut node.r#type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_property_entry(&mut node.entry).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_property_hook_list(&mut node.hooks).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_property_hook_list_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut PropertyHookList,
+) -> Traversal {
+    for item in &mut node.hooks {
+        if visitor.visit_property_hook(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_property_hook_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut PropertyHook,
+) -> Traversal {
+    if visitor.visit_property_hook_kind(&mut node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &mut node.parameters {
+        if visitor.visit_function_parameter_list(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_property_hook_body(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_property_hook_body_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut PropertyHookBody,
+) -> Traversal {
+    match node {
+        PropertyHookBody::Concrete(inner) => {
+            if visitor.visit_concrete_property_hook_body(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_concrete_property_hook_body_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ConcretePropertyHookBody,
+) -> Traversal {
+    match node {
+        ConcretePropertyHookBody::Block(inner) => {
+            if visitor
+                .visit_concrete_property_hook_body_block(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        ConcretePropertyHookBody::Expression(inner) => {
+            if visitor
+                .visit_concrete_property_hook_body_expression(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_concrete_property_hook_body_block_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ConcretePropertyHookBodyBlock,
+) -> Traversal {
+    for item in &mut node.body {
+        if visitor.visit_statement(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_concrete_property_hook_body_expression_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ConcretePropertyHookBodyExpression,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.expression).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_property_entry_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut PropertyEntry,
+) -> Traversal {
+    if visitor.visit_property_entry_kind(&mut node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_property_entry_kind_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut PropertyEntryKind,
+) -> Traversal {
+    match node {
+        PropertyEntryKind::Uninitialized(inner) => {
+            if visitor.visit_uninitialized_property_entry(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        PropertyEntryKind::Initialized(inner) => {
+            if visitor.visit_initialized_property_entry(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_uninitialized_property_entry_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut UninitializedPropertyEntry,
+) -> Traversal {
+    if visitor.visit_simple_variable(&mut node.variable).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_initialized_property_entry_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut InitializedPropertyEntry,
+) -> Traversal {
+    if visitor.visit_simple_variable(&mut node.variable).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_expression(&mut node.value).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_trait_body_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut TraitBody,
+) -> Traversal {
+    for item in &mut node.members {
+        if visitor.visit_classish_member(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_trait_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut TraitStatement,
+) -> Traversal {
+    if visitor.visit_name(&mut node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &mut node.attributes {
+        if visitor.visit_attribute_group(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_trait_body(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_trait_usage_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut TraitUsage,
+) -> Traversal {
+    for item in &mut node.traits {
+        if visitor.visit_name(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    for item in &mut node.adaptations {
+        if visitor.visit_trait_usage_adaptation(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_trait_usage_adaptation_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut TraitUsageAdaptation,
+) -> Traversal {
+    if visitor
+        .visit_trait_usage_adaptation_kind(&mut node.kind)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_trait_usage_adaptation_kind_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut TraitUsageAdaptationKind,
+) -> Traversal {
+    match node {
+        TraitUsageAdaptationKind::Alias(inner) => {
+            if visitor.visit_trait_usage_adaptation_alias(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        TraitUsageAdaptationKind::Visibility(inner) => {
+            if visitor
+                .visit_trait_usage_adaptation_visibility(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        TraitUsageAdaptationKind::Precedence(inner) => {
+            if visitor
+                .visit_trait_usage_adaptation_precedence(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_trait_usage_adaptation_alias_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut TraitUsageAdaptationAlias,
+) -> Traversal {
+    if let Some(item) = &mut node.r#trait {
+        if visitor.visit_name(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_simple_identifier(&mut node.method).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_simple_identifier(&mut node.alias).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &mut node.visibility {
+        if visitor.visit_visibility_modifier(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_trait_usage_adaptation_visibility_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut TraitUsageAdaptationVisibility,
+) -> Traversal {
+    if let Some(item) = &mut node.r#trait {
+        if visitor.visit_name(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_simple_identifier(&mut node.method).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor
+        .visit_visibility_modifier(&mut node.visibility)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_trait_usage_adaptation_precedence_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut TraitUsageAdaptationPrecedence,
+) -> Traversal {
+    if let Some(item) = &mut node.r#trait {
+        if visitor.visit_name(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_simple_identifier(&mut node.method).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &mut node.insteadof {
+        if visitor.visit_name(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_catch_type_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut CatchType,
+) -> Traversal {
+    if visitor.visit_catch_type_kind(&mut node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_catch_type_kind_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut CatchTypeKind,
+) -> Traversal {
+    match node {
+        CatchTypeKind::Identifier(inner) => {
+            if visitor.visit_catch_type_kind_identifier(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        CatchTypeKind::Union(inner) => {
+            if visitor.visit_catch_type_kind_union(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_catch_type_kind_identifier_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut CatchTypeKindIdentifier,
+) -> Traversal {
+    if visitor.visit_name(&mut node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_catch_type_kind_union_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut CatchTypeKindUnion,
+) -> Traversal {
+    for item in &mut node.names {
+        if visitor.visit_name(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_try_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut TryStatement,
+) -> Traversal {
+    if visitor.visit(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &mut node.catches {
+        if visitor.visit_catch_block(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &mut node.finally {
+        if visitor.visit_finally_block(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_catch_block_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut CatchBlock,
+) -> Traversal {
+    if visitor.visit_catch_type(&mut node.types).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &mut node.var {
+        if visitor.visit_simple_variable(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_finally_block_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut FinallyBlock,
+) -> Traversal {
+    if visitor.visit(&mut node.body).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_variable_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Variable,
+) -> Traversal {
+    match node {
+        Variable::SimpleVariable(inner) => {
+            if visitor.visit_simple_variable(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        Variable::VariableVariable(inner) => {
+            if visitor.visit_variable_variable(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        Variable::BracedVariableVariable(inner) => {
+            if visitor.visit_braced_variable_variable(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_variable_variable_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut VariableVariable,
+) -> Traversal {
+    if visitor.visit_variable(&mut node.variable).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_braced_variable_variable_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut BracedVariableVariable,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.variable).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_static_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut StaticStatement,
+) -> Traversal {
+    for item in &mut node.vars {
+        if visitor.visit_static_var(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_switch_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut SwitchStatement,
+) -> Traversal {
+    if visitor.visit_expression(&mut node.condition).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &mut node.cases {
+        if visitor.visit_case(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_echo_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut EchoStatement,
+) -> Traversal {
+    for item in &mut node.values {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_ending(&mut node.ending).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_return_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut ReturnStatement,
+) -> Traversal {
+    if let Some(item) = &mut node.value {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_ending(&mut node.ending).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_use_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut UseStatement,
+) -> Traversal {
+    if visitor.visit_use_kind(&mut node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &mut node.uses {
+        if visitor.visit_use(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_group_use_statement_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut GroupUseStatement,
+) -> Traversal {
+    if visitor.visit_simple_identifier(&mut node.prefix).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_use_kind(&mut node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &mut node.uses {
+        if visitor.visit_use(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_static_var_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut StaticVar,
+) -> Traversal {
+    if visitor.visit_variable(&mut node.var).is_stop() {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &mut node.default {
+        if visitor.visit_expression(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_comment_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut Comment,
+) -> Traversal {
+    if visitor.visit_comment_kind(&mut node.kind).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_comment_kind_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut CommentKind,
+) -> Traversal {
+    match node {
+        CommentKind::SingleLine(inner) => {
+            if visitor.visit_single_line_comment(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        CommentKind::MultiLine(inner) => {
+            if visitor.visit_multi_line_comment(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        CommentKind::HashMark(inner) => {
+            if visitor.visit_hash_mark_comment(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        CommentKind::DocBlock(inner) => {
+            if visitor.visit_doc_block_comment(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_comment_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DocBlockComment,
+) -> Traversal {
+    if visitor.visit_doc_block(&mut node.doc).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DocBlock,
+) -> Traversal {
+    for item in &mut node.nodes {
+        if visitor.visit_doc_block_node(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_node_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DocBlockNode,
+) -> Traversal {
+    match node {
+        DocBlockNode::Text(inner) => {
+            if visitor.visit_doc_block_text_node(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockNode::Tag(inner) => {
+            if visitor.visit_doc_block_tag_node(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_tag_node_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DocBlockTagNode,
+) -> Traversal {
+    if visitor.visit_doc_block_tag(&mut node.tag).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_tag_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DocBlockTag,
+) -> Traversal {
+    match node {
+        DocBlockTag::ParamClosureThis(inner) => {
+            if visitor
+                .visit_doc_block_param_closure_this_tag(inner)
+                .is_stop()
+            {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Param(inner) => {
+            if visitor.visit_doc_block_param_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Return(inner) => {
+            if visitor.visit_doc_block_return_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Throws(inner) => {
+            if visitor.visit_doc_block_throws_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Var(inner) => {
+            if visitor.visit_doc_block_var_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Property(inner) => {
+            if visitor.visit_doc_block_property_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Method(inner) => {
+            if visitor.visit_doc_block_method_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Template(inner) => {
+            if visitor.visit_doc_block_template_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Extends(inner) => {
+            if visitor.visit_doc_block_extends_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Implements(inner) => {
+            if visitor.visit_doc_block_implements_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Uses(inner) => {
+            if visitor.visit_doc_block_uses_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Deprecated(inner) => {
+            if visitor.visit_doc_block_deprecated_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        DocBlockTag::Generic(inner) => {
+            if visitor.visit_doc_block_generic_tag(inner).is_stop() {
+                return Traversal::Stop;
+            }
+        }
+        _ => {}
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_param_closure_this_tag_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DocBlockParamClosureThisTag,
+) -> Traversal {
+    if visitor.visit_data_type(&mut node.r#type).is_stop() {
+        return Traversal::Stop;
+    }
+    if visitor.visit_simple_variable(&mut node.variable).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_param_tag_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DocBlockParamTag,
+) -> Traversal {
+    if let Some(item) = &mut node.data_type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &mut node.variable {
+        if visitor.visit_simple_variable(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_return_tag_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DocBlockReturnTag,
+) -> Traversal {
+    if let Some(item) = &mut node.data_type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_throws_tag_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DocBlockThrowsTag,
+) -> Traversal {
+    if let Some(item) = &mut node.data_type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_var_tag_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DocBlockVarTag,
+) -> Traversal {
+    if let Some(item) = &mut node.data_type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &mut node.variable {
+        if visitor.visit_simple_variable(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_property_tag_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DocBlockPropertyTag,
+) -> Traversal {
+    if let Some(item) = &mut node.data_type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_simple_variable(&mut node.variable).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_method_tag_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DocBlockMethodTag,
+) -> Traversal {
+    if let Some(item) = &mut node.return_type {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor.visit_simple_identifier(&mut node.name).is_stop() {
+        return Traversal::Stop;
+    }
+    for item in &mut node.templates {
+        if visitor.visit_doc_block_template_tag_value(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if visitor
+        .visit_function_parameter_list(&mut node.parameters)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_template_tag_value_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DocBlockTemplateTagValue,
+) -> Traversal {
+    if visitor
+        .visit_simple_identifier(&mut node.template)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    if let Some(item) = &mut node.bound {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &mut node.default {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &mut node.lower_bound {
+        if visitor.visit_data_type(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    if let Some(item) = &mut node.description {
+        if visitor.visit_doc_block_text_node(item).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_template_tag_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DocBlockTemplateTag,
+) -> Traversal {
+    if visitor
+        .visit_doc_block_template_tag_value(&mut node.value)
+        .is_stop()
+    {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_extends_tag_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DocBlockExtendsTag,
+) -> Traversal {
+    if visitor.visit_data_type(&mut node.data_type).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_implements_tag_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DocBlockImplementsTag,
+) -> Traversal {
+    if visitor.visit_data_type(&mut node.data_type).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
+
+pub fn walk_doc_block_uses_tag_mut<V: ControlFlowVisitorMut + ?Sized>(
+    visitor: &mut V,
+    node: &mut DocBlockUsesTag,
+) -> Traversal {
+    if visitor.visit_data_type(&mut node.data_type).is_stop() {
+        return Traversal::Stop;
+    }
+    Traversal::Continue
+}
@@ -192,6 +192,7 @@ pub fn walk_case_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Case) {
     if let Some(item) = &mut node.condition {
         visitor.visit_expression(item);
     }
+    visitor.visit_case_separator(&mut node.separator);
     visitor.visit(&mut node.body);
 }
 
@@ -259,12 +260,7 @@ pub fn walk_print_expression_mut<V: VisitorMut + ?Sized>(
     visitor: &mut V,
     node: &mut PrintExpression,
 ) {
-    if let Some(item) = &mut node.value {
-        visitor.visit_expression(item);
-    }
-    if let Some(item) = &mut node.argument {
-        visitor.visit_single_argument(item);
-    }
+    visitor.visit_expression(&mut node.value);
 }
 
 pub fn walk_concat_expression_mut<V: VisitorMut + ?Sized>(
@@ -625,6 +621,7 @@ pub fn walk_magic_constant_kind_mut<V: VisitorMut + ?Sized>(
         MagicConstantKind::Function => {}
         MagicConstantKind::Class => {}
         MagicConstantKind::Method => {}
+        MagicConstantKind::Property => {}
         MagicConstantKind::Namespace => {}
         MagicConstantKind::Trait => {}
         MagicConstantKind::CompilerHaltOffset => {}
@@ -930,6 +927,9 @@ pub fn walk_constant_statement_mut<V: VisitorMut + ?Sized>(
     visitor: &mut V,
     node: &mut ConstantStatement,
 ) {
+    for item in &mut node.attributes {
+        visitor.visit_attribute_group(item);
+    }
     for item in &mut node.entries {
         visitor.visit_constant_entry(item);
     }
@@ -1050,7 +1050,7 @@ pub fn walk_declare_body_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut
     match node {
         DeclareBody::Noop(inner) => visitor.visit_declare_body_noop(inner),
         DeclareBody::Braced(inner) => visitor.visit_declare_body_braced(inner),
-        DeclareBody::Expression(inner) => visitor.visit_declare_body_expression(inner),
+        DeclareBody::Statement(inner) => visitor.visit_declare_body_statement(inner),
         DeclareBody::Block(inner) => visitor.visit_declare_body_block(inner),
         _ => {}
     }
@@ -1065,11 +1065,11 @@ pub fn walk_declare_body_braced_mut<V: VisitorMut + ?Sized>(
     }
 }
 
-pub fn walk_declare_body_expression_mut<V: VisitorMut + ?Sized>(
+pub fn walk_declare_body_statement_mut<V: VisitorMut + ?Sized>(
     visitor: &mut V,
-    node: &mut DeclareBodyExpression,
+    node: &mut DeclareBodyStatement,
 ) {
-    visitor.visit_expression(&mut node.expression);
+    visitor.visit_statement(&mut node.statement);
 }
 
 pub fn walk_declare_body_block_mut<V: VisitorMut + ?Sized>(
@@ -2168,7 +2168,7 @@ pub fn walk_trait_usage_adaptation_precedence_mut<V: VisitorMut + ?Sized>(
     }
     visitor.visit_simple_identifier(&mut node.method);
     for item in &mut node.insteadof {
-        visitor.visit_simple_identifier(item);
+        visitor.visit_name(item);
     }
 }
 
@@ -2188,15 +2188,15 @@ pub fn walk_catch_type_kind_identifier_mut<V: VisitorMut + ?Sized>(
     visitor: &mut V,
     node: &mut CatchTypeKindIdentifier,
 ) {
-    visitor.visit_simple_identifier(&mut node.identifier);
+    visitor.visit_name(&mut node.name);
 }
 
 pub fn walk_catch_type_kind_union_mut<V: VisitorMut + ?Sized>(
     visitor: &mut V,
     node: &mut CatchTypeKindUnion,
 ) {
-    for item in &mut node.identifiers {
-        visitor.visit_simple_identifier(item);
+    for item in &mut node.names {
+        visitor.visit_name(item);
     }
 }
 
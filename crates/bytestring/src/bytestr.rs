@@ -64,9 +64,14 @@ impl ByteStr {
     }
 
     pub fn strip_string_quotes(&self) -> &ByteStr {
-        if self.0[0] == b'"' && self.0[self.0.len() - 1] == b'"' {
-            ByteStr::new(&self.0[1..self.0.len() - 1])
-        } else if self.0[0] == b'\'' && self.0[self.0.len() - 1] == b'\'' {
+        if self.0.len() < 2 {
+            return self;
+        }
+
+        let first = self.0[0];
+        let last = self.0[self.0.len() - 1];
+
+        if (first == b'"' || first == b'\'') && first == last {
             ByteStr::new(&self.0[1..self.0.len() - 1])
         } else {
             self
@@ -146,6 +151,14 @@ impl<'a, const N: usize> From<&'a [u8; N]> for &'a ByteStr {
     }
 }
 
+impl ToOwned for ByteStr {
+    type Owned = ByteString;
+
+    fn to_owned(&self) -> ByteString {
+        self.to_bytestring()
+    }
+}
+
 impl Deref for ByteStr {
     type Target = [u8];
 
@@ -1,3 +1,4 @@
+use std::borrow::Borrow;
 use std::ops::Deref;
 use std::ops::DerefMut;
 use std::str::from_utf8;
@@ -184,6 +185,12 @@ impl AsRef<ByteStr> for ByteString {
     }
 }
 
+impl Borrow<ByteStr> for ByteString {
+    fn borrow(&self) -> &ByteStr {
+        ByteStr::new(&self.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,37 @@
+use std::fmt::Display;
+
+/// The broad kind of problem that a diagnostic describes, so that tools can group and filter them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiagnosticCategory {
+    /// Code that PHP can't compile.
+    Syntax,
+    /// Names, members and variables that can't be resolved.
+    NameResolution,
+    /// Code that relies on something deprecated or removed in a newer version of PHP.
+    Deprecation,
+    /// Code that works, but doesn't follow convention.
+    Style,
+    /// Code that compiles, but is likely to fail or behave unexpectedly at runtime.
+    Correctness,
+    /// Code that lets untrusted input reach somewhere that it can do harm.
+    Security,
+}
+
+impl DiagnosticCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiagnosticCategory::Syntax => "syntax",
+            DiagnosticCategory::NameResolution => "name-resolution",
+            DiagnosticCategory::Deprecation => "deprecation",
+            DiagnosticCategory::Style => "style",
+            DiagnosticCategory::Correctness => "correctness",
+            DiagnosticCategory::Security => "security",
+        }
+    }
+}
+
+impl Display for DiagnosticCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
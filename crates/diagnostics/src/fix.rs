@@ -0,0 +1,62 @@
+use pxp_span::Span;
+
+/// A suggested change to the source that resolves a diagnostic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticFix {
+    /// A short description of the change, e.g. "insert a semicolon".
+    pub message: String,
+    pub edits: Vec<TextEdit>,
+}
+
+impl DiagnosticFix {
+    pub fn new(message: impl Into<String>, edits: Vec<TextEdit>) -> Self {
+        Self {
+            message: message.into(),
+            edits,
+        }
+    }
+
+    /// Apply the edits to the source that the diagnostic was reported for.
+    ///
+    /// Edits must not overlap, but can be given in any order.
+    pub fn apply(&self, source: &[u8]) -> Vec<u8> {
+        let mut edits = self.edits.iter().collect::<Vec<_>>();
+        edits.sort_by_key(|edit| edit.span.start);
+
+        let mut output = Vec::with_capacity(source.len());
+        let mut offset = 0;
+
+        for edit in edits {
+            output.extend_from_slice(&source[offset..edit.span.start]);
+            output.extend_from_slice(edit.replacement.as_bytes());
+            offset = edit.span.end;
+        }
+
+        output.extend_from_slice(&source[offset..]);
+        output
+    }
+}
+
+/// Replace the source covered by a span. An empty span inserts and an empty replacement deletes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub span: Span,
+    pub replacement: String,
+}
+
+impl TextEdit {
+    pub fn new(span: Span, replacement: impl Into<String>) -> Self {
+        Self {
+            span,
+            replacement: replacement.into(),
+        }
+    }
+
+    pub fn insert(offset: usize, text: impl Into<String>) -> Self {
+        Self::new(Span::flat(offset), text)
+    }
+
+    pub fn delete(span: Span) -> Self {
+        Self::new(span, "")
+    }
+}
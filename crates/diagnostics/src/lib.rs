@@ -1,12 +1,29 @@
+mod category;
+mod fix;
+#[cfg(feature = "sarif")]
+mod sarif;
 mod severity;
 
+pub use category::*;
+pub use fix::*;
 use pxp_span::Span;
+#[cfg(feature = "sarif")]
+pub use sarif::SarifReport;
 pub use severity::*;
 
 pub trait DiagnosticKind {
+    /// A short, stable code for the diagnostic, e.g. `P067`.
     fn get_code(&self) -> String;
+    /// A stable, human-readable identifier for the diagnostic, e.g. `parser.missing-semicolon`.
     fn get_identifier(&self) -> String;
     fn get_message(&self) -> String;
+    fn get_category(&self) -> DiagnosticCategory;
+    /// A mechanical change to the source that resolves the diagnostic reported at the given span.
+    fn get_fix(&self, span: Span) -> Option<DiagnosticFix> {
+        let _ = span;
+
+        None
+    }
     fn get_help(&self) -> Option<String> {
         None
     }
@@ -51,6 +68,9 @@ pub struct Diagnostic<K: DiagnosticKind> {
     pub kind: K,
     pub severity: Severity,
     pub span: Span,
+    /// Other locations that help explain the diagnostic, each with a short note, e.g. the opening
+    /// brace that a mismatched closing brace was expected to close.
+    pub related: Vec<(Span, String)>,
 }
 
 impl<K: DiagnosticKind> Diagnostic<K> {
@@ -59,6 +79,16 @@ impl<K: DiagnosticKind> Diagnostic<K> {
             kind,
             severity,
             span,
+            related: Vec::new(),
         }
     }
+
+    pub fn with_related(mut self, span: Span, message: impl Into<String>) -> Self {
+        self.related.push((span, message.into()));
+        self
+    }
+
+    pub fn get_fix(&self) -> Option<DiagnosticFix> {
+        self.kind.get_fix(self.span)
+    }
 }
@@ -0,0 +1,235 @@
+use std::{fmt::Debug, path::Path};
+
+use pxp_span::{LineIndex, Span};
+use serde::Serialize;
+
+use crate::{Diagnostic, DiagnosticKind, Severity};
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const VERSION: &str = "2.1.0";
+
+/// Collects diagnostics from any number of files into a single SARIF 2.1.0 run, the format read by
+/// GitHub code scanning, GitLab and most other CI integrations.
+#[derive(Debug, Clone)]
+pub struct SarifReport {
+    name: String,
+    version: String,
+    rules: Vec<SarifRule>,
+    results: Vec<SarifResult>,
+}
+
+impl SarifReport {
+    pub fn new(name: impl Into<String>, version: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            rules: Vec::new(),
+            results: Vec::new(),
+        }
+    }
+
+    /// Add the diagnostics reported for the file at `path`. The line index must have been built from
+    /// the same source that the diagnostic spans point into.
+    pub fn add<K: DiagnosticKind + Debug>(
+        &mut self,
+        path: &Path,
+        line_index: &LineIndex,
+        diagnostics: &[Diagnostic<K>],
+    ) -> &mut Self {
+        let uri = path.to_string_lossy().replace('\\', "/");
+
+        for diagnostic in diagnostics {
+            let rule_index = self.rule(&diagnostic.kind);
+
+            self.results.push(SarifResult {
+                rule_id: self.rules[rule_index].id.clone(),
+                rule_index,
+                level: level(diagnostic.severity),
+                message: SarifMessage {
+                    text: diagnostic.kind.get_message(),
+                },
+                locations: vec![location(&uri, line_index, diagnostic.span, None)],
+                related_locations: diagnostic
+                    .related
+                    .iter()
+                    .map(|(span, message)| location(&uri, line_index, *span, Some(message)))
+                    .collect(),
+            });
+        }
+
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    pub fn to_value(&self) -> serde_json::Value {
+        serde_json::to_value(self.log()).expect("a SARIF log is always valid JSON")
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.log()).expect("a SARIF log is always valid JSON")
+    }
+
+    fn log(&self) -> SarifLog<'_> {
+        SarifLog {
+            schema: SCHEMA,
+            version: VERSION,
+            runs: [SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: &self.name,
+                        version: &self.version,
+                        rules: &self.rules,
+                    },
+                },
+                column_kind: "unicodeCodePoints",
+                results: &self.results,
+            }],
+        }
+    }
+
+    /// Find the index of the rule for the given kind, registering it the first time it's seen.
+    fn rule<K: DiagnosticKind + Debug>(&mut self, kind: &K) -> usize {
+        let id = rule_id(kind);
+
+        if let Some(index) = self.rules.iter().position(|rule| rule.id == id) {
+            return index;
+        }
+
+        let name = kind.get_identifier();
+
+        self.rules.push(SarifRule {
+            id,
+            name: (!name.is_empty()).then_some(name),
+        });
+
+        self.rules.len() - 1
+    }
+}
+
+/// Kinds without a code fall back to the name of their enum variant.
+fn rule_id<K: DiagnosticKind + Debug>(kind: &K) -> String {
+    let code = kind.get_code();
+
+    if !code.is_empty() {
+        return code;
+    }
+
+    format!("{:?}", kind)
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect()
+}
+
+fn location(uri: &str, line_index: &LineIndex, span: Span, message: Option<&str>) -> SarifLocation {
+    let start = line_index.line_column(span.start);
+    let end = line_index.line_column(span.end);
+
+    SarifLocation {
+        physical_location: SarifPhysicalLocation {
+            artifact_location: SarifArtifactLocation {
+                uri: uri.to_string(),
+            },
+            region: SarifRegion {
+                start_line: start.line + 1,
+                start_column: start.column + 1,
+                end_line: end.line + 1,
+                end_column: end.column + 1,
+            },
+        },
+        message: message.map(|text| SarifMessage {
+            text: text.to_string(),
+        }),
+    }
+}
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Information | Severity::Hint => "note",
+    }
+}
+
+#[derive(Serialize)]
+struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [SarifRun<'a>; 1],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRun<'a> {
+    tool: SarifTool<'a>,
+    column_kind: &'static str,
+    results: &'a [SarifResult],
+}
+
+#[derive(Serialize)]
+struct SarifTool<'a> {
+    driver: SarifDriver<'a>,
+}
+
+#[derive(Serialize)]
+struct SarifDriver<'a> {
+    name: &'a str,
+    version: &'a str,
+    rules: &'a [SarifRule],
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SarifRule {
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    rule_index: usize,
+    level: &'static str,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related_locations: Vec<SarifLocation>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<SarifMessage>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    region: SarifRegion,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
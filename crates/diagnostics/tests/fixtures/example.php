@@ -0,0 +1,7 @@
+<?php
+
+use App\Unused;
+
+function greet(string $name): string {
+    return "Hello, {$nmae}!";
+}
@@ -0,0 +1,155 @@
+use std::path::Path;
+
+use pxp_diagnostics::{Diagnostic, DiagnosticCategory, DiagnosticKind, SarifReport, Severity};
+use pxp_span::{LineIndex, Span};
+
+#[derive(Debug)]
+enum ExampleDiagnostic {
+    UndefinedVariable { name: String },
+    UnusedImport,
+}
+
+impl DiagnosticKind for ExampleDiagnostic {
+    fn get_code(&self) -> String {
+        match self {
+            ExampleDiagnostic::UndefinedVariable { .. } => "E001".to_string(),
+            ExampleDiagnostic::UnusedImport => String::new(),
+        }
+    }
+
+    fn get_identifier(&self) -> String {
+        match self {
+            ExampleDiagnostic::UndefinedVariable { .. } => "example.undefined-variable".to_string(),
+            ExampleDiagnostic::UnusedImport => String::new(),
+        }
+    }
+
+    fn get_message(&self) -> String {
+        match self {
+            ExampleDiagnostic::UndefinedVariable { name } => {
+                format!("Undefined variable ${}", name)
+            }
+            ExampleDiagnostic::UnusedImport => "Unused import".to_string(),
+        }
+    }
+
+    fn get_category(&self) -> DiagnosticCategory {
+        DiagnosticCategory::Correctness
+    }
+}
+
+fn span_of(source: &[u8], needle: &str) -> Span {
+    let start = source
+        .windows(needle.len())
+        .position(|window| window == needle.as_bytes())
+        .unwrap();
+
+    Span::new(start, start + needle.len())
+}
+
+fn example() -> (Vec<u8>, Vec<Diagnostic<ExampleDiagnostic>>) {
+    let source = std::fs::read("tests/fixtures/example.php").unwrap();
+    let diagnostics = vec![
+        Diagnostic::new(
+            ExampleDiagnostic::UnusedImport,
+            Severity::Hint,
+            span_of(&source, "App\\Unused"),
+        ),
+        Diagnostic::new(
+            ExampleDiagnostic::UndefinedVariable {
+                name: "nmae".to_string(),
+            },
+            Severity::Error,
+            span_of(&source, "$nmae"),
+        ),
+    ];
+
+    (source, diagnostics)
+}
+
+/// The tool version changes with every release, so it's replaced before comparing.
+fn normalise(report: &SarifReport) -> serde_json::Value {
+    let mut value = report.to_value();
+    value["runs"][0]["tool"]["driver"]["version"] = "[version]".into();
+    value
+}
+
+#[test]
+fn it_produces_sarif_for_diagnostics() {
+    let (source, diagnostics) = example();
+    let mut report = SarifReport::new("pxp", env!("CARGO_PKG_VERSION"));
+    report.add(
+        Path::new("tests/fixtures/example.php"),
+        &LineIndex::new(&source),
+        &diagnostics,
+    );
+
+    let expected = std::fs::read_to_string("tests/fixtures/example.sarif.json").unwrap();
+    let expected: serde_json::Value = serde_json::from_str(&expected).unwrap();
+
+    assert_eq!(normalise(&report), expected);
+}
+
+#[test]
+fn it_aggregates_multiple_files_into_one_run() {
+    let (source, diagnostics) = example();
+    let line_index = LineIndex::new(&source);
+    let mut report = SarifReport::new("pxp", env!("CARGO_PKG_VERSION"));
+    report
+        .add(Path::new("src/first.php"), &line_index, &diagnostics)
+        .add(Path::new("src\\second.php"), &line_index, &diagnostics);
+
+    let value = report.to_value();
+    let run = &value["runs"][0];
+
+    assert_eq!(value["runs"].as_array().unwrap().len(), 1);
+    assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), 2);
+
+    let uris = run["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|result| {
+            result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"]
+                .as_str()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        uris,
+        vec![
+            "src/first.php",
+            "src/first.php",
+            "src/second.php",
+            "src/second.php"
+        ]
+    );
+}
+
+#[test]
+fn it_includes_related_locations() {
+    let (source, mut diagnostics) = example();
+    let diagnostic = diagnostics.pop().unwrap();
+    diagnostics.push(diagnostic.with_related(span_of(&source, "$name"), "did you mean $name?"));
+
+    let mut report = SarifReport::new("pxp", env!("CARGO_PKG_VERSION"));
+    report.add(
+        Path::new("example.php"),
+        &LineIndex::new(&source),
+        &diagnostics,
+    );
+
+    let value = report.to_value();
+    let results = &value["runs"][0]["results"];
+
+    assert!(results[0].get("relatedLocations").is_none());
+    assert_eq!(
+        results[1]["relatedLocations"][0]["message"]["text"],
+        "did you mean $name?"
+    );
+    assert_eq!(
+        results[1]["relatedLocations"][0]["physicalLocation"]["region"]["startLine"],
+        5
+    );
+}
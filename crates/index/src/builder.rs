@@ -0,0 +1,74 @@
+use std::{path::Path, sync::Arc};
+
+use pxp_ast::{visitor::Visitor, Statement};
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+
+use crate::{
+    entities::EntityRegistry,
+    file::{hash_contents, FileRegistry},
+    indexer::IndexingVisitor,
+    FileId, Index,
+};
+
+/// Collects the declarations in a set of files, before they're frozen into an [`Index`].
+///
+/// ```
+/// use pxp_index::{FileId, IndexBuilder};
+/// use pxp_lexer::Lexer;
+/// use pxp_parser::Parser;
+///
+/// let result = Parser::parse(Lexer::new("<?php function a() {}"));
+///
+/// let mut builder = IndexBuilder::new();
+/// builder.index(FileId::new(0), &result.ast);
+///
+/// let index = builder.build();
+///
+/// assert!(index.get_function("a").is_some());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct IndexBuilder {
+    pub(crate) files: FileRegistry,
+    pub(crate) entities: EntityRegistry,
+}
+
+impl IndexBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn index_file(&mut self, path: &Path) {
+        index_file(&mut self.files, &mut self.entities, path);
+    }
+
+    pub fn index(&mut self, file_id: FileId, ast: &[Statement]) {
+        self.files.forget_hash(file_id);
+
+        index_ast(&mut self.entities, file_id, ast);
+    }
+
+    pub fn build(self) -> Index {
+        Index {
+            files: Arc::new(self.files),
+            entities: Arc::new(self.entities),
+        }
+    }
+}
+
+pub(crate) fn index_file(files: &mut FileRegistry, entities: &mut EntityRegistry, path: &Path) {
+    let file_id = files.get_or_insert(path);
+    let contents = std::fs::read(path).unwrap();
+    let parse_result = Parser::parse(Lexer::new(&contents));
+
+    index_ast(entities, file_id, &parse_result.ast);
+    files.set_hash(file_id, hash_contents(&contents));
+}
+
+pub(crate) fn index_ast(entities: &mut EntityRegistry, file_id: FileId, ast: &[Statement]) {
+    // Re-indexing a file should replace everything it previously declared.
+    entities.remove_file(file_id);
+
+    let mut visitor = IndexingVisitor::new(file_id, entities);
+    visitor.visit(ast);
+}
@@ -0,0 +1,68 @@
+use std::fmt::Display;
+
+use pxp_ast::ResolvedName;
+use pxp_bytestring::ByteString;
+use pxp_diagnostics::{DiagnosticCategory, DiagnosticKind};
+use pxp_type::Type;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndexDiagnostic {
+    IncompatibleDocblockParameterType {
+        parameter: ByteString,
+        native: Type<ResolvedName>,
+        docblock: Type<ResolvedName>,
+    },
+    IncompatibleDocblockReturnType {
+        native: Type<ResolvedName>,
+        docblock: Type<ResolvedName>,
+    },
+}
+
+impl DiagnosticKind for IndexDiagnostic {
+    fn get_code(&self) -> String {
+        match self {
+            IndexDiagnostic::IncompatibleDocblockParameterType { .. } => "I001",
+            IndexDiagnostic::IncompatibleDocblockReturnType { .. } => "I002",
+        }
+        .to_string()
+    }
+
+    fn get_identifier(&self) -> String {
+        match self {
+            IndexDiagnostic::IncompatibleDocblockParameterType { .. } => {
+                "index.incompatible-docblock-parameter-type"
+            }
+            IndexDiagnostic::IncompatibleDocblockReturnType { .. } => {
+                "index.incompatible-docblock-return-type"
+            }
+        }
+        .to_string()
+    }
+
+    fn get_message(&self) -> String {
+        match self {
+            IndexDiagnostic::IncompatibleDocblockParameterType {
+                parameter,
+                native,
+                docblock,
+            } => format!(
+                "docblock type {} for parameter ${} is not compatible with native type {}",
+                docblock, parameter, native
+            ),
+            IndexDiagnostic::IncompatibleDocblockReturnType { native, docblock } => format!(
+                "docblock return type {} is not compatible with native return type {}",
+                docblock, native
+            ),
+        }
+    }
+
+    fn get_category(&self) -> DiagnosticCategory {
+        DiagnosticCategory::Correctness
+    }
+}
+
+impl Display for IndexDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.get_message())
+    }
+}
@@ -1,14 +1,91 @@
-use pxp_ast::ResolvedName;
+use pxp_ast::{ResolvedName, SimpleIdentifier, SimpleVariable, Visibility};
+use pxp_bytestring::ByteString;
+use pxp_type::Type;
 
-use crate::{location::Location, HasFileId};
+use crate::{location::Location, signature::SignatureCache, HasFileId};
 
-use super::MethodEntity;
+use super::{MethodEntity, Parameters};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ClassEntity {
     pub(crate) name: ResolvedName,
     pub(crate) kind: ClassEntityKind,
+    pub(crate) r#abstract: bool,
+    pub(crate) r#final: bool,
+    pub(crate) readonly: bool,
+    pub(crate) parent: Option<ResolvedName>,
+    pub(crate) interfaces: Vec<ResolvedName>,
+    pub(crate) traits: Vec<ResolvedName>,
     pub(crate) methods: Vec<MethodEntity>,
+    pub(crate) properties: Vec<PropertyEntity>,
+    pub(crate) constants: Vec<ClassConstantEntity>,
+    pub(crate) cases: Vec<EnumCaseEntity>,
+    /// The type of the values of a backed enum's cases.
+    pub(crate) backed_type: Option<Type<ResolvedName>>,
+    pub(crate) virtual_methods: Vec<VirtualMethodEntity>,
+    pub(crate) virtual_properties: Vec<VirtualPropertyEntity>,
+    /// The methods that PHP declares implicitly, like `cases()` on enums.
+    pub(crate) implicit_methods: Vec<MethodEntity>,
+    /// The properties that PHP declares implicitly, like `name` on enums.
+    pub(crate) implicit_properties: Vec<PropertyEntity>,
+    /// The message of the `@deprecated` tag or `#[\Deprecated]` attribute, which may be empty.
+    pub(crate) deprecated: Option<ByteString>,
+    pub(crate) location: Location,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyEntity {
+    pub(crate) name: SimpleVariable,
+    pub(crate) r#type: Option<Type<ResolvedName>>,
+    pub(crate) r#static: bool,
+    pub(crate) readonly: bool,
+    pub(crate) visibility: Visibility,
+    /// A fingerprint of the default value, used to compare properties without keeping the expression.
+    pub(crate) default: Option<u64>,
+    /// The message of the `@deprecated` tag or `#[\Deprecated]` attribute, which may be empty.
+    pub(crate) deprecated: Option<ByteString>,
+    pub(crate) location: Location,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassConstantEntity {
+    pub(crate) name: SimpleIdentifier,
+    pub(crate) r#type: Option<Type<ResolvedName>>,
+    /// The type of the constant's value, when the value is a literal.
+    pub(crate) value_type: Option<Type<ResolvedName>>,
+    pub(crate) r#final: bool,
+    pub(crate) visibility: Visibility,
+    /// The message of the `@deprecated` tag or `#[\Deprecated]` attribute, which may be empty.
+    pub(crate) deprecated: Option<ByteString>,
+    pub(crate) location: Location,
+}
+
+/// A method declared with a `@method` tag in the class-like's docblock.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VirtualMethodEntity {
+    pub(crate) name: SimpleIdentifier,
+    pub(crate) parameters: Parameters,
+    pub(crate) return_type: Option<Type<ResolvedName>>,
+    pub(crate) r#static: bool,
+    pub(crate) location: Location,
+    pub(crate) signature: SignatureCache,
+}
+
+/// A property declared with a `@property` tag in the class-like's docblock.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VirtualPropertyEntity {
+    pub(crate) name: SimpleVariable,
+    pub(crate) r#type: Option<Type<ResolvedName>>,
+    /// Whether the property can be read, which `@property-write` tags prevent.
+    pub(crate) readable: bool,
+    /// Whether the property can be written to, which `@property-read` tags prevent.
+    pub(crate) writable: bool,
+    pub(crate) location: Location,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumCaseEntity {
+    pub(crate) name: SimpleIdentifier,
     pub(crate) location: Location,
 }
 
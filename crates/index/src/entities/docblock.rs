@@ -0,0 +1,10 @@
+use pxp_ast::ResolvedName;
+use pxp_span::Span;
+use pxp_type::Type;
+
+/// A type taken from a docblock tag, along with the span of the tag it came from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocblockType {
+    pub(crate) r#type: Type<ResolvedName>,
+    pub(crate) span: Span,
+}
@@ -1,17 +1,27 @@
-use pxp_ast::{Name, ResolvedName};
+use pxp_ast::ResolvedName;
+use pxp_bytestring::ByteString;
 use pxp_type::Type;
 
-use crate::{location::Location, FileId, HasFileId};
+use crate::{location::Location, signature::SignatureCache, FileId, HasFileId};
 
-use super::parameters::Parameters;
+use super::{parameters::Parameters, DocblockType};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct FunctionEntity {
     pub(crate) name: ResolvedName,
     pub(crate) parameters: Parameters,
     pub(crate) return_type: Option<Type<ResolvedName>>,
+    pub(crate) docblock_return_type: Option<DocblockType>,
     pub(crate) returns_reference: bool,
+    /// The message of the `@deprecated` tag or `#[\Deprecated]` attribute, which may be empty.
+    pub(crate) deprecated: Option<ByteString>,
+    /// The exceptions listed in `@throws` tags.
+    pub(crate) throws: Vec<ResolvedName>,
+    /// Whether the body reads its arguments with `func_get_args()`, `func_num_args()` or
+    /// `func_get_arg()`, so it can be called with more arguments than it declares.
+    pub(crate) reads_arguments: bool,
     pub(crate) location: Location,
+    pub(crate) signature: SignatureCache,
 }
 
 impl HasFileId for FunctionEntity {
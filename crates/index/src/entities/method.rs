@@ -1,18 +1,28 @@
-use pxp_ast::{MethodModifierGroup, Name, ResolvedName, SimpleIdentifier};
+use pxp_ast::{MethodModifierGroup, ResolvedName, SimpleIdentifier};
+use pxp_bytestring::ByteString;
 use pxp_type::Type;
 
-use crate::{location::Location, HasFileId};
+use crate::{location::Location, signature::SignatureCache, HasFileId};
 
-use super::Parameters;
+use super::{DocblockType, Parameters};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct MethodEntity {
     pub(crate) name: SimpleIdentifier,
     pub(crate) parameters: Parameters,
     pub(crate) return_type: Option<Type<ResolvedName>>,
+    pub(crate) docblock_return_type: Option<DocblockType>,
     pub(crate) returns_reference: bool,
     pub(crate) modifiers: MethodModifierGroup,
+    /// The message of the `@deprecated` tag or `#[\Deprecated]` attribute, which may be empty.
+    pub(crate) deprecated: Option<ByteString>,
+    /// The exceptions listed in `@throws` tags.
+    pub(crate) throws: Vec<ResolvedName>,
+    /// Whether the body reads its arguments with `func_get_args()`, `func_num_args()` or
+    /// `func_get_arg()`, so it can be called with more arguments than it declares.
+    pub(crate) reads_arguments: bool,
     pub(crate) location: Location,
+    pub(crate) signature: SignatureCache,
 }
 
 impl HasFileId for MethodEntity {
@@ -1,46 +1,169 @@
 mod class;
+mod docblock;
 mod function;
 mod method;
 mod parameters;
 
-pub use class::{ClassEntity, ClassEntityKind};
+use std::{collections::HashMap, sync::Arc};
+
+pub use class::{
+    ClassConstantEntity, ClassEntity, ClassEntityKind, EnumCaseEntity, PropertyEntity,
+    VirtualMethodEntity, VirtualPropertyEntity,
+};
+pub use docblock::DocblockType;
 pub use function::FunctionEntity;
 pub use method::MethodEntity;
 pub use parameters::{Parameter, Parameters};
 use pxp_bytestring::ByteString;
 
-#[derive(Debug, Clone, Default)]
+use crate::{FileId, HasFileId};
+
+/// The entities are behind an `Arc`, so that cloning the registry to update a single file only
+/// copies the lookup tables and not the declarations of every other file.
+#[derive(Debug, Clone, Default, PartialEq)]
 pub(crate) struct EntityRegistry {
-    functions: Vec<FunctionEntity>,
-    classes: Vec<ClassEntity>,
+    functions: Vec<Arc<FunctionEntity>>,
+    classes: Vec<Arc<ClassEntity>>,
+
+    /// The symbols declared by each file, used to clean up when a file is re-indexed.
+    symbols: HashMap<FileId, FileSymbols>,
+
+    /// Interface -> class-likes that directly implement (or extend) it.
+    implementors: HashMap<ByteString, Vec<ByteString>>,
+    /// Class -> classes that directly extend it.
+    subclasses: HashMap<ByteString, Vec<ByteString>>,
+    /// Trait -> class-likes that directly use it.
+    trait_users: HashMap<ByteString, Vec<ByteString>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct FileSymbols {
+    functions: Vec<ByteString>,
+    classes: Vec<ByteString>,
 }
 
 impl EntityRegistry {
     pub fn add_function(&mut self, function: FunctionEntity) {
-        self.functions.push(function);
+        self.symbols
+            .entry(function.file_id())
+            .or_default()
+            .functions
+            .push(function.name.resolved.clone());
+
+        self.functions.push(Arc::new(function));
     }
 
-    pub fn functions(&self) -> &[FunctionEntity] {
+    pub fn functions(&self) -> &[Arc<FunctionEntity>] {
         &self.functions
     }
 
     pub fn get_function(&self, name: impl Into<ByteString>) -> Option<&FunctionEntity> {
         let name = name.into();
 
-        self.functions.iter().find(|f| f.name.resolved == name)
+        self.functions
+            .iter()
+            .find(|f| f.name.resolved == name)
+            .map(Arc::as_ref)
     }
 
     pub fn add_class(&mut self, class: ClassEntity) {
-        self.classes.push(class);
+        let name = &class.name.resolved;
+
+        if let Some(parent) = &class.parent {
+            link(&mut self.subclasses, &parent.resolved, name);
+        }
+
+        for interface in class.interfaces.iter() {
+            link(&mut self.implementors, &interface.resolved, name);
+        }
+
+        for r#trait in class.traits.iter() {
+            link(&mut self.trait_users, &r#trait.resolved, name);
+        }
+
+        self.symbols
+            .entry(class.file_id())
+            .or_default()
+            .classes
+            .push(name.clone());
+
+        self.classes.push(Arc::new(class));
     }
 
-    pub fn classes(&self) -> &[ClassEntity] {
+    pub fn classes(&self) -> &[Arc<ClassEntity>] {
         &self.classes
     }
 
     pub fn get_class(&self, name: impl Into<ByteString>) -> Option<&ClassEntity> {
         let name = name.into();
 
-        self.classes.iter().find(|c| c.name.resolved == name)
+        self.classes
+            .iter()
+            .find(|c| c.name.resolved == name)
+            .map(Arc::as_ref)
+    }
+
+    pub fn get_implementors(&self, name: &ByteString) -> &[ByteString] {
+        self.implementors.get(name).map_or(&[], |names| names)
+    }
+
+    pub fn get_subclasses(&self, name: &ByteString) -> &[ByteString] {
+        self.subclasses.get(name).map_or(&[], |names| names)
+    }
+
+    pub fn get_trait_users(&self, name: &ByteString) -> &[ByteString] {
+        self.trait_users.get(name).map_or(&[], |names| names)
+    }
+
+    /// Remove every entity declared in the given file, along with any reverse edges they own.
+    pub fn remove_file(&mut self, file: FileId) {
+        let Some(symbols) = self.symbols.remove(&file) else {
+            return;
+        };
+
+        if !symbols.functions.is_empty() {
+            self.functions.retain(|f| f.file_id() != file);
+        }
+
+        if symbols.classes.is_empty() {
+            return;
+        }
+
+        for class in self.classes.iter().filter(|c| c.file_id() == file) {
+            let name = &class.name.resolved;
+
+            if let Some(parent) = &class.parent {
+                unlink(&mut self.subclasses, &parent.resolved, name);
+            }
+
+            for interface in class.interfaces.iter() {
+                unlink(&mut self.implementors, &interface.resolved, name);
+            }
+
+            for r#trait in class.traits.iter() {
+                unlink(&mut self.trait_users, &r#trait.resolved, name);
+            }
+        }
+
+        self.classes.retain(|c| c.file_id() != file);
+    }
+}
+
+fn link(edges: &mut HashMap<ByteString, Vec<ByteString>>, from: &ByteString, to: &ByteString) {
+    edges.entry(from.clone()).or_default().push(to.clone());
+}
+
+fn unlink(edges: &mut HashMap<ByteString, Vec<ByteString>>, from: &ByteString, to: &ByteString) {
+    let Some(names) = edges.get_mut(from) else {
+        return;
+    };
+
+    // The same name can be declared in more than one file, so we only remove a single edge.
+    if let Some(position) = names.iter().position(|name| name == to) {
+        names.remove(position);
+    }
+
+    if names.is_empty() {
+        edges.remove(from);
     }
 }
@@ -1,8 +1,11 @@
-use pxp_ast::{Name, ResolvedName, SimpleVariable};
+use pxp_ast::{ResolvedName, SimpleVariable};
+use pxp_bytestring::ByteString;
 use pxp_type::Type;
 
 use crate::location::Location;
 
+use super::DocblockType;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Parameters {
     parameters: Vec<Parameter>,
@@ -30,7 +33,10 @@ impl Parameters {
 pub struct Parameter {
     pub(crate) name: SimpleVariable,
     pub(crate) r#type: Option<Type<ResolvedName>>,
+    pub(crate) docblock_type: Option<DocblockType>,
     pub(crate) optional: bool,
     pub(crate) variadic: bool,
+    pub(crate) by_reference: bool,
+    pub(crate) attributes: Vec<ByteString>,
     pub(crate) location: Location,
 }
@@ -3,13 +3,19 @@ use std::{
     path::{Path, PathBuf},
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+use pxp_ast::Fingerprinter;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct FileId(usize);
 
 impl FileId {
     pub fn new(id: usize) -> Self {
         Self(id)
     }
+
+    pub(crate) fn to_usize(self) -> usize {
+        self.0
+    }
 }
 
 pub trait HasFileId {
@@ -22,9 +28,13 @@ impl HasFileId for FileId {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub(crate) struct FileRegistry {
     files: HashMap<PathBuf, FileId>,
+
+    /// The hash of each file's contents when it was last indexed from disk, used to tell whether
+    /// a persisted index is still up to date.
+    hashes: HashMap<FileId, u64>,
 }
 
 impl FileRegistry {
@@ -54,7 +64,34 @@ impl FileRegistry {
         }
     }
 
+    pub fn insert(&mut self, path: PathBuf, id: FileId) {
+        self.files.insert(path, id);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Path, FileId)> {
+        self.files.iter().map(|(path, &id)| (path.as_path(), id))
+    }
+
+    pub fn get_hash(&self, id: FileId) -> Option<u64> {
+        self.hashes.get(&id).copied()
+    }
+
+    pub fn set_hash(&mut self, id: FileId, hash: u64) {
+        self.hashes.insert(id, hash);
+    }
+
+    pub fn forget_hash(&mut self, id: FileId) {
+        self.hashes.remove(&id);
+    }
+
     pub fn len(&self) -> usize {
         self.files.len()
     }
 }
+
+/// Hash the contents of a file, so that changes can be detected without re-indexing it.
+pub(crate) fn hash_contents(contents: &[u8]) -> u64 {
+    let mut fingerprinter = Fingerprinter::new();
+    fingerprinter.write(contents);
+    fingerprinter.finish()
+}
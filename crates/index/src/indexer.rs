@@ -1,26 +1,69 @@
 use pxp_ast::{
-    visitor::Visitor, ClassStatement, ClassishMember, FunctionParameterList, FunctionStatement,
-    Method, MethodParameterList, ResolvedName, ReturnType,
+    literals::IntegerValue,
+    visitor::{walk_function_call_expression, walk_statement, Visitor},
+    AnonymousClassExpression, Argument, ArrowFunctionExpression, BackedEnumMember,
+    BackedEnumStatement, BackedEnumType, ClassStatement, ClassishMember, ClosureExpression,
+    CommentGroup, DataType, Expression, ExpressionKind, Fingerprint, Fingerprinter,
+    FunctionCallExpression, FunctionParameterList, FunctionStatement, HasAttributes,
+    InterfaceStatement, LiteralKind, Method, MethodBodyKind, MethodModifier, MethodModifierGroup,
+    MethodParameterList, Name, Property, PropertyEntryKind, ResolvedName, ReturnType,
+    SimpleIdentifier, SimpleVariable, Statement, StatementKind, TraitStatement, UnitEnumMember,
+    UnitEnumStatement, Visibility,
 };
-use pxp_type::Type;
+use pxp_bytestring::ByteString;
+use pxp_span::Span;
+use pxp_token::TokenKind;
+use pxp_type::{GenericTypeArgument, Type};
 
 use crate::{
-    entities::{ClassEntity, ClassEntityKind, FunctionEntity, MethodEntity, Parameter, Parameters},
+    entities::{
+        ClassConstantEntity, ClassEntity, ClassEntityKind, DocblockType, EntityRegistry,
+        EnumCaseEntity, FunctionEntity, MethodEntity, Parameter, Parameters, PropertyEntity,
+        VirtualMethodEntity, VirtualPropertyEntity,
+    },
     location::Location,
-    FileId, Index,
+    signature::SignatureCache,
+    FileId,
 };
 
+#[derive(Default)]
+struct ClassishMembers {
+    methods: Vec<MethodEntity>,
+    traits: Vec<ResolvedName>,
+    properties: Vec<PropertyEntity>,
+    constants: Vec<ClassConstantEntity>,
+}
+
+#[derive(Default)]
+struct VirtualMembers {
+    methods: Vec<VirtualMethodEntity>,
+    properties: Vec<VirtualPropertyEntity>,
+}
+
 pub struct IndexingVisitor<'a> {
     file_id: FileId,
-    index: &'a mut Index,
+    entities: &'a mut EntityRegistry,
+    // The members declared by the docblock of the statement being visited, since docblocks
+    // are attached to the statement rather than the class-like itself.
+    virtual_members: VirtualMembers,
+    deprecated: Option<ByteString>,
 }
 
 impl<'a> IndexingVisitor<'a> {
-    pub fn new(file_id: FileId, index: &'a mut Index) -> Self {
-        Self { file_id, index }
+    pub fn new(file_id: FileId, entities: &'a mut EntityRegistry) -> Self {
+        Self {
+            file_id,
+            entities,
+            virtual_members: VirtualMembers::default(),
+            deprecated: None,
+        }
     }
 
-    fn transform_function_parameter_list(&self, node: &FunctionParameterList) -> Parameters {
+    fn transform_function_parameter_list(
+        &self,
+        node: &FunctionParameterList,
+        comments: &CommentGroup,
+    ) -> Parameters {
         let mut parameters = Vec::new();
 
         for parameter in node.parameters.iter() {
@@ -30,8 +73,14 @@ impl<'a> IndexingVisitor<'a> {
                     .data_type
                     .as_ref()
                     .map(|data_type| data_type.get_type().clone()),
+                docblock_type: self.transform_docblock_parameter_type(comments, &parameter.name),
                 optional: parameter.default.is_some(),
                 variadic: parameter.ellipsis.is_some(),
+                by_reference: parameter.ampersand.is_some(),
+                attributes: parameter
+                    .attributes()
+                    .map(|attribute| attribute.to_bytestring())
+                    .collect(),
                 location: Location::new(self.file_id, parameter.span),
             })
         }
@@ -39,7 +88,11 @@ impl<'a> IndexingVisitor<'a> {
         Parameters::new(parameters)
     }
 
-    fn transform_method_parameter_list(&self, node: &MethodParameterList) -> Parameters {
+    fn transform_method_parameter_list(
+        &self,
+        node: &MethodParameterList,
+        comments: &CommentGroup,
+    ) -> Parameters {
         let mut parameters = Vec::new();
 
         for parameter in node.parameters.iter() {
@@ -49,8 +102,14 @@ impl<'a> IndexingVisitor<'a> {
                     .data_type
                     .as_ref()
                     .map(|data_type| data_type.get_type().clone()),
+                docblock_type: self.transform_docblock_parameter_type(comments, &parameter.name),
                 optional: parameter.default.is_some(),
                 variadic: parameter.ellipsis.is_some(),
+                by_reference: parameter.ampersand.is_some(),
+                attributes: parameter
+                    .attributes()
+                    .map(|attribute| attribute.to_bytestring())
+                    .collect(),
                 location: Location::new(self.file_id, parameter.span),
             })
         }
@@ -62,50 +121,763 @@ impl<'a> IndexingVisitor<'a> {
         node.map(|return_type| return_type.data_type.get_type().clone())
     }
 
+    /// Names in docblock types have already been resolved against the file's imports by the parser.
+    fn transform_docblock_type(&self, data_type: &DataType) -> Option<DocblockType> {
+        Some(data_type.get_type())
+            .filter(|ty| !ty.is_missing())
+            .map(|ty| DocblockType {
+                r#type: ty.clone(),
+                span: data_type.span,
+            })
+    }
+
+    fn transform_docblock_parameter_type(
+        &self,
+        comments: &CommentGroup,
+        name: &SimpleVariable,
+    ) -> Option<DocblockType> {
+        comments
+            .docblock()?
+            .tags()
+            .get_param_tags()
+            .into_iter()
+            .filter(|tag| {
+                tag.variable
+                    .as_ref()
+                    .is_some_and(|variable| variable.symbol == name.symbol)
+            })
+            .find_map(|tag| self.transform_docblock_type(tag.data_type.as_ref()?))
+    }
+
+    fn transform_docblock_return_type(&self, comments: &CommentGroup) -> Option<DocblockType> {
+        comments
+            .docblock()?
+            .tags()
+            .get_return_tags()
+            .into_iter()
+            .find_map(|tag| self.transform_docblock_type(tag.data_type.as_ref()?))
+    }
+
+    /// Each `@throws` tag can list several exceptions, e.g. `@throws FooException|BarException`.
+    fn transform_throws(&self, comments: &CommentGroup) -> Vec<ResolvedName> {
+        let Some(docblock) = comments.docblock() else {
+            return Vec::new();
+        };
+
+        let mut throws = Vec::new();
+
+        for tag in docblock.tags().get_throws_tags() {
+            let Some(data_type) = &tag.data_type else {
+                continue;
+            };
+
+            match data_type.get_type() {
+                Type::Named(name) => throws.push(name.clone()),
+                Type::Union(types) => throws.extend(types.iter().filter_map(|ty| match ty {
+                    Type::Named(name) => Some(name.clone()),
+                    _ => None,
+                })),
+                _ => {}
+            }
+        }
+
+        throws
+    }
+
+    /// Symbols can be deprecated with a `@deprecated` tag or, since PHP 8.4, a `#[\Deprecated]`
+    /// attribute. The docblock is preferred since its message is usually more descriptive.
+    fn transform_deprecation(
+        &self,
+        comments: &CommentGroup,
+        node: &impl HasAttributes,
+    ) -> Option<ByteString> {
+        self.transform_docblock_deprecation(comments)
+            .or_else(|| self.transform_deprecated_attribute(node))
+    }
+
+    fn transform_docblock_deprecation(&self, comments: &CommentGroup) -> Option<ByteString> {
+        comments
+            .docblock()?
+            .tags()
+            .get_deprecated_tags()
+            .first()
+            .map(|tag| {
+                // The text of a single-line docblock includes the whitespace before the `*/`.
+                let text: &[u8] = tag.text.as_ref().map_or(&[], |text| text.as_ref());
+                let end = text
+                    .iter()
+                    .rposition(|byte| !byte.is_ascii_whitespace())
+                    .map_or(0, |position| position + 1);
+
+                ByteString::from(&text[..end])
+            })
+    }
+
+    /// The message is taken from the `message` and `since` arguments of the attribute, which
+    /// are ignored unless they're string literals.
+    fn transform_deprecated_attribute(&self, node: &impl HasAttributes) -> Option<ByteString> {
+        let attribute = node.get_attribute(b"Deprecated".into())?;
+
+        let mut message = None;
+        let mut since = None;
+
+        let arguments = attribute
+            .arguments
+            .iter()
+            .flat_map(|arguments| arguments.arguments.iter());
+
+        for (position, argument) in arguments.enumerate() {
+            let (name, value) = match argument {
+                Argument::Positional(argument) => match position {
+                    0 => (b"message".as_slice(), &argument.value),
+                    _ => (b"since".as_slice(), &argument.value),
+                },
+                Argument::Named(argument) => (argument.name.symbol.as_bytes(), &argument.value),
+            };
+
+            let ExpressionKind::Literal(literal) = &value.kind else {
+                continue;
+            };
+
+            let Some(value) = literal.string_value() else {
+                continue;
+            };
+
+            match name {
+                b"message" => message = Some(value.value().to_bytestring()),
+                b"since" => since = Some(value.value().to_bytestring()),
+                _ => {}
+            }
+        }
+
+        Some(match (message, since) {
+            (Some(message), Some(since)) => format!("{} (since {})", message, since).into(),
+            (Some(message), None) => message,
+            (None, Some(since)) => format!("since {}", since).into(),
+            (None, None) => ByteString::default(),
+        })
+    }
+
     fn transform_method(&self, node: &Method) -> MethodEntity {
         MethodEntity {
             name: node.name.clone(),
-            parameters: self.transform_method_parameter_list(&node.parameters),
+            parameters: self.transform_method_parameter_list(&node.parameters, &node.comments),
             return_type: self.transform_return_type(node.return_type.as_ref()),
+            docblock_return_type: self.transform_docblock_return_type(&node.comments),
             returns_reference: node.ampersand.is_some(),
             modifiers: node.modifiers.clone(),
+            deprecated: self.transform_deprecation(&node.comments, node),
+            throws: self.transform_throws(&node.comments),
+            reads_arguments: match &node.body.kind {
+                MethodBodyKind::Concrete(body) => reads_arguments(&body.statements),
+                MethodBodyKind::Abstract(_) | MethodBodyKind::Missing(_) => false,
+            },
             location: Location::new(self.file_id, node.span),
+            signature: SignatureCache::default(),
         }
     }
 
-    fn transform_classish_members(&self, nodes: &[ClassishMember]) -> (Vec<MethodEntity>, ()) {
-        let mut methods = Vec::new();
+    fn transform_classish_members<'b>(
+        &self,
+        nodes: impl IntoIterator<Item = &'b ClassishMember>,
+    ) -> ClassishMembers {
+        let mut members = ClassishMembers::default();
 
-        for member in nodes.iter() {
+        for member in nodes {
             match member {
-                ClassishMember::Method(method) => methods.push(self.transform_method(method)),
+                ClassishMember::Method(method) => {
+                    if method.name.symbol.eq_ignore_ascii_case(b"__construct") {
+                        members
+                            .properties
+                            .extend(self.transform_promoted_properties(&method.parameters));
+                    }
+
+                    members.methods.push(self.transform_method(method))
+                }
+                ClassishMember::TraitUsage(usage) => {
+                    members.traits.extend(self.transform_names(&usage.traits))
+                }
+                ClassishMember::Property(Property::Simple(property)) => {
+                    members
+                        .properties
+                        .extend(property.entries.iter().map(|entry| {
+                            PropertyEntity {
+                                name: entry.kind.variable().clone(),
+                                r#type: property
+                                    .r#type
+                                    .as_ref()
+                                    .map(|data_type| data_type.get_type().clone()),
+                                r#static: property.modifiers.has_static(),
+                                readonly: property.modifiers.has_readonly(),
+                                visibility: property.modifiers.visibility(),
+                                default: self.transform_property_default(&entry.kind),
+                                deprecated: self
+                                    .transform_deprecation(&property.comments, property),
+                                location: Location::new(self.file_id, entry.span),
+                            }
+                        }))
+                }
+                ClassishMember::Property(Property::Hooked(property)) => {
+                    members.properties.push(PropertyEntity {
+                        name: property.entry.kind.variable().clone(),
+                        r#type: property
+                            .r#type
+                            .as_ref()
+                            .map(|data_type| data_type.get_type().clone()),
+                        r#static: property.modifiers.has_static(),
+                        readonly: property.modifiers.has_readonly(),
+                        visibility: property.modifiers.visibility(),
+                        default: self.transform_property_default(&property.entry.kind),
+                        deprecated: self.transform_deprecation(&property.comments, property),
+                        location: Location::new(self.file_id, property.span),
+                    })
+                }
+                ClassishMember::Constant(constant) => {
+                    members
+                        .constants
+                        .extend(constant.entries.iter().map(|entry| {
+                            ClassConstantEntity {
+                                name: entry.name.clone(),
+                                r#type: constant
+                                    .data_type
+                                    .as_ref()
+                                    .map(|data_type| data_type.get_type().clone()),
+                                value_type: self.transform_constant_value(&entry.value),
+                                r#final: constant.modifiers.has_final(),
+                                visibility: constant.modifiers.visibility(),
+                                deprecated: self
+                                    .transform_deprecation(&constant.comments, constant),
+                                location: Location::new(self.file_id, entry.span),
+                            }
+                        }))
+                }
                 _ => {}
             }
         }
 
-        (methods, ())
+        members
+    }
+
+    fn transform_property_default(&self, entry: &PropertyEntryKind) -> Option<u64> {
+        let PropertyEntryKind::Initialized(entry) = entry else {
+            return None;
+        };
+
+        let mut fingerprinter = Fingerprinter::new();
+        entry.value.fingerprint(&mut fingerprinter);
+
+        Some(fingerprinter.finish())
+    }
+
+    fn transform_constant_value(&self, value: &Expression) -> Option<Type<ResolvedName>> {
+        match &value.kind {
+            ExpressionKind::Literal(literal) => match literal.kind {
+                // Integers that are too large are converted to floats.
+                LiteralKind::Integer => match literal.integer_value()? {
+                    IntegerValue::Overflow(_) => Some(Type::Float),
+                    _ => Some(Type::Integer),
+                },
+                LiteralKind::Float => Some(Type::Float),
+                LiteralKind::String => literal
+                    .string_value()
+                    .map(|value| Type::LiteralString(value.value().to_bytestring())),
+                LiteralKind::Missing => None,
+            },
+            ExpressionKind::Bool(inner) => Some(match inner.value.kind {
+                TokenKind::True => Type::True,
+                TokenKind::False => Type::False,
+                _ => Type::Boolean,
+            }),
+            ExpressionKind::Null(_) => Some(Type::Null),
+            _ => None,
+        }
+    }
+
+    fn transform_promoted_properties(&self, node: &MethodParameterList) -> Vec<PropertyEntity> {
+        node.parameters
+            .iter()
+            .filter(|parameter| parameter.modifiers.is_some())
+            .map(|parameter| PropertyEntity {
+                name: parameter.name.clone(),
+                r#type: parameter
+                    .data_type
+                    .as_ref()
+                    .map(|data_type| data_type.get_type().clone()),
+                r#static: false,
+                readonly: parameter
+                    .modifiers
+                    .as_ref()
+                    .is_some_and(|modifiers| modifiers.has_readonly()),
+                visibility: parameter
+                    .modifiers
+                    .as_ref()
+                    .map(|modifiers| modifiers.visibility())
+                    .unwrap_or_default(),
+                default: None,
+                deprecated: None,
+                location: Location::new(self.file_id, parameter.span),
+            })
+            .collect()
+    }
+
+    fn transform_virtual_members(&self, comments: &CommentGroup) -> VirtualMembers {
+        let Some(docblock) = comments.docblock() else {
+            return VirtualMembers::default();
+        };
+
+        let tags = docblock.tags();
+
+        VirtualMembers {
+            methods: tags
+                .get_method_tags()
+                .into_iter()
+                .map(|tag| VirtualMethodEntity {
+                    name: tag.name.clone(),
+                    parameters: self.transform_function_parameter_list(
+                        &tag.parameters,
+                        &CommentGroup::default(),
+                    ),
+                    return_type: tag
+                        .return_type
+                        .as_ref()
+                        .map(|data_type| data_type.get_type().clone())
+                        .filter(|ty| !ty.is_missing()),
+                    r#static: tag.r#static.is_some(),
+                    location: Location::new(self.file_id, tag.span),
+                    signature: SignatureCache::default(),
+                })
+                .collect(),
+            properties: tags
+                .get_property_tags()
+                .into_iter()
+                .map(|tag| VirtualPropertyEntity {
+                    name: tag.variable.clone(),
+                    r#type: tag
+                        .data_type
+                        .as_ref()
+                        .map(|data_type| data_type.get_type().clone())
+                        .filter(|ty| !ty.is_missing()),
+                    readable: !tag.tag.symbol.ends_with(b"-write"),
+                    writable: !tag.tag.symbol.ends_with(b"-read"),
+                    location: Location::new(self.file_id, tag.span),
+                })
+                .collect(),
+        }
+    }
+
+    fn transform_names<'b>(&self, names: impl IntoIterator<Item = &'b Name>) -> Vec<ResolvedName> {
+        names
+            .into_iter()
+            .filter_map(|name| name.as_resolved().cloned())
+            .collect()
+    }
+
+    fn transform_backed_type(&self, backed_type: &BackedEnumType) -> Option<Type<ResolvedName>> {
+        match backed_type {
+            BackedEnumType::String(_) => Some(Type::String),
+            BackedEnumType::Int(_) => Some(Type::Integer),
+            BackedEnumType::Invalid => None,
+        }
+    }
+
+    /// Every enum implicitly implements `UnitEnum`, and backed enums also implement `BackedEnum`.
+    fn transform_enum_interfaces(&self, implements: &[Name], backed: bool) -> Vec<ResolvedName> {
+        let mut interfaces = self.transform_names(implements);
+
+        interfaces.push(builtin_name(b"UnitEnum"));
+
+        if backed {
+            interfaces.push(builtin_name(b"BackedEnum"));
+        }
+
+        interfaces
+    }
+
+    /// The members that PHP declares implicitly on enums through `UnitEnum` and `BackedEnum`,
+    /// located at the enum itself.
+    fn transform_enum_members(
+        &self,
+        name: &ResolvedName,
+        backed_type: Option<&Type<ResolvedName>>,
+        span: Span,
+    ) -> ClassishMembers {
+        let instance = Type::Named(name.clone());
+
+        let method = |name: &str, parameters: Vec<Parameter>, return_type: Type<ResolvedName>| {
+            MethodEntity {
+                name: SimpleIdentifier {
+                    id: 0,
+                    symbol: name.into(),
+                    span,
+                },
+                parameters: Parameters::new(parameters),
+                return_type: Some(return_type),
+                docblock_return_type: None,
+                returns_reference: false,
+                modifiers: MethodModifierGroup {
+                    id: 0,
+                    span,
+                    modifiers: vec![MethodModifier::Public(span), MethodModifier::Static(span)],
+                },
+                deprecated: None,
+                throws: Vec::new(),
+                reads_arguments: false,
+                location: Location::new(self.file_id, span),
+                signature: SignatureCache::default(),
+            }
+        };
+
+        let property = |name: &str, r#type: Type<ResolvedName>| PropertyEntity {
+            name: variable(name, span),
+            r#type: Some(r#type),
+            r#static: false,
+            readonly: true,
+            visibility: Visibility::Public,
+            default: None,
+            deprecated: None,
+            location: Location::new(self.file_id, span),
+        };
+
+        let mut members = ClassishMembers {
+            methods: vec![method(
+                "cases",
+                Vec::new(),
+                Type::Generic(
+                    Box::new(Type::List),
+                    vec![GenericTypeArgument {
+                        r#type: instance.clone(),
+                        variance: None,
+                    }],
+                ),
+            )],
+            properties: vec![property("name", Type::String)],
+            ..Default::default()
+        };
+
+        if let Some(backed_type) = backed_type {
+            let value = || {
+                vec![Parameter {
+                    name: variable("value", span),
+                    r#type: Some(Type::Union(vec![Type::Integer, Type::String])),
+                    docblock_type: None,
+                    optional: false,
+                    variadic: false,
+                    by_reference: false,
+                    attributes: Vec::new(),
+                    location: Location::new(self.file_id, span),
+                }]
+            };
+
+            members
+                .methods
+                .push(method("from", value(), instance.clone()));
+            members.methods.push(method(
+                "tryFrom",
+                value(),
+                Type::Nullable(Box::new(instance)),
+            ));
+            members
+                .properties
+                .push(property("value", backed_type.clone()));
+        }
+
+        members
+    }
+
+    fn index_function(&mut self, node: &FunctionStatement, comments: &CommentGroup) {
+        self.entities.add_function(FunctionEntity {
+            name: node.name.to_resolved().clone(),
+            parameters: self.transform_function_parameter_list(&node.parameters, comments),
+            return_type: self.transform_return_type(node.return_type.as_ref()),
+            docblock_return_type: self.transform_docblock_return_type(comments),
+            returns_reference: node.ampersand.is_some(),
+            deprecated: self.transform_deprecation(comments, node),
+            throws: self.transform_throws(comments),
+            reads_arguments: reads_arguments(&node.body.statements),
+            location: Location::new(self.file_id, node.span),
+            signature: SignatureCache::default(),
+        });
+    }
+}
+
+fn variable(name: &str, span: Span) -> SimpleVariable {
+    SimpleVariable {
+        id: 0,
+        symbol: format!("${}", name).into(),
+        stripped: name.into(),
+        span,
+    }
+}
+
+/// Whether a function body reads its arguments with `func_get_args()`, `func_num_args()` or
+/// `func_get_arg()`.
+fn reads_arguments(statements: &[Statement]) -> bool {
+    let mut visitor = ArgumentsReader::default();
+
+    visitor.visit(statements);
+    visitor.reads
+}
+
+#[derive(Default)]
+struct ArgumentsReader {
+    reads: bool,
+}
+
+impl Visitor for ArgumentsReader {
+    fn visit_function_call_expression(&mut self, node: &FunctionCallExpression) {
+        if let ExpressionKind::Name(name) = &node.target.kind {
+            let name = name.symbol();
+            let name = name.strip_prefix(b"\\").unwrap_or(name);
+
+            self.reads |= [
+                b"func_get_args".as_slice(),
+                b"func_num_args",
+                b"func_get_arg",
+            ]
+            .iter()
+            .any(|function| name.eq_ignore_ascii_case(function));
+        }
+
+        walk_function_call_expression(self, node);
+    }
+
+    // Nested functions read their own arguments.
+    fn visit_function_statement(&mut self, _: &FunctionStatement) {}
+
+    fn visit_closure_expression(&mut self, _: &ClosureExpression) {}
+
+    fn visit_arrow_function_expression(&mut self, _: &ArrowFunctionExpression) {}
+
+    fn visit_anonymous_class_expression(&mut self, _: &AnonymousClassExpression) {}
+
+    fn visit_class_statement(&mut self, _: &ClassStatement) {}
+
+    fn visit_interface_statement(&mut self, _: &InterfaceStatement) {}
+
+    fn visit_trait_statement(&mut self, _: &TraitStatement) {}
+
+    fn visit_unit_enum_statement(&mut self, _: &UnitEnumStatement) {}
+
+    fn visit_backed_enum_statement(&mut self, _: &BackedEnumStatement) {}
+}
+
+fn builtin_name(name: &[u8]) -> ResolvedName {
+    ResolvedName {
+        resolved: ByteString::from(name),
+        original: ByteString::from(name),
     }
 }
 
 impl<'a> Visitor for IndexingVisitor<'a> {
     fn visit_class_statement(&mut self, node: &ClassStatement) {
-        let (methods, properties) = self.transform_classish_members(&node.body.members);
+        let members = self.transform_classish_members(&node.body.members);
 
-        self.index.entities.add_class(ClassEntity {
+        let virtual_members = std::mem::take(&mut self.virtual_members);
+        let deprecated = std::mem::take(&mut self.deprecated);
+
+        self.entities.add_class(ClassEntity {
             name: node.name.to_resolved().clone(),
             kind: ClassEntityKind::Class,
-            methods,
+            r#abstract: node.modifiers.has_abstract(),
+            r#final: node.modifiers.has_final(),
+            readonly: node.modifiers.has_readonly(),
+            parent: node
+                .extends
+                .as_ref()
+                .and_then(|extends| extends.parent.as_resolved().cloned()),
+            interfaces: node
+                .implements
+                .as_ref()
+                .map(|implements| self.transform_names(&implements.interfaces.inner))
+                .unwrap_or_default(),
+            traits: members.traits,
+            methods: members.methods,
+            properties: members.properties,
+            constants: members.constants,
+            cases: Vec::new(),
+            backed_type: None,
+            virtual_methods: virtual_members.methods,
+            virtual_properties: virtual_members.properties,
+            implicit_methods: Vec::new(),
+            implicit_properties: Vec::new(),
+            deprecated,
             location: Location::new(self.file_id, node.span),
         })
     }
 
-    fn visit_function_statement(&mut self, node: &FunctionStatement) {
-        self.index.entities.add_function(FunctionEntity {
+    fn visit_interface_statement(&mut self, node: &InterfaceStatement) {
+        let members = self.transform_classish_members(&node.body.members);
+
+        let virtual_members = std::mem::take(&mut self.virtual_members);
+        let deprecated = std::mem::take(&mut self.deprecated);
+
+        self.entities.add_class(ClassEntity {
             name: node.name.to_resolved().clone(),
-            parameters: self.transform_function_parameter_list(&node.parameters),
-            return_type: self.transform_return_type(node.return_type.as_ref()),
-            returns_reference: node.ampersand.is_some(),
+            kind: ClassEntityKind::Interface,
+            r#abstract: false,
+            r#final: false,
+            readonly: false,
+            parent: None,
+            interfaces: node
+                .extends
+                .as_ref()
+                .map(|extends| self.transform_names(&extends.parents.inner))
+                .unwrap_or_default(),
+            traits: Vec::new(),
+            methods: members.methods,
+            properties: members.properties,
+            constants: members.constants,
+            cases: Vec::new(),
+            backed_type: None,
+            virtual_methods: virtual_members.methods,
+            virtual_properties: virtual_members.properties,
+            implicit_methods: Vec::new(),
+            implicit_properties: Vec::new(),
+            deprecated,
             location: Location::new(self.file_id, node.span),
-        });
+        })
+    }
+
+    fn visit_trait_statement(&mut self, node: &TraitStatement) {
+        let members = self.transform_classish_members(&node.body.members);
+
+        let virtual_members = std::mem::take(&mut self.virtual_members);
+        let deprecated = std::mem::take(&mut self.deprecated);
+
+        self.entities.add_class(ClassEntity {
+            name: node.name.to_resolved().clone(),
+            kind: ClassEntityKind::Trait,
+            r#abstract: false,
+            r#final: false,
+            readonly: false,
+            parent: None,
+            interfaces: Vec::new(),
+            traits: members.traits,
+            methods: members.methods,
+            properties: members.properties,
+            constants: members.constants,
+            cases: Vec::new(),
+            backed_type: None,
+            virtual_methods: virtual_members.methods,
+            virtual_properties: virtual_members.properties,
+            implicit_methods: Vec::new(),
+            implicit_properties: Vec::new(),
+            deprecated,
+            location: Location::new(self.file_id, node.span),
+        })
+    }
+
+    fn visit_unit_enum_statement(&mut self, node: &UnitEnumStatement) {
+        let members =
+            self.transform_classish_members(node.body.members.iter().filter_map(|member| {
+                match member {
+                    UnitEnumMember::Classish(member) => Some(member),
+                    _ => None,
+                }
+            }));
+
+        let cases = node
+            .body
+            .members
+            .iter()
+            .filter_map(|member| match member {
+                UnitEnumMember::Case(case) => Some(EnumCaseEntity {
+                    name: case.name.clone(),
+                    location: Location::new(self.file_id, case.span),
+                }),
+                _ => None,
+            })
+            .collect();
+
+        let virtual_members = std::mem::take(&mut self.virtual_members);
+        let deprecated = std::mem::take(&mut self.deprecated);
+        let name = node.name.to_resolved();
+        let implicit = self.transform_enum_members(name, None, node.span);
+
+        self.entities.add_class(ClassEntity {
+            name: name.clone(),
+            kind: ClassEntityKind::Enum,
+            r#abstract: false,
+            r#final: true,
+            readonly: false,
+            parent: None,
+            interfaces: self.transform_enum_interfaces(&node.implements, false),
+            traits: members.traits,
+            methods: members.methods,
+            properties: members.properties,
+            constants: members.constants,
+            cases,
+            backed_type: None,
+            virtual_methods: virtual_members.methods,
+            virtual_properties: virtual_members.properties,
+            implicit_methods: implicit.methods,
+            implicit_properties: implicit.properties,
+            deprecated,
+            location: Location::new(self.file_id, node.span),
+        })
+    }
+
+    fn visit_backed_enum_statement(&mut self, node: &BackedEnumStatement) {
+        let members =
+            self.transform_classish_members(node.body.members.iter().filter_map(|member| {
+                match member {
+                    BackedEnumMember::Classish(member) => Some(member),
+                    _ => None,
+                }
+            }));
+
+        let cases = node
+            .body
+            .members
+            .iter()
+            .filter_map(|member| match member {
+                BackedEnumMember::Case(case) => Some(EnumCaseEntity {
+                    name: case.name.clone(),
+                    location: Location::new(self.file_id, case.span),
+                }),
+                _ => None,
+            })
+            .collect();
+
+        let virtual_members = std::mem::take(&mut self.virtual_members);
+        let deprecated = std::mem::take(&mut self.deprecated);
+        let name = node.name.to_resolved();
+        let backed_type = self.transform_backed_type(&node.backed_type);
+        let implicit = self.transform_enum_members(name, backed_type.as_ref(), node.span);
+
+        self.entities.add_class(ClassEntity {
+            name: name.clone(),
+            kind: ClassEntityKind::Enum,
+            r#abstract: false,
+            r#final: true,
+            readonly: false,
+            parent: None,
+            interfaces: self.transform_enum_interfaces(&node.implements, true),
+            traits: members.traits,
+            methods: members.methods,
+            properties: members.properties,
+            constants: members.constants,
+            cases,
+            backed_type,
+            virtual_methods: virtual_members.methods,
+            virtual_properties: virtual_members.properties,
+            implicit_methods: implicit.methods,
+            implicit_properties: implicit.properties,
+            deprecated,
+            location: Location::new(self.file_id, node.span),
+        })
+    }
+
+    fn visit_statement(&mut self, node: &Statement) {
+        match &node.kind {
+            // The docblock for a function is attached to the statement, not the function itself.
+            StatementKind::Function(function) => self.index_function(function, &node.comments),
+            _ => {
+                self.virtual_members = self.transform_virtual_members(&node.comments);
+                self.deprecated = self.transform_docblock_deprecation(&node.comments);
+
+                walk_statement(self, node)
+            }
+        }
     }
 }
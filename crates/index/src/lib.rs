@@ -1,50 +1,94 @@
-use std::path::Path;
+use std::{collections::HashSet, path::Path, sync::Arc};
 
-use entities::EntityRegistry;
+use builder::{index_ast, index_file};
+use entities::{ClassEntityKind, EntityRegistry};
 use file::FileRegistry;
 
+mod builder;
+mod diagnostics;
 mod entities;
 mod file;
 mod indexer;
 mod location;
+mod members;
+mod persistence;
 mod reflection;
+mod signature;
 
 pub use file::{FileId, HasFileId};
-use indexer::IndexingVisitor;
-use pxp_ast::{visitor::Visitor, Statement};
+use pxp_ast::Statement;
 use pxp_bytestring::ByteString;
-use pxp_lexer::Lexer;
-use pxp_parser::Parser;
 
+pub use builder::IndexBuilder;
+pub use diagnostics::IndexDiagnostic;
 pub use entities::{FunctionEntity, Parameter, Parameters};
 pub use location::{HasLocation, Location};
+pub use members::{AccessContext, MemberCandidate, MemberKind};
+pub use persistence::{Decoder, Encoder, LoadedIndex, PersistenceError};
 pub use reflection::{
-    ReflectionClass, ReflectionFunction, ReflectionFunctionLike, ReflectionParameter,
-    ReflectsParameters, ReflectionType,
+    CanReflectParameters, ReflectionClass, ReflectionClassConstant, ReflectionEnumCase,
+    ReflectionFunction, ReflectionFunctionLike, ReflectionMethod, ReflectionParameter,
+    ReflectionProperty, ReflectionType, ReflectionVirtualMethod, ReflectionVirtualProperty,
+    ReflectsParameters,
 };
+pub use signature::{Signature, SignatureParameter};
 
-#[derive(Debug, Clone, Default)]
+/// The functions and class-likes declared in a set of files, which is built with an
+/// [`IndexBuilder`].
+///
+/// An index can't be changed once it's built, so it can be queried from any number of threads.
+/// Everything is behind an `Arc`, so cloning an index is cheap too.
+///
+/// A file that has changed can be indexed again with [`Index::update`], which returns a new index
+/// and leaves this one as it was. The new index shares the declarations of every other file with
+/// this one, but it has its own copy of the lookup tables, e.g. the names of the subclasses of each
+/// class. An update costs a copy of those tables, which grows with the number of declarations in
+/// the index, on top of indexing the file itself. Indexing many files at once should go through a
+/// builder instead.
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct Index {
-    files: FileRegistry,
-    pub(crate) entities: EntityRegistry,
+    files: Arc<FileRegistry>,
+    pub(crate) entities: Arc<EntityRegistry>,
 }
 
 impl Index {
+    /// Create an empty index. An index with something in it is built with an [`IndexBuilder`].
     pub fn new() -> Self {
         Self::default()
     }
 
-    pub fn index_file(&mut self, path: &Path) {
-        let file_id = self.files.get_or_insert(path);
-        let contents = std::fs::read(path).unwrap();
-        let parse_result = Parser::parse(Lexer::new(&contents));
+    /// Create a new index where everything that the given file declared is replaced by the
+    /// declarations in the given AST. See [`Index`] for what an update costs.
+    pub fn update(&self, file_id: FileId, ast: &[Statement]) -> Index {
+        let mut files = self.files.clone();
+        let mut entities = EntityRegistry::clone(&self.entities);
 
-        self.index(file_id, &parse_result.ast);
+        // The declarations no longer come from the file on disk, so there's nothing to compare
+        // its contents against when the index is loaded.
+        if files.get_hash(file_id).is_some() {
+            Arc::make_mut(&mut files).forget_hash(file_id);
+        }
+
+        index_ast(&mut entities, file_id, ast);
+
+        Index {
+            files,
+            entities: Arc::new(entities),
+        }
     }
 
-    pub fn index(&mut self, file_id: FileId, ast: &[Statement]) {
-        let mut visitor = IndexingVisitor::new(file_id, self);
-        visitor.visit(ast);
+    /// Create a new index where everything that the file at the given path declared is replaced
+    /// by what it declares now. See [`Index`] for what an update costs.
+    pub fn update_file(&self, path: &Path) -> Index {
+        let mut files = FileRegistry::clone(&self.files);
+        let mut entities = EntityRegistry::clone(&self.entities);
+
+        index_file(&mut files, &mut entities, path);
+
+        Index {
+            files: Arc::new(files),
+            entities: Arc::new(entities),
+        }
     }
 
     pub fn number_of_files(&self) -> usize {
@@ -55,7 +99,7 @@ impl Index {
         self.entities.functions().len()
     }
 
-    pub fn get_function(&self, name: impl Into<ByteString>) -> Option<ReflectionFunction> {
+    pub fn get_function(&self, name: impl Into<ByteString>) -> Option<ReflectionFunction<'_>> {
         self.entities
             .get_function(name)
             .map(ReflectionFunction::new)
@@ -65,10 +109,162 @@ impl Index {
         self.entities.classes().len()
     }
 
-    pub fn get_class(&self, name: impl Into<ByteString>) -> Option<ReflectionClass> {
+    pub fn get_class(&self, name: impl Into<ByteString>) -> Option<ReflectionClass<'_>> {
         self.entities.get_class(name).map(ReflectionClass::new)
     }
 
+    /// Get every class (or enum) that implements the given interface, either directly,
+    /// through an interface that extends it, or by extending a class that implements it.
+    pub fn get_implementations(&self, name: impl Into<ByteString>) -> Vec<ReflectionClass<'_>> {
+        let mut visited = HashSet::new();
+        let mut pending = vec![name.into()];
+        let mut implementations = Vec::new();
+
+        while let Some(name) = pending.pop() {
+            for implementor in self.entities.get_implementors(&name) {
+                if !visited.insert(implementor.clone()) {
+                    continue;
+                }
+
+                pending.push(implementor.clone());
+
+                let Some(class) = self.entities.get_class(implementor.clone()) else {
+                    continue;
+                };
+
+                if class.kind == ClassEntityKind::Interface {
+                    continue;
+                }
+
+                implementations.push(ReflectionClass::new(class));
+
+                for subclass in self.collect_subclasses(implementor, true) {
+                    if visited.insert(subclass.clone()) {
+                        if let Some(class) = self.entities.get_class(subclass.clone()) {
+                            implementations.push(ReflectionClass::new(class));
+                        }
+
+                        pending.push(subclass);
+                    }
+                }
+            }
+        }
+
+        implementations
+    }
+
+    /// Check whether the given class-like implements the given interface, either directly,
+    /// through an interface that extends it, or through one of its parent classes.
+    pub fn implements(
+        &self,
+        class: impl Into<ByteString>,
+        interface: impl Into<ByteString>,
+    ) -> bool {
+        let interface = interface.into();
+        let mut visited = HashSet::new();
+        let mut pending = vec![class.into()];
+
+        while let Some(name) = pending.pop() {
+            if !visited.insert(name.clone()) {
+                continue;
+            }
+
+            let Some(class) = self.entities.get_class(name) else {
+                continue;
+            };
+
+            for candidate in class.interfaces.iter() {
+                if candidate.resolved == interface {
+                    return true;
+                }
+
+                pending.push(candidate.resolved.clone());
+            }
+
+            pending.extend(class.parent.iter().map(|parent| parent.resolved.clone()));
+        }
+
+        false
+    }
+
+    /// Check whether the given class-like extends the other, either directly or through one of its
+    /// ancestors, or implements it if it's an interface. Class-likes aren't subclasses of themselves.
+    pub fn is_subclass_of(
+        &self,
+        class: impl Into<ByteString>,
+        parent: impl Into<ByteString>,
+    ) -> bool {
+        let parent = parent.into();
+        let mut visited = HashSet::new();
+        let mut pending = vec![class.into()];
+
+        while let Some(name) = pending.pop() {
+            if !visited.insert(name.clone()) {
+                continue;
+            }
+
+            let Some(class) = self.entities.get_class(name) else {
+                continue;
+            };
+
+            for candidate in class.parent.iter().chain(class.interfaces.iter()) {
+                if candidate.resolved == parent {
+                    return true;
+                }
+
+                pending.push(candidate.resolved.clone());
+            }
+        }
+
+        false
+    }
+
+    /// Get the classes that extend the given class. When `transitive` is `true`,
+    /// subclasses of subclasses are included too.
+    pub fn get_subclasses(
+        &self,
+        name: impl Into<ByteString>,
+        transitive: bool,
+    ) -> Vec<ReflectionClass<'_>> {
+        self.collect_subclasses(&name.into(), transitive)
+            .into_iter()
+            .filter_map(|name| self.entities.get_class(name))
+            .map(ReflectionClass::new)
+            .collect()
+    }
+
+    /// Get the class-likes that directly use the given trait.
+    pub fn get_trait_users(&self, name: impl Into<ByteString>) -> Vec<ReflectionClass<'_>> {
+        self.entities
+            .get_trait_users(&name.into())
+            .iter()
+            .filter_map(|name| self.entities.get_class(name.clone()))
+            .map(ReflectionClass::new)
+            .collect()
+    }
+
+    fn collect_subclasses(&self, name: &ByteString, transitive: bool) -> Vec<ByteString> {
+        let mut visited = HashSet::new();
+        let mut pending = vec![name.clone()];
+        let mut subclasses = Vec::new();
+
+        while let Some(name) = pending.pop() {
+            for subclass in self.entities.get_subclasses(&name) {
+                if !visited.insert(subclass.clone()) {
+                    continue;
+                }
+
+                subclasses.push(subclass.clone());
+
+                if transitive {
+                    pending.push(subclass.clone());
+                }
+            }
+        }
+
+        subclasses
+    }
+
     pub fn get_file_path(&self, from: impl HasFileId) -> Option<&std::path::Path> {
         self.files.get_file_path(from.file_id())
     }
@@ -0,0 +1,408 @@
+use std::collections::HashSet;
+
+use pxp_ast::{ResolvedName, Visibility};
+use pxp_bytestring::{ByteStr, ByteString};
+use pxp_type::Type;
+
+use crate::{
+    entities::{ClassEntity, ClassEntityKind},
+    reflection::{
+        ReflectionClass, ReflectionFunctionLike, ReflectionMethod, ReflectionVirtualMethod,
+    },
+    Index, Signature,
+};
+
+/// The place that members are being accessed from, which decides whether protected and
+/// private members are visible.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AccessContext {
+    /// Code outside of any class-like, where only public members are visible.
+    Global,
+    /// Code inside of the class-like with the given resolved name. This is also used to resolve
+    /// `self`, `static` and `parent` receivers.
+    Class(ByteString),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MemberKind {
+    Method,
+    Property,
+    Constant,
+    EnumCase,
+}
+
+/// A member that can be accessed on a receiver, as returned by [`Index::members_of`].
+#[derive(Debug, Clone)]
+pub struct MemberCandidate {
+    name: ByteString,
+    kind: MemberKind,
+    r#type: Option<Type<ResolvedName>>,
+    signature: Option<Signature>,
+    visibility: Visibility,
+    r#static: bool,
+    deprecated: bool,
+    declaring_class: ByteString,
+    partial: bool,
+    r#virtual: bool,
+}
+
+impl MemberCandidate {
+    /// Get the name of the member, without the leading `$` for properties.
+    pub fn get_name(&self) -> &ByteStr {
+        self.name.as_ref()
+    }
+
+    pub fn get_kind(&self) -> MemberKind {
+        self.kind
+    }
+
+    /// Get the type of a property or constant, or the return type of a method.
+    pub fn get_type(&self) -> Option<&Type<ResolvedName>> {
+        self.r#type.as_ref()
+    }
+
+    /// Get the signature of a method.
+    pub fn get_signature(&self) -> Option<&Signature> {
+        self.signature.as_ref()
+    }
+
+    pub fn get_visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    /// Constants and enum cases are always static.
+    pub fn is_static(&self) -> bool {
+        self.r#static
+    }
+
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated
+    }
+
+    /// Get the name of the class-like that declares the member, which is the trait for
+    /// members that are imported from one.
+    pub fn get_declaring_class(&self) -> &ByteStr {
+        self.declaring_class.as_ref()
+    }
+
+    /// Check whether the member is missing from some of the types in a union.
+    pub fn is_partial(&self) -> bool {
+        self.partial
+    }
+
+    /// Check whether the member only exists through a `@method` or `@property` tag.
+    pub fn is_virtual(&self) -> bool {
+        self.r#virtual
+    }
+
+    fn key(&self) -> (MemberKind, ByteString) {
+        match self.kind {
+            MemberKind::Method => (self.kind, self.name.to_ascii_lowercase().into()),
+            _ => (self.kind, self.name.clone()),
+        }
+    }
+}
+
+impl Index {
+    /// Get the members that are accessible on a receiver of the given type.
+    ///
+    /// Inherited members are flattened, with the most-derived declaration winning. Members declared
+    /// with `@method` and `@property` tags are included after the real members of each class-like. For a union,
+    /// every member of every class-like in the union is returned, and members that are missing
+    /// from any of them are flagged as partial. `null` is ignored, since it's handled by the nullsafe
+    /// operator. For an intersection, the members of every class-like are returned.
+    pub fn members_of(
+        &self,
+        r#type: &Type<ResolvedName>,
+        accessibility: AccessContext,
+    ) -> Vec<MemberCandidate> {
+        self.collect_members_of(r#type, &accessibility)
+    }
+
+    fn collect_members_of(
+        &self,
+        r#type: &Type<ResolvedName>,
+        context: &AccessContext,
+    ) -> Vec<MemberCandidate> {
+        match r#type {
+            Type::Named(name) => self.collect_class_members(&name.resolved, context),
+            Type::Generic(base, _) | Type::Nullable(base) => self.collect_members_of(base, context),
+            Type::SelfReference | Type::StaticReference => match context {
+                AccessContext::Class(name) => self.collect_class_members(name, context),
+                AccessContext::Global => Vec::new(),
+            },
+            Type::ParentReference => match context {
+                AccessContext::Class(name) => self
+                    .entities
+                    .get_class(name.clone())
+                    .and_then(|class| class.parent.as_ref())
+                    .map(|parent| self.collect_class_members(&parent.resolved, context))
+                    .unwrap_or_default(),
+                AccessContext::Global => Vec::new(),
+            },
+            Type::Intersection(types) => {
+                let mut members = Vec::new();
+                let mut seen = HashSet::new();
+
+                for r#type in types {
+                    for member in self.collect_members_of(r#type, context) {
+                        if seen.insert(member.key()) {
+                            members.push(member);
+                        }
+                    }
+                }
+
+                members
+            }
+            Type::Union(types) => {
+                let variants = types
+                    .iter()
+                    .filter(|r#type| !matches!(r#type, Type::Null))
+                    .map(|r#type| self.collect_members_of(r#type, context))
+                    .collect::<Vec<_>>();
+
+                let mut members = Vec::new();
+                let mut seen = HashSet::new();
+
+                for member in variants.iter().flatten() {
+                    let key = member.key();
+
+                    if !seen.insert(key.clone()) {
+                        continue;
+                    }
+
+                    let partial = member.partial
+                        || variants
+                            .iter()
+                            .any(|variant| !variant.iter().any(|candidate| candidate.key() == key));
+
+                    members.push(MemberCandidate {
+                        partial,
+                        ..member.clone()
+                    });
+                }
+
+                members
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn collect_class_members(
+        &self,
+        name: &ByteString,
+        context: &AccessContext,
+    ) -> Vec<MemberCandidate> {
+        let Some(class) = self.entities.get_class(name.clone()) else {
+            return Vec::new();
+        };
+
+        let mut members = Vec::new();
+        let mut seen = HashSet::new();
+
+        if class.kind == ClassEntityKind::Enum {
+            for member in enum_members(class) {
+                seen.insert(member.key());
+                members.push(member);
+            }
+        }
+
+        for (entity, owner) in self.lineage(name) {
+            let reflection = ReflectionClass::new(entity);
+            let declaring_class = &entity.name.resolved;
+
+            let candidates = entity
+                .implicit_methods
+                .iter()
+                .chain(entity.methods.iter())
+                .map(|method| {
+                    let method = ReflectionMethod::new(method, &reflection);
+                    let signature = method.effective_signature();
+
+                    MemberCandidate {
+                        name: method.get_name().to_bytestring(),
+                        kind: MemberKind::Method,
+                        r#type: signature.get_return_type().cloned(),
+                        signature: Some(signature.clone()),
+                        visibility: method.get_visibility(),
+                        r#static: method.is_static(),
+                        deprecated: method.is_deprecated(),
+                        declaring_class: declaring_class.clone(),
+                        partial: false,
+                        r#virtual: false,
+                    }
+                })
+                .chain(
+                    entity
+                        .implicit_properties
+                        .iter()
+                        .chain(entity.properties.iter())
+                        .map(|property| MemberCandidate {
+                            name: property.name.stripped.clone(),
+                            kind: MemberKind::Property,
+                            r#type: property.r#type.clone(),
+                            signature: None,
+                            visibility: property.visibility,
+                            r#static: property.r#static,
+                            deprecated: property.deprecated.is_some(),
+                            declaring_class: declaring_class.clone(),
+                            partial: false,
+                            r#virtual: false,
+                        }),
+                )
+                .chain(entity.constants.iter().map(|constant| MemberCandidate {
+                    name: constant.name.symbol.clone(),
+                    kind: MemberKind::Constant,
+                    r#type: constant.r#type.clone(),
+                    signature: None,
+                    visibility: constant.visibility,
+                    r#static: true,
+                    deprecated: constant.deprecated.is_some(),
+                    declaring_class: declaring_class.clone(),
+                    partial: false,
+                    r#virtual: false,
+                }))
+                .chain(entity.virtual_methods.iter().map(|method| {
+                    let method = ReflectionVirtualMethod::new(method, &reflection);
+                    let signature = method.effective_signature();
+
+                    MemberCandidate {
+                        name: method.get_name().to_bytestring(),
+                        kind: MemberKind::Method,
+                        r#type: signature.get_return_type().cloned(),
+                        signature: Some(signature.clone()),
+                        visibility: Visibility::Public,
+                        r#static: method.is_static(),
+                        deprecated: false,
+                        declaring_class: declaring_class.clone(),
+                        partial: false,
+                        r#virtual: true,
+                    }
+                }))
+                .chain(
+                    entity
+                        .virtual_properties
+                        .iter()
+                        .map(|property| MemberCandidate {
+                            name: property.name.stripped.clone(),
+                            kind: MemberKind::Property,
+                            r#type: property.r#type.clone(),
+                            signature: None,
+                            visibility: Visibility::Public,
+                            r#static: false,
+                            deprecated: false,
+                            declaring_class: declaring_class.clone(),
+                            partial: false,
+                            r#virtual: true,
+                        }),
+                )
+                .collect::<Vec<_>>();
+
+            for candidate in candidates {
+                // The most-derived declaration shadows the others, even when it isn't accessible.
+                if seen.insert(candidate.key()) && self.is_accessible(&candidate, owner, context) {
+                    members.push(candidate);
+                }
+            }
+        }
+
+        members
+    }
+
+    /// Get the class-like followed by everything it inherits from, in the order that members are
+    /// resolved: its own members, then its traits, then its parents, and finally its interfaces.
+    ///
+    /// Each class-like is paired with the class that owns its members for visibility checks,
+    /// which for a trait is the class that uses it.
+    fn lineage<'a>(&'a self, name: &ByteString) -> Vec<(&'a ClassEntity, &'a ByteString)> {
+        let mut lineage = Vec::new();
+        let mut visited = HashSet::new();
+
+        self.collect_lineage(name, None, &mut lineage, &mut visited);
+
+        lineage
+    }
+
+    fn collect_lineage<'a>(
+        &'a self,
+        name: &ByteString,
+        owner: Option<&'a ByteString>,
+        lineage: &mut Vec<(&'a ClassEntity, &'a ByteString)>,
+        visited: &mut HashSet<ByteString>,
+    ) {
+        if !visited.insert(name.clone()) {
+            return;
+        }
+
+        let Some(class) = self.entities.get_class(name.clone()) else {
+            return;
+        };
+
+        let owner = owner.unwrap_or(&class.name.resolved);
+
+        lineage.push((class, owner));
+
+        for r#trait in class.traits.iter() {
+            self.collect_lineage(&r#trait.resolved, Some(owner), lineage, visited);
+        }
+
+        if let Some(parent) = &class.parent {
+            self.collect_lineage(&parent.resolved, None, lineage, visited);
+        }
+
+        for interface in class.interfaces.iter() {
+            self.collect_lineage(&interface.resolved, None, lineage, visited);
+        }
+    }
+
+    fn is_accessible(
+        &self,
+        member: &MemberCandidate,
+        owner: &ByteString,
+        context: &AccessContext,
+    ) -> bool {
+        match (member.visibility, context) {
+            (Visibility::Public, _) => true,
+            (_, AccessContext::Global) => false,
+            (Visibility::Private, AccessContext::Class(scope)) => scope == owner,
+            (Visibility::Protected, AccessContext::Class(scope)) => {
+                self.inherits_from(scope, owner) || self.inherits_from(owner, scope)
+            }
+        }
+    }
+
+    fn inherits_from(&self, class: &ByteString, ancestor: &ByteString) -> bool {
+        self.lineage(class)
+            .iter()
+            .any(|(entity, _)| &entity.name.resolved == ancestor)
+    }
+}
+
+/// The cases of an enum, which are accessed like constants.
+fn enum_members(class: &ClassEntity) -> Vec<MemberCandidate> {
+    let instance = Type::Named(class.name.clone());
+
+    class
+        .cases
+        .iter()
+        .map(|case| MemberCandidate {
+            name: case.name.symbol.clone(),
+            ..member_of(&class.name.resolved, MemberKind::EnumCase, instance.clone())
+        })
+        .collect()
+}
+
+fn member_of(class: &ByteString, kind: MemberKind, r#type: Type<ResolvedName>) -> MemberCandidate {
+    MemberCandidate {
+        name: ByteString::default(),
+        kind,
+        r#type: Some(r#type),
+        signature: None,
+        visibility: Visibility::Public,
+        r#static: true,
+        deprecated: false,
+        declaring_class: class.clone(),
+        partial: false,
+        r#virtual: false,
+    }
+}
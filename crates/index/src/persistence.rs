@@ -0,0 +1,1439 @@
+use std::{
+    collections::HashSet,
+    fmt::Display,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use pxp_ast::{
+    Fingerprinter, MethodModifier, MethodModifierGroup, ResolvedName, SimpleIdentifier,
+    SimpleVariable, Visibility,
+};
+use pxp_bytestring::ByteString;
+use pxp_span::{IsSpanned, Span};
+use pxp_type::{
+    CallableParameter, ConstExpr, GenericTypeArgument, GenericTypeArgumentVariance, ShapeItem,
+    ShapeItemKey, ShapeUnsealedType, Type,
+};
+
+use crate::{
+    entities::{
+        ClassConstantEntity, ClassEntity, ClassEntityKind, DocblockType, EnumCaseEntity,
+        FunctionEntity, MethodEntity, Parameter, Parameters, PropertyEntity, VirtualMethodEntity,
+        VirtualPropertyEntity,
+    },
+    file::hash_contents,
+    signature::SignatureCache,
+    FileId, HasFileId, Index, IndexBuilder, Location,
+};
+
+const MAGIC: &[u8; 4] = b"PXPI";
+
+/// Bumped whenever the layout of a persisted index changes, so that old caches are rejected
+/// instead of being misread.
+const VERSION: u32 = 7;
+
+/// Types nest recursively, so a corrupted file could otherwise run the reader out of stack.
+const MAX_DEPTH: usize = 128;
+
+#[derive(Debug)]
+pub enum PersistenceError {
+    Io(std::io::Error),
+    /// The file doesn't start with the expected header, so it isn't a persisted index.
+    NotAnIndex,
+    UnsupportedVersion {
+        found: u32,
+        expected: u32,
+    },
+    /// The file ended before the index was fully read.
+    Truncated,
+    /// The file contains something that can't be part of a valid index.
+    Malformed(&'static str),
+}
+
+impl Display for PersistenceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PersistenceError::Io(error) => write!(f, "{}", error),
+            PersistenceError::NotAnIndex => write!(f, "file is not a persisted index"),
+            PersistenceError::UnsupportedVersion { found, expected } => write!(
+                f,
+                "persisted index has version {}, expected version {}",
+                found, expected
+            ),
+            PersistenceError::Truncated => write!(f, "persisted index is truncated"),
+            PersistenceError::Malformed(what) => {
+                write!(f, "persisted index is malformed: {}", what)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PersistenceError {}
+
+impl From<std::io::Error> for PersistenceError {
+    fn from(error: std::io::Error) -> Self {
+        PersistenceError::Io(error)
+    }
+}
+
+/// The result of loading a persisted index, see [`IndexBuilder::load`].
+#[derive(Debug)]
+pub struct LoadedIndex {
+    pub builder: IndexBuilder,
+    /// Files whose contents have changed since the index was saved. Everything they declared has
+    /// been dropped, so they should be indexed again before the index is built.
+    pub stale: Vec<PathBuf>,
+    /// The bytes passed to [`Index::save_with`], or nothing if the index was saved on its own.
+    pub extension: Vec<u8>,
+}
+
+/// Writes values in the same format as a persisted index, for data that's saved along with an
+/// index by [`Index::save_with`]. The data is read back with a [`Decoder`].
+#[derive(Default)]
+pub struct Encoder {
+    writer: Writer,
+}
+
+impl Encoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn write_bool(&mut self, value: bool) {
+        value.write(&mut self.writer);
+    }
+
+    pub fn write_u64(&mut self, value: u64) {
+        self.writer.u64(value);
+    }
+
+    pub fn write_usize(&mut self, value: usize) {
+        self.writer.usize(value);
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.writer.bytes(bytes);
+    }
+
+    pub fn write_file_id(&mut self, id: FileId) {
+        id.write(&mut self.writer);
+    }
+
+    pub fn write_span(&mut self, span: Span) {
+        span.write(&mut self.writer);
+    }
+
+    pub fn write_type(&mut self, r#type: &Type<ResolvedName>) {
+        r#type.write(&mut self.writer);
+    }
+
+    pub fn finish(self) -> Vec<u8> {
+        self.writer.buffer
+    }
+}
+
+/// Reads values written by an [`Encoder`].
+pub struct Decoder<'a> {
+    reader: Reader<'a>,
+}
+
+impl<'a> Decoder<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            reader: Reader::new(bytes),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.reader.is_empty()
+    }
+
+    pub fn read_bool(&mut self) -> Result<bool, PersistenceError> {
+        bool::read(&mut self.reader)
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, PersistenceError> {
+        self.reader.u64()
+    }
+
+    pub fn read_usize(&mut self) -> Result<usize, PersistenceError> {
+        self.reader.usize()
+    }
+
+    pub fn read_bytes(&mut self) -> Result<&'a [u8], PersistenceError> {
+        self.reader.bytes()
+    }
+
+    pub fn read_file_id(&mut self) -> Result<FileId, PersistenceError> {
+        FileId::read(&mut self.reader)
+    }
+
+    pub fn read_span(&mut self) -> Result<Span, PersistenceError> {
+        Span::read(&mut self.reader)
+    }
+
+    pub fn read_type(&mut self) -> Result<Type<ResolvedName>, PersistenceError> {
+        Type::read(&mut self.reader)
+    }
+}
+
+impl Index {
+    /// A hash of the parts of a function that its callers can see, e.g. its parameters and return
+    /// type, or `None` if there's no function with the given name. Where the function is declared
+    /// isn't part of the hash, so it only changes when the function's signature does.
+    pub fn function_signature_hash(&self, name: impl Into<ByteString>) -> Option<u64> {
+        self.entities.get_function(name).map(signature_hash)
+    }
+
+    /// A hash of the parts of a class-like that code using it can see, or `None` if there's no
+    /// class-like with the given name.
+    ///
+    /// Members can be inherited, so the class-likes that it extends, implements or uses are part of
+    /// the hash too, along with whether each of them exists.
+    pub fn class_signature_hash(&self, name: impl Into<ByteString>) -> Option<u64> {
+        let name = name.into();
+        self.entities.get_class(name.clone())?;
+
+        let mut fingerprinter = Fingerprinter::new();
+        let mut visited = HashSet::new();
+        let mut pending = vec![name];
+
+        while let Some(name) = pending.pop() {
+            if !visited.insert(name.clone()) {
+                continue;
+            }
+
+            fingerprinter.write_str(&name);
+
+            let Some(class) = self.entities.get_class(name) else {
+                fingerprinter.write_tag("Missing");
+                continue;
+            };
+
+            fingerprinter.write(&signature_hash(class).to_le_bytes());
+
+            pending.extend(
+                class
+                    .parent
+                    .iter()
+                    .chain(class.interfaces.iter())
+                    .chain(class.traits.iter())
+                    .map(|name| name.resolved.clone()),
+            );
+        }
+
+        Some(fingerprinter.finish())
+    }
+
+    /// Write the index to disk, so that it can be loaded by a later run instead of re-indexing
+    /// the whole project.
+    pub fn save(&self, path: &Path) -> Result<(), PersistenceError> {
+        self.save_with(path, &[])
+    }
+
+    /// Write the index to disk along with data that was built from it, e.g. a cache of inferred
+    /// types. The data is usually written with an [`Encoder`], and is handed back as-is by
+    /// [`IndexBuilder::load`].
+    pub fn save_with(&self, path: &Path, extension: &[u8]) -> Result<(), PersistenceError> {
+        let mut writer = Writer::default();
+
+        writer.buffer.extend_from_slice(MAGIC);
+        writer.u32(VERSION);
+
+        let mut files = self.files.iter().collect::<Vec<_>>();
+        files.sort_by_key(|(_, id)| id.to_usize());
+
+        writer.usize(files.len());
+
+        for (path, id) in files {
+            id.write(&mut writer);
+            writer.bytes(&path_to_bytes(path));
+            self.files.get_hash(id).write(&mut writer);
+        }
+
+        write_slice(self.entities.functions(), &mut writer);
+        write_slice(self.entities.classes(), &mut writer);
+        writer.bytes(extension);
+
+        std::fs::write(path, writer.buffer)?;
+
+        Ok(())
+    }
+}
+
+impl IndexBuilder {
+    /// Read an index written by [`Index::save`].
+    ///
+    /// Every file that was indexed from disk is hashed again, and anything declared by a file that
+    /// has changed (or no longer exists) is dropped. Changed files are returned so that the caller
+    /// can index them again.
+    pub fn load(path: &Path) -> Result<LoadedIndex, PersistenceError> {
+        let bytes = std::fs::read(path)?;
+        let mut reader = Reader::new(&bytes);
+
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(PersistenceError::NotAnIndex);
+        }
+
+        let version = reader.u32()?;
+
+        if version != VERSION {
+            return Err(PersistenceError::UnsupportedVersion {
+                found: version,
+                expected: VERSION,
+            });
+        }
+
+        let mut builder = IndexBuilder::new();
+
+        for _ in 0..reader.usize()? {
+            let id = FileId::read(&mut reader)?;
+            let path = path_from_bytes(reader.bytes()?)?;
+
+            if let Some(hash) = Option::<u64>::read(&mut reader)? {
+                builder.files.set_hash(id, hash);
+            }
+
+            builder.files.insert(path, id);
+        }
+
+        for function in Vec::<FunctionEntity>::read(&mut reader)? {
+            builder.entities.add_function(function);
+        }
+
+        for class in Vec::<ClassEntity>::read(&mut reader)? {
+            builder.entities.add_class(class);
+        }
+
+        let extension = reader.bytes()?.to_vec();
+
+        if !reader.is_empty() {
+            return Err(PersistenceError::Malformed(
+                "unexpected data after the index",
+            ));
+        }
+
+        let stale = builder.invalidate_changed_files();
+
+        Ok(LoadedIndex {
+            builder,
+            stale,
+            extension,
+        })
+    }
+
+    fn invalidate_changed_files(&mut self) -> Vec<PathBuf> {
+        let files = self
+            .files
+            .iter()
+            .map(|(path, id)| (path.to_path_buf(), id))
+            .collect::<Vec<_>>();
+
+        let mut stale = Vec::new();
+
+        for (path, id) in files {
+            let Some(hash) = self.files.get_hash(id) else {
+                continue;
+            };
+
+            match std::fs::read(&path) {
+                Ok(contents) if hash_contents(&contents) == hash => continue,
+                Ok(_) => stale.push(path),
+                // A file that has been deleted has nothing left to index.
+                Err(_) => {}
+            }
+
+            self.entities.remove_file(id);
+            self.files.forget_hash(id);
+        }
+
+        stale.sort();
+        stale
+    }
+}
+
+#[cfg(unix)]
+fn path_to_bytes(path: &Path) -> Vec<u8> {
+    use std::os::unix::ffi::OsStrExt;
+
+    path.as_os_str().as_bytes().to_vec()
+}
+
+#[cfg(not(unix))]
+fn path_to_bytes(path: &Path) -> Vec<u8> {
+    path.to_string_lossy().into_owned().into_bytes()
+}
+
+#[cfg(unix)]
+fn path_from_bytes(bytes: &[u8]) -> Result<PathBuf, PersistenceError> {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+    Ok(PathBuf::from(OsStr::from_bytes(bytes)))
+}
+
+#[cfg(not(unix))]
+fn path_from_bytes(bytes: &[u8]) -> Result<PathBuf, PersistenceError> {
+    std::str::from_utf8(bytes)
+        .map(PathBuf::from)
+        .map_err(|_| PersistenceError::Malformed("file path is not valid UTF-8"))
+}
+
+#[derive(Default)]
+struct Writer {
+    buffer: Vec<u8>,
+    /// Leave out where things are declared, so that the output only changes when what's declared
+    /// does. See [`signature_hash`].
+    signature: bool,
+}
+
+impl Writer {
+    fn u8(&mut self, value: u8) {
+        self.buffer.push(value);
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.buffer.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Integers are written as LEB128, since most of them (lengths, offsets, ids) are small.
+    fn u64(&mut self, mut value: u64) {
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+
+            if value == 0 {
+                self.u8(byte);
+                return;
+            }
+
+            self.u8(byte | 0x80);
+        }
+    }
+
+    fn usize(&mut self, value: usize) {
+        self.u64(value as u64);
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) {
+        self.usize(bytes.len());
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    fn node_id(&mut self, id: u32) {
+        if !self.signature {
+            id.write(self);
+        }
+    }
+}
+
+/// Hash the parts of an entity that code using it can see, i.e. everything except for the
+/// locations and node ids.
+fn signature_hash<T: Persist>(value: &T) -> u64 {
+    let mut writer = Writer {
+        signature: true,
+        ..Writer::default()
+    };
+
+    value.write(&mut writer);
+
+    let mut fingerprinter = Fingerprinter::new();
+    fingerprinter.write(&writer.buffer);
+    fingerprinter.finish()
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    depth: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, depth: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn take(&mut self, length: usize) -> Result<&'a [u8], PersistenceError> {
+        if length > self.bytes.len() {
+            return Err(PersistenceError::Truncated);
+        }
+
+        let (taken, rest) = self.bytes.split_at(length);
+        self.bytes = rest;
+
+        Ok(taken)
+    }
+
+    fn u8(&mut self) -> Result<u8, PersistenceError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, PersistenceError> {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(self.take(4)?);
+
+        Ok(u32::from_le_bytes(bytes))
+    }
+
+    fn u64(&mut self) -> Result<u64, PersistenceError> {
+        let mut value = 0u64;
+
+        for shift in (0..64).step_by(7) {
+            let byte = self.u8()?;
+            value |= ((byte & 0x7f) as u64) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err(PersistenceError::Malformed("integer is too large"))
+    }
+
+    fn usize(&mut self) -> Result<usize, PersistenceError> {
+        usize::try_from(self.u64()?)
+            .map_err(|_| PersistenceError::Malformed("integer is too large"))
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8], PersistenceError> {
+        let length = self.usize()?;
+
+        self.take(length)
+    }
+
+    fn nested<T>(
+        &mut self,
+        read: impl FnOnce(&mut Self) -> Result<T, PersistenceError>,
+    ) -> Result<T, PersistenceError> {
+        if self.depth == MAX_DEPTH {
+            return Err(PersistenceError::Malformed("types are nested too deeply"));
+        }
+
+        self.depth += 1;
+        let result = read(self);
+        self.depth -= 1;
+
+        result
+    }
+}
+
+trait Persist: Sized {
+    fn write(&self, writer: &mut Writer);
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError>;
+}
+
+impl Persist for bool {
+    fn write(&self, writer: &mut Writer) {
+        writer.u8(*self as u8);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        match reader.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(PersistenceError::Malformed("invalid boolean")),
+        }
+    }
+}
+
+impl Persist for u32 {
+    fn write(&self, writer: &mut Writer) {
+        writer.u64(*self as u64);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        u32::try_from(reader.u64()?)
+            .map_err(|_| PersistenceError::Malformed("integer is too large"))
+    }
+}
+
+impl Persist for u64 {
+    fn write(&self, writer: &mut Writer) {
+        writer.u64(*self);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        reader.u64()
+    }
+}
+
+impl Persist for usize {
+    fn write(&self, writer: &mut Writer) {
+        writer.usize(*self);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        reader.usize()
+    }
+}
+
+impl Persist for ByteString {
+    fn write(&self, writer: &mut Writer) {
+        writer.bytes(self);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(ByteString::from(reader.bytes()?))
+    }
+}
+
+impl<T: Persist> Persist for Option<T> {
+    fn write(&self, writer: &mut Writer) {
+        match self {
+            Some(value) => {
+                writer.u8(1);
+                value.write(writer);
+            }
+            None => writer.u8(0),
+        }
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        match reader.u8()? {
+            0 => Ok(None),
+            1 => Ok(Some(T::read(reader)?)),
+            _ => Err(PersistenceError::Malformed("invalid optional value")),
+        }
+    }
+}
+
+impl<T: Persist> Persist for Vec<T> {
+    fn write(&self, writer: &mut Writer) {
+        write_slice(self, writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        // The length isn't trusted for preallocation, every item takes at least one byte.
+        let length = reader.usize()?;
+        let mut items = Vec::with_capacity(length.min(reader.bytes.len()));
+
+        for _ in 0..length {
+            items.push(T::read(reader)?);
+        }
+
+        Ok(items)
+    }
+}
+
+fn write_slice<T: Persist>(items: &[T], writer: &mut Writer) {
+    writer.usize(items.len());
+
+    for item in items {
+        item.write(writer);
+    }
+}
+
+impl<T: Persist> Persist for Arc<T> {
+    fn write(&self, writer: &mut Writer) {
+        self.as_ref().write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        T::read(reader).map(Arc::new)
+    }
+}
+
+impl<T: Persist> Persist for Box<T> {
+    fn write(&self, writer: &mut Writer) {
+        self.as_ref().write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        reader.nested(|reader| Ok(Box::new(T::read(reader)?)))
+    }
+}
+
+impl Persist for Span {
+    fn write(&self, writer: &mut Writer) {
+        if writer.signature {
+            return;
+        }
+
+        writer.usize(self.start);
+        writer.usize(self.end);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(Span::new(reader.usize()?, reader.usize()?))
+    }
+}
+
+impl Persist for FileId {
+    fn write(&self, writer: &mut Writer) {
+        writer.usize(self.to_usize());
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(FileId::new(reader.usize()?))
+    }
+}
+
+impl Persist for Location {
+    fn write(&self, writer: &mut Writer) {
+        if writer.signature {
+            return;
+        }
+
+        self.file_id().write(writer);
+        self.span().write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(Location::new(FileId::read(reader)?, Span::read(reader)?))
+    }
+}
+
+impl Persist for SignatureCache {
+    // Signatures are derived from the rest of the entity, so they're computed again on demand.
+    fn write(&self, _: &mut Writer) {}
+
+    fn read(_: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(SignatureCache::default())
+    }
+}
+
+impl Persist for ResolvedName {
+    fn write(&self, writer: &mut Writer) {
+        self.resolved.write(writer);
+        self.original.write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(ResolvedName {
+            resolved: ByteString::read(reader)?,
+            original: ByteString::read(reader)?,
+        })
+    }
+}
+
+impl Persist for SimpleIdentifier {
+    fn write(&self, writer: &mut Writer) {
+        writer.node_id(self.id);
+        self.symbol.write(writer);
+        self.span.write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(SimpleIdentifier {
+            id: u32::read(reader)?,
+            symbol: ByteString::read(reader)?,
+            span: Span::read(reader)?,
+        })
+    }
+}
+
+impl Persist for SimpleVariable {
+    fn write(&self, writer: &mut Writer) {
+        writer.node_id(self.id);
+        self.symbol.write(writer);
+        self.stripped.write(writer);
+        self.span.write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(SimpleVariable {
+            id: u32::read(reader)?,
+            symbol: ByteString::read(reader)?,
+            stripped: ByteString::read(reader)?,
+            span: Span::read(reader)?,
+        })
+    }
+}
+
+impl Persist for Visibility {
+    fn write(&self, writer: &mut Writer) {
+        writer.u8(match self {
+            Visibility::Public => 0,
+            Visibility::Protected => 1,
+            Visibility::Private => 2,
+        });
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        match reader.u8()? {
+            0 => Ok(Visibility::Public),
+            1 => Ok(Visibility::Protected),
+            2 => Ok(Visibility::Private),
+            _ => Err(PersistenceError::Malformed("invalid visibility")),
+        }
+    }
+}
+
+impl Persist for MethodModifier {
+    fn write(&self, writer: &mut Writer) {
+        let (tag, span) = match self {
+            MethodModifier::Public(span) => (0, span),
+            MethodModifier::Protected(span) => (1, span),
+            MethodModifier::Private(span) => (2, span),
+            MethodModifier::Static(span) => (3, span),
+            MethodModifier::Abstract(span) => (4, span),
+            MethodModifier::Final(span) => (5, span),
+        };
+
+        writer.u8(tag);
+        span.write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        let modifier = match reader.u8()? {
+            0 => MethodModifier::Public,
+            1 => MethodModifier::Protected,
+            2 => MethodModifier::Private,
+            3 => MethodModifier::Static,
+            4 => MethodModifier::Abstract,
+            5 => MethodModifier::Final,
+            _ => return Err(PersistenceError::Malformed("invalid method modifier")),
+        };
+
+        Ok(modifier(Span::read(reader)?))
+    }
+}
+
+impl Persist for MethodModifierGroup {
+    fn write(&self, writer: &mut Writer) {
+        writer.node_id(self.id);
+        self.span.write(writer);
+        self.modifiers.write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(MethodModifierGroup {
+            id: u32::read(reader)?,
+            span: Span::read(reader)?,
+            modifiers: Vec::read(reader)?,
+        })
+    }
+}
+
+impl Persist for Type<ResolvedName> {
+    fn write(&self, writer: &mut Writer) {
+        match self {
+            Type::Named(name) => {
+                writer.u8(0);
+                name.write(writer);
+            }
+            Type::Generic(base, arguments) => {
+                writer.u8(1);
+                base.write(writer);
+                arguments.write(writer);
+            }
+            Type::Nullable(inner) => {
+                writer.u8(2);
+                inner.write(writer);
+            }
+            Type::Union(types) => {
+                writer.u8(3);
+                types.write(writer);
+            }
+            Type::Intersection(types) => {
+                writer.u8(4);
+                types.write(writer);
+            }
+            Type::Void => writer.u8(5),
+            Type::Null => writer.u8(6),
+            Type::True => writer.u8(7),
+            Type::False => writer.u8(8),
+            Type::Never => writer.u8(9),
+            Type::Float => writer.u8(10),
+            Type::Boolean => writer.u8(11),
+            Type::Integer => writer.u8(12),
+            Type::NonNegativeInteger => writer.u8(13),
+            Type::ClassString => writer.u8(14),
+            Type::String => writer.u8(15),
+            Type::LiteralString(value) => {
+                writer.u8(16);
+                value.write(writer);
+            }
+            Type::NumericString => writer.u8(17),
+            Type::NonEmptyString => writer.u8(18),
+            Type::Empty => writer.u8(19),
+            Type::List => writer.u8(20),
+            Type::NonEmptyList => writer.u8(21),
+            Type::Array => writer.u8(22),
+            Type::NonEmptyArray => writer.u8(23),
+            Type::Object => writer.u8(24),
+            Type::Mixed => writer.u8(25),
+            Type::NonEmptyMixed => writer.u8(26),
+            Type::Callable => writer.u8(27),
+            Type::CallableString => writer.u8(28),
+            Type::CallableSignature(callable, parameters, return_type) => {
+                writer.u8(29);
+                callable.write(writer);
+                parameters.write(writer);
+                return_type.write(writer);
+            }
+            Type::Iterable => writer.u8(30),
+            Type::StaticReference => writer.u8(31),
+            Type::SelfReference => writer.u8(32),
+            Type::ParentReference => writer.u8(33),
+            Type::ArrayKey => writer.u8(34),
+            Type::TypedArray(key, value) => {
+                writer.u8(35);
+                key.write(writer);
+                value.write(writer);
+            }
+            Type::Shaped {
+                base,
+                items,
+                sealed,
+                unsealed_type,
+            } => {
+                writer.u8(36);
+                base.write(writer);
+                items.write(writer);
+                sealed.write(writer);
+                unsealed_type.write(writer);
+            }
+            Type::ConditionalForParameter {
+                parameter,
+                negated,
+                target,
+                then,
+                otherwise,
+            } => {
+                writer.u8(37);
+                parameter.write(writer);
+                negated.write(writer);
+                target.write(writer);
+                then.write(writer);
+                otherwise.write(writer);
+            }
+            Type::Conditional {
+                subject,
+                negated,
+                target,
+                then,
+                otherwise,
+            } => {
+                writer.u8(38);
+                subject.write(writer);
+                negated.write(writer);
+                target.write(writer);
+                then.write(writer);
+                otherwise.write(writer);
+            }
+            Type::ValueOf => writer.u8(39),
+            Type::This => writer.u8(40),
+            Type::Missing => writer.u8(41),
+            Type::ConstExpr(expression) => {
+                writer.u8(42);
+                expression.write(writer);
+            }
+            Type::Invalid => writer.u8(43),
+        }
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        reader.nested(|reader| {
+            Ok(match reader.u8()? {
+                0 => Type::Named(ResolvedName::read(reader)?),
+                1 => Type::Generic(Box::read(reader)?, Vec::read(reader)?),
+                2 => Type::Nullable(Box::read(reader)?),
+                3 => Type::Union(Vec::read(reader)?),
+                4 => Type::Intersection(Vec::read(reader)?),
+                5 => Type::Void,
+                6 => Type::Null,
+                7 => Type::True,
+                8 => Type::False,
+                9 => Type::Never,
+                10 => Type::Float,
+                11 => Type::Boolean,
+                12 => Type::Integer,
+                13 => Type::NonNegativeInteger,
+                14 => Type::ClassString,
+                15 => Type::String,
+                16 => Type::LiteralString(ByteString::read(reader)?),
+                17 => Type::NumericString,
+                18 => Type::NonEmptyString,
+                19 => Type::Empty,
+                20 => Type::List,
+                21 => Type::NonEmptyList,
+                22 => Type::Array,
+                23 => Type::NonEmptyArray,
+                24 => Type::Object,
+                25 => Type::Mixed,
+                26 => Type::NonEmptyMixed,
+                27 => Type::Callable,
+                28 => Type::CallableString,
+                29 => Type::CallableSignature(
+                    Box::read(reader)?,
+                    Vec::read(reader)?,
+                    Box::read(reader)?,
+                ),
+                30 => Type::Iterable,
+                31 => Type::StaticReference,
+                32 => Type::SelfReference,
+                33 => Type::ParentReference,
+                34 => Type::ArrayKey,
+                35 => Type::TypedArray(Box::read(reader)?, Box::read(reader)?),
+                36 => Type::Shaped {
+                    base: Box::read(reader)?,
+                    items: Vec::read(reader)?,
+                    sealed: bool::read(reader)?,
+                    unsealed_type: Option::read(reader)?,
+                },
+                37 => Type::ConditionalForParameter {
+                    parameter: ByteString::read(reader)?,
+                    negated: bool::read(reader)?,
+                    target: Box::read(reader)?,
+                    then: Box::read(reader)?,
+                    otherwise: Box::read(reader)?,
+                },
+                38 => Type::Conditional {
+                    subject: Box::read(reader)?,
+                    negated: bool::read(reader)?,
+                    target: Box::read(reader)?,
+                    then: Box::read(reader)?,
+                    otherwise: Box::read(reader)?,
+                },
+                39 => Type::ValueOf,
+                40 => Type::This,
+                41 => Type::Missing,
+                42 => Type::ConstExpr(Box::read(reader)?),
+                43 => Type::Invalid,
+                _ => return Err(PersistenceError::Malformed("invalid type")),
+            })
+        })
+    }
+}
+
+impl Persist for GenericTypeArgument<ResolvedName> {
+    fn write(&self, writer: &mut Writer) {
+        self.r#type.write(writer);
+        self.variance.write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(GenericTypeArgument {
+            r#type: Type::read(reader)?,
+            variance: Option::read(reader)?,
+        })
+    }
+}
+
+impl Persist for GenericTypeArgumentVariance {
+    fn write(&self, writer: &mut Writer) {
+        writer.u8(match self {
+            GenericTypeArgumentVariance::Invariant => 0,
+            GenericTypeArgumentVariance::Covariant => 1,
+            GenericTypeArgumentVariance::Contravariant => 2,
+            GenericTypeArgumentVariance::Bivariant => 3,
+        });
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        match reader.u8()? {
+            0 => Ok(GenericTypeArgumentVariance::Invariant),
+            1 => Ok(GenericTypeArgumentVariance::Covariant),
+            2 => Ok(GenericTypeArgumentVariance::Contravariant),
+            3 => Ok(GenericTypeArgumentVariance::Bivariant),
+            _ => Err(PersistenceError::Malformed("invalid variance")),
+        }
+    }
+}
+
+impl Persist for CallableParameter<ResolvedName> {
+    fn write(&self, writer: &mut Writer) {
+        self.r#type.write(writer);
+        self.ellipsis.write(writer);
+        self.ampersand.write(writer);
+        self.equal.write(writer);
+        self.name.write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(CallableParameter {
+            r#type: Type::read(reader)?,
+            ellipsis: Option::read(reader)?,
+            ampersand: Option::read(reader)?,
+            equal: Option::read(reader)?,
+            name: Option::read(reader)?,
+        })
+    }
+}
+
+impl Persist for ShapeItem<ResolvedName> {
+    fn write(&self, writer: &mut Writer) {
+        self.key_name.write(writer);
+        self.value_type.write(writer);
+        self.optional.write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(ShapeItem {
+            key_name: Option::read(reader)?,
+            value_type: Type::read(reader)?,
+            optional: bool::read(reader)?,
+        })
+    }
+}
+
+impl Persist for ShapeItemKey {
+    fn write(&self, writer: &mut Writer) {
+        let (tag, key) = match self {
+            ShapeItemKey::Integer(key) => (0, key),
+            ShapeItemKey::String(key) => (1, key),
+        };
+
+        writer.u8(tag);
+        key.write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        let key = match reader.u8()? {
+            0 => ShapeItemKey::Integer,
+            1 => ShapeItemKey::String,
+            _ => return Err(PersistenceError::Malformed("invalid shape key")),
+        };
+
+        Ok(key(ByteString::read(reader)?))
+    }
+}
+
+impl Persist for ShapeUnsealedType<ResolvedName> {
+    fn write(&self, writer: &mut Writer) {
+        self.key_type.write(writer);
+        self.value_type.write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(ShapeUnsealedType {
+            key_type: Option::read(reader)?,
+            value_type: Type::read(reader)?,
+        })
+    }
+}
+
+impl Persist for ConstExpr<ResolvedName> {
+    fn write(&self, writer: &mut Writer) {
+        match self {
+            ConstExpr::NegativeInteger(value) => {
+                writer.u8(0);
+                value.write(writer);
+            }
+            ConstExpr::Integer(value) => {
+                writer.u8(1);
+                value.write(writer);
+            }
+            ConstExpr::Float(value) => {
+                writer.u8(2);
+                value.write(writer);
+            }
+            ConstExpr::String(value) => {
+                writer.u8(3);
+                value.write(writer);
+            }
+            ConstExpr::ConstFetch(target, constant) => {
+                writer.u8(4);
+                target.write(writer);
+                constant.write(writer);
+            }
+        }
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(match reader.u8()? {
+            0 => ConstExpr::NegativeInteger(ByteString::read(reader)?),
+            1 => ConstExpr::Integer(ByteString::read(reader)?),
+            2 => ConstExpr::Float(ByteString::read(reader)?),
+            3 => ConstExpr::String(ByteString::read(reader)?),
+            4 => ConstExpr::ConstFetch(Type::read(reader)?, ByteString::read(reader)?),
+            _ => return Err(PersistenceError::Malformed("invalid constant expression")),
+        })
+    }
+}
+
+impl Persist for DocblockType {
+    fn write(&self, writer: &mut Writer) {
+        self.r#type.write(writer);
+        self.span.write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(DocblockType {
+            r#type: Type::read(reader)?,
+            span: Span::read(reader)?,
+        })
+    }
+}
+
+impl Persist for Parameters {
+    fn write(&self, writer: &mut Writer) {
+        writer.usize(self.len());
+
+        for parameter in self.iter() {
+            parameter.write(writer);
+        }
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(Parameters::new(Vec::read(reader)?))
+    }
+}
+
+impl Persist for Parameter {
+    fn write(&self, writer: &mut Writer) {
+        self.name.write(writer);
+        self.r#type.write(writer);
+        self.docblock_type.write(writer);
+        self.optional.write(writer);
+        self.variadic.write(writer);
+        self.by_reference.write(writer);
+        self.attributes.write(writer);
+        self.location.write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(Parameter {
+            name: SimpleVariable::read(reader)?,
+            r#type: Option::read(reader)?,
+            docblock_type: Option::read(reader)?,
+            optional: bool::read(reader)?,
+            variadic: bool::read(reader)?,
+            by_reference: bool::read(reader)?,
+            attributes: Vec::read(reader)?,
+            location: Location::read(reader)?,
+        })
+    }
+}
+
+impl Persist for FunctionEntity {
+    fn write(&self, writer: &mut Writer) {
+        self.name.write(writer);
+        self.parameters.write(writer);
+        self.return_type.write(writer);
+        self.docblock_return_type.write(writer);
+        self.returns_reference.write(writer);
+        self.deprecated.write(writer);
+        self.throws.write(writer);
+        self.reads_arguments.write(writer);
+        self.location.write(writer);
+        self.signature.write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(FunctionEntity {
+            name: ResolvedName::read(reader)?,
+            parameters: Parameters::read(reader)?,
+            return_type: Option::read(reader)?,
+            docblock_return_type: Option::read(reader)?,
+            returns_reference: bool::read(reader)?,
+            deprecated: Option::read(reader)?,
+            throws: Vec::read(reader)?,
+            reads_arguments: bool::read(reader)?,
+            location: Location::read(reader)?,
+            signature: SignatureCache::read(reader)?,
+        })
+    }
+}
+
+impl Persist for MethodEntity {
+    fn write(&self, writer: &mut Writer) {
+        self.name.write(writer);
+        self.parameters.write(writer);
+        self.return_type.write(writer);
+        self.docblock_return_type.write(writer);
+        self.returns_reference.write(writer);
+        self.modifiers.write(writer);
+        self.deprecated.write(writer);
+        self.throws.write(writer);
+        self.reads_arguments.write(writer);
+        self.location.write(writer);
+        self.signature.write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(MethodEntity {
+            name: SimpleIdentifier::read(reader)?,
+            parameters: Parameters::read(reader)?,
+            return_type: Option::read(reader)?,
+            docblock_return_type: Option::read(reader)?,
+            returns_reference: bool::read(reader)?,
+            modifiers: MethodModifierGroup::read(reader)?,
+            deprecated: Option::read(reader)?,
+            throws: Vec::read(reader)?,
+            reads_arguments: bool::read(reader)?,
+            location: Location::read(reader)?,
+            signature: SignatureCache::read(reader)?,
+        })
+    }
+}
+
+impl Persist for ClassEntityKind {
+    fn write(&self, writer: &mut Writer) {
+        writer.u8(match self {
+            ClassEntityKind::Class => 0,
+            ClassEntityKind::Interface => 1,
+            ClassEntityKind::Enum => 2,
+            ClassEntityKind::Trait => 3,
+        });
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        match reader.u8()? {
+            0 => Ok(ClassEntityKind::Class),
+            1 => Ok(ClassEntityKind::Interface),
+            2 => Ok(ClassEntityKind::Enum),
+            3 => Ok(ClassEntityKind::Trait),
+            _ => Err(PersistenceError::Malformed("invalid class kind")),
+        }
+    }
+}
+
+impl Persist for ClassEntity {
+    fn write(&self, writer: &mut Writer) {
+        self.name.write(writer);
+        self.kind.write(writer);
+        self.r#abstract.write(writer);
+        self.r#final.write(writer);
+        self.readonly.write(writer);
+        self.parent.write(writer);
+        self.interfaces.write(writer);
+        self.traits.write(writer);
+        self.methods.write(writer);
+        self.properties.write(writer);
+        self.constants.write(writer);
+        self.cases.write(writer);
+        self.backed_type.write(writer);
+        self.virtual_methods.write(writer);
+        self.virtual_properties.write(writer);
+        self.implicit_methods.write(writer);
+        self.implicit_properties.write(writer);
+        self.deprecated.write(writer);
+        self.location.write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(ClassEntity {
+            name: ResolvedName::read(reader)?,
+            kind: ClassEntityKind::read(reader)?,
+            r#abstract: bool::read(reader)?,
+            r#final: bool::read(reader)?,
+            readonly: bool::read(reader)?,
+            parent: Option::read(reader)?,
+            interfaces: Vec::read(reader)?,
+            traits: Vec::read(reader)?,
+            methods: Vec::read(reader)?,
+            properties: Vec::read(reader)?,
+            constants: Vec::read(reader)?,
+            cases: Vec::read(reader)?,
+            backed_type: Option::read(reader)?,
+            virtual_methods: Vec::read(reader)?,
+            virtual_properties: Vec::read(reader)?,
+            implicit_methods: Vec::read(reader)?,
+            implicit_properties: Vec::read(reader)?,
+            deprecated: Option::read(reader)?,
+            location: Location::read(reader)?,
+        })
+    }
+}
+
+impl Persist for PropertyEntity {
+    fn write(&self, writer: &mut Writer) {
+        self.name.write(writer);
+        self.r#type.write(writer);
+        self.r#static.write(writer);
+        self.readonly.write(writer);
+        self.visibility.write(writer);
+        self.default.write(writer);
+        self.deprecated.write(writer);
+        self.location.write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(PropertyEntity {
+            name: SimpleVariable::read(reader)?,
+            r#type: Option::read(reader)?,
+            r#static: bool::read(reader)?,
+            readonly: bool::read(reader)?,
+            visibility: Visibility::read(reader)?,
+            default: Option::read(reader)?,
+            deprecated: Option::read(reader)?,
+            location: Location::read(reader)?,
+        })
+    }
+}
+
+impl Persist for ClassConstantEntity {
+    fn write(&self, writer: &mut Writer) {
+        self.name.write(writer);
+        self.r#type.write(writer);
+        self.value_type.write(writer);
+        self.r#final.write(writer);
+        self.visibility.write(writer);
+        self.deprecated.write(writer);
+        self.location.write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(ClassConstantEntity {
+            name: SimpleIdentifier::read(reader)?,
+            r#type: Option::read(reader)?,
+            value_type: Option::read(reader)?,
+            r#final: bool::read(reader)?,
+            visibility: Visibility::read(reader)?,
+            deprecated: Option::read(reader)?,
+            location: Location::read(reader)?,
+        })
+    }
+}
+
+impl Persist for EnumCaseEntity {
+    fn write(&self, writer: &mut Writer) {
+        self.name.write(writer);
+        self.location.write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(EnumCaseEntity {
+            name: SimpleIdentifier::read(reader)?,
+            location: Location::read(reader)?,
+        })
+    }
+}
+
+impl Persist for VirtualMethodEntity {
+    fn write(&self, writer: &mut Writer) {
+        self.name.write(writer);
+        self.parameters.write(writer);
+        self.return_type.write(writer);
+        self.r#static.write(writer);
+        self.location.write(writer);
+        self.signature.write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(VirtualMethodEntity {
+            name: SimpleIdentifier::read(reader)?,
+            parameters: Parameters::read(reader)?,
+            return_type: Option::read(reader)?,
+            r#static: bool::read(reader)?,
+            location: Location::read(reader)?,
+            signature: SignatureCache::read(reader)?,
+        })
+    }
+}
+
+impl Persist for VirtualPropertyEntity {
+    fn write(&self, writer: &mut Writer) {
+        self.name.write(writer);
+        self.r#type.write(writer);
+        self.readable.write(writer);
+        self.writable.write(writer);
+        self.location.write(writer);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, PersistenceError> {
+        Ok(VirtualPropertyEntity {
+            name: SimpleVariable::read(reader)?,
+            r#type: Option::read(reader)?,
+            readable: bool::read(reader)?,
+            writable: bool::read(reader)?,
+            location: Location::read(reader)?,
+        })
+    }
+}
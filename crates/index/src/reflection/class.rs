@@ -1,11 +1,14 @@
 use pxp_bytestring::ByteStr;
 
 use crate::{
-    entities::{ClassEntity, ClassEntityKind},
+    entities::{ClassEntity, ClassEntityKind, EnumCaseEntity},
     location::{HasLocation, Location},
 };
 
-use super::ReflectionMethod;
+use super::{
+    ReflectionClassConstant, ReflectionMethod, ReflectionProperty, ReflectionType,
+    ReflectionVirtualMethod, ReflectionVirtualProperty,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ReflectionClass<'a> {
@@ -31,6 +34,27 @@ impl<'a> ReflectionClass<'a> {
         self.entity.name.original.as_ref()
     }
 
+    pub fn get_parent_name(&self) -> Option<&ByteStr> {
+        self.entity
+            .parent
+            .as_ref()
+            .map(|parent| parent.resolved.as_ref())
+    }
+
+    pub fn get_interface_names(&self) -> impl Iterator<Item = &ByteStr> {
+        self.entity
+            .interfaces
+            .iter()
+            .map(|interface| interface.resolved.as_ref())
+    }
+
+    pub fn get_trait_names(&self) -> impl Iterator<Item = &ByteStr> {
+        self.entity
+            .traits
+            .iter()
+            .map(|r#trait| r#trait.resolved.as_ref())
+    }
+
     pub fn is_class(&self) -> bool {
         self.entity.kind == ClassEntityKind::Class
     }
@@ -47,7 +71,41 @@ impl<'a> ReflectionClass<'a> {
         self.entity.kind == ClassEntityKind::Trait
     }
 
-    pub fn get_methods(&self) -> Vec<ReflectionMethod> {
+    pub fn is_backed_enum(&self) -> bool {
+        self.entity.backed_type.is_some()
+    }
+
+    /// Get the type of the values of a backed enum's cases, either `int` or `string`.
+    pub fn get_backed_type(&self) -> Option<ReflectionType<'a>> {
+        self.entity.backed_type.as_ref().map(ReflectionType::new)
+    }
+
+    pub fn is_abstract(&self) -> bool {
+        self.entity.r#abstract
+    }
+
+    /// Enums can't be extended, so they are always final.
+    pub fn is_final(&self) -> bool {
+        self.entity.r#final
+    }
+
+    pub fn is_readonly(&self) -> bool {
+        self.entity.readonly
+    }
+
+    pub fn is_deprecated(&self) -> bool {
+        self.entity.deprecated.is_some()
+    }
+
+    /// Get the deprecation message of the class-like, which is empty when it was deprecated without one.
+    pub fn get_deprecation_message(&self) -> Option<&ByteStr> {
+        self.entity
+            .deprecated
+            .as_ref()
+            .map(|message| message.as_ref())
+    }
+
+    pub fn get_methods(&self) -> Vec<ReflectionMethod<'_>> {
         self.entity
             .methods
             .iter()
@@ -55,22 +113,165 @@ impl<'a> ReflectionClass<'a> {
             .collect()
     }
 
-    pub fn get_method(&self, name: &ByteStr) -> Option<ReflectionMethod> {
+    /// Get the methods that PHP declares implicitly, like `cases()`, `from()` and `tryFrom()` on
+    /// enums.
+    pub fn get_implicit_methods(&self) -> Vec<ReflectionMethod<'_>> {
+        self.entity
+            .implicit_methods
+            .iter()
+            .map(|m| ReflectionMethod::new(m, self))
+            .collect()
+    }
+
+    /// Get a declared or implicit method by name.
+    pub fn get_method(&self, name: &ByteStr) -> Option<ReflectionMethod<'_>> {
         self.get_methods()
             .into_iter()
+            .chain(self.get_implicit_methods())
             .find(|method| method.get_name() == name)
     }
 
-    pub fn get_static_methods(&self) -> Vec<ReflectionMethod> {
+    pub fn get_static_methods(&self) -> Vec<ReflectionMethod<'_>> {
         self.get_methods()
             .into_iter()
             .filter(|method| method.is_static())
             .collect()
     }
 
-    pub fn get_static_method(&self, name: &ByteStr) -> Option<ReflectionMethod> {
-        self.get_static_methods()
+    /// Get a declared or implicit static method by name.
+    pub fn get_static_method(&self, name: &ByteStr) -> Option<ReflectionMethod<'_>> {
+        self.get_method(name).filter(|method| method.is_static())
+    }
+
+    /// Get the names of the properties declared directly on the class-like, without the leading `$`.
+    pub fn get_property_names(&self) -> impl Iterator<Item = &ByteStr> {
+        self.entity
+            .properties
+            .iter()
+            .map(|property| property.name.stripped.as_ref())
+    }
+
+    pub fn has_properties(&self) -> bool {
+        !self.entity.properties.is_empty()
+    }
+
+    /// Get the properties declared directly on the class-like, including promoted constructor properties.
+    pub fn get_properties(&self) -> Vec<ReflectionProperty<'_>> {
+        self.entity
+            .properties
+            .iter()
+            .map(|property| ReflectionProperty::new(property, self))
+            .collect()
+    }
+
+    /// Get the properties that PHP declares implicitly, like `name` and `value` on enums.
+    pub fn get_implicit_properties(&self) -> Vec<ReflectionProperty<'_>> {
+        self.entity
+            .implicit_properties
+            .iter()
+            .map(|property| ReflectionProperty::new(property, self))
+            .collect()
+    }
+
+    /// Get a declared or implicit property by name, without the leading `$`.
+    pub fn get_property(&self, name: &ByteStr) -> Option<ReflectionProperty<'_>> {
+        self.get_properties()
             .into_iter()
-            .find(|method| method.get_name() == name)
+            .chain(self.get_implicit_properties())
+            .find(|property| property.get_name() == name)
+    }
+
+    /// Get the constants declared directly on the class-like.
+    pub fn get_constants(&self) -> Vec<ReflectionClassConstant<'_>> {
+        self.entity
+            .constants
+            .iter()
+            .map(|constant| ReflectionClassConstant::new(constant, self))
+            .collect()
+    }
+
+    pub fn get_constant(&self, name: &ByteStr) -> Option<ReflectionClassConstant<'_>> {
+        self.get_constants()
+            .into_iter()
+            .find(|constant| constant.get_name() == name)
+    }
+
+    pub fn has_constant(&self, name: &ByteStr) -> bool {
+        self.get_constant(name).is_some()
+    }
+
+    /// Get the methods declared with `@method` tags in the class-like's docblock.
+    pub fn get_virtual_methods(&self) -> Vec<ReflectionVirtualMethod<'_>> {
+        self.entity
+            .virtual_methods
+            .iter()
+            .map(|method| ReflectionVirtualMethod::new(method, self))
+            .collect()
+    }
+
+    /// Method names are case-insensitive, so `@method` tags are matched ignoring case.
+    pub fn get_virtual_method(&self, name: &ByteStr) -> Option<ReflectionVirtualMethod<'_>> {
+        self.get_virtual_methods()
+            .into_iter()
+            .find(|method| method.get_name().eq_ignore_ascii_case(name))
+    }
+
+    /// Get the properties declared with `@property` tags in the class-like's docblock.
+    pub fn get_virtual_properties(&self) -> Vec<ReflectionVirtualProperty<'_>> {
+        self.entity
+            .virtual_properties
+            .iter()
+            .map(|property| ReflectionVirtualProperty::new(property, self))
+            .collect()
+    }
+
+    pub fn get_virtual_property(&self, name: &ByteStr) -> Option<ReflectionVirtualProperty<'_>> {
+        self.get_virtual_properties()
+            .into_iter()
+            .find(|property| property.get_name() == name)
+    }
+
+    pub fn get_cases(&self) -> Vec<ReflectionEnumCase<'_>> {
+        self.entity
+            .cases
+            .iter()
+            .map(|case| ReflectionEnumCase::new(case, self))
+            .collect()
+    }
+
+    pub fn get_case(&self, name: &ByteStr) -> Option<ReflectionEnumCase<'_>> {
+        self.get_cases()
+            .into_iter()
+            .find(|case| case.get_name() == name)
+    }
+
+    pub fn has_case(&self, name: &ByteStr) -> bool {
+        self.get_case(name).is_some()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReflectionEnumCase<'a> {
+    entity: &'a EnumCaseEntity,
+    owner: &'a ReflectionClass<'a>,
+}
+
+impl<'a> HasLocation for ReflectionEnumCase<'a> {
+    fn location(&self) -> Location {
+        self.entity.location
+    }
+}
+
+impl<'a> ReflectionEnumCase<'a> {
+    pub fn new(entity: &'a EnumCaseEntity, owner: &'a ReflectionClass<'a>) -> Self {
+        Self { entity, owner }
+    }
+
+    pub fn get_name(&self) -> &ByteStr {
+        self.entity.name.symbol.as_ref()
+    }
+
+    pub fn get_enum(&self) -> &ReflectionClass<'a> {
+        self.owner
     }
 }
@@ -0,0 +1,77 @@
+use pxp_ast::Visibility;
+use pxp_bytestring::ByteStr;
+
+use crate::{
+    entities::ClassConstantEntity,
+    location::{HasLocation, Location},
+};
+
+use super::{ReflectionClass, ReflectionType};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReflectionClassConstant<'a> {
+    entity: &'a ClassConstantEntity,
+    owner: &'a ReflectionClass<'a>,
+}
+
+impl<'a> HasLocation for ReflectionClassConstant<'a> {
+    fn location(&self) -> Location {
+        self.entity.location
+    }
+}
+
+impl<'a> ReflectionClassConstant<'a> {
+    pub fn new(entity: &'a ClassConstantEntity, owner: &'a ReflectionClass<'a>) -> Self {
+        Self { entity, owner }
+    }
+
+    pub fn get_name(&self) -> &ByteStr {
+        self.entity.name.symbol.as_ref()
+    }
+
+    pub fn get_class(&self) -> &ReflectionClass<'a> {
+        self.owner
+    }
+
+    /// Get the declared type of the constant, only present on typed constants.
+    pub fn get_type(&self) -> Option<ReflectionType<'a>> {
+        self.entity.r#type.as_ref().map(ReflectionType::new)
+    }
+
+    /// Get the type of the constant's value, only present when the value is a literal.
+    pub fn get_value_type(&self) -> Option<ReflectionType<'a>> {
+        self.entity.value_type.as_ref().map(ReflectionType::new)
+    }
+
+    pub fn is_final(&self) -> bool {
+        self.entity.r#final
+    }
+
+    pub fn get_visibility(&self) -> Visibility {
+        self.entity.visibility
+    }
+
+    pub fn is_public(&self) -> bool {
+        self.get_visibility() == Visibility::Public
+    }
+
+    pub fn is_protected(&self) -> bool {
+        self.get_visibility() == Visibility::Protected
+    }
+
+    pub fn is_private(&self) -> bool {
+        self.get_visibility() == Visibility::Private
+    }
+
+    pub fn is_deprecated(&self) -> bool {
+        self.entity.deprecated.is_some()
+    }
+
+    /// Get the deprecation message of the constant, which is empty when it was deprecated without one.
+    pub fn get_deprecation_message(&self) -> Option<&ByteStr> {
+        self.entity
+            .deprecated
+            .as_ref()
+            .map(|message| message.as_ref())
+    }
+}
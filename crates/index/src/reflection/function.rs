@@ -1,13 +1,15 @@
-use pxp_ast::{Name, ResolvedName};
+use pxp_ast::ResolvedName;
 use pxp_bytestring::ByteStr;
-use pxp_type::Type;
 
 use crate::{
     location::{HasLocation, Location},
-    FunctionEntity,
+    FunctionEntity, Signature,
 };
 
-use super::{parameters::{CanReflectParameters, ReflectionParameter, ReflectsParameters}, ReflectionType};
+use super::{
+    parameters::{CanReflectParameters, ReflectionParameter, ReflectsParameters},
+    ReflectionType,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ReflectionFunction<'a> {
@@ -30,6 +32,18 @@ impl<'a> ReflectionFunction<'a> {
     pub fn in_namespace(&self) -> bool {
         self.entity.name.resolved != self.entity.name.original
     }
+
+    pub fn is_deprecated(&self) -> bool {
+        self.entity.deprecated.is_some()
+    }
+
+    /// Get the deprecation message of the function, which is empty when it was deprecated without one.
+    pub fn get_deprecation_message(&self) -> Option<&ByteStr> {
+        self.entity
+            .deprecated
+            .as_ref()
+            .map(|message| message.as_ref())
+    }
 }
 
 impl<'a> HasLocation for ReflectionFunction<'a> {
@@ -54,12 +68,31 @@ impl IsFunctionLike for ReflectionFunction<'_> {}
 
 impl<'a> ReflectionFunctionLike<'a> for ReflectionFunction<'a> {
     fn get_return_type(&self) -> Option<ReflectionType<'a>> {
-        self.entity.return_type.as_ref().map(|t| ReflectionType::new(t))
+        self.entity.return_type.as_ref().map(ReflectionType::new)
     }
 
     fn returns_reference(&self) -> bool {
         self.entity.returns_reference
     }
+
+    fn get_throws(&self) -> &'a [ResolvedName] {
+        &self.entity.throws
+    }
+
+    fn reads_arguments(&self) -> bool {
+        self.entity.reads_arguments
+    }
+
+    fn effective_signature(&self) -> &'a Signature {
+        self.entity.signature.get_or_init(|| {
+            Signature::new(
+                &self.entity.parameters,
+                self.entity.return_type.as_ref(),
+                self.entity.docblock_return_type.as_ref(),
+                self.entity.returns_reference,
+            )
+        })
+    }
 }
 
 pub trait IsFunctionLike {}
@@ -72,4 +105,15 @@ pub trait ReflectionFunctionLike<'a>: IsFunctionLike {
     }
 
     fn returns_reference(&self) -> bool;
+
+    /// Get the exceptions that the function is documented to throw with `@throws` tags.
+    fn get_throws(&self) -> &'a [ResolvedName];
+
+    /// Whether the body reads its arguments with `func_get_args()`, `func_num_args()` or
+    /// `func_get_arg()`, so it can be called with more arguments than it declares.
+    fn reads_arguments(&self) -> bool;
+
+    /// Get the signature as seen by callers, where docblock types (`@param`, `@return`) replace the
+    /// native types that they refine. The signature is computed once and cached.
+    fn effective_signature(&self) -> &'a Signature;
 }
@@ -1,10 +1,10 @@
-use pxp_ast::{Name, ResolvedName};
+use pxp_ast::{ResolvedName, Visibility};
 use pxp_bytestring::ByteStr;
-use pxp_type::Type;
 
 use crate::{
-    entities::MethodEntity,
+    entities::{MethodEntity, VirtualMethodEntity},
     location::{HasLocation, Location},
+    Signature,
 };
 
 use super::{
@@ -38,6 +38,10 @@ impl<'a> ReflectionMethod<'a> {
         self.owner
     }
 
+    pub fn get_visibility(&self) -> Visibility {
+        self.entity.modifiers.visibility()
+    }
+
     pub fn is_public(&self) -> bool {
         self.entity.modifiers.is_public()
     }
@@ -61,6 +65,18 @@ impl<'a> ReflectionMethod<'a> {
     pub fn is_abstract(&self) -> bool {
         self.entity.modifiers.has_abstract()
     }
+
+    pub fn is_deprecated(&self) -> bool {
+        self.entity.deprecated.is_some()
+    }
+
+    /// Get the deprecation message of the method, which is empty when it was deprecated without one.
+    pub fn get_deprecation_message(&self) -> Option<&ByteStr> {
+        self.entity
+            .deprecated
+            .as_ref()
+            .map(|message| message.as_ref())
+    }
 }
 
 impl CanReflectParameters for ReflectionMethod<'_> {}
@@ -79,10 +95,104 @@ impl IsFunctionLike for ReflectionMethod<'_> {}
 
 impl<'a> ReflectionFunctionLike<'a> for ReflectionMethod<'a> {
     fn get_return_type(&self) -> Option<ReflectionType<'a>> {
-        self.entity.return_type.as_ref().map(|t| ReflectionType::new(t))
+        self.entity.return_type.as_ref().map(ReflectionType::new)
     }
 
     fn returns_reference(&self) -> bool {
         self.entity.returns_reference
     }
+
+    fn get_throws(&self) -> &'a [ResolvedName] {
+        &self.entity.throws
+    }
+
+    fn reads_arguments(&self) -> bool {
+        self.entity.reads_arguments
+    }
+
+    fn effective_signature(&self) -> &'a Signature {
+        self.entity.signature.get_or_init(|| {
+            Signature::new(
+                &self.entity.parameters,
+                self.entity.return_type.as_ref(),
+                self.entity.docblock_return_type.as_ref(),
+                self.entity.returns_reference,
+            )
+        })
+    }
+}
+
+/// A method that only exists through a `@method` tag, usually backed by `__call` or `__callStatic`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReflectionVirtualMethod<'a> {
+    entity: &'a VirtualMethodEntity,
+    owner: &'a ReflectionClass<'a>,
+}
+
+impl<'a> HasLocation for ReflectionVirtualMethod<'a> {
+    fn location(&self) -> Location {
+        self.entity.location
+    }
+}
+
+impl<'a> ReflectionVirtualMethod<'a> {
+    pub fn new(entity: &'a VirtualMethodEntity, owner: &'a ReflectionClass<'a>) -> Self {
+        Self { entity, owner }
+    }
+
+    pub fn get_name(&self) -> &ByteStr {
+        self.entity.name.symbol.as_ref()
+    }
+
+    pub fn get_class(&self) -> &ReflectionClass<'a> {
+        self.owner
+    }
+
+    pub fn is_static(&self) -> bool {
+        self.entity.r#static
+    }
+}
+
+impl CanReflectParameters for ReflectionVirtualMethod<'_> {}
+
+impl<'a> ReflectsParameters<'a, ReflectionVirtualMethod<'a>> for ReflectionVirtualMethod<'a> {
+    fn get_parameters(&self) -> Vec<ReflectionParameter<'a, ReflectionVirtualMethod<'a>>> {
+        self.entity
+            .parameters
+            .iter()
+            .map(|p| ReflectionParameter::new(p, *self))
+            .collect()
+    }
+}
+
+impl IsFunctionLike for ReflectionVirtualMethod<'_> {}
+
+impl<'a> ReflectionFunctionLike<'a> for ReflectionVirtualMethod<'a> {
+    fn get_return_type(&self) -> Option<ReflectionType<'a>> {
+        self.entity.return_type.as_ref().map(ReflectionType::new)
+    }
+
+    fn returns_reference(&self) -> bool {
+        false
+    }
+
+    fn get_throws(&self) -> &'a [ResolvedName] {
+        &[]
+    }
+
+    fn reads_arguments(&self) -> bool {
+        false
+    }
+
+    /// The types in a `@method` tag are already docblock types, so they're used as they are.
+    fn effective_signature(&self) -> &'a Signature {
+        self.entity.signature.get_or_init(|| {
+            Signature::new(
+                &self.entity.parameters,
+                self.entity.return_type.as_ref(),
+                None,
+                false,
+            )
+        })
+    }
 }
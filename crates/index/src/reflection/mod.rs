@@ -1,11 +1,15 @@
 mod class;
+mod constant;
 mod function;
 mod method;
 mod parameters;
+mod property;
 mod r#type;
 
-pub use class::ReflectionClass;
+pub use class::{ReflectionClass, ReflectionEnumCase};
+pub use constant::ReflectionClassConstant;
 pub use function::{ReflectionFunction, ReflectionFunctionLike};
-pub use method::ReflectionMethod;
-pub use parameters::{ReflectionParameter, ReflectsParameters};
+pub use method::{ReflectionMethod, ReflectionVirtualMethod};
+pub use parameters::{CanReflectParameters, ReflectionParameter, ReflectsParameters};
+pub use property::{ReflectionProperty, ReflectionVirtualProperty};
 pub use r#type::ReflectionType;
@@ -27,7 +27,7 @@ impl<'a, O: CanReflectParameters> ReflectionParameter<'a, O> {
     }
 
     pub fn get_type(&self) -> Option<ReflectionType<'a>> {
-        self.entity.r#type.as_ref().map(|t| ReflectionType::new(t))
+        self.entity.r#type.as_ref().map(ReflectionType::new)
     }
 
     pub fn is_optional(&self) -> bool {
@@ -35,7 +35,23 @@ impl<'a, O: CanReflectParameters> ReflectionParameter<'a, O> {
     }
 
     pub fn is_variadic(&self) -> bool {
-        todo!()
+        self.entity.variadic
+    }
+
+    /// Get the resolved names of the attributes on the parameter.
+    pub fn attributes(&self) -> impl Iterator<Item = &'a ByteStr> {
+        self.entity
+            .attributes
+            .iter()
+            .map(|attribute| attribute.as_bytestr())
+    }
+
+    /// Check if the parameter has the given attribute, ignoring case and any leading backslash.
+    pub fn has_attribute(&self, name: &ByteStr) -> bool {
+        let name = name.strip_prefix(b'\\');
+
+        self.attributes()
+            .any(|attribute| attribute.strip_prefix(b'\\').eq_ignore_ascii_case(name))
     }
 }
 
@@ -0,0 +1,132 @@
+use pxp_ast::Visibility;
+use pxp_bytestring::ByteStr;
+
+use crate::{
+    entities::{PropertyEntity, VirtualPropertyEntity},
+    location::{HasLocation, Location},
+};
+
+use super::{ReflectionClass, ReflectionType};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReflectionProperty<'a> {
+    entity: &'a PropertyEntity,
+    owner: &'a ReflectionClass<'a>,
+}
+
+impl<'a> HasLocation for ReflectionProperty<'a> {
+    fn location(&self) -> Location {
+        self.entity.location
+    }
+}
+
+impl<'a> ReflectionProperty<'a> {
+    pub fn new(entity: &'a PropertyEntity, owner: &'a ReflectionClass<'a>) -> Self {
+        Self { entity, owner }
+    }
+
+    /// Get the name of the property, without the leading `$`.
+    pub fn get_name(&self) -> &ByteStr {
+        self.entity.name.stripped.as_ref()
+    }
+
+    pub fn get_class(&self) -> &ReflectionClass<'a> {
+        self.owner
+    }
+
+    pub fn get_type(&self) -> Option<ReflectionType<'a>> {
+        self.entity.r#type.as_ref().map(ReflectionType::new)
+    }
+
+    pub fn is_static(&self) -> bool {
+        self.entity.r#static
+    }
+
+    /// Check whether the property is readonly, either itself or because its class is.
+    pub fn is_readonly(&self) -> bool {
+        self.entity.readonly || self.owner.is_readonly()
+    }
+
+    pub fn get_visibility(&self) -> Visibility {
+        self.entity.visibility
+    }
+
+    pub fn is_public(&self) -> bool {
+        self.get_visibility() == Visibility::Public
+    }
+
+    pub fn is_protected(&self) -> bool {
+        self.get_visibility() == Visibility::Protected
+    }
+
+    pub fn is_private(&self) -> bool {
+        self.get_visibility() == Visibility::Private
+    }
+
+    pub fn has_default(&self) -> bool {
+        self.entity.default.is_some()
+    }
+
+    /// Check whether another property with the same name could be composed into the same class,
+    /// which requires the same visibility, staticness, type and default value.
+    pub fn is_compatible_with(&self, other: &ReflectionProperty) -> bool {
+        self.entity.visibility == other.entity.visibility
+            && self.entity.r#static == other.entity.r#static
+            && self.entity.r#type == other.entity.r#type
+            && self.entity.default == other.entity.default
+    }
+
+    pub fn is_deprecated(&self) -> bool {
+        self.entity.deprecated.is_some()
+    }
+
+    /// Get the deprecation message of the property, which is empty when it was deprecated without one.
+    pub fn get_deprecation_message(&self) -> Option<&ByteStr> {
+        self.entity
+            .deprecated
+            .as_ref()
+            .map(|message| message.as_ref())
+    }
+}
+
+/// A property that only exists through a `@property` tag, usually backed by `__get`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReflectionVirtualProperty<'a> {
+    entity: &'a VirtualPropertyEntity,
+    owner: &'a ReflectionClass<'a>,
+}
+
+impl<'a> HasLocation for ReflectionVirtualProperty<'a> {
+    fn location(&self) -> Location {
+        self.entity.location
+    }
+}
+
+impl<'a> ReflectionVirtualProperty<'a> {
+    pub fn new(entity: &'a VirtualPropertyEntity, owner: &'a ReflectionClass<'a>) -> Self {
+        Self { entity, owner }
+    }
+
+    /// Get the name of the property, without the leading `$`.
+    pub fn get_name(&self) -> &ByteStr {
+        self.entity.name.stripped.as_ref()
+    }
+
+    pub fn get_class(&self) -> &ReflectionClass<'a> {
+        self.owner
+    }
+
+    pub fn get_type(&self) -> Option<ReflectionType<'a>> {
+        self.entity.r#type.as_ref().map(ReflectionType::new)
+    }
+
+    /// Check whether the property can be read, i.e. it wasn't declared with `@property-write`.
+    pub fn is_readable(&self) -> bool {
+        self.entity.readable
+    }
+
+    /// Check whether the property can be written to, i.e. it wasn't declared with `@property-read`.
+    pub fn is_writable(&self) -> bool {
+        self.entity.writable
+    }
+}
@@ -0,0 +1,171 @@
+use std::sync::OnceLock;
+
+use pxp_ast::ResolvedName;
+use pxp_bytestring::{ByteStr, ByteString};
+use pxp_diagnostics::{Diagnostic, Severity};
+use pxp_type::Type;
+
+use crate::{
+    entities::{DocblockType, Parameters},
+    IndexDiagnostic,
+};
+
+/// The signature of a function or method as seen by callers, with the native types overlaid by
+/// any docblock types that refine them.
+#[derive(Debug, Clone)]
+pub struct Signature {
+    parameters: Vec<SignatureParameter>,
+    return_type: Option<Type<ResolvedName>>,
+    returns_reference: bool,
+    diagnostics: Vec<Diagnostic<IndexDiagnostic>>,
+}
+
+impl Signature {
+    pub(crate) fn new(
+        parameters: &Parameters,
+        return_type: Option<&Type<ResolvedName>>,
+        docblock_return_type: Option<&DocblockType>,
+        returns_reference: bool,
+    ) -> Self {
+        let mut diagnostics = Vec::new();
+
+        let parameters = parameters
+            .iter()
+            .map(|parameter| SignatureParameter {
+                name: parameter.name.stripped.clone(),
+                r#type: merge(
+                    parameter.r#type.as_ref(),
+                    parameter.docblock_type.as_ref(),
+                    &mut diagnostics,
+                    |native, docblock| IndexDiagnostic::IncompatibleDocblockParameterType {
+                        parameter: parameter.name.stripped.clone(),
+                        native,
+                        docblock,
+                    },
+                ),
+                native_type: parameter.r#type.clone(),
+                optional: parameter.optional,
+                variadic: parameter.variadic,
+                by_reference: parameter.by_reference,
+            })
+            .collect();
+
+        let return_type = merge(
+            return_type,
+            docblock_return_type,
+            &mut diagnostics,
+            |native, docblock| IndexDiagnostic::IncompatibleDocblockReturnType { native, docblock },
+        );
+
+        Self {
+            parameters,
+            return_type,
+            returns_reference,
+            diagnostics,
+        }
+    }
+
+    pub fn get_parameters(&self) -> &[SignatureParameter] {
+        &self.parameters
+    }
+
+    pub fn get_parameter(&self, name: &ByteStr) -> Option<&SignatureParameter> {
+        self.parameters
+            .iter()
+            .find(|parameter| parameter.name.as_bytestr() == name)
+    }
+
+    pub fn get_return_type(&self) -> Option<&Type<ResolvedName>> {
+        self.return_type.as_ref()
+    }
+
+    pub fn returns_reference(&self) -> bool {
+        self.returns_reference
+    }
+
+    /// Get the notes produced while merging docblock types, e.g. a docblock type that contradicts
+    /// the native type.
+    pub fn get_diagnostics(&self) -> &[Diagnostic<IndexDiagnostic>] {
+        &self.diagnostics
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignatureParameter {
+    name: ByteString,
+    r#type: Option<Type<ResolvedName>>,
+    native_type: Option<Type<ResolvedName>>,
+    optional: bool,
+    variadic: bool,
+    by_reference: bool,
+}
+
+impl SignatureParameter {
+    pub fn get_name(&self) -> &ByteStr {
+        self.name.as_ref()
+    }
+
+    pub fn get_type(&self) -> Option<&Type<ResolvedName>> {
+        self.r#type.as_ref()
+    }
+
+    pub fn get_native_type(&self) -> Option<&Type<ResolvedName>> {
+        self.native_type.as_ref()
+    }
+
+    pub fn is_optional(&self) -> bool {
+        self.optional
+    }
+
+    pub fn is_variadic(&self) -> bool {
+        self.variadic
+    }
+
+    pub fn is_by_reference(&self) -> bool {
+        self.by_reference
+    }
+}
+
+/// The docblock type is only used when it's a refinement of the native type. Anything else
+/// can't be trusted, so the native type is kept and a note is produced instead.
+fn merge(
+    native: Option<&Type<ResolvedName>>,
+    docblock: Option<&DocblockType>,
+    diagnostics: &mut Vec<Diagnostic<IndexDiagnostic>>,
+    incompatible: impl FnOnce(Type<ResolvedName>, Type<ResolvedName>) -> IndexDiagnostic,
+) -> Option<Type<ResolvedName>> {
+    match (native, docblock) {
+        (native, None) => native.cloned(),
+        (None, Some(docblock)) => Some(docblock.r#type.clone()),
+        (Some(native), Some(docblock)) if docblock.r#type.is_refinement_of(native) => {
+            Some(docblock.r#type.clone())
+        }
+        (Some(native), Some(docblock)) => {
+            diagnostics.push(Diagnostic::new(
+                incompatible(native.clone(), docblock.r#type.clone()),
+                Severity::Information,
+                docblock.span,
+            ));
+
+            Some(native.clone())
+        }
+    }
+}
+
+/// Lazily computed signature stored alongside a function-like entity.
+///
+/// The signature is derived from the entity's other fields, so it's ignored when comparing entities.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SignatureCache(OnceLock<Signature>);
+
+impl SignatureCache {
+    pub(crate) fn get_or_init(&self, f: impl FnOnce() -> Signature) -> &Signature {
+        self.0.get_or_init(f)
+    }
+}
+
+impl PartialEq for SignatureCache {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,16 @@
+<?php
+
+namespace App\Constants;
+
+trait HasVersion {
+    const MAJOR = 1;
+    final public const int MINOR = 2, PATCH = 3;
+}
+
+final readonly class Version {
+    use HasVersion;
+
+    const LABEL = 'v1';
+}
+
+abstract class Release {}
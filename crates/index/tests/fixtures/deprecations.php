@@ -0,0 +1,35 @@
+<?php
+
+namespace App\Deprecations;
+
+/**
+ * @deprecated Use Client instead.
+ */
+class LegacyClient
+{
+    /** @deprecated */
+    public $handle;
+
+    public $timeout;
+
+    /** @deprecated since 2.0 */
+    const VERSION = 1;
+
+    #[\Deprecated(message: "Use send() instead", since: "1.5")]
+    public function post() {}
+
+    #[\Deprecated("Use send() instead")]
+    public function get() {}
+
+    public function send() {}
+}
+
+/**
+ * @deprecated
+ */
+function connect() {}
+
+#[\Deprecated]
+function disconnect() {}
+
+function reconnect() {}
@@ -0,0 +1,31 @@
+<?php
+
+namespace App\Enums;
+
+interface HasLabel {}
+
+interface HasColor extends HasLabel {}
+
+trait Labels {
+    public string $prefix = '';
+}
+
+enum Suit: string implements HasColor {
+    const Wild = self::Spades;
+
+    case Hearts = 'H';
+    case Spades = 'S';
+
+    public static function fromChar(string $char): self {}
+
+    public function color(): string {}
+
+    case Clubs = 'C';
+}
+
+enum Size {
+    use Labels;
+
+    case Small;
+    case Large;
+}
@@ -7,3 +7,5 @@ function b(string $a, int $b) {}
 function c(): void {}
 
 function &d() {}
+
+function e(string $user, #[\SensitiveParameter, Deprecated] string $password) {}
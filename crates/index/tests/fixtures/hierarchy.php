@@ -0,0 +1,38 @@
+<?php
+
+namespace App\Contracts;
+
+interface Repository {}
+
+interface CachingRepository extends Repository {}
+
+namespace App\Concerns;
+
+trait Loggable {}
+
+trait Cacheable {
+    use Loggable;
+}
+
+namespace App\Repositories;
+
+use App\Concerns\Cacheable;
+use App\Concerns\Loggable;
+use App\Contracts\CachingRepository;
+use App\Contracts\Repository;
+
+class UserRepository implements Repository {
+    use Loggable;
+}
+
+class AdminRepository extends UserRepository {}
+
+class SuperAdminRepository extends AdminRepository {}
+
+class PostRepository implements CachingRepository {
+    use Cacheable;
+}
+
+enum Status implements Repository {
+    case Active;
+}
@@ -0,0 +1,20 @@
+<?php
+
+namespace App\Magic;
+
+/**
+ * @property string $title
+ * @property-read int $views
+ * @property-write string $slug
+ * @method static Post find(int $id)
+ * @method static Post[] where(string $column, mixed $value = null, string ...$columns)
+ * @method bool save()
+ */
+class Post
+{
+    public static int $count = 0;
+
+    public function __construct(public readonly int $id, string $body) {}
+
+    public function __get(string $name): mixed {}
+}
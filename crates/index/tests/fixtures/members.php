@@ -0,0 +1,60 @@
+<?php
+
+namespace App\Members;
+
+interface Shape {
+    const SIDES = 0;
+
+    public function area(): float;
+}
+
+trait Named {
+    private string $name = '';
+
+    public function getName(): string {}
+
+    private function setName(string $name): void {}
+}
+
+abstract class Base implements Shape {
+    use Named;
+
+    protected static int $count = 0;
+
+    private const SECRET = 'base';
+
+    public function area(): float {}
+
+    public function describe(): string {}
+
+    /** @deprecated Use describe() instead. */
+    public function summary(): string {}
+
+    protected function scale(float $factor): static {}
+
+    private function reset(): void {}
+}
+
+final class Square extends Base {
+    const SIDES = 4;
+
+    public function __construct(
+        public readonly float $side,
+    ) {}
+
+    public function area(): float {}
+
+    protected function scale(float $factor): static {}
+}
+
+class Circle implements Shape {
+    public float $radius = 0.0;
+
+    public function area(): float {}
+
+    public function describe(): string {}
+}
+
+class Triangle extends Base {
+    protected function grow(): void {}
+}
@@ -0,0 +1,11 @@
+<?php
+
+namespace App\Promoted;
+
+class Post {
+    public function __construct(
+        private readonly string $slug,
+        protected int $count = 0,
+        public ?Post $parent = null,
+    ) {}
+}
@@ -0,0 +1,35 @@
+<?php
+
+namespace App\Signatures;
+
+use App\Models\User;
+
+/**
+ * @param array<int, User> $users
+ * @param string ...$tags
+ * @return list<string>
+ */
+function refined(array $users, int &$count = 0, string ...$tags): array {}
+
+/**
+ * @param string $id
+ * @return int
+ */
+function conflicting(int $id): string {}
+
+function undocumented(?int $limit = null, $options = []): iterable {}
+
+/**
+ * @param User $user
+ * @return non-empty-string
+ */
+function untyped($user, $unknown) {}
+
+class Builder
+{
+    /** @return $this */
+    public function where(string $column): static {}
+
+    /** @return User */
+    public function first(): ?object {}
+}
@@ -0,0 +1,27 @@
+<?php
+
+namespace App\Throws;
+
+use RuntimeException;
+
+class StorageException extends RuntimeException {}
+
+class DiskFullException extends StorageException implements Retryable {}
+
+interface Retryable {}
+
+/**
+ * @throws StorageException When the file can't be written.
+ * @throws \InvalidArgumentException|DiskFullException
+ */
+function write(string $path) {}
+
+class Disk
+{
+    /**
+     * @throws DiskFullException
+     */
+    public function put(string $path) {}
+
+    public function get(string $path) {}
+}
@@ -1,6 +1,13 @@
 use discoverer::discover;
-use pxp_index::{Index, ReflectionFunctionLike, ReflectsParameters};
-use pxp_type::Type;
+use pxp_ast::ResolvedName;
+use pxp_diagnostics::DiagnosticKind;
+use pxp_index::{
+    AccessContext, FileId, Index, IndexBuilder, IndexDiagnostic, MemberCandidate, MemberKind,
+    ReflectionClass, ReflectionFunctionLike, ReflectsParameters,
+};
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+use pxp_type::{GenericTypeArgument, Type};
 
 #[test]
 fn it_indexes_plain_functions() {
@@ -21,7 +28,7 @@ fn it_indexes_functions_with_parameters() {
     let b = index.get_function("b").unwrap();
 
     assert_eq!(b.get_number_of_parameters(), 2);
-    
+
     let parameters = b.get_parameters();
 
     assert_eq!(parameters[0].get_name(), b"a");
@@ -52,13 +59,972 @@ fn it_indexes_functions_that_return_by_ref() {
     assert!(d.returns_reference());
 }
 
+#[test]
+fn it_indexes_parameter_attributes() {
+    let index = index();
+
+    let e = index.get_function("e").unwrap();
+    let parameters = e.get_parameters();
+
+    assert_eq!(parameters[0].attributes().count(), 0);
+    assert!(!parameters[0].has_attribute(b"SensitiveParameter".into()));
+
+    assert_eq!(
+        parameters[1]
+            .attributes()
+            .map(|attribute| attribute.to_string())
+            .collect::<Vec<_>>(),
+        vec!["SensitiveParameter", "Deprecated"]
+    );
+    assert!(parameters[1].has_attribute(b"SensitiveParameter".into()));
+    assert!(parameters[1].has_attribute(b"\\sensitiveparameter".into()));
+    assert!(!parameters[1].has_attribute(b"Override".into()));
+}
+
+#[test]
+fn it_finds_implementations_of_interfaces() {
+    let index = index();
+
+    assert_eq!(
+        names(index.get_implementations("App\\Contracts\\Repository")),
+        vec![
+            "App\\Repositories\\AdminRepository",
+            "App\\Repositories\\PostRepository",
+            "App\\Repositories\\Status",
+            "App\\Repositories\\SuperAdminRepository",
+            "App\\Repositories\\UserRepository",
+        ]
+    );
+
+    assert_eq!(
+        names(index.get_implementations("App\\Contracts\\CachingRepository")),
+        vec!["App\\Repositories\\PostRepository"]
+    );
+}
+
+#[test]
+fn it_finds_subclasses_of_classes() {
+    let index = index();
+
+    assert_eq!(
+        names(index.get_subclasses("App\\Repositories\\UserRepository", false)),
+        vec!["App\\Repositories\\AdminRepository"]
+    );
+
+    assert_eq!(
+        names(index.get_subclasses("App\\Repositories\\UserRepository", true)),
+        vec![
+            "App\\Repositories\\AdminRepository",
+            "App\\Repositories\\SuperAdminRepository",
+        ]
+    );
+}
+
+#[test]
+fn it_finds_users_of_traits() {
+    let index = index();
+
+    assert_eq!(
+        names(index.get_trait_users("App\\Concerns\\Loggable")),
+        vec![
+            "App\\Concerns\\Cacheable",
+            "App\\Repositories\\UserRepository",
+        ]
+    );
+}
+
+#[test]
+fn it_indexes_enum_cases() {
+    let index = index();
+    let suit = index.get_class("App\\Enums\\Suit").unwrap();
+
+    assert!(suit.is_enum());
+    assert_eq!(
+        suit.get_cases()
+            .iter()
+            .map(|case| case.get_name().to_string())
+            .collect::<Vec<_>>(),
+        vec!["Hearts", "Spades", "Clubs"]
+    );
+    assert!(suit.has_case(b"Clubs".into()));
+    assert!(!suit.has_case(b"Wild".into()));
+    assert!(suit.get_static_method(b"fromChar".into()).is_some());
+}
+
+#[test]
+fn it_reflects_the_implicit_members_of_enums() {
+    let index = index();
+    let suit = index.get_class("App\\Enums\\Suit").unwrap();
+    let size = index.get_class("App\\Enums\\Size").unwrap();
+
+    let return_type = |class: &ReflectionClass, name: &str| {
+        class
+            .get_static_method(name.as_bytes().into())
+            .and_then(|method| method.get_return_type())
+            .map(|r#type| r#type.to_type().clone())
+    };
+
+    assert_eq!(return_type(&suit, "from"), Some(named("App\\Enums\\Suit")));
+    assert_eq!(
+        return_type(&suit, "tryFrom"),
+        Some(Type::Nullable(Box::new(named("App\\Enums\\Suit"))))
+    );
+    assert_eq!(
+        return_type(&size, "cases"),
+        Some(Type::Generic(
+            Box::new(Type::List),
+            vec![argument(named("App\\Enums\\Size"))]
+        ))
+    );
+    assert_eq!(return_type(&size, "from"), None);
+
+    let property_type = |class: &ReflectionClass, name: &str| {
+        class
+            .get_property(name.as_bytes().into())
+            .and_then(|property| property.get_type())
+            .map(|r#type| r#type.to_type().clone())
+    };
+
+    assert_eq!(property_type(&suit, "value"), Some(Type::String));
+    assert_eq!(property_type(&size, "name"), Some(Type::String));
+    assert_eq!(property_type(&size, "value"), None);
+
+    // Implicit members aren't declared by the enum.
+    assert!(suit
+        .get_methods()
+        .iter()
+        .all(|method| method.get_name() != b"from"));
+    assert!(!size.has_properties());
+}
+
+#[test]
+fn it_indexes_class_constants() {
+    let index = index();
+    let version = index.get_class("App\\Constants\\HasVersion").unwrap();
+
+    assert!(version.is_trait());
+    assert_eq!(
+        version
+            .get_constants()
+            .iter()
+            .map(|constant| constant.get_name().to_string())
+            .collect::<Vec<_>>(),
+        vec!["MAJOR", "MINOR", "PATCH"]
+    );
+
+    let minor = version.get_constant(b"MINOR".into()).unwrap();
+
+    assert!(minor.is_final());
+    assert!(minor.get_type().unwrap().is(&Type::Integer));
+    assert!(version
+        .get_constant(b"MAJOR".into())
+        .unwrap()
+        .get_type()
+        .is_none());
+
+    let suit = index.get_class("App\\Enums\\Suit").unwrap();
+
+    assert!(suit.has_constant(b"Wild".into()));
+    assert!(!suit.has_constant(b"Hearts".into()));
+}
+
+#[test]
+fn it_indexes_class_modifiers() {
+    let index = index();
+    let version = index.get_class("App\\Constants\\Version").unwrap();
+
+    assert!(version.is_readonly());
+    assert!(version.is_final());
+    assert!(!version.is_abstract());
+
+    let release = index.get_class("App\\Constants\\Release").unwrap();
+
+    assert!(release.is_abstract());
+    assert!(!release.is_final());
+    assert!(!release.is_readonly());
+
+    assert!(index.get_class("App\\Enums\\Suit").unwrap().is_final());
+}
+
+#[test]
+fn it_checks_interface_conformance_of_enums() {
+    let index = index();
+
+    assert!(index.implements("App\\Enums\\Suit", "App\\Enums\\HasColor"));
+    assert!(index.implements("App\\Enums\\Suit", "App\\Enums\\HasLabel"));
+    assert!(index.implements("App\\Enums\\Suit", "UnitEnum"));
+    assert!(index.implements("App\\Enums\\Suit", "BackedEnum"));
+    assert!(index.implements("App\\Enums\\Size", "UnitEnum"));
+    assert!(!index.implements("App\\Enums\\Size", "BackedEnum"));
+    assert!(index.implements(
+        "App\\Repositories\\SuperAdminRepository",
+        "App\\Contracts\\Repository"
+    ));
+
+    assert_eq!(
+        names(index.get_implementations("BackedEnum")),
+        vec!["App\\Enums\\Suit"]
+    );
+}
+
+#[test]
+fn it_indexes_properties() {
+    let index = index();
+    let labels = index.get_class("App\\Enums\\Labels").unwrap();
+
+    assert!(labels.has_properties());
+    assert_eq!(
+        labels
+            .get_property_names()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>(),
+        vec!["prefix"]
+    );
+    assert!(!index
+        .get_class("App\\Enums\\Size")
+        .unwrap()
+        .has_properties());
+}
+
+#[test]
+fn it_indexes_promoted_properties() {
+    let index = index();
+    let post = index.get_class("App\\Promoted\\Post").unwrap();
+
+    let slug = post.get_property(b"slug".into()).unwrap();
+    assert_eq!(
+        slug.get_type().map(|r#type| r#type.to_type().clone()),
+        Some(Type::String)
+    );
+    assert!(slug.is_private());
+    assert!(slug.is_readonly());
+
+    let count = post.get_property(b"count".into()).unwrap();
+    assert_eq!(
+        count.get_type().map(|r#type| r#type.to_type().clone()),
+        Some(Type::Integer)
+    );
+    assert!(count.is_protected());
+    assert!(!count.is_readonly());
+
+    let parent = post.get_property(b"parent".into()).unwrap();
+    assert_eq!(
+        parent.get_type().map(|r#type| r#type.to_type().clone()),
+        Some(Type::Nullable(Box::new(named("App\\Promoted\\Post"))))
+    );
+    assert!(parent.is_public());
+}
+
+#[test]
+fn it_refines_native_types_with_docblock_types() {
+    let index = index();
+    let refined = index.get_function("App\\Signatures\\refined").unwrap();
+    let signature = refined.effective_signature();
+    let parameters = signature.get_parameters();
+
+    assert_eq!(
+        parameters[0].get_type(),
+        Some(&Type::Generic(
+            Box::new(Type::Array),
+            vec![
+                argument(Type::Integer),
+                argument(named("App\\Models\\User"))
+            ]
+        ))
+    );
+    assert_eq!(parameters[0].get_native_type(), Some(&Type::Array));
+
+    assert_eq!(parameters[1].get_type(), Some(&Type::Integer));
+    assert!(parameters[1].is_optional());
+    assert!(parameters[1].is_by_reference());
+
+    assert_eq!(parameters[2].get_type(), Some(&Type::String));
+    assert!(parameters[2].is_variadic());
+
+    assert_eq!(
+        signature.get_return_type(),
+        Some(&Type::Generic(
+            Box::new(Type::List),
+            vec![argument(Type::String)]
+        ))
+    );
+    assert!(signature.get_diagnostics().is_empty());
+
+    // The native return type is still available through reflection.
+    assert!(refined.get_return_type().unwrap().is(&Type::Array));
+}
+
+#[test]
+fn it_keeps_native_types_when_docblock_types_conflict() {
+    let index = index();
+    let conflicting = index.get_function("App\\Signatures\\conflicting").unwrap();
+    let signature = conflicting.effective_signature();
+
+    assert_eq!(
+        signature.get_parameter(b"id".into()).unwrap().get_type(),
+        Some(&Type::Integer)
+    );
+    assert_eq!(signature.get_return_type(), Some(&Type::String));
+
+    assert_eq!(
+        signature
+            .get_diagnostics()
+            .iter()
+            .map(|diagnostic| diagnostic.kind.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            IndexDiagnostic::IncompatibleDocblockParameterType {
+                parameter: b"id".into(),
+                native: Type::Integer,
+                docblock: Type::String,
+            },
+            IndexDiagnostic::IncompatibleDocblockReturnType {
+                native: Type::String,
+                docblock: Type::Integer,
+            },
+        ]
+    );
+    assert!(signature
+        .get_diagnostics()
+        .iter()
+        .all(|diagnostic| !diagnostic.severity.is_error()));
+    assert_eq!(
+        signature.get_diagnostics()[0].kind.get_message(),
+        "docblock type string for parameter $id is not compatible with native type int"
+    );
+}
+
+#[test]
+fn it_uses_native_types_without_a_docblock() {
+    let index = index();
+    let undocumented = index.get_function("App\\Signatures\\undocumented").unwrap();
+    let signature = undocumented.effective_signature();
+
+    assert_eq!(
+        signature.get_parameters()[0].get_type(),
+        Some(&Type::Nullable(Box::new(Type::Integer)))
+    );
+    assert_eq!(signature.get_parameters()[1].get_type(), None);
+    assert_eq!(signature.get_return_type(), Some(&Type::Iterable));
+    assert!(signature.get_diagnostics().is_empty());
+
+    // Signatures are computed once and then reused.
+    assert!(std::ptr::eq(signature, undocumented.effective_signature()));
+
+    let untyped = index.get_function("App\\Signatures\\untyped").unwrap();
+    let signature = untyped.effective_signature();
+
+    assert_eq!(
+        signature.get_parameters()[0].get_type(),
+        Some(&named("App\\Models\\User"))
+    );
+    assert_eq!(signature.get_parameters()[1].get_type(), None);
+    assert_eq!(signature.get_return_type(), Some(&Type::NonEmptyString));
+}
+
+#[test]
+fn it_refines_method_return_types_with_docblock_types() {
+    let index = index();
+    let builder = index.get_class("App\\Signatures\\Builder").unwrap();
+
+    assert_eq!(
+        builder
+            .get_method(b"where".into())
+            .unwrap()
+            .effective_signature()
+            .get_return_type(),
+        Some(&Type::This)
+    );
+    assert_eq!(
+        builder
+            .get_method(b"first".into())
+            .unwrap()
+            .effective_signature()
+            .get_return_type(),
+        Some(&named("App\\Models\\User"))
+    );
+}
+
+#[test]
+fn it_indexes_properties_and_virtual_members() {
+    let index = index();
+    let post = index.get_class("App\\Magic\\Post").unwrap();
+
+    let count = post.get_property(b"count".into()).unwrap();
+    assert!(count.is_static());
+    assert!(count.get_type().unwrap().is(&Type::Integer));
+
+    // Promoted constructor parameters are properties, other parameters aren't.
+    let id = post.get_property(b"id".into()).unwrap();
+    assert!(!id.is_static());
+    assert!(id.get_type().unwrap().is(&Type::Integer));
+    assert!(post.get_property(b"body".into()).is_none());
+
+    let title = post.get_virtual_property(b"title".into()).unwrap();
+    assert!(title.get_type().unwrap().is(&Type::String));
+    assert!(post.get_property(b"title".into()).is_none());
+
+    let find = post.get_virtual_method(b"FIND".into()).unwrap();
+    assert!(find.is_static());
+    assert_eq!(
+        find.get_return_type().unwrap().to_type(),
+        &named("App\\Magic\\Post")
+    );
+
+    let save = post.get_virtual_method(b"save".into()).unwrap();
+    assert!(!save.is_static());
+    assert!(save.get_return_type().unwrap().is(&Type::Boolean));
+    assert!(post.get_method(b"save".into()).is_none());
+}
+
+#[test]
+fn it_indexes_the_parameters_of_virtual_methods() {
+    let index = index();
+    let post = index.get_class("App\\Magic\\Post").unwrap();
+
+    let find = post.get_virtual_method(b"find".into()).unwrap();
+    assert_eq!(find.get_number_of_parameters(), 1);
+    assert_eq!(find.get_number_of_required_parameters(), 1);
+
+    let r#where = post.get_virtual_method(b"where".into()).unwrap();
+    let parameters = r#where.get_parameters();
+
+    assert_eq!(
+        parameters
+            .iter()
+            .map(|parameter| parameter.get_name().to_string())
+            .collect::<Vec<_>>(),
+        vec!["column", "value", "columns"]
+    );
+    assert!(!parameters[0].is_optional());
+    assert!(parameters[1].is_optional());
+    assert!(parameters[2].is_variadic());
+
+    let signature = r#where.effective_signature();
+    assert_eq!(signature.get_parameters()[1].get_type(), Some(&Type::Mixed));
+    assert_eq!(
+        signature.get_return_type(),
+        Some(&Type::TypedArray(
+            Box::new(Type::Union(vec![Type::String, Type::Integer])),
+            Box::new(named("App\\Magic\\Post"))
+        ))
+    );
+}
+
+#[test]
+fn it_indexes_read_only_and_write_only_virtual_properties() {
+    let index = index();
+    let post = index.get_class("App\\Magic\\Post").unwrap();
+
+    let title = post.get_virtual_property(b"title".into()).unwrap();
+    assert!(title.is_readable() && title.is_writable());
+
+    let views = post.get_virtual_property(b"views".into()).unwrap();
+    assert!(views.is_readable() && !views.is_writable());
+
+    let slug = post.get_virtual_property(b"slug".into()).unwrap();
+    assert!(!slug.is_readable() && slug.is_writable());
+}
+
+#[test]
+fn it_indexes_deprecations() {
+    let index = index();
+    let client = index.get_class("App\\Deprecations\\LegacyClient").unwrap();
+
+    assert_eq!(
+        client.get_deprecation_message().unwrap(),
+        b"Use Client instead."
+    );
+
+    let handle = client.get_property(b"handle".into()).unwrap();
+    assert_eq!(handle.get_deprecation_message().unwrap(), b"");
+    assert!(!client
+        .get_property(b"timeout".into())
+        .unwrap()
+        .is_deprecated());
+
+    let version = client.get_constant(b"VERSION".into()).unwrap();
+    assert_eq!(version.get_deprecation_message().unwrap(), b"since 2.0");
+
+    // Messages can also come from the `#[\Deprecated]` attribute.
+    let post = client.get_method(b"post".into()).unwrap();
+    assert_eq!(
+        post.get_deprecation_message().unwrap(),
+        b"Use send() instead (since 1.5)"
+    );
+
+    let get = client.get_method(b"get".into()).unwrap();
+    assert_eq!(
+        get.get_deprecation_message().unwrap(),
+        b"Use send() instead"
+    );
+    assert!(!client.get_method(b"send".into()).unwrap().is_deprecated());
+
+    let connect = index.get_function("App\\Deprecations\\connect").unwrap();
+    assert_eq!(connect.get_deprecation_message().unwrap(), b"");
+
+    let disconnect = index.get_function("App\\Deprecations\\disconnect").unwrap();
+    assert!(disconnect.is_deprecated());
+
+    let reconnect = index.get_function("App\\Deprecations\\reconnect").unwrap();
+    assert!(!reconnect.is_deprecated());
+}
+
+#[test]
+fn it_indexes_documented_exceptions() {
+    let index = index();
+
+    let write = index.get_function("App\\Throws\\write").unwrap();
+    assert_eq!(
+        write
+            .get_throws()
+            .iter()
+            .map(|name| name.resolved.to_string())
+            .collect::<Vec<_>>(),
+        vec![
+            "App\\Throws\\StorageException",
+            "InvalidArgumentException",
+            "App\\Throws\\DiskFullException"
+        ]
+    );
+
+    let disk = index.get_class("App\\Throws\\Disk").unwrap();
+    let put = disk.get_method(b"put".into()).unwrap();
+    assert_eq!(put.get_throws().len(), 1);
+    assert_eq!(put.get_throws()[0].original, b"DiskFullException");
+    assert!(disk
+        .get_method(b"get".into())
+        .unwrap()
+        .get_throws()
+        .is_empty());
+}
+
+#[test]
+fn it_checks_whether_a_class_is_a_subclass_of_another() {
+    let index = index();
+    let exception = "App\\Throws\\DiskFullException";
+
+    assert!(index.is_subclass_of(exception, "App\\Throws\\StorageException"));
+    assert!(index.is_subclass_of(exception, "App\\Throws\\Retryable"));
+    // Classes that aren't indexed are still matched by name.
+    assert!(index.is_subclass_of(exception, "RuntimeException"));
+    assert!(!index.is_subclass_of(exception, exception));
+    assert!(!index.is_subclass_of("App\\Throws\\StorageException", exception));
+}
+
+#[test]
+fn it_updates_reverse_lookups_when_a_file_is_reindexed() {
+    let path = std::env::temp_dir().join(format!("pxp-index-reindex-{}.php", std::process::id()));
+    let mut builder = IndexBuilder::new();
+
+    std::fs::write(
+        &path,
+        "<?php interface I {} class A implements I {} class B extends A {}",
+    )
+    .unwrap();
+    builder.index_file(&path);
+
+    let index = builder.build();
+
+    assert_eq!(names(index.get_implementations("I")), vec!["A", "B"]);
+    assert_eq!(names(index.get_subclasses("A", false)), vec!["B"]);
+
+    std::fs::write(
+        &path,
+        "<?php interface I {} class A {} class C extends A implements I {}",
+    )
+    .unwrap();
+
+    let updated = index.update_file(&path);
+
+    // The original index is left as it was.
+    assert_eq!(names(index.get_implementations("I")), vec!["A", "B"]);
+
+    let index = updated;
+
+    assert_eq!(index.number_of_classes(), 3);
+    assert_eq!(names(index.get_implementations("I")), vec!["C"]);
+    assert_eq!(names(index.get_subclasses("A", false)), vec!["C"]);
+    assert!(index.get_class("B").is_none());
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn it_updates_a_file_without_changing_the_original_index() {
+    let parse = |code: &str| Parser::parse(Lexer::new(code)).ast;
+
+    let mut builder = IndexBuilder::new();
+    builder.index(FileId::new(0), &parse("<?php function a() {}"));
+    builder.index(FileId::new(1), &parse("<?php class A {}"));
+
+    let index = builder.build();
+    let updated = index.update(FileId::new(1), &parse("<?php class B extends A {}"));
+
+    assert!(index.get_class("A").is_some());
+    assert!(index.get_class("B").is_none());
+
+    assert!(updated.get_function("a").is_some());
+    assert!(updated.get_class("A").is_none());
+    assert_eq!(names(updated.get_subclasses("A", false)), vec!["B"]);
+    assert!(index.get_subclasses("A", false).is_empty());
+}
+
+#[test]
+fn it_hashes_the_signatures_that_other_code_can_see() {
+    let parse = |code: &str| Parser::parse(Lexer::new(code)).ast;
+
+    let mut builder = IndexBuilder::new();
+    builder.index(
+        FileId::new(0),
+        &parse("<?php function a(int $x): int { return $x; } class A {} class B extends A {}"),
+    );
+    let index = builder.build();
+
+    let moved = index.update(
+        FileId::new(0),
+        &parse("<?php\n\nfunction a(int $x): int { return $x * 2; }\n\nclass A {} class B extends A {}"),
+    );
+    let changed = index.update(
+        FileId::new(0),
+        &parse("<?php function a(int $x): string { return $x; } class A { public $a; } class B extends A {}"),
+    );
+
+    assert!(index.function_signature_hash("a").is_some());
+    assert!(index.function_signature_hash("missing").is_none());
+    assert!(index.class_signature_hash("missing").is_none());
+
+    // Moving a declaration or changing its body doesn't change what other code can see.
+    assert_eq!(
+        index.function_signature_hash("a"),
+        moved.function_signature_hash("a")
+    );
+    assert_eq!(
+        index.class_signature_hash("B"),
+        moved.class_signature_hash("B")
+    );
+
+    assert_ne!(
+        index.function_signature_hash("a"),
+        changed.function_signature_hash("a")
+    );
+    assert_ne!(
+        index.class_signature_hash("A"),
+        changed.class_signature_hash("A")
+    );
+    // A class' hash covers the classes it extends.
+    assert_ne!(
+        index.class_signature_hash("B"),
+        changed.class_signature_hash("B")
+    );
+}
+
+#[test]
+fn it_can_be_shared_between_threads() {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<Index>();
+
+    let index = index();
+
+    std::thread::scope(|scope| {
+        let handles = (0..4)
+            .map(|_| scope.spawn(|| index.number_of_classes()))
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), index.number_of_classes());
+        }
+    });
+}
+
+#[test]
+fn it_lists_the_members_visible_from_outside_of_a_class() {
+    let index = index();
+    let members = index.members_of(&named("App\\Members\\Square"), AccessContext::Global);
+
+    assert_eq!(
+        members_summary(&members),
+        vec![
+            "method __construct App\\Members\\Square",
+            "method area App\\Members\\Square",
+            "property side App\\Members\\Square",
+            "constant SIDES App\\Members\\Square",
+            "method describe App\\Members\\Base",
+            "method summary App\\Members\\Base",
+            "method getName App\\Members\\Named",
+        ]
+    );
+
+    let summary = members
+        .iter()
+        .find(|member| member.get_name() == b"summary")
+        .unwrap();
+
+    assert!(summary.is_deprecated());
+    assert!(!summary.is_static());
+    assert_eq!(summary.get_type(), Some(&Type::String));
+    assert!(summary.get_signature().unwrap().get_parameters().is_empty());
+}
+
+#[test]
+fn it_lists_protected_and_private_members_from_inside_of_a_class() {
+    let index = index();
+
+    let inside = index.members_of(
+        &named("App\\Members\\Square"),
+        AccessContext::Class("App\\Members\\Square".into()),
+    );
+
+    assert_eq!(
+        members_summary(&inside),
+        vec![
+            "method __construct App\\Members\\Square",
+            "method area App\\Members\\Square",
+            "method scale App\\Members\\Square",
+            "property side App\\Members\\Square",
+            "constant SIDES App\\Members\\Square",
+            "method describe App\\Members\\Base",
+            "method summary App\\Members\\Base",
+            "property count App\\Members\\Base",
+            "method getName App\\Members\\Named",
+        ]
+    );
+
+    // Private members of a trait belong to the class that uses it.
+    let base = index.members_of(
+        &named("App\\Members\\Base"),
+        AccessContext::Class("App\\Members\\Base".into()),
+    );
+
+    assert_eq!(
+        members_summary(&base),
+        vec![
+            "method area App\\Members\\Base",
+            "method describe App\\Members\\Base",
+            "method summary App\\Members\\Base",
+            "method scale App\\Members\\Base",
+            "method reset App\\Members\\Base",
+            "property count App\\Members\\Base",
+            "constant SECRET App\\Members\\Base",
+            "method getName App\\Members\\Named",
+            "method setName App\\Members\\Named",
+            "property name App\\Members\\Named",
+            "constant SIDES App\\Members\\Shape",
+        ]
+    );
+
+    // Protected members are visible from sibling classes that share the declaring class.
+    let sibling = index.members_of(
+        &named("App\\Members\\Square"),
+        AccessContext::Class("App\\Members\\Triangle".into()),
+    );
+
+    assert!(sibling
+        .iter()
+        .any(|member| member.get_name() == b"count" && member.is_static()));
+    assert!(!sibling.iter().any(|member| member.get_name() == b"reset"));
+}
+
+#[test]
+fn it_flags_members_missing_from_part_of_a_union() {
+    let index = index();
+    let members = index.members_of(
+        &Type::Union(vec![
+            named("App\\Members\\Square"),
+            named("App\\Members\\Circle"),
+            Type::Null,
+        ]),
+        AccessContext::Global,
+    );
+
+    let partial = |name: &str| {
+        members
+            .iter()
+            .find(|member| member.get_name().to_string() == name)
+            .map(|member| member.is_partial())
+    };
+
+    assert_eq!(partial("area"), Some(false));
+    assert_eq!(partial("describe"), Some(false));
+    assert_eq!(partial("SIDES"), Some(false));
+    assert_eq!(partial("summary"), Some(true));
+    assert_eq!(partial("radius"), Some(true));
+    assert_eq!(partial("side"), Some(true));
+
+    // The most-derived declaration of `area` is the one from the first type in the union.
+    assert_eq!(
+        members
+            .iter()
+            .find(|member| member.get_name() == b"area")
+            .unwrap()
+            .get_declaring_class(),
+        b"App\\Members\\Square"
+    );
+}
+
+#[test]
+fn it_merges_the_members_of_an_intersection() {
+    let index = index();
+    let members = index.members_of(
+        &Type::Intersection(vec![
+            named("App\\Members\\Circle"),
+            named("App\\Members\\Triangle"),
+        ]),
+        AccessContext::Global,
+    );
+
+    assert!(members.iter().all(|member| !member.is_partial()));
+    assert!(members.iter().any(|member| member.get_name() == b"radius"));
+    assert!(members.iter().any(|member| member.get_name() == b"getName"));
+    assert_eq!(
+        members
+            .iter()
+            .filter(|member| member.get_name() == b"area")
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn it_lists_the_implicit_members_of_enums() {
+    let index = index();
+    let members = index.members_of(&named("App\\Enums\\Suit"), AccessContext::Global);
+
+    assert_eq!(
+        members_summary(&members),
+        vec![
+            "case Hearts App\\Enums\\Suit",
+            "case Spades App\\Enums\\Suit",
+            "case Clubs App\\Enums\\Suit",
+            "method cases App\\Enums\\Suit",
+            "method from App\\Enums\\Suit",
+            "method tryFrom App\\Enums\\Suit",
+            "method fromChar App\\Enums\\Suit",
+            "method color App\\Enums\\Suit",
+            "property name App\\Enums\\Suit",
+            "property value App\\Enums\\Suit",
+            "constant Wild App\\Enums\\Suit",
+        ]
+    );
+
+    let r#type = |name: &str| {
+        members
+            .iter()
+            .find(|member| member.get_name().to_string() == name)
+            .and_then(|member| member.get_type().cloned())
+            .unwrap()
+    };
+
+    assert_eq!(
+        r#type("cases"),
+        Type::Generic(
+            Box::new(Type::List),
+            vec![argument(named("App\\Enums\\Suit"))]
+        )
+    );
+    assert_eq!(
+        r#type("tryFrom"),
+        Type::Nullable(Box::new(named("App\\Enums\\Suit")))
+    );
+    assert_eq!(r#type("value"), Type::String);
+
+    let size =
+        members_summary(&index.members_of(&named("App\\Enums\\Size"), AccessContext::Global));
+
+    assert_eq!(
+        size,
+        vec![
+            "case Small App\\Enums\\Size",
+            "case Large App\\Enums\\Size",
+            "method cases App\\Enums\\Size",
+            "property name App\\Enums\\Size",
+            "property prefix App\\Enums\\Labels",
+        ]
+    );
+}
+
+#[test]
+fn it_lists_virtual_members_after_real_members() {
+    let index = index();
+    let members = index.members_of(&named("App\\Magic\\Post"), AccessContext::Global);
+
+    assert_eq!(
+        members
+            .iter()
+            .map(|member| format!(
+                "{}{}",
+                if member.is_virtual() { "@" } else { "" },
+                member.get_name()
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            "__construct",
+            "__get",
+            "count",
+            "id",
+            "@find",
+            "@where",
+            "@save",
+            "@title",
+            "@views",
+            "@slug",
+        ]
+    );
+}
+
+fn members_summary(members: &[MemberCandidate]) -> Vec<String> {
+    members
+        .iter()
+        .map(|member| {
+            let kind = match member.get_kind() {
+                MemberKind::Method => "method",
+                MemberKind::Property => "property",
+                MemberKind::Constant => "constant",
+                MemberKind::EnumCase => "case",
+            };
+
+            format!(
+                "{} {} {}",
+                kind,
+                member.get_name(),
+                member.get_declaring_class()
+            )
+        })
+        .collect()
+}
+
+fn names(classes: Vec<ReflectionClass>) -> Vec<String> {
+    let mut names = classes
+        .iter()
+        .map(|class| class.name().to_string())
+        .collect::<Vec<_>>();
+
+    names.sort();
+    names
+}
+
+fn named(name: &str) -> Type<ResolvedName> {
+    Type::Named(ResolvedName {
+        resolved: name.into(),
+        original: name.rsplit('\\').next().unwrap().into(),
+    })
+}
+
+fn argument(r#type: Type<ResolvedName>) -> GenericTypeArgument<ResolvedName> {
+    GenericTypeArgument {
+        r#type,
+        variance: None,
+    }
+}
+
 fn index() -> Index {
-    let mut index = Index::new();
+    let mut builder = IndexBuilder::new();
     let files = discover(&["php"], &["./tests/fixtures"]).expect("Failed to load fixture files.");
 
     for file in files.iter() {
-        index.index_file(&file);
+        builder.index_file(file);
     }
 
-    index
+    builder.build()
 }
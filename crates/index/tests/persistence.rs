@@ -0,0 +1,181 @@
+use std::path::PathBuf;
+
+use discoverer::discover;
+use pxp_bytestring::ByteStr;
+use pxp_index::{Index, IndexBuilder, PersistenceError};
+
+#[test]
+fn it_round_trips_an_index() {
+    let index = index();
+    let path = temp_path("round-trip.idx");
+
+    index.save(&path).unwrap();
+
+    let loaded = IndexBuilder::load(&path).unwrap();
+
+    assert!(loaded.stale.is_empty());
+    assert_eq!(loaded.builder.build(), index);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn it_round_trips_extra_data_saved_with_an_index() {
+    let index = index();
+    let path = temp_path("extension.idx");
+
+    index.save_with(&path, b"extra").unwrap();
+
+    let loaded = IndexBuilder::load(&path).unwrap();
+
+    assert_eq!(loaded.extension, b"extra");
+    assert_eq!(loaded.builder.build(), index);
+
+    index.save(&path).unwrap();
+
+    assert!(IndexBuilder::load(&path).unwrap().extension.is_empty());
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn it_round_trips_names_that_are_not_valid_utf8() {
+    let directory = temp_directory("bytes");
+    let file = directory.join("bytes.php");
+    let cache = directory.join("bytes.idx");
+
+    std::fs::write(
+        &file,
+        b"<?php class Caf\xe9 { public function \xff\xfe() {} }",
+    )
+    .unwrap();
+
+    let mut builder = IndexBuilder::new();
+    builder.index_file(&file);
+
+    let index = builder.build();
+    index.save(&cache).unwrap();
+
+    let loaded = IndexBuilder::load(&cache).unwrap().builder.build();
+    let class = loaded.get_class(&b"Caf\xe9"[..]).unwrap();
+
+    assert!(class.get_method(ByteStr::new(b"\xff\xfe")).is_some());
+    assert_eq!(loaded, index);
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn it_drops_entities_from_files_that_changed_since_the_index_was_saved() {
+    let directory = temp_directory("stale");
+    let unchanged = directory.join("unchanged.php");
+    let changed = directory.join("changed.php");
+    let deleted = directory.join("deleted.php");
+    let cache = directory.join("stale.idx");
+
+    std::fs::write(&unchanged, "<?php interface I {} function a() {}").unwrap();
+    std::fs::write(&changed, "<?php class A implements I {}").unwrap();
+    std::fs::write(&deleted, "<?php class B extends A {}").unwrap();
+
+    let mut builder = IndexBuilder::new();
+
+    for file in [&unchanged, &changed, &deleted] {
+        builder.index_file(file);
+    }
+
+    builder.build().save(&cache).unwrap();
+
+    std::fs::write(&changed, "<?php class C implements I {}").unwrap();
+    std::fs::remove_file(&deleted).unwrap();
+
+    let loaded = IndexBuilder::load(&cache).unwrap();
+    let index = loaded.builder.build();
+
+    assert_eq!(loaded.stale, vec![changed.clone()]);
+    assert!(index.get_function("a").is_some());
+    assert!(index.get_class("I").is_some());
+    assert!(index.get_class("A").is_none());
+    assert!(index.get_class("B").is_none());
+    assert!(index.get_implementations("I").is_empty());
+
+    let index = loaded
+        .stale
+        .iter()
+        .fold(index, |index, file| index.update_file(file));
+
+    assert_eq!(index.number_of_files(), 3);
+    assert_eq!(index.get_implementations("I").len(), 1);
+    assert!(index.get_class("C").is_some());
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn it_reports_truncated_indexes() {
+    let path = temp_path("truncated.idx");
+
+    index().save(&path).unwrap();
+
+    let bytes = std::fs::read(&path).unwrap();
+
+    for length in (0..bytes.len()).step_by(7) {
+        std::fs::write(&path, &bytes[..length]).unwrap();
+
+        assert!(
+            matches!(
+                IndexBuilder::load(&path),
+                Err(PersistenceError::Truncated | PersistenceError::Malformed(_))
+            ),
+            "loading {} of {} bytes",
+            length,
+            bytes.len()
+        );
+    }
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn it_reports_files_that_are_not_indexes() {
+    let path = temp_path("invalid.idx");
+
+    std::fs::write(&path, "<?php echo 'Hello, world!';").unwrap();
+    assert!(matches!(
+        IndexBuilder::load(&path),
+        Err(PersistenceError::NotAnIndex)
+    ));
+
+    std::fs::write(&path, b"PXPI\xff\x00\x00\x00").unwrap();
+    assert!(matches!(
+        IndexBuilder::load(&path),
+        Err(PersistenceError::UnsupportedVersion { found: 255, .. })
+    ));
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(matches!(
+        IndexBuilder::load(&path),
+        Err(PersistenceError::Io(_))
+    ));
+}
+
+fn index() -> Index {
+    let mut builder = IndexBuilder::new();
+    let files = discover(&["php"], &["./tests/fixtures"]).expect("Failed to load fixture files.");
+
+    for file in files.iter() {
+        builder.index_file(file);
+    }
+
+    builder.build()
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("pxp-index-{}-{}", std::process::id(), name))
+}
+
+fn temp_directory(name: &str) -> PathBuf {
+    let directory = temp_path(name);
+    std::fs::create_dir_all(&directory).unwrap();
+    directory
+}
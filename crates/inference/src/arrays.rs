@@ -0,0 +1,219 @@
+use std::fmt::{Debug, Display};
+
+use pxp_ast::{constants::ConstantValue, Expression};
+use pxp_bytestring::ByteString;
+use pxp_type::{ShapeItem, ShapeItemKey, Type};
+
+/// Literal arrays with more entries than this are tracked as `array<K, V>` instead of a shape, so
+/// that large lookup tables don't make the type map grow without bound.
+pub(crate) const MAX_SHAPE_SIZE: usize = 32;
+
+/// The key of an array element, after PHP has converted strings that contain a decimal integer
+/// into integers.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ArrayKey {
+    Integer(i64),
+    String(ByteString),
+}
+
+impl ArrayKey {
+    /// Create the key for a string, which becomes an integer if it's written like one.
+    pub fn new(key: &[u8]) -> Self {
+        match std::str::from_utf8(key).ok().and_then(canonical_integer) {
+            Some(integer) => ArrayKey::Integer(integer),
+            None => ArrayKey::String(key.into()),
+        }
+    }
+
+    /// Create the key that PHP uses when the given value is used as an array key.
+    ///
+    /// Floats are truncated, `true` and `false` become `1` and `0`, and `null` becomes `""`.
+    /// Floats that don't fit in an integer and values that can't be keys return `None`.
+    pub fn from_constant(value: &ConstantValue) -> Option<Self> {
+        match value {
+            ConstantValue::Null => Some(ArrayKey::String(ByteString::empty())),
+            ConstantValue::Bool(value) => Some(ArrayKey::Integer(*value as i64)),
+            ConstantValue::Int(value) => Some(ArrayKey::Integer(*value)),
+            ConstantValue::Float(value) => {
+                let truncated = value.trunc();
+
+                (truncated.is_finite()
+                    && truncated >= i64::MIN as f64
+                    && truncated < i64::MAX as f64)
+                    .then_some(ArrayKey::Integer(truncated as i64))
+            }
+            ConstantValue::String(value) => Some(ArrayKey::new(value)),
+            _ => None,
+        }
+    }
+
+    /// Create the key for an expression whose value is known without running the code.
+    pub fn from_expression(expression: &Expression) -> Option<Self> {
+        Self::from_constant(&expression.evaluate_constant().ok()?)
+    }
+
+    fn to_shape_key(&self) -> ShapeItemKey {
+        match self {
+            ArrayKey::Integer(integer) => ShapeItemKey::Integer(integer.to_string().into()),
+            ArrayKey::String(string) => ShapeItemKey::String(string.clone()),
+        }
+    }
+}
+
+/// Only integers written the way PHP would print them are converted, so `"05"` stays a string.
+fn canonical_integer(key: &str) -> Option<i64> {
+    let integer = key.parse::<i64>().ok()?;
+
+    (integer.to_string() == key).then_some(integer)
+}
+
+/// The elements of a sealed array shape, in the order that they were added.
+#[derive(Debug, Clone)]
+pub(crate) struct Shape<N: Debug + Display> {
+    entries: Vec<(ArrayKey, Type<N>)>,
+}
+
+impl<N: Debug + Display + Clone> Shape<N> {
+    pub(crate) fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Read the entries of a sealed shape type. Items without a key are numbered like a list.
+    pub(crate) fn from_type(r#type: &Type<N>) -> Option<Self> {
+        let Type::Shaped {
+            items,
+            sealed: true,
+            ..
+        } = r#type
+        else {
+            return None;
+        };
+
+        // Optional items can't be represented yet, so they're treated as unknown.
+        if items.iter().any(|item| item.optional) {
+            return None;
+        }
+
+        let mut shape = Shape::new();
+
+        for item in items {
+            let key = item.key_name.as_ref().map(|key| match key {
+                ShapeItemKey::Integer(key) | ShapeItemKey::String(key) => {
+                    ArrayKey::new(key.as_bytestr().strip_string_quotes())
+                }
+            });
+
+            shape.insert(key, item.value_type.clone());
+        }
+
+        Some(shape)
+    }
+
+    /// Set the value of the given key, like `$array[$key] = $value`. Without a key, the value is
+    /// appended using the next integer key.
+    pub(crate) fn insert(&mut self, key: Option<ArrayKey>, r#type: Type<N>) {
+        let key = key.unwrap_or_else(|| ArrayKey::Integer(self.next_index()));
+
+        match self
+            .entries
+            .iter_mut()
+            .find(|(existing, _)| *existing == key)
+        {
+            Some((_, existing)) => *existing = r#type,
+            None => self.entries.push((key, r#type)),
+        }
+    }
+
+    fn next_index(&self) -> i64 {
+        self.entries
+            .iter()
+            .filter_map(|(key, _)| match key {
+                ArrayKey::Integer(integer) => Some(integer + 1),
+                ArrayKey::String(_) => None,
+            })
+            .max()
+            .unwrap_or(0)
+            .max(0)
+    }
+
+    /// Remove the given key, like `unset($array[$key])`.
+    pub(crate) fn remove(&mut self, key: &ArrayKey) {
+        self.entries.retain(|(existing, _)| existing != key);
+    }
+
+    pub(crate) fn get(&self, key: &ArrayKey) -> Option<&Type<N>> {
+        self.entries
+            .iter()
+            .find(|(existing, _)| existing == key)
+            .map(|(_, r#type)| r#type)
+    }
+
+    /// The result of `$left + $right`, where keys that are already in the left array win.
+    pub(crate) fn union(mut self, other: Shape<N>) -> Self {
+        for (key, r#type) in other.entries {
+            if self.get(&key).is_none() {
+                self.entries.push((key, r#type));
+            }
+        }
+
+        self
+    }
+
+    /// The result of `array_merge($left, $right)`, where string keys are overwritten and integer keys
+    /// are renumbered.
+    pub(crate) fn merge(self, other: Shape<N>) -> Self {
+        let mut merged = Shape::new();
+
+        for (key, r#type) in self.entries.into_iter().chain(other.entries) {
+            match key {
+                ArrayKey::Integer(_) => merged.insert(None, r#type),
+                key => merged.insert(Some(key), r#type),
+            }
+        }
+
+        merged
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// The types of the keys and values, for when the shape has to fall back to `array<K, V>`.
+    pub(crate) fn key_and_value_types(&self) -> (Vec<Type<N>>, Vec<Type<N>>) {
+        self.entries
+            .iter()
+            .map(|(key, r#type)| {
+                let key = match key {
+                    ArrayKey::Integer(_) => Type::Integer,
+                    ArrayKey::String(key) => Type::LiteralString(key.clone()),
+                };
+
+                (key, r#type.clone())
+            })
+            .unzip()
+    }
+
+    /// Convert the shape into a type, or `None` if it has too many entries to be tracked.
+    pub(crate) fn into_type(self) -> Option<Type<N>> {
+        if self.len() > MAX_SHAPE_SIZE {
+            return None;
+        }
+
+        Some(Type::Shaped {
+            base: Box::new(Type::Array),
+            items: self
+                .entries
+                .into_iter()
+                .map(|(key, value_type)| ShapeItem {
+                    key_name: Some(key.to_shape_key()),
+                    value_type,
+                    optional: false,
+                })
+                .collect(),
+            sealed: true,
+            unsealed_type: None,
+        })
+    }
+}
@@ -0,0 +1,616 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+};
+
+use pxp_ast::{
+    visitor::{
+        walk_control_flow::{
+            walk_return_statement, walk_yield_expression, walk_yield_from_expression,
+        },
+        ControlFlowVisitor, Traversal,
+    },
+    AnonymousClassExpression, ArrowFunctionExpression, ClosureExpression, Fingerprinter,
+    FunctionStatement, NodeId, ResolvedName, ReturnStatement, Statement, YieldExpression,
+    YieldFromExpression,
+};
+use pxp_bytestring::ByteString;
+use pxp_index::{
+    Decoder, Encoder, FileId, Index, PersistenceError, ReflectionClass, ReflectionFunction,
+};
+use pxp_type::Type;
+
+use crate::map::{MapEntries, VirtualMember};
+
+/// Bumped whenever the layout of an encoded cache changes.
+const VERSION: u32 = 1;
+
+/// A function or class-like that a unit looked up in the `Index` while it was being inferred.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum Dependency {
+    Function(ByteString),
+    Class(ByteString),
+}
+
+impl Dependency {
+    fn sort_key(&self) -> (u8, &[u8]) {
+        match self {
+            Dependency::Function(name) => (0, name),
+            Dependency::Class(name) => (1, name),
+        }
+    }
+
+    /// The hash of the dependency's signature in the given index, or `None` if it doesn't exist.
+    fn signature_hash(&self, index: &Index) -> Option<u64> {
+        match self {
+            Dependency::Function(name) => index.function_signature_hash(name.clone()),
+            Dependency::Class(name) => index.class_signature_hash(name.clone()),
+        }
+    }
+}
+
+/// Wraps the `Index` so that the symbols looked up while a unit is inferred can be recorded as
+/// its dependencies.
+pub(crate) struct TrackedIndex<'a> {
+    index: &'a Index,
+    lookups: RefCell<Option<HashSet<Dependency>>>,
+}
+
+impl<'a> TrackedIndex<'a> {
+    pub(crate) fn new(index: &'a Index) -> Self {
+        Self {
+            index,
+            lookups: RefCell::new(None),
+        }
+    }
+
+    pub(crate) fn inner(&self) -> &'a Index {
+        self.index
+    }
+
+    pub(crate) fn get_class(&self, name: impl Into<ByteString>) -> Option<ReflectionClass<'a>> {
+        let name = name.into();
+        self.record(|| Dependency::Class(name.clone()));
+        self.index.get_class(name)
+    }
+
+    pub(crate) fn get_function(
+        &self,
+        name: impl Into<ByteString>,
+    ) -> Option<ReflectionFunction<'a>> {
+        let name = name.into();
+        self.record(|| Dependency::Function(name.clone()));
+        self.index.get_function(name)
+    }
+
+    pub(crate) fn implements(
+        &self,
+        class: impl Into<ByteString>,
+        interface: impl Into<ByteString>,
+    ) -> bool {
+        // The hash of a class covers the interfaces it implements, so the class is enough.
+        let class = class.into();
+        self.record(|| Dependency::Class(class.clone()));
+        self.index.implements(class, interface)
+    }
+
+    fn start_tracking(&self) {
+        *self.lookups.borrow_mut() = Some(HashSet::new());
+    }
+
+    fn finish_tracking(&self) -> HashSet<Dependency> {
+        self.lookups.borrow_mut().take().unwrap_or_default()
+    }
+
+    fn record(&self, dependency: impl FnOnce() -> Dependency) {
+        if let Some(lookups) = self.lookups.borrow_mut().as_mut() {
+            lookups.insert(dependency());
+        }
+    }
+}
+
+/// A function or method, identified by the file it's declared in and its node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct UnitKey {
+    file: FileId,
+    id: NodeId,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct CachedUnit {
+    name: ByteString,
+    // The hash of the source of the file that the unit was inferred from.
+    fingerprint: u64,
+    return_type: Option<Type<ResolvedName>>,
+    // The symbols that the unit looked up, along with the hashes of their signatures at the time.
+    dependencies: Vec<(Dependency, Option<u64>)>,
+    entries: MapEntries,
+}
+
+/// The names of the functions and methods that were inferred, and the ones that were reused from
+/// the cache, since the statistics were last taken.
+///
+/// Functions are named by their fully qualified name, and methods as `Class::method`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CacheStatistics {
+    pub inferred: Vec<ByteString>,
+    pub reused: Vec<ByteString>,
+}
+
+/// Remembers what was inferred for each function and method, so that a unit only has to be
+/// inferred again when its file changes or when the signature of something it used changes.
+///
+/// The code outside of functions and methods is always inferred, since it's rarely expensive and
+/// can't be isolated from the rest of the file. The engine is expected to be configured the same
+/// way on every run that shares a cache.
+#[derive(Debug, Default)]
+pub struct InferenceCache {
+    units: HashMap<UnitKey, CachedUnit>,
+    // The units that depend on each symbol, so that stale units can be found without checking all of them.
+    dependents: HashMap<Dependency, HashSet<UnitKey>>,
+    statistics: CacheStatistics,
+}
+
+impl InferenceCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of functions and methods in the cache.
+    pub fn len(&self) -> usize {
+        self.units.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.units.is_empty()
+    }
+
+    /// Get the return type that was inferred from the `return` statements of a function, or of a
+    /// method when given as `Class::method`.
+    ///
+    /// Returns `None` when the unit isn't cached, has no body, or is a generator.
+    pub fn get_return_type(&self, name: &[u8]) -> Option<&Type<ResolvedName>> {
+        self.units
+            .values()
+            .find(|unit| unit.name.eq_ignore_ascii_case(name))
+            .and_then(|unit| unit.return_type.as_ref())
+    }
+
+    /// Remove every unit that depends on a symbol whose signature has changed in the given index,
+    /// and return the files that the units were declared in, which need to be inferred again.
+    ///
+    /// Units whose own file has changed are found when the file is inferred again, or can be
+    /// removed up front with [`InferenceCache::remove_file`].
+    pub fn invalidate(&mut self, index: &Index) -> Vec<FileId> {
+        let mut stale = HashSet::new();
+
+        for (dependency, dependents) in &self.dependents {
+            let hash = dependency.signature_hash(index);
+
+            stale.extend(dependents.iter().copied().filter(|key| {
+                self.units[key]
+                    .dependencies
+                    .iter()
+                    .any(|(other, recorded)| other == dependency && *recorded != hash)
+            }));
+        }
+
+        let mut files = stale.iter().map(|key| key.file).collect::<Vec<_>>();
+        files.sort();
+        files.dedup();
+
+        for key in stale {
+            self.remove(key);
+        }
+
+        files
+    }
+
+    /// Remove every unit that was declared in the given file.
+    pub fn remove_file(&mut self, file: FileId) {
+        let keys = self
+            .units
+            .keys()
+            .filter(|key| key.file == file)
+            .copied()
+            .collect::<Vec<_>>();
+
+        for key in keys {
+            self.remove(key);
+        }
+    }
+
+    pub fn statistics(&self) -> &CacheStatistics {
+        &self.statistics
+    }
+
+    /// Take the statistics, leaving them empty for the next run.
+    pub fn take_statistics(&mut self) -> CacheStatistics {
+        std::mem::take(&mut self.statistics)
+    }
+
+    /// Encode the cache so that it can be persisted alongside the index with
+    /// [`Index::save_with`].
+    pub fn encode(&self) -> Vec<u8> {
+        let mut encoder = Encoder::new();
+        encoder.write_u64(VERSION as u64);
+
+        let mut keys = self.units.keys().copied().collect::<Vec<_>>();
+        keys.sort_by_key(|key| (key.file, key.id));
+
+        encoder.write_usize(keys.len());
+
+        for key in keys {
+            let unit = &self.units[&key];
+
+            encoder.write_file_id(key.file);
+            encoder.write_u64(key.id as u64);
+            encoder.write_bytes(&unit.name);
+            encoder.write_u64(unit.fingerprint);
+
+            encoder.write_bool(unit.return_type.is_some());
+            if let Some(return_type) = &unit.return_type {
+                encoder.write_type(return_type);
+            }
+
+            encoder.write_usize(unit.dependencies.len());
+            for (dependency, hash) in &unit.dependencies {
+                let (tag, name) = dependency.sort_key();
+
+                encoder.write_usize(tag as usize);
+                encoder.write_bytes(name);
+                encoder.write_bool(hash.is_some());
+                encoder.write_u64(hash.unwrap_or_default());
+            }
+
+            let entries = &unit.entries;
+
+            encoder.write_usize(entries.types.len());
+            for (id, r#type) in &entries.types {
+                encoder.write_u64(*id as u64);
+                encoder.write_type(r#type);
+            }
+
+            encoder.write_usize(entries.spans.len());
+            for (id, span) in &entries.spans {
+                encoder.write_u64(*id as u64);
+                encoder.write_span(*span);
+            }
+
+            for ids in [&entries.sensitive, &entries.undefined_keys] {
+                encoder.write_usize(ids.len());
+                for id in ids {
+                    encoder.write_u64(*id as u64);
+                }
+            }
+
+            encoder.write_usize(entries.virtual_members.len());
+            for (id, member) in &entries.virtual_members {
+                encoder.write_u64(*id as u64);
+                encoder.write_usize(match member {
+                    VirtualMember::Docblock => 0,
+                    VirtualMember::Magic => 1,
+                });
+            }
+        }
+
+        encoder.finish()
+    }
+
+    /// Decode a cache from the output of [`InferenceCache::encode`]. An empty slice, such as the
+    /// extension of an index that was saved without a cache, decodes to an empty cache.
+    pub fn decode(bytes: &[u8]) -> Result<Self, PersistenceError> {
+        let mut cache = Self::new();
+        let mut decoder = Decoder::new(bytes);
+
+        if decoder.is_empty() {
+            return Ok(cache);
+        }
+
+        let version = decoder.read_u64()?;
+
+        if version != VERSION as u64 {
+            return Err(PersistenceError::UnsupportedVersion {
+                found: version as u32,
+                expected: VERSION,
+            });
+        }
+
+        for _ in 0..decoder.read_usize()? {
+            let key = UnitKey {
+                file: decoder.read_file_id()?,
+                id: read_id(&mut decoder)?,
+            };
+
+            let name = ByteString::from(decoder.read_bytes()?);
+            let fingerprint = decoder.read_u64()?;
+
+            let return_type = if decoder.read_bool()? {
+                Some(decoder.read_type()?)
+            } else {
+                None
+            };
+
+            let mut dependencies = Vec::new();
+            for _ in 0..decoder.read_usize()? {
+                let tag = decoder.read_usize()?;
+                let name = ByteString::from(decoder.read_bytes()?);
+                let dependency = match tag {
+                    0 => Dependency::Function(name),
+                    1 => Dependency::Class(name),
+                    _ => return Err(PersistenceError::Malformed("dependency kind")),
+                };
+
+                let exists = decoder.read_bool()?;
+                let hash = decoder.read_u64()?;
+
+                dependencies.push((dependency, exists.then_some(hash)));
+            }
+
+            let mut entries = MapEntries::default();
+
+            for _ in 0..decoder.read_usize()? {
+                entries
+                    .types
+                    .push((read_id(&mut decoder)?, decoder.read_type()?));
+            }
+
+            for _ in 0..decoder.read_usize()? {
+                entries
+                    .spans
+                    .push((read_id(&mut decoder)?, decoder.read_span()?));
+            }
+
+            for ids in [&mut entries.sensitive, &mut entries.undefined_keys] {
+                for _ in 0..decoder.read_usize()? {
+                    ids.push(read_id(&mut decoder)?);
+                }
+            }
+
+            for _ in 0..decoder.read_usize()? {
+                let id = read_id(&mut decoder)?;
+                let member = match decoder.read_usize()? {
+                    0 => VirtualMember::Docblock,
+                    1 => VirtualMember::Magic,
+                    _ => return Err(PersistenceError::Malformed("virtual member")),
+                };
+
+                entries.virtual_members.push((id, member));
+            }
+
+            cache.insert(
+                key,
+                CachedUnit {
+                    name,
+                    fingerprint,
+                    return_type,
+                    dependencies,
+                    entries,
+                },
+            );
+        }
+
+        if !decoder.is_empty() {
+            return Err(PersistenceError::Malformed("trailing bytes"));
+        }
+
+        Ok(cache)
+    }
+
+    fn insert(&mut self, key: UnitKey, unit: CachedUnit) {
+        self.remove(key);
+
+        for (dependency, _) in &unit.dependencies {
+            self.dependents
+                .entry(dependency.clone())
+                .or_default()
+                .insert(key);
+        }
+
+        self.units.insert(key, unit);
+    }
+
+    fn remove(&mut self, key: UnitKey) {
+        let Some(unit) = self.units.remove(&key) else {
+            return;
+        };
+
+        for (dependency, _) in unit.dependencies {
+            if let Some(dependents) = self.dependents.get_mut(&dependency) {
+                dependents.remove(&key);
+
+                if dependents.is_empty() {
+                    self.dependents.remove(&dependency);
+                }
+            }
+        }
+    }
+}
+
+fn read_id(decoder: &mut Decoder) -> Result<NodeId, PersistenceError> {
+    NodeId::try_from(decoder.read_u64()?).map_err(|_| PersistenceError::Malformed("node id"))
+}
+
+/// The state of a single run of the engine over a file with an [`InferenceCache`].
+pub(crate) struct CacheSession<'a> {
+    cache: &'a mut InferenceCache,
+    file: FileId,
+    fingerprint: u64,
+    // The signature hashes that have already been looked up during this run.
+    hashes: HashMap<Dependency, Option<u64>>,
+    // The units that were seen during this run, so that units which no longer exist can be dropped.
+    seen: HashSet<NodeId>,
+    // Whether a unit is being inferred, in which case the units inside of it are part of it.
+    active: bool,
+}
+
+impl<'a> CacheSession<'a> {
+    pub(crate) fn new(cache: &'a mut InferenceCache, file: FileId, source: &[u8]) -> Self {
+        let mut fingerprinter = Fingerprinter::new();
+        fingerprinter.write(source);
+
+        Self {
+            cache,
+            file,
+            fingerprint: fingerprinter.finish(),
+            hashes: HashMap::new(),
+            seen: HashSet::new(),
+            active: false,
+        }
+    }
+
+    pub(crate) fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Get the entries for a unit from the cache, if it was inferred from the same source and
+    /// nothing it depends on has changed since.
+    pub(crate) fn reuse(
+        &mut self,
+        index: &Index,
+        id: NodeId,
+        name: &ByteString,
+    ) -> Option<&MapEntries> {
+        self.seen.insert(id);
+
+        let key = UnitKey {
+            file: self.file,
+            id,
+        };
+
+        let unit = self.cache.units.get(&key)?;
+
+        if unit.fingerprint != self.fingerprint || unit.name != *name {
+            return None;
+        }
+
+        for (dependency, recorded) in &unit.dependencies {
+            let hash = *self
+                .hashes
+                .entry(dependency.clone())
+                .or_insert_with(|| dependency.signature_hash(index));
+
+            if hash != *recorded {
+                return None;
+            }
+        }
+
+        self.cache.statistics.reused.push(name.clone());
+
+        Some(&unit.entries)
+    }
+
+    pub(crate) fn start(&mut self, index: &TrackedIndex) {
+        self.active = true;
+        index.start_tracking();
+    }
+
+    pub(crate) fn finish(
+        &mut self,
+        index: &TrackedIndex,
+        id: NodeId,
+        name: ByteString,
+        return_type: Option<Type<ResolvedName>>,
+        entries: MapEntries,
+    ) {
+        self.active = false;
+
+        let mut dependencies = index
+            .finish_tracking()
+            .into_iter()
+            .map(|dependency| {
+                let hash = *self
+                    .hashes
+                    .entry(dependency.clone())
+                    .or_insert_with(|| dependency.signature_hash(index.inner()));
+
+                (dependency, hash)
+            })
+            .collect::<Vec<_>>();
+
+        dependencies.sort_by(|(a, _), (b, _)| a.sort_key().cmp(&b.sort_key()));
+
+        self.cache.statistics.inferred.push(name.clone());
+        self.cache.insert(
+            UnitKey {
+                file: self.file,
+                id,
+            },
+            CachedUnit {
+                name,
+                fingerprint: self.fingerprint,
+                return_type,
+                dependencies,
+                entries,
+            },
+        );
+    }
+
+    /// Drop the units of the file that weren't seen during this run, since they no longer exist.
+    pub(crate) fn end(self) {
+        let stale = self
+            .cache
+            .units
+            .keys()
+            .filter(|key| key.file == self.file && !self.seen.contains(&key.id))
+            .copied()
+            .collect::<Vec<_>>();
+
+        for key in stale {
+            self.cache.remove(key);
+        }
+    }
+}
+
+/// Find the `return` statements that belong to a function body, leaving out the ones inside of
+/// closures, arrow functions, anonymous classes and nested functions.
+#[derive(Default)]
+pub(crate) struct Returns {
+    /// The values of the `return` statements, which are `None` when there's no value.
+    pub(crate) values: Vec<Option<NodeId>>,
+    pub(crate) yields: bool,
+}
+
+impl Returns {
+    pub(crate) fn find(statements: &[Statement]) -> Self {
+        let mut returns = Self::default();
+        returns.visit(statements);
+        returns
+    }
+}
+
+impl ControlFlowVisitor for Returns {
+    fn visit_return_statement(&mut self, node: &ReturnStatement) -> Traversal {
+        self.values.push(node.value.as_ref().map(|value| value.id));
+
+        walk_return_statement(self, node)
+    }
+
+    fn visit_yield_expression(&mut self, node: &YieldExpression) -> Traversal {
+        self.yields = true;
+
+        walk_yield_expression(self, node)
+    }
+
+    fn visit_yield_from_expression(&mut self, node: &YieldFromExpression) -> Traversal {
+        self.yields = true;
+
+        walk_yield_from_expression(self, node)
+    }
+
+    fn visit_closure_expression(&mut self, _: &ClosureExpression) -> Traversal {
+        Traversal::SkipChildren
+    }
+
+    fn visit_arrow_function_expression(&mut self, _: &ArrowFunctionExpression) -> Traversal {
+        Traversal::SkipChildren
+    }
+
+    fn visit_anonymous_class_expression(&mut self, _: &AnonymousClassExpression) -> Traversal {
+        Traversal::SkipChildren
+    }
+
+    fn visit_function_statement(&mut self, _: &FunctionStatement) -> Traversal {
+        Traversal::SkipChildren
+    }
+}
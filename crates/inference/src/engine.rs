@@ -1,62 +1,203 @@
 use std::{
     cell::RefCell,
-    collections::{HashMap, HashSet},
+    collections::{HashMap, HashSet, VecDeque},
+    path::{Path, PathBuf},
     rc::Rc,
 };
 
 use pxp_ast::{
-    visitor::{walk_expression, Visitor},
+    literals::IntegerValue,
+    utils::StringLike,
+    visitor::{ControlFlowVisitor, Traversal},
     *,
 };
 use pxp_bytestring::{ByteStr, ByteString};
-use pxp_index::{Index, ReflectionClass, ReflectionFunctionLike};
+use pxp_index::{
+    CanReflectParameters, FileId, Index, ReflectionClass, ReflectionFunction,
+    ReflectionFunctionLike, ReflectionMethod, ReflectionParameter, ReflectionType,
+    ReflectsParameters, Signature,
+};
 use pxp_token::TokenKind;
-use pxp_type::{ConstExpr, Type};
-use visitor::{
-    walk_array_expression, walk_concat_expression, walk_die_expression, walk_empty_expression,
-    walk_error_suppress_expression, walk_eval_expression, walk_exit_expression,
-    walk_function_call_expression, walk_function_closure_creation_expression,
-    walk_function_statement, walk_include_expression, walk_include_once_expression,
-    walk_instanceof_expression, walk_isset_expression, walk_method_call_expression,
+use pxp_type::{ConstExpr, GenericTypeArgument, ShapeItem, ShapeItemKey, Type};
+use visitor::walk_control_flow::{
+    walk_anonymous_class_expression, walk_array_expression, walk_array_index_expression,
+    walk_backed_enum_statement, walk_braced_namespace, walk_class_statement, walk_clone_expression,
+    walk_concat_expression, walk_constant_fetch_expression, walk_die_expression,
+    walk_empty_expression, walk_error_suppress_expression, walk_eval_expression,
+    walk_exit_expression, walk_expression, walk_function_call_expression,
+    walk_function_closure_creation_expression, walk_heredoc_expression, walk_hooked_property,
+    walk_include_expression, walk_include_once_expression, walk_instanceof_expression,
+    walk_interface_statement, walk_interpolated_string_expression, walk_isset_expression,
+    walk_logical_operation_expression, walk_method, walk_method_call_expression,
     walk_method_closure_creation_expression, walk_new_expression,
-    walk_nullsafe_method_call_expression, walk_parenthesized_expression, walk_print_expression,
+    walk_nullsafe_method_call_expression, walk_nullsafe_property_fetch_expression,
+    walk_parenthesized_expression, walk_print_expression, walk_property_fetch_expression,
     walk_reference_expression, walk_require_expression, walk_require_once_expression,
-    walk_static_method_call_expression, walk_unset_expression,
+    walk_static_method_call_expression, walk_throw_expression, walk_trait_statement,
+    walk_unbraced_namespace, walk_unit_enum_statement, walk_unset_expression,
+};
+
+use crate::{
+    arrays::{ArrayKey, Shape},
+    cache::{CacheSession, Returns, TrackedIndex},
+    includes::{IncludeResolver, Includes},
+    magic::{self, Declaration},
+    map::VirtualMember,
+    numbers,
+    strings::{self, StringValue},
+    InferenceCache, Superglobals, TypeMap,
 };
 
-use crate::TypeMap;
+/// The number of `IteratorAggregate` classes that are followed when inferring the types produced
+/// by a `foreach` loop.
+const MAX_ITERATOR_DEPTH: usize = 8;
 
 /// The `TypeEngine` is responsible for generating a `TypeMap` for a given AST.
 /// It uses the provided `Index` to resolve types for method calls, property accesses, etc.
 pub struct TypeEngine<'a> {
     index: &'a Index,
+    max_literal_string_length: usize,
+    superglobals: Superglobals,
+    includes: Includes<'a>,
 }
 
 impl<'a> TypeEngine<'a> {
     /// Create a new `TypeEngine` with the provided `Index`.
     pub fn new(index: &'a Index) -> Self {
-        TypeEngine { index }
+        TypeEngine {
+            index,
+            max_literal_string_length: strings::MAX_LITERAL_STRING_LENGTH,
+            superglobals: Superglobals::default(),
+            includes: Includes::new(),
+        }
+    }
+
+    /// Set the length of the longest string that is tracked as a literal string type. Longer
+    /// strings are inferred as `non-empty-string` so that large literals aren't copied into the
+    /// `TypeMap`.
+    pub fn with_max_literal_string_length(mut self, max_literal_string_length: usize) -> Self {
+        self.max_literal_string_length = max_literal_string_length;
+        self
+    }
+
+    /// Set the types of the variables that PHP defines before any code runs, e.g. `$_SERVER`.
+    pub fn with_superglobals(mut self, superglobals: Superglobals) -> Self {
+        self.superglobals = superglobals;
+        self
+    }
+
+    /// Set the resolver that works out which file an `include` or `require` refers to. Defaults
+    /// to [`DefaultIncludeResolver`](crate::DefaultIncludeResolver).
+    pub fn with_include_resolver(mut self, resolver: impl IncludeResolver + 'static) -> Self {
+        self.includes.set_resolver(resolver);
+        self
+    }
+
+    /// Make a file available to includes in the files passed to [`TypeEngine::infer_file`].
+    ///
+    /// When an include in the global scope resolves to the file, the variables that it assigns at
+    /// the top level are visible to the code after the include. Functions, classes and constants
+    /// are already visible through the `Index`.
+    pub fn with_included_file(mut self, path: impl Into<PathBuf>, ast: &'a [Statement]) -> Self {
+        self.includes.insert(path.into(), ast);
+        self
     }
 
     /// Infer the types for the given AST and return a `TypeMap`.
     pub fn infer(&self, ast: &[Statement]) -> TypeMap {
+        self.generate(ast, None, None)
+    }
+
+    /// Infer the types for the AST of the file at the given path and return a `TypeMap`, following
+    /// includes into the files given to [`TypeEngine::with_included_file`].
+    pub fn infer_file(&self, path: &Path, ast: &[Statement]) -> TypeMap {
+        self.generate(ast, None, Some(path))
+    }
+
+    /// Infer the types for the AST of the given file, reusing the functions and methods in the
+    /// cache that were inferred from the same source and whose dependencies have the same
+    /// signatures in the `Index`. Everything that is inferred again is stored in the cache.
+    pub fn infer_with_cache(
+        &self,
+        file: FileId,
+        source: &[u8],
+        ast: &[Statement],
+        cache: &mut InferenceCache,
+    ) -> TypeMap {
+        self.generate(ast, Some(CacheSession::new(cache, file, source)), None)
+    }
+
+    fn generate(
+        &self,
+        ast: &[Statement],
+        session: Option<CacheSession>,
+        file: Option<&Path>,
+    ) -> TypeMap {
         let mut map = TypeMap::new();
 
         let mut generator = TypeMapGenerator {
             map: &mut map,
-            index: self.index,
+            index: TrackedIndex::new(self.index),
+            session,
             scopes: ScopeStack::new(),
+            max_literal_string_length: self.max_literal_string_length,
+            superglobals: &self.superglobals,
+            declarations: Vec::new(),
+            namespace: None,
+            includes: &self.includes,
+            file,
+            including: Vec::new(),
         };
 
         generator.visit(ast);
+
+        if let Some(session) = generator.session.take() {
+            session.end();
+        }
+
         map
     }
 }
 
 struct TypeMapGenerator<'a> {
     map: &'a mut TypeMap,
-    index: &'a Index,
+    index: TrackedIndex<'a>,
+    session: Option<CacheSession<'a>>,
     scopes: ScopeStack,
+    max_literal_string_length: usize,
+    superglobals: &'a Superglobals,
+    // The declarations that enclose the current node, which magic constants like `__CLASS__` refer to.
+    declarations: Vec<Declaration>,
+    namespace: Option<ByteString>,
+    includes: &'a Includes<'a>,
+    file: Option<&'a Path>,
+    // The files that are in the middle of including the current one, so that cycles are skipped.
+    including: Vec<&'a Path>,
+}
+
+/// A method or property resolved against one of the classes that a receiver could be an instance of.
+struct ResolvedMember {
+    r#type: Option<Type<ResolvedName>>,
+    sensitive: Vec<NodeId>,
+    // The variables passed to by-reference parameters, with the types they have after the call.
+    references: Vec<(SimpleVariable, Type<ResolvedName>)>,
+    via: Option<VirtualMember>,
+}
+
+/// The types that variables are narrowed to when a condition is true, and when it's false.
+#[derive(Debug, Default)]
+struct Narrowing {
+    truthy: Vec<(SimpleVariable, Type<ResolvedName>)>,
+    falsy: Vec<(SimpleVariable, Type<ResolvedName>)>,
+}
+
+impl Narrowing {
+    fn negate(self) -> Self {
+        Self {
+            truthy: self.falsy,
+            falsy: self.truthy,
+        }
+    }
 }
 
 struct ScopeStack {
@@ -82,6 +223,14 @@ impl ScopeStack {
         self.scopes.pop();
     }
 
+    fn is_global(&self) -> bool {
+        self.scopes.len() == 1
+    }
+
+    fn global(&self) -> &Scope {
+        &self.scopes[0]
+    }
+
     fn current(&self) -> &Scope {
         self.scopes.last().unwrap()
     }
@@ -94,6 +243,11 @@ impl ScopeStack {
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct Scope {
     variables: HashMap<ByteString, Type<ResolvedName>>,
+    // The types of properties assigned through `$this`, which are more precise than the declared types.
+    properties: HashMap<ByteString, Type<ResolvedName>>,
+    // Variables that are bound to each other by reference, keyed by the name of the variable that
+    // the first reference was taken to.
+    references: HashMap<ByteString, ByteString>,
     outer: Option<Rc<RefCell<Scope>>>,
 }
 
@@ -101,6 +255,8 @@ impl Scope {
     fn new() -> Self {
         Self {
             variables: HashMap::new(),
+            properties: HashMap::new(),
+            references: HashMap::new(),
             outer: None,
         }
     }
@@ -108,21 +264,88 @@ impl Scope {
     fn enclose(&self) -> Self {
         Scope {
             variables: HashMap::new(),
+            properties: HashMap::new(),
+            references: HashMap::new(),
             outer: Some(Rc::new(RefCell::new(self.clone()))),
         }
     }
 
+    fn set_property(&mut self, name: &ByteStr, ty: Type<ResolvedName>) {
+        self.properties.insert(name.to_bytestring(), ty);
+    }
+
+    fn get_property(&self, name: &ByteStr) -> Option<Type<ResolvedName>> {
+        if let Some(ty) = self.properties.get(&name.to_bytestring()) {
+            return Some(ty.clone());
+        }
+
+        if let Some(outer) = &self.outer {
+            return outer.borrow().get_property(name);
+        }
+
+        None
+    }
+
+    /// Set the type of a variable, along with every variable that is bound to it by reference.
     fn set_variable(&mut self, variable: &SimpleVariable, ty: Type<ResolvedName>) {
-        self.variables.insert(variable.symbol.clone(), ty);
+        self.set_named_variable(&variable.symbol, ty);
     }
 
-    fn get_variable(&self, variable: &SimpleVariable) -> Option<Type<ResolvedName>> {
-        if let Some(ty) = self.variables.get(&variable.symbol) {
+    fn set_named_variable(&mut self, symbol: &ByteString, ty: Type<ResolvedName>) {
+        for alias in self.aliases(symbol) {
+            self.variables.insert(alias, ty.clone());
+        }
+
+        self.variables.insert(symbol.clone(), ty);
+    }
+
+    /// Remove a variable, as `unset()` does. Any variable that was bound to it by reference keeps
+    /// its value, since only the binding is removed.
+    fn unset_variable(&mut self, variable: &SimpleVariable) {
+        self.variables.remove(&variable.symbol);
+        self.references.remove(&variable.symbol);
+    }
+
+    /// Forget what is known about every variable, e.g. after `extract()` is called with an array
+    /// whose keys aren't known. Any variable could have been defined or overwritten.
+    fn poison(&mut self) {
+        for ty in self.variables.values_mut() {
+            *ty = Type::Mixed;
+        }
+    }
+
+    /// Bind a variable to another by reference, as `$variable = &$target` does. The variable stops
+    /// being bound to whatever it was bound to before.
+    fn bind_reference(&mut self, variable: &SimpleVariable, target: &SimpleVariable) {
+        let set = self
+            .references
+            .get(&target.symbol)
+            .cloned()
+            .unwrap_or_else(|| target.symbol.clone());
+
+        self.references.insert(target.symbol.clone(), set.clone());
+        self.references.insert(variable.symbol.clone(), set);
+    }
+
+    fn aliases(&self, name: &ByteString) -> Vec<ByteString> {
+        let Some(set) = self.references.get(name) else {
+            return Vec::new();
+        };
+
+        self.references
+            .iter()
+            .filter(|(alias, other)| *other == set && *alias != name)
+            .map(|(alias, _)| alias.clone())
+            .collect()
+    }
+
+    fn get_named_variable(&self, symbol: &ByteString) -> Option<Type<ResolvedName>> {
+        if let Some(ty) = self.variables.get(symbol) {
             return Some(ty.clone());
         }
 
         if let Some(outer) = &self.outer {
-            return outer.borrow().get_variable(variable);
+            return outer.borrow().get_named_variable(symbol);
         }
 
         None
@@ -130,8 +353,171 @@ impl Scope {
 }
 
 impl<'a> TypeMapGenerator<'a> {
+    /// Run the file that an include in the global scope refers to, so that the variables it
+    /// assigns are visible to the code after the include.
+    fn include(&mut self, path: &Expression) {
+        if !self.scopes.is_global() {
+            return;
+        }
+
+        let Some(file) = self.file else {
+            return;
+        };
+
+        let Some((included, ast)) = self.includes.resolve(path, file) else {
+            return;
+        };
+
+        if included == file || self.including.contains(&included) {
+            return;
+        }
+
+        let mut map = TypeMap::new();
+        let mut including = self.including.clone();
+        including.push(file);
+
+        let mut generator = TypeMapGenerator {
+            map: &mut map,
+            index: TrackedIndex::new(self.index.inner()),
+            session: None,
+            scopes: ScopeStack::new(),
+            max_literal_string_length: self.max_literal_string_length,
+            superglobals: self.superglobals,
+            declarations: Vec::new(),
+            namespace: None,
+            includes: self.includes,
+            file: Some(included),
+            including,
+        };
+
+        // The included file runs in the scope of the include, so it can see and overwrite the
+        // variables that were assigned before it.
+        generator.scopes.current_mut().variables = self.scopes.current().variables.clone();
+        generator.visit(ast);
+
+        self.scopes.current_mut().variables =
+            std::mem::take(&mut generator.scopes.current_mut().variables);
+    }
+
+    /// Infer a function or method with the given body, unless it can be reused from the cache.
+    ///
+    /// Functions and methods inside of another one are inferred and cached as part of it.
+    fn unit(
+        &mut self,
+        id: NodeId,
+        name: ByteString,
+        body: Option<&[Statement]>,
+        infer: impl FnOnce(&mut Self),
+    ) {
+        let Some(session) = self.session.as_mut().filter(|session| !session.is_active()) else {
+            return infer(self);
+        };
+
+        if let Some(entries) = session.reuse(self.index.inner(), id, &name) {
+            return self.map.extend(entries);
+        }
+
+        session.start(&self.index);
+        self.map.start_recording();
+
+        infer(self);
+
+        let entries = self.map.finish_recording();
+        let return_type = body.map(Returns::find).and_then(|returns| {
+            if returns.yields {
+                return None;
+            }
+
+            if returns.values.is_empty() {
+                return Some(Type::Void);
+            }
+
+            let types = returns
+                .values
+                .iter()
+                .map(|value| value.map_or(Type::Void, |id| self.map.resolve(id).clone()))
+                .collect();
+
+            Some(self.simplify_union(types))
+        });
+
+        if let Some(session) = self.session.as_mut() {
+            session.finish(&self.index, id, name, return_type, entries);
+        }
+    }
+
+    /// The name of the method with the given name in the innermost class-like, as `Class::method`.
+    fn method_name(&self, method: &ByteStr) -> ByteString {
+        let class = self
+            .declarations
+            .iter()
+            .rev()
+            .find_map(|declaration| match declaration {
+                Declaration::Class(Some(name)) => Some(name.resolved.to_vec()),
+                Declaration::Class(None) => Some(b"class@anonymous".to_vec()),
+                Declaration::Trait(name) => Some(name.to_vec()),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        ByteString::from([&class[..], b"::", method].concat())
+    }
+
+    /// Walk a node inside of the given declaration.
+    fn within(
+        &mut self,
+        declaration: Declaration,
+        walk: impl FnOnce(&mut Self) -> Traversal,
+    ) -> Traversal {
+        self.declarations.push(declaration);
+        let traversal = walk(self);
+        self.declarations.pop();
+
+        traversal
+    }
+
+    fn get_variable(&self, variable: &SimpleVariable) -> Option<Type<ResolvedName>> {
+        self.get_named_variable(&variable.symbol)
+    }
+
+    /// Get the type of a variable in the current scope, falling back to the variables that PHP
+    /// defines before any code runs.
+    fn get_named_variable(&self, symbol: &ByteString) -> Option<Type<ResolvedName>> {
+        self.scopes
+            .current()
+            .get_named_variable(symbol)
+            .or_else(|| {
+                self.superglobals
+                    .get(symbol, self.scopes.is_global())
+                    .cloned()
+            })
+    }
+
+    /// Give each parameter its type in the current scope, preferring the types from the indexed
+    /// signature of the function when there is one.
+    fn declare_parameters(&mut self, node: &FunctionParameterList, signature: Option<&Signature>) {
+        for parameter in node.parameters.iter() {
+            let mut r#type = match signature
+                .and_then(|signature| signature.get_parameter(parameter.name.stripped.as_ref()))
+            {
+                Some(parameter) => parameter
+                    .get_type()
+                    .map_or(Type::Mixed, expand_declared_type),
+                None => self.unwrap_data_type(parameter.data_type.as_ref()),
+            };
+
+            if parameter.is_variadic() {
+                r#type = Type::TypedArray(Box::new(Type::Integer), Box::new(r#type));
+            }
+
+            self.scopes
+                .current_mut()
+                .set_variable(&parameter.name, r#type);
+        }
+    }
+
     fn unwrap_data_type(&self, data_type: Option<&'a DataType>) -> Type<ResolvedName> {
-        data_type.map_or(Type::Mixed, |ty| ty.get_type().clone())
+        data_type.map_or(Type::Mixed, |ty| expand_declared_type(ty.get_type()))
     }
 
     fn is_newable_string(&self, value: &ByteStr) -> bool {
@@ -145,7 +531,7 @@ impl<'a> TypeMapGenerator<'a> {
             todo!();
         }
 
-        self.index.get_function(name).is_some()
+        is_exit_function(name) || self.index.get_function(name).is_some()
     }
 
     fn determine_function_call_target_return_type(
@@ -176,7 +562,10 @@ impl<'a> TypeMapGenerator<'a> {
         }
     }
 
-    fn determine_class_from_type(&self, ty: &Type<ResolvedName>) -> Option<Vec<ReflectionClass>> {
+    fn determine_class_from_type(
+        &self,
+        ty: &Type<ResolvedName>,
+    ) -> Option<Vec<ReflectionClass<'a>>> {
         if !ty.is_object_like() {
             return None;
         }
@@ -199,8 +588,8 @@ impl<'a> TypeMapGenerator<'a> {
                         .flatten(),
                 );
             }
-            Type::SelfReference | Type::StaticReference | Type::ParentReference => todo!(),
-            _ => unreachable!(),
+            // FIXME: Resolve relative types against the current class.
+            _ => return None,
         };
 
         Some(classes)
@@ -217,354 +606,2595 @@ impl<'a> TypeMapGenerator<'a> {
             return Type::Mixed;
         }
 
+        if is_exit_function(name) {
+            return Type::Never;
+        }
+
         match self.index.get_function(name) {
             Some(function) => function
+                .effective_signature()
                 .get_return_type()
-                .as_ref()
-                .map(|t| t.to_type())
-                .unwrap_or_else(|| &Type::Mixed)
-                .clone(),
+                .map(expand_declared_type)
+                .unwrap_or(Type::Mixed),
             None => Type::Mixed,
         }
     }
 
-    fn get_function_call_target_return_type_from_name(&self, name: &Name) -> Type<ResolvedName> {
+    fn function_from_name(&self, name: &Name) -> Option<ReflectionFunction<'a>> {
         match &name.kind {
-            NameKind::Resolved(inner) => match self.index.get_function(inner.resolved.as_bytestr())
-            {
-                Some(function) => function
-                    .get_return_type()
-                    .as_ref()
-                    .map(|t| t.to_type())
-                    .unwrap_or_else(|| &Type::Mixed)
-                    .clone(),
-                None => Type::Mixed,
-            },
-
-            _ => todo!(),
+            NameKind::Resolved(inner) => self.index.get_function(inner.resolved.as_bytestr()),
+            // Unqualified calls inside of a namespace fall back to the global function.
+            NameKind::Unresolved(inner) => self.index.get_function(inner.symbol.as_bytestr()),
+            NameKind::Special(_) => None,
         }
     }
 
-    fn simplify_union(&self, mut types: Vec<Type<ResolvedName>>) -> Type<ResolvedName> {
-        if types.len() == 1 {
-            return types[0].clone();
+    fn get_function_call_target_return_type_from_name(&self, name: &Name) -> Type<ResolvedName> {
+        match self.function_from_name(name) {
+            Some(function) => function
+                .effective_signature()
+                .get_return_type()
+                .map(expand_declared_type)
+                .unwrap_or(Type::Mixed),
+            None => Type::Mixed,
         }
+    }
 
-        let mut uniques = HashSet::new();
-
-        types.retain(|ty| uniques.insert(ty.clone()));
+    /// Pair each argument with the parameter that it's passed to.
+    fn match_arguments<'p, 'b, P>(
+        parameters: &'p [P],
+        arguments: &'b ArgumentList,
+        name: impl Fn(&P) -> &ByteStr,
+        variadic: impl Fn(&P) -> bool,
+    ) -> Vec<(&'p P, &'b Expression)> {
+        let mut matched = Vec::new();
+
+        for (position, argument) in arguments.arguments.iter().enumerate() {
+            let (parameter, value) = match argument {
+                // We can't know which parameters an unpacked argument ends up in.
+                Argument::Positional(argument) if argument.ellipsis.is_some() => break,
+                Argument::Positional(argument) => (
+                    parameters
+                        .get(position)
+                        .or_else(|| parameters.last().filter(|parameter| variadic(parameter))),
+                    &argument.value,
+                ),
+                Argument::Named(argument) => (
+                    parameters
+                        .iter()
+                        .find(|parameter| name(parameter) == argument.name.symbol.as_bytestr()),
+                    &argument.value,
+                ),
+            };
 
-        if types.len() == 1 {
-            return types[0].clone();
+            if let Some(parameter) = parameter {
+                matched.push((parameter, value));
+            }
         }
 
-        Type::Union(types)
+        matched
     }
 
-    fn determine_array_type(&self, node: &ArrayExpression) -> Type<ResolvedName> {
-        let value_types: Vec<Type<ResolvedName>> = node
-            .items
-            .iter()
-            .filter_map(|item| -> Option<Type<ResolvedName>> {
-                match item {
-                    ArrayItem::Skipped(_) => None,
-                    ArrayItem::Value(inner) => Some(self.map.resolve(inner.value.id).clone()),
-                    ArrayItem::ReferencedValue(inner) => {
-                        Some(self.map.resolve(inner.value.id).clone())
-                    }
-                    ArrayItem::SpreadValue(inner) => Some(self.map.resolve(inner.value.id).clone()),
-                    ArrayItem::KeyValue(inner) => Some(self.map.resolve(inner.value.id).clone()),
-                    ArrayItem::ReferencedKeyValue(inner) => {
-                        Some(self.map.resolve(inner.value.id).clone())
-                    }
-                }
-            })
-            .collect();
+    /// Find the arguments whose values flow into a parameter marked with `#[SensitiveParameter]`.
+    fn find_sensitive_arguments<O: CanReflectParameters>(
+        parameters: &[ReflectionParameter<'_, O>],
+        arguments: &ArgumentList,
+    ) -> Vec<NodeId> {
+        Self::match_arguments(
+            parameters,
+            arguments,
+            |parameter| parameter.get_name(),
+            |parameter| parameter.is_variadic(),
+        )
+        .into_iter()
+        .filter(|(parameter, _)| parameter.has_attribute(b"SensitiveParameter".into()))
+        .map(|(_, value)| value.id)
+        .collect()
+    }
 
-        if node.is_list() {
-            return Type::TypedArray(
-                Box::new(Type::Integer),
-                Box::new(self.simplify_union(value_types)),
-            );
+    /// Find the variables passed to by-reference parameters, along with the types that they have
+    /// once the call returns.
+    fn find_reference_arguments(
+        signature: &Signature,
+        arguments: &ArgumentList,
+    ) -> Vec<(SimpleVariable, Type<ResolvedName>)> {
+        Self::match_arguments(
+            signature.get_parameters(),
+            arguments,
+            |parameter| parameter.get_name(),
+            |parameter| parameter.is_variadic(),
+        )
+        .into_iter()
+        .filter(|(parameter, _)| parameter.is_by_reference())
+        .filter_map(|(parameter, value)| {
+            let r#type = parameter
+                .get_type()
+                .map_or(Type::Mixed, expand_declared_type);
+
+            Some((Self::simple_variable(value)?.clone(), r#type))
+        })
+        .collect()
+    }
+
+    fn simple_variable(expression: &Expression) -> Option<&SimpleVariable> {
+        match &expression.kind {
+            ExpressionKind::Variable(variable) if variable.is_simple() => {
+                Some(variable.to_simple())
+            }
+            _ => None,
         }
+    }
 
-        let key_types: Vec<Type<ResolvedName>> = node
-            .items
-            .iter()
-            .map(|item| -> Type<ResolvedName> {
-                match item {
-                    ArrayItem::KeyValue(array_item_key_value) => {
-                        self.map.resolve(array_item_key_value.key.id).clone()
-                    }
-                    ArrayItem::ReferencedKeyValue(array_item_referenced_key_value) => self
-                        .map
-                        .resolve(array_item_referenced_key_value.key.id)
-                        .clone(),
-                    _ => Type::Integer,
-                }
-            })
-            .collect();
+    fn assign_references(&mut self, references: Vec<(SimpleVariable, Type<ResolvedName>)>) {
+        for (variable, r#type) in references {
+            self.scopes
+                .current_mut()
+                .set_variable(&variable, r#type.clone());
+            self.map.insert(variable.id, r#type);
+        }
+    }
 
-        Type::TypedArray(
-            Box::new(self.simplify_union(key_types)),
-            Box::new(self.simplify_union(value_types)),
-        )
+    fn is_function(name: &Name, function: &[u8]) -> bool {
+        name.symbol()
+            .as_bytestr()
+            .after_last(b'\\')
+            .eq_ignore_ascii_case(function)
     }
-}
 
-impl<'a> Visitor for TypeMapGenerator<'a> {
-    fn visit_expression(&mut self, node: &Expression) {
-        walk_expression(self, node);
+    /// `$GLOBALS['name']` reads the variable with the given name from the global scope.
+    fn global_variable_type(&self, node: &ArrayIndexExpression) -> Option<Type<ResolvedName>> {
+        let globals = Self::simple_variable(&node.array)?;
 
-        let inner = self.map.resolve(node.kind.id()).clone();
+        if globals.symbol != b"$GLOBALS" || !self.superglobals.is_defined(&globals.symbol) {
+            return None;
+        }
 
-        self.map.insert(node.id, inner);
-    }
+        let ArrayKey::String(name) = Self::array_key(node.index.as_deref()?)? else {
+            return None;
+        };
 
-    fn visit_literal(&mut self, node: &Literal) {
-        self.map.insert(
-            node.id,
-            match node.kind {
-                LiteralKind::Integer => Type::Integer,
-                LiteralKind::Float => Type::Float,
-                LiteralKind::String => Type::LiteralString(
-                    node.token
-                        .symbol
-                        .as_bytestr()
-                        .strip_string_quotes()
-                        .to_bytestring(),
-                ),
-                LiteralKind::Missing => Type::Missing,
-            },
-        )
-    }
+        let symbol = Self::variable_symbol(name.as_bytestr());
 
-    fn visit_interpolated_string_expression(&mut self, node: &InterpolatedStringExpression) {
-        self.map.insert(node.id, Type::String);
+        self.scopes
+            .global()
+            .get_named_variable(&symbol)
+            .or_else(|| self.superglobals.get(&symbol, true).cloned())
     }
 
-    fn visit_bool_expression(&mut self, node: &BoolExpression) {
-        self.map.insert(
-            node.id,
-            match node.value.kind {
-                TokenKind::True => Type::True,
-                TokenKind::False => Type::False,
-                _ => Type::Boolean,
-            },
-        );
+    fn variable_symbol(name: &ByteStr) -> ByteString {
+        let mut symbol = ByteString::from(b"$");
+        symbol.extend_with_bytes(name);
+        symbol
     }
 
-    fn visit_function_call_expression(&mut self, node: &FunctionCallExpression) {
-        // We need to walk the function call expression first to ensure all arguments are resolved
-        // for conditional return types and resolving generics.
-        //
-        // We also need the information for the `node.target` to be available in the map.
-        walk_function_call_expression(self, node);
+    /// `compact('a', 'b')` creates an array from the variables with the given names. Variables
+    /// that aren't defined are left out, just like they are at runtime.
+    fn compact_type(&self, arguments: &ArgumentList) -> Option<Type<ResolvedName>> {
+        let mut names = Vec::new();
 
-        // FIXME: Once we've got this information, we can resolve generics based on the arguments.
-        let return_type = self.determine_function_call_target_return_type(&node.target);
+        for argument in arguments.arguments.iter() {
+            match argument {
+                Argument::Positional(argument) if argument.ellipsis.is_none() => {
+                    Self::compact_names(&argument.value, &mut names)?
+                }
+                _ => return None,
+            }
+        }
 
-        self.map.insert(node.id, return_type);
-    }
+        let mut items: Vec<ShapeItem<ResolvedName>> = Vec::new();
 
-    fn visit_simple_variable(&mut self, node: &SimpleVariable) {
-        if let Some(ty) = self.scopes.current().get_variable(node) {
-            self.map.insert(node.id(), ty);
+        for name in names {
+            let key = Some(ShapeItemKey::String(name.clone()));
+
+            if items.iter().any(|item| item.key_name == key) {
+                continue;
+            }
+
+            let Some(value_type) =
+                self.get_named_variable(&Self::variable_symbol(name.as_bytestr()))
+            else {
+                continue;
+            };
+
+            items.push(ShapeItem {
+                key_name: key,
+                value_type,
+                optional: false,
+            });
         }
+
+        Some(Type::Shaped {
+            base: Box::new(Type::Array),
+            items,
+            sealed: true,
+            unsealed_type: None,
+        })
     }
 
-    fn visit_assignment_operation_expression(&mut self, node: &AssignmentOperationExpression) {
-        // Walk the right-hand side of the assignment first to ensure the type is resolved.
-        walk_expression(self, &node.right);
+    /// Merge the shapes of the arrays passed to `array_merge()`, if they're all known.
+    fn array_merge_type(&self, arguments: &ArgumentList) -> Option<Type<ResolvedName>> {
+        let mut shapes = arguments.arguments.iter().map(|argument| match argument {
+            Argument::Positional(argument) if argument.ellipsis.is_none() => {
+                Shape::from_type(self.map.resolve(argument.value.id))
+            }
+            _ => None,
+        });
 
-        // Assignment expressions are always resolved to the type of the right-hand side.
-        self.map
-            .insert(node.id, self.map.resolve(node.right.kind.id()).clone());
+        let first = shapes.next()??;
 
-        // If the left-hand side is a variable, we can use that to assign the type in the current scope.
-        match &node.left.kind {
+        shapes
+            .try_fold(first, |merged, shape| Some(merged.merge(shape?)))?
+            .into_type()
+    }
+
+    /// Collect the variable names passed to `compact()`, which also accepts nested arrays of names.
+    fn compact_names(expression: &Expression, names: &mut Vec<ByteString>) -> Option<()> {
+        match &expression.kind {
+            ExpressionKind::Literal(literal) => {
+                names.push(literal.string_value()?.value().to_bytestring())
+            }
+            ExpressionKind::Array(array) => {
+                for item in array.items.iter() {
+                    let ArrayItem::Value(item) = item else {
+                        return None;
+                    };
+
+                    Self::compact_names(&item.value, names)?;
+                }
+            }
+            _ => return None,
+        }
+
+        Some(())
+    }
+
+    /// `extract()` defines a variable for each key in the given array. When the keys can't be known,
+    /// the whole scope is poisoned instead.
+    fn extract(&mut self, arguments: &ArgumentList) {
+        // The flags change which variables are written and what they're called.
+        let variables = match arguments.arguments.as_slice() {
+            [Argument::Positional(argument)] if argument.ellipsis.is_none() => {
+                self.extracted_variables(&argument.value)
+            }
+            _ => None,
+        };
+
+        let Some(variables) = variables else {
+            self.scopes.current_mut().poison();
+
+            return;
+        };
+
+        for (name, r#type) in variables {
+            if SimpleVariable::is_valid_name(&name) && name != b"this" {
+                self.scopes
+                    .current_mut()
+                    .set_named_variable(&Self::variable_symbol(name.as_bytestr()), r#type);
+            }
+        }
+    }
+
+    /// The variables defined by passing the given array to `extract()`, if its keys are known.
+    fn extracted_variables(
+        &self,
+        array: &Expression,
+    ) -> Option<Vec<(ByteString, Type<ResolvedName>)>> {
+        if let ExpressionKind::Array(array) = &array.kind {
+            return array
+                .items
+                .iter()
+                .map(|item| match item {
+                    ArrayItem::KeyValue(item) => match &item.key.kind {
+                        ExpressionKind::Literal(literal) => Some((
+                            literal.string_value()?.value().to_bytestring(),
+                            self.map.resolve(item.value.id).clone(),
+                        )),
+                        _ => None,
+                    },
+                    _ => None,
+                })
+                .collect();
+        }
+
+        match self.map.resolve(array.id) {
+            Type::Shaped {
+                items,
+                sealed: true,
+                ..
+            } => items
+                .iter()
+                .map(|item| match &item.key_name {
+                    Some(ShapeItemKey::String(key)) => Some((
+                        key.as_bytestr().strip_string_quotes().to_bytestring(),
+                        item.value_type.clone(),
+                    )),
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        }
+    }
+
+    fn mark_sensitive_arguments(&mut self, sensitive: Vec<NodeId>) {
+        for id in sensitive {
+            self.map.mark_sensitive(id);
+        }
+    }
+
+    /// Walk up the hierarchy of the given class to find the class that declares the given method.
+    fn find_declaring_class(
+        &self,
+        class: ReflectionClass<'a>,
+        method: &ByteStr,
+    ) -> Option<ReflectionClass<'a>> {
+        self.find_in_hierarchy(class, |class| class.get_method(method).is_some())
+    }
+
+    /// Walk up the hierarchy of the given class to find the first class that matches the predicate.
+    fn find_in_hierarchy(
+        &self,
+        class: ReflectionClass<'a>,
+        predicate: impl Fn(&ReflectionClass<'a>) -> bool,
+    ) -> Option<ReflectionClass<'a>> {
+        let mut class = class;
+        let mut visited = HashSet::new();
+
+        while visited.insert(class.name().to_bytestring()) {
+            if predicate(&class) {
+                return Some(class);
+            }
+
+            class = self
+                .index
+                .get_class(class.get_parent_name()?.to_bytestring())?;
+        }
+
+        None
+    }
+
+    fn receiver_type(class: &ReflectionClass) -> Type<ResolvedName> {
+        Type::Named(ResolvedName {
+            resolved: class.name().to_bytestring(),
+            original: class.short_name().to_bytestring(),
+        })
+    }
+
+    /// Get the return type of a method called on an instance of the given class, with any
+    /// late-bound `static` and `$this` types replaced by the class itself.
+    fn method_return_type(
+        class: &ReflectionClass,
+        method: &ReflectionMethod,
+    ) -> Option<Type<ResolvedName>> {
+        method
+            .effective_signature()
+            .get_return_type()
+            .map(|ty| expand_declared_type(&ty.with_receiver(&Self::receiver_type(class))))
+    }
+
+    /// Expand a type declared on a member of the given class, e.g. a property type or `@method` return type.
+    fn member_type(
+        class: &ReflectionClass,
+        ty: Option<ReflectionType<'_>>,
+    ) -> Option<Type<ResolvedName>> {
+        ty.map(|ty| expand_declared_type(&ty.to_type().with_receiver(&Self::receiver_type(class))))
+    }
+
+    /// Resolve a method that is actually declared on the given class or one of its parents.
+    fn resolve_declared_method(
+        &self,
+        class: ReflectionClass<'a>,
+        name: &ByteStr,
+        arguments: &ArgumentList,
+        r#static: bool,
+    ) -> Option<ResolvedMember> {
+        let declaring = self.find_declaring_class(class, name)?;
+        let method = if r#static {
+            declaring.get_static_method(name)?
+        } else {
+            declaring.get_method(name)?
+        };
+
+        Some(ResolvedMember {
+            r#type: Self::method_return_type(&class, &method),
+            sensitive: Self::find_sensitive_arguments(&method.get_parameters(), arguments),
+            references: Self::find_reference_arguments(method.effective_signature(), arguments),
+            via: None,
+        })
+    }
+
+    /// Resolve a method call against the given class.
+    ///
+    /// Declared methods win, then `@method` tags from the class docblock and finally the
+    /// `__call` (or `__callStatic`) magic method.
+    fn resolve_method_call(
+        &self,
+        class: ReflectionClass<'a>,
+        name: &ByteStr,
+        arguments: &ArgumentList,
+        r#static: bool,
+    ) -> Option<ResolvedMember> {
+        if self.find_declaring_class(class, name).is_some() {
+            return self.resolve_declared_method(class, name, arguments, r#static);
+        }
+
+        if let Some(owner) = self.find_in_hierarchy(class, |class| {
+            class
+                .get_virtual_method(name)
+                .is_some_and(|method| method.is_static() || !r#static)
+        }) {
+            let method = owner.get_virtual_method(name)?;
+
+            return Some(ResolvedMember {
+                r#type: Self::member_type(&class, method.get_return_type()),
+                sensitive: Vec::new(),
+                references: Self::find_reference_arguments(method.effective_signature(), arguments),
+                via: Some(VirtualMember::Docblock),
+            });
+        }
+
+        let magic: &ByteStr = if r#static {
+            b"__callStatic".into()
+        } else {
+            b"__call".into()
+        };
+
+        let mut resolved = self.resolve_declared_method(class, magic, arguments, r#static)?;
+
+        // Arguments are passed to the magic method as an array, so we can't know which are sensitive
+        // or passed by reference.
+        resolved.sensitive.clear();
+        resolved.references.clear();
+        resolved.via = Some(VirtualMember::Magic);
+
+        Some(resolved)
+    }
+
+    /// Resolve a property fetch against the given class.
+    ///
+    /// Declared properties win, then `@property` tags from the class docblock and finally the
+    /// `__get` magic method.
+    fn resolve_property_fetch(
+        &self,
+        class: ReflectionClass<'a>,
+        name: &ByteStr,
+    ) -> Option<ResolvedMember> {
+        if let Some(owner) = self.find_in_hierarchy(class, |class| {
+            class
+                .get_property(name)
+                .is_some_and(|property| !property.is_static())
+        }) {
+            let property = owner.get_property(name)?;
+
+            return Some(ResolvedMember {
+                r#type: Self::member_type(&class, property.get_type()),
+                sensitive: Vec::new(),
+                references: Vec::new(),
+                via: None,
+            });
+        }
+
+        if let Some(owner) =
+            self.find_in_hierarchy(class, |class| class.get_virtual_property(name).is_some())
+        {
+            let property = owner.get_virtual_property(name)?;
+
+            return Some(ResolvedMember {
+                r#type: Self::member_type(&class, property.get_type()),
+                sensitive: Vec::new(),
+                references: Vec::new(),
+                via: Some(VirtualMember::Docblock),
+            });
+        }
+
+        let declaring = self.find_declaring_class(class, b"__get".into())?;
+        let method = declaring.get_method(b"__get".into())?;
+
+        Some(ResolvedMember {
+            r#type: Self::method_return_type(&class, &method),
+            sensitive: Vec::new(),
+            references: Vec::new(),
+            via: Some(VirtualMember::Magic),
+        })
+    }
+
+    /// Determine the classes that the given receiver could be an instance of.
+    fn receiver_classes(&self, target: &Expression) -> Option<Vec<ReflectionClass<'a>>> {
+        let target = self.receiver_type_in_chain(target);
+
+        if !target.is_object_like() || target.is_object() {
+            return None;
+        }
+
+        self.determine_class_from_type(&target)
+    }
+
+    /// Combine the members resolved against each possible class of a receiver into a single type,
+    /// recording how the member was resolved for the given node.
+    fn insert_resolved_members(
+        &mut self,
+        id: NodeId,
+        resolved: Vec<ResolvedMember>,
+        nullsafe: bool,
+    ) {
+        let types = resolved
+            .iter()
+            .map(|member| member.r#type.clone().unwrap_or(Type::Mixed))
+            .collect::<Vec<_>>();
+
+        let mut r#type = self.simplify_union(types);
+
+        // FIXME: If we can determine that the thing we're calling isn't nullable, we can
+        // omit the null type from the union.
+        if nullsafe {
+            r#type = r#type.with_null();
+        }
+
+        // The least reliable resolution wins, so that analyses can be conservative.
+        if let Some(via) = resolved.iter().filter_map(|member| member.via).max() {
+            self.map.mark_virtual_member(id, via);
+        }
+
+        // A variable passed by reference could have the type of the parameter from any of the classes.
+        let mut references: Vec<(SimpleVariable, Vec<Type<ResolvedName>>)> = Vec::new();
+
+        for (variable, r#type) in resolved.iter().flat_map(|member| member.references.iter()) {
+            match references
+                .iter_mut()
+                .find(|(other, _)| other.symbol == variable.symbol)
+            {
+                Some((_, types)) => types.push(r#type.clone()),
+                None => references.push((variable.clone(), vec![r#type.clone()])),
+            }
+        }
+
+        let references = references
+            .into_iter()
+            .map(|(variable, types)| (variable, self.simplify_union(types)))
+            .collect();
+
+        let sensitive = resolved
+            .into_iter()
+            .flat_map(|member| member.sensitive)
+            .collect();
+
+        self.map.insert(id, r#type);
+        self.mark_sensitive_arguments(sensitive);
+        self.assign_references(references);
+    }
+
+    /// Determine whether evaluating the given expression can short-circuit to `null`, i.e. whether
+    /// it is a link in a chain of property fetches, method calls and array accesses that contains
+    /// a nullsafe operator. Parentheses end the chain, as they do in PHP.
+    fn short_circuits(expression: &Expression) -> bool {
+        match &expression.kind {
+            ExpressionKind::NullsafePropertyFetch(_) | ExpressionKind::NullsafeMethodCall(_) => {
+                true
+            }
+            ExpressionKind::PropertyFetch(inner) => Self::short_circuits(&inner.target),
+            ExpressionKind::MethodCall(inner) => Self::short_circuits(&inner.target),
+            ExpressionKind::StaticPropertyFetch(inner) => Self::short_circuits(&inner.target),
+            ExpressionKind::StaticMethodCall(inner) => Self::short_circuits(&inner.target),
+            ExpressionKind::StaticVariableMethodCall(inner) => Self::short_circuits(&inner.target),
+            ExpressionKind::FunctionCall(inner) => Self::short_circuits(&inner.target),
+            ExpressionKind::ArrayIndex(inner) => Self::short_circuits(&inner.array),
+            _ => false,
+        }
+    }
+
+    /// Get the type of the given receiver as seen by the next link of its chain.
+    ///
+    /// When the receiver short-circuits, the rest of the chain is only evaluated if it isn't `null`.
+    fn receiver_type_in_chain(&self, receiver: &Expression) -> Type<ResolvedName> {
+        let r#type = self.map.resolve(receiver.id);
+
+        if Self::short_circuits(receiver) {
+            r#type.without_null()
+        } else {
+            r#type.clone()
+        }
+    }
+
+    /// Determine the type of the value read from an array (or string) of the given type.
+    fn array_value_type(
+        &self,
+        array: &Type<ResolvedName>,
+        index: Option<&Expression>,
+    ) -> Type<ResolvedName> {
+        self.array_key_value_type(array, index.and_then(Self::array_key).as_ref())
+    }
+
+    /// Determine the type of the value stored under the given key of an array of the given type,
+    /// or of any value in the array if the key isn't known.
+    fn array_key_value_type(
+        &self,
+        array: &Type<ResolvedName>,
+        key: Option<&ArrayKey>,
+    ) -> Type<ResolvedName> {
+        match array {
+            Type::TypedArray(_, value) => value.as_ref().clone(),
+            Type::Generic(base, arguments)
+                if matches!(
+                    base.as_ref(),
+                    Type::Array
+                        | Type::NonEmptyArray
+                        | Type::List
+                        | Type::NonEmptyList
+                        | Type::Iterable
+                ) =>
+            {
+                arguments
+                    .last()
+                    .map_or(Type::Mixed, |argument| argument.r#type.clone())
+            }
+            Type::Shaped {
+                items,
+                unsealed_type,
+                ..
+            } => {
+                // Items without a key are numbered from zero, like in a list.
+                let mut positions = 0..;
+
+                let item = key.and_then(|key| {
+                    items.iter().find(|item| {
+                        let name = match &item.key_name {
+                            Some(ShapeItemKey::Integer(name) | ShapeItemKey::String(name)) => {
+                                ArrayKey::new(name.as_bytestr().strip_string_quotes())
+                            }
+                            None => ArrayKey::Integer(positions.next().unwrap_or(0)),
+                        };
+
+                        name == *key
+                    })
+                });
+
+                match (item, unsealed_type) {
+                    (Some(item), _) => item.value_type.clone(),
+                    (None, Some(unsealed)) => unsealed.value_type.clone(),
+                    (None, None) => Type::Mixed,
+                }
+            }
+            Type::String
+            | Type::LiteralString(_)
+            | Type::NonEmptyString
+            | Type::NumericString
+            | Type::ClassString
+            | Type::CallableString => Type::String,
+            Type::Null => Type::Null,
+            // Objects can only be read like an array through `ArrayAccess::offsetGet()`.
+            Type::Named(_) | Type::Generic(..) => self
+                .protocol_method_type(array, b"ArrayAccess", b"offsetGet")
+                .unwrap_or(Type::Mixed),
+            // An array type intersected with an `ArrayAccess` class describes the same object, so
+            // the protocol method wins over the array type.
+            Type::Intersection(types) => types
+                .iter()
+                .find_map(|ty| self.protocol_method_type(ty, b"ArrayAccess", b"offsetGet"))
+                .or_else(|| {
+                    types
+                        .iter()
+                        .map(|ty| self.array_key_value_type(ty, key))
+                        .find(|ty| *ty != Type::Mixed)
+                })
+                .unwrap_or(Type::Mixed),
+            Type::Nullable(inner) => self.array_key_value_type(inner, key).with_null(),
+            Type::Union(types) => self.simplify_union(
+                types
+                    .iter()
+                    .map(|ty| self.array_key_value_type(ty, key))
+                    .collect(),
+            ),
+            _ => Type::Mixed,
+        }
+    }
+
+    /// Get the return type of a method from one of PHP's protocol interfaces, e.g. `offsetGet()`
+    /// from `ArrayAccess`, when the given type is a class that implements the interface.
+    fn protocol_method_type(
+        &self,
+        r#type: &Type<ResolvedName>,
+        interface: &[u8],
+        method: &[u8],
+    ) -> Option<Type<ResolvedName>> {
+        let class = match r#type {
+            Type::Named(name) => self.index.get_class(name.resolved.clone())?,
+            Type::Generic(base, _) => match base.as_ref() {
+                Type::Named(name) => self.index.get_class(name.resolved.clone())?,
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        if !self
+            .index
+            .implements(class.name().to_bytestring(), interface)
+        {
+            return None;
+        }
+
+        let declaring = self.find_declaring_class(class, method.into())?;
+        let method = declaring.get_method(method.into())?;
+
+        Some(Self::method_return_type(&class, &method).unwrap_or(Type::Mixed))
+    }
+
+    /// Determine the types of the keys and values produced by iterating over a value of the
+    /// given type with `foreach`.
+    fn iteration_types(
+        &self,
+        r#type: &Type<ResolvedName>,
+        depth: usize,
+    ) -> (Type<ResolvedName>, Type<ResolvedName>) {
+        // `getIterator()` can return another `IteratorAggregate`, so give up on cycles.
+        if depth > MAX_ITERATOR_DEPTH {
+            return (Type::Mixed, Type::Mixed);
+        }
+
+        match r#type {
+            Type::TypedArray(key, value) => (key.as_ref().clone(), value.as_ref().clone()),
+            Type::List | Type::NonEmptyList => (Type::NonNegativeInteger, Type::Mixed),
+            Type::Shaped { .. } => (Type::Mixed, self.array_value_type(r#type, None)),
+            Type::Generic(base, arguments) => match (base.as_ref(), arguments.as_slice()) {
+                (Type::List | Type::NonEmptyList, [value]) => {
+                    (Type::NonNegativeInteger, value.r#type.clone())
+                }
+                // Arrays and traversables are templated over their keys and values, while
+                // generators add the types that are sent to them and returned after them.
+                (_, [value]) => (Type::Mixed, value.r#type.clone()),
+                (_, [key, value, ..]) => (key.r#type.clone(), value.r#type.clone()),
+                _ => (Type::Mixed, Type::Mixed),
+            },
+            Type::Named(_) => {
+                if let Some(iterator) =
+                    self.protocol_method_type(r#type, b"IteratorAggregate", b"getIterator")
+                {
+                    return self.iteration_types(&iterator, depth + 1);
+                }
+
+                match self.protocol_method_type(r#type, b"Iterator", b"current") {
+                    Some(value) => (
+                        self.protocol_method_type(r#type, b"Iterator", b"key")
+                            .unwrap_or(Type::Mixed),
+                        value,
+                    ),
+                    None => (Type::Mixed, Type::Mixed),
+                }
+            }
+            Type::Nullable(inner) => self.iteration_types(inner, depth),
+            Type::Union(types) => {
+                let (keys, values) = types
+                    .iter()
+                    .filter(|ty| !matches!(ty, Type::Null))
+                    .map(|ty| self.iteration_types(ty, depth))
+                    .unzip();
+
+                (self.simplify_union(keys), self.simplify_union(values))
+            }
+            _ => (Type::Mixed, Type::Mixed),
+        }
+    }
+
+    /// `count()` always returns an integer, but a `Countable` object decides what that integer is.
+    fn count_type(&self, arguments: &ArgumentList) -> Type<ResolvedName> {
+        let Some(Argument::Positional(argument)) = arguments.arguments.first() else {
+            return Type::Integer;
+        };
+
+        match self.protocol_method_type(self.map.resolve(argument.value.id), b"Countable", b"count")
+        {
+            Some(r#type @ (Type::Integer | Type::NonNegativeInteger)) => r#type,
+            _ => Type::Integer,
+        }
+    }
+
+    /// Assign the key or value of a `foreach` loop to its variable, or to the variables that it's
+    /// destructured into.
+    fn assign_iteration_variable(&mut self, target: &Expression, r#type: Type<ResolvedName>) {
+        let variable = match &target.kind {
+            ExpressionKind::Variable(variable) => variable,
+            ExpressionKind::Array(array) => {
+                let entries = array.items.iter().map(|item| match item {
+                    ArrayItem::Skipped(_) => None,
+                    ArrayItem::Value(ArrayItemValue { value, .. })
+                    | ArrayItem::ReferencedValue(ArrayItemReferencedValue { value, .. })
+                    | ArrayItem::SpreadValue(ArrayItemSpreadValue { value, .. }) => {
+                        Some((None, value))
+                    }
+                    ArrayItem::KeyValue(ArrayItemKeyValue { key, value, .. })
+                    | ArrayItem::ReferencedKeyValue(ArrayItemReferencedKeyValue {
+                        key,
+                        value,
+                        ..
+                    }) => Some((Some(key), value)),
+                });
+
+                return self.destructure_iteration_variables(entries.collect(), &r#type);
+            }
+            ExpressionKind::List(list) => {
+                let entries = list.items.iter().map(|entry| match entry {
+                    ListEntry::Skipped(_) => None,
+                    ListEntry::Value(ListEntryValue { value, .. }) => Some((None, value)),
+                    ListEntry::KeyValue(ListEntryKeyValue { key, value, .. }) => {
+                        Some((Some(key), value))
+                    }
+                });
+
+                return self.destructure_iteration_variables(entries.collect(), &r#type);
+            }
+            _ => return,
+        };
+
+        if !variable.is_simple() {
+            return;
+        }
+
+        let variable = variable.to_simple();
+
+        self.scopes
+            .current_mut()
+            .set_variable(variable, r#type.clone());
+        self.map.insert(variable.id, r#type);
+    }
+
+    /// Assign the values of an array of the given type to the variables that it's destructured
+    /// into. Entries without a key are numbered from zero, and skipped entries still take up a
+    /// position.
+    fn destructure_iteration_variables(
+        &mut self,
+        entries: Vec<Option<(Option<&Expression>, &Expression)>>,
+        r#type: &Type<ResolvedName>,
+    ) {
+        let mut positions = 0..;
+
+        for entry in entries {
+            let key = match entry {
+                Some((Some(key), _)) => Self::array_key(key),
+                _ => positions.next().map(ArrayKey::Integer),
+            };
+
+            if let Some((_, value)) = entry {
+                let r#type = self.array_key_value_type(r#type, key.as_ref());
+
+                self.assign_iteration_variable(value, r#type);
+            }
+        }
+    }
+
+    /// Get the key used by an array access, if its value is known. The key is normalized the
+    /// same way that PHP does, so `'5'`, `5.9` and `true` are integer keys.
+    fn array_key(index: &Expression) -> Option<ArrayKey> {
+        ArrayKey::from_expression(index)
+    }
+
+    /// Get the type of a key in an array literal, after PHP has cast it to an integer or string.
+    fn array_key_type(&self, key: &Expression) -> Type<ResolvedName> {
+        match Self::array_key(key) {
+            Some(ArrayKey::Integer(_)) => Type::Integer,
+            _ => match self.map.resolve(key.id) {
+                Type::Float | Type::Boolean | Type::True | Type::False => Type::Integer,
+                Type::Null => Type::LiteralString(ByteString::empty()),
+                r#type => r#type.clone(),
+            },
+        }
+    }
+
+    fn member_name(member: &Expression) -> Option<&ByteStr> {
+        match &member.kind {
+            ExpressionKind::Identifier(identifier) if identifier.is_simple() => {
+                Some(identifier.to_simple().symbol.as_bytestr())
+            }
+            _ => None,
+        }
+    }
+
+    fn infer_method_call(
+        &mut self,
+        id: NodeId,
+        target: &Expression,
+        method: &Expression,
+        arguments: &ArgumentList,
+        nullsafe: bool,
+    ) {
+        // FIXME: Can we support dynamic method names here if we know the value of the expression?
+        let Some(method) = Self::member_name(method) else {
+            self.map.insert(id, Type::Mixed);
+
+            return;
+        };
+
+        if !self.receiver_type_in_chain(target).is_object_like() {
+            self.map.insert(id, Type::Invalid);
+
+            return;
+        }
+
+        // If we can't figure out what class-like thing we're calling the method on,
+        // we'll just return a mixed type and continue on.
+        let Some(classes) = self.receiver_classes(target) else {
+            self.map.insert(id, Type::Mixed);
+
+            return;
+        };
+
+        let resolved = classes
+            .into_iter()
+            .filter_map(|class| self.resolve_method_call(class, method, arguments, false))
+            .collect::<Vec<_>>();
+
+        if resolved.is_empty() {
+            self.map.insert(id, Type::Mixed);
+
+            return;
+        }
+
+        self.insert_resolved_members(id, resolved, nullsafe);
+    }
+
+    fn infer_property_fetch(
+        &mut self,
+        id: NodeId,
+        target: &Expression,
+        property: &Expression,
+        nullsafe: bool,
+    ) {
+        let resolved = match (Self::member_name(property), self.receiver_classes(target)) {
+            (Some(property), Some(classes)) => classes
+                .into_iter()
+                .filter_map(|class| self.resolve_property_fetch(class, property))
+                .collect::<Vec<_>>(),
+            _ => Vec::new(),
+        };
+
+        if resolved.is_empty() {
+            self.map.insert(id, Type::Mixed);
+
+            return;
+        }
+
+        self.insert_resolved_members(id, resolved, nullsafe);
+    }
+
+    /// Calling an object as a function calls its `__invoke` method.
+    fn resolve_invokable_call(
+        &self,
+        target: &Expression,
+        arguments: &ArgumentList,
+    ) -> Option<Vec<ResolvedMember>> {
+        let resolved = self
+            .receiver_classes(target)?
+            .into_iter()
+            .filter_map(|class| {
+                self.resolve_declared_method(class, b"__invoke".into(), arguments, false)
+            })
+            .collect::<Vec<_>>();
+
+        (!resolved.is_empty()).then_some(resolved)
+    }
+
+    /// Determine what the given expression would look like once converted to a string.
+    fn string_value(&self, expression: &Expression) -> StringValue {
+        if let Some(value) = expression.parts().and_then(|parts| parts.constant_value()) {
+            return StringValue::Literal(value);
+        }
+
+        match self.map.resolve(expression.id) {
+            Type::Integer | Type::Float => {
+                let converted = match &expression.kind {
+                    ExpressionKind::Literal(literal) => match literal.integer_value() {
+                        Some(IntegerValue::Int(value)) => Some(ByteString::from(value.to_string())),
+                        Some(IntegerValue::Overflow(value)) => strings::float_to_string(value),
+                        None => literal.float_value().and_then(strings::float_to_string),
+                    },
+                    _ => None,
+                };
+
+                // Numbers always produce at least one character, even when we can't work out which.
+                converted.map_or(StringValue::NonEmpty, StringValue::Literal)
+            }
+            r#type => Self::type_string_value(r#type),
+        }
+    }
+
+    /// The type of a string-like expression, built from what we know about each of its parts.
+    fn string_like_type(&self, string: &impl StringLike) -> Type<ResolvedName> {
+        let Some(parts) = string.parts() else {
+            return Type::String;
+        };
+
+        let values = parts
+            .map(|part| match part.as_ref() {
+                StringPart::Literal(literal) => StringValue::Literal(literal.value.clone()),
+                StringPart::Expression(part) => self.string_value(&part.expression),
+            })
+            .collect::<Vec<_>>();
+
+        strings::concat(values, self.max_literal_string_length)
+    }
+
+    /// What we know about a value of the given type once it has been converted to a string.
+    fn type_string_value(r#type: &Type<ResolvedName>) -> StringValue {
+        match r#type {
+            Type::LiteralString(value) => StringValue::Literal(value.clone()),
+            Type::ConstExpr(inner) => match inner.as_ref() {
+                ConstExpr::String(value) | ConstExpr::Integer(value) => {
+                    StringValue::Literal(value.clone())
+                }
+                ConstExpr::NegativeInteger(value) => {
+                    let mut negated = ByteString::from("-");
+                    negated.extend(value);
+
+                    StringValue::Literal(negated)
+                }
+                _ => StringValue::Unknown,
+            },
+            Type::Integer | Type::Float => StringValue::NonEmpty,
+            Type::True => StringValue::Literal(ByteString::from("1")),
+            Type::False | Type::Null => StringValue::Literal(ByteString::empty()),
+            Type::NonEmptyString
+            | Type::NumericString
+            | Type::ClassString
+            | Type::CallableString => StringValue::NonEmpty,
+            _ => StringValue::Unknown,
+        }
+    }
+
+    fn simplify_union(&self, mut types: Vec<Type<ResolvedName>>) -> Type<ResolvedName> {
+        if types.len() == 1 {
+            return types[0].clone();
+        }
+
+        let mut uniques = HashSet::new();
+
+        types.retain(|ty| uniques.insert(ty.clone()));
+
+        if types.len() == 1 {
+            return types[0].clone();
+        }
+
+        Type::Union(types)
+    }
+
+    /// The type of the value stored by an assignment, given the current type of its target. A
+    /// target without a current type is undefined, which PHP treats as `null`.
+    fn assignment_type(
+        &self,
+        node: &AssignmentOperationExpression,
+        current: Option<Type<ResolvedName>>,
+    ) -> Type<ResolvedName> {
+        let current = current.unwrap_or(Type::Null);
+        let right = self.map.resolve(node.right.id).clone();
+
+        match node.kind {
+            AssignmentOperationKind::Assign(_) => right,
+            AssignmentOperationKind::Concat(_) => strings::concat(
+                [
+                    Self::type_string_value(&current),
+                    self.string_value(&node.right),
+                ],
+                self.max_literal_string_length,
+            ),
+            AssignmentOperationKind::Addition(_)
+            | AssignmentOperationKind::Subtraction(_)
+            | AssignmentOperationKind::Multiplication(_)
+            | AssignmentOperationKind::Division(_)
+            | AssignmentOperationKind::Modulo(_)
+            | AssignmentOperationKind::Exponentiation(_) => {
+                numbers::arithmetic(node.kind, &current, &right)
+            }
+            AssignmentOperationKind::BitwiseAnd(_)
+            | AssignmentOperationKind::BitwiseOr(_)
+            | AssignmentOperationKind::BitwiseXor(_)
+            | AssignmentOperationKind::LeftShift(_)
+            | AssignmentOperationKind::RightShift(_) => Type::Integer,
+            // The right-hand side is only evaluated when the current value is `null`.
+            AssignmentOperationKind::Coalesce(_) if !current.allows_null() => current,
+            AssignmentOperationKind::Coalesce(_) => match current.without_null() {
+                Type::Never => right,
+                current => self.simplify_union(vec![current, right]),
+            },
+        }
+    }
+
+    /// Assign to an element of an array, widening the key and value types of the array when it is
+    /// stored in a variable.
+    fn assign_array_element(
+        &mut self,
+        node: &AssignmentOperationExpression,
+        target: &ArrayIndexExpression,
+    ) -> Type<ResolvedName> {
+        // The indexes from the variable outwards, e.g. `'b'` and then the append in `$a['b'][] = 1`.
+        let mut indexes = vec![target.index.as_deref()];
+        let mut root = target;
+
+        while let ExpressionKind::ArrayIndex(inner) = &root.array.kind {
+            indexes.push(inner.index.as_deref());
+            root = inner;
+        }
+
+        indexes.reverse();
+
+        for index in indexes.iter().flatten() {
+            self.visit_expression(index);
+        }
+
+        let Some(variable) = Self::simple_variable(&root.array) else {
+            return self.assignment_type(node, Some(Type::Mixed));
+        };
+
+        // The arrays that each index is written to, where `None` is an array that doesn't exist yet.
+        let mut arrays = vec![self.get_variable(variable)];
+
+        for index in &indexes[..indexes.len() - 1] {
+            let array = arrays
+                .last()
+                .unwrap()
+                .as_ref()
+                .and_then(|array| self.existing_element_type(array, *index));
+
+            arrays.push(array);
+        }
+
+        // Compound assignments read the element before writing to it.
+        let current = match &node.kind {
+            AssignmentOperationKind::Assign(_) => None,
+            _ => Some(arrays.last().unwrap().as_ref().map_or(Type::Null, |array| {
+                self.array_value_type(array, target.index.as_deref())
+            })),
+        };
+
+        let r#type = self.assignment_type(node, current);
+        let mut widened = r#type.clone();
+
+        for (array, index) in arrays.into_iter().zip(indexes).rev() {
+            match self.array_with_element(array, index, widened) {
+                Some(array) => widened = array,
+                None => return r#type,
+            }
+        }
+
+        self.scopes
+            .current_mut()
+            .set_variable(variable, widened.clone());
+        self.map.insert(variable.id, widened);
+
+        r#type
+    }
+
+    /// The type of an element that's about to be written to, or `None` if it doesn't exist yet.
+    fn existing_element_type(
+        &self,
+        array: &Type<ResolvedName>,
+        index: Option<&Expression>,
+    ) -> Option<Type<ResolvedName>> {
+        let index = index?;
+
+        if let (Some(shape), Some(key)) = (Shape::from_type(array), Self::array_key(index)) {
+            return shape.get(&key).cloned();
+        }
+
+        match self.array_value_type(array, Some(index)) {
+            // An empty array has no values, so the value type is an empty union.
+            Type::Null => None,
+            Type::Union(types) if types.is_empty() => None,
+            r#type => Some(r#type),
+        }
+    }
+
+    /// The type of an array after a value is written to it. An empty index appends the value with
+    /// the next integer key.
+    ///
+    /// Returns `None` when the array's type isn't specific enough to be widened, e.g. `array`.
+    fn array_with_element(
+        &self,
+        array: Option<Type<ResolvedName>>,
+        index: Option<&Expression>,
+        r#type: Type<ResolvedName>,
+    ) -> Option<Type<ResolvedName>> {
+        let key = index.map_or(Type::Integer, |index| self.map.resolve(index.id).clone());
+
+        match array {
+            // Writing to an undefined variable or `null` creates a new array.
+            None | Some(Type::Null) => Some(Type::TypedArray(Box::new(key), Box::new(r#type))),
+            Some(array @ Type::Shaped { .. }) => Shape::from_type(&array).map(|mut shape| {
+                let literal = match index {
+                    Some(index) => Self::array_key(index).map(Some),
+                    None => Some(None),
+                };
+
+                // Writing to a key that can't be known turns the shape into a regular array.
+                if let Some(literal) = literal {
+                    shape.insert(literal, r#type.clone());
+
+                    if let Some(shape) = shape.clone().into_type() {
+                        return shape;
+                    }
+                }
+
+                let (mut keys, mut values) = shape.key_and_value_types();
+                keys.push(key);
+                values.push(r#type);
+
+                Type::TypedArray(
+                    Box::new(self.simplify_union(keys)),
+                    Box::new(self.simplify_union(values)),
+                )
+            }),
+            // An empty array's key type is only a placeholder, so the first write replaces it.
+            Some(Type::TypedArray(_, values)) if *values == Type::Union(Vec::new()) => {
+                Some(Type::TypedArray(Box::new(key), Box::new(r#type)))
+            }
+            // When the only key is the literal string being written to, every value is replaced.
+            Some(Type::TypedArray(keys, _))
+                if matches!(key, Type::LiteralString(_)) && *keys == key =>
+            {
+                Some(Type::TypedArray(Box::new(key), Box::new(r#type)))
+            }
+            Some(Type::TypedArray(keys, values)) => Some(Type::TypedArray(
+                Box::new(self.widen(*keys, key)),
+                Box::new(self.widen(*values, r#type)),
+            )),
+            Some(Type::Generic(base, mut arguments)) => match (base.as_ref(), arguments.len()) {
+                // Appending to a list keeps it a list, but any other key turns it into an array.
+                (Type::List | Type::NonEmptyList, 1) if index.is_none() => {
+                    let value = arguments.remove(0).r#type;
+
+                    Some(Type::Generic(
+                        Box::new(Type::NonEmptyList),
+                        vec![argument(self.widen(value, r#type))],
+                    ))
+                }
+                (Type::List | Type::NonEmptyList, 1) => {
+                    let value = arguments.remove(0).r#type;
+
+                    Some(Type::TypedArray(
+                        Box::new(self.widen(Type::Integer, key)),
+                        Box::new(self.widen(value, r#type)),
+                    ))
+                }
+                (Type::Array | Type::NonEmptyArray, 1) => {
+                    let value = arguments.remove(0).r#type;
+
+                    Some(Type::Generic(
+                        Box::new(Type::NonEmptyArray),
+                        vec![argument(self.widen(value, r#type))],
+                    ))
+                }
+                (Type::Array | Type::NonEmptyArray, 2) => {
+                    let value = arguments.remove(1).r#type;
+                    let keys = arguments.remove(0).r#type;
+
+                    Some(Type::Generic(
+                        Box::new(Type::NonEmptyArray),
+                        vec![
+                            argument(self.widen(keys, key)),
+                            argument(self.widen(value, r#type)),
+                        ],
+                    ))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Add a type to the types that an array's keys or values could already have.
+    fn widen(
+        &self,
+        existing: Type<ResolvedName>,
+        r#type: Type<ResolvedName>,
+    ) -> Type<ResolvedName> {
+        self.simplify_union(
+            [existing, r#type]
+                .into_iter()
+                .flat_map(|r#type| match r#type {
+                    Type::Union(types) => types,
+                    r#type => vec![r#type],
+                })
+                .collect(),
+        )
+    }
+
+    /// Forget what is known about a value that is passed to `unset()`.
+    ///
+    /// Keys can only be removed from sealed shapes when the key is a literal. Other arrays keep
+    /// their type, since it already describes every key that could be missing.
+    fn unset(&mut self, value: &Expression) {
+        match &value.kind {
+            ExpressionKind::Variable(variable) if variable.is_simple() => {
+                self.scopes
+                    .current_mut()
+                    .unset_variable(variable.to_simple());
+            }
+            ExpressionKind::ArrayIndex(node) => {
+                let Some(variable) = Self::simple_variable(&node.array) else {
+                    return;
+                };
+
+                let Some(key) = node.index.as_deref().and_then(Self::array_key) else {
+                    return;
+                };
+
+                let Some(mut shape) = self
+                    .get_variable(variable)
+                    .and_then(|array| Shape::from_type(&array))
+                else {
+                    return;
+                };
+
+                shape.remove(&key);
+
+                if let Some(r#type) = shape.into_type() {
+                    self.scopes.current_mut().set_variable(variable, r#type);
+                }
+            }
+            ExpressionKind::PropertyFetch(node) if Self::is_this(&node.target) => {
+                if let Some(property) = Self::member_name(&node.property) {
+                    self.scopes
+                        .current_mut()
+                        .properties
+                        .remove(&property.to_bytestring());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Get the variable that an assignment by reference (`$a = &$b`) binds to.
+    fn reference_target(node: &AssignmentOperationExpression) -> Option<&SimpleVariable> {
+        match (&node.kind, &node.right.kind) {
+            (AssignmentOperationKind::Assign(_), ExpressionKind::Reference(reference)) => {
+                Self::simple_variable(&reference.right)
+            }
+            _ => None,
+        }
+    }
+
+    fn is_this(expression: &Expression) -> bool {
+        matches!(&expression.kind, ExpressionKind::Variable(variable) if variable.is_simple() && variable.to_simple().symbol == b"$this")
+    }
+
+    /// The type of an enum case, e.g. `Status::Active`, which is an instance of the enum itself.
+    fn determine_enum_case_type(
+        &self,
+        node: &ConstantFetchExpression,
+    ) -> Option<Type<ResolvedName>> {
+        let ExpressionKind::Name(name) = &node.target.kind else {
+            return None;
+        };
+
+        let name = name.as_resolved()?;
+        let class = self.index.get_class(name.resolved.as_bytestr())?;
+
+        if !node.constant.is_simple() || !class.is_enum() {
+            return None;
+        }
+
+        class
+            .has_case(node.constant.to_simple().symbol.as_bytestr())
+            .then(|| Type::Named(name.clone()))
+    }
+
+    /// Look up a class constant in the index, preferring its declared type over the type of its
+    /// literal value.
+    fn determine_class_constant_type(
+        &self,
+        node: &ConstantFetchExpression,
+    ) -> Option<Type<ResolvedName>> {
+        if !node.constant.is_simple() {
+            return None;
+        }
+
+        let name = node.constant.to_simple().symbol.as_bytestr();
+        let classes = match &node.target.kind {
+            ExpressionKind::Name(target) => {
+                vec![self
+                    .index
+                    .get_class(target.as_resolved()?.resolved.as_bytestr())?]
+            }
+            _ => self.receiver_classes(&node.target)?,
+        };
+
+        let types = classes
+            .into_iter()
+            .map(|class| {
+                let owner = self.find_constant_owner(class, name)?;
+                let constant = owner.get_constant(name)?;
+
+                Self::member_type(&class, constant.get_type()).or_else(|| {
+                    constant
+                        .get_value_type()
+                        .map(|ty| self.limit_literal_string(ty.to_type().clone()))
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(self.simplify_union(types))
+    }
+
+    /// Find the class-like that declares the given constant, which can be a parent class or one of
+    /// the interfaces implemented anywhere in the hierarchy.
+    fn find_constant_owner(
+        &self,
+        class: ReflectionClass<'a>,
+        name: &ByteStr,
+    ) -> Option<ReflectionClass<'a>> {
+        let mut pending = VecDeque::from([class]);
+        let mut visited = HashSet::new();
+
+        while let Some(class) = pending.pop_front() {
+            if !visited.insert(class.name().to_bytestring()) {
+                continue;
+            }
+
+            if class.has_constant(name) {
+                return Some(class);
+            }
+
+            pending.extend(
+                class
+                    .get_parent_name()
+                    .into_iter()
+                    .chain(class.get_interface_names())
+                    .filter_map(|name| self.index.get_class(name.to_bytestring())),
+            );
+        }
+
+        None
+    }
+
+    /// Literal strings that are longer than the limit are widened, as they are for string literals.
+    fn limit_literal_string(&self, ty: Type<ResolvedName>) -> Type<ResolvedName> {
+        match ty {
+            Type::LiteralString(value) if value.len() > self.max_literal_string_length => {
+                Type::NonEmptyString
+            }
+            ty => ty,
+        }
+    }
+
+    /// Give the variable a narrower type while visiting part of the AST, then restore the type
+    /// it had before unless it was reassigned.
+    fn with_narrowed_variable(
+        &mut self,
+        variable: &SimpleVariable,
+        r#type: Type<ResolvedName>,
+        visit: impl FnOnce(&mut Self),
+    ) {
+        self.with_narrowed_variables(&[(variable.clone(), r#type)], visit);
+    }
+
+    /// Give each of the variables a narrower type while visiting part of the AST, then restore the
+    /// types they had before unless they were reassigned.
+    fn with_narrowed_variables(
+        &mut self,
+        narrowed: &[(SimpleVariable, Type<ResolvedName>)],
+        visit: impl FnOnce(&mut Self),
+    ) {
+        let previous = narrowed
+            .iter()
+            .map(|(variable, _)| {
+                self.scopes
+                    .current()
+                    .variables
+                    .get(&variable.symbol)
+                    .cloned()
+            })
+            .collect::<Vec<_>>();
+
+        self.narrow(narrowed);
+
+        visit(self);
+
+        let scope = self.scopes.current_mut();
+
+        for ((variable, r#type), previous) in narrowed.iter().zip(previous).rev() {
+            if scope.variables.get(&variable.symbol) != Some(r#type) {
+                continue;
+            }
+
+            match previous {
+                Some(previous) => scope.set_variable(variable, previous),
+                None => {
+                    scope.variables.remove(&variable.symbol);
+                }
+            }
+        }
+    }
+
+    fn narrow(&mut self, narrowed: &[(SimpleVariable, Type<ResolvedName>)]) {
+        for (variable, r#type) in narrowed {
+            self.scopes
+                .current_mut()
+                .set_variable(variable, r#type.clone());
+        }
+    }
+
+    /// Work out the types that variables have when the condition is true, and when it's false.
+    ///
+    /// Only checks against `null` are understood, i.e. `$a === null`, `$a !== null` and
+    /// `is_null($a)`, along with `!`, `&&` and `||` made up of them.
+    fn narrow_condition(&self, condition: &Expression) -> Narrowing {
+        match &condition.kind {
+            ExpressionKind::Parenthesized(inner) => self.narrow_condition(&inner.expr),
+            ExpressionKind::LogicalOperation(operation) => match &operation.kind {
+                LogicalOperationKind::Not { right, .. } => self.narrow_condition(right).negate(),
+                // Both sides are true when the whole condition is, but when it's false it isn't
+                // known which side was false.
+                LogicalOperationKind::And { left, right, .. }
+                | LogicalOperationKind::LogicalAnd { left, right, .. } => Narrowing {
+                    truthy: [
+                        self.narrow_condition(left).truthy,
+                        self.narrow_condition(right).truthy,
+                    ]
+                    .concat(),
+                    falsy: Vec::new(),
+                },
+                LogicalOperationKind::Or { left, right, .. }
+                | LogicalOperationKind::LogicalOr { left, right, .. } => Narrowing {
+                    truthy: Vec::new(),
+                    falsy: [
+                        self.narrow_condition(left).falsy,
+                        self.narrow_condition(right).falsy,
+                    ]
+                    .concat(),
+                },
+                _ => Narrowing::default(),
+            },
+            ExpressionKind::ComparisonOperation(operation) => match &operation.kind {
+                ComparisonOperationKind::Identical { left, right, .. } => {
+                    self.narrow_null_comparison(left, right)
+                }
+                ComparisonOperationKind::NotIdentical { left, right, .. } => {
+                    self.narrow_null_comparison(left, right).negate()
+                }
+                _ => Narrowing::default(),
+            },
+            ExpressionKind::FunctionCall(call) => {
+                match (&call.target.kind, call.arguments.arguments.as_slice()) {
+                    (ExpressionKind::Name(name), [Argument::Positional(argument)])
+                        if Self::is_function(name, b"is_null") && argument.ellipsis.is_none() =>
+                    {
+                        Self::simple_variable(&argument.value)
+                            .map(|variable| self.narrow_null(variable))
+                            .unwrap_or_default()
+                    }
+                    _ => Narrowing::default(),
+                }
+            }
+            _ => Narrowing::default(),
+        }
+    }
+
+    /// The narrowing for `$a === null`, which can be written either way around.
+    fn narrow_null_comparison(&self, left: &Expression, right: &Expression) -> Narrowing {
+        let variable = match (&left.kind, &right.kind) {
+            (_, ExpressionKind::Null(_)) => Self::simple_variable(left),
+            (ExpressionKind::Null(_), _) => Self::simple_variable(right),
+            _ => None,
+        };
+
+        variable
+            .map(|variable| self.narrow_null(variable))
+            .unwrap_or_default()
+    }
+
+    /// The narrowing for a variable that is `null` when the condition is true.
+    fn narrow_null(&self, variable: &SimpleVariable) -> Narrowing {
+        // A variable that can't be `null` is left alone, since the branch where it's `null` never
+        // runs, and anything could be `null` when the type isn't known.
+        match self.get_variable(variable) {
+            Some(r#type) if r#type.allows_null() => Narrowing {
+                truthy: vec![(variable.clone(), Type::Null)],
+                falsy: vec![(variable.clone(), r#type.without_null())],
+            },
+            _ => Narrowing::default(),
+        }
+    }
+
+    /// The type of the variable in a `catch` block, i.e. an instance of any of the caught classes.
+    fn determine_catch_type(&self, types: &CatchType) -> Type<ResolvedName> {
+        let names = match &types.kind {
+            CatchTypeKind::Identifier(inner) => std::slice::from_ref(&inner.name),
+            CatchTypeKind::Union(inner) => &inner.names[..],
+        };
+
+        self.simplify_union(
+            names
+                .iter()
+                .map(|name| {
+                    name.as_resolved()
+                        .map_or(Type::Mixed, |name| Type::Named(name.clone()))
+                })
+                .collect(),
+        )
+    }
+
+    /// Merge the variables from each of the paths that control can take to reach the same point.
+    ///
+    /// A variable that isn't assigned on every path could be undefined, which PHP reads as `null`.
+    fn merge_variables(
+        &self,
+        paths: Vec<HashMap<ByteString, Type<ResolvedName>>>,
+    ) -> HashMap<ByteString, Type<ResolvedName>> {
+        let names = paths
+            .iter()
+            .flat_map(|variables| variables.keys().cloned())
+            .collect::<HashSet<_>>();
+
+        names
+            .into_iter()
+            .map(|name| {
+                let types = paths
+                    .iter()
+                    .flat_map(|variables| match variables.get(&name) {
+                        Some(Type::Union(types)) => types.clone(),
+                        Some(r#type) => vec![r#type.clone()],
+                        None => vec![Type::Null],
+                    })
+                    .collect();
+
+                (name, self.simplify_union(types))
+            })
+            .collect()
+    }
+
+    /// Build the shape of an array literal, if every key in it is a literal.
+    fn array_shape(&self, node: &ArrayExpression) -> Option<Shape<ResolvedName>> {
+        let mut shape = Shape::new();
+
+        for item in node.items.iter() {
+            match item {
+                ArrayItem::Skipped(_) => {}
+                ArrayItem::Value(item) => {
+                    shape.insert(None, self.map.resolve(item.value.id).clone())
+                }
+                ArrayItem::KeyValue(item) => shape.insert(
+                    Some(Self::array_key(&item.key)?),
+                    self.map.resolve(item.value.id).clone(),
+                ),
+                // References and spreads can't be followed.
+                _ => return None,
+            }
+        }
+
+        Some(shape)
+    }
+
+    fn determine_array_type(&self, node: &ArrayExpression) -> Type<ResolvedName> {
+        let value_types: Vec<Type<ResolvedName>> = node
+            .items
+            .iter()
+            .filter_map(|item| -> Option<Type<ResolvedName>> {
+                match item {
+                    ArrayItem::Skipped(_) => None,
+                    ArrayItem::Value(inner) => Some(self.map.resolve(inner.value.id).clone()),
+                    ArrayItem::ReferencedValue(inner) => {
+                        Some(self.map.resolve(inner.value.id).clone())
+                    }
+                    ArrayItem::SpreadValue(inner) => Some(self.map.resolve(inner.value.id).clone()),
+                    ArrayItem::KeyValue(inner) => Some(self.map.resolve(inner.value.id).clone()),
+                    ArrayItem::ReferencedKeyValue(inner) => {
+                        Some(self.map.resolve(inner.value.id).clone())
+                    }
+                }
+            })
+            .collect();
+
+        if node.is_list() {
+            return Type::TypedArray(
+                Box::new(Type::Integer),
+                Box::new(self.simplify_union(value_types)),
+            );
+        }
+
+        if let Some(shape) = self.array_shape(node).and_then(Shape::into_type) {
+            return shape;
+        }
+
+        let key_types: Vec<Type<ResolvedName>> = node
+            .items
+            .iter()
+            .map(|item| -> Type<ResolvedName> {
+                match item {
+                    ArrayItem::KeyValue(array_item_key_value) => {
+                        self.array_key_type(&array_item_key_value.key)
+                    }
+                    ArrayItem::ReferencedKeyValue(array_item_referenced_key_value) => {
+                        self.array_key_type(&array_item_referenced_key_value.key)
+                    }
+                    _ => Type::Integer,
+                }
+            })
+            .collect();
+
+        Type::TypedArray(
+            Box::new(self.simplify_union(key_types)),
+            Box::new(self.simplify_union(value_types)),
+        )
+    }
+}
+
+impl<'a> ControlFlowVisitor for TypeMapGenerator<'a> {
+    fn visit_expression(&mut self, node: &Expression) -> Traversal {
+        walk_expression(self, node);
+
+        // `null` doesn't have a node of its own, so there's nothing to copy the type from.
+        let inner = match node.kind {
+            ExpressionKind::Null(_) => Type::Null,
+            _ => self.map.resolve(node.kind.id()).clone(),
+        };
+
+        self.map.insert(node.id, inner);
+        self.map.insert_span(node.id, node.span);
+
+        if let Some(member) = self.map.get_virtual_member(node.kind.id()) {
+            self.map.mark_virtual_member(node.id, member);
+        }
+
+        if self.map.is_undefined_key(node.kind.id()) {
+            self.map.mark_undefined_key(node.id);
+        }
+
+        Traversal::Continue
+    }
+
+    fn visit_literal(&mut self, node: &Literal) -> Traversal {
+        self.map.insert(
+            node.id,
+            match node.kind {
+                // Integers that are too large are converted to floats.
+                LiteralKind::Integer => match node.integer_value() {
+                    Some(IntegerValue::Overflow(_)) => Type::Float,
+                    _ => Type::Integer,
+                },
+                LiteralKind::Float => Type::Float,
+                LiteralKind::String => match node.string_value() {
+                    Some(value) if value.len() <= self.max_literal_string_length => {
+                        Type::LiteralString(value.value().to_bytestring())
+                    }
+                    Some(value) if !value.is_empty() => Type::NonEmptyString,
+                    _ => Type::String,
+                },
+                LiteralKind::Missing => Type::Missing,
+            },
+        );
+
+        Traversal::Continue
+    }
+
+    fn visit_interpolated_string_expression(
+        &mut self,
+        node: &InterpolatedStringExpression,
+    ) -> Traversal {
+        walk_interpolated_string_expression(self, node);
+
+        self.map.insert(node.id, self.string_like_type(node));
+
+        Traversal::Continue
+    }
+
+    fn visit_heredoc_expression(&mut self, node: &HeredocExpression) -> Traversal {
+        walk_heredoc_expression(self, node);
+
+        self.map.insert(node.id, self.string_like_type(node));
+
+        Traversal::Continue
+    }
+
+    fn visit_nowdoc_expression(&mut self, node: &NowdocExpression) -> Traversal {
+        self.map.insert(node.id, self.string_like_type(node));
+
+        Traversal::Continue
+    }
+
+    fn visit_bool_expression(&mut self, node: &BoolExpression) -> Traversal {
+        self.map.insert(
+            node.id,
+            match node.value.kind {
+                TokenKind::True => Type::True,
+                TokenKind::False => Type::False,
+                _ => Type::Boolean,
+            },
+        );
+
+        Traversal::Continue
+    }
+
+    fn visit_function_call_expression(&mut self, node: &FunctionCallExpression) -> Traversal {
+        // We need to walk the function call expression first to ensure all arguments are resolved
+        // for conditional return types and resolving generics.
+        //
+        // We also need the information for the `node.target` to be available in the map.
+        walk_function_call_expression(self, node);
+
+        if !matches!(node.target.kind, ExpressionKind::Name(_)) {
+            if let Some(resolved) = self.resolve_invokable_call(&node.target, &node.arguments) {
+                self.insert_resolved_members(node.id, resolved, Self::short_circuits(&node.target));
+
+                return Traversal::Continue;
+            }
+        }
+
+        // FIXME: Once we've got this information, we can resolve generics based on the arguments.
+        let return_type = self.determine_function_call_target_return_type(&node.target);
+
+        self.map.insert(node.id, return_type);
+
+        let ExpressionKind::Name(name) = &node.target.kind else {
+            return Traversal::Continue;
+        };
+
+        // These read and write variables by name, so their signatures don't describe them.
+        if Self::is_function(name, b"compact") {
+            if let Some(r#type) = self.compact_type(&node.arguments) {
+                self.map.insert(node.id, r#type);
+            }
+
+            return Traversal::Continue;
+        }
+
+        // Execution only carries on past `assert()` when the condition is true.
+        if Self::is_function(name, b"assert") {
+            if let Some(Argument::Positional(argument)) = node.arguments.arguments.first() {
+                let narrowing = self.narrow_condition(&argument.value);
+
+                self.narrow(&narrowing.truthy);
+            }
+        }
+
+        if Self::is_function(name, b"extract") {
+            self.extract(&node.arguments);
+
+            return Traversal::Continue;
+        }
+
+        // There's no signature for `count()` without stubs, but it's simple enough to describe here.
+        if (Self::is_function(name, b"count") || Self::is_function(name, b"sizeof"))
+            && self.function_from_name(name).is_none()
+        {
+            self.map.insert(node.id, self.count_type(&node.arguments));
+
+            return Traversal::Continue;
+        }
+
+        if Self::is_function(name, b"array_merge") {
+            if let Some(r#type) = self.array_merge_type(&node.arguments) {
+                self.map.insert(node.id, r#type);
+            }
+        }
+
+        match self.function_from_name(name) {
+            Some(function) => {
+                let sensitive =
+                    Self::find_sensitive_arguments(&function.get_parameters(), &node.arguments);
+                let references =
+                    Self::find_reference_arguments(function.effective_signature(), &node.arguments);
+
+                self.mark_sensitive_arguments(sensitive);
+                self.assign_references(references);
+            }
+            // Any undefined variable passed to an unknown function could be an out-parameter.
+            None => {
+                let references = node
+                    .arguments
+                    .arguments
+                    .iter()
+                    .filter_map(|argument| match argument {
+                        Argument::Positional(argument) => Self::simple_variable(&argument.value),
+                        Argument::Named(argument) => Self::simple_variable(&argument.value),
+                    })
+                    .filter(|variable| self.get_variable(variable).is_none())
+                    .map(|variable| (variable.clone(), Type::Mixed))
+                    .collect();
+
+                self.assign_references(references);
+            }
+        }
+
+        Traversal::Continue
+    }
+
+    fn visit_simple_variable(&mut self, node: &SimpleVariable) -> Traversal {
+        if let Some(ty) = self.get_variable(node) {
+            self.map.insert(node.id(), ty);
+        }
+
+        Traversal::Continue
+    }
+
+    fn visit_assignment_operation_expression(
+        &mut self,
+        node: &AssignmentOperationExpression,
+    ) -> Traversal {
+        // Walk the right-hand side of the assignment first to ensure the type is resolved.
+        self.visit_expression(&node.right);
+
+        let r#type = match &node.left.kind {
             ExpressionKind::Variable(variable) if variable.is_simple() => {
                 let variable = variable.to_simple();
-                let resolved = self.map.resolve(node.right.kind.id());
+                let current = self.get_variable(variable);
+                let mut r#type = self.assignment_type(node, current);
+
+                // Taking a reference to an undefined variable defines it as `null`.
+                if let Some(target) = Self::reference_target(node) {
+                    r#type = self.get_variable(target).unwrap_or(Type::Null);
+
+                    self.scopes.current_mut().bind_reference(variable, target);
+                }
 
                 self.scopes
                     .current_mut()
-                    .set_variable(variable, resolved.clone());
-                self.map.insert(variable.id, resolved.clone());
+                    .set_variable(variable, r#type.clone());
+                self.map.insert(variable.id, r#type.clone());
+
+                r#type
+            }
+            ExpressionKind::ArrayIndex(target) => self.assign_array_element(node, target),
+            ExpressionKind::PropertyFetch(target) => {
+                match (
+                    Self::is_this(&target.target),
+                    Self::member_name(&target.property),
+                ) {
+                    (true, Some(property)) => {
+                        let current = self.scopes.current().get_property(property);
+                        let r#type =
+                            self.assignment_type(node, Some(current.unwrap_or(Type::Mixed)));
+
+                        self.scopes
+                            .current_mut()
+                            .set_property(property, r#type.clone());
+
+                        r#type
+                    }
+                    _ => self.assignment_type(node, Some(Type::Mixed)),
+                }
+            }
+            _ => self.assignment_type(node, Some(Type::Mixed)),
+        };
+
+        self.map.insert(node.id, r#type.clone());
+        self.map.insert(node.left.id, r#type);
+        self.map.insert_span(node.left.id, node.left.span);
+
+        Traversal::Continue
+    }
+
+    fn visit_array_index_expression(&mut self, node: &ArrayIndexExpression) -> Traversal {
+        walk_array_index_expression(self, node);
+
+        let array = self.receiver_type_in_chain(&node.array);
+        let mut r#type = match self.global_variable_type(node) {
+            Some(r#type) => r#type,
+            None => self.array_value_type(&array, node.index.as_deref()),
+        };
+
+        let key = node.index.as_deref().and_then(Self::array_key);
+
+        if let (Some(shape), Some(key)) = (Shape::from_type(&array), key) {
+            if shape.get(&key).is_none() {
+                self.map.mark_undefined_key(node.id);
+            }
+        }
+
+        // The whole chain evaluates to `null` when one of its nullsafe links short-circuits.
+        if Self::short_circuits(&node.array) {
+            r#type = r#type.with_null();
+        }
+
+        self.map.insert(node.id, r#type);
+
+        Traversal::Continue
+    }
+
+    fn visit_foreach_statement_iterator_value(
+        &mut self,
+        node: &ForeachStatementIteratorValue,
+    ) -> Traversal {
+        self.visit_expression(&node.expression);
+
+        let (_, value) = self.iteration_types(self.map.resolve(node.expression.id), 0);
+
+        self.assign_iteration_variable(&node.value, value);
+        self.visit_expression(&node.value);
+
+        Traversal::Continue
+    }
+
+    fn visit_foreach_statement_iterator_key_and_value(
+        &mut self,
+        node: &ForeachStatementIteratorKeyAndValue,
+    ) -> Traversal {
+        self.visit_expression(&node.expression);
+
+        let (key, value) = self.iteration_types(self.map.resolve(node.expression.id), 0);
+
+        self.assign_iteration_variable(&node.key, key);
+        self.assign_iteration_variable(&node.value, value);
+        self.visit_expression(&node.key);
+        self.visit_expression(&node.value);
+
+        Traversal::Continue
+    }
+
+    fn visit_new_expression(&mut self, node: &NewExpression) -> Traversal {
+        walk_new_expression(self, node);
+
+        self.map.insert(
+            node.id,
+            match &node.target.kind {
+                ExpressionKind::Name(name) => match true {
+                    _ if name.is_resolved() => Type::Named(name.to_resolved().clone()),
+                    _ => Type::Mixed,
+                },
+                _ => match self.map.resolve(node.target.id) {
+                    Type::LiteralString(value) if self.is_newable_string(value.as_ref()) => {
+                        Type::Named(ResolvedName {
+                            resolved: value.clone(),
+                            original: value.clone(),
+                        })
+                    }
+                    _ => Type::Object,
+                },
+            },
+        );
+
+        Traversal::Continue
+    }
+
+    fn visit_array_expression(&mut self, node: &ArrayExpression) -> Traversal {
+        walk_array_expression(self, node);
+
+        // We've walked the array expression, so we can now figure out a more specific type for
+        // the array, rather than it just returning `Type::Array`.
+        self.map.insert(node.id, self.determine_array_type(node));
+
+        Traversal::Continue
+    }
+
+    fn visit_function_statement(&mut self, node: &FunctionStatement) -> Traversal {
+        let signature = node
+            .name
+            .as_resolved()
+            .and_then(|name| self.index.get_function(name.resolved.as_bytestr()))
+            .map(|function| function.effective_signature());
+
+        for attribute in &node.attributes {
+            self.visit_attribute_group(attribute);
+        }
+
+        self.unit(
+            node.id,
+            node.name.symbol().clone(),
+            Some(&node.body.statements),
+            |generator| {
+                generator.scopes.start();
+                generator
+                    .declarations
+                    .push(Declaration::Function(node.name.symbol().clone()));
+                generator.declare_parameters(&node.parameters, signature);
+                generator.visit_function_body(&node.body);
+                generator.declarations.pop();
+                generator.scopes.end();
+            },
+        );
+
+        // The parameters use the indexed signature, so they aren't walked like other parameters.
+        Traversal::SkipChildren
+    }
+
+    fn visit_function_parameter_list(&mut self, node: &FunctionParameterList) -> Traversal {
+        self.declare_parameters(node, None);
+
+        Traversal::SkipChildren
+    }
+
+    fn visit_closure_expression(&mut self, node: &ClosureExpression) -> Traversal {
+        // A closure only sees the variables that it captures, along with `$this`.
+        let captured = node
+            .uses
+            .iter()
+            .flat_map(|uses| uses.variables.iter())
+            .map(|capture| {
+                self.visit_simple_variable(&capture.variable);
+
+                let r#type = self.get_variable(&capture.variable);
+
+                (&capture.variable, r#type.unwrap_or(Type::Mixed))
+            })
+            .collect::<Vec<_>>();
+
+        for attribute in &node.attributes {
+            self.visit_attribute_group(attribute);
+        }
+
+        let properties = self.scopes.current().properties.clone();
+
+        self.scopes.start();
+        self.scopes.current_mut().properties = properties;
+
+        for (variable, r#type) in captured {
+            self.scopes.current_mut().set_variable(variable, r#type);
+        }
+
+        self.declarations.push(Declaration::Closure);
+        self.visit_function_parameter_list(&node.parameters);
+        self.visit_function_body(&node.body);
+        self.declarations.pop();
+        self.scopes.end();
+
+        Traversal::SkipChildren
+    }
+
+    fn visit_arrow_function_expression(&mut self, node: &ArrowFunctionExpression) -> Traversal {
+        // An arrow function captures the enclosing scope by value, so nothing it assigns leaks out.
+        for attribute in &node.attributes {
+            self.visit_attribute_group(attribute);
+        }
+
+        self.scopes.start_enclosed();
+        self.declarations.push(Declaration::Closure);
+        self.visit_function_parameter_list(&node.parameters);
+        self.visit_expression(&node.body);
+        self.declarations.pop();
+        self.scopes.end();
+
+        Traversal::SkipChildren
+    }
+
+    fn visit_if_statement(&mut self, node: &IfStatement) -> Traversal {
+        let (elseifs, otherwise): (Vec<(&Expression, &[Statement])>, _) = match &node.body {
+            IfStatementBody::Statement(body) => (
+                body.elseifs
+                    .iter()
+                    .map(|elseif| {
+                        (
+                            &elseif.condition,
+                            std::slice::from_ref(elseif.statement.as_ref()),
+                        )
+                    })
+                    .collect(),
+                body.r#else
+                    .as_ref()
+                    .map(|r#else| std::slice::from_ref(r#else.statement.as_ref())),
+            ),
+            IfStatementBody::Block(body) => (
+                body.elseifs
+                    .iter()
+                    .map(|elseif| (&elseif.condition, &elseif.statements[..]))
+                    .collect(),
+                body.r#else.as_ref().map(|r#else| &r#else.statements[..]),
+            ),
+        };
+
+        let branches = std::iter::once((&node.condition, statements_of(&node.body))).chain(elseifs);
+        let mut before = self.scopes.current().variables.clone();
+        let mut paths = Vec::new();
+        let mut narrowed = HashMap::new();
+
+        for (condition, statements) in branches {
+            // Each condition is only evaluated when the ones before it were false, so anything
+            // they assign is visible to the branches that follow.
+            self.scopes.current_mut().variables = before.clone();
+            self.visit_expression(condition);
+
+            let evaluated = self.scopes.current().variables.clone();
+            let narrowing = self.narrow_condition(condition);
+
+            for (variable, _) in narrowing.truthy.iter().chain(&narrowing.falsy) {
+                if let Some(r#type) = evaluated.get(&variable.symbol) {
+                    narrowed
+                        .entry(variable.symbol.clone())
+                        .or_insert_with(|| r#type.clone());
+                }
+            }
+
+            self.narrow(&narrowing.falsy);
+            before = std::mem::replace(&mut self.scopes.current_mut().variables, evaluated);
+
+            self.narrow(&narrowing.truthy);
+            self.visit(statements);
+
+            // A branch that returns or throws never reaches the code after the `if`.
+            if !terminates(statements) {
+                paths.push(self.scopes.current().variables.clone());
+            }
+        }
+
+        self.scopes.current_mut().variables = before.clone();
+
+        match otherwise {
+            Some(statements) => {
+                self.visit(statements);
+
+                if !terminates(statements) {
+                    paths.push(self.scopes.current().variables.clone());
+                }
+            }
+            None => paths.push(before.clone()),
+        }
+
+        let mut variables = if paths.is_empty() {
+            before
+        } else {
+            self.merge_variables(paths)
+        };
+
+        // When the branches put back together everything that a variable was narrowed from, it
+        // should look the same as it did before the `if`, e.g. `?Foo` instead of `Foo|null`.
+        for (symbol, original) in narrowed {
+            if let Some(r#type) = variables.get_mut(&symbol) {
+                if union_members(r#type) == union_members(&original) {
+                    *r#type = original;
+                }
+            }
+        }
+
+        self.scopes.current_mut().variables = variables;
+
+        Traversal::SkipChildren
+    }
+
+    fn visit_ternary_expression(&mut self, node: &TernaryExpression) -> Traversal {
+        self.visit_expression(&node.condition);
+
+        let narrowing = self.narrow_condition(&node.condition);
+
+        self.with_narrowed_variables(&narrowing.truthy, |generator| {
+            generator.visit_expression(&node.then);
+        });
+        self.with_narrowed_variables(&narrowing.falsy, |generator| {
+            generator.visit_expression(&node.r#else);
+        });
+
+        let mut types = vec![
+            self.map.resolve(node.then.id).clone(),
+            self.map.resolve(node.r#else.id).clone(),
+        ];
+
+        // A branch that throws never produces a value.
+        types.retain(|r#type| *r#type != Type::Never);
+
+        self.map.insert(
+            node.id,
+            if types.is_empty() {
+                Type::Never
+            } else {
+                self.simplify_union(types)
+            },
+        );
+
+        Traversal::SkipChildren
+    }
+
+    fn visit_logical_operation_expression(
+        &mut self,
+        node: &LogicalOperationExpression,
+    ) -> Traversal {
+        // The right-hand side of `&&` only runs when the left-hand side is true, and the
+        // right-hand side of `||` only runs when it's false.
+        match &node.kind {
+            LogicalOperationKind::And { left, right, .. }
+            | LogicalOperationKind::LogicalAnd { left, right, .. } => {
+                self.visit_expression(left);
+
+                let narrowing = self.narrow_condition(left);
+
+                self.with_narrowed_variables(&narrowing.truthy, |generator| {
+                    generator.visit_expression(right);
+                });
+            }
+            LogicalOperationKind::Or { left, right, .. }
+            | LogicalOperationKind::LogicalOr { left, right, .. } => {
+                self.visit_expression(left);
+
+                let narrowing = self.narrow_condition(left);
+
+                self.with_narrowed_variables(&narrowing.falsy, |generator| {
+                    generator.visit_expression(right);
+                });
+            }
+            _ => {
+                walk_logical_operation_expression(self, node);
             }
-            _ => (),
         }
-    }
 
-    fn visit_new_expression(&mut self, node: &NewExpression) {
-        walk_new_expression(self, node);
+        self.map.insert(node.id, Type::Boolean);
 
-        self.map.insert(
-            node.id,
-            match &node.target.kind {
-                ExpressionKind::Name(name) => match true {
-                    _ if name.is_resolved() => Type::Named(name.to_resolved().clone()),
-                    _ => Type::Mixed,
-                },
-                _ => match self.map.resolve(node.target.id) {
-                    Type::LiteralString(value) if self.is_newable_string(value.as_ref()) => {
-                        Type::Named(ResolvedName {
-                            resolved: value.clone(),
-                            original: value.clone(),
-                        })
-                    }
-                    _ => Type::Object,
-                },
-            },
-        );
+        Traversal::SkipChildren
     }
 
-    fn visit_array_expression(&mut self, node: &ArrayExpression) {
-        walk_array_expression(self, node);
+    fn visit_try_statement(&mut self, node: &TryStatement) -> Traversal {
+        let before = self.scopes.current().variables.clone();
 
-        // We've walked the array expression, so we can now figure out a more specific type for
-        // the array, rather than it just returning `Type::Array`.
-        self.map.insert(node.id, self.determine_array_type(node));
-    }
+        self.visit(&node.body);
 
-    fn visit_function_statement(&mut self, node: &FunctionStatement) {
-        self.scopes.start();
-        walk_function_statement(self, node);
-        self.scopes.end();
-    }
+        let completed = self.scopes.current().variables.clone();
 
-    fn visit_function_parameter_list(&mut self, node: &FunctionParameterList) {
-        for parameter in node.parameters.iter() {
-            let mut r#type = self.unwrap_data_type(parameter.data_type.as_ref());
+        // An exception could be thrown before or after any of the assignments in the `try` block,
+        // so the `catch` blocks could see the types from before it or from the end of it.
+        let caught = self.merge_variables(vec![before, completed.clone()]);
+        let mut paths = vec![completed];
 
-            if parameter.is_variadic() {
-                r#type = Type::TypedArray(Box::new(Type::Integer), Box::new(r#type));
+        for catch in node.catches.iter() {
+            self.scopes.current_mut().variables = caught.clone();
+            self.scopes.start_enclosed();
+
+            if let Some(variable) = &catch.var {
+                let r#type = self.determine_catch_type(&catch.types);
+
+                self.scopes
+                    .current_mut()
+                    .set_variable(variable, r#type.clone());
+                self.map.insert(variable.id, r#type);
             }
 
-            self.scopes
-                .current_mut()
-                .set_variable(&parameter.name, r#type);
+            self.visit(&catch.body);
+
+            let assigned = self.scopes.current().variables.clone();
+            self.scopes.end();
+
+            // A `catch` block that returns or rethrows never reaches the code after the `try`.
+            if !terminates(&catch.body) {
+                let mut variables = caught.clone();
+                variables.extend(assigned);
+                paths.push(variables);
+            }
+        }
+
+        self.scopes.current_mut().variables = self.merge_variables(paths);
+
+        // The `finally` block always runs, so anything it assigns applies after the `try`.
+        if let Some(finally) = &node.finally {
+            self.visit(&finally.body);
         }
+
+        Traversal::Continue
     }
 
-    fn visit_missing_expression(&mut self, node: &MissingExpression) {
+    fn visit_missing_expression(&mut self, node: &MissingExpression) -> Traversal {
         self.map.insert(node.id, Type::Missing);
+
+        Traversal::Continue
     }
 
-    fn visit_eval_expression(&mut self, node: &EvalExpression) {
+    fn visit_eval_expression(&mut self, node: &EvalExpression) -> Traversal {
         walk_eval_expression(self, node);
 
         self.map.insert(node.id, Type::Mixed);
+
+        Traversal::Continue
     }
 
-    fn visit_empty_expression(&mut self, node: &EmptyExpression) {
+    fn visit_empty_expression(&mut self, node: &EmptyExpression) -> Traversal {
         walk_empty_expression(self, node);
 
         // FIXME: We should be able to determine an exact true or false
         // here depending on the type of the value passed through.
         self.map.insert(node.id, Type::Boolean);
+
+        Traversal::Continue
+    }
+
+    fn visit_throw_expression(&mut self, node: &ThrowExpression) -> Traversal {
+        walk_throw_expression(self, node);
+
+        self.map.insert(node.id, Type::Never);
+
+        Traversal::Continue
+    }
+
+    fn visit_match_expression(&mut self, node: &MatchExpression) -> Traversal {
+        self.visit_expression(&node.condition);
+
+        let subject = match &node.condition.kind {
+            ExpressionKind::Variable(variable) if variable.is_simple() => {
+                Some(variable.to_simple())
+            }
+            _ => None,
+        };
+
+        let mut types = Vec::new();
+
+        for arm in node.arms.iter() {
+            for condition in arm.conditions.iter() {
+                self.visit_expression(condition);
+            }
+
+            // The subject can only be one of the values in the arm's conditions when the body runs.
+            let narrowed = subject
+                .filter(|_| arm.conditions.iter().all(is_narrowing_condition))
+                .map(|subject| {
+                    let types = arm
+                        .conditions
+                        .iter()
+                        .map(|condition| self.map.resolve(condition.id).clone())
+                        .collect::<Vec<_>>();
+
+                    (subject, types)
+                })
+                .filter(|(_, types)| !types.contains(&Type::Mixed));
+
+            match narrowed {
+                Some((subject, types)) => {
+                    let r#type = self.simplify_union(types);
+
+                    self.with_narrowed_variable(subject, r#type, |generator| {
+                        generator.visit_expression(&arm.body);
+                    });
+                }
+                None => {
+                    self.visit_expression(&arm.body);
+                }
+            }
+
+            types.push(self.map.resolve(arm.body.id).clone());
+        }
+
+        if let Some(default) = &node.default {
+            self.visit_expression(&default.body);
+
+            types.push(self.map.resolve(default.body.id).clone());
+        }
+
+        // Arms that throw or exit never produce a value.
+        types.retain(|r#type| *r#type != Type::Never);
+
+        self.map.insert(
+            node.id,
+            if types.is_empty() {
+                Type::Never
+            } else {
+                self.simplify_union(types)
+            },
+        );
+
+        Traversal::Continue
     }
 
-    fn visit_die_expression(&mut self, node: &DieExpression) {
+    fn visit_die_expression(&mut self, node: &DieExpression) -> Traversal {
         walk_die_expression(self, node);
 
         self.map.insert(node.id, Type::Never);
+
+        Traversal::Continue
     }
 
-    fn visit_exit_expression(&mut self, node: &ExitExpression) {
+    fn visit_exit_expression(&mut self, node: &ExitExpression) -> Traversal {
         walk_exit_expression(self, node);
 
         self.map.insert(node.id, Type::Never);
+
+        Traversal::Continue
     }
 
-    fn visit_isset_expression(&mut self, node: &IssetExpression) {
+    fn visit_isset_expression(&mut self, node: &IssetExpression) -> Traversal {
         walk_isset_expression(self, node);
 
         // FIXME: We should be able to determine an exact true or false
         // depending on the expressions passed through.
         self.map.insert(node.id, Type::Boolean);
+
+        Traversal::Continue
     }
 
-    fn visit_unset_expression(&mut self, node: &UnsetExpression) {
+    fn visit_unset_expression(&mut self, node: &UnsetExpression) -> Traversal {
         walk_unset_expression(self, node);
 
+        for argument in node.arguments.arguments.iter() {
+            match argument {
+                Argument::Positional(argument) => self.unset(&argument.value),
+                Argument::Named(argument) => self.unset(&argument.value),
+            }
+        }
+
         self.map.insert(node.id, Type::Void);
+
+        Traversal::Continue
     }
 
-    fn visit_print_expression(&mut self, node: &PrintExpression) {
+    fn visit_print_expression(&mut self, node: &PrintExpression) -> Traversal {
         walk_print_expression(self, node);
 
         self.map.insert(
             node.id,
             Type::ConstExpr(Box::new(ConstExpr::Integer(1.into()))),
         );
+
+        Traversal::Continue
     }
 
-    fn visit_concat_expression(&mut self, node: &ConcatExpression) {
+    fn visit_concat_expression(&mut self, node: &ConcatExpression) -> Traversal {
         walk_concat_expression(self, node);
 
-        // FIXME: We can be more precise here by checking the types on the
-        // left and right-hand side of the expression, e.g. empty strings, etc.
-        self.map.insert(node.id, Type::String);
+        let values = [
+            self.string_value(&node.left),
+            self.string_value(&node.right),
+        ];
+
+        self.map.insert(
+            node.id,
+            strings::concat(values, self.max_literal_string_length),
+        );
+
+        Traversal::Continue
     }
 
-    fn visit_instanceof_expression(&mut self, node: &InstanceofExpression) {
+    fn visit_instanceof_expression(&mut self, node: &InstanceofExpression) -> Traversal {
         walk_instanceof_expression(self, node);
 
         // FIXME: Can we do some smart stuff here to determine the
         // real true / false state based on the left-hand side?
         self.map.insert(node.id, Type::Boolean);
+
+        Traversal::Continue
     }
 
-    fn visit_reference_expression(&mut self, node: &ReferenceExpression) {
+    fn visit_reference_expression(&mut self, node: &ReferenceExpression) -> Traversal {
         walk_reference_expression(self, node);
 
         self.map
             .insert(node.id, self.map.resolve(node.right.id).clone());
+
+        Traversal::Continue
     }
 
-    fn visit_parenthesized_expression(&mut self, node: &ParenthesizedExpression) {
+    fn visit_parenthesized_expression(&mut self, node: &ParenthesizedExpression) -> Traversal {
         walk_parenthesized_expression(self, node);
 
         self.map
             .insert(node.id, self.map.resolve(node.expr.id).clone());
+
+        Traversal::Continue
     }
 
-    fn visit_error_suppress_expression(&mut self, node: &ErrorSuppressExpression) {
+    fn visit_error_suppress_expression(&mut self, node: &ErrorSuppressExpression) -> Traversal {
         walk_error_suppress_expression(self, node);
 
         self.map
             .insert(node.id, self.map.resolve(node.expr.id).clone());
+
+        Traversal::Continue
     }
 
-    fn visit_include_expression(&mut self, node: &IncludeExpression) {
+    fn visit_include_expression(&mut self, node: &IncludeExpression) -> Traversal {
         walk_include_expression(self, node);
 
+        self.include(&node.path);
         self.map.insert(node.id, Type::Mixed);
+
+        Traversal::Continue
     }
 
-    fn visit_include_once_expression(&mut self, node: &IncludeOnceExpression) {
+    fn visit_include_once_expression(&mut self, node: &IncludeOnceExpression) -> Traversal {
         walk_include_once_expression(self, node);
 
+        self.include(&node.path);
         self.map.insert(node.id, Type::Mixed);
+
+        Traversal::Continue
     }
 
-    fn visit_require_expression(&mut self, node: &RequireExpression) {
+    fn visit_require_expression(&mut self, node: &RequireExpression) -> Traversal {
         walk_require_expression(self, node);
 
+        self.include(&node.path);
         self.map.insert(node.id, Type::Mixed);
+
+        Traversal::Continue
     }
 
-    fn visit_require_once_expression(&mut self, node: &RequireOnceExpression) {
+    fn visit_require_once_expression(&mut self, node: &RequireOnceExpression) -> Traversal {
         walk_require_once_expression(self, node);
 
+        self.include(&node.path);
         self.map.insert(node.id, Type::Mixed);
+
+        Traversal::Continue
     }
 
     fn visit_function_closure_creation_expression(
         &mut self,
         node: &FunctionClosureCreationExpression,
-    ) {
+    ) -> Traversal {
         walk_function_closure_creation_expression(self, node);
 
         // FIXME: If the target is a function or if we can resolve the target to
@@ -576,140 +3206,199 @@ impl<'a> Visitor for TypeMapGenerator<'a> {
                 original: ByteString::from("Closure"),
             }),
         );
+
+        Traversal::Continue
     }
 
-    fn visit_method_call_expression(&mut self, node: &MethodCallExpression) {
+    fn visit_method_call_expression(&mut self, node: &MethodCallExpression) -> Traversal {
         walk_method_call_expression(self, node);
 
-        let method_name = match &node.method.kind {
-            ExpressionKind::Identifier(identifier) if identifier.is_simple() => {
-                identifier.to_simple().symbol.as_bytestr()
-            }
-            // FIXME: Can we support dynamic method names here if we know the value of the expression?
-            _ => {
-                self.map.insert(node.id, Type::Mixed);
+        self.infer_method_call(
+            node.id,
+            &node.target,
+            &node.method,
+            &node.arguments,
+            Self::short_circuits(&node.target),
+        );
 
-                return;
+        Traversal::Continue
+    }
+
+    fn visit_property_fetch_expression(&mut self, node: &PropertyFetchExpression) -> Traversal {
+        walk_property_fetch_expression(self, node);
+
+        self.infer_property_fetch(
+            node.id,
+            &node.target,
+            &node.property,
+            Self::short_circuits(&node.target),
+        );
+
+        // A property assigned earlier in the same method has the type of the value assigned to it.
+        if let (true, Some(property)) = (
+            Self::is_this(&node.target),
+            Self::member_name(&node.property),
+        ) {
+            if let Some(r#type) = self.scopes.current().get_property(property) {
+                self.map.insert(node.id, r#type);
             }
+        }
+
+        Traversal::Continue
+    }
+
+    fn visit_method(&mut self, node: &Method) -> Traversal {
+        let body = match &node.body.kind {
+            MethodBodyKind::Concrete(body) => Some(body.statements.as_slice()),
+            _ => None,
         };
 
-        let target = self.map.resolve(node.target.id);
+        self.unit(
+            node.id,
+            self.method_name(node.name.symbol.as_bytestr()),
+            body,
+            |generator| {
+                generator.scopes.start();
+                generator.within(Declaration::Method(node.name.symbol.clone()), |generator| {
+                    walk_method(generator, node)
+                });
+                generator.scopes.end();
+            },
+        );
 
-        if !target.is_object_like() {
-            self.map.insert(node.id, Type::Invalid);
+        Traversal::Continue
+    }
 
-            return;
-        }
+    fn visit_class_statement(&mut self, node: &ClassStatement) -> Traversal {
+        self.within(
+            Declaration::Class(node.name.as_resolved().cloned()),
+            |generator| walk_class_statement(generator, node),
+        )
+    }
 
-        if target.is_object() {
-            self.map.insert(node.id, Type::Mixed);
+    fn visit_anonymous_class_expression(&mut self, node: &AnonymousClassExpression) -> Traversal {
+        self.within(Declaration::Class(None), |generator| {
+            walk_anonymous_class_expression(generator, node)
+        })
+    }
 
-            return;
-        }
+    fn visit_interface_statement(&mut self, node: &InterfaceStatement) -> Traversal {
+        self.within(
+            Declaration::Class(node.name.as_resolved().cloned()),
+            |generator| walk_interface_statement(generator, node),
+        )
+    }
 
-        // If we can't figure out what class-like thing we're calling the method on,
-        // we'll just return a mixed type and continue on.
-        let Some(classes) = self.determine_class_from_type(target) else {
-            self.map.insert(node.id, Type::Mixed);
-            return;
-        };
+    fn visit_unit_enum_statement(&mut self, node: &UnitEnumStatement) -> Traversal {
+        self.within(
+            Declaration::Class(node.name.as_resolved().cloned()),
+            |generator| walk_unit_enum_statement(generator, node),
+        )
+    }
 
-        let methods = classes
-            .iter()
-            .filter_map(|class| class.get_method(method_name))
-            .collect::<Vec<_>>();
+    fn visit_backed_enum_statement(&mut self, node: &BackedEnumStatement) -> Traversal {
+        self.within(
+            Declaration::Class(node.name.as_resolved().cloned()),
+            |generator| walk_backed_enum_statement(generator, node),
+        )
+    }
 
-        if methods.is_empty() {
-            self.map.insert(node.id, Type::Mixed);
+    fn visit_trait_statement(&mut self, node: &TraitStatement) -> Traversal {
+        self.within(
+            Declaration::Trait(node.name.symbol().clone()),
+            |generator| walk_trait_statement(generator, node),
+        )
+    }
 
-            return;
-        }
+    fn visit_hooked_property(&mut self, node: &HookedProperty) -> Traversal {
+        let property = node.entry.kind.variable().stripped.clone();
 
-        let return_type = self.simplify_union(
-            methods
-                .iter()
-                .filter_map(|method| method.get_return_type().as_ref().map(|t| t.to_type().clone()))
-                .collect::<Vec<Type<ResolvedName>>>(),
-        );
+        self.within(Declaration::PropertyHook(property), |generator| {
+            walk_hooked_property(generator, node)
+        })
+    }
 
-        self.map.insert(node.id, return_type);
+    fn visit_unbraced_namespace(&mut self, node: &UnbracedNamespace) -> Traversal {
+        self.namespace = Some(node.name.symbol.clone());
+        let traversal = walk_unbraced_namespace(self, node);
+        self.namespace = None;
+
+        traversal
     }
 
-    fn visit_method_closure_creation_expression(&mut self, node: &MethodClosureCreationExpression) {
-        walk_method_closure_creation_expression(self, node);
+    fn visit_braced_namespace(&mut self, node: &BracedNamespace) -> Traversal {
+        self.namespace = node.name.as_ref().map(|name| name.symbol.clone());
+        let traversal = walk_braced_namespace(self, node);
+        self.namespace = None;
 
-        // FIXME: If we know what method is being called, we can determine a better type here.
+        traversal
+    }
+
+    fn visit_magic_constant_expression(&mut self, node: &MagicConstantExpression) -> Traversal {
         self.map.insert(
             node.id,
-            Type::Named(ResolvedName {
-                resolved: b"Closure".into(),
-                original: b"Closure".into(),
-            }),
+            magic::magic_constant_type(&node.kind, &self.declarations, self.namespace.as_ref()),
         );
-    }
-
-    fn visit_nullsafe_method_call_expression(&mut self, node: &NullsafeMethodCallExpression) {
-        walk_nullsafe_method_call_expression(self, node);
 
-        let method_name = match &node.method.kind {
-            ExpressionKind::Identifier(identifier) if identifier.is_simple() => {
-                identifier.to_simple().symbol.as_bytestr()
-            }
-            // FIXME: Can we support dynamic method names here if we know the value of the expression?
-            _ => {
-                self.map.insert(node.id, Type::Mixed);
+        Traversal::Continue
+    }
 
-                return;
-            }
-        };
+    fn visit_clone_expression(&mut self, node: &CloneExpression) -> Traversal {
+        walk_clone_expression(self, node);
 
-        let target = self.map.resolve(node.target.id);
+        // Cloning `null` throws, so the result is always an object. `__clone()` can't change the
+        // type either, since its return value is discarded.
+        let r#type = self.map.resolve(node.target.id).without_null();
 
-        if !target.is_object_like() {
-            self.map.insert(node.id, Type::Invalid);
+        self.map.insert(node.id, r#type);
 
-            return;
-        }
+        Traversal::Continue
+    }
 
-        if target.is_object() {
-            self.map.insert(node.id, Type::Mixed);
+    fn visit_nullsafe_property_fetch_expression(
+        &mut self,
+        node: &NullsafePropertyFetchExpression,
+    ) -> Traversal {
+        walk_nullsafe_property_fetch_expression(self, node);
 
-            return;
-        }
+        self.infer_property_fetch(node.id, &node.target, &node.property, true);
 
-        // If we can't figure out what class-like thing we're calling the method on,
-        // we'll just return a mixed type and continue on.
-        let Some(classes) = self.determine_class_from_type(target) else {
-            self.map.insert(node.id, Type::Mixed);
-            return;
-        };
+        Traversal::Continue
+    }
 
-        let methods = classes
-            .iter()
-            .filter_map(|class| class.get_method(method_name))
-            .collect::<Vec<_>>();
+    fn visit_method_closure_creation_expression(
+        &mut self,
+        node: &MethodClosureCreationExpression,
+    ) -> Traversal {
+        walk_method_closure_creation_expression(self, node);
 
-        if methods.is_empty() {
-            self.map.insert(node.id, Type::Mixed);
+        // FIXME: If we know what method is being called, we can determine a better type here.
+        self.map.insert(
+            node.id,
+            Type::Named(ResolvedName {
+                resolved: b"Closure".into(),
+                original: b"Closure".into(),
+            }),
+        );
 
-            return;
-        }
+        Traversal::Continue
+    }
 
-        let return_type = self.simplify_union(
-            methods
-                .iter()
-                .filter_map(|method| method.get_return_type().as_ref().map(|t| t.to_type().clone()))
-                .collect::<Vec<Type<ResolvedName>>>(),
-        );
+    fn visit_nullsafe_method_call_expression(
+        &mut self,
+        node: &NullsafeMethodCallExpression,
+    ) -> Traversal {
+        walk_nullsafe_method_call_expression(self, node);
 
-        // FIXME: If we can determine that the thing we're calling isn't nullable, we can
-        // omit the null type from the union.
-        let return_type = self.simplify_union(vec![return_type, Type::Null]);
+        self.infer_method_call(node.id, &node.target, &node.method, &node.arguments, true);
 
-        self.map.insert(node.id, return_type);
+        Traversal::Continue
     }
 
-    fn visit_static_method_call_expression(&mut self, node: &StaticMethodCallExpression) {
+    fn visit_static_method_call_expression(
+        &mut self,
+        node: &StaticMethodCallExpression,
+    ) -> Traversal {
         walk_static_method_call_expression(self, node);
 
         // FIXME: If we know that the target is a class-like thing, we can determine a better type here.
@@ -720,7 +3409,7 @@ impl<'a> Visitor for TypeMapGenerator<'a> {
             _ => {
                 self.map.insert(node.id, Type::Mixed);
 
-                return;
+                return Traversal::Continue;
             }
         };
 
@@ -731,27 +3420,143 @@ impl<'a> Visitor for TypeMapGenerator<'a> {
         else {
             self.map.insert(node.id, Type::Mixed);
 
-            return;
+            return Traversal::Continue;
         };
 
         let Some(class) = self.index.get_class(target) else {
             self.map.insert(node.id, Type::Invalid);
 
-            return;
+            return Traversal::Continue;
         };
 
-        let Some(method) = class.get_static_method(method_name.as_ref()) else {
+        let Some(resolved) =
+            self.resolve_method_call(class, method_name.as_ref(), &node.arguments, true)
+        else {
             self.map.insert(node.id, Type::Invalid);
 
-            return;
+            return Traversal::Continue;
         };
 
-        let return_type = method
-            .get_return_type()
-            .as_ref()
-            .map(|t| t.to_type().clone())
-            .unwrap_or_else(|| Type::Mixed);
+        self.insert_resolved_members(node.id, vec![resolved], false);
 
-        self.map.insert(node.id, return_type);
+        Traversal::Continue
+    }
+
+    fn visit_constant_fetch_expression(&mut self, node: &ConstantFetchExpression) -> Traversal {
+        walk_constant_fetch_expression(self, node);
+
+        if !node.is_class() {
+            let r#type = self
+                .determine_enum_case_type(node)
+                .or_else(|| self.determine_class_constant_type(node))
+                .unwrap_or(Type::Mixed);
+
+            self.map.insert(node.id, r#type);
+
+            return Traversal::Continue;
+        }
+
+        let class = match &node.target.kind {
+            ExpressionKind::Name(name) if name.is_resolved() => {
+                Some(Type::Named(name.to_resolved().clone()))
+            }
+            ExpressionKind::Name(_) => None,
+            _ => match self.map.resolve(node.target.id) {
+                ty @ Type::Named(_) => Some(ty.clone()),
+                _ => None,
+            },
+        };
+
+        let ty = match class {
+            Some(class) => Type::Generic(
+                Box::new(Type::ClassString),
+                vec![GenericTypeArgument {
+                    r#type: class,
+                    variance: None,
+                }],
+            ),
+            None => Type::ClassString,
+        };
+
+        self.map.insert(node.id, ty);
+
+        Traversal::Continue
+    }
+}
+
+/// `iterable` is an alias of `array|Traversable`, which is easier to work with once a declared type is used.
+fn expand_declared_type(ty: &Type<ResolvedName>) -> Type<ResolvedName> {
+    ty.with_expanded_iterable(&Type::Named(ResolvedName {
+        resolved: b"Traversable".into(),
+        original: b"Traversable".into(),
+    }))
+}
+
+/// Check whether the last statement in the block always leaves it, e.g. by returning or throwing.
+/// Whether a function name refers to `exit` or `die`, which are functions as of PHP 8.4 but aren't
+/// declared anywhere that could be indexed.
+fn is_exit_function(name: &[u8]) -> bool {
+    name.eq_ignore_ascii_case(b"exit") || name.eq_ignore_ascii_case(b"die")
+}
+
+/// The statements in the first branch of an `if` statement.
+fn statements_of(body: &IfStatementBody) -> &[Statement] {
+    match body {
+        IfStatementBody::Statement(body) => std::slice::from_ref(body.statement.as_ref()),
+        IfStatementBody::Block(body) => &body.statements,
+    }
+}
+
+fn argument(r#type: Type<ResolvedName>) -> GenericTypeArgument<ResolvedName> {
+    GenericTypeArgument {
+        r#type,
+        variance: None,
+    }
+}
+
+/// The distinct types that make up a union, treating `?Foo` as `Foo|null`.
+fn union_members(r#type: &Type<ResolvedName>) -> HashSet<Type<ResolvedName>> {
+    match r#type {
+        Type::Nullable(inner) => {
+            let mut members = union_members(inner);
+            members.insert(Type::Null);
+            members
+        }
+        Type::Union(types) => types.iter().flat_map(union_members).collect(),
+        _ => HashSet::from([r#type.clone()]),
+    }
+}
+
+fn terminates(block: &[Statement]) -> bool {
+    let last = block.iter().rev().find(|statement| {
+        !matches!(
+            statement.kind,
+            StatementKind::Comment(_) | StatementKind::Noop(_)
+        )
+    });
+
+    match last.map(|statement| &statement.kind) {
+        Some(
+            StatementKind::Return(_)
+            | StatementKind::Break(_)
+            | StatementKind::Continue(_)
+            | StatementKind::Goto(_),
+        ) => true,
+        Some(StatementKind::Expression(statement)) => matches!(
+            statement.expression.kind,
+            ExpressionKind::Throw(_) | ExpressionKind::Exit(_) | ExpressionKind::Die(_)
+        ),
+        Some(StatementKind::Block(block)) => terminates(&block.statements),
+        _ => false,
+    }
+}
+
+/// Check whether the match arm condition is a single value that the subject can be narrowed to,
+/// i.e. a literal or an enum case.
+fn is_narrowing_condition(condition: &Expression) -> bool {
+    match &condition.kind {
+        ExpressionKind::Literal(_) | ExpressionKind::Bool(_) | ExpressionKind::Null(_) => true,
+        ExpressionKind::ConstantFetch(fetch) => !fetch.is_class(),
+        _ => false,
     }
 }
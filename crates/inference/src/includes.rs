@@ -0,0 +1,113 @@
+use std::{
+    collections::HashMap,
+    path::{Component, Path, PathBuf},
+};
+
+use pxp_ast::{utils::StringLike, Expression, ExpressionKind, MagicConstantKind, Statement};
+
+/// Works out which file an `include` or `require` refers to.
+pub trait IncludeResolver {
+    /// Resolve the path passed to an include, or `None` if it can't be known statically.
+    fn resolve(&self, path: &Expression, including_file: &Path) -> Option<PathBuf>;
+}
+
+/// Resolves includes of literal paths and of paths built from `__DIR__`, e.g. `__DIR__ . '/helpers.php'`.
+///
+/// Relative paths are resolved against the directory of the including file. PHP searches the
+/// `include_path` first, which can't be known without running the code.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultIncludeResolver;
+
+impl IncludeResolver for DefaultIncludeResolver {
+    fn resolve(&self, path: &Expression, including_file: &Path) -> Option<PathBuf> {
+        let directory = including_file.parent()?;
+        let path = match &unparenthesize(path).kind {
+            ExpressionKind::Concat(concat) => match &unparenthesize(&concat.left).kind {
+                ExpressionKind::MagicConstant(constant)
+                    if constant.kind == MagicConstantKind::Directory =>
+                {
+                    let rest = literal_path(&concat.right)?;
+                    let rest = rest.strip_prefix("/").unwrap_or(rest.as_path());
+
+                    directory.join(rest)
+                }
+                _ => return None,
+            },
+            _ => directory.join(literal_path(path)?),
+        };
+
+        Some(normalize(&path))
+    }
+}
+
+fn unparenthesize(expression: &Expression) -> &Expression {
+    match &expression.kind {
+        ExpressionKind::Parenthesized(inner) => unparenthesize(&inner.expr),
+        _ => expression,
+    }
+}
+
+fn literal_path(expression: &Expression) -> Option<PathBuf> {
+    let value = expression.parts()?.constant_value()?;
+
+    std::str::from_utf8(value.as_bytes())
+        .ok()
+        .map(PathBuf::from)
+}
+
+/// Remove `.` and `..` from a path without touching the file system.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+
+    normalized
+}
+
+/// The files that includes can be followed into, along with the resolver that finds them.
+pub(crate) struct Includes<'a> {
+    resolver: Box<dyn IncludeResolver>,
+    files: HashMap<PathBuf, &'a [Statement]>,
+}
+
+impl<'a> Includes<'a> {
+    pub(crate) fn new() -> Self {
+        Self {
+            resolver: Box::new(DefaultIncludeResolver),
+            files: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn set_resolver(&mut self, resolver: impl IncludeResolver + 'static) {
+        self.resolver = Box::new(resolver);
+    }
+
+    pub(crate) fn insert(&mut self, path: PathBuf, ast: &'a [Statement]) {
+        self.files.insert(path, ast);
+    }
+
+    /// Find the file that an include refers to, if it's one that we know about.
+    pub(crate) fn resolve(
+        &self,
+        path: &Expression,
+        including_file: &Path,
+    ) -> Option<(&Path, &'a [Statement])> {
+        if self.files.is_empty() {
+            return None;
+        }
+
+        let path = self.resolver.resolve(path, including_file)?;
+
+        self.files
+            .get_key_value(&path)
+            .map(|(path, ast)| (path.as_path(), *ast))
+    }
+}
@@ -1,19 +1,32 @@
+mod arrays;
+mod cache;
 mod engine;
+mod includes;
+mod magic;
 mod map;
+mod numbers;
+mod strings;
+mod superglobals;
 
+pub use arrays::ArrayKey;
+pub use cache::{CacheStatistics, InferenceCache};
 pub use engine::TypeEngine;
-pub use map::TypeMap;
+pub use includes::{DefaultIncludeResolver, IncludeResolver};
+pub use map::{TypeDifference, TypeMap, VirtualMember};
+pub use superglobals::Superglobals;
 
 #[cfg(test)]
 mod tests {
-    use pxp_ast::{HasId, ResolvedName, Statement, StatementKind};
-    use pxp_index::{FileId, Index};
+    use pxp_ast::{
+        Argument, ExpressionKind, HasId, NodeId, ResolvedName, Statement, StatementKind,
+    };
+    use pxp_index::{FileId, Index, IndexBuilder};
     use pxp_lexer::Lexer;
     use pxp_node_finder::NodeFinder;
     use pxp_parser::Parser;
-    use pxp_type::{ConstExpr, Type};
+    use pxp_type::{ConstExpr, GenericTypeArgument, ShapeItem, ShapeItemKey, Type};
 
-    use crate::TypeEngine;
+    use crate::{Superglobals, TypeEngine, TypeMap, VirtualMember};
 
     #[test]
     fn it_infers_integer_literals() {
@@ -38,362 +51,2219 @@ mod tests {
     }
 
     #[test]
-    fn it_infers_interpolated_strings() {
-        assert_eq!(infer("\"Hello, $name!\""), Type::String);
+    fn it_decodes_escape_sequences_in_string_literals() {
+        assert_eq!(
+            infer(r"'it\'s \\ \n'"),
+            Type::LiteralString(br"it's \ \n".into())
+        );
+        assert_eq!(
+            infer(r#""\t\"\$\x41\101\u{1F418}\q\u""#),
+            Type::LiteralString(b"\t\"$AA\xF0\x9F\x90\x98\\q\\u".into())
+        );
+        assert_eq!(infer(r"b'\\'"), Type::LiteralString(b"\\".into()));
+        assert_eq!(infer(r#""""#), Type::LiteralString(b"".into()));
     }
 
     #[test]
-    fn it_infers_boolean_literals() {
-        assert_eq!(infer("true"), Type::True);
-        assert_eq!(infer("false"), Type::False);
+    fn it_infers_interpolated_strings() {
+        assert_eq!(infer("\"Hello, $name!\""), Type::NonEmptyString);
     }
 
     #[test]
-    fn it_infers_type_of_function_calls() {
+    fn it_infers_interpolated_strings_with_known_parts() {
         assert_eq!(
             infer(
                 r#"
-        function a(): int {}
-        a()
+        $a = 'Hello';
+        $b = 'World';
+        "{$a}, {$b}!"
         "#
             ),
-            Type::Integer
+            Type::LiteralString(b"Hello, World!".into())
         );
+        assert_eq!(infer("\"{$a}{$b}\""), Type::String);
     }
 
     #[test]
-    fn it_infers_type_of_iife() {
+    fn it_infers_concatenation_of_literal_strings() {
         assert_eq!(
             infer(
                 r#"
-        (function (): int {
-            return 42;
-        })()
+        $name = 'World';
+        $greeting = 'Hello ' . $name
         "#
             ),
-            Type::Integer
+            Type::LiteralString(b"Hello World".into())
         );
+        assert_eq!(infer("'a' . 'b' . 'c'"), Type::LiteralString(b"abc".into()));
     }
 
     #[test]
-    fn it_infers_type_of_function_calls_on_callable_string() {
+    fn it_converts_numbers_when_concatenating() {
         assert_eq!(
-            infer(
-                r#"
-        function a(): string {}
-        'a'()
-        "#
-            ),
-            Type::String
+            infer("'#' . 0x1A . 1_000 . 010"),
+            Type::LiteralString(b"#2610008".into())
+        );
+        assert_eq!(
+            infer("'' . 1.50 . '|' . 2.0 . '|' . 1e3"),
+            Type::LiteralString(b"1.5|2|1000".into())
         );
+        assert_eq!(infer("'' . true . false"), Type::LiteralString(b"1".into()));
+
+        // These would need scientific notation or more precision than PHP uses.
+        assert_eq!(infer("'' . 1e20"), Type::NonEmptyString);
+        assert_eq!(infer("'' . 0.1234567890123456"), Type::NonEmptyString);
     }
 
     #[test]
-    fn it_infers_type_of_variable() {
+    fn it_infers_concatenation_of_unknown_strings() {
+        assert_eq!(infer("'Hello ' . $name"), Type::NonEmptyString);
+        assert_eq!(infer("$name . ''"), Type::String);
+        assert_eq!(infer("$first . $last"), Type::String);
         assert_eq!(
             infer(
                 r#"
-        $a = 42;
-        $a
+        function name(): string {}
+        name() . '!'
         "#
             ),
-            Type::Integer
+            Type::NonEmptyString
         );
     }
 
     #[test]
-    fn it_infers_type_of_assignment_expression() {
-        assert_eq!(infer(r#"$a = 100"#), Type::Integer);
+    fn it_stops_tracking_long_literal_strings() {
+        let half = "a".repeat(256);
+
+        assert_eq!(
+            infer(&format!("'{half}' . '{half}'")),
+            Type::LiteralString("a".repeat(512).as_str().into())
+        );
+        assert_eq!(
+            infer(&format!("'{half}' . '{half}' . 'a'")),
+            Type::NonEmptyString
+        );
+        assert_eq!(
+            infer(&format!("'{half}{half}a' . $name")),
+            Type::NonEmptyString
+        );
     }
 
     #[test]
-    fn it_tracks_types_through_assignments() {
+    fn it_infers_boolean_literals() {
+        assert_eq!(infer("true"), Type::True);
+        assert_eq!(infer("false"), Type::False);
+    }
+
+    #[test]
+    fn it_expands_iterable_to_array_or_traversable() {
+        let traversable = Type::Named(ResolvedName {
+            resolved: b"Traversable".into(),
+            original: b"Traversable".into(),
+        });
+
         assert_eq!(
             infer(
                 r#"
-        $a = 42;
-        $b = $a;
-        $c = $b;
-        $c
+        function a(): iterable {}
+        a()
         "#
             ),
-            Type::Integer
+            Type::Union(vec![Type::Array, traversable.clone()])
         );
-    }
-
-    #[test]
-    fn it_infers_type_of_arrays() {
         assert_eq!(
-            infer(r#"$a = [1, 2, 3]"#),
-            Type::TypedArray(Box::new(Type::Integer), Box::new(Type::Integer))
+            infer_at(
+                r#"
+        function b(?iterable $items) {
+            $items^^
+        }
+        "#
+            ),
+            Type::Union(vec![Type::Array, traversable.clone(), Type::Null])
         );
-    }
-
-    #[test]
-    fn it_infers_type_of_keyed_array() {
         assert_eq!(
-            infer(r#"$a = ['a' => 1, 'b' => 2]"#),
-            Type::TypedArray(
-                Box::new(Type::Union(vec![
-                    Type::LiteralString(b"a".into()),
-                    Type::LiteralString(b"b".into())
-                ])),
-                Box::new(Type::Integer)
-            )
-        )
+            infer(
+                r#"
+        function c(): Iterable|int {}
+        c()
+        "#
+            ),
+            Type::Union(vec![Type::Array, traversable, Type::Integer])
+        );
     }
 
     #[test]
-    fn it_infers_type_of_mixed_keyed_array() {
+    fn it_infers_type_of_function_calls() {
         assert_eq!(
-            infer(r#"$a = ['a' => 1, 2]"#),
-            Type::TypedArray(
-                Box::new(Type::Union(vec![
-                    Type::LiteralString(b"a".into()),
-                    Type::Integer
-                ])),
-                Box::new(Type::Integer)
+            infer(
+                r#"
+        function a(): int {}
+        a()
+        "#
             ),
-        )
+            Type::Integer
+        );
     }
 
     #[test]
-    fn it_infers_type_of_new_expression() {
-        let inferred = infer(
-            r#"
-        class A {}
-        new A()
-        "#,
-        );
-
-        assert!(inferred.is_named());
+    fn it_infers_type_of_unqualified_function_calls_in_namespaces() {
+        assert_eq!(
+            infer_at(
+                r#"
+        namespace {
+            function a(): int {}
+        }
 
-        match inferred {
-            Type::Named(name) => assert_eq!(name.resolved, b"A"),
-            _ => panic!("Expected a named type 'A'."),
+        namespace App {
+            function b() {
+                $c = a();
+                $c^^
+            }
         }
+        "#
+            ),
+            Type::Integer
+        );
     }
 
     #[test]
-    fn it_infers_type_of_new_expression_on_class_string_literal() {
-        let inferred = infer(
-            r#"
-        class A {}
-        $a = 'A';
-        new $a()"#,
+    fn it_infers_type_of_caught_exceptions() {
+        assert_eq!(
+            infer_at(
+                r#"
+        try {
+        } catch (FooException|BarException $e) {
+            $e^^;
+        }
+        "#
+            ),
+            Type::Union(vec![
+                named("FooException", "FooException"),
+                named("BarException", "BarException"),
+            ])
         );
 
-        assert!(inferred.is_named());
+        assert_eq!(
+            infer_at(
+                r#"
+        namespace App;
 
-        match inferred {
-            Type::Named(name) => assert_eq!(name.resolved, b"A"),
-            _ => panic!("Expected a named type 'A'."),
+        use Foo\Exceptions\Failed;
+
+        try {
+        } catch (Failed $e) {
+            $e^^;
         }
+        "#
+            ),
+            named("Foo\\Exceptions\\Failed", "Failed")
+        );
     }
 
     #[test]
-    fn it_infers_types_of_function_parameters() {
+    fn it_merges_variables_assigned_in_try_blocks() {
+        // The assignment might not have happened if an exception was thrown.
         assert_eq!(
             infer_at(
                 r#"
-        function a(string $b) {
-            $b^^
+        try {
+            $a = 1;
+        } catch (Exception $e) {
         }
+
+        $a^^;
         "#
             ),
-            Type::String
+            Type::Union(vec![Type::Integer, Type::Null])
+        );
+
+        assert_eq!(
+            infer_at(
+                r#"
+        try {
+            $a = 1;
+        } catch (Exception) {
+            $a = 'a';
+        }
+
+        $a^^;
+        "#
+            ),
+            Type::Union(vec![Type::Integer, Type::LiteralString(b"a".into())])
         );
     }
 
     #[test]
-    fn outer_variables_are_not_accessible_inside_of_functions() {
+    fn it_ignores_catch_blocks_that_leave() {
         assert_eq!(
             infer_at(
                 r#"
-        $a = 42;
         function a() {
-            $a^^
+            try {
+                $a = 1;
+            } catch (FooException $e) {
+                return;
+            } catch (BarException) {
+                throw new Exception();
+            }
+
+            $a^^;
         }
         "#
             ),
-            Type::Mixed
+            Type::Integer
         );
     }
 
     #[test]
-    fn it_infers_type_of_variadic_parameters() {
+    fn it_applies_assignments_in_finally_blocks() {
         assert_eq!(
             infer_at(
                 r#"
-        function a(string ...$b) {
-            $b^^
+        try {
+            $a = 1;
+        } catch (Exception $e) {
+        } finally {
+            $a = 'b';
         }
+
+        $a^^;
         "#
             ),
-            Type::TypedArray(Box::new(Type::Integer), Box::new(Type::String))
+            Type::LiteralString(b"b".into())
         );
     }
 
     #[test]
-    fn it_infers_type_of_eval_expression() {
-        assert_eq!(infer(r#"eval('42')"#), Type::Mixed);
-    }
+    fn it_infers_types_in_nested_try_blocks() {
+        let code = r#"
+        try {
+            try {
+                $a = 1;
+            } catch (InnerException $e) {
+                throw $e;
+            }
 
-    #[test]
-    fn it_infers_type_of_empty_expression() {
-        assert_eq!(infer(r#"empty('')"#), Type::Boolean);
-    }
+            $b = $a;
+        } catch (OuterException $f) {
+            $f;
+        }
 
-    #[test]
-    fn it_infers_type_of_die_expression() {
-        assert_eq!(infer(r#"die('')"#), Type::Never);
-    }
+        $b;
+        "#;
 
-    #[test]
-    fn it_infers_type_of_exit_expression() {
-        assert_eq!(infer(r#"exit('')"#), Type::Never);
+        assert_eq!(
+            infer_at(&code.replace("$f;", "$f^^;")),
+            named("OuterException", "OuterException")
+        );
+        assert_eq!(
+            infer_at(&code.replace("$b;", "$b^^;")),
+            Type::Union(vec![Type::Integer, Type::Null])
+        );
     }
 
     #[test]
-    fn it_infers_type_of_isset_expression() {
-        assert_eq!(infer(r#"isset('')"#), Type::Boolean);
+    fn it_infers_type_of_match_expressions() {
+        assert_eq!(
+            infer_at(
+                r#"
+        enum Status {
+            case Active;
+            case Inactive;
+        }
+
+        function a(Status $status) {
+            $result^^ = match($status) {
+                Status::Active => 'on',
+                Status::Inactive => 'off',
+                default => null,
+            };
+        }
+        "#
+            ),
+            Type::Union(vec![
+                Type::LiteralString(b"on".into()),
+                Type::LiteralString(b"off".into()),
+                Type::Null,
+            ])
+        );
+
+        // Without a `default` arm, an unhandled value throws instead of producing `null`.
+        assert_eq!(
+            infer("match($a) { 1 => 'a', 2 => 'b' }"),
+            Type::Union(vec![
+                Type::LiteralString(b"a".into()),
+                Type::LiteralString(b"b".into()),
+            ])
+        );
     }
 
     #[test]
-    fn it_infers_type_of_unset_expression() {
-        assert_eq!(infer(r#"unset('')"#), Type::Void);
+    fn it_excludes_match_arms_that_throw() {
+        assert_eq!(
+            infer("match($a) { 1, 2 => 'a', default => throw new Exception() }"),
+            Type::LiteralString(b"a".into())
+        );
+        assert_eq!(
+            infer("match($a) { default => throw new Exception() }"),
+            Type::Never
+        );
     }
 
     #[test]
-    fn it_infers_type_of_print_expression() {
+    fn it_narrows_null_checks_in_if_statements() {
+        let code = r#"
+        class Foo {}
+
+        function a(?Foo $x) {
+            if ($x !== null) {
+                [$x, 'then'];
+            } else {
+                [$x, 'else'];
+            }
+
+            [$x, 'after'];
+        }
+        "#;
+
         assert_eq!(
-            infer(r#"print('')"#),
-            Type::ConstExpr(Box::new(ConstExpr::Integer(1.into())))
+            infer_at(&code.replace("[$x, 'then']", "[$x^^, 'then']")),
+            named("Foo", "Foo")
+        );
+        assert_eq!(
+            infer_at(&code.replace("[$x, 'else']", "[$x^^, 'else']")),
+            Type::Null
+        );
+        assert_eq!(
+            infer_at(&code.replace("[$x, 'after']", "[$x^^, 'after']")),
+            Type::Nullable(Box::new(named("Foo", "Foo")))
+        );
+        assert_eq!(
+            infer_at("class Foo {} function a(Foo|int|null $x) { if (is_null($x)) { $x^^; } }"),
+            Type::Null
+        );
+        assert_eq!(
+            infer_at("class Foo {} function a(Foo|int|null $x) { if (!is_null($x)) { $x^^; } }"),
+            Type::Union(vec![named("Foo", "Foo"), Type::Integer])
         );
     }
 
     #[test]
-    fn it_infers_type_of_concat_expression() {
-        assert_eq!(infer(r#"'a' . 'b'"#), Type::String);
+    fn it_narrows_null_checks_after_early_exits() {
+        for exit in ["return;", "throw new Exception();", "continue;", "break;"] {
+            assert_eq!(
+                infer_at(&format!(
+                    "class Foo {{}} function a(?Foo $x) {{ while (true) {{ if (null === $x) {{ {exit} }} $x^^; }} }}"
+                )),
+                named("Foo", "Foo"),
+                "{exit}"
+            );
+        }
+
+        assert_eq!(
+            infer_at("class Foo {} function a(?Foo $x, ?Foo $y) { if ($x === null || $y === null) { return; } [$x, $y^^]; }"),
+            named("Foo", "Foo")
+        );
     }
 
     #[test]
-    fn it_infers_type_of_instanceof_expression() {
-        assert_eq!(infer(r#"$a instanceof A"#), Type::Boolean);
+    fn it_narrows_both_sides_of_and_conditions() {
+        let code = "class Foo {} function a(?Foo $x, ?int $y) { if ($x !== null && $y !== null) { [$x, $y]; } }";
+
+        assert_eq!(
+            infer_at(&code.replace("[$x,", "[$x^^,")),
+            named("Foo", "Foo")
+        );
+        assert_eq!(infer_at(&code.replace("$y]", "$y^^]")), Type::Integer);
+        assert_eq!(
+            infer_at("class Foo {} function a(?Foo $x) { $x !== null && $x^^; }"),
+            named("Foo", "Foo")
+        );
+        assert_eq!(
+            infer_at("class Foo {} function a(?Foo $x) { $x === null || $x^^; }"),
+            named("Foo", "Foo")
+        );
     }
 
     #[test]
-    fn it_infers_type_of_reference_expression() {
-        assert_eq!(infer(r#"$b = 1; $a = &$b"#), Type::Integer);
+    fn it_narrows_null_checks_in_ternaries() {
+        let code = "class Foo {} function a(?Foo $x) { return $x === null ? [$x] : $x; }";
+
+        assert_eq!(infer_at(&code.replace("[$x]", "[$x^^]")), Type::Null);
+        assert_eq!(
+            infer_at(&code.replace(": $x", ": $x^^")),
+            named("Foo", "Foo")
+        );
+        assert_eq!(
+            infer("function a(?int $x) { return $x === null ? 'none' : $x; } $a = null; $a === null ? 'none' : 1"),
+            Type::Union(vec![Type::LiteralString(b"none".into()), Type::Integer])
+        );
     }
 
     #[test]
-    fn it_infers_type_of_parenthesized_expression() {
-        assert_eq!(infer(r#"(42)"#), Type::Integer);
+    fn it_narrows_null_checks_after_assert() {
+        assert_eq!(
+            infer_at("class Foo {} function a(?Foo $x) { assert($x !== null); $x^^; }"),
+            named("Foo", "Foo")
+        );
     }
 
     #[test]
-    fn it_infers_type_of_error_suppression_expression() {
-        assert_eq!(infer(r#"function foo(): int {} @foo()"#), Type::Integer);
+    fn it_infers_the_implicit_members_of_enums() {
+        let enums = r#"
+        enum Status: int {
+            case Active = 1;
+            case Inactive = 0;
+        }
+
+        enum Suit {
+            case Hearts;
+            case Spades;
+        }
+        "#;
+
+        let status = named("Status", "Status");
+        let suit = named("Suit", "Suit");
+        let list = |r#type: Type<ResolvedName>| {
+            Type::Generic(
+                Box::new(Type::List),
+                vec![GenericTypeArgument {
+                    r#type,
+                    variance: None,
+                }],
+            )
+        };
+
+        assert_eq!(infer(&format!("{enums} Status::from(1)")), status);
+        assert_eq!(
+            infer(&format!("{enums} Status::tryFrom(1)")),
+            Type::Nullable(Box::new(status.clone()))
+        );
+        assert_eq!(
+            infer(&format!("{enums} Status::cases()")),
+            list(status.clone())
+        );
+        assert_eq!(infer(&format!("{enums} Suit::cases()")), list(suit));
+        assert_eq!(infer(&format!("{enums} Suit::from(1)")), Type::Invalid);
+
+        assert_eq!(
+            infer(&format!("{enums} Status::Active->value")),
+            Type::Integer
+        );
+        assert_eq!(
+            infer(&format!("{enums} Status::Active->name")),
+            Type::String
+        );
+        assert_eq!(infer(&format!("{enums} Suit::Hearts->name")), Type::String);
+        assert_eq!(infer(&format!("{enums} Suit::Hearts->value")), Type::Mixed);
+
+        // The result of `tryFrom()` is narrowed by a null check like any other nullable value.
+        assert_eq!(
+            infer_at(&format!(
+                "{enums} function a(int $input) {{ $status = Status::tryFrom($input); if ($status === null) {{ return; }} $status^^; }}"
+            )),
+            status
+        );
+    }
+
+    #[test]
+    fn it_narrows_the_subject_of_match_arms() {
+        let code = r#"
+        enum Status {
+            case Active;
+            case Inactive;
+        }
+
+        function a(Status|string|null $status) {
+            match($status) {
+                Status::Active, Status::Inactive => $status,
+                'on', null => strlen($status),
+                default => [$status],
+            };
+
+            $status;
+        }
+        "#;
+
+        assert_eq!(
+            infer_at(&code.replace("=> $status,", "=> $status^^,")),
+            named("Status", "Status")
+        );
+        assert_eq!(
+            infer_at(&code.replace("strlen($status)", "strlen($status^^)")),
+            Type::Union(vec![Type::LiteralString(b"on".into()), Type::Null])
+        );
+        assert_eq!(
+            infer_at(&code.replace("[$status]", "[$status^^]")),
+            Type::Union(vec![named("Status", "Status"), Type::String, Type::Null])
+        );
+        assert_eq!(
+            infer_at(&code.replace("$status;", "$status^^;")),
+            Type::Union(vec![named("Status", "Status"), Type::String, Type::Null])
+        );
+    }
+
+    #[test]
+    fn it_infers_type_of_concat_assignments() {
+        assert_eq!(
+            infer("$s = 'Hello'; $s .= '!'; $s"),
+            Type::LiteralString(b"Hello!".into())
+        );
+        assert_eq!(
+            infer("function a(): string {} $s = a(); $s .= '!'; $s"),
+            Type::NonEmptyString
+        );
+        assert_eq!(
+            infer("function a(): string {} $s = a(); $s .= a(); $s"),
+            Type::String
+        );
+    }
+
+    #[test]
+    fn it_infers_type_of_arithmetic_assignments() {
+        assert_eq!(infer("$n = 1; $n += 1; $n"), Type::Integer);
+        assert_eq!(infer("$n = 1; $n -= 1.5; $n"), Type::Float);
+        assert_eq!(infer("$n = 1; $n *= true; $n"), Type::Integer);
+        assert_eq!(
+            infer("$n = 1; $n /= 2; $n"),
+            Type::Union(vec![Type::Integer, Type::Float])
+        );
+        assert_eq!(infer("$n = 1.5; $n %= 2; $n"), Type::Integer);
+        assert_eq!(
+            infer("$n = 2; $n **= 2; $n"),
+            Type::Union(vec![Type::Integer, Type::Float])
+        );
+        assert_eq!(
+            infer("$n = '1'; $n += 1; $n"),
+            Type::Union(vec![Type::Integer, Type::Float])
+        );
+        assert_eq!(infer("$n = 1; $n |= 2; $n"), Type::Integer);
+        assert_eq!(infer("$n = 1; $n <<= 2; $n"), Type::Integer);
+    }
+
+    #[test]
+    fn it_infers_type_of_coalesce_assignments() {
+        assert_eq!(
+            infer("function a(): ?int {} $x = a(); $x ??= 'a'; $x"),
+            Type::Union(vec![Type::Integer, Type::LiteralString(b"a".into())])
+        );
+        // The right-hand side is never evaluated when the value can't be null.
+        assert_eq!(infer("$x = 1; $x ??= 'a'; $x"), Type::Integer);
+        assert_eq!(infer("$x ??= 'a'; $x"), Type::LiteralString(b"a".into()));
+    }
+
+    #[test]
+    fn it_widens_arrays_when_assigning_to_elements() {
+        assert_eq!(
+            infer("$arr = [1, 2]; $arr[] = 'a'; $arr"),
+            Type::TypedArray(
+                Box::new(Type::Integer),
+                Box::new(Type::Union(vec![
+                    Type::Integer,
+                    Type::LiteralString(b"a".into())
+                ]))
+            )
+        );
+        assert_eq!(
+            infer("$arr = [1]; $arr['k'] = 2; $arr"),
+            Type::TypedArray(
+                Box::new(Type::Union(vec![
+                    Type::Integer,
+                    Type::LiteralString(b"k".into())
+                ])),
+                Box::new(Type::Integer)
+            )
+        );
+        assert_eq!(
+            infer("$arr['k'] = 1.5; $arr"),
+            Type::TypedArray(
+                Box::new(Type::LiteralString(b"k".into())),
+                Box::new(Type::Float)
+            )
+        );
+        assert_eq!(
+            infer("$arr = [1]; $arr[0] .= 'a'; $arr"),
+            Type::TypedArray(
+                Box::new(Type::Integer),
+                Box::new(Type::Union(vec![Type::Integer, Type::NonEmptyString]))
+            )
+        );
+    }
+
+    #[test]
+    fn it_infers_type_of_properties_assigned_in_the_same_method() {
+        let code = r#"
+        class Foo {
+            public $bar;
+
+            public function a() {
+                $before = $this->bar;
+                $this->bar = 1;
+                $this->bar += 1.5;
+                $after = $this->bar;
+            }
+
+            public function b() {
+                $other = $this->bar;
+            }
+        }
+        "#;
+
+        assert_eq!(infer_at(&code.replace("$before", "$before^^")), Type::Mixed);
+        assert_eq!(infer_at(&code.replace("$after", "$after^^")), Type::Float);
+        assert_eq!(infer_at(&code.replace("$other", "$other^^")), Type::Mixed);
+    }
+
+    #[test]
+    fn it_infers_type_of_iife() {
+        assert_eq!(
+            infer(
+                r#"
+        (function (): int {
+            return 42;
+        })()
+        "#
+            ),
+            Type::Integer
+        );
+    }
+
+    #[test]
+    fn it_infers_type_of_function_calls_on_callable_string() {
+        assert_eq!(
+            infer(
+                r#"
+        function a(): string {}
+        'a'()
+        "#
+            ),
+            Type::String
+        );
+
+        // `exit` and `die` are functions as of PHP 8.4, even though they aren't declared anywhere.
+        assert_eq!(infer("'exit'()"), Type::Never);
+        assert_eq!(infer("'DIE'('failed')"), Type::Never);
+    }
+
+    #[test]
+    fn it_infers_type_of_variable() {
+        assert_eq!(
+            infer(
+                r#"
+        $a = 42;
+        $a
+        "#
+            ),
+            Type::Integer
+        );
+    }
+
+    #[test]
+    fn it_infers_type_of_assignment_expression() {
+        assert_eq!(infer(r#"$a = 100"#), Type::Integer);
+    }
+
+    #[test]
+    fn it_tracks_types_through_assignments() {
+        assert_eq!(
+            infer(
+                r#"
+        $a = 42;
+        $b = $a;
+        $c = $b;
+        $c
+        "#
+            ),
+            Type::Integer
+        );
+    }
+
+    #[test]
+    fn it_infers_type_of_arrays() {
+        assert_eq!(
+            infer(r#"$a = [1, 2, 3]"#),
+            Type::TypedArray(Box::new(Type::Integer), Box::new(Type::Integer))
+        );
+    }
+
+    #[test]
+    fn it_infers_type_of_keyed_array() {
+        assert_eq!(
+            infer(r#"$a = ['a' => 1, 'b' => 2]"#),
+            shape(vec![
+                (string_key("a"), Type::Integer),
+                (string_key("b"), Type::Integer)
+            ])
+        )
+    }
+
+    #[test]
+    fn it_infers_type_of_mixed_keyed_array() {
+        assert_eq!(
+            infer(r#"$a = ['a' => 1, 2, '5' => true, 'b']"#),
+            shape(vec![
+                (string_key("a"), Type::Integer),
+                (integer_key("0"), Type::Integer),
+                (integer_key("5"), Type::True),
+                (integer_key("6"), Type::LiteralString(b"b".into())),
+            ])
+        )
+    }
+
+    #[test]
+    fn it_normalizes_array_keys_like_php() {
+        assert_eq!(
+            infer(r#"$a = ['5' => 'a', 5 => 'b']"#),
+            shape(vec![(integer_key("5"), Type::LiteralString(b"b".into()))])
+        );
+
+        assert_eq!(
+            infer(r#"$a = [5.9 => 'a', true => 'b', false => 'c', null => 'd', '05' => 'e']"#),
+            shape(vec![
+                (integer_key("5"), Type::LiteralString(b"a".into())),
+                (integer_key("1"), Type::LiteralString(b"b".into())),
+                (integer_key("0"), Type::LiteralString(b"c".into())),
+                (string_key(""), Type::LiteralString(b"d".into())),
+                (string_key("05"), Type::LiteralString(b"e".into())),
+            ])
+        );
+
+        assert_eq!(
+            infer(r#"$a = [-1 => 'a', 1.5 => 'b', 'c']"#),
+            shape(vec![
+                (integer_key("-1"), Type::LiteralString(b"a".into())),
+                (integer_key("1"), Type::LiteralString(b"b".into())),
+                (integer_key("2"), Type::LiteralString(b"c".into())),
+            ])
+        );
+    }
+
+    #[test]
+    fn it_normalizes_keys_when_reading_from_arrays() {
+        assert_eq!(
+            infer(
+                r#"
+        $a = [5 => 'five', 1 => 'one'];
+        $a['5']
+        "#
+            ),
+            Type::LiteralString(b"five".into())
+        );
+
+        assert_eq!(
+            infer(
+                r#"
+        $a = [5 => 'five', 1 => 'one'];
+        $a[true]
+        "#
+            ),
+            Type::LiteralString(b"one".into())
+        );
+
+        let (id, map) = infer_last(
+            r#"
+        $a = [5 => 'five'];
+        $a[5.5]
+        "#,
+        );
+
+        assert!(!map.is_undefined_key(id));
+    }
+
+    #[test]
+    fn it_normalizes_non_literal_key_types_in_typed_arrays() {
+        assert_eq!(
+            infer(r#"$a = [strtoupper('a') => 1, 1.5 => 2, '7' => 3]"#),
+            Type::TypedArray(
+                Box::new(Type::Union(vec![Type::Mixed, Type::Integer])),
+                Box::new(Type::Integer)
+            )
+        )
+    }
+
+    #[test]
+    fn it_infers_type_of_array_with_non_literal_keys() {
+        assert_eq!(
+            infer(r#"$a = [strtoupper('a') => 1, 'b' => 2]"#),
+            Type::TypedArray(
+                Box::new(Type::Union(vec![
+                    Type::Mixed,
+                    Type::LiteralString(b"b".into())
+                ])),
+                Box::new(Type::Integer)
+            )
+        )
+    }
+
+    #[test]
+    fn it_falls_back_to_typed_arrays_for_large_literal_arrays() {
+        let items = (0..=32)
+            .map(|i| format!("'key{i}' => {i}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        assert!(matches!(
+            infer(&format!("$a = [{items}]")),
+            Type::TypedArray(_, value) if *value == Type::Integer
+        ));
+
+        let items = (0..32)
+            .map(|i| format!("'key{i}' => {i}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        assert!(matches!(
+            infer(&format!("$a = [{items}]")),
+            Type::Shaped { items, .. } if items.len() == 32
+        ));
+    }
+
+    #[test]
+    fn it_infers_the_type_of_keys_in_array_shapes() {
+        assert_eq!(
+            infer(
+                r#"
+        $config = ['host' => 'localhost', 'port' => 3306];
+        $config['port']
+        "#
+            ),
+            Type::Integer
+        );
+    }
+
+    #[test]
+    fn it_marks_keys_that_are_not_in_the_array_shape() {
+        let (id, map) = infer_last(
+            r#"
+        $config = ['host' => 'localhost', 'port' => 3306];
+        $config['password']
+        "#,
+        );
+
+        assert_eq!(map.resolve(id), &Type::Mixed);
+        assert!(map.is_undefined_key(id));
+
+        let (id, map) = infer_last(
+            r#"
+        $config = ['host' => 'localhost', 'port' => 3306];
+        $config['host']
+        "#,
+        );
+
+        assert!(!map.is_undefined_key(id));
+    }
+
+    #[test]
+    fn it_widens_arrays_when_appending() {
+        assert_eq!(
+            infer("$arr = []; $arr[] = 1; $arr"),
+            Type::TypedArray(Box::new(Type::Integer), Box::new(Type::Integer))
+        );
+        assert_eq!(
+            infer_at("/** @param int[] $arr */ function a(array $arr) { $arr[] = 'a'; $arr^^; }"),
+            Type::TypedArray(
+                Box::new(Type::Union(vec![Type::String, Type::Integer])),
+                Box::new(Type::Union(vec![
+                    Type::Integer,
+                    Type::LiteralString(b"a".into())
+                ]))
+            )
+        );
+        assert_eq!(
+            infer_at(
+                "/** @param list<int> $arr */ function a(array $arr) { $arr[] = 'a'; $arr^^; }"
+            ),
+            Type::Generic(
+                Box::new(Type::NonEmptyList),
+                vec![GenericTypeArgument {
+                    r#type: Type::Union(vec![Type::Integer, Type::LiteralString(b"a".into())]),
+                    variance: None,
+                }]
+            )
+        );
+        assert_eq!(
+            infer_at(
+                "/** @param array<string, int> $arr */ function a(array $arr) { $arr[] = 1; $arr^^; }"
+            ),
+            Type::Generic(
+                Box::new(Type::NonEmptyArray),
+                vec![
+                    GenericTypeArgument {
+                        r#type: Type::Union(vec![Type::String, Type::Integer]),
+                        variance: None,
+                    },
+                    GenericTypeArgument {
+                        r#type: Type::Integer,
+                        variance: None,
+                    },
+                ]
+            )
+        );
+        // There's nothing to widen when the array's types aren't known.
+        assert_eq!(
+            infer_at("function a(array $arr) { $arr[] = 1; $arr^^; }"),
+            Type::Array
+        );
+    }
+
+    #[test]
+    fn it_widens_nested_arrays_when_appending() {
+        assert_eq!(
+            infer("$arr[][] = 1; $arr"),
+            Type::TypedArray(
+                Box::new(Type::Integer),
+                Box::new(Type::TypedArray(
+                    Box::new(Type::Integer),
+                    Box::new(Type::Integer)
+                ))
+            )
+        );
+        assert_eq!(
+            infer("$arr = ['a' => [1]]; $arr['a'][] = 'b'; $arr"),
+            shape(vec![(
+                string_key("a"),
+                Type::TypedArray(
+                    Box::new(Type::Integer),
+                    Box::new(Type::Union(vec![
+                        Type::Integer,
+                        Type::LiteralString(b"b".into())
+                    ]))
+                )
+            )])
+        );
+        assert_eq!(
+            infer("$arr = []; $arr['a'][] = 1; $arr"),
+            Type::TypedArray(
+                Box::new(Type::LiteralString(b"a".into())),
+                Box::new(Type::TypedArray(
+                    Box::new(Type::Integer),
+                    Box::new(Type::Integer)
+                ))
+            )
+        );
+    }
+
+    #[test]
+    fn it_sets_the_key_type_of_empty_arrays_on_the_first_nested_write() {
+        let nested = |keys: Type<ResolvedName>| {
+            Type::TypedArray(
+                Box::new(Type::LiteralString(b"a".into())),
+                Box::new(Type::TypedArray(Box::new(keys), Box::new(Type::Integer))),
+            )
+        };
+
+        assert_eq!(
+            infer("$n = []; $n['a']['b'] = 1; $n"),
+            nested(Type::LiteralString(b"b".into()))
+        );
+        assert_eq!(
+            infer("$n = []; $n['a']['b'] = 1; $n['a'][] = 2; $n"),
+            nested(Type::Union(vec![
+                Type::LiteralString(b"b".into()),
+                Type::Integer
+            ]))
+        );
+        // Other keys still widen the array.
+        assert_eq!(
+            infer("$n = []; $n['a'] = 1; $n['b'] = 'c'; $n"),
+            Type::TypedArray(
+                Box::new(Type::Union(vec![
+                    Type::LiteralString(b"a".into()),
+                    Type::LiteralString(b"b".into())
+                ])),
+                Box::new(Type::Union(vec![
+                    Type::Integer,
+                    Type::LiteralString(b"c".into())
+                ]))
+            )
+        );
+    }
+
+    #[test]
+    fn it_updates_array_shapes_when_keys_are_assigned() {
+        assert_eq!(
+            infer(
+                r#"
+        $config = ['host' => 'localhost'];
+        $config['port'] = 3306;
+        $config['host'] = null;
+        $config[] = true;
+        $config
+        "#
+            ),
+            shape(vec![
+                (string_key("host"), Type::Null),
+                (string_key("port"), Type::Integer),
+                (integer_key("0"), Type::True),
+            ])
+        );
+
+        assert_eq!(
+            infer(
+                r#"
+        $config = ['host' => 'localhost'];
+        $config[strtoupper('port')] = 3306;
+        $config
+        "#
+            ),
+            Type::TypedArray(
+                Box::new(Type::Union(vec![
+                    Type::LiteralString(b"host".into()),
+                    Type::Mixed
+                ])),
+                Box::new(Type::Union(vec![
+                    Type::LiteralString(b"localhost".into()),
+                    Type::Integer
+                ]))
+            )
+        );
+    }
+
+    #[test]
+    fn it_merges_array_shapes() {
+        assert_eq!(
+            infer(
+                r#"
+        $config = ['host' => 'localhost', 'port' => 3306];
+        $config += ['port' => '3306', 'debug' => false];
+        $config
+        "#
+            ),
+            shape(vec![
+                (string_key("host"), Type::LiteralString(b"localhost".into())),
+                (string_key("port"), Type::Integer),
+                (string_key("debug"), Type::False),
+            ])
+        );
+
+        assert_eq!(
+            infer(r#"array_merge(['host' => 'localhost', 'a'], ['host' => 1, 'b'])"#),
+            shape(vec![
+                (string_key("host"), Type::Integer),
+                (integer_key("0"), Type::LiteralString(b"a".into())),
+                (integer_key("1"), Type::LiteralString(b"b".into())),
+            ])
+        );
+    }
+
+    #[test]
+    fn it_infers_type_of_new_expression() {
+        let inferred = infer(
+            r#"
+        class A {}
+        new A()
+        "#,
+        );
+
+        assert!(inferred.is_named());
+
+        match inferred {
+            Type::Named(name) => assert_eq!(name.resolved, b"A"),
+            _ => panic!("Expected a named type 'A'."),
+        }
+    }
+
+    #[test]
+    fn it_infers_type_of_new_expression_on_class_string_literal() {
+        let inferred = infer(
+            r#"
+        class A {}
+        $a = 'A';
+        new $a()"#,
+        );
+
+        assert!(inferred.is_named());
+
+        match inferred {
+            Type::Named(name) => assert_eq!(name.resolved, b"A"),
+            _ => panic!("Expected a named type 'A'."),
+        }
+    }
+
+    #[test]
+    fn it_infers_types_of_function_parameters() {
+        assert_eq!(
+            infer_at(
+                r#"
+        function a(string $b) {
+            $b^^
+        }
+        "#
+            ),
+            Type::String
+        );
+    }
+
+    #[test]
+    fn it_infers_standalone_literal_types_of_parameters() {
+        assert_eq!(
+            infer_at(
+                r#"
+        function a(true $b) {
+            $b^^
+        }
+        "#
+            ),
+            Type::True
+        );
+        assert_eq!(
+            infer_at(
+                r#"
+        function a(false|null $b) {
+            $b^^
+        }
+        "#
+            ),
+            Type::Union(vec![Type::False, Type::Null])
+        );
+    }
+
+    #[test]
+    fn outer_variables_are_not_accessible_inside_of_functions() {
+        assert_eq!(
+            infer_at(
+                r#"
+        $a = 42;
+        function a() {
+            $a^^
+        }
+        "#
+            ),
+            Type::Mixed
+        );
+    }
+
+    #[test]
+    fn closures_only_see_the_variables_they_capture() {
+        let code = r#"
+        $a = 42;
+        $b = 'b';
+        $f = function (string $c) use ($a) {
+            $a;
+            $b;
+            $d = 1.5;
+        };
+        $c;
+        $d;
+        "#;
+
+        assert_eq!(infer_at(&code.replace("$a;", "$a^^;")), Type::Integer);
+        assert_eq!(infer_at(&code.replace("$b;", "$b^^;")), Type::Mixed);
+        assert_eq!(infer_at(&code.replace("$c;", "$c^^;")), Type::Mixed);
+        assert_eq!(infer_at(&code.replace("$d;", "$d^^;")), Type::Mixed);
+    }
+
+    #[test]
+    fn arrow_functions_see_the_enclosing_scope() {
+        let code = r#"
+        $a = 42;
+        $f = fn (string $b) => [$a, $b, $c = 1];
+        $b;
+        $c;
+        "#;
+
+        assert_eq!(infer_at(&code.replace("$a,", "$a^^,")), Type::Integer);
+        assert_eq!(infer_at(&code.replace("$b,", "$b^^,")), Type::String);
+        assert_eq!(infer_at(&code.replace("$b;", "$b^^;")), Type::Mixed);
+        assert_eq!(infer_at(&code.replace("$c;", "$c^^;")), Type::Mixed);
+    }
+
+    #[test]
+    fn it_infers_types_of_superglobals() {
+        assert_eq!(infer("$_SERVER['REQUEST_METHOD']"), Type::String);
+        assert_eq!(infer("$_SERVER['REQUEST_TIME']"), Type::Integer);
+        assert_eq!(infer("$_SERVER['X_CUSTOM']"), Type::Mixed);
+        assert_eq!(
+            infer("$_GET['page']"),
+            Type::Union(vec![Type::String, Type::Array])
+        );
+        assert_eq!(
+            infer_at(
+                r#"
+        function a() {
+            $_SESSION^^;
+        }
+        "#
+            ),
+            Type::TypedArray(Box::new(Type::String), Box::new(Type::Mixed))
+        );
+    }
+
+    #[test]
+    fn it_infers_argv_and_argc_in_the_global_scope() {
+        assert_eq!(infer("$argv[0]"), Type::String);
+        assert_eq!(infer("$argc"), Type::Integer);
+        assert_eq!(
+            infer_at(
+                r#"
+        function a() {
+            $argv^^;
+        }
+        "#
+            ),
+            Type::Mixed
+        );
+    }
+
+    #[test]
+    fn it_infers_globals_by_name() {
+        assert_eq!(
+            infer_at(
+                r#"
+        $count = 1;
+
+        function a() {
+            $GLOBALS['count']^^;
+        }
+        "#
+            ),
+            Type::Integer
+        );
+        assert_eq!(infer("$GLOBALS['argc']"), Type::Integer);
+        assert_eq!(infer("$GLOBALS['unknown']"), Type::Mixed);
+    }
+
+    #[test]
+    fn it_uses_the_configured_superglobals() {
+        let result = Parser::parse(Lexer::new(b"<?php $_SERVER['APP_ENV']; $_GET;"));
+        let index = Index::new();
+        let map = TypeEngine::new(&index)
+            .with_superglobals(
+                Superglobals::default()
+                    .with_superglobal(
+                        b"$_SERVER",
+                        Type::TypedArray(Box::new(Type::String), Box::new(Type::String)),
+                    )
+                    .without(b"$_GET"),
+            )
+            .infer(&result.ast);
+
+        let types = result
+            .ast
+            .iter()
+            .filter_map(|statement| match &statement.kind {
+                StatementKind::Expression(statement) => {
+                    Some(map.resolve(statement.expression.id).clone())
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(types, vec![Type::String, Type::Mixed]);
+    }
+
+    #[test]
+    fn it_infers_type_of_variadic_parameters() {
+        assert_eq!(
+            infer_at(
+                r#"
+        function a(string ...$b) {
+            $b^^
+        }
+        "#
+            ),
+            Type::TypedArray(Box::new(Type::Integer), Box::new(Type::String))
+        );
+    }
+
+    #[test]
+    fn it_infers_type_of_eval_expression() {
+        assert_eq!(infer(r#"eval('42')"#), Type::Mixed);
+    }
+
+    #[test]
+    fn it_infers_type_of_empty_expression() {
+        assert_eq!(infer(r#"empty('')"#), Type::Boolean);
+    }
+
+    #[test]
+    fn it_infers_type_of_die_expression() {
+        assert_eq!(infer(r#"die('')"#), Type::Never);
+    }
+
+    #[test]
+    fn it_infers_type_of_exit_expression() {
+        assert_eq!(infer(r#"exit('')"#), Type::Never);
+    }
+
+    #[test]
+    fn it_infers_type_of_isset_expression() {
+        assert_eq!(infer(r#"isset('')"#), Type::Boolean);
+    }
+
+    #[test]
+    fn it_infers_type_of_unset_expression() {
+        assert_eq!(infer(r#"unset('')"#), Type::Void);
+    }
+
+    #[test]
+    fn it_forgets_variables_that_are_unset() {
+        assert_eq!(infer("$a = 1; unset($a); $a"), Type::Mixed);
+        assert_eq!(
+            infer("$a = 1; unset($a); $a = 'a'; $a"),
+            Type::LiteralString(b"a".into())
+        );
+
+        // Unsetting a reference only removes the binding, so the other variable keeps its value.
+        assert_eq!(infer("$a = 1; $b = &$a; unset($b); $a"), Type::Integer);
+        assert_eq!(
+            infer("$a = 1; $b = &$a; unset($b); $b = 'b'; $a"),
+            Type::Integer
+        );
+    }
+
+    #[test]
+    fn it_removes_unset_keys_from_array_shapes() {
+        assert_eq!(
+            infer(
+                r#"
+        $config = ['host' => 'localhost', 'port' => 3306];
+        unset($config['port']);
+        $config
+        "#
+            ),
+            shape(vec![(
+                string_key("host"),
+                Type::LiteralString(b"localhost".into())
+            )])
+        );
+
+        let (id, map) = infer_last(
+            r#"
+        $config = ['host' => 'localhost', 'port' => 3306];
+        unset($config['port']);
+        $config['port']
+        "#,
+        );
+
+        assert!(map.is_undefined_key(id));
+
+        // Arrays without a shape already describe keys that could be missing.
+        assert_eq!(
+            infer("$a = [1, 2]; $a[] = 'a'; unset($a[0]); $a"),
+            Type::TypedArray(
+                Box::new(Type::Integer),
+                Box::new(Type::Union(vec![
+                    Type::Integer,
+                    Type::LiteralString(b"a".into())
+                ]))
+            )
+        );
+    }
+
+    #[test]
+    fn it_forgets_the_types_of_properties_that_are_unset() {
+        let code = r#"
+        class Foo {
+            public $bar;
+
+            public function a() {
+                $this->bar = 1;
+                unset($this->bar);
+                $after = $this->bar;
+            }
+        }
+        "#;
+
+        assert_eq!(infer_at(&code.replace("$after", "$after^^")), Type::Mixed);
+    }
+
+    #[test]
+    fn it_merges_variables_from_each_branch_of_an_if_statement() {
+        assert_eq!(
+            infer("$a = 1; if ($b) { unset($a); } $a"),
+            Type::Union(vec![Type::Null, Type::Integer])
+        );
+
+        assert_eq!(
+            infer("if ($b) { $a = 1; } elseif ($c) { $a = 'a'; } else { $a = null; } $a"),
+            Type::Union(vec![
+                Type::Integer,
+                Type::LiteralString(b"a".into()),
+                Type::Null
+            ])
+        );
+
+        assert_eq!(
+            infer("$a = 1; if ($b): $a = 'a'; endif; $a"),
+            Type::Union(vec![Type::LiteralString(b"a".into()), Type::Integer])
+        );
+
+        // Branches that leave never reach the code after the `if`.
+        assert_eq!(
+            infer(
+                r#"
+        function a($b) {
+            $a = 1;
+
+            if ($b) {
+                unset($a);
+                return;
+            }
+
+            return $a;
+        }
+
+        $a = 1;
+        if ($b) { $a = 'a'; } else { throw new Exception(); }
+        $a
+        "#
+            ),
+            Type::LiteralString(b"a".into())
+        );
+    }
+
+    #[test]
+    fn it_infers_type_of_print_expression() {
+        assert_eq!(
+            infer(r#"print('')"#),
+            Type::ConstExpr(Box::new(ConstExpr::Integer(1.into())))
+        );
+    }
+
+    #[test]
+    fn it_infers_type_of_concat_expression() {
+        assert_eq!(infer(r#"'a' . 'b'"#), Type::LiteralString(b"ab".into()));
+    }
+
+    #[test]
+    fn it_infers_type_of_instanceof_expression() {
+        assert_eq!(infer(r#"$a instanceof A"#), Type::Boolean);
+    }
+
+    #[test]
+    fn it_infers_type_of_reference_expression() {
+        assert_eq!(infer(r#"$b = 1; $a = &$b"#), Type::Integer);
+    }
+
+    #[test]
+    fn it_infers_type_of_parenthesized_expression() {
+        assert_eq!(infer(r#"(42)"#), Type::Integer);
+    }
+
+    #[test]
+    fn it_infers_type_of_error_suppression_expression() {
+        assert_eq!(infer(r#"function foo(): int {} @foo()"#), Type::Integer);
+    }
+
+    #[test]
+    fn it_infers_type_of_include_expression() {
+        assert_eq!(infer(r#"include 'file.php'"#), Type::Mixed);
+    }
+
+    #[test]
+    fn it_infers_type_of_include_once_expression() {
+        assert_eq!(infer(r#"include_once 'file.php'"#), Type::Mixed);
+    }
+
+    #[test]
+    fn it_infers_type_of_require_expression() {
+        assert_eq!(infer(r#"require 'file.php'"#), Type::Mixed);
+    }
+
+    #[test]
+    fn it_infers_type_of_require_once_expression() {
+        assert_eq!(infer(r#"require_once 'file.php'"#), Type::Mixed);
+    }
+
+    #[test]
+    fn it_infers_type_of_function_closure_creation_expression() {
+        assert_eq!(
+            infer(r#"foo(...)"#),
+            Type::Named(ResolvedName {
+                resolved: b"Closure".into(),
+                original: b"Closure".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn it_infers_type_of_method_call() {
+        assert_eq!(
+            infer(
+                r#"
+        class Foo {
+            function bar(): int {}
+        }
+
+        (new Foo)->bar()
+        "#
+            ),
+            Type::Integer
+        );
+    }
+
+    #[test]
+    fn it_infers_type_of_method_closure_creation_expression() {
+        assert_eq!(
+            infer(
+                r#"
+        class Foo {
+            function bar(): int {}
+        }
+
+        (new Foo)->bar(...)
+        "#
+            ),
+            Type::Named(ResolvedName {
+                resolved: b"Closure".into(),
+                original: b"Closure".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn it_infers_clone_expressions() {
+        assert_eq!(
+            infer_at("class Foo {} function a(?Foo $foo) { clone^^ $foo; }"),
+            named("Foo", "Foo")
+        );
+        assert_eq!(
+            infer_at("class Foo {} function a(?Foo $foo) { clone^^($foo); }"),
+            named("Foo", "Foo")
+        );
+        assert_eq!(
+            infer_at("class Foo {} function a(Foo|int|null $foo) { clone^^ $foo; }"),
+            Type::Union(vec![named("Foo", "Foo"), Type::Integer])
+        );
+        assert_eq!(infer("clone $unknown"), Type::Mixed);
+    }
+
+    #[test]
+    fn it_ignores_the_return_type_of_clone_methods() {
+        assert_eq!(
+            infer(
+                r#"
+            class Bar {}
+
+            class Foo {
+                /** @return Bar */
+                public function __clone() {}
+            }
+
+            clone new Foo()
+            "#
+            ),
+            named("Foo", "Foo")
+        );
+    }
+
+    #[test]
+    fn it_infers_type_of_nullsafe_method_call() {
+        assert_eq!(
+            infer(
+                r#"
+            class Foo {
+                function bar(): int {}
+            }
+
+            $foo = new Foo();
+            $foo?->bar()
+            "#
+            ),
+            Type::Union(vec![Type::Integer, Type::Null])
+        );
+    }
+
+    #[test]
+    fn it_infers_type_of_nullsafe_chains_with_array_access() {
+        let code = r#"
+        class Address {
+            /** @return array{city: string, zip?: int} */
+            function parts(): array {}
+        }
+
+        class User {
+            public Address $address;
+
+            /** @return array<string, Address> */
+            function addresses(): array {}
+        }
+
+        function user(): ?User {}
+        "#;
+
+        // The chain short-circuits to null, so `null` is only added once for the whole chain.
+        assert_eq!(
+            infer(&format!("{code} user()?->address->parts()['city']")),
+            Type::Union(vec![Type::String, Type::Null])
+        );
+        assert_eq!(
+            infer(&format!(
+                "{code} user()?->addresses()['home']?->parts()['zip']"
+            )),
+            Type::Union(vec![Type::Integer, Type::Null])
+        );
+
+        // Parentheses end the chain.
+        assert_eq!(
+            infer(&format!(
+                "{code} (new User)->addresses()['home']->parts()['city']"
+            )),
+            Type::String
+        );
+    }
+
+    #[test]
+    fn it_infers_type_of_static_method_call_on_named_class() {
+        assert_eq!(
+            infer(
+                r#"
+        class Foo {
+            static function bar(): int {}
+        }
+
+        Foo::bar()
+        "#
+            ),
+            Type::Integer
+        );
+    }
+
+    #[test]
+    fn it_infers_type_of_class_constant_on_name() {
+        assert_eq!(
+            infer(
+                r#"
+        class Foo {}
+
+        Foo::class
+        "#
+            )
+            .to_string(),
+            "class-string<Foo>"
+        );
     }
 
     #[test]
-    fn it_infers_type_of_include_expression() {
-        assert_eq!(infer(r#"include 'file.php'"#), Type::Mixed);
+    fn it_infers_type_of_class_constant_on_object() {
+        assert_eq!(
+            infer(
+                r#"
+        class Foo {}
+
+        $foo = new Foo();
+        $foo::class
+        "#
+            )
+            .to_string(),
+            "class-string<Foo>"
+        );
+
+        assert_eq!(infer("$foo::class"), Type::ClassString);
     }
 
     #[test]
-    fn it_infers_type_of_include_once_expression() {
-        assert_eq!(infer(r#"include_once 'file.php'"#), Type::Mixed);
+    fn it_infers_type_of_class_constants_from_the_index() {
+        let code = r#"
+        interface HasVersion {
+            const VERSION = 2;
+        }
+
+        class Foo implements HasVersion {
+            const string NAME = 'foo';
+            const LABEL = 'Foo';
+            const ENABLED = true;
+            const RATIO = 1.5;
+            const LIMIT = PHP_INT_MAX;
+        }
+
+        class Bar extends Foo {}
+        "#;
+
+        for (fetch, expected) in [
+            ("Foo::NAME", Type::String),
+            ("Foo::LABEL", Type::LiteralString(b"Foo".into())),
+            ("Foo::ENABLED", Type::True),
+            ("Foo::RATIO", Type::Float),
+            ("Bar::LABEL", Type::LiteralString(b"Foo".into())),
+            ("Bar::VERSION", Type::Integer),
+            ("(new Bar())::LABEL", Type::LiteralString(b"Foo".into())),
+            ("Foo::LIMIT", Type::Mixed),
+            ("Foo::MISSING", Type::Mixed),
+        ] {
+            assert_eq!(infer(&format!("{code}\n{fetch}")), expected, "{fetch}");
+        }
     }
 
     #[test]
-    fn it_infers_type_of_require_expression() {
-        assert_eq!(infer(r#"require 'file.php'"#), Type::Mixed);
+    fn it_substitutes_the_receiver_for_late_bound_docblock_return_types() {
+        let code = r#"
+        class Builder {
+            /** @return $this */
+            public function setName(string $name) {}
+
+            /** @return static */
+            public static function make() {}
+
+            /** @return self */
+            public function copy() {}
+        }
+
+        class QueryBuilder extends Builder {}
+        "#;
+
+        let inferred = infer(&format!(
+            "{code} $builder = new QueryBuilder(); $builder->setName('a')"
+        ));
+
+        assert_eq!(inferred.to_string(), "QueryBuilder");
+
+        let inferred = infer(&format!(
+            "{code} $builder = new QueryBuilder(); $builder->setName('a')->setName('b')"
+        ));
+
+        assert_eq!(inferred.to_string(), "QueryBuilder");
+
+        assert_eq!(
+            infer(&format!("{code} Builder::make()")).to_string(),
+            "Builder"
+        );
+
+        // `self` is resolved when the docblock is parsed, so it always refers to the declaring class.
+        match infer(&format!(
+            "{code} $builder = new QueryBuilder(); $builder->copy()"
+        )) {
+            Type::Named(name) => assert_eq!(name.resolved, b"Builder"),
+            _ => panic!("Expected a named type 'Builder'."),
+        }
     }
 
     #[test]
-    fn it_infers_type_of_require_once_expression() {
-        assert_eq!(infer(r#"require_once 'file.php'"#), Type::Mixed);
+    fn it_uses_docblock_types_that_refine_native_types() {
+        let code = r#"
+        /**
+         * @param non-empty-string $name
+         * @return list<string>
+         */
+        function names(string $name): array {}
+
+        /** @return string */
+        function total(): int {}
+        "#;
+
+        assert_eq!(
+            infer(&format!("{code} names('a')")).to_string(),
+            "list<string>"
+        );
+
+        // Docblock types that contradict the native type are ignored.
+        assert_eq!(infer(&format!("{code} total()")), Type::Integer);
+
+        assert_eq!(
+            infer_at("function greet(string $name) { ^^$name; }"),
+            Type::String
+        );
+        assert_eq!(
+            infer_at(
+                "/** @param non-empty-string $name */ function greet(string $name) { ^^$name; }"
+            ),
+            Type::NonEmptyString
+        );
     }
 
     #[test]
-    fn it_infers_type_of_function_closure_creation_expression() {
+    fn it_resolves_magic_members_and_docblock_tags() {
+        let code = r#"
+        class Service {}
+        class Logger {}
+
+        /**
+         * @property Logger $logger
+         * @method Service make(string $id)
+         * @method static static instance()
+         */
+        class Container {
+            public array $bindings = [];
+
+            public function __construct(public string $name) {}
+
+            public function __get(string $name): Service {}
+
+            public function __call(string $name, array $arguments): int {}
+
+            public static function __callStatic(string $name, array $arguments): string {}
+
+            public function __invoke(string $id): Service {}
+        }
+
+        class Application extends Container {}
+
+        $container = new Container();
+        "#;
+
+        let member = |expression: &str| {
+            let (id, map) = infer_last(&format!("{code} {expression}"));
+
+            (map.resolve(id).to_string(), map.get_virtual_member(id))
+        };
+
+        // Declared members always win.
+        assert_eq!(member("$container->bindings"), ("array".into(), None));
+        assert_eq!(member("$container->name"), ("string".into(), None));
+
+        // `@property` and `@method` tags are used before the magic methods.
         assert_eq!(
-            infer(r#"foo(...)"#),
-            Type::Named(ResolvedName {
-                resolved: b"Closure".into(),
-                original: b"Closure".into(),
-            })
+            member("$container->logger"),
+            ("Logger".into(), Some(VirtualMember::Docblock))
+        );
+        assert_eq!(
+            member("$container->make('cache')"),
+            ("Service".into(), Some(VirtualMember::Docblock))
+        );
+        assert_eq!(
+            member("Application::instance()"),
+            ("Application".into(), Some(VirtualMember::Docblock))
+        );
+        assert_eq!(
+            member("(new Application())->logger"),
+            ("Logger".into(), Some(VirtualMember::Docblock))
+        );
+
+        // Anything else falls back to the magic methods.
+        assert_eq!(
+            member("$container->cache"),
+            ("Service".into(), Some(VirtualMember::Magic))
+        );
+        assert_eq!(
+            member("$container->flush()"),
+            ("int".into(), Some(VirtualMember::Magic))
         );
+        assert_eq!(
+            member("Container::flush()"),
+            ("string".into(), Some(VirtualMember::Magic))
+        );
+        assert_eq!(
+            member("$container?->cache"),
+            ("Service | null".into(), Some(VirtualMember::Magic))
+        );
+
+        // Calling the object directly uses `__invoke`.
+        assert_eq!(member("$container('cache')"), ("Service".into(), None));
+
+        // Classes without magic methods don't resolve anything.
+        assert_eq!(member("(new Logger())->level"), ("mixed".into(), None));
+        assert_eq!(member("(new Logger())('debug')"), ("mixed".into(), None));
     }
 
     #[test]
-    fn it_infers_type_of_method_call() {
+    fn it_redacts_arguments_passed_to_sensitive_parameters() {
+        let (arguments, map) = infer_call_arguments(
+            r#"
+        function login(string $user, #[\SensitiveParameter] string $password) {}
+
+        login('ryan', 'secret')
+        "#,
+        );
+        let [user, password] = arguments[..] else {
+            panic!("Expected two arguments.");
+        };
+
+        assert!(!map.is_sensitive(user));
+        assert!(map.is_sensitive(password));
         assert_eq!(
-            infer(
-                r#"
-        class Foo {
-            function bar(): int {}
+            map.resolve(password),
+            &Type::LiteralString(b"secret".into())
+        );
+        assert_eq!(map.render(password, true), "string");
+        assert_eq!(map.render(password, false), "literal-string");
+        assert_eq!(map.render(user, true), "literal-string");
+    }
+
+    #[test]
+    fn it_redacts_named_arguments_passed_to_sensitive_method_parameters() {
+        let (arguments, map) = infer_call_arguments(
+            r#"
+        class Auth {
+            static function login(string $user, #[SensitiveParameter] string $password) {}
         }
 
-        (new Foo)->bar()
-        "#
-            ),
-            Type::Integer
+        Auth::login(password: 'secret', user: 'ryan')
+        "#,
         );
+        let [password, user] = arguments[..] else {
+            panic!("Expected two arguments.");
+        };
+
+        assert!(!map.is_sensitive(user));
+        assert!(map.is_sensitive(password));
     }
 
     #[test]
-    fn it_infers_type_of_method_closure_creation_expression() {
+    fn it_reports_differences_between_type_maps_by_span() {
+        let before = infer_map("$a = 1; $b = 'foo';");
+        let after = infer_map("$a = 1; $b = 12345;");
+
+        assert!(before.diff(&before).is_empty());
+
+        let differences = before.diff(&after);
+
         assert_eq!(
-            infer(
-                r#"
-        class Foo {
-            function bar(): int {}
+            differences
+                .iter()
+                .map(|difference| (difference.span.start, difference.span.end))
+                .collect::<Vec<_>>(),
+            vec![(14, 16), (14, 24), (19, 24)]
+        );
+        assert!(differences.iter().all(|difference| {
+            difference.before == Type::LiteralString(b"foo".into())
+                && difference.after == Type::Integer
+        }));
+    }
+
+    #[test]
+    fn it_uses_the_array_access_countable_and_iterator_protocols() {
+        let code = r#"
+        class Item {}
+
+        class Collection implements ArrayAccess, Countable, IteratorAggregate {
+            public function offsetExists(mixed $offset): bool {}
+            public function offsetGet(mixed $offset): Item {}
+            public function offsetSet(mixed $offset, mixed $value): void {}
+            public function offsetUnset(mixed $offset): void {}
+            public function count(): int {}
+
+            /** @return ArrayIterator<string, Item> */
+            public function getIterator(): ArrayIterator {}
         }
 
-        (new Foo)->bar(...)
-        "#
-            ),
-            Type::Named(ResolvedName {
-                resolved: b"Closure".into(),
-                original: b"Closure".into(),
+        class Cursor implements Iterator {
+            public function current(): Item {}
+            public function key(): int {}
+            public function next(): void {}
+            public function rewind(): void {}
+            public function valid(): bool {}
+        }
+
+        $items = new Collection;
+        "#;
+
+        assert_eq!(
+            infer(&format!("{code} $items['first']")).to_string(),
+            "Item"
+        );
+        assert_eq!(infer(&format!("{code} count($items)")), Type::Integer);
+        assert_eq!(
+            infer_at(&format!(
+                "{code} foreach ($items as $key => $item) {{ ^^$item; }}"
+            ))
+            .to_string(),
+            "Item"
+        );
+        assert_eq!(
+            infer_at(&format!(
+                "{code} foreach ($items as $key => $item) {{ ^^$key; }}"
+            )),
+            Type::String
+        );
+        assert_eq!(
+            infer_at(&format!(
+                "{code} foreach (new Cursor as $key => $item) {{ ^^$key; }}"
+            )),
+            Type::Integer
+        );
+
+        // The protocol method wins over an array type that describes the same object.
+        assert_eq!(
+            infer_at(&format!(
+                "{code} /** @param Collection&array<int, string> $items */ function first($items) {{ $items[0]^^; }}"
+            ))
+            .to_string(),
+            "Item"
+        );
+
+        // Arrays are still read and iterated as arrays.
+        assert_eq!(
+            infer_at("/** @param array<string, int> $a */ function f($a) { foreach ($a as $k => $v) { ^^$v; } }"),
+            Type::Integer
+        );
+    }
+
+    fn infer_map(code: &str) -> TypeMap {
+        let result = Parser::parse(Lexer::new(format!("<?php {}", code).as_bytes()));
+
+        let mut builder = IndexBuilder::new();
+        builder.index(FileId::new(0), &result.ast);
+        let index = builder.build();
+
+        TypeEngine::new(&index).infer(&result.ast)
+    }
+
+    /// Parse the given code, infer the types and return the IDs of the arguments passed to the
+    /// call in the last expression, along with the generated `TypeMap`.
+    fn infer_call_arguments(code: &str) -> (Vec<NodeId>, TypeMap) {
+        let result = Parser::parse(Lexer::new(format!("<?php {};", code).as_bytes()));
+
+        let mut builder = IndexBuilder::new();
+        builder.index(FileId::new(0), &result.ast);
+        let index = builder.build();
+
+        let map = TypeEngine::new(&index).infer(&result.ast);
+
+        let Some(Statement {
+            kind: StatementKind::Expression(statement),
+            ..
+        }) = result.ast.last()
+        else {
+            panic!("The code must end with an expression statement.");
+        };
+
+        let arguments = match &statement.expression.kind {
+            ExpressionKind::FunctionCall(call) => &call.arguments,
+            ExpressionKind::StaticMethodCall(call) => &call.arguments,
+            _ => panic!("The code must end with a function or static method call."),
+        };
+
+        let ids = arguments
+            .arguments
+            .iter()
+            .map(|argument| match argument {
+                Argument::Positional(argument) => argument.value.id,
+                Argument::Named(argument) => argument.value.id,
             })
+            .collect();
+
+        (ids, map)
+    }
+
+    #[test]
+    fn it_infers_the_types_of_magic_constants() {
+        assert_eq!(infer("__LINE__"), Type::Integer);
+        assert_eq!(infer("__FILE__"), Type::NonEmptyString);
+        assert_eq!(infer("__DIR__"), Type::NonEmptyString);
+        assert_eq!(infer("__NAMESPACE__"), Type::LiteralString("".into()));
+        assert_eq!(infer("__CLASS__"), Type::LiteralString("".into()));
+        assert_eq!(infer("__FUNCTION__"), Type::LiteralString("".into()));
+        assert_eq!(infer("__METHOD__"), Type::LiteralString("".into()));
+        assert_eq!(infer("__TRAIT__"), Type::LiteralString("".into()));
+        assert_eq!(infer("__PROPERTY__"), Type::LiteralString("".into()));
+    }
+
+    #[test]
+    fn it_infers_magic_constants_inside_a_namespaced_function() {
+        assert_eq!(
+            infer_at("namespace App; function a() { __NAMESPACE__^^; }"),
+            Type::LiteralString("App".into())
+        );
+        assert_eq!(
+            infer_at("namespace App; function a() { __FUNCTION__^^; }"),
+            Type::LiteralString("App\\a".into())
+        );
+        assert_eq!(
+            infer_at("namespace App; function a() { __METHOD__^^; }"),
+            Type::LiteralString("App\\a".into())
         );
     }
 
     #[test]
-    fn it_infers_type_of_nullsafe_method_call() {
+    fn it_infers_magic_constants_inside_a_method() {
         assert_eq!(
-            infer(
-                r#"
-            class Foo {
-                function bar(): int {}
-            }
+            infer_at("namespace App; class A { function b() { __CLASS__^^; } }"),
+            Type::Generic(
+                Box::new(Type::ClassString),
+                vec![GenericTypeArgument {
+                    r#type: named("App\\A", "A"),
+                    variance: None,
+                }]
+            )
+        );
+        assert_eq!(
+            infer_at("namespace App; class A { function b() { __FUNCTION__^^; } }"),
+            Type::LiteralString("b".into())
+        );
+        assert_eq!(
+            infer_at("namespace App; class A { function b() { __METHOD__^^; } }"),
+            Type::LiteralString("App\\A::b".into())
+        );
+        assert_eq!(
+            infer_at("class A { function b() { __TRAIT__^^; } }"),
+            Type::LiteralString("".into())
+        );
+    }
 
-            $foo = new Foo();
-            $foo?->bar()
-            "#
-            ),
-            Type::Union(vec![Type::Integer, Type::Null])
+    #[test]
+    fn it_infers_magic_constants_inside_a_trait() {
+        assert_eq!(
+            infer_at("trait T { function b() { __CLASS__^^; } }"),
+            Type::ClassString
+        );
+        assert_eq!(
+            infer_at("trait T { function b() { __TRAIT__^^; } }"),
+            Type::LiteralString("T".into())
+        );
+        assert_eq!(
+            infer_at("trait T { function b() { __METHOD__^^; } }"),
+            Type::LiteralString("T::b".into())
         );
     }
 
     #[test]
-    fn it_infers_type_of_static_method_call_on_named_class() {
+    fn it_infers_magic_constants_inside_closures() {
         assert_eq!(
-            infer(
-                r#"
-        class Foo {
-            static function bar(): int {}
-        }
+            infer_at("function a() { $b = function () { __FUNCTION__^^; }; }"),
+            Type::NonEmptyString
+        );
+        assert_eq!(
+            infer_at("class A { function b() { return fn () => __METHOD__^^; } }"),
+            Type::NonEmptyString
+        );
+    }
 
-        Foo::bar()
-        "#
+    #[test]
+    fn it_infers_the_property_inside_a_property_hook() {
+        assert_eq!(
+            infer_at("class A { public string $name { get => __PROPERTY__^^; } }"),
+            Type::LiteralString("name".into())
+        );
+        assert_eq!(
+            infer_at(
+                "class A { public string $name { get => 'a'; } function b() { __PROPERTY__^^; } }"
             ),
+            Type::LiteralString("".into())
+        );
+    }
+
+    #[test]
+    fn it_infers_variables_destructured_by_foreach() {
+        let rows = "/** @param list<array{id: int, name: string, tags: list<bool>}> $rows */ function f($rows) {";
+
+        assert_eq!(
+            infer_at(&format!(
+                "{rows} foreach ($rows as ['id' => $id, 'name' => &$name]) {{ ^^$id; }} }}"
+            )),
             Type::Integer
         );
+        assert_eq!(
+            infer_at(&format!(
+                "{rows} foreach ($rows as list('id' => $id, 'name' => &$name)) {{ ^^$name; }} }}"
+            )),
+            Type::String
+        );
+        assert_eq!(
+            infer_at(&format!(
+                "{rows} foreach ($rows as $i => ['tags' => [, &$tag]]) {{ ^^$tag; }} }}"
+            )),
+            Type::Boolean
+        );
+        assert_eq!(
+            infer_at(
+                "/** @param array<string, array{int, string}> $pairs */ function f($pairs) { foreach ($pairs as [&$a, $b]) { ^^$b; } }"
+            ),
+            Type::String
+        );
+        assert_eq!(
+            infer_at(&format!(
+                "{rows} foreach ($rows as ['missing' => $missing]) {{ ^^$missing; }} }}"
+            )),
+            Type::Mixed
+        );
+    }
+
+    fn named(resolved: &str, original: &str) -> Type<ResolvedName> {
+        Type::Named(ResolvedName {
+            resolved: resolved.into(),
+            original: original.into(),
+        })
     }
 
     /// Parse the given code, infer the types and return the type of the expression suffixed with a ^^ sequence.
@@ -405,8 +2275,9 @@ mod tests {
         let code = code.replace("^^", "");
         let result = Parser::parse(Lexer::new(code.as_bytes()));
 
-        let mut index = Index::new();
-        index.index(FileId::new(0), &result.ast);
+        let mut builder = IndexBuilder::new();
+        builder.index(FileId::new(0), &result.ast);
+        let index = builder.build();
 
         let engine = TypeEngine::new(&index);
         let map = engine.infer(&result.ast);
@@ -420,12 +2291,44 @@ mod tests {
 
     /// Parse the given code, infer the types and return the type of the last expression in the code.
     fn infer(code: &str) -> Type<ResolvedName> {
+        let (id, map) = infer_last(code);
+
+        map.resolve(id).clone()
+    }
+
+    fn shape(items: Vec<(ShapeItemKey, Type<ResolvedName>)>) -> Type<ResolvedName> {
+        Type::Shaped {
+            base: Box::new(Type::Array),
+            items: items
+                .into_iter()
+                .map(|(key, value_type)| ShapeItem {
+                    key_name: Some(key),
+                    value_type,
+                    optional: false,
+                })
+                .collect(),
+            sealed: true,
+            unsealed_type: None,
+        }
+    }
+
+    fn string_key(key: &str) -> ShapeItemKey {
+        ShapeItemKey::String(key.into())
+    }
+
+    fn integer_key(key: &str) -> ShapeItemKey {
+        ShapeItemKey::Integer(key.into())
+    }
+
+    /// Parse the given code, infer the types and return the type map along with the id of the last expression.
+    fn infer_last(code: &str) -> (NodeId, TypeMap) {
         // Parse the code.
         let result = Parser::parse(Lexer::new(format!("<?php {};", code).as_bytes()));
 
         // Create an index and index the generated AST.
-        let mut index = Index::new();
-        index.index(FileId::new(0), &result.ast);
+        let mut builder = IndexBuilder::new();
+        builder.index(FileId::new(0), &result.ast);
+        let index = builder.build();
 
         // Create a `TypeEngine` and infer the types.
         let engine = TypeEngine::new(&index);
@@ -440,9 +2343,6 @@ mod tests {
             panic!("The code must end with an expression statement.");
         };
 
-        let expression_id = statement.expression.id();
-
-        // Get the type of the last expression.
-        map.resolve(expression_id).clone()
+        (statement.expression.id(), map)
     }
 }
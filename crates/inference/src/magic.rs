@@ -0,0 +1,102 @@
+use pxp_ast::{MagicConstantKind, ResolvedName};
+use pxp_bytestring::ByteString;
+use pxp_type::{GenericTypeArgument, Type};
+
+/// A declaration that magic constants like `__CLASS__` and `__FUNCTION__` refer to.
+#[derive(Debug, Clone)]
+pub(crate) enum Declaration {
+    /// A class, interface or enum, which doesn't have a name when it's anonymous.
+    Class(Option<ResolvedName>),
+    Trait(ByteString),
+    Function(ByteString),
+    Method(ByteString),
+    Closure,
+    /// A property hook, along with the name of the property without the leading `$`.
+    PropertyHook(ByteString),
+}
+
+/// Determine the value of a magic constant inside of the given declarations, the innermost of which
+/// comes last.
+///
+/// Magic constants that are used outside of the declaration they describe are empty strings.
+pub(crate) fn magic_constant_type(
+    kind: &MagicConstantKind,
+    declarations: &[Declaration],
+    namespace: Option<&ByteString>,
+) -> Type<ResolvedName> {
+    let classlike = declarations
+        .iter()
+        .rev()
+        .find(|declaration| matches!(declaration, Declaration::Class(_) | Declaration::Trait(_)));
+
+    let function = declarations.iter().rev().find(|declaration| {
+        matches!(
+            declaration,
+            Declaration::Function(_)
+                | Declaration::Method(_)
+                | Declaration::Closure
+                | Declaration::PropertyHook(_)
+        )
+    });
+
+    match kind {
+        MagicConstantKind::Line | MagicConstantKind::CompilerHaltOffset => Type::Integer,
+        MagicConstantKind::File | MagicConstantKind::Directory => Type::NonEmptyString,
+        MagicConstantKind::Namespace => literal(namespace),
+        MagicConstantKind::Class => match classlike {
+            Some(Declaration::Class(Some(name))) => Type::Generic(
+                Box::new(Type::ClassString),
+                vec![GenericTypeArgument {
+                    r#type: Type::Named(name.clone()),
+                    variance: None,
+                }],
+            ),
+            // Traits are replaced by the class that uses them.
+            Some(_) => Type::ClassString,
+            None => literal(None),
+        },
+        MagicConstantKind::Trait => match classlike {
+            Some(Declaration::Trait(name)) => literal(Some(name)),
+            _ => literal(None),
+        },
+        MagicConstantKind::Function => match function {
+            Some(Declaration::Function(name) | Declaration::Method(name)) => literal(Some(name)),
+            Some(_) => Type::NonEmptyString,
+            None => literal(None),
+        },
+        MagicConstantKind::Method => match (function, classlike) {
+            (Some(Declaration::Method(method)), Some(Declaration::Trait(name))) => {
+                literal(Some(&qualified_method(name, method)))
+            }
+            (Some(Declaration::Method(method)), Some(Declaration::Class(Some(name)))) => {
+                literal(Some(&qualified_method(&name.resolved, method)))
+            }
+            (Some(Declaration::Function(name)), _) => literal(Some(name)),
+            (Some(_), _) => Type::NonEmptyString,
+            (None, _) => literal(None),
+        },
+        MagicConstantKind::Property => {
+            match declarations
+                .iter()
+                .rev()
+                .find_map(|declaration| match declaration {
+                    Declaration::PropertyHook(property) => Some(property),
+                    _ => None,
+                }) {
+                Some(property) => literal(Some(property)),
+                None => literal(None),
+            }
+        }
+    }
+}
+
+fn literal(value: Option<&ByteString>) -> Type<ResolvedName> {
+    Type::LiteralString(value.cloned().unwrap_or_else(ByteString::empty))
+}
+
+fn qualified_method(class: &ByteString, method: &ByteString) -> ByteString {
+    let mut qualified = class.clone();
+    qualified.extend_with_bytes(b"::");
+    qualified.extend(method);
+    qualified
+}
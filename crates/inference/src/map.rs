@@ -1,11 +1,54 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use pxp_ast::{NodeId, ResolvedName};
-use pxp_type::Type;
+use pxp_ast::{
+    visitor::{walk_expression, Visitor},
+    Expression, NodeId, ResolvedName, Statement,
+};
+use pxp_span::Span;
+use pxp_type::{ConstExpr, ShapeItem, Type};
+
+/// The maximum number of characters of source code shown for each expression in a dump.
+const MAX_SNIPPET_LENGTH: usize = 40;
+
+/// The maximum number of bytes of a literal string's value shown in a dump.
+const MAX_LITERAL_LENGTH: usize = 20;
 
 #[derive(Debug, Default)]
 pub struct TypeMap {
     map: HashMap<NodeId, Type<ResolvedName>>,
+    spans: HashMap<NodeId, Span>,
+    sensitive: HashSet<NodeId>,
+    undefined_keys: HashSet<NodeId>,
+    virtual_members: HashMap<NodeId, VirtualMember>,
+    // The nodes that have been given information since recording started, see `start_recording`.
+    recording: Option<HashSet<NodeId>>,
+}
+
+/// Everything that a `TypeMap` knows about a set of nodes, which can be copied into another map.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct MapEntries {
+    pub(crate) types: Vec<(NodeId, Type<ResolvedName>)>,
+    pub(crate) spans: Vec<(NodeId, Span)>,
+    pub(crate) sensitive: Vec<NodeId>,
+    pub(crate) undefined_keys: Vec<NodeId>,
+    pub(crate) virtual_members: Vec<(NodeId, VirtualMember)>,
+}
+
+/// How a member access was resolved when the member isn't actually declared on the class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum VirtualMember {
+    /// The member is declared by a `@method` or `@property` tag in the class' docblock.
+    Docblock,
+    /// The member is handled by one of the `__call`, `__callStatic` or `__get` magic methods.
+    Magic,
+}
+
+/// A node whose inferred type differs between two `TypeMap` instances.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeDifference {
+    pub span: Span,
+    pub before: Type<ResolvedName>,
+    pub after: Type<ResolvedName>,
 }
 
 /// A small wrapper around a dictionary that maps AST nodes to `Type<ResolvedName>` values based on their `NodeId`.
@@ -19,11 +62,286 @@ impl TypeMap {
 
     /// Insert a type for the given node.
     pub fn insert(&mut self, id: NodeId, ty: Type<ResolvedName>) {
+        self.record(id);
         self.map.insert(id, ty);
     }
 
     /// Get the type for the given node. If no type is present in the map, then `Type::Mixed` is returned.
     pub fn resolve(&self, id: NodeId) -> &Type<ResolvedName> {
-        self.map.get(&id).unwrap_or_else(|| &Type::Mixed)
+        self.map.get(&id).unwrap_or(&Type::Mixed)
+    }
+
+    /// Record the span of the given expression, used to compare maps generated from different parses.
+    pub fn insert_span(&mut self, id: NodeId, span: Span) {
+        self.record(id);
+        self.spans.insert(id, span);
+    }
+
+    /// Mark the given node as sensitive, i.e. its value flows into a `#[SensitiveParameter]`.
+    pub fn mark_sensitive(&mut self, id: NodeId) {
+        self.record(id);
+        self.sensitive.insert(id);
+    }
+
+    /// Check if the given node has been marked as sensitive.
+    pub fn is_sensitive(&self, id: NodeId) -> bool {
+        self.sensitive.contains(&id)
+    }
+
+    /// Mark the given array access as reading a key that isn't in the array's shape.
+    pub fn mark_undefined_key(&mut self, id: NodeId) {
+        self.record(id);
+        self.undefined_keys.insert(id);
+    }
+
+    /// Check if the given array access reads a key that isn't in the array's shape, so that it
+    /// can be reported.
+    pub fn is_undefined_key(&self, id: NodeId) -> bool {
+        self.undefined_keys.contains(&id)
+    }
+
+    /// Mark the given method call or property fetch as resolving to a member that isn't declared on the class.
+    pub fn mark_virtual_member(&mut self, id: NodeId, member: VirtualMember) {
+        self.record(id);
+        self.virtual_members.insert(id, member);
+    }
+
+    /// Get how the given method call or property fetch was resolved, if it doesn't refer to a declared member.
+    pub fn get_virtual_member(&self, id: NodeId) -> Option<VirtualMember> {
+        self.virtual_members.get(&id).copied()
+    }
+
+    /// Start keeping track of the nodes that are given information, so that it can be copied out
+    /// with `finish_recording`.
+    pub(crate) fn start_recording(&mut self) {
+        self.recording = Some(HashSet::new());
+    }
+
+    /// Stop keeping track of nodes, and copy out what's known about the ones that were recorded.
+    pub(crate) fn finish_recording(&mut self) -> MapEntries {
+        let mut ids = self
+            .recording
+            .take()
+            .unwrap_or_default()
+            .into_iter()
+            .collect::<Vec<_>>();
+        ids.sort();
+
+        let mut entries = MapEntries::default();
+
+        for id in ids {
+            if let Some(ty) = self.map.get(&id) {
+                entries.types.push((id, ty.clone()));
+            }
+
+            if let Some(span) = self.spans.get(&id) {
+                entries.spans.push((id, *span));
+            }
+
+            if self.sensitive.contains(&id) {
+                entries.sensitive.push(id);
+            }
+
+            if self.undefined_keys.contains(&id) {
+                entries.undefined_keys.push(id);
+            }
+
+            if let Some(member) = self.virtual_members.get(&id) {
+                entries.virtual_members.push((id, *member));
+            }
+        }
+
+        entries
+    }
+
+    /// Copy in entries that were recorded from another map.
+    pub(crate) fn extend(&mut self, entries: &MapEntries) {
+        for (id, ty) in &entries.types {
+            self.insert(*id, ty.clone());
+        }
+
+        for (id, span) in &entries.spans {
+            self.insert_span(*id, *span);
+        }
+
+        for id in &entries.sensitive {
+            self.mark_sensitive(*id);
+        }
+
+        for id in &entries.undefined_keys {
+            self.mark_undefined_key(*id);
+        }
+
+        for (id, member) in &entries.virtual_members {
+            self.mark_virtual_member(*id, *member);
+        }
+    }
+
+    fn record(&mut self, id: NodeId) {
+        if let Some(recording) = &mut self.recording {
+            recording.insert(id);
+        }
+    }
+
+    /// Render the type for the given node in a human-readable form.
+    ///
+    /// When `redacted` is `true`, the literal values of sensitive nodes are hidden. The type
+    /// stored in the map is never changed.
+    pub fn render(&self, id: NodeId, redacted: bool) -> String {
+        let ty = self.resolve(id);
+
+        if redacted && self.is_sensitive(id) {
+            return ty.redact().to_string();
+        }
+
+        ty.to_string()
+    }
+
+    /// Produce a stable, human-readable listing of every expression in the given AST along with its type.
+    ///
+    /// Each line contains the span of the expression, a truncated snippet of its source code and its
+    /// rendered type, which includes the escaped and truncated value of any literal strings.
+    /// Expressions are ordered by their span, then by their `NodeId`.
+    pub fn dump(&self, ast: &[Statement], source: &[u8]) -> String {
+        let mut collector = ExpressionCollector::default();
+        collector.visit(ast);
+
+        let mut expressions = collector.expressions;
+        expressions.sort_by_key(|(id, span)| (span.start, span.end, *id));
+
+        let mut output = String::new();
+
+        for (id, span) in expressions {
+            output.push_str(&format!(
+                "{}..{} `{}` => {}\n",
+                span.start,
+                span.end,
+                snippet(span, source),
+                with_literal_values(self.resolve(id))
+            ));
+        }
+
+        output
+    }
+
+    /// Compare this map against another and report the expressions whose types have changed.
+    ///
+    /// Expressions are matched by their span rather than their `NodeId`, so the two maps can come
+    /// from separate parses of the same source code. Expressions that only exist in one of the maps are ignored.
+    pub fn diff(&self, other: &TypeMap) -> Vec<TypeDifference> {
+        let before = self.types_by_span();
+        let after = other.types_by_span().into_iter().collect::<HashMap<_, _>>();
+
+        let mut differences = Vec::new();
+
+        for (span, before) in before {
+            let Some(after) = after.get(&span) else {
+                continue;
+            };
+
+            if before != *after {
+                differences.push(TypeDifference {
+                    span,
+                    before: before.clone(),
+                    after: (*after).clone(),
+                });
+            }
+        }
+
+        differences
+    }
+
+    /// Get the types of every recorded expression, ordered by span. When multiple expressions share
+    /// the same span, only the one with the lowest `NodeId` is kept.
+    fn types_by_span(&self) -> Vec<(Span, &Type<ResolvedName>)> {
+        let mut spans = self.spans.iter().collect::<Vec<_>>();
+        spans.sort_by_key(|(id, span)| (span.start, span.end, **id));
+        spans.dedup_by_key(|(_, span)| **span);
+
+        spans
+            .into_iter()
+            .map(|(id, span)| (*span, self.resolve(*id)))
+            .collect()
+    }
+}
+
+fn snippet(span: Span, source: &[u8]) -> String {
+    let text = source
+        .get(span.start..span.end)
+        .map(String::from_utf8_lossy)
+        .unwrap_or_default();
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if text.chars().count() > MAX_SNIPPET_LENGTH {
+        format!(
+            "{}...",
+            text.chars().take(MAX_SNIPPET_LENGTH).collect::<String>()
+        )
+    } else {
+        text
+    }
+}
+
+/// Show the values of literal strings, so that a dump changes when a literal is narrowed or widened.
+fn with_literal_values(ty: &Type<ResolvedName>) -> Type<ResolvedName> {
+    match ty {
+        Type::LiteralString(value) => {
+            let truncated = value.len() > MAX_LITERAL_LENGTH;
+            let escaped = value
+                .iter()
+                .take(MAX_LITERAL_LENGTH)
+                .flat_map(|byte| std::ascii::escape_default(*byte))
+                .map(char::from)
+                .collect::<String>();
+
+            Type::ConstExpr(Box::new(ConstExpr::String(
+                format!(
+                    "literal-string('{}{}')",
+                    escaped,
+                    if truncated { "..." } else { "" }
+                )
+                .into(),
+            )))
+        }
+        Type::Nullable(inner) => Type::Nullable(Box::new(with_literal_values(inner))),
+        Type::Union(types) => Type::Union(types.iter().map(with_literal_values).collect()),
+        Type::Intersection(types) => {
+            Type::Intersection(types.iter().map(with_literal_values).collect())
+        }
+        Type::TypedArray(key, value) => Type::TypedArray(
+            Box::new(with_literal_values(key)),
+            Box::new(with_literal_values(value)),
+        ),
+        Type::Shaped {
+            base,
+            items,
+            sealed,
+            unsealed_type,
+        } => Type::Shaped {
+            base: base.clone(),
+            items: items
+                .iter()
+                .map(|item| ShapeItem {
+                    value_type: with_literal_values(&item.value_type),
+                    ..item.clone()
+                })
+                .collect(),
+            sealed: *sealed,
+            unsealed_type: unsealed_type.clone(),
+        },
+        _ => ty.clone(),
+    }
+}
+
+#[derive(Default)]
+struct ExpressionCollector {
+    expressions: Vec<(NodeId, Span)>,
+}
+
+impl Visitor for ExpressionCollector {
+    fn visit_expression(&mut self, node: &Expression) {
+        self.expressions.push((node.id, node.span));
+
+        walk_expression(self, node);
     }
 }
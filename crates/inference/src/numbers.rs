@@ -0,0 +1,87 @@
+use pxp_ast::AssignmentOperationKind;
+use pxp_type::Type;
+
+use crate::arrays::Shape;
+
+/// What we know about an operand once PHP has converted it to a number.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Number {
+    Integer,
+    Float,
+    Unknown,
+}
+
+impl Number {
+    fn of<N: std::fmt::Debug + std::fmt::Display>(r#type: &Type<N>) -> Number {
+        match r#type {
+            // Booleans and `null` are converted to `0` or `1`.
+            Type::Integer
+            | Type::NonNegativeInteger
+            | Type::Boolean
+            | Type::True
+            | Type::False
+            | Type::Null => Number::Integer,
+            Type::Float => Number::Float,
+            Type::Union(types) => {
+                let mut numbers = types.iter().map(Number::of);
+                let first = numbers.next().unwrap_or(Number::Unknown);
+
+                if numbers.all(|number| number == first) {
+                    first
+                } else {
+                    Number::Unknown
+                }
+            }
+            // Strings could contain either an integer or a float.
+            _ => Number::Unknown,
+        }
+    }
+}
+
+fn is_array<N: std::fmt::Debug + std::fmt::Display>(r#type: &Type<N>) -> bool {
+    matches!(
+        r#type,
+        Type::Array
+            | Type::NonEmptyArray
+            | Type::List
+            | Type::NonEmptyList
+            | Type::TypedArray(..)
+            | Type::Shaped { .. }
+    )
+}
+
+/// Determine the type produced by an arithmetic operator, following PHP's rules for converting the
+/// operands to numbers.
+pub(crate) fn arithmetic<N: std::fmt::Debug + std::fmt::Display + Clone>(
+    kind: AssignmentOperationKind,
+    left: &Type<N>,
+    right: &Type<N>,
+) -> Type<N> {
+    let integer_or_float = || Type::Union(vec![Type::Integer, Type::Float]);
+
+    let (left_number, right_number) = (Number::of(left), Number::of(right));
+    let float = left_number == Number::Float || right_number == Number::Float;
+    let integers = left_number == Number::Integer && right_number == Number::Integer;
+
+    match kind {
+        // Adding two arrays together creates their union.
+        AssignmentOperationKind::Addition(_) if is_array(left) && is_array(right) => {
+            match (Shape::from_type(left), Shape::from_type(right)) {
+                (Some(left), Some(right)) => left.union(right).into_type(),
+                _ => None,
+            }
+            .unwrap_or_else(|| left.clone())
+        }
+        AssignmentOperationKind::Modulo(_) => Type::Integer,
+        AssignmentOperationKind::Addition(_)
+        | AssignmentOperationKind::Subtraction(_)
+        | AssignmentOperationKind::Multiplication(_)
+            if integers =>
+        {
+            Type::Integer
+        }
+        // Dividing integers or raising them to a negative power produces a float.
+        _ if float => Type::Float,
+        _ => integer_or_float(),
+    }
+}
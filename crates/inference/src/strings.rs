@@ -0,0 +1,86 @@
+use pxp_bytestring::ByteString;
+use pxp_type::Type;
+
+/// Literal strings longer than this are tracked as `non-empty-string` instead, so that large literals
+/// and strings built up in a loop don't make the type map grow without bound.
+pub(crate) const MAX_LITERAL_STRING_LENGTH: usize = 512;
+
+/// What we know about the value of an operand once it has been converted to a string.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum StringValue {
+    Literal(ByteString),
+    NonEmpty,
+    Unknown,
+}
+
+impl StringValue {
+    fn is_non_empty(&self) -> bool {
+        match self {
+            StringValue::Literal(value) => !value.is_empty(),
+            StringValue::NonEmpty => true,
+            StringValue::Unknown => false,
+        }
+    }
+}
+
+/// Determine the type of the string produced by joining the given values together.
+pub(crate) fn concat<N: std::fmt::Debug + std::fmt::Display>(
+    values: impl IntoIterator<Item = StringValue>,
+    max_length: usize,
+) -> Type<N> {
+    let mut literal = Some(Vec::new());
+    let mut non_empty = false;
+
+    for value in values {
+        non_empty |= value.is_non_empty();
+
+        literal = match (literal, value) {
+            (Some(mut bytes), StringValue::Literal(value))
+                if bytes.len() + value.len() <= max_length =>
+            {
+                bytes.extend_from_slice(value.as_bytes());
+                Some(bytes)
+            }
+            _ => None,
+        };
+    }
+
+    match literal {
+        Some(bytes) => Type::LiteralString(ByteString::new(bytes)),
+        _ if non_empty => Type::NonEmptyString,
+        _ => Type::String,
+    }
+}
+
+/// Convert a float to the string PHP would produce for it, e.g. `1.50` becomes `1.5`.
+///
+/// PHP formats floats with 14 significant digits and switches to scientific notation for very large or
+/// very small numbers. We only handle the cases where that matches Rust's shortest representation.
+pub(crate) fn float_to_string(value: f64) -> Option<ByteString> {
+    if value == 0.0 {
+        return Some(ByteString::from("0"));
+    }
+
+    if !value.is_finite() || !(1e-4..1e14).contains(&value.abs()) {
+        return None;
+    }
+
+    let formatted = value.to_string();
+    let significant = formatted
+        .bytes()
+        .filter(u8::is_ascii_digit)
+        .skip_while(|digit| *digit == b'0')
+        .collect::<Vec<_>>();
+    let significant = significant.len()
+        - significant
+            .iter()
+            .rev()
+            .take_while(|digit| **digit == b'0')
+            .count();
+
+    if significant > 14 {
+        return None;
+    }
+
+    Some(ByteString::from(formatted))
+}
@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use pxp_ast::ResolvedName;
+use pxp_bytestring::ByteString;
+use pxp_type::{GenericTypeArgument, ShapeItem, ShapeItemKey, ShapeUnsealedType, Type};
+
+/// The keys of `$_SERVER` that are set by most SAPIs, all of which are strings.
+const SERVER_STRING_KEYS: [&[u8]; 18] = [
+    b"DOCUMENT_ROOT",
+    b"GATEWAY_INTERFACE",
+    b"HTTP_ACCEPT",
+    b"HTTP_HOST",
+    b"HTTP_REFERER",
+    b"HTTP_USER_AGENT",
+    b"HTTPS",
+    b"PATH_INFO",
+    b"PHP_SELF",
+    b"QUERY_STRING",
+    b"REMOTE_ADDR",
+    b"REQUEST_METHOD",
+    b"REQUEST_URI",
+    b"SCRIPT_FILENAME",
+    b"SCRIPT_NAME",
+    b"SERVER_NAME",
+    b"SERVER_PROTOCOL",
+    b"SERVER_SOFTWARE",
+];
+
+/// The types of the variables that PHP defines before any code runs.
+///
+/// Superglobals are available in every scope, while globals such as `$argv` and `$argc` are only
+/// available in the global scope. The defaults can be replaced or removed, e.g. for a framework
+/// that fills `$_SERVER` with keys of its own.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Superglobals {
+    superglobals: HashMap<ByteString, Type<ResolvedName>>,
+    globals: HashMap<ByteString, Type<ResolvedName>>,
+}
+
+impl Superglobals {
+    /// Create an empty set, without any of PHP's predefined variables.
+    pub fn empty() -> Self {
+        Self {
+            superglobals: HashMap::new(),
+            globals: HashMap::new(),
+        }
+    }
+
+    /// Set the type of a superglobal, which is available in every scope.
+    pub fn with_superglobal(mut self, name: &[u8], r#type: Type<ResolvedName>) -> Self {
+        self.superglobals.insert(ByteString::from(name), r#type);
+        self
+    }
+
+    /// Set the type of a variable that is only predefined in the global scope.
+    pub fn with_global(mut self, name: &[u8], r#type: Type<ResolvedName>) -> Self {
+        self.globals.insert(ByteString::from(name), r#type);
+        self
+    }
+
+    /// Remove a predefined variable, so that it's treated like any other variable.
+    pub fn without(mut self, name: &[u8]) -> Self {
+        let name = ByteString::from(name);
+
+        self.superglobals.remove(&name);
+        self.globals.remove(&name);
+        self
+    }
+
+    /// Get the type of the predefined variable with the given name, e.g. `$_GET`.
+    pub fn get(&self, name: &ByteString, global_scope: bool) -> Option<&Type<ResolvedName>> {
+        self.superglobals
+            .get(name)
+            .or_else(|| global_scope.then(|| self.globals.get(name)).flatten())
+    }
+
+    pub fn is_defined(&self, name: &ByteString) -> bool {
+        self.superglobals.contains_key(name) || self.globals.contains_key(name)
+    }
+}
+
+impl Default for Superglobals {
+    fn default() -> Self {
+        let input = Type::TypedArray(
+            Box::new(Type::String),
+            Box::new(Type::Union(vec![Type::String, Type::Array])),
+        );
+        let strings = Type::TypedArray(Box::new(Type::String), Box::new(Type::String));
+        let values = Type::TypedArray(Box::new(Type::String), Box::new(Type::Mixed));
+
+        Self::empty()
+            .with_superglobal(b"$GLOBALS", values.clone())
+            .with_superglobal(b"$_SERVER", server())
+            .with_superglobal(b"$_GET", input.clone())
+            .with_superglobal(b"$_POST", input.clone())
+            .with_superglobal(b"$_REQUEST", input.clone())
+            .with_superglobal(b"$_COOKIE", input)
+            .with_superglobal(b"$_FILES", values.clone())
+            .with_superglobal(b"$_SESSION", values)
+            .with_superglobal(b"$_ENV", strings)
+            .with_global(
+                b"$argv",
+                Type::Generic(
+                    Box::new(Type::List),
+                    vec![GenericTypeArgument {
+                        r#type: Type::String,
+                        variance: None,
+                    }],
+                ),
+            )
+            .with_global(b"$argc", Type::Integer)
+    }
+}
+
+/// `$_SERVER` as a shape with the well-known keys, leaving the others unknown.
+fn server() -> Type<ResolvedName> {
+    let item = |key: &[u8], value_type: Type<ResolvedName>| ShapeItem {
+        key_name: Some(ShapeItemKey::String(ByteString::from(key))),
+        value_type,
+        optional: true,
+    };
+
+    let mut items = SERVER_STRING_KEYS
+        .iter()
+        .map(|key| item(key, Type::String))
+        .collect::<Vec<_>>();
+
+    items.push(item(b"REQUEST_TIME", Type::Integer));
+    items.push(item(b"REQUEST_TIME_FLOAT", Type::Float));
+    items.push(item(b"argc", Type::Integer));
+
+    Type::Shaped {
+        base: Box::new(Type::Array),
+        items,
+        sealed: false,
+        unsealed_type: Some(Box::new(ShapeUnsealedType {
+            key_type: Some(Type::String),
+            value_type: Type::Mixed,
+        })),
+    }
+}
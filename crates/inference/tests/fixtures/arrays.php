@@ -0,0 +1,7 @@
+<?php
+
+$empty = [];
+$list = [1, 2, 3];
+$keyed = ['a' => 1, 'b' => 2];
+$mixed = [1, 'a' => 'b', 2.5];
+$nested = [[1, 2], [3, 4]];
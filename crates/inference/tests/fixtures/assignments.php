@@ -0,0 +1,8 @@
+<?php
+
+$a = 1;
+$b = $a;
+$c = 'Hello, world!';
+$d = $c . ' How are you?';
+$e = true;
+$f = null;
@@ -0,0 +1,15 @@
+<?php
+
+function greet(string $name): string {}
+
+class Greeter {
+    public function greet(string $name): string {}
+
+    public static function make(): Greeter {}
+}
+
+greet('Ryan');
+$greeter = Greeter::make();
+$greeter->greet('Ryan');
+$greeter?->greet('Ryan');
+(new Greeter)->greet('Ryan');
@@ -0,0 +1,13 @@
+<?php
+
+class Greeter {
+    public function greet(string $name): string {}
+}
+
+$greet = function (string $name): string {
+    return 'Hello, ' . $name;
+};
+
+$arrow = fn (int $a) => $a + 1;
+$length = strlen(...);
+$method = (new Greeter)->greet(...);
@@ -0,0 +1,34 @@
+<?php
+
+function literal() {
+    $name = 'Ryan';
+    $age = 30;
+
+    compact('name', ['age', 'missing'], 'name');
+
+    extract(['title' => 'Post', 'views' => 100, 'not-valid' => true]);
+    $title;
+    $views;
+
+    extract(compact('name', 'age'));
+    $name;
+    $age;
+}
+
+/** @param array{published: bool, 'author': string} $options */
+function shaped(array $options) {
+    extract($options);
+    $published;
+    $author;
+}
+
+function non_literal(array $data, string $key) {
+    $name = 'Ryan';
+
+    compact($key);
+    compact(...$data);
+
+    extract($data);
+    $name;
+    $anything;
+}
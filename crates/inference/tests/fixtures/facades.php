@@ -0,0 +1,30 @@
+<?php
+
+class Builder {
+    public function get(): Collection {}
+}
+
+class Collection {}
+
+/**
+ * @method static Builder query()
+ * @method static Builder where(string $column, mixed $value = null)
+ * @method static ?Collection find(int ...$ids)
+ * @method static bool transaction(callable $callback, ?string &$error = null)
+ * @property-read Collection $items
+ */
+class Model {
+    public static function __callStatic(string $name, array $arguments): mixed {}
+}
+
+Model::query();
+Model::where('id', 1)->get();
+Model::find(1, 2, 3);
+Model::transaction(fn () => null, $error);
+$error;
+Model::missing();
+
+function items(Model $model) {
+    $model->items;
+    $model->query();
+}
@@ -0,0 +1,8 @@
+<?php
+
+$config = ['debug' => true, 'name' => 'World'];
+
+function greet(string $name): string
+{
+    return "Hello, {$name}!";
+}
@@ -0,0 +1,12 @@
+<?php
+
+require_once __DIR__ . '/config.php';
+
+$debug = $config['debug'];
+$greeting = greet($config['name']);
+
+if ($argc > 1) {
+    include 'verbose.php';
+}
+
+$verbose;
@@ -0,0 +1,28 @@
+<?php
+
+/** @param array<int, string> $matches */
+function match_all(string $pattern, string $subject, &$matches): int {}
+
+class Parser {
+    public function parse(string $input, ?int &$offset = null, string ...$rest): bool {}
+}
+
+match_all('/a/', 'abc', $matches);
+$matches;
+
+(new Parser)->parse('abc', offset: $offset);
+$offset;
+
+unknown_function($out, $defined = 1);
+$out;
+$defined;
+
+$a = 1;
+$b = &$a;
+$b = 'string';
+$a;
+$a = true;
+$b;
+
+$c = &$undefined;
+$undefined;
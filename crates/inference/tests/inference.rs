@@ -0,0 +1,439 @@
+use std::path::{Path, PathBuf};
+
+use pxp_ast::{Expression, ResolvedName, Statement, StatementKind};
+use pxp_bytestring::ByteString;
+use pxp_index::{FileId, Index, IndexBuilder};
+use pxp_inference::{IncludeResolver, InferenceCache, TypeEngine, TypeMap};
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+use pxp_type::Type;
+use snappers::{snap, Snapper};
+
+snap!(snapper, assignments, process("fixtures/assignments.php"));
+snap!(snapper, calls, process("fixtures/calls.php"));
+snap!(snapper, arrays, process("fixtures/arrays.php"));
+snap!(snapper, closures, process("fixtures/closures.php"));
+snap!(snapper, references, process("fixtures/references.php"));
+snap!(snapper, facades, process("fixtures/facades.php"));
+snap!(snapper, compact, process("fixtures/compact.php"));
+snap!(snapper, includes, process_includes("fixtures/includes"));
+
+#[test]
+fn it_does_not_copy_large_string_literals_into_the_type_map() {
+    // A 5MB literal, which used to be copied into the `TypeMap` in full.
+    let content = "a".repeat(5 * 1024 * 1024);
+
+    assert_eq!(
+        infer_last(format!("<?php '{content}';").as_bytes(), None),
+        Type::NonEmptyString
+    );
+    assert_eq!(
+        infer_last(format!("<?php \"{content}\";").as_bytes(), None),
+        Type::NonEmptyString
+    );
+}
+
+#[test]
+fn it_can_configure_the_length_of_literal_strings() {
+    assert_eq!(
+        infer_last(b"<?php 'abcd';", Some(4)),
+        Type::LiteralString(b"abcd".into())
+    );
+    assert_eq!(infer_last(b"<?php 'abcde';", Some(4)), Type::NonEmptyString);
+    assert_eq!(
+        infer_last(b"<?php 'ab' . 'cde';", Some(4)),
+        Type::NonEmptyString
+    );
+}
+
+#[test]
+fn it_escapes_and_truncates_literal_strings_in_dumps() {
+    let source = b"<?php \"it's\\n\\x00\"; 'abcdefghijklmnopqrstuvwxyz';";
+    let result = Parser::parse(Lexer::new(source));
+    let index = IndexBuilder::new().build();
+
+    assert_eq!(
+        TypeEngine::new(&index)
+            .infer(&result.ast)
+            .dump(&result.ast, source),
+        concat!(
+            "7..17 `it's\\n\\x00` => literal-string('it\\'s\\n\\x00')\n",
+            "20..48 `'abcdefghijklmnopqrstuvwxyz'` => literal-string('abcdefghijklmnopqrst...')\n",
+        )
+    );
+}
+
+#[test]
+fn it_keeps_binary_string_content() {
+    let mut source = b"<?php '".to_vec();
+    source.extend_from_slice(b"\x00\xFF\xC3(");
+    source.extend_from_slice(b"' . \"\x00\xFE\\x00\";");
+
+    assert_eq!(
+        infer_last(&source, None),
+        Type::LiteralString(b"\x00\xFF\xC3(\x00\xFE\x00".into())
+    );
+}
+
+#[test]
+fn it_converts_integer_literals_that_overflow_to_floats() {
+    assert_eq!(
+        infer_last(b"<?php 9223372036854775807;", None),
+        Type::Integer
+    );
+    assert_eq!(infer_last(b"<?php 9223372036854775808;", None), Type::Float);
+    assert_eq!(
+        infer_last(b"<?php 0x7FFF_FFFF_FFFF_FFFF;", None),
+        Type::Integer
+    );
+    assert_eq!(infer_last(b"<?php 0x8000000000000000;", None), Type::Float);
+}
+
+#[test]
+fn it_uses_the_value_of_numeric_literals_when_converting_them_to_strings() {
+    assert_eq!(
+        infer_last(b"<?php 'a' . 0x1A . 0b11 . 0o17 . 017 . 1_000;", None),
+        Type::LiteralString(b"a26315151000".into())
+    );
+    assert_eq!(
+        infer_last(b"<?php 'a' . 1_000.5 . 1e3;", None),
+        Type::LiteralString(b"a1000.51000".into())
+    );
+}
+
+#[test]
+fn it_infers_the_value_of_constant_heredocs_and_nowdocs() {
+    assert_eq!(
+        infer_last(
+            b"<?php <<<EOT\n    Hello,\n      \\$world\t\n    EOT;",
+            None
+        ),
+        Type::LiteralString(b"Hello,\n  $world\t".into())
+    );
+    assert_eq!(
+        infer_last(b"<?php <<<'EOT'\n  Hello, \\t$world\n  EOT;", None),
+        Type::LiteralString(b"Hello, \\t$world".into())
+    );
+    assert_eq!(
+        infer_last(b"<?php $a = 'world'; <<<EOT\n  Hello, {$a}\n  EOT;", None),
+        Type::LiteralString(b"Hello, world".into())
+    );
+    assert_eq!(
+        infer_last(b"<?php \"a\\tb\" . <<<EOT\n  c\n  EOT;", None),
+        Type::LiteralString(b"a\tbc".into())
+    );
+}
+
+#[test]
+fn it_infers_files_in_parallel_with_a_shared_index() {
+    let files = [
+        "assignments",
+        "calls",
+        "arrays",
+        "closures",
+        "references",
+        "facades",
+        "compact",
+    ]
+    .map(|name| {
+        let path = format!("{}/tests/fixtures/{name}.php", env!("CARGO_MANIFEST_DIR"));
+        let input = std::fs::read(path).unwrap();
+        let ast = Parser::parse(Lexer::new(&input)).ast;
+
+        (input, ast)
+    });
+
+    let mut builder = IndexBuilder::new();
+
+    for (id, (_, ast)) in files.iter().enumerate() {
+        builder.index(FileId::new(id), ast);
+    }
+
+    let index = builder.build();
+    let infer = |(input, ast): &(Vec<u8>, Vec<Statement>)| {
+        TypeEngine::new(&index).infer(ast).dump(ast, input)
+    };
+
+    let sequential = files.iter().map(infer).collect::<Vec<_>>();
+    let parallel = std::thread::scope(|scope| {
+        files
+            .iter()
+            .map(|file| scope.spawn(move || infer(file)))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    assert_eq!(parallel, sequential);
+}
+
+/// Resolves every include to the same file.
+struct AlwaysResolvesTo(PathBuf);
+
+impl IncludeResolver for AlwaysResolvesTo {
+    fn resolve(&self, _: &Expression, _: &Path) -> Option<PathBuf> {
+        Some(self.0.clone())
+    }
+}
+
+#[test]
+fn it_follows_includes_with_a_custom_resolver() {
+    let source = b"<?php function f() { include $file; $a; } include $file; $a;";
+    let included = Parser::parse(Lexer::new(b"<?php $a = 1; include $next;"));
+    let result = Parser::parse(Lexer::new(source));
+    let index = Index::new();
+
+    let types = TypeEngine::new(&index)
+        .with_include_resolver(AlwaysResolvesTo(PathBuf::from("/a.php")))
+        .with_included_file("/a.php", &included.ast)
+        .infer_file(Path::new("/main.php"), &result.ast);
+
+    let Some(Statement {
+        kind: StatementKind::Expression(statement),
+        ..
+    }) = result.ast.last()
+    else {
+        unreachable!();
+    };
+
+    // The include in the included file resolves to itself, which is skipped.
+    assert_eq!(types.resolve(statement.expression.id), &Type::Integer);
+
+    // Includes inside of functions aren't followed.
+    assert!(types
+        .dump(&result.ast, source)
+        .contains("36..38 `$a` => mixed"));
+}
+
+#[test]
+fn it_only_infers_the_callers_of_a_function_whose_signature_changed() {
+    let mut files = vec![
+        "<?php function price(): int { return 1; } function unrelated() { return 'a'; }",
+        "<?php function total() { $price = price(); return $price; } function label() { return 'total'; }",
+        "<?php class Cart { public function sum() { return price(); } public function name() { return 'cart'; } }",
+    ];
+
+    let (index, asts) = index_sources(&files);
+    let mut cache = InferenceCache::new();
+
+    infer_all(&index, &files, &asts, &mut cache);
+
+    assert_eq!(
+        cache.take_statistics().inferred,
+        names(&[
+            "price",
+            "unrelated",
+            "total",
+            "label",
+            "Cart::sum",
+            "Cart::name"
+        ])
+    );
+    assert_eq!(cache.get_return_type(b"total"), Some(&Type::Integer));
+
+    // Nothing has changed, so everything is reused.
+    infer_all(&index, &files, &asts, &mut cache);
+
+    let statistics = cache.take_statistics();
+    assert!(statistics.inferred.is_empty());
+    assert_eq!(statistics.reused.len(), 6);
+
+    files[0] = "<?php function price(): float { return 1.0; } function unrelated() { return 'a'; }";
+    let ast = Parser::parse(Lexer::new(files[0])).ast;
+    let index = index.update(FileId::new(0), &ast);
+    let asts = vec![ast, asts[1].clone(), asts[2].clone()];
+
+    assert_eq!(
+        cache.invalidate(&index),
+        vec![FileId::new(1), FileId::new(2)]
+    );
+    assert_eq!(cache.len(), 4);
+
+    let maps = infer_all(&index, &files, &asts, &mut cache);
+    let statistics = cache.take_statistics();
+
+    // The changed file is inferred again, along with the callers of `price()`.
+    assert_eq!(
+        statistics.inferred,
+        names(&["price", "unrelated", "total", "Cart::sum"])
+    );
+    assert_eq!(statistics.reused, names(&["label", "Cart::name"]));
+    assert_eq!(cache.get_return_type(b"total"), Some(&Type::Float));
+    assert_eq!(cache.get_return_type(b"Cart::sum"), Some(&Type::Float));
+
+    // Reusing the cache gives the same types as inferring everything from scratch.
+    for ((map, source), ast) in maps.iter().zip(&files).zip(&asts) {
+        assert_eq!(
+            map.dump(ast, source.as_bytes()),
+            TypeEngine::new(&index)
+                .infer(ast)
+                .dump(ast, source.as_bytes())
+        );
+    }
+}
+
+#[test]
+fn it_persists_the_inference_cache_with_the_index() {
+    let files = vec![
+        "<?php function price(): int { return 1; }",
+        "<?php class Cart { public function sum() { return [price(), 'total' => price()]; } }",
+    ];
+
+    let (index, asts) = index_sources(&files);
+    let mut cache = InferenceCache::new();
+    let maps = infer_all(&index, &files, &asts, &mut cache);
+
+    let path = std::env::temp_dir().join(format!("pxp-inference-cache-{}.idx", std::process::id()));
+
+    index.save_with(&path, &cache.encode()).unwrap();
+
+    let loaded = IndexBuilder::load(&path).unwrap();
+    let mut restored = InferenceCache::decode(&loaded.extension).unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(restored.len(), cache.len());
+    assert_eq!(restored.encode(), cache.encode());
+
+    let index = loaded.builder.build();
+    let restored_maps = infer_all(&index, &files, &asts, &mut restored);
+
+    assert!(restored.statistics().inferred.is_empty());
+    assert_eq!(restored.statistics().reused, names(&["price", "Cart::sum"]));
+
+    for ((map, restored_map), (source, ast)) in
+        maps.iter().zip(&restored_maps).zip(files.iter().zip(&asts))
+    {
+        assert_eq!(
+            map.dump(ast, source.as_bytes()),
+            restored_map.dump(ast, source.as_bytes())
+        );
+    }
+
+    assert!(InferenceCache::decode(&[]).unwrap().is_empty());
+    assert!(InferenceCache::decode(&cache.encode()[..10]).is_err());
+}
+
+/// Parse the sources and index them as consecutive files.
+fn index_sources(files: &[&str]) -> (Index, Vec<Vec<Statement>>) {
+    let asts = files
+        .iter()
+        .map(|source| Parser::parse(Lexer::new(source)).ast)
+        .collect::<Vec<_>>();
+
+    let mut builder = IndexBuilder::new();
+
+    for (i, ast) in asts.iter().enumerate() {
+        builder.index(FileId::new(i), ast);
+    }
+
+    (builder.build(), asts)
+}
+
+fn infer_all(
+    index: &Index,
+    files: &[&str],
+    asts: &[Vec<Statement>],
+    cache: &mut InferenceCache,
+) -> Vec<TypeMap> {
+    let engine = TypeEngine::new(index);
+
+    files
+        .iter()
+        .zip(asts)
+        .enumerate()
+        .map(|(i, (source, ast))| {
+            engine.infer_with_cache(FileId::new(i), source.as_bytes(), ast, cache)
+        })
+        .collect()
+}
+
+fn names(names: &[&str]) -> Vec<ByteString> {
+    names.iter().map(|name| ByteString::from(*name)).collect()
+}
+
+fn snapper() -> Snapper {
+    Snapper::new(format!("{}/{}", env!("CARGO_MANIFEST_DIR"), "tests/__snapshots__").into())
+}
+
+fn process(file: &str) -> String {
+    let path = format!("{}/tests/{}", env!("CARGO_MANIFEST_DIR"), file);
+    let input = std::fs::read(path).unwrap();
+
+    let result = Parser::parse(Lexer::new(&input));
+
+    let mut builder = IndexBuilder::new();
+    builder.index(FileId::new(0), &result.ast);
+    let index = builder.build();
+
+    TypeEngine::new(&index)
+        .infer(&result.ast)
+        .dump(&result.ast, &input)
+}
+
+/// Infer the types in `main.php`, following includes into the other files in the directory.
+fn process_includes(directory: &str) -> String {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join(directory);
+    let mut files = std::fs::read_dir(&directory)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect::<Vec<_>>();
+    files.sort();
+
+    let mut builder = IndexBuilder::new();
+    let mut parsed = Vec::new();
+
+    for (id, path) in files.into_iter().enumerate() {
+        let input = std::fs::read(&path).unwrap();
+        let result = Parser::parse(Lexer::new(&input));
+
+        builder.index(FileId::new(id), &result.ast);
+        parsed.push((path, input, result.ast));
+    }
+
+    let index = builder.build();
+
+    let engine = parsed
+        .iter()
+        .fold(TypeEngine::new(&index), |engine, (path, _, ast)| {
+            engine.with_included_file(path.clone(), ast)
+        });
+
+    let (path, input, ast) = parsed
+        .iter()
+        .find(|(path, _, _)| path.ends_with("main.php"))
+        .unwrap();
+
+    engine.infer_file(path, ast).dump(ast, input)
+}
+
+/// Infer the type of the last expression in the source.
+fn infer_last(source: &[u8], max_literal_string_length: Option<usize>) -> Type<ResolvedName> {
+    let result = Parser::parse(Lexer::new(source));
+
+    let mut builder = IndexBuilder::new();
+    builder.index(FileId::new(0), &result.ast);
+    let index = builder.build();
+
+    let mut engine = TypeEngine::new(&index);
+
+    if let Some(length) = max_literal_string_length {
+        engine = engine.with_max_literal_string_length(length);
+    }
+
+    let Some(Statement {
+        kind: StatementKind::Expression(statement),
+        ..
+    }) = result.ast.last()
+    else {
+        panic!("The source must end with an expression statement.");
+    };
+
+    engine
+        .infer(&result.ast)
+        .resolve(statement.expression.id)
+        .clone()
+}
@@ -1,4 +1,4 @@
-use pxp_diagnostics::DiagnosticKind;
+use pxp_diagnostics::{DiagnosticCategory, DiagnosticKind};
 
 #[derive(Debug, Clone)]
 pub enum LexerDiagnostic {
@@ -39,4 +39,8 @@ impl DiagnosticKind for LexerDiagnostic {
             Self::InvalidOctalSequence => "invalid octal escape sequence",
         })
     }
+
+    fn get_category(&self) -> DiagnosticCategory {
+        DiagnosticCategory::Syntax
+    }
 }
@@ -18,6 +18,63 @@ pub mod error;
 pub mod macros;
 pub mod source;
 
+/// Tokenize the given source without parsing it, e.g. for syntax highlighting.
+///
+/// Comments and inline HTML are included, but the `Eof` token is not. Whitespace between tokens
+/// isn't tokenized, so anything that sits between two consecutive token spans is whitespace.
+///
+/// Unlike the tokens given to the parser, the span of a double-quoted string includes its quotes
+/// and the opening quote of an interpolated string gets a `DoubleQuote` token of its own.
+pub fn tokenize<B: ?Sized + AsRef<[u8]>>(source: &B) -> Vec<OwnedToken> {
+    let mut lexer = Lexer::new(source);
+    let mut tokens = Vec::new();
+    let mut offset = 0;
+
+    loop {
+        let token = lexer.current;
+
+        if token.kind == TokenKind::Eof {
+            break;
+        }
+
+        // Tokens are read one at a time, so the lexer has only consumed the current token and
+        // anything before it that isn't part of its span, i.e. whitespace and opening quotes.
+        let end = lexer.source.offset();
+        let skipped = lexer.source.span_range(Span::new(offset, token.span.start));
+        let start = offset
+            + skipped
+                .iter()
+                .take_while(|b| b.is_ascii_whitespace())
+                .count();
+        let mut span = token.span;
+
+        if token.kind == TokenKind::LiteralDoubleQuotedString {
+            span = Span::new(start, end.max(span.end));
+        } else if start < span.start {
+            let quote = Span::new(start, span.start);
+
+            tokens.push(OwnedToken {
+                kind: TokenKind::DoubleQuote,
+                span: quote,
+                symbol: lexer.source.span_range(quote).into(),
+            });
+        }
+
+        if !(token.kind == TokenKind::StringPart && span.is_empty()) {
+            tokens.push(OwnedToken {
+                kind: token.kind,
+                span,
+                symbol: lexer.source.span_range(span).into(),
+            });
+        }
+
+        offset = end.max(span.end);
+        lexer.next();
+    }
+
+    tokens
+}
+
 #[derive(Debug)]
 pub struct Lexer<'a> {
     frames: VecDeque<StackFrame>,
@@ -50,9 +107,18 @@ pub enum StackFrame {
 
 impl<'a> Lexer<'a> {
     pub fn new<B: ?Sized + AsRef<[u8]>>(input: &'a B) -> Self {
+        Self::new_with_frame(input, StackFrame::Initial)
+    }
+
+    /// Create a lexer that starts in PHP code, rather than inline HTML waiting for an opening tag.
+    pub fn new_in_immediate<B: ?Sized + AsRef<[u8]>>(input: &'a B) -> Self {
+        Self::new_with_frame(input, StackFrame::Scripting)
+    }
+
+    fn new_with_frame<B: ?Sized + AsRef<[u8]>>(input: &'a B, frame: StackFrame) -> Self {
         let mut this = Self {
             source: Source::new(input.as_ref()),
-            frames: VecDeque::from([StackFrame::Initial]),
+            frames: VecDeque::from([frame]),
 
             current: Token::new(TokenKind::Eof, Span::default(), ByteStr::new(&[])),
             peek: None,
@@ -61,17 +127,12 @@ impl<'a> Lexer<'a> {
             diagnostics: Vec::new(),
         };
 
+        // The first token has to be read after the initial frame is set up, otherwise it would be
+        // lexed as inline HTML.
         this.next();
         this
     }
 
-    pub fn new_in_immediate<B: ?Sized + AsRef<[u8]>>(input: &'a B) -> Self {
-        let mut this = Self::new(input);
-
-        this.replace(StackFrame::Scripting);
-        this
-    }
-
     fn diagnostic(&mut self, diagnostic: LexerDiagnostic, severity: Severity, span: Span) {
         self.diagnostics
             .push(Diagnostic::new(diagnostic, severity, span));
@@ -95,11 +156,21 @@ impl<'a> Lexer<'a> {
         tokens
     }
 
-    pub fn current(&self) -> Token {
+    /// Get the raw source bytes covered by the given span.
+    pub fn span_range(&self, span: Span) -> &'a ByteStr {
+        self.source.span_range(span)
+    }
+
+    /// Check whether the given span is the first thing on its line, ignoring indentation.
+    pub fn starts_line(&self, span: Span) -> bool {
+        self.source.is_at_start_of_line(span.start)
+    }
+
+    pub fn current(&self) -> Token<'_> {
         self.current
     }
 
-    pub fn peek(&mut self) -> Token {
+    pub fn peek(&mut self) -> Token<'_> {
         if self.peek.is_none() {
             self.peek = Some(self.read_next());
         }
@@ -107,7 +178,7 @@ impl<'a> Lexer<'a> {
         self.peek.unwrap()
     }
 
-    pub fn peek_again(&mut self) -> Token {
+    pub fn peek_again(&mut self) -> Token<'_> {
         if self.peek_again.is_none() {
             self.peek_again = Some(self.read_next());
         }
@@ -131,6 +202,18 @@ impl<'a> Lexer<'a> {
         self.current = self.read_next();
     }
 
+    /// Abandon the rest of the input and move straight to the end of the file.
+    ///
+    /// Any pending state (heredocs, interpolated strings, etc.) is discarded so that
+    /// subsequent calls to `next()` keep producing `Eof` tokens.
+    pub fn skip_to_eof(&mut self) {
+        self.source.goto_end();
+        self.frames = VecDeque::from([StackFrame::Scripting]);
+        self.peek = None;
+        self.peek_again = None;
+        self.current = Token::new_without_symbol(TokenKind::Eof, self.source.span());
+    }
+
     fn read_next(&mut self) -> Token<'a> {
         if self.source.eof() {
             return Token::new_without_symbol(TokenKind::Eof, self.source.span());
@@ -832,6 +915,14 @@ impl<'a> Lexer<'a> {
                 // This is a close tag, we can enter "Initial" mode again.
                 self.source.skip(2);
 
+                // A single newline straight after the close tag belongs to it, rather than being
+                // output as inline HTML.
+                if self.source.at(b"\r\n", 2) {
+                    self.source.skip(2);
+                } else if self.source.at(b"\n", 1) {
+                    self.source.next();
+                }
+
                 self.replace(StackFrame::Initial);
 
                 TokenKind::CloseTag
@@ -895,45 +986,16 @@ impl<'a> Lexer<'a> {
             [b'\\', ident_start!(), ..] => {
                 self.source.next();
 
-                let mut span = self.source.span();
-
                 match self.scripting() {
                     Token {
-                        kind: TokenKind::Identifier | TokenKind::QualifiedIdentifier,
-                        span: ident_span,
+                        kind:
+                            TokenKind::Identifier
+                            | TokenKind::QualifiedIdentifier
+                            | TokenKind::True
+                            | TokenKind::False
+                            | TokenKind::Null,
                         ..
-                    } => {
-                        span.end = ident_span.end;
-
-                        TokenKind::FullyQualifiedIdentifier
-                    }
-                    Token {
-                        kind: TokenKind::True,
-                        span: ident_span,
-                        ..
-                    } => {
-                        span.end = ident_span.end;
-
-                        TokenKind::FullyQualifiedIdentifier
-                    }
-                    Token {
-                        kind: TokenKind::False,
-                        span: ident_span,
-                        ..
-                    } => {
-                        span.end = ident_span.end;
-
-                        TokenKind::FullyQualifiedIdentifier
-                    }
-                    Token {
-                        kind: TokenKind::Null,
-                        span: ident_span,
-                        ..
-                    } => {
-                        span.end = ident_span.end;
-
-                        TokenKind::FullyQualifiedIdentifier
-                    }
+                    } => TokenKind::FullyQualifiedIdentifier,
                     s => unreachable!("{:?}", s),
                 }
             }
@@ -961,9 +1023,18 @@ impl<'a> Lexer<'a> {
                             self.source.skip(2);
                             break;
                         }
-                        &[..] => {
+                        &[_, ..] => {
                             self.source.next();
                         }
+                        [] => {
+                            self.diagnostic(
+                                LexerDiagnostic::UnexpectedEndOfFile,
+                                Severity::Error,
+                                Span::flat(self.source.offset()),
+                            );
+
+                            break;
+                        }
                     }
                 }
 
@@ -1163,7 +1234,9 @@ impl<'a> Lexer<'a> {
             }
             [b'}', ..] => {
                 self.source.next();
-                if self.frame() != &StackFrame::DocBlock {
+                // An unmatched closing brace shouldn't pop the last remaining frame,
+                // otherwise the lexer ends up in an invalid state.
+                if self.frame() != &StackFrame::DocBlock && self.frames.len() > 1 {
                     self.exit();
                 }
                 TokenKind::RightBrace
@@ -1465,7 +1538,10 @@ impl<'a> Lexer<'a> {
 
         // NOTE: This is a bit hacky, but it works for now.
         //        We're doing this so that the closing double quote isn't included in the span.
-        if kind == TokenKind::LiteralDoubleQuotedString {
+        //        Strings that are cut off by the end of the input don't have one.
+        if kind == TokenKind::LiteralDoubleQuotedString
+            && ends_with_closing_quote(self.source.span_range(span))
+        {
             span.end -= 1;
         }
 
@@ -1529,6 +1605,8 @@ impl<'a> Lexer<'a> {
         };
 
         loop {
+            self.skip_double_quoted_content();
+
             match self.source.read(2) {
                 &[b'\\', b'"' | b'\\' | b'$', ..] => {
                     self.source.skip(2);
@@ -1879,6 +1957,8 @@ impl<'a> Lexer<'a> {
 
     fn tokenize_single_quote_string(&mut self) -> TokenKind {
         loop {
+            self.source.skip_until(b"'\\");
+
             match self.source.read(2) {
                 [b'\'', ..] => {
                     self.source.next();
@@ -1909,6 +1989,8 @@ impl<'a> Lexer<'a> {
         self.source.start_token();
 
         let constant = loop {
+            self.skip_double_quoted_content();
+
             match self.source.read(3) {
                 [b'\\', b'"' | b'\\' | b'$', ..] => {
                     self.source.skip(2);
@@ -1944,6 +2026,22 @@ impl<'a> Lexer<'a> {
         }
     }
 
+    /// Skip the content of a double-quoted string up to the next byte that could end it, escape
+    /// something or start an interpolation.
+    fn skip_double_quoted_content(&mut self) {
+        let start = self.source.offset();
+
+        self.source.skip_until(b"\"\\$");
+
+        // An interpolation like `{$name}` starts at the brace, which we've just skipped over.
+        if self.source.offset() > start
+            && self.source.previous() == Some(&b'{')
+            && self.source.read(1) == b"$"
+        {
+            self.source.goto(self.source.offset() - 1);
+        }
+    }
+
     fn peek_identifier(&self) -> Option<&[u8]> {
         let mut size = 0;
 
@@ -2062,7 +2160,7 @@ impl<'a> Lexer<'a> {
     }
 }
 
-const KEYWORDS: [(&[u8], TokenKind); 88] = [
+const KEYWORDS: [(&[u8], TokenKind); 89] = [
     (b"eval", TokenKind::Eval),
     (b"die", TokenKind::Die),
     (b"empty", TokenKind::Empty),
@@ -2140,6 +2238,7 @@ const KEYWORDS: [(&[u8], TokenKind); 88] = [
     (b"__function__", TokenKind::FunctionConstant),
     (b"__class__", TokenKind::ClassConstant),
     (b"__method__", TokenKind::MethodConstant),
+    (b"__property__", TokenKind::PropertyConstant),
     (b"__trait__", TokenKind::TraitConstant),
     (b"__namespace__", TokenKind::NamespaceConstant),
     (
@@ -2167,6 +2266,15 @@ fn identifier_to_keyword(ident: &[u8]) -> Option<TokenKind> {
     })
 }
 
+/// Whether the content of a double-quoted string ends with a quote that isn't escaped.
+fn ends_with_closing_quote(content: &[u8]) -> bool {
+    let Some((b'"', rest)) = content.split_last() else {
+        return false;
+    };
+
+    rest.iter().rev().take_while(|b| **b == b'\\').count() % 2 == 0
+}
+
 #[derive(Debug, Eq, PartialEq)]
 enum NumberKind {
     Int,
@@ -2177,9 +2285,14 @@ enum NumberKind {
 
 #[cfg(test)]
 mod tests {
-    use super::Lexer;
+    use std::path::Path;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
 
-    use pxp_token::{OpenTagKind, TokenKind};
+    use super::{diagnostics::LexerDiagnostic, tokenize, Lexer};
+
+    use pxp_token::{OpenTagKind, TokenCategory, TokenKind};
 
     #[test]
     fn it_can_tokenize_keywords() {
@@ -2273,6 +2386,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_includes_a_single_newline_in_close_tags() {
+        for (code, close, html) in [
+            ("<?php ?>\n<p></p>", "?>\n", "<p></p>"),
+            ("<?php ?>\r\n<p></p>", "?>\r\n", "<p></p>"),
+            ("<?php ?>\n\n<p></p>", "?>\n", "\n<p></p>"),
+            ("<?php ?> \n<p></p>", "?>", " \n<p></p>"),
+        ] {
+            let tokens = Lexer::new(code).collect();
+
+            assert_eq!(tokens[1].kind, TokenKind::CloseTag, "{code:?}");
+            assert_eq!(&tokens[1].symbol[..], close.as_bytes(), "{code:?}");
+            assert_eq!(tokens[2].kind, TokenKind::InlineHtml, "{code:?}");
+            assert_eq!(&tokens[2].symbol[..], html.as_bytes(), "{code:?}");
+        }
+    }
+
     #[test]
     fn it_can_tokenize_casts() {
         use TokenKind::*;
@@ -2426,6 +2556,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_finds_the_end_of_long_strings() {
+        let content = "a".repeat(100_000);
+        let source = format!("<?php '{content}\\'{content}' \"{content}\\\"{content}\";");
+        let tokens = tokenize(&source);
+
+        assert_eq!(tokens[1].kind, TokenKind::LiteralSingleQuotedString);
+        assert_eq!(tokens[1].symbol.len(), 200_004);
+        assert_eq!(tokens[2].kind, TokenKind::LiteralDoubleQuotedString);
+        assert_eq!(tokens[2].symbol.len(), 200_004);
+        assert_eq!(tokens[3].kind, TokenKind::SemiColon);
+    }
+
+    #[test]
+    fn it_finds_interpolations_after_long_string_content() {
+        let source = format!(
+            "<?php \"{0}{{$a}}{0}$b {0}${{c}}{0}\\$d $ {{}}\";",
+            "x".repeat(1000)
+        );
+
+        let tokens = tokenize(&source)
+            .into_iter()
+            .map(|t| t.kind)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            tokens,
+            vec![
+                TokenKind::OpenTag(OpenTagKind::Full),
+                TokenKind::DoubleQuote,
+                TokenKind::StringPart,
+                TokenKind::LeftBrace,
+                TokenKind::Variable,
+                TokenKind::RightBrace,
+                TokenKind::StringPart,
+                TokenKind::Variable,
+                TokenKind::StringPart,
+                TokenKind::DollarLeftBrace,
+                TokenKind::Identifier,
+                TokenKind::RightBrace,
+                TokenKind::StringPart,
+                TokenKind::DoubleQuote,
+                TokenKind::SemiColon,
+            ]
+        );
+    }
+
     #[test]
     fn it_can_tokenize_heredocs() {
         let tokens = Lexer::new("<?php <<<EOD\n    foo\n    EOD")
@@ -2548,4 +2725,197 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn it_knows_when_a_token_starts_a_line() {
+        let mut lexer = Lexer::new("<?php $a = 1\n\t  $b = 2; $c");
+
+        let spans = lexer
+            .collect()
+            .iter()
+            .filter(|t| t.kind == TokenKind::Variable)
+            .map(|t| t.span)
+            .collect::<Vec<_>>();
+
+        let lexer = Lexer::new("<?php $a = 1\n\t  $b = 2; $c");
+
+        assert_eq!(
+            spans
+                .iter()
+                .map(|span| lexer.starts_line(*span))
+                .collect::<Vec<_>>(),
+            vec![false, true, false]
+        );
+    }
+
+    #[test]
+    fn it_tokenizes_interpolated_strings_and_heredocs_with_full_spans() {
+        let source = b"<?php \"a\" . \"{$a->b} c\" . <<<EOT\n  hi $x\n  EOT;";
+
+        let tokens = tokenize(source)
+            .into_iter()
+            .map(|t| (t.kind, t.symbol.to_string()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            tokens
+                .iter()
+                .map(|(kind, symbol)| (*kind, symbol.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (TokenKind::OpenTag(OpenTagKind::Full), "<?php"),
+                (TokenKind::LiteralDoubleQuotedString, "\"a\""),
+                (TokenKind::Dot, "."),
+                (TokenKind::DoubleQuote, "\""),
+                (TokenKind::LeftBrace, "{"),
+                (TokenKind::Variable, "$a"),
+                (TokenKind::Arrow, "->"),
+                (TokenKind::Identifier, "b"),
+                (TokenKind::RightBrace, "}"),
+                (TokenKind::StringPart, " c"),
+                (TokenKind::DoubleQuote, "\""),
+                (TokenKind::Dot, "."),
+                (TokenKind::StartHeredoc, "<<<EOT\n"),
+                (TokenKind::StringPart, "  hi "),
+                (TokenKind::Variable, "$x"),
+                (TokenKind::StringPart, "\n  "),
+                (TokenKind::EndHeredoc, "EOT"),
+                (TokenKind::SemiColon, ";"),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_categorises_tokens() {
+        let categories = tokenize("<p><?php /* c */ echo (int) $a . __LINE__ . 'b' + 1;")
+            .iter()
+            .map(|t| t.kind.category())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            categories,
+            vec![
+                TokenCategory::Html,
+                TokenCategory::Punctuation,
+                TokenCategory::Comment,
+                TokenCategory::Keyword,
+                TokenCategory::Cast,
+                TokenCategory::Variable,
+                TokenCategory::Operator,
+                TokenCategory::MagicConstant,
+                TokenCategory::Operator,
+                TokenCategory::String,
+                TokenCategory::Operator,
+                TokenCategory::Number,
+                TokenCategory::Punctuation,
+            ]
+        );
+    }
+
+    #[test]
+    fn it_reconstructs_every_fixture_from_tokens_and_whitespace() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("../parser/tests/fixtures");
+        let mut pending = vec![fixtures];
+        let mut checked = 0;
+
+        while let Some(path) = pending.pop() {
+            if path.is_dir() {
+                pending.extend(path.read_dir().unwrap().map(|entry| entry.unwrap().path()));
+                continue;
+            }
+
+            if path.extension() != Some("php".as_ref()) {
+                continue;
+            }
+
+            let source = std::fs::read(&path).unwrap();
+            let mut reconstructed = Vec::new();
+
+            for token in tokenize(&source) {
+                let gap = &source[reconstructed.len()..token.span.start];
+
+                assert!(
+                    gap.iter().all(|b| b.is_ascii_whitespace()),
+                    "{}: unexpected {:?} before {:?}",
+                    path.display(),
+                    String::from_utf8_lossy(gap),
+                    token.kind
+                );
+
+                reconstructed.extend_from_slice(gap);
+                reconstructed.extend_from_slice(&token.symbol);
+            }
+
+            let rest = &source[reconstructed.len()..];
+
+            assert!(
+                rest.iter().all(|b| b.is_ascii_whitespace()),
+                "{}",
+                path.display()
+            );
+
+            reconstructed.extend_from_slice(rest);
+
+            assert_eq!(reconstructed, source, "{}", path.display());
+            checked += 1;
+        }
+
+        assert!(checked > 0);
+    }
+
+    /// Tokenize the given source on another thread, failing if it doesn't finish in time,
+    /// and rebuild the source from the token spans and the whitespace between them.
+    fn rebuild_within_time_limit(source: &'static str) -> Vec<u8> {
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || sender.send(tokenize(source)).unwrap());
+
+        let tokens = receiver
+            .recv_timeout(Duration::from_secs(5))
+            .unwrap_or_else(|_| panic!("tokenizing {source:?} did not finish"));
+        let source = source.as_bytes();
+        let mut rebuilt = Vec::new();
+
+        for token in tokens {
+            rebuilt.extend_from_slice(&source[rebuilt.len()..token.span.start]);
+            rebuilt.extend_from_slice(&token.symbol);
+        }
+
+        rebuilt.extend_from_slice(&source[rebuilt.len()..]);
+        rebuilt
+    }
+
+    #[test]
+    fn it_ends_unterminated_block_comments_at_the_end_of_the_input() {
+        for source in ["<?php /* x", "<?php $y = 2; /*", "<?php /** x", "<?php /**"] {
+            assert_eq!(rebuild_within_time_limit(source), source.as_bytes());
+        }
+
+        let mut lexer = Lexer::new("<?php /* x");
+        lexer.next();
+
+        assert_eq!(lexer.current.kind, TokenKind::MultiLineComment);
+        assert_eq!(&lexer.current.symbol[..], b"/* x");
+        assert!(matches!(
+            lexer.diagnostics[..],
+            [ref diagnostic] if matches!(diagnostic.kind, LexerDiagnostic::UnexpectedEndOfFile)
+        ));
+    }
+
+    #[test]
+    fn it_ends_unterminated_strings_and_heredocs_at_the_end_of_the_input() {
+        for source in [
+            "<?php \"",
+            "<?php '",
+            "<?php \"abc",
+            "<?php \"abc\\\"",
+            "<?php \"a $b c",
+            "<?php 'abc",
+            "<?php `ls",
+            "<?php <<<EOT\nhi $x\n",
+            "<?php <<<'EOT'\nhi\n",
+        ] {
+            assert_eq!(rebuild_within_time_limit(source), source.as_bytes());
+        }
+    }
 }
@@ -25,6 +25,11 @@ impl<'a> Source<'a> {
         self.offset = offset;
     }
 
+    pub fn goto_end(&mut self) {
+        self.offset = self.length;
+        self.current_token_start_offset = self.length;
+    }
+
     pub fn goto_start_of_token(&mut self) {
         self.offset = self.current_token_start_offset;
     }
@@ -50,9 +55,32 @@ impl<'a> Source<'a> {
     }
 
     pub fn skip(&mut self, count: usize) {
-        for _ in 0..count {
-            self.next();
-        }
+        self.offset += count;
+    }
+
+    /// Skip ahead to the next occurrence of any of the given bytes, or to the end of the input
+    /// if there isn't one.
+    ///
+    /// Long strings and comments are mostly made up of bytes that don't mean anything to the lexer,
+    /// so jumping over them in one go is much faster than matching each byte individually.
+    pub fn skip_until(&mut self, needles: &[u8]) {
+        let remaining = self.read_remaining();
+
+        let found = match needles {
+            [a] => memchr::memchr(*a, remaining),
+            [a, b] => memchr::memchr2(*a, *b, remaining),
+            [a, b, c] => memchr::memchr3(*a, *b, *c, remaining),
+            _ => remaining.iter().position(|byte| needles.contains(byte)),
+        };
+
+        self.offset += found.unwrap_or(remaining.len());
+    }
+
+    /// The byte before the current position.
+    pub fn previous(&self) -> Option<&'a u8> {
+        self.offset
+            .checked_sub(1)
+            .and_then(|offset| self.input.get(offset))
     }
 
     pub fn read_and_skip(&mut self, count: usize) -> &'a [u8] {
@@ -150,4 +178,12 @@ impl<'a> Source<'a> {
     pub fn span_range(&self, span: Span) -> &'a ByteStr {
         ByteStr::new(&self.input[span.start..span.end])
     }
+
+    pub fn is_at_start_of_line(&self, offset: ByteOffset) -> bool {
+        self.input[..offset.min(self.length)]
+            .iter()
+            .rev()
+            .find(|byte| !matches!(byte, b' ' | b'\t'))
+            .map_or(true, |byte| matches!(byte, b'\n' | b'\r'))
+    }
 }
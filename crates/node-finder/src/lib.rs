@@ -43,7 +43,7 @@ impl<'a> NodeVisitor<'a> for NodeFinder<'a> {
         // If the current node contains the offset we're interested in,
         // we should keep track of it and continue traversing the AST.
         if span.contains_offset(self.offset) {
-            self.found = Some((node.clone(), ancestors.clone()));
+            self.found = Some((node, ancestors.clone()));
         }
 
         NodeVisitorEscapeHatch::Continue
@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use pxp_ast::UseKind;
+use pxp_bytestring::{ByteStr, ByteString};
+use pxp_span::Span;
+
+/// The namespaces of a parsed file and the imports inside of each one.
+///
+/// This is the same information the parser uses to resolve names, so tools that work with the
+/// AST after parsing can resolve names that the parser didn't, e.g. names inside of strings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileContext {
+    namespaces: Vec<NamespaceContext>,
+    // The namespace that the parser is currently inside of.
+    current: usize,
+}
+
+impl FileContext {
+    pub(crate) fn new() -> Self {
+        Self {
+            namespaces: vec![NamespaceContext::new(
+                None,
+                NamespaceKind::Implicit,
+                Span::default(),
+            )],
+            current: 0,
+        }
+    }
+
+    /// The namespaces in the file, in source order.
+    ///
+    /// The first namespace is always the implicit global namespace that covers the entire file,
+    /// which holds the imports of files that don't declare a namespace.
+    pub fn namespaces(&self) -> &[NamespaceContext] {
+        &self.namespaces
+    }
+
+    /// Find the namespace that contains the given span.
+    pub fn namespace_at(&self, at: Span) -> &NamespaceContext {
+        self.namespaces
+            .iter()
+            .rev()
+            .find(|namespace| {
+                namespace.span.contains_offset(at.start) && namespace.span.contains_offset(at.end)
+            })
+            .unwrap_or(&self.namespaces[0])
+    }
+
+    /// Resolve a name the same way the parser would have if it appeared at the given position.
+    ///
+    /// Unqualified function and constant names inside of a namespace can't be resolved without
+    /// knowing which functions and constants exist, so the name is returned as it was written.
+    pub fn resolve(&self, name: &ByteStr, kind: UseKind, at: Span) -> ByteString {
+        let namespace = self.namespace_at(at);
+
+        if name.starts_with(b"\\") {
+            return ByteStr::new(&name[1..]).to_bytestring();
+        }
+
+        if name.len() > 10 && name[..10].eq_ignore_ascii_case(b"namespace\\") {
+            return namespace.join(ByteStr::new(&name[10..]));
+        }
+
+        namespace
+            .resolve(name, kind)
+            .unwrap_or_else(|| name.to_bytestring())
+    }
+
+    pub(crate) fn current(&self) -> &NamespaceContext {
+        &self.namespaces[self.current]
+    }
+
+    pub(crate) fn current_mut(&mut self) -> &mut NamespaceContext {
+        &mut self.namespaces[self.current]
+    }
+
+    /// Start a new namespace, returning its index so that the span can be completed later.
+    pub(crate) fn enter(
+        &mut self,
+        name: Option<ByteString>,
+        kind: NamespaceKind,
+        start: Span,
+    ) -> usize {
+        self.namespaces
+            .push(NamespaceContext::new(name, kind, start));
+        self.current = self.namespaces.len() - 1;
+        self.current
+    }
+
+    /// Finish the namespace once its last statement has been parsed.
+    pub(crate) fn exit(&mut self, index: usize, end: Span) {
+        let namespace = &mut self.namespaces[index];
+        namespace.span = namespace.span.join(end);
+
+        self.current = 0;
+    }
+
+    /// Set the span of the implicit global namespace once the whole file has been parsed.
+    pub(crate) fn finish(&mut self, end: Span) {
+        self.namespaces[0].span = Span::new(0, end.end);
+    }
+}
+
+impl Default for FileContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NamespaceKind {
+    /// The global namespace, used by code that isn't inside of a namespace declaration.
+    Implicit,
+    /// A namespace declared with `namespace Foo;`.
+    Unbraced,
+    /// A namespace declared with `namespace Foo { ... }` or `namespace { ... }`.
+    Braced,
+}
+
+/// A single namespace declaration and the imports that it contains.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamespaceContext {
+    /// The name of the namespace, or `None` for the global namespace.
+    pub name: Option<ByteString>,
+    pub kind: NamespaceKind,
+    pub span: Span,
+    imports: HashMap<UseKind, HashMap<ByteString, Import>>,
+}
+
+/// A name imported by a `use` statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Import {
+    /// The fully qualified name that was imported.
+    pub name: ByteString,
+    /// The span of the `use` item, including the alias.
+    pub span: Span,
+}
+
+impl NamespaceContext {
+    fn new(name: Option<ByteString>, kind: NamespaceKind, span: Span) -> Self {
+        let mut imports = HashMap::new();
+        imports.insert(UseKind::Normal, HashMap::new());
+        imports.insert(UseKind::Function, HashMap::new());
+        imports.insert(UseKind::Const, HashMap::new());
+
+        Self {
+            name,
+            kind,
+            span,
+            imports,
+        }
+    }
+
+    /// The imports of the given kind, keyed by their alias.
+    pub fn imports(&self, kind: UseKind) -> &HashMap<ByteString, Import> {
+        &self.imports[&kind]
+    }
+
+    pub(crate) fn import(&mut self, kind: UseKind, alias: ByteString, import: Import) {
+        self.imports.get_mut(&kind).unwrap().insert(alias, import);
+    }
+
+    /// Prefix the name with the name of the namespace.
+    pub fn join(&self, name: &ByteStr) -> ByteString {
+        match &self.name {
+            Some(namespace) => namespace.as_bytestr().coagulate(&[name], b'\\'),
+            None => name.to_bytestring(),
+        }
+    }
+
+    /// Resolve an unqualified or qualified name, returning `None` when it depends on which
+    /// functions or constants exist at runtime.
+    pub(crate) fn resolve(&self, name: &ByteStr, kind: UseKind) -> Option<ByteString> {
+        let qualified = name.contains(b"\\");
+        let part = name.before_first(b'\\');
+
+        // An import that matches the first part of the name. The rest of a qualified name is
+        // appended to the full, un-aliased import.
+        if let Some(import) = self.imports(kind).get(&part.to_bytestring()) {
+            return Some(if qualified {
+                import
+                    .name
+                    .as_bytestr()
+                    .coagulate(&[name.after_first(b'\\')], b'\\')
+            } else {
+                import.name.clone()
+            });
+        }
+
+        // Without an import, class names and qualified names follow PHP's rules and are
+        // prefixed with the current namespace.
+        if kind == UseKind::Normal || qualified {
+            return Some(self.join(name));
+        }
+
+        // Unqualified functions and constants in the global namespace can only refer to something
+        // else in the global namespace.
+        if self.name.is_none() {
+            return Some(name.to_bytestring());
+        }
+
+        None
+    }
+}
@@ -0,0 +1,314 @@
+use std::fmt::Display;
+
+use pxp_ast::{
+    visitor::{
+        walk_backed_enum_statement, walk_classish_constant, walk_die_expression,
+        walk_exit_expression, walk_function_closure_creation_expression, walk_hooked_property,
+        walk_match_expression, walk_method_closure_creation_expression, walk_named_argument,
+        walk_nullsafe_method_call_expression, walk_nullsafe_property_fetch_expression,
+        walk_positional_argument, walk_static_method_closure_creation_expression,
+        walk_static_variable_method_closure_creation_expression, walk_unit_enum_statement, Visitor,
+    },
+    *,
+};
+use pxp_span::Span;
+use pxp_type::Type;
+
+use crate::PhpVersion;
+
+/// Syntax that was introduced in a particular version of PHP.
+///
+/// This is the same list that's used to report syntax that's too new for the version of PHP that
+/// code is being parsed for, so the two can't disagree about when a feature was introduced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Feature {
+    MatchExpression,
+    NamedArguments,
+    NullsafeOperator,
+    Enums,
+    ReadonlyProperties,
+    FirstClassCallables,
+    UnpackingStringKeys,
+    NeverType,
+    DnfTypes,
+    ReadonlyClasses,
+    StandaloneNullFalseTrue,
+    TypedClassConstants,
+    PropertyHooks,
+    AsymmetricVisibility,
+    ExitNamedArgument,
+    ExitClosureCreation,
+    CloneClosureCreation,
+}
+
+impl Feature {
+    /// The version of PHP that the feature was introduced in.
+    pub fn version(&self) -> PhpVersion {
+        match self {
+            Feature::MatchExpression | Feature::NamedArguments | Feature::NullsafeOperator => {
+                PhpVersion::PHP_80
+            }
+            Feature::Enums
+            | Feature::ReadonlyProperties
+            | Feature::FirstClassCallables
+            | Feature::UnpackingStringKeys
+            | Feature::NeverType => PhpVersion::PHP_81,
+            Feature::DnfTypes | Feature::ReadonlyClasses | Feature::StandaloneNullFalseTrue => {
+                PhpVersion::PHP_82
+            }
+            Feature::TypedClassConstants => PhpVersion::PHP_83,
+            Feature::PropertyHooks
+            | Feature::AsymmetricVisibility
+            | Feature::ExitNamedArgument
+            | Feature::ExitClosureCreation => PhpVersion::PHP_84,
+            Feature::CloneClosureCreation => PhpVersion::PHP_85,
+        }
+    }
+}
+
+impl Display for Feature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Feature::MatchExpression => "match expressions",
+                Feature::NamedArguments => "named arguments",
+                Feature::NullsafeOperator => "the nullsafe operator",
+                Feature::Enums => "enums",
+                Feature::ReadonlyProperties => "readonly properties",
+                Feature::FirstClassCallables => "first-class callable syntax",
+                Feature::UnpackingStringKeys => "unpacking an array with string keys",
+                Feature::NeverType => "the never type",
+                Feature::DnfTypes => "disjunctive normal form types",
+                Feature::ReadonlyClasses => "readonly classes",
+                Feature::StandaloneNullFalseTrue => "null, false and true as standalone types",
+                Feature::TypedClassConstants => "typed class constants",
+                Feature::PropertyHooks => "property hooks",
+                Feature::AsymmetricVisibility => "asymmetric visibility",
+                Feature::ExitNamedArgument => "passing a named argument to exit",
+                Feature::ExitClosureCreation => "creating a closure from exit or die",
+                Feature::CloneClosureCreation => "creating a closure from clone",
+            }
+        )
+    }
+}
+
+/// A use of a [`Feature`] in some code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeatureUse {
+    pub feature: Feature,
+    pub span: Span,
+}
+
+/// Work out the oldest version of PHP that can parse the given AST, along with every use of a
+/// feature that needs a newer version than PHP 7.0, in the order that they appear.
+///
+/// Only features from PHP 8.0 onwards are detected, so PHP 7.0 is reported for code that doesn't
+/// use any of them. Types in docblocks aren't counted, since PHP never reads them.
+pub fn minimum_php_version(ast: &[Statement]) -> (PhpVersion, Vec<FeatureUse>) {
+    let mut visitor = FeatureVisitor { uses: Vec::new() };
+    visitor.visit(ast);
+
+    let version = visitor
+        .uses
+        .iter()
+        .map(|feature| feature.feature.version())
+        .max()
+        .unwrap_or(PhpVersion::PHP_70);
+
+    (version, visitor.uses)
+}
+
+/// The first literal string key in an array that's unpacked into a list of arguments.
+pub(crate) fn unpacked_string_key(array: &ArrayExpression) -> Option<Span> {
+    array
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            ArrayItem::KeyValue(item) => Some(&item.key),
+            ArrayItem::ReferencedKeyValue(item) => Some(&item.key),
+            _ => None,
+        })
+        .find(|key| {
+            matches!(&key.kind, ExpressionKind::Literal(literal) if literal.kind == LiteralKind::String)
+        })
+        .map(|key| key.span)
+}
+
+struct FeatureVisitor {
+    uses: Vec<FeatureUse>,
+}
+
+impl FeatureVisitor {
+    fn push(&mut self, feature: Feature, span: Span) {
+        self.uses.push(FeatureUse { feature, span });
+    }
+
+    fn exit_argument(&mut self, argument: Option<&SingleArgument>) {
+        if let Some(SingleArgument {
+            argument: Some(Argument::Named(argument)),
+            ..
+        }) = argument
+        {
+            self.push(Feature::ExitNamedArgument, argument.span);
+        }
+    }
+}
+
+impl Visitor for FeatureVisitor {
+    fn visit_match_expression(&mut self, node: &MatchExpression) {
+        self.push(Feature::MatchExpression, node.keyword);
+        walk_match_expression(self, node);
+    }
+
+    fn visit_named_argument(&mut self, node: &NamedArgument) {
+        self.push(Feature::NamedArguments, node.span);
+        walk_named_argument(self, node);
+    }
+
+    fn visit_positional_argument(&mut self, node: &PositionalArgument) {
+        if let (Some(_), ExpressionKind::Array(array)) = (node.ellipsis, &node.value.kind) {
+            if let Some(span) = unpacked_string_key(array) {
+                self.push(Feature::UnpackingStringKeys, span);
+            }
+        }
+
+        walk_positional_argument(self, node);
+    }
+
+    fn visit_nullsafe_property_fetch_expression(&mut self, node: &NullsafePropertyFetchExpression) {
+        self.push(Feature::NullsafeOperator, node.question_arrow);
+        walk_nullsafe_property_fetch_expression(self, node);
+    }
+
+    fn visit_nullsafe_method_call_expression(&mut self, node: &NullsafeMethodCallExpression) {
+        self.push(Feature::NullsafeOperator, node.question_arrow);
+        walk_nullsafe_method_call_expression(self, node);
+    }
+
+    fn visit_unit_enum_statement(&mut self, node: &UnitEnumStatement) {
+        self.push(Feature::Enums, node.r#enum);
+        walk_unit_enum_statement(self, node);
+    }
+
+    fn visit_backed_enum_statement(&mut self, node: &BackedEnumStatement) {
+        self.push(Feature::Enums, node.r#enum);
+        walk_backed_enum_statement(self, node);
+    }
+
+    fn visit_property_modifier(&mut self, node: &PropertyModifier) {
+        match node {
+            PropertyModifier::Readonly(span) => self.push(Feature::ReadonlyProperties, *span),
+            PropertyModifier::PublicSet(span)
+            | PropertyModifier::ProtectedSet(span)
+            | PropertyModifier::PrivateSet(span) => self.push(Feature::AsymmetricVisibility, *span),
+            _ => {}
+        }
+    }
+
+    fn visit_promoted_property_modifier(&mut self, node: &PromotedPropertyModifier) {
+        match node {
+            PromotedPropertyModifier::Readonly(span) => {
+                self.push(Feature::ReadonlyProperties, *span)
+            }
+            PromotedPropertyModifier::PublicSet(span)
+            | PromotedPropertyModifier::ProtectedSet(span)
+            | PromotedPropertyModifier::PrivateSet(span) => {
+                self.push(Feature::AsymmetricVisibility, *span)
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_class_modifier(&mut self, node: &ClassModifier) {
+        if let ClassModifier::Readonly(span) = node {
+            self.push(Feature::ReadonlyClasses, *span);
+        }
+    }
+
+    fn visit_function_closure_creation_expression(
+        &mut self,
+        node: &FunctionClosureCreationExpression,
+    ) {
+        let feature = match &node.target.kind {
+            ExpressionKind::Name(name)
+                if name.symbol().eq_ignore_ascii_case(b"exit")
+                    || name.symbol().eq_ignore_ascii_case(b"die") =>
+            {
+                Feature::ExitClosureCreation
+            }
+            ExpressionKind::Name(name) if name.symbol().eq_ignore_ascii_case(b"clone") => {
+                Feature::CloneClosureCreation
+            }
+            _ => Feature::FirstClassCallables,
+        };
+
+        self.push(feature, node.span);
+
+        walk_function_closure_creation_expression(self, node);
+    }
+
+    fn visit_method_closure_creation_expression(&mut self, node: &MethodClosureCreationExpression) {
+        self.push(Feature::FirstClassCallables, node.span);
+        walk_method_closure_creation_expression(self, node);
+    }
+
+    fn visit_static_method_closure_creation_expression(
+        &mut self,
+        node: &StaticMethodClosureCreationExpression,
+    ) {
+        self.push(Feature::FirstClassCallables, node.span);
+        walk_static_method_closure_creation_expression(self, node);
+    }
+
+    fn visit_static_variable_method_closure_creation_expression(
+        &mut self,
+        node: &StaticVariableMethodClosureCreationExpression,
+    ) {
+        self.push(Feature::FirstClassCallables, node.span);
+        walk_static_variable_method_closure_creation_expression(self, node);
+    }
+
+    fn visit_exit_expression(&mut self, node: &ExitExpression) {
+        self.exit_argument(node.argument.as_deref());
+        walk_exit_expression(self, node);
+    }
+
+    fn visit_die_expression(&mut self, node: &DieExpression) {
+        self.exit_argument(node.argument.as_deref());
+        walk_die_expression(self, node);
+    }
+
+    fn visit_data_type(&mut self, node: &DataType) {
+        match &node.kind {
+            Type::Never => self.push(Feature::NeverType, node.span),
+            Type::Null | Type::False | Type::True => {
+                self.push(Feature::StandaloneNullFalseTrue, node.span)
+            }
+            Type::Union(types)
+                if types
+                    .iter()
+                    .any(|r#type| matches!(r#type, Type::Intersection(_))) =>
+            {
+                self.push(Feature::DnfTypes, node.span)
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_classish_constant(&mut self, node: &ClassishConstant) {
+        if let Some(data_type) = &node.data_type {
+            self.push(Feature::TypedClassConstants, data_type.span);
+        }
+
+        walk_classish_constant(self, node);
+    }
+
+    fn visit_hooked_property(&mut self, node: &HookedProperty) {
+        self.push(Feature::PropertyHooks, node.hooks.span);
+        walk_hooked_property(self, node);
+    }
+
+    fn visit_doc_block(&mut self, _: &DocBlock) {}
+}
@@ -0,0 +1,128 @@
+use pxp_ast::{ClassishMember, Expression, Statement};
+use pxp_diagnostics::{Diagnostic, Severity};
+use pxp_lexer::Lexer;
+use pxp_token::TokenKind;
+
+use crate::internal::modifiers::ModifierContext;
+use crate::{ParseResult, Parser, ParserDiagnostic, ParserOptions};
+
+/// The kind of code that a fragment contains, see [`Parser::parse_fragment`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FragmentKind {
+    /// A single expression, optionally followed by a semicolon.
+    Expression,
+    /// Any number of statements.
+    Statements,
+    /// The members of a class-like body, without the surrounding braces.
+    ClassMembers,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Fragment {
+    Expression(Expression),
+    Statements(Vec<Statement>),
+    ClassMembers(Vec<ClassishMember>),
+}
+
+#[derive(Debug)]
+pub struct FragmentResult {
+    pub fragment: Fragment,
+    pub diagnostics: Vec<Diagnostic<ParserDiagnostic>>,
+}
+
+impl<'a> Parser<'a> {
+    /// Parse a piece of code that isn't a full file, such as an expression typed into a watch window
+    /// or a method body in isolation.
+    ///
+    /// The source is parsed as PHP code straight away, so it shouldn't start with an opening tag.
+    pub fn parse_fragment(source: &'a [u8], kind: FragmentKind) -> FragmentResult {
+        let mut parser = Parser::new(
+            Lexer::new_in_immediate(source),
+            ParserOptions::default(),
+            None,
+        );
+
+        let fragment = match kind {
+            FragmentKind::Expression => Fragment::Expression(parser.parse_expression_fragment()),
+            FragmentKind::Statements => {
+                let result = parser.parse_statements();
+
+                return FragmentResult {
+                    fragment: Fragment::Statements(result.ast),
+                    diagnostics: result.diagnostics,
+                };
+            }
+            FragmentKind::ClassMembers => {
+                Fragment::ClassMembers(parser.parse_class_members_fragment())
+            }
+        };
+
+        parser.report_unclosed_delimiters();
+
+        FragmentResult {
+            fragment,
+            diagnostics: parser.diagnostics,
+        }
+    }
+
+    /// Parse a fragment of PHP code that's embedded in another file, e.g. the code in a string
+    /// that's passed to `eval()`. The fragment doesn't start with an opening tag, and the spans
+    /// of its nodes and diagnostics are shifted by `base_offset`, which is where the fragment
+    /// starts in the outer file.
+    pub fn parse_embedded(source_fragment: &[u8], base_offset: usize) -> ParseResult {
+        // Lexing the fragment behind `base_offset` bytes of whitespace puts every span where it
+        // belongs without having to shift each of them afterwards.
+        let mut input = vec![b' '; base_offset];
+        input.extend_from_slice(source_fragment);
+
+        Parser::parse(Lexer::new_in_immediate(&input))
+    }
+
+    fn parse_expression_fragment(&mut self) -> Expression {
+        let expression = self.parse_expression();
+
+        if self.current_kind() == TokenKind::SemiColon {
+            self.next();
+        }
+
+        // Anything after a complete expression would otherwise be silently dropped.
+        if !self.is_eof() {
+            self.report_unexpected_token();
+            self.lexer.skip_to_eof();
+        }
+
+        expression
+    }
+
+    fn parse_class_members_fragment(&mut self) -> Vec<ClassishMember> {
+        // The surrounding class-like is unknown, so it's treated like a trait that could be used by
+        // an abstract class with a parent.
+        self.enter_classlike(None, true);
+
+        let mut members = Vec::new();
+
+        while !self.is_eof() {
+            members.extend(self.parse_classish_members(ModifierContext::AbstractClass));
+
+            // There's no body to close, so a stray closing brace would stop the loop above.
+            if self.current_kind() == TokenKind::RightBrace {
+                self.report_unexpected_token();
+                self.next();
+            }
+        }
+
+        self.exit_classlike();
+
+        members
+    }
+
+    fn report_unexpected_token(&mut self) {
+        self.diagnostic(
+            ParserDiagnostic::UnexpectedToken {
+                token: self.current().to_owned(),
+            },
+            Severity::Error,
+            self.current_span(),
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use std::ops::ControlFlow;
+
+use pxp_diagnostics::Diagnostic;
+use pxp_span::Span;
+
+use crate::ParserDiagnostic;
+
+/// Callbacks that let the caller observe, and cancel, a parse while it's running.
+///
+/// Every method has a default implementation that does nothing, so implementors only need
+/// to override the events they care about. Hooks are only checked once per top-level statement
+/// and once per diagnostic, never per token.
+pub trait ParserHooks {
+    /// Called after each top-level statement has been parsed, where `index` is the position of the
+    /// statement in the resulting AST.
+    ///
+    /// Returning `ControlFlow::Break` stops the parser. The statements parsed so far are still
+    /// returned and `ParseResult::cancelled` is set.
+    fn on_statement_parsed(&mut self, index: usize, span: Span) -> ControlFlow<()> {
+        let _ = (index, span);
+
+        ControlFlow::Continue(())
+    }
+
+    /// Called for each diagnostic as soon as it is produced.
+    fn on_diagnostic(&mut self, diagnostic: &Diagnostic<ParserDiagnostic>) {
+        let _ = diagnostic;
+    }
+}
+
+pub(crate) struct Hooks<'a>(Option<&'a mut dyn ParserHooks>);
+
+impl<'a> Hooks<'a> {
+    pub(crate) fn new(hooks: Option<&'a mut dyn ParserHooks>) -> Self {
+        Self(hooks)
+    }
+
+    pub(crate) fn on_statement_parsed(&mut self, index: usize, span: Span) -> ControlFlow<()> {
+        match &mut self.0 {
+            Some(hooks) => hooks.on_statement_parsed(index, span),
+            None => ControlFlow::Continue(()),
+        }
+    }
+
+    pub(crate) fn on_diagnostic(&mut self, diagnostic: &Diagnostic<ParserDiagnostic>) {
+        if let Some(hooks) = &mut self.0 {
+            hooks.on_diagnostic(diagnostic);
+        }
+    }
+}
+
+impl<'a> std::fmt::Debug for Hooks<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Hooks").field(&self.0.is_some()).finish()
+    }
+}
@@ -47,9 +47,10 @@ impl<'a> Parser<'a> {
                     );
                 }
 
+                let mut ampersand = self.optional(TokenKind::Ampersand);
                 let mut value = self.parse_expression();
 
-                if self.current_kind() == TokenKind::DoubleArrow {
+                if ampersand.is_none() && self.current_kind() == TokenKind::DoubleArrow {
                     if !has_at_least_one_key && !items.is_empty() {
                         self.diagnostic(
                             ParserDiagnostic::CannotMixKeyedAndUnkeyedListEntries,
@@ -70,6 +71,8 @@ impl<'a> Parser<'a> {
                         );
                     }
 
+                    ampersand = self.optional(TokenKind::Ampersand);
+
                     let mut key = self.parse_expression();
 
                     std::mem::swap(&mut key, &mut value);
@@ -79,6 +82,7 @@ impl<'a> Parser<'a> {
                         span: Span::combine(key.span, value.span),
                         key,
                         double_arrow,
+                        ampersand,
                         value,
                     }));
 
@@ -94,7 +98,11 @@ impl<'a> Parser<'a> {
 
                     items.push(ListEntry::Value(ListEntryValue {
                         id: self.id(),
-                        span: value.span,
+                        span: match ampersand {
+                            Some(ampersand) => Span::combine(ampersand, value.span),
+                            None => value.span,
+                        },
+                        ampersand,
                         value,
                     }));
                 }
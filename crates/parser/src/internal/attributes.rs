@@ -1,5 +1,7 @@
+use crate::internal::diagnostics::ParserDiagnostic;
 use crate::Parser;
 use pxp_ast::*;
+use pxp_diagnostics::Severity;
 use pxp_span::Span;
 use pxp_token::TokenKind;
 
@@ -12,6 +14,34 @@ impl<'a> Parser<'a> {
         attributes
     }
 
+    /// Take the attribute groups that start after the given span, leaving any that were gathered
+    /// for an outer construct in place, e.g. the parameter whose default value is being parsed.
+    pub(crate) fn get_attributes_after(&mut self, span: Span) -> Vec<AttributeGroup> {
+        let index = self
+            .attributes
+            .iter()
+            .position(|group| group.span.start >= span.end)
+            .unwrap_or(self.attributes.len());
+
+        self.attributes.split_off(index)
+    }
+
+    /// Parse any attributes in a position where they aren't allowed, e.g. before a trait usage,
+    /// reporting and dropping each group.
+    pub(crate) fn skip_misplaced_attributes(&mut self) {
+        let start = Span::flat(self.current_span().start);
+
+        self.gather_attributes();
+
+        for group in self.get_attributes_after(start) {
+            self.diagnostic(
+                ParserDiagnostic::InvalidTargetForAttributes,
+                Severity::Error,
+                group.span,
+            );
+        }
+    }
+
     pub(crate) fn attribute(&mut self, attr: AttributeGroup) {
         self.attributes.push(attr);
     }
@@ -63,6 +93,8 @@ impl<'a> Parser<'a> {
         let id = self.id();
 
         self.attribute(AttributeGroup { id, span, members });
-        self.gather_attributes()
+        self.gather_attributes();
+
+        true
     }
 }
@@ -8,14 +8,19 @@ use pxp_token::TokenKind;
 
 impl<'a> Parser<'a> {
     pub fn parse_block_statement(&mut self) -> StatementKind {
-        let (left_brace, statements, right_brace) =
-            self.braced(|parser| parser.parse_multiple_statements_until(TokenKind::RightBrace));
+        let (left_brace, (statements, trailing_comments), right_brace) = self.braced(|parser| {
+            (
+                parser.parse_multiple_statements_until(TokenKind::RightBrace),
+                parser.comments(),
+            )
+        });
 
         StatementKind::Block(Box::new(BlockStatement {
             id: self.id(),
             span: Span::combine(left_brace, right_brace),
             left_brace,
             statements,
+            trailing_comments,
             right_brace,
         }))
     }
@@ -39,7 +44,7 @@ impl<'a> Parser<'a> {
     pub fn parse_multiple_statements_until_any(&mut self, until: &[TokenKind]) -> Vec<Statement> {
         let mut statements = Vec::new();
 
-        while !until.contains(&self.current_kind()) {
+        while !self.is_eof() && !until.contains(&self.current_kind()) {
             if let TokenKind::OpenTag(OpenTagKind::Full) = self.current_kind() {
                 self.next();
 
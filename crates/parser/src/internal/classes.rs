@@ -1,21 +1,79 @@
 use crate::internal::diagnostics::ParserDiagnostic;
-use crate::Parser;
+use crate::internal::modifiers::{ModifierContext, ModifierTarget};
+use crate::{Classlike, Parser};
 use pxp_ast::Expression;
 use pxp_ast::StatementKind;
 use pxp_ast::UseKind;
 use pxp_ast::*;
 use pxp_ast::{ExpressionKind, NewExpression};
 
+use pxp_bytestring::ByteString;
 use pxp_diagnostics::Severity;
 use pxp_span::IsSpanned;
 use pxp_span::Span;
 use pxp_token::TokenKind;
 
 impl<'a> Parser<'a> {
-    pub fn parse_class(&mut self) -> StatementKind {
-        let attributes = self.get_attributes();
+    /// Track the class-like whose body is being parsed so that relative types can be resolved
+    /// and validated. Anonymous classes don't have a name, so `None` is used for them.
+    ///
+    /// `has_parent` should be `true` when `parent` can be used inside of the class-like. Traits don't
+    /// know which class they'll be used by, so they're always assumed to have one.
+    pub(crate) fn enter_classlike(&mut self, name: Option<ByteString>, has_parent: bool) {
+        self.classlikes.push(Classlike {
+            name,
+            has_parent,
+            interface: false,
+        });
+    }
+
+    pub(crate) fn enter_interface(&mut self, name: Option<ByteString>) {
+        self.classlikes.push(Classlike {
+            name,
+            has_parent: false,
+            interface: true,
+        });
+    }
+
+    pub(crate) fn in_interface(&self) -> bool {
+        self.classlikes
+            .last()
+            .is_some_and(|classlike| classlike.interface)
+    }
 
+    pub(crate) fn exit_classlike(&mut self) {
+        self.classlikes.pop();
+    }
+
+    pub fn parse_class(&mut self) -> StatementKind {
         let modifiers = self.collect_modifiers();
+
+        // Other class-likes can't have modifiers, but they're still parsed as what was written.
+        match self.current_kind() {
+            TokenKind::Enum => {
+                self.check_modifiers(ModifierTarget::Enum, ModifierContext::Statement, &modifiers);
+                return self.parse_enum();
+            }
+            TokenKind::Interface => {
+                self.check_modifiers(
+                    ModifierTarget::Interface,
+                    ModifierContext::Statement,
+                    &modifiers,
+                );
+                return self.parse_interface();
+            }
+            TokenKind::Trait => {
+                self.check_modifiers(
+                    ModifierTarget::Trait,
+                    ModifierContext::Statement,
+                    &modifiers,
+                );
+                return self.parse_trait();
+            }
+            _ => {}
+        }
+
+        let attributes = self.get_attributes();
         let modifiers = self.parse_class_group(modifiers);
         let class = self.skip(TokenKind::Class);
         let name = self.parse_type_name();
@@ -37,9 +95,10 @@ impl<'a> Parser<'a> {
         let implements = if self.current_kind() == TokenKind::Implements {
             let span = self.next();
 
-            let interfaces = self.at_least_one_comma_separated_no_trailing::<Name>(|parser| {
-                parser.parse_full_name(UseKind::Normal)
-            });
+            let interfaces = self.at_least_one_comma_separated_no_trailing::<Name>(
+                |parser| parser.parse_full_name(UseKind::Normal),
+                TokenKind::LeftBrace,
+            );
 
             Some(ClassImplements {
                 id: self.id(),
@@ -51,23 +110,21 @@ impl<'a> Parser<'a> {
             None
         };
 
-        let has_abstract = modifiers.has_abstract();
+        let context = if modifiers.has_abstract() {
+            ModifierContext::AbstractClass
+        } else {
+            ModifierContext::Class
+        };
+        self.enter_classlike(
+            name.as_resolved().map(|name| name.resolved.clone()),
+            extends.is_some(),
+        );
         let left_brace = self.skip_left_brace();
 
-        let members = {
-            let mut members = Vec::new();
-
-            while !self.is_eof() && self.current_kind() != TokenKind::RightBrace {
-                if self.is_eof() {
-                    break;
-                }
-
-                members.push(self.parse_classish_member(has_abstract));
-            }
-
-            members
-        };
+        let members = self.parse_classish_members(context);
 
+        let trailing_comments = self.comments();
+        self.exit_classlike();
         let right_brace = self.skip_right_brace();
 
         let body = ClassBody {
@@ -75,6 +132,7 @@ impl<'a> Parser<'a> {
             span: Span::combine(left_brace, right_brace),
             left_brace,
             members,
+            trailing_comments,
             right_brace,
         };
 
@@ -105,15 +163,11 @@ impl<'a> Parser<'a> {
 
         self.gather_attributes();
 
-        let attributes = self.get_attributes();
+        let attributes = self.get_attributes_after(new);
         let class = self.skip(TokenKind::Class);
         let class_span = class;
 
-        let arguments = if self.current_kind() == TokenKind::LeftParen {
-            Some(self.parse_argument_list())
-        } else {
-            None
-        };
+        let arguments = self.parse_new_argument_list();
 
         let extends = if self.current_kind() == TokenKind::Extends {
             let extends = self.next();
@@ -131,9 +185,10 @@ impl<'a> Parser<'a> {
 
         let implements = if self.current_kind() == TokenKind::Implements {
             let implements = self.next();
-            let interfaces = self.at_least_one_comma_separated_no_trailing::<Name>(|parser| {
-                parser.parse_full_name(UseKind::Normal)
-            });
+            let interfaces = self.at_least_one_comma_separated_no_trailing::<Name>(
+                |parser| parser.parse_full_name(UseKind::Normal),
+                TokenKind::LeftBrace,
+            );
 
             Some(ClassImplements {
                 id: self.id(),
@@ -145,14 +200,11 @@ impl<'a> Parser<'a> {
             None
         };
 
+        self.enter_classlike(None, extends.is_some());
         let left_brace = self.skip_left_brace();
-        let members = {
-            let mut members = Vec::new();
-            while !self.is_eof() && self.current_kind() != TokenKind::RightBrace {
-                members.push(self.parse_classish_member(false));
-            }
-            members
-        };
+        let members = self.parse_classish_members(ModifierContext::Class);
+        let trailing_comments = self.comments();
+        self.exit_classlike();
         let right_brace = self.skip_right_brace();
         let span = Span::combine(left_brace, right_brace);
 
@@ -161,6 +213,7 @@ impl<'a> Parser<'a> {
             span,
             left_brace,
             members,
+            trailing_comments,
             right_brace,
         };
 
@@ -197,10 +250,75 @@ impl<'a> Parser<'a> {
         )
     }
 
-    pub fn parse_classish_member(&mut self, has_abstract: bool) -> ClassishMember {
-        let has_attributes = self.gather_attributes();
+    /// Parse the members of a class body up to the closing brace.
+    pub(crate) fn parse_classish_members(
+        &mut self,
+        context: ModifierContext,
+    ) -> Vec<ClassishMember> {
+        let mut members = Vec::new();
+
+        while !self.is_eof() && self.current_kind() != TokenKind::RightBrace {
+            members.push(self.parse_classish_member(context));
+        }
+
+        self.check_promoted_properties(&members);
+
+        members
+    }
+
+    /// A promoted constructor parameter declares a property, so it can't have the same name as a
+    /// property that's declared explicitly, no matter which one comes first.
+    fn check_promoted_properties(&mut self, members: &[ClassishMember]) {
+        let declared = members
+            .iter()
+            .flat_map(|member| match member {
+                ClassishMember::Property(Property::Simple(property)) => {
+                    property.entries.iter().collect()
+                }
+                ClassishMember::Property(Property::Hooked(property)) => vec![&property.entry],
+                _ => Vec::new(),
+            })
+            .map(|entry| &entry.kind.variable().symbol)
+            .collect::<Vec<_>>();
+
+        let promoted = members
+            .iter()
+            .filter_map(|member| match member {
+                ClassishMember::Method(method)
+                    if method.name.symbol.eq_ignore_ascii_case(b"__construct") =>
+                {
+                    Some(method)
+                }
+                _ => None,
+            })
+            .flat_map(|constructor| constructor.parameters.parameters.iter())
+            .filter(|parameter| parameter.modifiers.is_some())
+            .filter(|parameter| declared.contains(&&parameter.name.symbol))
+            .map(|parameter| (parameter.name.symbol.clone(), parameter.name.span))
+            .collect::<Vec<_>>();
+
+        for (property, span) in promoted {
+            self.diagnostic(
+                ParserDiagnostic::PromotedPropertyRedeclared { property },
+                Severity::Error,
+                span,
+            );
+        }
+    }
+
+    pub fn parse_classish_member(&mut self, context: ModifierContext) -> ClassishMember {
+        self.gather_attributes();
+
+        if self.current_kind() == TokenKind::Use {
+            // Attributes can't be applied to trait usages.
+            for group in self.get_attributes() {
+                self.diagnostic(
+                    ParserDiagnostic::InvalidTargetForAttributes,
+                    Severity::Error,
+                    group.span,
+                );
+            }
 
-        if !has_attributes && self.current_kind() == TokenKind::Use {
             return ClassishMember::TraitUsage(self.parse_trait_usage());
         }
 
@@ -210,6 +328,15 @@ impl<'a> Parser<'a> {
 
         let modifiers = self.collect_modifiers();
 
+        self.parse_modified_classish_member(modifiers, context)
+    }
+
+    /// Parse the rest of a classish member, once its modifiers have been collected.
+    pub(crate) fn parse_modified_classish_member(
+        &mut self,
+        modifiers: Vec<(Span, TokenKind)>,
+        context: ModifierContext,
+    ) -> ClassishMember {
         if modifiers.is_empty()
             && !matches!(self.current_kind(), TokenKind::Const | TokenKind::Function)
         {
@@ -230,27 +357,17 @@ impl<'a> Parser<'a> {
         }
 
         if self.current_kind() == TokenKind::Const {
-            let modifiers = self.parse_constant_group(modifiers);
+            let modifiers = self.parse_constant_group(modifiers, context);
             return ClassishMember::Constant(self.parse_classish_constant(modifiers));
         }
 
         if self.current_kind() == TokenKind::Function {
-            let modifiers = self.parse_method_group(modifiers);
-            let method = self.parse_method(modifiers);
-
-            if method.is_abstract() && !has_abstract {
-                self.diagnostic(
-                    ParserDiagnostic::AbstractMethodInNonAbstractClass,
-                    Severity::Error,
-                    method.span(),
-                );
-            }
-
-            return ClassishMember::Method(method);
+            let modifiers = self.parse_method_group(modifiers, context);
+            return ClassishMember::Method(self.parse_method(modifiers));
         }
 
         // e.g: public static
-        let modifiers = self.parse_property_group(modifiers);
+        let modifiers = self.parse_property_group(modifiers, context);
 
         ClassishMember::Property(self.parse_property(modifiers))
     }
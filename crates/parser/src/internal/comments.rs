@@ -1,6 +1,7 @@
 use pxp_ast::{
     Comment, CommentGroup, CommentKind, HashMarkComment, MultiLineComment, SingleLineComment,
 };
+use pxp_span::Span;
 use pxp_token::TokenKind;
 
 use crate::Parser;
@@ -17,6 +18,31 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Take the pending comments that start on the same line as the end of the given span,
+    /// e.g. `$x = 1; // explains x`.
+    ///
+    /// DocBlocks are never treated as trailing comments so that they stay attached to the
+    /// declaration that follows them.
+    pub(crate) fn trailing_comments(&mut self, span: Span) -> CommentGroup {
+        let count = self
+            .comments
+            .iter()
+            .take_while(|comment| {
+                !matches!(comment.kind, CommentKind::DocBlock(_))
+                    && comment.span.start >= span.end
+                    && !self
+                        .lexer
+                        .span_range(Span::new(span.end, comment.span.start))
+                        .contains(b"\n")
+            })
+            .count();
+
+        CommentGroup {
+            id: self.id(),
+            comments: self.comments.drain(..count).collect(),
+        }
+    }
+
     pub(crate) fn collect_comments(&mut self) {
         loop {
             if self.is_eof() {
@@ -1,3 +1,4 @@
+use crate::internal::data_type::TypeContext;
 use crate::Parser;
 use pxp_ast::*;
 use pxp_span::Span;
@@ -6,6 +7,7 @@ use pxp_token::TokenKind;
 impl<'a> Parser<'a> {
     pub fn parse_constant(&mut self) -> ConstantStatement {
         let comments = self.comments();
+        let attributes = self.get_attributes();
         let start = self.skip(TokenKind::Const);
 
         let mut entries = vec![];
@@ -37,6 +39,7 @@ impl<'a> Parser<'a> {
             id: self.id(),
             span,
             comments,
+            attributes,
             r#const: start,
             entries,
             semicolon: end,
@@ -51,8 +54,9 @@ impl<'a> Parser<'a> {
         let comments = self.comments();
         let start = self.skip(TokenKind::Const);
 
-        let data_type = if self.peek_kind() == TokenKind::Identifier {
-            Some(self.parse_data_type())
+        // Constants without a type are immediately followed by `=`, e.g. `const FOO = 1;`.
+        let data_type = if self.peek_kind() != TokenKind::Equals {
+            Some(self.parse_data_type_in(TypeContext::ClassConstant))
         } else {
             None
         };
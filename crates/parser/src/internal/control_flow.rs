@@ -13,7 +13,7 @@ use pxp_ast::{Block, MatchExpression};
 use pxp_diagnostics::Severity;
 use pxp_span::IsSpanned;
 use pxp_span::Span;
-use pxp_token::TokenKind;
+use pxp_token::{OpenTagKind, TokenKind};
 
 impl<'a> Parser<'a> {
     pub fn parse_match_expression(&mut self) -> Expression {
@@ -126,52 +126,44 @@ impl<'a> Parser<'a> {
         };
 
         let mut cases = Vec::new();
-        while !self.is_eof() && self.current_kind() != end_token {
+        loop {
+            self.skip_switch_tags();
+
+            if self.is_eof() || self.current_kind() == end_token {
+                break;
+            }
+
             match self.current_kind() {
                 TokenKind::Case => {
-                    self.next();
+                    let comments = self.comments();
+                    let case = self.next();
 
                     let condition = self.parse_expression();
-
-                    self.skip_any_of(&[TokenKind::Colon, TokenKind::SemiColon]);
-
-                    let mut body = Block::new();
-
-                    while !self.is_eof()
-                        && (self.current_kind() != TokenKind::Case
-                            && self.current_kind() != TokenKind::Default
-                            && self.current_kind() != TokenKind::RightBrace
-                            && self.current_kind() != end_token)
-                    {
-                        body.push(self.parse_statement());
-                    }
+                    let separator = self.parse_case_separator();
+                    let body = self.parse_case_body(end_token);
 
                     cases.push(Case {
                         id: self.id(),
-                        span: Span::combine(condition.span, body.span()),
+                        span: Span::combine(case, Self::case_end(&separator, &body)),
+                        comments,
                         condition: Some(condition),
+                        separator,
                         body,
                     });
                 }
                 TokenKind::Default => {
-                    self.next();
+                    let comments = self.comments();
+                    let default = self.next();
 
-                    self.skip_any_of(&[TokenKind::Colon, TokenKind::SemiColon]);
-
-                    let mut body = Block::new();
-
-                    while !self.is_eof()
-                        && (self.current_kind() != TokenKind::Case
-                            && self.current_kind() != TokenKind::Default
-                            && self.current_kind() != end_token)
-                    {
-                        body.push(self.parse_statement());
-                    }
+                    let separator = self.parse_case_separator();
+                    let body = self.parse_case_body(end_token);
 
                     cases.push(Case {
                         id: self.id(),
-                        span: body.span(),
+                        span: Span::combine(default, Self::case_end(&separator, &body)),
+                        comments,
                         condition: None,
+                        separator,
                         body,
                     });
                 }
@@ -184,6 +176,8 @@ impl<'a> Parser<'a> {
                         Severity::Error,
                         self.current_span(),
                     );
+
+                    self.next();
                 }
             }
         }
@@ -206,6 +200,62 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    /// Skip the closing and opening tags that surround whitespace between cases, e.g.
+    /// `switch ($a): ?>\n<?php case 1: ?>`, which is common in templates.
+    fn skip_switch_tags(&mut self) {
+        while matches!(
+            self.current_kind(),
+            TokenKind::CloseTag | TokenKind::OpenTag(OpenTagKind::Full | OpenTagKind::Short)
+        ) || (self.current_kind() == TokenKind::InlineHtml
+            && self.current_symbol().iter().all(u8::is_ascii_whitespace))
+        {
+            self.next();
+        }
+    }
+
+    fn parse_case_separator(&mut self) -> CaseSeparator {
+        match self.current_kind() {
+            TokenKind::Colon => CaseSeparator::Colon(self.next()),
+            TokenKind::SemiColon => CaseSeparator::SemiColon(self.next()),
+            _ => {
+                self.diagnostic(
+                    ParserDiagnostic::ExpectedToken {
+                        expected: vec![TokenKind::Colon, TokenKind::SemiColon],
+                        found: self.current().to_owned(),
+                    },
+                    Severity::Error,
+                    self.current_span(),
+                );
+
+                CaseSeparator::Missing(Span::flat(self.current_span().start))
+            }
+        }
+    }
+
+    fn case_end(separator: &CaseSeparator, body: &Block) -> Span {
+        if body.is_empty() {
+            separator.span()
+        } else {
+            body.span()
+        }
+    }
+
+    fn parse_case_body(&mut self, end_token: TokenKind) -> Block {
+        let mut body = Block::new();
+
+        while !self.is_eof()
+            && !matches!(
+                self.current_kind(),
+                TokenKind::Case | TokenKind::Default | TokenKind::RightBrace
+            )
+            && self.current_kind() != end_token
+        {
+            body.push(self.parse_statement());
+        }
+
+        body
+    }
+
     pub fn parse_if_statement(&mut self) -> StatementKind {
         let r#if = self.skip(TokenKind::If);
 
@@ -291,9 +341,7 @@ impl<'a> Parser<'a> {
 
         let mut elseifs: Vec<IfStatementElseIfBlock> = vec![];
 
-        while self.current_kind() == TokenKind::ElseIf {
-            let start = self.next();
-
+        while let Some(start) = self.parse_elseif_keyword() {
             let (left_parenthesis, condition, right_parenthesis) =
                 self.parenthesized(|parser| parser.parse_expression());
 
@@ -350,4 +398,28 @@ impl<'a> Parser<'a> {
             ending,
         })
     }
+
+    /// Parse the `elseif` that starts a clause of an if statement that uses the alternative syntax.
+    ///
+    /// `else if` isn't allowed here, since the nested if statement would need its own `endif`.
+    /// It's reported and treated as an `elseif`, so that the rest of the statement still parses.
+    fn parse_elseif_keyword(&mut self) -> Option<Span> {
+        match self.current_kind() {
+            TokenKind::ElseIf => Some(self.next()),
+            TokenKind::Else if self.peek_kind() == TokenKind::If => {
+                let r#else = self.next();
+                let r#if = self.next();
+                let span = Span::combine(r#else, r#if);
+
+                self.diagnostic(
+                    ParserDiagnostic::ElseIfInAlternativeSyntax,
+                    Severity::Error,
+                    span,
+                );
+
+                Some(span)
+            }
+            _ => None,
+        }
+    }
 }
@@ -1,5 +1,7 @@
+use std::collections::HashSet;
+
 use crate::internal::diagnostics::ParserDiagnostic;
-use crate::Parser;
+use crate::{Feature, Parser};
 use pxp_ast::*;
 use pxp_bytestring::ByteString;
 use pxp_diagnostics::Severity;
@@ -10,12 +12,40 @@ use pxp_type::{
     ShapeItemKey, ShapeUnsealedType, Type,
 };
 
+/// The position that a native type is being parsed in, which determines the types that are allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TypeContext {
+    Parameter,
+    Return,
+    Property,
+    ClassConstant,
+}
+
 impl<'a> Parser<'a> {
+    pub(crate) fn parse_data_type_in(&mut self, context: TypeContext) -> DataType {
+        let previous = self.type_context.replace(context);
+        let ty = self.parse_data_type();
+        self.type_context = previous;
+
+        ty
+    }
+
+    pub(crate) fn parse_optional_data_type_in(&mut self, context: TypeContext) -> Option<DataType> {
+        let previous = self.type_context.replace(context);
+        let ty = self.parse_optional_data_type();
+        self.type_context = previous;
+
+        ty
+    }
+
     pub fn parse_data_type(&mut self) -> DataType {
+        let start = self.current_span();
         let kind = if self.is_in_docblock() {
             self.parse_docblock_type()
         } else if self.current_kind() == TokenKind::Question {
-            self.parse_nullable_type()
+            let ty = self.parse_nullable_type();
+
+            self.parse_combined_nullable_type(ty)
         } else if self.current_kind() == TokenKind::LeftParen {
             self.parse_dnf_type()
         } else {
@@ -35,17 +65,31 @@ impl<'a> Parser<'a> {
             }
         };
 
-        // FIXME: We need to create spans for types, but we don't have access to the previous token anymore.
-        let span = Span::missing();
+        let span = self.data_type_span(start);
+
+        self.check_standalone_type(&kind, span);
 
         DataType::new(self.id(), kind, span)
     }
 
+    /// The span of a type that started at the given token, which is empty when the type is
+    /// missing.
+    fn data_type_span(&self, start: Span) -> Span {
+        if self.previous_span.end < start.end {
+            return Span::flat(start.start);
+        }
+
+        Span::combine(start, self.previous_span)
+    }
+
     pub fn parse_optional_data_type(&mut self) -> Option<DataType> {
+        let start = self.current_span();
         let kind = if self.is_in_docblock() {
             self.parse_docblock_type()
         } else if self.current_kind() == TokenKind::Question {
-            self.parse_nullable_type()
+            let ty = self.parse_nullable_type();
+
+            self.parse_combined_nullable_type(ty)
         } else if self.current_kind() == TokenKind::LeftParen {
             self.parse_dnf_type()
         } else {
@@ -70,12 +114,20 @@ impl<'a> Parser<'a> {
             }
         };
 
-        // FIXME: We need to create spans for types, but we don't have access to the previous token anymore.
-        let span = Span::missing();
+        let span = self.data_type_span(start);
+
+        self.check_standalone_type(&kind, span);
 
         Some(DataType::new(self.id(), kind, span))
     }
 
+    /// `null`, `false` and `true` can only be used on their own from PHP 8.2 onwards.
+    fn check_standalone_type(&mut self, ty: &Type<ResolvedName>, span: Span) {
+        if !self.is_in_docblock() && matches!(ty, Type::Null | Type::False | Type::True) {
+            self.requires_feature(Feature::StandaloneNullFalseTrue, span);
+        }
+    }
+
     // Special type parsing logic for DocBlock comments, heavily based on the phpstan/phpdoc-parser package.
     fn parse_docblock_type(&mut self) -> Type<ResolvedName> {
         match self.current_kind() {
@@ -89,6 +141,15 @@ impl<'a> Parser<'a> {
 
                 match self.current_kind() {
                     TokenKind::Pipe => self.parse_docblock_union(r#type),
+                    // A by-reference parameter, e.g. `@param bool &$value`.
+                    TokenKind::Ampersand
+                        if matches!(
+                            self.peek_kind(),
+                            TokenKind::Variable | TokenKind::Ellipsis | TokenKind::Ampersand
+                        ) =>
+                    {
+                        r#type
+                    }
                     TokenKind::Ampersand => self.parse_docblock_intersection(r#type),
                     _ => r#type,
                 }
@@ -336,12 +397,13 @@ impl<'a> Parser<'a> {
                 }
             }
             TokenKind::Variable if self.current_symbol() == b"$this" => {
-                self.next();
+                let span = self.next();
+                let this = self.maybe_resolve_relative_type(Type::This, span);
 
                 if self.current_kind() == TokenKind::LeftBracket {
-                    self.parse_docblock_array_or_offset_access(Type::This)
+                    self.parse_docblock_array_or_offset_access(this)
                 } else {
-                    Type::This
+                    this
                 }
             }
             _ if self.current_is_docblock_const_expr() => self.parse_docblock_const_expr(),
@@ -357,7 +419,11 @@ impl<'a> Parser<'a> {
                 // FIXME: Check for ! T:: here.
                 let current = self.current();
 
-                if current.kind == TokenKind::LessThan {
+                // A `<` after a space is the start of the description rather than a list of
+                // generic arguments, e.g. `@return boolean <code>true</code> if ...`.
+                if current.kind == TokenKind::LessThan
+                    && current.span.start == self.previous_span.end
+                {
                     let mut r#type = self.parse_docblock_generic(r#type);
 
                     if self.current_kind() == TokenKind::LeftBracket {
@@ -382,11 +448,11 @@ impl<'a> Parser<'a> {
     }
 
     fn type_can_be_callable(&self, ty: &Type<ResolvedName>) -> bool {
-        return match ty {
+        match ty {
             Type::Callable => true,
             Type::Named(name) if name.resolved == b"Closure" => true,
             _ => false,
-        };
+        }
     }
 
     fn parse_docblock_array_shape(&mut self, lhs: Type<ResolvedName>) -> Type<ResolvedName> {
@@ -589,21 +655,74 @@ impl<'a> Parser<'a> {
             self.skip_doc_eol();
         }
 
-        if self.current_kind() == TokenKind::GreaterThan {
-            self.next();
-        } else {
-            self.diagnostic(
-                ParserDiagnostic::ExpectedTokenExFound {
-                    expected: vec![TokenKind::GreaterThan],
-                },
-                Severity::Warning,
-                self.current_span(),
-            );
+        match self.current_kind() {
+            TokenKind::GreaterThan => {
+                self.next();
+            }
+            // The list of arguments carries on past something that isn't a type, e.g. the
+            // missing comma in `array<int string>`, so the rest of it is skipped and the argument
+            // that couldn't be parsed is missing.
+            kind if !matches!(
+                kind,
+                TokenKind::Eof
+                    | TokenKind::PhpDocEol
+                    | TokenKind::ClosePhpDoc
+                    | TokenKind::Variable
+            ) =>
+            {
+                self.diagnostic(
+                    ParserDiagnostic::ExpectedTokenExFound {
+                        expected: vec![TokenKind::Comma, TokenKind::GreaterThan],
+                    },
+                    Severity::Warning,
+                    self.current_span(),
+                );
+
+                generic_types.push(GenericTypeArgument {
+                    r#type: Type::Missing,
+                    variance: None,
+                });
+
+                self.skip_docblock_generic_arguments();
+            }
+            _ => {
+                self.diagnostic(
+                    ParserDiagnostic::ExpectedTokenExFound {
+                        expected: vec![TokenKind::GreaterThan],
+                    },
+                    Severity::Warning,
+                    self.current_span(),
+                );
+            }
         }
 
         Type::Generic(Box::new(lhs), generic_types)
     }
 
+    /// Skip to the end of a list of generic arguments, including the closing `>`, without leaving
+    /// the line or consuming the variable that follows the type.
+    fn skip_docblock_generic_arguments(&mut self) {
+        let mut depth = 0;
+
+        while !matches!(
+            self.current_kind(),
+            TokenKind::Eof | TokenKind::PhpDocEol | TokenKind::ClosePhpDoc | TokenKind::Variable
+        ) {
+            match self.current_kind() {
+                TokenKind::LessThan => depth += 1,
+                TokenKind::GreaterThan if depth == 0 => {
+                    self.next();
+
+                    return;
+                }
+                TokenKind::GreaterThan => depth -= 1,
+                _ => {}
+            }
+
+            self.next();
+        }
+    }
+
     fn parse_docblock_callable(&mut self, lhs: Type<ResolvedName>) -> Type<ResolvedName> {
         self.skip(TokenKind::LeftParen);
         self.skip_doc_eol();
@@ -864,6 +983,15 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_optional_simple_data_type(&mut self) -> Option<Type<ResolvedName>> {
+        let span = self.current_span();
+        let ty = self.parse_optional_atomic_data_type()?;
+
+        self.check_type_in_context(&ty, span);
+
+        Some(ty)
+    }
+
+    fn parse_optional_atomic_data_type(&mut self) -> Option<Type<ResolvedName>> {
         match self.current_kind() {
             TokenKind::PhpDocEmpty if self.is_in_docblock() => {
                 self.next();
@@ -901,19 +1029,19 @@ impl<'a> Parser<'a> {
                 Some(Type::False)
             }
             TokenKind::Static => {
-                self.next();
+                let span = self.next();
 
-                Some(Type::StaticReference)
+                Some(self.maybe_resolve_relative_type(Type::StaticReference, span))
             }
             TokenKind::Self_ => {
-                self.next();
+                let span = self.next();
 
-                Some(Type::SelfReference)
+                Some(self.maybe_resolve_relative_type(Type::SelfReference, span))
             }
             TokenKind::Parent => {
-                self.next();
+                let span = self.next();
 
-                Some(Type::ParentReference)
+                Some(self.maybe_resolve_relative_type(Type::ParentReference, span))
             }
             TokenKind::Enum | TokenKind::From => {
                 self.next();
@@ -927,7 +1055,10 @@ impl<'a> Parser<'a> {
                 )))
             }
             TokenKind::Identifier => {
-                self.next_but_first(|parser| match parser.current_symbol().as_ref() {
+                // Native type names are case-insensitive, e.g. `Iterable` is still `iterable` and not a class.
+                let lowercase = self.current_symbol().to_ascii_lowercase();
+
+                self.next_but_first(|parser| match &lowercase[..] {
                     b"void" => Some(Type::Void),
                     b"never" => Some(Type::Never),
                     b"float" => Some(Type::Float),
@@ -974,8 +1105,10 @@ impl<'a> Parser<'a> {
             }
             TokenKind::FullyQualifiedIdentifier => {
                 let symbol = self.current_symbol_as_bytestring();
-                let resolved = self.strip_leading_namespace_qualifier(&symbol);
-                let span = self.next();
+                let resolved = self
+                    .strip_leading_namespace_qualifier(symbol.as_bytestr())
+                    .to_bytestring();
+                self.next();
 
                 Some(Type::Named(ResolvedName {
                     resolved,
@@ -1013,7 +1146,19 @@ impl<'a> Parser<'a> {
 
         let ty = self.parse_simple_data_type();
 
-        if ty.standalone() {
+        if ty.is_mixed() {
+            self.diagnostic(
+                ParserDiagnostic::MixedTypeCannotBeCombined,
+                Severity::Error,
+                span,
+            );
+        } else if ty.is_null() {
+            self.diagnostic(
+                ParserDiagnostic::NullCannotBeMarkedAsNullable,
+                Severity::Error,
+                span,
+            );
+        } else if ty.standalone() {
             self.diagnostic(
                 ParserDiagnostic::StandaloneTypeUsedInNullableType,
                 Severity::Error,
@@ -1024,23 +1169,144 @@ impl<'a> Parser<'a> {
         Type::Nullable(Box::new(ty))
     }
 
-    fn parse_union_type(
-        &mut self,
-        other: Type<ResolvedName>,
-        within_dnf: bool,
-    ) -> Type<ResolvedName> {
-        if other.standalone() {
+    // `?int|string` isn't valid, but we still want to consume the rest of the type
+    // so that the parser can carry on as if it were a union or intersection.
+    fn parse_combined_nullable_type(&mut self, ty: Type<ResolvedName>) -> Type<ResolvedName> {
+        match self.current_kind() {
+            TokenKind::Pipe => {
+                self.diagnostic(
+                    ParserDiagnostic::NullableTypeCannotBeCombined,
+                    Severity::Error,
+                    self.current_span(),
+                );
+
+                self.parse_union_type(ty, false)
+            }
+            TokenKind::Ampersand
+                if !matches!(
+                    self.peek_kind(),
+                    TokenKind::Variable | TokenKind::Ellipsis | TokenKind::Ampersand
+                ) =>
+            {
+                self.diagnostic(
+                    ParserDiagnostic::NullableTypeCannotBeCombined,
+                    Severity::Error,
+                    self.current_span(),
+                );
+
+                self.parse_intersection_type(ty, false)
+            }
+            _ => ty,
+        }
+    }
+
+    fn check_union_type_member(&mut self, ty: &Type<ResolvedName>, span: Span) {
+        if ty.is_mixed() {
+            self.diagnostic(
+                ParserDiagnostic::MixedTypeCannotBeCombined,
+                Severity::Error,
+                span,
+            );
+        } else if ty.is_nullable() {
+            // Nullable members have already been reported by `parse_combined_nullable_type`.
+        } else if ty.standalone() {
             self.diagnostic(
                 ParserDiagnostic::StandaloneTypeUsedInUnionType,
                 Severity::Error,
-                self.current_span(),
+                span,
             );
         }
+    }
+
+    fn check_union_type(&mut self, types: &[Type<ResolvedName>], spans: &[Span]) {
+        self.check_duplicate_types(types, spans);
+
+        let has_bool = types.iter().any(|ty| ty.is_boolean());
+        let has_true = types.iter().position(|ty| ty.is_true());
+        let has_false = types.iter().position(|ty| ty.is_false());
+
+        match (has_true, has_false) {
+            (Some(_), Some(index)) if !has_bool => self.diagnostic(
+                ParserDiagnostic::TrueAndFalseUsedInUnionType,
+                Severity::Error,
+                spans[index],
+            ),
+            _ if has_bool => {
+                for index in [has_true, has_false].into_iter().flatten() {
+                    self.diagnostic(
+                        ParserDiagnostic::DuplicateType {
+                            ty: types[index].clone(),
+                        },
+                        Severity::Error,
+                        spans[index],
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn check_intersection_type_member(&mut self, ty: &Type<ResolvedName>, span: Span) {
+        if ty.is_mixed() {
+            self.diagnostic(
+                ParserDiagnostic::MixedTypeCannotBeCombined,
+                Severity::Error,
+                span,
+            );
+        } else if ty.is_nullable() {
+            // Nullable members have already been reported by `parse_combined_nullable_type`.
+        } else if ty.standalone() {
+            self.diagnostic(
+                ParserDiagnostic::StandaloneTypeUsedInIntersectionType,
+                Severity::Error,
+                span,
+            );
+        } else if !matches!(
+            ty,
+            Type::Named(_)
+                | Type::SelfReference
+                | Type::StaticReference
+                | Type::ParentReference
+                | Type::Union(_)
+                | Type::Missing
+        ) {
+            self.diagnostic(
+                ParserDiagnostic::NonClassTypeUsedInIntersectionType { ty: ty.clone() },
+                Severity::Error,
+                span,
+            );
+        }
+    }
+
+    fn check_duplicate_types(&mut self, types: &[Type<ResolvedName>], spans: &[Span]) {
+        let mut seen = HashSet::new();
+
+        for (ty, span) in types.iter().zip(spans) {
+            if *ty != Type::Missing && !seen.insert(ty) {
+                self.diagnostic(
+                    ParserDiagnostic::DuplicateType { ty: ty.clone() },
+                    Severity::Error,
+                    *span,
+                );
+            }
+        }
+    }
+
+    fn parse_union_type(
+        &mut self,
+        other: Type<ResolvedName>,
+        within_dnf: bool,
+    ) -> Type<ResolvedName> {
+        let first_span = self.current_span();
+
+        self.check_union_type_member(&other, first_span);
 
         let mut types = vec![other];
-        let mut last_pipe = self.skip(TokenKind::Pipe);
+        let mut spans = vec![first_span];
+        self.skip(TokenKind::Pipe);
 
         loop {
+            let member_span = self.current_span();
             let current = self.current();
             let ty = if current.kind == TokenKind::LeftParen {
                 if within_dnf {
@@ -1074,26 +1340,24 @@ impl<'a> Parser<'a> {
                 ty
             } else {
                 let ty = self.parse_simple_data_type();
-                if ty.standalone() {
-                    self.diagnostic(
-                        ParserDiagnostic::StandaloneTypeUsedInUnionType,
-                        Severity::Error,
-                        last_pipe,
-                    );
-                }
+
+                self.check_union_type_member(&ty, member_span);
 
                 ty
             };
 
             types.push(ty);
+            spans.push(member_span);
 
             if self.current_kind() == TokenKind::Pipe {
-                last_pipe = self.skip(TokenKind::Pipe);
+                self.skip(TokenKind::Pipe);
             } else {
                 break;
             }
         }
 
+        self.check_union_type(&types, &spans);
+
         Type::Union(types)
     }
 
@@ -1102,19 +1366,17 @@ impl<'a> Parser<'a> {
         other: Type<ResolvedName>,
         within_dnf: bool,
     ) -> Type<ResolvedName> {
-        if other.standalone() {
-            self.diagnostic(
-                ParserDiagnostic::StandaloneTypeUsedInIntersectionType,
-                Severity::Error,
-                self.current_span(),
-            );
-        }
+        let first_span = self.current_span();
+
+        self.check_intersection_type_member(&other, first_span);
 
         let mut types = vec![other];
+        let mut spans = vec![first_span];
 
-        let mut last_ampersand = self.skip(TokenKind::Ampersand);
+        self.skip(TokenKind::Ampersand);
 
         loop {
+            let member_span = self.current_span();
             let current = self.current();
             let ty = if current.kind == TokenKind::LeftParen {
                 if within_dnf {
@@ -1149,18 +1411,14 @@ impl<'a> Parser<'a> {
                 ty
             } else {
                 let ty = self.parse_simple_data_type();
-                if ty.standalone() {
-                    self.diagnostic(
-                        ParserDiagnostic::StandaloneTypeUsedInIntersectionType,
-                        Severity::Error,
-                        last_ampersand,
-                    );
-                }
+
+                self.check_intersection_type_member(&ty, member_span);
 
                 ty
             };
 
             types.push(ty);
+            spans.push(member_span);
 
             if self.current_kind() == TokenKind::Ampersand
                 && !matches!(
@@ -1168,12 +1426,54 @@ impl<'a> Parser<'a> {
                     TokenKind::Variable | TokenKind::Ellipsis | TokenKind::Ampersand
                 )
             {
-                last_ampersand = self.skip(TokenKind::Ampersand);
+                self.skip(TokenKind::Ampersand);
             } else {
                 break;
             }
         }
 
+        self.check_duplicate_types(&types, &spans);
+
         Type::Intersection(types)
     }
+
+    /// Check that a single member of a native type is allowed in the current `TypeContext`.
+    fn check_type_in_context(&mut self, ty: &Type<ResolvedName>, span: Span) {
+        if self.is_in_docblock() {
+            return;
+        }
+
+        if *ty == Type::ParentReference
+            && self
+                .classlikes
+                .last()
+                .is_some_and(|classlike| !classlike.has_parent)
+        {
+            self.diagnostic(
+                ParserDiagnostic::ParentUsedInClassWithoutParent,
+                Severity::Error,
+                span,
+            );
+        }
+
+        let Some(context) = self.type_context else {
+            return;
+        };
+
+        let diagnostic = match (ty, context) {
+            (_, TypeContext::Return) => return,
+            (Type::Callable | Type::Void | Type::Never, TypeContext::Property) => {
+                ParserDiagnostic::ForbiddenTypeUsedInProperty
+            }
+            (Type::Callable, TypeContext::ClassConstant) => {
+                ParserDiagnostic::CallableCannotBeUsedInClassConstant
+            }
+            (Type::Void, _) => ParserDiagnostic::VoidCanOnlyBeUsedAsReturnType,
+            (Type::Never, _) => ParserDiagnostic::NeverCanOnlyBeUsedAsReturnType,
+            (Type::StaticReference, _) => ParserDiagnostic::StaticCanOnlyBeUsedAsReturnType,
+            _ => return,
+        };
+
+        self.diagnostic(diagnostic, Severity::Error, span);
+    }
 }
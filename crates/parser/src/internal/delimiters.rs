@@ -0,0 +1,178 @@
+use pxp_diagnostics::{Diagnostic, Severity};
+use pxp_span::Span;
+use pxp_token::TokenKind;
+
+use crate::{Parser, ParserDiagnostic};
+
+/// A delimiter that has been opened, but not closed yet.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Delimiter {
+    pub(crate) kind: TokenKind,
+    pub(crate) span: Span,
+}
+
+impl Delimiter {
+    pub(crate) fn closer(&self) -> TokenKind {
+        closer(self.kind).unwrap()
+    }
+}
+
+/// The token that closes the given delimiter, or `None` if it doesn't open one.
+fn closer(kind: TokenKind) -> Option<TokenKind> {
+    match kind {
+        TokenKind::LeftParen => Some(TokenKind::RightParen),
+        TokenKind::LeftBracket | TokenKind::Attribute => Some(TokenKind::RightBracket),
+        TokenKind::LeftBrace | TokenKind::DollarLeftBrace => Some(TokenKind::RightBrace),
+        _ => None,
+    }
+}
+
+/// What a closer that doesn't match the innermost delimiter does instead.
+pub(crate) enum MismatchedCloser {
+    /// It closes a delimiter further out, so the innermost one was never closed.
+    ClosesOuter,
+    /// It doesn't close anything else, so it was most likely meant to close the innermost one.
+    Replaces,
+}
+
+fn is_closer(kind: TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::RightParen | TokenKind::RightBracket | TokenKind::RightBrace
+    )
+}
+
+impl<'a> Parser<'a> {
+    /// Keep track of the delimiters that are open, given the token that is about to be consumed.
+    ///
+    /// A closer that doesn't match the innermost delimiter closes the nearest one that it does
+    /// match, if there is one, since the delimiters in between have already been reported by
+    /// [`Parser::skip`]. A closer that doesn't match anything is left to the rest of the parser.
+    pub(crate) fn track_delimiter(&mut self, kind: TokenKind, span: Span) {
+        if self.in_docblock {
+            return;
+        }
+
+        if closer(kind).is_some() {
+            // The span of `(` includes any whitespace that the lexer skipped while looking for a cast.
+            let width = match kind {
+                TokenKind::Attribute | TokenKind::DollarLeftBrace => 2,
+                _ => 1,
+            };
+
+            self.delimiters.push(Delimiter {
+                kind,
+                span: Span::new(span.start, span.start + width),
+            });
+
+            return;
+        }
+
+        if !is_closer(kind) {
+            return;
+        }
+
+        if let Some(position) = self
+            .delimiters
+            .iter()
+            .rposition(|delimiter| delimiter.closer() == kind)
+        {
+            self.delimiters.truncate(position);
+        }
+    }
+
+    /// Recover from finding a different closer to the one that we were expecting. Returns `None` if
+    /// the current token isn't a closer, or if the innermost delimiter isn't the one being closed.
+    ///
+    /// When the current token closes a delimiter further out, the innermost delimiter was never
+    /// closed and the current token is left for whatever opened the outer one. Otherwise, the current
+    /// token is a mismatched closer and takes the place of the expected one.
+    pub(crate) fn recover_from_mismatched_closer(
+        &mut self,
+        expected: TokenKind,
+    ) -> Option<MismatchedCloser> {
+        let found = self.current_kind();
+        let innermost = self.delimiters.last().copied()?;
+
+        if innermost.closer() != expected || !is_closer(found) {
+            return None;
+        }
+
+        self.delimiters.pop();
+
+        if self
+            .delimiters
+            .iter()
+            .any(|delimiter| delimiter.closer() == found)
+        {
+            self.report_unclosed_delimiter(innermost);
+
+            return Some(MismatchedCloser::ClosesOuter);
+        }
+
+        self.report(
+            Diagnostic::new(
+                ParserDiagnostic::MismatchedDelimiter { expected, found },
+                Severity::Error,
+                self.current_span(),
+            )
+            .with_related(innermost.span, format!("{} opened here", innermost.kind)),
+        );
+
+        Some(MismatchedCloser::Replaces)
+    }
+
+    /// Report the innermost delimiter as unclosed when we reach the end of the file, rather than
+    /// the end of the file itself. Returns `false` if the innermost delimiter isn't closed by the
+    /// expected token, in which case we've lost track of what's being closed.
+    pub(crate) fn recover_from_unclosed_delimiter(&mut self, expected: TokenKind) -> bool {
+        match self.delimiters.last().copied() {
+            Some(innermost) if innermost.closer() == expected => {
+                self.delimiters.pop();
+                self.report_unclosed_delimiter(innermost);
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Report every delimiter that's still open once the whole file has been parsed.
+    pub(crate) fn report_unclosed_delimiters(&mut self) {
+        while let Some(delimiter) = self.delimiters.pop() {
+            self.report_unclosed_delimiter(delimiter);
+        }
+    }
+
+    fn report_unclosed_delimiter(&mut self, delimiter: Delimiter) {
+        self.diagnostic(
+            ParserDiagnostic::UnclosedDelimiter {
+                delimiter: delimiter.kind,
+                expected: delimiter.closer(),
+            },
+            Severity::Error,
+            delimiter.span,
+        );
+    }
+
+    /// Whether a diagnostic about reaching the end of the file is only a symptom of a delimiter
+    /// that's never closed, which is reported by itself instead.
+    pub(crate) fn is_caused_by_unclosed_delimiter(&self, diagnostic: &ParserDiagnostic) -> bool {
+        let unclosed = !self.delimiters.is_empty()
+            || self.diagnostics.iter().any(|diagnostic| {
+                matches!(diagnostic.kind, ParserDiagnostic::UnclosedDelimiter { .. })
+            });
+
+        if !unclosed || !self.is_eof() {
+            return false;
+        }
+
+        match diagnostic {
+            ParserDiagnostic::UnexpectedEndOfFile
+            | ParserDiagnostic::UnexpectedEndOfFileExpected { .. } => true,
+            ParserDiagnostic::ExpectedToken { found, .. }
+            | ParserDiagnostic::UnexpectedToken { token: found } => found.kind == TokenKind::Eof,
+            _ => false,
+        }
+    }
+}
@@ -1,12 +1,17 @@
-use pxp_bytestring::ByteString;
-use pxp_diagnostics::{Diagnostic, DiagnosticKind, Severity};
+use pxp_diagnostics::{
+    Diagnostic, DiagnosticCategory, DiagnosticFix, DiagnosticKind, Severity, TextEdit,
+};
 use pxp_span::Span;
 
-use crate::Parser;
+use crate::{Feature, Parser, PhpVersion};
 
 use std::fmt::Display;
 
+use pxp_ast::literals::EscapeError;
+use pxp_ast::ResolvedName;
+use pxp_bytestring::ByteString;
 use pxp_token::{OwnedToken, TokenKind};
+use pxp_type::Type;
 
 #[derive(Debug, Clone)]
 pub enum ParserDiagnostic {
@@ -68,6 +73,111 @@ pub enum ParserDiagnostic {
     InterfaceCannotUseTraits,
     InterfaceCannotContainConcreteMethods,
     InterfaceMembersMustBePublic,
+    TooManyErrors,
+    DuplicateType {
+        ty: Type<ResolvedName>,
+    },
+    MixedTypeCannotBeCombined,
+    NeverCanOnlyBeUsedAsReturnType,
+    NullableTypeCannotBeCombined,
+    NullCannotBeMarkedAsNullable,
+    TrueAndFalseUsedInUnionType,
+    NonClassTypeUsedInIntersectionType {
+        ty: Type<ResolvedName>,
+    },
+    RelativeTypeOutsideOfClass {
+        ty: Type<ResolvedName>,
+    },
+    EnumCannotHaveProperties,
+    EnumCannotHaveMagicMethod {
+        method: ByteString,
+    },
+    VoidCanOnlyBeUsedAsReturnType,
+    StaticCanOnlyBeUsedAsReturnType,
+    CallableCannotBeUsedInClassConstant,
+    ParentUsedInClassWithoutParent,
+    MissingSemicolon,
+    CannotCreateClosureFromNewExpression,
+    CannotUseNullsafeOperatorInWriteContext,
+    NonConstantExpression,
+    UnexpectedTrailingComma,
+    DeprecatedCast {
+        cast: ByteString,
+        canonical: ByteString,
+    },
+    RemovedCast {
+        cast: ByteString,
+        replacement: Option<ByteString>,
+    },
+    NestedTernaryWithoutParentheses,
+    ExpectedStaticMember {
+        found: OwnedToken,
+    },
+    ClassFetchOnConstant,
+    AbstractMethodWithBody {
+        method: ByteString,
+    },
+    MethodWithoutBody {
+        method: ByteString,
+    },
+    CannotCaptureThis,
+    CannotCaptureSuperglobal {
+        variable: ByteString,
+    },
+    UnknownDeclareDirective {
+        directive: ByteString,
+    },
+    DeclareEncodingMustBeLiteral,
+    DeclareAfterNamespace {
+        directive: ByteString,
+    },
+    InvalidEnumModifier,
+    InvalidInterfaceModifier,
+    InvalidTraitModifier,
+    InvalidEnumCaseModifier,
+    InterfaceMethodCannotBeFinal,
+    InterfaceMethodCannotBeAbstract,
+    AbstractMethodCannotBePrivate,
+    UnsupportedPhpVersion {
+        feature: String,
+        version: PhpVersion,
+    },
+    UnclosedDelimiter {
+        delimiter: TokenKind,
+        expected: TokenKind,
+    },
+    MismatchedDelimiter {
+        expected: TokenKind,
+        found: TokenKind,
+    },
+    InvalidEscapeSequence {
+        error: EscapeError,
+    },
+    DeprecatedSyntax {
+        syntax: String,
+        replacement: String,
+        version: PhpVersion,
+    },
+    RemovedSyntax {
+        syntax: String,
+        replacement: String,
+        version: PhpVersion,
+    },
+    CannotUseArgumentUnpackingAfterNamedArgument,
+    CannotUsePositionalArgumentAfterArgumentUnpacking,
+    DuplicateNamedArgument {
+        name: ByteString,
+    },
+    ElseIfInAlternativeSyntax,
+    UnexpectedTokenAfterDocblockType {
+        token: OwnedToken,
+    },
+    CannotUseEmptyArrayIndexForReading,
+    PromotedPropertyOutsideConstructor,
+    PromotedPropertyInAbstractConstructor,
+    PromotedPropertyRedeclared {
+        property: ByteString,
+    },
 }
 
 impl DiagnosticKind for ParserDiagnostic {
@@ -122,6 +232,60 @@ impl DiagnosticKind for ParserDiagnostic {
             ParserDiagnostic::InterfaceCannotUseTraits => "P049",
             ParserDiagnostic::InterfaceCannotContainConcreteMethods => "P050",
             ParserDiagnostic::InterfaceMembersMustBePublic => "P051",
+            ParserDiagnostic::TooManyErrors => "P052",
+            ParserDiagnostic::DuplicateType { .. } => "P053",
+            ParserDiagnostic::MixedTypeCannotBeCombined => "P054",
+            ParserDiagnostic::NeverCanOnlyBeUsedAsReturnType => "P055",
+            ParserDiagnostic::NullableTypeCannotBeCombined => "P056",
+            ParserDiagnostic::NullCannotBeMarkedAsNullable => "P057",
+            ParserDiagnostic::TrueAndFalseUsedInUnionType => "P058",
+            ParserDiagnostic::NonClassTypeUsedInIntersectionType { .. } => "P059",
+            ParserDiagnostic::RelativeTypeOutsideOfClass { .. } => "P060",
+            ParserDiagnostic::EnumCannotHaveProperties => "P061",
+            ParserDiagnostic::EnumCannotHaveMagicMethod { .. } => "P062",
+            ParserDiagnostic::VoidCanOnlyBeUsedAsReturnType => "P063",
+            ParserDiagnostic::StaticCanOnlyBeUsedAsReturnType => "P064",
+            ParserDiagnostic::CallableCannotBeUsedInClassConstant => "P065",
+            ParserDiagnostic::ParentUsedInClassWithoutParent => "P066",
+            ParserDiagnostic::MissingSemicolon => "P067",
+            ParserDiagnostic::CannotCreateClosureFromNewExpression => "P068",
+            ParserDiagnostic::CannotUseNullsafeOperatorInWriteContext => "P069",
+            ParserDiagnostic::NonConstantExpression => "P070",
+            ParserDiagnostic::UnexpectedTrailingComma => "P071",
+            ParserDiagnostic::DeprecatedCast { .. } => "P072",
+            ParserDiagnostic::RemovedCast { .. } => "P073",
+            ParserDiagnostic::NestedTernaryWithoutParentheses => "P074",
+            ParserDiagnostic::ExpectedStaticMember { .. } => "P075",
+            ParserDiagnostic::ClassFetchOnConstant => "P076",
+            ParserDiagnostic::AbstractMethodWithBody { .. } => "P077",
+            ParserDiagnostic::MethodWithoutBody { .. } => "P078",
+            ParserDiagnostic::CannotCaptureThis => "P079",
+            ParserDiagnostic::CannotCaptureSuperglobal { .. } => "P080",
+            ParserDiagnostic::UnknownDeclareDirective { .. } => "P081",
+            ParserDiagnostic::DeclareEncodingMustBeLiteral => "P082",
+            ParserDiagnostic::DeclareAfterNamespace { .. } => "P083",
+            ParserDiagnostic::InvalidEnumModifier => "P084",
+            ParserDiagnostic::InvalidInterfaceModifier => "P085",
+            ParserDiagnostic::InvalidTraitModifier => "P086",
+            ParserDiagnostic::InvalidEnumCaseModifier => "P087",
+            ParserDiagnostic::InterfaceMethodCannotBeFinal => "P088",
+            ParserDiagnostic::InterfaceMethodCannotBeAbstract => "P089",
+            ParserDiagnostic::AbstractMethodCannotBePrivate => "P090",
+            ParserDiagnostic::UnsupportedPhpVersion { .. } => "P091",
+            ParserDiagnostic::UnclosedDelimiter { .. } => "P092",
+            ParserDiagnostic::MismatchedDelimiter { .. } => "P093",
+            ParserDiagnostic::InvalidEscapeSequence { .. } => "P094",
+            ParserDiagnostic::DeprecatedSyntax { .. } => "P095",
+            ParserDiagnostic::RemovedSyntax { .. } => "P096",
+            ParserDiagnostic::CannotUseArgumentUnpackingAfterNamedArgument => "P097",
+            ParserDiagnostic::CannotUsePositionalArgumentAfterArgumentUnpacking => "P098",
+            ParserDiagnostic::DuplicateNamedArgument { .. } => "P099",
+            ParserDiagnostic::ElseIfInAlternativeSyntax => "P100",
+            ParserDiagnostic::UnexpectedTokenAfterDocblockType { .. } => "P101",
+            ParserDiagnostic::CannotUseEmptyArrayIndexForReading => "P102",
+            ParserDiagnostic::PromotedPropertyOutsideConstructor => "P103",
+            ParserDiagnostic::PromotedPropertyInAbstractConstructor => "P104",
+            ParserDiagnostic::PromotedPropertyRedeclared { .. } => "P105",
         })
     }
 
@@ -224,6 +388,112 @@ impl DiagnosticKind for ParserDiagnostic {
             ParserDiagnostic::InterfaceMembersMustBePublic => {
                 "parser.interface-members-must-be-public"
             }
+            ParserDiagnostic::TooManyErrors => "parser.too-many-errors",
+            ParserDiagnostic::DuplicateType { .. } => "parser.duplicate-type",
+            ParserDiagnostic::MixedTypeCannotBeCombined => "parser.mixed-type-cannot-be-combined",
+            ParserDiagnostic::NeverCanOnlyBeUsedAsReturnType => {
+                "parser.never-can-only-be-used-as-return-type"
+            }
+            ParserDiagnostic::NullableTypeCannotBeCombined => {
+                "parser.nullable-type-cannot-be-combined"
+            }
+            ParserDiagnostic::NullCannotBeMarkedAsNullable => {
+                "parser.null-cannot-be-marked-as-nullable"
+            }
+            ParserDiagnostic::TrueAndFalseUsedInUnionType => {
+                "parser.true-and-false-used-in-union-type"
+            }
+            ParserDiagnostic::NonClassTypeUsedInIntersectionType { .. } => {
+                "parser.non-class-type-used-in-intersection-type"
+            }
+            ParserDiagnostic::RelativeTypeOutsideOfClass { .. } => {
+                "parser.relative-type-outside-of-class"
+            }
+            ParserDiagnostic::EnumCannotHaveProperties => "parser.enum-cannot-have-properties",
+            ParserDiagnostic::EnumCannotHaveMagicMethod { .. } => {
+                "parser.enum-cannot-have-magic-method"
+            }
+            ParserDiagnostic::VoidCanOnlyBeUsedAsReturnType => {
+                "parser.void-can-only-be-used-as-return-type"
+            }
+            ParserDiagnostic::StaticCanOnlyBeUsedAsReturnType => {
+                "parser.static-can-only-be-used-as-return-type"
+            }
+            ParserDiagnostic::CallableCannotBeUsedInClassConstant => {
+                "parser.callable-cannot-be-used-in-class-constant"
+            }
+            ParserDiagnostic::ParentUsedInClassWithoutParent => {
+                "parser.parent-used-in-class-without-parent"
+            }
+            ParserDiagnostic::MissingSemicolon => "parser.missing-semicolon",
+            ParserDiagnostic::CannotCreateClosureFromNewExpression => {
+                "parser.cannot-create-closure-from-new-expression"
+            }
+            ParserDiagnostic::CannotUseNullsafeOperatorInWriteContext => {
+                "parser.cannot-use-nullsafe-operator-in-write-context"
+            }
+            ParserDiagnostic::NonConstantExpression => "parser.non-constant-expression",
+            ParserDiagnostic::UnexpectedTrailingComma => "parser.unexpected-trailing-comma",
+            ParserDiagnostic::DeprecatedCast { .. } => "parser.deprecated-cast",
+            ParserDiagnostic::RemovedCast { .. } => "parser.removed-cast",
+            ParserDiagnostic::NestedTernaryWithoutParentheses => {
+                "parser.nested-ternary-without-parentheses"
+            }
+            ParserDiagnostic::ExpectedStaticMember { .. } => "parser.expected-static-member",
+            ParserDiagnostic::ClassFetchOnConstant => "parser.class-fetch-on-constant",
+            ParserDiagnostic::AbstractMethodWithBody { .. } => "parser.abstract-method-with-body",
+            ParserDiagnostic::MethodWithoutBody { .. } => "parser.method-without-body",
+            ParserDiagnostic::CannotCaptureThis => "parser.cannot-capture-this",
+            ParserDiagnostic::CannotCaptureSuperglobal { .. } => {
+                "parser.cannot-capture-superglobal"
+            }
+            ParserDiagnostic::UnknownDeclareDirective { .. } => "parser.unknown-declare-directive",
+            ParserDiagnostic::DeclareEncodingMustBeLiteral => {
+                "parser.declare-encoding-must-be-literal"
+            }
+            ParserDiagnostic::DeclareAfterNamespace { .. } => "parser.declare-after-namespace",
+            ParserDiagnostic::InvalidEnumModifier => "parser.invalid-enum-modifier",
+            ParserDiagnostic::InvalidInterfaceModifier => "parser.invalid-interface-modifier",
+            ParserDiagnostic::InvalidTraitModifier => "parser.invalid-trait-modifier",
+            ParserDiagnostic::InvalidEnumCaseModifier => "parser.invalid-enum-case-modifier",
+            ParserDiagnostic::InterfaceMethodCannotBeFinal => {
+                "parser.interface-method-cannot-be-final"
+            }
+            ParserDiagnostic::InterfaceMethodCannotBeAbstract => {
+                "parser.interface-method-cannot-be-abstract"
+            }
+            ParserDiagnostic::AbstractMethodCannotBePrivate => {
+                "parser.abstract-method-cannot-be-private"
+            }
+            ParserDiagnostic::UnsupportedPhpVersion { .. } => "parser.unsupported-php-version",
+            ParserDiagnostic::UnclosedDelimiter { .. } => "parser.unclosed-delimiter",
+            ParserDiagnostic::MismatchedDelimiter { .. } => "parser.mismatched-delimiter",
+            ParserDiagnostic::InvalidEscapeSequence { .. } => "parser.invalid-escape-sequence",
+            ParserDiagnostic::DeprecatedSyntax { .. } => "parser.deprecated-syntax",
+            ParserDiagnostic::RemovedSyntax { .. } => "parser.removed-syntax",
+            ParserDiagnostic::CannotUseArgumentUnpackingAfterNamedArgument => {
+                "parser.cannot-use-argument-unpacking-after-named-argument"
+            }
+            ParserDiagnostic::CannotUsePositionalArgumentAfterArgumentUnpacking => {
+                "parser.cannot-use-positional-argument-after-argument-unpacking"
+            }
+            ParserDiagnostic::DuplicateNamedArgument { .. } => "parser.duplicate-named-argument",
+            ParserDiagnostic::ElseIfInAlternativeSyntax => "parser.else-if-in-alternative-syntax",
+            ParserDiagnostic::UnexpectedTokenAfterDocblockType { .. } => {
+                "parser.unexpected-token-after-docblock-type"
+            }
+            ParserDiagnostic::CannotUseEmptyArrayIndexForReading => {
+                "parser.cannot-use-empty-array-index-for-reading"
+            }
+            ParserDiagnostic::PromotedPropertyOutsideConstructor => {
+                "parser.promoted-property-outside-constructor"
+            }
+            ParserDiagnostic::PromotedPropertyInAbstractConstructor => {
+                "parser.promoted-property-in-abstract-constructor"
+            }
+            ParserDiagnostic::PromotedPropertyRedeclared { .. } => {
+                "parser.promoted-property-redeclared"
+            }
         })
     }
 
@@ -393,6 +663,153 @@ impl DiagnosticKind for ParserDiagnostic {
                 }
             }
             ParserDiagnostic::MixedImportTypes => "cannot mix import types".to_string(),
+            ParserDiagnostic::TooManyErrors => {
+                "too many errors, the rest of the file has been skipped".to_string()
+            }
+            ParserDiagnostic::DuplicateType { ty } => {
+                format!("duplicate type {} is redundant", ty)
+            }
+            ParserDiagnostic::MixedTypeCannotBeCombined => {
+                "mixed can only be used as a standalone type".to_string()
+            }
+            ParserDiagnostic::NeverCanOnlyBeUsedAsReturnType => {
+                "never can only be used as a return type".to_string()
+            }
+            ParserDiagnostic::NullableTypeCannotBeCombined => {
+                "nullable type shorthand cannot be combined with union or intersection types"
+                    .to_string()
+            }
+            ParserDiagnostic::NullCannotBeMarkedAsNullable => {
+                "null cannot be marked as nullable".to_string()
+            }
+            ParserDiagnostic::TrueAndFalseUsedInUnionType => {
+                "type contains both true and false, bool should be used instead".to_string()
+            }
+            ParserDiagnostic::NonClassTypeUsedInIntersectionType { ty } => {
+                format!("type {} cannot be part of an intersection type", ty)
+            }
+            ParserDiagnostic::RelativeTypeOutsideOfClass { ty } => {
+                format!("type {} cannot be used outside of a class", ty)
+            }
+            ParserDiagnostic::EnumCannotHaveProperties => {
+                "enums cannot include properties".to_string()
+            }
+            ParserDiagnostic::EnumCannotHaveMagicMethod { method } => {
+                format!("enums cannot include magic method {}", method)
+            }
+            ParserDiagnostic::VoidCanOnlyBeUsedAsReturnType => {
+                "void can only be used as a standalone return type".to_string()
+            }
+            ParserDiagnostic::StaticCanOnlyBeUsedAsReturnType => {
+                "static can only be used as a return type".to_string()
+            }
+            ParserDiagnostic::CallableCannotBeUsedInClassConstant => {
+                "callable cannot be used as the type of a class constant".to_string()
+            }
+            ParserDiagnostic::ParentUsedInClassWithoutParent => {
+                "parent cannot be used when the current class has no parent".to_string()
+            }
+            ParserDiagnostic::MissingSemicolon => "missing semicolon".to_string(),
+            ParserDiagnostic::CannotCreateClosureFromNewExpression => {
+                "cannot create a closure from a `new` expression".to_string()
+            }
+            ParserDiagnostic::CannotUseNullsafeOperatorInWriteContext => {
+                "cannot use nullsafe operator in write context".to_string()
+            }
+            ParserDiagnostic::NonConstantExpression => {
+                "constant expression contains invalid operations".to_string()
+            }
+            ParserDiagnostic::UnexpectedTrailingComma => "unexpected trailing comma".to_string(),
+            ParserDiagnostic::DeprecatedCast { cast, canonical } => format!(
+                "the ({}) cast is deprecated, use ({}) instead",
+                cast, canonical
+            ),
+            ParserDiagnostic::RemovedCast {
+                cast,
+                replacement: Some(replacement),
+            } => format!(
+                "the ({}) cast has been removed, use ({}) instead",
+                cast, replacement
+            ),
+            ParserDiagnostic::RemovedCast {
+                cast,
+                replacement: None,
+            } => format!("the ({}) cast is no longer supported", cast),
+            ParserDiagnostic::NestedTernaryWithoutParentheses => {
+                "nested ternary expressions must be parenthesized".to_string()
+            }
+            ParserDiagnostic::ExpectedStaticMember { found } => format!("unexpected token {}, expected an identifier, a variable, `class` or `{{` after `::`", found.kind),
+            ParserDiagnostic::ClassFetchOnConstant => "`::class` cannot be used on a constant, only on a class name or an object".to_string(),
+            ParserDiagnostic::AbstractMethodWithBody { method } => format!("abstract method {}() cannot contain a body", method),
+            ParserDiagnostic::MethodWithoutBody { method } => format!("non-abstract method {}() must contain a body", method),
+            ParserDiagnostic::CannotCaptureThis => "cannot use $this as a lexical variable".to_string(),
+            ParserDiagnostic::CannotCaptureSuperglobal { variable } => format!("cannot use superglobal {} as a lexical variable", variable),
+            ParserDiagnostic::UnknownDeclareDirective { directive } => format!("unsupported declare directive {}", directive),
+            ParserDiagnostic::DeclareEncodingMustBeLiteral => "declare(encoding=...) must be a literal".to_string(),
+            ParserDiagnostic::DeclareAfterNamespace { directive } => format!("declare({}) must come before the namespace declaration", directive),
+            ParserDiagnostic::InvalidEnumModifier => "invalid enum modifier".to_string(),
+            ParserDiagnostic::InvalidInterfaceModifier => "invalid interface modifier".to_string(),
+            ParserDiagnostic::InvalidTraitModifier => "invalid trait modifier".to_string(),
+            ParserDiagnostic::InvalidEnumCaseModifier => "enum cases cannot have modifiers".to_string(),
+            ParserDiagnostic::InterfaceMethodCannotBeFinal => "interface methods cannot be final".to_string(),
+            ParserDiagnostic::InterfaceMethodCannotBeAbstract => "interface methods are implicitly abstract and cannot be marked abstract".to_string(),
+            ParserDiagnostic::AbstractMethodCannotBePrivate => "abstract methods cannot be private outside of a trait".to_string(),
+            ParserDiagnostic::UnsupportedPhpVersion { feature, version } => format!("{} requires PHP {} or newer", feature, version),
+            ParserDiagnostic::UnclosedDelimiter { delimiter, expected } => format!("unclosed {}, expected a matching {}", delimiter, expected),
+            ParserDiagnostic::MismatchedDelimiter { expected, found } => format!("mismatched closing delimiter {}, expected {}", found, expected),
+            ParserDiagnostic::InvalidEscapeSequence { error } => escape_error_message(error).to_string(),
+            ParserDiagnostic::DeprecatedSyntax { syntax, replacement, version } => format!("{} is deprecated as of PHP {}, {}", syntax, version, replacement),
+            ParserDiagnostic::RemovedSyntax { syntax, replacement, version } => format!("{} was removed in PHP {}, {}", syntax, version, replacement),
+            ParserDiagnostic::CannotUseArgumentUnpackingAfterNamedArgument => "cannot use argument unpacking after named argument".to_string(),
+            ParserDiagnostic::CannotUsePositionalArgumentAfterArgumentUnpacking => "cannot use positional argument after argument unpacking".to_string(),
+            ParserDiagnostic::DuplicateNamedArgument { name } => format!("duplicate named argument {}", name),
+            ParserDiagnostic::ElseIfInAlternativeSyntax => "cannot use `else if` in an if statement that uses the alternative syntax, use `elseif` instead".to_string(),
+            ParserDiagnostic::UnexpectedTokenAfterDocblockType { token } => format!("unexpected token {} after type in docblock", token.kind),
+            ParserDiagnostic::CannotUseEmptyArrayIndexForReading => "cannot use [] for reading, it can only be assigned to".to_string(),
+            ParserDiagnostic::PromotedPropertyOutsideConstructor => "cannot declare promoted property outside a constructor".to_string(),
+            ParserDiagnostic::PromotedPropertyInAbstractConstructor => "cannot declare promoted property in an abstract constructor".to_string(),
+            ParserDiagnostic::PromotedPropertyRedeclared { property } => format!("cannot redeclare property {} as a promoted property", property),
+        }
+    }
+
+    fn get_category(&self) -> DiagnosticCategory {
+        match self {
+            ParserDiagnostic::RelativeTypeOutsideOfClass { .. }
+            | ParserDiagnostic::ParentUsedInClassWithoutParent => {
+                DiagnosticCategory::NameResolution
+            }
+            ParserDiagnostic::DeprecatedCast { .. } | ParserDiagnostic::DeprecatedSyntax { .. } => {
+                DiagnosticCategory::Deprecation
+            }
+            // The value of a constant is never an object, so this always throws at runtime.
+            ParserDiagnostic::ClassFetchOnConstant => DiagnosticCategory::Correctness,
+            _ => DiagnosticCategory::Syntax,
+        }
+    }
+
+    fn get_fix(&self, span: Span) -> Option<DiagnosticFix> {
+        match self {
+            ParserDiagnostic::MissingSemicolon => Some(DiagnosticFix::new(
+                "insert a semicolon",
+                vec![TextEdit::insert(span.start, ";")],
+            )),
+            ParserDiagnostic::UnexpectedTrailingComma => Some(DiagnosticFix::new(
+                "remove the trailing comma",
+                vec![TextEdit::delete(span)],
+            )),
+            ParserDiagnostic::DeprecatedCast { canonical, .. }
+            | ParserDiagnostic::RemovedCast {
+                replacement: Some(canonical),
+                ..
+            } => Some(DiagnosticFix::new(
+                format!("use ({}) instead", canonical),
+                vec![TextEdit::new(span, format!("({})", canonical))],
+            )),
+            ParserDiagnostic::ElseIfInAlternativeSyntax => Some(DiagnosticFix::new(
+                "use elseif instead",
+                vec![TextEdit::new(span, "elseif")],
+            )),
+            _ => None,
         }
     }
 }
@@ -569,10 +986,121 @@ impl Display for ParserDiagnostic {
                 }
             }
             ParserDiagnostic::MixedImportTypes => write!(f, "cannot mix import types"),
+            ParserDiagnostic::TooManyErrors => {
+                write!(f, "too many errors, the rest of the file has been skipped")
+            }
+            ParserDiagnostic::DuplicateType { ty } => {
+                write!(f, "duplicate type {} is redundant", ty)
+            }
+            ParserDiagnostic::MixedTypeCannotBeCombined => {
+                write!(f, "mixed can only be used as a standalone type")
+            }
+            ParserDiagnostic::NeverCanOnlyBeUsedAsReturnType => {
+                write!(f, "never can only be used as a return type")
+            }
+            ParserDiagnostic::NullableTypeCannotBeCombined => write!(
+                f,
+                "nullable type shorthand cannot be combined with union or intersection types"
+            ),
+            ParserDiagnostic::NullCannotBeMarkedAsNullable => {
+                write!(f, "null cannot be marked as nullable")
+            }
+            ParserDiagnostic::TrueAndFalseUsedInUnionType => write!(
+                f,
+                "type contains both true and false, bool should be used instead"
+            ),
+            ParserDiagnostic::NonClassTypeUsedInIntersectionType { ty } => {
+                write!(f, "type {} cannot be part of an intersection type", ty)
+            }
+            ParserDiagnostic::RelativeTypeOutsideOfClass { ty } => {
+                write!(f, "type {} cannot be used outside of a class", ty)
+            }
+            ParserDiagnostic::EnumCannotHaveProperties => {
+                write!(f, "enums cannot include properties")
+            }
+            ParserDiagnostic::EnumCannotHaveMagicMethod { method } => {
+                write!(f, "enums cannot include magic method {}", method)
+            }
+            ParserDiagnostic::VoidCanOnlyBeUsedAsReturnType => {
+                write!(f, "void can only be used as a standalone return type")
+            }
+            ParserDiagnostic::StaticCanOnlyBeUsedAsReturnType => {
+                write!(f, "static can only be used as a return type")
+            }
+            ParserDiagnostic::CallableCannotBeUsedInClassConstant => {
+                write!(f, "callable cannot be used as the type of a class constant")
+            }
+            ParserDiagnostic::ParentUsedInClassWithoutParent => write!(
+                f,
+                "parent cannot be used when the current class has no parent"
+            ),
+            ParserDiagnostic::MissingSemicolon => write!(f, "missing semicolon"),
+            ParserDiagnostic::CannotCreateClosureFromNewExpression => {
+                write!(f, "cannot create a closure from a `new` expression")
+            }
+            ParserDiagnostic::CannotUseNullsafeOperatorInWriteContext => {
+                write!(f, "cannot use nullsafe operator in write context")
+            }
+            ParserDiagnostic::NonConstantExpression => {
+                write!(f, "constant expression contains invalid operations")
+            }
+            ParserDiagnostic::UnexpectedTrailingComma => write!(f, "unexpected trailing comma"),
+            ParserDiagnostic::DeprecatedCast { cast, canonical } => write!(
+                f,
+                "the ({}) cast is deprecated, use ({}) instead",
+                cast, canonical
+            ),
+            ParserDiagnostic::RemovedCast { .. } => write!(f, "{}", self.get_message()),
+            ParserDiagnostic::NestedTernaryWithoutParentheses => {
+                write!(f, "nested ternary expressions must be parenthesized")
+            }
+            ParserDiagnostic::ExpectedStaticMember { found } => write!(f, "unexpected token {}, expected an identifier, a variable, `class` or `{{` after `::`", found.kind),
+            ParserDiagnostic::ClassFetchOnConstant => write!(f, "`::class` cannot be used on a constant, only on a class name or an object"),
+            ParserDiagnostic::AbstractMethodWithBody { method } => write!(f, "abstract method {}() cannot contain a body", method),
+            ParserDiagnostic::MethodWithoutBody { method } => write!(f, "non-abstract method {}() must contain a body", method),
+            ParserDiagnostic::CannotCaptureThis => write!(f, "cannot use $this as a lexical variable"),
+            ParserDiagnostic::CannotCaptureSuperglobal { variable } => write!(f, "cannot use superglobal {} as a lexical variable", variable),
+            ParserDiagnostic::UnknownDeclareDirective { directive } => write!(f, "unsupported declare directive {}", directive),
+            ParserDiagnostic::DeclareEncodingMustBeLiteral => write!(f, "declare(encoding=...) must be a literal"),
+            ParserDiagnostic::DeclareAfterNamespace { directive } => write!(f, "declare({}) must come before the namespace declaration", directive),
+            ParserDiagnostic::InvalidEnumModifier => write!(f, "invalid enum modifier"),
+            ParserDiagnostic::InvalidInterfaceModifier => write!(f, "invalid interface modifier"),
+            ParserDiagnostic::InvalidTraitModifier => write!(f, "invalid trait modifier"),
+            ParserDiagnostic::InvalidEnumCaseModifier => write!(f, "enum cases cannot have modifiers"),
+            ParserDiagnostic::InterfaceMethodCannotBeFinal => write!(f, "interface methods cannot be final"),
+            ParserDiagnostic::InterfaceMethodCannotBeAbstract => write!(f, "interface methods are implicitly abstract and cannot be marked abstract"),
+            ParserDiagnostic::AbstractMethodCannotBePrivate => write!(f, "abstract methods cannot be private outside of a trait"),
+            ParserDiagnostic::UnsupportedPhpVersion { feature, version } => write!(f, "{} requires PHP {} or newer", feature, version),
+            ParserDiagnostic::UnclosedDelimiter { delimiter, expected } => write!(f, "unclosed {}, expected a matching {}", delimiter, expected),
+            ParserDiagnostic::MismatchedDelimiter { expected, found } => write!(f, "mismatched closing delimiter {}, expected {}", found, expected),
+            ParserDiagnostic::InvalidEscapeSequence { error } => write!(f, "{}", escape_error_message(error)),
+            ParserDiagnostic::DeprecatedSyntax { .. }
+            | ParserDiagnostic::RemovedSyntax { .. }
+            | ParserDiagnostic::CannotUseArgumentUnpackingAfterNamedArgument
+            | ParserDiagnostic::CannotUsePositionalArgumentAfterArgumentUnpacking
+            | ParserDiagnostic::DuplicateNamedArgument { .. }
+            | ParserDiagnostic::ElseIfInAlternativeSyntax
+            | ParserDiagnostic::UnexpectedTokenAfterDocblockType { .. }
+            | ParserDiagnostic::CannotUseEmptyArrayIndexForReading
+            | ParserDiagnostic::PromotedPropertyOutsideConstructor
+            | ParserDiagnostic::PromotedPropertyInAbstractConstructor
+            | ParserDiagnostic::PromotedPropertyRedeclared { .. } => write!(f, "{}", self.get_message()),
         }
     }
 }
 
+fn escape_error_message(error: &EscapeError) -> &'static str {
+    match error {
+        EscapeError::EmptyCodepoint => "empty unicode codepoint escape sequence",
+        EscapeError::InvalidCodepoint => "invalid unicode codepoint escape sequence",
+        EscapeError::CodepointTooLarge => {
+            "unicode codepoint escape sequence is larger than the maximum of U+10FFFF"
+        }
+        EscapeError::OctalOverflow => "octal escape sequence is larger than the maximum of \\377",
+        EscapeError::TrailingBackslash => "trailing backslash doesn't escape anything",
+    }
+}
+
 impl<'a> Parser<'a> {
     pub(crate) fn diagnostic(
         &mut self,
@@ -580,7 +1108,107 @@ impl<'a> Parser<'a> {
         severity: Severity,
         span: Span,
     ) {
-        self.diagnostics
-            .push(Diagnostic::new(diagnostic, severity, span));
+        self.report(Diagnostic::new(diagnostic, severity, span));
+    }
+
+    /// Report a diagnostic that has already been built, e.g. one with related locations.
+    pub(crate) fn report(&mut self, diagnostic: Diagnostic<ParserDiagnostic>) {
+        // Once the error budget has been used up, everything else is noise.
+        if self.exhausted || self.is_caused_by_unclosed_delimiter(&diagnostic.kind) {
+            return;
+        }
+
+        let (severity, span) = (diagnostic.severity, diagnostic.span);
+
+        self.push_diagnostic(diagnostic);
+
+        if severity != Severity::Error {
+            return;
+        }
+
+        self.errors += 1;
+
+        if self
+            .options
+            .max_diagnostics
+            .is_some_and(|max| self.errors >= max)
+        {
+            self.push_diagnostic(Diagnostic::new(
+                ParserDiagnostic::TooManyErrors,
+                Severity::Error,
+                span,
+            ));
+
+            self.exhausted = true;
+            self.lexer.skip_to_eof();
+        }
+    }
+
+    /// Whether the code is being parsed for a version of PHP that's older than the given one.
+    pub(crate) fn targets_php_older_than(&self, version: PhpVersion) -> bool {
+        self.options
+            .php_version
+            .is_some_and(|target| target < version)
+    }
+
+    /// Report a feature that was introduced in a newer version of PHP than the one that the code
+    /// is being parsed for.
+    pub(crate) fn requires_feature(&mut self, feature: Feature, span: Span) {
+        let version = feature.version();
+
+        if self.targets_php_older_than(version) {
+            self.diagnostic(
+                ParserDiagnostic::UnsupportedPhpVersion {
+                    feature: feature.to_string(),
+                    version,
+                },
+                Severity::Error,
+                span,
+            );
+        }
+    }
+
+    /// Report syntax that was deprecated in the given version of PHP and, optionally, removed in a
+    /// later one. Nothing is reported when the code is being parsed for a version of PHP from before
+    /// the deprecation.
+    pub(crate) fn deprecated_syntax(
+        &mut self,
+        syntax: String,
+        replacement: &str,
+        deprecated: PhpVersion,
+        removed: Option<PhpVersion>,
+        span: Span,
+    ) {
+        if self.targets_php_older_than(deprecated) {
+            return;
+        }
+
+        let replacement = replacement.to_string();
+
+        match removed {
+            Some(version) if !self.targets_php_older_than(version) => self.diagnostic(
+                ParserDiagnostic::RemovedSyntax {
+                    syntax,
+                    replacement,
+                    version,
+                },
+                Severity::Error,
+                span,
+            ),
+            _ => self.diagnostic(
+                ParserDiagnostic::DeprecatedSyntax {
+                    syntax,
+                    replacement,
+                    version: deprecated,
+                },
+                Severity::Warning,
+                span,
+            ),
+        }
+    }
+
+    fn push_diagnostic(&mut self, diagnostic: Diagnostic<ParserDiagnostic>) {
+        self.hooks.on_diagnostic(&diagnostic);
+        self.diagnostics.push(diagnostic);
     }
 }
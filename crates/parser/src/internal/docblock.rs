@@ -1,16 +1,17 @@
 use pxp_ast::{
-    DocBlock, DocBlockComment, DocBlockDeprecatedTag, DocBlockExtendsTag, DocBlockGenericTag,
-    DocBlockImplementsTag, DocBlockMethodTag, DocBlockNode, DocBlockParamClosureThisTag,
-    DocBlockParamTag, DocBlockPropertyTag, DocBlockReturnTag, DocBlockTag, DocBlockTagNode,
-    DocBlockTemplateTag, DocBlockTemplateTagValue, DocBlockTextNode, DocBlockUsesTag,
-    DocBlockVarTag, SimpleVariable,
+    DataType, DocBlock, DocBlockComment, DocBlockDeprecatedTag, DocBlockExtendsTag,
+    DocBlockGenericTag, DocBlockImplementsTag, DocBlockMethodTag, DocBlockNode,
+    DocBlockParamClosureThisTag, DocBlockParamTag, DocBlockPropertyTag, DocBlockReturnTag,
+    DocBlockTag, DocBlockTagNode, DocBlockTemplateTag, DocBlockTemplateTagValue, DocBlockTextNode,
+    DocBlockThrowsTag, DocBlockUsesTag, DocBlockVarTag, ResolvedName, SimpleVariable,
 };
 use pxp_bytestring::ByteString;
 use pxp_diagnostics::Severity;
 use pxp_span::{IsSpanned, Span};
 use pxp_token::TokenKind;
+use pxp_type::Type;
 
-use crate::{Parser, ParserDiagnostic};
+use crate::{Classlike, Parser, ParserDiagnostic};
 
 impl<'a> Parser<'a> {
     pub(crate) const fn is_in_docblock(&self) -> bool {
@@ -25,6 +26,49 @@ impl<'a> Parser<'a> {
         self.in_docblock = false;
     }
 
+    /// Resolve a relative type (`self`, `static`, `parent` or `$this`) found in a docblock.
+    ///
+    /// Inside of a named class-like, `self` is resolved to the class itself. The others are
+    /// late-bound, so they are kept as they are and resolved against the receiver later on.
+    pub(crate) fn maybe_resolve_relative_type(
+        &mut self,
+        ty: Type<ResolvedName>,
+        span: Span,
+    ) -> Type<ResolvedName> {
+        if !self.is_in_docblock() {
+            return ty;
+        }
+
+        match self.classlikes.last() {
+            Some(Classlike {
+                name: Some(name), ..
+            }) if ty == Type::SelfReference => Type::Named(ResolvedName {
+                resolved: name.clone(),
+                original: b"self".into(),
+            }),
+            Some(_) => ty,
+            None => {
+                self.relative_docblock_types.push((ty.clone(), span));
+
+                ty
+            }
+        }
+    }
+
+    fn is_classlike_declaration_start(&self) -> bool {
+        matches!(
+            self.current_kind(),
+            TokenKind::Attribute
+                | TokenKind::Abstract
+                | TokenKind::Final
+                | TokenKind::Readonly
+                | TokenKind::Class
+                | TokenKind::Interface
+                | TokenKind::Trait
+                | TokenKind::Enum
+        )
+    }
+
     pub(crate) fn skip_doc_eol(&mut self) {
         if self.current_kind() == TokenKind::PhpDocEol {
             self.next();
@@ -63,11 +107,24 @@ impl<'a> Parser<'a> {
             };
         }
 
+        let relative_types = std::mem::take(&mut self.relative_docblock_types);
         let close_phpdoc = self.skip(TokenKind::ClosePhpDoc);
         let span = start.join(close_phpdoc);
 
         self.exit_docblock();
 
+        // Docblocks attached to a class-like declaration can refer to the class itself,
+        // e.g. `@method static create()`.
+        if !self.is_classlike_declaration_start() {
+            for (ty, span) in relative_types {
+                self.diagnostic(
+                    ParserDiagnostic::RelativeTypeOutsideOfClass { ty },
+                    Severity::Warning,
+                    span,
+                );
+            }
+        }
+
         DocBlockComment {
             id: self.id(),
             span,
@@ -119,6 +176,7 @@ impl<'a> Parser<'a> {
                 self.implements_tag()
             }
             b"@use" | b"@phpstan-use" | b"@template-use" => self.use_tag(),
+            b"@throws" | b"@phpstan-throws" | b"@psalm-throws" => self.throws_tag(),
             b"@deprecated" => self.deprecated_tag(),
             _ => self.generic_tag(),
         };
@@ -347,7 +405,7 @@ impl<'a> Parser<'a> {
 
         self.next();
 
-        let data_type = self.parse_optional_data_type();
+        let data_type = self.parse_docblock_tag_type();
         let variable = self.parse_simple_variable();
         let (text, text_span) = self.read_text_until_eol_or_close();
 
@@ -375,6 +433,7 @@ impl<'a> Parser<'a> {
         self.next();
 
         let r#type = self.parse_data_type();
+        self.skip_invalid_docblock_type(Some(&r#type));
 
         let variable = match self.current_kind() {
             TokenKind::Variable => self.parse_simple_variable(),
@@ -417,9 +476,16 @@ impl<'a> Parser<'a> {
 
         self.next();
 
-        let ampersand = self.optional(TokenKind::Ampersand);
-        let ellipsis = self.optional(TokenKind::Ellipsis);
-        let data_type = self.parse_optional_data_type();
+        let mut ampersand = self.optional(TokenKind::Ampersand);
+        let mut ellipsis = self.optional(TokenKind::Ellipsis);
+        let data_type = self.parse_docblock_tag_type();
+
+        // The modifiers are usually written between the type and the variable.
+        if data_type.is_some() {
+            ampersand = ampersand.or_else(|| self.optional(TokenKind::Ampersand));
+            ellipsis = ellipsis.or_else(|| self.optional(TokenKind::Ellipsis));
+        }
+
         let variable = self.parse_optional_simple_variable();
         let (text, text_span) = self.read_text_until_eol_or_close();
 
@@ -450,7 +516,7 @@ impl<'a> Parser<'a> {
 
         self.next();
 
-        let data_type = self.parse_optional_data_type();
+        let data_type = self.parse_docblock_tag_type();
         let variable = self.parse_optional_simple_variable();
         let (text, text_span) = self.read_text_until_eol_or_close();
 
@@ -479,7 +545,7 @@ impl<'a> Parser<'a> {
 
         self.next();
 
-        let data_type = self.parse_optional_data_type();
+        let data_type = self.parse_docblock_tag_type();
         let (text, text_span) = self.read_text_until_eol_or_close();
 
         DocBlockTag::Return(DocBlockReturnTag {
@@ -497,6 +563,29 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn throws_tag(&mut self) -> DocBlockTag {
+        let tag = self.current().to_owned();
+
+        self.next();
+
+        let data_type = self.parse_docblock_tag_type();
+        let (text, text_span) = self.read_text_until_eol_or_close();
+
+        DocBlockTag::Throws(DocBlockThrowsTag {
+            id: self.id(),
+            span: if let Some(text_span) = text_span {
+                tag.span.join(text_span)
+            } else if data_type.is_some() {
+                tag.span.join(data_type.span())
+            } else {
+                tag.span
+            },
+            tag,
+            data_type,
+            text,
+        })
+    }
+
     fn generic_tag(&mut self) -> DocBlockTag {
         let tag = self.current().to_owned();
 
@@ -518,6 +607,44 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse the type of a tag, e.g. `@param`. Whatever is left of a malformed type is skipped, so
+    /// that the variable and description after it are still parsed.
+    fn parse_docblock_tag_type(&mut self) -> Option<DataType> {
+        let data_type = self.parse_optional_data_type();
+        self.skip_invalid_docblock_type(data_type.as_ref());
+
+        data_type
+    }
+
+    /// Skip the tokens that are stuck to the end of a type, e.g. the extra `>` in
+    /// `array<int, string>>`. A type that's missing has already been reported, so the tokens
+    /// where it should have been are skipped quietly.
+    fn skip_invalid_docblock_type(&mut self, data_type: Option<&DataType>) {
+        let missing = data_type.is_some_and(|data_type| data_type.kind == Type::Missing);
+        let start = self.current().to_owned();
+        let mut end = None;
+
+        while !matches!(
+            self.current_kind(),
+            TokenKind::Eof | TokenKind::PhpDocEol | TokenKind::ClosePhpDoc | TokenKind::Variable
+        ) && (self.current_span().start == self.previous_span.end
+            || (missing && end.is_none()))
+        {
+            end = Some(self.next());
+        }
+
+        match end {
+            Some(end) if !missing => self.diagnostic(
+                ParserDiagnostic::UnexpectedTokenAfterDocblockType {
+                    token: start.clone(),
+                },
+                Severity::Warning,
+                start.span.join(end),
+            ),
+            _ => {}
+        }
+    }
+
     fn parse_docblock_text(&mut self) -> Option<DocBlockTextNode> {
         let (content, span) = self.read_text_until_eol_or_close();
 
@@ -1,9 +1,11 @@
 use crate::internal::diagnostics::ParserDiagnostic;
+use crate::internal::modifiers::{ModifierContext, ModifierTarget};
 use crate::Parser;
 use pxp_ast::StatementKind;
 use pxp_ast::*;
 
 use pxp_diagnostics::Severity;
+use pxp_span::IsSpanned;
 use pxp_span::Span;
 use pxp_token::TokenKind;
 
@@ -87,6 +89,7 @@ impl<'a> Parser<'a> {
 
         let attributes = self.get_attributes();
         if let Some((colon, backed_type)) = backed_type {
+            self.enter_classlike(name.as_resolved().map(|name| name.resolved.clone()), false);
             let left_brace = self.skip_left_brace();
             let members = {
                 let mut members = Vec::new();
@@ -98,6 +101,8 @@ impl<'a> Parser<'a> {
 
                 members
             };
+            let trailing_comments = self.comments();
+            self.exit_classlike();
             let right_brace = self.skip_right_brace();
 
             let body = BackedEnumBody {
@@ -105,6 +110,7 @@ impl<'a> Parser<'a> {
                 span: Span::combine(left_brace, right_brace),
                 left_brace,
                 members,
+                trailing_comments,
                 right_brace,
             };
 
@@ -120,6 +126,7 @@ impl<'a> Parser<'a> {
                 body,
             }))
         } else {
+            self.enter_classlike(name.as_resolved().map(|name| name.resolved.clone()), false);
             let left_brace = self.skip_left_brace();
             let members = {
                 let mut members = Vec::new();
@@ -131,6 +138,8 @@ impl<'a> Parser<'a> {
 
                 members
             };
+            let trailing_comments = self.comments();
+            self.exit_classlike();
             let right_brace = self.skip_right_brace();
 
             let body = UnitEnumBody {
@@ -138,6 +147,7 @@ impl<'a> Parser<'a> {
                 span: Span::combine(left_brace, right_brace),
                 left_brace,
                 members,
+                trailing_comments,
                 right_brace,
             };
 
@@ -155,8 +165,11 @@ impl<'a> Parser<'a> {
 
     fn parse_unit_member(&mut self) -> Option<UnitEnumMember> {
         let _has_attributes = self.gather_attributes();
+        let modifiers = self.collect_modifiers();
 
         if self.current_kind() == TokenKind::Case {
+            self.check_modifiers(ModifierTarget::EnumCase, ModifierContext::Enum, &modifiers);
+
             let attributes = self.get_attributes();
 
             let start = self.next();
@@ -191,13 +204,18 @@ impl<'a> Parser<'a> {
             }));
         }
 
-        Some(UnitEnumMember::Classish(self.parse_classish_member(false)))
+        let member = self.parse_enum_classish_member(modifiers);
+
+        Some(UnitEnumMember::Classish(member))
     }
 
     fn parse_backed_member(&mut self) -> Option<BackedEnumMember> {
         let _has_attributes = self.gather_attributes();
+        let modifiers = self.collect_modifiers();
 
         if self.current_kind() == TokenKind::Case {
+            self.check_modifiers(ModifierTarget::EnumCase, ModifierContext::Enum, &modifiers);
+
             let attributes = self.get_attributes();
 
             let case = self.next();
@@ -232,8 +250,65 @@ impl<'a> Parser<'a> {
             }));
         }
 
-        Some(BackedEnumMember::Classish(
-            self.parse_classish_member(false),
-        ))
+        let member = self.parse_enum_classish_member(modifiers);
+
+        Some(BackedEnumMember::Classish(member))
+    }
+
+    fn parse_enum_classish_member(&mut self, modifiers: Vec<(Span, TokenKind)>) -> ClassishMember {
+        let member = if modifiers.is_empty() {
+            self.parse_classish_member(ModifierContext::Enum)
+        } else {
+            self.parse_modified_classish_member(modifiers, ModifierContext::Enum)
+        };
+
+        self.validate_enum_member(&member);
+
+        member
+    }
+
+    /// Enums share the classish member machinery with classes, but PHP forbids a few of those members
+    /// inside of an enum. The member is still kept in the AST so that tooling can see it.
+    fn validate_enum_member(&mut self, member: &ClassishMember) {
+        match member {
+            ClassishMember::Property(property) => {
+                self.diagnostic(
+                    ParserDiagnostic::EnumCannotHaveProperties,
+                    Severity::Error,
+                    property.span(),
+                );
+            }
+            ClassishMember::Method(method)
+                if FORBIDDEN_MAGIC_METHODS
+                    .iter()
+                    .any(|forbidden| method.name.symbol.eq_ignore_ascii_case(forbidden)) =>
+            {
+                self.diagnostic(
+                    ParserDiagnostic::EnumCannotHaveMagicMethod {
+                        method: method.name.symbol.clone(),
+                    },
+                    Severity::Error,
+                    method.name.span,
+                );
+            }
+            _ => {}
+        }
     }
 }
+
+const FORBIDDEN_MAGIC_METHODS: [&[u8]; 14] = [
+    b"__construct",
+    b"__destruct",
+    b"__clone",
+    b"__get",
+    b"__set",
+    b"__unset",
+    b"__isset",
+    b"__toString",
+    b"__debugInfo",
+    b"__serialize",
+    b"__unserialize",
+    b"__sleep",
+    b"__wakeup",
+    b"__set_state",
+];
@@ -1,7 +1,8 @@
 use crate::internal::diagnostics::ParserDiagnostic;
 use crate::internal::precedences::Associativity;
 use crate::internal::precedences::Precedence;
-use crate::Parser;
+use crate::{Feature, Parser, PhpVersion};
+use pxp_ast::visitor::{walk_array_item, walk_expression, Visitor};
 use pxp_ast::Expression;
 use pxp_ast::*;
 use pxp_ast::{
@@ -15,6 +16,7 @@ use pxp_ast::{
     StaticVariableMethodClosureCreationExpression, TernaryExpression,
 };
 
+use pxp_bytestring::ByteString;
 use pxp_diagnostics::Severity;
 use pxp_span::IsSpanned;
 use pxp_span::Span;
@@ -65,7 +67,7 @@ impl<'a> Parser<'a> {
                 break;
             }
 
-            if self.is_postfix(kind) {
+            if self.is_postfix(kind) || self.is_curly_brace_offset(&left, kind) {
                 let lpred = Precedence::postfix(kind);
 
                 if lpred < precedence {
@@ -79,7 +81,11 @@ impl<'a> Parser<'a> {
             if self.is_infix(kind) {
                 let rpred = Precedence::infix(kind);
 
-                if rpred < precedence {
+                // An assignment always binds to the variable on its left, so `!$a = $b` is
+                // parsed as `!($a = $b)` rather than `(!$a) = $b`.
+                if rpred < precedence
+                    && !(rpred == Precedence::Assignment && self.is_assignable(&left))
+                {
                     break;
                 }
 
@@ -99,6 +105,14 @@ impl<'a> Parser<'a> {
                     );
                 }
 
+                if self.is_nested_ternary(&left, kind) {
+                    self.diagnostic(
+                        ParserDiagnostic::NestedTernaryWithoutParentheses,
+                        Severity::Error,
+                        span,
+                    );
+                }
+
                 self.next();
 
                 let op = self.current().to_owned();
@@ -112,21 +126,23 @@ impl<'a> Parser<'a> {
                         if op.kind == TokenKind::Colon {
                             self.next();
 
-                            let r#else = self.parse_expression();
+                            let r#else = self.for_precedence(Precedence::Ternary);
+                            // The missing `then` is empty, rather than covering the colon.
+                            let then = Span::new(op.span.start, op.span.start);
 
                             ExpressionKind::Ternary(Box::new(TernaryExpression {
                                 id: self.id(),
                                 span: Span::combine(left.span, r#else.span),
                                 condition: Box::new(left),
                                 question: span,
-                                then: Box::new(Expression::noop(self.id(), start_span)),
+                                then: Box::new(Expression::noop(self.id(), then)),
                                 colon: op.span,
                                 r#else: Box::new(r#else),
                             }))
                         } else {
                             let then = self.parse_expression();
                             let colon = self.skip_colon();
-                            let r#else = self.parse_expression();
+                            let r#else = self.for_precedence(Precedence::Ternary);
 
                             ExpressionKind::Ternary(Box::new(TernaryExpression {
                                 id: self.id(),
@@ -140,7 +156,7 @@ impl<'a> Parser<'a> {
                         }
                     }
                     TokenKind::QuestionColon => {
-                        let r#else = self.parse_expression();
+                        let r#else = self.for_precedence(Precedence::Ternary);
                         ExpressionKind::ShortTernary(Box::new(ShortTernaryExpression {
                             id: self.id(),
                             span: Span::combine(left.span, r#else.span),
@@ -178,6 +194,7 @@ impl<'a> Parser<'a> {
                         ))
                     }
                     TokenKind::Instanceof if op.kind == TokenKind::Self_ => {
+                        let instanceof = span;
                         let self_span = op.span;
                         self.next();
                         let right = Expression::new(
@@ -195,11 +212,12 @@ impl<'a> Parser<'a> {
                             id: self.id(),
                             span,
                             left: Box::new(left),
-                            instanceof: span,
+                            instanceof,
                             right: Box::new(right),
                         }))
                     }
                     TokenKind::Instanceof if op.kind == TokenKind::Parent => {
+                        let instanceof = span;
                         self.next();
                         let right = Expression::new(
                             self.id(),
@@ -216,7 +234,7 @@ impl<'a> Parser<'a> {
                             id: self.id(),
                             span,
                             left: Box::new(left),
-                            instanceof: span,
+                            instanceof,
                             right: Box::new(right),
                         }))
                     }
@@ -241,6 +259,31 @@ impl<'a> Parser<'a> {
                             right: Box::new(right),
                         }))
                     }
+                    // The right-hand side is always a class name, never a constant.
+                    TokenKind::Instanceof
+                        if matches!(
+                            op.kind,
+                            TokenKind::Identifier
+                                | TokenKind::QualifiedIdentifier
+                                | TokenKind::FullyQualifiedIdentifier
+                        ) =>
+                    {
+                        let name = self.parse_full_name(UseKind::Normal);
+                        let right = Expression::new(
+                            self.id(),
+                            ExpressionKind::Name(Box::new(name)),
+                            op.span,
+                            CommentGroup::default(),
+                        );
+
+                        ExpressionKind::Instanceof(Box::new(InstanceofExpression {
+                            id: self.id(),
+                            span: Span::combine(left.span, right.span),
+                            left: Box::new(left),
+                            instanceof: span,
+                            right: Box::new(right),
+                        }))
+                    }
                     TokenKind::Instanceof if op.kind == TokenKind::Enum => {
                         let enum_span = op.span;
                         self.next();
@@ -748,11 +791,13 @@ impl<'a> Parser<'a> {
                     }
                 };
 
-                let span = Span::combine(start_span, kind.span());
+                let span = kind.span();
 
                 left = Expression::new(self.id(), kind, span, CommentGroup::default());
 
-                self.maybe_shift_assignment_operands(&mut left);
+                if let ExpressionKind::AssignmentOperation(assignment) = &left.kind {
+                    self.check_nullsafe_in_write_context(&assignment.left);
+                }
 
                 continue;
             }
@@ -763,124 +808,104 @@ impl<'a> Parser<'a> {
         left
     }
 
-    fn should_shift_assignment_operands(&self, expr: &Expression) -> bool {
-        match &expr.kind {
-            ExpressionKind::AssignmentOperation(inner) => matches!(
-                &inner.left.kind,
-                ExpressionKind::ComparisonOperation(_)
-                    | ExpressionKind::BitwiseOperation(_)
-                    | ExpressionKind::ArithmeticOperation(_)
-                    | ExpressionKind::LogicalOperation(_)
-            ),
-            _ => false,
+    /// PHP doesn't allow writing to a chain that contains a nullsafe operator, e.g. `$a?->b = 1`,
+    /// since the chain could short-circuit and leave nothing to write to.
+    fn check_nullsafe_in_write_context(&mut self, target: &Expression) {
+        if let Some(question_arrow) = find_nullsafe_operator(target) {
+            self.diagnostic(
+                ParserDiagnostic::CannotUseNullsafeOperatorInWriteContext,
+                Severity::Error,
+                question_arrow,
+            );
         }
     }
 
-    // This is a workaround for a problem somebody reported, but something that
-    // I've found in other PHP parsers too.
-    //
-    // Given the following code:
-    //     true !== $a = true
-    // The precedence system interprets this as:
-    //     (true !== $a) = true
-    // which isn't a valid assignment target.
-    //
-    // This seems to be a downfall of the precedence system and a side effect
-    // of PHP treating assignment as an expression rather than a statement.
-    //
-    // I found a similar piece of logic in the `microsoft/tolerant-php-parser` project,
-    // where they check to see if the expression being created is an assignment operation
-    // and if the left-hand side if a binary operation.
-    //
-    // If it is, then they shift the operands around to fake parentheses, so that the expression
-    // is instead interpreted as:
-    //     true !== ($a = true)
-    //
-    // This is a real mega hack, but it seems to work and should be the only place where
-    // we need to do this sort of trickery-bobbery.
-    fn maybe_shift_assignment_operands(&self, expr: &mut Expression) {
-        if !self.should_shift_assignment_operands(expr) {
-            return;
-        }
+    /// An empty index, e.g. `$a[]`, appends to the array, so PHP only allows it where it's written
+    /// to. Whether it's written to isn't known until the whole expression has been parsed, so the
+    /// statement is checked once it's complete.
+    pub(crate) fn check_empty_array_indexes(&mut self, statement: &Statement) {
+        let mut finder = EmptyArrayIndexFinder::default();
+        finder.visit_statement(statement);
 
-        // At this point, we know that the left-hand side of the expression is an assignment.
-        let (id, assignment_left, kind, assignment_right) = match &expr.kind {
-            ExpressionKind::AssignmentOperation(inner) => (
-                &inner.id,
-                inner.left.as_ref(),
-                &inner.kind,
-                inner.right.as_ref(),
-            ),
-            _ => unreachable!(),
-        };
+        for span in finder.reads {
+            self.diagnostic(
+                ParserDiagnostic::CannotUseEmptyArrayIndexForReading,
+                Severity::Error,
+                span,
+            );
+        }
+    }
 
-        // Given the following AST:
-        // AssignmentOperation {
-        //     left: ComparisonOperation {
-        //         left: true,
-        //         op: !==,
-        //         right: $a
-        //     },
-        //     right: true
-        // }
-        //
-        // We need to transform it into:
-        // ComparisonOperation {
-        //     left: true,
-        //     op: !==,
-        //     right: AssignmentOperation {
-        //         left: $a,
-        //         right: true
-        //     }
-        // }
-
-        // Then we need to get the right-hand side of the comparison, since
-        // this is the real assignment target.
-        let real_assignment_target = match &assignment_left.kind {
-            ExpressionKind::ComparisonOperation(inner) => Some(inner.kind.right()),
-            ExpressionKind::BitwiseOperation(inner) => Some(inner.kind.right()),
-            ExpressionKind::ArithmeticOperation(inner) => inner.kind.right(),
-            ExpressionKind::LogicalOperation(inner) => Some(inner.kind.right()),
-            _ => todo!(),
+    /// Casts have a canonical spelling, e.g. `(int)`. The aliases are deprecated and the `(real)`
+    /// and `(unset)` casts have been removed entirely as of PHP 8.0, after being deprecated in
+    /// PHP 7.4 and PHP 7.2 respectively.
+    fn check_cast_spelling(&mut self, kind: TokenKind, span: Span) {
+        let (cast, canonical) = match kind {
+            TokenKind::IntegerCast => ("integer", "int"),
+            TokenKind::BooleanCast => ("boolean", "bool"),
+            TokenKind::DoubleCast => ("double", "float"),
+            TokenKind::BinaryCast => ("binary", "string"),
+            TokenKind::RealCast if self.targets_php_older_than(PhpVersion::PHP_74) => return,
+            TokenKind::RealCast if self.targets_php_older_than(PhpVersion::PHP_80) => {
+                ("real", "float")
+            }
+            TokenKind::UnsetCast if self.targets_php_older_than(PhpVersion::PHP_80) => {
+                return self.deprecated_syntax(
+                    "the (unset) cast".to_string(),
+                    "assign null instead",
+                    PhpVersion::PHP_72,
+                    None,
+                    span,
+                )
+            }
+            TokenKind::RealCast => {
+                return self.diagnostic(
+                    ParserDiagnostic::RemovedCast {
+                        cast: "real".into(),
+                        replacement: Some("float".into()),
+                    },
+                    Severity::Error,
+                    span,
+                )
+            }
+            TokenKind::UnsetCast => {
+                return self.diagnostic(
+                    ParserDiagnostic::RemovedCast {
+                        cast: "unset".into(),
+                        replacement: None,
+                    },
+                    Severity::Error,
+                    span,
+                )
+            }
+            _ => return,
         };
 
-        if real_assignment_target.is_none() {
-            // This is a bit of a hack, but we can't really do anything about it.
-            // If we can't find the real assignment target, then we can't shift the operands.
-            return;
-        }
-
-        // Then we can create the new right-hand side of the comparison, which will
-        // be an assignment expression.
-        //
-        // This is a bit lengthy since we need to match against the existing assignment to
-        // make sure it's the right type of assignment.
-        let new_right = Expression::new(
-            expr.id,
-            ExpressionKind::AssignmentOperation(Box::new(AssignmentOperationExpression {
-                id: *id,
-                span: Span::default(),
-                left: Box::new(real_assignment_target.cloned().unwrap()),
-                kind: *kind,
-                right: Box::new(assignment_right.clone()),
-            })),
-            Span::default(),
-            CommentGroup::default(),
+        self.diagnostic(
+            ParserDiagnostic::DeprecatedCast {
+                cast: cast.into(),
+                canonical: canonical.into(),
+            },
+            Severity::Warning,
+            span,
         );
+    }
 
-        // Then we need to create the new binary operation, which will replace
-        // the existing assignment operation.
-        let mut new_expression = assignment_left.clone();
+    /// Parse an expression that must be evaluated at compile time, e.g. the default value of a
+    /// parameter. The whole expression is still parsed, but anything that isn't allowed in a
+    /// constant expression is reported.
+    pub(crate) fn parse_constant_expression(&mut self) -> Expression {
+        let expression = self.parse_expression();
 
-        match &mut new_expression.kind {
-            ExpressionKind::ComparisonOperation(inner) => inner.kind.set_right(Box::new(new_right)),
-            ExpressionKind::BitwiseOperation(inner) => inner.kind.set_right(Box::new(new_right)),
-            ExpressionKind::ArithmeticOperation(inner) => inner.kind.set_right(Box::new(new_right)),
-            ExpressionKind::LogicalOperation(inner) => inner.kind.set_right(Box::new(new_right)),
-            _ => unreachable!(),
-        };
+        if let Some(span) = find_non_constant_expression(&expression) {
+            self.diagnostic(
+                ParserDiagnostic::NonConstantExpression,
+                Severity::Error,
+                span,
+            );
+        }
 
-        *expr = new_expression;
+        expression
     }
 
     pub fn attributes(&mut self) -> Expression {
@@ -900,6 +925,9 @@ impl<'a> Parser<'a> {
                     self.current_span(),
                 );
 
+                // Drop the attributes so that they aren't attached to whatever comes next.
+                self.get_attributes();
+
                 Expression::missing(self.id(), self.current_span())
             }
         }
@@ -967,11 +995,20 @@ impl<'a> Parser<'a> {
             }
 
             (TokenKind::Die, _) => {
+                let symbol = self.current_symbol_as_bytestring();
                 let die = self.next();
 
-                let argument = self.parse_single_argument(false, true).map(Box::new);
+                if let Some(closure) =
+                    self.parse_keyword_closure_creation(symbol, die, Feature::ExitClosureCreation)
+                {
+                    return closure;
+                }
+
+                let argument = self.parse_exit_argument();
 
-                let span = Span::combine(die, argument.span());
+                let span = argument
+                    .as_ref()
+                    .map_or(die, |argument| Span::combine(die, argument.span()));
 
                 Expression::new(
                     self.id(),
@@ -987,11 +1024,20 @@ impl<'a> Parser<'a> {
             }
 
             (TokenKind::Exit, _) => {
+                let symbol = self.current_symbol_as_bytestring();
                 let exit = self.next();
 
-                let argument = self.parse_single_argument(false, true).map(Box::new);
+                if let Some(closure) =
+                    self.parse_keyword_closure_creation(symbol, exit, Feature::ExitClosureCreation)
+                {
+                    return closure;
+                }
 
-                let span = Span::combine(exit, argument.span());
+                let argument = self.parse_exit_argument();
+
+                let span = argument
+                    .as_ref()
+                    .map_or(exit, |argument| Span::combine(exit, argument.span()));
 
                 Expression::new(
                     self.id(),
@@ -1045,20 +1091,10 @@ impl<'a> Parser<'a> {
             (TokenKind::Print, _) => {
                 let print = self.next();
 
-                let mut value = None;
-                let mut argument = None;
-
-                if let Some(arg) = self.parse_single_argument(false, true) {
-                    argument = Some(Box::new(arg));
-                } else {
-                    value = Some(Box::new(self.parse_expression()));
-                }
-
-                let span = if let Some(argument) = &argument {
-                    Span::combine(print, argument.span())
-                } else {
-                    Span::combine(print, value.as_ref().unwrap().span())
-                };
+                // `print` isn't a function, so parentheses are part of its operand and
+                // `print ($a) && $b` prints the result of `($a) && $b`.
+                let value = Box::new(self.for_precedence(Precedence::Print));
+                let span = Span::combine(print, value.span);
 
                 Expression::new(
                     self.id(),
@@ -1067,7 +1103,6 @@ impl<'a> Parser<'a> {
                         span,
                         print,
                         value,
-                        argument,
                     })),
                     span,
                     CommentGroup::default(),
@@ -1102,14 +1137,12 @@ impl<'a> Parser<'a> {
                 let name = self.parse_full_name_including_self();
                 let span = name.span;
 
-                let lhs = Expression::new(
+                Expression::new(
                     self.id(),
                     ExpressionKind::Name(Box::new(name)),
                     span,
                     CommentGroup::default(),
-                );
-
-                self.postfix(lhs, TokenKind::DoubleColon)
+                )
             }
 
             (TokenKind::List, _) => self.parse_list_expression(),
@@ -1204,11 +1237,31 @@ impl<'a> Parser<'a> {
             }
 
             (TokenKind::Clone, _) => {
+                let symbol = self.current_symbol_as_bytestring();
                 let start_span = self.next();
 
-                let target = self.for_precedence(Precedence::CloneOrNew);
+                if let Some(closure) = self.parse_keyword_closure_creation(
+                    symbol,
+                    start_span,
+                    Feature::CloneClosureCreation,
+                ) {
+                    return closure;
+                }
+
+                let mut target = self.for_precedence(Precedence::CloneOrNew);
+                let mut parentheses = None;
+
+                // `clone($foo)` is the call form of `clone`. It has always parsed, since the
+                // parentheses can wrap any expression, so it isn't gated by version.
+                if let ExpressionKind::Parenthesized(parenthesized) = target.kind {
+                    parentheses = Some((parenthesized.start, parenthesized.end));
+                    target = *parenthesized.expr;
+                }
 
-                let span = Span::combine(start_span, target.span());
+                let span = Span::combine(
+                    start_span,
+                    parentheses.map_or(target.span(), |(_, end)| end),
+                );
 
                 Expression::new(
                     self.id(),
@@ -1216,7 +1269,9 @@ impl<'a> Parser<'a> {
                         id: self.id(),
                         span,
                         clone: start_span,
+                        left_parenthesis: parentheses.map(|(start, _)| start),
                         target: Box::new(target),
+                        right_parenthesis: parentheses.map(|(_, end)| end),
                     })),
                     span,
                     CommentGroup::default(),
@@ -1311,6 +1366,7 @@ impl<'a> Parser<'a> {
                     self.current_kind(),
                     TokenKind::LiteralSingleQuotedString | TokenKind::LiteralDoubleQuotedString
                 ) {
+                    self.check_double_quoted_escapes();
                     self.next_but_first(|parser| {
                         Expression::new(
                             parser.id(),
@@ -1361,48 +1417,8 @@ impl<'a> Parser<'a> {
                 )
             }
 
-            (TokenKind::Static, _) => {
-                let span = self.next();
-
-                let expression = Expression::new(
-                    self.id(),
-                    ExpressionKind::Static(Box::new(StaticExpression {
-                        id: self.id(),
-                        span,
-                    })),
-                    span,
-                    CommentGroup::default(),
-                );
-
-                self.postfix(expression, TokenKind::DoubleColon)
-            }
-
-            (TokenKind::Self_, _) => {
-                let span = self.next();
-
-                Expression::new(
-                    self.id(),
-                    ExpressionKind::Self_(Box::new(SelfExpression {
-                        id: self.id(),
-                        span,
-                    })),
-                    span,
-                    CommentGroup::default(),
-                )
-            }
-
-            (TokenKind::Parent, _) => {
-                let span = self.next();
-
-                Expression::new(
-                    self.id(),
-                    ExpressionKind::Parent(Box::new(ParentExpression {
-                        id: self.id(),
-                        span,
-                    })),
-                    span,
-                    CommentGroup::default(),
-                )
+            (TokenKind::Self_ | TokenKind::Static | TokenKind::Parent, _) => {
+                self.parse_special_class_target()
             }
 
             (TokenKind::LeftParen, _) => {
@@ -1506,7 +1522,9 @@ impl<'a> Parser<'a> {
 
                         self.next();
 
-                        let resolved = self.strip_leading_namespace_qualifier(&symbol);
+                        let resolved = self
+                            .strip_leading_namespace_qualifier(symbol.as_bytestr())
+                            .to_bytestring();
 
                         Expression::new(
                             self.id(),
@@ -1551,11 +1569,7 @@ impl<'a> Parser<'a> {
                     _ => self.clone_or_new_precedence(),
                 };
 
-                let arguments = if self.current_kind() == TokenKind::LeftParen {
-                    Some(self.parse_argument_list())
-                } else {
-                    None
-                };
+                let arguments = self.parse_new_argument_list();
 
                 let span = if arguments.is_some() {
                     Span::combine(new, arguments.span())
@@ -1673,6 +1687,22 @@ impl<'a> Parser<'a> {
                 )
             }
 
+            (TokenKind::PropertyConstant, _) => {
+                let span = self.current().span;
+                self.next();
+
+                Expression::new(
+                    self.id(),
+                    ExpressionKind::MagicConstant(Box::new(MagicConstantExpression {
+                        id: self.id(),
+                        span,
+                        kind: MagicConstantKind::Property,
+                    })),
+                    span,
+                    CommentGroup::default(),
+                )
+            }
+
             (TokenKind::NamespaceConstant, _) => {
                 let span = self.current().span;
                 self.next();
@@ -1790,6 +1820,7 @@ impl<'a> Parser<'a> {
                 let span = self.current_span();
                 let kind = self.current().into();
 
+                self.check_cast_spelling(self.current_kind(), span);
                 self.next();
 
                 let rhs = self.for_precedence(Precedence::Prefix);
@@ -1820,10 +1851,22 @@ impl<'a> Parser<'a> {
 
                 self.next();
 
-                let right = Box::new(self.for_precedence(Precedence::Prefix));
+                // Increments and decrements only apply to a variable, so `++$a ** 2` is parsed as
+                // `(++$a) ** 2`.
+                let right = Box::new(
+                    if matches!(op, TokenKind::Decrement | TokenKind::Increment) {
+                        self.for_precedence(Precedence::CloneOrNew)
+                    } else {
+                        self.for_precedence(Precedence::Prefix)
+                    },
+                );
                 let right_span = right.span;
                 let span = Span::combine(start_span, right_span);
 
+                if matches!(op, TokenKind::Decrement | TokenKind::Increment) {
+                    self.check_nullsafe_in_write_context(&right);
+                }
+
                 let expr = match op {
                     TokenKind::Minus => ExpressionKind::ArithmeticOperation(Box::new(
                         ArithmeticOperationExpression {
@@ -1988,8 +2031,91 @@ impl<'a> Parser<'a> {
         Expression::missing(self.id(), span)
     }
 
+    /// `exit` and `die` are functions as of PHP 8.4, and `clone` as of PHP 8.5, so `exit(...)`
+    /// creates a closure. The name is parsed like the name of any other function in that case.
+    fn parse_keyword_closure_creation(
+        &mut self,
+        symbol: ByteString,
+        span: Span,
+        feature: Feature,
+    ) -> Option<Expression> {
+        if self.current_kind() != TokenKind::LeftParen
+            || self.peek_kind() != TokenKind::Ellipsis
+            || self.peek_again_kind() != TokenKind::RightParen
+        {
+            return None;
+        }
+
+        let name = Expression::new(
+            self.id(),
+            ExpressionKind::Name(Box::new(Name::resolved(
+                self.id(),
+                symbol.clone(),
+                symbol.clone(),
+                span,
+            ))),
+            span,
+            CommentGroup::default(),
+        );
+
+        let closure = self.postfix(name, TokenKind::LeftParen);
+
+        self.requires_feature(feature, closure.span);
+
+        Some(closure)
+    }
+
+    /// The status passed to `exit` or `die`, which can be a named argument as of PHP 8.4.
+    fn parse_exit_argument(&mut self) -> Option<Box<SingleArgument>> {
+        let argument = self.parse_single_argument(false, false)?;
+
+        if let Some(Argument::Named(named)) = &argument.argument {
+            self.requires_feature(Feature::ExitNamedArgument, named.span);
+        }
+
+        Some(Box::new(argument))
+    }
+
+    /// Parse `self`, `static` or `parent` as the target of a `::`. The member is parsed by
+    /// [`Self::postfix`], the same as for any other target.
+    fn parse_special_class_target(&mut self) -> Expression {
+        let token = self.current().to_owned();
+        let span = self.next();
+        let id = self.id();
+
+        let kind = match token.kind {
+            TokenKind::Self_ => ExpressionKind::Self_(Box::new(SelfExpression {
+                id: self.id(),
+                span,
+            })),
+            TokenKind::Parent => ExpressionKind::Parent(Box::new(ParentExpression {
+                id: self.id(),
+                span,
+            })),
+            _ => {
+                // Unlike `self` and `parent`, `static` can't be used as a constant name.
+                if self.current_kind() != TokenKind::DoubleColon {
+                    self.diagnostic(
+                        ParserDiagnostic::ExpectedToken {
+                            expected: vec![TokenKind::DoubleColon],
+                            found: self.current().to_owned(),
+                        },
+                        Severity::Error,
+                        self.current_span(),
+                    );
+                }
+
+                ExpressionKind::Static(Box::new(StaticExpression {
+                    id: self.id(),
+                    span,
+                }))
+            }
+        };
+
+        Expression::new(id, kind, span, CommentGroup::default())
+    }
+
     fn postfix(&mut self, lhs: Expression, op: TokenKind) -> Expression {
-        let start_span = self.current().span;
         let kind = match op {
             TokenKind::DoubleQuestion => {
                 let double_question = self.current().span;
@@ -2066,17 +2192,40 @@ impl<'a> Parser<'a> {
                     right_bracket,
                 }))
             }
+            TokenKind::LeftBrace => {
+                let left_brace = self.skip_left_brace();
+                let index = self.parse_expression();
+                let right_brace = self.skip_right_brace();
+                let span = Span::combine(lhs.span, right_brace);
+
+                self.deprecated_syntax(
+                    "array and string offset access with curly braces".to_string(),
+                    "use [] instead",
+                    PhpVersion::PHP_74,
+                    Some(PhpVersion::PHP_80),
+                    Span::combine(left_brace, right_brace),
+                );
+
+                ExpressionKind::ArrayIndex(Box::new(ArrayIndexExpression {
+                    id: self.id(),
+                    span,
+                    array: Box::new(lhs),
+                    left_bracket: left_brace,
+                    index: Some(Box::new(index)),
+                    right_bracket: right_brace,
+                }))
+            }
             TokenKind::DoubleColon => {
                 let double_colon = self.skip_double_colon();
 
-                let property = match self.current_kind() {
+                let member = match self.current_kind() {
                     TokenKind::Variable | TokenKind::Dollar | TokenKind::DollarLeftBrace => {
-                        ExpressionKind::Variable(Box::new(self.parse_dynamic_variable()))
+                        StaticMember::Variable(self.parse_dynamic_variable())
                     }
                     _ if self.is_identifier_maybe_reserved(self.current_kind()) => {
-                        ExpressionKind::Identifier(Box::new(Identifier::SimpleIdentifier(
+                        StaticMember::Identifier(Identifier::SimpleIdentifier(
                             self.parse_identifier_maybe_reserved(),
-                        )))
+                        ))
                     }
                     TokenKind::LeftBrace => {
                         let start = self.next();
@@ -2086,41 +2235,46 @@ impl<'a> Parser<'a> {
 
                         let span = Span::new(start.start, end.end);
 
-                        ExpressionKind::Identifier(Box::new(Identifier::DynamicIdentifier(
-                            DynamicIdentifier {
-                                id: self.id(),
-                                span,
-                                expr,
-                            },
-                        )))
+                        StaticMember::Identifier(Identifier::DynamicIdentifier(DynamicIdentifier {
+                            id: self.id(),
+                            span,
+                            expr,
+                        }))
                     }
                     TokenKind::Class => {
                         let symbol = self.current_symbol_as_bytestring();
                         let span = self.next();
 
-                        ExpressionKind::Identifier(Box::new(Identifier::SimpleIdentifier(
+                        StaticMember::Identifier(Identifier::SimpleIdentifier(
                             SimpleIdentifier::new(self.id(), symbol, span),
-                        )))
+                        ))
                     }
                     _ => {
                         self.diagnostic(
-                            ParserDiagnostic::ExpectedToken {
-                                expected: vec![
-                                    TokenKind::LeftBrace,
-                                    TokenKind::Dollar,
-                                    TokenKind::Identifier,
-                                ],
+                            ParserDiagnostic::ExpectedStaticMember {
                                 found: self.current().to_owned(),
                             },
                             Severity::Error,
                             self.current().span,
                         );
 
-                        let span = self.current_span();
-
-                        self.next();
+                        // Only skip the unexpected token if it can't start something else,
+                        // e.g. `Foo::;` should leave the semicolon for the statement.
+                        if !matches!(
+                            self.current_kind(),
+                            TokenKind::SemiColon
+                                | TokenKind::LeftParen
+                                | TokenKind::RightParen
+                                | TokenKind::RightBrace
+                                | TokenKind::Eof
+                        ) {
+                            self.next();
+                        }
 
-                        ExpressionKind::Missing(MissingExpression { id: 0, span })
+                        StaticMember::Identifier(Identifier::missing(
+                            self.id(),
+                            Span::flat(double_colon.end),
+                        ))
                     }
                 };
 
@@ -2144,98 +2298,99 @@ impl<'a> Parser<'a> {
                             right_parenthesis: end,
                         };
 
-                        match property {
-                            ExpressionKind::Identifier(identifier) => {
+                        match member {
+                            StaticMember::Identifier(identifier) => {
                                 ExpressionKind::StaticMethodClosureCreation(Box::new(
                                     StaticMethodClosureCreationExpression {
                                         id: self.id(),
                                         span: Span::combine(lhs.span, placeholder.span),
                                         target: lhs,
                                         double_colon,
-                                        method: *identifier,
+                                        method: identifier,
                                         placeholder,
                                     },
                                 ))
                             }
-                            ExpressionKind::Variable(variable) => {
+                            StaticMember::Variable(variable) => {
                                 ExpressionKind::StaticVariableMethodClosureCreation(Box::new(
                                     StaticVariableMethodClosureCreationExpression {
                                         id: self.id(),
                                         span: Span::combine(lhs.span, placeholder.span),
                                         target: lhs,
                                         double_colon,
-                                        method: *variable,
+                                        method: variable,
                                         placeholder,
                                     },
                                 ))
                             }
-                            _ => unreachable!(),
                         }
                     } else {
                         let arguments = self.parse_argument_list();
 
-                        match property {
-                            ExpressionKind::Identifier(identifier) => {
+                        match member {
+                            StaticMember::Identifier(identifier) => {
                                 ExpressionKind::StaticMethodCall(Box::new(
                                     StaticMethodCallExpression {
                                         id: self.id(),
                                         span: Span::combine(lhs.span, arguments.span),
                                         target: lhs,
                                         double_colon,
-                                        method: *identifier,
+                                        method: identifier,
                                         arguments,
                                     },
                                 ))
                             }
-                            ExpressionKind::Variable(variable) => {
+                            StaticMember::Variable(variable) => {
                                 ExpressionKind::StaticVariableMethodCall(Box::new(
                                     StaticVariableMethodCallExpression {
                                         id: self.id(),
                                         span: Span::combine(lhs.span, arguments.span),
                                         target: lhs,
                                         double_colon,
-                                        method: *variable,
+                                        method: variable,
                                         arguments,
                                     },
                                 ))
                             }
-                            _ => unreachable!(),
                         }
                     }
                 } else {
-                    match property {
-                        ExpressionKind::Identifier(identifier) => {
+                    match member {
+                        StaticMember::Identifier(identifier) => {
+                            let span = if identifier.is_missing() {
+                                Span::combine(lhs.span, double_colon)
+                            } else {
+                                Span::combine(lhs.span, identifier.span())
+                            };
+
+                            // `Foo::BAR::class` parses, but the value of a constant is never an object.
+                            if matches!(lhs.kind, ExpressionKind::ConstantFetch(_))
+                                && matches!(&identifier, Identifier::SimpleIdentifier(identifier) if identifier.symbol.eq_ignore_ascii_case(b"class"))
+                            {
+                                self.diagnostic(
+                                    ParserDiagnostic::ClassFetchOnConstant,
+                                    Severity::Error,
+                                    span,
+                                );
+                            }
+
                             ExpressionKind::ConstantFetch(Box::new(ConstantFetchExpression {
                                 id: self.id(),
-                                span: Span::combine(lhs.span, identifier.span()),
+                                span,
                                 target: lhs,
                                 double_colon,
-                                constant: *identifier,
+                                constant: identifier,
                             }))
                         }
-                        ExpressionKind::Variable(variable) => ExpressionKind::StaticPropertyFetch(
+                        StaticMember::Variable(variable) => ExpressionKind::StaticPropertyFetch(
                             Box::new(StaticPropertyFetchExpression {
                                 id: self.id(),
                                 span: Span::combine(lhs.span, variable.span()),
                                 target: lhs,
                                 double_colon,
-                                property: *variable,
+                                property: variable,
                             }),
                         ),
-                        _ => {
-                            let span = Span::combine(lhs.span, double_colon);
-
-                            ExpressionKind::ConstantFetch(Box::new(ConstantFetchExpression {
-                                id: self.id(),
-                                span,
-                                target: lhs,
-                                double_colon,
-                                constant: Identifier::missing(
-                                    self.id(),
-                                    Span::flat(double_colon.end),
-                                ),
-                            }))
-                        }
                     }
                 }
             }
@@ -2380,6 +2535,8 @@ impl<'a> Parser<'a> {
                 let op = self.current().span;
                 self.next();
 
+                self.check_nullsafe_in_write_context(&lhs);
+
                 ExpressionKind::ArithmeticOperation(Box::new(ArithmeticOperationExpression {
                     id: self.id(),
                     span: Span::combine(lhs.span, op),
@@ -2394,6 +2551,8 @@ impl<'a> Parser<'a> {
                 let op = self.current().span;
                 self.next();
 
+                self.check_nullsafe_in_write_context(&lhs);
+
                 ExpressionKind::ArithmeticOperation(Box::new(ArithmeticOperationExpression {
                     id: self.id(),
                     span: Span::combine(lhs.span, op),
@@ -2407,7 +2566,7 @@ impl<'a> Parser<'a> {
             _ => unreachable!(),
         };
 
-        let span = Span::combine(start_span, kind.span());
+        let span = kind.span();
 
         Expression::new(self.id(), kind, span, CommentGroup::default())
     }
@@ -2476,4 +2635,292 @@ impl<'a> Parser<'a> {
                 | TokenKind::DoubleQuestion
         )
     }
+
+    /// Offsets used to be accessible with curly braces, e.g. `$a{0}`, which was removed in PHP 8.0.
+    /// Only variables and the things that can be chained onto them are accepted, since a brace
+    /// after anything else starts a block.
+    fn is_curly_brace_offset(&self, expression: &Expression, t: TokenKind) -> bool {
+        t == TokenKind::LeftBrace
+            && matches!(
+                expression.kind,
+                ExpressionKind::Variable(_)
+                    | ExpressionKind::ArrayIndex(_)
+                    | ExpressionKind::PropertyFetch(_)
+                    | ExpressionKind::NullsafePropertyFetch(_)
+                    | ExpressionKind::StaticPropertyFetch(_)
+            )
+    }
+
+    fn is_assignable(&self, expression: &Expression) -> bool {
+        matches!(
+            expression.kind,
+            ExpressionKind::Variable(_)
+                | ExpressionKind::ArrayIndex(_)
+                | ExpressionKind::PropertyFetch(_)
+                | ExpressionKind::NullsafePropertyFetch(_)
+                | ExpressionKind::StaticPropertyFetch(_)
+                | ExpressionKind::List(_)
+                | ExpressionKind::Array(_)
+        )
+    }
+
+    /// Since PHP 8, a ternary can only be nested inside another without parentheses
+    /// when both are short ternaries, e.g. `$a ?: $b ?: $c`.
+    fn is_nested_ternary(&self, left: &Expression, kind: TokenKind) -> bool {
+        matches!(
+            (&left.kind, kind),
+            (
+                ExpressionKind::Ternary(_),
+                TokenKind::Question | TokenKind::QuestionColon
+            ) | (ExpressionKind::ShortTernary(_), TokenKind::Question)
+        )
+    }
+}
+
+/// The member on the right-hand side of a `::` operator.
+enum StaticMember {
+    Identifier(Identifier),
+    Variable(Variable),
+}
+
+/// Find the `?->` of the first nullsafe operator in the given chain of property fetches, method calls and array accesses.
+fn find_nullsafe_operator(expression: &Expression) -> Option<Span> {
+    match &expression.kind {
+        ExpressionKind::NullsafePropertyFetch(inner) => Some(inner.question_arrow),
+        ExpressionKind::NullsafeMethodCall(inner) => Some(inner.question_arrow),
+        ExpressionKind::PropertyFetch(inner) => find_nullsafe_operator(&inner.target),
+        ExpressionKind::MethodCall(inner) => find_nullsafe_operator(&inner.target),
+        ExpressionKind::StaticPropertyFetch(inner) => find_nullsafe_operator(&inner.target),
+        ExpressionKind::StaticMethodCall(inner) => find_nullsafe_operator(&inner.target),
+        ExpressionKind::ArrayIndex(inner) => find_nullsafe_operator(&inner.array),
+        _ => None,
+    }
+}
+
+/// Find the first part of the given expression that isn't allowed in a constant expression, e.g. a
+/// variable, an assignment or a function call.
+fn find_non_constant_expression(expression: &Expression) -> Option<Span> {
+    let mut finder = NonConstantExpressionFinder(None);
+    finder.visit_expression(expression);
+    finder.0
+}
+
+struct NonConstantExpressionFinder(Option<Span>);
+
+impl Visitor for NonConstantExpressionFinder {
+    fn visit_expression(&mut self, node: &Expression) {
+        if self.0.is_some() {
+            return;
+        }
+
+        let allowed = match &node.kind {
+            ExpressionKind::ArithmeticOperation(inner) => !matches!(
+                inner.kind,
+                ArithmeticOperationKind::PreIncrement { .. }
+                    | ArithmeticOperationKind::PostIncrement { .. }
+                    | ArithmeticOperationKind::PreDecrement { .. }
+                    | ArithmeticOperationKind::PostDecrement { .. }
+            ),
+            // Strings and `new` expressions are checked through their parts and arguments, so that
+            // `"{$a}"` and `new Foo($a)` are reported at the variable.
+            ExpressionKind::Missing(_)
+            | ExpressionKind::Noop(_)
+            | ExpressionKind::Literal(_)
+            | ExpressionKind::Bool(_)
+            | ExpressionKind::Null(_)
+            | ExpressionKind::MagicConstant(_)
+            | ExpressionKind::InterpolatedString(_)
+            | ExpressionKind::Heredoc(_)
+            | ExpressionKind::Nowdoc(_)
+            | ExpressionKind::Name(_)
+            | ExpressionKind::Identifier(_)
+            | ExpressionKind::Self_(_)
+            | ExpressionKind::Parent(_)
+            | ExpressionKind::Parenthesized(_)
+            | ExpressionKind::Array(_)
+            | ExpressionKind::ArrayIndex(_)
+            | ExpressionKind::BitwiseOperation(_)
+            | ExpressionKind::ComparisonOperation(_)
+            | ExpressionKind::LogicalOperation(_)
+            | ExpressionKind::Concat(_)
+            | ExpressionKind::Ternary(_)
+            | ExpressionKind::ShortTernary(_)
+            | ExpressionKind::Coalesce(_)
+            | ExpressionKind::ConstantFetch(_)
+            | ExpressionKind::PropertyFetch(_)
+            | ExpressionKind::NullsafePropertyFetch(_)
+            | ExpressionKind::New(_) => true,
+            _ => false,
+        };
+
+        if allowed {
+            walk_expression(self, node);
+        } else {
+            self.0 = Some(node.span);
+        }
+    }
+}
+
+/// Finds the empty array indexes that are read from, e.g. `$b = $a[];`.
+///
+/// An empty index can be assigned to, including through a destructuring assignment or a `foreach`
+/// loop, bound by reference, incremented or passed as an argument to a function, method or
+/// constructor, since the argument could be passed by reference. Indexing into it or fetching a property from it is fine as long as the
+/// outer expression is written to, e.g. `$a[][0] = 1`.
+#[derive(Default)]
+struct EmptyArrayIndexFinder {
+    // Whether the next expression that's visited is written to.
+    writing: bool,
+    reads: Vec<Span>,
+}
+
+impl EmptyArrayIndexFinder {
+    fn visit_written(&mut self, expression: &Expression) {
+        self.writing = true;
+        self.visit_expression(expression);
+    }
+
+    /// Visit the arguments of a language construct like `isset()`, which are never passed by
+    /// reference.
+    fn visit_read_arguments<'a>(&mut self, arguments: impl IntoIterator<Item = &'a Argument>) {
+        for argument in arguments {
+            match argument {
+                Argument::Positional(argument) => self.visit_expression(&argument.value),
+                Argument::Named(argument) => self.visit_expression(&argument.value),
+            }
+        }
+    }
+}
+
+impl Visitor for EmptyArrayIndexFinder {
+    fn visit_expression(&mut self, node: &Expression) {
+        let writing = std::mem::take(&mut self.writing);
+
+        match &node.kind {
+            ExpressionKind::ArrayIndex(inner) => {
+                if inner.is_append() && !writing {
+                    self.reads
+                        .push(Span::combine(inner.left_bracket, inner.right_bracket));
+                }
+
+                self.writing = writing;
+                self.visit_expression(&inner.array);
+
+                if let Some(index) = &inner.index {
+                    self.visit_expression(index);
+                }
+            }
+            ExpressionKind::PropertyFetch(inner) => {
+                self.writing = writing;
+                self.visit_expression(&inner.target);
+                self.visit_expression(&inner.property);
+            }
+            // `??=` reads the target before it writes to it.
+            ExpressionKind::AssignmentOperation(inner)
+                if !matches!(inner.kind, AssignmentOperationKind::Coalesce(_)) =>
+            {
+                self.visit_written(&inner.left);
+                self.visit_expression(&inner.right);
+            }
+            ExpressionKind::Reference(inner) => self.visit_written(&inner.right),
+            ExpressionKind::ArithmeticOperation(inner) => match &inner.kind {
+                ArithmeticOperationKind::PreIncrement { right, .. }
+                | ArithmeticOperationKind::PreDecrement { right, .. } => self.visit_written(right),
+                ArithmeticOperationKind::PostIncrement { left, .. }
+                | ArithmeticOperationKind::PostDecrement { left, .. } => self.visit_written(left),
+                _ => walk_expression(self, node),
+            },
+            ExpressionKind::Array(inner) if writing => {
+                for item in inner.items.iter() {
+                    match item {
+                        ArrayItem::Value(item) => self.visit_written(&item.value),
+                        ArrayItem::ReferencedValue(item) => self.visit_written(&item.value),
+                        ArrayItem::KeyValue(item) => {
+                            self.visit_expression(&item.key);
+                            self.visit_written(&item.value);
+                        }
+                        ArrayItem::ReferencedKeyValue(item) => {
+                            self.visit_expression(&item.key);
+                            self.visit_written(&item.value);
+                        }
+                        _ => self.visit_array_item(item),
+                    }
+                }
+            }
+            ExpressionKind::List(inner) => {
+                for entry in inner.items.iter() {
+                    match entry {
+                        ListEntry::Value(entry) => self.visit_written(&entry.value),
+                        ListEntry::KeyValue(entry) => {
+                            self.visit_expression(&entry.key);
+                            self.visit_written(&entry.value);
+                        }
+                        ListEntry::Skipped(_) => {}
+                    }
+                }
+            }
+            _ => walk_expression(self, node),
+        }
+    }
+
+    fn visit_array_item(&mut self, node: &ArrayItem) {
+        match node {
+            ArrayItem::ReferencedValue(item) => self.visit_written(&item.value),
+            ArrayItem::ReferencedKeyValue(item) => {
+                self.visit_expression(&item.key);
+                self.visit_written(&item.value);
+            }
+            _ => walk_array_item(self, node),
+        }
+    }
+
+    fn visit_positional_argument(&mut self, node: &PositionalArgument) {
+        self.visit_written(&node.value);
+    }
+
+    fn visit_named_argument(&mut self, node: &NamedArgument) {
+        self.visit_written(&node.value);
+    }
+
+    fn visit_isset_expression(&mut self, node: &IssetExpression) {
+        self.visit_read_arguments(&node.arguments.arguments);
+    }
+
+    fn visit_unset_expression(&mut self, node: &UnsetExpression) {
+        self.visit_read_arguments(&node.arguments.arguments);
+    }
+
+    fn visit_empty_expression(&mut self, node: &EmptyExpression) {
+        self.visit_read_arguments(&node.argument.argument);
+    }
+
+    fn visit_eval_expression(&mut self, node: &EvalExpression) {
+        self.visit_read_arguments(&node.argument.argument);
+    }
+
+    fn visit_die_expression(&mut self, node: &DieExpression) {
+        if let Some(argument) = &node.argument {
+            self.visit_read_arguments(&argument.argument);
+        }
+    }
+
+    fn visit_exit_expression(&mut self, node: &ExitExpression) {
+        if let Some(argument) = &node.argument {
+            self.visit_read_arguments(&argument.argument);
+        }
+    }
+
+    fn visit_foreach_statement_iterator_value(&mut self, node: &ForeachStatementIteratorValue) {
+        self.visit_expression(&node.expression);
+        self.visit_written(&node.value);
+    }
+
+    fn visit_foreach_statement_iterator_key_and_value(
+        &mut self,
+        node: &ForeachStatementIteratorKeyAndValue,
+    ) {
+        self.visit_expression(&node.expression);
+        self.visit_written(&node.key);
+        self.visit_written(&node.value);
+    }
 }
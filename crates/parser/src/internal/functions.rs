@@ -1,3 +1,4 @@
+use crate::internal::data_type::TypeContext;
 use crate::Parser;
 use pxp_ast::Expression;
 use pxp_ast::ExpressionKind;
@@ -10,6 +11,19 @@ use pxp_token::TokenKind;
 
 use super::diagnostics::ParserDiagnostic;
 
+/// Superglobals can't be captured by a closure, since they're available everywhere.
+const SUPERGLOBALS: [&[u8]; 9] = [
+    b"$GLOBALS",
+    b"$_SERVER",
+    b"$_GET",
+    b"$_POST",
+    b"$_FILES",
+    b"$_COOKIE",
+    b"$_SESSION",
+    b"$_REQUEST",
+    b"$_ENV",
+];
+
 impl<'a> Parser<'a> {
     pub(crate) fn parse_anonymous_function(&mut self) -> Expression {
         let comments = self.comments();
@@ -35,24 +49,7 @@ impl<'a> Parser<'a> {
             let r#use = self.next();
             let left_parenthesis = self.skip_left_parenthesis();
             let variables = self.comma_separated::<ClosureUseVariable>(
-                |parser| {
-                    let use_comments = parser.comments();
-                    let use_ampersand = if parser.current_kind() == TokenKind::Ampersand {
-                        Some(parser.next())
-                    } else {
-                        None
-                    };
-
-                    let var = parser.parse_simple_variable();
-
-                    ClosureUseVariable {
-                        id: parser.id(),
-                        span: var.span,
-                        comments: use_comments,
-                        variable: var,
-                        ampersand: use_ampersand,
-                    }
-                },
+                |parser| parser.parse_closure_use_variable(),
                 TokenKind::RightParen,
             );
 
@@ -75,6 +72,7 @@ impl<'a> Parser<'a> {
         let body_comments = self.comments();
         let left_brace = self.skip_left_brace();
         let statements = self.parse_multiple_statements_until(TokenKind::RightBrace);
+        let trailing_comments = self.comments();
         let right_brace = self.skip_right_brace();
 
         let body = FunctionBody {
@@ -83,6 +81,7 @@ impl<'a> Parser<'a> {
             comments: body_comments,
             left_brace,
             statements,
+            trailing_comments,
             right_brace,
         };
 
@@ -108,6 +107,77 @@ impl<'a> Parser<'a> {
         )
     }
 
+    fn parse_closure_use_variable(&mut self) -> ClosureUseVariable {
+        let comments = self.comments();
+        let ampersand = if self.current_kind() == TokenKind::Ampersand {
+            Some(self.next())
+        } else {
+            None
+        };
+
+        let variable = self.parse_simple_variable();
+
+        if variable.symbol == b"$this" {
+            self.diagnostic(
+                ParserDiagnostic::CannotCaptureThis,
+                Severity::Error,
+                variable.span,
+            );
+        } else if SUPERGLOBALS.contains(&&variable.symbol[..]) {
+            self.diagnostic(
+                ParserDiagnostic::CannotCaptureSuperglobal {
+                    variable: variable.symbol.clone(),
+                },
+                Severity::Warning,
+                variable.span,
+            );
+        }
+
+        // A malformed entry, e.g. `use ($x + 1)`, is skipped up to the next comma or the closing
+        // parenthesis so that the rest of the list can still be parsed.
+        if !matches!(
+            self.current_kind(),
+            TokenKind::Comma | TokenKind::RightParen
+        ) {
+            if !variable.is_missing() {
+                self.diagnostic(
+                    ParserDiagnostic::UnexpectedToken {
+                        token: self.current().to_owned(),
+                    },
+                    Severity::Error,
+                    self.current_span(),
+                );
+            }
+
+            self.skip_closure_use_entry();
+        }
+
+        ClosureUseVariable {
+            id: self.id(),
+            span: Span::combine(ampersand.unwrap_or(variable.span), variable.span),
+            comments,
+            variable,
+            ampersand,
+        }
+    }
+
+    fn skip_closure_use_entry(&mut self) {
+        let mut depth = 0usize;
+
+        while !self.is_eof() {
+            match self.current_kind() {
+                TokenKind::Comma | TokenKind::RightParen | TokenKind::LeftBrace if depth == 0 => {
+                    break
+                }
+                TokenKind::LeftParen | TokenKind::LeftBracket => depth += 1,
+                TokenKind::RightParen | TokenKind::RightBracket => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+
+            self.next();
+        }
+    }
+
     pub(crate) fn parse_arrow_function(&mut self) -> Expression {
         let comments = self.comments();
         let start_span = self.current_span();
@@ -177,6 +247,7 @@ impl<'a> Parser<'a> {
         let body_comments = self.comments();
         let left_brace = self.skip_left_brace();
         let statements = self.parse_multiple_statements_until(TokenKind::RightBrace);
+        let trailing_comments = self.comments();
         let right_brace = self.skip_right_brace();
 
         let body = FunctionBody {
@@ -185,6 +256,7 @@ impl<'a> Parser<'a> {
             comments: body_comments,
             left_brace,
             statements,
+            trailing_comments,
             right_brace,
         };
 
@@ -212,10 +284,19 @@ impl<'a> Parser<'a> {
             None
         };
         let name = self.parse_identifier_maybe_reserved();
-        let parameters = self.parse_method_parameter_list();
+        let promotion = if !name.symbol.eq_ignore_ascii_case(b"__construct") {
+            Promotion::OutsideConstructor
+        } else if modifiers.has_abstract() || self.in_interface() {
+            Promotion::AbstractConstructor
+        } else {
+            Promotion::Allowed
+        };
+        let parameters = self.parse_method_parameter_list(promotion);
         let return_type = self.parse_return_type();
         let body = self.parse_method_body();
 
+        self.check_method_body(&modifiers, &name, &body);
+
         Method {
             id: self.id(),
             span: modifiers.span.join(body.span),
@@ -244,6 +325,7 @@ impl<'a> Parser<'a> {
     fn parse_concrete_method_body(&mut self) -> MethodBodyKind {
         let left_brace = self.expect(TokenKind::LeftBrace);
         let statements = self.parse_multiple_statements_until(TokenKind::RightBrace);
+        let trailing_comments = self.comments();
         let right_brace = self.expect(TokenKind::RightBrace);
 
         MethodBodyKind::Concrete(ConcreteMethodBody {
@@ -251,6 +333,7 @@ impl<'a> Parser<'a> {
             span: Span::combine(left_brace, right_brace),
             left_brace,
             statements,
+            trailing_comments,
             right_brace,
         })
     }
@@ -283,14 +366,61 @@ impl<'a> Parser<'a> {
         }
     }
 
-    fn parse_method_parameter_list(&mut self) -> MethodParameterList {
+    /// Interface methods can never have a body, and other methods must have one unless they're
+    /// abstract. The body is kept as it was written either way, so that it can still be analysed.
+    fn check_method_body(
+        &mut self,
+        modifiers: &MethodModifierGroup,
+        name: &SimpleIdentifier,
+        body: &MethodBody,
+    ) {
+        let (diagnostic, span) = match &body.kind {
+            MethodBodyKind::Concrete(body) if self.in_interface() => (
+                ParserDiagnostic::InterfaceCannotContainConcreteMethods,
+                body.span,
+            ),
+            MethodBodyKind::Concrete(body) if modifiers.has_abstract() => (
+                ParserDiagnostic::AbstractMethodWithBody {
+                    method: name.symbol.clone(),
+                },
+                body.span,
+            ),
+            MethodBodyKind::Abstract(body) if !self.in_interface() && !modifiers.has_abstract() => {
+                (
+                    ParserDiagnostic::MethodWithoutBody {
+                        method: name.symbol.clone(),
+                    },
+                    body.semicolon,
+                )
+            }
+            _ => return,
+        };
+
+        self.diagnostic(diagnostic, Severity::Error, span);
+    }
+
+    fn parse_method_parameter_list(&mut self, promotion: Promotion) -> MethodParameterList {
         let left_parenthesis = self.expect(TokenKind::LeftParen);
         let parameters = self.comma_separated(
-            |parser| parser.parse_method_parameter(),
+            |parser| parser.parse_method_parameter(promotion),
             TokenKind::RightParen,
         );
         let right_parenthesis = self.expect(TokenKind::RightParen);
 
+        self.check_optional_parameters(
+            parameters
+                .iter()
+                .map(|parameter| {
+                    (
+                        &parameter.name,
+                        parameter.data_type.is_some(),
+                        parameter.default.as_ref(),
+                        parameter.ellipsis.is_some(),
+                    )
+                })
+                .collect(),
+        );
+
         MethodParameterList {
             id: self.id(),
             span: Span::combine(left_parenthesis, right_parenthesis),
@@ -300,14 +430,36 @@ impl<'a> Parser<'a> {
         }
     }
 
-    fn parse_method_parameter(&mut self) -> MethodParameter {
+    fn parse_method_parameter(&mut self, promotion: Promotion) -> MethodParameter {
         self.gather_attributes();
 
         let attributes = self.get_attributes();
         let modifiers = self.collect_modifiers();
         let modifiers = self.parse_promoted_property_group(modifiers);
 
-        let ty = self.parse_optional_data_type();
+        if !modifiers.is_empty() {
+            let diagnostic = match promotion {
+                Promotion::Allowed => None,
+                Promotion::OutsideConstructor => {
+                    Some(ParserDiagnostic::PromotedPropertyOutsideConstructor)
+                }
+                Promotion::AbstractConstructor => {
+                    Some(ParserDiagnostic::PromotedPropertyInAbstractConstructor)
+                }
+            };
+
+            if let Some(diagnostic) = diagnostic {
+                self.diagnostic(diagnostic, Severity::Error, modifiers.span);
+            }
+        }
+
+        // Promoted properties follow the rules for property types.
+        let ty = self.parse_optional_data_type_in(if modifiers.is_empty() {
+            TypeContext::Parameter
+        } else {
+            TypeContext::Property
+        });
+
         let ampersand = if self.current_kind() == TokenKind::Ampersand {
             Some(self.next())
         } else {
@@ -331,33 +483,20 @@ impl<'a> Parser<'a> {
             (None, self.parse_simple_variable())
         };
 
-        if !modifiers.is_empty() {
-            match &ty {
-                Some(ty) => {
-                    if ty.includes_callable() || ty.is_bottom() {
-                        self.diagnostic(
-                            ParserDiagnostic::ForbiddenTypeUsedInProperty,
-                            Severity::Error,
-                            ty.get_span(),
-                        );
-                    }
-                }
-                None => {
-                    if let Some(modifier) = modifiers.get_readonly() {
-                        self.diagnostic(
-                            ParserDiagnostic::ReadonlyPropertyMustHaveType,
-                            Severity::Error,
-                            modifier.span(),
-                        );
-                    }
-                }
+        if !modifiers.is_empty() && ty.is_none() {
+            if let Some(modifier) = modifiers.get_readonly() {
+                self.diagnostic(
+                    ParserDiagnostic::ReadonlyPropertyMustHaveType,
+                    Severity::Error,
+                    modifier.span(),
+                );
             }
         }
 
         let default = if self.current_kind() == TokenKind::Equals {
             self.next();
 
-            Some(self.parse_expression())
+            Some(self.parse_constant_expression())
         } else {
             None
         };
@@ -399,7 +538,7 @@ impl<'a> Parser<'a> {
         }
 
         let colon = self.expect(TokenKind::Colon);
-        let data_type = self.parse_data_type();
+        let data_type = self.parse_data_type_in(TypeContext::Return);
 
         Some(ReturnType {
             id: self.id(),
@@ -409,3 +548,12 @@ impl<'a> Parser<'a> {
         })
     }
 }
+
+/// Whether the parameters of a method can be promoted to properties, which is only the case for
+/// the constructor of a class or trait that has a body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Promotion {
+    Allowed,
+    OutsideConstructor,
+    AbstractConstructor,
+}
@@ -420,6 +420,7 @@ impl<'a> Parser<'a> {
                 | TokenKind::TraitConstant
                 | TokenKind::FunctionConstant
                 | TokenKind::MethodConstant
+                | TokenKind::PropertyConstant
                 | TokenKind::LineConstant
                 | TokenKind::FileConstant
                 | TokenKind::DirConstant
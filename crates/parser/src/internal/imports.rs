@@ -1,12 +1,47 @@
+use std::collections::HashMap;
+
 use pxp_ast::{Name, NameKind, NodeId, ResolvedName, UseKind};
-use pxp_bytestring::ByteStr;
-use pxp_diagnostics::Severity;
+use pxp_bytestring::{ByteStr, ByteString};
+use pxp_span::Span;
 use pxp_token::{Token, TokenKind};
 
-use crate::{Parser, ParserDiagnostic};
+use crate::{Import, Parser};
+
+/// Remembers how names were resolved, since a file tends to refer to the same classes many times.
+///
+/// The entries only hold for the namespace and imports that they were resolved with, so the cache
+/// has to be invalidated whenever either of them changes.
+#[derive(Debug, Default)]
+pub(crate) struct NameCache {
+    normal: HashMap<ByteString, Option<ByteString>>,
+    function: HashMap<ByteString, Option<ByteString>>,
+    constant: HashMap<ByteString, Option<ByteString>>,
+}
+
+impl NameCache {
+    pub(crate) fn invalidate(&mut self) {
+        self.normal.clear();
+        self.function.clear();
+        self.constant.clear();
+    }
+
+    fn entries(&mut self, kind: UseKind) -> &mut HashMap<ByteString, Option<ByteString>> {
+        match kind {
+            UseKind::Normal => &mut self.normal,
+            UseKind::Function => &mut self.function,
+            UseKind::Const => &mut self.constant,
+        }
+    }
+}
 
 impl<'a> Parser<'a> {
-    pub(crate) fn add_import(&mut self, kind: &UseKind, name: &ByteStr, alias: Option<&ByteStr>) {
+    pub(crate) fn add_import(
+        &mut self,
+        kind: &UseKind,
+        name: &ByteStr,
+        alias: Option<&ByteStr>,
+        span: Span,
+    ) {
         // We first need to check if the alias has been provided, and if not, create a new
         // symbol using the last part of the name.
         let alias = match alias {
@@ -14,11 +49,16 @@ impl<'a> Parser<'a> {
             None => name.after_last(b'\\'),
         };
 
-        // Then we can insert the import into the hashmap.
-        self.imports
-            .get_mut(kind)
-            .unwrap()
-            .insert(alias.to_bytestring(), name.to_bytestring());
+        // Then we can insert the import into the current namespace.
+        self.names.get_mut().invalidate();
+        self.context.current_mut().import(
+            *kind,
+            alias.to_bytestring(),
+            Import {
+                name: name.to_bytestring(),
+                span,
+            },
+        );
     }
 
     pub(crate) fn add_prefixed_import(
@@ -27,10 +67,11 @@ impl<'a> Parser<'a> {
         prefix: &ByteStr,
         name: &ByteStr,
         alias: Option<&ByteStr>,
+        span: Span,
     ) {
         let coagulated = prefix.coagulate(&[name], b'\\');
 
-        self.add_import(kind, coagulated.as_bytestr(), alias);
+        self.add_import(kind, coagulated.as_bytestr(), alias, span);
     }
 
     pub(crate) fn resolve_identifier(
@@ -56,65 +97,43 @@ impl<'a> Parser<'a> {
         token: &Token,
         kind: UseKind,
     ) -> Name {
-        let part = match &token.kind {
-            TokenKind::Identifier | TokenKind::Enum | TokenKind::From => token.symbol,
-            TokenKind::QualifiedIdentifier => token.symbol.before_first(b'\\'),
-            _ if self.is_soft_reserved_identifier(token.kind) => token.symbol,
+        match &token.kind {
+            TokenKind::Identifier
+            | TokenKind::Enum
+            | TokenKind::From
+            | TokenKind::QualifiedIdentifier => {}
+            _ if self.is_soft_reserved_identifier(token.kind) => {}
             _ => unreachable!("{:?}", token.kind),
         };
 
-        let map = self.imports.get(&kind).unwrap();
-
-        // We found an import that matches the first part of the identifier, so we can resolve it.
-        if let Some(imported) = map.get(&part.to_bytestring()) {
-            match &token.kind {
-                TokenKind::Identifier | TokenKind::From | TokenKind::Enum => Name::resolved(
-                    id,
-                    imported.clone(),
-                    token.symbol.to_bytestring(),
-                    token.span,
-                ),
-                TokenKind::QualifiedIdentifier => {
-                    // Qualified identifiers might be aliased, so we need to take the full un-aliased import and
-                    // concatenate that with everything after the first part of the qualified identifier.
-                    let rest = token.symbol.after_first(b'\\');
-                    let coagulated = imported.as_bytestr().coagulate(&[rest], b'\\');
-
-                    Name::resolved(id, coagulated, token.symbol.to_bytestring(), token.span)
-                }
-                _ => unreachable!(),
+        match self.resolve_symbol(token.symbol, kind) {
+            Some(resolved) => {
+                Name::resolved(id, resolved, token.symbol.to_bytestring(), token.span)
             }
-        // We didn't find an import, but since we're trying to resolve the name of a class like, we can
-        // follow PHP's name resolution rules and just prepend the current namespace.
-        //
-        // Additionally, if the name we're trying to resolve is qualified, then PHP's name resolution rules say that
-        // we should just prepend the current namespace if the import map doesn't contain the first part.
-        } else if kind == UseKind::Normal || token.kind == TokenKind::QualifiedIdentifier {
-            Name::resolved(
-                id,
-                self.join_with_namespace(&token.symbol.to_bytestring()),
-                token.symbol.to_bytestring(),
-                token.span,
-            )
-        // Unqualified names in the global namespace can be resolved without any imports, since we can
-        // only be referencing something else inside of the global namespace.
-        } else if (kind == UseKind::Function || kind == UseKind::Const)
-            && token.kind == TokenKind::Identifier
-            && self.namespace().is_none()
-        {
-            Name::resolved(
-                id,
-                token.symbol.to_bytestring(),
-                token.symbol.to_bytestring(),
-                token.span,
-            )
-        } else {
-            Name::unresolved(
+            None => Name::unresolved(
                 id,
                 token.symbol.to_bytestring(),
                 token.kind.into(),
                 token.span,
-            )
+            ),
         }
     }
+
+    fn resolve_symbol(&self, symbol: &ByteStr, kind: UseKind) -> Option<ByteString> {
+        if !self.options.cache_names {
+            return self.context.current().resolve(symbol, kind);
+        }
+
+        let mut names = self.names.borrow_mut();
+        let entries = names.entries(kind);
+
+        if let Some(resolved) = entries.get(symbol) {
+            return resolved.clone();
+        }
+
+        let resolved = self.context.current().resolve(symbol, kind);
+        entries.insert(symbol.to_bytestring(), resolved.clone());
+
+        resolved
+    }
 }
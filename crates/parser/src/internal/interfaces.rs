@@ -8,6 +8,7 @@ use pxp_span::Span;
 use pxp_token::TokenKind;
 
 use super::diagnostics::ParserDiagnostic;
+use super::modifiers::ModifierContext;
 
 impl<'a> Parser<'a> {
     pub fn parse_interface(&mut self) -> StatementKind {
@@ -21,9 +22,10 @@ impl<'a> Parser<'a> {
 
             self.next();
 
-            let parents = self.at_least_one_comma_separated_no_trailing::<Name>(|parser| {
-                parser.parse_full_name(UseKind::Normal)
-            });
+            let parents = self.at_least_one_comma_separated_no_trailing::<Name>(
+                |parser| parser.parse_full_name(UseKind::Normal),
+                TokenKind::LeftBrace,
+            );
 
             Some(InterfaceExtends {
                 id: self.id(),
@@ -37,43 +39,29 @@ impl<'a> Parser<'a> {
 
         let attributes = self.get_attributes();
 
+        self.enter_interface(name.as_resolved().map(|name| name.resolved.clone()));
         let left_brace = self.skip_left_brace();
         let members = {
             let mut members = Vec::new();
 
             while !self.is_eof() && self.current_kind() != TokenKind::RightBrace {
-                let member = self.parse_classish_member(true);
+                let member = self.parse_classish_member(ModifierContext::Interface);
 
-                match member {
-                    ClassishMember::TraitUsage(TraitUsage { span, .. }) => {
-                        self.diagnostic(
-                            ParserDiagnostic::InterfaceCannotUseTraits,
-                            Severity::Error,
-                            span,
-                        );
-                    }
-                    ClassishMember::Method(ref method) if method.is_concrete() => {
-                        self.diagnostic(
-                            ParserDiagnostic::InterfaceCannotContainConcreteMethods,
-                            Severity::Error,
-                            span,
-                        );
-                    }
-                    ClassishMember::Property(ref property) if !property.is_public() => {
-                        self.diagnostic(
-                            ParserDiagnostic::InterfaceMembersMustBePublic,
-                            Severity::Error,
-                            property.span(),
-                        );
-                    }
-                    _ => {}
-                };
+                if let ClassishMember::TraitUsage(TraitUsage { span, .. }) = member {
+                    self.diagnostic(
+                        ParserDiagnostic::InterfaceCannotUseTraits,
+                        Severity::Error,
+                        span,
+                    );
+                }
 
                 members.push(member);
             }
 
             members
         };
+        let trailing_comments = self.comments();
+        self.exit_classlike();
         let right_brace = self.skip_right_brace();
 
         let body = InterfaceBody {
@@ -81,6 +69,7 @@ impl<'a> Parser<'a> {
             span: Span::combine(left_brace, right_brace),
             left_brace,
             members,
+            trailing_comments,
             right_brace,
         };
 
@@ -11,7 +11,9 @@ impl<'a> Parser<'a> {
         let kind = match self.current_kind() {
             TokenKind::LiteralInteger => self.next_but_first(|_| LiteralKind::Integer),
             TokenKind::LiteralFloat => self.next_but_first(|_| LiteralKind::Float),
-            TokenKind::LiteralSingleQuotedString | TokenKind::LiteralDoubleQuotedString => {
+            TokenKind::LiteralSingleQuotedString => self.next_but_first(|_| LiteralKind::String),
+            TokenKind::LiteralDoubleQuotedString => {
+                self.check_double_quoted_escapes();
                 self.next_but_first(|_| LiteralKind::String)
             }
             _ => {
@@ -1,6 +1,8 @@
+use crate::internal::diagnostics::ParserDiagnostic;
 use crate::Parser;
 use pxp_ast::StatementKind;
 use pxp_ast::*;
+use pxp_diagnostics::Severity;
 use pxp_span::IsSpanned;
 use pxp_span::Span;
 use pxp_token::TokenKind;
@@ -259,7 +261,10 @@ impl<'a> Parser<'a> {
     fn maybe_parse_loop_level(&mut self) -> Option<Level> {
         let current = &self.current_kind();
 
-        if current == &TokenKind::SemiColon || current == &TokenKind::CloseTag {
+        if current == &TokenKind::SemiColon
+            || current == &TokenKind::CloseTag
+            || current == &TokenKind::Eof
+        {
             None
         } else {
             Some(self.parse_loop_level())
@@ -278,6 +283,24 @@ impl<'a> Parser<'a> {
             });
         }
 
+        if self.current_kind() != TokenKind::LeftParen {
+            self.diagnostic(
+                ParserDiagnostic::ExpectedToken {
+                    expected: vec![TokenKind::LiteralInteger, TokenKind::LeftParen],
+                    found: self.current().to_owned(),
+                },
+                Severity::Error,
+                self.current_span(),
+            );
+
+            let span = Span::flat(self.current_span().start);
+
+            return Level::Literal(LiteralLevel {
+                id: self.id(),
+                literal: Literal::missing(self.id(), span),
+            });
+        }
+
         let (left_parenthesis, level, right_parenthesis) =
             self.parenthesized(|parser| Box::new(parser.parse_loop_level()));
 
@@ -6,6 +6,7 @@ pub(crate) mod comments;
 pub(crate) mod constants;
 pub(crate) mod control_flow;
 pub(crate) mod data_type;
+pub(crate) mod delimiters;
 pub(crate) mod diagnostics;
 pub(crate) mod docblock;
 pub(crate) mod enums;
@@ -6,50 +6,278 @@ use pxp_diagnostics::Severity;
 use pxp_span::{IsSpanned, Span};
 use pxp_token::TokenKind;
 
+/// The kinds of declaration that modifiers can be written on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModifierTarget {
+    Class,
+    Enum,
+    Interface,
+    Trait,
+    Property,
+    PromotedProperty,
+    Method,
+    Constant,
+    EnumCase,
+}
+
+/// Where a modified declaration appears, since some modifiers are only valid in certain class-likes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModifierContext {
+    /// A class-like declaration, which isn't nested inside of another class-like.
+    Statement,
+    Class,
+    AbstractClass,
+    Interface,
+    Enum,
+    Trait,
+    /// The parameter list of a method.
+    Parameter,
+}
+
+/// The modifiers that each target accepts, and the diagnostic for any other modifier.
+const ALLOWED: [(ModifierTarget, &[TokenKind], ParserDiagnostic); 9] = [
+    (
+        ModifierTarget::Class,
+        &[TokenKind::Readonly, TokenKind::Final, TokenKind::Abstract],
+        ParserDiagnostic::InvalidClassModifier,
+    ),
+    (
+        ModifierTarget::Enum,
+        &[],
+        ParserDiagnostic::InvalidEnumModifier,
+    ),
+    (
+        ModifierTarget::Interface,
+        &[],
+        ParserDiagnostic::InvalidInterfaceModifier,
+    ),
+    (
+        ModifierTarget::Trait,
+        &[],
+        ParserDiagnostic::InvalidTraitModifier,
+    ),
+    (
+        ModifierTarget::Property,
+        &[
+            TokenKind::Readonly,
+            TokenKind::Static,
+            TokenKind::Public,
+            TokenKind::Protected,
+            TokenKind::Private,
+            TokenKind::PublicSet,
+            TokenKind::ProtectedSet,
+            TokenKind::PrivateSet,
+        ],
+        ParserDiagnostic::InvalidPropertyModifier,
+    ),
+    (
+        ModifierTarget::PromotedProperty,
+        &[
+            TokenKind::Readonly,
+            TokenKind::Public,
+            TokenKind::Protected,
+            TokenKind::Private,
+            TokenKind::PublicSet,
+            TokenKind::ProtectedSet,
+            TokenKind::PrivateSet,
+        ],
+        ParserDiagnostic::InvalidPropertyModifier,
+    ),
+    (
+        ModifierTarget::Method,
+        &[
+            TokenKind::Final,
+            TokenKind::Abstract,
+            TokenKind::Public,
+            TokenKind::Protected,
+            TokenKind::Private,
+            TokenKind::Static,
+        ],
+        ParserDiagnostic::InvalidMethodModifier,
+    ),
+    (
+        ModifierTarget::Constant,
+        &[
+            TokenKind::Final,
+            TokenKind::Public,
+            TokenKind::Protected,
+            TokenKind::Private,
+        ],
+        ParserDiagnostic::InvalidConstantModifier,
+    ),
+    (
+        ModifierTarget::EnumCase,
+        &[],
+        ParserDiagnostic::InvalidEnumCaseModifier,
+    ),
+];
+
+/// A pair of modifiers that can't be used together on a target, unless it's declared in one of
+/// the excepted contexts. The diagnostic points at whichever of the two was written last.
+struct Exclusive {
+    target: ModifierTarget,
+    modifiers: (TokenKind, TokenKind),
+    except: &'static [ModifierContext],
+    diagnostic: ParserDiagnostic,
+}
+
+const EXCLUSIVE: [Exclusive; 5] = [
+    Exclusive {
+        target: ModifierTarget::Class,
+        modifiers: (TokenKind::Final, TokenKind::Abstract),
+        except: &[],
+        diagnostic: ParserDiagnostic::CannotUseFinalWithAbstract,
+    },
+    Exclusive {
+        target: ModifierTarget::Method,
+        modifiers: (TokenKind::Final, TokenKind::Abstract),
+        except: &[],
+        diagnostic: ParserDiagnostic::CannotUseFinalWithAbstract,
+    },
+    // Traits can require the classes using them to implement a private method.
+    Exclusive {
+        target: ModifierTarget::Method,
+        modifiers: (TokenKind::Abstract, TokenKind::Private),
+        except: &[ModifierContext::Trait],
+        diagnostic: ParserDiagnostic::AbstractMethodCannotBePrivate,
+    },
+    Exclusive {
+        target: ModifierTarget::Property,
+        modifiers: (TokenKind::Static, TokenKind::Readonly),
+        except: &[],
+        diagnostic: ParserDiagnostic::StaticPropertyCannotBeReadonly,
+    },
+    Exclusive {
+        target: ModifierTarget::Constant,
+        modifiers: (TokenKind::Final, TokenKind::Private),
+        except: &[],
+        diagnostic: ParserDiagnostic::CannotUseFinalWithPrivateOnConstant,
+    },
+];
+
+/// Modifiers that a target accepts in general, but not when it's declared in one of the given
+/// contexts.
+struct Forbidden {
+    target: ModifierTarget,
+    modifiers: &'static [TokenKind],
+    contexts: &'static [ModifierContext],
+    diagnostic: ParserDiagnostic,
+}
+
+const FORBIDDEN: [Forbidden; 6] = [
+    Forbidden {
+        target: ModifierTarget::Method,
+        modifiers: &[TokenKind::Abstract],
+        contexts: &[ModifierContext::Class, ModifierContext::Enum],
+        diagnostic: ParserDiagnostic::AbstractMethodInNonAbstractClass,
+    },
+    Forbidden {
+        target: ModifierTarget::Method,
+        modifiers: &[TokenKind::Abstract],
+        contexts: &[ModifierContext::Interface],
+        diagnostic: ParserDiagnostic::InterfaceMethodCannotBeAbstract,
+    },
+    Forbidden {
+        target: ModifierTarget::Method,
+        modifiers: &[TokenKind::Final],
+        contexts: &[ModifierContext::Interface],
+        diagnostic: ParserDiagnostic::InterfaceMethodCannotBeFinal,
+    },
+    Forbidden {
+        target: ModifierTarget::Method,
+        modifiers: &[TokenKind::Protected, TokenKind::Private],
+        contexts: &[ModifierContext::Interface],
+        diagnostic: ParserDiagnostic::InterfaceMembersMustBePublic,
+    },
+    Forbidden {
+        target: ModifierTarget::Property,
+        modifiers: &[TokenKind::Protected, TokenKind::Private],
+        contexts: &[ModifierContext::Interface],
+        diagnostic: ParserDiagnostic::InterfaceMembersMustBePublic,
+    },
+    Forbidden {
+        target: ModifierTarget::Constant,
+        modifiers: &[TokenKind::Protected, TokenKind::Private],
+        contexts: &[ModifierContext::Interface],
+        diagnostic: ParserDiagnostic::InterfaceMembersMustBePublic,
+    },
+];
+
+/// Check a set of modifiers against the rules for the given target and context, returning the
+/// diagnostics along with the span of the offending modifier.
+pub(crate) fn validate_modifiers(
+    target: ModifierTarget,
+    context: ModifierContext,
+    modifiers: &[(Span, TokenKind)],
+) -> Vec<(ParserDiagnostic, Span)> {
+    let mut diagnostics = Vec::new();
+
+    if let Some((_, allowed, diagnostic)) = ALLOWED.iter().find(|(t, ..)| *t == target) {
+        for (span, modifier) in modifiers {
+            if !allowed.contains(modifier) {
+                diagnostics.push((diagnostic.clone(), *span));
+            }
+        }
+    }
+
+    for rule in EXCLUSIVE
+        .iter()
+        .filter(|rule| rule.target == target && !rule.except.contains(&context))
+    {
+        let (first, second) = rule.modifiers;
+        let first = modifiers.iter().position(|(_, kind)| *kind == first);
+        let second = modifiers.iter().position(|(_, kind)| *kind == second);
+
+        if let (Some(first), Some(second)) = (first, second) {
+            diagnostics.push((rule.diagnostic.clone(), modifiers[first.max(second)].0));
+        }
+    }
+
+    for rule in FORBIDDEN
+        .iter()
+        .filter(|rule| rule.target == target && rule.contexts.contains(&context))
+    {
+        for (span, modifier) in modifiers {
+            if rule.modifiers.contains(modifier) {
+                diagnostics.push((rule.diagnostic.clone(), *span));
+            }
+        }
+    }
+
+    diagnostics.sort_by_key(|(_, span)| span.start);
+    diagnostics
+}
+
 impl<'a> Parser<'a> {
     #[inline(always)]
     pub fn parse_class_group(&mut self, input: Vec<(Span, TokenKind)>) -> ClassModifierGroup {
+        self.check_modifiers(ModifierTarget::Class, ModifierContext::Statement, &input);
+
         let modifiers = input
             .iter()
             .filter_map(|(span, token)| match token {
                 TokenKind::Readonly => Some(ClassModifier::Readonly(*span)),
                 TokenKind::Final => Some(ClassModifier::Final(*span)),
                 TokenKind::Abstract => Some(ClassModifier::Abstract(*span)),
-                _ => {
-                    self.diagnostic(
-                        ParserDiagnostic::InvalidClassModifier,
-                        Severity::Error,
-                        *span,
-                    );
-
-                    None
-                }
+                _ => None,
             })
             .collect::<Vec<ClassModifier>>();
 
-        let group = ClassModifierGroup {
+        ClassModifierGroup {
             id: self.id(),
             span: modifiers.span(),
             modifiers,
-        };
-
-        if group.has_abstract() && group.has_final() {
-            let start = input.first().unwrap().0;
-            let end = input.last().unwrap().0;
-            let span = Span::new(start.start, end.end);
-
-            self.diagnostic(
-                ParserDiagnostic::CannotUseFinalWithAbstract,
-                Severity::Error,
-                span,
-            );
         }
-
-        group
     }
 
     #[inline(always)]
-    pub fn parse_method_group(&mut self, input: Vec<(Span, TokenKind)>) -> MethodModifierGroup {
+    pub fn parse_method_group(
+        &mut self,
+        input: Vec<(Span, TokenKind)>,
+        context: ModifierContext,
+    ) -> MethodModifierGroup {
+        self.check_modifiers(ModifierTarget::Method, context, &input);
+
         let modifiers = input
             .iter()
             .filter_map(|(span, token)| match token {
@@ -59,41 +287,25 @@ impl<'a> Parser<'a> {
                 TokenKind::Protected => Some(MethodModifier::Protected(*span)),
                 TokenKind::Public => Some(MethodModifier::Public(*span)),
                 TokenKind::Static => Some(MethodModifier::Static(*span)),
-                _ => {
-                    self.diagnostic(
-                        ParserDiagnostic::InvalidMethodModifier,
-                        Severity::Error,
-                        *span,
-                    );
-
-                    None
-                }
+                _ => None,
             })
             .collect::<Vec<MethodModifier>>();
 
-        let group = MethodModifierGroup {
+        MethodModifierGroup {
             id: self.id(),
             span: modifiers.span(),
             modifiers,
-        };
-
-        if group.has_abstract() && group.has_final() {
-            let start = input.first().unwrap().0;
-            let end = input.last().unwrap().0;
-            let span = Span::new(start.start, end.end);
-
-            self.diagnostic(
-                ParserDiagnostic::CannotUseFinalWithAbstract,
-                Severity::Error,
-                span,
-            );
         }
-
-        group
     }
 
     #[inline(always)]
-    pub fn parse_property_group(&mut self, input: Vec<(Span, TokenKind)>) -> PropertyModifierGroup {
+    pub fn parse_property_group(
+        &mut self,
+        input: Vec<(Span, TokenKind)>,
+        context: ModifierContext,
+    ) -> PropertyModifierGroup {
+        self.check_modifiers(ModifierTarget::Property, context, &input);
+
         let modifiers = input
             .iter()
             .filter_map(|(span, token)| match token {
@@ -105,15 +317,7 @@ impl<'a> Parser<'a> {
                 TokenKind::PrivateSet => Some(PropertyModifier::PrivateSet(*span)),
                 TokenKind::ProtectedSet => Some(PropertyModifier::ProtectedSet(*span)),
                 TokenKind::PublicSet => Some(PropertyModifier::PublicSet(*span)),
-                _ => {
-                    self.diagnostic(
-                        ParserDiagnostic::InvalidPropertyModifier,
-                        Severity::Error,
-                        *span,
-                    );
-
-                    None
-                }
+                _ => None,
             })
             .collect::<Vec<PropertyModifier>>();
 
@@ -129,6 +333,12 @@ impl<'a> Parser<'a> {
         &mut self,
         input: Vec<(Span, TokenKind)>,
     ) -> PromotedPropertyModifierGroup {
+        self.check_modifiers(
+            ModifierTarget::PromotedProperty,
+            ModifierContext::Parameter,
+            &input,
+        );
+
         let modifiers = input
             .iter()
             .filter_map(|(span, token)| match token {
@@ -139,15 +349,7 @@ impl<'a> Parser<'a> {
                 TokenKind::PrivateSet => Some(PromotedPropertyModifier::PrivateSet(*span)),
                 TokenKind::ProtectedSet => Some(PromotedPropertyModifier::ProtectedSet(*span)),
                 TokenKind::PublicSet => Some(PromotedPropertyModifier::PublicSet(*span)),
-                _ => {
-                    self.diagnostic(
-                        ParserDiagnostic::InvalidPropertyModifier,
-                        Severity::Error,
-                        *span,
-                    );
-
-                    None
-                }
+                _ => None,
             })
             .collect::<Vec<PromotedPropertyModifier>>();
 
@@ -158,7 +360,13 @@ impl<'a> Parser<'a> {
         }
     }
 
-    pub fn parse_constant_group(&mut self, input: Vec<(Span, TokenKind)>) -> ConstantModifierGroup {
+    pub fn parse_constant_group(
+        &mut self,
+        input: Vec<(Span, TokenKind)>,
+        context: ModifierContext,
+    ) -> ConstantModifierGroup {
+        self.check_modifiers(ModifierTarget::Constant, context, &input);
+
         let modifiers = input
             .iter()
             .filter_map(|(span, token)| match token {
@@ -166,37 +374,27 @@ impl<'a> Parser<'a> {
                 TokenKind::Public => Some(ConstantModifier::Public(*span)),
                 TokenKind::Private => Some(ConstantModifier::Private(*span)),
                 TokenKind::Final => Some(ConstantModifier::Final(*span)),
-                _ => {
-                    self.diagnostic(
-                        ParserDiagnostic::InvalidConstantModifier,
-                        Severity::Error,
-                        *span,
-                    );
-
-                    None
-                }
+                _ => None,
             })
             .collect::<Vec<ConstantModifier>>();
 
-        let group = ConstantModifierGroup {
+        ConstantModifierGroup {
             id: self.id(),
             span: modifiers.span(),
             modifiers,
-        };
-
-        if group.has_final() && group.has_private() {
-            let start = input.first().unwrap().0;
-            let end = input.last().unwrap().0;
-            let span = Span::new(start.start, end.end);
-
-            self.diagnostic(
-                ParserDiagnostic::CannotUseFinalWithPrivateOnConstant,
-                Severity::Error,
-                span,
-            );
         }
+    }
 
-        group
+    /// Report any modifiers that aren't valid for the given target and context.
+    pub(crate) fn check_modifiers(
+        &mut self,
+        target: ModifierTarget,
+        context: ModifierContext,
+        modifiers: &[(Span, TokenKind)],
+    ) {
+        for (diagnostic, span) in validate_modifiers(target, context, modifiers) {
+            self.diagnostic(diagnostic, Severity::Error, span);
+        }
     }
 
     pub fn collect_modifiers(&mut self) -> Vec<(Span, TokenKind)> {
@@ -13,7 +13,8 @@ impl<'a> Parser<'a> {
                 Name::resolved(
                     parser.id(),
                     parser
-                        .strip_leading_namespace_qualifier(&parser.current_symbol_as_bytestring()),
+                        .strip_leading_namespace_qualifier(parser.current_symbol())
+                        .to_bytestring(),
                     parser.current_symbol_as_bytestring(),
                     parser.current_span(),
                 )
@@ -43,7 +44,7 @@ impl<'a> Parser<'a> {
     pub fn parse_type_name_maybe_soft_reserved(&mut self) -> Name {
         if self.is_soft_reserved_identifier(self.current_kind()) {
             let symbol = self.current_symbol_as_bytestring();
-            let resolved = self.join_with_namespace(&symbol);
+            let resolved = self.join_with_namespace(symbol.as_bytestr()).into_owned();
             let span = self.current_span();
 
             self.next();
@@ -71,7 +72,7 @@ impl<'a> Parser<'a> {
             TokenKind::Identifier | TokenKind::Enum | TokenKind::From => {
                 self.next_but_first(|parser| {
                     let symbol = parser.current_symbol_as_bytestring();
-                    let resolved = parser.join_with_namespace(&symbol);
+                    let resolved = parser.join_with_namespace(symbol.as_bytestr()).into_owned();
 
                     Name::resolved(parser.id(), resolved, symbol, parser.current_span())
                 })
@@ -85,7 +86,7 @@ impl<'a> Parser<'a> {
 
                 self.next_but_first(|parser| {
                     let symbol = parser.current_symbol_as_bytestring();
-                    let resolved = parser.join_with_namespace(&symbol);
+                    let resolved = parser.join_with_namespace(symbol.as_bytestr()).into_owned();
 
                     Name::resolved(parser.id(), resolved, symbol, parser.current_span())
                 })
@@ -99,7 +100,7 @@ impl<'a> Parser<'a> {
 
                 self.next_but_first(|parser| {
                     let symbol = parser.current_symbol_as_bytestring();
-                    let resolved = parser.join_with_namespace(&symbol);
+                    let resolved = parser.join_with_namespace(symbol.as_bytestr()).into_owned();
 
                     Name::resolved(parser.id(), resolved, symbol, parser.current_span())
                 })
@@ -144,7 +145,9 @@ impl<'a> Parser<'a> {
         match self.current_kind() {
             TokenKind::FullyQualifiedIdentifier => self.next_but_first(|parser| {
                 let symbol = parser.current_symbol_as_bytestring();
-                let resolved = parser.strip_leading_namespace_qualifier(&symbol);
+                let resolved = parser
+                    .strip_leading_namespace_qualifier(symbol.as_bytestr())
+                    .to_bytestring();
 
                 Name::resolved(parser.id(), resolved, symbol, parser.current_span())
             }),
@@ -209,7 +212,7 @@ impl<'a> Parser<'a> {
             | TokenKind::Self_
             | TokenKind::Parent => self.next_but_first(|parser| {
                 let symbol = parser.current_symbol_as_bytestring();
-                let resolved = parser.join_with_namespace(&symbol);
+                let resolved = parser.join_with_namespace(symbol.as_bytestr()).into_owned();
 
                 Name::resolved(parser.id(), resolved, symbol, parser.current_span())
             }),
@@ -222,7 +225,7 @@ impl<'a> Parser<'a> {
 
                 self.next_but_first(|parser| {
                     let symbol = parser.current_symbol_as_bytestring();
-                    let resolved = parser.join_with_namespace(&symbol);
+                    let resolved = parser.join_with_namespace(symbol.as_bytestr()).into_owned();
 
                     Name::resolved(parser.id(), resolved, symbol, parser.current_span())
                 })
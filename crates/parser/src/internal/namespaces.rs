@@ -1,11 +1,13 @@
 use crate::internal::diagnostics::ParserDiagnostic;
 use crate::scoped;
-use crate::Parser;
+use crate::{NamespaceKind, Parser};
 use pxp_ast::Block;
 use pxp_ast::StatementKind;
 use pxp_ast::*;
 
-use pxp_bytestring::ByteString;
+use std::borrow::Cow;
+
+use pxp_bytestring::{ByteStr, ByteString};
 use pxp_diagnostics::Severity;
 use pxp_span::{IsSpanned, Span};
 use pxp_token::TokenKind;
@@ -53,9 +55,15 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_unbraced_namespace(&mut self, start: Span, name: SimpleIdentifier) -> StatementKind {
-        let end = self.skip_semicolon();
-
-        let statements = scoped!(self, Scope::Namespace(name.symbol.clone()), {
+        let context = self
+            .context
+            .enter(Some(name.symbol.clone()), NamespaceKind::Unbraced, start);
+        self.names.get_mut().invalidate();
+
+        // The semicolon is skipped inside of the namespace's scope so that a docblock directly
+        // after it is parsed with the namespace available for resolving names.
+        let (end, statements) = scoped!(self, Scope::Namespace(name.symbol.clone()), {
+            let end = self.skip_semicolon();
             let mut statements = Block::new();
 
             while !self.is_eof() && self.current_kind() != TokenKind::Namespace {
@@ -67,12 +75,23 @@ impl<'a> Parser<'a> {
                     }
                 }
 
+                let start = self.current_span();
+
                 statements.push(self.parse_top_level_statement());
+
+                // A stray closing brace can't close anything here either, so it's skipped like it
+                // is at the top-level to keep making progress.
+                if self.current_span() == start && self.current_kind() == TokenKind::RightBrace {
+                    self.next();
+                }
             }
 
-            statements
+            (end, statements)
         });
 
+        self.context.exit(context, self.previous_span);
+        self.names.get_mut().invalidate();
+
         StatementKind::Namespace(Box::new(NamespaceStatement::Unbraced(UnbracedNamespace {
             id: self.id(),
             span: Span::combine(start, statements.span()),
@@ -88,6 +107,13 @@ impl<'a> Parser<'a> {
         span: Span,
         name: Option<SimpleIdentifier>,
     ) -> StatementKind {
+        let context = self.context.enter(
+            name.as_ref().map(|name| name.symbol.clone()),
+            NamespaceKind::Braced,
+            span,
+        );
+        self.names.get_mut().invalidate();
+
         let body = scoped!(
             self,
             Scope::BracedNamespace(name.as_ref().map(|n| n.symbol.clone())),
@@ -102,6 +128,7 @@ impl<'a> Parser<'a> {
                     statements.push(self.parse_top_level_statement());
                 }
 
+                let trailing_comments = self.comments();
                 let end = self.skip_right_brace();
 
                 BracedNamespaceBody {
@@ -110,10 +137,14 @@ impl<'a> Parser<'a> {
                     start,
                     end,
                     statements,
+                    trailing_comments,
                 }
             }
         );
 
+        self.context.exit(context, body.end);
+        self.names.get_mut().invalidate();
+
         StatementKind::Namespace(Box::new(NamespaceStatement::Braced(BracedNamespace {
             id: self.id(),
             span: Span::combine(span, body.span),
@@ -123,7 +154,7 @@ impl<'a> Parser<'a> {
         })))
     }
 
-    fn namespace_type(&self) -> Option<&NamespaceType> {
+    pub(crate) fn namespace_type(&self) -> Option<&NamespaceType> {
         self.namespace_type.as_ref()
     }
 
@@ -131,26 +162,27 @@ impl<'a> Parser<'a> {
         self.stack.iter().next()
     }
 
-    pub(crate) fn strip_leading_namespace_qualifier(&mut self, symbol: &ByteString) -> ByteString {
+    /// Remove the leading `\\` from a fully qualified name.
+    pub(crate) fn strip_leading_namespace_qualifier<'b>(&self, symbol: &'b ByteStr) -> &'b ByteStr {
         if symbol.starts_with(b"\\") {
-            ByteString::from(&symbol[1..])
+            ByteStr::new(&symbol[1..])
         } else {
-            symbol.clone()
+            symbol
         }
     }
 
-    pub(crate) fn join_with_namespace(&self, name: &ByteString) -> ByteString {
+    /// Prefix the name with the current namespace. Names in the global namespace are borrowed.
+    pub(crate) fn join_with_namespace<'b>(&self, name: &'b ByteStr) -> Cow<'b, ByteStr> {
         match self.namespace() {
-            Some(Scope::Namespace(namespace)) => namespace.coagulate(&[name.clone()], Some(b"\\")),
-            Some(Scope::BracedNamespace(Some(namespace))) => {
-                namespace.coagulate(&[name.clone()], Some(b"\\"))
+            Some(Scope::Namespace(namespace) | Scope::BracedNamespace(Some(namespace))) => {
+                Cow::Owned(namespace.as_bytestr().coagulate(&[name], b'\\'))
             }
-            _ => name.clone(),
+            _ => Cow::Borrowed(name),
         }
     }
 
     pub(crate) fn previous_scope(&self) -> Option<&Scope> {
-        self.stack.get(self.stack.len() - 2)
+        self.stack.get(self.stack.len().checked_sub(2)?)
     }
 
     pub(crate) fn enter(&mut self, scope: Scope) {
@@ -1,7 +1,10 @@
+use crate::features::unpacked_string_key;
+use crate::internal::data_type::TypeContext;
 use crate::internal::diagnostics::ParserDiagnostic;
-use crate::Parser;
+use crate::{Feature, Parser, PhpVersion};
 use pxp_ast::*;
 
+use pxp_bytestring::ByteString;
 use pxp_diagnostics::Severity;
 use pxp_span::IsSpanned;
 use pxp_span::Span;
@@ -15,7 +18,7 @@ impl<'a> Parser<'a> {
             |parser| {
                 parser.gather_attributes();
 
-                let ty = parser.parse_optional_data_type();
+                let ty = parser.parse_optional_data_type_in(TypeContext::Parameter);
 
                 let ampersand = if parser.current_kind() == TokenKind::Ampersand {
                     Some(parser.next())
@@ -35,7 +38,7 @@ impl<'a> Parser<'a> {
                 let mut default = None;
                 if parser.current_kind() == TokenKind::Equals {
                     parser.next();
-                    default = Some(parser.parse_expression());
+                    default = Some(parser.parse_constant_expression());
                 }
 
                 FunctionParameter {
@@ -60,6 +63,20 @@ impl<'a> Parser<'a> {
 
         let right_parenthesis = self.skip_right_parenthesis();
 
+        self.check_optional_parameters(
+            parameters
+                .iter()
+                .map(|parameter| {
+                    (
+                        &parameter.name,
+                        parameter.data_type.is_some(),
+                        parameter.default.as_ref(),
+                        parameter.ellipsis.is_some(),
+                    )
+                })
+                .collect(),
+        );
+
         FunctionParameterList {
             id: self.id(),
             span: Span::combine(left_parenthesis, right_parenthesis),
@@ -70,25 +87,91 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Optional parameters that come before a required one are implicitly required, which was
+    /// deprecated in PHP 8.0. Typed parameters that default to `null` are allowed, since that's how
+    /// they were made nullable before PHP 7.1.
+    pub(crate) fn check_optional_parameters(
+        &mut self,
+        parameters: Vec<(&SimpleVariable, bool, Option<&Expression>, bool)>,
+    ) {
+        if self.is_in_docblock() {
+            return;
+        }
+
+        let mut required: Option<&SimpleVariable> = None;
+        let mut optional = Vec::new();
+
+        for (name, typed, default, variadic) in parameters.into_iter().rev() {
+            match default {
+                None if !variadic => required = required.or(Some(name)),
+                Some(default) if typed && matches!(default.kind, ExpressionKind::Null(_)) => {}
+                Some(_) => {
+                    if let Some(required) = required {
+                        optional.push((name, required));
+                    }
+                }
+                None => {}
+            }
+        }
+
+        for (name, required) in optional.into_iter().rev() {
+            self.deprecated_syntax(
+                format!(
+                    "declaring optional parameter {} before required parameter {}",
+                    name.symbol, required.symbol
+                ),
+                "remove the default value",
+                PhpVersion::PHP_80,
+                None,
+                name.span,
+            );
+        }
+    }
+
+    /// Parse the optional argument list of a `new` expression.
+    ///
+    /// Closures can't be created from a `new` expression, so a `(...)` placeholder is reported and
+    /// treated as an empty argument list rather than as an argument.
+    pub(crate) fn parse_new_argument_list(&mut self) -> Option<ArgumentList> {
+        if self.current_kind() != TokenKind::LeftParen {
+            return None;
+        }
+
+        if self.peek_kind() == TokenKind::Ellipsis
+            && self.peek_again_kind() == TokenKind::RightParen
+        {
+            let comments = self.comments();
+            let start = self.skip_left_parenthesis();
+            self.skip(TokenKind::Ellipsis);
+            let end = self.skip_right_parenthesis();
+
+            self.diagnostic(
+                ParserDiagnostic::CannotCreateClosureFromNewExpression,
+                Severity::Error,
+                Span::combine(start, end),
+            );
+
+            return Some(ArgumentList {
+                id: self.id(),
+                span: Span::combine(start, end),
+                comments,
+                left_parenthesis: start,
+                right_parenthesis: end,
+                arguments: Vec::new(),
+            });
+        }
+
+        Some(self.parse_argument_list())
+    }
+
     pub fn parse_argument_list(&mut self) -> ArgumentList {
         let comments = self.comments();
         let start = self.skip_left_parenthesis();
 
         let mut arguments = Vec::new();
-        let mut has_used_named_arguments = false;
 
         while !self.is_eof() && self.current_kind() != TokenKind::RightParen {
-            let span = self.current_span();
-            let (named, argument) = self.parse_argument();
-            if named {
-                has_used_named_arguments = true;
-            } else if has_used_named_arguments {
-                self.diagnostic(
-                    ParserDiagnostic::CannotUsePositionalArgumentAfterNamedArgument,
-                    Severity::Error,
-                    span,
-                );
-            }
+            let (_, argument) = self.parse_argument();
 
             arguments.push(argument);
 
@@ -101,6 +184,8 @@ impl<'a> Parser<'a> {
 
         let end = self.skip_right_parenthesis();
 
+        self.check_argument_order(&arguments);
+
         ArgumentList {
             id: self.id(),
             span: Span::combine(start, end),
@@ -111,6 +196,70 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Positional arguments have to come before named arguments, and arguments can only be unpacked
+    /// after other positional arguments. Every argument is kept, even the ones that are reported.
+    fn check_argument_order(&mut self, arguments: &[Argument]) {
+        let mut named: Vec<&ByteString> = Vec::new();
+        let mut unpacked = false;
+
+        for argument in arguments {
+            let (diagnostic, span) = match argument {
+                Argument::Positional(PositionalArgument {
+                    ellipsis: Some(ellipsis),
+                    value,
+                    ..
+                }) => {
+                    if let ExpressionKind::Array(array) = &value.kind {
+                        self.check_unpacked_string_keys(array);
+                    }
+
+                    unpacked = true;
+
+                    (
+                        (!named.is_empty()).then_some(
+                            ParserDiagnostic::CannotUseArgumentUnpackingAfterNamedArgument,
+                        ),
+                        Span::combine(*ellipsis, value.span),
+                    )
+                }
+                Argument::Positional(argument) => (
+                    if !named.is_empty() {
+                        Some(ParserDiagnostic::CannotUsePositionalArgumentAfterNamedArgument)
+                    } else if unpacked {
+                        Some(ParserDiagnostic::CannotUsePositionalArgumentAfterArgumentUnpacking)
+                    } else {
+                        None
+                    },
+                    argument.span,
+                ),
+                Argument::Named(argument) => {
+                    let name = &argument.name.symbol;
+                    let duplicate = named.contains(&name);
+
+                    named.push(name);
+
+                    (
+                        duplicate.then(|| ParserDiagnostic::DuplicateNamedArgument {
+                            name: name.clone(),
+                        }),
+                        argument.span,
+                    )
+                }
+            };
+
+            if let Some(diagnostic) = diagnostic {
+                self.diagnostic(diagnostic, Severity::Error, span);
+            }
+        }
+    }
+
+    /// Unpacking arrays with string keys passes them as named arguments, which requires PHP 8.1.
+    fn check_unpacked_string_keys(&mut self, array: &ArrayExpression) {
+        if let Some(span) = unpacked_string_key(array) {
+            self.requires_feature(Feature::UnpackingStringKeys, span);
+        }
+    }
+
     pub fn parse_single_argument(
         &mut self,
         required: bool,
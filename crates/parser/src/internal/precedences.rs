@@ -10,10 +10,10 @@ pub enum Associativity {
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 pub enum Precedence {
     Lowest,
-    IncDec,
     KeyOr,
     KeyXor,
     KeyAnd,
+    Print,
     Assignment,
     Ternary,
     NullCoalesce,
@@ -32,6 +32,7 @@ pub enum Precedence {
     Instanceof,
     Prefix,
     Pow,
+    IncDec,
     CloneOrNew,
     CallDim,
     ObjectAccess,
@@ -74,7 +75,7 @@ impl Precedence {
         match kind {
             DoubleQuestion => Self::NullCoalesce,
             Increment | Decrement => Self::IncDec,
-            LeftParen | LeftBracket => Self::CallDim,
+            LeftParen | LeftBracket | LeftBrace => Self::CallDim,
             Arrow | QuestionArrow | DoubleColon => Self::ObjectAccess,
             _ => unimplemented!("postfix precedence for op {:}", kind),
         }
@@ -94,9 +95,10 @@ impl Precedence {
             | Self::Or
             | Self::KeyAnd
             | Self::KeyOr
-            | Self::KeyXor => Associativity::Left,
+            | Self::KeyXor
+            | Self::Ternary => Associativity::Left,
             Self::Pow | Self::NullCoalesce | Self::Assignment => Associativity::Right,
-            Self::Ternary | Self::Equality | Self::LtGt => Associativity::Non,
+            Self::Equality | Self::LtGt => Associativity::Non,
             _ => return None,
         })
     }
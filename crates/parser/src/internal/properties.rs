@@ -1,3 +1,4 @@
+use crate::internal::data_type::TypeContext;
 use crate::internal::diagnostics::ParserDiagnostic;
 use crate::Parser;
 use pxp_ast::*;
@@ -9,41 +10,23 @@ use pxp_token::TokenKind;
 
 impl<'a> Parser<'a> {
     pub(crate) fn parse_property(&mut self, modifiers: PropertyModifierGroup) -> Property {
-        let ty = self.parse_optional_data_type();
+        let comments = self.comments();
+        let ty = self.parse_optional_data_type_in(TypeContext::Property);
 
-        if modifiers.has_readonly() && modifiers.has_static() {
-            self.diagnostic(
-                ParserDiagnostic::StaticPropertyCannotBeReadonly,
-                Severity::Error,
-                self.current_span(),
-            );
-        }
-
-        match &ty {
-            Some(ty) => {
-                if ty.includes_callable() || ty.is_bottom() {
-                    self.diagnostic(
-                        ParserDiagnostic::ForbiddenTypeUsedInProperty,
-                        Severity::Error,
-                        ty.get_span(),
-                    );
-                }
-            }
-            None => {
-                if let Some(modifier) = modifiers.get_readonly() {
-                    self.diagnostic(
-                        ParserDiagnostic::ReadonlyPropertyMustHaveType,
-                        Severity::Error,
-                        modifier.span(),
-                    );
-                }
+        if ty.is_none() {
+            if let Some(modifier) = modifiers.get_readonly() {
+                self.diagnostic(
+                    ParserDiagnostic::ReadonlyPropertyMustHaveType,
+                    Severity::Error,
+                    modifier.span(),
+                );
             }
         }
 
         let entry = self.parse_property_entry(&modifiers);
 
         if self.current_kind() == TokenKind::LeftBrace {
-            return self.parse_hooked_property(modifiers, ty, entry);
+            return self.parse_hooked_property(comments, modifiers, ty, entry);
         }
 
         let mut entries = vec![entry];
@@ -67,6 +50,7 @@ impl<'a> Parser<'a> {
             } else {
                 entries.span()
             },
+            comments,
             var: None,
             r#type: ty,
             modifiers,
@@ -118,6 +102,7 @@ impl<'a> Parser<'a> {
 
     fn parse_hooked_property(
         &mut self,
+        comments: CommentGroup,
         modifiers: PropertyModifierGroup,
         r#type: Option<DataType>,
         entry: PropertyEntry,
@@ -150,6 +135,7 @@ impl<'a> Parser<'a> {
         Property::Hooked(HookedProperty {
             id: self.id(),
             span: Span::combine(modifiers.span(), hooks.span),
+            comments,
             attributes: self.get_attributes(),
             modifiers,
             r#type,
@@ -228,6 +214,7 @@ impl<'a> Parser<'a> {
                     statements.push(self.parse_statement());
                 }
 
+                let trailing_comments = self.comments();
                 let right_brace = self.skip_right_brace();
 
                 PropertyHookBody::Concrete(ConcretePropertyHookBody::Block(
@@ -235,6 +222,7 @@ impl<'a> Parser<'a> {
                         id: self.id(),
                         span: left_brace.join(right_brace),
                         left_brace,
+                        trailing_comments,
                         right_brace,
                         body: statements,
                     },
@@ -256,28 +244,14 @@ impl<'a> Parser<'a> {
     }
 
     pub(crate) fn parse_var_property(&mut self) -> Property {
+        let comments = self.comments();
         let var = self.skip(TokenKind::Var);
-        let ty = self.parse_optional_data_type();
+        let ty = self.parse_optional_data_type_in(TypeContext::Property);
 
         let mut entries: Vec<PropertyEntry> = vec![];
-        let mut type_checked = false;
         loop {
             let variable = self.parse_simple_variable();
 
-            if !type_checked {
-                type_checked = true;
-
-                if let Some(ty) = &ty {
-                    if ty.includes_callable() || ty.is_bottom() {
-                        self.diagnostic(
-                            ParserDiagnostic::ForbiddenTypeUsedInProperty,
-                            Severity::Error,
-                            ty.get_span(),
-                        );
-                    }
-                }
-            }
-
             let current = self.current();
             if current.kind == TokenKind::Equals {
                 self.next();
@@ -328,6 +302,7 @@ impl<'a> Parser<'a> {
             } else {
                 entries.span()
             },
+            comments,
             modifiers,
             var: Some(var),
             r#type: ty,
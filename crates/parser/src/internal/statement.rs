@@ -1,16 +1,75 @@
 use pxp_ast::{
-    ClosingTagStatement, DeclareBody, DeclareBodyBlock, DeclareBodyBraced, DeclareBodyExpression,
-    DeclareBodyNoop, DeclareEntry, DeclareEntryGroup, DeclareStatement, EchoOpeningTagStatement,
-    EchoStatement, ExpressionStatement, FullOpeningTagStatement, GlobalStatement,
-    HaltCompilerStatement, InlineHtmlStatement, ReturnStatement, ShortOpeningTagStatement,
-    Statement, StatementKind, StaticStatement, StaticVar, Variable,
+    ClosingTagStatement, DeclareBody, DeclareBodyBlock, DeclareBodyBraced, DeclareBodyNoop,
+    DeclareBodyStatement, DeclareEntry, DeclareEntryGroup, DeclareStatement,
+    EchoOpeningTagStatement, EchoStatement, ExpressionStatement, FullOpeningTagStatement,
+    GlobalStatement, HaltCompilerStatement, InlineHtmlStatement, Literal, ReturnStatement,
+    ShortOpeningTagStatement, SimpleIdentifier, Statement, StatementKind, StaticStatement,
+    StaticVar, Variable,
 };
+use pxp_diagnostics::Severity;
 use pxp_span::{IsSpanned, Span};
 use pxp_token::{OpenTagKind, TokenKind};
 
+use crate::internal::diagnostics::ParserDiagnostic;
 use crate::Parser;
 
+/// The directives that PHP understands inside of `declare()`.
+const DECLARE_DIRECTIVES: [&[u8]; 3] = [b"strict_types", b"ticks", b"encoding"];
+
 impl<'a> Parser<'a> {
+    fn parse_declare_value(&mut self, key: &SimpleIdentifier) -> Literal {
+        let literal = matches!(
+            self.current_kind(),
+            TokenKind::LiteralInteger
+                | TokenKind::LiteralFloat
+                | TokenKind::LiteralSingleQuotedString
+                | TokenKind::LiteralDoubleQuotedString
+        );
+
+        if literal || !key.symbol.eq_ignore_ascii_case(b"encoding") {
+            return self.parse_literal();
+        }
+
+        // The expression is still parsed so that the rest of the statement can be recovered.
+        let expression = self.parse_expression();
+
+        self.diagnostic(
+            ParserDiagnostic::DeclareEncodingMustBeLiteral,
+            Severity::Error,
+            expression.span,
+        );
+
+        Literal::missing(self.id(), expression.span)
+    }
+
+    fn diagnose_declare_directive(&mut self, key: &SimpleIdentifier) {
+        if !DECLARE_DIRECTIVES
+            .iter()
+            .any(|directive| key.symbol.eq_ignore_ascii_case(directive))
+        {
+            self.diagnostic(
+                ParserDiagnostic::UnknownDeclareDirective {
+                    directive: key.symbol.clone(),
+                },
+                Severity::Warning,
+                key.span,
+            );
+
+            return;
+        }
+
+        // `ticks` can be declared anywhere, but the others apply to the whole file.
+        if !key.symbol.eq_ignore_ascii_case(b"ticks") && self.namespace_type().is_some() {
+            self.diagnostic(
+                ParserDiagnostic::DeclareAfterNamespace {
+                    directive: key.symbol.clone(),
+                },
+                Severity::Error,
+                key.span,
+            );
+        }
+    }
+
     pub(crate) fn parse_top_level_statement(&mut self) -> Statement {
         match self.current_kind() {
             TokenKind::Namespace | TokenKind::Use | TokenKind::Const | TokenKind::HaltCompiler => {
@@ -41,7 +100,13 @@ impl<'a> Parser<'a> {
 
                 let span = kind.span();
 
-                Statement::new(self.id(), kind, span, comments)
+                Statement::new(
+                    self.id(),
+                    kind,
+                    span,
+                    comments,
+                    self.trailing_comments(span),
+                )
             }
             _ => self.parse_statement(),
         }
@@ -64,6 +129,7 @@ impl<'a> Parser<'a> {
                 TokenKind::Class => self.parse_class(),
                 TokenKind::Interface => self.parse_interface(),
                 TokenKind::Trait => self.parse_trait(),
+                TokenKind::Const => StatementKind::Constant(Box::new(self.parse_constant())),
                 TokenKind::Enum
                     if !matches!(
                         peek_kind,
@@ -92,7 +158,13 @@ impl<'a> Parser<'a> {
                                 ending,
                             }));
 
-                            return Statement::new(self.id(), kind, span, comments);
+                            return Statement::new(
+                                self.id(),
+                                kind,
+                                span,
+                                comments,
+                                self.trailing_comments(span),
+                            );
                         }
 
                         self.parse_function()
@@ -183,7 +255,13 @@ impl<'a> Parser<'a> {
                                 ending,
                             }));
 
-                            return Statement::new(self.id(), kind, span, comments);
+                            return Statement::new(
+                                self.id(),
+                                kind,
+                                span,
+                                comments,
+                                self.trailing_comments(span),
+                            );
                         }
 
                         self.parse_function()
@@ -208,9 +286,11 @@ impl<'a> Parser<'a> {
                             let key = self.parse_identifier();
                             let start = key.span;
                             let equals = self.skip(TokenKind::Equals);
-                            let value = self.parse_literal();
+                            let value = self.parse_declare_value(&key);
                             let end = value.span;
 
+                            self.diagnose_declare_directive(&key);
+
                             entries.push(DeclareEntry {
                                 id: self.id(),
                                 span: Span::combine(start, end),
@@ -252,6 +332,7 @@ impl<'a> Parser<'a> {
                             let start = self.skip_left_brace();
                             let statements =
                                 self.parse_multiple_statements_until(TokenKind::RightBrace);
+                            let trailing_comments = self.comments();
                             let end = self.skip_right_brace();
 
                             DeclareBody::Braced(DeclareBodyBraced {
@@ -259,6 +340,7 @@ impl<'a> Parser<'a> {
                                 span: Span::combine(start, end),
                                 left_brace: start,
                                 statements,
+                                trailing_comments,
                                 right_brace: end,
                             })
                         }
@@ -279,15 +361,12 @@ impl<'a> Parser<'a> {
                             })
                         }
                         _ => {
-                            let expression = self.parse_expression();
-                            let end = self.skip_semicolon();
-                            let span = Span::combine(expression.span(), end.span());
+                            let statement = self.parse_statement();
 
-                            DeclareBody::Expression(DeclareBodyExpression {
+                            DeclareBody::Statement(DeclareBodyStatement {
                                 id: self.id(),
-                                span,
-                                expression,
-                                semicolon: end,
+                                span: statement.span,
+                                statement: Box::new(statement),
                             })
                         }
                     };
@@ -463,6 +542,12 @@ impl<'a> Parser<'a> {
 
         let span = statement.span();
 
-        Statement::new(self.id(), statement, span, comments)
+        Statement::new(
+            self.id(),
+            statement,
+            span,
+            comments,
+            self.trailing_comments(span),
+        )
     }
 }
@@ -1,5 +1,6 @@
 use crate::internal::diagnostics::ParserDiagnostic;
 use crate::Parser;
+use pxp_ast::literals::{decode_escapes, EscapeError, InvalidEscape, StringQuote};
 use pxp_ast::Expression;
 use pxp_ast::ExpressionStringPart;
 use pxp_ast::LiteralStringPart;
@@ -22,7 +23,7 @@ impl<'a> Parser<'a> {
         let mut parts = Vec::new();
 
         while !self.is_eof() && self.current_kind() != TokenKind::DoubleQuote {
-            if let Some(part) = self.maybe_parse_string_part() {
+            if let Some(part) = self.maybe_parse_string_part(StringQuote::Double) {
                 parts.push(part);
             }
         }
@@ -51,7 +52,7 @@ impl<'a> Parser<'a> {
         let mut parts = Vec::new();
 
         while !self.is_eof() && self.current_kind() != TokenKind::Backtick {
-            if let Some(part) = self.maybe_parse_string_part() {
+            if let Some(part) = self.maybe_parse_string_part(StringQuote::Backtick) {
                 parts.push(part);
             }
         }
@@ -81,13 +82,13 @@ impl<'a> Parser<'a> {
 
         let mut parts = Vec::new();
 
-        while !matches!(self.current_kind(), TokenKind::EndHeredoc) {
-            if let Some(part) = self.maybe_parse_string_part() {
+        while !self.is_eof() && self.current_kind() != TokenKind::EndHeredoc {
+            if let Some(part) = self.maybe_parse_string_part(StringQuote::Heredoc) {
                 parts.push(part);
             }
         }
 
-        let end = self.next();
+        let end = self.skip(TokenKind::EndHeredoc);
 
         Expression::new(
             self.id(),
@@ -143,16 +144,22 @@ impl<'a> Parser<'a> {
         )
     }
 
-    fn maybe_parse_string_part(&mut self) -> Option<StringPart> {
+    fn maybe_parse_string_part(&mut self, quote: StringQuote) -> Option<StringPart> {
         match &self.current_kind() {
             TokenKind::StringPart => {
                 let span = self.current_span();
 
                 let part = if !span.is_empty() {
+                    let value = self.current_symbol_as_bytestring();
+
+                    // A part that ends with a backslash is always followed by something else, so
+                    // the backslash can't be escaping the end of the string.
+                    self.check_escapes(&value, span.start, quote, false);
+
                     Some(StringPart::Literal(LiteralStringPart {
                         id: self.id(),
                         span,
-                        value: self.current_symbol_as_bytestring(),
+                        value,
                     }))
                 } else {
                     None
@@ -205,13 +212,16 @@ impl<'a> Parser<'a> {
                         // so we can't call expression.
                         let index = match self.current_kind() {
                             TokenKind::LiteralInteger => {
+                                let span = self.current_span();
+                                let literal = self.current().to_owned();
+
                                 self.next();
 
                                 ExpressionKind::Literal(Box::new(Literal::new(
                                     self.id(),
                                     LiteralKind::Integer,
-                                    self.current().to_owned(),
-                                    self.current_span(),
+                                    literal,
+                                    span,
                                 )))
                             }
                             TokenKind::Minus => {
@@ -397,4 +407,46 @@ impl<'a> Parser<'a> {
             }
         }
     }
+
+    /// Report the invalid escape sequences in the current token, if it's a double-quoted string.
+    pub(crate) fn check_double_quoted_escapes(&mut self) {
+        if self.current_kind() != TokenKind::LiteralDoubleQuotedString
+            || !self.current_symbol().contains(b"\\")
+        {
+            return;
+        }
+
+        // The symbol of a double-quoted string is just its content, and so is its span.
+        let token = self.current().to_owned();
+
+        self.check_escapes(&token.symbol, token.span.start, StringQuote::Double, true);
+    }
+
+    /// Report the invalid escape sequences in the content of a string, which starts at the given
+    /// offset. A trailing backslash is only reported when it's at the very end of the string.
+    pub(crate) fn check_escapes(
+        &mut self,
+        raw: &[u8],
+        offset: usize,
+        quote: StringQuote,
+        is_complete: bool,
+    ) {
+        if !raw.contains(&b'\\') {
+            return;
+        }
+
+        let (_, errors) = decode_escapes(raw, quote);
+
+        for InvalidEscape { error, span } in errors {
+            if error == EscapeError::TrailingBackslash && !is_complete {
+                continue;
+            }
+
+            self.diagnostic(
+                ParserDiagnostic::InvalidEscapeSequence { error },
+                Severity::Warning,
+                Span::new(offset + span.start, offset + span.end),
+            );
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use crate::internal::diagnostics::ParserDiagnostic;
+use crate::internal::modifiers::ModifierContext;
 use crate::Parser;
 use pxp_ast::StatementKind;
 use pxp_ast::TraitUsageAdaptation;
@@ -39,10 +40,16 @@ impl<'a> Parser<'a> {
         }
 
         let mut adaptations = Vec::new();
-        if self.current_kind() == TokenKind::LeftBrace {
+        let end = if self.current_kind() == TokenKind::LeftBrace {
             self.skip_left_brace();
 
             while !self.is_eof() && self.current_kind() != TokenKind::RightBrace {
+                self.skip_misplaced_attributes();
+
+                if self.current_kind() == TokenKind::RightBrace {
+                    break;
+                }
+
                 let (r#trait, method): (Option<Name>, SimpleIdentifier) = match self.peek_kind() {
                     TokenKind::DoubleColon => {
                         let r#trait = self.parse_full_name_including_self();
@@ -74,6 +81,7 @@ impl<'a> Parser<'a> {
                 match self.current_kind() {
                     TokenKind::As => {
                         self.next();
+                        self.skip_misplaced_attributes();
 
                         match self.current_kind() {
                             TokenKind::Public | TokenKind::Protected | TokenKind::Private => {
@@ -110,9 +118,9 @@ impl<'a> Parser<'a> {
                                 } else {
                                     let alias: SimpleIdentifier = self.parse_name_identifier();
                                     let span = if r#trait.is_some() {
-                                        Span::combine(r#trait.span(), visibility.span())
+                                        Span::combine(r#trait.span(), alias.span())
                                     } else {
-                                        Span::combine(method.span, visibility.span())
+                                        Span::combine(method.span, alias.span())
                                     };
 
                                     adaptations.push(TraitUsageAdaptation {
@@ -159,7 +167,7 @@ impl<'a> Parser<'a> {
                     TokenKind::Insteadof => {
                         self.next();
 
-                        let mut insteadof = vec![self.parse_full_type_name_identifier()];
+                        let mut insteadof = vec![self.parse_full_name(UseKind::Normal)];
 
                         if self.current_kind() == TokenKind::Comma {
                             if self.peek_kind() == TokenKind::SemiColon {
@@ -171,7 +179,7 @@ impl<'a> Parser<'a> {
                             self.next();
 
                             while !self.is_eof() && (self.current_kind() != TokenKind::SemiColon) {
-                                insteadof.push(self.parse_full_type_name_identifier());
+                                insteadof.push(self.parse_full_name(UseKind::Normal));
 
                                 if self.current_kind() == TokenKind::Comma {
                                     if self.peek_kind() == TokenKind::SemiColon {
@@ -207,20 +215,21 @@ impl<'a> Parser<'a> {
                             ),
                         });
                     }
-                    _ => unreachable!("{:?}", self.current()),
+                    // The end of the file was reached while looking for `as` or `insteadof`.
+                    _ => break,
                 };
 
                 self.skip_semicolon();
             }
 
-            self.skip_right_brace();
+            self.skip_right_brace()
         } else {
-            self.skip_semicolon();
-        }
+            self.skip_semicolon()
+        };
 
         TraitUsage {
             id: self.id(),
-            span: Span::combine(span, adaptations.span()),
+            span: Span::combine(span, end),
             r#use: span,
             traits,
             adaptations,
@@ -232,14 +241,11 @@ impl<'a> Parser<'a> {
         let name = self.parse_type_name();
         let attributes = self.get_attributes();
 
+        self.enter_classlike(name.as_resolved().map(|name| name.resolved.clone()), true);
         let left_brace = self.skip_left_brace();
-        let members = {
-            let mut members = Vec::new();
-            while !self.is_eof() && self.current_kind() != TokenKind::RightBrace && !self.is_eof() {
-                members.push(self.parse_classish_member(true));
-            }
-            members
-        };
+        let members = self.parse_classish_members(ModifierContext::Trait);
+        let trailing_comments = self.comments();
+        self.exit_classlike();
         let right_brace = self.skip_right_brace();
 
         let body = TraitBody {
@@ -247,6 +253,7 @@ impl<'a> Parser<'a> {
             span: Span::combine(left_brace, right_brace),
             left_brace,
             members,
+            trailing_comments,
             right_brace,
         };
 
@@ -106,16 +106,16 @@ impl<'a> Parser<'a> {
 
     #[inline(always)]
     fn parse_catch_type(&mut self) -> CatchType {
-        let id = self.parse_full_name_identifier();
+        let name = self.parse_full_name(UseKind::Normal);
 
         if self.current_kind() == TokenKind::Pipe {
             self.next();
 
-            let mut types = vec![id];
+            let mut types = vec![name];
 
             while !self.is_eof() {
-                let id = self.parse_full_name_identifier();
-                types.push(id);
+                let name = self.parse_full_name(UseKind::Normal);
+                types.push(name);
 
                 if self.current_kind() != TokenKind::Pipe {
                     break;
@@ -132,18 +132,18 @@ impl<'a> Parser<'a> {
                 kind: CatchTypeKind::Union(CatchTypeKindUnion {
                     id: self.id(),
                     span,
-                    identifiers: types,
+                    names: types,
                 }),
             };
         }
 
         CatchType {
             id: self.id(),
-            span: id.span(),
+            span: name.span(),
             kind: CatchTypeKind::Identifier(CatchTypeKindIdentifier {
                 id: self.id(),
-                span: id.span(),
-                identifier: id,
+                span: name.span(),
+                name,
             }),
         }
     }
@@ -85,6 +85,7 @@ impl<'a> Parser<'a> {
                     prefix_symbol.as_bytestr(),
                     name.symbol.as_bytestr(),
                     alias.as_ref().map(|a| a.symbol.as_bytestr()),
+                    span,
                 );
 
                 uses.push(Use {
@@ -92,7 +93,7 @@ impl<'a> Parser<'a> {
                     span,
                     name: Name::resolved(
                         self.id(),
-                        prefix_symbol.coagulate(&[name.symbol.clone()], Some(b"\\")),
+                        prefix_symbol.coagulate(std::slice::from_ref(&name.symbol), Some(b"\\")),
                         name.symbol,
                         name.span,
                     ),
@@ -139,6 +140,7 @@ impl<'a> Parser<'a> {
                     &kind,
                     name.symbol().as_bytestr(),
                     alias.as_ref().map(|a| a.symbol.as_bytestr()),
+                    span,
                 );
 
                 uses.push(Use {
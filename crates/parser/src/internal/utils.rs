@@ -1,3 +1,4 @@
+use crate::internal::delimiters::MismatchedCloser;
 use crate::{Parser, ParserDiagnostic};
 use pxp_ast::utils::CommaSeparated;
 use pxp_ast::Ending;
@@ -11,6 +12,13 @@ impl<'a> Parser<'a> {
             Ending::CloseTag(self.next())
         } else if self.current_kind() == TokenKind::SemiColon {
             Ending::Semicolon(self.next())
+        } else if !self.is_eof() && self.is_missing_semicolon() {
+            // The semicolon belongs straight after the previous token, not before the next one.
+            let span = Span::flat(self.previous_span.end);
+
+            self.diagnostic(ParserDiagnostic::MissingSemicolon, Severity::Error, span);
+
+            Ending::Missing(span)
         } else {
             let span = Span::flat(self.current_span().start);
 
@@ -31,8 +39,51 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// When a statement ends without a semicolon and the next token clearly starts another statement,
+    /// we report a single missing semicolon and let the next statement parse as normal.
+    fn is_missing_semicolon(&self) -> bool {
+        matches!(
+            self.current_kind(),
+            TokenKind::Variable
+                | TokenKind::RightBrace
+                | TokenKind::If
+                | TokenKind::Return
+                | TokenKind::Echo
+                | TokenKind::Print
+                | TokenKind::While
+                | TokenKind::Do
+                | TokenKind::For
+                | TokenKind::Foreach
+                | TokenKind::Switch
+                | TokenKind::Break
+                | TokenKind::Continue
+                | TokenKind::Throw
+                | TokenKind::Try
+                | TokenKind::Unset
+                | TokenKind::Global
+                | TokenKind::Function
+                | TokenKind::Fn
+                | TokenKind::Class
+                | TokenKind::Interface
+                | TokenKind::Trait
+                | TokenKind::Enum
+                | TokenKind::Abstract
+                | TokenKind::Final
+                | TokenKind::Namespace
+                | TokenKind::Use
+                | TokenKind::Const
+                | TokenKind::Goto
+                | TokenKind::Declare
+        ) || self.lexer.starts_line(self.current_span())
+    }
+
+    /// Skip the semicolon at the end of a statement. A close tag also ends a statement, in which
+    /// case its span is returned instead.
     pub fn skip_semicolon(&mut self) -> Span {
-        if self.current_kind() == TokenKind::SemiColon {
+        if matches!(
+            self.current_kind(),
+            TokenKind::SemiColon | TokenKind::CloseTag
+        ) {
             self.next()
         } else {
             self.diagnostic(
@@ -116,16 +167,25 @@ impl<'a> Parser<'a> {
     pub fn skip(&mut self, kind: TokenKind) -> Span {
         while self.current_kind() != kind {
             if self.is_eof() {
-                self.diagnostic(
-                    ParserDiagnostic::UnexpectedEndOfFileExpected {
-                        expected: vec![kind],
-                    },
-                    Severity::Error,
-                    self.current_span(),
-                );
+                if !self.recover_from_unclosed_delimiter(kind) {
+                    self.diagnostic(
+                        ParserDiagnostic::UnexpectedEndOfFileExpected {
+                            expected: vec![kind],
+                        },
+                        Severity::Error,
+                        self.current_span(),
+                    );
+                }
+
                 break;
             }
 
+            match self.recover_from_mismatched_closer(kind) {
+                Some(MismatchedCloser::Replaces) => return self.next(),
+                Some(MismatchedCloser::ClosesOuter) => return Span::flat(self.previous_span.end),
+                None => {}
+            }
+
             self.diagnostic(
                 ParserDiagnostic::ExpectedToken {
                     expected: vec![kind],
@@ -236,9 +296,16 @@ impl<'a> Parser<'a> {
                 break;
             }
 
-            // If the next token is the until token, we don't want to consume the comma.
-            // This ensures that trailing commas are not allowed.
+            // Trailing commas aren't allowed, so the comma is skipped but reported.
             if self.peek_kind() == until {
+                let comma = self.next();
+
+                self.diagnostic(
+                    ParserDiagnostic::UnexpectedTrailingComma,
+                    Severity::Error,
+                    comma,
+                );
+
                 break;
             }
 
@@ -252,6 +319,7 @@ impl<'a> Parser<'a> {
     pub fn at_least_one_comma_separated_no_trailing<T>(
         &mut self,
         mut func: impl FnMut(&mut Parser) -> T,
+        until: TokenKind,
     ) -> CommaSeparated<T> {
         let mut inner: Vec<T> = vec![];
         let mut commas: Vec<Span> = vec![];
@@ -263,6 +331,18 @@ impl<'a> Parser<'a> {
                 break;
             }
 
+            if self.peek_kind() == until {
+                let comma = self.next();
+
+                self.diagnostic(
+                    ParserDiagnostic::UnexpectedTrailingComma,
+                    Severity::Error,
+                    comma,
+                );
+
+                break;
+            }
+
             commas.push(self.next());
         }
 
@@ -1,72 +1,228 @@
+mod context;
+mod features;
+mod fragment;
+mod hooks;
 mod internal;
 mod macros;
+mod version;
 
-use std::collections::{HashMap, VecDeque};
+use std::cell::RefCell;
+use std::collections::VecDeque;
 
+use hooks::Hooks;
+use internal::data_type::TypeContext;
+use internal::delimiters::Delimiter;
+use internal::imports::NameCache;
 use internal::namespaces::{NamespaceType, Scope};
-use pxp_ast::{AttributeGroup, Comment, Statement, UseKind};
+use pxp_ast::{AttributeGroup, Comment, ResolvedName, Statement};
 use pxp_bytestring::{ByteStr, ByteString};
 use pxp_diagnostics::Diagnostic;
 use pxp_lexer::Lexer;
 use pxp_span::Span;
 use pxp_token::{Token, TokenKind};
+use pxp_type::Type;
 
+pub use context::{FileContext, Import, NamespaceContext, NamespaceKind};
+pub use features::{minimum_php_version, Feature, FeatureUse};
+pub use fragment::{Fragment, FragmentKind, FragmentResult};
+pub use hooks::ParserHooks;
 pub use internal::diagnostics::ParserDiagnostic;
+pub use version::PhpVersion;
 
 #[derive(Debug)]
 pub struct ParseResult {
     pub ast: Vec<Statement>,
     pub diagnostics: Vec<Diagnostic<ParserDiagnostic>>,
+    /// The namespaces and imports that were used to resolve names.
+    pub context: FileContext,
+    /// Whether the parse was stopped early by `ParserHooks::on_statement_parsed`.
+    pub cancelled: bool,
+}
+
+impl ParseResult {
+    /// A content hash of the AST that ignores ids, spans and comments.
+    ///
+    /// See [`pxp_ast::fingerprint`] for what is and isn't covered.
+    pub fn fingerprint(&self) -> u64 {
+        pxp_ast::fingerprint(&self.ast)
+    }
+}
+
+/// Configuration that controls how the parser behaves.
+#[derive(Debug, Clone, Copy)]
+pub struct ParserOptions {
+    /// The maximum number of error-severity diagnostics the parser will report before giving up.
+    ///
+    /// Once the budget is reached, a final `TooManyErrors` diagnostic is emitted and the rest of
+    /// the file is skipped. Warnings do not count towards the budget. `None` means unlimited.
+    pub max_diagnostics: Option<usize>,
+    /// The version of PHP that the code has to run on. Syntax that was introduced in a newer
+    /// version is reported. `None` means that every supported version of the syntax is accepted.
+    pub php_version: Option<PhpVersion>,
+    /// Whether names that have already been resolved are remembered, rather than being resolved
+    /// again every time they're used. This doesn't change how names are resolved, so it's only
+    /// worth turning off to compare the two.
+    pub cache_names: bool,
+}
+
+impl Default for ParserOptions {
+    fn default() -> Self {
+        Self {
+            max_diagnostics: None,
+            php_version: None,
+            cache_names: true,
+        }
+    }
+}
+
+impl ParserOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_diagnostics(mut self, max_diagnostics: usize) -> Self {
+        self.max_diagnostics = Some(max_diagnostics);
+        self
+    }
+
+    pub fn with_php_version(mut self, php_version: PhpVersion) -> Self {
+        self.php_version = Some(php_version);
+        self
+    }
+
+    pub fn with_cache_names(mut self, cache_names: bool) -> Self {
+        self.cache_names = cache_names;
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Classlike {
+    name: Option<ByteString>,
+    has_parent: bool,
+    interface: bool,
 }
 
 #[derive(Debug)]
 pub struct Parser<'a> {
     lexer: Lexer<'a>,
+    options: ParserOptions,
+    previous_span: Span,
+    hooks: Hooks<'a>,
 
     id: u32,
     comments: Vec<Comment>,
     attributes: Vec<AttributeGroup>,
     stack: VecDeque<Scope>,
     namespace_type: Option<NamespaceType>,
-    imports: HashMap<UseKind, HashMap<ByteString, ByteString>>,
+    context: FileContext,
+    names: RefCell<NameCache>,
     in_docblock: bool,
+    classlikes: Vec<Classlike>,
+    type_context: Option<TypeContext>,
+    relative_docblock_types: Vec<(Type<ResolvedName>, Span)>,
+    delimiters: Vec<Delimiter>,
 
     diagnostics: Vec<Diagnostic<ParserDiagnostic>>,
+    errors: usize,
+    exhausted: bool,
 }
 
 impl<'a> Parser<'a> {
     pub fn parse(lexer: Lexer<'a>) -> ParseResult {
-        let mut parser = Parser::new(lexer);
+        Self::parse_with_options(lexer, ParserOptions::default())
+    }
+
+    pub fn parse_with_options(lexer: Lexer<'a>, options: ParserOptions) -> ParseResult {
+        Parser::new(lexer, options, None).parse_statements()
+    }
+
+    /// Parse with a set of hooks that are notified about progress and diagnostics, and which
+    /// can cancel the parse between top-level statements.
+    pub fn parse_with_hooks(
+        lexer: Lexer<'a>,
+        options: ParserOptions,
+        hooks: &'a mut dyn ParserHooks,
+    ) -> ParseResult {
+        Parser::new(lexer, options, Some(hooks)).parse_statements()
+    }
+
+    fn parse_statements(mut self) -> ParseResult {
         let mut ast = Vec::new();
+        let mut cancelled = false;
+
+        while !self.is_eof() {
+            let start = self.current_span();
+            let statement = self.parse_top_level_statement();
+
+            // A stray closing brace can't close anything at the top-level and expressions
+            // deliberately leave it alone, so we need to skip it here to keep making progress.
+            if self.current_span() == start && self.current_kind() == TokenKind::RightBrace {
+                self.next();
+            }
+
+            // If the error budget ran out while parsing this statement, it's most likely
+            // full of missing nodes, so we only keep the statements that came before it.
+            if self.exhausted {
+                break;
+            }
+
+            self.check_empty_array_indexes(&statement);
+
+            let span = statement.span;
+            ast.push(statement);
+
+            if self
+                .hooks
+                .on_statement_parsed(ast.len() - 1, span)
+                .is_break()
+            {
+                cancelled = true;
+                break;
+            }
+        }
 
-        while !parser.is_eof() {
-            ast.push(parser.parse_top_level_statement());
+        if !cancelled {
+            self.report_unclosed_delimiters();
         }
 
+        self.context.finish(self.current_span());
+
         ParseResult {
             ast,
-            diagnostics: parser.diagnostics,
+            diagnostics: self.diagnostics,
+            context: self.context,
+            cancelled,
         }
     }
 
-    fn new(lexer: Lexer<'a>) -> Self {
-        let mut imports = HashMap::new();
-        imports.insert(UseKind::Normal, HashMap::new());
-        imports.insert(UseKind::Function, HashMap::new());
-        imports.insert(UseKind::Const, HashMap::new());
-
+    fn new(
+        lexer: Lexer<'a>,
+        options: ParserOptions,
+        hooks: Option<&'a mut dyn ParserHooks>,
+    ) -> Self {
         let mut this = Self {
             lexer,
+            options,
+            previous_span: Span::default(),
+            hooks: Hooks::new(hooks),
 
             id: 0,
             attributes: vec![],
             comments: vec![],
             stack: VecDeque::with_capacity(8),
             namespace_type: None,
-            imports,
+            context: FileContext::new(),
+            names: RefCell::default(),
             in_docblock: false,
+            classlikes: vec![],
+            type_context: None,
+            relative_docblock_types: vec![],
+            delimiters: vec![],
 
             diagnostics: vec![],
+            errors: 0,
+            exhausted: false,
         };
 
         this.collect_comments();
@@ -82,23 +238,44 @@ impl<'a> Parser<'a> {
     /// Return the current span and move on to the next token.
     fn next(&mut self) -> Span {
         let span = self.current_span();
+        let end = self.current_full_span();
 
+        self.track_delimiter(self.current_kind(), span);
         self.lexer.next();
         self.collect_comments();
         self.skip_horizontal_whitespace();
 
+        // Comments and whitespace are skipped by nested calls, so the outermost token wins.
+        self.previous_span = end;
+
         span
     }
 
     fn next_without_skipping_whitespace(&mut self) -> Span {
         let span = self.current_span();
+        let end = self.current_full_span();
 
+        self.track_delimiter(self.current_kind(), span);
         self.lexer.next();
         self.collect_comments();
 
+        self.previous_span = end;
+
         span
     }
 
+    /// The span of the current token in the source. The span given to double-quoted strings
+    /// doesn't include the closing quote.
+    fn current_full_span(&self) -> Span {
+        let span = self.current_span();
+
+        if self.current_kind() == TokenKind::LiteralDoubleQuotedString {
+            Span::new(span.start, span.end + 1)
+        } else {
+            span
+        }
+    }
+
     fn skip_horizontal_whitespace(&mut self) {
         while !self.is_eof() && self.current_kind() == TokenKind::PhpDocHorizontalWhitespace {
             self.next();
@@ -109,7 +286,7 @@ impl<'a> Parser<'a> {
         self.current_kind() == TokenKind::Eof
     }
 
-    fn current(&self) -> Token {
+    fn current(&self) -> Token<'_> {
         self.lexer.current()
     }
 
@@ -0,0 +1,38 @@
+use std::fmt::Display;
+
+/// A minor version of PHP, e.g. 8.4.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PhpVersion {
+    major: u8,
+    minor: u8,
+}
+
+impl PhpVersion {
+    pub const PHP_70: PhpVersion = PhpVersion::new(7, 0);
+    pub const PHP_72: PhpVersion = PhpVersion::new(7, 2);
+    pub const PHP_74: PhpVersion = PhpVersion::new(7, 4);
+    pub const PHP_80: PhpVersion = PhpVersion::new(8, 0);
+    pub const PHP_81: PhpVersion = PhpVersion::new(8, 1);
+    pub const PHP_82: PhpVersion = PhpVersion::new(8, 2);
+    pub const PHP_83: PhpVersion = PhpVersion::new(8, 3);
+    pub const PHP_84: PhpVersion = PhpVersion::new(8, 4);
+    pub const PHP_85: PhpVersion = PhpVersion::new(8, 5);
+
+    pub const fn new(major: u8, minor: u8) -> Self {
+        Self { major, minor }
+    }
+
+    pub fn major(&self) -> u8 {
+        self.major
+    }
+
+    pub fn minor(&self) -> u8 {
+        self.minor
+    }
+}
+
+impl Display for PhpVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
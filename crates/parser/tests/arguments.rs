@@ -0,0 +1,167 @@
+use pxp_ast::{Argument, ArgumentList, ExpressionKind, StatementKind};
+use pxp_diagnostics::DiagnosticKind;
+use pxp_lexer::Lexer;
+use pxp_parser::{Parser, ParserOptions, PhpVersion};
+use pxp_span::IsSpanned;
+
+/// The code of every diagnostic, along with the source that it points at.
+fn diagnostics(code: &str, options: ParserOptions) -> Vec<(String, String)> {
+    let source = format!("<?php {code}");
+
+    Parser::parse_with_options(Lexer::new(&source), options)
+        .diagnostics
+        .iter()
+        .map(|diagnostic| {
+            (
+                diagnostic.kind.get_code(),
+                source[diagnostic.span.start..diagnostic.span.end].to_string(),
+            )
+        })
+        .collect()
+}
+
+fn located(code: &str) -> Vec<(String, String)> {
+    diagnostics(code, ParserOptions::new())
+}
+
+fn expected(diagnostics: &[(&str, &str)]) -> Vec<(String, String)> {
+    diagnostics
+        .iter()
+        .map(|(code, source)| (code.to_string(), source.to_string()))
+        .collect()
+}
+
+/// Parse a single call or `new` expression and return its arguments.
+fn arguments(code: &str) -> ArgumentList {
+    let source = format!("<?php {code};");
+    let result = Parser::parse(Lexer::new(&source));
+
+    let Some(statement) = result
+        .ast
+        .into_iter()
+        .find_map(|statement| match statement.kind {
+            StatementKind::Expression(statement) => Some(statement),
+            _ => None,
+        })
+    else {
+        panic!("expected an expression statement");
+    };
+
+    match statement.expression.kind {
+        ExpressionKind::FunctionCall(call) => call.arguments,
+        ExpressionKind::New(new) => new.arguments.unwrap(),
+        _ => panic!("expected a call"),
+    }
+}
+
+#[test]
+fn it_reports_positional_arguments_after_named_arguments() {
+    assert_eq!(located("foo(a: 1, $b);"), expected(&[("P036", "$b")]));
+}
+
+#[test]
+fn it_reports_argument_unpacking_after_named_arguments() {
+    assert_eq!(
+        located("new Foo(x: 1, ...$rest);"),
+        expected(&[("P097", "...$rest")])
+    );
+}
+
+#[test]
+fn it_reports_positional_arguments_after_argument_unpacking() {
+    assert_eq!(
+        located("foo(...$rest, 1, ...$more);"),
+        expected(&[("P098", "1")])
+    );
+}
+
+#[test]
+fn it_reports_duplicate_named_arguments() {
+    assert_eq!(
+        located("foo(a: 1, b: 2, a: 3);"),
+        expected(&[("P099", "a: 3")])
+    );
+}
+
+#[test]
+fn it_accepts_arguments_in_order() {
+    assert!(located("foo(1, ...$rest, a: 2, b: 3); new Foo(1, x: 2);").is_empty());
+}
+
+#[test]
+fn it_reports_unpacking_string_keys_before_php_81() {
+    let code = "foo(...['a' => 1, 'b' => 2]);";
+
+    assert_eq!(
+        diagnostics(
+            code,
+            ParserOptions::new().with_php_version(PhpVersion::PHP_80)
+        ),
+        expected(&[("P091", "'a'")])
+    );
+    assert!(diagnostics(
+        code,
+        ParserOptions::new().with_php_version(PhpVersion::PHP_81)
+    )
+    .is_empty());
+    assert!(located("foo(...[1, 2]);").is_empty());
+}
+
+#[test]
+fn it_keeps_every_argument_when_the_order_is_invalid() {
+    assert_eq!(arguments("foo(a: 1, $b, ...$c, a: 2)").arguments.len(), 4);
+}
+
+#[test]
+fn it_provides_the_arguments_of_a_mixed_call() {
+    let code = "foo(1, 2, c: 3, d: 4)";
+    let arguments = arguments(code);
+    let source = |argument: Option<&Argument>| {
+        argument.map(|argument| {
+            let span = argument.span();
+
+            // The spans are offset by the `<?php ` that's prepended to the code.
+            code[span.start - 6..span.end - 6].to_string()
+        })
+    };
+
+    assert_eq!(arguments.positional().count(), 2);
+    assert!(!arguments.has_unpacking());
+
+    let mut named = arguments
+        .named()
+        .into_keys()
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>();
+    named.sort();
+
+    assert_eq!(named, vec!["c", "d"]);
+
+    assert_eq!(
+        source(arguments.argument_for_parameter(0, b"$a")),
+        Some("1".into())
+    );
+    assert_eq!(
+        source(arguments.argument_for_parameter(1, b"b")),
+        Some("2".into())
+    );
+    assert_eq!(
+        source(arguments.argument_for_parameter(2, b"c")),
+        Some("c: 3".into())
+    );
+    assert_eq!(
+        source(arguments.argument_for_parameter(3, b"d")),
+        Some("d: 4".into())
+    );
+    assert_eq!(source(arguments.argument_for_parameter(4, b"e")), None);
+}
+
+#[test]
+fn it_cant_find_arguments_for_parameters_after_unpacking() {
+    let arguments = arguments("foo(1, ...$rest, c: 3)");
+
+    assert!(arguments.has_unpacking());
+    assert!(arguments.argument_for_parameter(0, b"a").is_some());
+    assert!(arguments.argument_for_parameter(1, b"b").is_none());
+    assert!(arguments.argument_for_parameter(2, b"c").is_some());
+}
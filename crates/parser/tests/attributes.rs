@@ -0,0 +1,170 @@
+use pxp_ast::constants::{ConstantValue, NotConstant};
+use pxp_ast::{
+    Attribute, ConstantArgument, ExpressionKind, HasAttributes, StatementKind, UnitEnumMember,
+};
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+use pxp_span::Span;
+
+/// Parse the given function and return the first attribute on it.
+fn attribute(source: &str) -> Attribute {
+    let result = Parser::parse(Lexer::new(
+        format!("<?php {source} function f() {{}}").as_bytes(),
+    ));
+
+    result
+        .ast
+        .into_iter()
+        .find_map(|statement| match statement.kind {
+            StatementKind::Function(function) => function.attributes[0].members.first().cloned(),
+            _ => None,
+        })
+        .unwrap()
+}
+
+fn arguments(attribute: &Attribute) -> Vec<(Option<String>, Result<ConstantValue, NotConstant>)> {
+    attribute
+        .arguments_as_const()
+        .into_iter()
+        .map(|ConstantArgument { name, value }| (name.map(|name| name.symbol.to_string()), value))
+        .collect()
+}
+
+fn string(value: &str) -> ConstantValue {
+    ConstantValue::String(value.into())
+}
+
+#[test]
+fn it_evaluates_positional_and_named_arguments() {
+    let route = attribute("#[Route('/users', methods: ['GET'])]");
+
+    assert_eq!(
+        arguments(&route),
+        vec![
+            (None, Ok(string("/users"))),
+            (
+                Some("methods".to_string()),
+                Ok(ConstantValue::Array(vec![(None, string("GET"))]))
+            ),
+        ]
+    );
+
+    assert_eq!(
+        route.arguments_as_const()[0]
+            .value
+            .as_ref()
+            .ok()
+            .and_then(ConstantValue::as_string),
+        Some(b"/users".into())
+    );
+}
+
+#[test]
+fn it_evaluates_constant_expressions() {
+    assert_eq!(
+        arguments(&attribute(
+            r#"#[A(null, true, -1, 1.5, "a\tb" . 'c', ['key' => 0x10], PHP_EOL, Status::Active, \App\User::class)]"#
+        ))
+        .into_iter()
+        .map(|(_, value)| value.unwrap())
+        .collect::<Vec<_>>(),
+        vec![
+            ConstantValue::Null,
+            ConstantValue::Bool(true),
+            ConstantValue::Int(-1),
+            ConstantValue::Float(1.5),
+            string("a\tbc"),
+            ConstantValue::Array(vec![(Some(string("key")), ConstantValue::Int(16))]),
+            ConstantValue::Constant("PHP_EOL".into()),
+            ConstantValue::ClassConstant {
+                class: "Status".into(),
+                constant: "Active".into(),
+            },
+            string("App\\User"),
+        ]
+    );
+}
+
+#[test]
+fn it_reports_arguments_that_are_not_constant() {
+    let source = "#[A(1, [foo()], ...$rest, self::class)]";
+    let offset = "<?php ".len();
+    let span = |needle: &str| {
+        let start = offset + source.find(needle).unwrap();
+
+        Span::new(start, start + needle.len())
+    };
+
+    assert_eq!(
+        arguments(&attribute(source))
+            .into_iter()
+            .map(|(_, value)| value)
+            .collect::<Vec<_>>(),
+        vec![
+            Ok(ConstantValue::Int(1)),
+            Err(NotConstant {
+                span: span("foo()")
+            }),
+            Err(NotConstant {
+                span: span("$rest")
+            }),
+            Err(NotConstant {
+                span: span("self::class")
+            }),
+        ]
+    );
+}
+
+#[test]
+fn it_attaches_attributes_to_constants_enum_cases_and_closure_parameters() {
+    let result = Parser::parse(Lexer::new(
+        br#"<?php
+        #[Deprecated] const FOO = 1;
+
+        enum Suit { #[Deprecated] case Hearts; }
+
+        $f = #[Pure] function (#[SensitiveParameter] $password) {};
+        "#,
+    ));
+
+    assert!(result.diagnostics.is_empty());
+
+    let mut statements = result.ast.iter().map(|statement| &statement.kind);
+
+    let Some(StatementKind::Constant(constant)) =
+        statements.find(|kind| matches!(kind, StatementKind::Constant(_)))
+    else {
+        panic!("Expected a constant statement.");
+    };
+
+    assert!(constant.has_attribute(b"Deprecated".into()));
+
+    let Some(StatementKind::UnitEnum(r#enum)) =
+        statements.find(|kind| matches!(kind, StatementKind::UnitEnum(_)))
+    else {
+        panic!("Expected an enum statement.");
+    };
+
+    let UnitEnumMember::Case(case) = &r#enum.body.members[0] else {
+        panic!("Expected an enum case.");
+    };
+
+    assert!(case.has_attribute(b"Deprecated".into()));
+
+    let Some(StatementKind::Expression(statement)) =
+        statements.find(|kind| matches!(kind, StatementKind::Expression(_)))
+    else {
+        panic!("Expected an expression statement.");
+    };
+
+    let ExpressionKind::AssignmentOperation(assignment) = &statement.expression.kind else {
+        panic!("Expected an assignment.");
+    };
+
+    let ExpressionKind::Closure(closure) = &assignment.right.kind else {
+        panic!("Expected a closure.");
+    };
+
+    assert!(closure.has_attribute(b"Pure".into()));
+    assert!(closure.parameters.parameters.inner[0].has_attribute(b"SensitiveParameter".into()));
+}
@@ -0,0 +1,169 @@
+use pxp_ast::builder::{Builder, IdAllocator};
+use pxp_ast::{fingerprint, ResolvedName, Statement, StatementKind, UseKind};
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+use pxp_type::Type;
+
+fn parse(code: &str) -> Vec<Statement> {
+    let result = Parser::parse(Lexer::new(format!("<?php {code}").as_bytes()));
+
+    assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+
+    result
+        .ast
+        .into_iter()
+        .filter(|statement| !matches!(statement.kind, StatementKind::FullOpeningTag(_)))
+        .collect()
+}
+
+fn assert_builds(code: &str, built: Vec<Statement>) {
+    let parsed = parse(code);
+
+    assert_eq!(
+        fingerprint(&parsed),
+        fingerprint(&built),
+        "\nparsed: {parsed:#?}\nbuilt: {built:#?}"
+    );
+}
+
+fn named(name: &str) -> Type<ResolvedName> {
+    Type::Named(ResolvedName {
+        resolved: name.into(),
+        original: name.into(),
+    })
+}
+
+#[test]
+fn it_builds_use_statements() {
+    let ids = IdAllocator::new();
+    let b = Builder::new(&ids);
+
+    assert_builds(
+        "use Foo\\Bar; use function Foo\\baz as qux;",
+        vec![
+            b.r#use("Foo\\Bar", None, UseKind::Normal),
+            b.r#use("Foo\\baz", Some("qux"), UseKind::Function),
+        ],
+    );
+}
+
+#[test]
+fn it_builds_classes() {
+    let ids = IdAllocator::new();
+    let b = Builder::new(&ids);
+
+    let class = b
+        .class("Foo")
+        .r#abstract()
+        .extends("Bar")
+        .implements("Baz")
+        .implements("Qux")
+        .property(
+            b.property("a")
+                .public()
+                .r#type(Type::Nullable(Box::new(Type::Integer)))
+                .default(b.int(1)),
+        )
+        .property(b.property("$b").private().r#static())
+        .method(
+            b.method("c")
+                .public()
+                .parameter(b.parameter("d").r#type(Type::Integer).default(b.int(2)))
+                .parameter(b.parameter("e").r#type(named("Bar")).variadic())
+                .returns(Type::Void)
+                .body([b.r#return(None)]),
+        )
+        .method(b.method("f").protected().r#abstract().returns(Type::String))
+        .build();
+
+    assert_builds(
+        "abstract class Foo extends Bar implements Baz, Qux {
+            public ?int $a = 1;
+            private static $b;
+            public function c(int $d = 2, Bar ...$e): void { return; }
+            protected abstract function f(): string;
+        }",
+        vec![class],
+    );
+}
+
+#[test]
+fn it_builds_functions() {
+    let ids = IdAllocator::new();
+    let b = Builder::new(&ids);
+
+    let function = b
+        .function("f")
+        .parameter(b.parameter("x"))
+        .parameter(b.parameter("y").by_reference().default(b.null()))
+        .returns(Type::Union(vec![Type::String, Type::Boolean]))
+        .body([
+            b.r#if(
+                b.variable("x"),
+                [b.expression(b.call(
+                    "foo",
+                    [b.variable("x"), b.string("a'b"), b.float(1.5), b.bool(true)],
+                ))],
+            )
+            .elseif(
+                b.variable("y"),
+                [b.expression(b.assign(b.variable("x"), b.bool(false)))],
+            )
+            .r#else([b.r#return(Some(b.variable("x")))])
+            .build(),
+            b.foreach(
+                b.variable("x"),
+                Some(b.variable("k")),
+                b.variable("v"),
+                [b.expression(b.method_call(b.variable("v"), "y", [b.variable("k")]))],
+            ),
+            b.foreach(b.variable("x"), None, b.variable("v"), []),
+            b.r#return(Some(b.string("done"))),
+        ])
+        .build();
+
+    assert_builds(
+        "function f($x, &$y = null): string|bool {
+            if ($x) {
+                foo($x, 'a\\'b', 1.5, true);
+            } elseif ($y) {
+                $x = false;
+            } else {
+                return $x;
+            }
+
+            foreach ($x as $k => $v) {
+                $v->y($k);
+            }
+
+            foreach ($x as $v) {}
+
+            return 'done';
+        }",
+        vec![function],
+    );
+}
+
+#[test]
+fn it_detects_differences_from_parsed_code() {
+    let ids = IdAllocator::new();
+    let b = Builder::new(&ids);
+
+    let parsed = parse("foo(1);");
+    let built = vec![b.expression(b.call("foo", [b.int(2)]))];
+
+    assert_ne!(fingerprint(&parsed), fingerprint(&built));
+}
+
+#[test]
+fn it_allocates_unique_ids() {
+    let ids = IdAllocator::starting_at(100);
+    let b = Builder::new(&ids);
+
+    let first = b.variable("a");
+    let second = b.variable("a");
+
+    assert!(first.id >= 100);
+    assert!(second.id > first.id);
+    assert!(ids.next() > second.id);
+}
@@ -0,0 +1,158 @@
+use pxp_ast::UseKind;
+use pxp_bytestring::{ByteStr, ByteString};
+use pxp_lexer::Lexer;
+use pxp_parser::{FileContext, NamespaceKind, Parser};
+use pxp_span::Span;
+
+const CODE: &str = r#"<?php
+
+namespace App\Http {
+    use App\Models\User;
+    use function App\Support\format as fmt;
+    use const App\Support\VERSION;
+
+    new User(fmt(VERSION));
+}
+
+namespace App\Console {
+    use Illuminate\Foundation\Auth\User;
+
+    new User(fmt(VERSION));
+}
+"#;
+
+fn context() -> FileContext {
+    Parser::parse(Lexer::new(CODE)).context
+}
+
+/// The span of the `n`th occurrence of `needle` in the code.
+fn at(needle: &str, n: usize) -> Span {
+    let start = CODE
+        .match_indices(needle)
+        .nth(n)
+        .map(|(start, _)| start)
+        .unwrap();
+
+    Span::new(start, start + needle.len())
+}
+
+fn resolve(name: &str, kind: UseKind, at: Span) -> ByteString {
+    context().resolve(ByteStr::new(name.as_bytes()), kind, at)
+}
+
+#[test]
+fn it_collects_namespaces() {
+    let context = context();
+    let namespaces = context
+        .namespaces()
+        .iter()
+        .map(|namespace| (namespace.name.clone(), namespace.kind))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        namespaces,
+        vec![
+            (None, NamespaceKind::Implicit),
+            (Some(ByteString::from("App\\Http")), NamespaceKind::Braced),
+            (
+                Some(ByteString::from("App\\Console")),
+                NamespaceKind::Braced
+            ),
+        ]
+    );
+}
+
+#[test]
+fn it_collects_imports_per_namespace() {
+    let context = context();
+    let http = &context.namespaces()[1];
+    let console = &context.namespaces()[2];
+
+    let user = &http.imports(UseKind::Normal)[&ByteString::from("User")];
+    assert_eq!(user.name, ByteString::from("App\\Models\\User"));
+    assert_eq!(user.span, at("App\\Models\\User", 0));
+
+    assert_eq!(
+        http.imports(UseKind::Function)[&ByteString::from("fmt")].name,
+        ByteString::from("App\\Support\\format")
+    );
+    assert_eq!(
+        http.imports(UseKind::Const)[&ByteString::from("VERSION")].name,
+        ByteString::from("App\\Support\\VERSION")
+    );
+
+    assert_eq!(
+        console.imports(UseKind::Normal)[&ByteString::from("User")].name,
+        ByteString::from("Illuminate\\Foundation\\Auth\\User")
+    );
+    assert!(console.imports(UseKind::Function).is_empty());
+    assert!(console.imports(UseKind::Const).is_empty());
+}
+
+#[test]
+fn it_resolves_names_depending_on_the_namespace() {
+    assert_eq!(
+        resolve("User", UseKind::Normal, at("new User", 0)),
+        ByteString::from("App\\Models\\User")
+    );
+    assert_eq!(
+        resolve("User", UseKind::Normal, at("new User", 1)),
+        ByteString::from("Illuminate\\Foundation\\Auth\\User")
+    );
+
+    assert_eq!(
+        resolve("fmt", UseKind::Function, at("fmt(VERSION)", 0)),
+        ByteString::from("App\\Support\\format")
+    );
+    assert_eq!(
+        resolve("fmt", UseKind::Function, at("fmt(VERSION)", 1)),
+        ByteString::from("fmt")
+    );
+
+    assert_eq!(
+        resolve("Request", UseKind::Normal, at("new User", 0)),
+        ByteString::from("App\\Http\\Request")
+    );
+    assert_eq!(
+        resolve("User\\Profile", UseKind::Normal, at("new User", 1)),
+        ByteString::from("Illuminate\\Foundation\\Auth\\User\\Profile")
+    );
+    assert_eq!(
+        resolve("namespace\\Kernel", UseKind::Normal, at("new User", 1)),
+        ByteString::from("App\\Console\\Kernel")
+    );
+    assert_eq!(
+        resolve("\\User", UseKind::Normal, at("new User", 1)),
+        ByteString::from("User")
+    );
+}
+
+#[test]
+fn it_resolves_names_the_same_way_as_the_parser() {
+    let result = Parser::parse(Lexer::new(CODE));
+    let debug = format!("{:?}", result.ast);
+
+    assert!(debug.contains(r#"resolved: "App\Models\User", original: "User""#));
+    assert!(debug.contains(r#"resolved: "Illuminate\Foundation\Auth\User", original: "User""#));
+    assert!(debug.contains(r#"resolved: "App\Support\format", original: "fmt""#));
+}
+
+#[test]
+fn it_resolves_names_in_files_without_namespaces() {
+    let code = "<?php use App\\Models\\User; new User;";
+    let context = Parser::parse(Lexer::new(code)).context;
+
+    assert_eq!(context.namespaces().len(), 1);
+    assert_eq!(
+        context.resolve(
+            ByteStr::new(b"User"),
+            UseKind::Normal,
+            Span::new(code.len() - 5, code.len() - 1)
+        ),
+        ByteString::from("App\\Models\\User")
+    );
+    assert_eq!(
+        context.resolve(ByteStr::new(b"strlen"), UseKind::Function, Span::new(0, 0)),
+        ByteString::from("strlen")
+    );
+}
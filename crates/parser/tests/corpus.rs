@@ -0,0 +1,297 @@
+//! Parses real-world projects to catch the gaps that hand-written fixtures miss.
+//!
+//! Every directory in `tests/corpus` is a project. The bundled projects are checked on every test
+//! run and the summary of their diagnostics is snapshotted, so that changes in coverage show up in
+//! review. Larger projects can be downloaded with `tests/corpus/fetch.sh` and checked with:
+//!
+//!     cargo test -p pxp-parser --test corpus -- --ignored
+//!
+//! A report listing every diagnostic is written to `target/tmp/corpus/<project>.txt`.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Mutex};
+use std::time::Duration;
+
+use pxp_diagnostics::{DiagnosticKind, Severity};
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+use snappers::{snap, Snapper};
+
+/// How long a single file can take to parse before we assume the parser is stuck.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+snap!(
+    snapper,
+    corpus_appengine_php_sdk,
+    check(&corpus_directory().join("appengine-php-sdk"))
+);
+
+#[test]
+#[ignore = "needs the projects downloaded by tests/corpus/fetch.sh"]
+fn it_parses_fetched_corpora() {
+    let directory = corpus_directory().join(".fetched");
+
+    let Ok(entries) = std::fs::read_dir(&directory) else {
+        panic!("Run tests/corpus/fetch.sh to download the projects first.");
+    };
+
+    let mut projects = entries
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .collect::<Vec<_>>();
+
+    projects.sort();
+
+    for project in projects {
+        println!("{}", check(&project));
+    }
+}
+
+struct FileResult {
+    path: PathBuf,
+    outcome: Outcome,
+}
+
+enum Outcome {
+    Parsed(Vec<FileDiagnostic>),
+    TimedOut,
+}
+
+struct FileDiagnostic {
+    code: String,
+    identifier: String,
+    severity: Severity,
+    message: String,
+    line: usize,
+}
+
+/// Parse every file in the project, fail if any file that PHP accepts has an error or takes too
+/// long to parse and return a summary of the diagnostics.
+fn check(project: &Path) -> String {
+    let results = parse_project(project);
+    let report = write_report(project, &results);
+
+    let failures = results
+        .iter()
+        .filter(|result| match &result.outcome {
+            Outcome::Parsed(diagnostics) => {
+                diagnostics
+                    .iter()
+                    .any(|diagnostic| diagnostic.severity.is_error())
+                    && php_accepts(&result.path)
+            }
+            Outcome::TimedOut => true,
+        })
+        .map(|result| display(project, &result.path))
+        .collect::<Vec<_>>();
+
+    assert!(
+        failures.is_empty(),
+        "{} file(s) failed to parse, see {} for details:\n{}",
+        failures.len(),
+        report.display(),
+        failures.join("\n")
+    );
+
+    summarise(&results)
+}
+
+fn parse_project(project: &Path) -> Vec<FileResult> {
+    let files = php_files(project);
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(files.len()));
+    let workers = std::thread::available_parallelism().map_or(4, |workers| workers.get());
+
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                while let Some(path) = files.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let result = FileResult {
+                        path: path.clone(),
+                        outcome: parse_file(path),
+                    };
+
+                    results.lock().unwrap().push(result);
+                }
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by(|a, b| a.path.cmp(&b.path));
+    results
+}
+
+/// Parse the file on its own thread, so that a parser that never terminates can't hang the test.
+fn parse_file(path: &Path) -> Outcome {
+    let source = std::fs::read(path).unwrap();
+    let (sender, receiver) = mpsc::channel();
+
+    std::thread::spawn(move || {
+        let result = Parser::parse(Lexer::new(&source));
+        let diagnostics = result
+            .diagnostics
+            .iter()
+            .map(|diagnostic| FileDiagnostic {
+                code: diagnostic.kind.get_code(),
+                identifier: diagnostic.kind.get_identifier(),
+                severity: diagnostic.severity,
+                message: diagnostic.kind.get_message(),
+                line: source[..diagnostic.span.start.min(source.len())]
+                    .iter()
+                    .filter(|byte| **byte == b'\n')
+                    .count()
+                    + 1,
+            })
+            .collect();
+
+        let _ = sender.send(diagnostics);
+    });
+
+    match receiver.recv_timeout(TIMEOUT) {
+        Ok(diagnostics) => Outcome::Parsed(diagnostics),
+        Err(_) => Outcome::TimedOut,
+    }
+}
+
+/// Ask PHP's own linter whether the file is valid. Without a `php` binary on the `PATH`, every
+/// file is assumed to be valid.
+fn php_accepts(path: &Path) -> bool {
+    let php = std::env::var("PHP_BINARY").unwrap_or_else(|_| "php".to_string());
+
+    match Command::new(php).arg("-l").arg(path).output() {
+        Ok(output) => output.status.success(),
+        Err(_) => true,
+    }
+}
+
+/// A JSON summary of the project, with the number of diagnostics of each kind.
+fn summarise(results: &[FileResult]) -> String {
+    let mut kinds: BTreeMap<(String, String, String), usize> = BTreeMap::new();
+    let mut timeouts = 0;
+    let mut files_with_diagnostics = 0;
+
+    for result in results {
+        match &result.outcome {
+            Outcome::Parsed(diagnostics) => {
+                if !diagnostics.is_empty() {
+                    files_with_diagnostics += 1;
+                }
+
+                for diagnostic in diagnostics {
+                    *kinds
+                        .entry((
+                            diagnostic.code.clone(),
+                            diagnostic.identifier.clone(),
+                            severity(diagnostic.severity).to_string(),
+                        ))
+                        .or_default() += 1;
+                }
+            }
+            Outcome::TimedOut => timeouts += 1,
+        }
+    }
+
+    let diagnostics = kinds
+        .iter()
+        .map(|((code, identifier, severity), count)| {
+            format!(
+                "    {{ \"code\": \"{code}\", \"identifier\": \"{identifier}\", \"severity\": \"{severity}\", \"count\": {count} }}"
+            )
+        })
+        .collect::<Vec<_>>();
+
+    format!(
+        "{{\n  \"files\": {},\n  \"files_with_diagnostics\": {},\n  \"timeouts\": {},\n  \"diagnostics\": [{}]\n}}\n",
+        results.len(),
+        files_with_diagnostics,
+        timeouts,
+        if diagnostics.is_empty() {
+            String::new()
+        } else {
+            format!("\n{}\n  ", diagnostics.join(",\n"))
+        }
+    )
+}
+
+/// Write every diagnostic to a report that can be read when the test fails.
+fn write_report(project: &Path, results: &[FileResult]) -> PathBuf {
+    let mut report = String::new();
+
+    for result in results {
+        let path = display(project, &result.path);
+
+        match &result.outcome {
+            Outcome::Parsed(diagnostics) => {
+                for diagnostic in diagnostics {
+                    report.push_str(&format!(
+                        "{}:{}: {} {} {}\n",
+                        path,
+                        diagnostic.line,
+                        severity(diagnostic.severity),
+                        diagnostic.code,
+                        diagnostic.message
+                    ));
+                }
+            }
+            Outcome::TimedOut => {
+                report.push_str(&format!("{}: timed out after {:?}\n", path, TIMEOUT))
+            }
+        }
+    }
+
+    let directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join("corpus");
+    let path = directory.join(format!(
+        "{}.txt",
+        project.file_name().unwrap().to_string_lossy()
+    ));
+
+    std::fs::create_dir_all(&directory).unwrap();
+    std::fs::write(&path, report).unwrap();
+
+    path
+}
+
+fn severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Hint => "hint",
+        Severity::Information => "information",
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+    }
+}
+
+fn display(project: &Path, path: &Path) -> String {
+    path.strip_prefix(project)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+fn php_files(directory: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+
+    for entry in std::fs::read_dir(directory).unwrap() {
+        let path = entry.unwrap().path();
+
+        if path.is_dir() {
+            files.extend(php_files(&path));
+        } else if path.extension() == Some("php".as_ref()) {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+    files
+}
+
+fn corpus_directory() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus")
+}
+
+fn snapper() -> Snapper {
+    Snapper::new(format!("{}/{}", env!("CARGO_MANIFEST_DIR"), "tests/__snapshots__").into())
+}
@@ -0,0 +1,74 @@
+<?php
+/**
+ * Copyright 2007 Google Inc.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+/**
+ * Simple Singleton VFS wrapper to faciliate lazy initialization of the
+ * underlying vfs stream.
+ */
+
+namespace google\appengine\runtime;
+
+use org\bovigo\vfs\vfsStream;
+
+final class VirtualFileSystem {
+
+  private static $instance = null;
+
+  private $initialized = false;
+
+  /**
+   * Obtain the singleton instance of the VirtualFileSystem class.
+   */
+  public static function getInstance() {
+    if (self::$instance === null) {
+      self::$instance = new VirtualFileSystem();
+    }
+    return self::$instance;
+  }
+
+  /**
+   * Initialize the virtual file system, must be called at least once before
+   * using the vfs:: stream.
+   */
+  public function initialize() {
+    if (!$this->initialized) {
+      // Initialize direct upload and temp files using VFS.
+      vfsStream::setup('root', null, ['temp' => [], 'uploads' => []]);
+      $this->initialized = true;
+    }
+  }
+
+  /**
+   * Prevent direct construction of a new instance from outside the class.
+   */
+  private function __construct() {
+  }
+
+  /**
+   * Prevent cloning
+   */
+  private function __clone() {
+  }
+
+  /**
+   * Prevent serializetion
+   */
+  private function __wakeup() {
+  }
+}
+
+
+
@@ -0,0 +1,37 @@
+#!/usr/bin/env bash
+#
+# Download tagged releases of open-source projects into tests/corpus/.fetched, where they're
+# picked up by `cargo test -p pxp-parser --test corpus -- --ignored`.
+#
+# Every project listed here is MIT or BSD licensed. Pass project names to only fetch some of them,
+# e.g. `./fetch.sh monolog`.
+
+set -euo pipefail
+
+# name, GitHub repository and tag.
+PROJECTS=(
+    "monolog monolog/monolog 3.8.1"
+    "php-parser nikic/PHP-Parser v5.4.0"
+    "symfony-console symfony/console v7.2.1"
+    "laravel-framework laravel/framework v11.38.2"
+)
+
+DIRECTORY="$(cd "$(dirname "$0")" && pwd)/.fetched"
+
+mkdir -p "$DIRECTORY"
+
+for project in "${PROJECTS[@]}"; do
+    read -r name repository tag <<< "$project"
+
+    if [[ $# -gt 0 && ! " $* " =~ " $name " ]]; then
+        continue
+    fi
+
+    echo "Fetching $repository@$tag..."
+
+    rm -rf "${DIRECTORY:?}/$name"
+    mkdir -p "$DIRECTORY/$name"
+
+    curl -sSfL "https://codeload.github.com/$repository/tar.gz/refs/tags/$tag" \
+        | tar -xz -C "$DIRECTORY/$name" --strip-components=1
+done
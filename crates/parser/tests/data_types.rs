@@ -0,0 +1,58 @@
+use pxp_ast::{DataType, StatementKind};
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+use pxp_type::Type;
+
+#[test]
+fn it_preserves_the_grouping_of_dnf_types() {
+    assert_eq!(return_type("(A&B)|C").get_type().to_string(), "(A & B) | C");
+    assert_eq!(
+        return_type("C|(A&B)|(D&E)").get_type().to_string(),
+        "C | (A & B) | (D & E)"
+    );
+}
+
+#[test]
+fn it_knows_when_a_type_is_nullable() {
+    assert!(return_type("?int").is_nullable());
+    assert!(return_type("int|null").is_nullable());
+    assert!(return_type("(A&B)|null").is_nullable());
+    assert!(!return_type("int|string").is_nullable());
+    assert!(!return_type("mixed").is_nullable());
+}
+
+#[test]
+fn it_knows_when_a_type_allows_null() {
+    assert!(return_type("?int").allows_null());
+    assert!(return_type("mixed").allows_null());
+    assert!(return_type("null").allows_null());
+    assert!(!return_type("A&B").allows_null());
+}
+
+#[test]
+fn it_flattens_type_members() {
+    let ty = return_type("(A&B)|C|null");
+    let members = ty
+        .flattened_members()
+        .into_iter()
+        .map(|member| member.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(members, vec!["A", "B", "C", "null"]);
+    assert_eq!(
+        return_type("?int").flattened_members(),
+        vec![&Type::Integer, &Type::Null]
+    );
+}
+
+fn return_type(ty: &str) -> DataType {
+    let code = format!("<?php function a(): {} {{}}", ty);
+    let result = Parser::parse(Lexer::new(&code));
+
+    assert!(result.diagnostics.is_empty(), "{:#?}", result.diagnostics);
+
+    match &result.ast[1].kind {
+        StatementKind::Function(function) => function.return_type.clone().unwrap().data_type,
+        _ => panic!("Expected a function statement."),
+    }
+}
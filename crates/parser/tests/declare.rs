@@ -0,0 +1,53 @@
+use pxp_ast::{Statement, StatementKind};
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+
+/// Parse the code and return the names of the functions governed by the first declare.
+fn governed(code: &str) -> Vec<String> {
+    let result = Parser::parse(Lexer::new(format!("<?php {code}").as_bytes()));
+
+    assert!(result.diagnostics.is_empty());
+
+    let position = result
+        .ast
+        .iter()
+        .position(|statement| matches!(statement.kind, StatementKind::Declare(_)))
+        .unwrap();
+
+    let StatementKind::Declare(declare) = &result.ast[position].kind else {
+        unreachable!();
+    };
+
+    declare
+        .governed_statements(&result.ast[position + 1..])
+        .iter()
+        .filter_map(|statement: &Statement| match &statement.kind {
+            StatementKind::Function(function) => Some(function.name.symbol().to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn it_governs_the_rest_of_the_file_without_a_body() {
+    assert_eq!(
+        governed("function a() {} declare(ticks=1); function b() {} function c() {}"),
+        vec!["b", "c"]
+    );
+}
+
+#[test]
+fn it_governs_the_statements_in_its_body() {
+    assert_eq!(
+        governed("declare(ticks=1) { function a() {} } function b() {}"),
+        vec!["a"]
+    );
+    assert_eq!(
+        governed("declare(ticks=1): function a() {} enddeclare; function b() {}"),
+        vec!["a"]
+    );
+    assert_eq!(
+        governed("declare(ticks=1) function a() {} function b() {}"),
+        vec!["a"]
+    );
+}
@@ -0,0 +1,207 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use pxp_diagnostics::{DiagnosticCategory, DiagnosticKind};
+use pxp_lexer::Lexer;
+use pxp_parser::{Parser, ParserDiagnostic};
+
+/// Collect the codes from the `get_code()` implementation in the given source file.
+fn codes(source: &str, prefix: char) -> Vec<String> {
+    let start = source.find("fn get_code(&self)").unwrap();
+    let end = start + source[start..].find("fn get_identifier(&self)").unwrap();
+
+    source[start..end]
+        .split('"')
+        .filter(|part| part.len() == 4 && part.starts_with(prefix))
+        .map(|part| part.to_string())
+        .collect()
+}
+
+fn assert_unique(codes: &[String]) {
+    let mut seen = HashSet::new();
+
+    for code in codes {
+        assert!(
+            seen.insert(code),
+            "{} is used by more than one diagnostic",
+            code
+        );
+    }
+}
+
+#[test]
+fn it_uses_unique_codes_for_parser_diagnostics() {
+    let codes = codes(include_str!("../src/internal/diagnostics.rs"), 'P');
+
+    assert!(!codes.is_empty());
+    assert_unique(&codes);
+}
+
+#[test]
+fn it_uses_unique_codes_for_lexer_diagnostics() {
+    let codes = codes(include_str!("../../lexer/src/diagnostics.rs"), 'L');
+
+    assert!(!codes.is_empty());
+    assert_unique(&codes);
+}
+
+#[test]
+fn it_categorises_diagnostics() {
+    assert_eq!(
+        ParserDiagnostic::MissingSemicolon.get_category(),
+        DiagnosticCategory::Syntax
+    );
+    assert_eq!(
+        ParserDiagnostic::ParentUsedInClassWithoutParent.get_category(),
+        DiagnosticCategory::NameResolution
+    );
+    assert_eq!(
+        ParserDiagnostic::DeprecatedCast {
+            cast: "integer".into(),
+            canonical: "int".into(),
+        }
+        .get_category(),
+        DiagnosticCategory::Deprecation
+    );
+}
+
+/// Apply the fixes for every diagnostic in the fixture and return the fixed source, asserting that
+/// it parses without any diagnostics.
+fn fix(fixture: &str) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/fixes")
+        .join(fixture);
+    let source = std::fs::read(path).unwrap();
+    let result = Parser::parse(Lexer::new(&source));
+
+    assert!(!result.diagnostics.is_empty());
+
+    // Fixes are applied from the end of the file, so that the spans of earlier fixes stay valid.
+    let mut fixes = result
+        .diagnostics
+        .iter()
+        .map(|diagnostic| {
+            diagnostic.get_fix().unwrap_or_else(|| {
+                panic!("{} doesn't have a fix", diagnostic.kind.get_identifier())
+            })
+        })
+        .collect::<Vec<_>>();
+
+    fixes.sort_by_key(|fix| std::cmp::Reverse(fix.edits[0].span.start));
+
+    let fixed = fixes.iter().fold(source, |source, fix| fix.apply(&source));
+
+    let result = Parser::parse(Lexer::new(&fixed));
+
+    assert!(
+        result.diagnostics.is_empty(),
+        "{:?}",
+        result
+            .diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.kind.get_message())
+            .collect::<Vec<_>>()
+    );
+
+    String::from_utf8(fixed).unwrap()
+}
+
+#[test]
+fn it_fixes_missing_semicolons() {
+    assert_eq!(
+        fix("missing-semicolon.php"),
+        "<?php\n\n$name = \"pxp\";\necho $name;\n\nfunction greet() {\n    return \"hello\";\n}\n"
+    );
+}
+
+#[test]
+fn it_fixes_trailing_commas() {
+    assert_eq!(
+        fix("trailing-comma.php"),
+        "<?php\n\ninterface Shape extends Countable, Stringable {}\n\nclass Square implements Shape {}\n\nfor ($i = 0, $j = 0; $i < 10; $i++) {}\n"
+    );
+}
+
+#[test]
+fn it_fixes_cast_spellings() {
+    assert_eq!(
+        fix("casts.php"),
+        "<?php\n\n$count = (int) $value;\n$enabled = (bool) $value;\n$ratio = (float) $value;\n$bytes = (string) $value;\n$total = (float) $value;\n"
+    );
+}
+
+#[test]
+fn it_doesnt_fix_removed_casts_without_a_replacement() {
+    let result = Parser::parse(Lexer::new("<?php (unset) $value;"));
+
+    assert_eq!(result.diagnostics.len(), 1);
+    assert_eq!(
+        result.diagnostics[0].kind.get_message(),
+        "the (unset) cast is no longer supported"
+    );
+    assert!(result.diagnostics[0].get_fix().is_none());
+}
+
+/// The diagnostics for a fixture, as the source they point at and their message.
+fn located(fixture: &str) -> Vec<(String, String, Vec<String>)> {
+    let path = format!(
+        "{}/tests/fixtures/recovery/{fixture}",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let input = std::fs::read(path).unwrap();
+    let result = Parser::parse(Lexer::new(&input));
+    let text =
+        |span: pxp_span::Span| String::from_utf8_lossy(&input[span.start..span.end]).to_string();
+
+    result
+        .diagnostics
+        .iter()
+        .map(|diagnostic| {
+            (
+                text(diagnostic.span),
+                diagnostic.kind.get_message(),
+                diagnostic
+                    .related
+                    .iter()
+                    .map(|(span, message)| format!("{}: {message}", text(*span)))
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn it_points_at_the_delimiter_that_is_never_closed() {
+    assert_eq!(
+        located("unclosed-class-brace.php"),
+        vec![(
+            "{".to_string(),
+            "unclosed {, expected a matching }".to_string(),
+            vec![]
+        )]
+    );
+
+    let result = Parser::parse(Lexer::new("<?php\nfoo(\n  [1, 2,\n"));
+    let codes = result
+        .diagnostics
+        .iter()
+        .map(|diagnostic| (diagnostic.kind.get_code(), diagnostic.span.start))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        codes,
+        vec![("P092".to_string(), 13), ("P092".to_string(), 9)]
+    );
+}
+
+#[test]
+fn it_points_at_both_sides_of_a_mismatched_delimiter() {
+    assert_eq!(
+        located("mismatched-bracket.php"),
+        vec![(
+            "]".to_string(),
+            "mismatched closing delimiter ], expected )".to_string(),
+            vec!["(: ( opened here".to_string()]
+        )]
+    );
+}
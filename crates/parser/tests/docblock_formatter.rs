@@ -0,0 +1,136 @@
+use pxp_ast::{
+    visitor::{
+        walk_function_statement, walk_method, walk_simple_property, walk_statement, Visitor,
+    },
+    Comment, CommentGroup, CommentKind, DataType, DocBlockFormatter, DocBlockFormatterOptions,
+    FunctionStatement, Method, SimpleProperty, Statement, StatementKind,
+};
+use pxp_diagnostics::{DiagnosticFix, TextEdit};
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+use snappers::{snap, Snapper};
+
+snap!(
+    snapper,
+    docblock_formatter_defaults,
+    format(
+        "fixtures/docblock-formatter/messy.php",
+        DocBlockFormatterOptions::new()
+    )
+);
+snap!(
+    snapper,
+    docblock_formatter_unaligned_in_written_order,
+    format(
+        "fixtures/docblock-formatter/messy.php",
+        DocBlockFormatterOptions::new()
+            .with_align(false)
+            .with_reorder_tags(false)
+    )
+);
+snap!(
+    snapper,
+    docblock_formatter_wrapped_without_redundant_tags,
+    format(
+        "fixtures/docblock-formatter/messy.php",
+        DocBlockFormatterOptions::new()
+            .with_wrap_at(60)
+            .with_remove_redundant_tags(true)
+    )
+);
+
+#[test]
+fn it_formats_an_aligned_docblock_without_changes() {
+    let code = "<?php\n\n/**\n * @param  string $a The a.\n * @param  int    $b\n * @return void\n */\nfunction a($a, $b) {}\n";
+
+    assert_eq!(format_source(code, DocBlockFormatterOptions::new()), code);
+}
+
+fn snapper() -> Snapper {
+    Snapper::new(format!("{}/{}", env!("CARGO_MANIFEST_DIR"), "tests/__snapshots__").into())
+}
+
+fn format(path: &str, options: DocBlockFormatterOptions) -> String {
+    let input =
+        std::fs::read_to_string(format!("{}/tests/{}", env!("CARGO_MANIFEST_DIR"), path)).unwrap();
+
+    format_source(&input, options)
+}
+
+/// Format every docblock in the code, splicing the results back into it.
+fn format_source(code: &str, options: DocBlockFormatterOptions) -> String {
+    let result = Parser::parse(Lexer::new(code.as_bytes()));
+    let mut visitor = DocBlockVisitor {
+        formatter: DocBlockFormatter::new(code.as_bytes(), options),
+        edits: Vec::new(),
+    };
+
+    visitor.visit(&result.ast);
+
+    String::from_utf8(DiagnosticFix::new("format docblocks", visitor.edits).apply(code.as_bytes()))
+        .unwrap()
+}
+
+struct DocBlockVisitor<'a> {
+    formatter: DocBlockFormatter<'a>,
+    edits: Vec<TextEdit>,
+}
+
+impl<'a> DocBlockVisitor<'a> {
+    fn format(&mut self, comments: &CommentGroup, return_type: Option<&DataType>) {
+        for comment in comments.comments.iter() {
+            if let Comment {
+                kind: CommentKind::DocBlock(docblock),
+                ..
+            } = comment
+            {
+                // A docblock can be attached to both a statement and the declaration inside of it.
+                if self.edits.iter().any(|edit| edit.span == docblock.span) {
+                    continue;
+                }
+
+                let formatted = self.formatter.format(docblock, return_type);
+
+                self.edits
+                    .push(TextEdit::new(docblock.span, String::from(formatted)));
+            }
+        }
+    }
+}
+
+impl<'a> Visitor for DocBlockVisitor<'a> {
+    fn visit_statement(&mut self, node: &Statement) {
+        let return_type = match &node.kind {
+            StatementKind::Function(function) => function.return_type.as_ref(),
+            _ => None,
+        };
+
+        self.format(&node.comments, return_type.map(|r| &r.data_type));
+
+        walk_statement(self, node);
+    }
+
+    fn visit_simple_property(&mut self, node: &SimpleProperty) {
+        self.format(&node.comments, None);
+
+        walk_simple_property(self, node);
+    }
+
+    fn visit_function_statement(&mut self, node: &FunctionStatement) {
+        self.format(
+            &node.comments,
+            node.return_type.as_ref().map(|r| &r.data_type),
+        );
+
+        walk_function_statement(self, node);
+    }
+
+    fn visit_method(&mut self, node: &Method) {
+        self.format(
+            &node.comments,
+            node.return_type.as_ref().map(|r| &r.data_type),
+        );
+
+        walk_method(self, node);
+    }
+}
@@ -233,3 +233,38 @@ snap!(
     docblock_template_tag_lower_bound,
     process("fixtures/docblocks/template-tag-lower-bound.php")
 );
+snap!(
+    snapper,
+    docblock_relative_types_in_class,
+    process("fixtures/docblocks/relative-types-in-class.php")
+);
+snap!(
+    snapper,
+    docblock_relative_types_outside_class,
+    process("fixtures/docblocks/relative-types-outside-class.php")
+);
+snap!(
+    snapper,
+    docblock_names_after_namespace,
+    process("fixtures/docblocks/names-after-namespace.php")
+);
+snap!(
+    snapper,
+    docblock_throws_tag,
+    process("fixtures/docblocks/throws-tag.php")
+);
+snap!(
+    snapper,
+    docblock_param_generic_missing_comma,
+    process("fixtures/docblocks/param-generic-missing-comma.php")
+);
+snap!(
+    snapper,
+    docblock_param_unbalanced_generic,
+    process("fixtures/docblocks/param-unbalanced-generic.php")
+);
+snap!(
+    snapper,
+    docblock_param_garbled_type,
+    process("fixtures/docblocks/param-garbled-type.php")
+);
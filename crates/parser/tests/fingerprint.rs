@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use pxp_lexer::{tokenize, Lexer};
+use pxp_parser::Parser;
+
+fn fingerprint(code: &str) -> u64 {
+    Parser::parse(Lexer::new(code)).fingerprint()
+}
+
+fn fixtures(directory: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+
+    for entry in std::fs::read_dir(directory).unwrap() {
+        let path = entry.unwrap().path();
+
+        if path.is_dir() {
+            files.extend(fixtures(&path));
+        } else if path.extension() == Some("php".as_ref()) {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+    files
+}
+
+fn fixture_directory() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+/// Double every run of whitespace between tokens.
+fn reformat(source: &[u8]) -> Vec<u8> {
+    let mut output = Vec::new();
+    let mut offset = 0;
+
+    for token in tokenize(source) {
+        let gap = &source[offset..token.span.start];
+
+        output.extend_from_slice(gap);
+        output.extend_from_slice(gap);
+        output.extend_from_slice(&source[token.span.start..token.span.end]);
+
+        offset = token.span.end;
+    }
+
+    output.extend_from_slice(&source[offset..]);
+    output
+}
+
+#[test]
+fn it_ignores_whitespace_when_reformatting_fixtures() {
+    for path in fixtures(&fixture_directory()) {
+        let source = std::fs::read(&path).unwrap();
+
+        // Whitespace is significant in heredocs, strings and after closing tags.
+        if [&b"<<<"[..], b"?>", b"\""]
+            .iter()
+            .any(|needle| source.windows(needle.len()).any(|window| window == *needle))
+        {
+            continue;
+        }
+
+        let reformatted = reformat(&source);
+
+        assert_eq!(
+            Parser::parse(Lexer::new(&source)).fingerprint(),
+            Parser::parse(Lexer::new(&reformatted)).fingerprint(),
+            "{}",
+            path.display()
+        );
+    }
+}
+
+#[test]
+fn it_ignores_comments() {
+    assert_eq!(
+        fingerprint("<?php function a($b) { return $b + 1; }"),
+        fingerprint(
+            r#"<?php
+            // Adds one.
+            /**
+             * @param int $b
+             */
+            function a($b /* the number */) {
+                # Comment statements are ignored too.
+                return $b + 1; // So are trailing comments.
+            }
+            "#
+        )
+    );
+}
+
+#[test]
+fn it_changes_when_the_content_changes() {
+    let original = fingerprint("<?php $name = 'World'; echo \"Hello, {$name}\";");
+
+    for changed in [
+        "<?php $other = 'World'; echo \"Hello, {$other}\";",
+        "<?php $name = 'world'; echo \"Hello, {$name}\";",
+        "<?php $name = 'World'; echo \"Hello {$name}\";",
+        "<?php $name = 'World'; print \"Hello, {$name}\";",
+        "<?php $name = 'World'; echo \"Hello, {$name}\", 1;",
+    ] {
+        assert_ne!(original, fingerprint(changed), "{}", changed);
+    }
+
+    assert_ne!(fingerprint("<?php $a + $b;"), fingerprint("<?php $a - $b;"));
+    assert_ne!(
+        fingerprint("<?php function a($b) {}"),
+        fingerprint("<?php function a(&$b) {}")
+    );
+    assert_ne!(
+        fingerprint("<?php namespace A; new B;"),
+        fingerprint("<?php namespace C; new B;")
+    );
+}
+
+/// The debug output of the AST without ids, spans or comments, i.e. what the fingerprint covers.
+fn structure(source: &[u8]) -> Vec<String> {
+    let debug = format!("{:#?}", Parser::parse(Lexer::new(source)).ast);
+    let mut lines = Vec::new();
+    let mut skipping: Option<usize> = None;
+
+    for line in debug.lines() {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+
+        if let Some(depth) = skipping {
+            if indent > depth {
+                continue;
+            }
+
+            skipping = None;
+
+            if indent == depth && trimmed.starts_with(['}', ']', ')']) {
+                continue;
+            }
+        }
+
+        if trimmed.starts_with("id: ") {
+            continue;
+        }
+
+        if trimmed.ends_with("Span {") || trimmed.contains("CommentGroup {") {
+            skipping = Some(indent);
+            continue;
+        }
+
+        lines.push(trimmed.to_string());
+    }
+
+    lines
+}
+
+#[test]
+fn it_does_not_collide_across_fixtures() {
+    let mut seen: HashMap<u64, (PathBuf, Vec<String>)> = HashMap::new();
+
+    for path in fixtures(&fixture_directory()) {
+        let source = std::fs::read(&path).unwrap();
+        let fingerprint = Parser::parse(Lexer::new(&source)).fingerprint();
+        let structure = structure(&source);
+
+        // Fixtures that only differ by comments or insignificant syntax, e.g. a trailing comma,
+        // are expected to share a fingerprint.
+        if let Some((previous, previous_structure)) = seen.get(&fingerprint) {
+            assert_eq!(
+                previous_structure,
+                &structure,
+                "{} and {} have the same fingerprint",
+                previous.display(),
+                path.display()
+            );
+        }
+
+        seen.insert(fingerprint, (path, structure));
+    }
+}
@@ -0,0 +1,10 @@
+<?php
+
+$b = $a[];
+echo $a[]['b'];
+$a[]->b();
+isset($a[]);
+unset($a[]);
+$a[] ??= 1;
+$b = [$a[]];
+$b[$a[]] = 1;
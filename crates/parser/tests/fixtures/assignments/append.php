@@ -0,0 +1,14 @@
+<?php
+
+$a[] = 1;
+$a[][] = 2;
+$a[]['b'] = 3;
+$a[foo()][$k] = 4;
+$a->list[] = 5;
+$a[] .= 6;
+$a[]++;
+[$a[], 'b' => $b[]] = [7, 8];
+list($a[], list($b[])) = [9, [10]];
+foreach ($items as $k[] => $a[]) {}
+$b = &$a[];
+sort($a[]);
@@ -0,0 +1,6 @@
+<?php
+
+$a?->b = 1;
+$a?->b->c['d'] .= 2;
+$a?->b()->c++;
+--$a?->b;
@@ -0,0 +1,5 @@
+<?php
+
+Foo::1();
+Foo::1(...);
+Foo::;
@@ -0,0 +1,5 @@
+<?php
+
+$foo::class;
+$foo->bar()::class;
+(new Foo)::class;
@@ -0,0 +1,7 @@
+<?php
+
+function foo(#[Bar] $a = new class {}, #[Baz] $b = new #[Qux] class {}) {}
+
+class Foo {
+    public function __construct(#[Bar] private $a = new class {}) {}
+}
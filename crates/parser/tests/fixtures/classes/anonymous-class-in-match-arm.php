@@ -0,0 +1,7 @@
+<?php
+
+$handler = match ($type) {
+    'a' => $this->register(new class($type) {}, $fallback),
+    'b', 'c' => $this->register(new class implements Foo {}, new class {}),
+    default => null,
+};
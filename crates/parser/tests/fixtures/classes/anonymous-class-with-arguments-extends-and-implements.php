@@ -0,0 +1,5 @@
+<?php
+
+$a = new #[Foo] class($a, ...$c) extends Bar implements Baz, Qux {
+    public function __construct(public $a, public $b, ...$c) {}
+};
@@ -0,0 +1,4 @@
+<?php
+
+$a = new #[Foo] class {};
+$b = new #[Foo, Bar(1)] #[Baz] class {};
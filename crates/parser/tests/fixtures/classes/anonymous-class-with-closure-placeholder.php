@@ -0,0 +1,4 @@
+<?php
+
+$a = new class(...) {};
+$b = new Foo(...);
@@ -0,0 +1,12 @@
+<?php
+
+class Foo
+{
+    #[Bar]
+    use Baz {
+        #[Qux] foo as bar;
+        baz as #[Qux] protected qux;
+    }
+
+    public function method() {}
+}
@@ -0,0 +1,9 @@
+<?php
+
+$a = new class {
+    public function make() {
+        return new #[Foo] class($this) extends Bar {
+            public function __construct(public $parent) {}
+        };
+    }
+};
@@ -0,0 +1,5 @@
+<?php
+
+final readonly class Foo {}
+readonly final class Bar {}
+abstract readonly class Baz {}
@@ -0,0 +1,4 @@
+<?php
+
+clone($a);
+clone ($a)->b;
@@ -0,0 +1,21 @@
+<?php
+
+function foo() {
+    $x = 1;
+    // nothing else to do
+}
+
+if ($x) {
+    // todo
+}
+
+class Foo
+{
+    public function bar()
+    {
+        return; // early return
+        // unreachable
+    }
+
+    // more members coming soon
+}
@@ -0,0 +1,9 @@
+<?php
+
+$x = 1; /** @var int $y */
+$y = foo();
+
+/**
+ * Does a thing.
+ */
+function foo() {} // returns nothing
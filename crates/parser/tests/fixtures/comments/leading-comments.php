@@ -0,0 +1,8 @@
+<?php
+
+// explains x
+$x = 1;
+
+/* explains y */
+# and so does this
+$y = 2;
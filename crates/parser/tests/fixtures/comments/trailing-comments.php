@@ -0,0 +1,5 @@
+<?php
+
+$x = 1; // explains x
+$y = 2; /* explains y */ # and so does this
+$z = 3;
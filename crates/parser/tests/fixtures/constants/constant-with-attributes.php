@@ -0,0 +1,7 @@
+<?php
+
+#[Deprecated]
+const FOO = 1;
+
+#[A, B(1)] #[C]
+const BAR = 2, BAZ = 3;
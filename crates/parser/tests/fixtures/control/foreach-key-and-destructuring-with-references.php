@@ -0,0 +1,4 @@
+<?php
+
+foreach ($rows as $key => [[&$a, $b], 'c' => ['d' => &$d]]) {
+}
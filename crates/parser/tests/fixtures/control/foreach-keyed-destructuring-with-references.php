@@ -0,0 +1,4 @@
+<?php
+
+foreach ($rows as ['id' => $id, 'name' => &$name]) {
+}
@@ -0,0 +1,4 @@
+<?php
+
+foreach ($rows as list('a' => &$a, 'b' => list(, &$b))) {
+}
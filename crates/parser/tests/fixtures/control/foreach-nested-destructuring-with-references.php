@@ -0,0 +1,4 @@
+<?php
+
+foreach ($rows as [&$a, [$b, &$c]]) {
+}
@@ -0,0 +1,5 @@
+<?php
+
+if ($a)
+    if ($b) x();
+    else y();
@@ -0,0 +1,9 @@
+<?php
+
+if ($a):
+    x();
+else if ($b):
+    y();
+else:
+    z();
+endif;
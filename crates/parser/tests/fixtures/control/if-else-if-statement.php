@@ -0,0 +1,9 @@
+<?php
+
+if ($a) {
+
+} else if ($b) {
+
+} else {
+
+}
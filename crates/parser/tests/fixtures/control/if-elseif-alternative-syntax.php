@@ -0,0 +1,9 @@
+<?php
+
+if ($a):
+    x();
+elseif ($b):
+    y();
+else:
+    z();
+endif;
@@ -0,0 +1,7 @@
+<?php switch ($a): ?>
+<?php case 1: ?>
+  <p>One</p>
+<?php break; ?>
+<?php case 2: ?>
+  <p>Two</p>
+<?php endswitch; ?>
@@ -0,0 +1,9 @@
+<?php
+
+switch ($a):
+    case 1;
+        echo 'one';
+        break;
+    default:
+        echo 'other';
+endswitch;
@@ -0,0 +1,10 @@
+<?php
+
+switch ($a) {
+    case 1:
+    case 2;
+        break;
+    case 3
+        break;
+    default;
+}
@@ -0,0 +1,6 @@
+<?php
+
+namespace App;
+
+declare(strict_types=1);
+declare(ticks=1);
@@ -0,0 +1,5 @@
+<?php
+
+declare(ticks=1) {
+    echo 'tick';
+}
@@ -0,0 +1,9 @@
+<?php
+
+$first = $string{0};
+$nested = $matrix[0]{1};
+$property = $object->name{0};
+
+if ($string) {
+    echo $string;
+}
@@ -0,0 +1,12 @@
+<?php
+
+function connect($host = 'localhost', $port) {}
+
+function nullable(Logger $logger = null, $message) {}
+
+function variadic($separator = ',', ...$parts) {}
+
+class Connection
+{
+    public function __construct(private array $options = [], string $dsn) {}
+}
@@ -0,0 +1,3 @@
+<?php
+
+$ratio = (real) $value;
@@ -0,0 +1,3 @@
+<?php
+
+$nothing = (unset) $value;
@@ -0,0 +1,34 @@
+<?php
+
+/**
+ * Sends a greeting to someone.
+ *
+ * The greeting is printed as-is, see {@see Greeter::greet()} for details.
+ *
+ *     $greeter->send('Ryan', 3);
+ *
+ * @return void
+ * @throws    InvalidArgumentException when the name is empty
+ * @param string $name   the name of the person that is being greeted, which can be any length and is printed as-is
+ * @param int $times how many times
+ *   to greet them
+ * @custom-tag   keeps   its   spacing
+ * @param array<string, mixed>    ...$options
+ *
+ * @since 1.0
+ * @deprecated
+ */
+function send(string $name, int $times, array ...$options): void {}
+
+class Greeter
+{
+    /** @var   string|null   $name */
+    public $name;
+
+    /**
+     * @return string the greeting, see {@link https://example.com/a very long inline tag} for more
+     * @param string $name
+     * @param bool &$shout
+     */
+    public function greet(string $name, bool &$shout): string {}
+}
@@ -0,0 +1,9 @@
+<?php
+
+namespace App;
+
+/**
+ * @property Foo $foo
+ * @method Bar bar()
+ */
+class A {}
@@ -0,0 +1,7 @@
+<?php
+
+/**
+ * @param array<int string> $a The first parameter.
+ * @param int $b The second parameter.
+ */
+function a($a, $b) {}
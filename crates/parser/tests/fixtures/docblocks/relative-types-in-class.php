@@ -0,0 +1,20 @@
+<?php
+
+/** @method static create() */
+class A {
+    /**
+     * @param self $other
+     * @return $this
+     */
+    public function merge($other) {}
+
+    /** @var list<static> */
+    public $children;
+
+    public function anonymous() {
+        return new class {
+            /** @return self */
+            public function make() {}
+        };
+    }
+}
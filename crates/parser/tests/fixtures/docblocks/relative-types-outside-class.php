@@ -0,0 +1,8 @@
+<?php
+
+/**
+ * @param self $a
+ * @param parent $b
+ * @return static|$this
+ */
+function foo($a, $b) {}
@@ -0,0 +1,12 @@
+<?php
+
+namespace App;
+
+use Http\Exceptions\NotFoundException;
+
+/**
+ * @throws NotFoundException When the user doesn't exist.
+ * @throws \RuntimeException|InvalidStateException
+ * @throws
+ */
+function find() {}
@@ -0,0 +1,13 @@
+<?php
+
+enum Suit
+{
+    #[Deprecated]
+    case Hearts;
+}
+
+enum Status: string
+{
+    #[Label('Active')]
+    case Active = 'active';
+}
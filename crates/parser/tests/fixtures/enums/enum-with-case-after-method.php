@@ -0,0 +1,11 @@
+<?php
+
+enum Suit
+{
+    public function label(): string
+    {
+        return 'Hearts';
+    }
+
+    case Hearts;
+}
@@ -0,0 +1,12 @@
+<?php
+
+enum Suit: string
+{
+    case Hearts = 'H';
+
+    public function __construct() {}
+
+    public function __GET(string $name) {}
+
+    public function __call(string $name, array $arguments) {}
+}
@@ -0,0 +1,8 @@
+<?php
+
+enum Suit
+{
+    public string $label;
+
+    case Hearts;
+}
@@ -0,0 +1,30 @@
+<?php
+
+enum Suit: string implements HasColor, JsonSerializable
+{
+    use HasLabel;
+
+    const Wild = self::Spades;
+
+    case Hearts = 'H';
+
+    public static function fromChar(string $char): self
+    {
+        return self::from($char);
+    }
+
+    case Spades = 'S';
+
+    public function color(): string
+    {
+        return match ($this) {
+            self::Hearts => 'Red',
+            self::Spades => 'Black',
+        };
+    }
+
+    public function jsonSerialize(): mixed
+    {
+        return $this->value;
+    }
+}
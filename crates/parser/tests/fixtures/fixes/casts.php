@@ -0,0 +1,7 @@
+<?php
+
+$count = (integer) $value;
+$enabled = (boolean) $value;
+$ratio = ( double ) $value;
+$bytes = (binary) $value;
+$total = (real) $value;
@@ -0,0 +1,8 @@
+<?php
+
+$name = "pxp"
+echo $name;
+
+function greet() {
+    return "hello"
+}
@@ -0,0 +1,7 @@
+<?php
+
+interface Shape extends Countable, Stringable, {}
+
+class Square implements Shape, {}
+
+for ($i = 0, $j = 0,; $i < 10; $i++) {}
@@ -0,0 +1,4 @@
+<?php
+
+$login = function (string $user, #[SensitiveParameter] string $password) {};
+$hash = fn (#[SensitiveParameter] $password) => $password;
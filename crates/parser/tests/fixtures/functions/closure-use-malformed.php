@@ -0,0 +1,5 @@
+<?php
+
+$f = function () use ($x + 1, $y, 2, &$z) {
+    return $y;
+};
@@ -0,0 +1,7 @@
+<?php
+
+class Foo {
+    public function bar() {
+        return function () use ($this) {};
+    }
+}
@@ -0,0 +1,5 @@
+<?php
+
+$exit = exit(...);
+$die = die(...);
+array_map(exit(...), $codes);
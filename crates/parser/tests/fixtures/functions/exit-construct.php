@@ -0,0 +1,6 @@
+<?php
+
+exit;
+exit();
+exit(1);
+die("failed");
@@ -0,0 +1,4 @@
+<?php
+
+exit(status: 1);
+die(status: "failed");
@@ -0,0 +1,10 @@
+<?php
+
+function configure($mode = $default = 1, $flags = flags(), $name = "{$prefix}name") {}
+
+$sort = fn ($offset = $start++) => $offset;
+
+class Config
+{
+    public function __construct(private array $options = [new Option(self::NAME), $extra]) {}
+}
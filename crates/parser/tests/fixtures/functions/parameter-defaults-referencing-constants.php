@@ -0,0 +1,9 @@
+<?php
+
+namespace App;
+
+use App\Enums\SortMode as Mode;
+
+$sort = fn ($mode = Mode::Asc, $flags = JSON_THROW_ON_ERROR | JSON_PRETTY_PRINT) => $mode;
+
+$closure = function ($mode = Mode::Desc, $fallback = \App\DEFAULT_MODE) {};
@@ -0,0 +1,11 @@
+<?php
+
+interface A
+{
+    public function a()
+    {
+        return 1;
+    }
+
+    public function b();
+}
@@ -0,0 +1,13 @@
+<?php
+
+abstract class A
+{
+    abstract public function a()
+    {
+        return 1;
+    }
+
+    public function b()
+    {
+    }
+}
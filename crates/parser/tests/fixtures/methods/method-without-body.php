@@ -0,0 +1,13 @@
+<?php
+
+abstract class A
+{
+    public function a();
+
+    abstract public function b();
+}
+
+trait B
+{
+    public static function c(): int;
+}
@@ -0,0 +1,23 @@
+<?php
+
+namespace App;
+
+new Request();
+
+use Http\Request;
+
+new Request();
+new Request\Factory();
+
+use function Http\respond;
+
+respond(Request::class);
+
+namespace Other;
+
+new Request();
+respond();
+
+use Http\{Request as BaseRequest, Response};
+
+new BaseRequest(new Response());
@@ -0,0 +1,6 @@
+<?php
+
+abstract class A
+{
+    abstract public function __construct(protected int $id);
+}
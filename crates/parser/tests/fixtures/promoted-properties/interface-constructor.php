@@ -0,0 +1,6 @@
+<?php
+
+interface A
+{
+    public function __construct(public int $id);
+}
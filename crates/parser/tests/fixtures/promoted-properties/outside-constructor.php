@@ -0,0 +1,6 @@
+<?php
+
+class A
+{
+    public function setName(private string $name) {}
+}
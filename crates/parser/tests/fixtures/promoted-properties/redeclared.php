@@ -0,0 +1,10 @@
+<?php
+
+class A
+{
+    public int $id;
+
+    public function __construct(public int $id, private string $name) {}
+
+    private string $name;
+}
@@ -0,0 +1,6 @@
+<?php
+
+class A
+{
+    public function __construct(public string ...$names) {}
+}
@@ -0,0 +1,8 @@
+<?php
+
+class A
+{
+    public string $a {
+        get => __PROPERTY__;
+    }
+}
@@ -0,0 +1,5 @@
+<?php
+
+$total = max($a, $b];
+
+echo $total;
@@ -0,0 +1,11 @@
+<?php
+
+foo()
+bar();
+
+function baz() {
+    return 1
+}
+
+echo 1
+if ($a) {}
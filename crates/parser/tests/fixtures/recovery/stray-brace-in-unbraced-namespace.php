@@ -0,0 +1,13 @@
+<?php
+
+namespace App;
+
+class Foo
+{
+    public function bar()
+    {
+    }
+}
+}
+
+function baz() {}
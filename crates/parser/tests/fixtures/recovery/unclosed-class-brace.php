@@ -0,0 +1,12 @@
+<?php
+
+class Foo {
+    public function bar()
+    {
+        return [1, 2, 3];
+    }
+
+function baz(int $a): int
+{
+    return $a * 2;
+}
@@ -0,0 +1,4 @@
+<?php
+
+Foo::BAR::class;
+Foo::{$bar}::CLASS;
@@ -0,0 +1,3 @@
+<?php
+
+(clone $obj)::method();
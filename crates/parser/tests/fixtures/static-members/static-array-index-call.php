@@ -0,0 +1,3 @@
+<?php
+
+static::$handlers[0]::handle();
@@ -0,0 +1,2 @@
+<p>Before</p>
+<?php echo $a ?>
\ No newline at end of file
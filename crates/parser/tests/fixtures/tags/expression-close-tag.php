@@ -0,0 +1,2 @@
+<?php $a = 1 ?>
+<p>After</p>
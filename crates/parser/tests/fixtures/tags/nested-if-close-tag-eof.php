@@ -0,0 +1,3 @@
+<?php if ($a): ?>
+    <?php if ($b): echo $b ?><?php endif ?>
+<?php endif ?>
\ No newline at end of file
@@ -0,0 +1,8 @@
+<?php if ($a): ?>
+    <?php if ($b) echo $b ?>
+    <p>Inside</p>
+    <?php if ($c): $c++ ?>
+        <p>Nested</p>
+    <?php endif ?>
+<?php endif ?>
+<p>After</p>
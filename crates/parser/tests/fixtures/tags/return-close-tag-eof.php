@@ -0,0 +1,2 @@
+<p>Before</p>
+<?php return $a ?>
\ No newline at end of file
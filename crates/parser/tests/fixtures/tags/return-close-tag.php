@@ -0,0 +1,2 @@
+<?php return $a ?>
+<p>After</p>
@@ -0,0 +1,7 @@
+<?php global $a ?>
+<?php static $b = 1 ?>
+<?php const C = 1 ?>
+<?php use D\E ?>
+<?php goto f ?>
+<?php do { $g++; } while ($g < 10) ?>
+<p>After</p>
@@ -0,0 +1,11 @@
+<?php
+
+class Foo
+{
+    use A, B, C {
+        A::hello insteadof B, C;
+        B::hello as protected greet;
+        C::hello as private;
+        world as universe;
+    }
+}
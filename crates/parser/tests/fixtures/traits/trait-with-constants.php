@@ -0,0 +1,7 @@
+<?php
+
+trait Foo {
+    const BAR = 1;
+    public const BAZ = 2, QUX = 3;
+    final protected const int QUUX = 4;
+}
@@ -0,0 +1,7 @@
+<?php
+
+class A {
+    const callable B = 'strlen';
+
+    const array|callable C = [];
+}
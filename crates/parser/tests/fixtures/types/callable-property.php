@@ -0,0 +1,14 @@
+<?php
+
+class A {
+    public callable $a;
+
+    public ?callable $b;
+
+    public function __construct(
+        private callable $c,
+        callable $d,
+    ) {}
+
+    public function e(callable $e): callable {}
+}
@@ -0,0 +1,5 @@
+<?php
+
+function a(Iterable $a, INT $b, ?Callable $c, Mixed $d): Void {}
+
+function b(Self $a, Stringable $b) {}
@@ -0,0 +1,3 @@
+<?php
+
+function a((A&B)|C $a, C|(A&B)|null $b): (A&B)|(C&D) {}
@@ -0,0 +1,3 @@
+<?php
+
+function a(int|string|int $a, A&B&A $b) {}
@@ -0,0 +1,3 @@
+<?php
+
+function a(int&string $a, A&array $b) {}
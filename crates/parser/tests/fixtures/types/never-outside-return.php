@@ -0,0 +1,7 @@
+<?php
+
+function a(never $a) {}
+
+class A {
+    public function b(int|never $b) {}
+}
@@ -0,0 +1,29 @@
+<?php
+
+class A {
+    public function a(): parent {}
+}
+
+class B extends A {
+    public function b(parent $b): parent {}
+}
+
+interface C {
+    public function c(parent $c);
+}
+
+trait D {
+    public function d(): parent {}
+}
+
+enum E {
+    public function e(): parent {}
+}
+
+$f = new class {
+    public function f(): parent {}
+};
+
+$g = new class extends A {
+    public function g(): parent {}
+};
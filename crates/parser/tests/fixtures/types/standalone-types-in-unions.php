@@ -0,0 +1,3 @@
+<?php
+
+function a(?true $a, false|null $b, true|string $c): null|false {}
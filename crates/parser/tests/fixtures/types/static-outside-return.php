@@ -0,0 +1,13 @@
+<?php
+
+function a(static $a) {}
+
+class A {
+    public static $b;
+
+    public function c(): static {}
+
+    public function d(): ?static {}
+
+    const static E = null;
+}
@@ -0,0 +1,3 @@
+<?php
+
+function a(true|false $a, bool|false $b) {}
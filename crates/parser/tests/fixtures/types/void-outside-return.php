@@ -0,0 +1,7 @@
+<?php
+
+function a(void $a): void {}
+
+class A {
+    const void B = null;
+}
@@ -0,0 +1,5 @@
+<?php
+
+$user?->address['city'];
+$a?->b?->c()['x'];
+$a->b = $c?->d['e'];
@@ -0,0 +1,11 @@
+<?php
+
+/**
+ * @param (A&B)|null $a
+ * @return never
+ */
+function a($a, ...$rest) {
+    $b = ['c' => 1] + [...$rest];
+
+    return $a->b ?? b(...$rest);
+}
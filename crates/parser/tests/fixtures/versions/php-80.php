@@ -0,0 +1,8 @@
+<?php
+
+function a(?A $a) {
+    return match ($a?->b) {
+        1 => b(c: 2),
+        default => null,
+    };
+}
@@ -0,0 +1,14 @@
+<?php
+
+enum Suit: string {
+    case Hearts = 'H';
+}
+
+class A {
+    public function __construct(private readonly int $a) {}
+
+    public function b(): never {
+        $c = strlen(...);
+        $this->b(...['a' => 1]);
+    }
+}
@@ -0,0 +1,7 @@
+<?php
+
+readonly class A {
+    public function b((B&C)|null $b): void {
+        $this?->b(b: null);
+    }
+}
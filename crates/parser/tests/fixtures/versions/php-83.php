@@ -0,0 +1,5 @@
+<?php
+
+interface A {
+    const string B = 'b';
+}
@@ -0,0 +1,14 @@
+<?php
+
+class A {
+    public private(set) string $a;
+
+    public string $b {
+        get => 'b';
+    }
+
+    public function c() {
+        $d = die(...);
+        exit(status: 1);
+    }
+}
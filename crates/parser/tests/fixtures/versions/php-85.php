@@ -0,0 +1,4 @@
+<?php
+
+$a = clone(...);
+$b = clone($a);
@@ -0,0 +1,9 @@
+<?php
+
+function a(): null {}
+
+function b(false $b): int|null {}
+
+class C {
+    public true $c = true;
+}
@@ -0,0 +1,133 @@
+use pxp_ast::{ArithmeticOperationKind, ClassishMember, ExpressionKind, StatementKind};
+use pxp_diagnostics::DiagnosticKind;
+use pxp_parser::{Fragment, FragmentKind, FragmentResult, Parser};
+
+fn diagnostics(result: &FragmentResult) -> Vec<String> {
+    result
+        .diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.kind.get_identifier())
+        .collect()
+}
+
+#[test]
+fn it_parses_expression_fragments() {
+    let result = Parser::parse_fragment(b"$a + $b * 2", FragmentKind::Expression);
+
+    assert!(result.diagnostics.is_empty());
+
+    let Fragment::Expression(expression) = result.fragment else {
+        panic!("expected an expression, got {:?}", result.fragment);
+    };
+
+    let ExpressionKind::ArithmeticOperation(operation) = expression.kind else {
+        panic!(
+            "expected an arithmetic operation, got {:?}",
+            expression.kind
+        );
+    };
+
+    let ArithmeticOperationKind::Addition { left, right, .. } = operation.kind else {
+        panic!("expected an addition, got {:?}", operation.kind);
+    };
+
+    assert!(matches!(left.kind, ExpressionKind::Variable(_)));
+    assert!(matches!(
+        right.kind,
+        ExpressionKind::ArithmeticOperation(ref operation)
+            if matches!(operation.kind, ArithmeticOperationKind::Multiplication { .. })
+    ));
+}
+
+#[test]
+fn it_allows_a_trailing_semicolon_after_expression_fragments() {
+    let result = Parser::parse_fragment(b"$a->b();", FragmentKind::Expression);
+
+    assert!(result.diagnostics.is_empty());
+    assert!(matches!(
+        result.fragment,
+        Fragment::Expression(ref expression)
+            if matches!(expression.kind, ExpressionKind::MethodCall(_))
+    ));
+}
+
+#[test]
+fn it_reports_trailing_tokens_after_expression_fragments() {
+    for source in ["$a + $b $c", "$a; $b;", "$a)"] {
+        let result = Parser::parse_fragment(source.as_bytes(), FragmentKind::Expression);
+
+        assert_eq!(
+            diagnostics(&result),
+            vec!["parser.unexpected-token"],
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn it_parses_statement_fragments() {
+    let result = Parser::parse_fragment(
+        b"foreach ($items as $key => $item) {\n    echo $key, $item;\n}\n$done = true;",
+        FragmentKind::Statements,
+    );
+
+    assert!(result.diagnostics.is_empty());
+
+    let Fragment::Statements(statements) = result.fragment else {
+        panic!("expected statements, got {:?}", result.fragment);
+    };
+
+    assert_eq!(statements.len(), 2);
+    assert!(matches!(statements[0].kind, StatementKind::Foreach(_)));
+    assert!(matches!(statements[1].kind, StatementKind::Expression(_)));
+}
+
+#[test]
+fn it_parses_class_member_fragments() {
+    let result = Parser::parse_fragment(
+        b"public function first(): static { return $this; }\n\nabstract protected function second(int $a): int;",
+        FragmentKind::ClassMembers,
+    );
+
+    assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+
+    let Fragment::ClassMembers(members) = result.fragment else {
+        panic!("expected class members, got {:?}", result.fragment);
+    };
+
+    let names = members
+        .iter()
+        .map(|member| match member {
+            ClassishMember::Method(method) => method.name.symbol.to_string(),
+            _ => panic!("expected a method, got {:?}", member),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(names, vec!["first", "second"]);
+}
+
+#[test]
+fn it_reports_stray_closing_braces_in_class_member_fragments() {
+    let result = Parser::parse_fragment(b"public $a; } public $b;", FragmentKind::ClassMembers);
+
+    assert_eq!(diagnostics(&result), vec!["parser.unexpected-token"]);
+    assert!(matches!(result.fragment, Fragment::ClassMembers(ref members) if members.len() == 2));
+}
+
+#[test]
+fn it_shifts_the_spans_of_embedded_code() {
+    let result = Parser::parse_embedded(b"$a = 1; $b = ;", 20);
+
+    assert_eq!(result.ast[0].span.start, 20);
+    assert_eq!(
+        result
+            .diagnostics
+            .iter()
+            .map(|diagnostic| (diagnostic.kind.get_identifier(), diagnostic.span.start))
+            .collect::<Vec<_>>(),
+        vec![
+            ("parser.unexpected-token".to_string(), 33),
+            ("parser.unexpected-end-of-file".to_string(), 33),
+        ]
+    );
+}
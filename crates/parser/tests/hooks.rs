@@ -0,0 +1,125 @@
+use std::ops::ControlFlow;
+
+use pxp_diagnostics::{Diagnostic, DiagnosticKind, Severity};
+use pxp_lexer::Lexer;
+use pxp_parser::{Parser, ParserDiagnostic, ParserHooks, ParserOptions};
+use pxp_span::Span;
+
+struct CancelAfter {
+    limit: usize,
+    seen: Vec<(usize, Span)>,
+}
+
+impl ParserHooks for CancelAfter {
+    fn on_statement_parsed(&mut self, index: usize, span: Span) -> ControlFlow<()> {
+        self.seen.push((index, span));
+
+        if self.seen.len() >= self.limit {
+            return ControlFlow::Break(());
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+#[derive(Default)]
+struct CollectDiagnostics {
+    diagnostics: Vec<Diagnostic<ParserDiagnostic>>,
+}
+
+impl ParserHooks for CollectDiagnostics {
+    fn on_diagnostic(&mut self, diagnostic: &Diagnostic<ParserDiagnostic>) {
+        self.diagnostics.push(diagnostic.clone());
+    }
+}
+
+#[test]
+fn it_stops_parsing_when_a_hook_cancels() {
+    let mut hooks = CancelAfter {
+        limit: 3,
+        seen: Vec::new(),
+    };
+
+    let result = Parser::parse_with_hooks(
+        Lexer::new("<?php $a = 1; $b = 2; $c = 3; $d = 4; $e = 5;"),
+        ParserOptions::default(),
+        &mut hooks,
+    );
+
+    assert!(result.cancelled);
+    assert_eq!(result.ast.len(), 3);
+    assert_eq!(
+        hooks.seen,
+        result
+            .ast
+            .iter()
+            .enumerate()
+            .map(|(index, statement)| (index, statement.span))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn it_does_not_cancel_when_hooks_allow_it() {
+    let mut hooks = CancelAfter {
+        limit: 100,
+        seen: Vec::new(),
+    };
+
+    let result = Parser::parse_with_hooks(
+        Lexer::new("<?php $a = 1; $b = 2;"),
+        ParserOptions::default(),
+        &mut hooks,
+    );
+
+    assert!(!result.cancelled);
+    assert_eq!(hooks.seen.len(), result.ast.len());
+    assert!(!Parser::parse(Lexer::new("<?php $a = 1;")).cancelled);
+}
+
+#[test]
+fn it_streams_diagnostics_as_they_are_produced() {
+    let mut hooks = CollectDiagnostics::default();
+    let input = "<?php enum Foo { public $bar; } $a = ; function () {} class { }";
+
+    let result = Parser::parse_with_hooks(Lexer::new(input), ParserOptions::default(), &mut hooks);
+
+    assert!(!result.diagnostics.is_empty());
+    assert_eq!(
+        summarise(&hooks.diagnostics),
+        summarise(&result.diagnostics)
+    );
+}
+
+#[test]
+fn it_streams_the_too_many_errors_diagnostic() {
+    let mut hooks = CollectDiagnostics::default();
+
+    let result = Parser::parse_with_hooks(
+        Lexer::new("<?php ) ] } ) ] } ) ] }"),
+        ParserOptions::new().with_max_diagnostics(2),
+        &mut hooks,
+    );
+
+    assert_eq!(
+        summarise(&hooks.diagnostics),
+        summarise(&result.diagnostics)
+    );
+    assert!(matches!(
+        hooks.diagnostics.last().unwrap().kind,
+        ParserDiagnostic::TooManyErrors
+    ));
+}
+
+fn summarise(diagnostics: &[Diagnostic<ParserDiagnostic>]) -> Vec<(String, Severity, Span)> {
+    diagnostics
+        .iter()
+        .map(|diagnostic| {
+            (
+                diagnostic.kind.get_message(),
+                diagnostic.severity,
+                diagnostic.span,
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,168 @@
+use pxp_ast::{
+    Expression, ExpressionKind, IfBranchKind, IfStatement, IfStatementBody, Statement,
+    StatementKind, Variable,
+};
+use pxp_diagnostics::DiagnosticKind;
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+
+fn parse(code: &str) -> (Vec<Statement>, Vec<String>) {
+    let result = Parser::parse(Lexer::new(format!("<?php {code}").as_bytes()));
+
+    let codes = result
+        .diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.kind.get_code())
+        .collect();
+
+    (result.ast, codes)
+}
+
+fn first_if(ast: &[Statement]) -> &IfStatement {
+    ast.iter()
+        .find_map(|statement| match &statement.kind {
+            StatementKind::If(statement) => Some(statement.as_ref()),
+            _ => None,
+        })
+        .expect("expected an if statement")
+}
+
+fn variable(expression: &Expression) -> String {
+    match &expression.kind {
+        ExpressionKind::Variable(variable) => match variable.as_ref() {
+            Variable::SimpleVariable(variable) => variable.symbol.to_string(),
+            _ => panic!("expected a simple variable"),
+        },
+        _ => panic!("expected a variable"),
+    }
+}
+
+/// The kind and condition of each branch, and the number of statements in the `else`.
+fn branches(code: &str) -> (Vec<(IfBranchKind, String)>, Option<usize>) {
+    let (ast, diagnostics) = parse(code);
+    assert!(diagnostics.is_empty(), "{diagnostics:?}");
+
+    let branches = first_if(&ast).branches();
+
+    (
+        branches
+            .branches
+            .iter()
+            .map(|branch| (branch.kind, variable(branch.condition)))
+            .collect(),
+        branches.r#else.map(|statements| statements.len()),
+    )
+}
+
+#[test]
+fn it_binds_a_dangling_else_to_the_inner_if() {
+    let (ast, diagnostics) = parse("if ($a) if ($b) x(); else y();");
+    assert!(diagnostics.is_empty());
+
+    let IfStatementBody::Statement(outer) = &first_if(&ast).body else {
+        panic!("expected a statement body");
+    };
+
+    assert!(outer.r#else.is_none());
+
+    let StatementKind::If(inner) = &outer.statement.kind else {
+        panic!("expected a nested if statement");
+    };
+    let IfStatementBody::Statement(inner) = &inner.body else {
+        panic!("expected a statement body");
+    };
+
+    assert!(inner.r#else.is_some());
+}
+
+#[test]
+fn it_distinguishes_elseif_from_else_if() {
+    let (ast, _) = parse("if ($a) {} elseif ($b) {}");
+    let IfStatementBody::Statement(body) = &first_if(&ast).body else {
+        panic!("expected a statement body");
+    };
+
+    assert_eq!(body.elseifs.len(), 1);
+    assert!(body.r#else.is_none());
+
+    let (ast, _) = parse("if ($a) {} else if ($b) {}");
+    let IfStatementBody::Statement(body) = &first_if(&ast).body else {
+        panic!("expected a statement body");
+    };
+
+    assert!(body.elseifs.is_empty());
+    assert!(body.r#else.as_ref().unwrap().nested_if().is_some());
+
+    let (ast, _) = parse("if ($a) {} else { if ($b) {} }");
+    let IfStatementBody::Statement(body) = &first_if(&ast).body else {
+        panic!("expected a statement body");
+    };
+
+    assert!(body.r#else.as_ref().unwrap().nested_if().is_none());
+}
+
+#[test]
+fn it_flattens_elseif_and_else_if_into_the_same_branches() {
+    let expected = vec![
+        (IfBranchKind::If, "$a".to_string()),
+        (IfBranchKind::ElseIf, "$b".to_string()),
+        (IfBranchKind::ElseNestedIf, "$c".to_string()),
+        (IfBranchKind::ElseIf, "$d".to_string()),
+    ];
+
+    assert_eq!(
+        branches("if ($a) {} elseif ($b) {} else if ($c) {} elseif ($d) {} else { x(); }"),
+        (expected.clone(), Some(1))
+    );
+
+    let (kinds, r#else) = branches("if ($a): elseif ($b): elseif ($c): elseif ($d): endif;");
+
+    assert_eq!(
+        kinds
+            .iter()
+            .map(|(_, condition)| condition)
+            .collect::<Vec<_>>(),
+        expected
+            .iter()
+            .map(|(_, condition)| condition)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(r#else, None);
+}
+
+#[test]
+fn it_does_not_flatten_an_if_inside_of_braces() {
+    assert_eq!(
+        branches("if ($a) x(); else { if ($b) y(); }"),
+        (vec![(IfBranchKind::If, "$a".to_string())], Some(1))
+    );
+}
+
+#[test]
+fn it_follows_else_if_into_the_alternative_syntax() {
+    assert_eq!(
+        branches("if ($a) x(); else if ($b): y(); z(); else: z(); endif;"),
+        (
+            vec![
+                (IfBranchKind::If, "$a".to_string()),
+                (IfBranchKind::ElseNestedIf, "$b".to_string()),
+            ],
+            Some(1)
+        )
+    );
+}
+
+#[test]
+fn it_reports_else_if_in_the_alternative_syntax() {
+    let (ast, diagnostics) = parse("if ($a): x(); else if ($b): y(); else: z(); endif; w();");
+
+    assert_eq!(diagnostics, vec!["P100"]);
+    assert_eq!(ast.len(), 3);
+
+    let IfStatementBody::Block(body) = &first_if(&ast).body else {
+        panic!("expected a block body");
+    };
+
+    assert_eq!(body.elseifs.len(), 1);
+    assert!(body.r#else.is_some());
+}
@@ -0,0 +1,134 @@
+use std::path::Path;
+
+use pxp_ast::visitor::{Ancestors, NodeVisitor, NodeVisitorEscapeHatch};
+use pxp_ast::{AstIterator, IterNodes, Method, Node, Statement};
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+
+fn parse(code: &[u8]) -> Vec<Statement> {
+    Parser::parse(Lexer::new(code)).ast
+}
+
+#[derive(Default)]
+struct RecordingVisitor<'a> {
+    depth: usize,
+    nodes: Vec<(Node<'a>, usize)>,
+}
+
+impl<'a> NodeVisitor<'a> for RecordingVisitor<'a> {
+    fn enter(&mut self, node: Node<'a>, _: &mut Ancestors<'a>) -> NodeVisitorEscapeHatch {
+        self.nodes.push((node, self.depth));
+        self.depth += 1;
+
+        NodeVisitorEscapeHatch::Continue
+    }
+
+    fn leave(&mut self, _: Node<'a>, _: &mut Ancestors<'a>) -> NodeVisitorEscapeHatch {
+        self.depth -= 1;
+
+        NodeVisitorEscapeHatch::Continue
+    }
+}
+
+fn fixtures(directory: &Path, files: &mut Vec<std::path::PathBuf>) {
+    for entry in std::fs::read_dir(directory).unwrap() {
+        let path = entry.unwrap().path();
+
+        if path.is_dir() {
+            fixtures(&path, files);
+        } else if path.extension().is_some_and(|extension| extension == "php") {
+            files.push(path);
+        }
+    }
+}
+
+#[test]
+fn it_visits_nodes_in_the_same_order_as_the_node_visitor() {
+    let mut files = Vec::new();
+    fixtures(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures"),
+        &mut files,
+    );
+
+    assert!(!files.is_empty());
+
+    for file in files {
+        let ast = parse(&std::fs::read(&file).unwrap());
+        let mut visitor = RecordingVisitor::default();
+        visitor.traverse(&ast);
+
+        let nodes = ast.iter_nodes().collect::<Vec<_>>();
+
+        assert_eq!(nodes, visitor.nodes, "{}", file.display());
+    }
+}
+
+#[test]
+fn it_walks_structurally_equal_asts_in_lockstep() {
+    let a = parse(b"<?php class A { public function a($b) { return [$b, fn () => $b + 1]; } }");
+    let b = parse(b"<?php\n\nclass A\n{\n    public function a($b)\n    {\n        return [$b, fn () => $b + 1];\n    }\n}\n");
+
+    let mut left = a.iter_nodes();
+    let mut right = b.iter_nodes();
+    let mut count = 0;
+
+    // Stop halfway through, then carry on where we left off.
+    for ((l, ld), (r, rd)) in left.by_ref().zip(right.by_ref()).take(10) {
+        assert_eq!((l.name(), ld), (r.name(), rd));
+        count += 1;
+    }
+
+    for ((l, ld), (r, rd)) in left.by_ref().zip(right.by_ref()) {
+        assert_eq!((l.name(), ld), (r.name(), rd));
+        count += 1;
+    }
+
+    assert_eq!(count, a.iter_nodes().count());
+    assert!(left.next().is_none());
+    assert!(right.next().is_none());
+}
+
+#[test]
+fn it_skips_the_children_of_a_node() {
+    let ast = parse(b"<?php class A { public function a() { $b; } } $c;");
+    let mut iterator = ast.iter_nodes();
+    let mut names = Vec::new();
+
+    while let Some((node, _)) = iterator.next() {
+        if node.is_class_body() {
+            iterator.skip_children();
+        }
+
+        if let Some(variable) = node.as_simple_variable() {
+            names.push(variable.symbol.to_string());
+        }
+    }
+
+    assert_eq!(names, vec!["$c"]);
+}
+
+#[test]
+fn it_iterates_over_nodes_of_a_given_type() {
+    let ast = parse(b"<?php class A { public function a() {} } class B { public function b() {} }");
+    let methods = ast
+        .iter_nodes_of::<Method>()
+        .map(|(method, depth)| (method.name.symbol.to_string(), depth))
+        .collect::<Vec<_>>();
+
+    assert_eq!(methods.len(), 2);
+    assert_eq!(methods[0].0, "a");
+    assert_eq!(methods[1].0, "b");
+    assert_eq!(methods[0].1, methods[1].1);
+}
+
+#[test]
+fn it_reuses_the_stack_when_reset() {
+    let a = parse(b"<?php $a;");
+    let b = parse(b"<?php $b; $c;");
+
+    let mut iterator = AstIterator::new(&a);
+    assert_eq!(iterator.by_ref().count(), a.iter_nodes().count());
+
+    iterator.reset(&b);
+    assert_eq!(iterator.count(), b.iter_nodes().count());
+}
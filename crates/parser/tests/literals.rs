@@ -0,0 +1,292 @@
+use pxp_ast::literals::{decode_escapes, EscapeError, IntegerBase, IntegerValue, StringQuote};
+use pxp_ast::{ExpressionKind, Literal, StatementKind};
+use pxp_diagnostics::{DiagnosticKind, Severity};
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+
+fn literal(source: &str) -> Literal {
+    let result = Parser::parse(Lexer::new(format!("<?php {source};").as_bytes()));
+
+    result
+        .ast
+        .into_iter()
+        .find_map(|statement| match statement.kind {
+            StatementKind::Expression(statement) => match statement.expression.kind {
+                ExpressionKind::Literal(literal) => Some(*literal),
+                _ => None,
+            },
+            _ => None,
+        })
+        .unwrap()
+}
+
+fn integer(source: &str) -> Option<IntegerValue> {
+    literal(source).integer_value()
+}
+
+#[test]
+fn it_decodes_integers_in_every_base() {
+    assert_eq!(integer("0"), Some(IntegerValue::Int(0)));
+    assert_eq!(integer("42"), Some(IntegerValue::Int(42)));
+    assert_eq!(integer("0x1A"), Some(IntegerValue::Int(26)));
+    assert_eq!(integer("0XFF"), Some(IntegerValue::Int(255)));
+    assert_eq!(integer("0b101"), Some(IntegerValue::Int(5)));
+    assert_eq!(integer("0B11"), Some(IntegerValue::Int(3)));
+    assert_eq!(integer("0o17"), Some(IntegerValue::Int(15)));
+    assert_eq!(integer("0O17"), Some(IntegerValue::Int(15)));
+    assert_eq!(integer("017"), Some(IntegerValue::Int(15)));
+
+    assert_eq!(literal("0").integer_base(), Some(IntegerBase::Decimal));
+    assert_eq!(literal("10").integer_base(), Some(IntegerBase::Decimal));
+    assert_eq!(
+        literal("0x1").integer_base(),
+        Some(IntegerBase::Hexadecimal)
+    );
+    assert_eq!(literal("0b1").integer_base(), Some(IntegerBase::Binary));
+    assert_eq!(literal("0o1").integer_base(), Some(IntegerBase::Octal));
+    assert_eq!(literal("01").integer_base(), Some(IntegerBase::Octal));
+}
+
+#[test]
+fn it_decodes_integers_with_separators() {
+    assert_eq!(integer("1_000_000"), Some(IntegerValue::Int(1_000_000)));
+    assert_eq!(integer("0x7F_FF"), Some(IntegerValue::Int(0x7FFF)));
+    assert_eq!(integer("0b1111_0000"), Some(IntegerValue::Int(0b1111_0000)));
+    assert_eq!(integer("0o7_7"), Some(IntegerValue::Int(0o77)));
+    assert_eq!(integer("07_7"), Some(IntegerValue::Int(0o77)));
+}
+
+#[test]
+fn it_converts_integers_that_overflow_to_floats() {
+    assert_eq!(
+        integer("9223372036854775807"),
+        Some(IntegerValue::Int(i64::MAX))
+    );
+    assert_eq!(
+        integer("9223372036854775808"),
+        Some(IntegerValue::Overflow(9223372036854775808.0))
+    );
+    assert_eq!(
+        integer("99_999_999_999_999_999_999"),
+        Some(IntegerValue::Overflow(1e20))
+    );
+    assert_eq!(
+        integer("0x7FFFFFFFFFFFFFFF"),
+        Some(IntegerValue::Int(i64::MAX))
+    );
+    assert_eq!(
+        integer("0xFFFFFFFFFFFFFFFF"),
+        Some(IntegerValue::Overflow(18446744073709551615.0))
+    );
+    assert_eq!(
+        integer("0b1_0000000000000000000000000000000000000000000000000000000000000000"),
+        Some(IntegerValue::Overflow(18446744073709551616.0))
+    );
+    assert_eq!(
+        integer("01000000000000000000000"),
+        Some(IntegerValue::Overflow(9223372036854775808.0))
+    );
+}
+
+#[test]
+fn it_does_not_decode_legacy_octals_with_invalid_digits() {
+    assert_eq!(integer("089"), None);
+    assert_eq!(integer("0_9"), None);
+}
+
+#[test]
+fn it_decodes_floats() {
+    assert_eq!(literal("1.5").float_value(), Some(1.5));
+    assert_eq!(literal(".5").float_value(), Some(0.5));
+    assert_eq!(literal("1.").float_value(), Some(1.0));
+    assert_eq!(literal("1e3").float_value(), Some(1000.0));
+    assert_eq!(literal("1E-3").float_value(), Some(0.001));
+    assert_eq!(literal("2.5e+2").float_value(), Some(250.0));
+    assert_eq!(literal("1_000.000_5").float_value(), Some(1000.0005));
+    assert_eq!(literal("1_0e1_0").float_value(), Some(1e11));
+
+    assert_eq!(literal("1").float_value(), None);
+    assert_eq!(literal("1.5").integer_value(), None);
+}
+
+fn decode(raw: &str, quote: StringQuote) -> Vec<u8> {
+    let (value, errors) = decode_escapes(raw.as_bytes(), quote);
+
+    assert!(errors.is_empty(), "{raw}: {errors:?}");
+
+    value.as_bytes().to_vec()
+}
+
+/// The errors for a double-quoted string, with the escape sequence that each of them is for.
+fn errors(raw: &str) -> Vec<(EscapeError, &str)> {
+    decode_escapes(raw.as_bytes(), StringQuote::Double)
+        .1
+        .into_iter()
+        .map(|error| (error.error, &raw[error.span.start..error.span.end]))
+        .collect()
+}
+
+#[test]
+fn it_decodes_simple_escapes() {
+    assert_eq!(
+        decode(r#"\n\t\r\v\e\f\\\$\""#, StringQuote::Double),
+        b"\n\t\r\x0B\x1B\x0C\\$\""
+    );
+}
+
+#[test]
+fn it_only_decodes_the_quote_of_the_string() {
+    assert_eq!(decode(r#"\"\`"#, StringQuote::Double), br#""\`"#);
+    assert_eq!(decode(r#"\"\`"#, StringQuote::Heredoc), br#"\"\`"#);
+    assert_eq!(decode(r#"\"\`"#, StringQuote::Backtick), br#"\"`"#);
+}
+
+#[test]
+fn it_decodes_octal_escapes() {
+    assert_eq!(decode(r"\101\60\0", StringQuote::Double), b"A0\0");
+    assert_eq!(decode(r"\1012", StringQuote::Double), b"A2");
+    assert_eq!(decode(r"\377", StringQuote::Double), b"\xFF");
+    assert_eq!(decode(r"\8", StringQuote::Double), br"\8");
+}
+
+#[test]
+fn it_decodes_hexadecimal_escapes() {
+    assert_eq!(decode(r"\x41\x4a\xA", StringQuote::Double), b"AJ\n");
+    assert_eq!(decode(r"\x414", StringQuote::Double), b"A4");
+    assert_eq!(decode(r"\xg", StringQuote::Double), br"\xg");
+}
+
+#[test]
+fn it_decodes_codepoint_escapes() {
+    assert_eq!(decode(r"\u{41}", StringQuote::Double), b"A");
+    assert_eq!(decode(r"\u{e9}", StringQuote::Double), "é".as_bytes());
+    assert_eq!(decode(r"\u{20AC}", StringQuote::Double), "€".as_bytes());
+    assert_eq!(decode(r"\u{1F600}", StringQuote::Double), "😀".as_bytes());
+    assert_eq!(decode(r"\u{000041}", StringQuote::Double), b"A");
+    assert_eq!(
+        decode(r"\u{10FFFF}", StringQuote::Double),
+        b"\xF4\x8F\xBF\xBF"
+    );
+    // Surrogates aren't valid codepoints, but PHP encodes them anyway.
+    assert_eq!(decode(r"\u{D800}", StringQuote::Double), b"\xED\xA0\x80");
+    // Without a brace, it's not an escape sequence at all.
+    assert_eq!(decode(r"\u0041", StringQuote::Double), br"\u0041");
+}
+
+#[test]
+fn it_keeps_unknown_escapes() {
+    assert_eq!(decode(r"\q\{\'", StringQuote::Double), br"\q\{\'");
+}
+
+#[test]
+fn it_only_decodes_quotes_and_backslashes_in_single_quoted_strings() {
+    assert_eq!(decode(r"\'\\\n\u{}", StringQuote::Single), br"'\\n\u{}");
+    assert_eq!(decode(r"\", StringQuote::Single), br"\");
+}
+
+#[test]
+fn it_does_not_decode_anything_in_nowdocs() {
+    assert_eq!(decode(r"\\\n\u{}", StringQuote::Nowdoc), br"\\\n\u{}");
+}
+
+#[test]
+fn it_reports_invalid_codepoint_escapes() {
+    assert_eq!(
+        errors(r"a\u{}b"),
+        vec![(EscapeError::EmptyCodepoint, r"\u{}")]
+    );
+    assert_eq!(
+        errors(r"a\u{110000}b"),
+        vec![(EscapeError::CodepointTooLarge, r"\u{110000}")]
+    );
+    assert_eq!(
+        errors(r"\u{FFFFFFFFFFFF}"),
+        vec![(EscapeError::CodepointTooLarge, r"\u{FFFFFFFFFFFF}")]
+    );
+    assert_eq!(
+        errors(r"\u{zz} \u{41"),
+        vec![
+            (EscapeError::InvalidCodepoint, r"\u{zz}"),
+            (EscapeError::InvalidCodepoint, r"\u{41"),
+        ]
+    );
+
+    // Invalid escape sequences are kept as they were written.
+    assert_eq!(
+        decode_escapes(br"\u{}\u{110000}", StringQuote::Double)
+            .0
+            .as_bytes(),
+        br"\u{}\u{110000}"
+    );
+}
+
+#[test]
+fn it_reports_octal_escapes_that_overflow() {
+    assert_eq!(errors(r"\400"), vec![(EscapeError::OctalOverflow, r"\400")]);
+    assert_eq!(errors(r"\777"), vec![(EscapeError::OctalOverflow, r"\777")]);
+    assert_eq!(
+        decode_escapes(br"\400", StringQuote::Double).0.as_bytes(),
+        b"\0"
+    );
+}
+
+#[test]
+fn it_reports_trailing_backslashes() {
+    assert_eq!(
+        errors(r"abc\"),
+        vec![(EscapeError::TrailingBackslash, r"\")]
+    );
+    assert_eq!(errors(r"abc\\"), vec![]);
+}
+
+/// The warnings reported while parsing the given code, with the source they point at.
+fn warnings(source: &str) -> Vec<(String, String)> {
+    let source = format!("<?php {source}");
+    let result = Parser::parse(Lexer::new(source.as_bytes()));
+
+    result
+        .diagnostics
+        .iter()
+        .inspect(|diagnostic| assert_eq!(diagnostic.severity, Severity::Warning))
+        .map(|diagnostic| {
+            (
+                diagnostic.kind.get_code(),
+                source[diagnostic.span.start..diagnostic.span.end].to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn it_warns_about_invalid_escapes_at_the_escape_itself() {
+    assert_eq!(
+        warnings(r#""a\u{}b\400";"#),
+        vec![
+            ("P094".to_string(), r"\u{}".to_string()),
+            ("P094".to_string(), r"\400".to_string())
+        ]
+    );
+    assert_eq!(
+        warnings(r#"b"\u{110000}";"#),
+        vec![("P094".to_string(), r"\u{110000}".to_string())]
+    );
+    assert_eq!(
+        warnings(r#""$a \u{zz} {$b}";"#),
+        vec![("P094".to_string(), r"\u{zz}".to_string())]
+    );
+    assert_eq!(
+        warnings("<<<EOT\n  \\u{}\n  EOT;"),
+        vec![("P094".to_string(), r"\u{}".to_string())]
+    );
+    assert_eq!(
+        warnings(r#"`ls \u{} $a`;"#),
+        vec![("P094".to_string(), r"\u{}".to_string())]
+    );
+}
+
+#[test]
+fn it_does_not_warn_about_escapes_that_are_not_processed() {
+    assert_eq!(warnings(r#"'\u{} \400 \q';"#), vec![]);
+    assert_eq!(warnings("<<<'EOT'\n\\u{}\nEOT;"), vec![]);
+    assert_eq!(warnings(r#""\q \u0041 \{$a}";"#), vec![]);
+}
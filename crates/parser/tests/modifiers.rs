@@ -0,0 +1,126 @@
+use pxp_diagnostics::DiagnosticKind;
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+
+/// The code of each expected diagnostic, along with the modifier that it points at.
+type Expected = &'static [(&'static str, &'static str)];
+
+/// Each case is the class-like that the member is declared in, the member itself, and the expected
+/// diagnostics. An empty context means that the code is a top-level statement.
+const CASES: &[(&str, &str, Expected)] = &[
+    // Class-likes.
+    ("", "final class A {}", &[]),
+    ("", "abstract readonly class A {}", &[]),
+    ("", "final abstract class A {}", &[("P026", "abstract")]),
+    ("", "readonly enum A {}", &[("P084", "readonly")]),
+    ("", "abstract enum A {}", &[("P084", "abstract")]),
+    ("", "final enum A {}", &[("P084", "final")]),
+    ("", "readonly interface A {}", &[("P085", "readonly")]),
+    ("", "final trait A {}", &[("P086", "final")]),
+    // Constants.
+    ("class", "final public const X = 1;", &[]),
+    ("class", "static const X = 1;", &[("P023", "static")]),
+    ("class", "abstract const X = 1;", &[("P023", "abstract")]),
+    ("class", "readonly const X = 1;", &[("P023", "readonly")]),
+    (
+        "class",
+        "final private const X = 1;",
+        &[("P027", "private")],
+    ),
+    ("class", "private final const X = 1;", &[("P027", "final")]),
+    ("interface", "const X = 1;", &[]),
+    ("interface", "final public const X = 1;", &[]),
+    (
+        "interface",
+        "protected const X = 1;",
+        &[("P051", "protected")],
+    ),
+    ("interface", "private const X = 1;", &[("P051", "private")]),
+    ("enum", "final const X = 1;", &[]),
+    ("enum", "static const X = 1;", &[("P023", "static")]),
+    ("trait", "private const X = 1;", &[]),
+    // Methods.
+    ("class", "final public static function a() {}", &[]),
+    ("class", "readonly function a() {}", &[("P021", "readonly")]),
+    ("class", "abstract function a();", &[("P007", "abstract")]),
+    ("abstract class", "abstract protected function a();", &[]),
+    (
+        "abstract class",
+        "final abstract function a();",
+        &[("P026", "abstract")],
+    ),
+    (
+        "abstract class",
+        "abstract private function a();",
+        &[("P090", "private")],
+    ),
+    ("trait", "abstract private function a();", &[]),
+    ("interface", "public static function a();", &[]),
+    ("interface", "private function a();", &[("P051", "private")]),
+    ("interface", "final function a();", &[("P088", "final")]),
+    (
+        "interface",
+        "abstract function a();",
+        &[("P089", "abstract")],
+    ),
+    ("enum", "abstract function a();", &[("P007", "abstract")]),
+    ("enum", "final public function a() {}", &[]),
+    // Properties.
+    ("class", "public readonly int $a;", &[]),
+    (
+        "class",
+        "public static readonly int $a;",
+        &[("P040", "readonly")],
+    ),
+    ("class", "final int $a;", &[("P022", "final")]),
+    ("class", "private(set) public int $a;", &[]),
+    ("interface", "public int $a { get; }", &[]),
+    (
+        "interface",
+        "protected int $a { get; }",
+        &[("P051", "protected")],
+    ),
+    (
+        "class",
+        "public function __construct(static $a) {}",
+        &[("P022", "static")],
+    ),
+    (
+        "class",
+        "public function __construct(private readonly int $a) {}",
+        &[],
+    ),
+    // Enum cases.
+    ("enum", "case A;", &[]),
+    ("enum", "public case A;", &[("P087", "public")]),
+    ("enum", "final case A;", &[("P087", "final")]),
+];
+
+#[test]
+fn it_validates_modifiers() {
+    for (context, member, expected) in CASES {
+        let code = if context.is_empty() {
+            format!("<?php {member}")
+        } else {
+            format!("<?php {context} A {{ {member} }}")
+        };
+
+        let result = Parser::parse(Lexer::new(code.as_bytes()));
+        let diagnostics = result
+            .diagnostics
+            .iter()
+            .map(|diagnostic| {
+                (
+                    diagnostic.kind.get_code(),
+                    &code[diagnostic.span.start..diagnostic.span.end],
+                )
+            })
+            .collect::<Vec<_>>();
+        let expected = expected
+            .iter()
+            .map(|(code, modifier)| (code.to_string(), *modifier))
+            .collect::<Vec<_>>();
+
+        assert_eq!(diagnostics, expected, "{code}");
+    }
+}
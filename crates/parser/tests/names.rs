@@ -0,0 +1,94 @@
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use pxp_lexer::Lexer;
+use pxp_parser::{Parser, ParserOptions};
+
+fn parse(code: &[u8], cache_names: bool) -> String {
+    let result = Parser::parse_with_options(
+        Lexer::new(code),
+        ParserOptions::new().with_cache_names(cache_names),
+    );
+
+    format!("{:?}\n{:?}", result.ast, result.diagnostics)
+}
+
+fn fixtures(directory: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+
+    for entry in std::fs::read_dir(directory).unwrap() {
+        let path = entry.unwrap().path();
+
+        if path.is_dir() {
+            files.extend(fixtures(&path));
+        } else if path.extension() == Some("php".as_ref()) {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+    files
+}
+
+/// A file that refers to the same handful of classes over and over again.
+fn identifier_heavy_file() -> String {
+    let mut code = String::from("<?php\n\nnamespace App\\Http\\Controllers;\n\n");
+
+    code.push_str("use Illuminate\\Http\\Request;\nuse Illuminate\\Http\\Response;\n\n");
+
+    for i in 0..2_000 {
+        code.push_str(&format!(
+            "function handle{i}(Request $request, Response $response): Response {{\n    \
+             return new Response(Request::capture(), \\Illuminate\\Http\\Request::class, Collection::make());\n}}\n\n"
+        ));
+    }
+
+    code
+}
+
+#[test]
+fn it_resolves_names_the_same_way_with_and_without_the_cache() {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+
+    for path in fixtures(&directory) {
+        let code = std::fs::read(&path).unwrap();
+
+        assert_eq!(
+            parse(&code, true),
+            parse(&code, false),
+            "{}",
+            path.display()
+        );
+    }
+
+    let code = identifier_heavy_file();
+
+    assert_eq!(parse(code.as_bytes(), true), parse(code.as_bytes(), false));
+}
+
+#[test]
+#[ignore = "benchmark, run with --release -- --ignored --nocapture"]
+fn it_parses_identifier_heavy_files_faster_with_the_cache() {
+    let code = identifier_heavy_file();
+
+    let time = |cache_names: bool| {
+        let start = Instant::now();
+
+        for _ in 0..20 {
+            Parser::parse_with_options(
+                Lexer::new(code.as_bytes()),
+                ParserOptions::new().with_cache_names(cache_names),
+            );
+        }
+
+        start.elapsed() / 20
+    };
+
+    // Warm up, so that the first measurement isn't penalised.
+    time(true);
+
+    let uncached = time(false);
+    let cached = time(true);
+
+    println!("without cache: {uncached:?}, with cache: {cached:?}");
+}
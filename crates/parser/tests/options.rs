@@ -0,0 +1,330 @@
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use pxp_diagnostics::{DiagnosticKind, Severity};
+use pxp_lexer::Lexer;
+use pxp_parser::{ParseResult, Parser, ParserDiagnostic, ParserOptions, PhpVersion};
+
+fn garbage() -> String {
+    let mut input = String::from("<?php\n");
+
+    for _ in 0..1_000 {
+        input.push_str(") ] } => = ;\n");
+    }
+
+    input
+}
+
+#[test]
+fn it_stops_parsing_once_the_error_budget_is_used_up() {
+    let input = garbage();
+
+    let unbudgeted = Parser::parse(Lexer::new(&input));
+    let budgeted = Parser::parse_with_options(
+        Lexer::new(&input),
+        ParserOptions::new().with_max_diagnostics(10),
+    );
+
+    assert_eq!(budgeted.diagnostics.len(), 11);
+    assert!(matches!(
+        budgeted.diagnostics.last().unwrap().kind,
+        ParserDiagnostic::TooManyErrors
+    ));
+    assert_eq!(
+        budgeted.diagnostics.last().unwrap().kind.get_identifier(),
+        "parser.too-many-errors"
+    );
+    assert!(budgeted.ast.len() < 10);
+    assert!(unbudgeted.ast.len() > 1_000);
+}
+
+#[test]
+fn it_does_not_limit_diagnostics_by_default() {
+    let result = Parser::parse(Lexer::new(&garbage()));
+
+    assert!(result.diagnostics.len() > 11);
+    assert!(!result
+        .diagnostics
+        .iter()
+        .any(|diagnostic| matches!(diagnostic.kind, ParserDiagnostic::TooManyErrors)));
+}
+
+#[test]
+fn it_does_not_panic_when_skipping_a_dangling_heredoc() {
+    let input = "<?php ) ) ) $a = <<<EOT\nHello {$world}\n";
+    let result = Parser::parse_with_options(
+        Lexer::new(input),
+        ParserOptions::new().with_max_diagnostics(2),
+    );
+
+    assert_eq!(result.diagnostics.len(), 3);
+}
+
+/// Parse the given input on another thread, failing if the parser doesn't finish in time.
+fn parse_within_time_limit(input: Vec<u8>, options: ParserOptions) -> ParseResult {
+    let (sender, receiver) = mpsc::channel();
+    let source = String::from_utf8_lossy(&input).into_owned();
+
+    thread::spawn(move || {
+        sender
+            .send(Parser::parse_with_options(Lexer::new(&input), options))
+            .unwrap()
+    });
+
+    receiver
+        .recv_timeout(Duration::from_secs(5))
+        .unwrap_or_else(|_| panic!("parsing {source:?} did not finish"))
+}
+
+#[test]
+fn it_finishes_parsing_unterminated_input_within_the_error_budget() {
+    for input in [
+        "<?php $y = 2; /* ex",
+        "<?php /** @param",
+        "<?php\nif ($a):\n    x",
+        "<?php if ($a): elseif ($b): x",
+        "<?php $a = <<<EOT\nhi $x\n",
+        "<?php switch ($a): case 1: break",
+    ] {
+        let result = parse_within_time_limit(
+            input.as_bytes().to_vec(),
+            ParserOptions::new().with_max_diagnostics(10),
+        );
+
+        assert!(result.diagnostics.len() <= 11, "{input:?}");
+    }
+}
+
+#[test]
+fn it_finishes_parsing_binary_input_with_an_unclosed_comment() {
+    let mut input = b"<?php /*".to_vec();
+    input.extend((0..10_000u32).map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8));
+
+    let result = parse_within_time_limit(input, ParserOptions::new().with_max_diagnostics(10));
+
+    assert!(result.diagnostics.len() <= 11);
+}
+
+#[test]
+fn it_does_not_count_warnings_towards_the_error_budget() {
+    let warnings = "function f($a = 1, $b) {}\n".repeat(20);
+    let input = format!("<?php\n{warnings}{}", &garbage()["<?php\n".len()..]);
+
+    let result = Parser::parse_with_options(
+        Lexer::new(&input),
+        ParserOptions::new().with_max_diagnostics(5),
+    );
+    let severities = result
+        .diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.severity)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        severities
+            .iter()
+            .filter(|severity| **severity == Severity::Warning)
+            .count(),
+        20
+    );
+    assert_eq!(
+        severities
+            .iter()
+            .filter(|severity| **severity == Severity::Error)
+            .count(),
+        6
+    );
+    assert!(matches!(
+        result.diagnostics.last().unwrap().kind,
+        ParserDiagnostic::TooManyErrors
+    ));
+
+    let warnings_only = format!("<?php\n{warnings}");
+    let result = Parser::parse_with_options(
+        Lexer::new(&warnings_only),
+        ParserOptions::new().with_max_diagnostics(5),
+    );
+
+    assert_eq!(result.diagnostics.len(), 20);
+    assert!(!result
+        .diagnostics
+        .iter()
+        .any(|diagnostic| matches!(diagnostic.kind, ParserDiagnostic::TooManyErrors)));
+}
+
+fn diagnostics_for(input: &str, options: ParserOptions) -> Vec<(String, String, &str)> {
+    Parser::parse_with_options(Lexer::new(input), options)
+        .diagnostics
+        .iter()
+        .map(|diagnostic| {
+            (
+                diagnostic.kind.get_code(),
+                diagnostic.kind.get_message(),
+                &input[diagnostic.span.start..diagnostic.span.end],
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn it_reports_exit_used_as_a_function_before_php_84() {
+    let input = "<?php exit; exit(1); exit(status: 1); $a = die(...);";
+
+    assert_eq!(
+        diagnostics_for(
+            input,
+            ParserOptions::new().with_php_version(PhpVersion::PHP_83)
+        ),
+        vec![
+            (
+                "P091".to_string(),
+                "passing a named argument to exit requires PHP 8.4 or newer".to_string(),
+                "status: 1"
+            ),
+            (
+                "P091".to_string(),
+                "creating a closure from exit or die requires PHP 8.4 or newer".to_string(),
+                "die(...)"
+            ),
+        ]
+    );
+
+    assert!(diagnostics_for(
+        input,
+        ParserOptions::new().with_php_version(PhpVersion::PHP_84)
+    )
+    .is_empty());
+    assert!(diagnostics_for(input, ParserOptions::new()).is_empty());
+}
+
+#[test]
+fn it_reports_clone_used_as_a_closure_before_php_85() {
+    let input = "<?php clone $a; clone($a); $b = clone(...);";
+
+    assert_eq!(
+        diagnostics_for(
+            input,
+            ParserOptions::new().with_php_version(PhpVersion::PHP_84)
+        ),
+        vec![(
+            "P091".to_string(),
+            "creating a closure from clone requires PHP 8.5 or newer".to_string(),
+            "clone(...)"
+        )]
+    );
+
+    assert!(diagnostics_for(
+        input,
+        ParserOptions::new().with_php_version(PhpVersion::PHP_85)
+    )
+    .is_empty());
+}
+
+fn deprecations_in(fixture: &str, version: Option<PhpVersion>) -> Vec<(String, Severity, String)> {
+    let path = format!(
+        "{}/tests/fixtures/deprecations/{fixture}",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let input = std::fs::read_to_string(path).unwrap();
+    let options = match version {
+        Some(version) => ParserOptions::new().with_php_version(version),
+        None => ParserOptions::new(),
+    };
+
+    Parser::parse_with_options(Lexer::new(&input), options)
+        .diagnostics
+        .iter()
+        .map(|diagnostic| {
+            (
+                diagnostic.kind.get_code(),
+                diagnostic.severity,
+                input[diagnostic.span.start..diagnostic.span.end].to_string(),
+            )
+        })
+        .collect()
+}
+
+fn flagged(code: &str, severity: Severity, spans: &[&str]) -> Vec<(String, Severity, String)> {
+    spans
+        .iter()
+        .map(|span| (code.to_string(), severity, span.to_string()))
+        .collect()
+}
+
+#[test]
+fn it_reports_curly_brace_offsets_for_the_targeted_php_version() {
+    let fixture = "curly-brace-offsets.php";
+    let spans = ["{0}", "{1}", "{0}"];
+
+    assert_eq!(
+        deprecations_in(fixture, None),
+        flagged("P096", Severity::Error, &spans)
+    );
+    assert_eq!(
+        deprecations_in(fixture, Some(PhpVersion::PHP_80)),
+        flagged("P096", Severity::Error, &spans)
+    );
+    assert_eq!(
+        deprecations_in(fixture, Some(PhpVersion::PHP_74)),
+        flagged("P095", Severity::Warning, &spans)
+    );
+    assert!(deprecations_in(fixture, Some(PhpVersion::new(7, 3))).is_empty());
+}
+
+#[test]
+fn it_reports_removed_casts_for_the_targeted_php_version() {
+    assert_eq!(
+        deprecations_in("real-cast.php", None),
+        flagged("P073", Severity::Error, &["(real)"])
+    );
+    assert_eq!(
+        deprecations_in("real-cast.php", Some(PhpVersion::PHP_74)),
+        flagged("P072", Severity::Warning, &["(real)"])
+    );
+    assert!(deprecations_in("real-cast.php", Some(PhpVersion::new(7, 3))).is_empty());
+
+    assert_eq!(
+        deprecations_in("unset-cast.php", Some(PhpVersion::PHP_80)),
+        flagged("P073", Severity::Error, &["(unset)"])
+    );
+    assert_eq!(
+        deprecations_in("unset-cast.php", Some(PhpVersion::PHP_74)),
+        flagged("P095", Severity::Warning, &["(unset)"])
+    );
+    assert!(deprecations_in("unset-cast.php", Some(PhpVersion::new(7, 1))).is_empty());
+}
+
+#[test]
+fn it_reports_optional_parameters_before_required_ones_for_the_targeted_php_version() {
+    let fixture = "optional-before-required.php";
+    let spans = ["$host", "$options"];
+
+    assert_eq!(
+        deprecations_in(fixture, None),
+        flagged("P095", Severity::Warning, &spans)
+    );
+    assert_eq!(
+        deprecations_in(fixture, Some(PhpVersion::PHP_80)),
+        flagged("P095", Severity::Warning, &spans)
+    );
+    assert!(deprecations_in(fixture, Some(PhpVersion::PHP_74)).is_empty());
+}
+
+#[test]
+fn it_names_the_replacement_for_deprecated_syntax() {
+    let result = Parser::parse(Lexer::new("<?php $a{0}; function f($a = 1, $b) {}"));
+    let messages = result
+        .diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.kind.get_message())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        messages,
+        vec![
+            "array and string offset access with curly braces was removed in PHP 8.0, use [] instead",
+            "declaring optional parameter $a before required parameter $b is deprecated as of PHP 8.0, remove the default value",
+        ]
+    );
+}
@@ -8,6 +8,51 @@ snap!(snapper, empty_file, process("fixtures/tags/empty-file.php"));
 snap!(snapper, tag, process("fixtures/tags/tag.php"));
 snap!(snapper, short_tag, process("fixtures/tags/short-tag.php"));
 snap!(snapper, echo_tag, process("fixtures/tags/echo-tag.php"));
+snap!(
+    snapper,
+    return_close_tag,
+    process("fixtures/tags/return-close-tag.php")
+);
+snap!(
+    snapper,
+    return_close_tag_eof,
+    process("fixtures/tags/return-close-tag-eof.php")
+);
+snap!(
+    snapper,
+    echo_close_tag,
+    process("fixtures/tags/echo-close-tag.php")
+);
+snap!(
+    snapper,
+    echo_close_tag_eof,
+    process("fixtures/tags/echo-close-tag-eof.php")
+);
+snap!(
+    snapper,
+    expression_close_tag,
+    process("fixtures/tags/expression-close-tag.php")
+);
+snap!(
+    snapper,
+    expression_close_tag_eof,
+    process("fixtures/tags/expression-close-tag-eof.php")
+);
+snap!(
+    snapper,
+    nested_if_close_tag,
+    process("fixtures/tags/nested-if-close-tag.php")
+);
+snap!(
+    snapper,
+    nested_if_close_tag_eof,
+    process("fixtures/tags/nested-if-close-tag-eof.php")
+);
+snap!(
+    snapper,
+    statements_close_tag,
+    process("fixtures/tags/statements-close-tag.php")
+);
 
 // Echo
 snap!(
@@ -104,6 +149,17 @@ snap!(
     coalesce_assign,
     process("fixtures/assignments/coalesce-assign.php")
 );
+snap!(
+    snapper,
+    nullsafe_assign,
+    process("fixtures/assignments/nullsafe-assign.php")
+);
+snap!(snapper, append, process("fixtures/assignments/append.php"));
+snap!(
+    snapper,
+    append_read,
+    process("fixtures/assignments/append-read.php")
+);
 
 // Arithmetic
 snap!(snapper, add, process("fixtures/arithmetic/add.php"));
@@ -311,6 +367,11 @@ snap!(
     variable_variable_complex,
     process("fixtures/variables/variable-variable-complex.php")
 );
+snap!(
+    snapper,
+    nullsafe_chain_with_array_access,
+    process("fixtures/variables/nullsafe-chain-with-array-access.php")
+);
 
 // Constants
 snap!(
@@ -323,6 +384,11 @@ snap!(
     magic_constants,
     process("fixtures/constants/magic-constants.php")
 );
+snap!(
+    snapper,
+    constant_with_attributes,
+    process("fixtures/constants/constant-with-attributes.php")
+);
 
 // Functions
 snap!(
@@ -355,6 +421,61 @@ snap!(
     function_with_return_type,
     process("fixtures/functions/function-with-return-type.php")
 );
+snap!(
+    snapper,
+    parameter_defaults_referencing_constants,
+    process("fixtures/functions/parameter-defaults-referencing-constants.php")
+);
+snap!(
+    snapper,
+    parameter_defaults_non_constant,
+    process("fixtures/functions/parameter-defaults-non-constant.php")
+);
+snap!(
+    snapper,
+    closure_use_trailing_comma,
+    process("fixtures/functions/closure-use-trailing-comma.php")
+);
+snap!(
+    snapper,
+    closure_use_by_reference,
+    process("fixtures/functions/closure-use-by-reference.php")
+);
+snap!(
+    snapper,
+    closure_use_this,
+    process("fixtures/functions/closure-use-this.php")
+);
+snap!(
+    snapper,
+    closure_use_superglobal,
+    process("fixtures/functions/closure-use-superglobal.php")
+);
+snap!(
+    snapper,
+    closure_use_malformed,
+    process("fixtures/functions/closure-use-malformed.php")
+);
+snap!(
+    snapper,
+    closure_parameters_with_attributes,
+    process("fixtures/functions/closure-parameters-with-attributes.php")
+);
+snap!(
+    snapper,
+    exit_construct,
+    process("fixtures/functions/exit-construct.php")
+);
+snap!(
+    snapper,
+    exit_named_argument,
+    process("fixtures/functions/exit-named-argument.php")
+);
+snap!(
+    snapper,
+    exit_closure_creation,
+    process("fixtures/functions/exit-closure-creation.php")
+);
 
 // Control Structures
 snap!(
@@ -377,6 +498,46 @@ snap!(
     if_elseif_else_statement_no_else,
     process("fixtures/control/if-elseif-statement-no-else.php")
 );
+snap!(
+    snapper,
+    if_else_if_statement,
+    process("fixtures/control/if-else-if-statement.php")
+);
+snap!(
+    snapper,
+    if_dangling_else,
+    process("fixtures/control/if-dangling-else.php")
+);
+snap!(
+    snapper,
+    if_elseif_alternative_syntax,
+    process("fixtures/control/if-elseif-alternative-syntax.php")
+);
+snap!(
+    snapper,
+    if_else_if_alternative_syntax,
+    process("fixtures/control/if-else-if-alternative-syntax.php")
+);
+snap!(
+    snapper,
+    foreach_keyed_destructuring_with_references,
+    process("fixtures/control/foreach-keyed-destructuring-with-references.php")
+);
+snap!(
+    snapper,
+    foreach_nested_destructuring_with_references,
+    process("fixtures/control/foreach-nested-destructuring-with-references.php")
+);
+snap!(
+    snapper,
+    foreach_list_destructuring_with_references,
+    process("fixtures/control/foreach-list-destructuring-with-references.php")
+);
+snap!(
+    snapper,
+    foreach_key_and_destructuring_with_references,
+    process("fixtures/control/foreach-key-and-destructuring-with-references.php")
+);
 snap!(
     snapper,
     switch_statement,
@@ -387,6 +548,21 @@ snap!(
     switch_statement_no_case,
     process("fixtures/control/switch-statement-no-case.php")
 );
+snap!(
+    snapper,
+    switch_statement_alternative_syntax,
+    process("fixtures/control/switch-statement-alternative-syntax.php")
+);
+snap!(
+    snapper,
+    switch_statement_alternative_syntax_with_html,
+    process("fixtures/control/switch-statement-alternative-syntax-with-html.php")
+);
+snap!(
+    snapper,
+    switch_statement_case_separators,
+    process("fixtures/control/switch-statement-case-separators.php")
+);
 snap!(
     snapper,
     while_statement,
@@ -484,6 +660,11 @@ snap!(
     readonly_class,
     process("fixtures/classes/readonly-class.php")
 );
+snap!(
+    snapper,
+    readonly_class_with_modifiers,
+    process("fixtures/classes/readonly-class-with-modifiers.php")
+);
 snap!(
     snapper,
     class_with_traits,
@@ -504,11 +685,46 @@ snap!(
     class_with_traits_and_visibility,
     process("fixtures/classes/class-with-traits-and-visibility.php")
 );
+snap!(
+    snapper,
+    class_with_traits_and_attributes,
+    process("fixtures/classes/class-with-traits-and-attributes.php")
+);
 snap!(
     snapper,
     class_with_attributes,
     process("fixtures/classes/class-with-attributes.php")
 );
+snap!(
+    snapper,
+    anonymous_class_with_attributes,
+    process("fixtures/classes/anonymous-class-with-attributes.php")
+);
+snap!(
+    snapper,
+    anonymous_class_with_arguments_extends_and_implements,
+    process("fixtures/classes/anonymous-class-with-arguments-extends-and-implements.php")
+);
+snap!(
+    snapper,
+    anonymous_class_in_match_arm,
+    process("fixtures/classes/anonymous-class-in-match-arm.php")
+);
+snap!(
+    snapper,
+    anonymous_class_as_parameter_default,
+    process("fixtures/classes/anonymous-class-as-parameter-default.php")
+);
+snap!(
+    snapper,
+    anonymous_class_with_closure_placeholder,
+    process("fixtures/classes/anonymous-class-with-closure-placeholder.php")
+);
+snap!(
+    snapper,
+    nested_anonymous_classes,
+    process("fixtures/classes/nested-anonymous-classes.php")
+);
 
 // Interfaces
 snap!(
@@ -531,6 +747,11 @@ snap!(
     interface_with_extends,
     process("fixtures/interfaces/interface-with-extends.php")
 );
+snap!(
+    snapper,
+    interface_method_with_body,
+    process("fixtures/interfaces/interface-method-with-body.php")
+);
 
 // Traits
 snap!(
@@ -543,6 +764,11 @@ snap!(
     trait_with_properties,
     process("fixtures/traits/trait-with-properties.php")
 );
+snap!(
+    snapper,
+    trait_with_constants,
+    process("fixtures/traits/trait-with-constants.php")
+);
 snap!(
     snapper,
     trait_with_methods,
@@ -553,6 +779,11 @@ snap!(
     trait_with_trait_use,
     process("fixtures/traits/trait-with-trait-use.php")
 );
+snap!(
+    snapper,
+    trait_adaptations,
+    process("fixtures/traits/trait-adaptations.php")
+);
 
 // Enums
 snap!(
@@ -590,6 +821,35 @@ snap!(
     enum_with_attributes,
     process("fixtures/enums/enum-with-attributes.php")
 );
+snap!(
+    snapper,
+    enum_cases_with_attributes,
+    process("fixtures/enums/enum-cases-with-attributes.php")
+);
+
+snap!(
+    snapper,
+    kitchen_sink_enum,
+    process("fixtures/enums/kitchen-sink-enum.php")
+);
+
+snap!(
+    snapper,
+    enum_with_property,
+    process("fixtures/enums/enum-with-property.php")
+);
+
+snap!(
+    snapper,
+    enum_with_magic_methods,
+    process("fixtures/enums/enum-with-magic-methods.php")
+);
+
+snap!(
+    snapper,
+    enum_with_case_after_method,
+    process("fixtures/enums/enum-with-case-after-method.php")
+);
 
 // Namespaces
 snap!(
@@ -634,6 +894,173 @@ snap!(
     class_const_with_attributes,
     process("fixtures/class-constants/class-const-with-attributes.php")
 );
+snap!(
+    snapper,
+    object_class_const,
+    process("fixtures/class-constants/object-class-const.php")
+);
+snap!(
+    snapper,
+    object_class_name,
+    process("fixtures/class-constants/object-class-name.php")
+);
+snap!(
+    snapper,
+    missing_class_const_name,
+    process("fixtures/class-constants/missing-class-const-name.php")
+);
+
+// Static Members
+snap!(
+    snapper,
+    static_member_identifier,
+    process("fixtures/static-members/identifier.php")
+);
+snap!(
+    snapper,
+    static_member_identifier_call,
+    process("fixtures/static-members/identifier-call.php")
+);
+snap!(
+    snapper,
+    static_member_identifier_array_index,
+    process("fixtures/static-members/identifier-array-index.php")
+);
+snap!(
+    snapper,
+    static_member_variable,
+    process("fixtures/static-members/variable.php")
+);
+snap!(
+    snapper,
+    static_member_variable_call,
+    process("fixtures/static-members/variable-call.php")
+);
+snap!(
+    snapper,
+    static_member_variable_array_index,
+    process("fixtures/static-members/variable-array-index.php")
+);
+snap!(
+    snapper,
+    static_member_dynamic,
+    process("fixtures/static-members/dynamic.php")
+);
+snap!(
+    snapper,
+    static_member_dynamic_call,
+    process("fixtures/static-members/dynamic-call.php")
+);
+snap!(
+    snapper,
+    static_member_dynamic_array_index,
+    process("fixtures/static-members/dynamic-array-index.php")
+);
+snap!(
+    snapper,
+    static_member_class,
+    process("fixtures/static-members/class.php")
+);
+snap!(
+    snapper,
+    static_member_class_call,
+    process("fixtures/static-members/class-call.php")
+);
+snap!(
+    snapper,
+    static_member_class_array_index,
+    process("fixtures/static-members/class-array-index.php")
+);
+snap!(
+    snapper,
+    static_member_class_on_constant,
+    process("fixtures/static-members/class-on-constant.php")
+);
+snap!(
+    snapper,
+    static_member_invalid_member,
+    process("fixtures/static-members/invalid-member.php")
+);
+snap!(
+    snapper,
+    static_member_unclosed_dynamic,
+    process("fixtures/static-members/unclosed-dynamic.php")
+);
+snap!(
+    snapper,
+    static_member_self_class,
+    process("fixtures/static-members/self-class.php")
+);
+snap!(
+    snapper,
+    static_member_static_class,
+    process("fixtures/static-members/static-class.php")
+);
+snap!(
+    snapper,
+    static_member_parent_class,
+    process("fixtures/static-members/parent-class.php")
+);
+snap!(
+    snapper,
+    static_member_static_constant,
+    process("fixtures/static-members/static-constant.php")
+);
+snap!(
+    snapper,
+    static_member_parent_constant,
+    process("fixtures/static-members/parent-constant.php")
+);
+snap!(
+    snapper,
+    static_member_static_call,
+    process("fixtures/static-members/static-call.php")
+);
+snap!(
+    snapper,
+    static_member_static_property,
+    process("fixtures/static-members/static-property.php")
+);
+snap!(
+    snapper,
+    static_member_static_property_nested,
+    process("fixtures/static-members/static-property-nested.php")
+);
+snap!(
+    snapper,
+    static_member_name_property_nested,
+    process("fixtures/static-members/name-property-nested.php")
+);
+snap!(
+    snapper,
+    static_member_clone_parenthesized,
+    process("fixtures/static-members/clone-parenthesized.php")
+);
+snap!(
+    snapper,
+    static_member_parenthesized_new,
+    process("fixtures/static-members/parenthesized-new.php")
+);
+snap!(
+    snapper,
+    static_member_variable_target,
+    process("fixtures/static-members/variable-target.php")
+);
+snap!(
+    snapper,
+    static_member_call_chain,
+    process("fixtures/static-members/call-chain.php")
+);
+snap!(
+    snapper,
+    static_member_static_array_index_call,
+    process("fixtures/static-members/static-array-index-call.php")
+);
+snap!(
+    snapper,
+    static_member_static_without_member,
+    process("fixtures/static-members/static-without-member.php")
+);
 
 // Methods
 snap!(snapper, method, process("fixtures/methods/method.php"));
@@ -657,6 +1084,16 @@ snap!(
     method_with_abstract,
     process("fixtures/methods/method-with-abstract.php")
 );
+snap!(
+    snapper,
+    abstract_method_with_body,
+    process("fixtures/methods/abstract-method-with-body.php")
+);
+snap!(
+    snapper,
+    method_without_body,
+    process("fixtures/methods/method-without-body.php")
+);
 snap!(
     snapper,
     method_with_return_type,
@@ -909,6 +1346,11 @@ snap!(
     constant_use,
     process("fixtures/name-resolving/constant-use.php")
 );
+snap!(
+    snapper,
+    name_resolving_imports_mid_file,
+    process("fixtures/name-resolving/imports-mid-file.php")
+);
 
 // Uses
 snap!(snapper, simple_use, process("fixtures/uses/simple-use.php"));
@@ -963,6 +1405,16 @@ snap!(
     clone_function_call_args,
     process("fixtures/clone/clone-function-call-args.php")
 );
+snap!(
+    snapper,
+    clone_call,
+    process("fixtures/clone/clone-call.php")
+);
+snap!(
+    snapper,
+    clone_closure_creation,
+    process("fixtures/clone/clone-closure-creation.php")
+);
 
 // Yield
 snap!(snapper, yield_empty, process("fixtures/yield/empty.php"));
@@ -1005,6 +1457,11 @@ snap!(
     property_hooks_parameter_list,
     process("fixtures/property-hooks/parameter-list.php")
 );
+snap!(
+    snapper,
+    property_hooks_property_constant,
+    process("fixtures/property-hooks/property-constant.php")
+);
 
 // Asymmetric Visibility
 snap!(
@@ -1018,6 +1475,218 @@ snap!(
     process("fixtures/asymmetric-visibility/promoted-property.php")
 );
 
+// Promoted Properties
+snap!(
+    snapper,
+    promoted_property_outside_constructor,
+    process("fixtures/promoted-properties/outside-constructor.php")
+);
+snap!(
+    snapper,
+    promoted_property_abstract_constructor,
+    process("fixtures/promoted-properties/abstract-constructor.php")
+);
+snap!(
+    snapper,
+    promoted_property_interface_constructor,
+    process("fixtures/promoted-properties/interface-constructor.php")
+);
+snap!(
+    snapper,
+    promoted_property_variadic,
+    process("fixtures/promoted-properties/variadic.php")
+);
+snap!(
+    snapper,
+    promoted_property_untyped_readonly,
+    process("fixtures/promoted-properties/untyped-readonly.php")
+);
+snap!(
+    snapper,
+    promoted_property_redeclared,
+    process("fixtures/promoted-properties/redeclared.php")
+);
+
+// Types
+snap!(snapper, dnf_types, process("fixtures/types/dnf.php"));
+snap!(
+    snapper,
+    standalone_null_false_true_types,
+    process("fixtures/types/standalone-null-false-true.php")
+);
+snap!(
+    snapper,
+    standalone_types_in_unions,
+    process("fixtures/types/standalone-types-in-unions.php")
+);
+snap!(
+    snapper,
+    duplicate_type_member,
+    process("fixtures/types/duplicate-member.php")
+);
+snap!(
+    snapper,
+    mixed_type_combined,
+    process("fixtures/types/mixed-combined.php")
+);
+snap!(
+    snapper,
+    never_type_outside_of_return,
+    process("fixtures/types/never-outside-return.php")
+);
+snap!(
+    snapper,
+    void_outside_return,
+    process("fixtures/types/void-outside-return.php")
+);
+snap!(
+    snapper,
+    static_outside_return,
+    process("fixtures/types/static-outside-return.php")
+);
+snap!(
+    snapper,
+    callable_property,
+    process("fixtures/types/callable-property.php")
+);
+snap!(
+    snapper,
+    callable_class_constant,
+    process("fixtures/types/callable-class-constant.php")
+);
+snap!(
+    snapper,
+    parent_without_parent,
+    process("fixtures/types/parent-without-parent.php")
+);
+snap!(
+    snapper,
+    case_insensitive_native_types,
+    process("fixtures/types/case-insensitive-native-types.php")
+);
+snap!(
+    snapper,
+    nullable_type_in_union,
+    process("fixtures/types/nullable-union.php")
+);
+snap!(
+    snapper,
+    nullable_null_type,
+    process("fixtures/types/nullable-null.php")
+);
+snap!(
+    snapper,
+    true_and_false_in_union_type,
+    process("fixtures/types/true-and-false.php")
+);
+snap!(
+    snapper,
+    non_class_type_in_intersection,
+    process("fixtures/types/intersection-non-class.php")
+);
+
+// Comments
+snap!(
+    snapper,
+    leading_comments,
+    process("fixtures/comments/leading-comments.php")
+);
+snap!(
+    snapper,
+    trailing_comments,
+    process("fixtures/comments/trailing-comments.php")
+);
+snap!(
+    snapper,
+    block_closing_comments,
+    process("fixtures/comments/block-closing-comments.php")
+);
+snap!(
+    snapper,
+    docblock_after_statement,
+    process("fixtures/comments/docblock-after-statement.php")
+);
+snap!(
+    snapper,
+    missing_semicolon_same_line,
+    process("fixtures/recovery/missing-semicolon-same-line.php")
+);
+snap!(
+    snapper,
+    missing_semicolon_across_lines,
+    process("fixtures/recovery/missing-semicolon-across-lines.php")
+);
+snap!(
+    snapper,
+    missing_semicolon_mid_line,
+    process("fixtures/recovery/missing-semicolon-mid-line.php")
+);
+snap!(
+    snapper,
+    unclosed_class_brace,
+    process("fixtures/recovery/unclosed-class-brace.php")
+);
+snap!(
+    snapper,
+    mismatched_bracket,
+    process("fixtures/recovery/mismatched-bracket.php")
+);
+snap!(
+    snapper,
+    stray_brace_in_unbraced_namespace,
+    process("fixtures/recovery/stray-brace-in-unbraced-namespace.php")
+);
+snap!(
+    snapper,
+    declare_ticks_braced,
+    process("fixtures/declare/ticks-braced.php")
+);
+snap!(
+    snapper,
+    declare_ticks_block,
+    process("fixtures/declare/ticks-block.php")
+);
+snap!(
+    snapper,
+    declare_ticks_statement,
+    process("fixtures/declare/ticks-statement.php")
+);
+snap!(
+    snapper,
+    declare_unknown_directive,
+    process("fixtures/declare/unknown-directive.php")
+);
+snap!(
+    snapper,
+    declare_encoding_non_literal,
+    process("fixtures/declare/encoding-non-literal.php")
+);
+snap!(
+    snapper,
+    declare_strict_types_after_namespace,
+    process("fixtures/declare/strict-types-after-namespace.php")
+);
+snap!(
+    snapper,
+    deprecated_curly_brace_offsets,
+    process("fixtures/deprecations/curly-brace-offsets.php")
+);
+snap!(
+    snapper,
+    deprecated_real_cast,
+    process("fixtures/deprecations/real-cast.php")
+);
+snap!(
+    snapper,
+    deprecated_unset_cast,
+    process("fixtures/deprecations/unset-cast.php")
+);
+snap!(
+    snapper,
+    deprecated_optional_before_required,
+    process("fixtures/deprecations/optional-before-required.php")
+);
+
 pub fn snapper() -> Snapper {
     Snapper::new(format!("{}/{}", env!("CARGO_MANIFEST_DIR"), "tests/__snapshots__").into())
 }
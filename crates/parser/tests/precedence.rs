@@ -0,0 +1,221 @@
+use pxp_ast::{Expression, Node, StatementKind};
+use pxp_diagnostics::DiagnosticKind;
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+
+/// Expressions and the shape of the tree that PHP parses them into.
+///
+/// Operators are printed in prefix position, e.g. `(+ 1 2)`. Anything that isn't a plain
+/// prefix or infix operator is printed as a pattern, with `_` standing in for each operand,
+/// e.g. `(_++ $a)` or `(_?_:_ $a $b $c)`.
+const CASES: &[(&str, &str)] = &[
+    // Arithmetic
+    ("1 + 2 * 3", "(+ 1 (* 2 3))"),
+    ("1 * 2 + 3", "(+ (* 1 2) 3)"),
+    ("1 - 2 - 3", "(- (- 1 2) 3)"),
+    ("1 / 2 % 3", "(% (/ 1 2) 3)"),
+    ("-$a * $b", "(* (- $a) $b)"),
+    // Exponentiation is right-associative and binds tighter than unary operators on its left.
+    ("2 ** 3 ** 2", "(** 2 (** 3 2))"),
+    ("-2 ** 2", "(- (** 2 2))"),
+    ("2 ** -1", "(** 2 (- 1))"),
+    ("-$a ** -$b", "(- (** $a (- $b)))"),
+    ("2 ** -1 * 3", "(* (** 2 (- 1)) 3)"),
+    ("~$a ** 2", "(~ (** $a 2))"),
+    ("(int) $a ** 2", "((int) (** $a 2))"),
+    ("(int) $a + 2", "(+ ((int) $a) 2)"),
+    ("@$a + 1", "(+ (@ $a) 1)"),
+    // Increment and decrement only apply to variables.
+    ("++$a ** 2", "(** (++ $a) 2)"),
+    ("2 ** $a++", "(** 2 (_++ $a))"),
+    ("-$a--", "(- (_-- $a))"),
+    ("!$a++", "(! (_++ $a))"),
+    // Concatenation has a lower precedence than arithmetic and shifts since PHP 8.
+    ("$a . $b + $c", "(. $a (+ $b $c))"),
+    ("$a << $b + $c", "(<< $a (+ $b $c))"),
+    ("$a . $b << $c", "(. $a (<< $b $c))"),
+    // Comparisons
+    ("$a < $b == $c", "(== (< $a $b) $c)"),
+    ("$a == $b < $c", "(== $a (< $b $c))"),
+    ("$a == $b && $c != $d", "(&& (== $a $b) (!= $c $d))"),
+    ("$a & $b == $c", "(& $a (== $b $c))"),
+    ("$a | $b ^ $c & $d", "(| $a (^ $b (& $c $d)))"),
+    // Logical operators
+    ("$a || $b && $c", "(|| $a (&& $b $c))"),
+    ("!$a && $b", "(&& (! $a) $b)"),
+    ("!$a instanceof B", "(! (instanceof $a B))"),
+    ("$a instanceof B && $c", "(&& (instanceof $a B) $c)"),
+    ("$a and $b or $c", "(or (and $a $b) $c)"),
+    ("$a or $b xor $c and $d", "(or $a (xor $b (and $c $d)))"),
+    // Null coalescing is right-associative, short ternaries are left-associative.
+    ("$a ?? $b ?? $c", "(?? $a (?? $b $c))"),
+    ("$a ?: $b ?: $c", "(?: (?: $a $b) $c)"),
+    ("$a ?? $b ?: $c", "(?: (?? $a $b) $c)"),
+    ("$a ? $b : $c", "(_?_:_ $a $b $c)"),
+    (
+        "$a ? $b : ($c ? $d : $e)",
+        "(_?_:_ $a $b ((_) (_?_:_ $c $d $e)))",
+    ),
+    ("$a || $b ? $c : $d", "(_?_:_ (|| $a $b) $c $d)"),
+    // Assignments are right-associative, and bind to the variable on their left regardless of
+    // the precedence of the operator before it.
+    ("$a = $b = $c", "(= $a (= $b $c))"),
+    ("$a += $b * $c", "(+= $a (* $b $c))"),
+    ("$a = $b and $c", "(and (= $a $b) $c)"),
+    ("$a && $b = $c", "(&& $a (= $b $c))"),
+    ("!$a = $b", "(! (= $a $b))"),
+    ("$a ?: $b = $c", "(?: $a (= $b $c))"),
+    ("$a ? $b : $c = $d", "(_?_:_ $a $b (= $c $d))"),
+    ("$a . $b->c = $d", "(. $a (= (-> $b c) $d))"),
+    ("$ok = foo() or die()", "(or (= $ok (_() foo)) die())"),
+    ("$ok = foo() || die()", "(= $ok (|| (_() foo) die()))"),
+    ("$a = $b xor $c", "(xor (= $a $b) $c)"),
+    ("$a = ($b and $c)", "(= $a ((_) (and $b $c)))"),
+    // `print` binds looser than everything except `and`, `or` and `xor`, and its parentheses
+    // belong to the operand rather than to a call.
+    ("print $a && $b", "(print (&& $a $b))"),
+    ("print ($a) && $b", "(print (&& ((_) $a) $b))"),
+    ("print $a = 1", "(print (= $a 1))"),
+    ("print $a ? $b : $c", "(print (_?_:_ $a $b $c))"),
+    ("print $a and $b", "(and (print $a) $b)"),
+    ("print print $a", "(print (print $a))"),
+    ("$x = print 'hi'", "(= $x (print 'hi'))"),
+    ("$a && print $b || $c", "(&& $a (print (|| $b $c)))"),
+    ("1 + print 2 + 3", "(+ 1 (print (+ 2 3)))"),
+    // `include` and `require` have the lowest precedence of all, so even `or` is part of the
+    // path, e.g. `include 'f.php' or die()` includes the file named `'1'`.
+    ("require $path . '.php'", "(require (. $path '.php'))"),
+    ("include 'a' . 'b'", "(include (. 'a' 'b'))"),
+    ("include $a ?? $b", "(include (?? $a $b))"),
+    ("require $a or $b", "(require (or $a $b))"),
+    ("$a = include 'f.php'", "(= $a (include 'f.php'))"),
+    (
+        "$a = include 'f.php' or die()",
+        "(= $a (include (or 'f.php' die())))",
+    ),
+    (
+        "(include 'f.php') or die()",
+        "(or ((_) (include 'f.php')) die())",
+    ),
+    ("!include 'a' . 'b'", "(! (include (. 'a' 'b')))"),
+];
+
+fn parse(expression: &str) -> (Expression, Vec<String>) {
+    let result = Parser::parse(Lexer::new(format!("<?php {expression};").as_bytes()));
+
+    let diagnostics = result
+        .diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.kind.get_identifier())
+        .collect();
+
+    let expression = result
+        .ast
+        .into_iter()
+        .find_map(|statement| match statement.kind {
+            StatementKind::Expression(statement) => Some(statement.expression),
+            _ => None,
+        })
+        .unwrap();
+
+    (expression, diagnostics)
+}
+
+/// Print an expression as an S-expression, using the source code of the operators.
+fn sexp(source: &str, node: Node) -> String {
+    let mut operands = Vec::new();
+    collect_operands(node, &mut operands);
+
+    if operands.is_empty() {
+        return source[node.span.start..node.span.end].to_string();
+    }
+
+    let mut pattern = String::new();
+    let mut offset = node.span.start;
+
+    for operand in operands.iter() {
+        pattern.push_str(&source[offset..operand.span.start]);
+        pattern.push('_');
+        offset = operand.span.end;
+    }
+
+    pattern.push_str(&source[offset..node.span.end]);
+    pattern.retain(|c| !c.is_whitespace());
+
+    let operator = match (pattern.strip_prefix('_'), pattern.strip_suffix('_')) {
+        (Some(infix), Some(_)) if !infix[..infix.len() - 1].contains('_') => {
+            &infix[..infix.len() - 1]
+        }
+        (None, Some(prefix)) if !prefix.contains('_') => prefix,
+        _ => &pattern,
+    };
+
+    let operands = operands
+        .into_iter()
+        .map(|operand| sexp(source, operand))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    format!("({} {})", operator, operands)
+}
+
+fn collect_operands<'a>(node: Node<'a>, operands: &mut Vec<Node<'a>>) {
+    for child in node.children() {
+        if child.is_expression() {
+            operands.push(child);
+        } else {
+            collect_operands(child, operands);
+        }
+    }
+}
+
+#[test]
+fn it_parses_operators_with_the_correct_precedence_and_associativity() {
+    let mut failures = Vec::new();
+
+    for (expression, expected) in CASES {
+        let (parsed, diagnostics) = parse(expression);
+        let source = format!("<?php {expression};");
+        let actual = sexp(&source, Node::from(&parsed));
+
+        if actual != *expected || !diagnostics.is_empty() {
+            failures.push(format!(
+                "{expression}\n  expected: {expected}\n  actual:   {actual} {diagnostics:?}"
+            ));
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn it_reports_chained_non_associative_operators() {
+    for expression in [
+        "$a == $b == $c",
+        "$a === $b != $c",
+        "$a < $b > $c",
+        "$a <= $b >= $c",
+        "1 < 2 < 3",
+    ] {
+        let (_, diagnostics) = parse(expression);
+
+        assert_eq!(diagnostics, vec!["parser.unexpected-token"], "{expression}");
+    }
+}
+
+#[test]
+fn it_reports_nested_ternaries_without_parentheses() {
+    for expression in [
+        "$a ? $b : $c ? $d : $e",
+        "$a ? $b : $c ?: $d",
+        "$a ?: $b ? $c : $d",
+    ] {
+        let (_, diagnostics) = parse(expression);
+
+        assert_eq!(
+            diagnostics,
+            vec!["parser.nested-ternary-without-parentheses"],
+            "{expression}"
+        );
+    }
+}
@@ -0,0 +1,357 @@
+use pxp_ast::*;
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+use pxp_span::{IsSpanned, Span};
+
+fn parse(code: &str) -> Vec<Statement> {
+    let result = Parser::parse(Lexer::new(code));
+
+    assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+
+    result.ast
+}
+
+fn expression(statement: &Statement) -> &Expression {
+    match &statement.kind {
+        StatementKind::Expression(statement) => &statement.expression,
+        kind => panic!("expected an expression statement, found {:?}", kind),
+    }
+}
+
+fn text(code: &str, span: Span) -> &str {
+    &code[span.start..span.end]
+}
+
+#[test]
+fn it_spans_every_array_item_variant() {
+    let code = "<?php [, 1, &$a, ...$b, 'k' => 2, 'r' => &$c];";
+    let ast = parse(code);
+
+    let ExpressionKind::Array(array) = &expression(&ast[1]).kind else {
+        panic!("expected an array");
+    };
+
+    let spans = array
+        .items
+        .iter()
+        .map(|item| match item {
+            ArrayItem::Skipped(span) => *span,
+            ArrayItem::Value(item) => item.value.span,
+            ArrayItem::ReferencedValue(item) => Span::combine(item.ampersand, item.value.span),
+            ArrayItem::SpreadValue(item) => Span::combine(item.ellipsis, item.value.span),
+            ArrayItem::KeyValue(item) => Span::combine(item.key.span, item.value.span),
+            ArrayItem::ReferencedKeyValue(item) => Span::combine(item.key.span, item.value.span),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        array
+            .items
+            .iter()
+            .map(|item| item.span())
+            .collect::<Vec<_>>(),
+        spans
+    );
+    assert_eq!(
+        array
+            .items
+            .iter()
+            .skip(1)
+            .map(|item| text(code, item.span()))
+            .collect::<Vec<_>>(),
+        vec!["1", "&$a", "...$b", "'k' => 2", "'r' => &$c"]
+    );
+}
+
+#[test]
+fn it_spans_operation_variants_from_their_operands() {
+    let code = "<?php $a + $b; -$a; $a++; !$a; ~$a; $a <=> $b; $a and $b;";
+    let ast = parse(code);
+
+    let spans = ast[1..]
+        .iter()
+        .map(|statement| match &expression(statement).kind {
+            ExpressionKind::ArithmeticOperation(operation) => operation.kind.span(),
+            ExpressionKind::LogicalOperation(operation) => operation.kind.span(),
+            ExpressionKind::BitwiseOperation(operation) => operation.kind.span(),
+            ExpressionKind::ComparisonOperation(operation) => operation.kind.span(),
+            kind => panic!("expected an operation, found {:?}", kind),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        spans,
+        ast[1..]
+            .iter()
+            .map(|statement| expression(statement).span)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        spans
+            .iter()
+            .map(|span| text(code, *span))
+            .collect::<Vec<_>>(),
+        vec![
+            "$a + $b",
+            "-$a",
+            "$a++",
+            "!$a",
+            "~$a",
+            "$a <=> $b",
+            "$a and $b"
+        ]
+    );
+}
+
+#[test]
+fn it_spans_foreach_iterators_identifiers_and_arguments() {
+    let code = "<?php foreach ($items as $key => $value) {} foo(1, named: 2); $a->{$b};";
+    let ast = parse(code);
+
+    let StatementKind::Foreach(foreach) = &ast[1].kind else {
+        panic!("expected a foreach statement");
+    };
+
+    let ForeachStatementIterator::KeyAndValue(iterator) = &foreach.iterator else {
+        panic!("expected a key and value iterator");
+    };
+
+    assert_eq!(
+        foreach.iterator.span(),
+        Span::combine(iterator.expression.span, iterator.value.span)
+    );
+    assert_eq!(text(code, foreach.body.span()), "{}");
+
+    let ExpressionKind::FunctionCall(call) = &expression(&ast[2]).kind else {
+        panic!("expected a function call");
+    };
+
+    assert_eq!(
+        call.arguments
+            .arguments
+            .iter()
+            .map(|argument| text(code, argument.span()))
+            .collect::<Vec<_>>(),
+        vec!["1", "named: 2"]
+    );
+    assert_eq!(
+        call.arguments.arguments.span(),
+        Span::combine(
+            call.arguments.arguments[0].span(),
+            call.arguments.arguments[1].span()
+        )
+    );
+
+    let ExpressionKind::PropertyFetch(fetch) = &expression(&ast[3]).kind else {
+        panic!("expected a property fetch");
+    };
+
+    assert_eq!(text(code, fetch.property.span()), "{$b}");
+}
+
+#[test]
+fn it_uses_the_missing_span_for_absent_nodes() {
+    let none: Option<Expression> = None;
+    let empty: Vec<Argument> = Vec::new();
+
+    assert_eq!(none.span(), Span::missing());
+    assert_eq!(empty.span(), Span::missing());
+}
+
+/// The spans of the operator tokens of a binary, assignment or ternary expression.
+fn operator_spans(expression: &Expression) -> Vec<Span> {
+    let span = match &expression.kind {
+        ExpressionKind::ArithmeticOperation(operation) => match &operation.kind {
+            ArithmeticOperationKind::Addition { plus, .. } => *plus,
+            ArithmeticOperationKind::Subtraction { minus, .. } => *minus,
+            ArithmeticOperationKind::Multiplication { asterisk, .. } => *asterisk,
+            ArithmeticOperationKind::Division { slash, .. } => *slash,
+            ArithmeticOperationKind::Modulo { percent, .. } => *percent,
+            ArithmeticOperationKind::Exponentiation { pow, .. } => *pow,
+            kind => panic!("expected a binary operation, found {:?}", kind),
+        },
+        ExpressionKind::AssignmentOperation(operation) => match operation.kind {
+            AssignmentOperationKind::Assign(span)
+            | AssignmentOperationKind::Addition(span)
+            | AssignmentOperationKind::Subtraction(span)
+            | AssignmentOperationKind::Multiplication(span)
+            | AssignmentOperationKind::Division(span)
+            | AssignmentOperationKind::Modulo(span)
+            | AssignmentOperationKind::Exponentiation(span)
+            | AssignmentOperationKind::Concat(span)
+            | AssignmentOperationKind::BitwiseAnd(span)
+            | AssignmentOperationKind::BitwiseOr(span)
+            | AssignmentOperationKind::BitwiseXor(span)
+            | AssignmentOperationKind::LeftShift(span)
+            | AssignmentOperationKind::RightShift(span)
+            | AssignmentOperationKind::Coalesce(span) => span,
+        },
+        ExpressionKind::BitwiseOperation(operation) => match &operation.kind {
+            BitwiseOperationKind::And { and, .. } => *and,
+            BitwiseOperationKind::Or { or, .. } => *or,
+            BitwiseOperationKind::Xor { xor, .. } => *xor,
+            BitwiseOperationKind::LeftShift { left_shift, .. } => *left_shift,
+            BitwiseOperationKind::RightShift { right_shift, .. } => *right_shift,
+            kind => panic!("expected a binary operation, found {:?}", kind),
+        },
+        ExpressionKind::ComparisonOperation(operation) => match &operation.kind {
+            ComparisonOperationKind::Equal { double_equals, .. } => *double_equals,
+            ComparisonOperationKind::Identical { triple_equals, .. } => *triple_equals,
+            ComparisonOperationKind::NotEqual { bang_equals, .. } => *bang_equals,
+            ComparisonOperationKind::AngledNotEqual {
+                angled_left_right, ..
+            } => *angled_left_right,
+            ComparisonOperationKind::NotIdentical {
+                bang_double_equals, ..
+            } => *bang_double_equals,
+            ComparisonOperationKind::LessThan { less_than, .. } => *less_than,
+            ComparisonOperationKind::GreaterThan { greater_than, .. } => *greater_than,
+            ComparisonOperationKind::LessThanOrEqual {
+                less_than_equals, ..
+            } => *less_than_equals,
+            ComparisonOperationKind::GreaterThanOrEqual {
+                greater_than_equals,
+                ..
+            } => *greater_than_equals,
+            ComparisonOperationKind::Spaceship { spaceship, .. } => *spaceship,
+        },
+        ExpressionKind::LogicalOperation(operation) => match &operation.kind {
+            LogicalOperationKind::And {
+                double_ampersand, ..
+            } => *double_ampersand,
+            LogicalOperationKind::Or { double_pipe, .. } => *double_pipe,
+            LogicalOperationKind::LogicalAnd { and, .. } => *and,
+            LogicalOperationKind::LogicalOr { or, .. } => *or,
+            LogicalOperationKind::LogicalXor { xor, .. } => *xor,
+            kind => panic!("expected a binary operation, found {:?}", kind),
+        },
+        ExpressionKind::Concat(concat) => concat.dot,
+        ExpressionKind::Instanceof(instanceof) => instanceof.instanceof,
+        ExpressionKind::Coalesce(coalesce) => coalesce.double_question,
+        ExpressionKind::ShortTernary(ternary) => ternary.question_colon,
+        ExpressionKind::Ternary(ternary) => return vec![ternary.question, ternary.colon],
+        kind => panic!("expected an operation, found {:?}", kind),
+    };
+
+    vec![span]
+}
+
+#[test]
+fn it_spans_the_operator_token_of_every_binary_and_assignment_operation() {
+    let operators = [
+        "+", "-", "*", "/", "%", "**", ".", "&", "|", "^", "<<", ">>", "==", "===", "!=", "<>",
+        "!==", "<", ">", "<=", ">=", "<=>", "&&", "||", "and", "or", "xor", "??", "?:", "=", "+=",
+        "-=", "*=", "/=", "%=", "**=", ".=", "&=", "|=", "^=", "<<=", ">>=", "??=",
+    ];
+
+    for operator in operators {
+        // Extra whitespace makes sure that the span doesn't start at, or include, an operand.
+        let code = format!("<?php $a  {}  $b;", operator);
+        let ast = parse(&code);
+
+        assert_eq!(
+            operator_spans(expression(&ast[1]))
+                .into_iter()
+                .map(|span| text(&code, span))
+                .collect::<Vec<_>>(),
+            vec![operator],
+            "{}",
+            code
+        );
+    }
+
+    for right in [
+        "$b",
+        "self",
+        "parent",
+        "static",
+        "Foo",
+        "\\Foo\\Bar",
+        "enum",
+        "from",
+    ] {
+        let code = format!("<?php $a  instanceof  {};", right);
+        let ast = parse(&code);
+
+        assert_eq!(
+            operator_spans(expression(&ast[1]))
+                .into_iter()
+                .map(|span| text(&code, span))
+                .collect::<Vec<_>>(),
+            vec!["instanceof"],
+            "{}",
+            code
+        );
+    }
+
+    let code = "<?php $a  =  &$b;";
+    let ast = parse(code);
+
+    assert_eq!(
+        operator_spans(expression(&ast[1]))
+            .into_iter()
+            .map(|span| text(code, span))
+            .collect::<Vec<_>>(),
+        vec!["="]
+    );
+}
+
+#[test]
+fn it_spans_the_question_and_colon_of_a_ternary() {
+    for (code, then) in [
+        ("<?php $a  ?  $b  :  $c;", "$b"),
+        ("<?php $a ? /* c */ : $b;", ""),
+        ("<?php $a  ?  /* c */  $b  /* d */  :  $c;", "$b"),
+    ] {
+        let ast = parse(code);
+
+        assert_eq!(
+            operator_spans(expression(&ast[1]))
+                .into_iter()
+                .map(|span| text(code, span))
+                .collect::<Vec<_>>(),
+            vec!["?", ":"],
+            "{}",
+            code
+        );
+
+        let ExpressionKind::Ternary(ternary) = &expression(&ast[1]).kind else {
+            panic!("expected a ternary");
+        };
+
+        assert_eq!(text(code, ternary.then.span), then, "{}", code);
+    }
+}
+
+#[test]
+fn it_spans_the_call_form_of_clone() {
+    let code = "<?php clone($a); clone $b; clone ($c)->d;";
+    let ast = parse(code);
+
+    let clones = ast[1..]
+        .iter()
+        .map(|statement| match &expression(statement).kind {
+            ExpressionKind::Clone(clone) => clone,
+            kind => panic!("expected a clone, found {:?}", kind),
+        })
+        .collect::<Vec<_>>();
+
+    assert!(clones[0].is_call());
+    assert_eq!(text(code, clones[0].span), "clone($a)");
+    assert_eq!(text(code, clones[0].target.span), "$a");
+    assert_eq!(
+        clones[0].left_parenthesis.map(|span| text(code, span)),
+        Some("(")
+    );
+    assert_eq!(
+        clones[0].right_parenthesis.map(|span| text(code, span)),
+        Some(")")
+    );
+
+    assert!(!clones[1].is_call());
+    assert_eq!(text(code, clones[1].span), "clone $b");
+
+    // The parentheses only wrap part of the operand, so they aren't the call form.
+    assert!(!clones[2].is_call());
+    assert_eq!(text(code, clones[2].target.span), "($c)->d");
+}
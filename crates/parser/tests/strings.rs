@@ -0,0 +1,123 @@
+use pxp_ast::utils::StringLike;
+use pxp_ast::{Expression, ExpressionKind, StatementKind, StringPart, Variable};
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+
+fn expression(source: &str) -> Expression {
+    let result = Parser::parse(Lexer::new(format!("<?php {source};").as_bytes()));
+
+    result
+        .ast
+        .into_iter()
+        .find_map(|statement| match statement.kind {
+            StatementKind::Expression(statement) => Some(statement.expression),
+            _ => None,
+        })
+        .unwrap()
+}
+
+/// The parts of a string, with expressions replaced by the name of the variable they contain.
+fn parts(source: &str) -> Vec<String> {
+    expression(source)
+        .parts()
+        .unwrap()
+        .map(|part| match part.as_ref() {
+            StringPart::Literal(literal) => literal.value.to_string(),
+            StringPart::Expression(part) => match &part.expression.kind {
+                ExpressionKind::Variable(variable) => match variable.as_ref() {
+                    Variable::SimpleVariable(variable) => format!("{{{}}}", variable.symbol),
+                    variable => panic!("unexpected variable: {variable:?}"),
+                },
+                kind => panic!("unexpected expression: {kind:?}"),
+            },
+        })
+        .collect()
+}
+
+fn constant_value(source: &str) -> Option<String> {
+    expression(source)
+        .parts()
+        .unwrap()
+        .constant_value()
+        .map(|value| value.to_string())
+}
+
+#[test]
+fn it_produces_the_same_parts_for_every_form_of_string() {
+    let expected = vec!["Hello\t", "{$name}", "!\n  x"];
+
+    assert_eq!(parts(r#""Hello\t$name!\n  x""#), expected);
+    assert_eq!(parts(r#"`Hello\t{$name}!\n  x`"#), expected);
+    assert_eq!(
+        parts("<<<EOT\n    Hello\\t$name!\n      x\n    EOT"),
+        expected
+    );
+    assert_eq!(parts("<<<\"EOT\"\nHello\\t{$name}!\n  x\nEOT"), expected);
+}
+
+#[test]
+fn it_produces_the_same_parts_for_every_form_of_constant_string() {
+    let expected = vec!["Hello, \\n world\n  !"];
+
+    assert_eq!(parts("'Hello, \\n world\n  !'"), expected);
+    assert_eq!(parts(r#""Hello, \\n world\n  !""#), expected);
+    assert_eq!(parts(r#"`Hello, \\n world\n  !`"#), expected);
+    assert_eq!(
+        parts("<<<EOT\n\tHello, \\\\n world\n\t  !\n\tEOT"),
+        expected
+    );
+    assert_eq!(
+        parts("<<<'EOT'\n  Hello, \\n world\n    !\n  EOT"),
+        expected
+    );
+}
+
+#[test]
+fn it_dedents_heredocs_and_nowdocs_by_the_closing_label() {
+    assert_eq!(
+        constant_value("<<<EOT\n    a\n\n      b\n    EOT"),
+        Some("a\n\n  b".to_string())
+    );
+    assert_eq!(
+        constant_value("<<<'EOT'\n  a\n    $b\n  EOT"),
+        Some("a\n  $b".to_string())
+    );
+    assert_eq!(constant_value("<<<EOT\nEOT\nEOT"), Some("EOT".to_string()));
+
+    // Only the start of a line is indented, not text that follows an interpolated expression.
+    assert_eq!(
+        parts("<<<EOT\n  {$a}  b\n    c\n  EOT"),
+        vec!["{$a}", "  b\n  c"]
+    );
+}
+
+#[test]
+fn it_decodes_escapes_according_to_the_quoting_rules() {
+    assert_eq!(constant_value(r#"'a\'b\n'"#), Some("a'b\\n".to_string()));
+    assert_eq!(constant_value(r#""a\"b\`""#), Some("a\"b\\`".to_string()));
+    assert_eq!(constant_value(r#"`a\"b\``"#), Some("a\\\"b`".to_string()));
+    assert_eq!(
+        constant_value("<<<EOT\na\\\"b\\$\nEOT"),
+        Some("a\\\"b$".to_string())
+    );
+    assert_eq!(
+        constant_value("<<<'EOT'\na\\tb\nEOT"),
+        Some("a\\tb".to_string())
+    );
+}
+
+#[test]
+fn it_knows_whether_a_string_is_constant() {
+    assert!(expression("'a'").parts().unwrap().is_constant());
+    assert!(expression("\"a\\$b\"").parts().unwrap().is_constant());
+    assert!(!expression("\"a$b\"").parts().unwrap().is_constant());
+    assert!(!expression("<<<EOT\n{$b}\nEOT")
+        .parts()
+        .unwrap()
+        .is_constant());
+    assert_eq!(constant_value("\"a$b\""), None);
+    assert_eq!(constant_value("''"), Some(String::new()));
+
+    assert!(expression("1").parts().is_none());
+    assert!(expression("$a . 'b'").parts().is_none());
+}
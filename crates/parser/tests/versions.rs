@@ -0,0 +1,217 @@
+use pxp_lexer::Lexer;
+use pxp_parser::{
+    minimum_php_version, Feature, Parser, ParserDiagnostic, ParserOptions, PhpVersion,
+};
+
+/// The minimum version of PHP for a fixture, along with each feature and the source it points at.
+fn minimum(fixture: &str) -> (PhpVersion, Vec<(Feature, String)>) {
+    let path = format!(
+        "{}/tests/fixtures/versions/{fixture}",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let input = std::fs::read_to_string(path).unwrap();
+    let result = Parser::parse(Lexer::new(&input));
+
+    assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+
+    let (version, uses) = minimum_php_version(&result.ast);
+
+    (
+        version,
+        uses.iter()
+            .map(|feature| {
+                (
+                    feature.feature,
+                    input[feature.span.start..feature.span.end].to_string(),
+                )
+            })
+            .collect(),
+    )
+}
+
+fn uses(uses: &[(Feature, &str)]) -> Vec<(Feature, String)> {
+    uses.iter()
+        .map(|(feature, source)| (*feature, source.to_string()))
+        .collect()
+}
+
+#[test]
+fn it_reports_php_70_when_no_newer_features_are_used() {
+    assert_eq!(minimum("php-70.php"), (PhpVersion::PHP_70, vec![]));
+}
+
+#[test]
+fn it_reports_php_80_features() {
+    assert_eq!(
+        minimum("php-80.php"),
+        (
+            PhpVersion::PHP_80,
+            uses(&[
+                (Feature::MatchExpression, "match"),
+                (Feature::NullsafeOperator, "?->"),
+                (Feature::NamedArguments, "c: 2"),
+            ])
+        )
+    );
+}
+
+#[test]
+fn it_reports_php_81_features() {
+    assert_eq!(
+        minimum("php-81.php"),
+        (
+            PhpVersion::PHP_81,
+            uses(&[
+                (Feature::Enums, "enum"),
+                (Feature::ReadonlyProperties, "readonly"),
+                (Feature::NeverType, "never"),
+                (Feature::FirstClassCallables, "strlen(...)"),
+                (Feature::UnpackingStringKeys, "'a'"),
+            ])
+        )
+    );
+}
+
+#[test]
+fn it_reports_php_82_features() {
+    assert_eq!(
+        minimum("php-82.php"),
+        (
+            PhpVersion::PHP_82,
+            uses(&[
+                (Feature::ReadonlyClasses, "readonly"),
+                (Feature::DnfTypes, "(B&C)|null"),
+                (Feature::NullsafeOperator, "?->"),
+                (Feature::NamedArguments, "b: null"),
+            ])
+        )
+    );
+}
+
+#[test]
+fn it_reports_standalone_null_false_and_true_as_php_82_features() {
+    assert_eq!(
+        minimum("standalone-null-false-true.php"),
+        (
+            PhpVersion::PHP_82,
+            uses(&[
+                (Feature::StandaloneNullFalseTrue, "null"),
+                (Feature::StandaloneNullFalseTrue, "false"),
+                (Feature::StandaloneNullFalseTrue, "true"),
+            ])
+        )
+    );
+}
+
+#[test]
+fn it_only_accepts_standalone_null_false_and_true_from_php_82() {
+    let path = format!(
+        "{}/tests/fixtures/versions/standalone-null-false-true.php",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let input = std::fs::read_to_string(path).unwrap();
+    let unsupported = |target: PhpVersion| {
+        Parser::parse_with_options(
+            Lexer::new(&input),
+            ParserOptions::new().with_php_version(target),
+        )
+        .diagnostics
+        .iter()
+        .filter(|diagnostic| {
+            matches!(
+                diagnostic.kind,
+                ParserDiagnostic::UnsupportedPhpVersion { .. }
+            )
+        })
+        .map(|diagnostic| input[diagnostic.span.start..diagnostic.span.end].to_string())
+        .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        unsupported(PhpVersion::PHP_80),
+        vec!["null", "false", "true"]
+    );
+    assert_eq!(
+        unsupported(PhpVersion::PHP_81),
+        vec!["null", "false", "true"]
+    );
+    assert!(unsupported(PhpVersion::PHP_82).is_empty());
+}
+
+#[test]
+fn it_reports_php_83_features() {
+    assert_eq!(
+        minimum("php-83.php"),
+        (
+            PhpVersion::PHP_83,
+            uses(&[(Feature::TypedClassConstants, "string")])
+        )
+    );
+}
+
+#[test]
+fn it_reports_php_84_features() {
+    assert_eq!(
+        minimum("php-84.php"),
+        (
+            PhpVersion::PHP_84,
+            uses(&[
+                (Feature::AsymmetricVisibility, "private(set)"),
+                (Feature::PropertyHooks, "{\n        get => 'b';\n    }"),
+                (Feature::ExitClosureCreation, "die(...)"),
+                (Feature::ExitNamedArgument, "status: 1"),
+                (Feature::NamedArguments, "status: 1"),
+            ])
+        )
+    );
+}
+
+#[test]
+fn it_reports_php_85_features() {
+    assert_eq!(
+        minimum("php-85.php"),
+        (
+            PhpVersion::PHP_85,
+            uses(&[(Feature::CloneClosureCreation, "clone(...)")])
+        )
+    );
+}
+
+#[test]
+fn it_uses_the_same_versions_as_the_parser() {
+    for fixture in [
+        "php-70.php",
+        "php-80.php",
+        "php-81.php",
+        "php-82.php",
+        "php-83.php",
+        "php-84.php",
+        "php-85.php",
+        "standalone-null-false-true.php",
+    ] {
+        let path = format!(
+            "{}/tests/fixtures/versions/{fixture}",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let input = std::fs::read_to_string(path).unwrap();
+        let (version, _) = minimum(fixture);
+
+        let reported = |target: PhpVersion| {
+            Parser::parse_with_options(
+                Lexer::new(&input),
+                ParserOptions::new().with_php_version(target),
+            )
+            .diagnostics
+            .iter()
+            .any(|diagnostic| {
+                matches!(
+                    diagnostic.kind,
+                    ParserDiagnostic::UnsupportedPhpVersion { .. }
+                )
+            })
+        };
+
+        // The parser only gates some of the features, but never asks for a newer version.
+        assert!(!reported(version), "{fixture}");
+    }
+}
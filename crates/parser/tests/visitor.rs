@@ -0,0 +1,112 @@
+use pxp_ast::visitor::{ControlFlowVisitor, ControlFlowVisitorMut, Traversal};
+use pxp_ast::{ClassBody, FunctionCallExpression, Method, SimpleVariable, Statement};
+use pxp_lexer::Lexer;
+use pxp_parser::Parser;
+
+fn parse(code: &str) -> Vec<Statement> {
+    let result = Parser::parse(Lexer::new(format!("<?php {code}").as_bytes()));
+
+    assert!(result.diagnostics.is_empty());
+
+    result.ast
+}
+
+#[derive(Default)]
+struct StopAtFirstCall {
+    variables: Vec<String>,
+}
+
+impl ControlFlowVisitor for StopAtFirstCall {
+    fn visit_function_call_expression(&mut self, _: &FunctionCallExpression) -> Traversal {
+        Traversal::Stop
+    }
+
+    fn visit_simple_variable(&mut self, node: &SimpleVariable) -> Traversal {
+        self.variables.push(node.symbol.to_string());
+
+        Traversal::Continue
+    }
+}
+
+#[derive(Default)]
+struct SkipClassBodies {
+    methods: Vec<String>,
+}
+
+impl ControlFlowVisitor for SkipClassBodies {
+    fn visit_class_body(&mut self, _: &ClassBody) -> Traversal {
+        Traversal::SkipChildren
+    }
+
+    fn visit_method(&mut self, node: &Method) -> Traversal {
+        self.methods.push(node.name.symbol.to_string());
+
+        Traversal::Continue
+    }
+}
+
+#[test]
+fn it_stops_walking_at_the_first_function_call() {
+    let ast = parse("$a; if ($b) { foo($c); } $d; bar($e);");
+    let mut visitor = StopAtFirstCall::default();
+
+    assert_eq!(visitor.visit(&ast), Traversal::Stop);
+    assert_eq!(visitor.variables, vec!["$a", "$b"]);
+
+    let mut visitor = StopAtFirstCall::default();
+
+    assert_eq!(visitor.visit(&parse("$a; $b;")), Traversal::Continue);
+    assert_eq!(visitor.variables, vec!["$a", "$b"]);
+}
+
+#[test]
+fn it_skips_the_children_of_a_node() {
+    let ast = parse(
+        r#"
+        class A {
+            public function a() {}
+        }
+
+        trait B {
+            public function b() {}
+        }
+
+        class C {
+            public function c() {}
+        }
+        "#,
+    );
+    let mut visitor = SkipClassBodies::default();
+
+    assert_eq!(visitor.visit(&ast), Traversal::Continue);
+    assert_eq!(visitor.methods, vec!["b"]);
+}
+
+#[test]
+fn it_stops_walking_a_mutable_tree() {
+    struct Rename {
+        remaining: usize,
+    }
+
+    impl ControlFlowVisitorMut for Rename {
+        fn visit_simple_variable(&mut self, node: &mut SimpleVariable) -> Traversal {
+            if self.remaining == 0 {
+                return Traversal::Stop;
+            }
+
+            self.remaining -= 1;
+            node.symbol = b"$renamed".into();
+
+            Traversal::Continue
+        }
+    }
+
+    let mut ast = parse("$a; $b; $c;");
+
+    assert_eq!(Rename { remaining: 2 }.visit(&mut ast), Traversal::Stop);
+    assert_eq!(
+        format!("{:?}", ast).matches("$renamed").count(),
+        2,
+        "Only the first two variables should be renamed."
+    );
+}
@@ -51,8 +51,6 @@ impl Snapper {
 mod tests {
     use crate::Snapper;
 
-    use super::snap;
-
     snap!(snapper, it_can_say_hello_world, say_hello("world"));
 
     fn say_hello(name: &str) -> String {
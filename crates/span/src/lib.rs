@@ -2,6 +2,10 @@ use std::{fmt::Debug, ops::Range};
 
 use serde::{Deserialize, Serialize};
 
+mod line_index;
+
+pub use line_index::{LineColumn, LineIndex};
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize, Hash)]
 pub struct Span {
     pub start: ByteOffset,
@@ -106,10 +110,12 @@ pub trait IsSpanned {
     }
 }
 
+/// The span of a list covers its first and last elements. An empty list has no position, so it
+/// uses the same `0..0` sentinel as [`Span::missing`].
 impl<T: IsSpanned> IsSpanned for Vec<T> {
     fn span(&self) -> Span {
         if self.is_empty() {
-            Span::default()
+            Span::missing()
         } else if self.len() == 1 {
             self.first().unwrap().span()
         } else {
@@ -121,11 +127,13 @@ impl<T: IsSpanned> IsSpanned for Vec<T> {
     }
 }
 
+/// An absent node has no position, so `None` uses the `0..0` sentinel from [`Span::missing`].
+/// Check for `None` directly rather than comparing against the sentinel when that matters.
 impl<T: IsSpanned> IsSpanned for Option<T> {
     fn span(&self) -> Span {
         match self {
             Some(t) => t.span(),
-            None => Span::default(),
+            None => Span::missing(),
         }
     }
 }
@@ -0,0 +1,98 @@
+use crate::ByteOffset;
+
+/// A 0-based line and column within a source file. The column counts Unicode code points from the
+/// start of the line, which is what most editors and reporting formats expect.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct LineColumn {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Maps byte offsets in a source file to lines and columns.
+///
+/// The start of every line is recorded up front, so looking up an offset is a binary search instead
+/// of a scan over the whole file.
+#[derive(Debug, Clone)]
+pub struct LineIndex<'a> {
+    source: &'a [u8],
+    line_starts: Vec<ByteOffset>,
+}
+
+impl<'a> LineIndex<'a> {
+    pub fn new(source: &'a [u8]) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(
+                source
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, byte)| **byte == b'\n')
+                    .map(|(offset, _)| offset + 1),
+            )
+            .collect();
+
+        Self {
+            source,
+            line_starts,
+        }
+    }
+
+    /// The source that the index was built from.
+    pub fn source(&self) -> &'a [u8] {
+        self.source
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Offsets past the end of the source are clamped to the end of the source.
+    pub fn line_column(&self, offset: ByteOffset) -> LineColumn {
+        let offset = offset.min(self.source.len());
+        let line = match self.line_starts.binary_search(&offset) {
+            Ok(line) => line,
+            Err(next) => next - 1,
+        };
+
+        let column = self.source[self.line_starts[line]..offset]
+            .iter()
+            .filter(|byte| !is_continuation_byte(**byte))
+            .count();
+
+        LineColumn { line, column }
+    }
+}
+
+fn is_continuation_byte(byte: u8) -> bool {
+    byte & 0b1100_0000 == 0b1000_0000
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_maps_offsets_to_lines_and_columns() {
+        let index = LineIndex::new(b"<?php\n\necho $a;\n");
+
+        assert_eq!(index.line_count(), 4);
+        assert_eq!(index.line_column(0), LineColumn { line: 0, column: 0 });
+        assert_eq!(index.line_column(5), LineColumn { line: 0, column: 5 });
+        assert_eq!(index.line_column(6), LineColumn { line: 1, column: 0 });
+        assert_eq!(index.line_column(12), LineColumn { line: 2, column: 5 });
+        assert_eq!(index.line_column(100), LineColumn { line: 3, column: 0 });
+    }
+
+    #[test]
+    fn it_counts_columns_in_code_points() {
+        let source = "$café = 'ü';".as_bytes();
+        let index = LineIndex::new(source);
+
+        assert_eq!(
+            index.line_column(source.len()),
+            LineColumn {
+                line: 0,
+                column: 12
+            }
+        );
+    }
+}
@@ -64,6 +64,7 @@ pub enum TokenKind {
     TraitConstant,
     FunctionConstant,
     MethodConstant,
+    PropertyConstant,
     LineConstant,
     FileConstant,
     Clone,
@@ -224,6 +225,244 @@ pub enum TokenKind {
     PhpDocSuper,
 }
 
+/// A coarse classification of a token, useful for syntax highlighting.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum TokenCategory {
+    Keyword,
+    Identifier,
+    Variable,
+    String,
+    Number,
+    Comment,
+    Operator,
+    Punctuation,
+    Html,
+    Cast,
+    MagicConstant,
+    /// Tokens that don't correspond to any source text, e.g. `Eof` and `Missing`.
+    Other,
+}
+
+impl TokenKind {
+    pub fn category(&self) -> TokenCategory {
+        match self {
+            Self::Die
+            | Self::Self_
+            | Self::Parent
+            | Self::From
+            | Self::Print
+            | Self::HaltCompiler
+            | Self::Readonly
+            | Self::Global
+            | Self::Abstract
+            | Self::Array
+            | Self::As
+            | Self::Break
+            | Self::Callable
+            | Self::Case
+            | Self::Catch
+            | Self::Class
+            | Self::Clone
+            | Self::Const
+            | Self::Continue
+            | Self::Declare
+            | Self::Default
+            | Self::Do
+            | Self::Echo
+            | Self::Else
+            | Self::ElseIf
+            | Self::Empty
+            | Self::EndDeclare
+            | Self::EndFor
+            | Self::EndForeach
+            | Self::EndIf
+            | Self::EndSwitch
+            | Self::EndWhile
+            | Self::Enum
+            | Self::Extends
+            | Self::False
+            | Self::Final
+            | Self::Finally
+            | Self::Fn
+            | Self::For
+            | Self::Foreach
+            | Self::Function
+            | Self::Goto
+            | Self::If
+            | Self::Implements
+            | Self::Include
+            | Self::IncludeOnce
+            | Self::Instanceof
+            | Self::Insteadof
+            | Self::Eval
+            | Self::Exit
+            | Self::Unset
+            | Self::Isset
+            | Self::List
+            | Self::Interface
+            | Self::Match
+            | Self::Namespace
+            | Self::New
+            | Self::Null
+            | Self::Private
+            | Self::Protected
+            | Self::Public
+            | Self::Require
+            | Self::RequireOnce
+            | Self::Return
+            | Self::Static
+            | Self::Switch
+            | Self::Throw
+            | Self::Trait
+            | Self::True
+            | Self::Try
+            | Self::Use
+            | Self::Var
+            | Self::Yield
+            | Self::While
+            | Self::LogicalAnd
+            | Self::LogicalOr
+            | Self::LogicalXor
+            | Self::PublicSet
+            | Self::ProtectedSet
+            | Self::PrivateSet => TokenCategory::Keyword,
+
+            Self::Identifier | Self::QualifiedIdentifier | Self::FullyQualifiedIdentifier => {
+                TokenCategory::Identifier
+            }
+
+            Self::Variable | Self::Dollar => TokenCategory::Variable,
+
+            Self::LiteralSingleQuotedString
+            | Self::LiteralDoubleQuotedString
+            | Self::StringPart
+            | Self::DoubleQuote
+            | Self::Backtick
+            | Self::StartHeredoc
+            | Self::StartNowdoc
+            | Self::EndHeredoc
+            | Self::EndNowdoc => TokenCategory::String,
+
+            Self::LiteralInteger | Self::LiteralFloat => TokenCategory::Number,
+
+            Self::SingleLineComment
+            | Self::HashMarkComment
+            | Self::MultiLineComment
+            | Self::DocBlockComment
+            | Self::OpenPhpDoc
+            | Self::ClosePhpDoc
+            | Self::PhpDocEol
+            | Self::PhpDocHorizontalWhitespace
+            | Self::PhpDocTag
+            | Self::PhpDocOther
+            | Self::PhpDocIs
+            | Self::PhpDocNot
+            | Self::PhpDocEmpty
+            | Self::PhpDocOf
+            | Self::PhpDocSuper => TokenCategory::Comment,
+
+            Self::Ampersand
+            | Self::AmpersandEquals
+            | Self::And
+            | Self::AndEquals
+            | Self::Arrow
+            | Self::QuestionArrow
+            | Self::At
+            | Self::Asterisk
+            | Self::AsteriskEquals
+            | Self::Bang
+            | Self::BangEquals
+            | Self::AngledLeftRight
+            | Self::BangDoubleEquals
+            | Self::Spaceship
+            | Self::BooleanAnd
+            | Self::BooleanOr
+            | Self::Caret
+            | Self::CaretEquals
+            | Self::MinusEquals
+            | Self::DoubleQuestion
+            | Self::DoubleQuestionEquals
+            | Self::Decrement
+            | Self::DivEquals
+            | Self::Dot
+            | Self::DotEquals
+            | Self::DoubleArrow
+            | Self::DoubleColon
+            | Self::DoubleEquals
+            | Self::Ellipsis
+            | Self::Equals
+            | Self::GreaterThan
+            | Self::GreaterThanEquals
+            | Self::Increment
+            | Self::LeftShift
+            | Self::LeftShiftEquals
+            | Self::RightShift
+            | Self::RightShiftEquals
+            | Self::LessThan
+            | Self::LessThanEquals
+            | Self::Minus
+            | Self::Percent
+            | Self::PercentEquals
+            | Self::Pipe
+            | Self::PipeEquals
+            | Self::Plus
+            | Self::PlusEquals
+            | Self::Pow
+            | Self::PowEquals
+            | Self::Question
+            | Self::QuestionColon
+            | Self::Slash
+            | Self::SlashEquals
+            | Self::TripleEquals
+            | Self::BitwiseNot => TokenCategory::Operator,
+
+            Self::Attribute
+            | Self::CloseTag
+            | Self::Colon
+            | Self::Comma
+            | Self::CurlyOpen
+            | Self::DollarLeftBrace
+            | Self::LeftBrace
+            | Self::LeftBracket
+            | Self::LeftParen
+            | Self::NamespaceSeparator
+            | Self::OpenTag(_)
+            | Self::RightBrace
+            | Self::RightBracket
+            | Self::RightParen
+            | Self::SemiColon => TokenCategory::Punctuation,
+
+            Self::InlineHtml => TokenCategory::Html,
+
+            Self::ArrayCast
+            | Self::BoolCast
+            | Self::BooleanCast
+            | Self::IntCast
+            | Self::IntegerCast
+            | Self::DoubleCast
+            | Self::RealCast
+            | Self::FloatCast
+            | Self::ObjectCast
+            | Self::UnsetCast
+            | Self::StringCast
+            | Self::BinaryCast => TokenCategory::Cast,
+
+            Self::ClassConstant
+            | Self::TraitConstant
+            | Self::FunctionConstant
+            | Self::MethodConstant
+            | Self::PropertyConstant
+            | Self::LineConstant
+            | Self::FileConstant
+            | Self::DirConstant
+            | Self::NamespaceConstant
+            | Self::CompilerHaltOffsetConstant => TokenCategory::MagicConstant,
+
+            Self::Missing | Self::Invalid | Self::Eof => TokenCategory::Other,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct Token<'a> {
     pub kind: TokenKind,
@@ -459,6 +698,7 @@ impl Display for TokenKind {
             Self::TraitConstant => "__TRAIT__",
             Self::FunctionConstant => "__FUNCTION__",
             Self::MethodConstant => "__METHOD__",
+            Self::PropertyConstant => "__PROPERTY__",
             Self::LineConstant => "__LINE__",
             Self::FileConstant => "__FILE__",
             Self::DollarLeftBrace => "${",
@@ -213,6 +213,21 @@ impl<N: Debug + Display> Type<N> {
         matches!(self, Type::Nullable(_))
     }
 
+    /// Whether the type is built in to PHP (or is a docblock pseudo-type), rather than referring
+    /// to a class-like. Composite types are built in when all of their members are.
+    pub fn is_builtin(&self) -> bool {
+        match self {
+            Type::Named(_)
+            | Type::SelfReference
+            | Type::StaticReference
+            | Type::ParentReference
+            | Type::This => false,
+            Type::Generic(base, _) | Type::Nullable(base) => base.is_builtin(),
+            Type::Union(types) | Type::Intersection(types) => types.iter().all(|t| t.is_builtin()),
+            _ => true,
+        }
+    }
+
     pub fn allows_null(&self) -> bool {
         match self {
             Type::Nullable(_) | Type::Null => true,
@@ -251,6 +266,235 @@ impl<N: Debug + Display> Type<N> {
     }
 }
 
+impl<N: Debug + Display + Clone> Type<N> {
+    /// Replace any literal values in the type with their general counterparts, e.g. a literal
+    /// string becomes `string`. This is useful when a type is rendered for people to read but the
+    /// value it was inferred from shouldn't be revealed.
+    pub fn redact(&self) -> Type<N> {
+        match self {
+            Type::LiteralString(_) => Type::String,
+            Type::ConstExpr(inner) => match inner.as_ref() {
+                ConstExpr::NegativeInteger(_) | ConstExpr::Integer(_) => Type::Integer,
+                ConstExpr::Float(_) => Type::Float,
+                ConstExpr::String(_) => Type::String,
+                ConstExpr::ConstFetch(..) => self.clone(),
+            },
+            Type::Nullable(inner) => Type::Nullable(Box::new(inner.redact())),
+            Type::Union(types) => Type::Union(types.iter().map(|t| t.redact()).collect()),
+            Type::Intersection(types) => {
+                Type::Intersection(types.iter().map(|t| t.redact()).collect())
+            }
+            Type::TypedArray(key, value) => {
+                Type::TypedArray(Box::new(key.redact()), Box::new(value.redact()))
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Replace the late-bound `static` and `$this` types with the type of the receiver,
+    /// e.g. the class that a method returning `static` was called on.
+    pub fn with_receiver(&self, receiver: &Type<N>) -> Type<N> {
+        match self {
+            Type::StaticReference | Type::This => receiver.clone(),
+            Type::Nullable(inner) => Type::Nullable(Box::new(inner.with_receiver(receiver))),
+            Type::Union(types) => {
+                Type::Union(types.iter().map(|t| t.with_receiver(receiver)).collect())
+            }
+            Type::Intersection(types) => {
+                Type::Intersection(types.iter().map(|t| t.with_receiver(receiver)).collect())
+            }
+            Type::TypedArray(key, value) => Type::TypedArray(
+                Box::new(key.with_receiver(receiver)),
+                Box::new(value.with_receiver(receiver)),
+            ),
+            Type::Generic(base, arguments) => Type::Generic(
+                Box::new(base.with_receiver(receiver)),
+                arguments
+                    .iter()
+                    .map(|argument| GenericTypeArgument {
+                        r#type: argument.r#type.with_receiver(receiver),
+                        variance: argument.variance,
+                    })
+                    .collect(),
+            ),
+            _ => self.clone(),
+        }
+    }
+
+    /// Replace `iterable` with `array|Traversable`, which is what it has been an alias of since PHP 8.2.
+    /// Type arguments are kept on both sides, e.g. `iterable<int, string>` becomes
+    /// `array<int, string>|Traversable<int, string>`.
+    pub fn with_expanded_iterable(&self, traversable: &Type<N>) -> Type<N> {
+        match self {
+            Type::Iterable => Type::Union(vec![Type::Array, traversable.clone()]),
+            Type::Generic(base, arguments) if matches!(**base, Type::Iterable) => {
+                Type::Union(vec![
+                    Type::Generic(Box::new(Type::Array), arguments.clone()),
+                    Type::Generic(Box::new(traversable.clone()), arguments.clone()),
+                ])
+            }
+            Type::Nullable(inner) if inner.includes_iterable() => {
+                let mut types = inner
+                    .with_expanded_iterable(traversable)
+                    .into_union_members();
+                types.push(Type::Null);
+
+                Type::Union(types)
+            }
+            Type::Union(types) => Type::Union(
+                types
+                    .iter()
+                    .flat_map(|t| t.with_expanded_iterable(traversable).into_union_members())
+                    .collect(),
+            ),
+            _ => self.clone(),
+        }
+    }
+
+    /// Remove `null` from the type, e.g. `?Foo` and `Foo|null` both become `Foo`.
+    pub fn without_null(&self) -> Type<N> {
+        match self {
+            Type::Nullable(inner) => inner.as_ref().clone(),
+            Type::Union(types) => {
+                let mut types = types
+                    .iter()
+                    .filter(|t| !matches!(t, Type::Null))
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                match types.len() {
+                    0 => Type::Never,
+                    1 => types.remove(0),
+                    _ => Type::Union(types),
+                }
+            }
+            Type::Null => Type::Never,
+            _ => self.clone(),
+        }
+    }
+
+    /// Add `null` to the type, unless it already allows it.
+    pub fn with_null(&self) -> Type<N> {
+        match self {
+            _ if self.allows_null() => self.clone(),
+            Type::Mixed => self.clone(),
+            Type::Never => Type::Null,
+            Type::Union(types) => {
+                let mut types = types.clone();
+                types.push(Type::Null);
+
+                Type::Union(types)
+            }
+            _ => Type::Union(vec![self.clone(), Type::Null]),
+        }
+    }
+
+    fn includes_iterable(&self) -> bool {
+        match self {
+            Type::Iterable => true,
+            Type::Generic(base, _) => matches!(**base, Type::Iterable),
+            _ => false,
+        }
+    }
+
+    fn into_union_members(self) -> Vec<Type<N>> {
+        match self {
+            Type::Union(types) => types,
+            _ => vec![self],
+        }
+    }
+}
+
+impl<N: Debug + Display + Clone + PartialEq> Type<N> {
+    /// Check whether every value of this type is also a value of the `other` type, e.g. `list<string>`
+    /// is a refinement of `array` and `non-empty-string` is a refinement of `string`.
+    ///
+    /// This is a structural check. Class-likes aren't resolved, so any class-like is assumed to be a
+    /// refinement of any other class-like and generic type arguments aren't compared.
+    pub fn is_refinement_of(&self, other: &Type<N>) -> bool {
+        if self == other {
+            return true;
+        }
+
+        match (self, other) {
+            (Type::Void, _) | (_, Type::Void) => false,
+            (_, Type::Mixed) | (Type::Never, _) => true,
+            (Type::Union(types), _) => types.iter().all(|t| t.is_refinement_of(other)),
+            (Type::Nullable(inner), _) => {
+                Type::Null.is_refinement_of(other) && inner.is_refinement_of(other)
+            }
+            (Type::ArrayKey, _) => {
+                Type::Integer.is_refinement_of(other) && Type::String.is_refinement_of(other)
+            }
+            (_, Type::Union(types)) => types.iter().any(|t| self.is_refinement_of(t)),
+            (_, Type::Nullable(inner)) => self == &Type::Null || self.is_refinement_of(inner),
+            (_, Type::Intersection(types)) => types.iter().all(|t| self.is_refinement_of(t)),
+            (Type::Intersection(types), _) => types.iter().any(|t| t.is_refinement_of(other)),
+            (Type::Generic(base, _), Type::Generic(other, _)) => base.is_refinement_of(other),
+            (_, Type::ArrayKey) => {
+                self.is_refinement_of(&Type::Integer) || self.is_refinement_of(&Type::String)
+            }
+            (_, Type::Object) if self.is_class_like() => true,
+            (
+                _,
+                Type::Named(_)
+                | Type::SelfReference
+                | Type::StaticReference
+                | Type::ParentReference,
+            ) if self.is_class_like() => true,
+            // Any class-like could implement `Traversable` or be invokable.
+            (_, Type::Iterable) if self.is_class_like() => true,
+            (_, Type::Callable) if self.is_class_like() => true,
+            (
+                Type::Array | Type::List | Type::NonEmptyArray | Type::NonEmptyList,
+                Type::Iterable,
+            ) => true,
+            _ => self
+                .widen()
+                .is_some_and(|widened| widened.is_refinement_of(other)),
+        }
+    }
+
+    fn is_class_like(&self) -> bool {
+        match self {
+            Type::Named(_)
+            | Type::SelfReference
+            | Type::StaticReference
+            | Type::ParentReference
+            | Type::This => true,
+            Type::Generic(base, _) => base.is_class_like(),
+            _ => false,
+        }
+    }
+
+    /// Get the closest type that this type is a refinement of, e.g. `string` for `non-empty-string`.
+    fn widen(&self) -> Option<Type<N>> {
+        Some(match self {
+            Type::True | Type::False => Type::Boolean,
+            Type::NonNegativeInteger => Type::Integer,
+            Type::LiteralString(_)
+            | Type::NumericString
+            | Type::NonEmptyString
+            | Type::ClassString
+            | Type::CallableString => Type::String,
+            Type::ConstExpr(inner) => match inner.as_ref() {
+                ConstExpr::NegativeInteger(_) | ConstExpr::Integer(_) => Type::Integer,
+                ConstExpr::Float(_) => Type::Float,
+                ConstExpr::String(_) => Type::String,
+                ConstExpr::ConstFetch(..) => return None,
+            },
+            Type::NonEmptyList => Type::List,
+            Type::List | Type::NonEmptyArray | Type::TypedArray(..) => Type::Array,
+            Type::Shaped { base, .. } | Type::Generic(base, _) => *base.clone(),
+            Type::CallableSignature(base, ..) => *base.clone(),
+            Type::This => Type::StaticReference,
+            Type::StaticReference => Type::SelfReference,
+            Type::NonEmptyMixed => Type::Mixed,
+            _ => return None,
+        })
+    }
+}
+
 impl<N: Debug + Display> Display for Type<N> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self {
@@ -311,12 +555,17 @@ impl<N: Debug + Display> Display for Type<N> {
                 )
             }
             Type::Nullable(inner) => write!(f, "?{}", inner),
+            // Nested unions and intersections need to be wrapped in parentheses to keep
+            // the grouping of DNF types, e.g. `(A & B) | C`.
             Type::Union(inner) => write!(
                 f,
                 "{}",
                 inner
                     .iter()
-                    .map(|t| t.to_string())
+                    .map(|t| match t {
+                        Type::Intersection(_) => format!("({})", t),
+                        _ => t.to_string(),
+                    })
                     .collect::<Vec<String>>()
                     .join(" | ")
             ),
@@ -325,7 +574,10 @@ impl<N: Debug + Display> Display for Type<N> {
                 "{}",
                 inner
                     .iter()
-                    .map(|t| t.to_string())
+                    .map(|t| match t {
+                        Type::Union(_) => format!("({})", t),
+                        _ => t.to_string(),
+                    })
                     .collect::<Vec<String>>()
                     .join(" & ")
             ),
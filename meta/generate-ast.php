@@ -12,7 +12,7 @@ $output = <<<'RUST'
 // This file is generated by meta/scripts/generate-ast.php.
 // Do not make modifications to this file directly.
 
-use crate::{HasId, utils::CommaSeparated, Node, name::NameQualification};
+use crate::{HasId, utils::CommaSeparated, Node, Downcast, name::NameQualification, Fingerprint, Fingerprinter};
 use pxp_type::Type;
 use pxp_token::OwnedToken;
 use pxp_span::{Span, IsSpanned};
@@ -22,28 +22,79 @@ use std::ptr::NonNull;
 
 RUST;
 
-$reserved = ['as', 'derive', 'node', 'children', 'feature', 'rename'];
+$reserved = ['as', 'derive', 'node', 'children', 'feature', 'rename', 'fingerprint'];
 
-function is_spanned(string $node, array $structure): bool {
+function fields_of(array $structure): array {
+    global $reserved;
+
+    return array_filter($structure, fn ($field) => ! in_array($field, $reserved, true), ARRAY_FILTER_USE_KEY);
+}
+
+function is_spanned_type(string $type, array $seen = []): bool {
+    global $ast;
+
+    if (preg_match('/^(?:Box|Vec|Option|CommaSeparated)<(.*)>$/', $type, $matches)) {
+        return is_spanned_type($matches[1], $seen);
+    }
+
+    if ($type === 'Span') {
+        return true;
+    }
+
+    if (! isset($ast[$type]) || ! is_array($ast[$type]) || in_array($type, $seen, true)) {
+        return false;
+    }
+
+    return is_spanned($type, $ast[$type], [...$seen, $type]);
+}
+
+function spans_fields(array $fields, array $seen): bool {
+    return $fields !== []
+        && is_spanned_type(Arr::first($fields), $seen)
+        && is_spanned_type(Arr::last($fields), $seen);
+}
+
+function is_spanned(string $node, array $structure, array $seen = []): bool {
     if (isset($structure['span'])) {
         return true;
     }
 
-    if ($node === 'StatementKind' || $node === 'ExpressionKind') {
+    if (($structure['as'] ?? null) !== 'Enum') {
+        return ($structure['node'] ?? true) !== false && spans_fields(fields_of($structure), $seen);
+    }
+
+    $variants = fields_of($structure);
+
+    if (array_filter($variants, fn ($value) => $value !== '') === []) {
         return false;
     }
 
-    foreach ($structure as $field => $value) {
-        if (is_array($value) && isset($value['span'])) {
-            return true;
+    foreach ($variants as $value) {
+        if (is_array($value)) {
+            if (! isset($value['span']) && ! spans_fields(fields_of($value), $seen)) {
+                return false;
+            }
+        } elseif ($value !== '' && ! is_spanned_type($value, $seen)) {
+            return false;
         }
+    }
 
-        if (is_string($value) && $value === 'Span') {
-            return true;
-        }
+    return true;
+}
+
+function fingerprinted_fields(array $fields): array {
+    return array_keys(array_filter($fields, fn ($type) => ! in_array($type, ['Span', 'CommentGroup'], true)));
+}
+
+function span_of_fields(array $fields, string $prefix): string {
+    $first = array_key_first($fields);
+    $last = array_key_last($fields);
+
+    if ($first === $last) {
+        return "{$prefix}{$first}.span()";
     }
 
-    return false;
+    return "Span::combine({$prefix}{$first}.span(), {$prefix}{$last}.span())";
 }
 
 function feature_flag($structure): string {
@@ -162,6 +213,44 @@ foreach ($ast as $node => $structure) {
         $output .= "}\n\n";
     }
 
+    if (($structure['fingerprint'] ?? true) !== false) {
+        $output .= feature_flag($structure);
+        $output .= "impl Fingerprint for {$node} {\n";
+        $output .= "    fn fingerprint(&self, fingerprinter: &mut Fingerprinter) {\n";
+
+        if ($enum) {
+            $output .= "        match self {\n";
+            foreach (fields_of($structure) as $field => $value) {
+                if ($value === '' || $value === 'Span') {
+                    $pattern = "{$node}::{$field}" . ($value === '' ? '' : '(_)');
+                    $output .= "{$pattern} => fingerprinter.write_tag(\"{$field}\"),\n";
+                } elseif (is_string($value)) {
+                    $output .= "{$node}::{$field}(inner) => {\n";
+                    $output .= "fingerprinter.write_tag(\"{$field}\");\n";
+                    $output .= "inner.fingerprint(fingerprinter);\n";
+                    $output .= "}\n";
+                } else {
+                    $fields = fingerprinted_fields(fields_of($value));
+                    $bindings = implode('', array_map(fn ($field) => "{$field}, ", $fields));
+                    $output .= "{$node}::{$field} { {$bindings}.. } => {\n";
+                    $output .= "fingerprinter.write_tag(\"{$field}\");\n";
+                    foreach ($fields as $subfield) {
+                        $output .= "{$subfield}.fingerprint(fingerprinter);\n";
+                    }
+                    $output .= "}\n";
+                }
+            }
+            $output .= "        }\n";
+        } else {
+            foreach (fingerprinted_fields(fields_of($structure)) as $field) {
+                $output .= "        self.{$field}.fingerprint(fingerprinter);\n";
+            }
+        }
+
+        $output .= "    }\n";
+        $output .= "}\n\n";
+    }
+
     if (! is_spanned($node, $structure)) {
         continue;
     }
@@ -172,20 +261,25 @@ foreach ($ast as $node => $structure) {
     
     if (isset($structure['span'])) {
         $output .= "        self.span";
-    } elseif ($enum) {
+    } elseif (! $enum) {
+        $output .= "        " . span_of_fields(fields_of($structure), 'self.');
+    } else {
         $output .= "        match self {";
-        foreach ($structure as $field => $value) {
-            if (in_array($field, $reserved, true)) {
-                continue;
-            }
-
-            if (is_string($value) && $value === 'Span') {
+        foreach (fields_of($structure) as $field => $value) {
+            if ($value === '') {
+                $output .= "{$node}::{$field} => Span::default(),\n";
+            } elseif ($value === 'Span') {
                 $output .= "{$node}::{$field}(span) => *span,\n";
-            } elseif (is_array($value)) {
+            } elseif (is_string($value)) {
+                $output .= "{$node}::{$field}(inner) => inner.span(),\n";
+            } elseif (isset($value['span'])) {
                 $output .= "{$node}::{$field} { span, .. } => *span,";
+            } else {
+                $fields = fields_of($value);
+                $bindings = implode(', ', array_unique([array_key_first($fields), array_key_last($fields)]));
+                $output .= "{$node}::{$field} { {$bindings}, .. } => " . span_of_fields($fields, '') . ",\n";
             }
         }
-        $output .= "_ => Span::default(),\n";
         $output .= "        }";
     }
 
@@ -455,6 +549,30 @@ foreach ($ast as $node => $structure) {
     $output .= "}\n\n";
 }
 
+foreach ($ast as $node => $structure) {
+    if ($node === 'NodeId') {
+        continue;
+    }
+
+    if (isset($structure['node']) && $structure['node'] === false) {
+        continue;
+    }
+
+    if (isset($structure['rename'])) {
+        $node = $structure['rename'];
+    }
+
+    $output .= feature_flag($structure);
+
+    $kebab = strtolower(Str::snake($node));
+
+    $output .= "impl Downcast for {$node} {\n";
+    $output .= "    fn downcast<'a>(node: Node<'a>) -> Option<&'a Self> {\n";
+    $output .= "        node.as_{$kebab}()\n";
+    $output .= "    }\n";
+    $output .= "}\n\n";
+}
+
 file_put_contents(__DIR__ . '/../crates/ast/src/generated.rs', $output);
 
 echo "AST file generated.\n";
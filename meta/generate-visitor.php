@@ -67,11 +67,13 @@ class VisitorGenerator
                 continue;
             }
 
-            $method = feature_flag($fields) . sprintf("fn %s(&mut self, node: %s%s) {\n", $this->generateVisitorMethodName($type, $fields), $template->getNodeTypePrefix(), $this->stripTypeToRoot($type, $fields));
+            $method = feature_flag($fields) . sprintf("fn %s(&mut self, node: %s%s)%s {\n", $this->generateVisitorMethodName($type, $fields), $template->getNodeTypePrefix(), $this->stripTypeToRoot($type, $fields), $template->hasControlFlow() ? ' -> Traversal' : '');
             $fields = $this->getAllVisitableFields($fields);
 
             if (count($fields) > 0) {
-                $method .= sprintf("%s(self, node);\n", $this->generateWalkMethodName($type, $template));
+                $method .= sprintf("%s(self, node)%s\n", $this->generateWalkMethodName($type, $template), $template->hasControlFlow() ? '' : ';');
+            } elseif ($template->hasControlFlow()) {
+                $method .= "Traversal::Continue\n";
             }
 
             $method .= "}\n";
@@ -106,11 +108,12 @@ class VisitorGenerator
             }
 
             $function = feature_flag($originalFields) . sprintf(
-                "pub fn %s<V: %s + ?Sized>(visitor: &mut V, node: %s%s) {\n",
+                "pub fn %s<V: %s + ?Sized>(visitor: &mut V, node: %s%s)%s {\n",
                 $this->generateWalkMethodName($type, $template),
                 class_basename($template),
                 $template->getNodeTypePrefix(),
                 $this->stripTypeToRoot($type),
+                $template->hasControlFlow() ? ' -> Traversal' : '',
             );
 
             $function = match ($isEnum) {
@@ -118,6 +121,10 @@ class VisitorGenerator
                 false => $this->generateWalkStructFunction($function, $type, $fields, $template),
             };
 
+            if ($template->hasControlFlow()) {
+                $function .= "Traversal::Continue\n";
+            }
+
             $function .= "}\n";
 
             $walkers[] = $function;
@@ -142,7 +149,12 @@ class VisitorGenerator
                 $function .= " => {},\n";
             } elseif (is_string($field) && $this->isSimpleType($field) && $field !== 'Span') {
                 // Enum variants with a single field can be walked directly.
-                $function .= sprintf("(inner) => visitor.%s(inner),\n", $this->generateVisitorMethodName($field, $template));
+                $call = sprintf("visitor.%s(inner)", $this->generateVisitorMethodName($field, $template));
+
+                $function .= match ($template->hasControlFlow()) {
+                    true => sprintf("(inner) => {\n%s},\n", $this->generateVisitCall($call, $template)),
+                    false => sprintf("(inner) => %s,\n", $call),
+                };
             } elseif (is_array($field)) {
                 // Enum struct variants needs to be destructured.
                 $function .= " { ";
@@ -194,30 +206,43 @@ class VisitorGenerator
         $logic = '';
 
         if (is_string($type) && $this->isSimpleType($type)) {
-            $logic .= sprintf("visitor.%s(%s%s);\n", $this->generateVisitorMethodName($type), $prefix ? "{$template->getNodeTypePrefix()}node." : '', $field);
+            $logic .= $this->generateVisitCall(sprintf("visitor.%s(%s%s)", $this->generateVisitorMethodName($type), $prefix ? "{$template->getNodeTypePrefix()}node." : '', $field), $template);
         }
 
         if (is_string($type) && $this->isVecType($type)) {
             $logic .= sprintf("for item in %s%s {\n", $prefix ? "{$template->getNodeTypePrefix()}node." : '', $field);
-            $logic .= sprintf("visitor.%s(item);\n", $this->generateVisitorMethodName($type));
+            $logic .= $this->generateVisitCall(sprintf("visitor.%s(item)", $this->generateVisitorMethodName($type)), $template);
             $logic .= '}';
         }
 
         if (is_string($type) && $this->isOptionType($type)) {
             $logic .= sprintf("if let Some(item) = %s%s {\n", $prefix ? "{$template->getNodeTypePrefix()}node." : '', $field);
-            $logic .= sprintf("visitor.%s(item);\n", $this->generateVisitorMethodName($type));
+            $logic .= $this->generateVisitCall(sprintf("visitor.%s(item)", $this->generateVisitorMethodName($type)), $template);
             $logic .= '}';
         }
 
         if (is_string($type) && $this->isCommaSeparatedType($type)) {
             $logic .= sprintf("for item in %s%s.inner {\n", $prefix ? "{$template->getNodeTypePrefix()}node." : '', $field);
-            $logic .= sprintf("visitor.%s(item);\n", $this->generateVisitorMethodName($type));
+            $logic .= $this->generateVisitCall(sprintf("visitor.%s(item)", $this->generateVisitorMethodName($type)), $template);
             $logic .= '}';
         }
 
         return $logic;
     }
 
+    /**
+     * Generate a statement calling a `visit_*` method. Visitors with control flow
+     * return early when the call asks for the whole walk to stop.
+     */
+    private function generateVisitCall(string $call, VisitorTemplate $template): string
+    {
+        if (! $template->hasControlFlow()) {
+            return "{$call};\n";
+        }
+
+        return "if {$call}.is_stop() {\nreturn Traversal::Stop;\n}\n";
+    }
+
     private function isSimpleType(string $type): bool
     {
         return !$this->isVecType($type) && ! $this->isOptionType($type) && ! $this->isCommaSeparatedType($type);
@@ -243,7 +268,7 @@ class VisitorGenerator
         return collect($fields)
             ->filter(function (mixed $field, string $key) {
                 // These are reserved keys.
-                if (in_array($key, ['as', 'derive', 'node', 'children', 'feature', 'rename'])) {
+                if (in_array($key, ['as', 'derive', 'node', 'children', 'feature', 'rename', 'fingerprint'])) {
                     return false;
                 }
 
@@ -315,6 +340,9 @@ interface VisitorTemplate
 
     /** The prefix added to node types for `visit_*` and `walk_*` functions. */
     public function getNodeTypePrefix(): string;
+
+    /** Whether `visit_*` and `walk_*` functions return a `Traversal`. */
+    public function hasControlFlow(): bool;
 }
 
 class VisitorMut implements VisitorTemplate
@@ -380,6 +408,11 @@ RUST;
     {
         return '&mut ';
     }
+
+    public function hasControlFlow(): bool
+    {
+        return false;
+    }
 }
 
 class Visitor implements VisitorTemplate
@@ -440,7 +473,155 @@ RUST;
     public function getNodeTypePrefix(): string {
         return '&';
     }
+
+    public function hasControlFlow(): bool {
+        return false;
+    }
+}
+
+class ControlFlowVisitorMut implements VisitorTemplate
+{
+    public function getVisitorTemplate(): string
+    {
+        return <<<RUST
+// This file is automatically generated by the generate-visitor.php script.
+// Do not modify this file directly.
+#![allow(unused, clippy::single_match)]
+
+use crate::*;
+use super::Traversal;
+use super::walk_control_flow_mut::*;
+use pxp_span::Span;
+use pxp_type::Type;
+
+pub trait ControlFlowVisitorMut {
+    fn visit(&mut self, node: &mut [Statement]) -> Traversal {
+        walk_mut(self, node)
+    }
+
+    %s
+}
+RUST;
+    }
+
+    public function getVisitorFilename(): string
+    {
+        return 'control_flow_mut.rs';
+    }
+
+    public function getWalkMethodSuffix(): string
+    {
+        return '_mut';
+    }
+
+    public function getWalkTemplate(): string
+    {
+        return <<<RUST
+// This file is automatically generated by the generate-visitor.php script.
+// Do not modify this file directly.
+#![allow(unused, clippy::single_match, clippy::collapsible_match)]
+
+use super::{ControlFlowVisitorMut, Traversal};
+use crate::*;
+
+pub fn walk_mut<V: ControlFlowVisitorMut + ?Sized>(visitor: &mut V, node: &mut [Statement]) -> Traversal {
+    for statement in node {
+        if visitor.visit_statement(statement).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+
+    Traversal::Continue
+}
+
+%s
+RUST;
+    }
+
+    public function getWalkFilename(): string
+    {
+        return 'walk_control_flow_mut.rs';
+    }
+
+    public function getNodeTypePrefix(): string
+    {
+        return '&mut ';
+    }
+
+    public function hasControlFlow(): bool
+    {
+        return true;
+    }
+}
+
+class ControlFlowVisitor implements VisitorTemplate
+{
+    public function getVisitorTemplate(): string
+    {
+        return <<<RUST
+// This file is automatically generated by the generate-visitor.php script.
+// Do not modify this file directly.
+#![allow(unused, clippy::single_match)]
+
+use crate::*;
+use super::Traversal;
+use super::walk_control_flow::*;
+use pxp_span::Span;
+use pxp_type::Type;
+
+pub trait ControlFlowVisitor {
+    fn visit(&mut self, node: &[Statement]) -> Traversal {
+        walk(self, node)
+    }
+
+    %s
+}
+RUST;
+    }
+
+    public function getVisitorFilename(): string {
+        return 'control_flow.rs';
+    }
+
+    public function getWalkMethodSuffix(): string {
+        return '';
+    }
+
+    public function getWalkTemplate(): string {
+        return <<<RUST
+// This file is automatically generated by the generate-visitor.php script.
+// Do not modify this file directly.
+#![allow(unused, clippy::single_match, clippy::collapsible_match)]
+
+use super::{ControlFlowVisitor, Traversal};
+use crate::*;
+
+pub fn walk<V: ControlFlowVisitor + ?Sized>(visitor: &mut V, node: &[Statement]) -> Traversal {
+    for statement in node {
+        if visitor.visit_statement(statement).is_stop() {
+            return Traversal::Stop;
+        }
+    }
+
+    Traversal::Continue
+}
+
+%s
+RUST;
+    }
+
+    public function getWalkFilename(): string {
+        return 'walk_control_flow.rs';
+    }
+
+    public function getNodeTypePrefix(): string {
+        return '&';
+    }
+
+    public function hasControlFlow(): bool {
+        return true;
+    }
 }
 
-$generator = new VisitorGenerator([new VisitorMut, new Visitor]);
+$generator = new VisitorGenerator([new VisitorMut, new Visitor, new ControlFlowVisitorMut, new ControlFlowVisitor]);
 $generator->generate();
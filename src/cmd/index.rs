@@ -2,7 +2,7 @@ use std::path::{Path, PathBuf};
 
 use clap::Parser;
 use colored::Colorize;
-use pxp_index::Index as Indexer;
+use pxp_index::{Index as Indexer, IndexBuilder};
 use rustyline::{error::ReadlineError, CompletionType, Config, DefaultEditor};
 
 use crate::utils::{find_php_files_in, pxp_home_dir, ProgressBar};
@@ -26,10 +26,10 @@ pub fn index(args: Index) -> anyhow::Result<()> {
         anyhow::bail!("The path `{}` does not exist.", args.path.display());
     }
 
-    let mut index = Indexer::new();
+    let mut builder = IndexBuilder::new();
 
-    perform(&args, &mut index, &args.path)?;
-    repl(&index)?;
+    perform(&args, &mut builder, &args.path)?;
+    repl(&builder.build())?;
 
     Ok(())
 }
@@ -99,9 +99,9 @@ fn handle(command: &str, index: &Indexer) -> anyhow::Result<()> {
     Ok(())
 }
 
-fn perform(args: &Index, index: &mut Indexer, path: &Path) -> anyhow::Result<()> {
+fn perform(args: &Index, builder: &mut IndexBuilder, path: &Path) -> anyhow::Result<()> {
     if path.is_file() {
-        index.index_file(path);
+        builder.index_file(path);
     } else {
         let files = find_php_files_in(path)?;
         let bar = ProgressBar::new(!args.no_progress, files.len() as u64);
@@ -109,7 +109,7 @@ fn perform(args: &Index, index: &mut Indexer, path: &Path) -> anyhow::Result<()>
         for file in files {
             bar.set_message(file.display().to_string());
 
-            index.index_file(&file);
+            builder.index_file(&file);
 
             bar.inc(1);
         }
@@ -1,10 +1,10 @@
 use std::path::{Path, PathBuf};
 
 use clap::Parser as Args;
-use pxp_diagnostics::DiagnosticKind;
+use pxp_diagnostics::{DiagnosticKind, SarifReport};
 use pxp_lexer::Lexer;
 use pxp_parser::Parser;
-use pxp_span::IsSpanned;
+use pxp_span::{IsSpanned, LineIndex};
 
 use crate::utils::find_php_files_in;
 
@@ -22,6 +22,12 @@ pub struct Parse {
 
     #[arg(short, long, help = "Print diagnostics after parsing a file.")]
     print_diagnostics: bool,
+
+    #[arg(
+        long,
+        help = "Print the diagnostics for all files as a single SARIF log instead."
+    )]
+    sarif: bool,
 }
 
 pub fn parse(args: Parse) -> anyhow::Result<()> {
@@ -31,18 +37,31 @@ pub fn parse(args: Parse) -> anyhow::Result<()> {
         vec![args.path]
     };
 
+    let mut report = args
+        .sarif
+        .then(|| SarifReport::new("pxp", env!("CARGO_PKG_VERSION")));
+
     for file in files {
         if args.print_filenames {
             println!("{}", file.display());
         }
 
-        parse_file(&file, args.dump, args.print_diagnostics)?;
+        parse_file(&file, args.dump, args.print_diagnostics, report.as_mut())?;
+    }
+
+    if let Some(report) = report {
+        println!("{}", report.to_json());
     }
 
     Ok(())
 }
 
-fn parse_file(path: &Path, dump: bool, print_diagnostics: bool) -> anyhow::Result<()> {
+fn parse_file(
+    path: &Path,
+    dump: bool,
+    print_diagnostics: bool,
+    report: Option<&mut SarifReport>,
+) -> anyhow::Result<()> {
     let contents = std::fs::read(path)?;
     let ast = Parser::parse(Lexer::new(&contents));
 
@@ -50,14 +69,31 @@ fn parse_file(path: &Path, dump: bool, print_diagnostics: bool) -> anyhow::Resul
         println!("{:#?}", ast);
     }
 
+    if let Some(report) = report {
+        report.add(path, &LineIndex::new(&contents), &ast.diagnostics);
+
+        return Ok(());
+    }
+
     if print_diagnostics && !ast.diagnostics.is_empty() {
         for diagnostic in &ast.diagnostics {
             println!(
-                "{} on line {}, column {}",
+                "{} {}: {} on line {}, column {}",
+                diagnostic.severity,
+                diagnostic.kind.get_code(),
                 diagnostic.kind.get_message(),
                 diagnostic.span.start_line(&contents),
                 diagnostic.span.start_column(&contents)
             );
+
+            for (span, message) in &diagnostic.related {
+                println!(
+                    "  note: {} on line {}, column {}",
+                    message,
+                    span.start_line(&contents),
+                    span.start_column(&contents)
+                );
+            }
         }
     }
 
@@ -0,0 +1,224 @@
+//! The public API of pxp, a suite of tools for working with PHP code.
+//!
+//! The workspace is split into a crate for each part of the pipeline, but those crates are free
+//! to move things around between them. This crate re-exports the parts that are meant to be used
+//! from the outside under paths that stay the same, so depending on `pxp` is all that's needed.
+//!
+//! ```
+//! let result = pxp::parse("<?php echo 'Hello, world!';");
+//!
+//! assert!(result.diagnostics.is_empty());
+//! assert_eq!(result.ast.len(), 2);
+//! ```
+//!
+//! # Features
+//!
+//! - `index` (default) adds [`index`], which collects the functions, classes and constants that
+//!   are declared in a set of files.
+//! - `inference` (default) adds [`inference`], which works out the types of expressions, along
+//!   with [`analyse`] to parse, index and infer a file in one go.
+//! - `docblocks` (default) adds `ast::DocBlockFormatter`, which formats docblocks. Docblocks are
+//!   always parsed into the AST, since they're attached to the nodes around them.
+//! - `cli` (default) builds the `pxp` command line tool.
+
+use std::{io, path::Path};
+
+pub use pxp_bytestring::{ByteStr, ByteString};
+pub use pxp_span::{IsSpanned, Span};
+
+/// The nodes that make up a PHP file.
+pub mod ast {
+    pub use pxp_ast::*;
+}
+
+/// Walking the AST, either by reference or mutably.
+pub mod visitor {
+    pub use pxp_ast::visitor::*;
+}
+
+/// Turning PHP source code into tokens.
+pub mod lexer {
+    pub use pxp_lexer::Lexer;
+    pub use pxp_token::{OwnedToken, Token, TokenKind};
+}
+
+/// Turning tokens into an AST.
+pub mod parser {
+    pub use pxp_parser::{
+        minimum_php_version, Feature, FeatureUse, FileContext, Import, NamespaceContext,
+        NamespaceKind, ParseResult, Parser, ParserDiagnostic, ParserHooks, ParserOptions,
+        PhpVersion,
+    };
+}
+
+/// Types, as written in type declarations and docblocks or as inferred.
+pub mod types {
+    pub use pxp_type::*;
+}
+
+/// The diagnostics that are reported by each part of the pipeline.
+pub mod diagnostics {
+    pub use pxp_diagnostics::*;
+}
+
+#[cfg(feature = "index")]
+pub mod index {
+    //! The functions, classes and constants that are declared in a set of files.
+    pub use pxp_index::*;
+}
+
+#[cfg(feature = "inference")]
+pub mod inference {
+    //! The types of the expressions in a file.
+    pub use pxp_inference::*;
+}
+
+/// PHP source code that has been read into memory, along with the path it was read from.
+///
+/// ```
+/// let source = pxp::Source::from("<?php $a = 1;");
+/// let result = source.parse();
+///
+/// assert!(source.path().is_none());
+/// assert_eq!(result.ast.len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Source {
+    path: Option<std::path::PathBuf>,
+    contents: Vec<u8>,
+}
+
+impl Source {
+    /// Read the source code from a file.
+    pub fn from_path(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+
+        Ok(Self {
+            path: Some(path.to_path_buf()),
+            contents: std::fs::read(path)?,
+        })
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn contents(&self) -> &[u8] {
+        &self.contents
+    }
+
+    /// Create a lexer that reads the source code from the start.
+    pub fn lexer(&self) -> lexer::Lexer<'_> {
+        lexer::Lexer::new(&self.contents)
+    }
+
+    pub fn parse(&self) -> parser::ParseResult {
+        parse(&self.contents)
+    }
+
+    pub fn parse_with_options(&self, options: parser::ParserOptions) -> parser::ParseResult {
+        parse_with_options(&self.contents, options)
+    }
+}
+
+impl From<&str> for Source {
+    fn from(contents: &str) -> Self {
+        Self::from(contents.as_bytes())
+    }
+}
+
+impl From<&[u8]> for Source {
+    fn from(contents: &[u8]) -> Self {
+        Self {
+            path: None,
+            contents: contents.to_vec(),
+        }
+    }
+}
+
+impl From<String> for Source {
+    fn from(contents: String) -> Self {
+        Self {
+            path: None,
+            contents: contents.into_bytes(),
+        }
+    }
+}
+
+/// Parse PHP source code, which can be a `&str`, `&[u8]` or anything else that can be read as
+/// bytes.
+///
+/// Parsing never fails. Anything that isn't valid is reported in the diagnostics, and the AST
+/// contains everything that could be recovered.
+pub fn parse<B: ?Sized + AsRef<[u8]>>(input: &B) -> parser::ParseResult {
+    parser::Parser::parse(lexer::Lexer::new(input))
+}
+
+/// Parse PHP source code with the given options, e.g. to report syntax that's too new for a
+/// particular version of PHP.
+///
+/// ```
+/// use pxp::parser::{ParserOptions, PhpVersion};
+///
+/// let result = pxp::parse_with_options(
+///     "<?php exit(status: 1);",
+///     ParserOptions::new().with_php_version(PhpVersion::PHP_83),
+/// );
+///
+/// assert_eq!(result.diagnostics.len(), 1);
+/// ```
+pub fn parse_with_options<B: ?Sized + AsRef<[u8]>>(
+    input: &B,
+    options: parser::ParserOptions,
+) -> parser::ParseResult {
+    parser::Parser::parse_with_options(lexer::Lexer::new(input), options)
+}
+
+/// Read and parse the file at the given path.
+pub fn parse_file(path: impl AsRef<Path>) -> io::Result<parser::ParseResult> {
+    Ok(Source::from_path(path)?.parse())
+}
+
+/// A file that has been parsed, indexed and had its types inferred, returned by [`analyse`].
+#[cfg(feature = "inference")]
+#[derive(Debug)]
+pub struct Analysis {
+    pub result: parser::ParseResult,
+    /// An index of the declarations in the file, which is the only file it knows about.
+    pub index: index::Index,
+    pub types: inference::TypeMap,
+}
+
+/// Parse PHP source code, index the declarations in it and infer the types of its expressions.
+///
+/// This is enough for a single file. When the code uses declarations from other files, they
+/// need to be indexed first, so the parts should be used directly instead.
+///
+/// ```
+/// use pxp::ast::StatementKind;
+/// use pxp::types::Type;
+///
+/// let analysis = pxp::analyse("<?php function a(): int {} a();");
+///
+/// let StatementKind::Expression(statement) = &analysis.result.ast[2].kind else {
+///     unreachable!()
+/// };
+///
+/// assert_eq!(analysis.types.resolve(statement.expression.id), &Type::Integer);
+/// ```
+#[cfg(feature = "inference")]
+pub fn analyse<B: ?Sized + AsRef<[u8]>>(input: &B) -> Analysis {
+    let result = parse(input);
+
+    let mut builder = index::IndexBuilder::new();
+    builder.index(index::FileId::new(0), &result.ast);
+    let index = builder.build();
+
+    let types = inference::TypeEngine::new(&index).infer(&result.ast);
+
+    Analysis {
+        result,
+        index,
+        types,
+    }
+}
@@ -41,7 +41,7 @@ pub(crate) fn find_php_files_in(path: &Path) -> anyhow::Result<Vec<PathBuf>> {
 
         if path.is_dir() {
             files.append(&mut find_php_files_in(&path)?);
-        } else if path.extension().map_or(false, |ext| ext == "php") {
+        } else if path.extension().is_some_and(|ext| ext == "php") {
             files.push(path);
         }
     }